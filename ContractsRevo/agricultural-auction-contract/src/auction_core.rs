@@ -1,8 +1,14 @@
-use soroban_sdk::{contractimpl, Address, Env, Symbol};
+use soroban_sdk::{contractimpl, vec, Address, Env, Symbol};
 
+use crate::lot_auction::is_product_locked;
+use crate::moderation::{is_blacklisted, track_open_auction};
+use crate::price_oracle::check_start_price;
+use crate::quality_link::meets_min_grade;
+use crate::settlement::{escrow_winning_bid, has_open_settlement};
 use crate::{
     AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
     AgriculturalAuctionContractClient, AgriculturalProduct, Auction, AuctionError, DataKey,
+    SettledProduct,
 };
 
 pub trait AuctionOperations {
@@ -52,6 +58,11 @@ impl AuctionOperations for AgriculturalAuctionContract {
     ) -> Result<(), AuctionError> {
         farmer.require_auth();
 
+        // Blacklisted sellers cannot open new auctions
+        if is_blacklisted(&env, &farmer) {
+            return Err(AuctionError::Blacklisted);
+        }
+
         let key = &DataKey::Auction(farmer.clone(), product_id);
 
         // Ensure auction does not already exist
@@ -59,6 +70,16 @@ impl AuctionOperations for AgriculturalAuctionContract {
             return Err(AuctionError::AuctionAlreadyExists);
         }
 
+        // Products bundled into a lot cannot be auctioned individually
+        if is_product_locked(&env, &farmer, product_id) {
+            return Err(AuctionError::ProductLocked);
+        }
+
+        // The previous sale of the product has to be settled first
+        if has_open_settlement(&env, &farmer, product_id) {
+            return Err(AuctionError::SettlementPending);
+        }
+
         // Get product details
         let product_key = DataKey::Product(farmer.clone(), product_id);
         let product: AgriculturalProduct = env
@@ -67,6 +88,11 @@ impl AuctionOperations for AgriculturalAuctionContract {
             .get(&product_key)
             .ok_or(AuctionError::ProductNotFound)?;
 
+        // Product types may require a minimum certified grade
+        if !meets_min_grade(&env, &product) {
+            return Err(AuctionError::GradeTooLow);
+        }
+
         // Check if product is expired
         let current_time = env.ledger().timestamp();
         if product.expiry_date <= current_time {
@@ -88,6 +114,12 @@ impl AuctionOperations for AgriculturalAuctionContract {
             return Err(AuctionError::QuantityUnavailable);
         }
 
+        // Validate the start price against the market price band, if one is configured
+        let reference_price = check_start_price(&env, &product, reserve_price)?;
+
+        // Enforce the per-farmer cap on concurrently open auctions
+        track_open_auction(&env, &farmer, product_id)?;
+
         // Create a new auction
         let auction = Auction {
             product_id,
@@ -101,6 +133,7 @@ impl AuctionOperations for AgriculturalAuctionContract {
             bulk_discount_threshold,
             bulk_discount_percentage,
             dynamic_pricing,
+            reference_price,
         };
 
         // Save the auction to storage
@@ -129,6 +162,11 @@ impl AuctionOperations for AgriculturalAuctionContract {
     ) -> Result<bool, AuctionError> {
         bidder.require_auth();
 
+        // Blacklisted bidders cannot participate
+        if is_blacklisted(&env, &bidder) {
+            return Err(AuctionError::Blacklisted);
+        }
+
         let key = DataKey::Auction(farmer.clone(), product_id);
 
         // Fetch the auction from storage
@@ -289,9 +327,10 @@ impl AuctionOperations for AgriculturalAuctionContract {
         }
 
         // Check if there were any bids
-        if auction.highest_bidder.is_none() {
-            return Err(AuctionError::NoBidsPlaced);
-        }
+        let winner = auction
+            .highest_bidder
+            .clone()
+            .ok_or(AuctionError::NoBidsPlaced)?;
 
         // Get the product to update quantity
         let product_key = DataKey::Product(farmer.clone(), product_id);
@@ -307,6 +346,21 @@ impl AuctionOperations for AgriculturalAuctionContract {
         // Save the updated product
         env.storage().persistent().set(&product_key, &product);
 
+        // Hold the winning bid in escrow until delivery is confirmed
+        escrow_winning_bid(
+            &env,
+            &farmer,
+            vec![
+                &env,
+                SettledProduct {
+                    product_id,
+                    quantity: auction.quantity_available,
+                },
+            ],
+            &winner,
+            auction.highest_bid,
+        )?;
+
         // Remove the auction (or mark as completed)
         env.storage().instance().remove(&key);
 
@@ -317,7 +371,7 @@ impl AuctionOperations for AgriculturalAuctionContract {
                 Symbol::new(&env, "AuctionFinalized"),
                 product_id,
             ),
-            (auction.highest_bidder.unwrap(), auction.highest_bid),
+            (winner, auction.highest_bid),
         );
 
         Ok(())
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Symbol, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[contracterror]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -24,6 +24,17 @@ pub enum AuctionError {
     ProductExpired = 12,
     BulkPurchaseUnavailable = 13,
     QuantityUnavailable = 14,
+    StartPriceOutOfBand = 15,
+    ReferencePriceUnavailable = 16,
+    Blacklisted = 17,
+    TooManyOpenAuctions = 18,
+    ProductLocked = 19,
+    InvalidLot = 20,
+    LotHasBids = 21,
+    PartialBidsDisabled = 22,
+    LotNotOpen = 23,
+    GradeTooLow = 24,
+    SettlementPending = 25,
 }
 
 #[contracterror]
@@ -41,6 +52,8 @@ pub enum ProductError {
     OutOfSeason = 10,
     InvalidCertification = 11,
     SeasonalDataNotAvailable = 12,
+    QualityContractNotSet = 13,
+    InvalidGrade = 14,
 }
 
 #[contracterror]
@@ -60,6 +73,31 @@ pub enum OracleError {
     PriceDataNotAvailable = 1,
     RegionNotSupported = 2,
     InvalidPriceData = 3,
+    Unauthorized = 4,
+    InvalidPriceBand = 5,
+}
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettlementError {
+    Unauthorized = 1,
+    SettlementNotFound = 2,
+    InvalidStatus = 3,
+    DeliveryWindowOpen = 4,
+    SupplyChainProductNotFound = 5,
+    InvalidTimeout = 6,
+    PaymentWindowClosed = 7,
+    PaymentWindowOpen = 8,
+    InvalidSplit = 9,
+}
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ModerationError {
+    Unauthorized = 1,
+    AlreadyBlacklisted = 2,
+    NotBlacklisted = 3,
+    InvalidThreshold = 4,
 }
 
 #[contracterror]
@@ -134,6 +172,49 @@ pub struct AgriculturalProduct {
     pub product_type: Symbol,
     pub region: Symbol,
     pub seasonal_status: SeasonalStatus,
+    pub quality_certification: Option<BytesN<32>>, // Approved certification in the quality contract
+    pub grade: Symbol, // Grade derived from the certification audit score, `ungraded` until attached
+}
+
+// Mirror of the agricultural-quality-contract certification types, used to
+// decode its `get_certification` result.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QualityCertStandard {
+    GlobalGAP,
+    Organic,
+    Fairtrade,
+    UTZ,
+    NonGMO,
+    PDO,
+    PGI,
+    Kosher,
+    GOTS,
+    Demeter,
+    Custom(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QualityCertStatus {
+    Pending,
+    Active,
+    Suspended,
+    Revoked,
+    Expired,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QualityCertification {
+    pub holder: Address,
+    pub standard: QualityCertStandard,
+    pub status: QualityCertStatus,
+    pub issue_date: u64,
+    pub expiry_date: u64,
+    pub issuer: Address,
+    pub audit_score: u32,
+    pub conditions: Vec<String>,
 }
 
 #[contracttype]
@@ -150,6 +231,7 @@ pub struct Auction {
     pub bulk_discount_threshold: u32,
     pub bulk_discount_percentage: u32,
     pub dynamic_pricing: bool,
+    pub reference_price: Option<u64>, // Market price observed at creation, for audit
 }
 
 #[contracttype]
@@ -163,6 +245,125 @@ pub struct MarketPrice {
     pub volume: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceBand {
+    pub min_bps: u32, // Lowest allowed start price, in basis points of market price
+    pub max_bps: u32, // Highest allowed start price, in basis points of market price
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceStalenessConfig {
+    pub max_age: u64, // Seconds after which a market price is considered stale
+    pub allow_stale_bypass: bool, // Skip band validation instead of failing on stale data
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementConfig {
+    pub payment_token: Address,
+    pub payment_window: u64, // Seconds the winner has to fund escrow if it could not be pulled
+    pub delivery_timeout: u64, // Seconds the farmer has to deliver before the buyer may reclaim
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoyaltyConfig {
+    pub loyalty_contract: Address,
+    pub program_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProceedsSplit {
+    pub coop_treasury: Address,
+    pub coop_bps: u32, // Share of delivered proceeds routed to the cooperative treasury
+    pub cert_royalty_bps: u32, // Share paid to the issuer of the product's quality certification
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettlementStatus {
+    AwaitingPayment, // Winner has not funded the escrow yet
+    Defaulted,       // Winner never paid, sale cancelled
+    Escrowed,        // Winning bid held by the contract
+    Delivered,       // Buyer confirmed delivery, funds released to farmer
+    Disputed,        // Farmer disputes non-confirmation, escrow on hold
+    Refunded,        // Escrow returned to the buyer
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettledProduct {
+    pub product_id: u64,
+    pub quantity: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Settlement {
+    pub product_id: u64,               // Product the settlement is stored under
+    pub products: Vec<SettledProduct>, // Every product the sale covers, starting with `product_id`
+    pub farmer: Address,
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub quantity: u32,
+    pub finalized_at: u64,
+    pub payment_deadline: u64,
+    pub delivery_deadline: u64,
+    pub status: SettlementStatus,
+    pub supply_chain_product_id: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LotStatus {
+    Open,
+    Finalized,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotAward {
+    pub winner: Address,
+    pub product_ids: Vec<u64>,
+    pub amount: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LotAuction {
+    pub lot_id: u64,
+    pub farmer: Address,
+    pub product_ids: Vec<u64>,
+    pub reserve_price: u64, // Minimum bid for the whole lot
+    pub auction_end_time: u64,
+    pub highest_bid: u64,
+    pub highest_bidder: Option<Address>,
+    pub allow_partial: bool, // Accept per-product component bids
+    pub component_bid_count: u32,
+    pub status: LotStatus,
+    pub awards: Vec<LotAward>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComponentBid {
+    pub bidder: Address,
+    pub amount: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlacklistEntry {
+    pub reason: String,
+    pub blacklisted_at: u64,
+    pub automatic: bool, // Set by the strike system rather than the admin
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct QualityDispute {
@@ -197,4 +398,26 @@ pub enum DataKey {
     SeasonalStatus(Symbol, Symbol),        // Seasonal status for product type in a region
     PriceHistory(Symbol, Symbol, u64),     // Historical price data with timestamp
     StorageConditionMonitor(Address, u64), // Storage condition monitoring for a product
+    PriceOracle,                           // Address allowed to push market prices
+    PriceBand(Symbol),                     // Start price band for a product type
+    PriceStaleness,                        // Staleness rules for reference prices
+    SettlementConfig,                      // Payment token and delivery timeout
+    SupplyChainContract,                   // Supply-chain tracking contract for delivery checks
+    Settlement(Address, u64),              // Escrowed settlement for a finalized auction
+    SettledUnder(Address, u64),            // Product whose lot settlement covers this product
+    Blacklist(Address),                    // Blacklist entry for a seller or bidder
+    BlacklistIndex,                        // All currently blacklisted addresses
+    Strikes(Address),                      // Settlement or delivery failures per address
+    StrikeThreshold,                       // Strikes that trigger an automatic blacklist
+    MaxOpenAuctions,                       // Cap on concurrent open auctions per farmer
+    FarmerAuctions(Address),               // Product ids of a farmer's possibly-open auctions
+    LotCounter,                            // Last assigned lot auction id
+    LotAuction(u64),                       // Lot auction bundling several products
+    LotComponentBid(u64, u64),             // Highest per-product bid within a lot
+    ProductLock(Address, u64),             // Lot currently holding a farmer's product
+    FarmerLots(Address),                   // Lot ids of a farmer's possibly-open lot auctions
+    QualityContract,                       // Agricultural-quality contract trusted for grades
+    MinGrade(Symbol),                      // Minimum grade to auction a product type
+    LoyaltyProgram,                        // Loyalty program credited on delivered settlements
+    ProceedsSplit,                         // Treasury and certifier shares of delivered proceeds
 }
@@ -3,8 +3,12 @@ use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
 
 mod auction_core;
 mod datatype;
+mod lot_auction;
+mod moderation;
 mod price_oracle;
 mod product_listing;
+mod quality_link;
+mod settlement;
 mod time_management;
 
 #[cfg(test)]
@@ -14,6 +18,7 @@ mod original_tests;
 mod tests;
 
 pub use datatype::*;
+pub use settlement::{LoyaltyTokenContract, SupplyChainContract};
 
 #[contract]
 pub struct AgriculturalAuctionContract;
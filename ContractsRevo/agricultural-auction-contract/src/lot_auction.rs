@@ -0,0 +1,431 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::moderation::{is_blacklisted, track_open_lot};
+use crate::settlement::{escrow_winning_bid, has_open_settlement};
+use crate::{
+    AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
+    AgriculturalAuctionContractClient, AgriculturalProduct, AuctionError, ComponentBid, DataKey,
+    LotAuction, LotAward, LotStatus, SettledProduct,
+};
+
+// Maximum number of products that can be bundled into a single lot
+const MAX_LOT_PRODUCTS: u32 = 20;
+
+pub trait LotAuctionOperations {
+    fn create_lot_auction(
+        env: Env,
+        farmer: Address,
+        product_ids: Vec<u64>,
+        reserve_price: u64,
+        auction_end_time: u64,
+        allow_partial: bool,
+    ) -> Result<u64, AuctionError>;
+
+    fn place_lot_bid(
+        env: Env,
+        lot_id: u64,
+        bidder: Address,
+        bid_amount: u64,
+    ) -> Result<(), AuctionError>;
+
+    fn place_lot_component_bid(
+        env: Env,
+        lot_id: u64,
+        product_id: u64,
+        bidder: Address,
+        bid_amount: u64,
+    ) -> Result<(), AuctionError>;
+
+    fn finalize_lot_auction(
+        env: Env,
+        farmer: Address,
+        lot_id: u64,
+    ) -> Result<Vec<LotAward>, AuctionError>;
+
+    fn cancel_lot_auction(env: Env, farmer: Address, lot_id: u64) -> Result<(), AuctionError>;
+
+    fn get_lot_auction(env: Env, lot_id: u64) -> Result<LotAuction, AuctionError>;
+
+    fn get_lot_component_bid(env: Env, lot_id: u64, product_id: u64) -> Option<ComponentBid>;
+}
+
+#[contractimpl]
+impl LotAuctionOperations for AgriculturalAuctionContract {
+    fn create_lot_auction(
+        env: Env,
+        farmer: Address,
+        product_ids: Vec<u64>,
+        reserve_price: u64,
+        auction_end_time: u64,
+        allow_partial: bool,
+    ) -> Result<u64, AuctionError> {
+        farmer.require_auth();
+
+        if is_blacklisted(&env, &farmer) {
+            return Err(AuctionError::Blacklisted);
+        }
+
+        if product_ids.is_empty() || product_ids.len() > MAX_LOT_PRODUCTS {
+            return Err(AuctionError::InvalidLot);
+        }
+
+        let current_time = env.ledger().timestamp();
+        if auction_end_time <= current_time {
+            return Err(AuctionError::InvalidAuctionEndTime);
+        }
+
+        for (i, product_id) in product_ids.iter().enumerate() {
+            // Each product may appear only once in the lot
+            if product_ids.first_index_of(product_id) != Some(i as u32) {
+                return Err(AuctionError::InvalidLot);
+            }
+
+            let product: AgriculturalProduct = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Product(farmer.clone(), product_id))
+                .ok_or(AuctionError::ProductNotFound)?;
+
+            if product.expiry_date <= current_time {
+                return Err(AuctionError::ProductExpired);
+            }
+
+            if auction_end_time >= product.expiry_date {
+                return Err(AuctionError::InvalidAuctionEndTime);
+            }
+
+            if product.quantity == 0 {
+                return Err(AuctionError::OutOfStock);
+            }
+
+            // Products already in another lot or an individual auction cannot be bundled
+            if is_product_locked(&env, &farmer, product_id)
+                || env
+                    .storage()
+                    .instance()
+                    .has(&DataKey::Auction(farmer.clone(), product_id))
+            {
+                return Err(AuctionError::ProductLocked);
+            }
+
+            // The previous sale of each product has to be settled first
+            if has_open_settlement(&env, &farmer, product_id) {
+                return Err(AuctionError::SettlementPending);
+            }
+        }
+
+        let lot_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LotCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::LotCounter, &lot_id);
+
+        // Lots count towards the farmer's cap on concurrently open auctions
+        track_open_lot(&env, &farmer, lot_id)?;
+
+        for product_id in product_ids.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ProductLock(farmer.clone(), product_id), &lot_id);
+        }
+
+        let lot = LotAuction {
+            lot_id,
+            farmer: farmer.clone(),
+            product_ids,
+            reserve_price,
+            auction_end_time,
+            highest_bid: 0,
+            highest_bidder: None,
+            allow_partial,
+            component_bid_count: 0,
+            status: LotStatus::Open,
+            awards: Vec::new(&env),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::LotAuction(lot_id), &lot);
+
+        env.events().publish(
+            (farmer, Symbol::new(&env, "LotAuctionCreated"), lot_id),
+            lot.product_ids,
+        );
+
+        Ok(lot_id)
+    }
+
+    fn place_lot_bid(
+        env: Env,
+        lot_id: u64,
+        bidder: Address,
+        bid_amount: u64,
+    ) -> Result<(), AuctionError> {
+        bidder.require_auth();
+
+        let mut lot = get_open_lot(&env, lot_id, &bidder)?;
+
+        if bid_amount < lot.reserve_price || bid_amount <= lot.highest_bid {
+            return Err(AuctionError::BidTooLow);
+        }
+
+        lot.highest_bid = bid_amount;
+        lot.highest_bidder = Some(bidder.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::LotAuction(lot_id), &lot);
+
+        env.events().publish(
+            (lot.farmer, Symbol::new(&env, "NewLotBid"), lot_id),
+            (bidder, bid_amount),
+        );
+
+        Ok(())
+    }
+
+    fn place_lot_component_bid(
+        env: Env,
+        lot_id: u64,
+        product_id: u64,
+        bidder: Address,
+        bid_amount: u64,
+    ) -> Result<(), AuctionError> {
+        bidder.require_auth();
+
+        let mut lot = get_open_lot(&env, lot_id, &bidder)?;
+
+        if !lot.allow_partial {
+            return Err(AuctionError::PartialBidsDisabled);
+        }
+
+        if !lot.product_ids.contains(product_id) {
+            return Err(AuctionError::ProductNotFound);
+        }
+
+        // Each component bid must cover its product's even share of the lot reserve
+        let key = DataKey::LotComponentBid(lot_id, product_id);
+        let current = env.storage().persistent().get::<_, ComponentBid>(&key);
+        if bid_amount == 0
+            || bid_amount < component_reserve(&lot)
+            || current.is_some_and(|bid| bid_amount <= bid.amount)
+        {
+            return Err(AuctionError::BidTooLow);
+        }
+
+        let bid = ComponentBid {
+            bidder: bidder.clone(),
+            amount: bid_amount,
+        };
+        env.storage().persistent().set(&key, &bid);
+
+        lot.component_bid_count += 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::LotAuction(lot_id), &lot);
+
+        env.events().publish(
+            (lot.farmer, Symbol::new(&env, "NewLotComponentBid"), lot_id),
+            (product_id, bidder, bid_amount),
+        );
+
+        Ok(())
+    }
+
+    fn finalize_lot_auction(
+        env: Env,
+        farmer: Address,
+        lot_id: u64,
+    ) -> Result<Vec<LotAward>, AuctionError> {
+        farmer.require_auth();
+
+        let key = DataKey::LotAuction(lot_id);
+        let mut lot: LotAuction = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(AuctionError::AuctionNotFound)?;
+
+        if lot.farmer != farmer {
+            return Err(AuctionError::AuctionNotFound);
+        }
+
+        if lot.status != LotStatus::Open {
+            return Err(AuctionError::LotNotOpen);
+        }
+
+        if lot.auction_end_time > env.ledger().timestamp() {
+            return Err(AuctionError::AuctionNotYetEnded);
+        }
+
+        // Collect the best component bid for each product
+        let mut component_awards = Vec::new(&env);
+        let mut component_total: u64 = 0;
+        if lot.allow_partial {
+            for product_id in lot.product_ids.iter() {
+                if let Some(bid) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, ComponentBid>(&DataKey::LotComponentBid(lot_id, product_id))
+                {
+                    component_total = component_total.saturating_add(bid.amount);
+                    let mut product_ids = Vec::new(&env);
+                    product_ids.push_back(product_id);
+                    component_awards.push_back(LotAward {
+                        winner: bid.bidder,
+                        product_ids,
+                        amount: bid.amount,
+                    });
+                }
+            }
+        }
+
+        // Split the lot only when the component bids meet the lot reserve and beat the best
+        // whole-lot bid. Component bids falling short of the reserve leave the lot unsold.
+        let awards = if component_total >= lot.reserve_price && component_total > lot.highest_bid {
+            component_awards
+        } else if let Some(winner) = lot.highest_bidder.clone() {
+            let mut awards = Vec::new(&env);
+            awards.push_back(LotAward {
+                winner,
+                product_ids: lot.product_ids.clone(),
+                amount: lot.highest_bid,
+            });
+            awards
+        } else if lot.component_bid_count > 0 {
+            Vec::new(&env)
+        } else {
+            return Err(AuctionError::NoBidsPlaced);
+        };
+
+        // Awarded products are sold out; everything in the lot is released
+        for award in awards.iter() {
+            let mut products = Vec::new(&env);
+            for product_id in award.product_ids.iter() {
+                let product_key = DataKey::Product(farmer.clone(), product_id);
+                if let Some(mut product) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, AgriculturalProduct>(&product_key)
+                {
+                    products.push_back(SettledProduct {
+                        product_id,
+                        quantity: product.quantity,
+                    });
+                    product.quantity = 0;
+                    env.storage().persistent().set(&product_key, &product);
+                }
+            }
+
+            // Each award is escrowed as one sale covering all of its products
+            escrow_winning_bid(&env, &farmer, products, &award.winner, award.amount)?;
+        }
+        unlock_products(&env, &lot);
+
+        lot.status = LotStatus::Finalized;
+        lot.awards = awards.clone();
+        env.storage().persistent().set(&key, &lot);
+
+        env.events().publish(
+            (farmer, Symbol::new(&env, "LotAuctionFinalized"), lot_id),
+            awards.clone(),
+        );
+
+        Ok(awards)
+    }
+
+    fn cancel_lot_auction(env: Env, farmer: Address, lot_id: u64) -> Result<(), AuctionError> {
+        farmer.require_auth();
+
+        let key = DataKey::LotAuction(lot_id);
+        let mut lot: LotAuction = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(AuctionError::AuctionNotFound)?;
+
+        if lot.farmer != farmer {
+            return Err(AuctionError::AuctionNotFound);
+        }
+
+        if lot.status != LotStatus::Open {
+            return Err(AuctionError::LotNotOpen);
+        }
+
+        // Bidders committed to the lot; it can only be withdrawn before any bid
+        if lot.highest_bidder.is_some() || lot.component_bid_count > 0 {
+            return Err(AuctionError::LotHasBids);
+        }
+
+        unlock_products(&env, &lot);
+
+        lot.status = LotStatus::Cancelled;
+        env.storage().persistent().set(&key, &lot);
+
+        env.events().publish(
+            (farmer, Symbol::new(&env, "LotAuctionCancelled"), lot_id),
+            lot.product_ids,
+        );
+
+        Ok(())
+    }
+
+    fn get_lot_auction(env: Env, lot_id: u64) -> Result<LotAuction, AuctionError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LotAuction(lot_id))
+            .ok_or(AuctionError::AuctionNotFound)
+    }
+
+    fn get_lot_component_bid(env: Env, lot_id: u64, product_id: u64) -> Option<ComponentBid> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LotComponentBid(lot_id, product_id))
+    }
+}
+
+/// Returns true while the product is bundled into an open lot auction.
+pub(crate) fn is_product_locked(env: &Env, farmer: &Address, product_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ProductLock(farmer.clone(), product_id))
+}
+
+fn get_open_lot(env: &Env, lot_id: u64, bidder: &Address) -> Result<LotAuction, AuctionError> {
+    if is_blacklisted(env, bidder) {
+        return Err(AuctionError::Blacklisted);
+    }
+
+    let lot: LotAuction = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LotAuction(lot_id))
+        .ok_or(AuctionError::AuctionNotFound)?;
+
+    if *bidder == lot.farmer {
+        return Err(AuctionError::InvalidBidder);
+    }
+
+    if lot.status != LotStatus::Open {
+        return Err(AuctionError::LotNotOpen);
+    }
+
+    if lot.auction_end_time < env.ledger().timestamp() {
+        return Err(AuctionError::AuctionEnded);
+    }
+
+    Ok(lot)
+}
+
+/// Lowest component bid accepted for any one product of the lot
+fn component_reserve(lot: &LotAuction) -> u64 {
+    lot.reserve_price / lot.product_ids.len() as u64
+}
+
+fn unlock_products(env: &Env, lot: &LotAuction) {
+    for product_id in lot.product_ids.iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ProductLock(lot.farmer.clone(), product_id));
+    }
+}
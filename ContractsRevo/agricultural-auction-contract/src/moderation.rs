@@ -0,0 +1,330 @@
+use soroban_sdk::{contractimpl, Address, Env, String, Symbol, Vec};
+
+use crate::{
+    AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
+    AgriculturalAuctionContractClient, Auction, AuctionError, BlacklistEntry, DataKey, LotAuction,
+    LotStatus, ModerationError,
+};
+
+// Strikes before an address is blacklisted when the admin has not set a threshold
+const DEFAULT_STRIKE_THRESHOLD: u32 = 3;
+
+pub trait ModerationOperations {
+    fn blacklist(
+        env: Env,
+        admin: Address,
+        address: Address,
+        reason: String,
+    ) -> Result<(), ModerationError>;
+
+    fn unblacklist(env: Env, admin: Address, address: Address) -> Result<(), ModerationError>;
+
+    fn is_blacklisted(env: Env, address: Address) -> bool;
+
+    fn get_blacklist_entry(env: Env, address: Address) -> Option<BlacklistEntry>;
+
+    fn list_blacklisted(env: Env, offset: u32, limit: u32) -> Vec<Address>;
+
+    fn get_strikes(env: Env, address: Address) -> u32;
+
+    fn set_strike_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+    ) -> Result<(), ModerationError>;
+
+    fn set_max_open_auctions(
+        env: Env,
+        admin: Address,
+        max_open: u32,
+    ) -> Result<(), ModerationError>;
+}
+
+#[contractimpl]
+impl ModerationOperations for AgriculturalAuctionContract {
+    fn blacklist(
+        env: Env,
+        admin: Address,
+        address: Address,
+        reason: String,
+    ) -> Result<(), ModerationError> {
+        require_admin(&env, &admin)?;
+
+        if is_blacklisted(&env, &address) {
+            return Err(ModerationError::AlreadyBlacklisted);
+        }
+
+        add_to_blacklist(&env, &address, reason, false);
+
+        Ok(())
+    }
+
+    fn unblacklist(env: Env, admin: Address, address: Address) -> Result<(), ModerationError> {
+        require_admin(&env, &admin)?;
+
+        if !is_blacklisted(&env, &address) {
+            return Err(ModerationError::NotBlacklisted);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Blacklist(address.clone()));
+
+        let mut index = get_blacklist_index(&env);
+        if let Some(position) = index.first_index_of(&address) {
+            index.remove(position);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::BlacklistIndex, &index);
+
+        // Give the address a clean slate so a single new strike does not re-blacklist it
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Strikes(address.clone()));
+
+        env.events()
+            .publish((Symbol::new(&env, "Unblacklisted"), address), admin);
+
+        Ok(())
+    }
+
+    fn is_blacklisted(env: Env, address: Address) -> bool {
+        is_blacklisted(&env, &address)
+    }
+
+    fn get_blacklist_entry(env: Env, address: Address) -> Option<BlacklistEntry> {
+        env.storage().persistent().get(&DataKey::Blacklist(address))
+    }
+
+    fn list_blacklisted(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let index = get_blacklist_index(&env);
+        let mut page = Vec::new(&env);
+
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            page.push_back(index.get(i).unwrap());
+        }
+
+        page
+    }
+
+    fn get_strikes(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Strikes(address))
+            .unwrap_or(0)
+    }
+
+    fn set_strike_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+    ) -> Result<(), ModerationError> {
+        require_admin(&env, &admin)?;
+
+        if threshold == 0 {
+            return Err(ModerationError::InvalidThreshold);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::StrikeThreshold, &threshold);
+
+        Ok(())
+    }
+
+    fn set_max_open_auctions(
+        env: Env,
+        admin: Address,
+        max_open: u32,
+    ) -> Result<(), ModerationError> {
+        require_admin(&env, &admin)?;
+
+        if max_open == 0 {
+            return Err(ModerationError::InvalidThreshold);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxOpenAuctions, &max_open);
+
+        Ok(())
+    }
+}
+
+pub(crate) fn is_blacklisted(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Blacklist(address.clone()))
+}
+
+/// Records a settlement or delivery failure, blacklisting the address once it
+/// reaches the configured strike threshold.
+pub(crate) fn record_strike(env: &Env, address: &Address) {
+    let key = DataKey::Strikes(address.clone());
+    let strikes: u32 = env.storage().persistent().get(&key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&key, &strikes);
+
+    env.events().publish(
+        (Symbol::new(env, "StrikeRecorded"), address.clone()),
+        strikes,
+    );
+
+    let threshold: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::StrikeThreshold)
+        .unwrap_or(DEFAULT_STRIKE_THRESHOLD);
+
+    if strikes >= threshold && !is_blacklisted(env, address) {
+        add_to_blacklist(
+            env,
+            address,
+            String::from_str(env, "Strike threshold reached"),
+            true,
+        );
+    }
+}
+
+/// Ensures the farmer is below the concurrent open auction cap and tracks the new auction.
+pub(crate) fn track_open_auction(
+    env: &Env,
+    farmer: &Address,
+    product_id: u64,
+) -> Result<(), AuctionError> {
+    let (mut auctions, lots) = open_auctions(env, farmer);
+    check_open_cap(env, auctions.len() + lots.len())?;
+
+    auctions.push_back(product_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FarmerAuctions(farmer.clone()), &auctions);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FarmerLots(farmer.clone()), &lots);
+
+    Ok(())
+}
+
+/// Same as `track_open_auction` for a lot auction; lots count towards the same cap.
+pub(crate) fn track_open_lot(env: &Env, farmer: &Address, lot_id: u64) -> Result<(), AuctionError> {
+    let (auctions, mut lots) = open_auctions(env, farmer);
+    check_open_cap(env, auctions.len() + lots.len())?;
+
+    lots.push_back(lot_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FarmerAuctions(farmer.clone()), &auctions);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FarmerLots(farmer.clone()), &lots);
+
+    Ok(())
+}
+
+// The farmer's tracked auctions and lots, dropping those that were finalized, cancelled or
+// whose bidding window has closed
+fn open_auctions(env: &Env, farmer: &Address) -> (Vec<u64>, Vec<u64>) {
+    let now = env.ledger().timestamp();
+
+    let mut auctions = Vec::new(env);
+    let tracked: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FarmerAuctions(farmer.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    for id in tracked.iter() {
+        let auction = env
+            .storage()
+            .instance()
+            .get::<_, Auction>(&DataKey::Auction(farmer.clone(), id));
+        if let Some(auction) = auction {
+            if auction.auction_end_time >= now {
+                auctions.push_back(id);
+            }
+        }
+    }
+
+    let mut lots = Vec::new(env);
+    let tracked: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FarmerLots(farmer.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    for lot_id in tracked.iter() {
+        let lot = env
+            .storage()
+            .persistent()
+            .get::<_, LotAuction>(&DataKey::LotAuction(lot_id));
+        if let Some(lot) = lot {
+            if lot.status == LotStatus::Open && lot.auction_end_time >= now {
+                lots.push_back(lot_id);
+            }
+        }
+    }
+
+    (auctions, lots)
+}
+
+fn check_open_cap(env: &Env, open: u32) -> Result<(), AuctionError> {
+    if let Some(max_open) = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::MaxOpenAuctions)
+    {
+        if open >= max_open {
+            return Err(AuctionError::TooManyOpenAuctions);
+        }
+    }
+
+    Ok(())
+}
+
+fn add_to_blacklist(env: &Env, address: &Address, reason: String, automatic: bool) {
+    let entry = BlacklistEntry {
+        reason,
+        blacklisted_at: env.ledger().timestamp(),
+        automatic,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Blacklist(address.clone()), &entry);
+
+    let mut index = get_blacklist_index(env);
+    index.push_back(address.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::BlacklistIndex, &index);
+
+    let event_name = if automatic {
+        "AutoBlacklisted"
+    } else {
+        "Blacklisted"
+    };
+    env.events()
+        .publish((Symbol::new(env, event_name), address.clone()), entry);
+}
+
+fn get_blacklist_index(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BlacklistIndex)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), ModerationError> {
+    admin.require_auth();
+
+    let stored_admin = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Admin)
+        .ok_or(ModerationError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(ModerationError::Unauthorized);
+    }
+
+    Ok(())
+}
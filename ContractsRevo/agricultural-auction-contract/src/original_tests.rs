@@ -700,9 +700,9 @@ fn test_update_market_price_unauthorized() {
 
     let result =
         client.try_update_market_price(&farmer1, &product_type, &region, &price, &1, &1000);
-    // It should fail because farmer1 != stored admin
+    // It should fail because farmer1 is not the oracle
     match result {
-        Err(Ok(e)) if (e) == OracleError::InvalidPriceData => (),
+        Err(Ok(e)) if (e) == OracleError::Unauthorized => (),
         // It could also fail authorization at a higher level depending on mock_auths setup vs require_auth details
         Err(Err(_host_error)) => { /* Could be a host error like Auth */ }
         _ => panic!("Result does not match expected error: {:?}", result),
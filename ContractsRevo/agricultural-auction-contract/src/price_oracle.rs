@@ -2,15 +2,38 @@ use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
 
 use crate::{
     AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
-    AgriculturalAuctionContractClient, AgriculturalProduct, DataKey, MarketPrice, OracleError,
-    ProductError, SeasonalStatus,
+    AgriculturalAuctionContractClient, AgriculturalProduct, AuctionError, DataKey, MarketPrice,
+    OracleError, PriceBand, PriceStalenessConfig, ProductError, SeasonalStatus,
 };
 
+// Market prices older than a day are stale unless the admin configures otherwise
+const DEFAULT_PRICE_MAX_AGE: u64 = 24 * 60 * 60;
+const BPS_DENOMINATOR: u128 = 10_000;
+
 pub trait PriceOracle {
-    fn update_market_price(
+    fn set_price_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError>;
+
+    fn set_price_band(
         env: Env,
         admin: Address,
         product_type: Symbol,
+        min_bps: u32,
+        max_bps: u32,
+    ) -> Result<(), OracleError>;
+
+    fn get_price_band(env: Env, product_type: Symbol) -> Option<PriceBand>;
+
+    fn set_price_staleness(
+        env: Env,
+        admin: Address,
+        max_age: u64,
+        allow_stale_bypass: bool,
+    ) -> Result<(), OracleError>;
+
+    fn update_market_price(
+        env: Env,
+        oracle: Address,
+        product_type: Symbol,
         region: Symbol,
         price: u64,
         trend: i32,
@@ -45,28 +68,80 @@ pub trait PriceOracle {
 
 #[contractimpl]
 impl PriceOracle for AgriculturalAuctionContract {
-    fn update_market_price(
+    fn set_price_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::PriceOracle, &oracle);
+
+        env.events()
+            .publish((Symbol::new(&env, "PriceOracleSet"),), oracle);
+
+        Ok(())
+    }
+
+    fn set_price_band(
         env: Env,
         admin: Address,
         product_type: Symbol,
+        min_bps: u32,
+        max_bps: u32,
+    ) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+
+        // Band must be non-empty and include some positive price
+        if max_bps == 0 || min_bps > max_bps {
+            return Err(OracleError::InvalidPriceBand);
+        }
+
+        let band = PriceBand { min_bps, max_bps };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PriceBand(product_type.clone()), &band);
+
+        env.events().publish(
+            (Symbol::new(&env, "PriceBandSet"), product_type),
+            (min_bps, max_bps),
+        );
+
+        Ok(())
+    }
+
+    fn get_price_band(env: Env, product_type: Symbol) -> Option<PriceBand> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PriceBand(product_type))
+    }
+
+    fn set_price_staleness(
+        env: Env,
+        admin: Address,
+        max_age: u64,
+        allow_stale_bypass: bool,
+    ) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+
+        let config = PriceStalenessConfig {
+            max_age,
+            allow_stale_bypass,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PriceStaleness, &config);
+
+        Ok(())
+    }
+
+    fn update_market_price(
+        env: Env,
+        oracle: Address,
+        product_type: Symbol,
         region: Symbol,
         price: u64,
         trend: i32,
         volume: u64,
     ) -> Result<(), OracleError> {
-        // Ensure admin authorization
-        admin.require_auth();
-
-        // Verify admin is authorized
-        let stored_admin = env
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::Admin)
-            .ok_or(OracleError::InvalidPriceData)?;
-
-        if admin != stored_admin {
-            return Err(OracleError::InvalidPriceData);
-        }
+        // Ensure only the registered price feed can push prices
+        require_oracle(&env, &oracle)?;
 
         // Create market price data
         let market_price = MarketPrice {
@@ -257,19 +332,8 @@ impl PriceOracle for AgriculturalAuctionContract {
         regions: Vec<Symbol>,
         prices: Vec<u64>,
     ) -> Result<(), OracleError> {
-        // Ensure admin authorization
-        admin.require_auth();
-
-        // Verify admin is authorized
-        let stored_admin = env
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::Admin)
-            .ok_or(OracleError::InvalidPriceData)?;
-
-        if admin != stored_admin {
-            return Err(OracleError::InvalidPriceData);
-        }
+        // Ensure only the registered price feed can push prices
+        require_oracle(&env, &admin)?;
 
         // Ensure regions and prices vectors have same length
         if regions.len() != prices.len() {
@@ -321,3 +385,96 @@ impl PriceOracle for AgriculturalAuctionContract {
         Ok(())
     }
 }
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), OracleError> {
+    admin.require_auth();
+
+    let stored_admin = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Admin)
+        .ok_or(OracleError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(OracleError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+// The registered oracle may push prices; until one is registered the admin acts as the oracle
+fn require_oracle(env: &Env, caller: &Address) -> Result<(), OracleError> {
+    caller.require_auth();
+
+    let authorized = match env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::PriceOracle)
+    {
+        Some(oracle) => oracle,
+        None => env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Admin)
+            .ok_or(OracleError::Unauthorized)?,
+    };
+
+    if *caller != authorized {
+        return Err(OracleError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+/// Validates an auction start price against the configured band for the product type.
+///
+/// Returns the market price used as reference, if a fresh one was available.
+pub(crate) fn check_start_price(
+    env: &Env,
+    product: &AgriculturalProduct,
+    start_price: u64,
+) -> Result<Option<u64>, AuctionError> {
+    let band = env
+        .storage()
+        .persistent()
+        .get::<_, PriceBand>(&DataKey::PriceBand(product.product_type.clone()));
+
+    let staleness = env
+        .storage()
+        .instance()
+        .get::<_, PriceStalenessConfig>(&DataKey::PriceStaleness)
+        .unwrap_or(PriceStalenessConfig {
+            max_age: DEFAULT_PRICE_MAX_AGE,
+            allow_stale_bypass: true,
+        });
+
+    let market_key = DataKey::MarketPrice(product.product_type.clone(), product.region.clone());
+    let reference = env
+        .storage()
+        .persistent()
+        .get::<_, MarketPrice>(&market_key)
+        .filter(|mp| env.ledger().timestamp().saturating_sub(mp.timestamp) <= staleness.max_age)
+        .map(|mp| mp.price);
+
+    let band = match band {
+        Some(band) => band,
+        None => return Ok(reference),
+    };
+
+    let reference_price = match reference {
+        Some(price) => price,
+        None if staleness.allow_stale_bypass => return Ok(None),
+        None => return Err(AuctionError::ReferencePriceUnavailable),
+    };
+
+    // Compare in basis points to avoid rounding the band edges
+    let scaled_start = start_price as u128 * BPS_DENOMINATOR;
+    let lower = reference_price as u128 * band.min_bps as u128;
+    let upper = reference_price as u128 * band.max_bps as u128;
+
+    if scaled_start < lower || scaled_start > upper {
+        return Err(AuctionError::StartPriceOutOfBand);
+    }
+
+    Ok(Some(reference_price))
+}
@@ -6,6 +6,8 @@ use crate::{
     QualityGrade, SeasonalStatus, StorageCondition, TimeError,
 };
 
+pub const UNGRADED: &str = "ungraded";
+
 pub trait ProductListing {
     fn add_product(
         env: Env,
@@ -144,6 +146,8 @@ impl ProductListing for AgriculturalAuctionContract {
             product_type: product_details.product_type.clone(),
             region: product_details.region.clone(),
             seasonal_status,
+            quality_certification: None,
+            grade: Symbol::new(&env, UNGRADED),
         };
 
         // Retrieve or initialize the product list for the farmer
@@ -0,0 +1,198 @@
+use soroban_sdk::{contractclient, contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+use crate::product_listing::UNGRADED;
+use crate::{
+    AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
+    AgriculturalAuctionContractClient, AgriculturalProduct, DataKey, ProductError,
+    QualityCertStatus, QualityCertification,
+};
+
+// Grades from best to worst, with the lowest audit score each requires
+const GRADES: [(&str, u32); 4] = [("A", 90), ("B", 75), ("C", 60), ("D", 0)];
+
+// Minimal interface of the agricultural-quality contract used to read certifications.
+#[allow(dead_code)]
+#[contractclient(name = "QualityContractClient")]
+pub trait QualityContract {
+    fn get_certification(env: Env, certification_id: BytesN<32>) -> QualityCertification;
+}
+
+pub trait QualityCertificationOps {
+    fn set_quality_contract(
+        env: Env,
+        admin: Address,
+        quality_contract: Address,
+    ) -> Result<(), ProductError>;
+
+    fn attach_quality_certification(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+        cert_contract: Address,
+        certification_id: BytesN<32>,
+    ) -> Result<Symbol, ProductError>;
+
+    fn set_min_grade_for_auction(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        min_grade: Option<Symbol>,
+    ) -> Result<(), ProductError>;
+
+    fn get_min_grade_for_auction(env: Env, product_type: Symbol) -> Option<Symbol>;
+}
+
+#[contractimpl]
+impl QualityCertificationOps for AgriculturalAuctionContract {
+    fn set_quality_contract(
+        env: Env,
+        admin: Address,
+        quality_contract: Address,
+    ) -> Result<(), ProductError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::QualityContract, &quality_contract);
+
+        Ok(())
+    }
+
+    fn attach_quality_certification(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+        cert_contract: Address,
+        certification_id: BytesN<32>,
+    ) -> Result<Symbol, ProductError> {
+        farmer.require_auth();
+
+        // Only the quality contract configured by the admin is trusted
+        let quality_contract = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::QualityContract)
+            .ok_or(ProductError::QualityContractNotSet)?;
+        if cert_contract != quality_contract {
+            return Err(ProductError::InvalidCertification);
+        }
+
+        let product_key = DataKey::Product(farmer.clone(), product_id);
+        let mut product: AgriculturalProduct = env
+            .storage()
+            .persistent()
+            .get(&product_key)
+            .ok_or(ProductError::ProductNotFound)?;
+
+        let client = QualityContractClient::new(&env, &cert_contract);
+        let certification = match client.try_get_certification(&certification_id) {
+            Ok(Ok(certification)) => certification,
+            _ => return Err(ProductError::InvalidCertification),
+        };
+        if certification.holder != farmer
+            || certification.status != QualityCertStatus::Active
+            || certification.expiry_date <= env.ledger().timestamp()
+        {
+            return Err(ProductError::InvalidCertification);
+        }
+
+        let grade = grade_for_score(&env, certification.audit_score);
+        product.quality_certification = Some(certification_id.clone());
+        product.grade = grade.clone();
+        env.storage().persistent().set(&product_key, &product);
+
+        // Keep the farmer's listing in sync so listings surface the grade
+        let list_key = DataKey::ProductList(farmer.clone());
+        let mut products = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<AgriculturalProduct>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if let Some(index) = products.iter().position(|p| p.id == product_id) {
+            products.set(index as u32, product);
+            env.storage().persistent().set(&list_key, &products);
+        }
+
+        env.events().publish(
+            (farmer, Symbol::new(&env, "QualityCertAttached"), product_id),
+            (certification_id, grade.clone()),
+        );
+
+        Ok(grade)
+    }
+
+    fn set_min_grade_for_auction(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        min_grade: Option<Symbol>,
+    ) -> Result<(), ProductError> {
+        require_admin(&env, &admin)?;
+
+        let key = DataKey::MinGrade(product_type);
+        match min_grade {
+            Some(grade) => {
+                if grade_rank(&env, &grade) == 0 {
+                    return Err(ProductError::InvalidGrade);
+                }
+                env.storage().persistent().set(&key, &grade);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        Ok(())
+    }
+
+    fn get_min_grade_for_auction(env: Env, product_type: Symbol) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MinGrade(product_type))
+    }
+}
+
+/// Whether a product's grade satisfies the minimum set for its type, if any
+pub fn meets_min_grade(env: &Env, product: &AgriculturalProduct) -> bool {
+    match env
+        .storage()
+        .persistent()
+        .get::<_, Symbol>(&DataKey::MinGrade(product.product_type.clone()))
+    {
+        Some(min_grade) => grade_rank(env, &product.grade) >= grade_rank(env, &min_grade),
+        None => true,
+    }
+}
+
+fn grade_for_score(env: &Env, audit_score: u32) -> Symbol {
+    for (grade, min_score) in GRADES {
+        if audit_score >= min_score {
+            return Symbol::new(env, grade);
+        }
+    }
+    Symbol::new(env, UNGRADED)
+}
+
+// Higher is better; 0 for ungraded or unknown grades
+fn grade_rank(env: &Env, grade: &Symbol) -> u32 {
+    for (index, (name, _)) in GRADES.iter().enumerate() {
+        if *grade == Symbol::new(env, name) {
+            return (GRADES.len() - index) as u32;
+        }
+    }
+    0
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), ProductError> {
+    admin.require_auth();
+
+    let stored_admin = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Admin)
+        .ok_or(ProductError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(ProductError::Unauthorized);
+    }
+
+    Ok(())
+}
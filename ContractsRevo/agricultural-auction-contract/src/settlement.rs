@@ -0,0 +1,815 @@
+use soroban_sdk::{
+    contractclient, contractimpl, token, xdr::ToXdr, Address, BytesN, Env, Symbol, Val, Vec,
+};
+
+use crate::moderation::record_strike;
+use crate::quality_link::QualityContractClient;
+use crate::{
+    AgriculturalAuctionContract, AgriculturalAuctionContractArgs,
+    AgriculturalAuctionContractClient, AgriculturalProduct, AuctionError, DataKey, LoyaltyConfig,
+    ProceedsSplit, SettledProduct, Settlement, SettlementConfig, SettlementError, SettlementStatus,
+};
+
+// Highest combined share of proceeds the treasury and certifier may take
+const MAX_SPLIT_BPS: u32 = 2000;
+const BPS_DENOMINATOR: i128 = 10_000;
+// Longest payment window or delivery timeout the admin may configure
+const MAX_SETTLEMENT_WINDOW: u64 = 365 * 24 * 60 * 60;
+
+// Minimal interface of the supply-chain tracking contract used to validate deliveries.
+#[contractclient(name = "SupplyChainContractClient")]
+pub trait SupplyChainContract {
+    fn get_product_details(env: Env, product_id: BytesN<32>) -> Val;
+}
+
+// Minimal interface of the loyalty token contract used to reward completed purchases.
+#[contractclient(name = "LoyaltyTokenContractClient")]
+pub trait LoyaltyTokenContract {
+    fn award_for_purchase(
+        env: Env,
+        merchant_contract: Address,
+        program_id: BytesN<32>,
+        buyer: Address,
+        amount_spent: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64;
+}
+
+pub trait SettlementOperations {
+    fn set_settlement_config(
+        env: Env,
+        admin: Address,
+        payment_token: Address,
+        payment_window: u64,
+        delivery_timeout: u64,
+    ) -> Result<(), SettlementError>;
+
+    fn set_supply_chain_contract(
+        env: Env,
+        admin: Address,
+        supply_chain_contract: Address,
+    ) -> Result<(), SettlementError>;
+
+    fn set_loyalty_program(
+        env: Env,
+        admin: Address,
+        loyalty_contract: Address,
+        program_id: BytesN<32>,
+    ) -> Result<(), SettlementError>;
+
+    fn get_loyalty_program(env: Env) -> Option<LoyaltyConfig>;
+
+    fn set_proceeds_split(
+        env: Env,
+        admin: Address,
+        coop_treasury: Address,
+        coop_bps: u32,
+        cert_royalty_bps: u32,
+    ) -> Result<(), SettlementError>;
+
+    fn get_proceeds_split(env: Env) -> Option<ProceedsSplit>;
+
+    fn pay_settlement(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError>;
+
+    fn report_unpaid(env: Env, farmer: Address, product_id: u64) -> Result<(), SettlementError>;
+
+    fn confirm_delivery(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+        supply_chain_product_id: Option<BytesN<32>>,
+    ) -> Result<(), SettlementError>;
+
+    fn dispute_settlement(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError>;
+
+    fn resolve_settlement_dispute(
+        env: Env,
+        admin: Address,
+        farmer: Address,
+        product_id: u64,
+        release_to_farmer: bool,
+    ) -> Result<(), SettlementError>;
+
+    fn reclaim_escrow(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError>;
+
+    fn get_settlement(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<Settlement, SettlementError>;
+}
+
+#[contractimpl]
+impl SettlementOperations for AgriculturalAuctionContract {
+    fn set_settlement_config(
+        env: Env,
+        admin: Address,
+        payment_token: Address,
+        payment_window: u64,
+        delivery_timeout: u64,
+    ) -> Result<(), SettlementError> {
+        require_admin(&env, &admin)?;
+
+        if payment_window == 0
+            || delivery_timeout == 0
+            || payment_window > MAX_SETTLEMENT_WINDOW
+            || delivery_timeout > MAX_SETTLEMENT_WINDOW
+        {
+            return Err(SettlementError::InvalidTimeout);
+        }
+
+        let config = SettlementConfig {
+            payment_token,
+            payment_window,
+            delivery_timeout,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::SettlementConfig, &config);
+
+        Ok(())
+    }
+
+    fn set_supply_chain_contract(
+        env: Env,
+        admin: Address,
+        supply_chain_contract: Address,
+    ) -> Result<(), SettlementError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SupplyChainContract, &supply_chain_contract);
+
+        Ok(())
+    }
+
+    fn set_loyalty_program(
+        env: Env,
+        admin: Address,
+        loyalty_contract: Address,
+        program_id: BytesN<32>,
+    ) -> Result<(), SettlementError> {
+        require_admin(&env, &admin)?;
+
+        env.storage().instance().set(
+            &DataKey::LoyaltyProgram,
+            &LoyaltyConfig {
+                loyalty_contract,
+                program_id,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn get_loyalty_program(env: Env) -> Option<LoyaltyConfig> {
+        env.storage().instance().get(&DataKey::LoyaltyProgram)
+    }
+
+    fn set_proceeds_split(
+        env: Env,
+        admin: Address,
+        coop_treasury: Address,
+        coop_bps: u32,
+        cert_royalty_bps: u32,
+    ) -> Result<(), SettlementError> {
+        require_admin(&env, &admin)?;
+
+        if coop_bps.saturating_add(cert_royalty_bps) > MAX_SPLIT_BPS {
+            return Err(SettlementError::InvalidSplit);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ProceedsSplit,
+            &ProceedsSplit {
+                coop_treasury,
+                coop_bps,
+                cert_royalty_bps,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn get_proceeds_split(env: Env) -> Option<ProceedsSplit> {
+        env.storage().instance().get(&DataKey::ProceedsSplit)
+    }
+
+    fn pay_settlement(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError> {
+        buyer.require_auth();
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.buyer != buyer {
+            return Err(SettlementError::Unauthorized);
+        }
+
+        if settlement.status != SettlementStatus::AwaitingPayment {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > settlement.payment_deadline {
+            return Err(SettlementError::PaymentWindowClosed);
+        }
+
+        token::Client::new(&env, &settlement.payment_token).transfer(
+            &buyer,
+            &env.current_contract_address(),
+            &settlement.amount,
+        );
+
+        // The delivery clock starts once the funds are actually escrowed
+        let delivery_timeout = env
+            .storage()
+            .instance()
+            .get::<_, SettlementConfig>(&DataKey::SettlementConfig)
+            .map(|config| config.delivery_timeout)
+            .unwrap_or(0);
+        settlement.status = SettlementStatus::Escrowed;
+        settlement.delivery_deadline = now + delivery_timeout;
+        env.storage().persistent().set(&key, &settlement);
+
+        env.events().publish(
+            (farmer.clone(), Symbol::new(&env, "BidEscrowed"), product_id),
+            (buyer, settlement.amount),
+        );
+
+        Ok(())
+    }
+
+    fn report_unpaid(env: Env, farmer: Address, product_id: u64) -> Result<(), SettlementError> {
+        farmer.require_auth();
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.status != SettlementStatus::AwaitingPayment {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        if env.ledger().timestamp() <= settlement.payment_deadline {
+            return Err(SettlementError::PaymentWindowOpen);
+        }
+
+        // Return the unsold quantity of every covered product to the farmer's inventory
+        for settled in settlement.products.iter() {
+            let product_key = DataKey::Product(farmer.clone(), settled.product_id);
+            if let Some(mut product) = env
+                .storage()
+                .persistent()
+                .get::<_, AgriculturalProduct>(&product_key)
+            {
+                product.quantity = product.quantity.saturating_add(settled.quantity);
+                env.storage().persistent().set(&product_key, &product);
+            }
+        }
+
+        settlement.status = SettlementStatus::Defaulted;
+        env.storage().persistent().set(&key, &settlement);
+
+        record_strike(&env, &settlement.buyer);
+
+        env.events().publish(
+            (
+                farmer.clone(),
+                Symbol::new(&env, "SettlementDefaulted"),
+                product_id,
+            ),
+            settlement.buyer,
+        );
+
+        Ok(())
+    }
+
+    fn confirm_delivery(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+        supply_chain_product_id: Option<BytesN<32>>,
+    ) -> Result<(), SettlementError> {
+        buyer.require_auth();
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.buyer != buyer {
+            return Err(SettlementError::Unauthorized);
+        }
+
+        // A buyer confirmation also settles a pending farmer dispute
+        if settlement.status != SettlementStatus::Escrowed
+            && settlement.status != SettlementStatus::Disputed
+        {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        // Ensure the traced product exists in the supply-chain contract, when linked
+        if let Some(traced_id) = supply_chain_product_id.clone() {
+            if let Some(supply_chain) = env
+                .storage()
+                .instance()
+                .get::<_, Address>(&DataKey::SupplyChainContract)
+            {
+                let client = SupplyChainContractClient::new(&env, &supply_chain);
+                if !matches!(client.try_get_product_details(&traced_id), Ok(Ok(_))) {
+                    return Err(SettlementError::SupplyChainProductNotFound);
+                }
+            }
+        }
+
+        pay_out_proceeds(&env, &settlement);
+
+        settlement.status = SettlementStatus::Delivered;
+        settlement.supply_chain_product_id = supply_chain_product_id;
+        env.storage().persistent().set(&key, &settlement);
+
+        award_loyalty_points(&env, &settlement);
+
+        env.events().publish(
+            (
+                farmer.clone(),
+                Symbol::new(&env, "DeliveryConfirmed"),
+                product_id,
+            ),
+            (buyer, settlement.amount),
+        );
+
+        Ok(())
+    }
+
+    fn dispute_settlement(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError> {
+        farmer.require_auth();
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.status != SettlementStatus::Escrowed {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        settlement.status = SettlementStatus::Disputed;
+        env.storage().persistent().set(&key, &settlement);
+
+        env.events().publish(
+            (
+                farmer.clone(),
+                Symbol::new(&env, "SettlementDisputed"),
+                product_id,
+            ),
+            settlement.buyer,
+        );
+
+        Ok(())
+    }
+
+    fn resolve_settlement_dispute(
+        env: Env,
+        admin: Address,
+        farmer: Address,
+        product_id: u64,
+        release_to_farmer: bool,
+    ) -> Result<(), SettlementError> {
+        require_admin(&env, &admin)?;
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.status != SettlementStatus::Disputed {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        if release_to_farmer {
+            pay_out_proceeds(&env, &settlement);
+            settlement.status = SettlementStatus::Delivered;
+        } else {
+            release_escrow(&env, &settlement, &settlement.buyer);
+            settlement.status = SettlementStatus::Refunded;
+            record_strike(&env, &farmer);
+        }
+        env.storage().persistent().set(&key, &settlement);
+
+        if release_to_farmer {
+            award_loyalty_points(&env, &settlement);
+        }
+
+        env.events().publish(
+            (
+                farmer.clone(),
+                Symbol::new(&env, "SettlementResolved"),
+                product_id,
+            ),
+            settlement.status,
+        );
+
+        Ok(())
+    }
+
+    fn reclaim_escrow(
+        env: Env,
+        buyer: Address,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<(), SettlementError> {
+        buyer.require_auth();
+
+        let key = settlement_key(&env, &farmer, product_id);
+        let mut settlement: Settlement = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(SettlementError::SettlementNotFound)?;
+
+        if settlement.buyer != buyer {
+            return Err(SettlementError::Unauthorized);
+        }
+
+        // Disputed escrows stay on hold until the admin resolves them
+        if settlement.status != SettlementStatus::Escrowed {
+            return Err(SettlementError::InvalidStatus);
+        }
+
+        if env.ledger().timestamp() <= settlement.delivery_deadline {
+            return Err(SettlementError::DeliveryWindowOpen);
+        }
+
+        release_escrow(&env, &settlement, &buyer);
+
+        settlement.status = SettlementStatus::Refunded;
+        env.storage().persistent().set(&key, &settlement);
+
+        // The farmer let the delivery window lapse without delivering or disputing
+        record_strike(&env, &farmer);
+
+        env.events().publish(
+            (
+                farmer.clone(),
+                Symbol::new(&env, "EscrowReclaimed"),
+                product_id,
+            ),
+            (buyer, settlement.amount),
+        );
+
+        Ok(())
+    }
+
+    fn get_settlement(
+        env: Env,
+        farmer: Address,
+        product_id: u64,
+    ) -> Result<Settlement, SettlementError> {
+        env.storage()
+            .persistent()
+            .get(&settlement_key(&env, &farmer, product_id))
+            .ok_or(SettlementError::SettlementNotFound)
+    }
+}
+
+/// Pulls the winning bid into escrow and records the pending settlement.
+///
+/// The buyer is expected to have approved the contract to spend the bid amount; if the
+/// pull fails the settlement waits for `pay_settlement` until the payment window closes.
+/// A sale covering several products (a lot award) is stored under the first one and can be
+/// looked up through any of them. Does nothing when no payment token is configured, and fails
+/// while an earlier sale of any of the products is still being settled.
+pub(crate) fn escrow_winning_bid(
+    env: &Env,
+    farmer: &Address,
+    products: Vec<SettledProduct>,
+    buyer: &Address,
+    amount: u64,
+) -> Result<(), AuctionError> {
+    let config = match env
+        .storage()
+        .instance()
+        .get::<_, SettlementConfig>(&DataKey::SettlementConfig)
+    {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    let mut quantity: u32 = 0;
+    for settled in products.iter() {
+        if has_open_settlement(env, farmer, settled.product_id) {
+            return Err(AuctionError::SettlementPending);
+        }
+        quantity = quantity.saturating_add(settled.quantity);
+    }
+    let product_id = products.get(0).ok_or(AuctionError::InvalidLot)?.product_id;
+    cover_products(env, farmer, &products);
+
+    let contract_address = env.current_contract_address();
+    let amount = amount as i128;
+    let escrowed = token::Client::new(env, &config.payment_token)
+        .try_transfer_from(&contract_address, buyer, &contract_address, &amount)
+        .is_ok();
+
+    let now = env.ledger().timestamp();
+    let settlement = Settlement {
+        product_id,
+        products,
+        farmer: farmer.clone(),
+        buyer: buyer.clone(),
+        payment_token: config.payment_token,
+        amount,
+        quantity,
+        finalized_at: now,
+        payment_deadline: now + config.payment_window,
+        delivery_deadline: if escrowed {
+            now + config.delivery_timeout
+        } else {
+            0
+        },
+        status: if escrowed {
+            SettlementStatus::Escrowed
+        } else {
+            SettlementStatus::AwaitingPayment
+        },
+        supply_chain_product_id: None,
+    };
+    env.storage().persistent().set(
+        &DataKey::Settlement(farmer.clone(), product_id),
+        &settlement,
+    );
+
+    if escrowed {
+        env.events().publish(
+            (farmer.clone(), Symbol::new(env, "BidEscrowed"), product_id),
+            (buyer.clone(), amount),
+        );
+    }
+
+    Ok(())
+}
+
+/// Points every product of a new sale at the first one, which stores its settlement, after
+/// dropping the pointers left by the closed settlement it replaces
+fn cover_products(env: &Env, farmer: &Address, products: &Vec<SettledProduct>) {
+    let product_id = products.get(0).unwrap().product_id;
+    if let Some(previous) = env
+        .storage()
+        .persistent()
+        .get::<_, Settlement>(&DataKey::Settlement(farmer.clone(), product_id))
+    {
+        for settled in previous.products.iter().skip(1) {
+            let key = DataKey::SettledUnder(farmer.clone(), settled.product_id);
+            if env.storage().persistent().get::<_, u64>(&key) == Some(product_id) {
+                env.storage().persistent().remove(&key);
+            }
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SettledUnder(farmer.clone(), product_id));
+    for settled in products.iter().skip(1) {
+        env.storage().persistent().set(
+            &DataKey::SettledUnder(farmer.clone(), settled.product_id),
+            &product_id,
+        );
+    }
+}
+
+/// Storage key of the settlement covering the product, which for a lot award is stored under
+/// the lot's first product
+pub(crate) fn settlement_key(env: &Env, farmer: &Address, product_id: u64) -> DataKey {
+    let settled_under = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SettledUnder(farmer.clone(), product_id))
+        .unwrap_or(product_id);
+    DataKey::Settlement(farmer.clone(), settled_under)
+}
+
+/// Whether a sale of the product is awaiting payment, escrowed or disputed
+pub(crate) fn has_open_settlement(env: &Env, farmer: &Address, product_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Settlement>(&settlement_key(env, farmer, product_id))
+        .is_some_and(|settlement| {
+            matches!(
+                settlement.status,
+                SettlementStatus::AwaitingPayment
+                    | SettlementStatus::Escrowed
+                    | SettlementStatus::Disputed
+            )
+        })
+}
+
+/// Credits the buyer's loyalty points once a settlement is delivered. Best effort: a missing
+/// or failing loyalty contract never blocks the settlement.
+///
+/// The purchase is referenced by the farmer, product and finalization time, so a product
+/// auctioned again later is a distinct purchase.
+fn award_loyalty_points(env: &Env, settlement: &Settlement) {
+    let config: LoyaltyConfig = match env.storage().instance().get(&DataKey::LoyaltyProgram) {
+        Some(config) => config,
+        None => return,
+    };
+
+    let amount_spent = match u64::try_from(settlement.amount) {
+        Ok(amount) if amount > 0 => amount,
+        _ => return,
+    };
+
+    let purchase_ref: BytesN<32> = env
+        .crypto()
+        .sha256(
+            &(
+                settlement.farmer.clone(),
+                settlement.product_id,
+                settlement.finalized_at,
+            )
+                .to_xdr(env),
+        )
+        .into();
+
+    let client = LoyaltyTokenContractClient::new(env, &config.loyalty_contract);
+    if let Ok(Ok(points)) = client.try_award_for_purchase(
+        &env.current_contract_address(),
+        &config.program_id,
+        &settlement.buyer,
+        &amount_spent,
+        &purchase_ref,
+    ) {
+        env.events().publish(
+            (
+                Symbol::new(env, "LoyaltyPointsAwarded"),
+                settlement.buyer.clone(),
+            ),
+            (purchase_ref, points),
+        );
+    }
+}
+
+/// Releases delivered proceeds, routing the configured shares to the cooperative treasury and
+/// to the issuer of each covered product's quality certification before paying the farmer the
+/// rest.
+///
+/// A sale covering several products splits its amount between them by quantity, and each
+/// certifier's royalty is taken on its product's part. Products without a readable
+/// certification skip the royalty. Shares round down, so any rounding remainder goes to the
+/// farmer.
+fn pay_out_proceeds(env: &Env, settlement: &Settlement) {
+    let split: ProceedsSplit = match env.storage().instance().get(&DataKey::ProceedsSplit) {
+        Some(split) => split,
+        None => {
+            release_escrow(env, settlement, &settlement.farmer);
+            return;
+        }
+    };
+
+    let coop_share = settlement.amount * split.coop_bps as i128 / BPS_DENOMINATOR;
+    let mut royalties: Vec<(Address, i128)> = Vec::new(env);
+    let mut royalty_total: i128 = 0;
+    for settled in settlement.products.iter() {
+        if let Some(certifier) = certification_issuer(env, &settlement.farmer, settled.product_id) {
+            let royalty = product_proceeds(settlement, &settled) * split.cert_royalty_bps as i128
+                / BPS_DENOMINATOR;
+            royalty_total += royalty;
+            royalties.push_back((certifier, royalty));
+        }
+    }
+    let farmer_share = settlement.amount - coop_share - royalty_total;
+
+    pay_share(
+        env,
+        settlement,
+        &split.coop_treasury,
+        coop_share,
+        Symbol::new(env, "treasury"),
+    );
+    for (certifier, royalty) in royalties.iter() {
+        pay_share(
+            env,
+            settlement,
+            &certifier,
+            royalty,
+            Symbol::new(env, "certifier"),
+        );
+    }
+    pay_share(
+        env,
+        settlement,
+        &settlement.farmer,
+        farmer_share,
+        Symbol::new(env, "farmer"),
+    );
+}
+
+// Part of the settled amount attributed to one covered product, weighted by quantity
+fn product_proceeds(settlement: &Settlement, settled: &SettledProduct) -> i128 {
+    if settlement.quantity == 0 {
+        return settlement.amount / settlement.products.len() as i128;
+    }
+    settlement.amount * settled.quantity as i128 / settlement.quantity as i128
+}
+
+fn pay_share(env: &Env, settlement: &Settlement, to: &Address, amount: i128, role: Symbol) {
+    if amount <= 0 {
+        return;
+    }
+
+    token::Client::new(env, &settlement.payment_token).transfer(
+        &env.current_contract_address(),
+        to,
+        &amount,
+    );
+
+    env.events().publish(
+        (
+            settlement.farmer.clone(),
+            Symbol::new(env, "ProceedsPaid"),
+            settlement.product_id,
+        ),
+        (role, to.clone(), amount),
+    );
+}
+
+// Issuer of the quality certification attached to a settled product, if it can be read
+fn certification_issuer(env: &Env, farmer: &Address, product_id: u64) -> Option<Address> {
+    let product: AgriculturalProduct = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Product(farmer.clone(), product_id))?;
+    let certification_id = product.quality_certification?;
+    let quality_contract: Address = env.storage().instance().get(&DataKey::QualityContract)?;
+
+    match QualityContractClient::new(env, &quality_contract)
+        .try_get_certification(&certification_id)
+    {
+        Ok(Ok(certification)) => Some(certification.issuer),
+        _ => None,
+    }
+}
+
+fn release_escrow(env: &Env, settlement: &Settlement, to: &Address) {
+    token::Client::new(env, &settlement.payment_token).transfer(
+        &env.current_contract_address(),
+        to,
+        &settlement.amount,
+    );
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), SettlementError> {
+    admin.require_auth();
+
+    let stored_admin = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Admin)
+        .ok_or(SettlementError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(SettlementError::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,568 @@
+use crate::datatype::*;
+use crate::settlement::{escrow_winning_bid, LoyaltyTokenContract};
+use crate::tests::utils::*;
+use crate::AgriculturalAuctionContractClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, token, vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+const WINNING_BID: u64 = 2000;
+const PAYMENT_WINDOW: u64 = 24 * 60 * 60;
+const DELIVERY_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MockSupplyChainError {
+    ProductNotFound = 1,
+}
+
+#[contract]
+pub struct MockSupplyChain;
+
+#[contractimpl]
+impl MockSupplyChain {
+    pub fn register(env: Env, product_id: BytesN<32>) {
+        env.storage().persistent().set(&product_id, &true);
+    }
+
+    pub fn get_product_details(
+        env: Env,
+        product_id: BytesN<32>,
+    ) -> Result<BytesN<32>, MockSupplyChainError> {
+        if env.storage().persistent().has(&product_id) {
+            Ok(product_id)
+        } else {
+            Err(MockSupplyChainError::ProductNotFound)
+        }
+    }
+}
+
+// Stands in for the loyalty token contract, awarding one point per unit spent
+#[contract]
+pub struct MockLoyaltyContract;
+
+#[contractimpl]
+impl LoyaltyTokenContract for MockLoyaltyContract {
+    fn award_for_purchase(
+        env: Env,
+        merchant_contract: Address,
+        _program_id: BytesN<32>,
+        buyer: Address,
+        amount_spent: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64 {
+        merchant_contract.require_auth();
+        if env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "failing"))
+            .unwrap_or(false)
+        {
+            panic!("Merchant not registered");
+        }
+        if env.storage().instance().has(&purchase_ref) {
+            return 0;
+        }
+        env.storage().instance().set(&purchase_ref, &true);
+
+        let points: u64 = env.storage().instance().get(&buyer).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&buyer, &(points + amount_spent));
+        amount_spent
+    }
+}
+
+#[contractimpl]
+impl MockLoyaltyContract {
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "failing"), &failing);
+    }
+
+    pub fn points(env: Env, user: Address) -> u64 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+// Stands in for the agricultural-quality contract, recording who issued each certification
+#[contract]
+pub struct MockCertifier;
+
+#[contractimpl]
+impl MockCertifier {
+    pub fn issue(env: Env, certification_id: BytesN<32>, holder: Address, issuer: Address) {
+        let now = env.ledger().timestamp();
+        let certification = QualityCertification {
+            holder,
+            standard: QualityCertStandard::Organic,
+            status: QualityCertStatus::Active,
+            issue_date: now,
+            expiry_date: now + DELIVERY_TIMEOUT * 10,
+            issuer,
+            audit_score: 95,
+            conditions: Vec::new(&env),
+        };
+        env.storage()
+            .persistent()
+            .set(&certification_id, &certification);
+    }
+
+    pub fn get_certification(
+        env: Env,
+        certification_id: BytesN<32>,
+    ) -> Result<QualityCertification, MockSupplyChainError> {
+        env.storage()
+            .persistent()
+            .get(&certification_id)
+            .ok_or(MockSupplyChainError::ProductNotFound)
+    }
+}
+
+struct DeliveryTest<'a> {
+    test_env: TestEnv,
+    client: AgriculturalAuctionContractClient<'a>,
+    token: token::Client<'a>,
+}
+
+// Runs an auction to completion with bidder1 winning and the bid escrowed.
+fn setup_finalized_auction<'a>() -> DeliveryTest<'a> {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+
+    let token_address = test_env
+        .env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    let token = token::Client::new(&test_env.env, &token_address);
+    token::StellarAssetClient::new(&test_env.env, &token_address)
+        .mint(&test_env.bidder1, &(WINNING_BID as i128));
+    client.set_settlement_config(
+        &test_env.admin,
+        &token_address,
+        &PAYMENT_WINDOW,
+        &DELIVERY_TIMEOUT,
+    );
+
+    let product = create_standard_product(&test_env.env, test_env.farmer.clone(), 1);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Product(test_env.farmer.clone(), 1), &product);
+    });
+
+    let current_time = test_env.env.ledger().timestamp();
+    client.create_auction(
+        &test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &(current_time + 100),
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    client.place_bid(&1, &WINNING_BID, &20, &test_env.bidder1, &test_env.farmer);
+
+    // Winner allows the auction contract to pull the bid at finalization
+    let expiration = test_env.env.ledger().sequence() + 1000;
+    token.approve(
+        &test_env.bidder1,
+        &test_env.contract_id,
+        &(WINNING_BID as i128),
+        &expiration,
+    );
+
+    test_env.env.ledger().with_mut(|li| {
+        li.timestamp = current_time + 200;
+    });
+    client.finalize_auction(&test_env.farmer, &1);
+
+    DeliveryTest {
+        test_env,
+        client,
+        token,
+    }
+}
+
+#[test]
+fn test_finalize_auction_escrows_winning_bid() {
+    let t = setup_finalized_auction();
+
+    assert_eq!(t.token.balance(&t.test_env.bidder1), 0);
+    assert_eq!(
+        t.token.balance(&t.test_env.contract_id),
+        WINNING_BID as i128
+    );
+
+    let settlement = t.client.get_settlement(&t.test_env.farmer, &1);
+    assert_eq!(settlement.buyer, t.test_env.bidder1);
+    assert_eq!(settlement.amount, WINNING_BID as i128);
+    assert_eq!(settlement.status, SettlementStatus::Escrowed);
+}
+
+#[test]
+fn test_confirm_delivery_with_supply_chain_product() {
+    let t = setup_finalized_auction();
+    let env = &t.test_env.env;
+
+    let supply_chain_id = env.register(MockSupplyChain, ());
+    let supply_chain = MockSupplyChainClient::new(env, &supply_chain_id);
+    let traced_id = BytesN::from_array(env, &[7; 32]);
+    supply_chain.register(&traced_id);
+    t.client
+        .set_supply_chain_contract(&t.test_env.admin, &supply_chain_id);
+
+    t.client.confirm_delivery(
+        &t.test_env.bidder1,
+        &t.test_env.farmer,
+        &1,
+        &Some(traced_id.clone()),
+    );
+
+    assert_eq!(t.token.balance(&t.test_env.farmer), WINNING_BID as i128);
+    assert_eq!(t.token.balance(&t.test_env.contract_id), 0);
+
+    let settlement = t.client.get_settlement(&t.test_env.farmer, &1);
+    assert_eq!(settlement.status, SettlementStatus::Delivered);
+    assert_eq!(settlement.supply_chain_product_id, Some(traced_id));
+}
+
+#[test]
+fn test_confirm_delivery_unknown_supply_chain_product() {
+    let t = setup_finalized_auction();
+    let env = &t.test_env.env;
+
+    let supply_chain_id = env.register(MockSupplyChain, ());
+    t.client
+        .set_supply_chain_contract(&t.test_env.admin, &supply_chain_id);
+
+    let result = t.client.try_confirm_delivery(
+        &t.test_env.bidder1,
+        &t.test_env.farmer,
+        &1,
+        &Some(BytesN::from_array(env, &[9; 32])),
+    );
+    assert_eq!(result, Err(Ok(SettlementError::SupplyChainProductNotFound)));
+    assert_eq!(
+        t.token.balance(&t.test_env.contract_id),
+        WINNING_BID as i128
+    );
+}
+
+#[test]
+fn test_confirm_delivery_wrong_buyer() {
+    let t = setup_finalized_auction();
+
+    let result = t
+        .client
+        .try_confirm_delivery(&t.test_env.bidder2, &t.test_env.farmer, &1, &None);
+    assert_eq!(result, Err(Ok(SettlementError::Unauthorized)));
+}
+
+#[test]
+fn test_reclaim_escrow_after_timeout() {
+    let t = setup_finalized_auction();
+
+    let result = t
+        .client
+        .try_reclaim_escrow(&t.test_env.bidder1, &t.test_env.farmer, &1);
+    assert_eq!(result, Err(Ok(SettlementError::DeliveryWindowOpen)));
+
+    t.test_env.env.ledger().with_mut(|li| {
+        li.timestamp += DELIVERY_TIMEOUT + 1;
+    });
+    t.client
+        .reclaim_escrow(&t.test_env.bidder1, &t.test_env.farmer, &1);
+
+    assert_eq!(t.token.balance(&t.test_env.bidder1), WINNING_BID as i128);
+    let settlement = t.client.get_settlement(&t.test_env.farmer, &1);
+    assert_eq!(settlement.status, SettlementStatus::Refunded);
+}
+
+#[test]
+fn test_dispute_holds_escrow_until_resolved() {
+    let t = setup_finalized_auction();
+
+    t.client.dispute_settlement(&t.test_env.farmer, &1);
+
+    t.test_env.env.ledger().with_mut(|li| {
+        li.timestamp += DELIVERY_TIMEOUT + 1;
+    });
+    let result = t
+        .client
+        .try_reclaim_escrow(&t.test_env.bidder1, &t.test_env.farmer, &1);
+    assert_eq!(result, Err(Ok(SettlementError::InvalidStatus)));
+
+    let outsider = Address::generate(&t.test_env.env);
+    let result = t
+        .client
+        .try_resolve_settlement_dispute(&outsider, &t.test_env.farmer, &1, &true);
+    assert_eq!(result, Err(Ok(SettlementError::Unauthorized)));
+
+    t.client
+        .resolve_settlement_dispute(&t.test_env.admin, &t.test_env.farmer, &1, &true);
+    assert_eq!(t.token.balance(&t.test_env.farmer), WINNING_BID as i128);
+
+    let settlement = t.client.get_settlement(&t.test_env.farmer, &1);
+    assert_eq!(settlement.status, SettlementStatus::Delivered);
+}
+
+#[test]
+fn test_open_settlement_blocks_new_sale_of_product() {
+    let t = setup_finalized_auction();
+    let env = &t.test_env.env;
+    let end_time = env.ledger().timestamp() + 100;
+
+    let result = t.client.try_create_auction(
+        &t.test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::SettlementPending)));
+
+    // The escrowed settlement is never overwritten by another winning bid
+    let result = env.as_contract(&t.test_env.contract_id, || {
+        escrow_winning_bid(
+            env,
+            &t.test_env.farmer,
+            vec![
+                env,
+                SettledProduct {
+                    product_id: 1,
+                    quantity: 20,
+                },
+            ],
+            &t.test_env.bidder2,
+            WINNING_BID,
+        )
+    });
+    assert_eq!(result, Err(AuctionError::SettlementPending));
+    let settlement = t.client.get_settlement(&t.test_env.farmer, &1);
+    assert_eq!(settlement.buyer, t.test_env.bidder1);
+
+    // Once delivered the product can be sold again
+    t.client
+        .confirm_delivery(&t.test_env.bidder1, &t.test_env.farmer, &1, &None);
+    let restocked = create_standard_product(env, t.test_env.farmer.clone(), 1);
+    env.as_contract(&t.test_env.contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Product(t.test_env.farmer.clone(), 1), &restocked);
+    });
+    t.client.create_auction(
+        &t.test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+}
+
+fn link_loyalty_program<'a>(t: &DeliveryTest) -> MockLoyaltyContractClient<'a> {
+    let env = &t.test_env.env;
+    let loyalty = MockLoyaltyContractClient::new(env, &env.register(MockLoyaltyContract, ()));
+    t.client.set_loyalty_program(
+        &t.test_env.admin,
+        &loyalty.address,
+        &BytesN::from_array(env, &[1; 32]),
+    );
+    loyalty
+}
+
+#[test]
+fn test_confirmed_delivery_awards_loyalty_points_once() {
+    let t = setup_finalized_auction();
+    let loyalty = link_loyalty_program(&t);
+
+    // Escrowed but undelivered purchases earn nothing yet
+    assert_eq!(loyalty.points(&t.test_env.bidder1), 0);
+
+    t.client
+        .confirm_delivery(&t.test_env.bidder1, &t.test_env.farmer, &1, &None);
+    assert_eq!(loyalty.points(&t.test_env.bidder1), WINNING_BID);
+    assert_eq!(
+        t.client.get_loyalty_program().unwrap().loyalty_contract,
+        loyalty.address
+    );
+}
+
+#[test]
+fn test_resolved_dispute_awards_loyalty_points() {
+    let t = setup_finalized_auction();
+    let loyalty = link_loyalty_program(&t);
+
+    t.client.dispute_settlement(&t.test_env.farmer, &1);
+    t.client
+        .resolve_settlement_dispute(&t.test_env.admin, &t.test_env.farmer, &1, &true);
+
+    assert_eq!(loyalty.points(&t.test_env.bidder1), WINNING_BID);
+}
+
+#[test]
+fn test_failing_loyalty_contract_does_not_block_delivery() {
+    let t = setup_finalized_auction();
+    let loyalty = link_loyalty_program(&t);
+    loyalty.set_failing(&true);
+
+    t.client
+        .confirm_delivery(&t.test_env.bidder1, &t.test_env.farmer, &1, &None);
+
+    assert_eq!(t.token.balance(&t.test_env.farmer), WINNING_BID as i128);
+    assert_eq!(loyalty.points(&t.test_env.bidder1), 0);
+}
+
+// Attaches a certification issued by `certifier` to the auctioned product.
+fn certify_product(t: &DeliveryTest, certifier: &Address) {
+    let env = &t.test_env.env;
+    let quality_id = env.register(MockCertifier, ());
+    let certification_id = BytesN::from_array(env, &[3; 32]);
+    MockCertifierClient::new(env, &quality_id).issue(
+        &certification_id,
+        &t.test_env.farmer,
+        certifier,
+    );
+    t.client
+        .set_quality_contract(&t.test_env.admin, &quality_id);
+    t.client
+        .attach_quality_certification(&t.test_env.farmer, &1, &quality_id, &certification_id);
+}
+
+#[test]
+fn test_delivery_splits_proceeds_three_ways() {
+    let t = setup_finalized_auction();
+    let env = &t.test_env.env;
+    let treasury = Address::generate(env);
+    let certifier = Address::generate(env);
+    certify_product(&t, &certifier);
+    t.client
+        .set_proceeds_split(&t.test_env.admin, &treasury, &1234, &333);
+
+    t.client
+        .confirm_delivery(&t.test_env.bidder1, &t.test_env.farmer, &1, &None);
+
+    let paid_topics = (
+        t.test_env.farmer.clone(),
+        Symbol::new(env, "ProceedsPaid"),
+        1u64,
+    )
+        .into_val(env);
+    let mut payouts: Vec<(Symbol, Address, i128)> = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        if topics == paid_topics {
+            payouts.push_back(data.into_val(env));
+        }
+    }
+    assert_eq!(
+        payouts,
+        Vec::from_array(
+            env,
+            [
+                (Symbol::new(env, "treasury"), treasury.clone(), 246),
+                (Symbol::new(env, "certifier"), certifier.clone(), 66),
+                (Symbol::new(env, "farmer"), t.test_env.farmer.clone(), 1688),
+            ]
+        )
+    );
+
+    // 2000 * 12.34% = 246.8 and 2000 * 3.33% = 66.6 round down; the farmer keeps the rest
+    assert_eq!(t.token.balance(&treasury), 246);
+    assert_eq!(t.token.balance(&certifier), 66);
+    assert_eq!(t.token.balance(&t.test_env.farmer), 1688);
+    assert_eq!(t.token.balance(&t.test_env.contract_id), 0);
+}
+
+#[test]
+fn test_uncertified_product_skips_royalty() {
+    let t = setup_finalized_auction();
+    let treasury = Address::generate(&t.test_env.env);
+    t.client
+        .set_proceeds_split(&t.test_env.admin, &treasury, &1000, &500);
+
+    t.client.dispute_settlement(&t.test_env.farmer, &1);
+    t.client
+        .resolve_settlement_dispute(&t.test_env.admin, &t.test_env.farmer, &1, &true);
+
+    assert_eq!(t.token.balance(&treasury), 200);
+    assert_eq!(t.token.balance(&t.test_env.farmer), 1800);
+    assert_eq!(t.token.balance(&t.test_env.contract_id), 0);
+}
+
+#[test]
+fn test_refund_ignores_proceeds_split() {
+    let t = setup_finalized_auction();
+    let treasury = Address::generate(&t.test_env.env);
+    t.client
+        .set_proceeds_split(&t.test_env.admin, &treasury, &1000, &500);
+
+    t.client.dispute_settlement(&t.test_env.farmer, &1);
+    t.client
+        .resolve_settlement_dispute(&t.test_env.admin, &t.test_env.farmer, &1, &false);
+
+    assert_eq!(t.token.balance(&treasury), 0);
+    assert_eq!(t.token.balance(&t.test_env.bidder1), WINNING_BID as i128);
+}
+
+#[test]
+fn test_proceeds_split_bounds() {
+    let t = setup_finalized_auction();
+    let treasury = Address::generate(&t.test_env.env);
+
+    let result = t
+        .client
+        .try_set_proceeds_split(&t.test_env.admin, &treasury, &1500, &501);
+    assert_eq!(result, Err(Ok(SettlementError::InvalidSplit)));
+
+    let result = t
+        .client
+        .try_set_proceeds_split(&t.test_env.bidder1, &treasury, &100, &100);
+    assert_eq!(result, Err(Ok(SettlementError::Unauthorized)));
+
+    t.client
+        .set_proceeds_split(&t.test_env.admin, &treasury, &1500, &500);
+    assert_eq!(
+        t.client.get_proceeds_split(),
+        Some(ProceedsSplit {
+            coop_treasury: treasury,
+            coop_bps: 1500,
+            cert_royalty_bps: 500,
+        })
+    );
+}
+
+#[test]
+fn test_settlement_windows_are_bounded() {
+    let t = setup_finalized_auction();
+    let token = t.token.address.clone();
+    let year = 365 * 24 * 60 * 60;
+
+    for (payment_window, delivery_timeout) in [
+        (0, DELIVERY_TIMEOUT),
+        (year + 1, DELIVERY_TIMEOUT),
+        (PAYMENT_WINDOW, u64::MAX),
+    ] {
+        let result = t.client.try_set_settlement_config(
+            &t.test_env.admin,
+            &token,
+            &payment_window,
+            &delivery_timeout,
+        );
+        assert_eq!(result, Err(Ok(SettlementError::InvalidTimeout)));
+    }
+
+    t.client
+        .set_settlement_config(&t.test_env.admin, &token, &year, &year);
+}
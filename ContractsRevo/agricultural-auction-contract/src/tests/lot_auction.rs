@@ -0,0 +1,470 @@
+use crate::datatype::*;
+use crate::tests::utils::*;
+use crate::AgriculturalAuctionContractClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{token, vec, Vec};
+
+fn setup_lot_test<'a>(product_count: u64) -> (TestEnv, AgriculturalAuctionContractClient<'a>) {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+
+    for product_id in 1..=product_count {
+        let product = create_standard_product(&test_env.env, test_env.farmer.clone(), product_id);
+        test_env.env.as_contract(&test_env.contract_id, || {
+            test_env.env.storage().persistent().set(
+                &DataKey::Product(test_env.farmer.clone(), product_id),
+                &product,
+            );
+        });
+    }
+
+    (test_env, client)
+}
+
+fn end_lot(test_env: &TestEnv, end_time: u64) {
+    test_env.env.ledger().with_mut(|li| {
+        li.timestamp = end_time + 1;
+    });
+}
+
+#[test]
+fn test_lot_locks_products_from_individual_auctions() {
+    let (test_env, client) = setup_lot_test(2);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+
+    let result = client.try_create_auction(
+        &test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::ProductLocked)));
+
+    // A product cannot be bundled into two lots either
+    let result = client.try_create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 2u64],
+        &500,
+        &end_time,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::ProductLocked)));
+}
+
+#[test]
+fn test_lot_size_and_duplicate_validation() {
+    let (test_env, client) = setup_lot_test(1);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let result = client.try_create_lot_auction(
+        &test_env.farmer,
+        &Vec::new(&test_env.env),
+        &1000,
+        &end_time,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::InvalidLot)));
+
+    let result = client.try_create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 1u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::InvalidLot)));
+
+    let mut too_many = Vec::new(&test_env.env);
+    for product_id in 1..=21u64 {
+        too_many.push_back(product_id);
+    }
+    let result =
+        client.try_create_lot_auction(&test_env.farmer, &too_many, &1000, &end_time, &false);
+    assert_eq!(result, Err(Ok(AuctionError::InvalidLot)));
+}
+
+#[test]
+fn test_full_lot_settlement() {
+    let (test_env, client) = setup_lot_test(3);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64, 3u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+
+    let result = client.try_place_lot_bid(&lot_id, &test_env.bidder1, &999);
+    assert_eq!(result, Err(Ok(AuctionError::BidTooLow)));
+
+    client.place_lot_bid(&lot_id, &test_env.bidder1, &1200);
+    client.place_lot_bid(&lot_id, &test_env.bidder2, &1500);
+
+    let result = client.try_place_lot_component_bid(&lot_id, &1, &test_env.bidder3, &800);
+    assert_eq!(result, Err(Ok(AuctionError::PartialBidsDisabled)));
+
+    end_lot(&test_env, end_time);
+    let awards = client.finalize_lot_auction(&test_env.farmer, &lot_id);
+
+    assert_eq!(awards.len(), 1);
+    let award = awards.get(0).unwrap();
+    assert_eq!(award.winner, test_env.bidder2);
+    assert_eq!(award.amount, 1500);
+    assert_eq!(award.product_ids.len(), 3);
+
+    let lot = client.get_lot_auction(&lot_id);
+    assert_eq!(lot.status, LotStatus::Finalized);
+    assert_eq!(client.get_product(&test_env.farmer, &2).quantity, 0);
+}
+
+#[test]
+fn test_partial_award_distribution() {
+    let (test_env, client) = setup_lot_test(3);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64, 3u64],
+        &1000,
+        &end_time,
+        &true,
+    );
+
+    client.place_lot_bid(&lot_id, &test_env.bidder1, &1000);
+    client.place_lot_component_bid(&lot_id, &1, &test_env.bidder2, &600);
+    client.place_lot_component_bid(&lot_id, &2, &test_env.bidder3, &500);
+    client.place_lot_component_bid(&lot_id, &2, &test_env.bidder2, &550);
+
+    let result = client.try_place_lot_component_bid(&lot_id, &2, &test_env.bidder3, &550);
+    assert_eq!(result, Err(Ok(AuctionError::BidTooLow)));
+
+    end_lot(&test_env, end_time);
+    let awards = client.finalize_lot_auction(&test_env.farmer, &lot_id);
+
+    // Component bids total 1150, beating the 1000 whole-lot bid
+    assert_eq!(awards.len(), 2);
+    let first = awards.get(0).unwrap();
+    assert_eq!(first.winner, test_env.bidder2);
+    assert_eq!(first.product_ids, vec![&test_env.env, 1u64]);
+    assert_eq!(first.amount, 600);
+    let second = awards.get(1).unwrap();
+    assert_eq!(second.winner, test_env.bidder2);
+    assert_eq!(second.product_ids, vec![&test_env.env, 2u64]);
+    assert_eq!(second.amount, 550);
+
+    // Product 3 received no component bid and stays in stock, unlocked
+    assert_eq!(client.get_product(&test_env.farmer, &1).quantity, 0);
+    assert_eq!(client.get_product(&test_env.farmer, &3).quantity, 100);
+    let new_end = test_env.env.ledger().timestamp() + 3600;
+    client.create_auction(
+        &test_env.farmer,
+        &3,
+        &STANDARD_RESERVE_PRICE,
+        &new_end,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+}
+
+#[test]
+fn test_partial_lot_prefers_whole_bid_when_higher() {
+    let (test_env, client) = setup_lot_test(2);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64],
+        &1000,
+        &end_time,
+        &true,
+    );
+    client.place_lot_bid(&lot_id, &test_env.bidder1, &1300);
+    client.place_lot_component_bid(&lot_id, &1, &test_env.bidder2, &700);
+
+    end_lot(&test_env, end_time);
+    let awards = client.finalize_lot_auction(&test_env.farmer, &lot_id);
+
+    assert_eq!(awards.len(), 1);
+    assert_eq!(awards.get(0).unwrap().winner, test_env.bidder1);
+}
+
+#[test]
+fn test_component_bids_below_lot_reserve_leave_lot_unsold() {
+    let (test_env, client) = setup_lot_test(2);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64],
+        &1000,
+        &end_time,
+        &true,
+    );
+
+    // Each component bid must cover its product's share of the reserve
+    let result = client.try_place_lot_component_bid(&lot_id, &1, &test_env.bidder1, &1);
+    assert_eq!(result, Err(Ok(AuctionError::BidTooLow)));
+    let result = client.try_place_lot_component_bid(&lot_id, &1, &test_env.bidder1, &499);
+    assert_eq!(result, Err(Ok(AuctionError::BidTooLow)));
+    client.place_lot_component_bid(&lot_id, &1, &test_env.bidder1, &600);
+
+    // 600 falls short of the 1000 lot reserve, so nothing is sold
+    end_lot(&test_env, end_time);
+    let awards = client.finalize_lot_auction(&test_env.farmer, &lot_id);
+    assert!(awards.is_empty());
+
+    let lot = client.get_lot_auction(&lot_id);
+    assert_eq!(lot.status, LotStatus::Finalized);
+    assert_eq!(client.get_product(&test_env.farmer, &1).quantity, 100);
+
+    let new_end = test_env.env.ledger().timestamp() + 3600;
+    client.create_auction(
+        &test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &new_end,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+}
+
+#[test]
+fn test_cancel_lot_unlocks_products() {
+    let (test_env, client) = setup_lot_test(2);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64, 2u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    client.cancel_lot_auction(&test_env.farmer, &lot_id);
+    assert_eq!(client.get_lot_auction(&lot_id).status, LotStatus::Cancelled);
+
+    let result = client.try_place_lot_bid(&lot_id, &test_env.bidder1, &1200);
+    assert_eq!(result, Err(Ok(AuctionError::LotNotOpen)));
+
+    client.create_auction(
+        &test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+}
+
+#[test]
+fn test_cancel_lot_with_bids_rejected() {
+    let (test_env, client) = setup_lot_test(1);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    client.place_lot_bid(&lot_id, &test_env.bidder1, &1000);
+
+    let result = client.try_cancel_lot_auction(&test_env.farmer, &lot_id);
+    assert_eq!(result, Err(Ok(AuctionError::LotHasBids)));
+}
+
+#[test]
+fn test_lots_count_towards_open_auction_cap() {
+    let (test_env, client) = setup_lot_test(3);
+    client.set_max_open_auctions(&test_env.admin, &2);
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 1u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    client.create_auction(
+        &test_env.farmer,
+        &2,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    let result = client.try_create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 3u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::TooManyOpenAuctions)));
+
+    // A cancelled lot frees its slot
+    client.cancel_lot_auction(&test_env.farmer, &lot_id);
+    client.create_lot_auction(
+        &test_env.farmer,
+        &vec![&test_env.env, 3u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+}
+
+#[test]
+fn test_lot_awards_are_escrowed_and_settled() {
+    let (test_env, client) = setup_lot_test(3);
+    let env = &test_env.env;
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    let token = token::Client::new(env, &token_address);
+    client.set_settlement_config(&test_env.admin, &token_address, &86_400, &604_800);
+
+    let end_time = env.ledger().timestamp() + 3600;
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![env, 1u64, 2u64, 3u64],
+        &1000,
+        &end_time,
+        &true,
+    );
+    client.place_lot_component_bid(&lot_id, &1, &test_env.bidder1, &600);
+    client.place_lot_component_bid(&lot_id, &2, &test_env.bidder2, &550);
+
+    // Only bidder1 approved the contract to pull their bid
+    token::StellarAssetClient::new(env, &token_address).mint(&test_env.bidder1, &600);
+    token.approve(
+        &test_env.bidder1,
+        &test_env.contract_id,
+        &600,
+        &(env.ledger().sequence() + 1000),
+    );
+
+    end_lot(&test_env, end_time);
+    client.finalize_lot_auction(&test_env.farmer, &lot_id);
+
+    let escrowed = client.get_settlement(&test_env.farmer, &1);
+    assert_eq!(escrowed.buyer, test_env.bidder1);
+    assert_eq!(escrowed.amount, 600);
+    assert_eq!(escrowed.quantity, 100);
+    assert_eq!(escrowed.status, SettlementStatus::Escrowed);
+    assert_eq!(token.balance(&test_env.contract_id), 600);
+
+    let awaiting = client.get_settlement(&test_env.farmer, &2);
+    assert_eq!(awaiting.buyer, test_env.bidder2);
+    assert_eq!(awaiting.status, SettlementStatus::AwaitingPayment);
+
+    client.confirm_delivery(&test_env.bidder1, &test_env.farmer, &1, &None);
+    assert_eq!(token.balance(&test_env.farmer), 600);
+
+    // The unpaid product cannot be bundled again until its sale is settled, even restocked
+    let restocked = create_standard_product(env, test_env.farmer.clone(), 2);
+    env.as_contract(&test_env.contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Product(test_env.farmer.clone(), 2), &restocked);
+    });
+    let result = client.try_create_lot_auction(
+        &test_env.farmer,
+        &vec![env, 2u64, 3u64],
+        &1000,
+        &(env.ledger().timestamp() + 3600),
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::SettlementPending)));
+}
+
+#[test]
+fn test_whole_lot_award_settles_every_product() {
+    let (test_env, client) = setup_lot_test(3);
+    let env = &test_env.env;
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    client.set_settlement_config(&test_env.admin, &token_address, &86_400, &604_800);
+
+    let end_time = env.ledger().timestamp() + 3600;
+    let lot_id = client.create_lot_auction(
+        &test_env.farmer,
+        &vec![env, 1u64, 2u64, 3u64],
+        &1000,
+        &end_time,
+        &false,
+    );
+    client.place_lot_bid(&lot_id, &test_env.bidder1, &1500);
+
+    end_lot(&test_env, end_time);
+    client.finalize_lot_auction(&test_env.farmer, &lot_id);
+
+    // One settlement covers the whole lot and is reachable through any of its products
+    let settlement = client.get_settlement(&test_env.farmer, &3);
+    assert_eq!(settlement.product_id, 1);
+    assert_eq!(settlement.products.len(), 3);
+    assert_eq!(settlement.quantity, 300);
+    assert_eq!(settlement.status, SettlementStatus::AwaitingPayment);
+
+    // The winner never pays: every product gets its own quantity back
+    env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+    client.report_unpaid(&test_env.farmer, &2);
+    for product_id in 1..=3u64 {
+        assert_eq!(
+            client.get_product(&test_env.farmer, &product_id).quantity,
+            100
+        );
+    }
+    assert_eq!(
+        client.get_settlement(&test_env.farmer, &1).status,
+        SettlementStatus::Defaulted
+    );
+
+    // A later individual sale of a covered product is settled on its own
+    let new_end = env.ledger().timestamp() + 3600;
+    client.create_auction(
+        &test_env.farmer,
+        &2,
+        &STANDARD_RESERVE_PRICE,
+        &new_end,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    client.place_bid(&2, &2000, &20, &test_env.bidder2, &test_env.farmer);
+    env.ledger().with_mut(|li| li.timestamp = new_end + 1);
+    client.finalize_auction(&test_env.farmer, &2);
+
+    let individual = client.get_settlement(&test_env.farmer, &2);
+    assert_eq!(individual.product_id, 2);
+    assert_eq!(individual.buyer, test_env.bidder2);
+    assert_eq!(client.get_settlement(&test_env.farmer, &3).product_id, 1);
+}
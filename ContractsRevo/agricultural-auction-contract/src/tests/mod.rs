@@ -1,4 +1,9 @@
 pub mod auction;
 pub mod bidding;
+pub mod delivery;
+pub mod lot_auction;
+pub mod moderation;
+pub mod price_band;
+pub mod quality_grade;
 pub mod settlement;
 pub mod utils;
@@ -0,0 +1,256 @@
+use crate::datatype::*;
+use crate::tests::utils::*;
+use crate::AgriculturalAuctionContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, String};
+
+const PAYMENT_WINDOW: u64 = 24 * 60 * 60;
+const DELIVERY_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+fn setup_moderation_test<'a>() -> (TestEnv, AgriculturalAuctionContractClient<'a>) {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+    (test_env, client)
+}
+
+fn store_product(test_env: &TestEnv, product_id: u64) {
+    let product = create_standard_product(&test_env.env, test_env.farmer.clone(), product_id);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::Product(test_env.farmer.clone(), product_id),
+            &product,
+        );
+    });
+}
+
+fn try_create_auction(
+    test_env: &TestEnv,
+    client: &AgriculturalAuctionContractClient,
+    product_id: u64,
+    end_time: u64,
+) -> Result<(), AuctionError> {
+    match client.try_create_auction(
+        &test_env.farmer,
+        &product_id,
+        &STANDARD_RESERVE_PRICE,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    ) {
+        Ok(_) => Ok(()),
+        Err(Ok(e)) => Err(e),
+        Err(Err(e)) => panic!("unexpected host error: {:?}", e),
+    }
+}
+
+#[test]
+fn test_blacklisted_farmer_cannot_create_auction() {
+    let (test_env, client) = setup_moderation_test();
+    store_product(&test_env, 1);
+
+    let reason = String::from_str(&test_env.env, "Repeated non-delivery");
+    client.blacklist(&test_env.admin, &test_env.farmer, &reason);
+    assert!(client.is_blacklisted(&test_env.farmer));
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    assert_eq!(
+        try_create_auction(&test_env, &client, 1, end_time),
+        Err(AuctionError::Blacklisted)
+    );
+
+    client.unblacklist(&test_env.admin, &test_env.farmer);
+    assert_eq!(try_create_auction(&test_env, &client, 1, end_time), Ok(()));
+}
+
+#[test]
+fn test_blacklisted_bidder_cannot_bid() {
+    let (test_env, client) = setup_moderation_test();
+    store_product(&test_env, 1);
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    try_create_auction(&test_env, &client, 1, end_time).unwrap();
+
+    let reason = String::from_str(&test_env.env, "Never paid");
+    client.blacklist(&test_env.admin, &test_env.bidder1, &reason);
+
+    let result = client.try_place_bid(&1, &2000, &20, &test_env.bidder1, &test_env.farmer);
+    assert_eq!(result, Err(Ok(AuctionError::Blacklisted)));
+
+    // Other bidders are unaffected
+    assert!(client.place_bid(&1, &2000, &20, &test_env.bidder2, &test_env.farmer));
+}
+
+#[test]
+fn test_blacklist_admin_only_and_listing() {
+    let (test_env, client) = setup_moderation_test();
+    let reason = String::from_str(&test_env.env, "Spam");
+
+    let result = client.try_blacklist(&test_env.farmer, &test_env.bidder1, &reason);
+    assert_eq!(result, Err(Ok(ModerationError::Unauthorized)));
+
+    client.blacklist(&test_env.admin, &test_env.bidder1, &reason);
+    client.blacklist(&test_env.admin, &test_env.bidder2, &reason);
+    client.blacklist(&test_env.admin, &test_env.bidder3, &reason);
+
+    let result = client.try_blacklist(&test_env.admin, &test_env.bidder1, &reason);
+    assert_eq!(result, Err(Ok(ModerationError::AlreadyBlacklisted)));
+
+    let page = client.list_blacklisted(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap(), test_env.bidder2);
+    assert_eq!(client.list_blacklisted(&3, &5).len(), 0);
+
+    client.unblacklist(&test_env.admin, &test_env.bidder2);
+    assert_eq!(client.list_blacklisted(&0, &10).len(), 2);
+}
+
+#[test]
+fn test_auto_blacklist_after_strike_threshold() {
+    let (test_env, client) = setup_moderation_test();
+    let env = &test_env.env;
+
+    // Payment token configured, but the winner never grants an allowance
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    client.set_settlement_config(
+        &test_env.admin,
+        &token_address,
+        &PAYMENT_WINDOW,
+        &DELIVERY_TIMEOUT,
+    );
+    client.set_strike_threshold(&test_env.admin, &2);
+
+    for product_id in 1..=2u64 {
+        store_product(&test_env, product_id);
+        let start = env.ledger().timestamp();
+        try_create_auction(&test_env, &client, product_id, start + 100).unwrap();
+        client.place_bid(&product_id, &2000, &20, &test_env.bidder1, &test_env.farmer);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp = start + 200;
+        });
+        client.finalize_auction(&test_env.farmer, &product_id);
+        let settlement = client.get_settlement(&test_env.farmer, &product_id);
+        assert_eq!(settlement.status, SettlementStatus::AwaitingPayment);
+
+        let result = client.try_report_unpaid(&test_env.farmer, &product_id);
+        assert_eq!(result, Err(Ok(SettlementError::PaymentWindowOpen)));
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += PAYMENT_WINDOW + 1;
+        });
+        client.report_unpaid(&test_env.farmer, &product_id);
+    }
+
+    assert_eq!(client.get_strikes(&test_env.bidder1), 2);
+    assert!(client.is_blacklisted(&test_env.bidder1));
+    let entry = client.get_blacklist_entry(&test_env.bidder1).unwrap();
+    assert!(entry.automatic);
+
+    // Inventory from the defaulted sale is returned to the farmer
+    assert_eq!(client.get_product(&test_env.farmer, &1).quantity, 100);
+}
+
+#[test]
+fn test_late_payment_rejected_after_window() {
+    let (test_env, client) = setup_moderation_test();
+    let env = &test_env.env;
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(&test_env.bidder1, &2000);
+    client.set_settlement_config(
+        &test_env.admin,
+        &token_address,
+        &PAYMENT_WINDOW,
+        &DELIVERY_TIMEOUT,
+    );
+
+    store_product(&test_env, 1);
+    let start = env.ledger().timestamp();
+    try_create_auction(&test_env, &client, 1, start + 100).unwrap();
+    client.place_bid(&1, &2000, &20, &test_env.bidder1, &test_env.farmer);
+    env.ledger().with_mut(|li| {
+        li.timestamp = start + 200;
+    });
+    client.finalize_auction(&test_env.farmer, &1);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += PAYMENT_WINDOW + 1;
+    });
+    let result = client.try_pay_settlement(&test_env.bidder1, &test_env.farmer, &1);
+    assert_eq!(result, Err(Ok(SettlementError::PaymentWindowClosed)));
+}
+
+#[test]
+fn test_pay_settlement_within_window() {
+    let (test_env, client) = setup_moderation_test();
+    let env = &test_env.env;
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(&test_env.bidder1, &2000);
+    client.set_settlement_config(
+        &test_env.admin,
+        &token_address,
+        &PAYMENT_WINDOW,
+        &DELIVERY_TIMEOUT,
+    );
+
+    store_product(&test_env, 1);
+    let start = env.ledger().timestamp();
+    try_create_auction(&test_env, &client, 1, start + 100).unwrap();
+    client.place_bid(&1, &2000, &20, &test_env.bidder1, &test_env.farmer);
+    env.ledger().with_mut(|li| {
+        li.timestamp = start + 200;
+    });
+    client.finalize_auction(&test_env.farmer, &1);
+
+    client.pay_settlement(&test_env.bidder1, &test_env.farmer, &1);
+
+    let settlement = client.get_settlement(&test_env.farmer, &1);
+    assert_eq!(settlement.status, SettlementStatus::Escrowed);
+    assert_eq!(settlement.delivery_deadline, start + 200 + DELIVERY_TIMEOUT);
+    assert_eq!(
+        token::Client::new(env, &token_address).balance(&test_env.contract_id),
+        2000
+    );
+    assert_eq!(client.get_strikes(&test_env.bidder1), 0);
+}
+
+#[test]
+fn test_concurrent_open_auction_cap() {
+    let (test_env, client) = setup_moderation_test();
+    client.set_max_open_auctions(&test_env.admin, &2);
+
+    for product_id in 1..=3u64 {
+        store_product(&test_env, product_id);
+    }
+
+    let start = test_env.env.ledger().timestamp();
+    try_create_auction(&test_env, &client, 1, start + 100).unwrap();
+    try_create_auction(&test_env, &client, 2, start + 3600).unwrap();
+    assert_eq!(
+        try_create_auction(&test_env, &client, 3, start + 3600),
+        Err(AuctionError::TooManyOpenAuctions)
+    );
+
+    // Once the first auction's bidding window closes, a slot frees up
+    test_env.env.ledger().with_mut(|li| {
+        li.timestamp = start + 200;
+    });
+    assert_eq!(
+        try_create_auction(&test_env, &client, 3, start + 3600),
+        Ok(())
+    );
+
+    let outsider = Address::generate(&test_env.env);
+    let result = client.try_set_max_open_auctions(&outsider, &5);
+    assert_eq!(result, Err(Ok(ModerationError::Unauthorized)));
+}
@@ -0,0 +1,175 @@
+use crate::datatype::*;
+use crate::tests::utils::*;
+use crate::AgriculturalAuctionContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Symbol};
+
+fn setup_band_test<'a>() -> (TestEnv, AgriculturalAuctionContractClient<'a>, Address) {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+
+    let oracle = Address::generate(&test_env.env);
+    client.set_price_oracle(&test_env.admin, &oracle);
+
+    let product = create_standard_product(&test_env.env, test_env.farmer.clone(), 1);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Product(test_env.farmer.clone(), 1), &product);
+    });
+
+    (test_env, client, oracle)
+}
+
+fn tomato(test_env: &TestEnv) -> (Symbol, Symbol) {
+    (
+        Symbol::new(&test_env.env, "Tomato"),
+        Symbol::new(&test_env.env, "North"),
+    )
+}
+
+#[test]
+fn test_create_auction_inside_band_records_reference_price() {
+    let (test_env, client, oracle) = setup_band_test();
+    let (product_type, region) = tomato(&test_env);
+
+    client.set_price_band(&test_env.admin, &product_type, &5_000, &20_000);
+    client.update_market_price(&oracle, &product_type, &region, &100, &0, &1000);
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    client.create_auction(
+        &test_env.farmer,
+        &1,
+        &150,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+
+    let auction = client.get_auction(&test_env.farmer, &1);
+    assert_eq!(auction.reference_price, Some(100));
+}
+
+#[test]
+fn test_create_auction_outside_band() {
+    let (test_env, client, oracle) = setup_band_test();
+    let (product_type, region) = tomato(&test_env);
+
+    client.set_price_band(&test_env.admin, &product_type, &5_000, &20_000);
+    client.update_market_price(&oracle, &product_type, &region, &100, &0, &1000);
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    for start_price in [49u64, 201u64] {
+        let result = client.try_create_auction(
+            &test_env.farmer,
+            &1,
+            &start_price,
+            &end_time,
+            &STANDARD_MIN_QUANTITY,
+            &STANDARD_BULK_THRESHOLD,
+            &STANDARD_BULK_DISCOUNT,
+            &false,
+        );
+        assert_eq!(result, Err(Ok(AuctionError::StartPriceOutOfBand)));
+    }
+}
+
+#[test]
+fn test_stale_reference_price_bypassed_by_default() {
+    let (test_env, client, oracle) = setup_band_test();
+    let (product_type, region) = tomato(&test_env);
+
+    client.set_price_band(&test_env.admin, &product_type, &5_000, &20_000);
+    client.update_market_price(&oracle, &product_type, &region, &100, &0, &1000);
+
+    // Let the market price age beyond the default one-day window
+    test_env.env.ledger().with_mut(|li| {
+        li.timestamp += 2 * 24 * 60 * 60;
+    });
+    let mut product = create_standard_product(&test_env.env, test_env.farmer.clone(), 1);
+    product.expiry_date = test_env.env.ledger().timestamp() + 86400 * 7;
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Product(test_env.farmer.clone(), 1), &product);
+    });
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    client.create_auction(
+        &test_env.farmer,
+        &1,
+        &500,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+
+    let auction = client.get_auction(&test_env.farmer, &1);
+    assert_eq!(auction.reference_price, None);
+}
+
+#[test]
+fn test_stale_reference_price_rejected_when_bypass_disabled() {
+    let (test_env, client, oracle) = setup_band_test();
+    let (product_type, region) = tomato(&test_env);
+
+    client.set_price_band(&test_env.admin, &product_type, &5_000, &20_000);
+    client.set_price_staleness(&test_env.admin, &600, &false);
+    client.update_market_price(&oracle, &product_type, &region, &100, &0, &1000);
+
+    test_env.env.ledger().with_mut(|li| {
+        li.timestamp += 601;
+    });
+
+    let end_time = test_env.env.ledger().timestamp() + 3600;
+    let result = client.try_create_auction(
+        &test_env.farmer,
+        &1,
+        &100,
+        &end_time,
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(AuctionError::ReferencePriceUnavailable)));
+}
+
+#[test]
+fn test_update_market_price_requires_registered_oracle() {
+    let (test_env, client, oracle) = setup_band_test();
+    let (product_type, region) = tomato(&test_env);
+
+    // Once an oracle is registered, even the admin can no longer push prices
+    let result =
+        client.try_update_market_price(&test_env.admin, &product_type, &region, &100, &0, &1000);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+
+    client.update_market_price(&oracle, &product_type, &region, &100, &0, &1000);
+    let market_price = client.fetch_market_price(&product_type, &region);
+    assert_eq!(market_price.price, 100);
+    assert_eq!(market_price.timestamp, test_env.env.ledger().timestamp());
+}
+
+#[test]
+fn test_set_price_band_validation() {
+    let (test_env, client, _) = setup_band_test();
+    let (product_type, _) = tomato(&test_env);
+
+    let result = client.try_set_price_band(&test_env.admin, &product_type, &20_000, &5_000);
+    assert_eq!(result, Err(Ok(OracleError::InvalidPriceBand)));
+
+    let result = client.try_set_price_band(&test_env.farmer, &product_type, &5_000, &20_000);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+
+    assert_eq!(client.get_price_band(&product_type), None);
+}
@@ -0,0 +1,257 @@
+use crate::datatype::*;
+use crate::tests::utils::*;
+use crate::AgriculturalAuctionContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contracterror, contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+const CERT_VALIDITY: u64 = 365 * 24 * 60 * 60;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MockQualityError {
+    CertificationNotFound = 1,
+}
+
+#[contract]
+pub struct MockQualityContract;
+
+#[contractimpl]
+impl MockQualityContract {
+    pub fn issue(
+        env: Env,
+        certification_id: BytesN<32>,
+        holder: Address,
+        status: QualityCertStatus,
+        audit_score: u32,
+    ) {
+        let now = env.ledger().timestamp();
+        let certification = QualityCertification {
+            holder: holder.clone(),
+            standard: QualityCertStandard::Organic,
+            status,
+            issue_date: now,
+            expiry_date: now + CERT_VALIDITY,
+            issuer: holder,
+            audit_score,
+            conditions: Vec::new(&env),
+        };
+        env.storage()
+            .persistent()
+            .set(&certification_id, &certification);
+    }
+
+    pub fn get_certification(
+        env: Env,
+        certification_id: BytesN<32>,
+    ) -> Result<QualityCertification, MockQualityError> {
+        env.storage()
+            .persistent()
+            .get(&certification_id)
+            .ok_or(MockQualityError::CertificationNotFound)
+    }
+}
+
+struct QualityTest<'a> {
+    test_env: TestEnv,
+    client: AgriculturalAuctionContractClient<'a>,
+    quality: MockQualityContractClient<'a>,
+}
+
+// Product 1 stored and listed for the farmer, with the mock quality contract trusted.
+fn setup_quality_test<'a>() -> QualityTest<'a> {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+
+    let quality_id = test_env.env.register(MockQualityContract, ());
+    let quality = MockQualityContractClient::new(&test_env.env, &quality_id);
+    client.set_quality_contract(&test_env.admin, &quality_id);
+
+    let product = create_standard_product(&test_env.env, test_env.farmer.clone(), 1);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        let storage = test_env.env.storage().persistent();
+        storage.set(&DataKey::Product(test_env.farmer.clone(), 1), &product);
+        let mut list = Vec::new(&test_env.env);
+        list.push_back(product.clone());
+        storage.set(&DataKey::ProductList(test_env.farmer.clone()), &list);
+    });
+
+    QualityTest {
+        test_env,
+        client,
+        quality,
+    }
+}
+
+fn cert_id(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+fn try_create_auction(test: &QualityTest) -> Result<(), AuctionError> {
+    let current_time = test.test_env.env.ledger().timestamp();
+    match test.client.try_create_auction(
+        &test.test_env.farmer,
+        &1,
+        &STANDARD_RESERVE_PRICE,
+        &(current_time + 100),
+        &STANDARD_MIN_QUANTITY,
+        &STANDARD_BULK_THRESHOLD,
+        &STANDARD_BULK_DISCOUNT,
+        &false,
+    ) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected conversion failure"),
+    }
+}
+
+#[test]
+fn test_attach_quality_certification() {
+    let test = setup_quality_test();
+    let env = &test.test_env.env;
+    let farmer = &test.test_env.farmer;
+    test.quality
+        .issue(&cert_id(env, 1), farmer, &QualityCertStatus::Active, &92);
+
+    let grade = test.client.attach_quality_certification(
+        farmer,
+        &1,
+        &test.quality.address,
+        &cert_id(env, 1),
+    );
+    assert_eq!(grade, Symbol::new(env, "A"));
+
+    let product = test.client.get_product(farmer, &1);
+    assert_eq!(product.grade, Symbol::new(env, "A"));
+    assert_eq!(product.quality_certification, Some(cert_id(env, 1)));
+
+    let listed = test.client.get_products(farmer).get(0).unwrap();
+    assert_eq!(listed.grade, Symbol::new(env, "A"));
+}
+
+#[test]
+fn test_invalid_or_foreign_certification_rejected() {
+    let test = setup_quality_test();
+    let env = &test.test_env.env;
+    let farmer = &test.test_env.farmer;
+    let other_farmer = Address::generate(env);
+    test.quality.issue(
+        &cert_id(env, 1),
+        &other_farmer,
+        &QualityCertStatus::Active,
+        &95,
+    );
+    test.quality
+        .issue(&cert_id(env, 2), farmer, &QualityCertStatus::Pending, &95);
+
+    // Held by someone else, not approved, and unknown
+    for seed in [1, 2, 3] {
+        let result = test.client.try_attach_quality_certification(
+            farmer,
+            &1,
+            &test.quality.address,
+            &cert_id(env, seed),
+        );
+        assert_eq!(result, Err(Ok(ProductError::InvalidCertification)));
+    }
+
+    // Certifications from an untrusted contract are not read
+    let untrusted = env.register(MockQualityContract, ());
+    MockQualityContractClient::new(env, &untrusted).issue(
+        &cert_id(env, 4),
+        farmer,
+        &QualityCertStatus::Active,
+        &95,
+    );
+    let result =
+        test.client
+            .try_attach_quality_certification(farmer, &1, &untrusted, &cert_id(env, 4));
+    assert_eq!(result, Err(Ok(ProductError::InvalidCertification)));
+
+    let product = test.client.get_product(farmer, &1);
+    assert_eq!(product.grade, Symbol::new(env, "ungraded"));
+    assert_eq!(product.quality_certification, None);
+}
+
+#[test]
+fn test_attach_requires_quality_contract() {
+    let test_env = setup_test();
+    let client = AgriculturalAuctionContractClient::new(&test_env.env, &test_env.contract_id);
+    client.initialize(&test_env.admin);
+
+    let result = client.try_attach_quality_certification(
+        &test_env.farmer,
+        &1,
+        &Address::generate(&test_env.env),
+        &cert_id(&test_env.env, 1),
+    );
+    assert_eq!(result, Err(Ok(ProductError::QualityContractNotSet)));
+
+    let result =
+        client.try_set_quality_contract(&test_env.farmer, &Address::generate(&test_env.env));
+    assert_eq!(result, Err(Ok(ProductError::Unauthorized)));
+}
+
+#[test]
+fn test_min_grade_gates_auction_creation() {
+    let test = setup_quality_test();
+    let env = &test.test_env.env;
+    let farmer = &test.test_env.farmer;
+    let tomato = Symbol::new(env, "Tomato");
+
+    let result = test.client.try_set_min_grade_for_auction(
+        &test.test_env.admin,
+        &tomato,
+        &Some(Symbol::new(env, "AAA")),
+    );
+    assert_eq!(result, Err(Ok(ProductError::InvalidGrade)));
+
+    test.client.set_min_grade_for_auction(
+        &test.test_env.admin,
+        &tomato,
+        &Some(Symbol::new(env, "B")),
+    );
+    assert_eq!(
+        test.client.get_min_grade_for_auction(&tomato),
+        Some(Symbol::new(env, "B"))
+    );
+
+    // Ungraded and grade C products are below the minimum
+    assert_eq!(try_create_auction(&test), Err(AuctionError::GradeTooLow));
+    test.quality
+        .issue(&cert_id(env, 1), farmer, &QualityCertStatus::Active, &65);
+    test.client
+        .attach_quality_certification(farmer, &1, &test.quality.address, &cert_id(env, 1));
+    assert_eq!(try_create_auction(&test), Err(AuctionError::GradeTooLow));
+
+    // A grade B certification meets it
+    test.quality
+        .issue(&cert_id(env, 2), farmer, &QualityCertStatus::Active, &80);
+    let grade = test.client.attach_quality_certification(
+        farmer,
+        &1,
+        &test.quality.address,
+        &cert_id(env, 2),
+    );
+    assert_eq!(grade, Symbol::new(env, "B"));
+    assert_eq!(try_create_auction(&test), Ok(()));
+}
+
+#[test]
+fn test_cleared_min_grade_allows_ungraded_products() {
+    let test = setup_quality_test();
+    let env = &test.test_env.env;
+    let tomato = Symbol::new(env, "Tomato");
+
+    test.client.set_min_grade_for_auction(
+        &test.test_env.admin,
+        &tomato,
+        &Some(Symbol::new(env, "C")),
+    );
+    assert_eq!(try_create_auction(&test), Err(AuctionError::GradeTooLow));
+
+    test.client
+        .set_min_grade_for_auction(&test.test_env.admin, &tomato, &None);
+    assert_eq!(test.client.get_min_grade_for_auction(&tomato), None);
+    assert_eq!(try_create_auction(&test), Ok(()));
+}
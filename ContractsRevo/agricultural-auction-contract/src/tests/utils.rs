@@ -67,6 +67,8 @@ pub fn create_standard_product(env: &Env, farmer: Address, product_id: u64) -> A
         product_type: Symbol::new(env, "Tomato"),
         region: Symbol::new(env, "North"),
         seasonal_status: SeasonalStatus::InSeason,
+        quality_certification: None,
+        grade: Symbol::new(env, "ungraded"),
     }
 }
 
@@ -0,0 +1,177 @@
+use crate::datatypes::*;
+use soroban_sdk::{token, Address, BytesN, Env, Symbol};
+
+// Helper function to verify the caller is the contract admin
+fn verify_admin(env: &Env, admin: &Address) -> Result<(), AgricQualityError> {
+    let stored: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(AgricQualityError::Unauthorized)?;
+
+    if stored != *admin {
+        return Err(AgricQualityError::Unauthorized);
+    }
+    admin.require_auth();
+    Ok(())
+}
+
+pub fn set_dispute_bond(
+    env: &Env,
+    admin: &Address,
+    token: Address,
+    amount: i128,
+    treasury: Option<Address>,
+) -> Result<(), AgricQualityError> {
+    verify_admin(env, admin)?;
+
+    if amount < 0 {
+        return Err(AgricQualityError::InvalidInput);
+    }
+
+    env.storage().instance().set(
+        &DataKey::BondConfig,
+        &BondConfig {
+            token: token.clone(),
+            amount,
+            treasury,
+        },
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "dispute_bond_set"),),
+        (admin, token, amount),
+    );
+
+    Ok(())
+}
+
+pub fn get_dispute_bond(env: &Env) -> Option<BondConfig> {
+    env.storage().instance().get(&DataKey::BondConfig)
+}
+
+pub fn set_bond_waiver(
+    env: &Env,
+    admin: &Address,
+    filer: &Address,
+    waived: bool,
+) -> Result<(), AgricQualityError> {
+    verify_admin(env, admin)?;
+
+    if waived {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BondWaiver(filer.clone()), &true);
+    } else {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BondWaiver(filer.clone()));
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "bond_waiver_set"),),
+        (admin, filer, waived),
+    );
+
+    Ok(())
+}
+
+pub fn is_bond_waived(env: &Env, filer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BondWaiver(filer.clone()))
+        .unwrap_or(false)
+}
+
+/// Escrows the configured filing bond from the complainant. A failed transfer fails the
+/// filing, so no dispute exists without its bond.
+pub fn collect_bond(env: &Env, complainant: &Address) -> Result<DisputeBond, AgricQualityError> {
+    let config = match get_dispute_bond(env) {
+        Some(config) if config.amount > 0 => config,
+        _ => {
+            return Ok(DisputeBond {
+                token: None,
+                amount: 0,
+                status: BondStatus::NotRequired,
+            })
+        }
+    };
+
+    if is_bond_waived(env, complainant) {
+        return Ok(DisputeBond {
+            token: Some(config.token),
+            amount: 0,
+            status: BondStatus::Waived,
+        });
+    }
+
+    let transfer = token::Client::new(env, &config.token).try_transfer(
+        complainant,
+        &env.current_contract_address(),
+        &config.amount,
+    );
+    if !matches!(transfer, Ok(Ok(()))) {
+        return Err(AgricQualityError::BondTransferFailed);
+    }
+
+    Ok(DisputeBond {
+        token: Some(config.token),
+        amount: config.amount,
+        status: BondStatus::Held,
+    })
+}
+
+pub fn store_bond(env: &Env, dispute_id: &BytesN<32>, bond: &DisputeBond) {
+    if bond.status != BondStatus::NotRequired {
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeBond(dispute_id.clone()), bond);
+    }
+}
+
+/// Bond posted with a dispute; disputes filed without one report `NotRequired`
+pub fn get_filing_bond(env: &Env, dispute_id: &BytesN<32>) -> DisputeBond {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeBond(dispute_id.clone()))
+        .unwrap_or(DisputeBond {
+            token: None,
+            amount: 0,
+            status: BondStatus::NotRequired,
+        })
+}
+
+/// Pays out a held bond: back to the complainant, or to the treasury (falling back to the
+/// respondent) when the dispute was dismissed.
+pub fn settle_bond(env: &Env, dispute: &DisputeData, forfeit: bool) {
+    let mut bond = get_filing_bond(env, &dispute.id);
+    let token = match (&bond.status, &bond.token) {
+        (BondStatus::Held, Some(token)) => token.clone(),
+        _ => return,
+    };
+
+    let recipient = if forfeit {
+        get_dispute_bond(env)
+            .and_then(|config| config.treasury)
+            .unwrap_or_else(|| dispute.respondent.clone())
+    } else {
+        dispute.complainant.clone()
+    };
+
+    token::Client::new(env, &token).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &bond.amount,
+    );
+    bond.status = if forfeit {
+        BondStatus::Forfeited
+    } else {
+        BondStatus::Refunded
+    };
+    store_bond(env, &dispute.id, &bond);
+
+    env.events().publish(
+        (Symbol::new(env, "dispute_bond_settled"),),
+        (dispute.id.clone(), recipient, bond.amount),
+    );
+}
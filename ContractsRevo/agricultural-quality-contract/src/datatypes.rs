@@ -117,6 +117,32 @@ pub struct DisputeData {
     pub appeal_deadline: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BondStatus {
+    NotRequired, // No bond was configured at filing
+    Waived,      // Filer exempted by the admin
+    Held,        // Escrowed by the contract until resolution
+    Refunded,    // Returned to the complainant
+    Forfeited,   // Paid to the treasury, or the respondent if none is set
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBond {
+    pub token: Option<Address>,
+    pub amount: i128,
+    pub status: BondStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    pub token: Address,
+    pub amount: i128,              // 0 disables the bond
+    pub treasury: Option<Address>, // Receives forfeited bonds instead of the respondent
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Evidence {
@@ -136,6 +162,8 @@ pub enum DataKey {
     Inspectors,                       // -> Vec<Address>
     Mediators,                        // -> Vec<Address>
     StandardMetrics(QualityStandard), // Standard -> Vec<Symbol>
+    IdCounter,                        // -> u64, bumped per generated ID
+    BondConfig,                       // -> BondConfig
 
     // Persistent storage (long-term data)
     Certification(BytesN<32>), // Certification ID -> CertificationData
@@ -146,7 +174,10 @@ pub enum DataKey {
     HolderCertifications(Address), // Address -> Vec<BytesN<32>>
     IssuerCertifications(Address), // Address -> Vec<BytesN<32>>
     DisputesByHolder(Address), // Address -> Vec<BytesN<32>>
+    OpenDisputes(Address),     // Address -> u32, disputes not yet resolved or closed
     DisputesByStandard(QualityStandard), // Standard -> Vec<BytesN<32>>
+    BondWaiver(Address),       // Filer -> bool
+    DisputeBond(BytesN<32>),   // Dispute ID -> DisputeBond, absent when filed without one
 }
 
 #[contracterror]
@@ -168,6 +199,8 @@ pub enum AgricQualityError {
     InsufficientAuthority = 13,
     InvalidTimestamp = 14,
     DuplicateSubmission = 15,
+    DuplicateId = 16,
+    BondTransferFailed = 17,
 }
 
 #[contracterror]
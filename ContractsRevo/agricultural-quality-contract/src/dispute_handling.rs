@@ -1,4 +1,6 @@
+use crate::bond::{collect_bond, store_bond};
 use crate::datatypes::*;
+use crate::utils::generate_id;
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
@@ -7,15 +9,12 @@ fn generate_dispute_id(
     env: &Env,
     complainant: &Address,
     certification_id: &BytesN<32>,
-    timestamp: u64,
 ) -> BytesN<32> {
-    let mut data = Bytes::new(env);
-    data.append(&complainant.to_xdr(env));
-    data.append(&Bytes::from_array(env, &certification_id.to_array()));
-    data.append(&Bytes::from_array(env, &timestamp.to_be_bytes()));
-    data.append(&env.current_contract_address().to_xdr(env));
+    let mut fields = Bytes::new(env);
+    fields.append(&complainant.to_xdr(env));
+    fields.append(&Bytes::from_array(env, &certification_id.to_array()));
 
-    env.crypto().sha256(&data).into()
+    generate_id(env, &fields)
 }
 
 // Helper function to verify mediator authorization
@@ -70,8 +69,17 @@ pub fn file_dispute(
         .ok_or(AgricQualityError::NotFound)?;
 
     // Generate dispute ID
-    let dispute_id =
-        generate_dispute_id(env, complainant, certification_id, env.ledger().timestamp());
+    let dispute_id = generate_dispute_id(env, complainant, certification_id);
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Dispute(dispute_id.clone()))
+    {
+        return Err(AgricQualityError::DuplicateId);
+    }
+
+    // Escrow the filing bond unless the complainant is exempt
+    let bond = collect_bond(env, complainant)?;
 
     // Create dispute data with default/empty values for non-Option fields
     let dispute = DisputeData {
@@ -92,6 +100,7 @@ pub fn file_dispute(
     env.storage()
         .persistent()
         .set(&DataKey::Dispute(dispute_id.clone()), &dispute);
+    store_bond(env, &dispute_id, &bond);
 
     // Update disputes by holder
     let mut holder_disputes: Vec<BytesN<32>> = env
@@ -103,9 +112,10 @@ pub fn file_dispute(
         .unwrap_or_else(|| vec![env]);
     holder_disputes.push_back(dispute_id.clone());
     env.storage().persistent().set(
-        &DataKey::DisputesByHolder(certification.holder),
+        &DataKey::DisputesByHolder(certification.holder.clone()),
         &holder_disputes,
     );
+    adjust_open_disputes(env, &certification.holder, true);
 
     // Update disputes by standard
     let mut standard_disputes: Vec<BytesN<32>> = env
@@ -152,18 +162,21 @@ pub fn submit_evidence(
     }
 
     // Generate evidence hash
-    let mut data = Bytes::new(env);
-    data.append(&handler.to_xdr(env));
-    data.append(&Bytes::from_array(env, &dispute_id.to_array()));
-    data.append(&Bytes::from_array(
-        env,
-        &env.ledger().timestamp().to_be_bytes(),
-    ));
-    let evidence_hash = env.crypto().sha256(&data);
+    let mut fields = Bytes::new(env);
+    fields.append(&handler.to_xdr(env));
+    fields.append(&Bytes::from_array(env, &dispute_id.to_array()));
+    let evidence_hash = generate_id(env, &fields);
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Evidence(evidence_hash.clone()))
+    {
+        return Err(AgricQualityError::DuplicateId);
+    }
 
     // Create evidence record
     let evidence = Evidence {
-        hash: evidence_hash.clone().into(),
+        hash: evidence_hash.clone(),
         handler: handler.clone(),
         timestamp: env.ledger().timestamp(),
         description,
@@ -174,10 +187,10 @@ pub fn submit_evidence(
     // Store evidence
     env.storage()
         .persistent()
-        .set(&DataKey::Evidence(evidence_hash.clone().into()), &evidence);
+        .set(&DataKey::Evidence(evidence_hash.clone()), &evidence);
 
     // Update dispute evidence list
-    dispute.evidence.push_back(evidence_hash.clone().into());
+    dispute.evidence.push_back(evidence_hash.clone());
     env.storage()
         .persistent()
         .set(&DataKey::Dispute(dispute_id.clone()), &dispute);
@@ -185,14 +198,10 @@ pub fn submit_evidence(
     // Emit event
     env.events().publish(
         (Symbol::new(env, "evidence_submitted"),),
-        (
-            handler,
-            dispute_id.clone(),
-            BytesN::from(evidence_hash.clone()),
-        ),
+        (handler, dispute_id.clone(), evidence_hash.clone()),
     );
 
-    Ok(BytesN::from(evidence_hash))
+    Ok(evidence_hash)
 }
 
 pub fn assign_mediator(
@@ -219,7 +228,7 @@ pub fn assign_mediator(
     // Get dispute data
     let mut dispute: DisputeData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -235,7 +244,7 @@ pub fn assign_mediator(
 
     // Store updated dispute
     env.storage()
-        .instance()
+        .persistent()
         .set(&DataKey::Dispute(dispute_id.clone()), &dispute);
 
     // Emit event
@@ -256,3 +265,23 @@ pub fn get_dispute_details(
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)
 }
+
+/// Number of disputes against `holder` that are not yet resolved or closed
+pub fn count_open_disputes(env: &Env, holder: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenDisputes(holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Tracks a dispute against `holder` being opened (or reopened) or resolved
+pub fn adjust_open_disputes(env: &Env, holder: &Address, opened: bool) {
+    let key = DataKey::OpenDisputes(holder.clone());
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    let count = if opened {
+        count + 1
+    } else {
+        count.saturating_sub(1)
+    };
+    env.storage().persistent().set(&key, &count);
+}
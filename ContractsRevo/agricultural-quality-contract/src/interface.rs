@@ -97,6 +97,13 @@ pub trait VerificationOps {
         env: Env,
         holder: Address,
     ) -> Result<Vec<CertificationData>, AgricQualityError>;
+
+    /// Get a single certification
+    /// * `certification_id` - ID of the certification
+    fn get_certification(
+        env: Env,
+        certification_id: BytesN<32>,
+    ) -> Result<CertificationData, AgricQualityError>;
 }
 
 /// Handles dispute filing and management
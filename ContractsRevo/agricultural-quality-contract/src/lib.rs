@@ -1,12 +1,14 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
+mod bond;
 mod datatypes;
 mod dispute_handling;
 mod interface;
 mod quality_metrics;
 mod resolution;
 mod test;
+mod utils;
 mod verification;
 
 #[cfg(test)]
@@ -92,6 +94,68 @@ impl AgricQualityContract {
 
         Ok(inspector)
     }
+
+    pub fn add_mediator(
+        env: Env,
+        admin: Address,
+        mediator: Address,
+    ) -> Result<Address, AdminError> {
+        admin.require_auth();
+
+        let mut mediators: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Mediators)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        mediators.push_back(mediator.clone());
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Mediators, &mediators);
+
+        Ok(mediator)
+    }
+
+    /// Require a bond of `amount` in `token` to file a dispute (0 disables it).
+    /// Forfeited bonds go to `treasury`, or to the respondent when unset.
+    pub fn set_dispute_bond(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        treasury: Option<Address>,
+    ) -> Result<(), AgricQualityError> {
+        bond::set_dispute_bond(&env, &admin, token, amount, treasury)
+    }
+
+    pub fn get_dispute_bond(env: Env) -> Option<BondConfig> {
+        bond::get_dispute_bond(&env)
+    }
+
+    /// Exempt a filer (e.g. a low-income farmer) from the dispute bond
+    pub fn set_bond_waiver(
+        env: Env,
+        admin: Address,
+        filer: Address,
+        waived: bool,
+    ) -> Result<(), AgricQualityError> {
+        bond::set_bond_waiver(&env, &admin, &filer, waived)
+    }
+
+    pub fn is_bond_waived(env: Env, filer: Address) -> bool {
+        bond::is_bond_waived(&env, &filer)
+    }
+
+    /// Bond posted with a dispute and whether it is held, refunded or forfeited
+    pub fn get_filing_bond(env: Env, dispute_id: BytesN<32>) -> DisputeBond {
+        bond::get_filing_bond(&env, &dispute_id)
+    }
+
+    /// Number of unresolved disputes filed against a certification holder
+    pub fn count_open_disputes(env: Env, holder: Address) -> u32 {
+        dispute_handling::count_open_disputes(&env, &holder)
+    }
 }
 
 #[contractimpl]
@@ -185,6 +249,13 @@ impl VerificationOps for AgricQualityContract {
     ) -> Result<Vec<CertificationData>, AgricQualityError> {
         verification::get_certification_history(&env, &holder)
     }
+
+    fn get_certification(
+        env: Env,
+        certification_id: BytesN<32>,
+    ) -> Result<CertificationData, AgricQualityError> {
+        verification::get_certification(&env, &certification_id)
+    }
 }
 
 #[contractimpl]
@@ -1,4 +1,6 @@
+use crate::bond::{get_filing_bond, settle_bond};
 use crate::datatypes::*;
+use crate::dispute_handling::adjust_open_disputes;
 use soroban_sdk::{vec, Address, BytesN, Env, String, Symbol, Vec};
 
 // Helper function to verify mediator authorization
@@ -57,7 +59,7 @@ pub fn resolve_dispute(
     // Get dispute data
     let mut dispute: DisputeData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -74,7 +76,7 @@ pub fn resolve_dispute(
     // Get certification data
     let mut certification: CertificationData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Certification(dispute.certification.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -106,14 +108,18 @@ pub fn resolve_dispute(
     dispute.status = DisputeStatus::Resolved;
     dispute.resolution = outcome;
 
+    // Only a dismissal marks the filing as frivolous; every other outcome refunds the bond
+    settle_bond(env, &dispute, outcome == ResolutionOutcome::Dismissed);
+
     // Store updated data
-    env.storage().instance().set(
+    env.storage().persistent().set(
         &DataKey::Certification(dispute.certification.clone()),
         &certification,
     );
     env.storage()
-        .instance()
+        .persistent()
         .set(&DataKey::Dispute(dispute_id.clone()), &dispute);
+    adjust_open_disputes(env, &dispute.respondent, false);
 
     // Emit event
     env.events().publish(
@@ -134,7 +140,7 @@ pub fn process_appeal(
     // Get dispute data
     let mut dispute: DisputeData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -156,6 +162,15 @@ pub fn process_appeal(
         return Err(AgricQualityError::InvalidStatus);
     }
 
+    // The bond was paid out on the resolution being appealed and cannot follow a new
+    // outcome, so bonded disputes are final once resolved
+    if matches!(
+        get_filing_bond(env, dispute_id).status,
+        BondStatus::Refunded | BondStatus::Forfeited
+    ) {
+        return Err(AgricQualityError::NotEligible);
+    }
+
     // Update dispute status and evidence
     dispute.status = DisputeStatus::Appealed;
     for evidence in new_evidence.iter() {
@@ -164,8 +179,9 @@ pub fn process_appeal(
 
     // Store updated dispute
     env.storage()
-        .instance()
+        .persistent()
         .set(&DataKey::Dispute(dispute_id.clone()), &dispute);
+    adjust_open_disputes(env, &dispute.respondent, true);
 
     // Emit event
     env.events().publish(
@@ -183,7 +199,7 @@ pub fn calculate_compensation(
     // Get dispute data
     let dispute: DisputeData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -195,7 +211,7 @@ pub fn calculate_compensation(
     // Get certification data
     let certification: CertificationData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Certification(dispute.certification.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -227,7 +243,7 @@ pub fn track_enforcement(
     // Get dispute data
     let dispute: DisputeData = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
         .ok_or(AgricQualityError::NotFound)?;
 
@@ -1,13 +1,16 @@
 #![cfg(test)]
 
+use crate::utils::generate_id;
 use crate::CertificationStatus;
 use crate::DisputeStatus;
 use crate::QualityStandard;
 use crate::ResolutionOutcome;
-use crate::{AgricQualityContract, AgricQualityContractClient};
+use crate::{AgricQualityContract, AgricQualityContractClient, AgricQualityError, DataKey};
 use soroban_sdk::{
     testutils::{Address as _, Events as _},
-    vec, Address, Bytes, BytesN, Env, String, Symbol, TryFromVal,
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Symbol, TryFromVal, Vec,
 };
 
 fn setup_test<'a>() -> (
@@ -373,3 +376,106 @@ fn test_file_dispute_bad() {
     let invalid_cert_id = BytesN::from_array(&env, &[0; 32]);
     client.file_dispute(&farmer1, &invalid_cert_id, &description, &evidence);
 }
+
+// Issue and certify a batch so disputes can be filed against it
+fn certified_batch(
+    env: &Env,
+    client: &AgricQualityContractClient,
+    admin: &Address,
+    holder: &Address,
+    inspector: &Address,
+) -> BytesN<32> {
+    client.add_authority(admin, inspector);
+    client.add_inspector(admin, inspector);
+
+    let conditions = vec![env, String::from_str(env, "organic_soil_used")];
+    let cert_id = client.submit_for_certification(holder, &QualityStandard::Organic, &conditions);
+    let metrics = vec![env, (Symbol::new(env, "score_a"), 90)];
+    let findings = vec![env, String::from_str(env, "Soil sample good")];
+    let recommendations = vec![env, String::from_str(env, "Continue monitoring")];
+    client.record_inspection(inspector, &cert_id, &metrics, &findings, &recommendations);
+    client.process_certification(inspector, &cert_id, &true, &1000);
+    cert_id
+}
+
+// Test generated IDs stay unique for identical inputs within one ledger
+#[test]
+fn test_generated_ids_unique() {
+    let (env, _, client, admin, farmer1, inspector, _) = setup_test();
+    let cert_id = certified_batch(&env, &client, &admin, &farmer1, &inspector);
+
+    let description = String::from_str(&env, "Residue found");
+    let evidence: Vec<BytesN<32>> = vec![
+        &env,
+        env.crypto()
+            .sha256(&Bytes::from_array(&env, &[1; 32]))
+            .into(),
+    ];
+
+    let mut ids: Vec<BytesN<32>> = vec![&env, cert_id.clone()];
+    for _ in 0..20 {
+        let dispute_id = client.file_dispute(&farmer1, &cert_id, &description, &evidence);
+        assert!(!ids.contains(&dispute_id));
+        ids.push_back(dispute_id.clone());
+
+        let evidence_id = client.submit_evidence(
+            &farmer1,
+            &dispute_id,
+            &description,
+            &Symbol::new(&env, "lab"),
+            &vec![&env],
+        );
+        assert!(!ids.contains(&evidence_id));
+        ids.push_back(evidence_id);
+    }
+    assert_eq!(ids.len(), 41);
+}
+
+// Test a second pending request for the same standard is rejected
+#[test]
+fn test_duplicate_pending_submission_rejected() {
+    let (env, _, client, _, farmer1, _, _) = setup_test();
+    let conditions = vec![&env, String::from_str(&env, "batch")];
+
+    client.submit_for_certification(&farmer1, &QualityStandard::Organic, &conditions);
+    let result =
+        client.try_submit_for_certification(&farmer1, &QualityStandard::Organic, &conditions);
+    assert_eq!(result, Err(Ok(AgricQualityError::DuplicateSubmission)));
+
+    // A different standard gets its own ID
+    client.submit_for_certification(&farmer1, &QualityStandard::GlobalGAP, &conditions);
+    assert_eq!(client.get_certification_history(&farmer1).len(), 2);
+}
+
+// Test an ID that already has a record is rejected instead of overwritten
+#[test]
+fn test_colliding_dispute_id_rejected() {
+    let (env, contract_id, client, admin, farmer1, inspector, _) = setup_test();
+    let cert_id = certified_batch(&env, &client, &admin, &farmer1, &inspector);
+
+    let description = String::from_str(&env, "Residue found");
+    let evidence: Vec<BytesN<32>> = vec![
+        &env,
+        env.crypto()
+            .sha256(&Bytes::from_array(&env, &[1; 32]))
+            .into(),
+    ];
+    let first = client.file_dispute(&farmer1, &cert_id, &description, &evidence);
+    let stored = client.get_dispute_details(&first);
+
+    // Occupy the ID the next dispute will derive
+    env.as_contract(&contract_id, || {
+        let counter: u64 = env.storage().instance().get(&DataKey::IdCounter).unwrap();
+        let mut fields = Bytes::new(&env);
+        fields.append(&farmer1.clone().to_xdr(&env));
+        fields.append(&Bytes::from_array(&env, &cert_id.to_array()));
+        let next = generate_id(&env, &fields);
+        env.storage().instance().set(&DataKey::IdCounter, &counter);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Dispute(next), &stored);
+    });
+
+    let result = client.try_file_dispute(&farmer1, &cert_id, &description, &evidence);
+    assert_eq!(result, Err(Ok(AgricQualityError::DuplicateId)));
+}
@@ -85,6 +85,13 @@ mod test {
             "Expiry date should match"
         );
 
+        // The certification can also be read by ID, as other contracts do
+        assert_eq!(agric_client.get_certification(&cert_id), agric_cert);
+        assert_eq!(
+            agric_client.try_get_certification(&BytesN::from_array(&env, &[9u8; 32])),
+            Err(Ok(crate::AgricQualityError::NotFound))
+        );
+
         // Create test data
         let verification_hash = create_document_hash(&env, "Organic certification document");
         let cert_type = symbol_short!("ORGANIC");
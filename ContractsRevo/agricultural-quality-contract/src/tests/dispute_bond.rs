@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod test {
+    use crate::tests::utils::setup_test;
+    use crate::{
+        AgricQualityContractClient, AgricQualityError, BondStatus, CertificationStatus,
+        QualityStandard, ResolutionOutcome,
+    };
+    use soroban_sdk::{
+        testutils::Address as _, token, vec, Address, Bytes, BytesN, Env, String, Symbol,
+    };
+
+    const BOND: i128 = 500;
+
+    struct BondSetup<'a> {
+        env: Env,
+        client: AgricQualityContractClient<'a>,
+        admin: Address,
+        holder: Address,
+        authority: Address,
+        mediator: Address,
+        token: token::Client<'a>,
+        token_admin: token::StellarAssetClient<'a>,
+        cert_id: BytesN<32>,
+    }
+
+    /// Certifies a batch for `holder` and requires a `BOND` filing bond
+    fn setup_bond(treasury: Option<Address>) -> BondSetup<'static> {
+        let (env, _contract_id, client, admin, holder, inspector, authority) = setup_test();
+        let mediator = Address::generate(&env);
+        client.add_authority(&admin, &inspector);
+        client.add_authority(&admin, &authority);
+        client.add_inspector(&admin, &inspector);
+        client.add_mediator(&admin, &mediator);
+
+        let conditions = vec![&env, String::from_str(&env, "organic_soil_used")];
+        let cert_id =
+            client.submit_for_certification(&holder, &QualityStandard::Organic, &conditions);
+        let metrics = vec![&env, (Symbol::new(&env, "score_a"), 90)];
+        let findings = vec![&env, String::from_str(&env, "Soil sample good")];
+        let recommendations = vec![&env, String::from_str(&env, "Continue monitoring")];
+        client.record_inspection(&inspector, &cert_id, &metrics, &findings, &recommendations);
+        client.process_certification(&inspector, &cert_id, &true, &1000);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        client.set_dispute_bond(&admin, &token_address, &BOND, &treasury);
+
+        BondSetup {
+            token: token::Client::new(&env, &token_address),
+            token_admin: token::StellarAssetClient::new(&env, &token_address),
+            env,
+            client,
+            admin,
+            holder,
+            authority,
+            mediator,
+            cert_id,
+        }
+    }
+
+    fn file(setup: &BondSetup, complainant: &Address) -> BytesN<32> {
+        let evidence_hash = setup
+            .env
+            .crypto()
+            .sha256(&Bytes::from_array(&setup.env, &[1; 32]));
+        setup.client.file_dispute(
+            complainant,
+            &setup.cert_id,
+            &String::from_str(&setup.env, "Pesticide residue found"),
+            &vec![&setup.env, evidence_hash.into()],
+        )
+    }
+
+    fn resolve(setup: &BondSetup, dispute_id: &BytesN<32>, outcome: ResolutionOutcome) {
+        setup
+            .client
+            .assign_mediator(&setup.authority, dispute_id, &setup.mediator);
+        setup.client.resolve_dispute(
+            &setup.mediator,
+            dispute_id,
+            &outcome,
+            &String::from_str(&setup.env, "Reviewed"),
+        );
+    }
+
+    #[test]
+    fn test_bond_refunded_when_dispute_upheld() {
+        let setup = setup_bond(None);
+        let buyer = Address::generate(&setup.env);
+        setup.token_admin.mint(&buyer, &BOND);
+
+        let dispute_id = file(&setup, &buyer);
+        let bond = setup.client.get_filing_bond(&dispute_id);
+        assert_eq!(bond.status, BondStatus::Held);
+        assert_eq!(bond.amount, BOND);
+        assert_eq!(bond.token, Some(setup.token.address.clone()));
+        assert_eq!(setup.token.balance(&buyer), 0);
+        assert_eq!(setup.token.balance(&setup.client.address), BOND);
+
+        // The complaint succeeded, so the certification is revoked and the bond returned
+        resolve(&setup, &dispute_id, ResolutionOutcome::Revoked);
+        assert_eq!(
+            setup.client.get_filing_bond(&dispute_id).status,
+            BondStatus::Refunded
+        );
+        assert_eq!(setup.token.balance(&buyer), BOND);
+        assert_eq!(setup.token.balance(&setup.client.address), 0);
+    }
+
+    #[test]
+    fn test_bond_forfeited_when_dismissed() {
+        let setup = setup_bond(None);
+        let buyer = Address::generate(&setup.env);
+        setup.token_admin.mint(&buyer, &BOND);
+
+        let dispute_id = file(&setup, &buyer);
+        resolve(&setup, &dispute_id, ResolutionOutcome::Dismissed);
+
+        // Without a treasury the respondent is compensated
+        assert_eq!(
+            setup.client.get_filing_bond(&dispute_id).status,
+            BondStatus::Forfeited
+        );
+        assert_eq!(setup.token.balance(&setup.holder), BOND);
+        assert_eq!(setup.token.balance(&buyer), 0);
+
+        // With a treasury configured it receives the forfeited bond instead
+        let treasury = Address::generate(&setup.env);
+        setup.client.set_dispute_bond(
+            &setup.admin,
+            &setup.token.address,
+            &BOND,
+            &Some(treasury.clone()),
+        );
+        setup.token_admin.mint(&buyer, &BOND);
+        let dispute_id = file(&setup, &buyer);
+        resolve(&setup, &dispute_id, ResolutionOutcome::Dismissed);
+        assert_eq!(setup.token.balance(&treasury), BOND);
+        assert_eq!(setup.token.balance(&setup.holder), BOND);
+    }
+
+    #[test]
+    fn test_open_disputes_counted_until_resolved() {
+        let setup = setup_bond(None);
+        let buyer = Address::generate(&setup.env);
+        setup.token_admin.mint(&buyer, &(2 * BOND));
+        assert_eq!(setup.client.count_open_disputes(&setup.holder), 0);
+
+        let first = file(&setup, &buyer);
+        file(&setup, &buyer);
+        assert_eq!(setup.client.count_open_disputes(&setup.holder), 2);
+
+        resolve(&setup, &first, ResolutionOutcome::Dismissed);
+        assert_eq!(setup.client.count_open_disputes(&setup.holder), 1);
+        assert_eq!(setup.client.count_open_disputes(&buyer), 0);
+    }
+
+    #[test]
+    fn test_paid_out_bond_blocks_appeal() {
+        let setup = setup_bond(None);
+        let buyer = Address::generate(&setup.env);
+        setup.token_admin.mint(&buyer, &BOND);
+
+        let dispute_id = file(&setup, &buyer);
+        resolve(&setup, &dispute_id, ResolutionOutcome::Dismissed);
+
+        // Neither party can reopen a dispute whose bond has already moved
+        for appellant in [&buyer, &setup.holder] {
+            let result = setup.client.try_process_appeal(
+                appellant,
+                &dispute_id,
+                &vec![&setup.env],
+                &String::from_str(&setup.env, "New lab results"),
+            );
+            assert_eq!(result, Err(Ok(AgricQualityError::NotEligible)));
+        }
+        assert_eq!(
+            setup.client.get_filing_bond(&dispute_id).status,
+            BondStatus::Forfeited
+        );
+
+        // Disputes filed without a bond can still be appealed
+        setup
+            .client
+            .set_dispute_bond(&setup.admin, &setup.token.address, &0, &None);
+        let unbonded = file(&setup, &setup.holder);
+        resolve(&setup, &unbonded, ResolutionOutcome::Upheld);
+        assert_eq!(
+            setup.client.get_filing_bond(&unbonded).status,
+            BondStatus::NotRequired
+        );
+        assert_eq!(setup.client.count_open_disputes(&setup.holder), 0);
+        setup.client.process_appeal(
+            &setup.holder,
+            &unbonded,
+            &vec![&setup.env],
+            &String::from_str(&setup.env, "New lab results"),
+        );
+
+        // An appeal reopens the dispute
+        assert_eq!(setup.client.count_open_disputes(&setup.holder), 1);
+    }
+
+    #[test]
+    fn test_waived_filer_pays_no_bond() {
+        let setup = setup_bond(None);
+        let farmer = Address::generate(&setup.env);
+
+        let outsider = Address::generate(&setup.env);
+        assert_eq!(
+            setup.client.try_set_bond_waiver(&outsider, &farmer, &true),
+            Err(Ok(AgricQualityError::Unauthorized))
+        );
+        setup.client.set_bond_waiver(&setup.admin, &farmer, &true);
+        assert!(setup.client.is_bond_waived(&farmer));
+
+        let dispute_id = file(&setup, &farmer);
+        let bond = setup.client.get_filing_bond(&dispute_id);
+        assert_eq!(bond.status, BondStatus::Waived);
+        assert_eq!(bond.amount, 0);
+
+        // Dismissal of a waived filing has nothing to forfeit
+        resolve(&setup, &dispute_id, ResolutionOutcome::Dismissed);
+        assert_eq!(
+            setup.client.get_filing_bond(&dispute_id).status,
+            BondStatus::Waived
+        );
+        assert_eq!(setup.token.balance(&setup.holder), 0);
+
+        setup.client.set_bond_waiver(&setup.admin, &farmer, &false);
+        assert!(!setup.client.is_bond_waived(&farmer));
+    }
+
+    #[test]
+    fn test_failed_bond_transfer_blocks_filing() {
+        let setup = setup_bond(None);
+        let buyer = Address::generate(&setup.env);
+        setup.token_admin.mint(&buyer, &(BOND - 1));
+
+        let evidence_hash = setup
+            .env
+            .crypto()
+            .sha256(&Bytes::from_array(&setup.env, &[1; 32]));
+        let result = setup.client.try_file_dispute(
+            &buyer,
+            &setup.cert_id,
+            &String::from_str(&setup.env, "Pesticide residue found"),
+            &vec![&setup.env, evidence_hash.into()],
+        );
+        assert_eq!(result, Err(Ok(AgricQualityError::BondTransferFailed)));
+
+        // No dispute was recorded, so there is nothing a mediator could resolve
+        assert_eq!(setup.token.balance(&buyer), BOND - 1);
+        let history = setup.client.get_certification_history(&setup.holder);
+        assert_eq!(history.get(0).unwrap().status, CertificationStatus::Active);
+        assert_eq!(
+            setup.client.try_resolve_dispute(
+                &setup.mediator,
+                &BytesN::from_array(&setup.env, &[9; 32]),
+                &ResolutionOutcome::Dismissed,
+                &String::from_str(&setup.env, "Reviewed"),
+            ),
+            Err(Ok(AgricQualityError::NotFound))
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod assessment;
 mod certification;
+mod dispute_bond;
 mod utils;
 mod validation;
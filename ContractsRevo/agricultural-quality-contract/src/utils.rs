@@ -0,0 +1,27 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::datatypes::*;
+
+// Derive a collision-resistant ID as sha256(contract address, counter, timestamp, fields).
+// The counter is bumped on every call so identical inputs in one ledger still differ.
+pub fn generate_id(env: &Env, fields: &Bytes) -> BytesN<32> {
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::IdCounter)
+        .unwrap_or(0u64)
+        + 1;
+    env.storage().instance().set(&DataKey::IdCounter, &counter);
+
+    let mut data = Bytes::new(env);
+    data.append(&env.current_contract_address().to_xdr(env));
+    data.append(&Bytes::from_array(env, &counter.to_be_bytes()));
+    data.append(&Bytes::from_array(
+        env,
+        &env.ledger().timestamp().to_be_bytes(),
+    ));
+    data.append(fields);
+
+    env.crypto().sha256(&data).into()
+}
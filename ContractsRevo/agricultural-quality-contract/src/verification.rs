@@ -2,21 +2,19 @@ use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{vec, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 use crate::datatypes::*;
+use crate::utils::generate_id;
 
 // Helper function to generate a unique certification ID
 fn generate_certification_id(
     env: &Env,
     holder: &Address,
     standard: &QualityStandard,
-    timestamp: u64,
 ) -> BytesN<32> {
-    let mut data = Bytes::new(env);
-    data.append(&holder.to_xdr(env));
-    data.append(&Bytes::from_array(env, &[standard.to_u8()]));
-    data.append(&Bytes::from_array(env, &timestamp.to_be_bytes()));
-    data.append(&env.current_contract_address().to_xdr(env));
+    let mut fields = Bytes::new(env);
+    fields.append(&holder.to_xdr(env));
+    fields.append(&Bytes::from_array(env, &[standard.to_u8()]));
 
-    env.crypto().sha256(&data).into()
+    generate_id(env, &fields)
 }
 
 // Helper function to verify inspector authorization
@@ -59,22 +57,40 @@ pub fn submit_for_certification(
     // Require holder authorization
     holder.require_auth();
 
-    // Generate certification ID
-    let certification_id =
-        generate_certification_id(env, holder, &standard, env.ledger().timestamp());
-
     let meta_data_len = conditions.len();
     if meta_data_len < 1 || meta_data_len > 8 {
         return Err(AgricQualityError::InvalidInput);
     }
 
+    // Only one pending request per holder and standard
+    let existing: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::HolderCertifications(holder.clone()))
+        .unwrap_or_else(|| vec![env]);
+    for id in existing.iter() {
+        let pending = env
+            .storage()
+            .persistent()
+            .get::<_, CertificationData>(&DataKey::Certification(id))
+            .is_some_and(|cert| {
+                cert.standard == standard && cert.status == CertificationStatus::Pending
+            });
+        if pending {
+            return Err(AgricQualityError::DuplicateSubmission);
+        }
+    }
+
+    // Generate certification ID
+    let certification_id = generate_certification_id(env, holder, &standard);
+
     // Check if certification already exists
     if env
         .storage()
         .persistent()
         .has(&DataKey::Certification(certification_id.clone()))
     {
-        return Err(AgricQualityError::AlreadyExists);
+        return Err(AgricQualityError::DuplicateId);
     }
 
     // Create certification data
@@ -260,3 +276,13 @@ pub fn get_certification_history(
 
     Ok(certifications)
 }
+
+pub fn get_certification(
+    env: &Env,
+    certification_id: &BytesN<32>,
+) -> Result<CertificationData, AgricQualityError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Certification(certification_id.clone()))
+        .ok_or(AgricQualityError::NotFound)
+}
@@ -0,0 +1,92 @@
+use crate::error::ContractError;
+use crate::storage::{self, Attestation, TokenReward};
+use crate::FarmerTokenContractClient;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+/// Configures the farmer token mint made when a certified farmer's field application is attested.
+pub fn set_token_reward(
+    env: &Env,
+    admin: Address,
+    farmer_token_contract: Address,
+    milestone: Symbol,
+    amount: i128,
+) -> Result<(), ContractError> {
+    if admin != storage::get_admin(env) {
+        return Err(ContractError::Unauthorized);
+    }
+    if amount <= 0 {
+        return Err(ContractError::InvalidData);
+    }
+
+    storage::set_token_reward(
+        env,
+        &TokenReward {
+            farmer_token_contract,
+            milestone,
+            amount,
+        },
+    );
+    Ok(())
+}
+
+/// Records that a certified farmer has applied the training in the field and, if a token
+/// reward is configured, mints it the first time for this program and farmer.
+pub fn attest_field_application(
+    env: &Env,
+    instructor: Address,
+    program_id: BytesN<32>,
+    farmer_id: Address,
+    evidence_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    let program = storage::get_program(env, &program_id)?;
+
+    // Verify that the caller is the instructor for this program.
+    if program.instructor_id != instructor {
+        return Err(ContractError::NotInstructor);
+    }
+
+    let status = program
+        .participants
+        .get(farmer_id.clone())
+        .ok_or(ContractError::ParticipantNotFound)?;
+
+    // Field application can only be attested once the certificate has been issued.
+    if status.certificate_id == BytesN::from_array(env, &[0; 32]) {
+        return Err(ContractError::NotCertified);
+    }
+
+    let mut attestations = storage::get_attestations(env, &program_id, &farmer_id);
+    if attestations
+        .iter()
+        .any(|a| a.evidence_hash == evidence_hash)
+    {
+        return Err(ContractError::AlreadyAttested);
+    }
+
+    // --- Mint Farmer Tokens via Cross-Contract Call ---
+    // The reward is paid at most once per program and farmer. This contract mints as
+    // itself, so it must be registered as a minter of the farmer token contract.
+    let mut rewarded = false;
+    if let Some(reward) = storage::get_token_reward(env) {
+        if !storage::is_reward_paid(env, &program_id, &farmer_id) {
+            storage::set_reward_paid(env, &program_id, &farmer_id);
+            FarmerTokenContractClient::new(env, &reward.farmer_token_contract).mint_for_milestone(
+                &env.current_contract_address(),
+                &farmer_id,
+                &reward.milestone,
+                &reward.amount,
+            );
+            rewarded = true;
+        }
+    }
+
+    attestations.push_back(Attestation {
+        instructor,
+        evidence_hash,
+        attested_at: env.ledger().timestamp(),
+        rewarded,
+    });
+    storage::set_attestations(env, &program_id, &farmer_id, &attestations);
+
+    Ok(())
+}
@@ -57,7 +57,13 @@ pub fn issue_certificate(
 
     // The `transaction_amount` is set to 1 to represent the completion of one training program.
     // The loyalty contract's `points_per_transaction` will determine the actual points awarded.
-    loyalty_token_client.award_points(&loyalty_program_id, &farmer_id, &1);
+    // This contract awards as itself, so it must be registered as an awarder of the program.
+    loyalty_token_client.award_points(
+        &loyalty_program_id,
+        &env.current_contract_address(),
+        &farmer_id,
+        &1,
+    );
 
     // --- Update Participant Status ---
     status.certificate_id = certificate_id.clone();
@@ -20,4 +20,6 @@ pub enum ContractError {
     AlreadyEnrolled = 7,
     NotCompleted = 8,
     AlreadyCertified = 9,
+    NotCertified = 10,
+    AlreadyAttested = 11,
 }
@@ -1,5 +1,6 @@
 #![no_std]
 
+mod attestation;
 mod certification;
 mod error;
 mod participation;
@@ -9,9 +10,11 @@ mod training;
 mod utils;
 
 pub use error::ContractError;
-pub use storage::{ParticipantStatus, TrainingProgram};
+pub use storage::{Attestation, ParticipantStatus, TokenReward, TrainingProgram};
 
-use soroban_sdk::{contract, contractclient, contractimpl, Address, BytesN, Env, String, Symbol};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, Address, BytesN, Env, String, Symbol, Vec,
+};
 
 // Manually define the interface for the external certificate management contract.
 #[contractclient(name = "CertificateManagementContractClient")]
@@ -32,11 +35,24 @@ pub trait LoyaltyTokenContract {
     fn award_points(
         env: Env,
         program_id: BytesN<32>,
+        awarder: Address,
         user_address: Address,
         transaction_amount: u32,
     );
 }
 
+// Manually define the interface for the external farmer token contract.
+#[contractclient(name = "FarmerTokenContractClient")]
+pub trait FarmerTokenContract {
+    fn mint_for_milestone(
+        env: Env,
+        minter: Address,
+        farmer: Address,
+        milestone_type: Symbol,
+        amount: i128,
+    );
+}
+
 #[contract]
 pub struct AgriculturalTrainingContract;
 
@@ -114,6 +130,30 @@ impl AgriculturalTrainingContract {
         certification::issue_certificate(&env, instructor, program_id, farmer_id)
     }
 
+    /// Configures the farmer token reward minted on a farmer's first field attestation (admin only).
+    pub fn set_token_reward(
+        env: Env,
+        admin: Address,
+        farmer_token_contract: Address,
+        milestone_symbol: Symbol,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        attestation::set_token_reward(&env, admin, farmer_token_contract, milestone_symbol, amount)
+    }
+
+    /// Attests that a certified farmer has applied the training in the field.
+    pub fn attest_field_application(
+        env: Env,
+        instructor: Address,
+        program_id: BytesN<32>,
+        farmer: Address,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        instructor.require_auth();
+        attestation::attest_field_application(&env, instructor, program_id, farmer, evidence_hash)
+    }
+
     // --- Read-Only Functions ---
 
     /// Retrieves the details of a specific training program.
@@ -129,4 +169,14 @@ impl AgriculturalTrainingContract {
     ) -> Result<ParticipantStatus, ContractError> {
         storage::get_participant_status(&env, &program_id, &farmer_id)
     }
+
+    /// Retrieves the field attestations recorded for a farmer in a program.
+    pub fn get_attestations(env: Env, program_id: BytesN<32>, farmer: Address) -> Vec<Attestation> {
+        storage::get_attestations(&env, &program_id, &farmer)
+    }
+
+    /// Retrieves the configured farmer token reward, if any.
+    pub fn get_token_reward(env: Env) -> Option<TokenReward> {
+        storage::get_token_reward(&env)
+    }
 }
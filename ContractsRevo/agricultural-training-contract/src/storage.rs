@@ -1,5 +1,5 @@
 use crate::error::ContractError;
-use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 // --- Data Structures ---
 
@@ -23,6 +23,25 @@ pub struct TrainingProgram {
     pub participants: Map<Address, ParticipantStatus>,
 }
 
+/// A field attestation that a certified farmer has applied the training in practice.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub instructor: Address,
+    pub evidence_hash: BytesN<32>,
+    pub attested_at: u64,
+    pub rewarded: bool,
+}
+
+/// Farmer token mint made for a farmer's first field attestation in a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenReward {
+    pub farmer_token_contract: Address,
+    pub milestone: Symbol,
+    pub amount: i128,
+}
+
 // --- Storage Keys ---
 
 #[contracttype]
@@ -33,6 +52,9 @@ pub enum StorageKey {
     LoyaltyToken,
     LoyaltyProgram,
     Program(BytesN<32>),
+    TokenReward,
+    Attestations(BytesN<32>, Address),
+    RewardPaid(BytesN<32>, Address),
 }
 
 // --- Admin and Token Management ---
@@ -45,6 +67,10 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&StorageKey::Admin, admin);
 }
 
+pub fn get_admin(env: &Env) -> Address {
+    env.storage().instance().get(&StorageKey::Admin).unwrap()
+}
+
 pub fn set_certificate_contract(env: &Env, contract_id: &Address) {
     env.storage()
         .instance()
@@ -84,6 +110,16 @@ pub fn get_loyalty_program(env: &Env) -> BytesN<32> {
         .unwrap()
 }
 
+pub fn set_token_reward(env: &Env, reward: &TokenReward) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::TokenReward, reward);
+}
+
+pub fn get_token_reward(env: &Env) -> Option<TokenReward> {
+    env.storage().instance().get(&StorageKey::TokenReward)
+}
+
 // --- Program Management ---
 
 pub fn get_program(env: &Env, program_id: &BytesN<32>) -> Result<TrainingProgram, ContractError> {
@@ -112,3 +148,45 @@ pub fn get_participant_status(
         .get(farmer_id.clone())
         .ok_or(ContractError::ParticipantNotFound)
 }
+
+// --- Field Attestations ---
+
+pub fn get_attestations(
+    env: &Env,
+    program_id: &BytesN<32>,
+    farmer_id: &Address,
+) -> Vec<Attestation> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Attestations(
+            program_id.clone(),
+            farmer_id.clone(),
+        ))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_attestations(
+    env: &Env,
+    program_id: &BytesN<32>,
+    farmer_id: &Address,
+    attestations: &Vec<Attestation>,
+) {
+    env.storage().persistent().set(
+        &StorageKey::Attestations(program_id.clone(), farmer_id.clone()),
+        attestations,
+    );
+}
+
+pub fn is_reward_paid(env: &Env, program_id: &BytesN<32>, farmer_id: &Address) -> bool {
+    env.storage().persistent().has(&StorageKey::RewardPaid(
+        program_id.clone(),
+        farmer_id.clone(),
+    ))
+}
+
+pub fn set_reward_paid(env: &Env, program_id: &BytesN<32>, farmer_id: &Address) {
+    env.storage().persistent().set(
+        &StorageKey::RewardPaid(program_id.clone(), farmer_id.clone()),
+        &true,
+    );
+}
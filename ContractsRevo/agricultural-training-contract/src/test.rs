@@ -39,6 +39,7 @@ impl LoyaltyTokenContract for MockLoyaltyContract {
     fn award_points(
         env: Env,
         program_id: BytesN<32>,
+        _awarder: Address,
         user_address: Address,
         transaction_amount: u32,
     ) {
@@ -49,6 +50,35 @@ impl LoyaltyTokenContract for MockLoyaltyContract {
     }
 }
 
+// A mock for the Farmer Token Contract that tallies minted amounts per farmer
+#[contract]
+pub struct MockFarmerTokenContract;
+
+#[contractimpl]
+impl FarmerTokenContract for MockFarmerTokenContract {
+    fn mint_for_milestone(
+        env: Env,
+        _minter: Address,
+        farmer: Address,
+        milestone_type: Symbol,
+        amount: i128,
+    ) {
+        let minted: i128 = env.storage().instance().get(&farmer).unwrap_or(0);
+        env.storage().instance().set(&farmer, &(minted + amount));
+        env.events().publish(
+            (Symbol::new(&env, "milestone_minted"), farmer),
+            (milestone_type, amount),
+        );
+    }
+}
+
+#[contractimpl]
+impl MockFarmerTokenContract {
+    pub fn minted(env: Env, farmer: Address) -> i128 {
+        env.storage().instance().get(&farmer).unwrap_or(0)
+    }
+}
+
 // --- Test ---
 
 struct TrainingTest<'a> {
@@ -227,3 +257,145 @@ fn test_issue_certificate() {
         Err(Ok(ContractError::AlreadyCertified))
     );
 }
+
+// Creates a program and completes it for the test farmer, optionally issuing the certificate.
+fn completed_program(test: &TrainingTest, certify: bool) -> BytesN<32> {
+    let program_id = test.contract.create_training_program(
+        &test.instructor,
+        &"T1".into_val(&test.env),
+        &"D1".into_val(&test.env),
+        &10,
+        &BytesN::random(&test.env),
+    );
+    test.contract.enroll_farmer(&test.farmer, &program_id);
+    test.contract
+        .update_progress(&test.instructor, &program_id, &test.farmer, &100);
+    if certify {
+        test.contract
+            .issue_certificate(&test.instructor, &program_id, &test.farmer);
+    }
+    program_id
+}
+
+#[test]
+fn test_attestation_requires_certificate() {
+    let test = TrainingTest::setup();
+    let program_id = completed_program(&test, false);
+
+    let result = test.contract.try_attest_field_application(
+        &test.instructor,
+        &program_id,
+        &test.farmer,
+        &BytesN::random(&test.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::NotCertified)));
+
+    // Only the program's instructor can attest
+    test.contract
+        .issue_certificate(&test.instructor, &program_id, &test.farmer);
+    let result = test.contract.try_attest_field_application(
+        &Address::generate(&test.env),
+        &program_id,
+        &test.farmer,
+        &BytesN::random(&test.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::NotInstructor)));
+    assert!(test
+        .contract
+        .get_attestations(&program_id, &test.farmer)
+        .is_empty());
+}
+
+#[test]
+fn test_attestation_mints_reward_once() {
+    let test = TrainingTest::setup();
+    let token_id = test.env.register(MockFarmerTokenContract, ());
+    let token = MockFarmerTokenContractClient::new(&test.env, &token_id);
+    let milestone = Symbol::new(&test.env, "field_practice");
+    test.contract
+        .set_token_reward(&test.admin, &token_id, &milestone, &250);
+
+    let program_id = completed_program(&test, true);
+    let first_evidence = BytesN::random(&test.env);
+    test.contract.attest_field_application(
+        &test.instructor,
+        &program_id,
+        &test.farmer,
+        &first_evidence,
+    );
+    assert_eq!(token.minted(&test.farmer), 250);
+
+    // A later harvest attestation is recorded but not rewarded again
+    test.contract.attest_field_application(
+        &test.instructor,
+        &program_id,
+        &test.farmer,
+        &BytesN::random(&test.env),
+    );
+    assert_eq!(token.minted(&test.farmer), 250);
+
+    let attestations = test.contract.get_attestations(&program_id, &test.farmer);
+    assert_eq!(attestations.len(), 2);
+    assert_eq!(attestations.get(0).unwrap().evidence_hash, first_evidence);
+    assert!(attestations.get(0).unwrap().rewarded);
+    assert!(!attestations.get(1).unwrap().rewarded);
+
+    // The same evidence cannot be attested twice
+    let result = test.contract.try_attest_field_application(
+        &test.instructor,
+        &program_id,
+        &test.farmer,
+        &first_evidence,
+    );
+    assert_eq!(result, Err(Ok(ContractError::AlreadyAttested)));
+}
+
+#[test]
+fn test_attestation_without_reward_hook() {
+    let test = TrainingTest::setup();
+    assert_eq!(test.contract.get_token_reward(), None);
+
+    let program_id = completed_program(&test, true);
+    test.contract.attest_field_application(
+        &test.instructor,
+        &program_id,
+        &test.farmer,
+        &BytesN::random(&test.env),
+    );
+
+    let attestations = test.contract.get_attestations(&program_id, &test.farmer);
+    assert_eq!(attestations.len(), 1);
+    assert_eq!(attestations.get(0).unwrap().instructor, test.instructor);
+    assert!(!attestations.get(0).unwrap().rewarded);
+}
+
+#[test]
+fn test_set_token_reward_validation() {
+    let test = TrainingTest::setup();
+    let token_id = Address::generate(&test.env);
+    let milestone = Symbol::new(&test.env, "field_practice");
+
+    let result = test.contract.try_set_token_reward(
+        &Address::generate(&test.env),
+        &token_id,
+        &milestone,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = test
+        .contract
+        .try_set_token_reward(&test.admin, &token_id, &milestone, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidData)));
+
+    test.contract
+        .set_token_reward(&test.admin, &token_id, &milestone, &100);
+    assert_eq!(
+        test.contract.get_token_reward(),
+        Some(TokenReward {
+            farmer_token_contract: token_id,
+            milestone,
+            amount: 100,
+        })
+    );
+}
@@ -1,7 +1,12 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::{AuditError, CertStatus, Certification, DataKey, UsersCertificates};
+use crate::{
+    certification::MAX_CERT_PAGE_SIZE, AuditError, AuditPage, CertStatus, Certification, DataKey,
+    GlobalAuditPage, IssuerSummary, UserCertCount, UsersCertificates,
+};
 
+/// Reports on the owner's first `MAX_CERT_PAGE_SIZE` certifications; use
+/// `generate_audit_report_cursor` to read further.
 pub fn generate_cert_audit_report(
     env: Env,
     owner: Address,
@@ -9,7 +14,86 @@ pub fn generate_cert_audit_report(
     status_filter: Option<CertStatus>,
     after_timestamp: Option<u64>,
 ) -> Result<Vec<Certification>, AuditError> {
+    let (report, _) = generate_audit_report_cursor(
+        env,
+        owner,
+        issuer,
+        status_filter,
+        after_timestamp,
+        None,
+        MAX_CERT_PAGE_SIZE,
+    )?;
+    Ok(report)
+}
+
+/// Pages through an owner's certifications in issuance order. Each call examines at most
+/// `limit` ids, clamped to `MAX_CERT_PAGE_SIZE`, so a page may hold fewer matches than that.
+/// `cursor` is the id to resume from; the returned cursor is `None` once every certification
+/// has been examined.
+pub fn generate_audit_report_cursor(
+    env: Env,
+    owner: Address,
+    issuer: Option<Address>,
+    status_filter: Option<CertStatus>,
+    after_timestamp: Option<u64>,
+    cursor: Option<u32>,
+    limit: u32,
+) -> Result<AuditPage, AuditError> {
+    let user_certificates = env
+        .storage()
+        .instance()
+        .get::<_, UsersCertificates>(&DataKey::UsersCertificates)
+        .and_then(|certs| certs.get(owner.clone()))
+        .ok_or(AuditError::NotFound)?;
+
+    let last_id = env
+        .storage()
+        .instance()
+        .get::<_, UserCertCount>(&DataKey::UserCertCount)
+        .and_then(|counts| counts.get(owner))
+        .unwrap_or(0);
+
+    let current_time = env.ledger().timestamp();
     let mut report = Vec::new(&env);
+    let mut id = cursor.unwrap_or(1).max(1);
+    let end = id.saturating_add(limit.min(MAX_CERT_PAGE_SIZE));
+
+    while id <= last_id && id < end {
+        if let Some(mut cert) = user_certificates.get(id) {
+            cert.status = cert.effective_status(current_time);
+            if matches_filters(&cert, &issuer, &status_filter, after_timestamp) {
+                report.push_back(cert);
+            }
+        }
+        id += 1;
+    }
+
+    let next_cursor = if id <= last_id { Some(id) } else { None };
+
+    Ok((report, next_cursor))
+}
+
+/// Same as the per-owner cursor report, but across every owner in global issuance order.
+/// Restricted to the admin. `limit` bounds the index entries examined in the same way.
+pub fn generate_global_audit_report(
+    env: Env,
+    admin: Address,
+    issuer: Option<Address>,
+    status_filter: Option<CertStatus>,
+    after_timestamp: Option<u64>,
+    cursor: Option<u32>,
+    limit: u32,
+) -> Result<GlobalAuditPage, AuditError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(AuditError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(AuditError::Unauthorized);
+    }
 
     let users_certificates: UsersCertificates = env
         .storage()
@@ -17,31 +101,110 @@ pub fn generate_cert_audit_report(
         .get(&DataKey::UsersCertificates)
         .ok_or(AuditError::NotFound)?;
 
-    let user_certificates = users_certificates
-        .get(owner.clone())
-        .ok_or(AuditError::NotFound)?;
+    let total: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::GlobalIssuanceCount)
+        .unwrap_or(0);
+
+    let current_time = env.ledger().timestamp();
+    let mut report = Vec::new(&env);
+    let mut index = cursor.unwrap_or(1).max(1);
+    let end = index.saturating_add(limit.min(MAX_CERT_PAGE_SIZE));
 
-    for (_, cert) in user_certificates.iter() {
-        if let Some(ref issuer) = issuer {
-            if cert.issuer != *issuer {
-                continue;
+    while index <= total && index < end {
+        let entry: Option<(Address, u32)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::GlobalIssuance(index));
+
+        if let Some((owner, id)) = entry {
+            let cert = users_certificates
+                .get(owner.clone())
+                .and_then(|user_certificates| user_certificates.get(id));
+
+            if let Some(mut cert) = cert {
+                cert.status = cert.effective_status(current_time);
+                if matches_filters(&cert, &issuer, &status_filter, after_timestamp) {
+                    report.push_back((owner, cert));
+                }
             }
         }
+        index += 1;
+    }
 
-        if let Some(ref status) = status_filter {
-            if cert.status != *status {
-                continue;
-            }
+    let next_cursor = if index <= total { Some(index) } else { None };
+
+    Ok((report, next_cursor))
+}
+
+pub fn get_issuer_summary(env: Env, issuer: Address) -> IssuerSummary {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IssuerSummary(issuer))
+        .unwrap_or_default()
+}
+
+/// Adds a new certification to the global issuance index and the issuer's summary.
+pub(crate) fn record_issued(env: &Env, issuer: &Address, owner: &Address, id: u32) {
+    let index: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::GlobalIssuanceCount)
+        .unwrap_or(0)
+        + 1;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::GlobalIssuance(index), &(owner.clone(), id));
+    env.storage()
+        .persistent()
+        .set(&DataKey::GlobalIssuanceCount, &index);
+
+    update_summary(env, issuer, |summary| {
+        summary.issued += 1;
+        summary.last_issued_at = env.ledger().timestamp();
+    });
+}
+
+pub(crate) fn record_revoked(env: &Env, issuer: &Address) {
+    update_summary(env, issuer, |summary| summary.revoked += 1);
+}
+
+pub(crate) fn record_expired(env: &Env, issuer: &Address) {
+    update_summary(env, issuer, |summary| summary.expired += 1);
+}
+
+fn update_summary(env: &Env, issuer: &Address, update: impl FnOnce(&mut IssuerSummary)) {
+    let key = DataKey::IssuerSummary(issuer.clone());
+    let mut summary: IssuerSummary = env.storage().persistent().get(&key).unwrap_or_default();
+    update(&mut summary);
+    env.storage().persistent().set(&key, &summary);
+}
+
+fn matches_filters(
+    cert: &Certification,
+    issuer: &Option<Address>,
+    status_filter: &Option<CertStatus>,
+    after_timestamp: Option<u64>,
+) -> bool {
+    if let Some(ref issuer) = issuer {
+        if cert.issuer != *issuer {
+            return false;
         }
+    }
 
-        if let Some(min_time) = after_timestamp {
-            if cert.issued_date < min_time {
-                continue;
-            }
+    if let Some(ref status) = status_filter {
+        if cert.status != *status {
+            return false;
         }
+    }
 
-        report.push_back(cert);
+    if let Some(min_time) = after_timestamp {
+        if cert.issued_date < min_time {
+            return false;
+        }
     }
 
-    Ok(report)
+    true
 }
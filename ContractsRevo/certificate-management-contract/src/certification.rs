@@ -1,6 +1,12 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
-use crate::{CertStatus, CertificationError, DataKey, UsersCertificates};
+use crate::{
+    audit::record_expired, CertStatus, CertificationError, CertificationPage, DataKey,
+    UsersCertificates,
+};
+
+/// Largest page any listing endpoint returns; larger limits are clamped to it.
+pub const MAX_CERT_PAGE_SIZE: u32 = 100;
 
 pub fn check_cert_status(
     env: Env,
@@ -21,7 +27,7 @@ pub fn check_cert_status(
         .get(id)
         .ok_or(CertificationError::NotFound)?;
 
-    Ok(certification.status)
+    Ok(certification.effective_status(env.ledger().timestamp()))
 }
 
 pub fn expire(env: Env, owner: Address, id: u32) -> Result<(), CertificationError> {
@@ -40,6 +46,7 @@ pub fn expire(env: Env, owner: Address, id: u32) -> Result<(), CertificationErro
         .ok_or(CertificationError::NotFound)?;
 
     certification.expire(env.ledger().timestamp())?;
+    record_expired(&env, &certification.issuer);
 
     user_certificates.set(id, certification);
     certs.set(owner.clone(), user_certificates);
@@ -77,3 +84,162 @@ pub fn get_cert(
 
     Ok(certification)
 }
+
+/// Lists certifications with `limit` clamped to `MAX_CERT_PAGE_SIZE`.
+pub fn list_certs(
+    env: Env,
+    owner: Address,
+    status_filter: Option<CertStatus>,
+    offset: u32,
+    limit: u32,
+) -> Vec<crate::Certification> {
+    list_certs_paged(env, owner, status_filter, offset, limit).certifications
+}
+
+pub fn list_certs_paged(
+    env: Env,
+    owner: Address,
+    status_filter: Option<CertStatus>,
+    offset: u32,
+    limit: u32,
+) -> CertificationPage {
+    let mut matching_certs = Vec::new(&env);
+
+    let user_certificates = env
+        .storage()
+        .instance()
+        .get::<_, UsersCertificates>(&DataKey::UsersCertificates)
+        .and_then(|certs| certs.get(owner));
+
+    let Some(user_certificates) = user_certificates else {
+        return paginate(&env, matching_certs, offset, limit);
+    };
+
+    let current_time = env.ledger().timestamp();
+
+    // Certifications are keyed by id, so iteration is already in issuance order
+    let matching = user_certificates
+        .values()
+        .into_iter()
+        .map(|mut cert| {
+            cert.status = cert.effective_status(current_time);
+            cert
+        })
+        .filter(|cert| {
+            status_filter
+                .as_ref()
+                .is_none_or(|status| cert.status == *status)
+        });
+
+    for cert in matching {
+        matching_certs.push_back(cert);
+    }
+
+    paginate(&env, matching_certs, offset, limit)
+}
+
+/// Persists the expired status for up to `max` certifications past their expiration date.
+pub fn sweep_expired(env: Env, owner: Address, max: u32) -> Result<u32, CertificationError> {
+    let mut certs: UsersCertificates = env
+        .storage()
+        .instance()
+        .get(&DataKey::UsersCertificates)
+        .ok_or(CertificationError::NotFound)?;
+
+    let mut user_certificates = certs
+        .get(owner.clone())
+        .ok_or(CertificationError::NotFound)?;
+
+    let current_time = env.ledger().timestamp();
+    let mut swept = 0;
+
+    for (id, mut certification) in user_certificates.clone().iter() {
+        if swept >= max {
+            break;
+        }
+
+        if certification.status == CertStatus::Valid && certification.expire(current_time).is_ok() {
+            record_expired(&env, &certification.issuer);
+            user_certificates.set(id, certification);
+            swept += 1;
+
+            env.events().publish(
+                (Symbol::new(&env, "certification_expired"), owner.clone()),
+                id,
+            );
+        }
+    }
+
+    if swept > 0 {
+        certs.set(owner, user_certificates);
+        env.storage()
+            .instance()
+            .set(&DataKey::UsersCertificates, &certs);
+    }
+
+    Ok(swept)
+}
+
+/// Returns the first `MAX_CERT_PAGE_SIZE` certifications expiring within `within_secs`.
+pub fn get_expiring_soon(env: Env, owner: Address, within_secs: u64) -> Vec<crate::Certification> {
+    get_expiring_soon_paged(env, owner, within_secs, 0, MAX_CERT_PAGE_SIZE).certifications
+}
+
+pub fn get_expiring_soon_paged(
+    env: Env,
+    owner: Address,
+    within_secs: u64,
+    offset: u32,
+    limit: u32,
+) -> CertificationPage {
+    let mut expiring = Vec::new(&env);
+
+    let user_certificates = env
+        .storage()
+        .instance()
+        .get::<_, UsersCertificates>(&DataKey::UsersCertificates)
+        .and_then(|certs| certs.get(owner));
+
+    let Some(user_certificates) = user_certificates else {
+        return paginate(&env, expiring, offset, limit);
+    };
+
+    let current_time = env.ledger().timestamp();
+    let horizon = current_time.saturating_add(within_secs);
+
+    for cert in user_certificates.values().iter() {
+        if cert.effective_status(current_time) == CertStatus::Valid
+            && cert.expiration_date <= horizon
+        {
+            expiring.push_back(cert);
+        }
+    }
+
+    paginate(&env, expiring, offset, limit)
+}
+
+/// Slices `certs` into the page starting at `offset`, clamping `limit` to `MAX_CERT_PAGE_SIZE`.
+fn paginate(
+    env: &Env,
+    certs: Vec<crate::Certification>,
+    offset: u32,
+    limit: u32,
+) -> CertificationPage {
+    let total = certs.len();
+    let start = offset.min(total);
+    let end = start
+        .saturating_add(limit.min(MAX_CERT_PAGE_SIZE))
+        .min(total);
+
+    let certifications = if start < end {
+        certs.slice(start..end)
+    } else {
+        Vec::new(env)
+    };
+
+    CertificationPage {
+        certifications,
+        total,
+        truncated: end < total,
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, Map, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 use crate::{CertificationError, RevokeError, VerifyError};
 
@@ -8,11 +8,24 @@ pub enum DataKey {
     Admin,
     UserCertCount,
     UsersCertificates,
+    Revocation(Address, u32), // Owner, certification id -> RevocationDetails
+    RevocationListeners,      // Contracts notified when a certification is revoked
+    PendingNotifications,     // Keys of notifications that failed and can be retried
+    PendingNotification(Address, Address, u32), // Listener, owner, id -> notification
+    Delegation(Address, Address), // Parent issuer, delegate -> Delegation
+    Delegates(Address),       // Parent issuer -> delegate addresses
+    CertMetadata(Address, u32), // Owner, certification id -> CertMetadata
+    AttributeHistory(Address, u32), // Owner, certification id -> AttributeChange log
+    GlobalIssuanceCount,      // Number of certifications issued across all owners
+    GlobalIssuance(u32),      // Global issuance index -> (owner, certification id)
+    IssuerSummary(Address),   // Issuer -> IssuerSummary
 }
 
 pub type UsersCertificates = Map<Address, UserCertificates>; // User -> UserCertificates
 pub type UserCertificates = Map<u32, Certification>; // Certification Id -> Certification
 pub type UserCertCount = Map<Address, u32>; // User -> Number of certifications
+pub type AuditPage = (Vec<Certification>, Option<u32>); // Report page, next cursor
+pub type GlobalAuditPage = (Vec<(Address, Certification)>, Option<u32>); // (Owner, cert) page, next cursor
 
 #[derive(Clone)]
 #[contracttype]
@@ -24,6 +37,88 @@ pub struct Certification {
     pub expiration_date: u64,
     pub verification_hash: BytesN<32>, // Hash of certification documents
     pub status: CertStatus,
+    pub delegate: Option<Address>, // Sub-issuer that issued on behalf of `issuer`
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct CertMetadata {
+    pub attributes: Map<Symbol, String>, // Farm size, crop, standard version, etc.
+    pub document_uri: Option<String>,    // Where the full certification document lives
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AttributeChange {
+    pub key: Symbol,
+    pub value: String,
+    pub previous: Option<String>,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Delegation {
+    pub parent: Address,
+    pub delegate: Address,
+    pub cert_types: Vec<Symbol>,
+    pub expiry: u64,
+    pub remaining_issuances: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct IssuerOfRecord {
+    pub issuer: Address,
+    pub delegate: Option<Address>,
+}
+
+/// Everything a dependent contract needs to validate a certification in a single read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct VerificationBundle {
+    pub status: CertStatus,
+    pub verification_hash: BytesN<32>,
+    pub cert_type: Symbol,
+    pub expiration_date: u64,
+    pub issuer: Address,
+}
+
+/// One page of a holder's certifications, in issuance order.
+#[derive(Clone)]
+#[contracttype]
+pub struct CertificationPage {
+    pub certifications: Vec<Certification>,
+    pub total: u32,      // Matching certifications across all pages
+    pub truncated: bool, // More matching certifications exist past this page
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[contracttype]
+pub struct IssuerSummary {
+    pub issued: u32,
+    pub revoked: u32,
+    pub expired: u32, // Expirations persisted through expire_certification or sweep_expired
+    pub last_issued_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct RevocationDetails {
+    pub reason: Symbol,
+    pub evidence_hash: Option<BytesN<32>>,
+    pub revoked_by: Address,
+    pub revoked_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PendingNotification {
+    pub listener: Address,
+    pub owner: Address,
+    pub id: u32,
+    pub attempts: u32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -51,6 +146,7 @@ impl Certification {
             expiration_date,
             verification_hash,
             status: CertStatus::Valid,
+            delegate: None,
         }
     }
 
@@ -77,6 +173,16 @@ impl Certification {
         Ok(())
     }
 
+    /// Status as of `current_time`: a valid certification past its expiration date reads as
+    /// expired even before anyone persists the change.
+    pub fn effective_status(&self, current_time: u64) -> CertStatus {
+        if self.status == CertStatus::Valid && self.is_expiration_due(current_time) {
+            CertStatus::Expired
+        } else {
+            self.status.clone()
+        }
+    }
+
     pub fn is_expired(&self) -> bool {
         match self.status {
             CertStatus::Expired => true,
@@ -0,0 +1,155 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+use crate::{issue::store_certification, DataKey, Delegation, DelegationError};
+
+pub fn delegate_issuance(
+    env: Env,
+    parent_issuer: Address,
+    delegate: Address,
+    cert_types: Vec<Symbol>,
+    expiry: u64,
+    max_issuances: u32,
+) -> Result<(), DelegationError> {
+    parent_issuer.require_auth();
+
+    if delegate == parent_issuer
+        || cert_types.is_empty()
+        || max_issuances == 0
+        || expiry <= env.ledger().timestamp()
+    {
+        return Err(DelegationError::InvalidDelegation);
+    }
+
+    let delegation = Delegation {
+        parent: parent_issuer.clone(),
+        delegate: delegate.clone(),
+        cert_types,
+        expiry,
+        remaining_issuances: max_issuances,
+    };
+
+    // Re-delegating replaces the previous scope and limits
+    env.storage().persistent().set(
+        &DataKey::Delegation(parent_issuer.clone(), delegate.clone()),
+        &delegation,
+    );
+
+    let delegates_key = DataKey::Delegates(parent_issuer.clone());
+    let mut delegates: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&delegates_key)
+        .unwrap_or_else(|| Vec::new(&env));
+    if !delegates.contains(&delegate) {
+        delegates.push_back(delegate.clone());
+        env.storage().persistent().set(&delegates_key, &delegates);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, "issuance_delegated"), parent_issuer),
+        delegation,
+    );
+
+    Ok(())
+}
+
+pub fn revoke_delegation(
+    env: Env,
+    parent_issuer: Address,
+    delegate: Address,
+) -> Result<(), DelegationError> {
+    parent_issuer.require_auth();
+
+    let delegation_key = DataKey::Delegation(parent_issuer.clone(), delegate.clone());
+    if !env.storage().persistent().has(&delegation_key) {
+        return Err(DelegationError::NotFound);
+    }
+    env.storage().persistent().remove(&delegation_key);
+
+    let delegates_key = DataKey::Delegates(parent_issuer.clone());
+    let mut delegates: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&delegates_key)
+        .unwrap_or_else(|| Vec::new(&env));
+    if let Some(index) = delegates.first_index_of(&delegate) {
+        delegates.remove(index);
+        env.storage().persistent().set(&delegates_key, &delegates);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, "delegation_revoked"), parent_issuer),
+        delegate,
+    );
+
+    Ok(())
+}
+
+pub fn issue_delegated(
+    env: Env,
+    delegate: Address,
+    parent_issuer: Address,
+    recipient: Address,
+    cert_type: Symbol,
+    expiration_date: u64,
+    verification_hash: BytesN<32>,
+) -> Result<u32, DelegationError> {
+    delegate.require_auth();
+
+    let delegation_key = DataKey::Delegation(parent_issuer.clone(), delegate.clone());
+    let mut delegation: Delegation = env
+        .storage()
+        .persistent()
+        .get(&delegation_key)
+        .ok_or(DelegationError::NotFound)?;
+
+    let current_time = env.ledger().timestamp();
+    if current_time >= delegation.expiry {
+        return Err(DelegationError::Expired);
+    }
+    if delegation.remaining_issuances == 0 {
+        return Err(DelegationError::Exhausted);
+    }
+    if !delegation.cert_types.contains(&cert_type) {
+        return Err(DelegationError::CertTypeNotAllowed);
+    }
+    if current_time >= expiration_date {
+        return Err(DelegationError::InvalidExpirationDate);
+    }
+
+    delegation.remaining_issuances -= 1;
+    env.storage().persistent().set(&delegation_key, &delegation);
+
+    // The parent stays the issuer of record so it keeps authority over the certification
+    Ok(store_certification(
+        &env,
+        parent_issuer,
+        Some(delegate),
+        recipient,
+        cert_type,
+        expiration_date,
+        verification_hash,
+    ))
+}
+
+pub fn get_delegations(env: Env, parent_issuer: Address) -> Vec<Delegation> {
+    let mut delegations = Vec::new(&env);
+
+    let delegates: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Delegates(parent_issuer.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    for delegate in delegates.iter() {
+        if let Some(delegation) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Delegation(parent_issuer.clone(), delegate))
+        {
+            delegations.push_back(delegation);
+        }
+    }
+
+    delegations
+}
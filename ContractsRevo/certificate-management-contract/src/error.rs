@@ -11,12 +11,15 @@ pub enum AdminError {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IssueError {
     InvalidExpirationDate = 1,
+    EmptyBatch = 2,
+    BatchTooLarge = 3,
 }
 
 #[contracterror]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AuditError {
     NotFound = 1,
+    Unauthorized = 2,
 }
 
 #[contracterror]
@@ -25,6 +28,9 @@ pub enum RevokeError {
     NotFound = 1,
     AlreadyRevoked = 2,
     Unauthorized = 3,
+    ListenerAlreadyRegistered = 4,
+    TooManyListeners = 5,
+    NotificationNotFound = 6,
 }
 
 #[contracterror]
@@ -44,3 +50,25 @@ pub enum CertificationError {
     AlreadyExpired = 2,
     NotExpired = 3,
 }
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DelegationError {
+    NotFound = 1,
+    InvalidDelegation = 2,
+    Expired = 3,
+    Exhausted = 4,
+    CertTypeNotAllowed = 5,
+    InvalidExpirationDate = 6,
+}
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MetadataError {
+    NotFound = 1,
+    Unauthorized = 2,
+    EmptyAttributes = 3,
+    TooManyAttributes = 4,
+    ValueTooLong = 5,
+    UriTooLong = 6,
+}
@@ -1,6 +1,11 @@
-use soroban_sdk::{Address, Env, Map, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Vec};
 
-use crate::{Certification, DataKey, IssueError, UserCertCount, UsersCertificates};
+use crate::{
+    audit::record_issued, Certification, DataKey, IssueError, UserCertCount, UsersCertificates,
+};
+
+// Maximum number of certifications issued in a single batch
+pub const MAX_BATCH_SIZE: u32 = 50;
 
 pub fn issue_certification(
     env: Env,
@@ -8,41 +13,103 @@ pub fn issue_certification(
     recipient: Address,
     cert_type: Symbol,
     expiration_date: u64,
-    verification_hash: soroban_sdk::BytesN<32>,
+    verification_hash: BytesN<32>,
 ) -> Result<(), IssueError> {
     issuer.require_auth();
 
+    if env.ledger().timestamp() >= expiration_date {
+        return Err(IssueError::InvalidExpirationDate);
+    }
+
+    store_certification(
+        &env,
+        issuer,
+        None,
+        recipient,
+        cert_type,
+        expiration_date,
+        verification_hash,
+    );
+
+    Ok(())
+}
+
+pub fn issue_batch(
+    env: Env,
+    issuer: Address,
+    recipients: Vec<(Address, Symbol, u64, BytesN<32>)>,
+) -> Result<Vec<u32>, IssueError> {
+    issuer.require_auth();
+
+    if recipients.is_empty() {
+        return Err(IssueError::EmptyBatch);
+    }
+    if recipients.len() > MAX_BATCH_SIZE {
+        return Err(IssueError::BatchTooLarge);
+    }
+
+    // Validate every entry before writing anything so a bad entry aborts the whole batch
+    let issued_date = env.ledger().timestamp();
+    for (_, _, expiration_date, _) in recipients.iter() {
+        if issued_date >= expiration_date {
+            return Err(IssueError::InvalidExpirationDate);
+        }
+    }
+
+    let mut ids = Vec::new(&env);
+    for (recipient, cert_type, expiration_date, verification_hash) in recipients.iter() {
+        ids.push_back(store_certification(
+            &env,
+            issuer.clone(),
+            None,
+            recipient,
+            cert_type,
+            expiration_date,
+            verification_hash,
+        ));
+    }
+
+    Ok(ids)
+}
+
+pub(crate) fn store_certification(
+    env: &Env,
+    issuer: Address,
+    delegate: Option<Address>,
+    recipient: Address,
+    cert_type: Symbol,
+    expiration_date: u64,
+    verification_hash: BytesN<32>,
+) -> u32 {
     let mut user_cert_count: UserCertCount = env
         .storage()
         .instance()
         .get(&DataKey::UserCertCount)
-        .unwrap_or_else(|| Map::new(&env));
+        .unwrap_or_else(|| Map::new(env));
 
     let id = user_cert_count.get(recipient.clone()).unwrap_or(0) + 1;
-    let issued_date = env.ledger().timestamp();
 
-    if issued_date >= expiration_date {
-        return Err(IssueError::InvalidExpirationDate);
-    }
+    record_issued(env, &issuer, &recipient, id);
 
-    let certification = Certification::new(
+    let mut certification = Certification::new(
         id,
         cert_type,
         issuer,
-        issued_date,
+        env.ledger().timestamp(),
         expiration_date,
         verification_hash,
     );
+    certification.delegate = delegate;
 
     let mut users_certificates: UsersCertificates = env
         .storage()
         .instance()
         .get(&DataKey::UsersCertificates)
-        .unwrap_or_else(|| Map::new(&env));
+        .unwrap_or_else(|| Map::new(env));
 
     let mut user_certificates = users_certificates
         .get(recipient.clone())
-        .unwrap_or_else(|| Map::new(&env));
+        .unwrap_or_else(|| Map::new(env));
 
     user_certificates.set(id, certification);
     users_certificates.set(recipient.clone(), user_certificates);
@@ -56,9 +123,9 @@ pub fn issue_certification(
         .set(&DataKey::UsersCertificates, &users_certificates);
 
     env.events().publish(
-        (Symbol::new(&env, "certification_issued"), recipient.clone()),
+        (Symbol::new(env, "certification_issued"), recipient),
         env.ledger().timestamp(),
     );
 
-    Ok(())
+    id
 }
@@ -1,19 +1,24 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 mod audit;
 mod certification;
 mod datatypes;
+mod delegation;
 mod error;
 mod initialize;
 mod issue;
+mod metadata;
 mod revoke;
 #[cfg(test)]
 mod tests;
 mod verify;
 
 pub use datatypes::*;
-pub use error::{AdminError, AuditError, CertificationError, IssueError, RevokeError, VerifyError};
+pub use error::{
+    AdminError, AuditError, CertificationError, DelegationError, IssueError, MetadataError,
+    RevokeError, VerifyError,
+};
 
 #[contract]
 pub struct CertificateManagementContract;
@@ -42,13 +47,96 @@ impl CertificateManagementContract {
         )
     }
 
+    pub fn issue_batch(
+        env: Env,
+        issuer: Address,
+        recipients: Vec<(Address, Symbol, u64, BytesN<32>)>,
+    ) -> Result<Vec<u32>, IssueError> {
+        issue::issue_batch(env, issuer, recipients)
+    }
+
+    pub fn delegate_issuance(
+        env: Env,
+        parent_issuer: Address,
+        delegate: Address,
+        cert_types: Vec<Symbol>,
+        expiry: u64,
+        max_issuances: u32,
+    ) -> Result<(), DelegationError> {
+        delegation::delegate_issuance(
+            env,
+            parent_issuer,
+            delegate,
+            cert_types,
+            expiry,
+            max_issuances,
+        )
+    }
+
+    pub fn revoke_delegation(
+        env: Env,
+        parent_issuer: Address,
+        delegate: Address,
+    ) -> Result<(), DelegationError> {
+        delegation::revoke_delegation(env, parent_issuer, delegate)
+    }
+
+    /// Issues a certification under `parent_issuer`'s authority; the parent is recorded as the
+    /// issuer and the delegate alongside it.
+    pub fn issue_delegated(
+        env: Env,
+        delegate: Address,
+        parent_issuer: Address,
+        recipient: Address,
+        cert_type: Symbol,
+        expiration_date: u64,
+        verification_hash: BytesN<32>,
+    ) -> Result<u32, DelegationError> {
+        delegation::issue_delegated(
+            env,
+            delegate,
+            parent_issuer,
+            recipient,
+            cert_type,
+            expiration_date,
+            verification_hash,
+        )
+    }
+
     pub fn revoke_certification(
         env: Env,
         issuer: Address,
         owner: Address,
         id: u32,
+        reason: Symbol,
+        evidence_hash: Option<BytesN<32>>,
+    ) -> Result<(), RevokeError> {
+        revoke::revoke_certification(env, issuer, owner, id, reason, evidence_hash)
+    }
+
+    pub fn register_revocation_listener(
+        env: Env,
+        admin: Address,
+        listener: Address,
+    ) -> Result<(), RevokeError> {
+        revoke::register_revocation_listener(env, admin, listener)
+    }
+
+    pub fn remove_revocation_listener(
+        env: Env,
+        admin: Address,
+        listener: Address,
     ) -> Result<(), RevokeError> {
-        revoke::revoke_certification(env, issuer, owner, id)
+        revoke::remove_revocation_listener(env, admin, listener)
+    }
+
+    pub fn retry_notification(
+        env: Env,
+        listener: Address,
+        owner: Address,
+        id: u32,
+    ) -> Result<bool, RevokeError> {
+        revoke::retry_notification(env, listener, owner, id)
     }
 
     pub fn expire_certification(
@@ -59,6 +147,30 @@ impl CertificateManagementContract {
         certification::expire(env, owner, id)
     }
 
+    pub fn sweep_expired(env: Env, owner: Address, max: u32) -> Result<u32, CertificationError> {
+        certification::sweep_expired(env, owner, max)
+    }
+
+    pub fn set_cert_attributes(
+        env: Env,
+        issuer: Address,
+        owner: Address,
+        id: u32,
+        attributes: Map<Symbol, String>,
+    ) -> Result<(), MetadataError> {
+        metadata::set_cert_attributes(env, issuer, owner, id, attributes)
+    }
+
+    pub fn set_document_uri(
+        env: Env,
+        issuer: Address,
+        owner: Address,
+        id: u32,
+        uri: String,
+    ) -> Result<(), MetadataError> {
+        metadata::set_document_uri(env, issuer, owner, id, uri)
+    }
+
     pub fn verify_document_hash(
         env: Env,
         owner: Address,
@@ -68,6 +180,16 @@ impl CertificateManagementContract {
         verify::verify_document_hash(env, owner, id, submitted_hash)
     }
 
+    pub fn verify_for_product(
+        env: Env,
+        owner: Address,
+        id: u32,
+        expected_hash: BytesN<32>,
+        min_remaining_validity_secs: u64,
+    ) -> Result<(), VerifyError> {
+        verify::verify_for_product(env, owner, id, expected_hash, min_remaining_validity_secs)
+    }
+
     // GETTERS
     pub fn get_admin(env: Env) -> Result<Address, AdminError> {
         env.storage()
@@ -92,6 +214,100 @@ impl CertificateManagementContract {
         certification::get_cert(env, owner, id)
     }
 
+    pub fn get_verification_bundle(
+        env: Env,
+        owner: Address,
+        id: u32,
+    ) -> Result<VerificationBundle, VerifyError> {
+        verify::get_verification_bundle(env, owner, id)
+    }
+
+    pub fn get_cert_attributes(
+        env: Env,
+        owner: Address,
+        id: u32,
+    ) -> Result<Map<Symbol, String>, MetadataError> {
+        metadata::get_cert_attributes(env, owner, id)
+    }
+
+    pub fn get_cert_metadata(
+        env: Env,
+        owner: Address,
+        id: u32,
+    ) -> Result<CertMetadata, MetadataError> {
+        metadata::get_cert_metadata(env, owner, id)
+    }
+
+    pub fn get_attribute_history(env: Env, owner: Address, id: u32) -> Vec<AttributeChange> {
+        metadata::get_attribute_history(env, owner, id)
+    }
+
+    pub fn get_delegations(env: Env, parent_issuer: Address) -> Vec<Delegation> {
+        delegation::get_delegations(env, parent_issuer)
+    }
+
+    pub fn get_issuer_of_record(
+        env: Env,
+        owner: Address,
+        id: u32,
+    ) -> Result<IssuerOfRecord, CertificationError> {
+        let cert = certification::get_cert(env, owner, id)?;
+        Ok(IssuerOfRecord {
+            issuer: cert.issuer,
+            delegate: cert.delegate,
+        })
+    }
+
+    pub fn get_revocation_details(
+        env: Env,
+        owner: Address,
+        id: u32,
+    ) -> Result<RevocationDetails, RevokeError> {
+        revoke::get_revocation_details(env, owner, id)
+    }
+
+    pub fn get_revocation_listeners(env: Env) -> Vec<Address> {
+        revoke::get_revocation_listeners(env)
+    }
+
+    pub fn get_pending_notifications(env: Env) -> Vec<PendingNotification> {
+        revoke::get_pending_notifications(env)
+    }
+
+    pub fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Certification> {
+        certification::list_certs(env, owner, status_filter, offset, limit)
+    }
+
+    pub fn list_certs_paged(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> CertificationPage {
+        certification::list_certs_paged(env, owner, status_filter, offset, limit)
+    }
+
+    pub fn get_expiring_soon(env: Env, owner: Address, within_secs: u64) -> Vec<Certification> {
+        certification::get_expiring_soon(env, owner, within_secs)
+    }
+
+    pub fn get_expiring_soon_paged(
+        env: Env,
+        owner: Address,
+        within_secs: u64,
+        offset: u32,
+        limit: u32,
+    ) -> CertificationPage {
+        certification::get_expiring_soon_paged(env, owner, within_secs, offset, limit)
+    }
+
     pub fn generate_cert_audit_report(
         env: Env,
         owner: Address,
@@ -101,4 +317,48 @@ impl CertificateManagementContract {
     ) -> Result<Vec<Certification>, AuditError> {
         audit::generate_cert_audit_report(env, owner, issuer, status_filter, after_timestamp)
     }
+
+    pub fn generate_audit_report_cursor(
+        env: Env,
+        owner: Address,
+        issuer: Option<Address>,
+        status_filter: Option<CertStatus>,
+        after_timestamp: Option<u64>,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Result<AuditPage, AuditError> {
+        audit::generate_audit_report_cursor(
+            env,
+            owner,
+            issuer,
+            status_filter,
+            after_timestamp,
+            cursor,
+            limit,
+        )
+    }
+
+    pub fn generate_global_audit_report(
+        env: Env,
+        admin: Address,
+        issuer: Option<Address>,
+        status_filter: Option<CertStatus>,
+        after_timestamp: Option<u64>,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> Result<GlobalAuditPage, AuditError> {
+        audit::generate_global_audit_report(
+            env,
+            admin,
+            issuer,
+            status_filter,
+            after_timestamp,
+            cursor,
+            limit,
+        )
+    }
+
+    pub fn get_issuer_summary(env: Env, issuer: Address) -> IssuerSummary {
+        audit::get_issuer_summary(env, issuer)
+    }
 }
@@ -0,0 +1,150 @@
+use soroban_sdk::{Address, Env, Map, String, Symbol, Vec};
+
+use crate::{
+    AttributeChange, CertMetadata, Certification, DataKey, MetadataError, UsersCertificates,
+};
+
+// Attribute keys are Symbols and therefore already limited to 32 characters
+pub const MAX_ATTRIBUTES: u32 = 20;
+pub const MAX_ATTRIBUTE_VALUE_LEN: u32 = 128;
+pub const MAX_DOCUMENT_URI_LEN: u32 = 256;
+
+pub fn set_cert_attributes(
+    env: Env,
+    issuer: Address,
+    owner: Address,
+    id: u32,
+    attributes: Map<Symbol, String>,
+) -> Result<(), MetadataError> {
+    issuer.require_auth();
+
+    if attributes.is_empty() {
+        return Err(MetadataError::EmptyAttributes);
+    }
+
+    load_for_issuer(&env, &issuer, &owner, id)?;
+    let mut metadata = load_metadata(&env, &owner, id);
+
+    let history_key = DataKey::AttributeHistory(owner.clone(), id);
+    let mut history: Vec<AttributeChange> = env
+        .storage()
+        .persistent()
+        .get(&history_key)
+        .unwrap_or_else(|| Vec::new(&env));
+
+    for (key, value) in attributes.iter() {
+        if value.len() > MAX_ATTRIBUTE_VALUE_LEN {
+            return Err(MetadataError::ValueTooLong);
+        }
+
+        let previous = metadata.attributes.get(key.clone());
+        if previous.is_none() && metadata.attributes.len() >= MAX_ATTRIBUTES {
+            return Err(MetadataError::TooManyAttributes);
+        }
+
+        metadata.attributes.set(key.clone(), value.clone());
+        history.push_back(AttributeChange {
+            key,
+            value,
+            previous,
+            changed_by: issuer.clone(),
+            changed_at: env.ledger().timestamp(),
+        });
+    }
+
+    save_metadata(&env, &owner, id, &metadata);
+    env.storage().persistent().set(&history_key, &history);
+
+    env.events().publish(
+        (Symbol::new(&env, "cert_attributes_set"), owner, id),
+        attributes.keys(),
+    );
+
+    Ok(())
+}
+
+pub fn set_document_uri(
+    env: Env,
+    issuer: Address,
+    owner: Address,
+    id: u32,
+    uri: String,
+) -> Result<(), MetadataError> {
+    issuer.require_auth();
+
+    if uri.len() > MAX_DOCUMENT_URI_LEN {
+        return Err(MetadataError::UriTooLong);
+    }
+
+    load_for_issuer(&env, &issuer, &owner, id)?;
+    let mut metadata = load_metadata(&env, &owner, id);
+    metadata.document_uri = Some(uri.clone());
+    save_metadata(&env, &owner, id, &metadata);
+
+    env.events()
+        .publish((Symbol::new(&env, "cert_document_uri_set"), owner, id), uri);
+
+    Ok(())
+}
+
+pub fn get_cert_attributes(
+    env: Env,
+    owner: Address,
+    id: u32,
+) -> Result<Map<Symbol, String>, MetadataError> {
+    Ok(get_cert_metadata(env, owner, id)?.attributes)
+}
+
+pub fn get_cert_metadata(env: Env, owner: Address, id: u32) -> Result<CertMetadata, MetadataError> {
+    load(&env, &owner, id)?;
+    Ok(load_metadata(&env, &owner, id))
+}
+
+pub fn get_attribute_history(env: Env, owner: Address, id: u32) -> Vec<AttributeChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AttributeHistory(owner, id))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+fn load(env: &Env, owner: &Address, id: u32) -> Result<Certification, MetadataError> {
+    env.storage()
+        .instance()
+        .get::<_, UsersCertificates>(&DataKey::UsersCertificates)
+        .and_then(|certs| certs.get(owner.clone()))
+        .and_then(|user_certificates| user_certificates.get(id))
+        .ok_or(MetadataError::NotFound)
+}
+
+// Only the issuing authority or the delegate that issued the certification may annotate it
+fn load_for_issuer(
+    env: &Env,
+    issuer: &Address,
+    owner: &Address,
+    id: u32,
+) -> Result<Certification, MetadataError> {
+    let certification = load(env, owner, id)?;
+
+    if certification.issuer != *issuer && certification.delegate.as_ref() != Some(issuer) {
+        return Err(MetadataError::Unauthorized);
+    }
+
+    Ok(certification)
+}
+
+// Metadata lives under its own key so annotating a certificate leaves the certificate map untouched
+fn load_metadata(env: &Env, owner: &Address, id: u32) -> CertMetadata {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CertMetadata(owner.clone(), id))
+        .unwrap_or_else(|| CertMetadata {
+            attributes: Map::new(env),
+            document_uri: None,
+        })
+}
+
+fn save_metadata(env: &Env, owner: &Address, id: u32, metadata: &CertMetadata) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CertMetadata(owner.clone(), id), metadata);
+}
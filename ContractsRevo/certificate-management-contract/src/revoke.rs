@@ -1,12 +1,29 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, Vec};
 
-use crate::{DataKey, RevokeError, UsersCertificates};
+use crate::{
+    audit::record_revoked, DataKey, PendingNotification, RevocationDetails, RevokeError,
+    UsersCertificates,
+};
+
+// Upper bound on listeners so a revocation cannot run out of budget notifying them
+pub const MAX_REVOCATION_LISTENERS: u32 = 10;
+
+// Upper bound on failed deliveries kept for retry; later failures are only reported as events
+pub const MAX_PENDING_NOTIFICATIONS: u32 = 50;
+
+// Revocation records outlive the instance, so they are kept alive for roughly a year (5s ledgers)
+const REVOCATION_LIFETIME_THRESHOLD: u32 = 518_400;
+const REVOCATION_BUMP_AMOUNT: u32 = 6_307_200;
+
+type NotificationKey = (Address, Address, u32); // Listener, owner, certification id
 
 pub fn revoke_certification(
     env: Env,
     issuer: Address,
     owner: Address,
     id: u32,
+    reason: Symbol,
+    evidence_hash: Option<BytesN<32>>,
 ) -> Result<(), RevokeError> {
     issuer.require_auth();
 
@@ -27,6 +44,7 @@ pub fn revoke_certification(
     }
 
     certification.revoke()?;
+    record_revoked(&env, &issuer);
 
     user_certificates.set(id, certification);
     users_certificates.set(owner.clone(), user_certificates);
@@ -35,10 +53,222 @@ pub fn revoke_certification(
         .instance()
         .set(&DataKey::UsersCertificates, &users_certificates);
 
+    let details = RevocationDetails {
+        reason,
+        evidence_hash,
+        revoked_by: issuer,
+        revoked_at: env.ledger().timestamp(),
+    };
+    let key = DataKey::Revocation(owner.clone(), id);
+    env.storage().persistent().set(&key, &details);
+    env.storage().persistent().extend_ttl(
+        &key,
+        REVOCATION_LIFETIME_THRESHOLD,
+        REVOCATION_BUMP_AMOUNT,
+    );
+
     env.events().publish(
-        (Symbol::new(&env, "certification_revoked"), owner.clone()),
-        env.ledger().timestamp(),
+        (Symbol::new(&env, "cert_revoked"), owner.clone(), id),
+        details,
+    );
+
+    notify_listeners(&env, &owner, id);
+
+    Ok(())
+}
+
+pub fn get_revocation_details(
+    env: Env,
+    owner: Address,
+    id: u32,
+) -> Result<RevocationDetails, RevokeError> {
+    let key = DataKey::Revocation(owner, id);
+    let details = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(RevokeError::NotFound)?;
+    env.storage().persistent().extend_ttl(
+        &key,
+        REVOCATION_LIFETIME_THRESHOLD,
+        REVOCATION_BUMP_AMOUNT,
     );
+    Ok(details)
+}
+
+pub fn register_revocation_listener(
+    env: Env,
+    admin: Address,
+    listener: Address,
+) -> Result<(), RevokeError> {
+    require_admin(&env, &admin)?;
+
+    let mut listeners = get_listeners(&env);
+    if listeners.contains(&listener) {
+        return Err(RevokeError::ListenerAlreadyRegistered);
+    }
+    if listeners.len() >= MAX_REVOCATION_LISTENERS {
+        return Err(RevokeError::TooManyListeners);
+    }
+
+    listeners.push_back(listener.clone());
+    env.storage()
+        .instance()
+        .set(&DataKey::RevocationListeners, &listeners);
+
+    env.events().publish(
+        (Symbol::new(&env, "revocation_listener_registered"), admin),
+        listener,
+    );
+
+    Ok(())
+}
+
+pub fn remove_revocation_listener(
+    env: Env,
+    admin: Address,
+    listener: Address,
+) -> Result<(), RevokeError> {
+    require_admin(&env, &admin)?;
+
+    let mut listeners = get_listeners(&env);
+    let index = listeners
+        .first_index_of(&listener)
+        .ok_or(RevokeError::NotFound)?;
+
+    listeners.remove(index);
+    env.storage()
+        .instance()
+        .set(&DataKey::RevocationListeners, &listeners);
+
+    Ok(())
+}
+
+pub fn get_revocation_listeners(env: Env) -> Vec<Address> {
+    get_listeners(&env)
+}
+
+pub fn get_pending_notifications(env: Env) -> Vec<PendingNotification> {
+    let mut pending = Vec::new(&env);
+    for (listener, owner, id) in get_pending_keys(&env).iter() {
+        if let Some(notification) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingNotification(listener, owner, id))
+        {
+            pending.push_back(notification);
+        }
+    }
+    pending
+}
+
+/// Re-delivers a failed notification; returns whether the listener accepted it this time.
+pub fn retry_notification(
+    env: Env,
+    listener: Address,
+    owner: Address,
+    id: u32,
+) -> Result<bool, RevokeError> {
+    let key = DataKey::PendingNotification(listener.clone(), owner.clone(), id);
+    let mut notification: PendingNotification = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(RevokeError::NotificationNotFound)?;
+
+    let delivered = deliver(&env, &listener, &owner, id);
+    if delivered {
+        env.storage().persistent().remove(&key);
+
+        let mut keys = get_pending_keys(&env);
+        if let Some(index) = keys.first_index_of(&(listener, owner, id)) {
+            keys.remove(index);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingNotifications, &keys);
+    } else {
+        notification.attempts += 1;
+        env.storage().persistent().set(&key, &notification);
+    }
+
+    Ok(delivered)
+}
+
+fn notify_listeners(env: &Env, owner: &Address, id: u32) {
+    let mut keys = get_pending_keys(env);
+    let mut failed = false;
+
+    // Best effort: a failing listener must not block the revocation itself
+    for listener in get_listeners(env).iter() {
+        if deliver(env, &listener, owner, id) {
+            continue;
+        }
+
+        if keys.len() >= MAX_PENDING_NOTIFICATIONS {
+            env.events().publish(
+                (Symbol::new(env, "notification_dropped"), listener),
+                (owner.clone(), id),
+            );
+            continue;
+        }
+
+        failed = true;
+        keys.push_back((listener.clone(), owner.clone(), id));
+        env.storage().persistent().set(
+            &DataKey::PendingNotification(listener.clone(), owner.clone(), id),
+            &PendingNotification {
+                listener,
+                owner: owner.clone(),
+                id,
+                attempts: 1,
+            },
+        );
+    }
+
+    if failed {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingNotifications, &keys);
+    }
+}
+
+fn deliver(env: &Env, listener: &Address, owner: &Address, id: u32) -> bool {
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        listener,
+        &Symbol::new(env, "on_cert_revoked"),
+        (owner.clone(), id).into_val(env),
+    );
+
+    matches!(result, Ok(Ok(())))
+}
+
+fn get_listeners(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RevocationListeners)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_pending_keys(env: &Env) -> Vec<NotificationKey> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingNotifications)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), RevokeError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(RevokeError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(RevokeError::Unauthorized);
+    }
 
     Ok(())
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
-use crate::{tests::utils::TestContext, AuditError, CertStatus};
+use crate::{certification::MAX_CERT_PAGE_SIZE, tests::utils::TestContext, AuditError, CertStatus};
+use soroban_sdk::{Address, Vec};
 
 #[test]
 fn test_audit_report_all_certifications() {
@@ -55,7 +56,13 @@ fn test_audit_report_by_status() {
 
     // Revoke one certification
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert1);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Filter by status - valid certs
     let valid_certs = client.generate_cert_audit_report(
@@ -122,7 +129,13 @@ fn test_audit_report_combined_filters() {
 
     // Revoke first cert
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert1);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Filter: issuer1, valid certs, after mid_time
     let filtered_certs = client.generate_cert_audit_report(
@@ -209,7 +222,13 @@ fn test_scalability_high_volume_operations() {
 
     // Revoke half of them
     for i in 1..26 {
-        client.revoke_certification(&context.issuer1, &context.recipient1, &i);
+        client.revoke_certification(
+            &context.issuer1,
+            &context.recipient1,
+            &i,
+            &context.symbol("NON_COMPLIANT"),
+            &None,
+        );
     }
 
     // Check revoked count
@@ -294,7 +313,13 @@ fn test_integration_cert_lifecycle() {
     assert_eq!(audit.len(), 1);
 
     // 5. Revoke certification
-    client.revoke_certification(&context.issuer1, &context.recipient1, &1);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // 6. Verify status changed
     let status = client.check_cert_status(&context.recipient1, &1);
@@ -313,3 +338,234 @@ fn test_integration_cert_lifecycle() {
     );
     assert_eq!(revoked_audit.len(), 1);
 }
+
+fn issue_many(context: &TestContext, recipient: &Address, count: u32) {
+    let client = context.client();
+    let expiration = context.env.ledger().timestamp() + 31536000;
+    let mut remaining = count;
+
+    context.env.mock_all_auths();
+    while remaining > 0 {
+        let size = remaining.min(50);
+        let mut batch = Vec::new(&context.env);
+        for _ in 0..size {
+            batch.push_back((
+                recipient.clone(),
+                context.symbol("ORGANIC"),
+                expiration,
+                context.create_document_hash("Batch document"),
+            ));
+        }
+        client.issue_batch(&context.issuer1, &batch);
+        remaining -= size;
+    }
+}
+
+#[test]
+fn test_audit_cursor_continuation() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context, &context.recipient1, 120);
+
+    let mut cursor = None;
+    let mut seen = 0u32;
+    let mut pages = 0;
+    loop {
+        let (page, next) = client.generate_audit_report_cursor(
+            &context.recipient1,
+            &None,
+            &None,
+            &None,
+            &cursor,
+            &50,
+        );
+        for cert in page.iter() {
+            seen += 1;
+            assert_eq!(cert.id, seen);
+        }
+        pages += 1;
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(seen, 120);
+    assert_eq!(pages, 3);
+
+    // Resuming after the last checkpoint only returns what was issued since
+    issue_many(&context, &context.recipient1, 2);
+    let (page, next) = client.generate_audit_report_cursor(
+        &context.recipient1,
+        &None,
+        &None,
+        &None,
+        &Some(121),
+        &50,
+    );
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 121);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_audit_cursor_filter_combinations() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    context.issue_test_cert(&context.issuer2, &context.recipient1, "ORGANIC", 365);
+    context.advance_time(100);
+    let later = context.env.ledger().timestamp();
+    context.issue_test_cert(&context.issuer1, &context.recipient1, "FAIRTRADE", 365);
+    context.issue_test_cert(&context.issuer2, &context.recipient1, "NON_GMO", 365);
+
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &3,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    let (page, next) = client.generate_audit_report_cursor(
+        &context.recipient1,
+        &Some(context.issuer1.clone()),
+        &Some(CertStatus::Valid),
+        &None,
+        &None,
+        &10,
+    );
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(next, None);
+
+    let (page, _) = client.generate_audit_report_cursor(
+        &context.recipient1,
+        &Some(context.issuer2.clone()),
+        &None,
+        &Some(later),
+        &None,
+        &10,
+    );
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 4);
+
+    // A full page stops early and hands back where to continue
+    let (page, next) =
+        client.generate_audit_report_cursor(&context.recipient1, &None, &None, &None, &None, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(next, Some(2));
+}
+
+#[test]
+fn test_audit_cursor_scan_is_bounded() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context, &context.recipient1, 120);
+
+    // Oversized limits are clamped
+    let (page, next) = client.generate_audit_report_cursor(
+        &context.recipient1,
+        &None,
+        &None,
+        &None,
+        &None,
+        &u32::MAX,
+    );
+    assert_eq!(page.len(), MAX_CERT_PAGE_SIZE);
+    assert_eq!(next, Some(MAX_CERT_PAGE_SIZE + 1));
+
+    // The limit counts certifications examined, not matches, so a filter that matches
+    // nothing still returns promptly with a cursor to continue from
+    let (page, next) = client.generate_audit_report_cursor(
+        &context.recipient1,
+        &Some(context.issuer2.clone()),
+        &None,
+        &None,
+        &None,
+        &10,
+    );
+    assert_eq!(page.len(), 0);
+    assert_eq!(next, Some(11));
+
+    let report = client.generate_cert_audit_report(&context.recipient1, &None, &None, &None);
+    assert_eq!(report.len(), MAX_CERT_PAGE_SIZE);
+}
+
+#[test]
+fn test_global_audit_report_across_owners() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    context.issue_test_cert(&context.issuer2, &context.recipient2, "ORGANIC", 365);
+    context.issue_test_cert(&context.issuer1, &context.recipient2, "FAIRTRADE", 365);
+
+    context.env.mock_all_auths();
+    let (page, next) =
+        client.generate_global_audit_report(&context.admin, &None, &None, &None, &None, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().0, context.recipient1);
+    assert_eq!(page.get(1).unwrap().0, context.recipient2);
+
+    let (page, next) =
+        client.generate_global_audit_report(&context.admin, &None, &None, &None, &next, &2);
+    assert_eq!(page.len(), 1);
+    let (owner, cert) = page.get(0).unwrap();
+    assert_eq!(owner, context.recipient2);
+    assert_eq!(cert.cert_type, context.symbol("FAIRTRADE"));
+    assert_eq!(next, None);
+
+    let (page, _) = client.generate_global_audit_report(
+        &context.admin,
+        &Some(context.issuer1.clone()),
+        &None,
+        &None,
+        &None,
+        &10,
+    );
+    assert_eq!(page.len(), 2);
+
+    let result =
+        client.try_generate_global_audit_report(&context.issuer1, &None, &None, &None, &None, &10);
+    assert!(matches!(result, Err(Ok(AuditError::Unauthorized))));
+}
+
+#[test]
+fn test_issuer_summary_accuracy() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    for _ in 0..3 {
+        context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 1);
+    }
+    context.issue_test_cert(&context.issuer1, &context.recipient2, "ORGANIC", 365);
+    context.issue_test_cert(&context.issuer2, &context.recipient2, "ORGANIC", 365);
+    let issued_at = context.env.ledger().timestamp();
+
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient2,
+        &1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    context.advance_time(2 * 86400);
+    client.expire_certification(&context.recipient1, &1);
+    client.sweep_expired(&context.recipient1, &10);
+
+    let summary = client.get_issuer_summary(&context.issuer1);
+    assert_eq!(summary.issued, 4);
+    assert_eq!(summary.revoked, 1);
+    assert_eq!(summary.expired, 3);
+    assert_eq!(summary.last_issued_at, issued_at);
+
+    let summary = client.get_issuer_summary(&context.issuer2);
+    assert_eq!(summary.issued, 1);
+    assert_eq!(summary.revoked, 0);
+
+    assert_eq!(client.get_issuer_summary(&context.admin).issued, 0);
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+use crate::{tests::utils::TestContext, DelegationError};
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+const YEAR: u64 = 31536000;
+
+#[test]
+fn test_delegate_issues_within_scope() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let regional_office = Address::generate(&context.env);
+    let now = context.env.ledger().timestamp();
+
+    context.env.mock_all_auths();
+    client.delegate_issuance(
+        &context.issuer1,
+        &regional_office,
+        &vec![&context.env, context.symbol("ORGANIC")],
+        &(now + YEAR),
+        &5,
+    );
+
+    let id = client.issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient1,
+        &context.symbol("ORGANIC"),
+        &(now + YEAR),
+        &context.create_document_hash("Regional organic audit"),
+    );
+
+    let record = client.get_issuer_of_record(&context.recipient1, &id);
+    assert_eq!(record.issuer, context.issuer1);
+    assert_eq!(record.delegate, Some(regional_office.clone()));
+
+    let delegations = client.get_delegations(&context.issuer1);
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations.get(0).unwrap().remaining_issuances, 4);
+
+    // The parent keeps authority over certifications issued by its delegates
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    // Directly issued certifications have no delegate
+    let direct = context.issue_test_cert(&context.issuer2, &context.recipient2, "ORGANIC", 30);
+    let record = client.get_issuer_of_record(&context.recipient2, &direct);
+    assert_eq!(record.issuer, context.issuer2);
+    assert_eq!(record.delegate, None);
+}
+
+#[test]
+fn test_delegate_scope_violation() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let regional_office = Address::generate(&context.env);
+    let now = context.env.ledger().timestamp();
+
+    context.env.mock_all_auths();
+    client.delegate_issuance(
+        &context.issuer1,
+        &regional_office,
+        &vec![&context.env, context.symbol("ORGANIC")],
+        &(now + 1000),
+        &5,
+    );
+
+    let result = client.try_issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient1,
+        &context.symbol("FAIRTRADE"),
+        &(now + YEAR),
+        &context.create_document_hash("Fair trade audit"),
+    );
+    assert_eq!(result, Err(Ok(DelegationError::CertTypeNotAllowed)));
+
+    // No delegation from issuer2
+    let result = client.try_issue_delegated(
+        &regional_office,
+        &context.issuer2,
+        &context.recipient1,
+        &context.symbol("ORGANIC"),
+        &(now + YEAR),
+        &context.create_document_hash("Organic audit"),
+    );
+    assert_eq!(result, Err(Ok(DelegationError::NotFound)));
+
+    context.advance_time(1000);
+    let result = client.try_issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient1,
+        &context.symbol("ORGANIC"),
+        &(now + YEAR),
+        &context.create_document_hash("Organic audit"),
+    );
+    assert_eq!(result, Err(Ok(DelegationError::Expired)));
+}
+
+#[test]
+fn test_delegation_exhausted() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let regional_office = Address::generate(&context.env);
+    let now = context.env.ledger().timestamp();
+    let organic = context.symbol("ORGANIC");
+
+    context.env.mock_all_auths();
+    client.delegate_issuance(
+        &context.issuer1,
+        &regional_office,
+        &vec![&context.env, organic.clone()],
+        &(now + YEAR),
+        &2,
+    );
+
+    for _ in 0..2 {
+        client.issue_delegated(
+            &regional_office,
+            &context.issuer1,
+            &context.recipient1,
+            &organic,
+            &(now + YEAR),
+            &context.create_document_hash("Organic audit"),
+        );
+    }
+
+    let result = client.try_issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient1,
+        &organic,
+        &(now + YEAR),
+        &context.create_document_hash("Organic audit"),
+    );
+    assert_eq!(result, Err(Ok(DelegationError::Exhausted)));
+    assert_eq!(
+        client.list_certs(&context.recipient1, &None, &0, &10).len(),
+        2
+    );
+}
+
+#[test]
+fn test_revoked_delegation_cannot_issue() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let regional_office = Address::generate(&context.env);
+    let now = context.env.ledger().timestamp();
+    let organic = context.symbol("ORGANIC");
+
+    context.env.mock_all_auths();
+    let result = client.try_delegate_issuance(
+        &context.issuer1,
+        &context.issuer1,
+        &vec![&context.env, organic.clone()],
+        &(now + YEAR),
+        &2,
+    );
+    assert_eq!(result, Err(Ok(DelegationError::InvalidDelegation)));
+
+    client.delegate_issuance(
+        &context.issuer1,
+        &regional_office,
+        &vec![&context.env, organic.clone()],
+        &(now + YEAR),
+        &2,
+    );
+    client.revoke_delegation(&context.issuer1, &regional_office);
+    assert_eq!(client.get_delegations(&context.issuer1).len(), 0);
+
+    let result = client.try_issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient1,
+        &organic,
+        &(now + YEAR),
+        &context.create_document_hash("Organic audit"),
+    );
+    assert_eq!(result, Err(Ok(DelegationError::NotFound)));
+
+    let result = client.try_revoke_delegation(&context.issuer1, &regional_office);
+    assert_eq!(result, Err(Ok(DelegationError::NotFound)));
+}
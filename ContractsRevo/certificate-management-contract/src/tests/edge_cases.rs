@@ -173,7 +173,13 @@ fn test_revocation_race_conditions() {
     context.env.mock_all_auths();
 
     // First revoke
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert_id);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Then try to expire (should fail as it's already revoked)
     let result = client.try_expire_certification(&context.recipient1, &cert_id);
@@ -243,7 +249,13 @@ fn test_self_issued_certificate() {
 
     // Self-revoke should work
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.issuer1, &cert_id);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.issuer1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     let status = client.check_cert_status(&context.issuer1, &cert_id);
     assert_eq!(status, CertStatus::Revoked);
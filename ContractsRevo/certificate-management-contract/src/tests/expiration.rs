@@ -0,0 +1,147 @@
+#![cfg(test)]
+use crate::{tests::utils::TestContext, CertStatus, CertificationError, VerifyError};
+
+const DAY: u64 = 86400;
+
+#[test]
+fn test_verification_fails_right_after_expiry_without_sweep() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 1);
+    let doc_hash = context.create_document_hash("Organic certification document");
+
+    client.verify_document_hash(&context.recipient1, &cert_id, &doc_hash);
+
+    context.advance_time(DAY + 1);
+
+    // Nobody has called expire or sweep, yet the certification already reads as expired
+    assert_eq!(
+        client.check_cert_status(&context.recipient1, &cert_id),
+        CertStatus::Expired
+    );
+    let result = client.try_verify_document_hash(&context.recipient1, &cert_id, &doc_hash);
+    assert_eq!(result, Err(Ok(VerifyError::Expired)));
+
+    // The stored record is untouched until it is swept
+    assert_eq!(
+        client.get_cert(&context.recipient1, &cert_id).status,
+        CertStatus::Valid
+    );
+}
+
+#[test]
+fn test_sweep_persists_expired_status() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    for _ in 0..3 {
+        context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 1);
+    }
+    let long_lived = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+
+    context.advance_time(2 * DAY);
+
+    // Bounded per call
+    assert_eq!(client.sweep_expired(&context.recipient1, &2), 2);
+    assert_eq!(
+        client.get_cert(&context.recipient1, &1).status,
+        CertStatus::Expired
+    );
+    assert_eq!(
+        client.get_cert(&context.recipient1, &2).status,
+        CertStatus::Expired
+    );
+    assert_eq!(
+        client.get_cert(&context.recipient1, &3).status,
+        CertStatus::Valid
+    );
+
+    assert_eq!(client.sweep_expired(&context.recipient1, &10), 1);
+    assert_eq!(
+        client.get_cert(&context.recipient1, &3).status,
+        CertStatus::Expired
+    );
+    assert_eq!(
+        client.get_cert(&context.recipient1, &long_lived).status,
+        CertStatus::Valid
+    );
+
+    // Nothing left to sweep
+    assert_eq!(client.sweep_expired(&context.recipient1, &10), 0);
+
+    let result = client.try_sweep_expired(&context.recipient2, &10);
+    assert_eq!(result, Err(Ok(CertificationError::NotFound)));
+}
+
+#[test]
+fn test_audit_report_uses_current_status() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let short = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 1);
+    let long = context.issue_test_cert(&context.issuer1, &context.recipient1, "FAIRTRADE", 365);
+
+    context.advance_time(2 * DAY);
+
+    let expired = client.generate_cert_audit_report(
+        &context.recipient1,
+        &None,
+        &Some(CertStatus::Expired),
+        &None,
+    );
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired.get(0).unwrap().id, short);
+    assert_eq!(expired.get(0).unwrap().status, CertStatus::Expired);
+
+    let valid = client.generate_cert_audit_report(
+        &context.recipient1,
+        &None,
+        &Some(CertStatus::Valid),
+        &None,
+    );
+    assert_eq!(valid.len(), 1);
+    assert_eq!(valid.get(0).unwrap().id, long);
+
+    let listed = client.list_certs(&context.recipient1, &Some(CertStatus::Expired), &0, &10);
+    assert_eq!(listed.len(), 1);
+}
+
+#[test]
+fn test_get_expiring_soon() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 5);
+    context.issue_test_cert(&context.issuer1, &context.recipient1, "FAIRTRADE", 20);
+    let revoked = context.issue_test_cert(&context.issuer1, &context.recipient1, "NON_GMO", 5);
+
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &revoked,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    let soon = client.get_expiring_soon(&context.recipient1, &(7 * DAY));
+    assert_eq!(soon.len(), 1);
+    assert_eq!(soon.get(0).unwrap().cert_type, context.symbol("ORGANIC"));
+
+    assert_eq!(
+        client
+            .get_expiring_soon(&context.recipient1, &(30 * DAY))
+            .len(),
+        2
+    );
+
+    // Already expired certifications are no longer "expiring"
+    context.advance_time(6 * DAY);
+    assert_eq!(
+        client
+            .get_expiring_soon(&context.recipient1, &(7 * DAY))
+            .len(),
+        0
+    );
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 use crate::{tests::utils::TestContext, AdminError, CertStatus, IssueError};
+use soroban_sdk::{vec, Vec};
 
 #[test]
 fn test_contract_initialization() {
@@ -206,3 +207,160 @@ fn test_different_cert_types() {
         assert_eq!(cert.cert_type, context.symbol(cert_type));
     }
 }
+
+#[test]
+fn test_issue_batch_success() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let expiration = context.env.ledger().timestamp() + 31536000;
+    let organic = context.symbol("ORGANIC");
+
+    context.env.mock_all_auths();
+    let ids = client.issue_batch(
+        &context.issuer1,
+        &vec![
+            &context.env,
+            (
+                context.recipient1.clone(),
+                organic.clone(),
+                expiration,
+                context.create_document_hash("Farm A"),
+            ),
+            (
+                context.recipient2.clone(),
+                organic.clone(),
+                expiration,
+                context.create_document_hash("Farm B"),
+            ),
+            (
+                context.recipient1.clone(),
+                context.symbol("FAIRTRADE"),
+                expiration,
+                context.create_document_hash("Farm A fair trade"),
+            ),
+        ],
+    );
+
+    // Ids are assigned per recipient
+    assert_eq!(ids, vec![&context.env, 1, 1, 2]);
+
+    let cert = client.get_cert(&context.recipient2, &1);
+    assert_eq!(cert.issuer, context.issuer1);
+    assert_eq!(
+        cert.verification_hash,
+        context.create_document_hash("Farm B")
+    );
+    assert_eq!(
+        client.get_cert(&context.recipient1, &2).cert_type,
+        context.symbol("FAIRTRADE")
+    );
+}
+
+#[test]
+fn test_issue_batch_invalid_entry_aborts_batch() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let now = context.env.ledger().timestamp();
+    let organic = context.symbol("ORGANIC");
+
+    context.env.mock_all_auths();
+    let result = client.try_issue_batch(
+        &context.issuer1,
+        &vec![
+            &context.env,
+            (
+                context.recipient1.clone(),
+                organic.clone(),
+                now + 31536000,
+                context.create_document_hash("Farm A"),
+            ),
+            (
+                context.recipient2.clone(),
+                organic.clone(),
+                now,
+                context.create_document_hash("Farm B"),
+            ),
+        ],
+    );
+    assert_eq!(result, Err(Ok(IssueError::InvalidExpirationDate)));
+
+    // Nothing from the batch was written
+    assert_eq!(
+        client.list_certs(&context.recipient1, &None, &0, &10).len(),
+        0
+    );
+
+    let result = client.try_issue_batch(&context.issuer1, &Vec::new(&context.env));
+    assert_eq!(result, Err(Ok(IssueError::EmptyBatch)));
+}
+
+#[test]
+fn test_issue_batch_size_cap() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let expiration = context.env.ledger().timestamp() + 31536000;
+    let mut recipients = Vec::new(&context.env);
+    for _ in 0..51 {
+        recipients.push_back((
+            context.recipient1.clone(),
+            context.symbol("ORGANIC"),
+            expiration,
+            context.create_document_hash("Farm A"),
+        ));
+    }
+
+    context.env.mock_all_auths();
+    let result = client.try_issue_batch(&context.issuer1, &recipients);
+    assert_eq!(result, Err(Ok(IssueError::BatchTooLarge)));
+}
+
+#[test]
+fn test_list_certs_with_status_filter_and_pagination() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    for _ in 0..5 {
+        context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    }
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &2,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &4,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    let all = client.list_certs(&context.recipient1, &None, &0, &10);
+    assert_eq!(all.len(), 5);
+
+    let page = client.list_certs(&context.recipient1, &None, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 2);
+    assert_eq!(page.get(1).unwrap().id, 3);
+
+    let valid = client.list_certs(&context.recipient1, &Some(CertStatus::Valid), &1, &10);
+    assert_eq!(valid.len(), 2);
+    assert_eq!(valid.get(0).unwrap().id, 3);
+    assert_eq!(valid.get(1).unwrap().id, 5);
+
+    let revoked = client.list_certs(&context.recipient1, &Some(CertStatus::Revoked), &0, &10);
+    assert_eq!(revoked.len(), 2);
+    assert_eq!(revoked.get(1).unwrap().id, 4);
+
+    // Owners without certifications get an empty page
+    assert_eq!(
+        client.list_certs(&context.recipient2, &None, &0, &10).len(),
+        0
+    );
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+use crate::{tests::utils::TestContext, MetadataError};
+use soroban_sdk::{map, testutils::Address as _, vec, Address, Map, String, Symbol};
+
+#[test]
+fn test_attribute_crud_with_history() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let env = &context.env;
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+
+    context.env.mock_all_auths();
+    client.set_cert_attributes(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &map![
+            env,
+            (context.symbol("crop"), String::from_str(env, "coffee")),
+            (context.symbol("farm_size"), String::from_str(env, "12ha")),
+        ],
+    );
+
+    // Overwrite one key, add another
+    client.set_cert_attributes(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &map![
+            env,
+            (context.symbol("farm_size"), String::from_str(env, "15ha")),
+            (
+                context.symbol("standard"),
+                String::from_str(env, "EU-2018/848")
+            ),
+        ],
+    );
+
+    let attributes = client.get_cert_attributes(&context.recipient1, &cert_id);
+    assert_eq!(attributes.len(), 3);
+    assert_eq!(
+        attributes.get(context.symbol("farm_size")),
+        Some(String::from_str(env, "15ha"))
+    );
+
+    let history = client.get_attribute_history(&context.recipient1, &cert_id);
+    assert_eq!(history.len(), 4);
+    let overwrite = history
+        .iter()
+        .find(|change| change.previous.is_some())
+        .unwrap();
+    assert_eq!(overwrite.key, context.symbol("farm_size"));
+    assert_eq!(overwrite.previous, Some(String::from_str(env, "12ha")));
+    assert_eq!(overwrite.value, String::from_str(env, "15ha"));
+    assert_eq!(overwrite.changed_by, context.issuer1);
+
+    let uri = String::from_str(
+        env,
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.set_document_uri(&context.issuer1, &context.recipient1, &cert_id, &uri);
+
+    // Both surface together in the certificate's metadata
+    let metadata = client.get_cert_metadata(&context.recipient1, &cert_id);
+    assert_eq!(metadata.document_uri, Some(uri));
+    assert_eq!(metadata.attributes, attributes);
+}
+
+#[test]
+fn test_attribute_caps() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let env = &context.env;
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    context.env.mock_all_auths();
+
+    let keys = [
+        "a0", "a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8", "a9", "b0", "b1", "b2", "b3", "b4",
+        "b5", "b6", "b7", "b8", "b9",
+    ];
+    let mut attributes = Map::new(env);
+    for key in keys {
+        attributes.set(Symbol::new(env, key), String::from_str(env, "x"));
+    }
+    client.set_cert_attributes(&context.issuer1, &context.recipient1, &cert_id, &attributes);
+
+    // Overwriting at the cap is fine, adding a new key is not
+    client.set_cert_attributes(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &map![env, (Symbol::new(env, "a0"), String::from_str(env, "y"))],
+    );
+    let result = client.try_set_cert_attributes(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &map![env, (Symbol::new(env, "c0"), String::from_str(env, "x"))],
+    );
+    assert_eq!(result, Err(Ok(MetadataError::TooManyAttributes)));
+
+    let other_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    let long_value = String::from_str(env, &"v".repeat(129));
+    let result = client.try_set_cert_attributes(
+        &context.issuer1,
+        &context.recipient1,
+        &other_id,
+        &map![env, (Symbol::new(env, "notes"), long_value)],
+    );
+    assert_eq!(result, Err(Ok(MetadataError::ValueTooLong)));
+
+    let long_uri = String::from_str(env, &"u".repeat(257));
+    let result =
+        client.try_set_document_uri(&context.issuer1, &context.recipient1, &other_id, &long_uri);
+    assert_eq!(result, Err(Ok(MetadataError::UriTooLong)));
+}
+
+#[test]
+fn test_unauthorized_setter_rejected() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let env = &context.env;
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    let attributes = map![
+        env,
+        (context.symbol("crop"), String::from_str(env, "cocoa"))
+    ];
+
+    context.env.mock_all_auths();
+    let result = client.try_set_cert_attributes(
+        &context.issuer2,
+        &context.recipient1,
+        &cert_id,
+        &attributes,
+    );
+    assert_eq!(result, Err(Ok(MetadataError::Unauthorized)));
+
+    let result = client.try_set_document_uri(
+        &context.recipient1,
+        &context.recipient1,
+        &cert_id,
+        &String::from_str(env, "https://example.org/cert.pdf"),
+    );
+    assert_eq!(result, Err(Ok(MetadataError::Unauthorized)));
+
+    // The delegate of record may annotate what it issued
+    let regional_office = Address::generate(env);
+    let now = env.ledger().timestamp();
+    client.delegate_issuance(
+        &context.issuer1,
+        &regional_office,
+        &vec![env, context.symbol("ORGANIC")],
+        &(now + 1000),
+        &1,
+    );
+    let delegated_id = client.issue_delegated(
+        &regional_office,
+        &context.issuer1,
+        &context.recipient2,
+        &context.symbol("ORGANIC"),
+        &(now + 1000),
+        &context.create_document_hash("Regional audit"),
+    );
+    client.set_cert_attributes(
+        &regional_office,
+        &context.recipient2,
+        &delegated_id,
+        &attributes,
+    );
+    assert_eq!(
+        client
+            .get_cert_attributes(&context.recipient2, &delegated_id)
+            .len(),
+        1
+    );
+}
@@ -1,8 +1,12 @@
 #![cfg(test)]
 
 pub mod audit;
+pub mod delegation;
 pub mod edge_cases;
+pub mod expiration;
 pub mod issuance;
+pub mod metadata;
+pub mod pagination;
 pub mod revocation;
 pub mod utils;
 pub mod verification;
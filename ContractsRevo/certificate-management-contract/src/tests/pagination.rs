@@ -0,0 +1,162 @@
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use super::utils::TestContext;
+use crate::{certification::MAX_CERT_PAGE_SIZE, CertStatus, CertificationPage};
+
+const CERT_COUNT: u32 = 150;
+
+/// Issues `CERT_COUNT` certifications to `recipient1`, expiring one day apart.
+fn issue_many(context: &TestContext) {
+    let client = context.client();
+    let now = context.env.ledger().timestamp();
+    let doc_hash = context.create_document_hash("Organic certification document");
+
+    context.env.mock_all_auths();
+    let mut issued = 0;
+    while issued < CERT_COUNT {
+        let mut recipients: Vec<(Address, _, u64, _)> = vec![&context.env];
+        for _ in 0..50 {
+            issued += 1;
+            recipients.push_back((
+                context.recipient1.clone(),
+                context.symbol("ORGANIC"),
+                now + issued as u64 * 86400,
+                doc_hash.clone(),
+            ));
+        }
+        client.issue_batch(&context.issuer1, &recipients);
+    }
+}
+
+fn page_ids(env: &Env, page: &CertificationPage) -> Vec<u32> {
+    let mut ids = Vec::new(env);
+    for cert in page.certifications.iter() {
+        ids.push_back(cert.id);
+    }
+    ids
+}
+
+fn id_range(env: &Env, first: u32, last: u32) -> Vec<u32> {
+    let mut ids = Vec::new(env);
+    for id in first..=last {
+        ids.push_back(id);
+    }
+    ids
+}
+
+#[test]
+fn test_list_certs_paged_walks_all_certifications_in_order() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context);
+
+    let mut seen = Vec::new(&context.env);
+    let mut offset = 0;
+    loop {
+        let page = client.list_certs_paged(&context.recipient1, &None, &offset, &40);
+        assert_eq!(page.total, CERT_COUNT);
+        seen.append(&page_ids(&context.env, &page));
+        offset += page.certifications.len();
+        if !page.truncated {
+            break;
+        }
+    }
+
+    assert_eq!(seen, id_range(&context.env, 1, CERT_COUNT));
+
+    // Reading the same page twice gives the same result
+    let first = client.list_certs_paged(&context.recipient1, &None, &40, &40);
+    let second = client.list_certs_paged(&context.recipient1, &None, &40, &40);
+    assert_eq!(
+        page_ids(&context.env, &first),
+        page_ids(&context.env, &second)
+    );
+    assert_eq!(first.certifications.get(0).unwrap().id, 41);
+}
+
+#[test]
+fn test_list_certs_paged_clamps_limit_and_reports_truncation() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context);
+
+    let page = client.list_certs_paged(&context.recipient1, &None, &0, &u32::MAX);
+    assert_eq!(page.certifications.len(), MAX_CERT_PAGE_SIZE);
+    assert_eq!(page.total, CERT_COUNT);
+    assert!(page.truncated);
+
+    // The legacy listing returns the same clamped page
+    let legacy = client.list_certs(&context.recipient1, &None, &0, &u32::MAX);
+    assert_eq!(legacy.len(), MAX_CERT_PAGE_SIZE);
+
+    let tail = client.list_certs_paged(&context.recipient1, &None, &MAX_CERT_PAGE_SIZE, &u32::MAX);
+    assert_eq!(tail.certifications.len(), CERT_COUNT - MAX_CERT_PAGE_SIZE);
+    assert_eq!(
+        tail.certifications.get(0).unwrap().id,
+        MAX_CERT_PAGE_SIZE + 1
+    );
+    assert!(!tail.truncated);
+
+    let past_end = client.list_certs_paged(&context.recipient1, &None, &u32::MAX, &10);
+    assert_eq!(past_end.certifications.len(), 0);
+    assert_eq!(past_end.total, CERT_COUNT);
+    assert!(!past_end.truncated);
+
+    let empty = client.list_certs_paged(&context.recipient2, &None, &0, &10);
+    assert_eq!(empty.total, 0);
+    assert!(!empty.truncated);
+}
+
+#[test]
+fn test_list_certs_paged_counts_only_matching_status() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context);
+
+    context.env.mock_all_auths();
+    for id in [10, 20, 30] {
+        client.revoke_certification(
+            &context.issuer1,
+            &context.recipient1,
+            &id,
+            &context.symbol("NON_COMPLIANT"),
+            &None,
+        );
+    }
+
+    let revoked = client.list_certs_paged(&context.recipient1, &Some(CertStatus::Revoked), &1, &1);
+    assert_eq!(revoked.total, 3);
+    assert_eq!(page_ids(&context.env, &revoked), vec![&context.env, 20]);
+    assert!(revoked.truncated);
+
+    let valid = client.list_certs_paged(&context.recipient1, &Some(CertStatus::Valid), &0, &5);
+    assert_eq!(valid.total, CERT_COUNT - 3);
+}
+
+#[test]
+fn test_get_expiring_soon_paged() {
+    let context = TestContext::setup();
+    let client = context.client();
+    issue_many(&context);
+
+    // Everything expires within the window, so the legacy call is clamped
+    let within = CERT_COUNT as u64 * 86400;
+    assert_eq!(
+        client.get_expiring_soon(&context.recipient1, &within).len(),
+        MAX_CERT_PAGE_SIZE
+    );
+
+    let page = client.get_expiring_soon_paged(&context.recipient1, &within, &120, &50);
+    assert_eq!(page.total, CERT_COUNT);
+    assert_eq!(
+        page_ids(&context.env, &page),
+        id_range(&context.env, 121, CERT_COUNT)
+    );
+    assert!(!page.truncated);
+
+    // Only the first 10 certifications expire within 10 days
+    let soon = client.get_expiring_soon_paged(&context.recipient1, &(10 * 86400), &0, &4);
+    assert_eq!(soon.total, 10);
+    assert_eq!(page_ids(&context.env, &soon), id_range(&context.env, 1, 4));
+    assert!(soon.truncated);
+}
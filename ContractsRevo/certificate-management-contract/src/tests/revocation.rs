@@ -1,5 +1,51 @@
 #![cfg(test)]
-use crate::{tests::utils::TestContext, CertStatus, CertificationError, RevokeError};
+use crate::{
+    revoke::MAX_PENDING_NOTIFICATIONS, tests::utils::TestContext, CertStatus, CertificationError,
+    DataKey, PendingNotification, RevokeError,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::storage::Persistent as _, Address, BytesN,
+    Env, Vec,
+};
+
+// Records every revocation it is told about, or rejects them while `fail` is set.
+#[contract]
+pub struct MockListener;
+
+#[contractimpl]
+impl MockListener {
+    pub fn set_fail(env: Env, fail: bool) {
+        env.storage().instance().set(&symbol_short!("fail"), &fail);
+    }
+
+    pub fn on_cert_revoked(env: Env, owner: Address, id: u32) {
+        if env
+            .storage()
+            .instance()
+            .get(&symbol_short!("fail"))
+            .unwrap_or(false)
+        {
+            panic!("listener unavailable");
+        }
+
+        let mut received: Vec<(Address, u32)> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("received"))
+            .unwrap_or_else(|| Vec::new(&env));
+        received.push_back((owner, id));
+        env.storage()
+            .instance()
+            .set(&symbol_short!("received"), &received);
+    }
+
+    pub fn received(env: Env) -> Vec<(Address, u32)> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("received"))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
 
 #[test]
 fn test_successful_revocation() {
@@ -14,7 +60,13 @@ fn test_successful_revocation() {
 
     // Revoke certification
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert_id);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Verify status updated
     let status = client.check_cert_status(&context.recipient1, &cert_id);
@@ -31,7 +83,13 @@ fn test_unauthorized_revocation() {
 
     // Try to revoke with issuer2 (unauthorized)
     context.env.mock_all_auths();
-    let result = client.try_revoke_certification(&context.issuer2, &context.recipient1, &cert_id);
+    let result = client.try_revoke_certification(
+        &context.issuer2,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     assert!(result.is_err());
     if let Err(Ok(e)) = result {
@@ -49,7 +107,13 @@ fn test_revoke_nonexistent_certification() {
     let client = context.client();
 
     context.env.mock_all_auths();
-    let result = client.try_revoke_certification(&context.issuer1, &context.recipient1, &999);
+    let result = client.try_revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &999,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     assert!(result.is_err());
     if let Err(Ok(e)) = result {
@@ -66,10 +130,22 @@ fn test_revoke_already_revoked_certification() {
 
     // Revoke once
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert_id);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Try to revoke again
-    let result = client.try_revoke_certification(&context.issuer1, &context.recipient1, &cert_id);
+    let result = client.try_revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     assert!(result.is_err());
     if let Err(Ok(e)) = result {
@@ -101,7 +177,13 @@ fn test_revoke_expired_certification() {
     client.expire_certification(&context.recipient1, &1);
 
     // Try to revoke expired cert
-    let result = client.try_revoke_certification(&context.issuer1, &context.recipient1, &1);
+    let result = client.try_revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     assert!(result.is_err());
     if let Err(Ok(e)) = result {
@@ -200,7 +282,13 @@ fn test_authorization_required_for_revocation() {
 
     // Don't authorize - should fail
     context.env.set_auths(&[]);
-    let result = client.try_revoke_certification(&context.issuer1, &context.recipient1, &cert_id);
+    let result = client.try_revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     assert!(result.is_err());
 }
@@ -216,8 +304,20 @@ fn test_multiple_revocations_by_same_issuer() {
 
     // Revoke both
     context.env.mock_all_auths();
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert1_id);
-    client.revoke_certification(&context.issuer1, &context.recipient1, &cert2_id);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert1_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert2_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Verify both are revoked
     let status1 = client.check_cert_status(&context.recipient1, &cert1_id);
@@ -225,3 +325,165 @@ fn test_multiple_revocations_by_same_issuer() {
     assert_eq!(status1, CertStatus::Revoked);
     assert_eq!(status2, CertStatus::Revoked);
 }
+
+#[test]
+fn test_revocation_reason_stored() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    let evidence = BytesN::from_array(&context.env, &[9; 32]);
+
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("PESTICIDE_FOUND"),
+        &Some(evidence.clone()),
+    );
+
+    let details = client.get_revocation_details(&context.recipient1, &cert_id);
+    assert_eq!(details.reason, context.symbol("PESTICIDE_FOUND"));
+    assert_eq!(details.evidence_hash, Some(evidence));
+    assert_eq!(details.revoked_by, context.issuer1);
+    assert_eq!(details.revoked_at, context.env.ledger().timestamp());
+
+    // The record lives in persistent storage rather than growing the instance
+    context.env.as_contract(&context.contract_id, || {
+        let key = DataKey::Revocation(context.recipient1.clone(), cert_id);
+        assert!(!context.env.storage().instance().has(&key));
+        assert!(context.env.storage().persistent().get_ttl(&key) >= 518_400);
+    });
+
+    let other = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    let result = client.try_get_revocation_details(&context.recipient1, &other);
+    assert_eq!(result, Err(Ok(RevokeError::NotFound)));
+}
+
+#[test]
+fn test_listener_notified_on_revocation() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let listener_id = context.env.register(MockListener, ());
+    let listener = MockListenerClient::new(&context.env, &listener_id);
+
+    context.env.mock_all_auths();
+    client.register_revocation_listener(&context.admin, &listener_id);
+    let result = client.try_register_revocation_listener(&context.admin, &listener_id);
+    assert_eq!(result, Err(Ok(RevokeError::ListenerAlreadyRegistered)));
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+
+    let received = listener.received();
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received.get(0).unwrap(),
+        (context.recipient1.clone(), cert_id)
+    );
+    assert_eq!(client.get_pending_notifications().len(), 0);
+}
+
+#[test]
+fn test_failing_listener_recorded_and_retried() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let listener_id = context.env.register(MockListener, ());
+    let listener = MockListenerClient::new(&context.env, &listener_id);
+    listener.set_fail(&true);
+
+    context.env.mock_all_auths();
+    client.register_revocation_listener(&context.admin, &listener_id);
+
+    // The revocation succeeds even though the listener fails
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &cert_id,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+    assert_eq!(
+        client.check_cert_status(&context.recipient1, &cert_id),
+        CertStatus::Revoked
+    );
+
+    let pending = client.get_pending_notifications();
+    assert_eq!(
+        pending.get(0).unwrap(),
+        PendingNotification {
+            listener: listener_id.clone(),
+            owner: context.recipient1.clone(),
+            id: cert_id,
+            attempts: 1,
+        }
+    );
+
+    assert!(!client.retry_notification(&listener_id, &context.recipient1, &cert_id));
+    assert_eq!(
+        client.get_pending_notifications().get(0).unwrap().attempts,
+        2
+    );
+
+    listener.set_fail(&false);
+    assert!(client.retry_notification(&listener_id, &context.recipient1, &cert_id));
+    assert_eq!(listener.received().len(), 1);
+    assert_eq!(client.get_pending_notifications().len(), 0);
+
+    let result = client.try_retry_notification(&listener_id, &context.recipient1, &cert_id);
+    assert_eq!(result, Err(Ok(RevokeError::NotificationNotFound)));
+}
+
+#[test]
+fn test_pending_notifications_are_capped() {
+    let context = TestContext::setup();
+    let client = context.client();
+
+    context.env.mock_all_auths();
+    for _ in 0..10 {
+        let listener_id = context.env.register(MockListener, ());
+        MockListenerClient::new(&context.env, &listener_id).set_fail(&true);
+        client.register_revocation_listener(&context.admin, &listener_id);
+    }
+
+    // Six revocations fail against ten listeners, ten more than the queue holds
+    for _ in 0..6 {
+        let cert_id =
+            context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 365);
+        client.revoke_certification(
+            &context.issuer1,
+            &context.recipient1,
+            &cert_id,
+            &context.symbol("NON_COMPLIANT"),
+            &None,
+        );
+    }
+
+    assert_eq!(
+        client.get_pending_notifications().len(),
+        MAX_PENDING_NOTIFICATIONS
+    );
+}
+
+#[test]
+fn test_only_admin_registers_listeners() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let listener_id = context.env.register(MockListener, ());
+
+    context.env.mock_all_auths();
+    let result = client.try_register_revocation_listener(&context.issuer1, &listener_id);
+    assert_eq!(result, Err(Ok(RevokeError::Unauthorized)));
+
+    client.register_revocation_listener(&context.admin, &listener_id);
+    client.remove_revocation_listener(&context.admin, &listener_id);
+    assert_eq!(client.get_revocation_listeners().len(), 0);
+}
@@ -88,7 +88,13 @@ fn test_verify_revoked_certification() {
         &doc_hash,
     );
 
-    client.revoke_certification(&context.issuer1, &context.recipient1, &1);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
 
     // Try to verify revoked certification
     let result = client.try_verify_document_hash(&context.recipient1, &1, &doc_hash);
@@ -214,7 +220,99 @@ fn test_check_cert_status() {
     assert_eq!(status, CertStatus::Valid);
 
     // Revoke and check status
-    client.revoke_certification(&context.issuer1, &context.recipient1, &1);
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &1,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
     let status = client.check_cert_status(&context.recipient1, &1);
     assert_eq!(status, CertStatus::Revoked);
 }
+
+#[test]
+fn test_verification_bundle_across_statuses() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let doc_hash = context.create_document_hash("Organic certification document");
+
+    let valid = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 30);
+    let revoked = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 30);
+    let expiring = context.issue_test_cert(&context.issuer2, &context.recipient1, "ORGANIC", 1);
+
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &revoked,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+    context.advance_time(2 * 86400);
+
+    let bundle = client.get_verification_bundle(&context.recipient1, &valid);
+    let cert = client.get_cert(&context.recipient1, &valid);
+    assert_eq!(bundle.status, CertStatus::Valid);
+    assert_eq!(bundle.verification_hash, doc_hash);
+    assert_eq!(bundle.cert_type, context.symbol("ORGANIC"));
+    assert_eq!(bundle.expiration_date, cert.expiration_date);
+    assert_eq!(bundle.issuer, context.issuer1);
+
+    assert_eq!(
+        client
+            .get_verification_bundle(&context.recipient1, &revoked)
+            .status,
+        CertStatus::Revoked
+    );
+
+    // Expiry is reflected even though it was never persisted
+    let bundle = client.get_verification_bundle(&context.recipient1, &expiring);
+    assert_eq!(bundle.status, CertStatus::Expired);
+    assert_eq!(bundle.issuer, context.issuer2);
+
+    let result = client.try_get_verification_bundle(&context.recipient1, &99);
+    assert_eq!(result, Err(Ok(VerifyError::NotFound)));
+}
+
+#[test]
+fn test_verify_for_product_checks() {
+    let context = TestContext::setup();
+    let client = context.client();
+    let doc_hash = context.create_document_hash("Organic certification document");
+
+    let cert_id = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 10);
+    let ten_days = 10 * 86400;
+
+    client.verify_for_product(&context.recipient1, &cert_id, &doc_hash, &0);
+    client.verify_for_product(&context.recipient1, &cert_id, &doc_hash, &ten_days);
+
+    // Not enough validity left for the requested window
+    let result =
+        client.try_verify_for_product(&context.recipient1, &cert_id, &doc_hash, &(ten_days + 1));
+    assert_eq!(result, Err(Ok(VerifyError::ExpirationDue)));
+
+    let result = client.try_verify_for_product(
+        &context.recipient1,
+        &cert_id,
+        &context.create_document_hash("Forged document"),
+        &0,
+    );
+    assert_eq!(result, Err(Ok(VerifyError::HashMismatch)));
+
+    context.advance_time(ten_days + 1);
+    let result = client.try_verify_for_product(&context.recipient1, &cert_id, &doc_hash, &0);
+    assert_eq!(result, Err(Ok(VerifyError::Expired)));
+
+    let revoked = context.issue_test_cert(&context.issuer1, &context.recipient1, "ORGANIC", 10);
+    context.env.mock_all_auths();
+    client.revoke_certification(
+        &context.issuer1,
+        &context.recipient1,
+        &revoked,
+        &context.symbol("NON_COMPLIANT"),
+        &None,
+    );
+    let result = client.try_verify_for_product(&context.recipient1, &revoked, &doc_hash, &0);
+    assert_eq!(result, Err(Ok(VerifyError::Revoked)));
+}
@@ -1,6 +1,8 @@
 use soroban_sdk::{Address, BytesN, Env, Symbol};
 
-use crate::{DataKey, UsersCertificates, VerifyError};
+use crate::{
+    CertStatus, Certification, DataKey, UsersCertificates, VerificationBundle, VerifyError,
+};
 
 pub fn verify_document_hash(
     env: Env,
@@ -8,6 +10,64 @@ pub fn verify_document_hash(
     id: u32,
     submitted_hash: BytesN<32>,
 ) -> Result<(), VerifyError> {
+    let certification = load(&env, &owner, id)?;
+
+    certification.verify(submitted_hash, env.ledger().timestamp())?;
+
+    env.events().publish(
+        (Symbol::new(&env, "certification_verified"), owner.clone()),
+        env.ledger().timestamp(),
+    );
+
+    Ok(())
+}
+
+pub fn get_verification_bundle(
+    env: Env,
+    owner: Address,
+    id: u32,
+) -> Result<VerificationBundle, VerifyError> {
+    let certification = load(&env, &owner, id)?;
+
+    Ok(VerificationBundle {
+        status: certification.effective_status(env.ledger().timestamp()),
+        verification_hash: certification.verification_hash,
+        cert_type: certification.cert_type,
+        expiration_date: certification.expiration_date,
+        issuer: certification.issuer,
+    })
+}
+
+/// Standard checks before linking a certification to a product: it must be valid, match the
+/// expected document hash, and stay valid for at least `min_remaining_validity_secs`.
+pub fn verify_for_product(
+    env: Env,
+    owner: Address,
+    id: u32,
+    expected_hash: BytesN<32>,
+    min_remaining_validity_secs: u64,
+) -> Result<(), VerifyError> {
+    let certification = load(&env, &owner, id)?;
+    let current_time = env.ledger().timestamp();
+
+    match certification.effective_status(current_time) {
+        CertStatus::Revoked => return Err(VerifyError::Revoked),
+        CertStatus::Expired => return Err(VerifyError::Expired),
+        CertStatus::Valid => {}
+    }
+
+    if certification.verification_hash != expected_hash {
+        return Err(VerifyError::HashMismatch);
+    }
+
+    if certification.expiration_date < current_time.saturating_add(min_remaining_validity_secs) {
+        return Err(VerifyError::ExpirationDue);
+    }
+
+    Ok(())
+}
+
+fn load(env: &Env, owner: &Address, id: u32) -> Result<Certification, VerifyError> {
     let users_certificates: UsersCertificates = env
         .storage()
         .instance()
@@ -18,14 +78,5 @@ pub fn verify_document_hash(
         .get(owner.clone())
         .ok_or(VerifyError::NotFound)?;
 
-    let certification = user_certificates.get(id).ok_or(VerifyError::NotFound)?;
-
-    certification.verify(submitted_hash, env.ledger().timestamp())?;
-
-    env.events().publish(
-        (Symbol::new(&env, "certification_verified"), owner.clone()),
-        env.ledger().timestamp(),
-    );
-
-    Ok(())
+    user_certificates.get(id).ok_or(VerifyError::NotFound)
 }
@@ -20,6 +20,20 @@ pub enum RedeemError {
     InsufficientQuantity = 3,
     TokenExpired = 4,
     InventoryUnderflow = 5,
+    QuantityLocked = 6,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LockError {
+    TokenNotFound = 1,
+    NotTokenOwner = 2,
+    InsufficientQuantity = 3,
+    InvalidQuantity = 4,
+    LockExists = 5,
+    LockNotFound = 6,
+    NotBeneficiary = 7,
 }
 
 // Implement From for ContractError -> RedeemError
@@ -32,3 +46,12 @@ impl From<ContractError> for RedeemError {
         }
     }
 }
+
+impl From<ContractError> for LockError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::OwnerNotFound => LockError::NotTokenOwner,
+            _ => LockError::TokenNotFound,
+        }
+    }
+}
@@ -5,6 +5,7 @@ use soroban_sdk::{
 
 mod error;
 mod issue;
+mod lock;
 mod metadata;
 mod redeem;
 mod storage;
@@ -12,6 +13,7 @@ mod validate;
 
 pub use error::*;
 pub use issue::*;
+pub use lock::*;
 pub use metadata::*;
 pub use redeem::*;
 pub use storage::*;
@@ -90,6 +92,52 @@ impl CommodityTokenContract {
         redeem::redeem_token(&env, &token_id, &redeemer, quantity)
     }
 
+    pub fn lock_for(
+        env: Env,
+        holder: Address,
+        token_id: BytesN<32>,
+        quantity: u32,
+        beneficiary_contract: Address,
+        lock_ref: BytesN<32>,
+    ) -> Result<CollateralLock, LockError> {
+        holder.require_auth();
+        lock::lock_for(
+            &env,
+            &holder,
+            &token_id,
+            quantity,
+            &beneficiary_contract,
+            &lock_ref,
+        )
+    }
+
+    pub fn get_lock(env: Env, lock_ref: BytesN<32>) -> Result<CollateralLock, LockError> {
+        lock::get_lock(&env, &lock_ref)
+    }
+
+    pub fn get_locked_quantity(env: Env, token_id: BytesN<32>) -> u32 {
+        storage::get_locked_quantity(&env, &token_id)
+    }
+
+    pub fn release_lock(
+        env: Env,
+        beneficiary_contract: Address,
+        lock_ref: BytesN<32>,
+    ) -> Result<(), LockError> {
+        beneficiary_contract.require_auth();
+        lock::release_lock(&env, &beneficiary_contract, &lock_ref)
+    }
+
+    pub fn seize_lock(
+        env: Env,
+        beneficiary_contract: Address,
+        lock_ref: BytesN<32>,
+        to: Address,
+    ) -> Result<BytesN<32>, LockError> {
+        beneficiary_contract.require_auth();
+        lock::seize_lock(&env, &beneficiary_contract, &lock_ref, &to)
+    }
+
     pub fn get_token_metadata(
         env: Env,
         token_id: BytesN<32>,
@@ -0,0 +1,151 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::{metadata, storage, LockError};
+
+/// Receipt for a quantity of a commodity token held as collateral by another contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralLock {
+    pub lock_ref: BytesN<32>,
+    pub token_id: BytesN<32>,
+    pub holder: Address,
+    pub quantity: u32,
+    pub beneficiary_contract: Address,
+    pub locked_at: u64,
+}
+
+pub fn lock_for(
+    env: &Env,
+    holder: &Address,
+    token_id: &BytesN<32>,
+    quantity: u32,
+    beneficiary_contract: &Address,
+    lock_ref: &BytesN<32>,
+) -> Result<CollateralLock, LockError> {
+    if quantity == 0 {
+        return Err(LockError::InvalidQuantity);
+    }
+    if storage::load_lock(env, lock_ref).is_some() {
+        return Err(LockError::LockExists);
+    }
+
+    let token = metadata::get_token_metadata(env, token_id)?;
+    let owner = storage::get_token_owner(env, token_id)?;
+    if owner != *holder {
+        return Err(LockError::NotTokenOwner);
+    }
+
+    // Concurrent locks on one holding can never exceed its quantity
+    let locked = storage::get_locked_quantity(env, token_id);
+    let new_locked = locked
+        .checked_add(quantity)
+        .ok_or(LockError::InsufficientQuantity)?;
+    if new_locked > token.quantity {
+        return Err(LockError::InsufficientQuantity);
+    }
+
+    let lock = CollateralLock {
+        lock_ref: lock_ref.clone(),
+        token_id: token_id.clone(),
+        holder: holder.clone(),
+        quantity,
+        beneficiary_contract: beneficiary_contract.clone(),
+        locked_at: env.ledger().timestamp(),
+    };
+    storage::store_lock(env, &lock);
+    storage::set_locked_quantity(env, token_id, new_locked);
+
+    env.events().publish(
+        (Symbol::new(env, "locked"), holder.clone()),
+        (
+            token_id.clone(),
+            lock_ref.clone(),
+            beneficiary_contract.clone(),
+            quantity,
+        ),
+    );
+
+    Ok(lock)
+}
+
+pub fn get_lock(env: &Env, lock_ref: &BytesN<32>) -> Result<CollateralLock, LockError> {
+    storage::load_lock(env, lock_ref).ok_or(LockError::LockNotFound)
+}
+
+pub fn release_lock(
+    env: &Env,
+    beneficiary_contract: &Address,
+    lock_ref: &BytesN<32>,
+) -> Result<(), LockError> {
+    let lock = take_lock(env, beneficiary_contract, lock_ref)?;
+
+    env.events().publish(
+        (Symbol::new(env, "lock_released"), lock.holder.clone()),
+        (lock.token_id, lock_ref.clone(), lock.quantity),
+    );
+
+    Ok(())
+}
+
+/// Hands the locked quantity to `to`. A lock covering the whole holding transfers
+/// ownership of the token; otherwise the quantity is split off into a new token
+/// with the same commodity details. Returns the token id now owned by `to`.
+pub fn seize_lock(
+    env: &Env,
+    beneficiary_contract: &Address,
+    lock_ref: &BytesN<32>,
+    to: &Address,
+) -> Result<BytesN<32>, LockError> {
+    let lock = take_lock(env, beneficiary_contract, lock_ref)?;
+    let mut token = metadata::get_token_metadata(env, &lock.token_id)?;
+
+    let seized_token_id = if lock.quantity == token.quantity {
+        storage::set_token_owner(env, &lock.token_id, to);
+        lock.token_id.clone()
+    } else {
+        token.quantity -= lock.quantity;
+        storage::store_token(env, &lock.token_id, &token);
+
+        let mut seized = token.clone();
+        seized.quantity = lock.quantity;
+        let mut seed = Bytes::from_array(env, &lock.token_id.to_array());
+        seed.extend_from_array(&lock_ref.to_array());
+        let seized_token_id: BytesN<32> = env.crypto().sha256(&seed).into();
+
+        storage::store_token(env, &seized_token_id, &seized);
+        storage::set_token_owner(env, &seized_token_id, to);
+        metadata::add_to_commodity_index(env, &seized.commodity_type, &seized_token_id);
+        seized_token_id
+    };
+
+    env.events().publish(
+        (Symbol::new(env, "lock_seized"), lock.holder.clone()),
+        (
+            lock.token_id,
+            lock_ref.clone(),
+            to.clone(),
+            seized_token_id.clone(),
+            lock.quantity,
+        ),
+    );
+
+    Ok(seized_token_id)
+}
+
+// Removes a lock on behalf of its beneficiary, returning the locked quantity to the holding.
+fn take_lock(
+    env: &Env,
+    beneficiary_contract: &Address,
+    lock_ref: &BytesN<32>,
+) -> Result<CollateralLock, LockError> {
+    let lock = get_lock(env, lock_ref)?;
+    if lock.beneficiary_contract != *beneficiary_contract {
+        return Err(LockError::NotBeneficiary);
+    }
+
+    let locked = storage::get_locked_quantity(env, &lock.token_id);
+    storage::set_locked_quantity(env, &lock.token_id, locked.saturating_sub(lock.quantity));
+    storage::remove_lock(env, lock_ref);
+
+    Ok(lock)
+}
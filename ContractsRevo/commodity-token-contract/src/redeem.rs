@@ -32,6 +32,11 @@ pub fn redeem_token(
         return Err(RedeemError::InsufficientQuantity);
     }
 
+    // Quantity held as collateral cannot be redeemed
+    if quantity > token.quantity - storage::get_locked_quantity(env, token_id) {
+        return Err(RedeemError::QuantityLocked);
+    }
+
     // Check if token has expired
     let current_time = env.ledger().timestamp();
     if current_time > token.expiration_date {
@@ -1,4 +1,4 @@
-use crate::{CommodityBackedToken, ContractError, Inventory};
+use crate::{CollateralLock, CommodityBackedToken, ContractError, Inventory};
 use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 #[contracttype]
@@ -12,6 +12,8 @@ pub enum DataKey {
     VerificationReg(String),
     CommodityIndex(String),
     TokenNonce,
+    Lock(BytesN<32>),
+    LockedQuantity(BytesN<32>),
 }
 
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -97,6 +99,35 @@ pub fn get_token_owner(env: &Env, token_id: &BytesN<32>) -> Result<Address, Cont
         .ok_or(ContractError::OwnerNotFound)
 }
 
+pub fn store_lock(env: &Env, lock: &CollateralLock) {
+    let key = DataKey::Lock(lock.lock_ref.clone());
+    env.storage().instance().set(&key, lock);
+}
+
+pub fn load_lock(env: &Env, lock_ref: &BytesN<32>) -> Option<CollateralLock> {
+    let key = DataKey::Lock(lock_ref.clone());
+    env.storage().instance().get(&key)
+}
+
+pub fn remove_lock(env: &Env, lock_ref: &BytesN<32>) {
+    let key = DataKey::Lock(lock_ref.clone());
+    env.storage().instance().remove(&key);
+}
+
+pub fn get_locked_quantity(env: &Env, token_id: &BytesN<32>) -> u32 {
+    let key = DataKey::LockedQuantity(token_id.clone());
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn set_locked_quantity(env: &Env, token_id: &BytesN<32>, quantity: u32) {
+    let key = DataKey::LockedQuantity(token_id.clone());
+    if quantity == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &quantity);
+    }
+}
+
 pub fn get_inventory(env: &Env, commodity_type: &String) -> Inventory {
     let key = DataKey::Inventory(commodity_type.clone());
     env.storage()
@@ -0,0 +1,163 @@
+#![cfg(test)]
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+use crate::tests::utils::TestContext;
+use crate::{CommodityTokenContractClient, LockError, RedeemError};
+
+fn lock_ref(ctx: &TestContext, n: u8) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[n; 32])
+}
+
+fn setup_holding(ctx: &TestContext, qty: u32) -> BytesN<32> {
+    ctx.init_with_admin();
+    ctx.add_inventory("MAIZE", 1000);
+    let verification = ctx.register_verification("MAIZE", [6u8; 32]);
+    ctx.issue_token(&ctx.admin, "MAIZE", qty, "A", "WH", 3600, &verification)
+}
+
+#[test]
+fn lock_blocks_redemption_of_locked_quantity() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let lender = Address::generate(&ctx.env);
+
+    let lock = client.lock_for(&ctx.admin, &token_id, &60, &lender, &lock_ref(&ctx, 1));
+    assert_eq!(lock.quantity, 60);
+    assert_eq!(lock.beneficiary_contract, lender);
+    assert_eq!(client.get_lock(&lock_ref(&ctx, 1)), lock);
+    assert_eq!(client.get_locked_quantity(&token_id), 60);
+
+    // Only the unlocked 40 can be redeemed
+    assert_eq!(
+        client.try_redeem_token(&token_id, &ctx.admin, &50),
+        Err(Ok(RedeemError::QuantityLocked))
+    );
+    client.redeem_token(&token_id, &ctx.admin, &40);
+    assert_eq!(client.get_token_metadata(&token_id).quantity, 60);
+
+    // Releasing the lock frees the quantity again
+    client.release_lock(&lender, &lock_ref(&ctx, 1));
+    assert_eq!(client.get_locked_quantity(&token_id), 0);
+    assert_eq!(
+        client.try_get_lock(&lock_ref(&ctx, 1)),
+        Err(Ok(LockError::LockNotFound))
+    );
+    client.redeem_token(&token_id, &ctx.admin, &60);
+}
+
+#[test]
+fn only_beneficiary_can_release_or_seize() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let lender = Address::generate(&ctx.env);
+    let other = Address::generate(&ctx.env);
+
+    client.lock_for(&ctx.admin, &token_id, &30, &lender, &lock_ref(&ctx, 1));
+
+    assert_eq!(
+        client.try_release_lock(&ctx.admin, &lock_ref(&ctx, 1)),
+        Err(Ok(LockError::NotBeneficiary))
+    );
+    assert_eq!(
+        client.try_seize_lock(&other, &lock_ref(&ctx, 1), &other),
+        Err(Ok(LockError::NotBeneficiary))
+    );
+    assert_eq!(client.get_locked_quantity(&token_id), 30);
+    assert_eq!(
+        client.try_release_lock(&lender, &lock_ref(&ctx, 9)),
+        Err(Ok(LockError::LockNotFound))
+    );
+}
+
+#[test]
+fn seizing_partial_lock_splits_holding() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let lender = Address::generate(&ctx.env);
+    let recipient = Address::generate(&ctx.env);
+
+    client.lock_for(&ctx.admin, &token_id, &30, &lender, &lock_ref(&ctx, 1));
+    let seized_id = client.seize_lock(&lender, &lock_ref(&ctx, 1), &recipient);
+    assert_ne!(seized_id, token_id);
+
+    let seized = client.get_token_metadata(&seized_id);
+    assert_eq!(seized.quantity, 30);
+    assert_eq!(client.get_token_metadata(&token_id).quantity, 70);
+    assert_eq!(client.get_locked_quantity(&token_id), 0);
+
+    // The recipient now owns and can redeem the seized quantity
+    client.redeem_token(&seized_id, &recipient, &30);
+    assert_eq!(
+        client.try_redeem_token(&token_id, &recipient, &10),
+        Err(Ok(RedeemError::NotTokenOwner))
+    );
+    assert_eq!(ctx.get_inventory("MAIZE").issued_tokens, 70);
+}
+
+#[test]
+fn seizing_whole_holding_transfers_ownership() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let insurer = Address::generate(&ctx.env);
+    let recipient = Address::generate(&ctx.env);
+
+    client.lock_for(&ctx.admin, &token_id, &100, &insurer, &lock_ref(&ctx, 1));
+    assert_eq!(
+        client.seize_lock(&insurer, &lock_ref(&ctx, 1), &recipient),
+        token_id
+    );
+
+    assert_eq!(
+        client.try_redeem_token(&token_id, &ctx.admin, &10),
+        Err(Ok(RedeemError::NotTokenOwner))
+    );
+    client.redeem_token(&token_id, &recipient, &100);
+}
+
+#[test]
+fn concurrent_locks_bounded_by_quantity() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let lender = Address::generate(&ctx.env);
+    let insurer = Address::generate(&ctx.env);
+
+    client.lock_for(&ctx.admin, &token_id, &50, &lender, &lock_ref(&ctx, 1));
+    client.lock_for(&ctx.admin, &token_id, &40, &insurer, &lock_ref(&ctx, 2));
+    assert_eq!(client.get_locked_quantity(&token_id), 90);
+
+    assert_eq!(
+        client.try_lock_for(&ctx.admin, &token_id, &11, &lender, &lock_ref(&ctx, 3)),
+        Err(Ok(LockError::InsufficientQuantity))
+    );
+    assert_eq!(
+        client.try_lock_for(&ctx.admin, &token_id, &5, &lender, &lock_ref(&ctx, 1)),
+        Err(Ok(LockError::LockExists))
+    );
+
+    // Releasing one lock makes room for another
+    client.release_lock(&insurer, &lock_ref(&ctx, 2));
+    client.lock_for(&ctx.admin, &token_id, &50, &insurer, &lock_ref(&ctx, 3));
+    assert_eq!(client.get_locked_quantity(&token_id), 100);
+}
+
+#[test]
+fn only_owner_can_lock() {
+    let ctx = TestContext::new();
+    let client = CommodityTokenContractClient::new(&ctx.env, &ctx.contract_id);
+    let token_id = setup_holding(&ctx, 100);
+    let stranger = Address::generate(&ctx.env);
+
+    assert_eq!(
+        client.try_lock_for(&stranger, &token_id, &10, &stranger, &lock_ref(&ctx, 1)),
+        Err(Ok(LockError::NotTokenOwner))
+    );
+    assert_eq!(
+        client.try_lock_for(&ctx.admin, &token_id, &0, &stranger, &lock_ref(&ctx, 1)),
+        Err(Ok(LockError::InvalidQuantity))
+    );
+}
@@ -1,5 +1,6 @@
 #![cfg(test)]
 mod balance;
+mod lock;
 mod token;
 mod transfer;
 pub mod utils;
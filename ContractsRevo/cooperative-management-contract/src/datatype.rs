@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[derive(Debug, PartialEq)]
 #[contracterror]
@@ -15,6 +15,35 @@ pub enum CooperativeError {
     ProposalRejected = 10,
     InsufficientFunds = 11,
     InvalidInput = 12,
+    MemberNotVerified = 13,
+    AlreadyVoted = 14,
+    VotingClosed = 15,
+    VotingStillOpen = 16,
+    ProposalNotActive = 17,
+    NoVotingPower = 18,
+    MemberSuspended = 19,
+    ApplicationNotFound = 20,
+    InvalidMemberStatus = 21,
+    ResourceAlreadyExists = 22,
+    BookingNotFound = 23,
+    InvalidBookingStatus = 24,
+    BookingStarted = 25,
+    RoundNotFound = 26,
+    InvalidRoundStatus = 27,
+    AlreadyClaimed = 28,
+    NothingToClaim = 29,
+    ClaimPeriodActive = 30,
+    NotASigner = 31,
+    ActionNotFound = 32,
+    ActionExpired = 33,
+    ActionNotPending = 34,
+    InsufficientApprovals = 35,
+    AlreadyApproved = 36,
+    CooperativeAlreadyRegistered = 37,
+    CooperativeNotRegistered = 38,
+    AttestationNotFound = 39,
+    AlreadyInitialized = 40,
+    CapitalTokenNotSet = 41,
 }
 
 #[derive(Debug)]
@@ -26,12 +55,53 @@ pub enum DataKey {
     ResourceCounter,
     OwnerResources(Address),
     MaintenanceLog(Address),
+    /// Amounts recorded before investments had to be paid in; no longer counted
     Investment(Address),
     Balance(Address),
     Expense(Address),
     Proposal(Address),
     Emergency,
     Reputation(Address),
+    VerifiedMemberCount,
+    GovernanceConfig,
+    ProposalCounter,
+    GovernanceProposal(u64),
+    Vote(u64, Address),
+    ApprovedDistribution(u64),
+    MemberStatus(Address),
+    /// Position in the member index -> address, one entry per member ever seen
+    MemberAt(u32),
+    MemberIndexed(Address),
+    MemberCount,
+    Application(Address),
+    MembershipApproval,
+    ExitSettlement(Address),
+    SharedResource(Symbol),
+    ResourceBookings(Symbol),
+    Booking(u64),
+    BookingCounter,
+    MemberBookings(Address),
+    FeeRevenue,
+    Patronage(Address),
+    RoundCounter,
+    DistributionRound(u64),
+    Allocation(u64, Address),
+    AllocationClaimed(u64, Address),
+    TreasurySigners,
+    TreasuryThreshold,
+    TreasuryActionCounter,
+    TreasuryAction(u64),
+    /// Number of treasury audit entries recorded so far
+    TreasuryHistoryCount,
+    TreasuryHistoryEntry(u64),
+    Budget(Symbol),
+    Cooperative(Address),
+    MembershipAttestation(Address, Address),
+    /// Token members pay capital in through `pool_investment`
+    CapitalToken,
+    /// Capital a member has paid in and the contract still holds
+    Capital(Address),
+    TotalCapital,
 }
 
 #[contracttype]
@@ -44,6 +114,47 @@ pub struct Member {
     pub verified: bool,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum MemberStatus {
+    /// Registered or applied, waiting for verification or approval
+    Pending = 0,
+    Active = 1,
+    /// Keeps the membership but loses voting and resource-sharing rights
+    Suspended = 2,
+    Rejected = 3,
+    Exited = 4,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ApprovalMode {
+    /// The admin approves or rejects applications directly
+    Admin = 0,
+    /// Applications are decided by `ApproveMember` / `RejectMember` proposals
+    Governance = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct MembershipApplication {
+    pub applicant: Address,
+    pub application_hash: BytesN<32>,
+    pub applied_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ExitSettlement {
+    pub member: Address,
+    /// Capital pooled through `pool_investment`
+    pub capital: i128,
+    /// Undistributed balance held for the member
+    pub balance: i128,
+    pub total: i128,
+    pub settled_at: u64,
+}
+
 #[contracttype]
 pub struct Resource {
     pub owner: Address,
@@ -53,6 +164,134 @@ pub struct Resource {
     pub schedule: Vec<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct SharedResource {
+    pub resource_id: Symbol,
+    pub name: String,
+    pub usage_fee_per_hour: i128,
+    /// Confirms each completed booking and the condition the asset came back in
+    pub custodian: Address,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum BookingStatus {
+    Booked = 0,
+    Cancelled = 1,
+    Completed = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Booking {
+    pub id: u64,
+    pub resource_id: Symbol,
+    pub member: Address,
+    pub start: u64,
+    pub end: u64,
+    /// Charged to the member's internal balance at booking time
+    pub fee: i128,
+    pub status: BookingStatus,
+    pub condition_notes_hash: Option<BytesN<32>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum DistributionWeighting {
+    Equal = 0,
+    /// Proportional to capital pooled through `pool_investment`
+    ByCapitalShare = 1,
+    /// Proportional to patronage points from recorded deliveries and completed resource usage
+    ByPatronage = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum RoundStatus {
+    Open = 0,
+    Closed = 1,
+    Swept = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DistributionRound {
+    pub id: u64,
+    pub token: Address,
+    pub total_amount: i128,
+    pub weighting: DistributionWeighting,
+    pub status: RoundStatus,
+    /// Sum of the member allocations computed at close
+    pub allocated: i128,
+    pub claimed: i128,
+    /// Left over from rounding allocations down; returned to the treasury with the sweep
+    pub remainder: i128,
+    pub claim_deadline: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum TreasuryAction {
+    /// Token, recipient and amount paid out of the contract's holdings
+    Transfer(Address, Address, i128),
+    /// Budget category and the amount approved for it
+    ApproveBudget(Symbol, i128),
+    /// New signer set and approval threshold
+    UpdateSigners(Vec<Address>, u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum TreasuryActionStatus {
+    Pending = 0,
+    Executed = 1,
+    Expired = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TreasuryProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: TreasuryAction,
+    pub approvals: Vec<Address>,
+    pub status: TreasuryActionStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TreasuryAuditEntry {
+    pub action_id: u64,
+    /// `proposed`, `approved` or `executed`
+    pub event: Symbol,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Cooperative {
+    /// Account that acts for the cooperative, including signing attestations
+    pub address: Address,
+    pub name: String,
+    pub region: Symbol,
+    pub registered_at: u64,
+    pub active: bool,
+    pub deregistration_reason: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct MembershipAttestation {
+    pub cooperative: Address,
+    pub member: Address,
+    pub role: Symbol,
+    pub attested_at: u64,
+}
+
 #[contracttype]
 pub enum RecordType {
     Expense,
@@ -75,3 +314,61 @@ pub struct Proposal {
     pub votes_against: u32,
     pub executed: bool,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum VotingMode {
+    /// Every verified member carries one vote
+    OneMemberOneVote = 0,
+    /// Votes are weighted by the capital a member has pooled into the cooperative
+    ShareWeighted = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct GovernanceConfig {
+    pub voting_mode: VotingMode,
+    /// Share of the total voting weight that has to take part, in basis points
+    pub quorum_bps: u32,
+    /// Share of the cast weight that has to be in favour, in basis points (strictly above)
+    pub threshold_bps: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ProposalAction {
+    /// Non-binding decision, nothing runs on execution
+    Signal,
+    UpdateGovernanceConfig(GovernanceConfig),
+    ApproveProfitDistribution(i128),
+    ApproveMember(Address),
+    RejectMember(Address),
+    /// Resource id, name and hourly usage fee; the admin becomes the custodian
+    RegisterResource(Symbol, String, i128),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ProposalStatus {
+    Active = 0,
+    Executed = 1,
+    Rejected = 2,
+    QuorumNotMet = 3,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct GovernanceProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub title: String,
+    pub description_hash: BytesN<32>,
+    pub action: ProposalAction,
+    pub voting_deadline: u64,
+    pub voting_mode: VotingMode,
+    /// Voting weight of the whole cooperative when the proposal was created
+    pub total_weight: i128,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub status: ProposalStatus,
+}
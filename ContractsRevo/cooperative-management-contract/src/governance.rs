@@ -1,10 +1,118 @@
-use crate::datatype::{CooperativeError, DataKey, Proposal};
+use crate::datatype::{
+    CooperativeError, DataKey, GovernanceConfig, GovernanceProposal, Member, MemberStatus,
+    Proposal, ProposalAction, ProposalStatus, VotingMode,
+};
 use crate::interface::Governance;
+use crate::membership::{admit_member, decline_member, member_status, require_admin};
+use crate::resource_sharing::add_shared_resource;
 use crate::{
     CooperativeManagementContract, CooperativeManagementContractArgs,
     CooperativeManagementContractClient,
 };
-use soroban_sdk::{contractimpl, Address, Env, String};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, String, Vec};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+fn default_config() -> GovernanceConfig {
+    GovernanceConfig {
+        voting_mode: VotingMode::OneMemberOneVote,
+        quorum_bps: 5_000,
+        threshold_bps: 5_000,
+    }
+}
+
+fn load_config(env: &Env) -> GovernanceConfig {
+    env.storage()
+        .persistent()
+        .get::<DataKey, GovernanceConfig>(&DataKey::GovernanceConfig)
+        .unwrap_or(default_config())
+}
+
+fn validate_config(config: &GovernanceConfig) -> Result<(), CooperativeError> {
+    if config.quorum_bps == 0
+        || config.quorum_bps > BPS_DENOMINATOR as u32
+        || config.threshold_bps >= BPS_DENOMINATOR as u32
+    {
+        return Err(CooperativeError::InvalidInput);
+    }
+    Ok(())
+}
+
+fn verified_member(env: &Env, address: &Address) -> Result<Member, CooperativeError> {
+    let member = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Member>(&DataKey::Member(address.clone()))
+        .ok_or(CooperativeError::NotAMember)?;
+    if !member.verified {
+        return Err(CooperativeError::MemberNotVerified);
+    }
+    if member_status(env, address) == Some(MemberStatus::Suspended) {
+        return Err(CooperativeError::MemberSuspended);
+    }
+    Ok(member)
+}
+
+/// Weight of the whole cooperative under `mode`
+fn total_weight(env: &Env, mode: VotingMode) -> i128 {
+    match mode {
+        VotingMode::OneMemberOneVote => env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&DataKey::VerifiedMemberCount)
+            .unwrap_or(0) as i128,
+        VotingMode::ShareWeighted => env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::TotalCapital)
+            .unwrap_or(0),
+    }
+}
+
+fn member_weight(env: &Env, member: &Address, mode: VotingMode) -> i128 {
+    match mode {
+        VotingMode::OneMemberOneVote => 1,
+        VotingMode::ShareWeighted => env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Capital(member.clone()))
+            .unwrap_or(0),
+    }
+}
+
+fn execute_action(env: &Env, proposal: &GovernanceProposal) -> Result<(), CooperativeError> {
+    match &proposal.action {
+        ProposalAction::Signal => {}
+        ProposalAction::UpdateGovernanceConfig(config) => {
+            validate_config(config)?;
+            env.storage()
+                .persistent()
+                .set(&DataKey::GovernanceConfig, config);
+        }
+        ProposalAction::ApproveProfitDistribution(amount) => {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ApprovedDistribution(proposal.id), amount);
+        }
+        ProposalAction::ApproveMember(applicant) => admit_member(env, applicant)?,
+        ProposalAction::RejectMember(applicant) => decline_member(env, applicant)?,
+        ProposalAction::RegisterResource(resource_id, name, usage_fee_per_hour) => {
+            let custodian = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::Admin)
+                .ok_or(CooperativeError::Unauthorized)?;
+            add_shared_resource(
+                env,
+                custodian,
+                resource_id.clone(),
+                name.clone(),
+                *usage_fee_per_hour,
+            )?;
+        }
+    }
+    Ok(())
+}
 
 #[contractimpl]
 impl Governance for CooperativeManagementContract {
@@ -107,4 +215,195 @@ impl Governance for CooperativeManagementContract {
             .unwrap_or(0);
         Ok(reputation)
     }
+
+    fn set_governance_config(
+        env: Env,
+        admin: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        validate_config(&config)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GovernanceConfig, &config);
+        Ok(())
+    }
+
+    fn get_governance_config(env: Env) -> GovernanceConfig {
+        load_config(&env)
+    }
+
+    fn create_proposal(
+        env: Env,
+        proposer: Address,
+        title: String,
+        description_hash: BytesN<32>,
+        action: ProposalAction,
+        voting_deadline: u64,
+    ) -> Result<u64, CooperativeError> {
+        proposer.require_auth();
+        verified_member(&env, &proposer)?;
+
+        if voting_deadline <= env.ledger().timestamp() {
+            return Err(CooperativeError::InvalidInput);
+        }
+        if let ProposalAction::UpdateGovernanceConfig(config) = &action {
+            validate_config(config)?;
+        }
+
+        let id = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::ProposalCounter)
+            .unwrap_or(0)
+            + 1;
+
+        // The voting mode and the cooperative's weight are fixed for the life of the proposal
+        let voting_mode = load_config(&env).voting_mode;
+        let proposal = GovernanceProposal {
+            id,
+            proposer,
+            title,
+            description_hash,
+            action,
+            voting_deadline,
+            voting_mode,
+            total_weight: total_weight(&env, voting_mode),
+            votes_for: 0,
+            votes_against: 0,
+            status: ProposalStatus::Active,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::GovernanceProposal(id), &proposal);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalCounter, &id);
+        Ok(id)
+    }
+
+    fn vote(
+        env: Env,
+        member: Address,
+        proposal_id: u64,
+        support: bool,
+    ) -> Result<(), CooperativeError> {
+        member.require_auth();
+        verified_member(&env, &member)?;
+
+        let key = DataKey::GovernanceProposal(proposal_id);
+        let mut proposal = env
+            .storage()
+            .persistent()
+            .get::<DataKey, GovernanceProposal>(&key)
+            .ok_or(CooperativeError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active
+            || env.ledger().timestamp() >= proposal.voting_deadline
+        {
+            return Err(CooperativeError::VotingClosed);
+        }
+
+        let vote_key = DataKey::Vote(proposal_id, member.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(CooperativeError::AlreadyVoted);
+        }
+
+        let weight = member_weight(&env, &member, proposal.voting_mode);
+        if weight <= 0 {
+            return Err(CooperativeError::NoVotingPower);
+        }
+
+        if support {
+            proposal.votes_for += weight;
+        } else {
+            proposal.votes_against += weight;
+        }
+        env.storage().persistent().set(&vote_key, &support);
+        env.storage().persistent().set(&key, &proposal);
+        Ok(())
+    }
+
+    fn finalize_proposal(env: Env, proposal_id: u64) -> Result<ProposalStatus, CooperativeError> {
+        let key = DataKey::GovernanceProposal(proposal_id);
+        let mut proposal = env
+            .storage()
+            .persistent()
+            .get::<DataKey, GovernanceProposal>(&key)
+            .ok_or(CooperativeError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(CooperativeError::ProposalNotActive);
+        }
+        if env.ledger().timestamp() < proposal.voting_deadline {
+            return Err(CooperativeError::VotingStillOpen);
+        }
+
+        let config = load_config(&env);
+        let cast = proposal.votes_for + proposal.votes_against;
+
+        proposal.status = if cast == 0
+            || cast * BPS_DENOMINATOR < proposal.total_weight * config.quorum_bps as i128
+        {
+            ProposalStatus::QuorumNotMet
+        } else if proposal.votes_for * BPS_DENOMINATOR > cast * config.threshold_bps as i128 {
+            execute_action(&env, &proposal)?;
+            ProposalStatus::Executed
+        } else {
+            ProposalStatus::Rejected
+        };
+
+        env.storage().persistent().set(&key, &proposal);
+        Ok(proposal.status)
+    }
+
+    fn get_proposal(env: Env, proposal_id: u64) -> Result<GovernanceProposal, CooperativeError> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, GovernanceProposal>(&DataKey::GovernanceProposal(proposal_id))
+            .ok_or(CooperativeError::ProposalNotFound)
+    }
+
+    fn list_proposals(
+        env: Env,
+        status: ProposalStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GovernanceProposal> {
+        let count = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::ProposalCounter)
+            .unwrap_or(0);
+
+        let mut proposals = Vec::new(&env);
+        let mut skipped = 0;
+        for id in 1..=count {
+            if proposals.len() >= limit {
+                break;
+            }
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, GovernanceProposal>(&DataKey::GovernanceProposal(id))
+            {
+                if proposal.status != status {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                proposals.push_back(proposal);
+            }
+        }
+        proposals
+    }
+
+    fn get_approved_distribution(env: Env, proposal_id: u64) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::ApprovedDistribution(proposal_id))
+    }
 }
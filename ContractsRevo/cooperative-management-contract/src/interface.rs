@@ -1,5 +1,10 @@
-use crate::datatype::CooperativeError;
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use crate::datatype::{
+    ApprovalMode, Booking, Cooperative, CooperativeError, DistributionRound, DistributionWeighting,
+    ExitSettlement, GovernanceConfig, GovernanceProposal, MemberStatus, MembershipAttestation,
+    ProposalAction, ProposalStatus, SharedResource, TreasuryAction, TreasuryAuditEntry,
+    TreasuryProposal,
+};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, Vec};
 
 #[allow(dead_code)]
 pub trait Membership {
@@ -17,6 +22,26 @@ pub trait Membership {
         address: Address,
         points: u32,
     ) -> Result<(), CooperativeError>;
+    fn set_membership_approval(
+        env: Env,
+        admin: Address,
+        mode: ApprovalMode,
+    ) -> Result<(), CooperativeError>;
+    fn apply_for_membership(
+        env: Env,
+        applicant: Address,
+        application_hash: BytesN<32>,
+    ) -> Result<(), CooperativeError>;
+    fn approve_member(env: Env, admin: Address, applicant: Address)
+        -> Result<(), CooperativeError>;
+    fn reject_member(env: Env, admin: Address, applicant: Address) -> Result<(), CooperativeError>;
+    fn suspend_member(env: Env, admin: Address, member: Address) -> Result<(), CooperativeError>;
+    fn reinstate_member(env: Env, admin: Address, member: Address) -> Result<(), CooperativeError>;
+    fn exit_membership(env: Env, member: Address) -> Result<ExitSettlement, CooperativeError>;
+    fn get_member_status(env: Env, address: Address) -> Result<MemberStatus, CooperativeError>;
+    fn list_members(env: Env, status_filter: MemberStatus, offset: u32, limit: u32)
+        -> Vec<Address>;
+    fn get_exit_settlement(env: Env, address: Address) -> Option<ExitSettlement>;
 }
 
 #[allow(dead_code)]
@@ -53,6 +78,35 @@ pub trait ResourceSharing {
         resource_id: u32,
         details: String,
     ) -> Result<(), CooperativeError>;
+    fn register_shared_resource(
+        env: Env,
+        admin: Address,
+        resource_id: Symbol,
+        name: String,
+        usage_fee_per_hour: i128,
+    ) -> Result<(), CooperativeError>;
+    fn get_shared_resource(
+        env: Env,
+        resource_id: Symbol,
+    ) -> Result<SharedResource, CooperativeError>;
+    fn book_resource(
+        env: Env,
+        member: Address,
+        resource_id: Symbol,
+        start: u64,
+        end: u64,
+    ) -> Result<u64, CooperativeError>;
+    fn cancel_booking(env: Env, member: Address, booking_id: u64)
+        -> Result<i128, CooperativeError>;
+    fn record_usage_completion(
+        env: Env,
+        custodian: Address,
+        booking_id: u64,
+        condition_notes_hash: BytesN<32>,
+    ) -> Result<(), CooperativeError>;
+    fn get_booking(env: Env, booking_id: u64) -> Result<Booking, CooperativeError>;
+    fn get_resource_schedule(env: Env, resource_id: Symbol, from: u64, to: u64) -> Vec<Booking>;
+    fn get_usage_history(env: Env, member: Address) -> Vec<Booking>;
 }
 
 #[allow(dead_code)]
@@ -68,11 +122,45 @@ pub trait ProfitDistribution {
         members: Vec<Address>,
     ) -> Result<Map<Address, i128>, CooperativeError>;
     fn pool_investment(env: Env, investor: Address, amount: i128) -> Result<(), CooperativeError>;
+    fn set_capital_token(env: Env, admin: Address, token: Address) -> Result<(), CooperativeError>;
+    fn get_capital_token(env: Env) -> Option<Address>;
+    fn get_member_capital(env: Env, member: Address) -> i128;
     fn process_automated_payments(
         env: Env,
         members: Vec<Address>,
         amount: i128,
     ) -> Result<(), CooperativeError>;
+    fn credit_member_balance(
+        env: Env,
+        admin: Address,
+        member: Address,
+        amount: i128,
+    ) -> Result<(), CooperativeError>;
+    fn get_member_balance(env: Env, member: Address) -> i128;
+    fn get_fee_revenue(env: Env) -> i128;
+    fn record_patronage(
+        env: Env,
+        admin: Address,
+        member: Address,
+        points: i128,
+    ) -> Result<(), CooperativeError>;
+    fn get_patronage(env: Env, member: Address) -> i128;
+    fn open_distribution_round(
+        env: Env,
+        admin: Address,
+        total_amount: i128,
+        token: Address,
+        weighting: DistributionWeighting,
+    ) -> Result<u64, CooperativeError>;
+    fn close_round(env: Env, admin: Address, round_id: u64) -> Result<(), CooperativeError>;
+    fn claim_distribution(
+        env: Env,
+        member: Address,
+        round_id: u64,
+    ) -> Result<i128, CooperativeError>;
+    fn sweep_unclaimed(env: Env, admin: Address, round_id: u64) -> Result<i128, CooperativeError>;
+    fn get_round(env: Env, round_id: u64) -> Result<DistributionRound, CooperativeError>;
+    fn get_member_allocation(env: Env, round_id: u64, member: Address) -> i128;
 }
 
 #[allow(dead_code)]
@@ -92,4 +180,91 @@ pub trait Governance {
     fn trigger_emergency(env: Env, caller: Address, reason: String)
         -> Result<(), CooperativeError>;
     fn track_accountability(env: Env, member: Address) -> Result<i128, CooperativeError>;
+    fn set_governance_config(
+        env: Env,
+        admin: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), CooperativeError>;
+    fn get_governance_config(env: Env) -> GovernanceConfig;
+    fn create_proposal(
+        env: Env,
+        proposer: Address,
+        title: String,
+        description_hash: BytesN<32>,
+        action: ProposalAction,
+        voting_deadline: u64,
+    ) -> Result<u64, CooperativeError>;
+    fn vote(
+        env: Env,
+        member: Address,
+        proposal_id: u64,
+        support: bool,
+    ) -> Result<(), CooperativeError>;
+    fn finalize_proposal(env: Env, proposal_id: u64) -> Result<ProposalStatus, CooperativeError>;
+    fn get_proposal(env: Env, proposal_id: u64) -> Result<GovernanceProposal, CooperativeError>;
+    fn list_proposals(
+        env: Env,
+        status: ProposalStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GovernanceProposal>;
+    fn get_approved_distribution(env: Env, proposal_id: u64) -> Option<i128>;
+}
+
+#[allow(dead_code)]
+pub trait Treasury {
+    fn set_treasury_signers(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), CooperativeError>;
+    fn get_treasury_signers(env: Env) -> (Vec<Address>, u32);
+    fn propose_treasury_action(
+        env: Env,
+        proposer: Address,
+        action: TreasuryAction,
+    ) -> Result<u64, CooperativeError>;
+    fn approve_action(env: Env, signer: Address, action_id: u64) -> Result<(), CooperativeError>;
+    fn execute_action(env: Env, signer: Address, action_id: u64) -> Result<(), CooperativeError>;
+    fn get_treasury_action(env: Env, action_id: u64) -> Result<TreasuryProposal, CooperativeError>;
+    fn get_pending_actions(env: Env) -> Vec<TreasuryProposal>;
+    fn get_treasury_history(env: Env, offset: u32, limit: u32) -> Vec<TreasuryAuditEntry>;
+    fn get_budget(env: Env, category: Symbol) -> i128;
+}
+
+#[allow(dead_code)]
+pub trait Registry {
+    fn register_cooperative(
+        env: Env,
+        admin: Address,
+        coop_address: Address,
+        name: String,
+        region: Symbol,
+    ) -> Result<(), CooperativeError>;
+    fn deregister_cooperative(
+        env: Env,
+        admin: Address,
+        coop_address: Address,
+        reason: String,
+    ) -> Result<(), CooperativeError>;
+    fn is_registered_cooperative(env: Env, address: Address) -> bool;
+    fn get_cooperative(env: Env, address: Address) -> Result<Cooperative, CooperativeError>;
+    fn attest_membership(
+        env: Env,
+        coop_admin: Address,
+        member: Address,
+        role: Symbol,
+    ) -> Result<(), CooperativeError>;
+    fn revoke_membership_attestation(
+        env: Env,
+        coop_admin: Address,
+        member: Address,
+    ) -> Result<(), CooperativeError>;
+    fn get_membership_attestation(
+        env: Env,
+        coop: Address,
+        member: Address,
+    ) -> Option<MembershipAttestation>;
+    fn is_member_of(env: Env, coop: Address, member: Address) -> bool;
 }
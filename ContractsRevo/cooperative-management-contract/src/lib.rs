@@ -1,6 +1,6 @@
 #![no_std]
 
-use datatype::DataKey;
+use datatype::{CooperativeError, DataKey};
 // Traits are implemented in separate modules
 use soroban_sdk::{contract, contractimpl, Address, Env};
 
@@ -9,7 +9,9 @@ mod governance;
 mod interface;
 mod membership;
 mod profit_distribution;
+mod registry;
 mod resource_sharing;
+mod treasury;
 
 #[cfg(test)]
 mod original_tests;
@@ -22,11 +24,12 @@ pub struct CooperativeManagementContract;
 
 #[contractimpl]
 impl CooperativeManagementContract {
-    pub fn init(env: Env, admin: Address) {
+    pub fn init(env: Env, admin: Address) -> Result<(), CooperativeError> {
         admin.require_auth();
         if env.storage().persistent().has(&DataKey::Admin) {
-            panic!("Contract is already initialized");
+            return Err(CooperativeError::AlreadyInitialized);
         }
         env.storage().persistent().set(&DataKey::Admin, &admin);
+        Ok(())
     }
 }
@@ -1,10 +1,174 @@
-use crate::datatype::{CooperativeError, DataKey, Member};
+use crate::datatype::{
+    ApprovalMode, CooperativeError, DataKey, ExitSettlement, Member, MemberStatus,
+    MembershipApplication,
+};
 use crate::interface::Membership;
 use crate::{
     CooperativeManagementContract, CooperativeManagementContractArgs,
     CooperativeManagementContractClient,
 };
-use soroban_sdk::{contractimpl, Address, Env, String};
+use soroban_sdk::{contractimpl, token, Address, BytesN, Env, String, Vec};
+
+pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), CooperativeError> {
+    admin.require_auth();
+    let stored_admin = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::Admin);
+    if Some(admin.clone()) != stored_admin {
+        return Err(CooperativeError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Members stored before statuses were tracked fall back to their verification flag
+pub(crate) fn member_status(env: &Env, address: &Address) -> Option<MemberStatus> {
+    if let Some(status) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, MemberStatus>(&DataKey::MemberStatus(address.clone()))
+    {
+        return Some(status);
+    }
+    env.storage()
+        .persistent()
+        .get::<DataKey, Member>(&DataKey::Member(address.clone()))
+        .map(|member| {
+            if member.verified {
+                MemberStatus::Active
+            } else {
+                MemberStatus::Pending
+            }
+        })
+}
+
+pub(crate) fn ensure_not_suspended(env: &Env, address: &Address) -> Result<(), CooperativeError> {
+    if member_status(env, address) == Some(MemberStatus::Suspended) {
+        return Err(CooperativeError::MemberSuspended);
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_active_member(env: &Env, address: &Address) -> Result<(), CooperativeError> {
+    match member_status(env, address) {
+        Some(MemberStatus::Active) => Ok(()),
+        Some(MemberStatus::Suspended) => Err(CooperativeError::MemberSuspended),
+        _ => Err(CooperativeError::NotAMember),
+    }
+}
+
+/// Approves a pending application or registration
+pub(crate) fn admit_member(env: &Env, applicant: &Address) -> Result<(), CooperativeError> {
+    if member_status(env, applicant) != Some(MemberStatus::Pending) {
+        return Err(CooperativeError::InvalidMemberStatus);
+    }
+
+    let member_key = DataKey::Member(applicant.clone());
+    let application_key = DataKey::Application(applicant.clone());
+    let mut member = match env
+        .storage()
+        .persistent()
+        .get::<DataKey, Member>(&member_key)
+    {
+        Some(member) => member,
+        None => {
+            if !env.storage().persistent().has(&application_key) {
+                return Err(CooperativeError::ApplicationNotFound);
+            }
+            Member {
+                address: applicant.clone(),
+                name: String::from_str(env, ""),
+                role: String::from_str(env, ""),
+                reputation: 0,
+                contributions: 0,
+                verified: false,
+            }
+        }
+    };
+
+    member.verified = true;
+    env.storage().persistent().set(&member_key, &member);
+    env.storage().persistent().remove(&application_key);
+    set_status(env, applicant, MemberStatus::Active);
+    adjust_verified_count(env, 1);
+    Ok(())
+}
+
+pub(crate) fn decline_member(env: &Env, applicant: &Address) -> Result<(), CooperativeError> {
+    let application_key = DataKey::Application(applicant.clone());
+    if member_status(env, applicant) != Some(MemberStatus::Pending)
+        || !env.storage().persistent().has(&application_key)
+    {
+        return Err(CooperativeError::ApplicationNotFound);
+    }
+
+    env.storage().persistent().remove(&application_key);
+    set_status(env, applicant, MemberStatus::Rejected);
+    Ok(())
+}
+
+fn set_status(env: &Env, address: &Address, status: MemberStatus) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MemberStatus(address.clone()), &status);
+}
+
+/// Adds the address to the member index the first time it is seen
+fn index_member(env: &Env, address: &Address) {
+    let indexed_key = DataKey::MemberIndexed(address.clone());
+    if env.storage().persistent().has(&indexed_key) {
+        return;
+    }
+    let count = member_count(env);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MemberAt(count), address);
+    env.storage().persistent().set(&indexed_key, &true);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MemberCount, &(count + 1));
+}
+
+pub(crate) fn member_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<DataKey, u32>(&DataKey::MemberCount)
+        .unwrap_or(0)
+}
+
+pub(crate) fn member_at(env: &Env, index: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::MemberAt(index))
+}
+
+/// Keeps the one-member-one-vote denominator in step with the active membership
+fn adjust_verified_count(env: &Env, delta: i32) {
+    let count = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u32>(&DataKey::VerifiedMemberCount)
+        .unwrap_or(0);
+    env.storage().persistent().set(
+        &DataKey::VerifiedMemberCount,
+        &count.saturating_add_signed(delta),
+    );
+}
+
+fn approval_mode(env: &Env) -> ApprovalMode {
+    env.storage()
+        .persistent()
+        .get::<DataKey, ApprovalMode>(&DataKey::MembershipApproval)
+        .unwrap_or(ApprovalMode::Admin)
+}
+
+fn require_admin_approval(env: &Env, admin: &Address) -> Result<(), CooperativeError> {
+    require_admin(env, admin)?;
+    if approval_mode(env) != ApprovalMode::Admin {
+        return Err(CooperativeError::Unauthorized);
+    }
+    Ok(())
+}
 
 #[contractimpl]
 impl Membership for CooperativeManagementContract {
@@ -31,18 +195,24 @@ impl Membership for CooperativeManagementContract {
         };
 
         env.storage().persistent().set(&key, &member);
+        set_status(&env, &address, MemberStatus::Pending);
+        index_member(&env, &address);
 
         Ok(())
     }
 
     fn verify_member(env: Env, admin: Address, address: Address) -> Result<(), CooperativeError> {
-        admin.require_auth();
+        require_admin_approval(&env, &admin)?;
         let address_key = DataKey::Member(address.clone());
         if let Some(mut member) = env
             .storage()
             .persistent()
             .get::<DataKey, Member>(&address_key)
         {
+            if !member.verified {
+                set_status(&env, &address, MemberStatus::Active);
+                adjust_verified_count(&env, 1);
+            }
             member.verified = true;
             env.storage().persistent().set(&address_key, &member);
             Ok(())
@@ -73,7 +243,7 @@ impl Membership for CooperativeManagementContract {
         points: u32,
     ) -> Result<(), CooperativeError> {
         // Ensure admin authorization
-        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         let address_key = DataKey::Member(address.clone());
         if let Some(mut member) = env
@@ -88,4 +258,181 @@ impl Membership for CooperativeManagementContract {
             Err(CooperativeError::MemberNotFound)
         }
     }
+
+    fn set_membership_approval(
+        env: Env,
+        admin: Address,
+        mode: ApprovalMode,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::MembershipApproval, &mode);
+        Ok(())
+    }
+
+    fn apply_for_membership(
+        env: Env,
+        applicant: Address,
+        application_hash: BytesN<32>,
+    ) -> Result<(), CooperativeError> {
+        applicant.require_auth();
+
+        // Rejected applicants and former members may apply again
+        match member_status(&env, &applicant) {
+            None | Some(MemberStatus::Rejected) | Some(MemberStatus::Exited) => {}
+            Some(_) => return Err(CooperativeError::MemberAlreadyExists),
+        }
+
+        let application = MembershipApplication {
+            applicant: applicant.clone(),
+            application_hash,
+            applied_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Application(applicant.clone()), &application);
+        set_status(&env, &applicant, MemberStatus::Pending);
+        index_member(&env, &applicant);
+        Ok(())
+    }
+
+    fn approve_member(
+        env: Env,
+        admin: Address,
+        applicant: Address,
+    ) -> Result<(), CooperativeError> {
+        require_admin_approval(&env, &admin)?;
+        admit_member(&env, &applicant)
+    }
+
+    fn reject_member(env: Env, admin: Address, applicant: Address) -> Result<(), CooperativeError> {
+        require_admin_approval(&env, &admin)?;
+        decline_member(&env, &applicant)
+    }
+
+    fn suspend_member(env: Env, admin: Address, member: Address) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        if member_status(&env, &member) != Some(MemberStatus::Active) {
+            return Err(CooperativeError::InvalidMemberStatus);
+        }
+
+        set_status(&env, &member, MemberStatus::Suspended);
+        adjust_verified_count(&env, -1);
+        Ok(())
+    }
+
+    fn reinstate_member(env: Env, admin: Address, member: Address) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        if member_status(&env, &member) != Some(MemberStatus::Suspended) {
+            return Err(CooperativeError::InvalidMemberStatus);
+        }
+
+        set_status(&env, &member, MemberStatus::Active);
+        adjust_verified_count(&env, 1);
+        Ok(())
+    }
+
+    fn exit_membership(env: Env, member: Address) -> Result<ExitSettlement, CooperativeError> {
+        member.require_auth();
+
+        let status = member_status(&env, &member);
+        if status != Some(MemberStatus::Active) && status != Some(MemberStatus::Suspended) {
+            return Err(CooperativeError::InvalidMemberStatus);
+        }
+
+        let capital_key = DataKey::Capital(member.clone());
+        let balance_key = DataKey::Balance(member.clone());
+        let capital = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&capital_key)
+            .unwrap_or(0);
+        let balance = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&balance_key)
+            .unwrap_or(0);
+
+        let pooled = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::TotalCapital)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TotalCapital, &(pooled - capital));
+        env.storage().persistent().remove(&capital_key);
+        env.storage().persistent().remove(&balance_key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Member(member.clone()));
+        if status == Some(MemberStatus::Active) {
+            adjust_verified_count(&env, -1);
+        }
+        set_status(&env, &member, MemberStatus::Exited);
+
+        let settlement = ExitSettlement {
+            member: member.clone(),
+            capital,
+            balance,
+            total: capital + balance,
+            settled_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ExitSettlement(member.clone()), &settlement);
+
+        // Paid-in capital goes back to the member; the balance is settled off-chain
+        if capital > 0 {
+            let capital_token = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::CapitalToken)
+                .ok_or(CooperativeError::CapitalTokenNotSet)?;
+            token::Client::new(&env, &capital_token).transfer(
+                &env.current_contract_address(),
+                &member,
+                &capital,
+            );
+        }
+        Ok(settlement)
+    }
+
+    fn get_member_status(env: Env, address: Address) -> Result<MemberStatus, CooperativeError> {
+        member_status(&env, &address).ok_or(CooperativeError::MemberNotFound)
+    }
+
+    fn list_members(
+        env: Env,
+        status_filter: MemberStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let mut page = Vec::new(&env);
+        let mut skipped = 0;
+        for index in 0..member_count(&env) {
+            let Some(address) = member_at(&env, index) else {
+                continue;
+            };
+            if page.len() >= limit {
+                break;
+            }
+            if member_status(&env, &address) != Some(status_filter) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(address);
+        }
+        page
+    }
+
+    fn get_exit_settlement(env: Env, address: Address) -> Option<ExitSettlement> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, ExitSettlement>(&DataKey::ExitSettlement(address))
+    }
 }
@@ -1,10 +1,58 @@
-use crate::datatype::{CooperativeError, DataKey};
+use crate::datatype::{
+    CooperativeError, DataKey, DistributionRound, DistributionWeighting, MemberStatus, RoundStatus,
+};
 use crate::interface::ProfitDistribution;
+use crate::membership::{member_at, member_count, member_status, require_admin};
 use crate::{
     CooperativeManagementContract, CooperativeManagementContractArgs,
     CooperativeManagementContractClient,
 };
-use soroban_sdk::{contractimpl, Address, Env, Map, Vec};
+use soroban_sdk::{contractimpl, token, Address, Env, Map, Vec};
+
+/// How long members have to claim their allocation once a round is closed
+pub const CLAIM_PERIOD: u64 = 90 * 24 * 60 * 60;
+
+pub(crate) fn add_patronage(env: &Env, member: &Address, points: i128) {
+    let key = DataKey::Patronage(member.clone());
+    let total = env
+        .storage()
+        .persistent()
+        .get::<DataKey, i128>(&key)
+        .unwrap_or(0);
+    env.storage().persistent().set(&key, &(total + points));
+}
+
+fn load_round(env: &Env, round_id: u64) -> Result<DistributionRound, CooperativeError> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, DistributionRound>(&DataKey::DistributionRound(round_id))
+        .ok_or(CooperativeError::RoundNotFound)
+}
+
+fn member_weight(env: &Env, member: &Address, weighting: DistributionWeighting) -> i128 {
+    let key = match weighting {
+        DistributionWeighting::Equal => return 1,
+        DistributionWeighting::ByCapitalShare => DataKey::Capital(member.clone()),
+        DistributionWeighting::ByPatronage => DataKey::Patronage(member.clone()),
+    };
+    env.storage()
+        .persistent()
+        .get::<DataKey, i128>(&key)
+        .unwrap_or(0)
+        .max(0)
+}
+
+fn active_members(env: &Env) -> Vec<Address> {
+    let mut active = Vec::new(env);
+    for index in 0..member_count(env) {
+        if let Some(member) = member_at(env, index) {
+            if member_status(env, &member) == Some(MemberStatus::Active) {
+                active.push_back(member);
+            }
+        }
+    }
+    active
+}
 
 #[contractimpl]
 impl ProfitDistribution for CooperativeManagementContract {
@@ -41,17 +89,73 @@ impl ProfitDistribution for CooperativeManagementContract {
     }
 
     fn pool_investment(env: Env, investor: Address, amount: i128) -> Result<(), CooperativeError> {
-        let key = DataKey::Investment(investor.clone());
-        let mut total = env
+        investor.require_auth();
+        if amount <= 0 {
+            return Err(CooperativeError::InvalidInput);
+        }
+        let capital_token = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Address>(&DataKey::CapitalToken)
+            .ok_or(CooperativeError::CapitalTokenNotSet)?;
+
+        // Capital only counts once the contract holds it
+        token::Client::new(&env, &capital_token).transfer(
+            &investor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let key = DataKey::Capital(investor.clone());
+        let total = env
             .storage()
             .persistent()
             .get::<DataKey, i128>(&key)
             .unwrap_or(0);
-        total += amount;
-        env.storage().persistent().set(&key, &total);
+        env.storage().persistent().set(&key, &(total + amount));
+
+        // Cooperative-wide capital, the denominator for share-weighted voting
+        let pooled = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::TotalCapital)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TotalCapital, &(pooled + amount));
         Ok(())
     }
 
+    fn set_capital_token(env: Env, admin: Address, token: Address) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        // Switching tokens would leave the capital already paid in unaccounted for
+        let pooled = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::TotalCapital)
+            .unwrap_or(0);
+        if pooled > 0 {
+            return Err(CooperativeError::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::CapitalToken, &token);
+        Ok(())
+    }
+
+    fn get_capital_token(env: Env) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, Address>(&DataKey::CapitalToken)
+    }
+
+    fn get_member_capital(env: Env, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Capital(member))
+            .unwrap_or(0)
+    }
+
     fn process_automated_payments(
         env: Env,
         members: Vec<Address>,
@@ -72,4 +176,229 @@ impl ProfitDistribution for CooperativeManagementContract {
         }
         Ok(())
     }
+
+    fn credit_member_balance(
+        env: Env,
+        admin: Address,
+        member: Address,
+        amount: i128,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(CooperativeError::InvalidInput);
+        }
+
+        let key = DataKey::Balance(member);
+        let balance = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&key)
+            .unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+        Ok(())
+    }
+
+    fn get_member_balance(env: Env, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Balance(member))
+            .unwrap_or(0)
+    }
+
+    fn get_fee_revenue(env: Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::FeeRevenue)
+            .unwrap_or(0)
+    }
+
+    fn record_patronage(
+        env: Env,
+        admin: Address,
+        member: Address,
+        points: i128,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        if points <= 0 {
+            return Err(CooperativeError::InvalidInput);
+        }
+        if member_status(&env, &member).is_none() {
+            return Err(CooperativeError::MemberNotFound);
+        }
+
+        add_patronage(&env, &member, points);
+        Ok(())
+    }
+
+    fn get_patronage(env: Env, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Patronage(member))
+            .unwrap_or(0)
+    }
+
+    fn open_distribution_round(
+        env: Env,
+        admin: Address,
+        total_amount: i128,
+        token: Address,
+        weighting: DistributionWeighting,
+    ) -> Result<u64, CooperativeError> {
+        require_admin(&env, &admin)?;
+        if total_amount <= 0 {
+            return Err(CooperativeError::InvalidInput);
+        }
+
+        token::Client::new(&env, &token).transfer(
+            &admin,
+            &env.current_contract_address(),
+            &total_amount,
+        );
+
+        let id = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::RoundCounter)
+            .unwrap_or(0)
+            + 1;
+        let round = DistributionRound {
+            id,
+            token,
+            total_amount,
+            weighting,
+            status: RoundStatus::Open,
+            allocated: 0,
+            claimed: 0,
+            remainder: 0,
+            claim_deadline: 0,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::DistributionRound(id), &round);
+        env.storage().persistent().set(&DataKey::RoundCounter, &id);
+        Ok(id)
+    }
+
+    fn close_round(env: Env, admin: Address, round_id: u64) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+
+        let mut round = load_round(&env, round_id)?;
+        if round.status != RoundStatus::Open {
+            return Err(CooperativeError::InvalidRoundStatus);
+        }
+
+        let members = active_members(&env);
+        let mut total_weight = 0;
+        for member in members.iter() {
+            total_weight += member_weight(&env, &member, round.weighting);
+        }
+
+        // Allocations round down; whatever is left stays with the round as its remainder
+        let mut allocated = 0;
+        if total_weight > 0 {
+            for member in members.iter() {
+                let share = round.total_amount * member_weight(&env, &member, round.weighting)
+                    / total_weight;
+                if share > 0 {
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::Allocation(round_id, member.clone()), &share);
+                    allocated += share;
+                }
+                // Patronage is earned per round and starts over once it has been paid on
+                if round.weighting == DistributionWeighting::ByPatronage {
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::Patronage(member));
+                }
+            }
+        }
+
+        round.status = RoundStatus::Closed;
+        round.allocated = allocated;
+        round.remainder = round.total_amount - allocated;
+        round.claim_deadline = env.ledger().timestamp() + CLAIM_PERIOD;
+        env.storage()
+            .persistent()
+            .set(&DataKey::DistributionRound(round_id), &round);
+        Ok(())
+    }
+
+    fn claim_distribution(
+        env: Env,
+        member: Address,
+        round_id: u64,
+    ) -> Result<i128, CooperativeError> {
+        member.require_auth();
+
+        let mut round = load_round(&env, round_id)?;
+        if round.status != RoundStatus::Closed {
+            return Err(CooperativeError::InvalidRoundStatus);
+        }
+
+        let claimed_key = DataKey::AllocationClaimed(round_id, member.clone());
+        if env.storage().persistent().has(&claimed_key) {
+            return Err(CooperativeError::AlreadyClaimed);
+        }
+        let amount = env
+            .storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Allocation(round_id, member.clone()))
+            .unwrap_or(0);
+        if amount == 0 {
+            return Err(CooperativeError::NothingToClaim);
+        }
+
+        env.storage().persistent().set(&claimed_key, &true);
+        round.claimed += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::DistributionRound(round_id), &round);
+
+        token::Client::new(&env, &round.token).transfer(
+            &env.current_contract_address(),
+            &member,
+            &amount,
+        );
+        Ok(amount)
+    }
+
+    fn sweep_unclaimed(env: Env, admin: Address, round_id: u64) -> Result<i128, CooperativeError> {
+        require_admin(&env, &admin)?;
+
+        let mut round = load_round(&env, round_id)?;
+        if round.status != RoundStatus::Closed {
+            return Err(CooperativeError::InvalidRoundStatus);
+        }
+        if env.ledger().timestamp() < round.claim_deadline {
+            return Err(CooperativeError::ClaimPeriodActive);
+        }
+
+        let unclaimed = round.total_amount - round.claimed;
+        round.status = RoundStatus::Swept;
+        env.storage()
+            .persistent()
+            .set(&DataKey::DistributionRound(round_id), &round);
+
+        if unclaimed > 0 {
+            token::Client::new(&env, &round.token).transfer(
+                &env.current_contract_address(),
+                &admin,
+                &unclaimed,
+            );
+        }
+        Ok(unclaimed)
+    }
+
+    fn get_round(env: Env, round_id: u64) -> Result<DistributionRound, CooperativeError> {
+        load_round(&env, round_id)
+    }
+
+    fn get_member_allocation(env: Env, round_id: u64, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Allocation(round_id, member))
+            .unwrap_or(0)
+    }
 }
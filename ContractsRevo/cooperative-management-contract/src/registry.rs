@@ -0,0 +1,156 @@
+use crate::datatype::{Cooperative, CooperativeError, DataKey, MembershipAttestation};
+use crate::interface::Registry;
+use crate::membership::require_admin;
+use crate::{
+    CooperativeManagementContract, CooperativeManagementContractArgs,
+    CooperativeManagementContractClient,
+};
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, String, Symbol};
+
+fn load_cooperative(env: &Env, address: &Address) -> Option<Cooperative> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Cooperative>(&DataKey::Cooperative(address.clone()))
+}
+
+fn require_active_cooperative(env: &Env, address: &Address) -> Result<(), CooperativeError> {
+    match load_cooperative(env, address) {
+        Some(cooperative) if cooperative.active => Ok(()),
+        _ => Err(CooperativeError::CooperativeNotRegistered),
+    }
+}
+
+#[contractimpl]
+impl Registry for CooperativeManagementContract {
+    fn register_cooperative(
+        env: Env,
+        admin: Address,
+        coop_address: Address,
+        name: String,
+        region: Symbol,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+
+        // Deregistered cooperatives may be registered again
+        if let Some(existing) = load_cooperative(&env, &coop_address) {
+            if existing.active {
+                return Err(CooperativeError::CooperativeAlreadyRegistered);
+            }
+        }
+
+        let cooperative = Cooperative {
+            address: coop_address.clone(),
+            name,
+            region,
+            registered_at: env.ledger().timestamp(),
+            active: true,
+            deregistration_reason: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Cooperative(coop_address.clone()), &cooperative);
+        env.events().publish(
+            (symbol_short!("registry"), symbol_short!("register")),
+            cooperative,
+        );
+        Ok(())
+    }
+
+    fn deregister_cooperative(
+        env: Env,
+        admin: Address,
+        coop_address: Address,
+        reason: String,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+
+        let mut cooperative = load_cooperative(&env, &coop_address)
+            .filter(|cooperative| cooperative.active)
+            .ok_or(CooperativeError::CooperativeNotRegistered)?;
+
+        // The record is kept so downstream contracts can see why it was removed
+        cooperative.active = false;
+        cooperative.deregistration_reason = Some(reason.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Cooperative(coop_address.clone()), &cooperative);
+        env.events().publish(
+            (symbol_short!("registry"), symbol_short!("deregist")),
+            (coop_address, reason),
+        );
+        Ok(())
+    }
+
+    fn is_registered_cooperative(env: Env, address: Address) -> bool {
+        require_active_cooperative(&env, &address).is_ok()
+    }
+
+    fn get_cooperative(env: Env, address: Address) -> Result<Cooperative, CooperativeError> {
+        load_cooperative(&env, &address).ok_or(CooperativeError::CooperativeNotRegistered)
+    }
+
+    fn attest_membership(
+        env: Env,
+        coop_admin: Address,
+        member: Address,
+        role: Symbol,
+    ) -> Result<(), CooperativeError> {
+        coop_admin.require_auth();
+        require_active_cooperative(&env, &coop_admin)?;
+
+        let attestation = MembershipAttestation {
+            cooperative: coop_admin.clone(),
+            member: member.clone(),
+            role,
+            attested_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(
+            &DataKey::MembershipAttestation(coop_admin, member),
+            &attestation,
+        );
+        env.events().publish(
+            (symbol_short!("registry"), symbol_short!("attest")),
+            attestation,
+        );
+        Ok(())
+    }
+
+    fn revoke_membership_attestation(
+        env: Env,
+        coop_admin: Address,
+        member: Address,
+    ) -> Result<(), CooperativeError> {
+        coop_admin.require_auth();
+
+        let key = DataKey::MembershipAttestation(coop_admin.clone(), member.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(CooperativeError::AttestationNotFound);
+        }
+
+        env.storage().persistent().remove(&key);
+        env.events().publish(
+            (symbol_short!("registry"), symbol_short!("revoke")),
+            (coop_admin, member),
+        );
+        Ok(())
+    }
+
+    fn get_membership_attestation(
+        env: Env,
+        coop: Address,
+        member: Address,
+    ) -> Option<MembershipAttestation> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, MembershipAttestation>(&DataKey::MembershipAttestation(coop, member))
+    }
+
+    /// Attestations from a deregistered cooperative no longer count
+    fn is_member_of(env: Env, coop: Address, member: Address) -> bool {
+        require_active_cooperative(&env, &coop).is_ok()
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::MembershipAttestation(coop, member))
+    }
+}
@@ -1,10 +1,104 @@
-use crate::datatype::{CooperativeError, DataKey, Resource};
+use crate::datatype::{
+    Booking, BookingStatus, CooperativeError, DataKey, Resource, SharedResource,
+};
 use crate::interface::ResourceSharing;
+use crate::membership::{ensure_active_member, ensure_not_suspended, require_admin};
+use crate::profit_distribution::add_patronage;
 use crate::{
     CooperativeManagementContract, CooperativeManagementContractArgs,
     CooperativeManagementContractClient,
 };
-use soroban_sdk::{contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, String, Symbol, Vec};
+
+/// Bookings cancelled at least this long before they start are refunded in full
+pub const CANCELLATION_WINDOW: u64 = 24 * 60 * 60;
+
+const HOUR: u64 = 60 * 60;
+
+pub(crate) fn add_shared_resource(
+    env: &Env,
+    custodian: Address,
+    resource_id: Symbol,
+    name: String,
+    usage_fee_per_hour: i128,
+) -> Result<(), CooperativeError> {
+    if usage_fee_per_hour < 0 {
+        return Err(CooperativeError::InvalidInput);
+    }
+
+    let key = DataKey::SharedResource(resource_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(CooperativeError::ResourceAlreadyExists);
+    }
+
+    let resource = SharedResource {
+        resource_id,
+        name,
+        usage_fee_per_hour,
+        custodian,
+    };
+    env.storage().persistent().set(&key, &resource);
+    Ok(())
+}
+
+fn load_booking(env: &Env, booking_id: u64) -> Result<Booking, CooperativeError> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Booking>(&DataKey::Booking(booking_id))
+        .ok_or(CooperativeError::BookingNotFound)
+}
+
+fn bookings_for(env: &Env, key: &DataKey) -> Vec<Booking> {
+    let ids = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<u64>>(key)
+        .unwrap_or(Vec::new(env));
+
+    let mut bookings = Vec::new(env);
+    for id in ids.iter() {
+        if let Ok(booking) = load_booking(env, id) {
+            bookings.push_back(booking);
+        }
+    }
+    bookings
+}
+
+fn append_id(env: &Env, key: &DataKey, id: u64) {
+    let mut ids = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<u64>>(key)
+        .unwrap_or(Vec::new(env));
+    ids.push_back(id);
+    env.storage().persistent().set(key, &ids);
+}
+
+/// Moves `amount` between a member's internal balance and the cooperative's fee revenue
+fn transfer_fee(env: &Env, member: &Address, amount: i128) -> Result<(), CooperativeError> {
+    let balance_key = DataKey::Balance(member.clone());
+    let balance = env
+        .storage()
+        .persistent()
+        .get::<DataKey, i128>(&balance_key)
+        .unwrap_or(0);
+    if balance < amount {
+        return Err(CooperativeError::InsufficientFunds);
+    }
+    let revenue = env
+        .storage()
+        .persistent()
+        .get::<DataKey, i128>(&DataKey::FeeRevenue)
+        .unwrap_or(0);
+
+    env.storage()
+        .persistent()
+        .set(&balance_key, &(balance - amount));
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeRevenue, &(revenue + amount));
+    Ok(())
+}
 
 #[contractimpl]
 impl ResourceSharing for CooperativeManagementContract {
@@ -26,6 +120,7 @@ impl ResourceSharing for CooperativeManagementContract {
         owner: Address,
         description: String,
     ) -> Result<(), CooperativeError> {
+        ensure_not_suspended(&env, &owner)?;
         let counter_key = DataKey::ResourceCounter;
         let mut counter = env
             .storage()
@@ -80,6 +175,7 @@ impl ResourceSharing for CooperativeManagementContract {
         if !env.storage().persistent().has(&member_key) {
             return Err(CooperativeError::NotAMember);
         }
+        ensure_not_suspended(&env, &borrower)?;
 
         let owner_key = DataKey::Resource(owner.clone(), counter);
         if let Some(mut resource) = env
@@ -138,6 +234,7 @@ impl ResourceSharing for CooperativeManagementContract {
         if !env.storage().persistent().has(&member_key) {
             return Err(CooperativeError::NotAMember);
         }
+        ensure_not_suspended(&env, &borrower)?;
 
         let owner_key = DataKey::Resource(owner.clone(), counter);
         if let Some(mut resource) = env
@@ -194,4 +291,198 @@ impl ResourceSharing for CooperativeManagementContract {
         env.storage().persistent().set(&maintenance_log_key, &logs);
         Ok(())
     }
+
+    fn register_shared_resource(
+        env: Env,
+        admin: Address,
+        resource_id: Symbol,
+        name: String,
+        usage_fee_per_hour: i128,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+        add_shared_resource(&env, admin, resource_id, name, usage_fee_per_hour)
+    }
+
+    fn get_shared_resource(
+        env: Env,
+        resource_id: Symbol,
+    ) -> Result<SharedResource, CooperativeError> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, SharedResource>(&DataKey::SharedResource(resource_id))
+            .ok_or(CooperativeError::ResourceNotFound)
+    }
+
+    fn book_resource(
+        env: Env,
+        member: Address,
+        resource_id: Symbol,
+        start: u64,
+        end: u64,
+    ) -> Result<u64, CooperativeError> {
+        member.require_auth();
+        ensure_active_member(&env, &member)?;
+
+        if start >= end || start < env.ledger().timestamp() {
+            return Err(CooperativeError::InvalidInput);
+        }
+
+        let resource = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SharedResource>(&DataKey::SharedResource(resource_id.clone()))
+            .ok_or(CooperativeError::ResourceNotFound)?;
+
+        // Cancelled and finished bookings can no longer conflict, so they leave the schedule
+        let now = env.ledger().timestamp();
+        let schedule_key = DataKey::ResourceBookings(resource_id.clone());
+        let mut upcoming = Vec::new(&env);
+        for booking in bookings_for(&env, &schedule_key).iter() {
+            if booking.status == BookingStatus::Cancelled || booking.end <= now {
+                continue;
+            }
+            if start < booking.end && booking.start < end {
+                return Err(CooperativeError::TimeSlotConflict);
+            }
+            upcoming.push_back(booking.id);
+        }
+
+        // Partial hours are charged as full hours
+        let hours = (end - start).div_ceil(HOUR) as i128;
+        let fee = hours * resource.usage_fee_per_hour;
+        transfer_fee(&env, &member, fee)?;
+
+        let id = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::BookingCounter)
+            .unwrap_or(0)
+            + 1;
+        let booking = Booking {
+            id,
+            resource_id,
+            member: member.clone(),
+            start,
+            end,
+            fee,
+            status: BookingStatus::Booked,
+            condition_notes_hash: None,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Booking(id), &booking);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BookingCounter, &id);
+        upcoming.push_back(id);
+        env.storage().persistent().set(&schedule_key, &upcoming);
+        append_id(&env, &DataKey::MemberBookings(member), id);
+        Ok(id)
+    }
+
+    fn cancel_booking(
+        env: Env,
+        member: Address,
+        booking_id: u64,
+    ) -> Result<i128, CooperativeError> {
+        member.require_auth();
+
+        let mut booking = load_booking(&env, booking_id)?;
+        if booking.member != member {
+            return Err(CooperativeError::Unauthorized);
+        }
+        if booking.status != BookingStatus::Booked {
+            return Err(CooperativeError::InvalidBookingStatus);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= booking.start {
+            return Err(CooperativeError::BookingStarted);
+        }
+
+        // Late cancellations free the slot but the fee is kept
+        let refund = if now + CANCELLATION_WINDOW <= booking.start {
+            booking.fee
+        } else {
+            0
+        };
+        if refund > 0 {
+            let revenue = env
+                .storage()
+                .persistent()
+                .get::<DataKey, i128>(&DataKey::FeeRevenue)
+                .unwrap_or(0);
+            let balance_key = DataKey::Balance(member.clone());
+            let balance = env
+                .storage()
+                .persistent()
+                .get::<DataKey, i128>(&balance_key)
+                .unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&DataKey::FeeRevenue, &(revenue - refund));
+            env.storage()
+                .persistent()
+                .set(&balance_key, &(balance + refund));
+        }
+
+        booking.status = BookingStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Booking(booking_id), &booking);
+        Ok(refund)
+    }
+
+    fn record_usage_completion(
+        env: Env,
+        custodian: Address,
+        booking_id: u64,
+        condition_notes_hash: BytesN<32>,
+    ) -> Result<(), CooperativeError> {
+        custodian.require_auth();
+
+        let mut booking = load_booking(&env, booking_id)?;
+        let resource = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SharedResource>(&DataKey::SharedResource(booking.resource_id.clone()))
+            .ok_or(CooperativeError::ResourceNotFound)?;
+        if resource.custodian != custodian {
+            return Err(CooperativeError::Unauthorized);
+        }
+        if booking.status != BookingStatus::Booked || env.ledger().timestamp() < booking.start {
+            return Err(CooperativeError::InvalidBookingStatus);
+        }
+
+        booking.status = BookingStatus::Completed;
+        booking.condition_notes_hash = Some(condition_notes_hash);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Booking(booking_id), &booking);
+        // Usage fees paid count towards the member's patronage
+        add_patronage(&env, &booking.member, booking.fee);
+        Ok(())
+    }
+
+    fn get_booking(env: Env, booking_id: u64) -> Result<Booking, CooperativeError> {
+        load_booking(&env, booking_id)
+    }
+
+    fn get_resource_schedule(env: Env, resource_id: Symbol, from: u64, to: u64) -> Vec<Booking> {
+        let mut schedule = Vec::new(&env);
+        for booking in bookings_for(&env, &DataKey::ResourceBookings(resource_id)).iter() {
+            if booking.status != BookingStatus::Cancelled
+                && booking.start < to
+                && from < booking.end
+            {
+                schedule.push_back(booking);
+            }
+        }
+        schedule
+    }
+
+    fn get_usage_history(env: Env, member: Address) -> Vec<Booking> {
+        bookings_for(&env, &DataKey::MemberBookings(member))
+    }
 }
@@ -0,0 +1,390 @@
+use crate::datatype::{BookingStatus, CooperativeError};
+use crate::interface::{Membership, ProfitDistribution, ResourceSharing};
+use crate::resource_sharing::CANCELLATION_WINDOW;
+use crate::tests::utils::*;
+use crate::CooperativeManagementContract;
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, BytesN, String, Symbol};
+
+const HOUR: u64 = 60 * 60;
+const FEE_PER_HOUR: i128 = 25;
+const DAY_ONE: u64 = 10 * CANCELLATION_WINDOW;
+
+fn tractor() -> Symbol {
+    symbol_short!("tractor")
+}
+
+fn setup_booking() -> TestEnv {
+    let test_env = setup_test();
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::register_shared_resource(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            tractor(),
+            String::from_str(&test_env.env, "Tractor"),
+            FEE_PER_HOUR,
+        )
+        .unwrap();
+    });
+    for member in [test_env.member1.clone(), test_env.member2.clone()] {
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as Membership>::register_member(
+                test_env.env.clone(),
+                member.clone(),
+                standard_member_name(&test_env.env),
+                standard_farmer_role(&test_env.env),
+            )
+            .unwrap();
+        });
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as Membership>::verify_member(
+                test_env.env.clone(),
+                test_env.admin.clone(),
+                member.clone(),
+            )
+            .unwrap();
+        });
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as ProfitDistribution>::credit_member_balance(
+                test_env.env.clone(),
+                test_env.admin.clone(),
+                member.clone(),
+                1_000,
+            )
+            .unwrap();
+        });
+    }
+    test_env
+}
+
+fn book(
+    test_env: &TestEnv,
+    member: &Address,
+    start: u64,
+    end: u64,
+) -> Result<u64, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::book_resource(
+            test_env.env.clone(),
+            member.clone(),
+            tractor(),
+            start,
+            end,
+        )
+    })
+}
+
+fn cancel(test_env: &TestEnv, member: &Address, booking_id: u64) -> Result<i128, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::cancel_booking(
+            test_env.env.clone(),
+            member.clone(),
+            booking_id,
+        )
+    })
+}
+
+fn balance(test_env: &TestEnv, member: &Address) -> i128 {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::get_member_balance(
+            test_env.env.clone(),
+            member.clone(),
+        )
+    })
+}
+
+fn revenue(test_env: &TestEnv) -> i128 {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::get_fee_revenue(test_env.env.clone())
+    })
+}
+
+#[test]
+fn test_register_shared_resource_duplicate() {
+    let test_env = setup_booking();
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::register_shared_resource(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            tractor(),
+            String::from_str(&test_env.env, "Another tractor"),
+            FEE_PER_HOUR,
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::ResourceAlreadyExists));
+}
+
+#[test]
+fn test_register_shared_resource_requires_admin() {
+    let test_env = setup_booking();
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::register_shared_resource(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            symbol_short!("coldstore"),
+            String::from_str(&test_env.env, "Cold store"),
+            FEE_PER_HOUR,
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+}
+
+#[test]
+fn test_overlapping_booking_rejected() {
+    let test_env = setup_booking();
+    book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + 4 * HOUR).unwrap();
+
+    assert_eq!(
+        book(
+            &test_env,
+            &test_env.member2,
+            DAY_ONE + 3 * HOUR,
+            DAY_ONE + 6 * HOUR
+        ),
+        Err(CooperativeError::TimeSlotConflict)
+    );
+    assert_eq!(
+        book(&test_env, &test_env.member2, DAY_ONE - HOUR, DAY_ONE + HOUR),
+        Err(CooperativeError::TimeSlotConflict)
+    );
+    // Back-to-back bookings do not overlap
+    assert!(book(
+        &test_env,
+        &test_env.member2,
+        DAY_ONE + 4 * HOUR,
+        DAY_ONE + 5 * HOUR
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_booking_fee_charged_to_member_balance() {
+    let test_env = setup_booking();
+
+    // 2.5 hours are charged as 3
+    let booking_id = book(
+        &test_env,
+        &test_env.member1,
+        DAY_ONE,
+        DAY_ONE + 2 * HOUR + HOUR / 2,
+    )
+    .unwrap();
+
+    let booking = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::get_booking(
+            test_env.env.clone(),
+            booking_id,
+        )
+        .unwrap()
+    });
+    assert_eq!(booking.fee, 3 * FEE_PER_HOUR);
+    assert_eq!(
+        balance(&test_env, &test_env.member1),
+        1_000 - 3 * FEE_PER_HOUR
+    );
+    assert_eq!(revenue(&test_env), 3 * FEE_PER_HOUR);
+}
+
+#[test]
+fn test_booking_rejected_without_funds() {
+    let test_env = setup_booking();
+
+    assert_eq!(
+        book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + 100 * HOUR),
+        Err(CooperativeError::InsufficientFunds)
+    );
+    assert_eq!(balance(&test_env, &test_env.member1), 1_000);
+}
+
+#[test]
+fn test_booking_requires_active_member() {
+    let test_env = setup_booking();
+
+    assert_eq!(
+        book(&test_env, &test_env.member3, DAY_ONE, DAY_ONE + HOUR),
+        Err(CooperativeError::NotAMember)
+    );
+}
+
+#[test]
+fn test_cancellation_inside_window_refunds() {
+    let test_env = setup_booking();
+    let booking_id = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + 2 * HOUR).unwrap();
+
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = DAY_ONE - CANCELLATION_WINDOW);
+
+    assert_eq!(cancel(&test_env, &test_env.member1, booking_id), Ok(50));
+    assert_eq!(balance(&test_env, &test_env.member1), 1_000);
+    assert_eq!(revenue(&test_env), 0);
+
+    // The freed slot can be booked again
+    assert!(book(&test_env, &test_env.member2, DAY_ONE, DAY_ONE + HOUR).is_ok());
+}
+
+#[test]
+fn test_late_cancellation_keeps_fee() {
+    let test_env = setup_booking();
+    let booking_id = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + 2 * HOUR).unwrap();
+
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = DAY_ONE - HOUR);
+
+    assert_eq!(cancel(&test_env, &test_env.member1, booking_id), Ok(0));
+    assert_eq!(balance(&test_env, &test_env.member1), 950);
+    assert_eq!(revenue(&test_env), 50);
+    assert_eq!(
+        cancel(&test_env, &test_env.member1, booking_id),
+        Err(CooperativeError::InvalidBookingStatus)
+    );
+}
+
+#[test]
+fn test_cancel_after_start_rejected() {
+    let test_env = setup_booking();
+    let booking_id = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + 2 * HOUR).unwrap();
+    test_env.env.ledger().with_mut(|li| li.timestamp = DAY_ONE);
+
+    assert_eq!(
+        cancel(&test_env, &test_env.member1, booking_id),
+        Err(CooperativeError::BookingStarted)
+    );
+    assert_eq!(
+        cancel(&test_env, &test_env.member2, booking_id),
+        Err(CooperativeError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_record_usage_completion() {
+    let test_env = setup_booking();
+    let booking_id = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + HOUR).unwrap();
+    let notes = BytesN::from_array(&test_env.env, &[9; 32]);
+
+    let record = |custodian: &Address| {
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as ResourceSharing>::record_usage_completion(
+                test_env.env.clone(),
+                custodian.clone(),
+                booking_id,
+                notes.clone(),
+            )
+        })
+    };
+
+    assert_eq!(
+        record(&test_env.admin),
+        Err(CooperativeError::InvalidBookingStatus)
+    );
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = DAY_ONE + HOUR);
+    assert_eq!(
+        record(&test_env.member1),
+        Err(CooperativeError::Unauthorized)
+    );
+    assert!(record(&test_env.admin).is_ok());
+
+    let history = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::get_usage_history(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+        )
+    });
+    assert_eq!(history.len(), 1);
+    let booking = history.get(0).unwrap();
+    assert_eq!(booking.status, BookingStatus::Completed);
+    assert_eq!(booking.condition_notes_hash, Some(notes));
+}
+
+#[test]
+fn test_resource_schedule_query() {
+    let test_env = setup_booking();
+    let first = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + HOUR).unwrap();
+    let second = book(
+        &test_env,
+        &test_env.member2,
+        DAY_ONE + 5 * HOUR,
+        DAY_ONE + 6 * HOUR,
+    )
+    .unwrap();
+    let third = book(
+        &test_env,
+        &test_env.member1,
+        DAY_ONE + 9 * HOUR,
+        DAY_ONE + 10 * HOUR,
+    )
+    .unwrap();
+    cancel(&test_env, &test_env.member1, third).unwrap();
+
+    let schedule = |from, to| {
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as ResourceSharing>::get_resource_schedule(
+                test_env.env.clone(),
+                tractor(),
+                from,
+                to,
+            )
+        })
+    };
+
+    let all = schedule(0, DAY_ONE + 24 * HOUR);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().id, first);
+    assert_eq!(all.get(1).unwrap().id, second);
+
+    let window = schedule(DAY_ONE + 2 * HOUR, DAY_ONE + 24 * HOUR);
+    assert_eq!(window.len(), 1);
+    assert_eq!(window.get(0).unwrap().id, second);
+}
+
+#[test]
+fn test_finished_bookings_leave_the_schedule() {
+    let test_env = setup_booking();
+    let first = book(&test_env, &test_env.member1, DAY_ONE, DAY_ONE + HOUR).unwrap();
+    let cancelled = book(
+        &test_env,
+        &test_env.member1,
+        DAY_ONE + 2 * HOUR,
+        DAY_ONE + 3 * HOUR,
+    )
+    .unwrap();
+    cancel(&test_env, &test_env.member1, cancelled).unwrap();
+
+    test_env.env.ledger().set_timestamp(DAY_ONE + 4 * HOUR);
+    let next = book(
+        &test_env,
+        &test_env.member1,
+        DAY_ONE + 5 * HOUR,
+        DAY_ONE + 6 * HOUR,
+    )
+    .unwrap();
+
+    // Booking prunes entries that can no longer conflict
+    let (schedule, history) = test_env.env.as_contract(&test_env.contract_id, || {
+        (
+            <CooperativeManagementContract as ResourceSharing>::get_resource_schedule(
+                test_env.env.clone(),
+                tractor(),
+                0,
+                DAY_ONE + 24 * HOUR,
+            ),
+            <CooperativeManagementContract as ResourceSharing>::get_usage_history(
+                test_env.env.clone(),
+                test_env.member1.clone(),
+            ),
+        )
+    });
+    assert_eq!(schedule.len(), 1);
+    assert_eq!(schedule.get(0).unwrap().id, next);
+
+    // The member's own history keeps every booking
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().id, first);
+}
@@ -0,0 +1,312 @@
+use crate::datatype::{
+    CooperativeError, DataKey, DistributionRound, DistributionWeighting, RoundStatus,
+};
+use crate::interface::{Membership, ProfitDistribution};
+use crate::profit_distribution::CLAIM_PERIOD;
+use crate::tests::utils::*;
+use crate::{CooperativeManagementContract, CooperativeManagementContractClient};
+use soroban_sdk::{testutils::Ledger, token, Address};
+
+fn setup_distribution() -> (TestEnv, Address) {
+    let test_env = setup_test();
+    for member in [
+        test_env.member1.clone(),
+        test_env.member2.clone(),
+        test_env.member3.clone(),
+    ] {
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as Membership>::register_member(
+                test_env.env.clone(),
+                member.clone(),
+                standard_member_name(&test_env.env),
+                standard_farmer_role(&test_env.env),
+            )
+            .unwrap();
+        });
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as Membership>::verify_member(
+                test_env.env.clone(),
+                test_env.admin.clone(),
+                member.clone(),
+            )
+            .unwrap();
+        });
+    }
+
+    let token = test_env
+        .env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    token::StellarAssetClient::new(&test_env.env, &token).mint(&test_env.admin, &1_000_000);
+    (test_env, token)
+}
+
+fn open_round(
+    test_env: &TestEnv,
+    token: &Address,
+    amount: i128,
+    weighting: DistributionWeighting,
+) -> u64 {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::open_distribution_round(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            amount,
+            token.clone(),
+            weighting,
+        )
+        .unwrap()
+    })
+}
+
+fn close(test_env: &TestEnv, round_id: u64) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::close_round(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            round_id,
+        )
+        .unwrap();
+    });
+}
+
+fn allocation(test_env: &TestEnv, round_id: u64, member: &Address) -> i128 {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::get_member_allocation(
+            test_env.env.clone(),
+            round_id,
+            member.clone(),
+        )
+    })
+}
+
+fn claim(test_env: &TestEnv, member: &Address, round_id: u64) -> Result<i128, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::claim_distribution(
+            test_env.env.clone(),
+            member.clone(),
+            round_id,
+        )
+    })
+}
+
+fn sweep(test_env: &TestEnv, round_id: u64) -> Result<i128, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::sweep_unclaimed(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            round_id,
+        )
+    })
+}
+
+fn invest(test_env: &TestEnv, member: &Address, amount: i128) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::pool_investment(
+            test_env.env.clone(),
+            member.clone(),
+            amount,
+        )
+        .unwrap();
+    });
+}
+
+fn patronage(test_env: &TestEnv, member: &Address, points: i128) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::record_patronage(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            member.clone(),
+            points,
+        )
+        .unwrap();
+    });
+}
+
+fn get_round(test_env: &TestEnv, round_id: u64) -> DistributionRound {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::get_round(
+            test_env.env.clone(),
+            round_id,
+        )
+        .unwrap()
+    })
+}
+
+#[test]
+fn test_equal_weighting_with_rounding_remainder() {
+    let (test_env, token) = setup_distribution();
+    let round_id = open_round(&test_env, &token, 1_000, DistributionWeighting::Equal);
+    close(&test_env, round_id);
+
+    for member in [&test_env.member1, &test_env.member2, &test_env.member3] {
+        assert_eq!(allocation(&test_env, round_id, member), 333);
+    }
+    let round = get_round(&test_env, round_id);
+    assert_eq!(round.status, RoundStatus::Closed);
+    assert_eq!(round.allocated, 999);
+    assert_eq!(round.remainder, 1);
+}
+
+#[test]
+fn test_capital_share_weighting() {
+    let (test_env, token) = setup_distribution();
+    invest(&test_env, &test_env.member1, 600);
+    invest(&test_env, &test_env.member2, 400);
+    let round_id = open_round(
+        &test_env,
+        &token,
+        1_000,
+        DistributionWeighting::ByCapitalShare,
+    );
+    close(&test_env, round_id);
+
+    assert_eq!(allocation(&test_env, round_id, &test_env.member1), 600);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member2), 400);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member3), 0);
+    assert_eq!(
+        claim(&test_env, &test_env.member3, round_id),
+        Err(CooperativeError::NothingToClaim)
+    );
+}
+
+#[test]
+fn test_capital_share_pays_only_funded_investors() {
+    let (test_env, token) = setup_distribution();
+    invest(&test_env, &test_env.member1, 500);
+
+    // member2 claims capital it never paid in, member3 cannot cover its investment
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::Investment(test_env.member2.clone()),
+            &1_000_000_i128,
+        );
+    });
+    let capital = token::Client::new(&test_env.env, &test_env.capital_token);
+    capital.transfer(&test_env.member3, &test_env.admin, &MEMBER_CAPITAL);
+    let client = CooperativeManagementContractClient::new(&test_env.env, &test_env.contract_id);
+    assert!(client
+        .try_pool_investment(&test_env.member3, &10_000)
+        .is_err());
+    assert_eq!(client.get_member_capital(&test_env.member3), 0);
+
+    let round_id = open_round(
+        &test_env,
+        &token,
+        1_000,
+        DistributionWeighting::ByCapitalShare,
+    );
+    close(&test_env, round_id);
+
+    assert_eq!(allocation(&test_env, round_id, &test_env.member1), 1_000);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member2), 0);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member3), 0);
+    assert_eq!(capital.balance(&test_env.contract_id), 500);
+}
+
+#[test]
+fn test_patronage_weighting_resets_points() {
+    let (test_env, token) = setup_distribution();
+    patronage(&test_env, &test_env.member1, 30);
+    patronage(&test_env, &test_env.member2, 10);
+    let round_id = open_round(&test_env, &token, 1_000, DistributionWeighting::ByPatronage);
+    close(&test_env, round_id);
+
+    assert_eq!(allocation(&test_env, round_id, &test_env.member1), 750);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member2), 250);
+
+    let points = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::get_patronage(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+        )
+    });
+    assert_eq!(points, 0);
+}
+
+#[test]
+fn test_suspended_member_excluded_from_round() {
+    let (test_env, token) = setup_distribution();
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::suspend_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            test_env.member3.clone(),
+        )
+        .unwrap();
+    });
+    let round_id = open_round(&test_env, &token, 1_000, DistributionWeighting::Equal);
+    close(&test_env, round_id);
+
+    assert_eq!(allocation(&test_env, round_id, &test_env.member1), 500);
+    assert_eq!(allocation(&test_env, round_id, &test_env.member3), 0);
+}
+
+#[test]
+fn test_claim_is_idempotent() {
+    let (test_env, token) = setup_distribution();
+    let round_id = open_round(&test_env, &token, 900, DistributionWeighting::Equal);
+
+    assert_eq!(
+        claim(&test_env, &test_env.member1, round_id),
+        Err(CooperativeError::InvalidRoundStatus)
+    );
+    close(&test_env, round_id);
+
+    assert_eq!(claim(&test_env, &test_env.member1, round_id), Ok(300));
+    assert_eq!(
+        claim(&test_env, &test_env.member1, round_id),
+        Err(CooperativeError::AlreadyClaimed)
+    );
+
+    let token_client = token::Client::new(&test_env.env, &token);
+    assert_eq!(token_client.balance(&test_env.member1), 300);
+    assert_eq!(token_client.balance(&test_env.contract_id), 600);
+    assert_eq!(get_round(&test_env, round_id).claimed, 300);
+}
+
+#[test]
+fn test_sweep_unclaimed_after_deadline() {
+    let (test_env, token) = setup_distribution();
+    let round_id = open_round(&test_env, &token, 1_000, DistributionWeighting::Equal);
+    close(&test_env, round_id);
+    claim(&test_env, &test_env.member1, round_id).unwrap();
+
+    assert_eq!(
+        sweep(&test_env, round_id),
+        Err(CooperativeError::ClaimPeriodActive)
+    );
+
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = CLAIM_PERIOD);
+
+    // Two unclaimed allocations plus the rounding remainder
+    assert_eq!(sweep(&test_env, round_id), Ok(667));
+    assert_eq!(get_round(&test_env, round_id).status, RoundStatus::Swept);
+    assert_eq!(
+        claim(&test_env, &test_env.member2, round_id),
+        Err(CooperativeError::InvalidRoundStatus)
+    );
+
+    let token_client = token::Client::new(&test_env.env, &token);
+    assert_eq!(token_client.balance(&test_env.admin), 1_000_000 - 333);
+    assert_eq!(token_client.balance(&test_env.contract_id), 0);
+}
+
+#[test]
+fn test_open_round_requires_admin() {
+    let (test_env, token) = setup_distribution();
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::open_distribution_round(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            1_000,
+            token.clone(),
+            DistributionWeighting::Equal,
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+}
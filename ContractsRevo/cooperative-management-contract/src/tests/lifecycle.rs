@@ -0,0 +1,409 @@
+use crate::datatype::{
+    ApprovalMode, CooperativeError, DataKey, MemberStatus, ProposalAction, ProposalStatus,
+};
+use crate::interface::{Governance, Membership, ProfitDistribution, ResourceSharing};
+use crate::tests::utils::*;
+use crate::{CooperativeManagementContract, CooperativeManagementContractClient};
+use soroban_sdk::{testutils::Ledger, token, Address, BytesN, String};
+
+fn apply(test_env: &TestEnv, applicant: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::apply_for_membership(
+            test_env.env.clone(),
+            applicant.clone(),
+            BytesN::from_array(&test_env.env, &[1; 32]),
+        )
+    })
+}
+
+fn approve(test_env: &TestEnv, applicant: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::approve_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            applicant.clone(),
+        )
+    })
+}
+
+fn status(test_env: &TestEnv, address: &Address) -> Result<MemberStatus, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::get_member_status(
+            test_env.env.clone(),
+            address.clone(),
+        )
+    })
+}
+
+fn suspend(test_env: &TestEnv, member: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::suspend_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            member.clone(),
+        )
+    })
+}
+
+fn admit(test_env: &TestEnv, member: &Address) {
+    apply(test_env, member).unwrap();
+    approve(test_env, member).unwrap();
+}
+
+fn create_proposal(
+    test_env: &TestEnv,
+    proposer: &Address,
+    action: ProposalAction,
+) -> Result<u64, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::create_proposal(
+            test_env.env.clone(),
+            proposer.clone(),
+            String::from_str(&test_env.env, "Membership"),
+            BytesN::from_array(&test_env.env, &[2; 32]),
+            action,
+            100,
+        )
+    })
+}
+
+fn vote(test_env: &TestEnv, member: &Address, proposal_id: u64) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::vote(
+            test_env.env.clone(),
+            member.clone(),
+            proposal_id,
+            true,
+        )
+    })
+}
+
+#[test]
+fn test_full_membership_lifecycle() {
+    let test_env = setup_test();
+    let member = test_env.member1.clone();
+
+    assert_eq!(
+        status(&test_env, &member),
+        Err(CooperativeError::MemberNotFound)
+    );
+
+    apply(&test_env, &member).unwrap();
+    assert_eq!(status(&test_env, &member), Ok(MemberStatus::Pending));
+    assert_eq!(
+        apply(&test_env, &member),
+        Err(CooperativeError::MemberAlreadyExists)
+    );
+
+    approve(&test_env, &member).unwrap();
+    assert_eq!(status(&test_env, &member), Ok(MemberStatus::Active));
+
+    suspend(&test_env, &member).unwrap();
+    assert_eq!(status(&test_env, &member), Ok(MemberStatus::Suspended));
+
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::reinstate_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            member.clone(),
+        )
+        .unwrap();
+    });
+    assert_eq!(status(&test_env, &member), Ok(MemberStatus::Active));
+
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::exit_membership(
+            test_env.env.clone(),
+            member.clone(),
+        )
+        .unwrap();
+    });
+    assert_eq!(status(&test_env, &member), Ok(MemberStatus::Exited));
+
+    // Former members can apply again
+    assert!(apply(&test_env, &member).is_ok());
+}
+
+#[test]
+fn test_reject_application() {
+    let test_env = setup_test();
+    apply(&test_env, &test_env.member1).unwrap();
+
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::reject_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            test_env.member1.clone(),
+        )
+        .unwrap();
+    });
+
+    assert_eq!(
+        status(&test_env, &test_env.member1),
+        Ok(MemberStatus::Rejected)
+    );
+    assert_eq!(
+        approve(&test_env, &test_env.member1),
+        Err(CooperativeError::InvalidMemberStatus)
+    );
+}
+
+#[test]
+fn test_approve_requires_admin() {
+    let test_env = setup_test();
+    apply(&test_env, &test_env.member1).unwrap();
+
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::approve_member(
+            test_env.env.clone(),
+            test_env.member2.clone(),
+            test_env.member1.clone(),
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+}
+
+#[test]
+fn test_verify_member_requires_stored_admin() {
+    let test_env = setup_test();
+    let verify = |admin: &Address| {
+        test_env.env.as_contract(&test_env.contract_id, || {
+            <CooperativeManagementContract as Membership>::verify_member(
+                test_env.env.clone(),
+                admin.clone(),
+                test_env.member2.clone(),
+            )
+        })
+    };
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::register_member(
+            test_env.env.clone(),
+            test_env.member2.clone(),
+            standard_member_name(&test_env.env),
+            standard_farmer_role(&test_env.env),
+        )
+        .unwrap();
+    });
+
+    // Registering does not let a member vouch for itself
+    assert_eq!(
+        verify(&test_env.member2),
+        Err(CooperativeError::Unauthorized)
+    );
+
+    // Under governance approval the admin cannot verify directly either
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::set_membership_approval(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            ApprovalMode::Governance,
+        )
+        .unwrap();
+    });
+    assert_eq!(verify(&test_env.admin), Err(CooperativeError::Unauthorized));
+    assert_eq!(
+        status(&test_env, &test_env.member2),
+        Ok(MemberStatus::Pending)
+    );
+}
+
+#[test]
+fn test_governance_approval_mode() {
+    let test_env = setup_test();
+    admit(&test_env, &test_env.member1);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::set_membership_approval(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            ApprovalMode::Governance,
+        )
+        .unwrap();
+    });
+    apply(&test_env, &test_env.member2).unwrap();
+
+    // The admin can no longer decide applications directly
+    assert_eq!(
+        approve(&test_env, &test_env.member2),
+        Err(CooperativeError::Unauthorized)
+    );
+
+    let proposal_id = create_proposal(
+        &test_env,
+        &test_env.member1,
+        ProposalAction::ApproveMember(test_env.member2.clone()),
+    )
+    .unwrap();
+    vote(&test_env, &test_env.member1, proposal_id).unwrap();
+    test_env.env.ledger().with_mut(|li| li.timestamp = 100);
+
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::finalize_proposal(
+            test_env.env.clone(),
+            proposal_id,
+        )
+    });
+    assert_eq!(result, Ok(ProposalStatus::Executed));
+    assert_eq!(
+        status(&test_env, &test_env.member2),
+        Ok(MemberStatus::Active)
+    );
+}
+
+#[test]
+fn test_suspended_member_blocked_from_voting() {
+    let test_env = setup_test();
+    admit(&test_env, &test_env.member1);
+    admit(&test_env, &test_env.member2);
+    let proposal_id =
+        create_proposal(&test_env, &test_env.member1, ProposalAction::Signal).unwrap();
+
+    suspend(&test_env, &test_env.member2).unwrap();
+
+    assert_eq!(
+        vote(&test_env, &test_env.member2, proposal_id),
+        Err(CooperativeError::MemberSuspended)
+    );
+    assert_eq!(
+        create_proposal(&test_env, &test_env.member2, ProposalAction::Signal),
+        Err(CooperativeError::MemberSuspended)
+    );
+}
+
+#[test]
+fn test_suspended_member_blocked_from_resources() {
+    let test_env = setup_test();
+    admit(&test_env, &test_env.member1);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::register_resource(
+            test_env.env.clone(),
+            test_env.member2.clone(),
+            standard_resource_description(&test_env.env),
+        )
+        .unwrap();
+    });
+    suspend(&test_env, &test_env.member1).unwrap();
+
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ResourceSharing>::borrow_resource(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            test_env.member2.clone(),
+            1,
+        )
+    });
+    assert_eq!(result, Err(CooperativeError::MemberSuspended));
+}
+
+#[test]
+fn test_exit_settlement_recorded() {
+    let test_env = setup_test();
+    let member = test_env.member1.clone();
+    admit(&test_env, &member);
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::pool_investment(
+            test_env.env.clone(),
+            member.clone(),
+            400,
+        )
+        .unwrap();
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Balance(member.clone()), &150_i128);
+    });
+    test_env.env.ledger().with_mut(|li| li.timestamp = 500);
+
+    let settlement = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::exit_membership(
+            test_env.env.clone(),
+            member.clone(),
+        )
+        .unwrap()
+    });
+    assert_eq!(settlement.capital, 400);
+    assert_eq!(settlement.balance, 150);
+    assert_eq!(settlement.total, 550);
+    assert_eq!(settlement.settled_at, 500);
+
+    let (stored, total_investment, has_member) =
+        test_env.env.as_contract(&test_env.contract_id, || {
+            (
+                <CooperativeManagementContract as Membership>::get_exit_settlement(
+                    test_env.env.clone(),
+                    member.clone(),
+                ),
+                test_env
+                    .env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, i128>(&DataKey::TotalCapital),
+                test_env
+                    .env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Member(member.clone())),
+            )
+        });
+    assert_eq!(stored, Some(settlement));
+    assert_eq!(total_investment, Some(0));
+    assert!(!has_member);
+
+    // The paid-in capital is returned with the exit
+    let capital = token::Client::new(&test_env.env, &test_env.capital_token);
+    assert_eq!(capital.balance(&member), MEMBER_CAPITAL);
+    assert_eq!(capital.balance(&test_env.contract_id), 0);
+}
+
+#[test]
+fn test_list_members_by_status() {
+    let test_env = setup_test();
+    admit(&test_env, &test_env.member1);
+    admit(&test_env, &test_env.member2);
+    apply(&test_env, &test_env.member3).unwrap();
+    suspend(&test_env, &test_env.member2).unwrap();
+
+    let (active, suspended, pending, paged) =
+        test_env.env.as_contract(&test_env.contract_id, || {
+            let list = |status, offset, limit| {
+                <CooperativeManagementContract as Membership>::list_members(
+                    test_env.env.clone(),
+                    status,
+                    offset,
+                    limit,
+                )
+            };
+            (
+                list(MemberStatus::Active, 0, 10),
+                list(MemberStatus::Suspended, 0, 10),
+                list(MemberStatus::Pending, 0, 10),
+                list(MemberStatus::Active, 1, 10),
+            )
+        });
+
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap(), test_env.member1);
+    assert_eq!(suspended.get(0).unwrap(), test_env.member2);
+    assert_eq!(pending.get(0).unwrap(), test_env.member3);
+    assert_eq!(paged.len(), 0);
+}
+
+#[test]
+fn test_init_twice_rejected() {
+    let test_env = setup_test();
+    let client = CooperativeManagementContractClient::new(&test_env.env, &test_env.contract_id);
+
+    let result = client.try_init(&test_env.member1);
+    assert_eq!(result, Err(Ok(CooperativeError::AlreadyInitialized)));
+
+    let admin: Address = test_env.env.as_contract(&test_env.contract_id, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .unwrap()
+    });
+    assert_eq!(admin, test_env.admin);
+}
@@ -1,4 +1,10 @@
+pub mod bookings;
+pub mod distribution;
 pub mod governance;
+pub mod lifecycle;
 pub mod membership;
+pub mod proposals;
+pub mod registry;
 pub mod resource_sharing;
+pub mod treasury;
 pub mod utils;
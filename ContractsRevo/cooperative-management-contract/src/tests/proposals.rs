@@ -0,0 +1,452 @@
+use crate::datatype::{
+    CooperativeError, DataKey, GovernanceConfig, ProposalAction, ProposalStatus, VotingMode,
+};
+use crate::interface::{Governance, Membership, ProfitDistribution};
+use crate::tests::utils::*;
+use crate::{CooperativeManagementContract, CooperativeManagementContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, String,
+};
+
+const DEADLINE: u64 = 1_000;
+
+fn register_verified(test_env: &TestEnv, member: &Address) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::register_member(
+            test_env.env.clone(),
+            member.clone(),
+            standard_member_name(&test_env.env),
+            standard_farmer_role(&test_env.env),
+        )
+        .unwrap();
+    });
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::verify_member(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            member.clone(),
+        )
+        .unwrap();
+    });
+}
+
+fn setup_members() -> TestEnv {
+    let test_env = setup_test();
+    register_verified(&test_env, &test_env.member1);
+    register_verified(&test_env, &test_env.member2);
+    register_verified(&test_env, &test_env.member3);
+    test_env
+}
+
+fn create(test_env: &TestEnv, action: ProposalAction) -> Result<u64, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::create_proposal(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            String::from_str(&test_env.env, "Buy a cold store"),
+            BytesN::from_array(&test_env.env, &[7; 32]),
+            action,
+            DEADLINE,
+        )
+    })
+}
+
+fn cast(
+    test_env: &TestEnv,
+    member: &Address,
+    proposal_id: u64,
+    support: bool,
+) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::vote(
+            test_env.env.clone(),
+            member.clone(),
+            proposal_id,
+            support,
+        )
+    })
+}
+
+fn finalize(test_env: &TestEnv, proposal_id: u64) -> Result<ProposalStatus, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::finalize_proposal(
+            test_env.env.clone(),
+            proposal_id,
+        )
+    })
+}
+
+fn set_config(test_env: &TestEnv, config: GovernanceConfig) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::set_governance_config(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            config,
+        )
+        .unwrap();
+    });
+}
+
+fn invest(test_env: &TestEnv, member: &Address, amount: i128) {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::pool_investment(
+            test_env.env.clone(),
+            member.clone(),
+            amount,
+        )
+        .unwrap();
+    });
+}
+
+fn pass_deadline(test_env: &TestEnv) {
+    test_env.env.ledger().with_mut(|li| li.timestamp = DEADLINE);
+}
+
+#[test]
+fn test_create_proposal_requires_verified_member() {
+    let test_env = setup_test();
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Membership>::register_member(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            standard_member_name(&test_env.env),
+            standard_farmer_role(&test_env.env),
+        )
+        .unwrap();
+    });
+
+    assert_eq!(
+        create(&test_env, ProposalAction::Signal),
+        Err(CooperativeError::MemberNotVerified)
+    );
+}
+
+#[test]
+fn test_create_proposal_rejects_past_deadline() {
+    let test_env = setup_members();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        create(&test_env, ProposalAction::Signal),
+        Err(CooperativeError::InvalidInput)
+    );
+}
+
+#[test]
+fn test_double_vote_rejected() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    assert!(cast(&test_env, &test_env.member2, proposal_id, true).is_ok());
+    assert_eq!(
+        cast(&test_env, &test_env.member2, proposal_id, false),
+        Err(CooperativeError::AlreadyVoted)
+    );
+
+    let proposal = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::get_proposal(
+            test_env.env.clone(),
+            proposal_id,
+        )
+        .unwrap()
+    });
+    assert_eq!(proposal.votes_for, 1);
+    assert_eq!(proposal.votes_against, 0);
+}
+
+#[test]
+fn test_vote_after_deadline_rejected() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        cast(&test_env, &test_env.member2, proposal_id, true),
+        Err(CooperativeError::VotingClosed)
+    );
+}
+
+#[test]
+fn test_finalize_before_deadline_rejected() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Err(CooperativeError::VotingStillOpen)
+    );
+}
+
+#[test]
+fn test_quorum_not_met() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::ApproveProfitDistribution(5_000)).unwrap();
+
+    // One vote out of three members is below the default 50% quorum
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::QuorumNotMet)
+    );
+    let approved = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::get_approved_distribution(
+            test_env.env.clone(),
+            proposal_id,
+        )
+    });
+    assert_eq!(approved, None);
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Err(CooperativeError::ProposalNotActive)
+    );
+}
+
+#[test]
+fn test_passing_proposal_approves_distribution() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::ApproveProfitDistribution(5_000)).unwrap();
+
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member2, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member3, proposal_id, false).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::Executed)
+    );
+    let approved = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::get_approved_distribution(
+            test_env.env.clone(),
+            proposal_id,
+        )
+    });
+    assert_eq!(approved, Some(5_000));
+}
+
+#[test]
+fn test_passing_proposal_updates_governance_config() {
+    let test_env = setup_members();
+    let new_config = GovernanceConfig {
+        voting_mode: VotingMode::ShareWeighted,
+        quorum_bps: 3_000,
+        threshold_bps: 6_000,
+    };
+    let proposal_id = create(
+        &test_env,
+        ProposalAction::UpdateGovernanceConfig(new_config.clone()),
+    )
+    .unwrap();
+
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member2, proposal_id, true).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::Executed)
+    );
+    let config = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::get_governance_config(test_env.env.clone())
+    });
+    assert_eq!(config, new_config);
+}
+
+#[test]
+fn test_tied_vote_rejected() {
+    let test_env = setup_members();
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member2, proposal_id, false).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::Rejected)
+    );
+}
+
+#[test]
+fn test_share_weighted_voting() {
+    let test_env = setup_members();
+    set_config(
+        &test_env,
+        GovernanceConfig {
+            voting_mode: VotingMode::ShareWeighted,
+            quorum_bps: 5_000,
+            threshold_bps: 5_000,
+        },
+    );
+    invest(&test_env, &test_env.member1, 700);
+    invest(&test_env, &test_env.member2, 200);
+    invest(&test_env, &test_env.member3, 100);
+
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    // A single large shareholder outweighs two smaller ones
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member2, proposal_id, false).unwrap();
+    cast(&test_env, &test_env.member3, proposal_id, false).unwrap();
+
+    let proposal = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::get_proposal(
+            test_env.env.clone(),
+            proposal_id,
+        )
+        .unwrap()
+    });
+    assert_eq!(proposal.voting_mode, VotingMode::ShareWeighted);
+    assert_eq!(proposal.total_weight, 1_000);
+    assert_eq!(proposal.votes_for, 700);
+    assert_eq!(proposal.votes_against, 300);
+
+    pass_deadline(&test_env);
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::Executed)
+    );
+}
+
+#[test]
+fn test_share_weighted_vote_requires_capital() {
+    let test_env = setup_members();
+    set_config(
+        &test_env,
+        GovernanceConfig {
+            voting_mode: VotingMode::ShareWeighted,
+            quorum_bps: 5_000,
+            threshold_bps: 5_000,
+        },
+    );
+    invest(&test_env, &test_env.member1, 100);
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    assert_eq!(
+        cast(&test_env, &test_env.member2, proposal_id, true),
+        Err(CooperativeError::NoVotingPower)
+    );
+}
+
+#[test]
+fn test_share_weight_counts_only_paid_in_capital() {
+    let test_env = setup_members();
+    set_config(
+        &test_env,
+        GovernanceConfig {
+            voting_mode: VotingMode::ShareWeighted,
+            quorum_bps: 5_000,
+            threshold_bps: 5_000,
+        },
+    );
+    let client = CooperativeManagementContractClient::new(&test_env.env, &test_env.contract_id);
+    client.pool_investment(&test_env.member1, &300);
+    assert_eq!(
+        test_env
+            .env
+            .auths()
+            .first()
+            .map(|(signer, _)| signer.clone()),
+        Some(test_env.member1.clone())
+    );
+    let capital = token::Client::new(&test_env.env, &test_env.capital_token);
+    assert_eq!(capital.balance(&test_env.member1), MEMBER_CAPITAL - 300);
+    assert_eq!(capital.balance(&test_env.contract_id), 300);
+
+    // An investment recorded without paying anything in carries no weight
+    test_env.env.as_contract(&test_env.contract_id, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::Investment(test_env.member2.clone()),
+            &1_000_000_i128,
+        );
+    });
+    let unfunded = Address::generate(&test_env.env);
+    assert!(client.try_pool_investment(&unfunded, &1_000_000).is_err());
+    assert_eq!(client.get_member_capital(&unfunded), 0);
+
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+    assert_eq!(
+        cast(&test_env, &test_env.member2, proposal_id, false),
+        Err(CooperativeError::NoVotingPower)
+    );
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.total_weight, 300);
+    assert_eq!(proposal.votes_for, 300);
+}
+
+#[test]
+fn test_one_member_one_vote_ignores_capital() {
+    let test_env = setup_members();
+    invest(&test_env, &test_env.member1, 700);
+    let proposal_id = create(&test_env, ProposalAction::Signal).unwrap();
+
+    cast(&test_env, &test_env.member1, proposal_id, true).unwrap();
+    cast(&test_env, &test_env.member2, proposal_id, false).unwrap();
+    cast(&test_env, &test_env.member3, proposal_id, false).unwrap();
+    pass_deadline(&test_env);
+
+    assert_eq!(
+        finalize(&test_env, proposal_id),
+        Ok(ProposalStatus::Rejected)
+    );
+}
+
+#[test]
+fn test_list_proposals_by_status() {
+    let test_env = setup_members();
+    let first = create(&test_env, ProposalAction::Signal).unwrap();
+    let second = create(&test_env, ProposalAction::Signal).unwrap();
+    let third = create(&test_env, ProposalAction::Signal).unwrap();
+
+    cast(&test_env, &test_env.member1, second, true).unwrap();
+    cast(&test_env, &test_env.member2, second, true).unwrap();
+    pass_deadline(&test_env);
+    finalize(&test_env, first).unwrap();
+    finalize(&test_env, second).unwrap();
+
+    let (executed, quorum_failed, active, paged) =
+        test_env.env.as_contract(&test_env.contract_id, || {
+            let list = |status, offset, limit| {
+                <CooperativeManagementContract as Governance>::list_proposals(
+                    test_env.env.clone(),
+                    status,
+                    offset,
+                    limit,
+                )
+            };
+            (
+                list(ProposalStatus::Executed, 0, 10),
+                list(ProposalStatus::QuorumNotMet, 0, 10),
+                list(ProposalStatus::Active, 0, 10),
+                list(ProposalStatus::Executed, 1, 10),
+            )
+        });
+
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed.get(0).unwrap().id, second);
+    assert_eq!(quorum_failed.get(0).unwrap().id, first);
+    assert_eq!(active.get(0).unwrap().id, third);
+    assert_eq!(paged.len(), 0);
+}
+
+#[test]
+fn test_set_governance_config_requires_admin() {
+    let test_env = setup_members();
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Governance>::set_governance_config(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            GovernanceConfig {
+                voting_mode: VotingMode::ShareWeighted,
+                quorum_bps: 5_000,
+                threshold_bps: 5_000,
+            },
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+}
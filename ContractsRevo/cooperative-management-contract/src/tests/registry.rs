@@ -0,0 +1,195 @@
+use crate::datatype::CooperativeError;
+use crate::interface::Registry;
+use crate::tests::utils::*;
+use crate::{CooperativeManagementContract, CooperativeManagementContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, String,
+};
+
+/// Stands in for a downstream contract such as cross-cooperative-trade
+#[contract]
+pub struct MockConsumer;
+
+#[contractimpl]
+impl MockConsumer {
+    pub fn can_trade(env: Env, registry: Address, coop: Address, member: Address) -> bool {
+        let client = CooperativeManagementContractClient::new(&env, &registry);
+        client.is_registered_cooperative(&coop) && client.is_member_of(&coop, &member)
+    }
+}
+
+fn register(test_env: &TestEnv, coop: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::register_cooperative(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            coop.clone(),
+            String::from_str(&test_env.env, "Valle Verde"),
+            symbol_short!("andes"),
+        )
+    })
+}
+
+fn deregister(test_env: &TestEnv, coop: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::deregister_cooperative(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            coop.clone(),
+            String::from_str(&test_env.env, "Dissolved"),
+        )
+    })
+}
+
+fn attest(test_env: &TestEnv, coop: &Address, member: &Address) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::attest_membership(
+            test_env.env.clone(),
+            coop.clone(),
+            member.clone(),
+            symbol_short!("grower"),
+        )
+    })
+}
+
+fn is_member_of(test_env: &TestEnv, coop: &Address, member: &Address) -> bool {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::is_member_of(
+            test_env.env.clone(),
+            coop.clone(),
+            member.clone(),
+        )
+    })
+}
+
+#[test]
+fn test_registry_crud() {
+    let test_env = setup_test();
+    let coop = Address::generate(&test_env.env);
+
+    register(&test_env, &coop).unwrap();
+    assert_eq!(
+        register(&test_env, &coop),
+        Err(CooperativeError::CooperativeAlreadyRegistered)
+    );
+
+    let cooperative = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::get_cooperative(
+            test_env.env.clone(),
+            coop.clone(),
+        )
+        .unwrap()
+    });
+    assert_eq!(cooperative.region, symbol_short!("andes"));
+    assert!(cooperative.active);
+
+    deregister(&test_env, &coop).unwrap();
+    let (registered, cooperative) = test_env.env.as_contract(&test_env.contract_id, || {
+        (
+            <CooperativeManagementContract as Registry>::is_registered_cooperative(
+                test_env.env.clone(),
+                coop.clone(),
+            ),
+            <CooperativeManagementContract as Registry>::get_cooperative(
+                test_env.env.clone(),
+                coop.clone(),
+            )
+            .unwrap(),
+        )
+    });
+    assert!(!registered);
+    assert_eq!(
+        cooperative.deregistration_reason,
+        Some(String::from_str(&test_env.env, "Dissolved"))
+    );
+    assert_eq!(
+        deregister(&test_env, &coop),
+        Err(CooperativeError::CooperativeNotRegistered)
+    );
+
+    // A deregistered cooperative can be registered again
+    assert!(register(&test_env, &coop).is_ok());
+}
+
+#[test]
+fn test_register_requires_admin() {
+    let test_env = setup_test();
+    let coop = Address::generate(&test_env.env);
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::register_cooperative(
+            test_env.env.clone(),
+            test_env.member1.clone(),
+            coop.clone(),
+            String::from_str(&test_env.env, "Valle Verde"),
+            symbol_short!("andes"),
+        )
+    });
+
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+}
+
+#[test]
+fn test_membership_attestation() {
+    let test_env = setup_test();
+    let coop = Address::generate(&test_env.env);
+
+    assert_eq!(
+        attest(&test_env, &coop, &test_env.member1),
+        Err(CooperativeError::CooperativeNotRegistered)
+    );
+
+    register(&test_env, &coop).unwrap();
+    attest(&test_env, &coop, &test_env.member1).unwrap();
+    assert!(is_member_of(&test_env, &coop, &test_env.member1));
+    assert!(!is_member_of(&test_env, &coop, &test_env.member2));
+
+    let attestation = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::get_membership_attestation(
+            test_env.env.clone(),
+            coop.clone(),
+            test_env.member1.clone(),
+        )
+        .unwrap()
+    });
+    assert_eq!(attestation.role, symbol_short!("grower"));
+
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Registry>::revoke_membership_attestation(
+            test_env.env.clone(),
+            coop.clone(),
+            test_env.member1.clone(),
+        )
+        .unwrap();
+    });
+    assert!(!is_member_of(&test_env, &coop, &test_env.member1));
+}
+
+#[test]
+fn test_attestation_ignored_after_deregistration() {
+    let test_env = setup_test();
+    let coop = Address::generate(&test_env.env);
+    register(&test_env, &coop).unwrap();
+    attest(&test_env, &coop, &test_env.member1).unwrap();
+
+    deregister(&test_env, &coop).unwrap();
+
+    assert!(!is_member_of(&test_env, &coop, &test_env.member1));
+}
+
+#[test]
+fn test_consumer_reads_registry_cross_contract() {
+    let test_env = setup_test();
+    let coop = Address::generate(&test_env.env);
+    let consumer_id = test_env.env.register(MockConsumer, ());
+    let consumer = MockConsumerClient::new(&test_env.env, &consumer_id);
+
+    assert!(!consumer.can_trade(&test_env.contract_id, &coop, &test_env.member1));
+
+    register(&test_env, &coop).unwrap();
+    attest(&test_env, &coop, &test_env.member1).unwrap();
+    assert!(consumer.can_trade(&test_env.contract_id, &coop, &test_env.member1));
+    assert!(!consumer.can_trade(&test_env.contract_id, &coop, &test_env.member2));
+
+    deregister(&test_env, &coop).unwrap();
+    assert!(!consumer.can_trade(&test_env.contract_id, &coop, &test_env.member1));
+}
@@ -0,0 +1,318 @@
+use crate::datatype::{CooperativeError, TreasuryAction, TreasuryActionStatus};
+use crate::interface::Treasury;
+use crate::tests::utils::*;
+use crate::treasury::TREASURY_ACTION_TTL;
+use crate::CooperativeManagementContract;
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Ledger, token, vec, Address, Vec,
+};
+
+fn setup_treasury() -> (TestEnv, Address) {
+    let test_env = setup_test();
+    let signers = vec![
+        &test_env.env,
+        test_env.member1.clone(),
+        test_env.member2.clone(),
+        test_env.member3.clone(),
+    ];
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::set_treasury_signers(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            signers,
+            2,
+        )
+        .unwrap();
+    });
+
+    let token = test_env
+        .env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+    token::StellarAssetClient::new(&test_env.env, &token).mint(&test_env.contract_id, &5_000);
+    (test_env, token)
+}
+
+fn propose(
+    test_env: &TestEnv,
+    proposer: &Address,
+    action: TreasuryAction,
+) -> Result<u64, CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::propose_treasury_action(
+            test_env.env.clone(),
+            proposer.clone(),
+            action,
+        )
+    })
+}
+
+fn approve(test_env: &TestEnv, signer: &Address, action_id: u64) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::approve_action(
+            test_env.env.clone(),
+            signer.clone(),
+            action_id,
+        )
+    })
+}
+
+fn execute(test_env: &TestEnv, signer: &Address, action_id: u64) -> Result<(), CooperativeError> {
+    test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::execute_action(
+            test_env.env.clone(),
+            signer.clone(),
+            action_id,
+        )
+    })
+}
+
+#[test]
+fn test_transfer_executes_at_threshold() {
+    let (test_env, token) = setup_treasury();
+    let recipient = Address::generate(&test_env.env);
+    let action_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::Transfer(token.clone(), recipient.clone(), 1_200),
+    )
+    .unwrap();
+    approve(&test_env, &test_env.member2, action_id).unwrap();
+    execute(&test_env, &test_env.member3, action_id).unwrap();
+
+    let token_client = token::Client::new(&test_env.env, &token);
+    assert_eq!(token_client.balance(&recipient), 1_200);
+    assert_eq!(token_client.balance(&test_env.contract_id), 3_800);
+
+    let action = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::get_treasury_action(
+            test_env.env.clone(),
+            action_id,
+        )
+        .unwrap()
+    });
+    assert_eq!(action.status, TreasuryActionStatus::Executed);
+    assert_eq!(
+        execute(&test_env, &test_env.member1, action_id),
+        Err(CooperativeError::ActionNotPending)
+    );
+}
+
+#[test]
+fn test_insufficient_approvals() {
+    let (test_env, token) = setup_treasury();
+    let action_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::Transfer(token.clone(), test_env.member1.clone(), 1_000),
+    )
+    .unwrap();
+
+    assert_eq!(
+        execute(&test_env, &test_env.member1, action_id),
+        Err(CooperativeError::InsufficientApprovals)
+    );
+    assert_eq!(
+        approve(&test_env, &test_env.member1, action_id),
+        Err(CooperativeError::AlreadyApproved)
+    );
+    assert_eq!(
+        token::Client::new(&test_env.env, &token).balance(&test_env.member1),
+        0
+    );
+}
+
+#[test]
+fn test_non_signer_rejected() {
+    let (test_env, _) = setup_treasury();
+
+    assert_eq!(
+        propose(
+            &test_env,
+            &test_env.admin,
+            TreasuryAction::ApproveBudget(symbol_short!("seeds"), 500),
+        ),
+        Err(CooperativeError::NotASigner)
+    );
+}
+
+#[test]
+fn test_approve_budget() {
+    let (test_env, _) = setup_treasury();
+    let action_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::ApproveBudget(symbol_short!("seeds"), 500),
+    )
+    .unwrap();
+    approve(&test_env, &test_env.member3, action_id).unwrap();
+    execute(&test_env, &test_env.member1, action_id).unwrap();
+
+    let budget = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::get_budget(
+            test_env.env.clone(),
+            symbol_short!("seeds"),
+        )
+    });
+    assert_eq!(budget, 500);
+}
+
+#[test]
+fn test_signer_rotation_requires_approvals() {
+    let (test_env, _) = setup_treasury();
+    let new_signer = Address::generate(&test_env.env);
+
+    // The admin cannot replace an existing signer set directly
+    let result = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::set_treasury_signers(
+            test_env.env.clone(),
+            test_env.admin.clone(),
+            Vec::from_array(&test_env.env, [new_signer.clone()]),
+            1,
+        )
+    });
+    assert_eq!(result, Err(CooperativeError::Unauthorized));
+
+    let new_signers = vec![&test_env.env, test_env.member1.clone(), new_signer.clone()];
+    let action_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::UpdateSigners(new_signers.clone(), 2),
+    )
+    .unwrap();
+    assert_eq!(
+        execute(&test_env, &test_env.member1, action_id),
+        Err(CooperativeError::InsufficientApprovals)
+    );
+    approve(&test_env, &test_env.member2, action_id).unwrap();
+    execute(&test_env, &test_env.member1, action_id).unwrap();
+
+    let (signers, threshold) = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::get_treasury_signers(test_env.env.clone())
+    });
+    assert_eq!(signers, new_signers);
+    assert_eq!(threshold, 2);
+
+    // Removed signers lose their rights immediately
+    assert_eq!(
+        propose(
+            &test_env,
+            &test_env.member2,
+            TreasuryAction::ApproveBudget(symbol_short!("seeds"), 500),
+        ),
+        Err(CooperativeError::NotASigner)
+    );
+}
+
+#[test]
+fn test_approvals_from_rotated_out_signers_do_not_count() {
+    let (test_env, _) = setup_treasury();
+    let budget_id = propose(
+        &test_env,
+        &test_env.member2,
+        TreasuryAction::ApproveBudget(symbol_short!("seeds"), 500),
+    )
+    .unwrap();
+
+    let rotation_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::UpdateSigners(
+            vec![
+                &test_env.env,
+                test_env.member1.clone(),
+                test_env.member3.clone(),
+            ],
+            2,
+        ),
+    )
+    .unwrap();
+    approve(&test_env, &test_env.member3, rotation_id).unwrap();
+    execute(&test_env, &test_env.member1, rotation_id).unwrap();
+
+    approve(&test_env, &test_env.member1, budget_id).unwrap();
+    assert_eq!(
+        execute(&test_env, &test_env.member1, budget_id),
+        Err(CooperativeError::InsufficientApprovals)
+    );
+}
+
+#[test]
+fn test_stale_action_expires() {
+    let (test_env, token) = setup_treasury();
+    let action_id = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::Transfer(token.clone(), test_env.member1.clone(), 100),
+    )
+    .unwrap();
+
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = TREASURY_ACTION_TTL);
+
+    assert_eq!(
+        approve(&test_env, &test_env.member2, action_id),
+        Err(CooperativeError::ActionExpired)
+    );
+    assert_eq!(
+        execute(&test_env, &test_env.member1, action_id),
+        Err(CooperativeError::ActionExpired)
+    );
+
+    let pending = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::get_pending_actions(test_env.env.clone())
+    });
+    assert_eq!(pending.len(), 0);
+}
+
+#[test]
+fn test_pending_actions_and_history() {
+    let (test_env, _) = setup_treasury();
+    let first = propose(
+        &test_env,
+        &test_env.member1,
+        TreasuryAction::ApproveBudget(symbol_short!("seeds"), 500),
+    )
+    .unwrap();
+    let second = propose(
+        &test_env,
+        &test_env.member2,
+        TreasuryAction::ApproveBudget(symbol_short!("fuel"), 300),
+    )
+    .unwrap();
+    approve(&test_env, &test_env.member3, first).unwrap();
+    execute(&test_env, &test_env.member3, first).unwrap();
+
+    let (pending, history) = test_env.env.as_contract(&test_env.contract_id, || {
+        (
+            <CooperativeManagementContract as Treasury>::get_pending_actions(test_env.env.clone()),
+            <CooperativeManagementContract as Treasury>::get_treasury_history(
+                test_env.env.clone(),
+                0,
+                10,
+            ),
+        )
+    });
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().id, second);
+
+    assert_eq!(history.len(), 4);
+    let executed = history.get(3).unwrap();
+    assert_eq!(executed.action_id, first);
+    assert_eq!(executed.event, symbol_short!("executed"));
+    assert_eq!(executed.actor, test_env.member3);
+
+    // Later pages pick up where the previous one stopped
+    let page = test_env.env.as_contract(&test_env.contract_id, || {
+        <CooperativeManagementContract as Treasury>::get_treasury_history(
+            test_env.env.clone(),
+            2,
+            10,
+        )
+    });
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().event, symbol_short!("approved"));
+    assert_eq!(page.get(1), history.get(3));
+}
@@ -1,6 +1,10 @@
 use crate::datatype::DataKey;
+use crate::interface::ProfitDistribution;
 use crate::CooperativeManagementContract;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+/// Capital token balance every test member starts with
+pub const MEMBER_CAPITAL: i128 = 1_000_000;
 
 pub struct TestEnv {
     pub env: Env,
@@ -9,6 +13,8 @@ pub struct TestEnv {
     pub member1: Address,
     pub member2: Address,
     pub member3: Address,
+    /// Token paid in through `pool_investment`
+    pub capital_token: Address,
 }
 
 pub fn setup_test() -> TestEnv {
@@ -28,6 +34,22 @@ pub fn setup_test() -> TestEnv {
         env.storage().persistent().set(&admin_key, &admin);
     });
 
+    let capital_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let capital = token::StellarAssetClient::new(&env, &capital_token);
+    for member in [&member1, &member2, &member3] {
+        capital.mint(member, &MEMBER_CAPITAL);
+    }
+    env.as_contract(&contract_id, || {
+        <CooperativeManagementContract as ProfitDistribution>::set_capital_token(
+            env.clone(),
+            admin.clone(),
+            capital_token.clone(),
+        )
+        .unwrap();
+    });
+
     TestEnv {
         env,
         contract_id,
@@ -35,6 +57,7 @@ pub fn setup_test() -> TestEnv {
         member1,
         member2,
         member3,
+        capital_token,
     }
 }
 
@@ -0,0 +1,292 @@
+use crate::datatype::{
+    CooperativeError, DataKey, TreasuryAction, TreasuryActionStatus, TreasuryAuditEntry,
+    TreasuryProposal,
+};
+use crate::interface::Treasury;
+use crate::membership::require_admin;
+use crate::{
+    CooperativeManagementContract, CooperativeManagementContractArgs,
+    CooperativeManagementContractClient,
+};
+use soroban_sdk::{contractimpl, symbol_short, token, Address, Env, Symbol, Vec};
+
+/// Treasury actions that do not reach the threshold within this time can no longer run
+pub const TREASURY_ACTION_TTL: u64 = 7 * 24 * 60 * 60;
+
+fn signers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::TreasurySigners)
+        .unwrap_or(Vec::new(env))
+}
+
+fn threshold(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<DataKey, u32>(&DataKey::TreasuryThreshold)
+        .unwrap_or(0)
+}
+
+fn require_signer(env: &Env, signer: &Address) -> Result<(), CooperativeError> {
+    signer.require_auth();
+    if !signers(env).contains(signer) {
+        return Err(CooperativeError::NotASigner);
+    }
+    Ok(())
+}
+
+fn store_signers(
+    env: &Env,
+    new_signers: &Vec<Address>,
+    new_threshold: u32,
+) -> Result<(), CooperativeError> {
+    // Duplicates would let one key count twice towards the threshold
+    let mut unique = Vec::new(env);
+    for signer in new_signers.iter() {
+        if unique.contains(&signer) {
+            return Err(CooperativeError::InvalidInput);
+        }
+        unique.push_back(signer);
+    }
+    if new_threshold == 0 || new_threshold > unique.len() {
+        return Err(CooperativeError::InvalidInput);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::TreasurySigners, &unique);
+    env.storage()
+        .persistent()
+        .set(&DataKey::TreasuryThreshold, &new_threshold);
+    Ok(())
+}
+
+/// Pending actions past their expiry are reported as expired without a write
+fn load_action(env: &Env, action_id: u64) -> Result<TreasuryProposal, CooperativeError> {
+    let mut action = env
+        .storage()
+        .persistent()
+        .get::<DataKey, TreasuryProposal>(&DataKey::TreasuryAction(action_id))
+        .ok_or(CooperativeError::ActionNotFound)?;
+    if action.status == TreasuryActionStatus::Pending
+        && env.ledger().timestamp() >= action.expires_at
+    {
+        action.status = TreasuryActionStatus::Expired;
+    }
+    Ok(action)
+}
+
+fn load_pending(env: &Env, action_id: u64) -> Result<TreasuryProposal, CooperativeError> {
+    let action = load_action(env, action_id)?;
+    match action.status {
+        TreasuryActionStatus::Pending => Ok(action),
+        TreasuryActionStatus::Expired => Err(CooperativeError::ActionExpired),
+        TreasuryActionStatus::Executed => Err(CooperativeError::ActionNotPending),
+    }
+}
+
+/// Only approvals from the current signer set count after a rotation
+fn valid_approvals(env: &Env, action: &TreasuryProposal) -> u32 {
+    let current = signers(env);
+    let mut count = 0;
+    for approver in action.approvals.iter() {
+        if current.contains(&approver) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn record(env: &Env, action_id: u64, event: Symbol, actor: &Address) {
+    // Entries are stored one per key so the trail can grow without bound
+    let index = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::TreasuryHistoryCount)
+        .unwrap_or(0);
+    env.storage().persistent().set(
+        &DataKey::TreasuryHistoryEntry(index),
+        &TreasuryAuditEntry {
+            action_id,
+            event: event.clone(),
+            actor: actor.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::TreasuryHistoryCount, &(index + 1));
+    env.events().publish(
+        (symbol_short!("treasury"), event),
+        (action_id, actor.clone()),
+    );
+}
+
+#[contractimpl]
+impl Treasury for CooperativeManagementContract {
+    fn set_treasury_signers(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), CooperativeError> {
+        require_admin(&env, &admin)?;
+
+        // The admin only bootstraps the first signer set; rotations go through `UpdateSigners`
+        if env.storage().persistent().has(&DataKey::TreasurySigners) {
+            return Err(CooperativeError::Unauthorized);
+        }
+        store_signers(&env, &signers, threshold)
+    }
+
+    fn get_treasury_signers(env: Env) -> (Vec<Address>, u32) {
+        (signers(&env), threshold(&env))
+    }
+
+    fn propose_treasury_action(
+        env: Env,
+        proposer: Address,
+        action: TreasuryAction,
+    ) -> Result<u64, CooperativeError> {
+        require_signer(&env, &proposer)?;
+        match &action {
+            TreasuryAction::Transfer(_, _, amount) | TreasuryAction::ApproveBudget(_, amount)
+                if *amount <= 0 =>
+            {
+                return Err(CooperativeError::InvalidInput);
+            }
+            _ => {}
+        }
+
+        let id = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::TreasuryActionCounter)
+            .unwrap_or(0)
+            + 1;
+        let now = env.ledger().timestamp();
+
+        // Proposing counts as the proposer's approval
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        let proposal = TreasuryProposal {
+            id,
+            proposer: proposer.clone(),
+            action,
+            approvals,
+            status: TreasuryActionStatus::Pending,
+            created_at: now,
+            expires_at: now + TREASURY_ACTION_TTL,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TreasuryAction(id), &proposal);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TreasuryActionCounter, &id);
+        record(&env, id, symbol_short!("proposed"), &proposer);
+        Ok(id)
+    }
+
+    fn approve_action(env: Env, signer: Address, action_id: u64) -> Result<(), CooperativeError> {
+        require_signer(&env, &signer)?;
+
+        let mut action = load_pending(&env, action_id)?;
+        if action.approvals.contains(&signer) {
+            return Err(CooperativeError::AlreadyApproved);
+        }
+
+        action.approvals.push_back(signer.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::TreasuryAction(action_id), &action);
+        record(&env, action_id, symbol_short!("approved"), &signer);
+        Ok(())
+    }
+
+    fn execute_action(env: Env, signer: Address, action_id: u64) -> Result<(), CooperativeError> {
+        require_signer(&env, &signer)?;
+
+        let mut action = load_pending(&env, action_id)?;
+        if valid_approvals(&env, &action) < threshold(&env) {
+            return Err(CooperativeError::InsufficientApprovals);
+        }
+
+        action.status = TreasuryActionStatus::Executed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::TreasuryAction(action_id), &action);
+
+        match &action.action {
+            TreasuryAction::Transfer(token, to, amount) => {
+                token::Client::new(&env, token).transfer(
+                    &env.current_contract_address(),
+                    to,
+                    amount,
+                );
+            }
+            TreasuryAction::ApproveBudget(category, amount) => {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Budget(category.clone()), amount);
+            }
+            TreasuryAction::UpdateSigners(new_signers, new_threshold) => {
+                store_signers(&env, new_signers, *new_threshold)?;
+            }
+        }
+
+        record(&env, action_id, symbol_short!("executed"), &signer);
+        Ok(())
+    }
+
+    fn get_treasury_action(env: Env, action_id: u64) -> Result<TreasuryProposal, CooperativeError> {
+        load_action(&env, action_id)
+    }
+
+    fn get_pending_actions(env: Env) -> Vec<TreasuryProposal> {
+        let count = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::TreasuryActionCounter)
+            .unwrap_or(0);
+
+        let mut pending = Vec::new(&env);
+        for id in 1..=count {
+            if let Ok(action) = load_action(&env, id) {
+                if action.status == TreasuryActionStatus::Pending {
+                    pending.push_back(action);
+                }
+            }
+        }
+        pending
+    }
+
+    fn get_treasury_history(env: Env, offset: u32, limit: u32) -> Vec<TreasuryAuditEntry> {
+        let count = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::TreasuryHistoryCount)
+            .unwrap_or(0);
+
+        let mut history = Vec::new(&env);
+        let end = count.min(offset as u64 + limit as u64);
+        for index in offset as u64..end {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, TreasuryAuditEntry>(&DataKey::TreasuryHistoryEntry(index))
+            {
+                history.push_back(entry);
+            }
+        }
+        history
+    }
+
+    fn get_budget(env: Env, category: Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<DataKey, i128>(&DataKey::Budget(category))
+            .unwrap_or(0)
+    }
+}
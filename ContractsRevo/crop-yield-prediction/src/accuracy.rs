@@ -0,0 +1,259 @@
+use crate::bonding::BondService;
+use crate::types::{
+    ActualYield, DataKey, PredictionScore, PredictionSubmission, PredictorAccuracy, SeasonOutcome,
+};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Number of most recent scored seasons kept per predictor
+pub const ACCURACY_WINDOW: u32 = 10;
+/// Number of predictors kept on each crop leaderboard
+pub const LEADERBOARD_SIZE: u32 = 10;
+
+const BPS: i128 = 10_000;
+
+pub struct AccuracyService;
+
+impl AccuracyService {
+    /// Score every participant of a season against its actual yield.
+    /// Participants are fixed when the actual is first reported: each
+    /// registered predictor plus anyone who had already submitted.
+    /// Re-reports rescore the same participants and replace their outcomes.
+    pub fn score_season(env: &Env, actual: &ActualYield, first_report: bool) {
+        let season_id =
+            utils::generate_season_id(env, &actual.crop, &actual.region, &actual.season);
+
+        let prediction_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submissions(
+                actual.crop.clone(),
+                actual.region.clone(),
+                actual.season.clone(),
+            ))
+            .unwrap_or(Vec::new(env));
+
+        let mut predicted: Vec<Address> = Vec::new(env);
+        for prediction_id in prediction_ids.iter() {
+            let submission: PredictionSubmission = match env
+                .storage()
+                .persistent()
+                .get(&DataKey::Submission(prediction_id.clone()))
+            {
+                Some(submission) => submission,
+                None => continue,
+            };
+
+            let score = Self::score_prediction(&submission, actual);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Score(prediction_id), &score);
+            if first_report {
+                BondService::settle(env, &score, &actual.reporter);
+            }
+
+            // Late predictions are scored but count as missed
+            Self::record_outcome(
+                env,
+                &submission.predictor,
+                &actual.crop,
+                SeasonOutcome {
+                    season_id: season_id.clone(),
+                    covered: !score.late,
+                    abs_error: score.abs_error,
+                    pct_error_bps: score.pct_error_bps,
+                },
+                first_report,
+            );
+            predicted.push_back(submission.predictor);
+        }
+
+        if first_report {
+            let predictors: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Predictors)
+                .unwrap_or(Vec::new(env));
+            for predictor in predictors.iter() {
+                if !predicted.contains(&predictor) {
+                    Self::record_outcome(
+                        env,
+                        &predictor,
+                        &actual.crop,
+                        SeasonOutcome {
+                            season_id: season_id.clone(),
+                            covered: false,
+                            abs_error: 0,
+                            pct_error_bps: 0,
+                        },
+                        true,
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn score_prediction(
+        submission: &PredictionSubmission,
+        actual: &ActualYield,
+    ) -> PredictionScore {
+        let abs_error =
+            (submission.predicted_yield_kg_per_ha - actual.actual_yield_kg_per_ha).abs();
+        let pct_error_bps = if actual.actual_yield_kg_per_ha > 0 {
+            abs_error * BPS / actual.actual_yield_kg_per_ha
+        } else if abs_error == 0 {
+            0
+        } else {
+            BPS
+        };
+
+        PredictionScore {
+            prediction_id: submission.prediction_id.clone(),
+            predictor: submission.predictor.clone(),
+            predicted_yield_kg_per_ha: submission.predicted_yield_kg_per_ha,
+            actual_yield_kg_per_ha: actual.actual_yield_kg_per_ha,
+            abs_error,
+            pct_error_bps,
+            late: submission.submitted_at > actual.first_reported_at,
+        }
+    }
+
+    /// Summarize a window of outcomes
+    pub fn summarize(predictor: &Address, outcomes: &Vec<SeasonOutcome>) -> PredictorAccuracy {
+        let mut scored: u32 = 0;
+        let mut total_abs_error: i128 = 0;
+        let mut total_pct_error: i128 = 0;
+        for outcome in outcomes.iter() {
+            if outcome.covered {
+                scored += 1;
+                total_abs_error += outcome.abs_error;
+                total_pct_error += outcome.pct_error_bps;
+            }
+        }
+        let missed = outcomes.len() - scored;
+
+        let (mean_abs_error, mean_pct_error_bps) = if scored > 0 {
+            (
+                total_abs_error / scored as i128,
+                total_pct_error / scored as i128,
+            )
+        } else {
+            (0, 0)
+        };
+        let coverage_bps = if outcomes.is_empty() {
+            0
+        } else {
+            scored * BPS as u32 / outcomes.len()
+        };
+        let rating_bps = if scored > 0 {
+            let accuracy = BPS - mean_pct_error_bps.min(BPS);
+            (accuracy * coverage_bps as i128 / BPS) as u32
+        } else {
+            0
+        };
+
+        PredictorAccuracy {
+            predictor: predictor.clone(),
+            scored,
+            missed,
+            mean_abs_error,
+            mean_pct_error_bps,
+            coverage_bps,
+            rating_bps,
+        }
+    }
+
+    pub fn get_accuracy(env: &Env, predictor: &Address) -> PredictorAccuracy {
+        let outcomes: Vec<SeasonOutcome> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Outcomes(predictor.clone()))
+            .unwrap_or(Vec::new(env));
+        Self::summarize(predictor, &outcomes)
+    }
+
+    pub fn get_leaderboard(env: &Env, crop: &Symbol) -> Vec<PredictorAccuracy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Leaderboard(crop.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn record_outcome(
+        env: &Env,
+        predictor: &Address,
+        crop: &Symbol,
+        outcome: SeasonOutcome,
+        append: bool,
+    ) {
+        Self::push_outcome(
+            env,
+            &DataKey::Outcomes(predictor.clone()),
+            outcome.clone(),
+            append,
+        );
+        let crop_outcomes = Self::push_outcome(
+            env,
+            &DataKey::CropOutcomes(predictor.clone(), crop.clone()),
+            outcome,
+            append,
+        );
+        Self::update_leaderboard(env, crop, Self::summarize(predictor, &crop_outcomes));
+    }
+
+    /// Replace the outcome for the same season if it is still in the window,
+    /// otherwise append it and drop the oldest beyond the window
+    fn push_outcome(
+        env: &Env,
+        key: &DataKey,
+        outcome: SeasonOutcome,
+        append: bool,
+    ) -> Vec<SeasonOutcome> {
+        let mut outcomes: Vec<SeasonOutcome> =
+            env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+
+        match outcomes
+            .iter()
+            .position(|existing| existing.season_id == outcome.season_id)
+        {
+            Some(index) => outcomes.set(index as u32, outcome),
+            None if append => {
+                outcomes.push_back(outcome);
+                if outcomes.len() > ACCURACY_WINDOW {
+                    outcomes.pop_front();
+                }
+            }
+            None => return outcomes,
+        }
+
+        env.storage().persistent().set(key, &outcomes);
+        outcomes
+    }
+
+    /// Keep the crop leaderboard sorted by rating, highest first, and bounded
+    /// to `LEADERBOARD_SIZE`; the lowest rated entry is evicted when full
+    fn update_leaderboard(env: &Env, crop: &Symbol, accuracy: PredictorAccuracy) {
+        let mut board = Self::get_leaderboard(env, crop);
+        if let Some(index) = board
+            .iter()
+            .position(|entry| entry.predictor == accuracy.predictor)
+        {
+            board.remove(index as u32);
+        }
+
+        if accuracy.scored > 0 {
+            let index = board
+                .iter()
+                .position(|entry| accuracy.rating_bps > entry.rating_bps)
+                .unwrap_or(board.len() as usize);
+            board.insert(index as u32, accuracy);
+            if board.len() > LEADERBOARD_SIZE {
+                board.pop_back();
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Leaderboard(crop.clone()), &board);
+    }
+}
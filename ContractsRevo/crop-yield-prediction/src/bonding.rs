@@ -0,0 +1,111 @@
+use crate::types::{BondConfig, BondStatus, CropYieldError, DataKey, PredictionScore};
+use soroban_sdk::{token, Address, Env};
+
+const BPS: i128 = 10_000;
+
+pub struct BondService;
+
+impl BondService {
+    fn config(env: &Env) -> Option<BondConfig> {
+        env.storage().instance().get(&DataKey::BondConfig)
+    }
+
+    pub fn status(env: &Env, predictor: &Address) -> BondStatus {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bond(predictor.clone()))
+            .unwrap_or(BondStatus {
+                predictor: predictor.clone(),
+                bonded: 0,
+                pending_predictions: 0,
+                total_slashed: 0,
+                can_withdraw: true,
+            })
+    }
+
+    fn save(env: &Env, mut status: BondStatus) {
+        status.can_withdraw = status.pending_predictions == 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Bond(status.predictor.clone()), &status);
+    }
+
+    /// Fail unless bonding is disabled or the predictor has the minimum bond
+    pub fn require_bond(env: &Env, predictor: &Address) -> Result<(), CropYieldError> {
+        if let Some(config) = Self::config(env) {
+            if Self::status(env, predictor).bonded < config.min_bond {
+                return Err(CropYieldError::InsufficientBond);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn post(env: &Env, predictor: &Address, amount: i128) -> Result<(), CropYieldError> {
+        let config = Self::config(env).ok_or(CropYieldError::BondNotConfigured)?;
+        token::Client::new(env, &config.token).transfer(
+            predictor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let mut status = Self::status(env, predictor);
+        status.bonded += amount;
+        Self::save(env, status);
+        Ok(())
+    }
+
+    pub fn withdraw(env: &Env, predictor: &Address, amount: i128) -> Result<(), CropYieldError> {
+        let config = Self::config(env).ok_or(CropYieldError::BondNotConfigured)?;
+        let mut status = Self::status(env, predictor);
+        if status.pending_predictions > 0 {
+            return Err(CropYieldError::PredictionsPending);
+        }
+        if amount > status.bonded {
+            return Err(CropYieldError::InsufficientBond);
+        }
+
+        status.bonded -= amount;
+        Self::save(env, status);
+        token::Client::new(env, &config.token).transfer(
+            &env.current_contract_address(),
+            predictor,
+            &amount,
+        );
+        Ok(())
+    }
+
+    pub fn add_pending(env: &Env, predictor: &Address) {
+        let mut status = Self::status(env, predictor);
+        status.pending_predictions += 1;
+        Self::save(env, status);
+    }
+
+    /// Release a prediction from pending when its season is first scored and
+    /// slash the bond if its error exceeds the configured threshold
+    pub fn settle(env: &Env, score: &PredictionScore, reporter: &Address) {
+        let mut status = Self::status(env, &score.predictor);
+        status.pending_predictions = status.pending_predictions.saturating_sub(1);
+
+        if let Some(config) = Self::config(env) {
+            if score.pct_error_bps > config.slash_threshold_bps {
+                let slash = status.bonded * config.slash_bps as i128 / BPS;
+                if slash > 0 {
+                    status.bonded -= slash;
+                    status.total_slashed += slash;
+                    let recipient = if config.reward_reporter {
+                        reporter.clone()
+                    } else {
+                        config.treasury.clone()
+                    };
+                    token::Client::new(env, &config.token).transfer(
+                        &env.current_contract_address(),
+                        &recipient,
+                        &slash,
+                    );
+                }
+            }
+        }
+
+        Self::save(env, status);
+    }
+}
@@ -1,9 +1,13 @@
 #![no_std]
+mod accuracy;
+mod bonding;
+mod outlook;
 mod prediction;
 mod reporting;
 mod types;
 mod utils;
 
+pub use crate::outlook::*;
 pub use crate::prediction::*;
 pub use crate::reporting::*;
 
@@ -0,0 +1,135 @@
+use crate::types::{DataKey, Outlook, OutlookConsumerConfig, PredictionSubmission};
+use soroban_sdk::{contractclient, Env, String, Symbol, Vec};
+
+const BPS: i128 = 10_000;
+
+/// Interface a consumer contract (e.g. farmer insurance or price stabilization)
+/// implements to be told about large moves in a regional outlook
+#[contractclient(name = "OutlookConsumerClient")]
+pub trait OutlookConsumer {
+    fn on_outlook_update(env: Env, crop: Symbol, region: Symbol, season: String, outlook: Outlook);
+}
+
+pub struct OutlookService;
+
+impl OutlookService {
+    /// Update the sorted prediction values for the submission's season,
+    /// replacing the predictor's previous value if it superseded one
+    pub fn record_prediction(
+        env: &Env,
+        submission: &PredictionSubmission,
+        previous_value: Option<i128>,
+    ) {
+        let values_key = DataKey::OutlookValues(
+            submission.crop.clone(),
+            submission.region.clone(),
+            submission.season.clone(),
+        );
+        let mut values: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&values_key)
+            .unwrap_or(Vec::new(env));
+
+        if let Some(previous_value) = previous_value {
+            if let Some(index) = values.first_index_of(previous_value) {
+                values.remove(index);
+            }
+        }
+        let index = values
+            .iter()
+            .position(|value| value > submission.predicted_yield_kg_per_ha)
+            .unwrap_or(values.len() as usize);
+        values.insert(index as u32, submission.predicted_yield_kg_per_ha);
+        env.storage().persistent().set(&values_key, &values);
+
+        let outlook = Self::compute(env, &values);
+        env.storage().persistent().set(
+            &DataKey::Outlook(
+                submission.crop.clone(),
+                submission.region.clone(),
+                submission.season.clone(),
+            ),
+            &outlook,
+        );
+
+        Self::notify_consumer(
+            env,
+            &submission.crop,
+            &submission.region,
+            &submission.season,
+            &outlook,
+        );
+    }
+
+    /// Median and dispersion of sorted values; an even count averages the
+    /// two middle values
+    pub fn compute(env: &Env, values: &Vec<i128>) -> Outlook {
+        let count = values.len();
+        let median_prediction = if count == 0 {
+            0
+        } else if count % 2 == 1 {
+            values.get(count / 2).unwrap()
+        } else {
+            (values.get(count / 2 - 1).unwrap() + values.get(count / 2).unwrap()) / 2
+        };
+
+        let dispersion_bps = if count == 0 || median_prediction == 0 {
+            0
+        } else {
+            let mut total_deviation: i128 = 0;
+            for value in values.iter() {
+                total_deviation += (value - median_prediction).abs();
+            }
+            total_deviation * BPS / (count as i128 * median_prediction)
+        };
+
+        Outlook {
+            median_prediction,
+            prediction_count: count,
+            dispersion_bps,
+            last_updated: env.ledger().timestamp(),
+        }
+    }
+
+    /// Push the outlook to the configured consumer when the median has moved
+    /// more than the threshold since the last successful notification.
+    /// A failing consumer does not block prediction submission.
+    fn notify_consumer(
+        env: &Env,
+        crop: &Symbol,
+        region: &Symbol,
+        season: &String,
+        outlook: &Outlook,
+    ) {
+        let config: OutlookConsumerConfig =
+            match env.storage().instance().get(&DataKey::OutlookConsumer) {
+                Some(config) => config,
+                None => return,
+            };
+
+        let notified_key = DataKey::NotifiedMedian(crop.clone(), region.clone(), season.clone());
+        if let Some(notified) = env.storage().persistent().get::<_, i128>(&notified_key) {
+            let moved = (outlook.median_prediction - notified).abs();
+            let moved_bps = if notified == 0 {
+                if moved == 0 {
+                    0
+                } else {
+                    i128::MAX
+                }
+            } else {
+                moved * BPS / notified
+            };
+            if moved_bps <= config.threshold_bps as i128 {
+                return;
+            }
+        }
+
+        let client = OutlookConsumerClient::new(env, &config.consumer);
+        if let Ok(Ok(())) = client.try_on_outlook_update(crop, region, season, outlook) {
+            env.storage()
+                .persistent()
+                .set(&notified_key, &outlook.median_prediction);
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use crate::types::{Crop, CropYieldError, DataKey, DataSource, YieldPrediction};
+use crate::accuracy::AccuracyService;
+use crate::bonding::BondService;
+use crate::outlook::OutlookService;
+use crate::reporting::ReportingService;
+use crate::types::{
+    ActualYield, BondConfig, BondStatus, Crop, CropYieldError, DataKey, DataSource, Observation,
+    Outlook, OutlookConsumerConfig, PredictionScore, PredictionSubmission, PredictorAccuracy,
+    ReportStatus, SeasonReport, YieldPrediction,
+};
 use crate::utils;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contract]
 pub struct CropYieldPredictionContract;
@@ -197,4 +205,699 @@ impl CropYieldPredictionContract {
             None => Err(CropYieldError::CropNotFound),
         }
     }
+
+    /// Register an address allowed to submit predictions (admin only)
+    pub fn add_predictor(env: Env, predictor: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+
+        if Self::is_predictor(env.clone(), predictor.clone()) {
+            return Ok(());
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Predictor(predictor.clone()), &true);
+
+        let mut predictors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Predictors)
+            .unwrap_or(Vec::new(&env));
+        predictors.push_back(predictor);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Predictors, &predictors);
+        Ok(())
+    }
+
+    /// Revoke a predictor; its earlier submissions are kept (admin only)
+    pub fn remove_predictor(env: Env, predictor: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Predictor(predictor.clone()));
+
+        let mut predictors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Predictors)
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = predictors.first_index_of(&predictor) {
+            predictors.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Predictors, &predictors);
+        }
+        Ok(())
+    }
+
+    /// Check whether an address is a registered predictor
+    pub fn is_predictor(env: Env, predictor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Predictor(predictor))
+    }
+
+    /// Submit a model prediction for a crop, region and season.
+    /// A predictor has one prediction per (crop, region, season); submitting
+    /// again supersedes it under the same ID and bumps its revision.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_prediction(
+        env: Env,
+        predictor: Address,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+        predicted_yield_kg_per_ha: i128,
+        model_version: Symbol,
+        inputs_hash: BytesN<32>,
+        observation_ids: Vec<u64>,
+    ) -> Result<BytesN<32>, CropYieldError> {
+        predictor.require_auth();
+
+        if !Self::is_predictor(env.clone(), predictor.clone()) {
+            return Err(CropYieldError::Unauthorized);
+        }
+        if season.is_empty() || observation_ids.is_empty() {
+            return Err(CropYieldError::InvalidInput);
+        }
+        if predicted_yield_kg_per_ha < 0 {
+            return Err(CropYieldError::InvalidYieldData);
+        }
+
+        // Inputs must already be anchored so they cannot be fitted afterwards
+        for observation_id in observation_ids.iter() {
+            Self::get_observation(env.clone(), observation_id)?;
+        }
+        BondService::require_bond(&env, &predictor)?;
+
+        let prediction_id =
+            utils::generate_submission_id(&env, &predictor, &crop, &region, &season);
+
+        let key = DataKey::Submission(prediction_id.clone());
+        let previous: Option<PredictionSubmission> = env.storage().persistent().get(&key);
+        let revision = match &previous {
+            Some(previous) => previous.revision + 1,
+            None => {
+                // First submission for this predictor, add it to the index
+                let index_key = DataKey::Submissions(crop.clone(), region.clone(), season.clone());
+                let mut ids: Vec<BytesN<32>> = env
+                    .storage()
+                    .persistent()
+                    .get(&index_key)
+                    .unwrap_or(Vec::new(&env));
+                ids.push_back(prediction_id.clone());
+                env.storage().persistent().set(&index_key, &ids);
+
+                // Seasons that already have an actual are scored right away
+                if !env.storage().persistent().has(&DataKey::Actual(
+                    crop.clone(),
+                    region.clone(),
+                    season.clone(),
+                )) {
+                    BondService::add_pending(&env, &predictor);
+                }
+                1
+            }
+        };
+
+        let submission = PredictionSubmission {
+            prediction_id: prediction_id.clone(),
+            predictor,
+            crop,
+            region,
+            season,
+            predicted_yield_kg_per_ha,
+            model_version,
+            inputs_hash,
+            observation_ids,
+            revision,
+            submitted_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &submission);
+
+        OutlookService::record_prediction(
+            &env,
+            &submission,
+            previous.map(|previous| previous.predicted_yield_kg_per_ha),
+        );
+
+        Ok(prediction_id)
+    }
+
+    /// Get a submitted prediction by ID
+    pub fn get_submitted_prediction(
+        env: Env,
+        prediction_id: BytesN<32>,
+    ) -> Result<PredictionSubmission, CropYieldError> {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submission(prediction_id))
+        {
+            Some(submission) => Ok(submission),
+            None => Err(CropYieldError::PredictionNotFound),
+        }
+    }
+
+    /// List the current submitted predictions for a crop, region and season,
+    /// one per predictor, in order of first submission
+    pub fn list_predictions(
+        env: Env,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<PredictionSubmission>, CropYieldError> {
+        let ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submissions(crop, region, season))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(ids.len());
+        for i in offset..end {
+            let id = ids.get(i).unwrap();
+            page.push_back(Self::get_submitted_prediction(env.clone(), id)?);
+        }
+
+        Ok(page)
+    }
+
+    /// Register an address allowed to report actual yields (admin only)
+    pub fn add_reporter(env: Env, reporter: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reporter(reporter), &true);
+        Ok(())
+    }
+
+    /// Revoke a reporter (admin only)
+    pub fn remove_reporter(env: Env, reporter: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Reporter(reporter));
+        Ok(())
+    }
+
+    /// Report the harvested yield for a crop, region and season and score
+    /// the season's predictions against it (admin or registered reporters).
+    /// Reporting again corrects the actual and rescores the same predictions.
+    pub fn report_actual_yield(
+        env: Env,
+        reporter: Address,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+        actual_yield_kg_per_ha: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), CropYieldError> {
+        reporter.require_auth();
+
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        if reporter != admin
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Reporter(reporter.clone()))
+        {
+            return Err(CropYieldError::Unauthorized);
+        }
+        if season.is_empty() {
+            return Err(CropYieldError::InvalidInput);
+        }
+        if actual_yield_kg_per_ha < 0 {
+            return Err(CropYieldError::InvalidYieldData);
+        }
+
+        let key = DataKey::Actual(crop.clone(), region.clone(), season.clone());
+        let now = env.ledger().timestamp();
+        let previous: Option<ActualYield> = env.storage().persistent().get(&key);
+        let first_report = previous.is_none();
+
+        let actual = ActualYield {
+            crop,
+            region,
+            season,
+            actual_yield_kg_per_ha,
+            evidence_hash,
+            reporter,
+            first_reported_at: previous.as_ref().map_or(now, |p| p.first_reported_at),
+            reported_at: now,
+            revision: previous.as_ref().map_or(1, |p| p.revision + 1),
+        };
+        env.storage().persistent().set(&key, &actual);
+
+        AccuracyService::score_season(&env, &actual, first_report);
+
+        Ok(())
+    }
+
+    /// Get the reported actual yield for a crop, region and season
+    pub fn get_actual_yield(
+        env: Env,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+    ) -> Result<ActualYield, CropYieldError> {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Actual(crop, region, season))
+        {
+            Some(actual) => Ok(actual),
+            None => Err(CropYieldError::ActualYieldNotFound),
+        }
+    }
+
+    /// Get the error of a submitted prediction once its actual is reported
+    pub fn get_prediction_score(
+        env: Env,
+        prediction_id: BytesN<32>,
+    ) -> Result<PredictionScore, CropYieldError> {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Score(prediction_id))
+        {
+            Some(score) => Ok(score),
+            None => Err(CropYieldError::PredictionNotFound),
+        }
+    }
+
+    /// Get a predictor's rolling accuracy across all crops
+    pub fn get_predictor_accuracy(env: Env, predictor: Address) -> PredictorAccuracy {
+        AccuracyService::get_accuracy(&env, &predictor)
+    }
+
+    /// Get the best rated predictors for a crop, highest rating first
+    pub fn get_leaderboard(env: Env, crop: Symbol, limit: u32) -> Vec<PredictorAccuracy> {
+        let board = AccuracyService::get_leaderboard(&env, &crop);
+        if board.len() <= limit {
+            board
+        } else {
+            board.slice(0..limit)
+        }
+    }
+
+    /// Get the consensus outlook from the current predictions for a crop,
+    /// region and season
+    pub fn get_regional_outlook(
+        env: Env,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+    ) -> Result<Outlook, CropYieldError> {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Outlook(crop, region, season))
+        {
+            Some(outlook) => Ok(outlook),
+            None => Err(CropYieldError::PredictionNotFound),
+        }
+    }
+
+    /// Set the contract notified when an outlook median moves by more than
+    /// `threshold_bps` since its last notification (admin only)
+    pub fn set_outlook_consumer(
+        env: Env,
+        admin: Address,
+        consumer: Address,
+        threshold_bps: u32,
+    ) -> Result<(), CropYieldError> {
+        let stored_admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        if admin != stored_admin {
+            return Err(CropYieldError::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(
+            &DataKey::OutlookConsumer,
+            &OutlookConsumerConfig {
+                consumer,
+                threshold_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the configured outlook consumer, if any
+    pub fn get_outlook_consumer(env: Env) -> Option<OutlookConsumerConfig> {
+        env.storage().instance().get(&DataKey::OutlookConsumer)
+    }
+
+    /// Commit the hash of a weather or soil observation for a region and
+    /// period. Observations are append-only and get sequential IDs.
+    pub fn anchor_observation(
+        env: Env,
+        observer: Address,
+        region: Symbol,
+        period: u64,
+        kind: Symbol,
+        data_hash: BytesN<32>,
+    ) -> Result<u64, CropYieldError> {
+        observer.require_auth();
+
+        let observation_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ObservationCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ObservationCounter, &observation_id);
+
+        let observation = Observation {
+            observation_id,
+            observer,
+            region: region.clone(),
+            period,
+            kind,
+            data_hash,
+            anchored_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Observation(observation_id), &observation);
+
+        let log_key = DataKey::RegionObservations(region);
+        let mut log: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&log_key)
+            .unwrap_or(Vec::new(&env));
+        log.push_back(observation_id);
+        env.storage().persistent().set(&log_key, &log);
+
+        Ok(observation_id)
+    }
+
+    /// Get an anchored observation by ID
+    pub fn get_observation(env: Env, observation_id: u64) -> Result<Observation, CropYieldError> {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Observation(observation_id))
+        {
+            Some(observation) => Ok(observation),
+            None => Err(CropYieldError::ObservationNotFound),
+        }
+    }
+
+    /// List a region's observations of one kind whose period falls within
+    /// `period_start..=period_end`, in anchoring order
+    pub fn get_observations(
+        env: Env,
+        region: Symbol,
+        period_start: u64,
+        period_end: u64,
+        kind: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Observation>, CropYieldError> {
+        if period_start > period_end {
+            return Err(CropYieldError::InvalidInput);
+        }
+
+        let log: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegionObservations(region))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let mut matched: u32 = 0;
+        for observation_id in log.iter() {
+            if page.len() >= limit {
+                break;
+            }
+            let observation = Self::get_observation(env.clone(), observation_id)?;
+            if observation.kind != kind
+                || observation.period < period_start
+                || observation.period > period_end
+            {
+                continue;
+            }
+            if matched >= offset {
+                page.push_back(observation);
+            }
+            matched += 1;
+        }
+
+        Ok(page)
+    }
+
+    /// Get the anchored observations a submitted prediction was based on
+    pub fn get_prediction_inputs(
+        env: Env,
+        prediction_id: BytesN<32>,
+    ) -> Result<Vec<Observation>, CropYieldError> {
+        let submission = Self::get_submitted_prediction(env.clone(), prediction_id)?;
+
+        let mut inputs = Vec::new(&env);
+        for observation_id in submission.observation_ids.iter() {
+            inputs.push_back(Self::get_observation(env.clone(), observation_id)?);
+        }
+
+        Ok(inputs)
+    }
+
+    /// Register an address allowed to compile and sign season reports (admin only)
+    pub fn add_report_signer(env: Env, signer: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReportSigner(signer), &true);
+        Ok(())
+    }
+
+    /// Revoke a report signer (admin only)
+    pub fn remove_report_signer(env: Env, signer: Address) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReportSigner(signer));
+        Ok(())
+    }
+
+    /// Compile a draft report for a season (registered signers only).
+    /// Recompiling refreshes an unsigned draft in place; once the season's
+    /// report is published, a new version linked to it is created instead.
+    pub fn compile_season_report(
+        env: Env,
+        compiler: Address,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+    ) -> Result<BytesN<32>, CropYieldError> {
+        compiler.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReportSigner(compiler.clone()))
+        {
+            return Err(CropYieldError::Unauthorized);
+        }
+        if season.is_empty() {
+            return Err(CropYieldError::InvalidInput);
+        }
+
+        let latest_key = DataKey::LatestReport(crop.clone(), region.clone(), season.clone());
+        let latest: Option<BytesN<32>> = env.storage().persistent().get(&latest_key);
+        let (version, previous_report) = match latest {
+            Some(latest_id) => {
+                let latest = Self::get_report(env.clone(), latest_id.clone())?;
+                if latest.status == ReportStatus::Published {
+                    (latest.version + 1, Some(latest_id))
+                } else {
+                    (latest.version, latest.previous_report)
+                }
+            }
+            None => (1, None),
+        };
+
+        let report_id = utils::generate_report_id(&env, &crop, &region, &season, version);
+        let is_new = !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Report(report_id.clone()));
+
+        let report = ReportingService::compile_season_report(
+            &env,
+            report_id.clone(),
+            compiler,
+            crop,
+            region.clone(),
+            season,
+            version,
+            previous_report,
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::Report(report_id.clone()), &report);
+        env.storage().persistent().set(&latest_key, &report_id);
+
+        if is_new {
+            let index_key = DataKey::RegionReports(region);
+            let mut reports: Vec<BytesN<32>> = env
+                .storage()
+                .persistent()
+                .get(&index_key)
+                .unwrap_or(Vec::new(&env));
+            reports.push_back(report_id.clone());
+            env.storage().persistent().set(&index_key, &reports);
+        }
+
+        Ok(report_id)
+    }
+
+    /// Sign a draft report; the second distinct signer publishes it, after
+    /// which it can no longer change
+    pub fn sign_report(
+        env: Env,
+        signer: Address,
+        report_id: BytesN<32>,
+    ) -> Result<ReportStatus, CropYieldError> {
+        signer.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReportSigner(signer.clone()))
+        {
+            return Err(CropYieldError::Unauthorized);
+        }
+
+        let mut report = Self::get_report(env.clone(), report_id.clone())?;
+        if report.status == ReportStatus::Published {
+            return Err(CropYieldError::ReportPublished);
+        }
+        if report.signers.contains(&signer) {
+            return Err(CropYieldError::AlreadySigned);
+        }
+
+        report.signers.push_back(signer);
+        if report.signers.len() >= 2 {
+            report.status = ReportStatus::Published;
+            report.published_at = env.ledger().timestamp();
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Report(report_id), &report);
+
+        Ok(report.status)
+    }
+
+    /// Get a season report by ID
+    pub fn get_report(env: Env, report_id: BytesN<32>) -> Result<SeasonReport, CropYieldError> {
+        match env.storage().persistent().get(&DataKey::Report(report_id)) {
+            Some(report) => Ok(report),
+            None => Err(CropYieldError::ReportNotFound),
+        }
+    }
+
+    /// List a region's reports, every version included, in compilation order
+    pub fn list_reports(
+        env: Env,
+        region: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SeasonReport>, CropYieldError> {
+        let report_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegionReports(region))
+            .unwrap_or(Vec::new(&env));
+
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(report_ids.len());
+        for i in offset..end {
+            page.push_back(Self::get_report(env.clone(), report_ids.get(i).unwrap())?);
+        }
+
+        Ok(page)
+    }
+
+    /// Configure predictor bonds; once set, `submit_prediction` requires at
+    /// least `min_bond` to be posted (admin only)
+    pub fn set_bond_config(env: Env, config: BondConfig) -> Result<(), CropYieldError> {
+        let admin: Address = match env.storage().instance().get(&DataKey::ADMIN) {
+            Some(admin) => admin,
+            None => return Err(CropYieldError::ContractNotInitialized),
+        };
+        admin.require_auth();
+
+        if config.min_bond < 0 || config.slash_threshold_bps < 0 || config.slash_bps > 10_000 {
+            return Err(CropYieldError::InvalidInput);
+        }
+        env.storage().instance().set(&DataKey::BondConfig, &config);
+        Ok(())
+    }
+
+    /// Get the bond configuration, if bonding is enabled
+    pub fn get_bond_config(env: Env) -> Option<BondConfig> {
+        env.storage().instance().get(&DataKey::BondConfig)
+    }
+
+    /// Post a bond in the configured token, held by this contract
+    pub fn post_bond(env: Env, predictor: Address, amount: i128) -> Result<(), CropYieldError> {
+        predictor.require_auth();
+
+        if amount <= 0 {
+            return Err(CropYieldError::InvalidInput);
+        }
+        BondService::post(&env, &predictor, amount)
+    }
+
+    /// Withdraw part or all of a bond; only allowed once every submitted
+    /// prediction has been scored
+    pub fn withdraw_bond(env: Env, predictor: Address, amount: i128) -> Result<(), CropYieldError> {
+        predictor.require_auth();
+
+        if amount <= 0 {
+            return Err(CropYieldError::InvalidInput);
+        }
+        BondService::withdraw(&env, &predictor, amount)
+    }
+
+    /// Get a predictor's bond, pending predictions and slashing history
+    pub fn get_bond_status(env: Env, predictor: Address) -> BondStatus {
+        BondService::status(&env, &predictor)
+    }
 }
@@ -1,9 +1,93 @@
-use crate::types::{Crop, MarketInsight, YieldPrediction, YieldReport};
-use soroban_sdk::{Env, String, Vec};
+use crate::accuracy::AccuracyService;
+use crate::types::{
+    ActualYield, Crop, DataKey, MarketInsight, Outlook, PredictionSubmission, ReportStatus,
+    SeasonReport, YieldPrediction, YieldReport,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+/// Number of leaderboard entries captured in a season report
+pub const REPORT_TOP_PREDICTORS: u32 = 5;
 
 pub struct ReportingService;
 
 impl ReportingService {
+    /// Snapshot the current outlook, actual yield, crop leaderboard and
+    /// prediction inputs of a season into an unsigned report
+    #[allow(clippy::too_many_arguments)]
+    pub fn compile_season_report(
+        env: &Env,
+        report_id: BytesN<32>,
+        compiler: Address,
+        crop: Symbol,
+        region: Symbol,
+        season: String,
+        version: u32,
+        previous_report: Option<BytesN<32>>,
+    ) -> SeasonReport {
+        let outlook: Option<Outlook> = env.storage().persistent().get(&DataKey::Outlook(
+            crop.clone(),
+            region.clone(),
+            season.clone(),
+        ));
+        let actual: Option<ActualYield> = env.storage().persistent().get(&DataKey::Actual(
+            crop.clone(),
+            region.clone(),
+            season.clone(),
+        ));
+
+        let leaderboard = AccuracyService::get_leaderboard(env, &crop);
+        let top_predictors = if leaderboard.len() > REPORT_TOP_PREDICTORS {
+            leaderboard.slice(0..REPORT_TOP_PREDICTORS)
+        } else {
+            leaderboard
+        };
+
+        let prediction_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submissions(
+                crop.clone(),
+                region.clone(),
+                season.clone(),
+            ))
+            .unwrap_or(Vec::new(env));
+        let mut observation_ids: Vec<u64> = Vec::new(env);
+        for prediction_id in prediction_ids.iter() {
+            if let Some(submission) = env
+                .storage()
+                .persistent()
+                .get::<_, PredictionSubmission>(&DataKey::Submission(prediction_id))
+            {
+                for observation_id in submission.observation_ids.iter() {
+                    if !observation_ids.contains(observation_id) {
+                        observation_ids.push_back(observation_id);
+                    }
+                }
+            }
+        }
+
+        SeasonReport {
+            report_id,
+            crop,
+            region,
+            season,
+            version,
+            previous_report,
+            compiler,
+            median_prediction: outlook.as_ref().map_or(0, |o| o.median_prediction),
+            prediction_count: outlook.as_ref().map_or(0, |o| o.prediction_count),
+            dispersion_bps: outlook.as_ref().map_or(0, |o| o.dispersion_bps),
+            actual_reported: actual.is_some(),
+            actual_yield_kg_per_ha: actual.map_or(0, |a| a.actual_yield_kg_per_ha),
+            top_predictors,
+            observation_count: observation_ids.len(),
+            status: ReportStatus::Draft,
+            signers: Vec::new(env),
+            compiled_at: env.ledger().timestamp(),
+            published_at: 0,
+        }
+    }
+
     /// Generate farmer report with recommendations
     pub fn generate_farmer_report(
         env: &Env,
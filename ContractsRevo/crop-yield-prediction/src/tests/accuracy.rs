@@ -0,0 +1,262 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env, String, Symbol,
+};
+
+use crate::{
+    accuracy::{ACCURACY_WINDOW, LEADERBOARD_SIZE},
+    types::CropYieldError,
+    CropYieldPredictionContractClient,
+};
+
+use super::utils::*;
+
+fn predictor(env: &Env, client: &CropYieldPredictionContractClient) -> Address {
+    let predictor = Address::generate(env);
+    client.add_predictor(&predictor);
+    predictor
+}
+
+fn submit(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    predictor: &Address,
+    season: &str,
+    yield_kg_per_ha: i128,
+) -> BytesN<32> {
+    client.submit_prediction(
+        predictor,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, season),
+        &yield_kg_per_ha,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        &anchor_test_observations(env, client),
+    )
+}
+
+fn report(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    reporter: &Address,
+    season: &str,
+    actual_kg_per_ha: i128,
+) {
+    client.report_actual_yield(
+        reporter,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, season),
+        &actual_kg_per_ha,
+        &create_test_data_hash(env, 7),
+    );
+}
+
+/// Test absolute and percentage error for each prediction
+#[test]
+fn test_prediction_error_math() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let high = predictor(&env, &client);
+    let close = predictor(&env, &client);
+
+    let high_id = submit(&env, &client, &high, "2025-long", 3000);
+    let close_id = submit(&env, &client, &close, "2025-long", 2400);
+    report(&env, &client, &admin, "2025-long", 2500);
+
+    let score = client.get_prediction_score(&high_id);
+    assert_eq!(score.abs_error, 500);
+    assert_eq!(score.pct_error_bps, 2000);
+    assert!(!score.late);
+
+    let score = client.get_prediction_score(&close_id);
+    assert_eq!(score.abs_error, 100);
+    assert_eq!(score.pct_error_bps, 400);
+
+    let accuracy = client.get_predictor_accuracy(&high);
+    assert_eq!(accuracy.scored, 1);
+    assert_eq!(accuracy.missed, 0);
+    assert_eq!(accuracy.mean_abs_error, 500);
+    assert_eq!(accuracy.mean_pct_error_bps, 2000);
+    assert_eq!(accuracy.coverage_bps, 10_000);
+    assert_eq!(accuracy.rating_bps, 8000);
+}
+
+/// Test that missing and late predictions count against coverage
+#[test]
+fn test_missing_and_late_predictions_reduce_coverage() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let steady = predictor(&env, &client);
+    let absent = predictor(&env, &client);
+
+    submit(&env, &client, &steady, "2024-long", 2500);
+    submit(&env, &client, &absent, "2024-long", 2500);
+    report(&env, &client, &admin, "2024-long", 2500);
+
+    submit(&env, &client, &steady, "2025-long", 2500);
+    report(&env, &client, &admin, "2025-long", 2500);
+
+    let accuracy = client.get_predictor_accuracy(&absent);
+    assert_eq!(accuracy.scored, 1);
+    assert_eq!(accuracy.missed, 1);
+    assert_eq!(accuracy.coverage_bps, 5000);
+    assert_eq!(accuracy.rating_bps, 5000);
+
+    // A prediction made after the actual is known is scored but stays missed
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    let late_id = submit(&env, &client, &absent, "2025-long", 2500);
+    report(&env, &client, &admin, "2025-long", 2500);
+    assert!(client.get_prediction_score(&late_id).late);
+
+    let accuracy = client.get_predictor_accuracy(&absent);
+    assert_eq!(accuracy.scored, 1);
+    assert_eq!(accuracy.missed, 1);
+
+    let board = client.get_leaderboard(&Symbol::new(&env, "maize"), &10);
+    assert_eq!(board.len(), 2);
+    assert_eq!(board.get(0).unwrap().predictor, steady);
+    assert_eq!(board.get(1).unwrap().predictor, absent);
+}
+
+/// Test leaderboard ordering, limits and eviction of the lowest rated predictor
+#[test]
+fn test_leaderboard_ordering_and_eviction() {
+    let (env, client, admin, _, _) = setup_test_environment();
+
+    // Predictor i misses the 1000 kg/ha actual by i * 10 kg/ha
+    let mut predictors = soroban_sdk::Vec::new(&env);
+    for i in 0..=LEADERBOARD_SIZE {
+        let p = predictor(&env, &client);
+        submit(&env, &client, &p, "2025-long", 1000 + (i as i128) * 10);
+        predictors.push_back(p);
+    }
+    report(&env, &client, &admin, "2025-long", 1000);
+
+    let board = client.get_leaderboard(&Symbol::new(&env, "maize"), &100);
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    for (rank, entry) in board.iter().enumerate() {
+        assert_eq!(entry.predictor, predictors.get(rank as u32).unwrap());
+    }
+    let worst = predictors.get(LEADERBOARD_SIZE).unwrap();
+    assert!(!board.iter().any(|entry| entry.predictor == worst));
+
+    let top = client.get_leaderboard(&Symbol::new(&env, "maize"), &3);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get(0).unwrap().rating_bps, 10_000);
+    assert_eq!(top.get(2).unwrap().rating_bps, 9800);
+
+    let other = client.get_leaderboard(&Symbol::new(&env, "beans"), &10);
+    assert_eq!(other.len(), 0);
+}
+
+/// Test that re-reporting an actual corrects scores without double counting
+#[test]
+fn test_rereported_actual_corrects_scores() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let high = predictor(&env, &client);
+    let low = predictor(&env, &client);
+
+    let high_id = submit(&env, &client, &high, "2025-long", 3000);
+    let low_id = submit(&env, &client, &low, "2025-long", 2400);
+    report(&env, &client, &admin, "2025-long", 2500);
+
+    let board = client.get_leaderboard(&Symbol::new(&env, "maize"), &10);
+    assert_eq!(board.get(0).unwrap().predictor, low);
+
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    report(&env, &client, &admin, "2025-long", 3000);
+
+    let actual = client.get_actual_yield(
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+    );
+    assert_eq!(actual.revision, 2);
+    assert_eq!(actual.actual_yield_kg_per_ha, 3000);
+    assert!(actual.first_reported_at < actual.reported_at);
+
+    assert_eq!(client.get_prediction_score(&high_id).abs_error, 0);
+    assert_eq!(client.get_prediction_score(&low_id).pct_error_bps, 2000);
+
+    let accuracy = client.get_predictor_accuracy(&high);
+    assert_eq!(accuracy.scored, 1);
+    assert_eq!(accuracy.mean_pct_error_bps, 0);
+
+    let board = client.get_leaderboard(&Symbol::new(&env, "maize"), &10);
+    assert_eq!(board.len(), 2);
+    assert_eq!(board.get(0).unwrap().predictor, high);
+    assert_eq!(board.get(1).unwrap().predictor, low);
+}
+
+/// Test that accuracy only covers the most recent scored seasons
+#[test]
+fn test_accuracy_window_is_rolling() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let p = predictor(&env, &client);
+
+    // The first season is badly missed, every later one is exact
+    submit(&env, &client, &p, "season-0", 2000);
+    report(&env, &client, &admin, "season-0", 1000);
+    let seasons = [
+        "season-1",
+        "season-2",
+        "season-3",
+        "season-4",
+        "season-5",
+        "season-6",
+        "season-7",
+        "season-8",
+        "season-9",
+        "season-10",
+    ];
+    for season in seasons {
+        submit(&env, &client, &p, season, 1000);
+        report(&env, &client, &admin, season, 1000);
+    }
+
+    let accuracy = client.get_predictor_accuracy(&p);
+    assert_eq!(accuracy.scored, ACCURACY_WINDOW);
+    assert_eq!(accuracy.mean_pct_error_bps, 0);
+}
+
+/// Test that only the admin and registered reporters can report actuals
+#[test]
+fn test_report_actual_yield_authorization() {
+    let (env, client, _, farmer, oracle) = setup_test_environment();
+
+    let result = client.try_report_actual_yield(
+        &farmer,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &2500,
+        &create_test_data_hash(&env, 7),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+
+    client.add_reporter(&oracle);
+    report(&env, &client, &oracle, "2025-long", 2500);
+
+    client.remove_reporter(&oracle);
+    let result = client.try_report_actual_yield(
+        &oracle,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &2600,
+        &create_test_data_hash(&env, 7),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+
+    let result = client.try_get_actual_yield(
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "uganda"),
+        &String::from_str(&env, "2025-long"),
+    );
+    assert!(matches!(
+        result,
+        Err(Ok(CropYieldError::ActualYieldNotFound))
+    ));
+}
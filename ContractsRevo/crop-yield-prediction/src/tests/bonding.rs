@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String, Symbol};
+
+use crate::{
+    types::{BondConfig, CropYieldError},
+    CropYieldPredictionContractClient,
+};
+
+use super::utils::*;
+
+const MIN_BOND: i128 = 1_000;
+
+struct BondSetup {
+    env: Env,
+    client: CropYieldPredictionContractClient<'static>,
+    admin: Address,
+    predictor: Address,
+    token: Address,
+    treasury: Address,
+}
+
+/// Bonding enabled with a 20% error threshold and a 10% slash to the treasury
+fn setup_bonding(reward_reporter: bool) -> BondSetup {
+    let (env, client, admin, farmer, _) = setup_test_environment();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let treasury = Address::generate(&env);
+    client.set_bond_config(&BondConfig {
+        token: token.clone(),
+        min_bond: MIN_BOND,
+        slash_threshold_bps: 2000,
+        slash_bps: 1000,
+        treasury: treasury.clone(),
+        reward_reporter,
+    });
+    client.add_predictor(&farmer);
+    token::StellarAssetClient::new(&env, &token).mint(&farmer, &10_000);
+
+    BondSetup {
+        env,
+        client,
+        admin,
+        predictor: farmer,
+        token,
+        treasury,
+    }
+}
+
+fn try_submit(
+    setup: &BondSetup,
+    season: &str,
+    yield_kg_per_ha: i128,
+) -> Result<BytesN<32>, CropYieldError> {
+    let env = &setup.env;
+    match setup.client.try_submit_prediction(
+        &setup.predictor,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, season),
+        &yield_kg_per_ha,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        &anchor_test_observations(env, &setup.client),
+    ) {
+        Ok(Ok(prediction_id)) => Ok(prediction_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected conversion failure"),
+    }
+}
+
+fn report(setup: &BondSetup, reporter: &Address, season: &str, actual_kg_per_ha: i128) {
+    setup.client.report_actual_yield(
+        reporter,
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, season),
+        &actual_kg_per_ha,
+        &create_test_data_hash(&setup.env, 7),
+    );
+}
+
+fn balance(setup: &BondSetup, address: &Address) -> i128 {
+    token::Client::new(&setup.env, &setup.token).balance(address)
+}
+
+/// Test that submission needs the minimum bond once bonding is configured
+#[test]
+fn test_submission_blocked_without_bond() {
+    let setup = setup_bonding(false);
+
+    assert_eq!(
+        try_submit(&setup, "2025-long", 2500),
+        Err(CropYieldError::InsufficientBond)
+    );
+
+    setup.client.post_bond(&setup.predictor, &(MIN_BOND - 1));
+    assert_eq!(
+        try_submit(&setup, "2025-long", 2500),
+        Err(CropYieldError::InsufficientBond)
+    );
+
+    setup.client.post_bond(&setup.predictor, &1);
+    assert!(try_submit(&setup, "2025-long", 2500).is_ok());
+
+    let status = setup.client.get_bond_status(&setup.predictor);
+    assert_eq!(status.bonded, MIN_BOND);
+    assert_eq!(status.pending_predictions, 1);
+    assert!(!status.can_withdraw);
+    assert_eq!(balance(&setup, &setup.predictor), 10_000 - MIN_BOND);
+}
+
+/// Test that a prediction beyond the error threshold is slashed at scoring
+#[test]
+fn test_bad_prediction_is_slashed() {
+    let setup = setup_bonding(false);
+    setup.client.post_bond(&setup.predictor, &2_000);
+
+    // 25% error is above the 20% threshold
+    try_submit(&setup, "2024-long", 2500).unwrap();
+    report(&setup, &setup.admin, "2024-long", 2000);
+
+    let status = setup.client.get_bond_status(&setup.predictor);
+    assert_eq!(status.bonded, 1_800);
+    assert_eq!(status.total_slashed, 200);
+    assert_eq!(status.pending_predictions, 0);
+    assert_eq!(balance(&setup, &setup.treasury), 200);
+
+    // A re-report does not slash the same prediction again
+    report(&setup, &setup.admin, "2024-long", 1000);
+    assert_eq!(setup.client.get_bond_status(&setup.predictor).bonded, 1_800);
+
+    // 10% error stays within the threshold
+    try_submit(&setup, "2025-long", 2200).unwrap();
+    report(&setup, &setup.admin, "2025-long", 2000);
+    assert_eq!(setup.client.get_bond_status(&setup.predictor).bonded, 1_800);
+}
+
+/// Test that slashes can reward the reporter of the actual yield
+#[test]
+fn test_slash_routed_to_reporter() {
+    let setup = setup_bonding(true);
+    let reporter = Address::generate(&setup.env);
+    setup.client.add_reporter(&reporter);
+    setup.client.post_bond(&setup.predictor, &2_000);
+
+    try_submit(&setup, "2025-long", 4000).unwrap();
+    report(&setup, &reporter, "2025-long", 2000);
+
+    assert_eq!(balance(&setup, &reporter), 200);
+    assert_eq!(balance(&setup, &setup.treasury), 0);
+}
+
+/// Test that bonds cannot be withdrawn while predictions await scoring
+#[test]
+fn test_withdrawal_blocked_with_pending_predictions() {
+    let setup = setup_bonding(false);
+    setup.client.post_bond(&setup.predictor, &MIN_BOND);
+    try_submit(&setup, "2025-long", 2500).unwrap();
+
+    // Superseding the prediction does not add another pending one
+    try_submit(&setup, "2025-long", 2600).unwrap();
+    assert_eq!(
+        setup
+            .client
+            .get_bond_status(&setup.predictor)
+            .pending_predictions,
+        1
+    );
+
+    let result = setup.client.try_withdraw_bond(&setup.predictor, &MIN_BOND);
+    assert_eq!(result, Err(Ok(CropYieldError::PredictionsPending)));
+}
+
+/// Test withdrawal once every prediction has been scored
+#[test]
+fn test_withdrawal_after_scoring() {
+    let setup = setup_bonding(false);
+    setup.client.post_bond(&setup.predictor, &MIN_BOND);
+    try_submit(&setup, "2025-long", 2500).unwrap();
+    report(&setup, &setup.admin, "2025-long", 2500);
+
+    let status = setup.client.get_bond_status(&setup.predictor);
+    assert!(status.can_withdraw);
+
+    let result = setup
+        .client
+        .try_withdraw_bond(&setup.predictor, &(MIN_BOND + 1));
+    assert_eq!(result, Err(Ok(CropYieldError::InsufficientBond)));
+
+    setup.client.withdraw_bond(&setup.predictor, &MIN_BOND);
+    assert_eq!(setup.client.get_bond_status(&setup.predictor).bonded, 0);
+    assert_eq!(balance(&setup, &setup.predictor), 10_000);
+
+    // A late prediction for an already scored season is never pending
+    let other = Address::generate(&setup.env);
+    setup.client.add_predictor(&other);
+    token::StellarAssetClient::new(&setup.env, &setup.token).mint(&other, &MIN_BOND);
+    setup.client.post_bond(&other, &MIN_BOND);
+    setup.client.submit_prediction(
+        &other,
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, "2025-long"),
+        &2500,
+        &Symbol::new(&setup.env, "v1"),
+        &create_test_data_hash(&setup.env, 1),
+        &anchor_test_observations(&setup.env, &setup.client),
+    );
+    let status = setup.client.get_bond_status(&other);
+    assert_eq!(status.pending_predictions, 0);
+    assert!(status.can_withdraw);
+}
+
+/// Test that bonds need a configured token
+#[test]
+fn test_post_bond_requires_config() {
+    let (_, client, _, farmer, _) = setup_test_environment();
+
+    let result = client.try_post_bond(&farmer, &MIN_BOND);
+    assert_eq!(result, Err(Ok(CropYieldError::BondNotConfigured)));
+    assert_eq!(client.get_bond_config(), None);
+}
@@ -1,5 +1,11 @@
 // Test modules for crop yield prediction contract
+pub mod accuracy;
+pub mod bonding;
 pub mod data;
+pub mod observation;
+pub mod outlook;
 pub mod prediction;
 pub mod reporting;
+pub mod season_report;
+pub mod submission;
 pub mod utils;
@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol, Vec};
+
+use crate::{types::CropYieldError, CropYieldPredictionContractClient};
+
+use super::utils::*;
+
+fn anchor(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    observer: &Address,
+    region: &str,
+    period: u64,
+    kind: &str,
+) -> u64 {
+    client.anchor_observation(
+        observer,
+        &Symbol::new(env, region),
+        &period,
+        &Symbol::new(env, kind),
+        &create_test_data_hash(env, period as u8),
+    )
+}
+
+fn try_submit(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    predictor: &Address,
+    observation_ids: &Vec<u64>,
+) -> Result<soroban_sdk::BytesN<32>, CropYieldError> {
+    match client.try_submit_prediction(
+        predictor,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, "2025-long"),
+        &2500,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        observation_ids,
+    ) {
+        Ok(Ok(prediction_id)) => Ok(prediction_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected conversion failure"),
+    }
+}
+
+/// Test observation retrieval filtered by region, period range and kind
+#[test]
+fn test_get_observations_filters_and_paginates() {
+    let (env, client, _, _, oracle) = setup_test_environment();
+
+    for period in 1..=5 {
+        anchor(&env, &client, &oracle, "kenya", period, "rainfall");
+    }
+    anchor(&env, &client, &oracle, "kenya", 3, "soil_moist");
+    anchor(&env, &client, &oracle, "uganda", 3, "rainfall");
+
+    let kenya = Symbol::new(&env, "kenya");
+    let rainfall = Symbol::new(&env, "rainfall");
+
+    let observations = client.get_observations(&kenya, &2, &4, &rainfall, &0, &10);
+    assert_eq!(observations.len(), 3);
+    assert_eq!(observations.get(0).unwrap().period, 2);
+    assert_eq!(observations.get(2).unwrap().period, 4);
+    assert_eq!(observations.get(0).unwrap().observer, oracle);
+
+    let page = client.get_observations(&kenya, &2, &4, &rainfall, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().period, 3);
+
+    let past_end = client.get_observations(&kenya, &2, &4, &rainfall, &3, &10);
+    assert_eq!(past_end.len(), 0);
+
+    let soil = client.get_observations(&kenya, &0, &10, &Symbol::new(&env, "soil_moist"), &0, &10);
+    assert_eq!(soil.len(), 1);
+    assert_eq!(soil.get(0).unwrap().observation_id, 6);
+
+    let result = client.try_get_observations(&kenya, &4, &2, &rainfall, &0, &10);
+    assert_eq!(result, Err(Ok(CropYieldError::InvalidInput)));
+}
+
+/// Test that predictions must reference observations that are already anchored
+#[test]
+fn test_prediction_requires_anchored_observations() {
+    let (env, client, _, farmer, oracle) = setup_test_environment();
+    client.add_predictor(&farmer);
+    let anchored = anchor(&env, &client, &oracle, "kenya", 1, "rainfall");
+
+    // The next ID has not been anchored yet
+    let result = try_submit(&env, &client, &farmer, &vec![&env, anchored, anchored + 1]);
+    assert_eq!(result, Err(CropYieldError::ObservationNotFound));
+
+    let result = try_submit(&env, &client, &farmer, &vec![&env, 99]);
+    assert_eq!(result, Err(CropYieldError::ObservationNotFound));
+
+    let result = try_submit(&env, &client, &farmer, &Vec::new(&env));
+    assert_eq!(result, Err(CropYieldError::InvalidInput));
+
+    // Anchoring the observation first makes the same submission valid
+    let next = anchor(&env, &client, &oracle, "kenya", 2, "rainfall");
+    assert_eq!(next, anchored + 1);
+    assert!(try_submit(&env, &client, &farmer, &vec![&env, anchored, next]).is_ok());
+}
+
+/// Test that a prediction's inputs can be retrieved and follow supersession
+#[test]
+fn test_get_prediction_inputs() {
+    let (env, client, _, farmer, oracle) = setup_test_environment();
+    client.add_predictor(&farmer);
+    let rainfall = anchor(&env, &client, &oracle, "kenya", 1, "rainfall");
+    let soil = anchor(&env, &client, &oracle, "kenya", 1, "soil_moist");
+
+    let prediction_id = try_submit(&env, &client, &farmer, &vec![&env, rainfall, soil]).unwrap();
+    let inputs = client.get_prediction_inputs(&prediction_id);
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(inputs.get(0).unwrap(), client.get_observation(&rainfall));
+    assert_eq!(inputs.get(1).unwrap().kind, Symbol::new(&env, "soil_moist"));
+
+    // Superseding the prediction replaces its inputs
+    let updated = anchor(&env, &client, &oracle, "kenya", 2, "rainfall");
+    try_submit(&env, &client, &farmer, &vec![&env, updated]).unwrap();
+    let inputs = client.get_prediction_inputs(&prediction_id);
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs.get(0).unwrap().observation_id, updated);
+
+    let missing = client.try_get_prediction_inputs(&create_test_prediction_id(&env, 9));
+    assert!(matches!(
+        missing,
+        Err(Ok(CropYieldError::PredictionNotFound))
+    ));
+}
+
+/// Test that anchoring requires the observer's authorization
+#[test]
+fn test_anchor_observation_requires_auth() {
+    let (env, client, _, _, _) = setup_strict_auth_environment();
+    let observer = Address::generate(&env);
+
+    let result = client.try_anchor_observation(
+        &observer,
+        &Symbol::new(&env, "kenya"),
+        &1,
+        &Symbol::new(&env, "rainfall"),
+        &create_test_data_hash(&env, 1),
+    );
+    assert!(
+        result.is_err(),
+        "Anchoring without observer auth should fail"
+    );
+}
@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String, Symbol,
+};
+
+use crate::{
+    types::{CropYieldError, Outlook},
+    CropYieldPredictionContractClient,
+};
+
+use super::utils::*;
+
+/// Consumer that records every outlook pushed to it
+#[contract]
+pub struct MockOutlookConsumer;
+
+#[contractimpl]
+impl MockOutlookConsumer {
+    pub fn on_outlook_update(
+        env: Env,
+        _crop: Symbol,
+        _region: Symbol,
+        _season: String,
+        outlook: Outlook,
+    ) {
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("count"))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("count"), &(count + 1));
+        env.storage()
+            .instance()
+            .set(&symbol_short!("last"), &outlook);
+    }
+
+    pub fn count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("count"))
+            .unwrap_or(0)
+    }
+
+    pub fn last(env: Env) -> Option<Outlook> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+mod failing {
+    use crate::types::Outlook;
+    use soroban_sdk::{contract, contractimpl, Env, String, Symbol};
+
+    /// Consumer that always fails
+    #[contract]
+    pub struct FailingOutlookConsumer;
+
+    #[contractimpl]
+    impl FailingOutlookConsumer {
+        pub fn on_outlook_update(
+            _env: Env,
+            _crop: Symbol,
+            _region: Symbol,
+            _season: String,
+            _outlook: Outlook,
+        ) {
+            panic!("consumer unavailable");
+        }
+    }
+}
+
+use failing::FailingOutlookConsumer;
+
+fn submit(env: &Env, client: &CropYieldPredictionContractClient, yield_kg_per_ha: i128) -> Address {
+    let predictor = Address::generate(env);
+    client.add_predictor(&predictor);
+    resubmit(env, client, &predictor, yield_kg_per_ha);
+    predictor
+}
+
+fn resubmit(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    predictor: &Address,
+    yield_kg_per_ha: i128,
+) {
+    client.submit_prediction(
+        predictor,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, "2025-long"),
+        &yield_kg_per_ha,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        &anchor_test_observations(env, client),
+    );
+}
+
+fn outlook(env: &Env, client: &CropYieldPredictionContractClient) -> Outlook {
+    client.get_regional_outlook(
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, "2025-long"),
+    )
+}
+
+/// Test median and dispersion with odd and even prediction counts
+#[test]
+fn test_outlook_median_and_dispersion() {
+    let (env, client, _, _, _) = setup_test_environment();
+
+    let first = submit(&env, &client, 2000);
+    submit(&env, &client, 3000);
+    submit(&env, &client, 2600);
+
+    let current = outlook(&env, &client);
+    assert_eq!(current.prediction_count, 3);
+    assert_eq!(current.median_prediction, 2600);
+    // (600 + 0 + 400) / 3 deviation over a 2600 median
+    assert_eq!(current.dispersion_bps, 1282);
+
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    submit(&env, &client, 2800);
+    let current = outlook(&env, &client);
+    assert_eq!(current.prediction_count, 4);
+    assert_eq!(current.median_prediction, 2700);
+    assert_eq!(current.dispersion_bps, 1111);
+    assert_eq!(current.last_updated, env.ledger().timestamp());
+
+    // Superseding a prediction replaces its value instead of adding one
+    resubmit(&env, &client, &first, 2700);
+    let current = outlook(&env, &client);
+    assert_eq!(current.prediction_count, 4);
+    assert_eq!(current.median_prediction, 2750);
+}
+
+/// Test that an identical set of predictions has no dispersion
+#[test]
+fn test_outlook_without_disagreement() {
+    let (env, client, _, _, _) = setup_test_environment();
+    submit(&env, &client, 2500);
+    submit(&env, &client, 2500);
+
+    let current = outlook(&env, &client);
+    assert_eq!(current.median_prediction, 2500);
+    assert_eq!(current.dispersion_bps, 0);
+
+    let missing = client.try_get_regional_outlook(
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "uganda"),
+        &String::from_str(&env, "2025-long"),
+    );
+    assert!(matches!(
+        missing,
+        Err(Ok(CropYieldError::PredictionNotFound))
+    ));
+}
+
+/// Test that the consumer is only notified when the median moves past the threshold
+#[test]
+fn test_consumer_notified_on_large_moves_only() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let consumer_id = env.register(MockOutlookConsumer, ());
+    let consumer = MockOutlookConsumerClient::new(&env, &consumer_id);
+    client.set_outlook_consumer(&admin, &consumer_id, &500);
+
+    // The first outlook is always pushed
+    submit(&env, &client, 2000);
+    assert_eq!(consumer.count(), 1);
+
+    // 2020 and 2040 are within 5% of the notified 2000 median
+    submit(&env, &client, 2040);
+    assert_eq!(consumer.count(), 1);
+    submit(&env, &client, 3000);
+    assert_eq!(consumer.count(), 1);
+
+    // The median jumps to 2520
+    submit(&env, &client, 3100);
+    assert_eq!(consumer.count(), 2);
+    assert_eq!(consumer.last().unwrap().median_prediction, 2520);
+    assert_eq!(consumer.last().unwrap().prediction_count, 4);
+}
+
+/// Test that a failing consumer does not block submissions
+#[test]
+fn test_failing_consumer_does_not_block_submission() {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let consumer_id = env.register(FailingOutlookConsumer, ());
+    client.set_outlook_consumer(&admin, &consumer_id, &0);
+
+    submit(&env, &client, 2000);
+    assert_eq!(outlook(&env, &client).median_prediction, 2000);
+}
+
+/// Test that only the admin can set the consumer
+#[test]
+fn test_set_outlook_consumer_unauthorized() {
+    let (env, client, _, farmer, _) = setup_test_environment();
+    let consumer_id = env.register(MockOutlookConsumer, ());
+
+    let result = client.try_set_outlook_consumer(&farmer, &consumer_id, &500);
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+    assert_eq!(client.get_outlook_consumer(), None);
+}
@@ -0,0 +1,209 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Symbol};
+
+use crate::{
+    types::{CropYieldError, ReportStatus},
+    CropYieldPredictionContractClient,
+};
+
+use super::utils::*;
+
+struct ReportSetup {
+    env: Env,
+    client: CropYieldPredictionContractClient<'static>,
+    admin: Address,
+    signers: [Address; 3],
+}
+
+fn setup_reports() -> ReportSetup {
+    let (env, client, admin, _, _) = setup_test_environment();
+    let signers = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for signer in signers.iter() {
+        client.add_report_signer(signer);
+    }
+    ReportSetup {
+        env,
+        client,
+        admin,
+        signers,
+    }
+}
+
+fn submit(setup: &ReportSetup, yield_kg_per_ha: i128) {
+    let env = &setup.env;
+    let predictor = Address::generate(env);
+    setup.client.add_predictor(&predictor);
+    setup.client.submit_prediction(
+        &predictor,
+        &Symbol::new(env, "maize"),
+        &Symbol::new(env, "kenya"),
+        &String::from_str(env, "2025-long"),
+        &yield_kg_per_ha,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        &anchor_test_observations(env, &setup.client),
+    );
+}
+
+fn compile(setup: &ReportSetup) -> BytesN<32> {
+    setup.client.compile_season_report(
+        &setup.signers[0],
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, "2025-long"),
+    )
+}
+
+/// Test that a report snapshots the season's outlook, actual, leaderboard and inputs
+#[test]
+fn test_compile_season_report_snapshot() {
+    let setup = setup_reports();
+    submit(&setup, 2400);
+    submit(&setup, 2600);
+    setup.client.report_actual_yield(
+        &setup.admin,
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, "2025-long"),
+        &2500,
+        &create_test_data_hash(&setup.env, 7),
+    );
+
+    let report = setup.client.get_report(&compile(&setup));
+    assert_eq!(report.version, 1);
+    assert_eq!(report.previous_report, None);
+    assert_eq!(report.compiler, setup.signers[0]);
+    assert_eq!(report.median_prediction, 2500);
+    assert_eq!(report.prediction_count, 2);
+    assert_eq!(report.dispersion_bps, 400);
+    assert!(report.actual_reported);
+    assert_eq!(report.actual_yield_kg_per_ha, 2500);
+    assert_eq!(report.top_predictors.len(), 2);
+    assert_eq!(report.observation_count, 2);
+    assert_eq!(report.status, ReportStatus::Draft);
+}
+
+/// Test that publication needs two distinct registered signers
+#[test]
+fn test_report_requires_two_distinct_signers() {
+    let setup = setup_reports();
+    submit(&setup, 2500);
+    let report_id = compile(&setup);
+
+    let status = setup.client.sign_report(&setup.signers[0], &report_id);
+    assert_eq!(status, ReportStatus::Draft);
+
+    let result = setup.client.try_sign_report(&setup.signers[0], &report_id);
+    assert_eq!(result, Err(Ok(CropYieldError::AlreadySigned)));
+
+    let outsider = Address::generate(&setup.env);
+    let result = setup.client.try_sign_report(&outsider, &report_id);
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+    assert_eq!(
+        setup.client.get_report(&report_id).status,
+        ReportStatus::Draft
+    );
+
+    let status = setup.client.sign_report(&setup.signers[1], &report_id);
+    assert_eq!(status, ReportStatus::Published);
+    let report = setup.client.get_report(&report_id);
+    assert_eq!(report.signers.len(), 2);
+    assert_eq!(report.published_at, setup.env.ledger().timestamp());
+}
+
+/// Test that a published report cannot change
+#[test]
+fn test_published_report_is_immutable() {
+    let setup = setup_reports();
+    submit(&setup, 2500);
+    let report_id = compile(&setup);
+    setup.client.sign_report(&setup.signers[0], &report_id);
+    setup.client.sign_report(&setup.signers[1], &report_id);
+    let published = setup.client.get_report(&report_id);
+
+    let result = setup.client.try_sign_report(&setup.signers[2], &report_id);
+    assert_eq!(result, Err(Ok(CropYieldError::ReportPublished)));
+
+    // New season data and recompilation leave the published snapshot untouched
+    submit(&setup, 3500);
+    compile(&setup);
+    assert_eq!(setup.client.get_report(&report_id), published);
+}
+
+/// Test that recompiling a published season creates a linked new version
+#[test]
+fn test_recompile_creates_versioned_report() {
+    let setup = setup_reports();
+    submit(&setup, 2500);
+
+    // Recompiling a draft refreshes it in place and clears its signatures
+    let draft_id = compile(&setup);
+    setup.client.sign_report(&setup.signers[0], &draft_id);
+    submit(&setup, 2700);
+    assert_eq!(compile(&setup), draft_id);
+    let draft = setup.client.get_report(&draft_id);
+    assert_eq!(draft.prediction_count, 2);
+    assert_eq!(draft.signers.len(), 0);
+
+    setup.client.sign_report(&setup.signers[0], &draft_id);
+    setup.client.sign_report(&setup.signers[1], &draft_id);
+
+    submit(&setup, 2900);
+    let second_id = compile(&setup);
+    assert_ne!(second_id, draft_id);
+    let second = setup.client.get_report(&second_id);
+    assert_eq!(second.version, 2);
+    assert_eq!(second.previous_report, Some(draft_id.clone()));
+    assert_eq!(second.prediction_count, 3);
+    assert_eq!(second.status, ReportStatus::Draft);
+
+    let reports = setup
+        .client
+        .list_reports(&Symbol::new(&setup.env, "kenya"), &0, &10);
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports.get(0).unwrap().report_id, draft_id);
+    assert_eq!(reports.get(1).unwrap().report_id, second_id);
+
+    let page = setup
+        .client
+        .list_reports(&Symbol::new(&setup.env, "kenya"), &1, &10);
+    assert_eq!(page.len(), 1);
+    let other = setup
+        .client
+        .list_reports(&Symbol::new(&setup.env, "uganda"), &0, &10);
+    assert_eq!(other.len(), 0);
+}
+
+/// Test that only registered signers can compile reports
+#[test]
+fn test_compile_requires_registered_signer() {
+    let setup = setup_reports();
+    let outsider = Address::generate(&setup.env);
+
+    let result = setup.client.try_compile_season_report(
+        &outsider,
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, "2025-long"),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+
+    setup.client.remove_report_signer(&setup.signers[0]);
+    let result = setup.client.try_compile_season_report(
+        &setup.signers[0],
+        &Symbol::new(&setup.env, "maize"),
+        &Symbol::new(&setup.env, "kenya"),
+        &String::from_str(&setup.env, "2025-long"),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+
+    let missing = setup
+        .client
+        .try_get_report(&create_test_prediction_id(&setup.env, 9));
+    assert!(matches!(missing, Err(Ok(CropYieldError::ReportNotFound))));
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, BytesN, Env, String, Symbol,
+};
+
+use crate::{types::CropYieldError, CropYieldPredictionContractClient};
+
+use super::utils::*;
+
+fn submit(
+    env: &Env,
+    client: &CropYieldPredictionContractClient,
+    predictor: &Address,
+    crop: &str,
+    region: &str,
+    season: &str,
+    yield_kg_per_ha: i128,
+) -> BytesN<32> {
+    client.submit_prediction(
+        predictor,
+        &Symbol::new(env, crop),
+        &Symbol::new(env, region),
+        &String::from_str(env, season),
+        &yield_kg_per_ha,
+        &Symbol::new(env, "v1"),
+        &create_test_data_hash(env, 1),
+        &anchor_test_observations(env, client),
+    )
+}
+
+/// Test submitting and reading back a prediction
+#[test]
+fn test_submit_prediction_success() {
+    let (env, client, _, farmer, _) = setup_test_environment();
+    client.add_predictor(&farmer);
+
+    let prediction_id = submit(&env, &client, &farmer, "maize", "kenya", "2025-long", 3200);
+    let submission = client.get_submitted_prediction(&prediction_id);
+
+    assert_eq!(submission.predictor, farmer);
+    assert_eq!(submission.crop, Symbol::new(&env, "maize"));
+    assert_eq!(submission.predicted_yield_kg_per_ha, 3200);
+    assert_eq!(submission.model_version, Symbol::new(&env, "v1"));
+    assert_eq!(submission.revision, 1);
+}
+
+/// Test that resubmitting for the same crop, region and season supersedes the earlier prediction
+#[test]
+fn test_submit_prediction_supersedes() {
+    let (env, client, _, farmer, _) = setup_test_environment();
+    client.add_predictor(&farmer);
+
+    let first_id = submit(&env, &client, &farmer, "maize", "kenya", "2025-long", 3200);
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    let second_id = client.submit_prediction(
+        &farmer,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &2900,
+        &Symbol::new(&env, "v2"),
+        &create_test_data_hash(&env, 2),
+        &anchor_test_observations(&env, &client),
+    );
+    assert_eq!(first_id, second_id);
+
+    let submission = client.get_submitted_prediction(&second_id);
+    assert_eq!(submission.predicted_yield_kg_per_ha, 2900);
+    assert_eq!(submission.model_version, Symbol::new(&env, "v2"));
+    assert_eq!(submission.inputs_hash, create_test_data_hash(&env, 2));
+    assert_eq!(submission.revision, 2);
+    assert_eq!(submission.submitted_at, env.ledger().timestamp());
+
+    let listed = client.list_predictions(
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &0,
+        &10,
+    );
+    assert_eq!(listed.len(), 1);
+}
+
+/// Test that listing only returns predictions for the requested crop, region and season
+#[test]
+fn test_list_predictions_filters_by_index() {
+    let (env, client, _, farmer, oracle) = setup_test_environment();
+    client.add_predictor(&farmer);
+    client.add_predictor(&oracle);
+
+    submit(&env, &client, &farmer, "maize", "kenya", "2025-long", 3200);
+    submit(&env, &client, &oracle, "maize", "kenya", "2025-long", 3000);
+    submit(&env, &client, &farmer, "maize", "kenya", "2025-short", 2100);
+    submit(&env, &client, &farmer, "maize", "uganda", "2025-long", 2800);
+    submit(&env, &client, &farmer, "beans", "kenya", "2025-long", 900);
+
+    let maize = Symbol::new(&env, "maize");
+    let kenya = Symbol::new(&env, "kenya");
+    let season = String::from_str(&env, "2025-long");
+
+    let listed = client.list_predictions(&maize, &kenya, &season, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(0).unwrap().predictor, farmer);
+    assert_eq!(listed.get(1).unwrap().predictor, oracle);
+
+    let page = client.list_predictions(&maize, &kenya, &season, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().predicted_yield_kg_per_ha, 3000);
+
+    let empty = client.list_predictions(&maize, &kenya, &season, &5, &10);
+    assert_eq!(empty.len(), 0);
+
+    let other = client.list_predictions(&Symbol::new(&env, "rice"), &kenya, &season, &0, &10);
+    assert_eq!(other.len(), 0);
+}
+
+/// Test that unregistered and removed predictors cannot submit
+#[test]
+fn test_submit_prediction_unauthorized() {
+    let (env, client, _, farmer, _) = setup_test_environment();
+    let outsider = Address::generate(&env);
+
+    let result = client.try_submit_prediction(
+        &outsider,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &3200,
+        &Symbol::new(&env, "v1"),
+        &create_test_data_hash(&env, 1),
+        &anchor_test_observations(&env, &client),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+
+    client.add_predictor(&farmer);
+    submit(&env, &client, &farmer, "maize", "kenya", "2025-long", 3200);
+    client.remove_predictor(&farmer);
+    assert!(!client.is_predictor(&farmer));
+
+    let result = client.try_submit_prediction(
+        &farmer,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &3100,
+        &Symbol::new(&env, "v1"),
+        &create_test_data_hash(&env, 1),
+        &anchor_test_observations(&env, &client),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::Unauthorized)));
+}
+
+/// Test that only the admin can register predictors
+#[test]
+fn test_add_predictor_requires_admin() {
+    let (_, client, _, farmer, _) = setup_strict_auth_environment();
+
+    let result = client.try_add_predictor(&farmer);
+    assert!(
+        result.is_err(),
+        "Adding a predictor without admin auth should fail"
+    );
+    assert!(!client.is_predictor(&farmer));
+}
+
+/// Test submission input validation
+#[test]
+fn test_submit_prediction_invalid_input() {
+    let (env, client, _, farmer, _) = setup_test_environment();
+    client.add_predictor(&farmer);
+
+    let result = client.try_submit_prediction(
+        &farmer,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, ""),
+        &3200,
+        &Symbol::new(&env, "v1"),
+        &create_test_data_hash(&env, 1),
+        &anchor_test_observations(&env, &client),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::InvalidInput)));
+
+    let result = client.try_submit_prediction(
+        &farmer,
+        &Symbol::new(&env, "maize"),
+        &Symbol::new(&env, "kenya"),
+        &String::from_str(&env, "2025-long"),
+        &-1,
+        &Symbol::new(&env, "v1"),
+        &create_test_data_hash(&env, 1),
+        &anchor_test_observations(&env, &client),
+    );
+    assert_eq!(result, Err(Ok(CropYieldError::InvalidYieldData)));
+
+    let missing = client.try_get_submitted_prediction(&create_test_prediction_id(&env, 9));
+    assert!(matches!(
+        missing,
+        Err(Ok(CropYieldError::PredictionNotFound))
+    ));
+}
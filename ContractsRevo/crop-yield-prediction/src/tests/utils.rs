@@ -81,6 +81,19 @@ pub fn create_test_data_hash(env: &Env, suffix: u8) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
+/// Anchor a rainfall observation and return its ID for use as prediction input
+pub fn anchor_test_observations(env: &Env, client: &CropYieldPredictionContractClient) -> Vec<u64> {
+    let observer = Address::generate(env);
+    let observation_id = client.anchor_observation(
+        &observer,
+        &soroban_sdk::Symbol::new(env, "kenya"),
+        &1,
+        &soroban_sdk::Symbol::new(env, "rainfall"),
+        &create_test_data_hash(env, 3),
+    );
+    vec![env, observation_id]
+}
+
 /// Create test crop name
 pub fn create_test_crop_name(env: &Env, suffix: u8) -> String {
     match suffix {
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, BytesN, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -39,6 +39,149 @@ pub struct YieldReport {
     pub report_date: u64,
 }
 
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct PredictionSubmission {
+    pub prediction_id: BytesN<32>,
+    pub predictor: Address,
+    pub crop: Symbol,
+    pub region: Symbol,
+    pub season: String,
+    pub predicted_yield_kg_per_ha: i128,
+    pub model_version: Symbol,
+    pub inputs_hash: BytesN<32>, // Hash of the model inputs used off-chain
+    pub observation_ids: Vec<u64>, // Anchored observations the model used
+    pub revision: u32,           // Bumped each time the predictor supersedes it
+    pub submitted_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Observation {
+    pub observation_id: u64,
+    pub observer: Address,
+    pub region: Symbol,
+    pub period: u64,  // Start of the observed period
+    pub kind: Symbol, // e.g. rainfall, soil_moist
+    pub data_hash: BytesN<32>,
+    pub anchored_at: u64,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ActualYield {
+    pub crop: Symbol,
+    pub region: Symbol,
+    pub season: String,
+    pub actual_yield_kg_per_ha: i128,
+    pub evidence_hash: BytesN<32>, // Hash of the harvest evidence
+    pub reporter: Address,
+    pub first_reported_at: u64, // Predictions submitted later are late
+    pub reported_at: u64,
+    pub revision: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PredictionScore {
+    pub prediction_id: BytesN<32>,
+    pub predictor: Address,
+    pub predicted_yield_kg_per_ha: i128,
+    pub actual_yield_kg_per_ha: i128,
+    pub abs_error: i128,
+    pub pct_error_bps: i128, // Absolute error relative to the actual yield
+    pub late: bool,          // Submitted after the actual was first reported
+}
+
+// One scored season in a predictor's rolling window
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeasonOutcome {
+    pub season_id: BytesN<32>,
+    pub covered: bool, // False when the prediction was late or missing
+    pub abs_error: i128,
+    pub pct_error_bps: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PredictorAccuracy {
+    pub predictor: Address,
+    pub scored: u32, // On-time predictions in the window
+    pub missed: u32, // Late or missing predictions in the window
+    pub mean_abs_error: i128,
+    pub mean_pct_error_bps: i128,
+    pub coverage_bps: u32,
+    pub rating_bps: u32, // Accuracy weighted by coverage, used for ranking
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Outlook {
+    pub median_prediction: i128,
+    pub prediction_count: u32,
+    pub dispersion_bps: i128, // Mean absolute deviation from the median, relative to it
+    pub last_updated: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OutlookConsumerConfig {
+    pub consumer: Address,
+    pub threshold_bps: u32, // Minimum median move since the last notification
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ReportStatus {
+    Draft,
+    Published,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeasonReport {
+    pub report_id: BytesN<32>,
+    pub crop: Symbol,
+    pub region: Symbol,
+    pub season: String,
+    pub version: u32,
+    pub previous_report: Option<BytesN<32>>, // Report this version supersedes
+    pub compiler: Address,
+    pub median_prediction: i128,
+    pub prediction_count: u32,
+    pub dispersion_bps: i128,
+    pub actual_reported: bool,
+    pub actual_yield_kg_per_ha: i128,
+    pub top_predictors: Vec<PredictorAccuracy>,
+    pub observation_count: u32, // Distinct observations referenced by the predictions
+    pub status: ReportStatus,
+    pub signers: Vec<Address>,
+    pub compiled_at: u64,
+    pub published_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BondConfig {
+    pub token: Address, // Farmer token the bonds are posted in
+    pub min_bond: i128,
+    pub slash_threshold_bps: i128, // Percentage error above which a prediction is slashed
+    pub slash_bps: u32,            // Share of the bond taken per slash
+    pub treasury: Address,
+    pub reward_reporter: bool, // Send slashes to the actual's reporter instead of the treasury
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BondStatus {
+    pub predictor: Address,
+    pub bonded: i128,
+    pub pending_predictions: u32, // Submitted predictions whose season is not scored yet
+    pub total_slashed: i128,
+    pub can_withdraw: bool,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct MarketInsight {
@@ -59,6 +202,29 @@ pub enum DataKey {
     CROPS,
     ADMIN,
     PREDICTIONS,
+    Predictor(Address),
+    Submission(BytesN<32>),
+    Submissions(Symbol, Symbol, String), // (crop, region, season) index
+    Predictors,
+    Reporter(Address),
+    Actual(Symbol, Symbol, String),
+    Score(BytesN<32>),
+    Outcomes(Address),             // Rolling window across all crops
+    CropOutcomes(Address, Symbol), // Rolling window for one crop
+    Leaderboard(Symbol),
+    Outlook(Symbol, Symbol, String),
+    OutlookValues(Symbol, Symbol, String), // Sorted current predictions
+    NotifiedMedian(Symbol, Symbol, String),
+    OutlookConsumer,
+    Observation(u64),
+    RegionObservations(Symbol), // Append-only log of observation IDs
+    ObservationCounter,
+    ReportSigner(Address),
+    Report(BytesN<32>),
+    LatestReport(Symbol, Symbol, String),
+    RegionReports(Symbol),
+    BondConfig,
+    Bond(Address),
 }
 
 /////////////////////////////////////////////////////
@@ -76,4 +242,12 @@ pub enum CropYieldError {
     ContractNotInitialized = 5,
     InvalidYieldData = 6,
     DataProcessingError = 7,
+    ActualYieldNotFound = 8,
+    ObservationNotFound = 9,
+    ReportNotFound = 10,
+    ReportPublished = 11,
+    AlreadySigned = 12,
+    BondNotConfigured = 13,
+    InsufficientBond = 14,
+    PredictionsPending = 15,
 }
@@ -1,5 +1,5 @@
 use crate::types::{Crop, DataSource};
-use soroban_sdk::{Bytes, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol};
 
 pub fn generate_prediction_id(env: &Env, crop_id: &BytesN<32>) -> BytesN<32> {
     let mut combined = Bytes::new(env);
@@ -13,6 +13,44 @@ pub fn generate_prediction_id(env: &Env, crop_id: &BytesN<32>) -> BytesN<32> {
     env.crypto().sha256(&combined).into()
 }
 
+/// One ID per (predictor, crop, region, season), so resubmissions supersede
+pub fn generate_submission_id(
+    env: &Env,
+    predictor: &Address,
+    crop: &Symbol,
+    region: &Symbol,
+    season: &String,
+) -> BytesN<32> {
+    let key = (
+        predictor.clone(),
+        crop.clone(),
+        region.clone(),
+        season.clone(),
+    );
+    env.crypto().sha256(&key.to_xdr(env)).into()
+}
+
+pub fn generate_season_id(
+    env: &Env,
+    crop: &Symbol,
+    region: &Symbol,
+    season: &String,
+) -> BytesN<32> {
+    let key = (crop.clone(), region.clone(), season.clone());
+    env.crypto().sha256(&key.to_xdr(env)).into()
+}
+
+pub fn generate_report_id(
+    env: &Env,
+    crop: &Symbol,
+    region: &Symbol,
+    season: &String,
+    version: u32,
+) -> BytesN<32> {
+    let key = (crop.clone(), region.clone(), season.clone(), version);
+    env.crypto().sha256(&key.to_xdr(env)).into()
+}
+
 pub fn hash_data_source(env: &Env, data_source: &DataSource) -> BytesN<32> {
     let mut combined = Bytes::new(env);
 
@@ -1,13 +1,20 @@
 use crate::{utils::generate_id, BarterAgreement, DataKey, TradeError};
-use soroban_sdk::{Address, BytesN, Env, String};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
 
 pub fn create_barter_agreement(
     env: Env,
     trade_offer_id: BytesN<32>,
     offering_cooperative: Address,
     accepting_cooperative: Address,
-) -> BytesN<32> {
-    let agreement_id = generate_id(&env);
+) -> Result<BytesN<32>, TradeError> {
+    let mut fields = Bytes::new(&env);
+    fields.append(&Bytes::from_array(&env, &trade_offer_id.to_array()));
+    fields.append(&offering_cooperative.clone().to_xdr(&env));
+    fields.append(&accepting_cooperative.clone().to_xdr(&env));
+    let agreement_id = generate_id(&env, &DataKey::AgreementCounter, &fields);
+    if barter_agreement_exists(&env, &agreement_id) {
+        return Err(TradeError::DuplicateId);
+    }
 
     let barter_agreement = BarterAgreement {
         agreement_id: agreement_id.clone(),
@@ -22,7 +29,14 @@ pub fn create_barter_agreement(
         &barter_agreement,
     );
 
-    agreement_id
+    Ok(agreement_id)
+}
+
+/// Whether a barter agreement is already stored under `agreement_id`
+pub fn barter_agreement_exists(env: &Env, agreement_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::BarterAgreement(agreement_id.clone()))
 }
 
 pub fn get_barter_agreement(
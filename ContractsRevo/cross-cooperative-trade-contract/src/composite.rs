@@ -0,0 +1,314 @@
+use crate::{AdminError, DataKey};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Val, Vec};
+
+/// Score components are expressed in basis points
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// A completed trade counts half as much after this long (~90 days)
+pub const TRADE_HALF_LIFE_SECS: u64 = 90 * 24 * 60 * 60;
+
+/// Decayed trades (in thousandths) at which the trade component is maxed out
+pub const TRADE_SCORE_SATURATION: u64 = 10_000;
+
+/// Each open dispute takes this much off the quality component
+pub const DISPUTE_PENALTY_BPS: u32 = 2_500;
+
+/// Each valid certification adds this much to the certification component
+pub const CERTIFICATION_CREDIT_BPS: u32 = 2_500;
+
+/// How long cross-contract reads are reused before they are fetched again
+pub const REPUTATION_CACHE_SECS: u64 = 300;
+
+/// Bits of `CompositeScore::flags` marking which components were included
+pub const COMPONENT_TRADE: u32 = 1;
+pub const COMPONENT_QUALITY: u32 = 2;
+pub const COMPONENT_CERTIFICATION: u32 = 4;
+
+/// Ledgers close roughly every five seconds
+const LEDGER_SECS: u64 = 5;
+
+/// Most certifications read in a single page
+const MAX_CERT_PAGE: u32 = 100;
+
+// Minimal interface of the agricultural-quality contract; only the open dispute count is read.
+#[allow(dead_code)]
+#[contractclient(name = "QualityClient")]
+pub trait Quality {
+    fn count_open_disputes(env: Env, holder: Address) -> u32;
+}
+
+// Minimal interface of the certificate-management contract; only the number of matching
+// certifications is read.
+#[allow(dead_code)]
+#[contractclient(name = "CertificationClient")]
+pub trait Certification {
+    fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Val>;
+}
+
+/// Mirror of the certificate-management contract's certification status
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertStatus {
+    Valid,
+    Expired,
+    Revoked,
+}
+
+/// Relative weight of each component in the composite score
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReputationWeights {
+    pub trade_bps: u32,
+    pub quality_bps: u32,
+    pub certification_bps: u32,
+}
+
+/// Successful trades in thousandths, decayed up to `updated_at`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeScore {
+    pub decayed_trades: u64,
+    pub updated_at: u64,
+}
+
+/// Contracts the quality and certification components are read from
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReputationSources {
+    pub quality: Option<Address>,
+    pub certification: Option<Address>,
+}
+
+/// Cross-contract reads for one cooperative, kept in temporary storage
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalReads {
+    pub sources: ReputationSources,
+    pub open_disputes: u32,
+    pub valid_certs: u32,
+    pub flags: u32,
+    pub fetched_at: u64,
+}
+
+/// Trust score shown for a cooperative. `score` and the component scores are in basis points;
+/// components missing from `flags` are left out of the weighting.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompositeScore {
+    pub cooperative_id: Address,
+    pub score: u32,
+    pub trade_score: u32,
+    pub quality_score: u32,
+    pub certification_score: u32,
+    pub open_disputes: u32,
+    pub valid_certs: u32,
+    pub flags: u32,
+    pub computed_at: u64,
+}
+
+/// Set the contracts disputes and certifications are read from (admin only).
+/// Unset sources leave their component out of the score.
+pub fn set_reputation_sources(
+    env: &Env,
+    admin: Address,
+    quality_contract: Option<Address>,
+    certification_contract: Option<Address>,
+) -> Result<(), AdminError> {
+    require_admin(env, &admin)?;
+
+    let sources = ReputationSources {
+        quality: quality_contract,
+        certification: certification_contract,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::ReputationSources, &sources);
+
+    Ok(())
+}
+
+pub fn get_reputation_sources(env: &Env) -> ReputationSources {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReputationSources)
+        .unwrap_or(ReputationSources {
+            quality: None,
+            certification: None,
+        })
+}
+
+/// Set how much each component counts towards the composite score (admin only)
+pub fn set_reputation_weights(
+    env: &Env,
+    admin: Address,
+    weights: ReputationWeights,
+) -> Result<(), AdminError> {
+    require_admin(env, &admin)?;
+
+    let total =
+        weights.trade_bps as u64 + weights.quality_bps as u64 + weights.certification_bps as u64;
+    if total != BPS_DENOMINATOR as u64 {
+        return Err(AdminError::InvalidWeights);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ReputationWeights, &weights);
+
+    Ok(())
+}
+
+pub fn get_reputation_weights(env: &Env) -> ReputationWeights {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReputationWeights)
+        .unwrap_or(ReputationWeights {
+            trade_bps: 5_000,
+            quality_bps: 2_500,
+            certification_bps: 2_500,
+        })
+}
+
+/// Decay the cooperative's trade score to now and add one successful trade
+pub fn record_successful_trade(env: &Env, cooperative_id: &Address) {
+    let now = env.ledger().timestamp();
+    let key = DataKey::TradeScore(cooperative_id.clone());
+    let score = TradeScore {
+        decayed_trades: decayed_trades(env, cooperative_id, now) + 1_000,
+        updated_at: now,
+    };
+    env.storage().persistent().set(&key, &score);
+}
+
+/// Combine the decayed trade score with dispute and certification data from the configured
+/// contracts. Reads that fail are left out and their flag stays unset.
+pub fn get_composite_reputation(env: &Env, cooperative_id: Address) -> CompositeScore {
+    let now = env.ledger().timestamp();
+    let weights = get_reputation_weights(env);
+    let reads = external_reads(env, &cooperative_id, now);
+
+    let trade_score = (decayed_trades(env, &cooperative_id, now).min(TRADE_SCORE_SATURATION)
+        * BPS_DENOMINATOR as u64
+        / TRADE_SCORE_SATURATION) as u32;
+    let quality_score =
+        BPS_DENOMINATOR.saturating_sub(reads.open_disputes.saturating_mul(DISPUTE_PENALTY_BPS));
+    let certification_score = reads
+        .valid_certs
+        .saturating_mul(CERTIFICATION_CREDIT_BPS)
+        .min(BPS_DENOMINATOR);
+
+    // Weights are spread over the components that could be read
+    let flags = COMPONENT_TRADE | reads.flags;
+    let mut weighted: u64 = trade_score as u64 * weights.trade_bps as u64;
+    let mut total_weight: u64 = weights.trade_bps as u64;
+    if flags & COMPONENT_QUALITY != 0 {
+        weighted += quality_score as u64 * weights.quality_bps as u64;
+        total_weight += weights.quality_bps as u64;
+    }
+    if flags & COMPONENT_CERTIFICATION != 0 {
+        weighted += certification_score as u64 * weights.certification_bps as u64;
+        total_weight += weights.certification_bps as u64;
+    }
+    let score = weighted.checked_div(total_weight).unwrap_or(0) as u32;
+
+    CompositeScore {
+        cooperative_id,
+        score,
+        trade_score,
+        quality_score,
+        certification_score,
+        open_disputes: reads.open_disputes,
+        valid_certs: reads.valid_certs,
+        flags,
+        computed_at: now,
+    }
+}
+
+/// Trade score with a half-life decay applied up to `now`: whole half-lives halve it and the
+/// remainder is interpolated linearly.
+fn decayed_trades(env: &Env, cooperative_id: &Address, now: u64) -> u64 {
+    let Some(score) = env
+        .storage()
+        .persistent()
+        .get::<_, TradeScore>(&DataKey::TradeScore(cooperative_id.clone()))
+    else {
+        return 0;
+    };
+
+    let elapsed = now.saturating_sub(score.updated_at);
+    let half_lives = elapsed / TRADE_HALF_LIFE_SECS;
+    if half_lives >= u64::BITS as u64 {
+        return 0;
+    }
+
+    let halved = score.decayed_trades >> half_lives;
+    let remainder = elapsed % TRADE_HALF_LIFE_SECS;
+    halved - (halved as u128 * remainder as u128 / (2 * TRADE_HALF_LIFE_SECS) as u128) as u64
+}
+
+/// Cached cross-contract reads, fetched again once stale or when the sources change
+fn external_reads(env: &Env, cooperative_id: &Address, now: u64) -> ExternalReads {
+    let sources = get_reputation_sources(env);
+    let key = DataKey::ReputationCache(cooperative_id.clone());
+
+    if let Some(cached) = env.storage().temporary().get::<_, ExternalReads>(&key) {
+        if cached.sources == sources && now < cached.fetched_at + REPUTATION_CACHE_SECS {
+            return cached;
+        }
+    }
+
+    let mut reads = ExternalReads {
+        sources: sources.clone(),
+        open_disputes: 0,
+        valid_certs: 0,
+        flags: 0,
+        fetched_at: now,
+    };
+
+    if let Some(contract) = sources.quality {
+        if let Ok(Ok(open_disputes)) =
+            QualityClient::new(env, &contract).try_count_open_disputes(cooperative_id)
+        {
+            reads.open_disputes = open_disputes;
+            reads.flags |= COMPONENT_QUALITY;
+        }
+    }
+
+    if let Some(contract) = sources.certification {
+        if let Ok(Ok(certs)) = CertificationClient::new(env, &contract).try_list_certs(
+            cooperative_id,
+            &Some(CertStatus::Valid),
+            &0,
+            &MAX_CERT_PAGE,
+        ) {
+            reads.valid_certs = certs.len();
+            reads.flags |= COMPONENT_CERTIFICATION;
+        }
+    }
+
+    env.storage().temporary().set(&key, &reads);
+    let ttl = (REPUTATION_CACHE_SECS / LEDGER_SECS + 1) as u32;
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+
+    reads
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), AdminError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(AdminError::NotInitialized)?;
+    if *admin != stored_admin {
+        return Err(AdminError::UnauthorizedAccess);
+    }
+    admin.require_auth();
+
+    Ok(())
+}
@@ -6,6 +6,8 @@ pub enum AdminError {
     AlreadyInitialized = 1,
     UnauthorizedAccess = 2,
     NotInitialized = 3,
+    InvalidRateLimit = 4,
+    InvalidWeights = 5,
 }
 
 #[contracterror]
@@ -18,4 +20,6 @@ pub enum TradeError {
     CannotAcceptOwnOffer = 5,
     InvalidQuantity = 6,
     BarterAgreementNotFound = 7,
+    RateLimited = 8,
+    DuplicateId = 9,
 }
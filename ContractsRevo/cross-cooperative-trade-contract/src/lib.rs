@@ -2,13 +2,17 @@
 use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
 
 mod barter;
+mod composite;
 mod error;
+mod rate_limit;
 mod reputation;
 mod trade;
 mod utils;
 
 pub use barter::*;
+pub use composite::*;
 pub use error::*;
+pub use rate_limit::*;
 pub use reputation::*;
 pub use trade::*;
 pub use utils::*;
@@ -60,6 +64,12 @@ pub enum DataKey {
     ActiveOffers,
     OfferCounter,
     AgreementCounter,
+    RateLimitConfig,
+    RateWindow(Address),
+    TradeScore(Address),
+    ReputationSources,
+    ReputationWeights,
+    ReputationCache(Address),
 }
 
 #[contract]
@@ -97,6 +107,26 @@ impl CrossCooperativeTradeContract {
             .ok_or(AdminError::NotInitialized)
     }
 
+    /// Limit how many trade offers one cooperative can create per window (admin only)
+    pub fn set_rate_limit(
+        env: Env,
+        admin: Address,
+        max_actions_per_window: u32,
+        window_secs: u64,
+    ) -> Result<(), AdminError> {
+        rate_limit::set_rate_limit(&env, admin, max_actions_per_window, window_secs)
+    }
+
+    /// Get the configured rate limit, if any
+    pub fn get_rate_limit(env: Env) -> Option<RateLimitConfig> {
+        rate_limit::get_rate_limit(&env)
+    }
+
+    /// When a rate-limited cooperative may create offers again; 0 if it can now
+    pub fn get_retry_after(env: Env, cooperative_id: Address) -> u64 {
+        rate_limit::get_retry_after(&env, &cooperative_id)
+    }
+
     // Trade Management Functions
     /// Create a new trade offer
     pub fn create_trade_offer(
@@ -154,4 +184,38 @@ impl CrossCooperativeTradeContract {
     ) -> Result<(), TradeError> {
         reputation::update_reputation_after_trade(&env, &cooperative_id, successful)
     }
+
+    /// Set the quality and certification contracts the composite reputation reads (admin only)
+    pub fn set_reputation_sources(
+        env: Env,
+        admin: Address,
+        quality_contract: Option<Address>,
+        certification_contract: Option<Address>,
+    ) -> Result<(), AdminError> {
+        composite::set_reputation_sources(&env, admin, quality_contract, certification_contract)
+    }
+
+    /// Get the configured reputation sources
+    pub fn get_reputation_sources(env: Env) -> ReputationSources {
+        composite::get_reputation_sources(&env)
+    }
+
+    /// Set the composite reputation weights; they must add up to 10_000 bps (admin only)
+    pub fn set_reputation_weights(
+        env: Env,
+        admin: Address,
+        weights: ReputationWeights,
+    ) -> Result<(), AdminError> {
+        composite::set_reputation_weights(&env, admin, weights)
+    }
+
+    /// Get the composite reputation weights
+    pub fn get_reputation_weights(env: Env) -> ReputationWeights {
+        composite::get_reputation_weights(&env)
+    }
+
+    /// Combined trust score from decayed trades, open disputes and valid certifications
+    pub fn get_composite_reputation(env: Env, cooperative_id: Address) -> CompositeScore {
+        composite::get_composite_reputation(&env, cooperative_id)
+    }
 }
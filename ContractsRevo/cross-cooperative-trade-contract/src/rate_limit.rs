@@ -0,0 +1,115 @@
+use crate::{AdminError, DataKey, TradeError};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Ledgers close roughly every five seconds
+const LEDGER_SECS: u64 = 5;
+
+/// Per-address limit on open-write endpoints
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitConfig {
+    pub max_actions_per_window: u32,
+    pub window_secs: u64,
+}
+
+/// Actions taken by one address in its current window, kept in temporary storage
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateWindow {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+/// Set the per-address action limit (admin only)
+pub fn set_rate_limit(
+    env: &Env,
+    admin: Address,
+    max_actions_per_window: u32,
+    window_secs: u64,
+) -> Result<(), AdminError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(AdminError::NotInitialized)?;
+    if admin != stored_admin {
+        return Err(AdminError::UnauthorizedAccess);
+    }
+    admin.require_auth();
+
+    // Window records must be able to live for the whole window
+    if max_actions_per_window == 0
+        || window_secs == 0
+        || window_ledgers(window_secs) > env.storage().max_ttl() as u64
+    {
+        return Err(AdminError::InvalidRateLimit);
+    }
+
+    let config = RateLimitConfig {
+        max_actions_per_window,
+        window_secs,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::RateLimitConfig, &config);
+
+    Ok(())
+}
+
+pub fn get_rate_limit(env: &Env) -> Option<RateLimitConfig> {
+    env.storage().instance().get(&DataKey::RateLimitConfig)
+}
+
+/// When `actor` may act again: 0 while its current window has room left,
+/// otherwise the timestamp at which the window reopens
+pub fn get_retry_after(env: &Env, actor: &Address) -> u64 {
+    let Some(config) = get_rate_limit(env) else {
+        return 0;
+    };
+
+    let window = current_window(env, &config, actor);
+    if window.count < config.max_actions_per_window {
+        0
+    } else {
+        window.window_start.saturating_add(config.window_secs)
+    }
+}
+
+/// Count one action for `actor`, failing once the window's allowance is used up.
+/// Without a configured limit every action is allowed.
+pub fn check_rate_limit(env: &Env, actor: &Address) -> Result<(), TradeError> {
+    let Some(config) = get_rate_limit(env) else {
+        return Ok(());
+    };
+
+    let mut window = current_window(env, &config, actor);
+    if window.count >= config.max_actions_per_window {
+        return Err(TradeError::RateLimited);
+    }
+
+    window.count += 1;
+    let key = DataKey::RateWindow(actor.clone());
+    env.storage().temporary().set(&key, &window);
+    // Keep the entry only as long as the window it tracks
+    let ttl = window_ledgers(config.window_secs) as u32;
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+
+    Ok(())
+}
+
+/// The actor's window if it is still open, otherwise a fresh one starting now
+fn current_window(env: &Env, config: &RateLimitConfig, actor: &Address) -> RateWindow {
+    let now = env.ledger().timestamp();
+    env.storage()
+        .temporary()
+        .get(&DataKey::RateWindow(actor.clone()))
+        .filter(|w: &RateWindow| now < w.window_start.saturating_add(config.window_secs))
+        .unwrap_or(RateWindow {
+            window_start: now,
+            count: 0,
+        })
+}
+
+fn window_ledgers(window_secs: u64) -> u64 {
+    window_secs / LEDGER_SECS + 1
+}
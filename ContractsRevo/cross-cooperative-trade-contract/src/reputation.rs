@@ -1,4 +1,4 @@
-use crate::{DataKey, Reputation, TradeError};
+use crate::{composite, DataKey, Reputation, TradeError};
 use soroban_sdk::{Address, Env};
 
 pub fn update_reputation_after_trade(
@@ -20,6 +20,7 @@ pub fn update_reputation_after_trade(
     // Update trade counts (simplified - just track successful trades)
     if trade_successful {
         reputation.successful_trades += 1;
+        composite::record_successful_trade(env, cooperative_id);
     }
 
     // Simple rating calculation based on successful trades
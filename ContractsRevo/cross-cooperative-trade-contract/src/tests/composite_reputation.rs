@@ -0,0 +1,268 @@
+#![cfg(test)]
+
+use super::*;
+use crate::tests::utils::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, testutils::Ledger, vec, Address,
+    Env, Vec,
+};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[contract]
+struct MockQuality;
+
+#[contractimpl]
+impl MockQuality {
+    pub fn set_open_disputes(env: Env, holder: Address, count: u32) {
+        env.storage().instance().set(&holder, &count);
+    }
+
+    /// Make every read fail, as an upgraded or misbehaving contract would
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("failing"), &failing);
+    }
+
+    pub fn count_open_disputes(env: Env, holder: Address) -> u32 {
+        if env
+            .storage()
+            .instance()
+            .get(&symbol_short!("failing"))
+            .unwrap_or(false)
+        {
+            panic!("storage unavailable");
+        }
+        env.storage().instance().get(&holder).unwrap_or(0)
+    }
+}
+
+#[contract]
+struct MockCertification;
+
+#[contractimpl]
+impl MockCertification {
+    pub fn set_valid_certs(env: Env, owner: Address, count: u32) {
+        env.storage().instance().set(&owner, &count);
+    }
+
+    pub fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        _offset: u32,
+        _limit: u32,
+    ) -> Vec<u32> {
+        assert_eq!(status_filter, Some(CertStatus::Valid));
+        let count: u32 = env.storage().instance().get(&owner).unwrap_or(0);
+        let mut certs = vec![&env];
+        for id in 0..count {
+            certs.push_back(id);
+        }
+        certs
+    }
+}
+
+struct Setup<'a> {
+    client: CrossCooperativeTradeContractClient<'a>,
+    admin: Address,
+    cooperative: Address,
+    quality: MockQualityClient<'a>,
+    certification: MockCertificationClient<'a>,
+}
+
+/// A cooperative with five successful trades, one open dispute and two valid certifications
+fn setup(env: &Env) -> Setup<'_> {
+    let (admin, client) = setup_contract_with_admin(env);
+    let cooperative = Address::generate(env);
+    for _ in 0..5 {
+        client.update_reputation(&cooperative, &true);
+    }
+
+    let quality = MockQualityClient::new(env, &env.register(MockQuality, ()));
+    let certification = MockCertificationClient::new(env, &env.register(MockCertification, ()));
+    quality.set_open_disputes(&cooperative, &1);
+    certification.set_valid_certs(&cooperative, &2);
+    client.set_reputation_sources(
+        &admin,
+        &Some(quality.address.clone()),
+        &Some(certification.address.clone()),
+    );
+
+    Setup {
+        client,
+        admin,
+        cooperative,
+        quality,
+        certification,
+    }
+}
+
+#[test]
+fn test_full_composite_score() {
+    let env = Env::default();
+    let setup = setup(&env);
+
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(
+        score.flags,
+        COMPONENT_TRADE | COMPONENT_QUALITY | COMPONENT_CERTIFICATION
+    );
+    assert_eq!(score.trade_score, 5_000);
+    assert_eq!(score.open_disputes, 1);
+    assert_eq!(score.quality_score, 7_500);
+    assert_eq!(score.valid_certs, 2);
+    assert_eq!(score.certification_score, 5_000);
+    // 50% trade, 25% quality, 25% certification
+    assert_eq!(score.score, 5_625);
+
+    // Trades lose half their weight every half-life
+    env.ledger().set_timestamp(TRADE_HALF_LIFE_SECS / 2);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.trade_score, 3_750);
+    env.ledger().set_timestamp(TRADE_HALF_LIFE_SECS);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.trade_score, 2_500);
+
+    // New trades add to what is left of the old ones
+    setup.client.update_reputation(&setup.cooperative, &true);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.trade_score, 3_500);
+}
+
+#[test]
+fn test_failed_reads_degrade_gracefully() {
+    let env = Env::default();
+    let setup = setup(&env);
+
+    // Only the contract's own trade data is used without sources
+    setup
+        .client
+        .set_reputation_sources(&setup.admin, &None, &None);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.flags, COMPONENT_TRADE);
+    assert_eq!(score.score, 5_000);
+
+    // A failing quality read is left out and its weight spread over the rest
+    setup.quality.set_failing(&true);
+    setup.client.set_reputation_sources(
+        &setup.admin,
+        &Some(setup.quality.address.clone()),
+        &Some(setup.certification.address.clone()),
+    );
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.flags, COMPONENT_TRADE | COMPONENT_CERTIFICATION);
+    assert_eq!(score.open_disputes, 0);
+    assert_eq!(score.score, 5_000);
+
+    // An address with no contract behind it is treated the same way
+    setup.quality.set_failing(&false);
+    setup.client.set_reputation_sources(
+        &setup.admin,
+        &Some(setup.quality.address.clone()),
+        &Some(Address::generate(&env)),
+    );
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.flags, COMPONENT_TRADE | COMPONENT_QUALITY);
+    assert_eq!(score.valid_certs, 0);
+    // (5_000 * 5_000 + 7_500 * 2_500) / 7_500
+    assert_eq!(score.score, 5_833);
+}
+
+#[test]
+fn test_cached_reads_refresh_once_stale() {
+    let env = Env::default();
+    let setup = setup(&env);
+    assert_eq!(
+        setup
+            .client
+            .get_composite_reputation(&setup.cooperative)
+            .open_disputes,
+        1
+    );
+
+    // Within the staleness window the cached reads are reused
+    setup.quality.set_open_disputes(&setup.cooperative, &3);
+    setup.certification.set_valid_certs(&setup.cooperative, &4);
+    env.ledger().set_timestamp(REPUTATION_CACHE_SECS - 1);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.open_disputes, 1);
+    assert_eq!(score.valid_certs, 2);
+
+    env.ledger().set_timestamp(REPUTATION_CACHE_SECS);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.open_disputes, 3);
+    assert_eq!(score.quality_score, 2_500);
+    assert_eq!(score.valid_certs, 4);
+    assert_eq!(score.certification_score, 10_000);
+
+    // Changing the sources invalidates the cache straight away
+    setup
+        .client
+        .set_reputation_sources(&setup.admin, &Some(setup.quality.address.clone()), &None);
+    let score = setup.client.get_composite_reputation(&setup.cooperative);
+    assert_eq!(score.flags, COMPONENT_TRADE | COMPONENT_QUALITY);
+    assert_eq!(score.valid_certs, 0);
+
+    // Other cooperatives are cached separately
+    let newcomer = Address::generate(&env);
+    env.ledger().set_timestamp(REPUTATION_CACHE_SECS + DAY);
+    let score = setup.client.get_composite_reputation(&newcomer);
+    assert_eq!(score.trade_score, 0);
+    assert_eq!(score.quality_score, 10_000);
+}
+
+#[test]
+fn test_weight_changes_apply_immediately() {
+    let env = Env::default();
+    let setup = setup(&env);
+    assert_eq!(
+        setup
+            .client
+            .get_composite_reputation(&setup.cooperative)
+            .score,
+        5_625
+    );
+
+    let weights = ReputationWeights {
+        trade_bps: 2_000,
+        quality_bps: 6_000,
+        certification_bps: 2_000,
+    };
+    setup.client.set_reputation_weights(&setup.admin, &weights);
+    assert_eq!(setup.client.get_reputation_weights(), weights);
+    // Cached reads are rescored with the new weights
+    assert_eq!(
+        setup
+            .client
+            .get_composite_reputation(&setup.cooperative)
+            .score,
+        6_500
+    );
+
+    // Weights must add up to the whole score
+    let invalid = ReputationWeights {
+        trade_bps: 5_000,
+        quality_bps: 5_000,
+        certification_bps: 1,
+    };
+    assert_eq!(
+        setup
+            .client
+            .try_set_reputation_weights(&setup.admin, &invalid),
+        Err(Ok(AdminError::InvalidWeights))
+    );
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        setup.client.try_set_reputation_weights(&outsider, &weights),
+        Err(Ok(AdminError::UnauthorizedAccess))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_set_reputation_sources(&outsider, &None, &None),
+        Err(Ok(AdminError::UnauthorizedAccess))
+    );
+}
@@ -2,7 +2,9 @@
 
 // Import all test modules
 mod barter;
+mod composite_reputation;
 mod integration;
+mod rate_limit;
 mod reputation;
 mod trade;
 mod utils;
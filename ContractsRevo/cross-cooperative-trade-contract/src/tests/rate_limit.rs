@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use super::*;
+use crate::tests::utils::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn create_offer(
+    env: &Env,
+    client: &CrossCooperativeTradeContractClient,
+    cooperative: &Address,
+    n: u32,
+) -> Result<BytesN<32>, TradeError> {
+    let offered = create_test_product(env, "corn");
+    let mut requested = [0u8; 32];
+    requested[..4].copy_from_slice(&n.to_be_bytes());
+    let requested = BytesN::from_array(env, &requested);
+    client
+        .try_create_trade_offer(cooperative, &offered, &requested)
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_no_limit_by_default() {
+    let env = Env::default();
+    let (_, client) = setup_contract_with_admin(&env);
+    let cooperative = Address::generate(&env);
+
+    assert_eq!(client.get_rate_limit(), None);
+    for n in 0..10 {
+        assert!(create_offer(&env, &client, &cooperative, n).is_ok());
+    }
+}
+
+#[test]
+fn test_limit_reached() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (admin, client) = setup_contract_with_admin(&env);
+    let cooperative = Address::generate(&env);
+    client.set_rate_limit(&admin, &2, &3_600);
+
+    assert!(create_offer(&env, &client, &cooperative, 0).is_ok());
+    env.ledger().set_timestamp(1_100);
+    assert!(create_offer(&env, &client, &cooperative, 1).is_ok());
+    assert_eq!(
+        create_offer(&env, &client, &cooperative, 2),
+        Err(TradeError::RateLimited)
+    );
+
+    // The cooperative can look up when the window reopens
+    assert_eq!(client.get_retry_after(&cooperative), 4_600);
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(client.get_retry_after(&cooperative), 0);
+    assert!(create_offer(&env, &client, &cooperative, 3).is_ok());
+}
+
+#[test]
+fn test_window_rollover_restores_capacity() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (admin, client) = setup_contract_with_admin(&env);
+    let cooperative = Address::generate(&env);
+    client.set_rate_limit(&admin, &1, &600);
+
+    assert!(create_offer(&env, &client, &cooperative, 0).is_ok());
+    env.ledger().set_timestamp(1_599);
+    assert_eq!(
+        create_offer(&env, &client, &cooperative, 1),
+        Err(TradeError::RateLimited)
+    );
+
+    env.ledger().set_timestamp(1_600);
+    assert!(create_offer(&env, &client, &cooperative, 2).is_ok());
+    assert_eq!(
+        create_offer(&env, &client, &cooperative, 3),
+        Err(TradeError::RateLimited)
+    );
+}
+
+#[test]
+fn test_limit_is_per_address() {
+    let env = Env::default();
+    let (admin, client) = setup_contract_with_admin(&env);
+    let spammer = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.set_rate_limit(&admin, &1, &600);
+
+    assert!(create_offer(&env, &client, &spammer, 0).is_ok());
+    assert_eq!(
+        create_offer(&env, &client, &spammer, 1),
+        Err(TradeError::RateLimited)
+    );
+    assert!(create_offer(&env, &client, &other, 2).is_ok());
+}
+
+#[test]
+fn test_admin_adjusts_limit() {
+    let env = Env::default();
+    let (admin, client) = setup_contract_with_admin(&env);
+    let cooperative = Address::generate(&env);
+    client.set_rate_limit(&admin, &1, &600);
+
+    assert!(create_offer(&env, &client, &cooperative, 0).is_ok());
+    assert_eq!(
+        create_offer(&env, &client, &cooperative, 1),
+        Err(TradeError::RateLimited)
+    );
+
+    client.set_rate_limit(&admin, &3, &600);
+    assert_eq!(
+        client.get_rate_limit(),
+        Some(RateLimitConfig {
+            max_actions_per_window: 3,
+            window_secs: 600,
+        })
+    );
+    assert!(create_offer(&env, &client, &cooperative, 1).is_ok());
+    assert!(create_offer(&env, &client, &cooperative, 2).is_ok());
+    assert_eq!(
+        create_offer(&env, &client, &cooperative, 3),
+        Err(TradeError::RateLimited)
+    );
+}
+
+#[test]
+fn test_set_rate_limit_validation() {
+    let env = Env::default();
+    let (admin, client) = setup_contract_with_admin(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_rate_limit(&stranger, &1, &600),
+        Err(Ok(AdminError::UnauthorizedAccess))
+    );
+    assert_eq!(
+        client.try_set_rate_limit(&admin, &0, &600),
+        Err(Ok(AdminError::InvalidRateLimit))
+    );
+    assert_eq!(
+        client.try_set_rate_limit(&admin, &1, &0),
+        Err(Ok(AdminError::InvalidRateLimit))
+    );
+    // Window records could not be kept for a window this long
+    assert_eq!(
+        client.try_set_rate_limit(&admin, &1, &(10 * 365 * 86_400)),
+        Err(Ok(AdminError::InvalidRateLimit))
+    );
+
+    let uninitialized = setup_contract(&env);
+    assert_eq!(
+        uninitialized.try_set_rate_limit(&admin, &1, &600),
+        Err(Ok(AdminError::NotInitialized))
+    );
+}
@@ -595,3 +595,86 @@ mod trade_queries {
         );
     }
 }
+
+mod id_generation {
+    use super::*;
+    use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Vec};
+
+    #[test]
+    fn test_identical_offers_get_unique_ids() {
+        let env = Env::default();
+        let (_, client) = setup_contract_with_admin(&env);
+        let cooperative = Address::generate(&env);
+        let accepting = Address::generate(&env);
+        let offered_product = create_test_product(&env, "corn");
+        let requested_product = create_test_product(&env, "wheat");
+
+        let mut ids: Vec<BytesN<32>> = Vec::new(&env);
+        for _ in 0..25 {
+            let offer_id =
+                client.create_trade_offer(&cooperative, &offered_product, &requested_product);
+            assert!(!ids.contains(&offer_id), "Offer ID should be unique");
+            ids.push_back(offer_id.clone());
+
+            let agreement_id = client.accept_trade(&offer_id, &accepting);
+            assert!(
+                !ids.contains(&agreement_id),
+                "Agreement ID should be unique"
+            );
+            ids.push_back(agreement_id);
+        }
+        assert_eq!(ids.len(), 50);
+    }
+
+    #[test]
+    fn test_separate_deployments_do_not_share_ids() {
+        let env = Env::default();
+        let (_, first) = setup_contract_with_admin(&env);
+        let (_, second) = setup_contract_with_admin(&env);
+        let cooperative = Address::generate(&env);
+        let offered_product = create_test_product(&env, "corn");
+        let requested_product = create_test_product(&env, "wheat");
+
+        let first_id = first.create_trade_offer(&cooperative, &offered_product, &requested_product);
+        let second_id =
+            second.create_trade_offer(&cooperative, &offered_product, &requested_product);
+        assert_ne!(first_id, second_id);
+    }
+
+    #[test]
+    fn test_colliding_offer_id_rejected() {
+        let env = Env::default();
+        let (_, client) = setup_contract_with_admin(&env);
+        let cooperative = Address::generate(&env);
+        let offered_product = create_test_product(&env, "corn");
+        let requested_product = create_test_product(&env, "wheat");
+        let existing =
+            client.create_trade_offer(&cooperative, &offered_product, &requested_product);
+        let stored = client.get_trade_details(&existing);
+
+        // Occupy the ID the next offer will derive
+        env.as_contract(&client.address, || {
+            let counter: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::OfferCounter)
+                .unwrap();
+            let mut fields = Bytes::new(&env);
+            fields.append(&cooperative.clone().to_xdr(&env));
+            fields.append(&Bytes::from_array(&env, &offered_product.to_array()));
+            fields.append(&Bytes::from_array(&env, &requested_product.to_array()));
+            let next = generate_id(&env, &DataKey::OfferCounter, &fields);
+            env.storage()
+                .instance()
+                .set(&DataKey::OfferCounter, &counter);
+            env.storage()
+                .persistent()
+                .set(&DataKey::TradeOffer(next), &stored);
+        });
+
+        let result =
+            client.try_create_trade_offer(&cooperative, &offered_product, &requested_product);
+        assert_error_contains(result, Ok(TradeError::DuplicateId));
+        assert_eq!(client.list_active_offers().len(), 1);
+    }
+}
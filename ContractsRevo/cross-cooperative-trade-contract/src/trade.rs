@@ -1,5 +1,5 @@
-use crate::{utils::generate_id, DataKey, TradeError, TradeOffer};
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use crate::{rate_limit::check_rate_limit, utils::generate_id, DataKey, TradeError, TradeOffer};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
 
 pub fn create_trade_offer(
     env: Env,
@@ -9,6 +9,7 @@ pub fn create_trade_offer(
 ) -> Result<BytesN<32>, TradeError> {
     // Verify the caller is the cooperative
     cooperative_id.require_auth();
+    check_rate_limit(&env, &cooperative_id)?;
 
     // Basic validation: offered and requested products should be different
     if offered_product == requested_product {
@@ -16,7 +17,14 @@ pub fn create_trade_offer(
     }
 
     // Generate unique offer ID
-    let offer_id = generate_id(&env);
+    let mut fields = Bytes::new(&env);
+    fields.append(&cooperative_id.clone().to_xdr(&env));
+    fields.append(&Bytes::from_array(&env, &offered_product.to_array()));
+    fields.append(&Bytes::from_array(&env, &requested_product.to_array()));
+    let offer_id = generate_id(&env, &DataKey::OfferCounter, &fields);
+    if trade_offer_exists(&env, &offer_id) {
+        return Err(TradeError::DuplicateId);
+    }
 
     // Create the simplified trade offer
     let trade_offer = TradeOffer {
@@ -82,7 +90,7 @@ pub fn accept_trade(
         offer_id.clone(),
         trade_offer.cooperative_id,
         accepting_cooperative,
-    );
+    )?;
 
     // Remove from active offers
     let active_offers: Vec<BytesN<32>> = env
@@ -136,6 +144,13 @@ pub fn complete_trade(env: Env, offer_id: BytesN<32>, caller: Address) -> Result
     Ok(())
 }
 
+/// Whether a trade offer is already stored under `offer_id`
+pub fn trade_offer_exists(env: &Env, offer_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TradeOffer(offer_id.clone()))
+}
+
 pub fn get_trade_details(env: Env, offer_id: BytesN<32>) -> Result<TradeOffer, TradeError> {
     env.storage()
         .persistent()
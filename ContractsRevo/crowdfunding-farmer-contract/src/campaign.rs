@@ -1,6 +1,6 @@
-use soroban_sdk::{contracttype, prng, Address, BytesN, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Vec};
 
-use crate::utils;
+use crate::{milestones, milestones::Milestone, platform, utils, ContractError};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -8,6 +8,17 @@ pub enum CampaignStatus {
     Active = 0,
     Successful = 1,
     Failed = 2,
+    Cancelled = 3,
+}
+
+/// How a campaign that misses its goal is settled
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FundingModel {
+    /// Contributors are refunded unless the goal is reached
+    AllOrNothing = 0,
+    /// The farmer keeps whatever was raised by the deadline
+    KeepWhatYouRaise = 1,
 }
 
 #[contracttype]
@@ -20,6 +31,9 @@ pub struct Campaign {
     pub total_funded: i128,
     pub status: CampaignStatus,
     pub reward_token: Address,
+    pub funding_model: FundingModel,
+    /// Most the campaign accepts in total; starts at the goal
+    pub funding_cap: i128,
 }
 
 pub fn create_campaign(
@@ -28,9 +42,15 @@ pub fn create_campaign(
     goal_amount: i128,
     deadline: u64,
     reward_token: Address,
-) -> BytesN<32> {
-    utils::validate_amount(goal_amount);
-    utils::validate_deadline(env.ledger().timestamp(), deadline);
+    funding_model: FundingModel,
+    milestones: Vec<Milestone>,
+) -> Result<BytesN<32>, ContractError> {
+    farmer_id.require_auth();
+
+    utils::validate_amount(goal_amount)?;
+    utils::validate_deadline(env.ledger().timestamp(), deadline)?;
+    milestones::validate_milestones(deadline, goal_amount, &milestones)?;
+    platform::require_verified_farmer(&env, &farmer_id)?;
 
     // Generate random bytes for the campaign ID
     let prng = env.prng();
@@ -46,44 +66,133 @@ pub fn create_campaign(
         total_funded: 0,
         status: CampaignStatus::Active,
         reward_token,
+        funding_model,
+        funding_cap: goal_amount,
     };
 
     utils::save_campaign(&env, &campaign_id, &campaign);
-    campaign_id
+    milestones::save_milestones(&env, &campaign_id, &milestones);
+    platform::update_stats(&env, |stats| stats.active_campaigns += 1);
+    Ok(campaign_id)
+}
+
+pub fn get_campaign_details(env: Env, campaign_id: BytesN<32>) -> Result<Campaign, ContractError> {
+    utils::load_campaign(&env, &campaign_id)
+}
+
+/// Lets the campaign accept contributions past its goal, up to `funding_cap`.
+pub fn set_funding_cap(
+    env: Env,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+    funding_cap: i128,
+) -> Result<(), ContractError> {
+    farmer.require_auth();
+
+    let mut campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.farmer_id != farmer {
+        return Err(ContractError::Unauthorized);
+    }
+    if campaign.status != CampaignStatus::Active {
+        return Err(ContractError::CampaignNotActive);
+    }
+    // The cap must cover both the goal and the amount already raised
+    if funding_cap < campaign.goal_amount || funding_cap < campaign.total_funded {
+        return Err(ContractError::InvalidFundingCap);
+    }
+
+    campaign.funding_cap = funding_cap;
+    utils::save_campaign(&env, &campaign_id, &campaign);
+    Ok(())
+}
+
+/// Closes the campaign once its deadline has passed, marking it Successful if the goal was
+/// reached and Failed otherwise. Anyone can call it.
+pub fn finalize_campaign(
+    env: Env,
+    campaign_id: BytesN<32>,
+) -> Result<CampaignStatus, ContractError> {
+    let mut campaign = utils::load_campaign(&env, &campaign_id)?;
+
+    if campaign.status != CampaignStatus::Active {
+        return Err(ContractError::CampaignAlreadyFinalized);
+    }
+    if env.ledger().timestamp() < campaign.deadline {
+        return Err(ContractError::DeadlineNotPassed);
+    }
+
+    campaign.status = if campaign.total_funded >= campaign.goal_amount {
+        CampaignStatus::Successful
+    } else {
+        CampaignStatus::Failed
+    };
+    utils::save_campaign(&env, &campaign_id, &campaign);
+    platform::update_stats(&env, |stats| stats.active_campaigns -= 1);
+
+    Ok(campaign.status)
 }
 
-pub fn get_campaign_details(env: Env, campaign_id: BytesN<32>) -> Campaign {
-    utils::read_campaign(&env, &campaign_id).unwrap_or_else(|| panic!("Campaign not found"))
+/// Whether the raised funds may go to the farmer: any finalized KeepWhatYouRaise campaign,
+/// or an AllOrNothing campaign that reached its goal.
+pub fn is_releasable(campaign: &Campaign) -> bool {
+    match campaign.funding_model {
+        FundingModel::AllOrNothing => campaign.status == CampaignStatus::Successful,
+        FundingModel::KeepWhatYouRaise => matches!(
+            campaign.status,
+            CampaignStatus::Successful | CampaignStatus::Failed
+        ),
+    }
 }
 
-pub fn update_campaign_status(env: Env, campaign_id: BytesN<32>) {
-    let mut campaign = get_campaign_details(env.clone(), campaign_id.clone());
-    let current_time = env.ledger().timestamp();
-
-    if campaign.status == CampaignStatus::Active && current_time >= campaign.deadline {
-        campaign.status = if campaign.total_funded >= campaign.goal_amount {
-            CampaignStatus::Successful
-        } else {
-            CampaignStatus::Failed
-        };
-        utils::save_campaign(&env, &campaign_id, &campaign);
+/// Lets the farmer call off a campaign before it is finalized, e.g. after a crop failure.
+/// Contributions stop and every backer can be refunded in full.
+pub fn cancel_campaign(
+    env: Env,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+    reason_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    farmer.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.farmer_id != farmer {
+        return Err(ContractError::Unauthorized);
     }
+
+    cancel(&env, campaign, reason_hash)
 }
 
-pub fn check_and_update_campaign_status(env: Env, campaign_id: BytesN<32>) {
-    let mut campaign = get_campaign_details(env.clone(), campaign_id.clone());
-    let current_time = env.ledger().timestamp();
-
-    if campaign.status == CampaignStatus::Active {
-        if current_time >= campaign.deadline {
-            campaign.status = if campaign.total_funded >= campaign.goal_amount {
-                CampaignStatus::Successful
-            } else {
-                CampaignStatus::Failed
-            };
-        } else if campaign.total_funded >= campaign.goal_amount {
-            campaign.status = CampaignStatus::Successful;
-        }
-        utils::save_campaign(&env, &campaign_id, &campaign);
+/// Cancellation path for the admin when a campaign turns out to be fraudulent.
+pub fn admin_cancel_campaign(
+    env: Env,
+    admin: Address,
+    campaign_id: BytesN<32>,
+    reason_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    cancel(&env, campaign, reason_hash)
+}
+
+pub fn get_cancellation_reason(env: Env, campaign_id: BytesN<32>) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("cancel"), campaign_id))
+}
+
+fn cancel(env: &Env, mut campaign: Campaign, reason_hash: BytesN<32>) -> Result<(), ContractError> {
+    if campaign.status != CampaignStatus::Active {
+        return Err(ContractError::CampaignAlreadyFinalized);
     }
+
+    campaign.status = CampaignStatus::Cancelled;
+    utils::save_campaign(env, &campaign.campaign_id, &campaign);
+    platform::update_stats(env, |stats| stats.active_campaigns -= 1);
+    env.storage().persistent().set(
+        &(symbol_short!("cancel"), campaign.campaign_id.clone()),
+        &reason_hash,
+    );
+    Ok(())
 }
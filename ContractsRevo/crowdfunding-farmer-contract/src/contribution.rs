@@ -1,6 +1,6 @@
 use soroban_sdk::{contracttype, token, Address, BytesN, Env, Vec};
 
-use crate::{campaign, utils, CampaignStatus};
+use crate::{platform, referrals, rewards, utils, CampaignStatus, ContractError, FundingModel};
 
 #[contracttype]
 #[derive(Clone)]
@@ -10,18 +10,30 @@ pub struct Contribution {
     pub amount: i128,
 }
 
-pub fn contribute(env: Env, contributor: Address, campaign_id: BytesN<32>, amount: i128) {
-    utils::validate_amount(amount);
-
-    let mut campaign =
-        utils::read_campaign(&env, &campaign_id).unwrap_or_else(|| panic!("Campaign not found"));
+pub fn contribute(
+    env: Env,
+    contributor: Address,
+    campaign_id: BytesN<32>,
+    amount: i128,
+    referrer: Option<Address>,
+) -> Result<(), ContractError> {
+    utils::validate_amount(amount)?;
+
+    let mut campaign = utils::load_campaign(&env, &campaign_id)?;
+    if let Some(referrer) = &referrer {
+        referrals::validate_referrer(&campaign, &contributor, referrer)?;
+    }
 
     if campaign.status != CampaignStatus::Active {
-        panic!("Campaign is not active");
+        return Err(ContractError::CampaignNotActive);
     }
 
     if env.ledger().timestamp() >= campaign.deadline {
-        panic!("Campaign deadline has passed");
+        return Err(ContractError::DeadlinePassed);
+    }
+
+    if campaign.total_funded + amount > campaign.funding_cap {
+        return Err(ContractError::FundingCapExceeded);
     }
 
     // Require auth from contributor
@@ -33,9 +45,7 @@ pub fn contribute(env: Env, contributor: Address, campaign_id: BytesN<32>, amoun
 
     campaign.total_funded += amount;
     utils::save_campaign(&env, &campaign_id, &campaign);
-
-    // Check and update campaign status after contribution
-    campaign::check_and_update_campaign_status(env.clone(), campaign_id.clone());
+    platform::update_stats(&env, |stats| stats.total_raised += amount);
 
     let mut contributions =
         utils::read_contributions(&env, &campaign_id).unwrap_or_else(|| Vec::new(&env));
@@ -45,35 +55,128 @@ pub fn contribute(env: Env, contributor: Address, campaign_id: BytesN<32>, amoun
         amount,
     });
     utils::save_contributions(&env, &campaign_id, &contributions);
+    add_backing(&env, &campaign_id, &contributor, amount);
+
+    if let Some(referrer) = referrer {
+        referrals::accrue(&env, &campaign, &referrer, amount);
+    }
+    Ok(())
 }
 
-pub fn refund_contributions(env: Env, campaign_id: BytesN<32>) {
-    let campaign =
-        utils::read_campaign(&env, &campaign_id).unwrap_or_else(|| panic!("Campaign not found"));
+pub fn refund_contributions(env: Env, campaign_id: BytesN<32>) -> Result<(), ContractError> {
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
 
-    if campaign.status != CampaignStatus::Failed {
-        panic!("Campaign is not failed");
+    let refundable = match campaign.status {
+        CampaignStatus::Cancelled => true,
+        CampaignStatus::Failed => campaign.funding_model == FundingModel::AllOrNothing,
+        _ => false,
+    };
+    if !refundable {
+        return Err(ContractError::NotRefundable);
     }
 
-    let contributions = utils::read_contributions(&env, &campaign_id)
-        .unwrap_or_else(|| panic!("No contributions found"));
-
+    // Anyone may trigger the refund; running it again finds nothing left to pay
     let token_client = token::Client::new(&env, &campaign.reward_token);
 
-    // Require auth from contract (since it's initiating the refund)
-    env.current_contract_address().require_auth();
-
-    for contribution in contributions.iter() {
-        token_client.transfer(
-            &env.current_contract_address(),
-            &contribution.contributor_id,
-            &contribution.amount,
-        );
+    for (backer, amount) in get_backer_totals(&env, &campaign_id).iter() {
+        token_client.transfer(&env.current_contract_address(), &backer, &amount);
+        utils::save_backing(&env, &campaign_id, &backer, 0);
+        platform::update_stats(&env, |stats| stats.total_refunded += amount);
     }
 
     utils::save_contributions(&env, &campaign_id, &Vec::new(&env));
+    utils::save_backers(&env, &campaign_id, &Vec::new(&env));
+
+    rewards::return_tier_inventory(&env, &campaign_id, &campaign.farmer_id);
+    Ok(())
 }
 
 pub fn get_contributions(env: Env, campaign_id: BytesN<32>) -> Vec<Contribution> {
     utils::read_contributions(&env, &campaign_id).unwrap_or_else(|| Vec::new(&env))
 }
+
+pub fn get_contributor_total(env: Env, campaign_id: BytesN<32>, contributor: Address) -> i128 {
+    utils::read_backing(&env, &campaign_id, &contributor)
+}
+
+/// Pages through the campaign's backers and their merged contribution, in order of first
+/// contribution.
+pub fn list_contributors(
+    env: Env,
+    campaign_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<(Address, i128)> {
+    let mut page = Vec::new(&env);
+
+    for backer in utils::read_backers(&env, &campaign_id)
+        .iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+    {
+        let amount = utils::read_backing(&env, &campaign_id, &backer);
+        page.push_back((backer, amount));
+    }
+
+    page
+}
+
+/// Moves part of a backer position to another address. The receiver takes over the reward
+/// share, refund claim and milestone voting weight of the amount moved.
+pub fn transfer_contribution(
+    env: Env,
+    from: Address,
+    to: Address,
+    campaign_id: BytesN<32>,
+    amount: i128,
+) -> Result<(), ContractError> {
+    utils::validate_amount(amount)?;
+    if from == to {
+        return Err(ContractError::SelfTransfer);
+    }
+
+    from.require_auth();
+    to.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.status == CampaignStatus::Failed || env.ledger().timestamp() >= campaign.deadline {
+        return Err(ContractError::CampaignAlreadyFinalized);
+    }
+
+    let from_amount = utils::read_backing(&env, &campaign_id, &from);
+    if from_amount < amount {
+        return Err(ContractError::InsufficientContribution);
+    }
+
+    utils::save_backing(&env, &campaign_id, &from, from_amount - amount);
+    if from_amount == amount {
+        let mut backers = utils::read_backers(&env, &campaign_id);
+        if let Some(index) = backers.first_index_of(&from) {
+            backers.remove(index);
+            utils::save_backers(&env, &campaign_id, &backers);
+        }
+    }
+    add_backing(&env, &campaign_id, &to, amount);
+    Ok(())
+}
+
+/// Every backer with their merged contribution; this, not the raw contribution log, decides
+/// who is paid rewards and refunds.
+pub(crate) fn get_backer_totals(env: &Env, campaign_id: &BytesN<32>) -> Vec<(Address, i128)> {
+    let mut totals = Vec::new(env);
+    for backer in utils::read_backers(env, campaign_id).iter() {
+        let amount = utils::read_backing(env, campaign_id, &backer);
+        totals.push_back((backer, amount));
+    }
+    totals
+}
+
+fn add_backing(env: &Env, campaign_id: &BytesN<32>, backer: &Address, amount: i128) {
+    let current = utils::read_backing(env, campaign_id, backer);
+    if current == 0 {
+        let mut backers = utils::read_backers(env, campaign_id);
+        backers.push_back(backer.clone());
+        utils::save_backers(env, campaign_id, &backers);
+    }
+    utils::save_backing(env, campaign_id, backer, current + amount);
+}
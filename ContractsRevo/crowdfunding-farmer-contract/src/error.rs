@@ -0,0 +1,65 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ContractError {
+    // General errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    InvalidAmount = 4,
+    InvalidDeadline = 5,
+
+    // Campaign errors
+    CampaignNotFound = 10,
+    CampaignNotActive = 11,
+    CampaignAlreadyFinalized = 12,
+    DeadlineNotPassed = 13,
+    DeadlinePassed = 14,
+    InvalidFundingCap = 15,
+    FundingCapExceeded = 16,
+    NotRefundable = 17,
+    FundsNotReleasable = 18,
+
+    // Contribution errors
+    SelfTransfer = 20,
+    InsufficientContribution = 21,
+
+    // Platform errors
+    FeeTooHigh = 30,
+    FarmerNotVerified = 31,
+
+    // Milestone errors
+    InvalidMilestones = 40,
+    MilestoneNotFound = 41,
+    MilestoneDeadlinePassed = 42,
+    PreviousMilestoneNotReleased = 43,
+    EvidenceAlreadySubmitted = 44,
+    MilestoneNotSubmitted = 45,
+    VotingClosed = 46,
+    VotingStillOpen = 47,
+    NotContributor = 48,
+    AlreadyVoted = 49,
+    NoMilestones = 50,
+    NoFailedMilestone = 51,
+    RemainingRefunded = 52,
+    ReleasedByMilestone = 53,
+
+    // Reward errors
+    TiersLocked = 60,
+    TooManyTiers = 61,
+    InvalidTiers = 62,
+    RewardAlreadyClaimed = 63,
+    NotQualified = 64,
+    NoRewardsLeft = 65,
+    AlreadyDistributed = 66,
+
+    // Update errors
+    InvalidTitle = 70,
+
+    // Referral errors
+    InvalidReferrer = 80,
+    InvalidReferralConfig = 81,
+    ReferralNotClaimable = 82,
+    NoReferralBonus = 83,
+}
@@ -2,55 +2,343 @@
 
 mod campaign;
 mod contribution;
+mod error;
+mod milestones;
+mod platform;
+mod referrals;
 mod rewards;
+mod updates;
 mod utils;
 
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
-pub use campaign::{Campaign, CampaignStatus};
+pub use campaign::{Campaign, CampaignStatus, FundingModel};
 pub use contribution::Contribution;
-pub use rewards::Reward;
+pub use error::ContractError;
+pub use milestones::{Milestone, MilestoneState, MilestoneStatus};
+pub use platform::{PlatformFee, PlatformStats};
+pub use referrals::{ReferralConfig, ReferralStats};
+pub use rewards::{Reward, RewardTier};
+pub use updates::CampaignUpdate;
 
 #[contract]
 pub struct CrowdfundingFarmerContract;
 
 #[contractimpl]
 impl CrowdfundingFarmerContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        if utils::read_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        utils::save_admin(&env, &admin);
+        Ok(())
+    }
+
+    pub fn set_platform_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), ContractError> {
+        platform::set_platform_fee(env, admin, fee_bps, treasury)
+    }
+
+    pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+        platform::get_platform_fee(env)
+    }
+
+    pub fn set_verification_required(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), ContractError> {
+        platform::set_verification_required(env, admin, required)
+    }
+
+    pub fn verify_farmer(
+        env: Env,
+        admin: Address,
+        farmer: Address,
+        kyc_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        platform::verify_farmer(env, admin, farmer, kyc_hash)
+    }
+
+    pub fn revoke_farmer_verification(
+        env: Env,
+        admin: Address,
+        farmer: Address,
+    ) -> Result<(), ContractError> {
+        platform::revoke_farmer_verification(env, admin, farmer)
+    }
+
+    pub fn is_farmer_verified(env: Env, farmer: Address) -> bool {
+        platform::is_farmer_verified(env, farmer)
+    }
+
+    pub fn get_platform_stats(env: Env) -> PlatformStats {
+        platform::get_platform_stats(env)
+    }
+
     pub fn create_campaign(
         env: Env,
         farmer_id: Address,
         goal_amount: i128,
         deadline: u64,
         reward_token: Address,
-    ) -> BytesN<32> {
-        campaign::create_campaign(env, farmer_id, goal_amount, deadline, reward_token)
+        funding_model: FundingModel,
+        milestones: Vec<Milestone>,
+    ) -> Result<BytesN<32>, ContractError> {
+        campaign::create_campaign(
+            env,
+            farmer_id,
+            goal_amount,
+            deadline,
+            reward_token,
+            funding_model,
+            milestones,
+        )
+    }
+
+    pub fn set_funding_cap(
+        env: Env,
+        farmer: Address,
+        campaign_id: BytesN<32>,
+        funding_cap: i128,
+    ) -> Result<(), ContractError> {
+        campaign::set_funding_cap(env, farmer, campaign_id, funding_cap)
+    }
+
+    pub fn finalize_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<CampaignStatus, ContractError> {
+        campaign::finalize_campaign(env, campaign_id)
+    }
+
+    pub fn contribute(
+        env: Env,
+        contributor: Address,
+        campaign_id: BytesN<32>,
+        amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<(), ContractError> {
+        contribution::contribute(env, contributor, campaign_id, amount, referrer)
+    }
+
+    pub fn set_referral_config(
+        env: Env,
+        admin: Address,
+        bonus_bps: u32,
+        campaign_cap: i128,
+    ) -> Result<(), ContractError> {
+        referrals::set_referral_config(env, admin, bonus_bps, campaign_cap)
+    }
+
+    pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+        referrals::get_referral_config(env)
+    }
+
+    pub fn claim_referral_bonus(
+        env: Env,
+        referrer: Address,
+        campaign_id: BytesN<32>,
+    ) -> Result<i128, ContractError> {
+        referrals::claim_referral_bonus(env, referrer, campaign_id)
+    }
+
+    pub fn get_referral_credit(env: Env, campaign_id: BytesN<32>, referrer: Address) -> i128 {
+        referrals::get_referral_credit(env, campaign_id, referrer)
+    }
+
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        referrals::get_referral_stats(env, referrer)
+    }
+
+    pub fn cancel_campaign(
+        env: Env,
+        farmer: Address,
+        campaign_id: BytesN<32>,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        campaign::cancel_campaign(env, farmer, campaign_id, reason_hash)
+    }
+
+    pub fn admin_cancel_campaign(
+        env: Env,
+        admin: Address,
+        campaign_id: BytesN<32>,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        campaign::admin_cancel_campaign(env, admin, campaign_id, reason_hash)
     }
 
-    pub fn contribute(env: Env, contributor: Address, campaign_id: BytesN<32>, amount: i128) {
-        contribution::contribute(env, contributor, campaign_id, amount)
+    pub fn get_cancellation_reason(env: Env, campaign_id: BytesN<32>) -> Option<BytesN<32>> {
+        campaign::get_cancellation_reason(env, campaign_id)
     }
 
-    pub fn distribute_rewards(env: Env, campaign_id: BytesN<32>) {
+    pub fn post_update(
+        env: Env,
+        farmer: Address,
+        campaign_id: BytesN<32>,
+        title: String,
+        body_hash: BytesN<32>,
+    ) -> Result<u32, ContractError> {
+        updates::post_update(env, farmer, campaign_id, title, body_hash)
+    }
+
+    pub fn list_updates(
+        env: Env,
+        campaign_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CampaignUpdate> {
+        updates::list_updates(env, campaign_id, offset, limit)
+    }
+
+    pub fn distribute_rewards(env: Env, campaign_id: BytesN<32>) -> Result<(), ContractError> {
         rewards::distribute_rewards(env, campaign_id)
     }
 
-    pub fn refund_contributions(env: Env, campaign_id: BytesN<32>) {
+    pub fn set_reward_tiers(
+        env: Env,
+        farmer: Address,
+        campaign_id: BytesN<32>,
+        tiers: Vec<RewardTier>,
+    ) -> Result<(), ContractError> {
+        rewards::set_reward_tiers(env, farmer, campaign_id, tiers)
+    }
+
+    pub fn claim_reward(
+        env: Env,
+        contributor: Address,
+        campaign_id: BytesN<32>,
+    ) -> Result<u32, ContractError> {
+        rewards::claim_reward(env, contributor, campaign_id)
+    }
+
+    pub fn get_my_tier(env: Env, contributor: Address, campaign_id: BytesN<32>) -> Option<u32> {
+        rewards::get_my_tier(env, contributor, campaign_id)
+    }
+
+    pub fn get_reward_tiers(env: Env, campaign_id: BytesN<32>) -> Vec<RewardTier> {
+        rewards::get_reward_tiers(env, campaign_id)
+    }
+
+    pub fn refund_contributions(env: Env, campaign_id: BytesN<32>) -> Result<(), ContractError> {
         contribution::refund_contributions(env, campaign_id)
     }
 
-    pub fn get_campaign_details(env: Env, campaign_id: BytesN<32>) -> Campaign {
+    pub fn get_campaign_details(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<Campaign, ContractError> {
         campaign::get_campaign_details(env, campaign_id)
     }
 
     pub fn get_contributions(env: Env, campaign_id: BytesN<32>) -> Vec<Contribution> {
         contribution::get_contributions(env, campaign_id)
     }
+
+    pub fn get_contributor_total(env: Env, campaign_id: BytesN<32>, contributor: Address) -> i128 {
+        contribution::get_contributor_total(env, campaign_id, contributor)
+    }
+
+    pub fn list_contributors(
+        env: Env,
+        campaign_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(Address, i128)> {
+        contribution::list_contributors(env, campaign_id, offset, limit)
+    }
+
+    pub fn transfer_contribution(
+        env: Env,
+        from: Address,
+        to: Address,
+        campaign_id: BytesN<32>,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        contribution::transfer_contribution(env, from, to, campaign_id, amount)
+    }
+
+    pub fn submit_milestone_evidence(
+        env: Env,
+        farmer: Address,
+        campaign_id: BytesN<32>,
+        milestone_idx: u32,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        milestones::submit_milestone_evidence(
+            env,
+            farmer,
+            campaign_id,
+            milestone_idx,
+            evidence_hash,
+        )
+    }
+
+    pub fn vote_milestone(
+        env: Env,
+        contributor: Address,
+        campaign_id: BytesN<32>,
+        milestone_idx: u32,
+        approve: bool,
+    ) -> Result<(), ContractError> {
+        milestones::vote_milestone(env, contributor, campaign_id, milestone_idx, approve)
+    }
+
+    pub fn close_milestone_vote(
+        env: Env,
+        campaign_id: BytesN<32>,
+        milestone_idx: u32,
+    ) -> Result<(), ContractError> {
+        milestones::close_milestone_vote(env, campaign_id, milestone_idx)
+    }
+
+    pub fn approve_milestone(
+        env: Env,
+        admin: Address,
+        campaign_id: BytesN<32>,
+        milestone_idx: u32,
+    ) -> Result<(), ContractError> {
+        milestones::approve_milestone(env, admin, campaign_id, milestone_idx)
+    }
+
+    pub fn refund_remaining(env: Env, campaign_id: BytesN<32>) -> Result<i128, ContractError> {
+        milestones::refund_remaining(env, campaign_id)
+    }
+
+    pub fn get_milestones(env: Env, campaign_id: BytesN<32>) -> Vec<Milestone> {
+        milestones::get_milestones(env, campaign_id)
+    }
+
+    pub fn get_milestone_state(
+        env: Env,
+        campaign_id: BytesN<32>,
+        milestone_idx: u32,
+    ) -> Result<MilestoneState, ContractError> {
+        milestones::get_milestone_state(env, campaign_id, milestone_idx)
+    }
+
+    pub fn get_disbursed_amount(env: Env, campaign_id: BytesN<32>) -> i128 {
+        milestones::get_disbursed(&env, &campaign_id)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     pub mod campaign;
     pub mod contribution;
+    pub mod contributors;
+    pub mod lifecycle;
+    pub mod milestones;
+    pub mod platform;
+    pub mod referrals;
     pub mod rewards;
+    pub mod tiers;
+    pub mod updates;
     pub mod utils;
 }
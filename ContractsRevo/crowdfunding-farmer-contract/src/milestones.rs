@@ -0,0 +1,376 @@
+use soroban_sdk::{contracttype, symbol_short, token, Address, BytesN, Env, Vec};
+
+use crate::{campaign, contribution, platform, utils, ContractError};
+
+/// How long backers can vote on a milestone after the farmer submits evidence
+pub const VOTING_WINDOW: u64 = 3 * 24 * 60 * 60;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub description_hash: BytesN<32>,
+    pub amount: i128,
+    pub deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MilestoneStatus {
+    Pending = 0,
+    Submitted = 1,
+    Released = 2,
+    Rejected = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneState {
+    pub status: MilestoneStatus,
+    pub evidence_hash: BytesN<32>,
+    pub submitted_at: u64,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub released_amount: i128,
+}
+
+/// Checks that the milestones fund the whole goal and fall due after the campaign closes.
+/// An empty list keeps the campaign on a single release through `distribute_rewards`.
+pub fn validate_milestones(
+    campaign_deadline: u64,
+    goal_amount: i128,
+    milestones: &Vec<Milestone>,
+) -> Result<(), ContractError> {
+    if milestones.is_empty() {
+        return Ok(());
+    }
+
+    let mut total: i128 = 0;
+    let mut previous_deadline = campaign_deadline;
+    for milestone in milestones.iter() {
+        utils::validate_amount(milestone.amount)?;
+        if milestone.deadline <= previous_deadline {
+            return Err(ContractError::InvalidMilestones);
+        }
+        previous_deadline = milestone.deadline;
+        total += milestone.amount;
+    }
+
+    if total != goal_amount {
+        return Err(ContractError::InvalidMilestones);
+    }
+    Ok(())
+}
+
+pub fn save_milestones(env: &Env, campaign_id: &BytesN<32>, milestones: &Vec<Milestone>) {
+    if milestones.is_empty() {
+        return;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("mstones"), campaign_id.clone()), milestones);
+}
+
+pub fn get_milestones(env: Env, campaign_id: BytesN<32>) -> Vec<Milestone> {
+    read_milestones(&env, &campaign_id)
+}
+
+pub fn get_milestone_state(
+    env: Env,
+    campaign_id: BytesN<32>,
+    milestone_idx: u32,
+) -> Result<MilestoneState, ContractError> {
+    get_milestone(&env, &campaign_id, milestone_idx)?;
+    Ok(read_state(&env, &campaign_id, milestone_idx))
+}
+
+/// Amount already released to the farmer through approved milestones.
+pub fn get_disbursed(env: &Env, campaign_id: &BytesN<32>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("disbursed"), campaign_id.clone()))
+        .unwrap_or(0)
+}
+
+pub fn has_milestones(env: &Env, campaign_id: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(symbol_short!("mstones"), campaign_id.clone()))
+}
+
+pub fn submit_milestone_evidence(
+    env: Env,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+    milestone_idx: u32,
+    evidence_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    farmer.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.farmer_id != farmer {
+        return Err(ContractError::Unauthorized);
+    }
+    if !campaign::is_releasable(&campaign) {
+        return Err(ContractError::FundsNotReleasable);
+    }
+    require_not_refunded(&env, &campaign_id)?;
+
+    let milestone = get_milestone(&env, &campaign_id, milestone_idx)?;
+    if env.ledger().timestamp() > milestone.deadline {
+        return Err(ContractError::MilestoneDeadlinePassed);
+    }
+
+    // Tranches are released in order, so earlier milestones must already be paid out
+    if milestone_idx > 0
+        && read_state(&env, &campaign_id, milestone_idx - 1).status != MilestoneStatus::Released
+    {
+        return Err(ContractError::PreviousMilestoneNotReleased);
+    }
+
+    let mut state = read_state(&env, &campaign_id, milestone_idx);
+    if state.status != MilestoneStatus::Pending {
+        return Err(ContractError::EvidenceAlreadySubmitted);
+    }
+
+    state.status = MilestoneStatus::Submitted;
+    state.evidence_hash = evidence_hash;
+    state.submitted_at = env.ledger().timestamp();
+    save_state(&env, &campaign_id, milestone_idx, &state);
+    Ok(())
+}
+
+/// Records a backer's vote weighted by their total contribution. The milestone resolves as
+/// soon as either side holds a majority of the campaign's funding.
+pub fn vote_milestone(
+    env: Env,
+    contributor: Address,
+    campaign_id: BytesN<32>,
+    milestone_idx: u32,
+    approve: bool,
+) -> Result<(), ContractError> {
+    contributor.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    let mut state = read_submitted_state(&env, &campaign_id, milestone_idx)?;
+
+    if env.ledger().timestamp() > state.submitted_at + VOTING_WINDOW {
+        return Err(ContractError::VotingClosed);
+    }
+
+    let weight = utils::read_backing(&env, &campaign_id, &contributor);
+    if weight == 0 {
+        return Err(ContractError::NotContributor);
+    }
+
+    let vote_key = (
+        symbol_short!("ms_vote"),
+        campaign_id.clone(),
+        milestone_idx,
+        contributor,
+    );
+    if env.storage().persistent().has(&vote_key) {
+        return Err(ContractError::AlreadyVoted);
+    }
+    env.storage().persistent().set(&vote_key, &approve);
+
+    if approve {
+        state.votes_for += weight;
+    } else {
+        state.votes_against += weight;
+    }
+
+    if state.votes_for * 2 > campaign.total_funded {
+        release_tranche(&env, &campaign_id, milestone_idx, state)?;
+    } else if state.votes_against * 2 >= campaign.total_funded {
+        state.status = MilestoneStatus::Rejected;
+        save_state(&env, &campaign_id, milestone_idx, &state);
+    } else {
+        save_state(&env, &campaign_id, milestone_idx, &state);
+    }
+    Ok(())
+}
+
+/// Settles a vote once its window has closed: the side with more contribution weight wins,
+/// and a tie or a vote nobody joined rejects the milestone.
+pub fn close_milestone_vote(
+    env: Env,
+    campaign_id: BytesN<32>,
+    milestone_idx: u32,
+) -> Result<(), ContractError> {
+    let state = read_submitted_state(&env, &campaign_id, milestone_idx)?;
+
+    if env.ledger().timestamp() <= state.submitted_at + VOTING_WINDOW {
+        return Err(ContractError::VotingStillOpen);
+    }
+
+    if state.votes_for > state.votes_against {
+        release_tranche(&env, &campaign_id, milestone_idx, state)?;
+    } else {
+        let mut state = state;
+        state.status = MilestoneStatus::Rejected;
+        save_state(&env, &campaign_id, milestone_idx, &state);
+    }
+    Ok(())
+}
+
+pub fn approve_milestone(
+    env: Env,
+    admin: Address,
+    campaign_id: BytesN<32>,
+    milestone_idx: u32,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    let state = read_submitted_state(&env, &campaign_id, milestone_idx)?;
+    release_tranche(&env, &campaign_id, milestone_idx, state)
+}
+
+/// Returns the undisbursed balance to contributors pro-rata once a milestone was rejected or
+/// its deadline passed without evidence. Returns the total amount refunded.
+pub fn refund_remaining(env: Env, campaign_id: BytesN<32>) -> Result<i128, ContractError> {
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    require_not_refunded(&env, &campaign_id)?;
+
+    let milestones = read_milestones(&env, &campaign_id);
+    if milestones.is_empty() {
+        return Err(ContractError::NoMilestones);
+    }
+
+    let now = env.ledger().timestamp();
+    let failed = milestones.iter().enumerate().any(|(idx, milestone)| {
+        let state = read_state(&env, &campaign_id, idx as u32);
+        state.status == MilestoneStatus::Rejected
+            || (state.status == MilestoneStatus::Pending && now > milestone.deadline)
+    });
+    if !failed {
+        return Err(ContractError::NoFailedMilestone);
+    }
+
+    let remaining = campaign.total_funded - get_disbursed(&env, &campaign_id);
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("ms_refund"), campaign_id.clone()), &true);
+
+    if remaining <= 0 || campaign.total_funded == 0 {
+        return Ok(0);
+    }
+
+    let token_client = token::Client::new(&env, &campaign.reward_token);
+    let mut refunded = 0;
+    for (backer, amount) in contribution::get_backer_totals(&env, &campaign_id).iter() {
+        let share = amount * remaining / campaign.total_funded;
+        if share > 0 {
+            token_client.transfer(&env.current_contract_address(), &backer, &share);
+            refunded += share;
+        }
+    }
+    platform::update_stats(&env, |stats| stats.total_refunded += refunded);
+
+    Ok(refunded)
+}
+
+fn release_tranche(
+    env: &Env,
+    campaign_id: &BytesN<32>,
+    milestone_idx: u32,
+    mut state: MilestoneState,
+) -> Result<(), ContractError> {
+    let campaign = utils::load_campaign(env, campaign_id)?;
+    let milestones = read_milestones(env, campaign_id);
+    let milestone = get_milestone(env, campaign_id, milestone_idx)?;
+    let disbursed = get_disbursed(env, campaign_id);
+
+    // Overfunding is shared across tranches; the last one sweeps any rounding remainder
+    let amount = if milestone_idx + 1 == milestones.len() {
+        campaign.total_funded - disbursed
+    } else {
+        milestone.amount * campaign.total_funded / campaign.goal_amount
+    };
+
+    platform::pay_farmer(env, &campaign, amount);
+
+    env.storage().persistent().set(
+        &(symbol_short!("disbursed"), campaign_id.clone()),
+        &(disbursed + amount),
+    );
+
+    state.status = MilestoneStatus::Released;
+    state.released_amount = amount;
+    save_state(env, campaign_id, milestone_idx, &state);
+    Ok(())
+}
+
+fn read_submitted_state(
+    env: &Env,
+    campaign_id: &BytesN<32>,
+    milestone_idx: u32,
+) -> Result<MilestoneState, ContractError> {
+    require_not_refunded(env, campaign_id)?;
+    get_milestone(env, campaign_id, milestone_idx)?;
+
+    let state = read_state(env, campaign_id, milestone_idx);
+    if state.status != MilestoneStatus::Submitted {
+        return Err(ContractError::MilestoneNotSubmitted);
+    }
+
+    Ok(state)
+}
+
+fn require_not_refunded(env: &Env, campaign_id: &BytesN<32>) -> Result<(), ContractError> {
+    if env
+        .storage()
+        .persistent()
+        .has(&(symbol_short!("ms_refund"), campaign_id.clone()))
+    {
+        return Err(ContractError::RemainingRefunded);
+    }
+    Ok(())
+}
+
+fn read_milestones(env: &Env, campaign_id: &BytesN<32>) -> Vec<Milestone> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("mstones"), campaign_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_milestone(
+    env: &Env,
+    campaign_id: &BytesN<32>,
+    milestone_idx: u32,
+) -> Result<Milestone, ContractError> {
+    read_milestones(env, campaign_id)
+        .get(milestone_idx)
+        .ok_or(ContractError::MilestoneNotFound)
+}
+
+fn read_state(env: &Env, campaign_id: &BytesN<32>, milestone_idx: u32) -> MilestoneState {
+    env.storage()
+        .persistent()
+        .get(&(
+            symbol_short!("ms_state"),
+            campaign_id.clone(),
+            milestone_idx,
+        ))
+        .unwrap_or(MilestoneState {
+            status: MilestoneStatus::Pending,
+            evidence_hash: BytesN::from_array(env, &[0; 32]),
+            submitted_at: 0,
+            votes_for: 0,
+            votes_against: 0,
+            released_amount: 0,
+        })
+}
+
+fn save_state(env: &Env, campaign_id: &BytesN<32>, milestone_idx: u32, state: &MilestoneState) {
+    env.storage().persistent().set(
+        &(
+            symbol_short!("ms_state"),
+            campaign_id.clone(),
+            milestone_idx,
+        ),
+        state,
+    );
+}
@@ -0,0 +1,164 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+
+use crate::{campaign::Campaign, referrals, utils, ContractError};
+
+/// Highest platform fee the admin can configure (10%)
+pub const MAX_FEE_BPS: u32 = 1_000;
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFee {
+    pub fee_bps: u32,
+    pub treasury: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlatformStats {
+    pub total_raised: i128,
+    pub total_refunded: i128,
+    pub fees_collected: i128,
+    pub active_campaigns: u32,
+}
+
+pub fn set_platform_fee(
+    env: Env,
+    admin: Address,
+    fee_bps: u32,
+    treasury: Address,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::FeeTooHigh);
+    }
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("fee"), &PlatformFee { fee_bps, treasury });
+    Ok(())
+}
+
+pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+    env.storage().instance().get(&symbol_short!("fee"))
+}
+
+/// Turns the verification gate for new campaigns on or off. It is off until the admin
+/// enables it.
+pub fn set_verification_required(
+    env: Env,
+    admin: Address,
+    required: bool,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&symbol_short!("kyc_req"), &required);
+    Ok(())
+}
+
+pub fn verify_farmer(
+    env: Env,
+    admin: Address,
+    farmer: Address,
+    kyc_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("verified"), farmer), &kyc_hash);
+    Ok(())
+}
+
+/// Stops the farmer from opening new campaigns; campaigns already running are unaffected.
+pub fn revoke_farmer_verification(
+    env: Env,
+    admin: Address,
+    farmer: Address,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    let key = (symbol_short!("verified"), farmer);
+    if !env.storage().persistent().has(&key) {
+        return Err(ContractError::FarmerNotVerified);
+    }
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+pub fn is_farmer_verified(env: Env, farmer: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(symbol_short!("verified"), farmer))
+}
+
+pub fn require_verified_farmer(env: &Env, farmer: &Address) -> Result<(), ContractError> {
+    let required: bool = env
+        .storage()
+        .instance()
+        .get(&symbol_short!("kyc_req"))
+        .unwrap_or(false);
+
+    if required && !is_farmer_verified(env.clone(), farmer.clone()) {
+        return Err(ContractError::FarmerNotVerified);
+    }
+    Ok(())
+}
+
+pub fn get_platform_stats(env: Env) -> PlatformStats {
+    read_stats(&env)
+}
+
+/// Sends funds released from escrow to the farmer, less the platform fee.
+pub fn pay_farmer(env: &Env, campaign: &Campaign, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+
+    let mut fee = 0;
+    if let Some(config) = get_platform_fee(env.clone()) {
+        fee = amount * config.fee_bps as i128 / BPS_DENOMINATOR;
+        // Referral bonuses stay in the contract until their referrers claim them
+        let treasury_fee = fee - referrals::reserve_from_fee(env, campaign, fee);
+        if treasury_fee > 0 {
+            utils::transfer_tokens(
+                env,
+                &campaign.reward_token,
+                &env.current_contract_address(),
+                &config.treasury,
+                treasury_fee,
+            );
+            update_stats(env, |stats| stats.fees_collected += treasury_fee);
+        }
+    }
+
+    utils::transfer_tokens(
+        env,
+        &campaign.reward_token,
+        &env.current_contract_address(),
+        &campaign.farmer_id,
+        amount - fee,
+    );
+}
+
+pub fn update_stats<F: FnOnce(&mut PlatformStats)>(env: &Env, update: F) {
+    let mut stats = read_stats(env);
+    update(&mut stats);
+    env.storage()
+        .instance()
+        .set(&symbol_short!("stats"), &stats);
+}
+
+fn read_stats(env: &Env) -> PlatformStats {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("stats"))
+        .unwrap_or_default()
+}
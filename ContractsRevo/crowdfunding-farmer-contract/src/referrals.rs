@@ -0,0 +1,214 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+
+use crate::{
+    campaign::Campaign,
+    platform::{self, BPS_DENOMINATOR},
+    utils, CampaignStatus, ContractError,
+};
+
+/// Referral bonuses come out of the platform fee, so they share its ceiling
+pub const MAX_REFERRAL_BPS: u32 = platform::MAX_FEE_BPS;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralConfig {
+    /// Share of each referred contribution credited to the referrer
+    pub bonus_bps: u32,
+    /// Most referral credit one campaign accrues across all its referrers
+    pub campaign_cap: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub referrals: u32,
+    pub referred_amount: i128,
+    pub credit_accrued: i128,
+    pub bonus_claimed: i128,
+}
+
+pub fn set_referral_config(
+    env: Env,
+    admin: Address,
+    bonus_bps: u32,
+    campaign_cap: i128,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    utils::require_admin(&env, &admin)?;
+
+    if bonus_bps > MAX_REFERRAL_BPS || campaign_cap < 0 {
+        return Err(ContractError::InvalidReferralConfig);
+    }
+
+    env.storage().instance().set(
+        &symbol_short!("ref_cfg"),
+        &ReferralConfig {
+            bonus_bps,
+            campaign_cap,
+        },
+    );
+    Ok(())
+}
+
+pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+    env.storage().instance().get(&symbol_short!("ref_cfg"))
+}
+
+pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+    read_stats(&env, &referrer)
+}
+
+pub fn get_referral_credit(env: Env, campaign_id: BytesN<32>, referrer: Address) -> i128 {
+    read_credit(&env, &campaign_id, &referrer)
+}
+
+pub fn validate_referrer(
+    campaign: &Campaign,
+    contributor: &Address,
+    referrer: &Address,
+) -> Result<(), ContractError> {
+    if referrer == contributor || *referrer == campaign.farmer_id {
+        return Err(ContractError::InvalidReferrer);
+    }
+    Ok(())
+}
+
+/// Credits the referrer with their share of a contribution, limited to what the campaign can
+/// still accrue under its cap.
+pub fn accrue(env: &Env, campaign: &Campaign, referrer: &Address, amount: i128) {
+    let campaign_id = &campaign.campaign_id;
+    let mut credit = 0;
+    if let Some(config) = get_referral_config(env.clone()) {
+        let accrued = read_campaign_total(env, campaign_id);
+        let headroom = (config.campaign_cap - accrued).max(0);
+        credit = (amount * config.bonus_bps as i128 / BPS_DENOMINATOR).min(headroom);
+    }
+
+    if credit > 0 {
+        let key = (
+            symbol_short!("ref_cred"),
+            campaign_id.clone(),
+            referrer.clone(),
+        );
+        let current = read_credit(env, campaign_id, referrer);
+        env.storage().persistent().set(&key, &(current + credit));
+        env.storage().persistent().set(
+            &(symbol_short!("ref_total"), campaign_id.clone()),
+            &(read_campaign_total(env, campaign_id) + credit),
+        );
+    }
+
+    let mut stats = read_stats(env, referrer);
+    stats.referrals += 1;
+    stats.referred_amount += amount;
+    stats.credit_accrued += credit;
+    save_stats(env, referrer, &stats);
+}
+
+/// Keeps back the part of a platform fee still owed to the campaign's referrers and returns
+/// it. Only successful campaigns pay referral bonuses.
+pub fn reserve_from_fee(env: &Env, campaign: &Campaign, fee: i128) -> i128 {
+    if campaign.status != CampaignStatus::Successful {
+        return 0;
+    }
+
+    let campaign_id = &campaign.campaign_id;
+    let reserved = read_reserved(env, campaign_id);
+    let share = (read_campaign_total(env, campaign_id) - reserved)
+        .min(fee)
+        .max(0);
+    if share > 0 {
+        env.storage().persistent().set(
+            &(symbol_short!("ref_res"), campaign_id.clone()),
+            &(reserved + share),
+        );
+    }
+    share
+}
+
+/// Pays the referrer's bonus for a successful campaign. Bonuses are funded as the platform fee
+/// is collected on each release, so a referrer can claim again after later releases.
+pub fn claim_referral_bonus(
+    env: Env,
+    referrer: Address,
+    campaign_id: BytesN<32>,
+) -> Result<i128, ContractError> {
+    referrer.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.status != CampaignStatus::Successful {
+        return Err(ContractError::ReferralNotClaimable);
+    }
+
+    let credit = read_credit(&env, &campaign_id, &referrer);
+    let total = read_campaign_total(&env, &campaign_id);
+    if credit == 0 || total == 0 {
+        return Err(ContractError::NoReferralBonus);
+    }
+
+    let funded = credit * read_reserved(&env, &campaign_id) / total;
+    let claimed_key = (
+        symbol_short!("ref_paid"),
+        campaign_id.clone(),
+        referrer.clone(),
+    );
+    let claimed: i128 = env.storage().persistent().get(&claimed_key).unwrap_or(0);
+    let payable = funded - claimed;
+    if payable <= 0 {
+        return Err(ContractError::NoReferralBonus);
+    }
+
+    utils::transfer_tokens(
+        &env,
+        &campaign.reward_token,
+        &env.current_contract_address(),
+        &referrer,
+        payable,
+    );
+    env.storage()
+        .persistent()
+        .set(&claimed_key, &(claimed + payable));
+
+    let mut stats = read_stats(&env, &referrer);
+    stats.bonus_claimed += payable;
+    save_stats(&env, &referrer, &stats);
+    Ok(payable)
+}
+
+fn read_credit(env: &Env, campaign_id: &BytesN<32>, referrer: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(
+            symbol_short!("ref_cred"),
+            campaign_id.clone(),
+            referrer.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+fn read_campaign_total(env: &Env, campaign_id: &BytesN<32>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("ref_total"), campaign_id.clone()))
+        .unwrap_or(0)
+}
+
+fn read_reserved(env: &Env, campaign_id: &BytesN<32>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("ref_res"), campaign_id.clone()))
+        .unwrap_or(0)
+}
+
+fn read_stats(env: &Env, referrer: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("ref_stat"), referrer.clone()))
+        .unwrap_or_default()
+}
+
+fn save_stats(env: &Env, referrer: &Address, stats: &ReferralStats) {
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("ref_stat"), referrer.clone()), stats);
+}
@@ -1,6 +1,8 @@
-use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, Vec};
 
-use crate::{contribution, utils, CampaignStatus};
+use crate::{campaign, contribution, milestones, platform, utils, CampaignStatus, ContractError};
+
+pub const MAX_REWARD_TIERS: u32 = 10;
 
 #[derive(Clone)]
 pub struct Reward {
@@ -9,49 +11,232 @@ pub struct Reward {
     pub amount: i128,
 }
 
-pub fn distribute_rewards(env: Env, campaign_id: BytesN<32>) {
-    let campaign =
-        utils::read_campaign(&env, &campaign_id).unwrap_or_else(|| panic!("Campaign not found"));
+/// A perk for backers who contributed at least `min_contribution`. Each claim pays
+/// `amount_or_id` units of `reward_token`, up to `max_claims` times.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTier {
+    pub min_contribution: i128,
+    pub reward_token: Address,
+    pub amount_or_id: i128,
+    pub max_claims: u32,
+}
+
+/// Replaces the campaign's reward tiers, ordered by increasing `min_contribution`. The farmer
+/// deposits the full inventory of every tier up front.
+pub fn set_reward_tiers(
+    env: Env,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+    tiers: Vec<RewardTier>,
+) -> Result<(), ContractError> {
+    farmer.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.farmer_id != farmer {
+        return Err(ContractError::Unauthorized);
+    }
+    // Tiers must be set before the first contribution
+    if campaign.status != CampaignStatus::Active || campaign.total_funded > 0 {
+        return Err(ContractError::TiersLocked);
+    }
+    if tiers.len() > MAX_REWARD_TIERS {
+        return Err(ContractError::TooManyTiers);
+    }
+
+    // Minimums must be positive and increasing, and every tier needs at least one claim
+    let mut previous_min = 0;
+    for tier in tiers.iter() {
+        utils::validate_amount(tier.amount_or_id)?;
+        if tier.min_contribution <= previous_min || tier.max_claims == 0 {
+            return Err(ContractError::InvalidTiers);
+        }
+        previous_min = tier.min_contribution;
+    }
+
+    return_tier_inventory(&env, &campaign_id, &farmer);
+
+    for tier in tiers.iter() {
+        utils::transfer_tokens(
+            &env,
+            &tier.reward_token,
+            &farmer,
+            &env.current_contract_address(),
+            tier.amount_or_id * tier.max_claims as i128,
+        );
+    }
+
+    let mut claims = Vec::new(&env);
+    for _ in tiers.iter() {
+        claims.push_back(0u32);
+    }
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("tiers"), campaign_id.clone()), &tiers);
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("tier_clm"), campaign_id), &claims);
+    Ok(())
+}
+
+pub fn get_reward_tiers(env: Env, campaign_id: BytesN<32>) -> Vec<RewardTier> {
+    read_tiers(&env, &campaign_id)
+}
 
-    if campaign.status != CampaignStatus::Successful {
-        panic!("Campaign is not successful");
+/// The tier the contributor has claimed, or otherwise the highest tier their contribution
+/// qualifies for. Inventory is only checked when claiming.
+pub fn get_my_tier(env: Env, contributor: Address, campaign_id: BytesN<32>) -> Option<u32> {
+    if let Some(claimed) = env.storage().persistent().get(&(
+        symbol_short!("rw_claim"),
+        campaign_id.clone(),
+        contributor.clone(),
+    )) {
+        return Some(claimed);
     }
 
-    let contributions = contribution::get_contributions(env.clone(), campaign_id.clone());
-    let total_rewards = campaign.total_funded / 10;
+    qualified_tier(&env, &campaign_id, &contributor)
+}
+
+/// Pays the contributor's tier reward once the campaign can release funds. When that tier is
+/// sold out, the next lower tier with inventory left is paid instead.
+pub fn claim_reward(
+    env: Env,
+    contributor: Address,
+    campaign_id: BytesN<32>,
+) -> Result<u32, ContractError> {
+    contributor.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if !campaign::is_releasable(&campaign) {
+        return Err(ContractError::FundsNotReleasable);
+    }
+
+    let claim_key = (
+        symbol_short!("rw_claim"),
+        campaign_id.clone(),
+        contributor.clone(),
+    );
+    if env.storage().persistent().has(&claim_key) {
+        return Err(ContractError::RewardAlreadyClaimed);
+    }
+
+    let top_tier =
+        qualified_tier(&env, &campaign_id, &contributor).ok_or(ContractError::NotQualified)?;
+
+    let tiers = read_tiers(&env, &campaign_id);
+    let claims_key = (symbol_short!("tier_clm"), campaign_id.clone());
+    let mut claims: Vec<u32> = env.storage().persistent().get(&claims_key).unwrap();
+
+    let tier_idx = (0..=top_tier)
+        .rev()
+        .find(|idx| claims.get_unchecked(*idx) < tiers.get_unchecked(*idx).max_claims)
+        .ok_or(ContractError::NoRewardsLeft)?;
+    let tier = tiers.get_unchecked(tier_idx);
+
+    claims.set(tier_idx, claims.get_unchecked(tier_idx) + 1);
+    env.storage().persistent().set(&claims_key, &claims);
+    env.storage().persistent().set(&claim_key, &tier_idx);
+
+    utils::transfer_tokens(
+        &env,
+        &tier.reward_token,
+        &env.current_contract_address(),
+        &contributor,
+        tier.amount_or_id,
+    );
+
+    Ok(tier_idx)
+}
+
+pub fn has_reward_tiers(env: &Env, campaign_id: &BytesN<32>) -> bool {
+    !read_tiers(env, campaign_id).is_empty()
+}
+
+/// Sends every unclaimed tier reward back to the farmer.
+pub fn return_tier_inventory(env: &Env, campaign_id: &BytesN<32>, farmer: &Address) {
+    let tiers = read_tiers(env, campaign_id);
+    if tiers.is_empty() {
+        return;
+    }
+
+    let claims_key = (symbol_short!("tier_clm"), campaign_id.clone());
+    let mut claims: Vec<u32> = env.storage().persistent().get(&claims_key).unwrap();
+    for (idx, tier) in tiers.iter().enumerate() {
+        let idx = idx as u32;
+        let unclaimed = tier.max_claims - claims.get_unchecked(idx);
+        if unclaimed > 0 {
+            utils::transfer_tokens(
+                env,
+                &tier.reward_token,
+                &env.current_contract_address(),
+                farmer,
+                tier.amount_or_id * unclaimed as i128,
+            );
+        }
+        claims.set(idx, tier.max_claims);
+    }
+    env.storage().persistent().set(&claims_key, &claims);
+}
+
+fn qualified_tier(env: &Env, campaign_id: &BytesN<32>, contributor: &Address) -> Option<u32> {
+    let contributed = utils::read_backing(env, campaign_id, contributor);
+
+    read_tiers(env, campaign_id)
+        .iter()
+        .enumerate()
+        .filter(|(_, tier)| contributed >= tier.min_contribution)
+        .map(|(idx, _)| idx as u32)
+        .last()
+}
+
+fn read_tiers(env: &Env, campaign_id: &BytesN<32>) -> Vec<RewardTier> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("tiers"), campaign_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn distribute_rewards(env: Env, campaign_id: BytesN<32>) -> Result<(), ContractError> {
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+
+    if !campaign::is_releasable(&campaign) {
+        return Err(ContractError::FundsNotReleasable);
+    }
+
+    if milestones::has_milestones(&env, &campaign_id) {
+        return Err(ContractError::ReleasedByMilestone);
+    }
+
+    let released_key = (symbol_short!("released"), campaign_id.clone());
+    if env.storage().persistent().has(&released_key) {
+        return Err(ContractError::AlreadyDistributed);
+    }
+    env.storage().persistent().set(&released_key, &true);
+
+    let backers = contribution::get_backer_totals(&env, &campaign_id);
+    // Tiered campaigns reward backers through claims, so the farmer receives everything
+    let total_rewards = if has_reward_tiers(&env, &campaign_id) {
+        0
+    } else {
+        campaign.total_funded / 10
+    };
 
     // Distribute rewards to contributors using mock token contract
     if campaign.total_funded > 0 {
-        for contribution in contributions.iter() {
-            let reward_amount = (contribution.amount * total_rewards) / campaign.total_funded;
+        for (backer, amount) in backers.iter() {
+            let reward_amount = (amount * total_rewards) / campaign.total_funded;
             if reward_amount > 0 {
                 env.invoke_contract::<()>(
                     &campaign.reward_token,
                     &symbol_short!("transfer"),
-                    (
-                        env.current_contract_address(),
-                        contribution.contributor_id.clone(),
-                        reward_amount,
-                    )
-                        .into_val(&env),
+                    (env.current_contract_address(), backer, reward_amount).into_val(&env),
                 );
             }
         }
 
         // Give remaining funds to farmer
         let farmer_amount = campaign.total_funded - total_rewards;
-        if farmer_amount > 0 {
-            env.invoke_contract::<()>(
-                &campaign.reward_token,
-                &symbol_short!("transfer"),
-                (
-                    env.current_contract_address(),
-                    campaign.farmer_id,
-                    farmer_amount,
-                )
-                    .into_val(&env),
-            );
-        }
+        platform::pay_farmer(&env, &campaign, farmer_amount);
     } else {
         // If no contributions were made, give all available tokens to farmer
         // This handles the case where the contract has tokens but no contributions
@@ -61,17 +246,7 @@ pub fn distribute_rewards(env: Env, campaign_id: BytesN<32>) {
             (env.current_contract_address(),).into_val(&env),
         );
 
-        if contract_balance > 0 {
-            env.invoke_contract::<()>(
-                &campaign.reward_token,
-                &symbol_short!("transfer"),
-                (
-                    env.current_contract_address(),
-                    campaign.farmer_id,
-                    contract_balance,
-                )
-                    .into_val(&env),
-            );
-        }
+        platform::pay_farmer(&env, &campaign, contract_balance);
     }
+    Ok(())
 }
@@ -3,7 +3,8 @@
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
 
 use crate::{
-    campaign::CampaignStatus, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient,
+    campaign::CampaignStatus, ContractError, CrowdfundingFarmerContract,
+    CrowdfundingFarmerContractClient, FundingModel,
 };
 
 fn create_token_contract(env: &Env, admin: &Address) -> Address {
@@ -13,6 +14,7 @@ fn create_token_contract(env: &Env, admin: &Address) -> Address {
 
 fn setup_test_env() -> (Env, CrowdfundingFarmerContractClient<'static>, Address) {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_id = env.register_contract(None, CrowdfundingFarmerContract);
     let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
     let farmer = Address::generate(&env);
@@ -33,7 +35,14 @@ fn test_campaign_creation_with_valid_goals_and_deadlines() {
 
     for (goal_amount, deadline_offset) in test_cases {
         let deadline = env.ledger().timestamp() + deadline_offset;
-        let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+        let campaign_id = client.create_campaign(
+            &farmer,
+            &goal_amount,
+            &deadline,
+            &reward_token,
+            &FundingModel::AllOrNothing,
+            &Vec::new(&env),
+        );
         let campaign = client.get_campaign_details(&campaign_id);
 
         assert_eq!(campaign.farmer_id, farmer);
@@ -54,7 +63,14 @@ fn test_campaign_creation_farmer_authorization() {
     let deadline = env.ledger().timestamp() + 1000;
 
     // Test that only the farmer can create campaigns
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
     let campaign = client.get_campaign_details(&campaign_id);
 
     assert_eq!(
@@ -62,55 +78,92 @@ fn test_campaign_creation_farmer_authorization() {
         "Campaign should be associated with the correct farmer"
     );
 
-    // Verify campaign ownership
-    assert_eq!(
-        campaign.farmer_id, farmer,
-        "Farmer authorization should be properly set"
+    // Nobody else can open a campaign in the farmer's name
+    env.set_auths(&[]);
+    let result = client.try_create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    assert!(
+        result.is_err(),
+        "Creation without the farmer's auth should fail"
     );
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_campaign_creation_invalid_goal_amount_zero() {
     let (env, client, farmer) = setup_test_env();
     let reward_token = create_token_contract(&env, &farmer);
     let invalid_goal = 0;
     let deadline = env.ledger().timestamp() + 1000;
 
-    client.create_campaign(&farmer, &invalid_goal, &deadline, &reward_token);
+    let result = client.try_create_campaign(
+        &farmer,
+        &invalid_goal,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_campaign_creation_invalid_goal_amount_negative() {
     let (env, client, farmer) = setup_test_env();
     let reward_token = create_token_contract(&env, &farmer);
     let invalid_goal = -1000;
     let deadline = env.ledger().timestamp() + 1000;
 
-    client.create_campaign(&farmer, &invalid_goal, &deadline, &reward_token);
+    let result = client.try_create_campaign(
+        &farmer,
+        &invalid_goal,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 }
 
 #[test]
-#[should_panic(expected = "Deadline must be in the future")]
 fn test_campaign_creation_invalid_deadline_past() {
     let (env, client, farmer) = setup_test_env();
     let reward_token = create_token_contract(&env, &farmer);
     let goal_amount = 1000;
     let past_deadline = env.ledger().timestamp().saturating_sub(1000);
 
-    client.create_campaign(&farmer, &goal_amount, &past_deadline, &reward_token);
+    let result = client.try_create_campaign(
+        &farmer,
+        &goal_amount,
+        &past_deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidDeadline)));
 }
 
 #[test]
-#[should_panic(expected = "Deadline must be in the future")]
 fn test_campaign_creation_invalid_deadline_current() {
     let (env, client, farmer) = setup_test_env();
     let reward_token = create_token_contract(&env, &farmer);
     let goal_amount = 1000;
     let current_deadline = env.ledger().timestamp();
 
-    client.create_campaign(&farmer, &goal_amount, &current_deadline, &reward_token);
+    let result = client.try_create_campaign(
+        &farmer,
+        &goal_amount,
+        &current_deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidDeadline)));
 }
 
 #[test]
@@ -124,8 +177,22 @@ fn test_campaign_creation_with_different_farmers() {
     let deadline = env.ledger().timestamp() + 1000;
 
     // Create campaigns for different farmers
-    let campaign_id1 = client.create_campaign(&farmer1, &goal_amount, &deadline, &reward_token1);
-    let campaign_id2 = client.create_campaign(&farmer2, &goal_amount, &deadline, &reward_token2);
+    let campaign_id1 = client.create_campaign(
+        &farmer1,
+        &goal_amount,
+        &deadline,
+        &reward_token1,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    let campaign_id2 = client.create_campaign(
+        &farmer2,
+        &goal_amount,
+        &deadline,
+        &reward_token2,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
 
     let campaign1 = client.get_campaign_details(&campaign_id1);
     let campaign2 = client.get_campaign_details(&campaign_id2);
@@ -148,8 +215,22 @@ fn test_campaign_creation_with_different_reward_tokens() {
     let deadline = env.ledger().timestamp() + 1000;
 
     // Create campaigns with different reward tokens
-    let campaign_id1 = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token1);
-    let campaign_id2 = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token2);
+    let campaign_id1 = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token1,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    let campaign_id2 = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token2,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
 
     let campaign1 = client.get_campaign_details(&campaign_id1);
     let campaign2 = client.get_campaign_details(&campaign_id2);
@@ -169,7 +250,14 @@ fn test_campaign_creation_unique_ids() {
     // Create multiple campaigns and verify they have unique IDs
     let mut campaign_ids = Vec::new(&env);
     for _ in 0..10 {
-        let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+        let campaign_id = client.create_campaign(
+            &farmer,
+            &goal_amount,
+            &deadline,
+            &reward_token,
+            &FundingModel::AllOrNothing,
+            &Vec::new(&env),
+        );
         campaign_ids.push_back(campaign_id);
     }
 
@@ -192,7 +280,14 @@ fn test_campaign_creation_initial_status() {
     let goal_amount = 1000;
     let deadline = env.ledger().timestamp() + 1000;
 
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
     let campaign = client.get_campaign_details(&campaign_id);
 
     // Verify initial campaign state
@@ -213,7 +308,14 @@ fn test_campaign_creation_with_maximum_values() {
 
     // Note: This test might fail due to ledger timestamp constraints
     // but we test the validation logic
-    let campaign_id = client.create_campaign(&farmer, &max_goal, &max_deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &max_goal,
+        &max_deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
     let campaign = client.get_campaign_details(&campaign_id);
 
     assert_eq!(campaign.goal_amount, max_goal);
@@ -230,7 +332,14 @@ fn test_campaign_creation_with_minimum_valid_values() {
     let min_goal = 1;
     let min_deadline = env.ledger().timestamp() + 1;
 
-    let campaign_id = client.create_campaign(&farmer, &min_goal, &min_deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &min_goal,
+        &min_deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
     let campaign = client.get_campaign_details(&campaign_id);
 
     assert_eq!(campaign.goal_amount, min_goal);
@@ -239,11 +348,11 @@ fn test_campaign_creation_with_minimum_valid_values() {
 }
 
 #[test]
-#[should_panic(expected = "Campaign not found")]
 fn test_get_nonexistent_campaign() {
     let (env, client, _farmer) = setup_test_env();
     let fake_campaign_id = BytesN::from_array(&env, &[1; 32]);
-    client.get_campaign_details(&fake_campaign_id);
+    let result = client.try_get_campaign_details(&fake_campaign_id);
+    assert!(matches!(result, Err(Ok(ContractError::CampaignNotFound))));
 }
 
 #[test]
@@ -254,7 +363,14 @@ fn test_campaign_creation_persistence() {
     let deadline = env.ledger().timestamp() + 1000;
 
     // Create campaign
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
 
     // Verify campaign persists and can be retrieved multiple times
     for _ in 0..5 {
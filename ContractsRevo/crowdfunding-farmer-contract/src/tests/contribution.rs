@@ -3,10 +3,12 @@
 use soroban_sdk::{
     contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
-    Address, BytesN, Env, IntoVal, Symbol,
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
 };
 
-use crate::{CrowdfundingFarmerContract, CrowdfundingFarmerContractClient};
+use crate::{
+    ContractError, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient, FundingModel,
+};
 
 // Simple mock token contract for testing
 #[contract]
@@ -97,7 +99,14 @@ fn setup_campaign(
     let reward_token = create_token_contract(env, &farmer);
     let goal_amount = 10000;
     let deadline = env.ledger().timestamp() + 1000;
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
     (farmer, reward_token, campaign_id)
 }
 
@@ -125,6 +134,7 @@ fn test_contribute_with_valid_amount() {
                 contributor.clone(),
                 campaign_id.clone(),
                 contribution_amount,
+                None::<Address>,
             )
                 .into_val(&env),
             sub_invokes: &[MockAuthInvoke {
@@ -142,7 +152,7 @@ fn test_contribute_with_valid_amount() {
     }]);
 
     // Call contribute function directly
-    client.contribute(&contributor, &campaign_id, &contribution_amount);
+    client.contribute(&contributor, &campaign_id, &contribution_amount, &None);
 
     let campaign = client.get_campaign_details(&campaign_id);
     assert_eq!(campaign.total_funded, contribution_amount);
@@ -181,7 +191,13 @@ fn test_contribute_multiple_contributors() {
             invoke: &MockAuthInvoke {
                 contract: &contract_id,
                 fn_name: "contribute",
-                args: (contributor.clone(), campaign_id.clone(), *amount).into_val(&env),
+                args: (
+                    contributor.clone(),
+                    campaign_id.clone(),
+                    *amount,
+                    None::<Address>,
+                )
+                    .into_val(&env),
                 sub_invokes: &[MockAuthInvoke {
                     contract: &reward_token,
                     fn_name: "transfer",
@@ -192,7 +208,7 @@ fn test_contribute_multiple_contributors() {
         }]);
 
         // Call contribute function directly
-        client.contribute(contributor, &campaign_id, amount);
+        client.contribute(contributor, &campaign_id, amount, &None);
         total_contributed += amount;
     }
 
@@ -209,7 +225,6 @@ fn test_contribute_multiple_contributors() {
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_contribute_invalid_amount_zero() {
     let env = Env::default();
     env.mock_all_auths();
@@ -219,11 +234,11 @@ fn test_contribute_invalid_amount_zero() {
     let (farmer, reward_token, campaign_id) = setup_campaign(&env, &client);
     let contributor = Address::generate(&env);
 
-    client.contribute(&contributor, &campaign_id, &0);
+    let result = client.try_contribute(&contributor, &campaign_id, &0, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_contribute_invalid_amount_negative() {
     let env = Env::default();
     env.mock_all_auths();
@@ -233,11 +248,11 @@ fn test_contribute_invalid_amount_negative() {
     let (farmer, reward_token, campaign_id) = setup_campaign(&env, &client);
     let contributor = Address::generate(&env);
 
-    client.contribute(&contributor, &campaign_id, &-1000);
+    let result = client.try_contribute(&contributor, &campaign_id, &-1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 }
 
 #[test]
-#[should_panic(expected = "Campaign not found")]
 fn test_contribute_to_nonexistent_campaign() {
     let env = Env::default();
     env.mock_all_auths();
@@ -247,11 +262,11 @@ fn test_contribute_to_nonexistent_campaign() {
     let contributor = Address::generate(&env);
     let fake_campaign_id = BytesN::from_array(&env, &[1; 32]);
 
-    client.contribute(&contributor, &fake_campaign_id, &1000);
+    let result = client.try_contribute(&contributor, &fake_campaign_id, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::CampaignNotFound)));
 }
 
 #[test]
-#[should_panic(expected = "Campaign deadline has passed")]
 fn test_contribute_after_deadline() {
     let env = Env::default();
     env.mock_all_auths();
@@ -276,11 +291,13 @@ fn test_contribute_after_deadline() {
                 contributor.clone(),
                 campaign_id.clone(),
                 contribution_amount,
+                None::<Address>,
             )
                 .into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
-    client.contribute(&contributor, &campaign_id, &contribution_amount);
+    let result = client.try_contribute(&contributor, &campaign_id, &contribution_amount, &None);
+    assert_eq!(result, Err(Ok(ContractError::DeadlinePassed)));
 }
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    CampaignStatus, ContractError, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient,
+    FundingModel,
+};
+
+const GOAL: i128 = 10000;
+const DEADLINE: u64 = 1000;
+
+fn setup_campaign(
+    env: &Env,
+) -> (
+    CrowdfundingFarmerContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    Address,
+    BytesN<32>,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(env, &contract_id);
+
+    let farmer = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &token_address,
+        &FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+
+    let token_admin = token::StellarAssetClient::new(env, &token_address);
+    (client, token_admin, farmer, campaign_id)
+}
+
+fn contribute(
+    client: &CrowdfundingFarmerContractClient,
+    token_admin: &token::StellarAssetClient,
+    contributor: &Address,
+    campaign_id: &BytesN<32>,
+    amount: i128,
+) {
+    token_admin.mint(contributor, &amount);
+    client.contribute(contributor, campaign_id, &amount, &None);
+}
+
+#[test]
+fn test_repeat_contributions_merge() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+    let backer = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    contribute(&client, &token_admin, &backer, &campaign_id, 1000);
+    contribute(&client, &token_admin, &other, &campaign_id, 500);
+    contribute(&client, &token_admin, &backer, &campaign_id, 1500);
+
+    assert_eq!(client.get_contributor_total(&campaign_id, &backer), 2500);
+    assert_eq!(client.get_contributor_total(&campaign_id, &other), 500);
+
+    // The raw log keeps every pledge, the registry lists each backer once
+    assert_eq!(client.get_contributions(&campaign_id).len(), 3);
+    let contributors = client.list_contributors(&campaign_id, &0, &10);
+    assert_eq!(contributors.len(), 2);
+    assert_eq!(contributors.get(0).unwrap(), (backer, 2500));
+    assert_eq!(contributors.get(1).unwrap(), (other, 500));
+}
+
+#[test]
+fn test_list_contributors_pagination() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+
+    let mut backers = Vec::new(&env);
+    for i in 1..=5 {
+        let backer = Address::generate(&env);
+        contribute(&client, &token_admin, &backer, &campaign_id, i * 100);
+        backers.push_back(backer);
+    }
+
+    let first = client.list_contributors(&campaign_id, &0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap(), (backers.get(0).unwrap(), 100));
+    assert_eq!(first.get(1).unwrap(), (backers.get(1).unwrap(), 200));
+
+    let last = client.list_contributors(&campaign_id, &4, &2);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap(), (backers.get(4).unwrap(), 500));
+
+    assert!(client.list_contributors(&campaign_id, &5, &2).is_empty());
+}
+
+#[test]
+fn test_transfer_splits_reward_eligibility() {
+    let env = Env::default();
+    let (client, token_admin, farmer, campaign_id) = setup_campaign(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let backer = Address::generate(&env);
+
+    contribute(&client, &token_admin, &seller, &campaign_id, 6000);
+    client.transfer_contribution(&seller, &buyer, &campaign_id, &2000);
+    contribute(&client, &token_admin, &backer, &campaign_id, 4000);
+
+    assert_eq!(client.get_contributor_total(&campaign_id, &seller), 4000);
+    assert_eq!(client.get_contributor_total(&campaign_id, &buyer), 2000);
+
+    env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        client.finalize_campaign(&campaign_id),
+        CampaignStatus::Successful
+    );
+
+    // The 10% reward pool follows the transferred position
+    client.distribute_rewards(&campaign_id);
+    assert_eq!(token.balance(&seller), 400);
+    assert_eq!(token.balance(&buyer), 200);
+    assert_eq!(token.balance(&backer), 400);
+    assert_eq!(token.balance(&farmer), 9000);
+}
+
+#[test]
+fn test_transfer_whole_position() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    contribute(&client, &token_admin, &seller, &campaign_id, 1000);
+    contribute(&client, &token_admin, &buyer, &campaign_id, 500);
+
+    // Moving a whole position drops the sender from the registry
+    client.transfer_contribution(&seller, &buyer, &campaign_id, &1000);
+    let contributors = client.list_contributors(&campaign_id, &0, &10);
+    assert_eq!(contributors.len(), 1);
+    assert_eq!(contributors.get(0).unwrap(), (buyer, 1500));
+}
+
+#[test]
+fn test_transfer_more_than_contributed() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+    let backer = Address::generate(&env);
+
+    contribute(&client, &token_admin, &backer, &campaign_id, 1000);
+    let result =
+        client.try_transfer_contribution(&backer, &Address::generate(&env), &campaign_id, &1001);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientContribution)));
+}
+
+#[test]
+fn test_transfer_after_deadline() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+    let backer = Address::generate(&env);
+
+    contribute(&client, &token_admin, &backer, &campaign_id, 1000);
+    env.ledger().set_timestamp(DEADLINE);
+    let result =
+        client.try_transfer_contribution(&backer, &Address::generate(&env), &campaign_id, &500);
+    assert_eq!(result, Err(Ok(ContractError::CampaignAlreadyFinalized)));
+}
+
+#[test]
+fn test_transfer_to_self_rejected() {
+    let env = Env::default();
+    let (client, token_admin, _, campaign_id) = setup_campaign(&env);
+    let backer = Address::generate(&env);
+    contribute(&client, &token_admin, &backer, &campaign_id, 1000);
+
+    let result = client.try_transfer_contribution(&backer, &backer, &campaign_id, &500);
+    assert_eq!(result, Err(Ok(ContractError::SelfTransfer)));
+    assert_eq!(client.get_contributor_total(&campaign_id, &backer), 1000);
+}
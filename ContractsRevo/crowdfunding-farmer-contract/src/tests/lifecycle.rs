@@ -0,0 +1,210 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    CampaignStatus, ContractError, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient,
+    FundingModel,
+};
+
+const GOAL: i128 = 10000;
+const DEADLINE: u64 = 1000;
+
+struct TestCampaign<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_campaign(funding_model: FundingModel) -> TestCampaign<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+
+    let farmer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &token_address,
+        &funding_model,
+        &Vec::new(&env),
+    );
+
+    TestCampaign {
+        token: token::Client::new(&env, &token_address),
+        token_admin: token::StellarAssetClient::new(&env, &token_address),
+        env,
+        client,
+        farmer,
+        campaign_id,
+    }
+}
+
+fn contribute(campaign: &TestCampaign, amount: i128) -> Address {
+    let contributor = Address::generate(&campaign.env);
+    campaign.token_admin.mint(&contributor, &amount);
+    campaign
+        .client
+        .contribute(&contributor, &campaign.campaign_id, &amount, &None);
+    contributor
+}
+
+fn pass_deadline(campaign: &TestCampaign) {
+    campaign.env.ledger().set_timestamp(DEADLINE);
+}
+
+#[test]
+fn test_active_campaign_rejects_settlement() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let client = &campaign.client;
+    contribute(&campaign, GOAL);
+
+    // Reaching the goal no longer settles the campaign on its own
+    assert_eq!(
+        client.get_campaign_details(&campaign.campaign_id).status,
+        CampaignStatus::Active
+    );
+    assert_eq!(
+        client.try_finalize_campaign(&campaign.campaign_id),
+        Err(Ok(ContractError::DeadlineNotPassed))
+    );
+    assert_eq!(
+        client.try_distribute_rewards(&campaign.campaign_id),
+        Err(Ok(ContractError::FundsNotReleasable))
+    );
+    assert_eq!(
+        client.try_refund_contributions(&campaign.campaign_id),
+        Err(Ok(ContractError::NotRefundable))
+    );
+}
+
+#[test]
+fn test_all_or_nothing_success() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let client = &campaign.client;
+    let backer = contribute(&campaign, GOAL);
+
+    pass_deadline(&campaign);
+    assert_eq!(
+        client.finalize_campaign(&campaign.campaign_id),
+        CampaignStatus::Successful
+    );
+
+    assert_eq!(
+        client.try_finalize_campaign(&campaign.campaign_id),
+        Err(Ok(ContractError::CampaignAlreadyFinalized))
+    );
+    assert_eq!(
+        client.try_refund_contributions(&campaign.campaign_id),
+        Err(Ok(ContractError::NotRefundable))
+    );
+    assert_eq!(
+        client.try_contribute(&backer, &campaign.campaign_id, &1, &None),
+        Err(Ok(ContractError::CampaignNotActive))
+    );
+
+    client.distribute_rewards(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 9000);
+    assert_eq!(campaign.token.balance(&backer), 1000);
+
+    // Funds leave escrow only once
+    assert_eq!(
+        client.try_distribute_rewards(&campaign.campaign_id),
+        Err(Ok(ContractError::AlreadyDistributed))
+    );
+}
+
+#[test]
+fn test_all_or_nothing_failure() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let client = &campaign.client;
+    let backer = contribute(&campaign, 4000);
+
+    pass_deadline(&campaign);
+    assert_eq!(
+        client.finalize_campaign(&campaign.campaign_id),
+        CampaignStatus::Failed
+    );
+    assert_eq!(
+        client.try_distribute_rewards(&campaign.campaign_id),
+        Err(Ok(ContractError::FundsNotReleasable))
+    );
+
+    client.refund_contributions(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&backer), 4000);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 0);
+}
+
+#[test]
+fn test_keep_what_you_raise_releases_below_goal() {
+    let campaign = setup_campaign(FundingModel::KeepWhatYouRaise);
+    let client = &campaign.client;
+    let backer = contribute(&campaign, 4000);
+
+    pass_deadline(&campaign);
+    assert_eq!(
+        client.finalize_campaign(&campaign.campaign_id),
+        CampaignStatus::Failed
+    );
+
+    // Backers of a flexible campaign are never refunded
+    assert_eq!(
+        client.try_refund_contributions(&campaign.campaign_id),
+        Err(Ok(ContractError::NotRefundable))
+    );
+
+    client.distribute_rewards(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 3600);
+    assert_eq!(campaign.token.balance(&backer), 400);
+}
+
+#[test]
+fn test_funding_cap() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let client = &campaign.client;
+    contribute(&campaign, 9000);
+
+    // By default the goal is also the cap
+    let late = Address::generate(&campaign.env);
+    campaign.token_admin.mint(&late, &2000);
+    assert_eq!(
+        client.try_contribute(&late, &campaign.campaign_id, &2000, &None),
+        Err(Ok(ContractError::FundingCapExceeded))
+    );
+
+    assert_eq!(
+        client.try_set_funding_cap(&late, &campaign.campaign_id, &12000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_funding_cap(&campaign.farmer, &campaign.campaign_id, &(GOAL - 1)),
+        Err(Ok(ContractError::InvalidFundingCap))
+    );
+
+    client.set_funding_cap(&campaign.farmer, &campaign.campaign_id, &12000);
+    client.contribute(&late, &campaign.campaign_id, &2000, &None);
+    assert_eq!(
+        client
+            .get_campaign_details(&campaign.campaign_id)
+            .total_funded,
+        11000
+    );
+
+    pass_deadline(&campaign);
+    client.finalize_campaign(&campaign.campaign_id);
+    assert_eq!(
+        client.try_set_funding_cap(&campaign.farmer, &campaign.campaign_id, &15000),
+        Err(Ok(ContractError::CampaignNotActive))
+    );
+}
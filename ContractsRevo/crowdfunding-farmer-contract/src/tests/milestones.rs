@@ -0,0 +1,368 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    milestones::VOTING_WINDOW, ContractError, CrowdfundingFarmerContract,
+    CrowdfundingFarmerContractClient, FundingModel, Milestone, MilestoneStatus,
+};
+
+const GOAL: i128 = 10000;
+const CAMPAIGN_DEADLINE: u64 = 1000;
+const FIRST_MILESTONE_DEADLINE: u64 = 10_000;
+const SECOND_MILESTONE_DEADLINE: u64 = 20_000;
+
+struct MilestoneCampaign<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    contract_id: Address,
+    admin: Address,
+    farmer: Address,
+    token: token::Client<'a>,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_milestone_campaign(contributions: &[i128]) -> (MilestoneCampaign<'static>, Vec<Address>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let farmer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    let token_admin = token::StellarAssetClient::new(&env, &token_address);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            description_hash: BytesN::from_array(&env, &[1; 32]),
+            amount: 4000,
+            deadline: FIRST_MILESTONE_DEADLINE,
+        },
+        Milestone {
+            description_hash: BytesN::from_array(&env, &[2; 32]),
+            amount: 6000,
+            deadline: SECOND_MILESTONE_DEADLINE,
+        },
+    ];
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &CAMPAIGN_DEADLINE,
+        &token_address,
+        &FundingModel::AllOrNothing,
+        &milestones,
+    );
+
+    let mut contributors = Vec::new(&env);
+    for amount in contributions {
+        let contributor = Address::generate(&env);
+        token_admin.mint(&contributor, amount);
+        client.contribute(&contributor, &campaign_id, amount, &None);
+        contributors.push_back(contributor);
+    }
+
+    set_time(&env, CAMPAIGN_DEADLINE);
+    client.finalize_campaign(&campaign_id);
+
+    (
+        MilestoneCampaign {
+            env,
+            client,
+            contract_id,
+            admin,
+            farmer,
+            token,
+            campaign_id,
+        },
+        contributors,
+    )
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9; 32])
+}
+
+#[test]
+fn test_two_tranche_release() {
+    let (campaign, contributors) = setup_milestone_campaign(&[3000, 7000]);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    set_time(env, 5000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &0, &evidence(env));
+    assert_eq!(
+        client.get_milestone_state(&campaign.campaign_id, &0).status,
+        MilestoneStatus::Submitted
+    );
+
+    // The admin releases the first tranche directly
+    client.approve_milestone(&campaign.admin, &campaign.campaign_id, &0);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 4000);
+    assert_eq!(campaign.token.balance(&campaign.contract_id), 6000);
+
+    // Backers release the second one by majority vote
+    set_time(env, 15_000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &1, &evidence(env));
+    client.vote_milestone(
+        &contributors.get(1).unwrap(),
+        &campaign.campaign_id,
+        &1,
+        &true,
+    );
+
+    let state = client.get_milestone_state(&campaign.campaign_id, &1);
+    assert_eq!(state.status, MilestoneStatus::Released);
+    assert_eq!(state.released_amount, 6000);
+    assert_eq!(campaign.token.balance(&campaign.farmer), GOAL);
+    assert_eq!(campaign.token.balance(&campaign.contract_id), 0);
+    assert_eq!(client.get_disbursed_amount(&campaign.campaign_id), GOAL);
+}
+
+#[test]
+fn test_milestones_release_in_order() {
+    let (campaign, _) = setup_milestone_campaign(&[GOAL]);
+    set_time(&campaign.env, 5000);
+
+    let result = campaign.client.try_submit_milestone_evidence(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &1,
+        &evidence(&campaign.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::PreviousMilestoneNotReleased)));
+}
+
+#[test]
+fn test_expired_milestone_refunds_remaining_pro_rata() {
+    let (campaign, contributors) = setup_milestone_campaign(&[3000, 7000]);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    set_time(env, 5000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &0, &evidence(env));
+    client.approve_milestone(&campaign.admin, &campaign.campaign_id, &0);
+
+    // Nothing has failed while the second milestone is still open
+    let result = client.try_refund_remaining(&campaign.campaign_id);
+    assert_eq!(result, Err(Ok(ContractError::NoFailedMilestone)));
+
+    // The farmer never submits evidence for the second milestone
+    set_time(env, SECOND_MILESTONE_DEADLINE + 1);
+    let refunded = client.refund_remaining(&campaign.campaign_id);
+
+    assert_eq!(refunded, 6000);
+    assert_eq!(campaign.token.balance(&contributors.get(0).unwrap()), 1800);
+    assert_eq!(campaign.token.balance(&contributors.get(1).unwrap()), 4200);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 4000);
+    assert_eq!(campaign.token.balance(&campaign.contract_id), 0);
+
+    // The balance can only be returned once
+    let result = client.try_refund_remaining(&campaign.campaign_id);
+    assert_eq!(result, Err(Ok(ContractError::RemainingRefunded)));
+}
+
+#[test]
+fn test_rejected_milestone_refunds_everything() {
+    let (campaign, contributors) = setup_milestone_campaign(&[5000, 5000]);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    set_time(env, 5000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &0, &evidence(env));
+
+    // Half of the funding against is enough to block the release
+    client.vote_milestone(
+        &contributors.get(0).unwrap(),
+        &campaign.campaign_id,
+        &0,
+        &false,
+    );
+    assert_eq!(
+        client.get_milestone_state(&campaign.campaign_id, &0).status,
+        MilestoneStatus::Rejected
+    );
+
+    client.refund_remaining(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&contributors.get(0).unwrap()), 5000);
+    assert_eq!(campaign.token.balance(&contributors.get(1).unwrap()), 5000);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 0);
+}
+
+#[test]
+fn test_votes_weighted_by_contribution() {
+    let (campaign, contributors) = setup_milestone_campaign(&[2000, 2000, 6000]);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    set_time(env, 5000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &0, &evidence(env));
+
+    // Two small backers are outnumbered by one large one
+    client.vote_milestone(
+        &contributors.get(0).unwrap(),
+        &campaign.campaign_id,
+        &0,
+        &false,
+    );
+    client.vote_milestone(
+        &contributors.get(1).unwrap(),
+        &campaign.campaign_id,
+        &0,
+        &false,
+    );
+    let state = client.get_milestone_state(&campaign.campaign_id, &0);
+    assert_eq!(state.status, MilestoneStatus::Submitted);
+    assert_eq!(state.votes_against, 4000);
+
+    client.vote_milestone(
+        &contributors.get(2).unwrap(),
+        &campaign.campaign_id,
+        &0,
+        &true,
+    );
+    let state = client.get_milestone_state(&campaign.campaign_id, &0);
+    assert_eq!(state.status, MilestoneStatus::Released);
+    assert_eq!(state.votes_for, 6000);
+    assert_eq!(campaign.token.balance(&campaign.farmer), 4000);
+
+    // Each backer votes once, and only backers vote
+    set_time(env, 6000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &1, &evidence(env));
+    client.vote_milestone(
+        &contributors.get(0).unwrap(),
+        &campaign.campaign_id,
+        &1,
+        &true,
+    );
+    let result = client.try_vote_milestone(
+        &contributors.get(0).unwrap(),
+        &campaign.campaign_id,
+        &1,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(ContractError::AlreadyVoted)));
+    let result =
+        client.try_vote_milestone(&Address::generate(env), &campaign.campaign_id, &1, &true);
+    assert_eq!(result, Err(Ok(ContractError::NotContributor)));
+
+    // Once the window closes the heavier side of the cast votes wins
+    client.vote_milestone(
+        &contributors.get(1).unwrap(),
+        &campaign.campaign_id,
+        &1,
+        &true,
+    );
+    let result = client.try_close_milestone_vote(&campaign.campaign_id, &1);
+    assert_eq!(result, Err(Ok(ContractError::VotingStillOpen)));
+
+    set_time(env, 6000 + VOTING_WINDOW + 1);
+    client.close_milestone_vote(&campaign.campaign_id, &1);
+    assert_eq!(campaign.token.balance(&campaign.farmer), GOAL);
+}
+
+#[test]
+fn test_approve_milestone_requires_admin() {
+    let (campaign, _) = setup_milestone_campaign(&[GOAL]);
+    set_time(&campaign.env, 5000);
+    campaign.client.submit_milestone_evidence(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &0,
+        &evidence(&campaign.env),
+    );
+
+    let result = campaign
+        .client
+        .try_approve_milestone(&campaign.farmer, &campaign.campaign_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_milestones_must_sum_to_goal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+
+    let milestones = vec![
+        &env,
+        Milestone {
+            description_hash: BytesN::from_array(&env, &[1; 32]),
+            amount: 4000,
+            deadline: FIRST_MILESTONE_DEADLINE,
+        },
+    ];
+    let result = client.try_create_campaign(
+        &Address::generate(&env),
+        &GOAL,
+        &CAMPAIGN_DEADLINE,
+        &Address::generate(&env),
+        &FundingModel::AllOrNothing,
+        &milestones,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidMilestones)));
+}
+
+#[test]
+fn test_milestone_error_codes() {
+    let (campaign, contributors) = setup_milestone_campaign(&[3000, 7000]);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    let result = client.try_get_milestone_state(&campaign.campaign_id, &2);
+    assert_eq!(result, Err(Ok(ContractError::MilestoneNotFound)));
+
+    // Milestone campaigns never release through a single payout
+    let result = client.try_distribute_rewards(&campaign.campaign_id);
+    assert_eq!(result, Err(Ok(ContractError::ReleasedByMilestone)));
+
+    let result = client.try_approve_milestone(&campaign.admin, &campaign.campaign_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::MilestoneNotSubmitted)));
+
+    set_time(env, 5000);
+    client.submit_milestone_evidence(&campaign.farmer, &campaign.campaign_id, &0, &evidence(env));
+    let result = client.try_submit_milestone_evidence(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &0,
+        &evidence(env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::EvidenceAlreadySubmitted)));
+
+    set_time(env, 5000 + VOTING_WINDOW + 1);
+    let result = client.try_vote_milestone(
+        &contributors.get(0).unwrap(),
+        &campaign.campaign_id,
+        &0,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(ContractError::VotingClosed)));
+}
+
+#[test]
+fn test_evidence_after_milestone_deadline_rejected() {
+    let (campaign, _) = setup_milestone_campaign(&[GOAL]);
+    set_time(&campaign.env, FIRST_MILESTONE_DEADLINE + 1);
+
+    let result = campaign.client.try_submit_milestone_evidence(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &0,
+        &evidence(&campaign.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::MilestoneDeadlinePassed)));
+}
@@ -0,0 +1,311 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    ContractError, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient, FundingModel,
+    Milestone, PlatformStats,
+};
+
+const GOAL: i128 = 10000;
+const DEADLINE: u64 = 1000;
+const FEE_BPS: u32 = 250;
+
+struct Platform<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    admin: Address,
+    treasury: Address,
+}
+
+fn setup_platform() -> Platform<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let treasury = Address::generate(&env);
+    client.set_platform_fee(&admin, &FEE_BPS, &treasury);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    Platform {
+        token: token::Client::new(&env, &token_address),
+        token_admin: token::StellarAssetClient::new(&env, &token_address),
+        env,
+        client,
+        admin,
+        treasury,
+    }
+}
+
+fn create_campaign(
+    platform: &Platform,
+    farmer: &Address,
+    funding_model: FundingModel,
+    milestones: &Vec<Milestone>,
+) -> BytesN<32> {
+    platform.client.create_campaign(
+        farmer,
+        &GOAL,
+        &DEADLINE,
+        &platform.token.address,
+        &funding_model,
+        milestones,
+    )
+}
+
+fn contribute(platform: &Platform, campaign_id: &BytesN<32>, amount: i128) -> Address {
+    let contributor = Address::generate(&platform.env);
+    platform.token_admin.mint(&contributor, &amount);
+    platform
+        .client
+        .contribute(&contributor, campaign_id, &amount, &None);
+    contributor
+}
+
+#[test]
+fn test_fee_deducted_on_release() {
+    let platform = setup_platform();
+    let env = &platform.env;
+    let farmer = Address::generate(env);
+    let campaign_id = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    contribute(&platform, &campaign_id, GOAL);
+
+    env.ledger().set_timestamp(DEADLINE);
+    platform.client.finalize_campaign(&campaign_id);
+    platform.client.distribute_rewards(&campaign_id);
+
+    // 2.5% of the farmer's 9000; the backers' 10% pool is not a release
+    assert_eq!(platform.token.balance(&platform.treasury), 225);
+    assert_eq!(platform.token.balance(&farmer), 8775);
+
+    // Milestone tranches pay the fee as they are released
+    let milestone_farmer = Address::generate(env);
+    let milestones = vec![
+        env,
+        Milestone {
+            description_hash: BytesN::from_array(env, &[1; 32]),
+            amount: GOAL,
+            deadline: DEADLINE * 10,
+        },
+    ];
+    env.ledger().set_timestamp(0);
+    let milestone_campaign = create_campaign(
+        &platform,
+        &milestone_farmer,
+        FundingModel::AllOrNothing,
+        &milestones,
+    );
+    contribute(&platform, &milestone_campaign, GOAL);
+    env.ledger().set_timestamp(DEADLINE);
+    platform.client.finalize_campaign(&milestone_campaign);
+    platform.client.submit_milestone_evidence(
+        &milestone_farmer,
+        &milestone_campaign,
+        &0,
+        &BytesN::from_array(env, &[2; 32]),
+    );
+    platform
+        .client
+        .approve_milestone(&platform.admin, &milestone_campaign, &0);
+
+    assert_eq!(platform.token.balance(&milestone_farmer), 9750);
+    assert_eq!(platform.token.balance(&platform.treasury), 225 + 250);
+}
+
+#[test]
+fn test_refunds_are_fee_free() {
+    let platform = setup_platform();
+    let env = &platform.env;
+    let farmer = Address::generate(env);
+
+    let failed = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    let backer = contribute(&platform, &failed, 4000);
+    let cancelled = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::KeepWhatYouRaise,
+        &Vec::new(env),
+    );
+    let other = contribute(&platform, &cancelled, 3000);
+
+    platform
+        .client
+        .cancel_campaign(&farmer, &cancelled, &BytesN::from_array(env, &[3; 32]));
+    platform.client.refund_contributions(&cancelled);
+
+    env.ledger().set_timestamp(DEADLINE);
+    platform.client.finalize_campaign(&failed);
+    platform.client.refund_contributions(&failed);
+
+    assert_eq!(platform.token.balance(&backer), 4000);
+    assert_eq!(platform.token.balance(&other), 3000);
+    assert_eq!(platform.token.balance(&platform.treasury), 0);
+}
+
+#[test]
+fn test_verification_gate() {
+    let platform = setup_platform();
+    let env = &platform.env;
+    let client = &platform.client;
+    let farmer = Address::generate(env);
+
+    // Off by default
+    create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+
+    client.set_verification_required(&platform.admin, &true);
+    let result = client.try_create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &platform.token.address,
+        &FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::FarmerNotVerified)));
+
+    let result = client.try_verify_farmer(&farmer, &farmer, &BytesN::from_array(env, &[4; 32]));
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    client.verify_farmer(&platform.admin, &farmer, &BytesN::from_array(env, &[4; 32]));
+    assert!(client.is_farmer_verified(&farmer));
+    let campaign_id = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+
+    // Revocation blocks new campaigns but the running one keeps raising
+    client.revoke_farmer_verification(&platform.admin, &farmer);
+    assert!(!client.is_farmer_verified(&farmer));
+    let result = client.try_create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &platform.token.address,
+        &FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::FarmerNotVerified)));
+    contribute(&platform, &campaign_id, 1000);
+    assert_eq!(client.get_campaign_details(&campaign_id).total_funded, 1000);
+}
+
+#[test]
+fn test_platform_fee_limits() {
+    let platform = setup_platform();
+    let client = &platform.client;
+
+    let result = client.try_set_platform_fee(&platform.admin, &1_001, &platform.treasury);
+    assert_eq!(result, Err(Ok(ContractError::FeeTooHigh)));
+    let result = client.try_set_platform_fee(&platform.treasury, &100, &platform.treasury);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let fee = client.get_platform_fee().unwrap();
+    assert_eq!(fee.fee_bps, FEE_BPS);
+    assert_eq!(fee.treasury, platform.treasury);
+}
+
+#[test]
+fn test_platform_stats() {
+    let platform = setup_platform();
+    let env = &platform.env;
+    let client = &platform.client;
+    let farmer = Address::generate(env);
+
+    let success = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    let failed = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    let cancelled = create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    create_campaign(
+        &platform,
+        &farmer,
+        FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
+    assert_eq!(client.get_platform_stats().active_campaigns, 4);
+
+    contribute(&platform, &success, GOAL);
+    contribute(&platform, &failed, 2000);
+    contribute(&platform, &cancelled, 500);
+
+    client.cancel_campaign(&farmer, &cancelled, &BytesN::from_array(env, &[5; 32]));
+    client.refund_contributions(&cancelled);
+
+    env.ledger().set_timestamp(DEADLINE);
+    client.finalize_campaign(&success);
+    client.finalize_campaign(&failed);
+    client.distribute_rewards(&success);
+    client.refund_contributions(&failed);
+
+    assert_eq!(
+        client.get_platform_stats(),
+        PlatformStats {
+            total_raised: 12500,
+            total_refunded: 2500,
+            fees_collected: 225,
+            active_campaigns: 1,
+        }
+    );
+}
+
+#[test]
+fn test_initialize_and_admin_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    // Admin actions need an initialized contract
+    let result = client.try_set_platform_fee(&admin, &FEE_BPS, &admin);
+    assert_eq!(result, Err(Ok(ContractError::NotInitialized)));
+
+    client.initialize(&admin);
+    let result = client.try_initialize(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+
+    // Only verified farmers can lose their verification
+    let result = client.try_revoke_farmer_verification(&admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::FarmerNotVerified)));
+}
@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    ContractError, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient, FundingModel,
+    ReferralStats,
+};
+
+const GOAL: i128 = 10000;
+const DEADLINE: u64 = 1000;
+const FEE_BPS: u32 = 250;
+const REFERRAL_BPS: u32 = 100;
+
+struct Referrals<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    treasury: Address,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup(funding_model: FundingModel, campaign_cap: i128) -> Referrals<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let treasury = Address::generate(&env);
+    client.set_platform_fee(&admin, &FEE_BPS, &treasury);
+    client.set_referral_config(&admin, &REFERRAL_BPS, &campaign_cap);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let farmer = Address::generate(&env);
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &token_address,
+        &funding_model,
+        &Vec::new(&env),
+    );
+
+    Referrals {
+        token: token::Client::new(&env, &token_address),
+        token_admin: token::StellarAssetClient::new(&env, &token_address),
+        env,
+        client,
+        treasury,
+        farmer,
+        campaign_id,
+    }
+}
+
+fn contribute(setup: &Referrals, amount: i128, referrer: Option<Address>) -> Address {
+    let contributor = Address::generate(&setup.env);
+    setup.token_admin.mint(&contributor, &amount);
+    setup
+        .client
+        .contribute(&contributor, &setup.campaign_id, &amount, &referrer);
+    contributor
+}
+
+#[test]
+fn test_bonus_claimed_from_fee_after_success() {
+    let setup = setup(FundingModel::AllOrNothing, 1_000);
+    let referrer = Address::generate(&setup.env);
+    contribute(&setup, 6000, Some(referrer.clone()));
+    contribute(&setup, 4000, None);
+
+    assert_eq!(
+        setup
+            .client
+            .get_referral_credit(&setup.campaign_id, &referrer),
+        60
+    );
+    // Nothing is claimable before the campaign succeeds
+    assert_eq!(
+        setup
+            .client
+            .try_claim_referral_bonus(&referrer, &setup.campaign_id),
+        Err(Ok(ContractError::ReferralNotClaimable))
+    );
+
+    setup.env.ledger().set_timestamp(DEADLINE);
+    setup.client.finalize_campaign(&setup.campaign_id);
+    setup.client.distribute_rewards(&setup.campaign_id);
+
+    // The bonus comes out of the 225 fee, not the farmer's share
+    assert_eq!(setup.token.balance(&setup.farmer), 8775);
+    assert_eq!(setup.token.balance(&setup.treasury), 165);
+    assert_eq!(setup.client.get_platform_stats().fees_collected, 165);
+
+    assert_eq!(
+        setup
+            .client
+            .claim_referral_bonus(&referrer, &setup.campaign_id),
+        60
+    );
+    assert_eq!(setup.token.balance(&referrer), 60);
+    assert_eq!(
+        setup
+            .client
+            .try_claim_referral_bonus(&referrer, &setup.campaign_id),
+        Err(Ok(ContractError::NoReferralBonus))
+    );
+
+    assert_eq!(
+        setup.client.get_referral_stats(&referrer),
+        ReferralStats {
+            referrals: 1,
+            referred_amount: 6000,
+            credit_accrued: 60,
+            bonus_claimed: 60,
+        }
+    );
+}
+
+#[test]
+fn test_no_bonus_on_failed_campaign() {
+    let setup = setup(FundingModel::AllOrNothing, 1_000);
+    let referrer = Address::generate(&setup.env);
+    let backer = contribute(&setup, 4000, Some(referrer.clone()));
+
+    setup.env.ledger().set_timestamp(DEADLINE);
+    setup.client.finalize_campaign(&setup.campaign_id);
+    setup.client.refund_contributions(&setup.campaign_id);
+
+    assert_eq!(
+        setup
+            .client
+            .try_claim_referral_bonus(&referrer, &setup.campaign_id),
+        Err(Ok(ContractError::ReferralNotClaimable))
+    );
+    // Backers get their full principal back
+    assert_eq!(setup.token.balance(&backer), 4000);
+    assert_eq!(setup.token.balance(&referrer), 0);
+}
+
+#[test]
+fn test_self_and_farmer_referral_rejected() {
+    let setup = setup(FundingModel::AllOrNothing, 1_000);
+    let contributor = Address::generate(&setup.env);
+    setup.token_admin.mint(&contributor, &1000);
+
+    assert_eq!(
+        setup.client.try_contribute(
+            &contributor,
+            &setup.campaign_id,
+            &1000,
+            &Some(contributor.clone())
+        ),
+        Err(Ok(ContractError::InvalidReferrer))
+    );
+    assert_eq!(
+        setup.client.try_contribute(
+            &contributor,
+            &setup.campaign_id,
+            &1000,
+            &Some(setup.farmer.clone())
+        ),
+        Err(Ok(ContractError::InvalidReferrer))
+    );
+    assert_eq!(setup.token.balance(&contributor), 1000);
+}
+
+#[test]
+fn test_credit_capped_per_campaign() {
+    let setup = setup(FundingModel::AllOrNothing, 50);
+    let first = Address::generate(&setup.env);
+    let second = Address::generate(&setup.env);
+    contribute(&setup, 3000, Some(first.clone()));
+    contribute(&setup, 3000, Some(second.clone()));
+    contribute(&setup, 4000, Some(second.clone()));
+
+    let client = &setup.client;
+    assert_eq!(client.get_referral_credit(&setup.campaign_id, &first), 30);
+    assert_eq!(client.get_referral_credit(&setup.campaign_id, &second), 20);
+
+    let stats = client.get_referral_stats(&second);
+    assert_eq!(stats.referrals, 2);
+    assert_eq!(stats.referred_amount, 7000);
+    assert_eq!(stats.credit_accrued, 20);
+
+    setup.env.ledger().set_timestamp(DEADLINE);
+    client.finalize_campaign(&setup.campaign_id);
+    client.distribute_rewards(&setup.campaign_id);
+    assert_eq!(client.claim_referral_bonus(&first, &setup.campaign_id), 30);
+    assert_eq!(client.claim_referral_bonus(&second, &setup.campaign_id), 20);
+    assert_eq!(setup.token.balance(&setup.treasury), 225 - 50);
+}
+
+#[test]
+fn test_referral_config_validation() {
+    let setup = setup(FundingModel::AllOrNothing, 1_000);
+    let outsider = Address::generate(&setup.env);
+
+    assert_eq!(
+        setup
+            .client
+            .try_set_referral_config(&outsider, &REFERRAL_BPS, &1_000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    let admin = setup
+        .env
+        .as_contract(&setup.client.address, || {
+            crate::utils::read_admin(&setup.env)
+        })
+        .unwrap();
+    assert_eq!(
+        setup.client.try_set_referral_config(&admin, &1_001, &1_000),
+        Err(Ok(ContractError::InvalidReferralConfig))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_set_referral_config(&admin, &REFERRAL_BPS, &-1),
+        Err(Ok(ContractError::InvalidReferralConfig))
+    );
+}
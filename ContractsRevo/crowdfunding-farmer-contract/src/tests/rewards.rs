@@ -3,11 +3,12 @@
 use soroban_sdk::{
     contract, contractimpl, symbol_short,
     testutils::{Address as _, MockAuth, MockAuthInvoke},
-    Address, BytesN, Env, IntoVal, Symbol,
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
 };
 
 use crate::{
     campaign::CampaignStatus, CrowdfundingFarmerContract, CrowdfundingFarmerContractClient,
+    FundingModel,
 };
 
 // Simple mock token contract for testing
@@ -91,6 +92,34 @@ fn set_token_balance(env: &Env, token: &Address, account: &Address, amount: i128
     });
 }
 
+// The farmer authorizes creating their campaign
+fn mock_create_campaign_auth(
+    env: &Env,
+    contract_id: &Address,
+    farmer: &Address,
+    goal_amount: i128,
+    deadline: u64,
+    reward_token: &Address,
+) {
+    env.mock_auths(&[MockAuth {
+        address: farmer,
+        invoke: &MockAuthInvoke {
+            contract: contract_id,
+            fn_name: "create_campaign",
+            args: (
+                farmer.clone(),
+                goal_amount,
+                deadline,
+                reward_token.clone(),
+                FundingModel::AllOrNothing,
+                Vec::<crate::milestones::Milestone>::new(env),
+            )
+                .into_val(env),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
 fn setup_successful_campaign(
     env: &Env,
     client: &CrowdfundingFarmerContractClient,
@@ -100,7 +129,22 @@ fn setup_successful_campaign(
     let reward_token = create_token_contract(env, &farmer);
     let goal_amount = 10000;
     let deadline = env.ledger().timestamp() + 1000;
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    mock_create_campaign_auth(
+        env,
+        contract_id,
+        &farmer,
+        goal_amount,
+        deadline,
+        &reward_token,
+    );
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(env),
+    );
 
     // Create contributors and make contributions
     let contributors = [
@@ -122,7 +166,13 @@ fn setup_successful_campaign(
             invoke: &MockAuthInvoke {
                 contract: contract_id,
                 fn_name: "contribute",
-                args: (contributor.clone(), campaign_id.clone(), *amount).into_val(env),
+                args: (
+                    contributor.clone(),
+                    campaign_id.clone(),
+                    *amount,
+                    None::<Address>,
+                )
+                    .into_val(env),
                 sub_invokes: &[MockAuthInvoke {
                     contract: &reward_token,
                     fn_name: "transfer",
@@ -131,7 +181,7 @@ fn setup_successful_campaign(
                 }],
             },
         }]);
-        client.contribute(contributor, &campaign_id, amount);
+        client.contribute(contributor, &campaign_id, amount, &None);
     }
 
     // Mark campaign as successful
@@ -206,7 +256,22 @@ fn test_reward_distribution_proportional_calculation() {
     let reward_token = create_token_contract(&env, &farmer);
     let goal_amount = 10000;
     let deadline = env.ledger().timestamp() + 1000;
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    mock_create_campaign_auth(
+        &env,
+        &contract_id,
+        &farmer,
+        goal_amount,
+        deadline,
+        &reward_token,
+    );
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
 
     // Create contributors with different amounts
     let contributors = [
@@ -228,7 +293,13 @@ fn test_reward_distribution_proportional_calculation() {
             invoke: &MockAuthInvoke {
                 contract: &contract_id,
                 fn_name: "contribute",
-                args: (contributor.clone(), campaign_id.clone(), *amount).into_val(&env),
+                args: (
+                    contributor.clone(),
+                    campaign_id.clone(),
+                    *amount,
+                    None::<Address>,
+                )
+                    .into_val(&env),
                 sub_invokes: &[MockAuthInvoke {
                     contract: &reward_token,
                     fn_name: "transfer",
@@ -237,7 +308,7 @@ fn test_reward_distribution_proportional_calculation() {
                 }],
             },
         }]);
-        client.contribute(contributor, &campaign_id, amount);
+        client.contribute(contributor, &campaign_id, amount, &None);
     }
 
     // Mark campaign as successful
@@ -299,7 +370,22 @@ fn test_reward_distribution_zero_contributions() {
     let reward_token = create_token_contract(&env, &farmer);
     let goal_amount = 10000;
     let deadline = env.ledger().timestamp() + 1000;
-    let campaign_id = client.create_campaign(&farmer, &goal_amount, &deadline, &reward_token);
+    mock_create_campaign_auth(
+        &env,
+        &contract_id,
+        &farmer,
+        goal_amount,
+        deadline,
+        &reward_token,
+    );
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &goal_amount,
+        &deadline,
+        &reward_token,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
 
     // Mark campaign as successful manually (no contributions made)
     let mut campaign = client.get_campaign_details(&campaign_id);
@@ -0,0 +1,267 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, Vec,
+};
+
+use crate::{
+    rewards::MAX_REWARD_TIERS, ContractError, CrowdfundingFarmerContract,
+    CrowdfundingFarmerContractClient, FundingModel, RewardTier,
+};
+
+const GOAL: i128 = 3000;
+const DEADLINE: u64 = 1000;
+
+struct TieredCampaign<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    perk: token::Client<'a>,
+    voucher: token::Client<'a>,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn create_token<'a>(env: &Env) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    (
+        token::Client::new(env, &address),
+        token::StellarAssetClient::new(env, &address),
+    )
+}
+
+/// A campaign with a 5-unit perk for 100+ (two available) and a single voucher for 1000+.
+fn setup_tiered_campaign() -> TieredCampaign<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+
+    let farmer = Address::generate(&env);
+    let (funding, token_admin) = create_token(&env);
+    let (perk, perk_admin) = create_token(&env);
+    let (voucher, voucher_admin) = create_token(&env);
+    perk_admin.mint(&farmer, &10);
+    voucher_admin.mint(&farmer, &1);
+
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &funding.address,
+        &FundingModel::AllOrNothing,
+        &Vec::new(&env),
+    );
+    let tiers = vec![
+        &env,
+        RewardTier {
+            min_contribution: 100,
+            reward_token: perk.address.clone(),
+            amount_or_id: 5,
+            max_claims: 2,
+        },
+        RewardTier {
+            min_contribution: 1000,
+            reward_token: voucher.address.clone(),
+            amount_or_id: 1,
+            max_claims: 1,
+        },
+    ];
+    client.set_reward_tiers(&farmer, &campaign_id, &tiers);
+
+    // The full inventory is held by the contract
+    assert_eq!(perk.balance(&farmer), 0);
+    assert_eq!(perk.balance(&contract_id), 10);
+    assert_eq!(voucher.balance(&contract_id), 1);
+
+    TieredCampaign {
+        env,
+        client,
+        token_admin,
+        perk,
+        voucher,
+        farmer,
+        campaign_id,
+    }
+}
+
+fn contribute(campaign: &TieredCampaign, amount: i128) -> Address {
+    let contributor = Address::generate(&campaign.env);
+    campaign.token_admin.mint(&contributor, &amount);
+    campaign
+        .client
+        .contribute(&contributor, &campaign.campaign_id, &amount, &None);
+    contributor
+}
+
+fn finalize(campaign: &TieredCampaign) {
+    campaign.env.ledger().set_timestamp(DEADLINE);
+    campaign.client.finalize_campaign(&campaign.campaign_id);
+}
+
+#[test]
+fn test_tier_boundaries() {
+    let campaign = setup_tiered_campaign();
+    let client = &campaign.client;
+
+    let below = contribute(&campaign, 99);
+    let perk_min = contribute(&campaign, 100);
+    let perk_max = contribute(&campaign, 999);
+    let voucher_min = contribute(&campaign, 1000);
+
+    assert_eq!(client.get_my_tier(&below, &campaign.campaign_id), None);
+    assert_eq!(
+        client.get_my_tier(&perk_min, &campaign.campaign_id),
+        Some(0)
+    );
+    assert_eq!(
+        client.get_my_tier(&perk_max, &campaign.campaign_id),
+        Some(0)
+    );
+    assert_eq!(
+        client.get_my_tier(&voucher_min, &campaign.campaign_id),
+        Some(1)
+    );
+
+    // Tiers are locked once money is in
+    assert_eq!(
+        client.try_set_reward_tiers(
+            &campaign.farmer,
+            &campaign.campaign_id,
+            &Vec::new(&campaign.env)
+        ),
+        Err(Ok(ContractError::TiersLocked))
+    );
+}
+
+#[test]
+fn test_inventory_exhaustion_falls_back_to_lower_tier() {
+    let campaign = setup_tiered_campaign();
+    let client = &campaign.client;
+
+    let first = contribute(&campaign, 1000);
+    let second = contribute(&campaign, 1500);
+    let third = contribute(&campaign, 200);
+    let fourth = contribute(&campaign, 300);
+
+    // Claims wait for the campaign to succeed
+    assert_eq!(
+        client.try_claim_reward(&first, &campaign.campaign_id),
+        Err(Ok(ContractError::FundsNotReleasable))
+    );
+    finalize(&campaign);
+
+    assert_eq!(client.claim_reward(&first, &campaign.campaign_id), 1);
+    assert_eq!(campaign.voucher.balance(&first), 1);
+
+    // The single voucher is gone, so the larger backer gets the perk instead
+    assert_eq!(client.claim_reward(&second, &campaign.campaign_id), 0);
+    assert_eq!(campaign.perk.balance(&second), 5);
+    assert_eq!(client.get_my_tier(&second, &campaign.campaign_id), Some(0));
+
+    assert_eq!(client.claim_reward(&third, &campaign.campaign_id), 0);
+    assert_eq!(
+        client.try_claim_reward(&fourth, &campaign.campaign_id),
+        Err(Ok(ContractError::NoRewardsLeft))
+    );
+    assert_eq!(campaign.perk.balance(&fourth), 0);
+}
+
+#[test]
+fn test_claim_reward_once() {
+    let campaign = setup_tiered_campaign();
+    let backer = contribute(&campaign, GOAL);
+    finalize(&campaign);
+
+    campaign.client.claim_reward(&backer, &campaign.campaign_id);
+    assert_eq!(campaign.voucher.balance(&backer), 1);
+
+    let result = campaign
+        .client
+        .try_claim_reward(&backer, &campaign.campaign_id);
+    assert_eq!(result, Err(Ok(ContractError::RewardAlreadyClaimed)));
+}
+
+#[test]
+fn test_tiered_release_and_failed_campaign_inventory() {
+    // Tiered campaigns pay the farmer in full; perks replace the 10% pool
+    let campaign = setup_tiered_campaign();
+    contribute(&campaign, GOAL);
+    finalize(&campaign);
+    campaign.client.distribute_rewards(&campaign.campaign_id);
+    let funding = token::Client::new(&campaign.env, &campaign.token_admin.address);
+    assert_eq!(funding.balance(&campaign.farmer), GOAL);
+
+    // A failed campaign hands the unclaimed inventory back to the farmer
+    let failed = setup_tiered_campaign();
+    let backer = contribute(&failed, 500);
+    finalize(&failed);
+    assert_eq!(
+        failed.client.try_claim_reward(&backer, &failed.campaign_id),
+        Err(Ok(ContractError::FundsNotReleasable))
+    );
+
+    failed.client.refund_contributions(&failed.campaign_id);
+    assert_eq!(failed.perk.balance(&failed.farmer), 10);
+    assert_eq!(failed.voucher.balance(&failed.farmer), 1);
+}
+
+#[test]
+fn test_invalid_reward_tiers_rejected() {
+    let campaign = setup_tiered_campaign();
+    let client = &campaign.client;
+    let env = &campaign.env;
+
+    let tier = |min_contribution: i128, max_claims: u32| RewardTier {
+        min_contribution,
+        reward_token: campaign.perk.address.clone(),
+        amount_or_id: 1,
+        max_claims,
+    };
+
+    let mut too_many = Vec::new(env);
+    for idx in 0..=MAX_REWARD_TIERS {
+        too_many.push_back(tier(100 * (idx as i128 + 1), 1));
+    }
+    let result = client.try_set_reward_tiers(&campaign.farmer, &campaign.campaign_id, &too_many);
+    assert_eq!(result, Err(Ok(ContractError::TooManyTiers)));
+
+    // Minimums must increase
+    let result = client.try_set_reward_tiers(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &vec![env, tier(500, 1), tier(500, 1)],
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidTiers)));
+
+    // Every tier needs at least one claim
+    let result = client.try_set_reward_tiers(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &vec![env, tier(100, 0)],
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidTiers)));
+
+    let result = client.try_set_reward_tiers(
+        &Address::generate(env),
+        &campaign.campaign_id,
+        &vec![env, tier(100, 1)],
+    );
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_claim_below_lowest_tier_rejected() {
+    let campaign = setup_tiered_campaign();
+    contribute(&campaign, GOAL - 50);
+    let small = contribute(&campaign, 50);
+    finalize(&campaign);
+
+    let result = campaign
+        .client
+        .try_claim_reward(&small, &campaign.campaign_id);
+    assert_eq!(result, Err(Ok(ContractError::NotQualified)));
+}
@@ -0,0 +1,216 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String, Vec,
+};
+
+use crate::{
+    updates::MAX_UPDATES, CampaignStatus, ContractError, CrowdfundingFarmerContract,
+    CrowdfundingFarmerContractClient, FundingModel,
+};
+
+const GOAL: i128 = 10000;
+const DEADLINE: u64 = 1000;
+
+struct TestCampaign<'a> {
+    env: Env,
+    client: CrowdfundingFarmerContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    admin: Address,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup_campaign(funding_model: FundingModel) -> TestCampaign<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingFarmerContract, ());
+    let client = CrowdfundingFarmerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let farmer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let campaign_id = client.create_campaign(
+        &farmer,
+        &GOAL,
+        &DEADLINE,
+        &token_address,
+        &funding_model,
+        &Vec::new(&env),
+    );
+
+    TestCampaign {
+        token: token::Client::new(&env, &token_address),
+        token_admin: token::StellarAssetClient::new(&env, &token_address),
+        env,
+        client,
+        admin,
+        farmer,
+        campaign_id,
+    }
+}
+
+fn contribute(campaign: &TestCampaign, amount: i128) -> Address {
+    let contributor = Address::generate(&campaign.env);
+    campaign.token_admin.mint(&contributor, &amount);
+    campaign
+        .client
+        .contribute(&contributor, &campaign.campaign_id, &amount, &None);
+    contributor
+}
+
+fn reason(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+#[test]
+fn test_update_feed_pagination() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let env = &campaign.env;
+    let client = &campaign.client;
+
+    for i in 0..MAX_UPDATES + 2 {
+        let id = client.post_update(
+            &campaign.farmer,
+            &campaign.campaign_id,
+            &String::from_str(env, "Planting progress"),
+            &BytesN::from_array(env, &[i as u8; 32]),
+        );
+        assert_eq!(id, i + 1);
+    }
+
+    // Newest first, and the two oldest updates have been dropped
+    let first = client.list_updates(&campaign.campaign_id, &0, &3);
+    assert_eq!(first.len(), 3);
+    assert_eq!(first.get(0).unwrap().id, MAX_UPDATES + 2);
+    assert_eq!(first.get(2).unwrap().id, MAX_UPDATES);
+
+    let last = client.list_updates(&campaign.campaign_id, &(MAX_UPDATES - 1), &10);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().id, 3);
+
+    let result = client.try_post_update(
+        &Address::generate(env),
+        &campaign.campaign_id,
+        &String::from_str(env, "Not the farmer"),
+        &reason(env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = client.try_post_update(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &String::from_str(env, ""),
+        &reason(env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidTitle)));
+}
+
+#[test]
+fn test_farmer_cancel_refunds_everyone() {
+    let campaign = setup_campaign(FundingModel::KeepWhatYouRaise);
+    let client = &campaign.client;
+    let first = contribute(&campaign, 3000);
+    let second = contribute(&campaign, 2000);
+    contribute(&campaign, 1000);
+
+    client.cancel_campaign(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+    assert_eq!(
+        client.get_campaign_details(&campaign.campaign_id).status,
+        CampaignStatus::Cancelled
+    );
+    assert_eq!(
+        client.get_cancellation_reason(&campaign.campaign_id),
+        Some(reason(&campaign.env))
+    );
+
+    // Anyone can trigger the refund, and repeating it pays nothing more
+    client.refund_contributions(&campaign.campaign_id);
+    client.refund_contributions(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&first), 3000);
+    assert_eq!(campaign.token.balance(&second), 2000);
+    assert_eq!(campaign.token.balance(&client.address), 0);
+
+    // Cancelled funds never reach the farmer, even on a flexible campaign
+    campaign.env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        client.try_finalize_campaign(&campaign.campaign_id),
+        Err(Ok(ContractError::CampaignAlreadyFinalized))
+    );
+    assert_eq!(
+        client.try_distribute_rewards(&campaign.campaign_id),
+        Err(Ok(ContractError::FundsNotReleasable))
+    );
+    assert_eq!(campaign.token.balance(&campaign.farmer), 0);
+}
+
+#[test]
+fn test_contribution_after_cancel_rejected() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    campaign.client.cancel_campaign(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+
+    let contributor = Address::generate(&campaign.env);
+    campaign.token_admin.mint(&contributor, &1000);
+    let result = campaign
+        .client
+        .try_contribute(&contributor, &campaign.campaign_id, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::CampaignNotActive)));
+}
+
+#[test]
+fn test_admin_cancel() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    let client = &campaign.client;
+    let backer = contribute(&campaign, 4000);
+
+    let result = client.try_admin_cancel_campaign(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    client.admin_cancel_campaign(
+        &campaign.admin,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+    client.refund_contributions(&campaign.campaign_id);
+    assert_eq!(campaign.token.balance(&backer), 4000);
+
+    // A cancelled campaign cannot be cancelled again or finalized
+    let result = client.try_cancel_campaign(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::CampaignAlreadyFinalized)));
+}
+
+#[test]
+fn test_cancel_after_finalization_rejected() {
+    let campaign = setup_campaign(FundingModel::AllOrNothing);
+    contribute(&campaign, GOAL);
+    campaign.env.ledger().set_timestamp(DEADLINE);
+    campaign.client.finalize_campaign(&campaign.campaign_id);
+
+    let result = campaign.client.try_cancel_campaign(
+        &campaign.farmer,
+        &campaign.campaign_id,
+        &reason(&campaign.env),
+    );
+    assert_eq!(result, Err(Ok(ContractError::CampaignAlreadyFinalized)));
+}
@@ -3,9 +3,9 @@
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
 
 use crate::{
-    campaign::{Campaign, CampaignStatus},
+    campaign::{Campaign, CampaignStatus, FundingModel},
     contribution::Contribution,
-    utils, CrowdfundingFarmerContract,
+    utils, ContractError, CrowdfundingFarmerContract,
 };
 
 fn with_contract_context<F, R>(f: F) -> R
@@ -23,24 +23,24 @@ fn test_validate_amount_positive() {
     let valid_amounts = [1, 100, 1000, 1000000, i128::MAX];
 
     for amount in valid_amounts {
-        // Should not panic
-        utils::validate_amount(amount);
+        assert_eq!(utils::validate_amount(amount), Ok(()));
     }
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_validate_amount_zero() {
-    utils::validate_amount(0);
+    assert_eq!(utils::validate_amount(0), Err(ContractError::InvalidAmount));
 }
 
 #[test]
-#[should_panic(expected = "Amount must be positive")]
 fn test_validate_amount_negative() {
     let negative_amounts = [-1, -100, -1000, i128::MIN];
 
     for amount in negative_amounts {
-        utils::validate_amount(amount);
+        assert_eq!(
+            utils::validate_amount(amount),
+            Err(ContractError::InvalidAmount)
+        );
     }
 }
 
@@ -57,13 +57,11 @@ fn test_validate_deadline_future() {
     ];
 
     for deadline in future_deadlines {
-        // Should not panic
-        utils::validate_deadline(current_time, deadline);
+        assert_eq!(utils::validate_deadline(current_time, deadline), Ok(()));
     }
 }
 
 #[test]
-#[should_panic(expected = "Deadline must be in the future")]
 fn test_validate_deadline_past() {
     let env = Env::default();
     let current_time = env.ledger().timestamp();
@@ -74,18 +72,23 @@ fn test_validate_deadline_past() {
     ];
 
     for deadline in past_deadlines {
-        utils::validate_deadline(current_time, deadline);
+        assert_eq!(
+            utils::validate_deadline(current_time, deadline),
+            Err(ContractError::InvalidDeadline)
+        );
     }
 }
 
 #[test]
-#[should_panic(expected = "Deadline must be in the future")]
 fn test_validate_deadline_current() {
     let env = Env::default();
     let current_time = env.ledger().timestamp();
 
-    // Should panic for current time
-    utils::validate_deadline(current_time, current_time);
+    // Should fail for current time
+    assert_eq!(
+        utils::validate_deadline(current_time, current_time),
+        Err(ContractError::InvalidDeadline)
+    );
 }
 
 #[test]
@@ -103,6 +106,8 @@ fn test_save_and_read_campaign() {
             total_funded: 0,
             status: CampaignStatus::Active,
             reward_token: reward_token.clone(),
+            funding_model: FundingModel::AllOrNothing,
+            funding_cap: 10000,
         };
 
         // Save campaign
@@ -208,6 +213,8 @@ fn test_campaign_persistence_across_calls() {
             total_funded: 0,
             status: CampaignStatus::Active,
             reward_token: reward_token.clone(),
+            funding_model: FundingModel::AllOrNothing,
+            funding_cap: 10000,
         };
 
         // Save campaign
@@ -272,6 +279,8 @@ fn test_campaign_overwrite() {
             total_funded: 0,
             status: CampaignStatus::Active,
             reward_token: reward_token.clone(),
+            funding_model: FundingModel::AllOrNothing,
+            funding_cap: 10000,
         };
 
         utils::save_campaign(env, &campaign_id, &initial_campaign);
@@ -285,6 +294,8 @@ fn test_campaign_overwrite() {
             total_funded: 5000,                        // Changed
             status: CampaignStatus::Successful,        // Changed
             reward_token: reward_token.clone(),
+            funding_model: FundingModel::AllOrNothing,
+            funding_cap: 20000,
         };
 
         utils::save_campaign(env, &campaign_id, &updated_campaign);
@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Vec};
+
+use crate::{utils, ContractError};
+
+/// Updates kept per campaign; the oldest is dropped when a new one would exceed this
+pub const MAX_UPDATES: u32 = 50;
+pub const MAX_TITLE_LEN: u32 = 128;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignUpdate {
+    pub id: u32,
+    pub title: String,
+    pub body_hash: BytesN<32>,
+    pub posted_at: u64,
+}
+
+pub fn post_update(
+    env: Env,
+    farmer: Address,
+    campaign_id: BytesN<32>,
+    title: String,
+    body_hash: BytesN<32>,
+) -> Result<u32, ContractError> {
+    farmer.require_auth();
+
+    let campaign = utils::load_campaign(&env, &campaign_id)?;
+    if campaign.farmer_id != farmer {
+        return Err(ContractError::Unauthorized);
+    }
+    if title.is_empty() || title.len() > MAX_TITLE_LEN {
+        return Err(ContractError::InvalidTitle);
+    }
+
+    let count_key = (symbol_short!("upd_count"), campaign_id.clone());
+    let id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &id);
+
+    let mut updates = read_updates(&env, &campaign_id);
+    if updates.len() >= MAX_UPDATES {
+        updates.pop_front();
+    }
+    updates.push_back(CampaignUpdate {
+        id,
+        title,
+        body_hash,
+        posted_at: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("updates"), campaign_id), &updates);
+
+    Ok(id)
+}
+
+/// Pages through a campaign's retained updates, newest first.
+pub fn list_updates(
+    env: Env,
+    campaign_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<CampaignUpdate> {
+    let mut page = Vec::new(&env);
+
+    for update in read_updates(&env, &campaign_id)
+        .iter()
+        .rev()
+        .skip(offset as usize)
+        .take(limit as usize)
+    {
+        page.push_back(update);
+    }
+
+    page
+}
+
+fn read_updates(env: &Env, campaign_id: &BytesN<32>) -> Vec<CampaignUpdate> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("updates"), campaign_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
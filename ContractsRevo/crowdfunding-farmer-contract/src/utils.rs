@@ -1,6 +1,6 @@
 use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Vec};
 
-use crate::{campaign::Campaign, contribution::Contribution};
+use crate::{campaign::Campaign, contribution::Contribution, ContractError};
 
 pub fn save_campaign(env: &Env, campaign_id: &BytesN<32>, campaign: &Campaign) {
     env.storage().persistent().set(campaign_id, campaign);
@@ -24,16 +24,67 @@ pub fn read_contributions(env: &Env, campaign_id: &BytesN<32>) -> Option<Vec<Con
         .get(&(contributions_key, campaign_id))
 }
 
-pub fn validate_amount(amount: i128) {
+pub fn save_backers(env: &Env, campaign_id: &BytesN<32>, backers: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("backers"), campaign_id), backers);
+}
+
+pub fn read_backers(env: &Env, campaign_id: &BytesN<32>) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("backers"), campaign_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn save_backing(env: &Env, campaign_id: &BytesN<32>, backer: &Address, amount: i128) {
+    let key = (symbol_short!("backing"), campaign_id, backer);
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+}
+
+pub fn read_backing(env: &Env, campaign_id: &BytesN<32>, backer: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("backing"), campaign_id, backer))
+        .unwrap_or(0)
+}
+
+pub fn save_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&symbol_short!("admin"), admin);
+}
+
+pub fn read_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&symbol_short!("admin"))
+}
+
+pub fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    let stored = read_admin(env).ok_or(ContractError::NotInitialized)?;
+    if stored != *admin {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub fn validate_amount(amount: i128) -> Result<(), ContractError> {
     if amount <= 0 {
-        panic!("Amount must be positive");
+        return Err(ContractError::InvalidAmount);
     }
+    Ok(())
 }
 
-pub fn validate_deadline(current_time: u64, deadline: u64) {
+pub fn validate_deadline(current_time: u64, deadline: u64) -> Result<(), ContractError> {
     if deadline <= current_time {
-        panic!("Deadline must be in the future");
+        return Err(ContractError::InvalidDeadline);
     }
+    Ok(())
+}
+
+pub fn load_campaign(env: &Env, campaign_id: &BytesN<32>) -> Result<Campaign, ContractError> {
+    read_campaign(env, campaign_id).ok_or(ContractError::CampaignNotFound)
 }
 
 pub fn transfer_tokens(
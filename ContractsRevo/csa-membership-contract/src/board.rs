@@ -0,0 +1,156 @@
+use crate::{farm::require_farm_admin, Announcement, DataKey, Error, ShareManifest};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+/// How long a published manifest can still be corrected before it becomes immutable
+pub const MANIFEST_LOCK_DELAY: u64 = 24 * 60 * 60;
+pub const MAX_SUMMARY_LEN: u32 = 256;
+pub const MAX_TITLE_LEN: u32 = 128;
+/// Announcements kept per farm; the oldest is dropped when a new one would exceed this
+pub const MAX_ANNOUNCEMENTS: u32 = 50;
+
+pub fn publish_share_manifest(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    week: u32,
+    manifest_hash: BytesN<32>,
+    summary: String,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if week == 0 {
+        return Err(Error::InvalidWeek);
+    }
+    if summary.len() > MAX_SUMMARY_LEN {
+        return Err(Error::InvalidContent);
+    }
+
+    let now = env.ledger().timestamp();
+    let key = DataKey::Manifest(farm_id.clone(), season.clone(), week);
+
+    // Corrections keep the original publication time, so the lock cannot be pushed back
+    let published_at = match env.storage().persistent().get::<_, ShareManifest>(&key) {
+        Some(existing) if now >= existing.published_at + MANIFEST_LOCK_DELAY => {
+            return Err(Error::ManifestLocked);
+        }
+        Some(existing) => existing.published_at,
+        None => now,
+    };
+
+    let manifest = ShareManifest {
+        week,
+        manifest_hash,
+        summary,
+        published_at,
+    };
+    env.storage().persistent().set(&key, &manifest);
+
+    env.events().publish(
+        (Symbol::new(&env, "share_manifest_published"), farm_id, week),
+        (season, manifest),
+    );
+
+    Ok(())
+}
+
+pub fn get_share_manifest(
+    env: Env,
+    farm_id: BytesN<32>,
+    season: String,
+    week: u32,
+) -> Option<ShareManifest> {
+    get_manifest(&env, &farm_id, &season, week)
+}
+
+pub fn post_announcement(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    title: String,
+    body_hash: BytesN<32>,
+) -> Result<u32, Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if title.is_empty() || title.len() > MAX_TITLE_LEN {
+        return Err(Error::InvalidContent);
+    }
+
+    let count_key = DataKey::AnnouncementCount(farm_id.clone());
+    let id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &id);
+
+    let key = DataKey::Announcements(farm_id.clone());
+    let mut announcements = get_announcements(&env, &key);
+    if announcements.len() >= MAX_ANNOUNCEMENTS {
+        announcements.pop_front();
+    }
+
+    let announcement = Announcement {
+        id,
+        title,
+        body_hash,
+        posted_at: env.ledger().timestamp(),
+    };
+    announcements.push_back(announcement.clone());
+    env.storage().persistent().set(&key, &announcements);
+
+    env.events().publish(
+        (Symbol::new(&env, "announcement_posted"), farm_id),
+        announcement,
+    );
+
+    Ok(id)
+}
+
+/// Pages through a farm's retained announcements, newest first.
+pub fn list_announcements(
+    env: Env,
+    farm_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<Announcement> {
+    let announcements = get_announcements(&env, &DataKey::Announcements(farm_id));
+    let mut page = Vec::new(&env);
+
+    for announcement in announcements
+        .iter()
+        .rev()
+        .skip(offset as usize)
+        .take(limit as usize)
+    {
+        page.push_back(announcement);
+    }
+
+    page
+}
+
+/// Hash of the content the farm promised for a week, if it published a manifest.
+pub(crate) fn manifest_hash(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    season: &String,
+    week: u32,
+) -> Option<BytesN<32>> {
+    get_manifest(env, farm_id, season, week).map(|manifest| manifest.manifest_hash)
+}
+
+fn get_manifest(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    season: &String,
+    week: u32,
+) -> Option<ShareManifest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Manifest(farm_id.clone(), season.clone(), week))
+}
+
+fn get_announcements(env: &Env, key: &DataKey) -> Vec<Announcement> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -18,7 +18,18 @@ pub fn cancel_membership(env: Env, token_id: BytesN<32>, member: Address) -> Res
     }
     env.logs().add("After member check", &[]);
 
+    crate::payments::settle_cancellation(&env, &token_id, &membership);
+    env.logs().add("After settling payment", &[]);
+
     env.storage().persistent().remove(&token_id);
+    crate::farm::remove_season_member(&env, &membership, &token_id);
+    crate::member::unindex_membership(&env, &member, &token_id);
+    crate::farm::release_share(
+        &env,
+        &membership.farm_id,
+        &membership.season,
+        membership.share_size,
+    );
     env.logs().add("After removing membership", &[]);
 
     // Evento modificado con Symbol
@@ -0,0 +1,180 @@
+use crate::{
+    board::manifest_hash,
+    farm::require_farm_admin,
+    payments::{credit_missed_share, season_weeks, SECONDS_PER_WEEK},
+    CSAMembership, DataKey, DistributionRecord, Error,
+};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, Vec};
+
+/// Time a farm has after the end of a week to record its distribution before members can claim a credit
+pub const MISSED_SHARE_GRACE_PERIOD: u64 = 3 * 24 * 60 * 60;
+
+pub fn record_distribution(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    week: u32,
+    member_token_ids: Vec<BytesN<32>>,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    let now = env.ledger().timestamp();
+
+    for token_id in member_token_ids.iter() {
+        let membership = get_membership(&env, &token_id)?;
+        if membership.farm_id != farm_id || membership.season != season {
+            return Err(Error::NotFound);
+        }
+        validate_week(&membership, week)?;
+
+        let mut log = get_log(&env, &token_id);
+        let mut record = log.get(week).unwrap_or(DistributionRecord {
+            week,
+            delivered_at: None,
+            confirmed_at: None,
+            credit: 0,
+            manifest_hash: None,
+        });
+
+        if record.credit > 0 {
+            return Err(Error::CreditAlreadyClaimed);
+        }
+        if record.delivered_at.is_none() {
+            record.delivered_at = Some(now);
+            record.manifest_hash = manifest_hash(&env, &farm_id, &season, week);
+            log.set(week, record);
+            save_log(&env, &token_id, &log);
+        }
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, "distribution_recorded"), farm_id, week),
+        (season, member_token_ids),
+    );
+
+    Ok(())
+}
+
+pub fn confirm_pickup(
+    env: Env,
+    member: Address,
+    token_id: BytesN<32>,
+    week: u32,
+) -> Result<(), Error> {
+    member.require_auth();
+
+    let membership = get_membership(&env, &token_id)?;
+    if membership.member != member {
+        return Err(Error::NotAuthorized);
+    }
+
+    let mut log = get_log(&env, &token_id);
+    let mut record = log
+        .get(week)
+        .filter(|record| record.delivered_at.is_some())
+        .ok_or(Error::DistributionNotRecorded)?;
+
+    if record.confirmed_at.is_none() {
+        record.confirmed_at = Some(env.ledger().timestamp());
+        log.set(week, record);
+        save_log(&env, &token_id, &log);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, "pickup_confirmed"), member),
+        (token_id, week),
+    );
+
+    Ok(())
+}
+
+/// Credits the member for a week the farm never recorded a distribution for.
+/// The credit is applied to the member's next enrollment at the farm or can be withdrawn.
+pub fn claim_missed_share_credit(
+    env: Env,
+    member: Address,
+    token_id: BytesN<32>,
+    week: u32,
+) -> Result<i128, Error> {
+    member.require_auth();
+
+    let membership = get_membership(&env, &token_id)?;
+    if membership.member != member {
+        return Err(Error::NotAuthorized);
+    }
+    validate_week(&membership, week)?;
+
+    let week_end = membership.start_date + week as u64 * SECONDS_PER_WEEK;
+    if env.ledger().timestamp() < week_end + MISSED_SHARE_GRACE_PERIOD {
+        return Err(Error::GracePeriodActive);
+    }
+
+    let mut log = get_log(&env, &token_id);
+    if let Some(record) = log.get(week) {
+        if record.delivered_at.is_some() {
+            return Err(Error::ShareDelivered);
+        }
+        if record.credit > 0 {
+            return Err(Error::CreditAlreadyClaimed);
+        }
+    }
+
+    let credit = credit_missed_share(&env, &token_id, &membership);
+    if credit == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    log.set(
+        week,
+        DistributionRecord {
+            week,
+            delivered_at: None,
+            confirmed_at: None,
+            credit,
+            manifest_hash: manifest_hash(&env, &membership.farm_id, &membership.season, week),
+        },
+    );
+    save_log(&env, &token_id, &log);
+
+    env.events().publish(
+        (Symbol::new(&env, "missed_share_credited"), member),
+        (token_id, week, credit),
+    );
+
+    Ok(credit)
+}
+
+/// Every recorded or credited week of the membership, in week order.
+pub fn get_distribution_log(env: Env, token_id: BytesN<32>) -> Vec<DistributionRecord> {
+    get_log(&env, &token_id).values()
+}
+
+fn validate_week(membership: &CSAMembership, week: u32) -> Result<(), Error> {
+    if week == 0 || week as u64 > season_weeks(membership) {
+        return Err(Error::InvalidWeek);
+    }
+
+    Ok(())
+}
+
+fn get_membership(env: &Env, token_id: &BytesN<32>) -> Result<CSAMembership, Error> {
+    env.storage()
+        .persistent()
+        .get(token_id)
+        .ok_or(Error::NotFound)
+}
+
+fn get_log(env: &Env, token_id: &BytesN<32>) -> Map<u32, DistributionRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DistributionLog(token_id.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn save_log(env: &Env, token_id: &BytesN<32>, log: &Map<u32, DistributionRecord>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DistributionLog(token_id.clone()), log);
+}
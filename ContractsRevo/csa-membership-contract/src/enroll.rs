@@ -1,4 +1,4 @@
-use crate::{CSAMembership, Error, ShareSize};
+use crate::{CSAMembership, DataKey, Error, ShareSize};
 use soroban_sdk::{Address, BytesN, Env, String, Symbol};
 
 pub fn enroll_membership(
@@ -15,11 +15,23 @@ pub fn enroll_membership(
     member.require_auth();
     env.logs().add("After require_auth", &[]);
 
-    crate::validate::validate_season(&env, farm_id.clone(), season.clone(), start_date, end_date)?;
+    // Seasons the farm created enforce their enrollment window; free-form seasons keep
+    // the original date checks
+    match crate::season::get_defined_season(&env, &farm_id, &season) {
+        Some(defined) => {
+            crate::validate::validate_enrollment_window(&env, &defined, start_date, end_date)?
+        }
+        None => crate::validate::validate_season(
+            &env,
+            farm_id.clone(),
+            season.clone(),
+            start_date,
+            end_date,
+        )?,
+    }
     env.logs().add("After validate_season", &[]);
 
-    let token_id = BytesN::from_array(&env, &[0; 32]);
-    env.logs().add("After generating token_id", &[]);
+    crate::farm::require_registered(&env, &farm_id)?;
 
     let membership = CSAMembership {
         farm_id,
@@ -28,16 +40,60 @@ pub fn enroll_membership(
         pickup_location,
         start_date,
         end_date,
-        member: member.clone(),
+        member,
     };
+    create_membership(&env, membership, 0)
+}
+
+/// Reserves capacity, collects payment and stores a validated membership under a new token id.
+pub(crate) fn create_membership(
+    env: &Env,
+    membership: CSAMembership,
+    discount_bps: u32,
+) -> Result<BytesN<32>, Error> {
+    crate::farm::reserve_share(
+        env,
+        &membership.farm_id,
+        &membership.season,
+        membership.share_size,
+    )?;
+    env.logs().add("After reserving capacity", &[]);
+
+    let token_id = next_token_id(env);
+    env.logs().add("After generating token_id", &[]);
+
+    crate::payments::collect_payment(env, &token_id, &membership, discount_bps)?;
+    env.logs().add("After collecting payment", &[]);
+
     env.storage().persistent().set(&token_id, &membership);
+    crate::farm::add_season_member(env, &membership, &token_id);
+    crate::member::index_membership(env, &membership.member, &token_id);
     env.logs().add("After storage set", &[]);
 
+    crate::loyalty::award_on_enrollment(env, &token_id, &membership);
+
     env.events().publish(
-        (Symbol::new(&env, "membership_enrolled"), member.clone()),
+        (Symbol::new(env, "membership_enrolled"), membership.member),
         token_id.clone(),
     );
     env.logs().add("After event publish", &[]);
 
     Ok(token_id)
 }
+
+// Token ids are a big-endian counter, so they never collide with the all-zero id
+fn next_token_id(env: &Env) -> BytesN<32> {
+    let count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MembershipCount)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::MembershipCount, &count);
+
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&count.to_be_bytes());
+    BytesN::from_array(env, &bytes)
+}
@@ -0,0 +1,211 @@
+use crate::{CSAMembership, DataKey, Error, Farm, SeasonCapacity, ShareSize};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+pub fn register_farm(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    name: String,
+    location: String,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+
+    if farm_id == BytesN::from_array(&env, &[0; 32]) || name.is_empty() {
+        return Err(Error::InvalidFarm);
+    }
+
+    let key = DataKey::Farm(farm_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::FarmAlreadyRegistered);
+    }
+
+    let farm = Farm {
+        farm_admin: farm_admin.clone(),
+        name,
+        location,
+        registered_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&key, &farm);
+
+    env.events()
+        .publish((Symbol::new(&env, "farm_registered"), farm_admin), farm_id);
+
+    Ok(())
+}
+
+pub fn get_farm(env: Env, farm_id: BytesN<32>) -> Option<Farm> {
+    env.storage().persistent().get(&DataKey::Farm(farm_id))
+}
+
+pub fn set_season_capacity(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    small_cap: u32,
+    medium_cap: u32,
+    large_cap: u32,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    let key = DataKey::SeasonCapacity(farm_id.clone(), season.clone());
+
+    // Shares already sold stay counted when the caps change
+    let mut capacity = get_capacity(&env, &key).unwrap_or(SeasonCapacity {
+        small_cap,
+        medium_cap,
+        large_cap,
+        small_enrolled: 0,
+        medium_enrolled: 0,
+        large_enrolled: 0,
+    });
+    capacity.small_cap = small_cap;
+    capacity.medium_cap = medium_cap;
+    capacity.large_cap = large_cap;
+
+    env.storage().persistent().set(&key, &capacity);
+
+    env.events().publish(
+        (Symbol::new(&env, "season_capacity_set"), farm_id),
+        (season, small_cap, medium_cap, large_cap),
+    );
+
+    Ok(())
+}
+
+/// Remaining (small, medium, large) shares. Seasons without caps report `u32::MAX`.
+pub fn get_season_availability(env: Env, farm_id: BytesN<32>, season: String) -> (u32, u32, u32) {
+    match get_capacity(&env, &DataKey::SeasonCapacity(farm_id, season)) {
+        Some(capacity) => (
+            capacity.small_cap.saturating_sub(capacity.small_enrolled),
+            capacity.medium_cap.saturating_sub(capacity.medium_enrolled),
+            capacity.large_cap.saturating_sub(capacity.large_enrolled),
+        ),
+        None => (u32::MAX, u32::MAX, u32::MAX),
+    }
+}
+
+pub fn list_farm_members(
+    env: Env,
+    farm_id: BytesN<32>,
+    season: String,
+    offset: u32,
+    limit: u32,
+) -> Vec<(BytesN<32>, CSAMembership)> {
+    let token_ids = get_season_members(&env, &farm_id, &season);
+    let mut members = Vec::new(&env);
+
+    for token_id in token_ids.iter().skip(offset as usize).take(limit as usize) {
+        if let Some(membership) = env
+            .storage()
+            .persistent()
+            .get::<_, CSAMembership>(&token_id)
+        {
+            members.push_back((token_id, membership));
+        }
+    }
+
+    members
+}
+
+pub(crate) fn require_registered(env: &Env, farm_id: &BytesN<32>) -> Result<Farm, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Farm(farm_id.clone()))
+        .ok_or(Error::InvalidFarm)
+}
+
+pub(crate) fn require_farm_admin(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    farm_admin: &Address,
+) -> Result<(), Error> {
+    let farm = require_registered(env, farm_id)?;
+
+    if farm.farm_admin != *farm_admin {
+        return Err(Error::NotAuthorized);
+    }
+
+    Ok(())
+}
+
+/// Takes one share of the given size out of the season's capacity, if the farm set one.
+pub(crate) fn reserve_share(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    season: &String,
+    size: ShareSize,
+) -> Result<(), Error> {
+    let key = DataKey::SeasonCapacity(farm_id.clone(), season.clone());
+    let Some(mut capacity) = get_capacity(env, &key) else {
+        return Ok(());
+    };
+
+    let (cap, enrolled) = match size {
+        ShareSize::Small => (capacity.small_cap, &mut capacity.small_enrolled),
+        ShareSize::Medium => (capacity.medium_cap, &mut capacity.medium_enrolled),
+        ShareSize::Large => (capacity.large_cap, &mut capacity.large_enrolled),
+    };
+
+    if *enrolled >= cap {
+        return Err(Error::SeasonFull);
+    }
+    *enrolled += 1;
+
+    env.storage().persistent().set(&key, &capacity);
+
+    Ok(())
+}
+
+/// Gives a share back to the season's capacity.
+pub(crate) fn release_share(env: &Env, farm_id: &BytesN<32>, season: &String, size: ShareSize) {
+    let key = DataKey::SeasonCapacity(farm_id.clone(), season.clone());
+    let Some(mut capacity) = get_capacity(env, &key) else {
+        return;
+    };
+
+    let enrolled = match size {
+        ShareSize::Small => &mut capacity.small_enrolled,
+        ShareSize::Medium => &mut capacity.medium_enrolled,
+        ShareSize::Large => &mut capacity.large_enrolled,
+    };
+    *enrolled = enrolled.saturating_sub(1);
+
+    env.storage().persistent().set(&key, &capacity);
+}
+
+pub(crate) fn get_season_members(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    season: &String,
+) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeasonMembers(farm_id.clone(), season.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub(crate) fn add_season_member(env: &Env, membership: &CSAMembership, token_id: &BytesN<32>) {
+    let mut members = get_season_members(env, &membership.farm_id, &membership.season);
+    members.push_back(token_id.clone());
+    env.storage().persistent().set(
+        &DataKey::SeasonMembers(membership.farm_id.clone(), membership.season.clone()),
+        &members,
+    );
+}
+
+pub(crate) fn remove_season_member(env: &Env, membership: &CSAMembership, token_id: &BytesN<32>) {
+    let mut members = get_season_members(env, &membership.farm_id, &membership.season);
+    if let Some(index) = members.first_index_of(token_id) {
+        members.remove(index);
+        env.storage().persistent().set(
+            &DataKey::SeasonMembers(membership.farm_id.clone(), membership.season.clone()),
+            &members,
+        );
+    }
+}
+
+fn get_capacity(env: &Env, key: &DataKey) -> Option<SeasonCapacity> {
+    env.storage().persistent().get(key)
+}
@@ -1,8 +1,21 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String,
+    contract, contractclient, contracterror, contractimpl, contracttype, Address, BytesN, Env,
+    String, Vec,
 };
 
+/// Interface of the loyalty token contract used to reward enrollments
+#[contractclient(name = "LoyaltyTokenContractClient")]
+pub trait LoyaltyTokenContract {
+    fn award_points(
+        env: Env,
+        program_id: BytesN<32>,
+        awarder: Address,
+        user_address: Address,
+        transaction_amount: u32,
+    );
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct CSAMembership {
@@ -32,6 +45,157 @@ pub enum Error {
     InvalidFarm = 4,
     InvalidSeason = 5,
     AlreadyCancelled = 6,
+    InvalidPrice = 7,
+    PricingNotSet = 8,
+    NothingToClaim = 9,
+    InvalidRefundPolicy = 10,
+    FarmAlreadyRegistered = 11,
+    SeasonFull = 12,
+    InvalidWeek = 13,
+    DistributionNotRecorded = 14,
+    ShareDelivered = 15,
+    GracePeriodActive = 16,
+    CreditAlreadyClaimed = 17,
+    TransferDisabled = 18,
+    InvalidTransfer = 19,
+    InvalidShareSize = 20,
+    SeasonAlreadyExists = 21,
+    EnrollmentClosed = 22,
+    RenewalNotOffered = 23,
+    AlreadyRenewed = 24,
+    ManifestLocked = 25,
+    InvalidContent = 26,
+    LoyaltyHookNotSet = 27,
+    LoyaltyAwardFailed = 28,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyHook {
+    pub loyalty_contract: Address,
+    pub program_id: BytesN<32>,
+    pub points_per_enrollment: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareManifest {
+    pub week: u32,
+    pub manifest_hash: BytesN<32>,
+    pub summary: String,
+    pub published_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Announcement {
+    pub id: u32,
+    pub title: String,
+    pub body_hash: BytesN<32>,
+    pub posted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Season {
+    pub code: String,
+    pub enrollment_open: u64,
+    pub enrollment_close: u64,
+    pub start_date: u64,
+    pub end_date: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalOffer {
+    pub from_season: String,
+    pub discount_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Farm {
+    pub farm_admin: Address,
+    pub name: String,
+    pub location: String,
+    pub registered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeasonCapacity {
+    pub small_cap: u32,
+    pub medium_cap: u32,
+    pub large_cap: u32,
+    pub small_enrolled: u32,
+    pub medium_enrolled: u32,
+    pub large_enrolled: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharePricing {
+    pub small: i128,
+    pub medium: i128,
+    pub large: i128,
+    pub payment_token: Address,
+    /// Share of the unused season returned on cancellation, in basis points
+    pub refund_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MembershipPayment {
+    pub payment_token: Address,
+    pub amount: i128,
+    /// Amount already released to the farm
+    pub released: i128,
+    /// Amount moved to the member's credit for missed shares
+    pub credited: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareCredit {
+    pub payment_token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistributionRecord {
+    pub week: u32,
+    pub delivered_at: Option<u64>,
+    pub confirmed_at: Option<u64>,
+    /// Credit granted when the farm missed the week
+    pub credit: i128,
+    /// Manifest of what the farm promised for the week, when one was published
+    pub manifest_hash: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    MembershipCount,
+    Farm(BytesN<32>),
+    SeasonCapacity(BytesN<32>, String),
+    SharePricing(BytesN<32>, String),
+    Payment(BytesN<32>),
+    SeasonMembers(BytesN<32>, String),
+    DistributionLog(BytesN<32>),
+    ShareCredit(Address, BytesN<32>),
+    TransfersDisabled(BytesN<32>, String),
+    Season(BytesN<32>, String),
+    FarmSeasons(BytesN<32>),
+    RenewalOffer(BytesN<32>, String),
+    Renewed(BytesN<32>),
+    Manifest(BytesN<32>, String, u32),
+    Announcements(BytesN<32>),
+    AnnouncementCount(BytesN<32>),
+    MemberTokens(Address),
+    LoyaltyHook(BytesN<32>),
+    LoyaltyAwarded(BytesN<32>),
+    LoyaltySeasonAwarded(BytesN<32>, String, Address),
 }
 
 #[contract]
@@ -77,6 +241,334 @@ impl CSAMembershipContract {
     pub fn cancel_membership(env: Env, token_id: BytesN<32>, member: Address) -> Result<(), Error> {
         crate::cancel::cancel_membership(env, token_id, member)
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_season(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season_code: String,
+        enrollment_open: u64,
+        enrollment_close: u64,
+        start_date: u64,
+        end_date: u64,
+    ) -> Result<(), Error> {
+        season::create_season(
+            env,
+            farm_admin,
+            farm_id,
+            season_code,
+            enrollment_open,
+            enrollment_close,
+            start_date,
+            end_date,
+        )
+    }
+
+    pub fn get_season(env: Env, farm_id: BytesN<32>, season_code: String) -> Option<Season> {
+        season::get_season(env, farm_id, season_code)
+    }
+
+    pub fn list_seasons(env: Env, farm_id: BytesN<32>) -> Vec<Season> {
+        season::list_seasons(env, farm_id)
+    }
+
+    pub fn offer_renewal(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        from_season: String,
+        to_season: String,
+        discount_bps: u32,
+    ) -> Result<(), Error> {
+        season::offer_renewal(
+            env,
+            farm_admin,
+            farm_id,
+            from_season,
+            to_season,
+            discount_bps,
+        )
+    }
+
+    pub fn get_renewal_offer(
+        env: Env,
+        farm_id: BytesN<32>,
+        to_season: String,
+    ) -> Option<RenewalOffer> {
+        season::get_renewal_offer(env, farm_id, to_season)
+    }
+
+    pub fn accept_renewal(
+        env: Env,
+        member: Address,
+        token_id: BytesN<32>,
+        to_season: String,
+    ) -> Result<BytesN<32>, Error> {
+        season::accept_renewal(env, member, token_id, to_season)
+    }
+
+    pub fn transfer_membership(
+        env: Env,
+        member: Address,
+        token_id: BytesN<32>,
+        new_member: Address,
+    ) -> Result<(), Error> {
+        transfer::transfer_membership(env, member, token_id, new_member)
+    }
+
+    pub fn change_share_size(
+        env: Env,
+        member: Address,
+        token_id: BytesN<32>,
+        new_size: ShareSize,
+    ) -> Result<i128, Error> {
+        transfer::change_share_size(env, member, token_id, new_size)
+    }
+
+    pub fn set_transfers_enabled(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        transfer::set_transfers_enabled(env, farm_admin, farm_id, season, enabled)
+    }
+
+    pub fn register_farm(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        name: String,
+        location: String,
+    ) -> Result<(), Error> {
+        farm::register_farm(env, farm_admin, farm_id, name, location)
+    }
+
+    pub fn get_farm(env: Env, farm_id: BytesN<32>) -> Option<Farm> {
+        farm::get_farm(env, farm_id)
+    }
+
+    pub fn set_season_capacity(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        small_cap: u32,
+        medium_cap: u32,
+        large_cap: u32,
+    ) -> Result<(), Error> {
+        farm::set_season_capacity(
+            env, farm_admin, farm_id, season, small_cap, medium_cap, large_cap,
+        )
+    }
+
+    pub fn get_season_availability(
+        env: Env,
+        farm_id: BytesN<32>,
+        season: String,
+    ) -> (u32, u32, u32) {
+        farm::get_season_availability(env, farm_id, season)
+    }
+
+    pub fn list_farm_members(
+        env: Env,
+        farm_id: BytesN<32>,
+        season: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(BytesN<32>, CSAMembership)> {
+        farm::list_farm_members(env, farm_id, season, offset, limit)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_share_pricing(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        small: i128,
+        medium: i128,
+        large: i128,
+        payment_token: Address,
+    ) -> Result<(), Error> {
+        payments::set_share_pricing(
+            env,
+            farm_admin,
+            farm_id,
+            season,
+            small,
+            medium,
+            large,
+            payment_token,
+        )
+    }
+
+    pub fn set_refund_policy(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        refund_bps: u32,
+    ) -> Result<(), Error> {
+        payments::set_refund_policy(env, farm_admin, farm_id, season, refund_bps)
+    }
+
+    pub fn get_share_price(
+        env: Env,
+        farm_id: BytesN<32>,
+        season: String,
+        size: ShareSize,
+    ) -> Result<i128, Error> {
+        payments::get_share_price(env, farm_id, season, size)
+    }
+
+    pub fn claim_season_revenue(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+    ) -> Result<i128, Error> {
+        payments::claim_season_revenue(env, farm_admin, farm_id, season)
+    }
+
+    pub fn record_distribution(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        week: u32,
+        member_token_ids: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        distribution::record_distribution(env, farm_admin, farm_id, season, week, member_token_ids)
+    }
+
+    pub fn confirm_pickup(
+        env: Env,
+        member: Address,
+        token_id: BytesN<32>,
+        week: u32,
+    ) -> Result<(), Error> {
+        distribution::confirm_pickup(env, member, token_id, week)
+    }
+
+    pub fn claim_missed_share_credit(
+        env: Env,
+        member: Address,
+        token_id: BytesN<32>,
+        week: u32,
+    ) -> Result<i128, Error> {
+        distribution::claim_missed_share_credit(env, member, token_id, week)
+    }
+
+    pub fn get_distribution_log(env: Env, token_id: BytesN<32>) -> Vec<DistributionRecord> {
+        distribution::get_distribution_log(env, token_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn publish_share_manifest(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        season: String,
+        week: u32,
+        manifest_hash: BytesN<32>,
+        summary: String,
+    ) -> Result<(), Error> {
+        board::publish_share_manifest(
+            env,
+            farm_admin,
+            farm_id,
+            season,
+            week,
+            manifest_hash,
+            summary,
+        )
+    }
+
+    pub fn get_share_manifest(
+        env: Env,
+        farm_id: BytesN<32>,
+        season: String,
+        week: u32,
+    ) -> Option<ShareManifest> {
+        board::get_share_manifest(env, farm_id, season, week)
+    }
+
+    pub fn post_announcement(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        title: String,
+        body_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        board::post_announcement(env, farm_admin, farm_id, title, body_hash)
+    }
+
+    pub fn list_announcements(
+        env: Env,
+        farm_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Announcement> {
+        board::list_announcements(env, farm_id, offset, limit)
+    }
+
+    pub fn is_active_member(
+        env: Env,
+        member: Address,
+        farm_id: BytesN<32>,
+        at_timestamp: u64,
+    ) -> bool {
+        member::is_active_member(env, member, farm_id, at_timestamp)
+    }
+
+    pub fn get_active_memberships(env: Env, member: Address) -> Vec<CSAMembership> {
+        member::get_active_memberships(env, member)
+    }
+
+    pub fn set_loyalty_hook(
+        env: Env,
+        farm_admin: Address,
+        farm_id: BytesN<32>,
+        loyalty_contract: Address,
+        program_id: BytesN<32>,
+        points_per_enrollment: u32,
+    ) -> Result<(), Error> {
+        loyalty::set_loyalty_hook(
+            env,
+            farm_admin,
+            farm_id,
+            loyalty_contract,
+            program_id,
+            points_per_enrollment,
+        )
+    }
+
+    pub fn get_loyalty_hook(env: Env, farm_id: BytesN<32>) -> Option<LoyaltyHook> {
+        loyalty::get_loyalty_hook(env, farm_id)
+    }
+
+    pub fn award_enrollment_points(env: Env, token_id: BytesN<32>) -> Result<bool, Error> {
+        loyalty::award_enrollment_points(env, token_id)
+    }
+
+    pub fn get_share_credit(env: Env, member: Address, farm_id: BytesN<32>) -> Option<ShareCredit> {
+        payments::get_share_credit(env, member, farm_id)
+    }
+
+    pub fn withdraw_share_credit(
+        env: Env,
+        member: Address,
+        farm_id: BytesN<32>,
+    ) -> Result<i128, Error> {
+        payments::withdraw_share_credit(env, member, farm_id)
+    }
+
+    pub fn get_membership_payment(env: Env, token_id: BytesN<32>) -> Option<MembershipPayment> {
+        payments::get_membership_payment(env, token_id)
+    }
 }
 
 #[contracterror]
@@ -86,11 +578,19 @@ pub enum ContractError {
     NotAuthorized = 2,
 }
 
+pub mod board;
 pub mod cancel;
+pub mod distribution;
 pub mod enroll;
 pub mod errors;
+pub mod farm;
+pub mod loyalty;
 pub mod manage;
+pub mod member;
 pub mod metadata;
+pub mod payments;
+pub mod season;
+pub mod transfer;
 pub mod types;
 pub mod validate;
 
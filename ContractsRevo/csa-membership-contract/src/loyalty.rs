@@ -0,0 +1,124 @@
+use crate::{
+    farm::require_farm_admin, CSAMembership, DataKey, Error, LoyaltyHook,
+    LoyaltyTokenContractClient,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn set_loyalty_hook(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    loyalty_contract: Address,
+    program_id: BytesN<32>,
+    points_per_enrollment: u32,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    let hook = LoyaltyHook {
+        loyalty_contract,
+        program_id,
+        points_per_enrollment,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoyaltyHook(farm_id.clone()), &hook);
+
+    env.events()
+        .publish((Symbol::new(&env, "loyalty_hook_set"), farm_id), hook);
+
+    Ok(())
+}
+
+pub fn get_loyalty_hook(env: Env, farm_id: BytesN<32>) -> Option<LoyaltyHook> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoyaltyHook(farm_id))
+}
+
+/// Retries the loyalty award for an enrollment. Returns false if the enrollment, or the
+/// member's season at the farm, was already awarded, so repeated calls never award twice.
+pub fn award_enrollment_points(env: Env, token_id: BytesN<32>) -> Result<bool, Error> {
+    let membership: CSAMembership = env
+        .storage()
+        .persistent()
+        .get(&token_id)
+        .ok_or(Error::NotFound)?;
+
+    if already_awarded(&env, &token_id, &membership) {
+        return Ok(false);
+    }
+
+    let hook = get_loyalty_hook(env.clone(), membership.farm_id.clone())
+        .ok_or(Error::LoyaltyHookNotSet)?;
+
+    if !award(&env, &hook, &token_id, &membership) {
+        return Err(Error::LoyaltyAwardFailed);
+    }
+
+    Ok(true)
+}
+
+/// Awards the farm's enrollment points for a new membership. Best effort: a failing loyalty
+/// contract does not block the enrollment, and the award can be retried later.
+///
+/// Points are awarded once per member and season, so cancelling and enrolling again, or
+/// buying several shares, earns nothing more.
+pub(crate) fn award_on_enrollment(env: &Env, token_id: &BytesN<32>, membership: &CSAMembership) {
+    if already_awarded(env, token_id, membership) {
+        return;
+    }
+
+    if let Some(hook) = get_loyalty_hook(env.clone(), membership.farm_id.clone()) {
+        award(env, &hook, token_id, membership);
+    }
+}
+
+fn already_awarded(env: &Env, token_id: &BytesN<32>, membership: &CSAMembership) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::LoyaltyAwarded(token_id.clone()))
+        || env.storage().persistent().has(&season_key(membership))
+}
+
+fn season_key(membership: &CSAMembership) -> DataKey {
+    DataKey::LoyaltySeasonAwarded(
+        membership.farm_id.clone(),
+        membership.season.clone(),
+        membership.member.clone(),
+    )
+}
+
+fn award(env: &Env, hook: &LoyaltyHook, token_id: &BytesN<32>, membership: &CSAMembership) -> bool {
+    if hook.points_per_enrollment == 0 {
+        return false;
+    }
+
+    let client = LoyaltyTokenContractClient::new(env, &hook.loyalty_contract);
+    let result = client.try_award_points(
+        &hook.program_id,
+        &env.current_contract_address(),
+        &membership.member,
+        &hook.points_per_enrollment,
+    );
+    if !matches!(result, Ok(Ok(()))) {
+        return false;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoyaltyAwarded(token_id.clone()), &true);
+    env.storage()
+        .persistent()
+        .set(&season_key(membership), &true);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "loyalty_points_awarded"),
+            membership.member.clone(),
+        ),
+        (token_id.clone(), hook.points_per_enrollment),
+    );
+
+    true
+}
@@ -0,0 +1,66 @@
+use crate::{CSAMembership, DataKey};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+/// Whether the member holds a membership at the farm covering `at_timestamp`.
+/// A membership covers its start date up to, but not including, its end date.
+pub fn is_active_member(env: Env, member: Address, farm_id: BytesN<32>, at_timestamp: u64) -> bool {
+    get_member_tokens(&env, &member).iter().any(|token_id| {
+        get_membership(&env, &token_id).is_some_and(|membership| {
+            membership.farm_id == farm_id
+                && membership.start_date <= at_timestamp
+                && at_timestamp < membership.end_date
+        })
+    })
+}
+
+/// Memberships held by the member that have not ended yet, including upcoming ones.
+pub fn get_active_memberships(env: Env, member: Address) -> Vec<CSAMembership> {
+    let now = env.ledger().timestamp();
+    let mut memberships = Vec::new(&env);
+
+    for token_id in get_member_tokens(&env, &member).iter() {
+        if let Some(membership) = get_membership(&env, &token_id) {
+            if now < membership.end_date {
+                memberships.push_back(membership);
+            }
+        }
+    }
+
+    memberships
+}
+
+pub(crate) fn index_membership(env: &Env, member: &Address, token_id: &BytesN<32>) {
+    let mut tokens = get_member_tokens(env, member);
+    if !tokens.contains(token_id) {
+        tokens.push_back(token_id.clone());
+        save_member_tokens(env, member, &tokens);
+    }
+}
+
+pub(crate) fn unindex_membership(env: &Env, member: &Address, token_id: &BytesN<32>) {
+    let mut tokens = get_member_tokens(env, member);
+    if let Some(index) = tokens.first_index_of(token_id) {
+        tokens.remove(index);
+        save_member_tokens(env, member, &tokens);
+    }
+}
+
+fn get_member_tokens(env: &Env, member: &Address) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MemberTokens(member.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_member_tokens(env: &Env, member: &Address, tokens: &Vec<BytesN<32>>) {
+    let key = DataKey::MemberTokens(member.clone());
+    if tokens.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tokens);
+    }
+}
+
+fn get_membership(env: &Env, token_id: &BytesN<32>) -> Option<CSAMembership> {
+    env.storage().persistent().get(token_id)
+}
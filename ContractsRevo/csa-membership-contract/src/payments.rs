@@ -0,0 +1,422 @@
+use crate::{
+    farm::require_farm_admin, CSAMembership, DataKey, Error, Farm, MembershipPayment, ShareCredit,
+    SharePricing, ShareSize,
+};
+use soroban_sdk::{token, Address, BytesN, Env, String, Symbol, Vec};
+
+pub const SECONDS_PER_WEEK: u64 = 604_800;
+pub const FULL_REFUND_BPS: u32 = 10_000;
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_share_pricing(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    small: i128,
+    medium: i128,
+    large: i128,
+    payment_token: Address,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if small < 0 || medium < 0 || large < 0 {
+        return Err(Error::InvalidPrice);
+    }
+
+    let key = DataKey::SharePricing(farm_id.clone(), season.clone());
+
+    // Repricing keeps whatever refund policy the farm already chose
+    let refund_bps = env
+        .storage()
+        .persistent()
+        .get::<_, SharePricing>(&key)
+        .map(|pricing| pricing.refund_bps)
+        .unwrap_or(FULL_REFUND_BPS);
+
+    let pricing = SharePricing {
+        small,
+        medium,
+        large,
+        payment_token,
+        refund_bps,
+    };
+    env.storage().persistent().set(&key, &pricing);
+
+    env.events().publish(
+        (Symbol::new(&env, "share_pricing_set"), farm_id),
+        (season, pricing),
+    );
+
+    Ok(())
+}
+
+pub fn set_refund_policy(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    refund_bps: u32,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if refund_bps > FULL_REFUND_BPS {
+        return Err(Error::InvalidRefundPolicy);
+    }
+
+    let key = DataKey::SharePricing(farm_id, season);
+    let mut pricing: SharePricing = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::PricingNotSet)?;
+
+    pricing.refund_bps = refund_bps;
+    env.storage().persistent().set(&key, &pricing);
+
+    Ok(())
+}
+
+pub fn get_share_price(
+    env: Env,
+    farm_id: BytesN<32>,
+    season: String,
+    size: ShareSize,
+) -> Result<i128, Error> {
+    let pricing = get_pricing(&env, &farm_id, &season).ok_or(Error::PricingNotSet)?;
+
+    Ok(price_for(&pricing, size))
+}
+
+pub fn get_membership_payment(env: Env, token_id: BytesN<32>) -> Option<MembershipPayment> {
+    env.storage().persistent().get(&DataKey::Payment(token_id))
+}
+
+/// Releases to the farm every installment that has vested since the last claim.
+pub fn claim_season_revenue(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+) -> Result<i128, Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    let members: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SeasonMembers(farm_id.clone(), season.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let now = env.ledger().timestamp();
+    let contract = env.current_contract_address();
+    let mut total = 0i128;
+
+    for token_id in members.iter() {
+        let key = DataKey::Payment(token_id.clone());
+        let Some(mut payment) = env.storage().persistent().get::<_, MembershipPayment>(&key) else {
+            continue;
+        };
+        let Some(membership) = env
+            .storage()
+            .persistent()
+            .get::<_, CSAMembership>(&token_id)
+        else {
+            continue;
+        };
+
+        let due = vested_amount(&payment, &membership, now) - payment.released;
+        if due > 0 {
+            token::Client::new(&env, &payment.payment_token).transfer(&contract, &farm_admin, &due);
+            payment.released += due;
+            env.storage().persistent().set(&key, &payment);
+            total += due;
+        }
+    }
+
+    if total == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, "season_revenue_claimed"), farm_id),
+        (season, total),
+    );
+
+    Ok(total)
+}
+
+/// Pulls the share price, less any discount, for a new membership into escrow.
+/// Seasons without pricing stay free.
+pub(crate) fn collect_payment(
+    env: &Env,
+    token_id: &BytesN<32>,
+    membership: &CSAMembership,
+    discount_bps: u32,
+) -> Result<(), Error> {
+    let Some(pricing) = get_pricing(env, &membership.farm_id, &membership.season) else {
+        return Ok(());
+    };
+
+    let price = price_for(&pricing, membership.share_size);
+    let amount = price - price * discount_bps as i128 / FULL_REFUND_BPS as i128;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Credits earned for missed shares at this farm pay for part of the new share;
+    // those funds are already held by the contract
+    let credit_key = DataKey::ShareCredit(membership.member.clone(), membership.farm_id.clone());
+    let mut applied = 0;
+    if let Some(mut credit) = env
+        .storage()
+        .persistent()
+        .get::<_, ShareCredit>(&credit_key)
+    {
+        if credit.payment_token == pricing.payment_token {
+            applied = credit.amount.min(amount);
+            credit.amount -= applied;
+            if credit.amount == 0 {
+                env.storage().persistent().remove(&credit_key);
+            } else {
+                env.storage().persistent().set(&credit_key, &credit);
+            }
+        }
+    }
+
+    if amount > applied {
+        token::Client::new(env, &pricing.payment_token).transfer(
+            &membership.member,
+            &env.current_contract_address(),
+            &(amount - applied),
+        );
+    }
+
+    env.storage().persistent().set(
+        &DataKey::Payment(token_id.clone()),
+        &MembershipPayment {
+            payment_token: pricing.payment_token,
+            amount,
+            released: 0,
+            credited: 0,
+        },
+    );
+
+    Ok(())
+}
+
+/// Splits the escrow of a cancelled membership: the unused part of the season is refunded
+/// according to the farm's policy and everything else goes to the farm. Returns the refund.
+pub(crate) fn settle_cancellation(
+    env: &Env,
+    token_id: &BytesN<32>,
+    membership: &CSAMembership,
+) -> i128 {
+    let key = DataKey::Payment(token_id.clone());
+    let Some(payment) = env.storage().persistent().get::<_, MembershipPayment>(&key) else {
+        return 0;
+    };
+
+    let refund_bps = get_pricing(env, &membership.farm_id, &membership.season)
+        .map(|pricing| pricing.refund_bps)
+        .unwrap_or(FULL_REFUND_BPS);
+
+    let escrowed = payment.amount - payment.credited;
+    let vested = vested_amount(&payment, membership, env.ledger().timestamp());
+    let refund = ((escrowed - vested) * refund_bps as i128 / FULL_REFUND_BPS as i128)
+        .min(escrowed - payment.released);
+    let farm_share = escrowed - refund - payment.released;
+
+    let token_client = token::Client::new(env, &payment.payment_token);
+    let contract = env.current_contract_address();
+
+    if refund > 0 {
+        token_client.transfer(&contract, &membership.member, &refund);
+    }
+
+    if farm_share > 0 {
+        if let Some(farm) = env
+            .storage()
+            .persistent()
+            .get::<_, Farm>(&DataKey::Farm(membership.farm_id.clone()))
+        {
+            token_client.transfer(&contract, &farm.farm_admin, &farm_share);
+        }
+    }
+
+    env.storage().persistent().remove(&key);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "membership_refunded"),
+            membership.member.clone(),
+        ),
+        (token_id.clone(), refund),
+    );
+
+    refund
+}
+
+/// Moves one weekly installment of a membership's escrow into the member's credit at the farm.
+/// Returns the credited amount, which is zero for unpaid or fully released memberships.
+pub(crate) fn credit_missed_share(
+    env: &Env,
+    token_id: &BytesN<32>,
+    membership: &CSAMembership,
+) -> i128 {
+    let key = DataKey::Payment(token_id.clone());
+    let Some(mut payment) = env.storage().persistent().get::<_, MembershipPayment>(&key) else {
+        return 0;
+    };
+
+    let installment = payment.amount / season_weeks(membership) as i128;
+    let amount = installment.min(payment.amount - payment.credited - payment.released);
+    if amount <= 0 {
+        return 0;
+    }
+
+    payment.credited += amount;
+    env.storage().persistent().set(&key, &payment);
+
+    let credit_key = DataKey::ShareCredit(membership.member.clone(), membership.farm_id.clone());
+    let mut credit = env
+        .storage()
+        .persistent()
+        .get::<_, ShareCredit>(&credit_key)
+        .unwrap_or(ShareCredit {
+            payment_token: payment.payment_token.clone(),
+            amount: 0,
+        });
+
+    // A credit in a token the farm no longer uses is paid out instead of mixed in
+    if credit.payment_token != payment.payment_token {
+        token::Client::new(env, &payment.payment_token).transfer(
+            &env.current_contract_address(),
+            &membership.member,
+            &amount,
+        );
+        return amount;
+    }
+
+    credit.amount += amount;
+    env.storage().persistent().set(&credit_key, &credit);
+
+    amount
+}
+
+pub fn get_share_credit(env: Env, member: Address, farm_id: BytesN<32>) -> Option<ShareCredit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShareCredit(member, farm_id))
+}
+
+/// Refunds a member's unused share credit at a farm instead of applying it to a renewal.
+pub fn withdraw_share_credit(
+    env: Env,
+    member: Address,
+    farm_id: BytesN<32>,
+) -> Result<i128, Error> {
+    member.require_auth();
+
+    let key = DataKey::ShareCredit(member.clone(), farm_id.clone());
+    let credit: ShareCredit = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NothingToClaim)?;
+
+    token::Client::new(&env, &credit.payment_token).transfer(
+        &env.current_contract_address(),
+        &member,
+        &credit.amount,
+    );
+    env.storage().persistent().remove(&key);
+
+    env.events().publish(
+        (Symbol::new(&env, "share_credit_withdrawn"), member),
+        (farm_id, credit.amount),
+    );
+
+    Ok(credit.amount)
+}
+
+/// Charges or refunds the price difference of a share-size change for the weeks of the season
+/// that have not started yet. Returns the amount charged, negative when the member is refunded.
+pub(crate) fn reprice_share(
+    env: &Env,
+    token_id: &BytesN<32>,
+    membership: &CSAMembership,
+    new_size: ShareSize,
+) -> i128 {
+    let Some(pricing) = get_pricing(env, &membership.farm_id, &membership.season) else {
+        return 0;
+    };
+    let key = DataKey::Payment(token_id.clone());
+    let Some(mut payment) = env.storage().persistent().get::<_, MembershipPayment>(&key) else {
+        return 0;
+    };
+
+    let total_weeks = season_weeks(membership);
+    let remaining_weeks = total_weeks - elapsed_weeks(membership, env.ledger().timestamp());
+    let mut delta = (price_for(&pricing, new_size) - price_for(&pricing, membership.share_size))
+        * remaining_weeks as i128
+        / total_weeks as i128;
+
+    let token_client = token::Client::new(env, &payment.payment_token);
+    let contract = env.current_contract_address();
+
+    // A downgrade can only refund what is still held in escrow
+    delta = delta.max(payment.released + payment.credited - payment.amount);
+
+    if delta > 0 {
+        token_client.transfer(&membership.member, &contract, &delta);
+    } else if delta < 0 {
+        token_client.transfer(&contract, &membership.member, &-delta);
+    }
+
+    payment.amount += delta;
+    env.storage().persistent().set(&key, &payment);
+
+    delta
+}
+
+/// Number of weekly installments in a membership's season; a partial last week counts.
+pub(crate) fn season_weeks(membership: &CSAMembership) -> u64 {
+    (membership.end_date - membership.start_date).div_ceil(SECONDS_PER_WEEK)
+}
+
+fn get_pricing(env: &Env, farm_id: &BytesN<32>, season: &String) -> Option<SharePricing> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SharePricing(farm_id.clone(), season.clone()))
+}
+
+fn price_for(pricing: &SharePricing, size: ShareSize) -> i128 {
+    match size {
+        ShareSize::Small => pricing.small,
+        ShareSize::Medium => pricing.medium,
+        ShareSize::Large => pricing.large,
+    }
+}
+
+/// Escrow is released in equal weekly installments, one per completed week of the season.
+/// Credited installments never reach the farm.
+fn vested_amount(payment: &MembershipPayment, membership: &CSAMembership, now: u64) -> i128 {
+    let escrowed = payment.amount - payment.credited;
+
+    if now >= membership.end_date {
+        return escrowed;
+    }
+
+    escrowed * elapsed_weeks(membership, now) as i128 / season_weeks(membership) as i128
+}
+
+fn elapsed_weeks(membership: &CSAMembership, now: u64) -> u64 {
+    if now <= membership.start_date {
+        return 0;
+    }
+
+    ((now - membership.start_date) / SECONDS_PER_WEEK).min(season_weeks(membership))
+}
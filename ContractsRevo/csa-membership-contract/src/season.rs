@@ -0,0 +1,195 @@
+use crate::{
+    enroll::create_membership, farm::require_farm_admin, payments::FULL_REFUND_BPS, CSAMembership,
+    DataKey, Error, RenewalOffer, Season,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_season(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season_code: String,
+    enrollment_open: u64,
+    enrollment_close: u64,
+    start_date: u64,
+    end_date: u64,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if season_code.is_empty() {
+        return Err(Error::InvalidSeason);
+    }
+
+    // Enrollment may run into the season but never past its end
+    if enrollment_open >= enrollment_close || start_date >= end_date || enrollment_close > end_date
+    {
+        return Err(Error::InvalidDates);
+    }
+
+    let key = DataKey::Season(farm_id.clone(), season_code.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::SeasonAlreadyExists);
+    }
+
+    let season = Season {
+        code: season_code.clone(),
+        enrollment_open,
+        enrollment_close,
+        start_date,
+        end_date,
+    };
+    env.storage().persistent().set(&key, &season);
+
+    let seasons_key = DataKey::FarmSeasons(farm_id.clone());
+    let mut codes: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&seasons_key)
+        .unwrap_or_else(|| Vec::new(&env));
+    codes.push_back(season_code);
+    env.storage().persistent().set(&seasons_key, &codes);
+
+    env.events()
+        .publish((Symbol::new(&env, "season_created"), farm_id), season);
+
+    Ok(())
+}
+
+pub fn get_season(env: Env, farm_id: BytesN<32>, season_code: String) -> Option<Season> {
+    get_defined_season(&env, &farm_id, &season_code)
+}
+
+pub fn list_seasons(env: Env, farm_id: BytesN<32>) -> Vec<Season> {
+    let codes: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FarmSeasons(farm_id.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut seasons = Vec::new(&env);
+    for code in codes.iter() {
+        if let Some(season) = get_defined_season(&env, &farm_id, &code) {
+            seasons.push_back(season);
+        }
+    }
+
+    seasons
+}
+
+/// Lets members of `from_season` renew into `to_season` at a discount until its public
+/// enrollment opens.
+pub fn offer_renewal(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    from_season: String,
+    to_season: String,
+    discount_bps: u32,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    if discount_bps > FULL_REFUND_BPS {
+        return Err(Error::InvalidPrice);
+    }
+    if from_season == to_season {
+        return Err(Error::InvalidSeason);
+    }
+    get_defined_season(&env, &farm_id, &to_season).ok_or(Error::InvalidSeason)?;
+
+    let offer = RenewalOffer {
+        from_season,
+        discount_bps,
+    };
+    env.storage().persistent().set(
+        &DataKey::RenewalOffer(farm_id.clone(), to_season.clone()),
+        &offer,
+    );
+
+    env.events().publish(
+        (Symbol::new(&env, "renewal_offered"), farm_id),
+        (to_season, offer),
+    );
+
+    Ok(())
+}
+
+pub fn get_renewal_offer(env: Env, farm_id: BytesN<32>, to_season: String) -> Option<RenewalOffer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RenewalOffer(farm_id, to_season))
+}
+
+/// Renews an existing membership into the offered season with the same share size and
+/// pickup location. Returns the new membership's token id.
+pub fn accept_renewal(
+    env: Env,
+    member: Address,
+    token_id: BytesN<32>,
+    to_season: String,
+) -> Result<BytesN<32>, Error> {
+    member.require_auth();
+
+    let current: CSAMembership = env
+        .storage()
+        .persistent()
+        .get(&token_id)
+        .ok_or(Error::NotFound)?;
+    if current.member != member {
+        return Err(Error::NotAuthorized);
+    }
+
+    let offer: RenewalOffer = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RenewalOffer(
+            current.farm_id.clone(),
+            to_season.clone(),
+        ))
+        .ok_or(Error::RenewalNotOffered)?;
+    if offer.from_season != current.season {
+        return Err(Error::RenewalNotOffered);
+    }
+
+    let season =
+        get_defined_season(&env, &current.farm_id, &to_season).ok_or(Error::InvalidSeason)?;
+    if env.ledger().timestamp() >= season.enrollment_open {
+        return Err(Error::EnrollmentClosed);
+    }
+
+    let renewed_key = DataKey::Renewed(token_id.clone());
+    if env.storage().persistent().has(&renewed_key) {
+        return Err(Error::AlreadyRenewed);
+    }
+
+    let membership = CSAMembership {
+        farm_id: current.farm_id,
+        season: to_season,
+        share_size: current.share_size,
+        pickup_location: current.pickup_location,
+        start_date: season.start_date,
+        end_date: season.end_date,
+        member: member.clone(),
+    };
+    let new_token_id = create_membership(&env, membership, offer.discount_bps)?;
+    env.storage().persistent().set(&renewed_key, &new_token_id);
+
+    env.events().publish(
+        (Symbol::new(&env, "renewal_accepted"), member),
+        (token_id, new_token_id.clone()),
+    );
+
+    Ok(new_token_id)
+}
+
+pub(crate) fn get_defined_season(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    season_code: &String,
+) -> Option<Season> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Season(farm_id.clone(), season_code.clone()))
+}
@@ -10,6 +10,19 @@ fn create_test_env() -> (Env, Address) {
         li.timestamp = 1700000000; // Set current time to Nov 2023
     });
     let contract_id = env.register(CSAMembershipContract, ());
+
+    let client = CSAMembershipContractClient::new(&env, &contract_id);
+    let farm_admin = Address::generate(&env);
+    env.mock_all_auths();
+    for seed in [1, 2, 42] {
+        client.register_farm(
+            &farm_admin,
+            &BytesN::from_array(&env, &[seed; 32]),
+            &String::from_str(&env, "Test Farm"),
+            &String::from_str(&env, "Test Valley"),
+        );
+    }
+
     (env, contract_id)
 }
 
@@ -0,0 +1,213 @@
+use crate::{
+    board::{MANIFEST_LOCK_DELAY, MAX_ANNOUNCEMENTS},
+    distribution::MISSED_SHARE_GRACE_PERIOD,
+    payments::SECONDS_PER_WEEK,
+    tests::utils::*,
+    Error, ShareSize,
+};
+use soroban_sdk::{testutils::Ledger, vec, BytesN, Env, String};
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+fn content_hash(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn test_manifest_locked_after_delay() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let season = standard_season(env);
+    let summary = String::from_str(env, "week 12: kale, carrots, eggs");
+
+    client.publish_share_manifest(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &12,
+        &content_hash(env, 1),
+        &summary,
+    );
+
+    // Corrections are allowed until the lock delay passes
+    set_time(&test_env, 1700000000 + MANIFEST_LOCK_DELAY - 1);
+    client.publish_share_manifest(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &12,
+        &content_hash(env, 2),
+        &summary,
+    );
+
+    let manifest = client.get_share_manifest(&farm_id, &season, &12).unwrap();
+    assert_eq!(manifest.manifest_hash, content_hash(env, 2));
+    assert_eq!(manifest.summary, summary);
+    assert_eq!(manifest.published_at, 1700000000);
+
+    set_time(&test_env, 1700000000 + MANIFEST_LOCK_DELAY);
+    let result = client.try_publish_share_manifest(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &12,
+        &content_hash(env, 3),
+        &summary,
+    );
+    assert_eq!(result, Err(Ok(Error::ManifestLocked)));
+
+    let result = client.try_publish_share_manifest(
+        &test_env.member1,
+        &farm_id,
+        &season,
+        &13,
+        &content_hash(env, 3),
+        &summary,
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_distribution_log_references_manifest() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let season = standard_season(env);
+
+    let token_id = client.enroll_membership(
+        &farm_id,
+        &season,
+        &ShareSize::Medium,
+        &standard_pickup_location(env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        &test_env.member1,
+    );
+
+    for week in 1..=2 {
+        client.publish_share_manifest(
+            &test_env.admin,
+            &farm_id,
+            &season,
+            &week,
+            &content_hash(env, week as u8),
+            &String::from_str(env, "greens and eggs"),
+        );
+    }
+    client.record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &2,
+        &vec![env, token_id.clone()],
+    );
+
+    let record = client.get_distribution_log(&token_id).get(0).unwrap();
+    assert_eq!(record.week, 2);
+    assert_eq!(record.manifest_hash, Some(content_hash(env, 2)));
+
+    // A disputed week points at what was promised even though nothing was delivered
+    setup_share_pricing(&test_env, &client);
+    let paid = client.enroll_membership(
+        &farm_id,
+        &season,
+        &ShareSize::Small,
+        &standard_pickup_location(env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        &test_env.member2,
+    );
+    set_time(
+        &test_env,
+        FUTURE_START_DATE + SECONDS_PER_WEEK + MISSED_SHARE_GRACE_PERIOD,
+    );
+    let credit = client.claim_missed_share_credit(&test_env.member2, &paid, &1);
+    assert_eq!(credit, SMALL_PRICE / 13);
+
+    let record = client.get_distribution_log(&paid).get(0).unwrap();
+    assert_eq!(record.manifest_hash, Some(content_hash(env, 1)));
+}
+
+#[test]
+fn test_announcement_pagination() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+
+    for i in 0..MAX_ANNOUNCEMENTS + 2 {
+        let id = client.post_announcement(
+            &test_env.admin,
+            &farm_id,
+            &String::from_str(env, "Pickup moved to Saturday"),
+            &content_hash(env, i as u8),
+        );
+        assert_eq!(id, i + 1);
+    }
+
+    // Newest first, and the two oldest were dropped to stay within the bound
+    let page = client.list_announcements(&farm_id, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, MAX_ANNOUNCEMENTS + 2);
+    assert_eq!(page.get(2).unwrap().id, MAX_ANNOUNCEMENTS);
+
+    let last = client.list_announcements(&farm_id, &(MAX_ANNOUNCEMENTS - 1), &10);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap().id, 3);
+
+    assert_eq!(
+        client
+            .list_announcements(&farm_id, &MAX_ANNOUNCEMENTS, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_unauthorized_poster_rejected() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+
+    let result = client.try_post_announcement(
+        &test_env.member1,
+        &farm_id,
+        &String::from_str(env, "Free boxes for everyone"),
+        &content_hash(env, 1),
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // The admin of one farm cannot post on another farm's board
+    let other_farm = create_farm_id(env, 9);
+    client.register_farm(
+        &test_env.member2,
+        &other_farm,
+        &String::from_str(env, "Other Farm"),
+        &String::from_str(env, "Elsewhere"),
+    );
+    let result = client.try_post_announcement(
+        &test_env.admin,
+        &other_farm,
+        &String::from_str(env, "Hello"),
+        &content_hash(env, 1),
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let result = client.try_post_announcement(
+        &test_env.admin,
+        &farm_id,
+        &String::from_str(env, ""),
+        &content_hash(env, 1),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidContent)));
+    assert_eq!(client.list_announcements(&farm_id, &0, &10).len(), 0);
+}
@@ -0,0 +1,225 @@
+use crate::{
+    distribution::MISSED_SHARE_GRACE_PERIOD, payments::SECONDS_PER_WEEK, tests::utils::*, Error,
+    ShareSize,
+};
+use soroban_sdk::{testutils::Ledger, vec, Address, BytesN, String};
+
+fn enroll(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+    member: &Address,
+) -> BytesN<32> {
+    client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &ShareSize::Medium,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        member,
+    )
+}
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+// First moment a member may claim a credit for the given week
+fn grace_end(week: u64) -> u64 {
+    FUTURE_START_DATE + week * SECONDS_PER_WEEK + MISSED_SHARE_GRACE_PERIOD
+}
+
+#[test]
+fn test_distribution_and_pickup_confirmation() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let season = standard_season(env);
+
+    let token1 = enroll(&test_env, &client, &test_env.member1);
+    let token2 = enroll(&test_env, &client, &test_env.member2);
+
+    set_time(&test_env, FUTURE_START_DATE + 3600);
+    client.record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &1,
+        &vec![env, token1.clone(), token2.clone()],
+    );
+    client.confirm_pickup(&test_env.member1, &token1, &1);
+
+    let log = client.get_distribution_log(&token1);
+    assert_eq!(log.len(), 1);
+    let record = log.get(0).unwrap();
+    assert_eq!(record.week, 1);
+    assert_eq!(record.delivered_at, Some(FUTURE_START_DATE + 3600));
+    assert_eq!(record.confirmed_at, Some(FUTURE_START_DATE + 3600));
+    assert_eq!(record.credit, 0);
+
+    // Member 2 got the box but has not acknowledged it yet
+    let record = client.get_distribution_log(&token2).get(0).unwrap();
+    assert!(record.delivered_at.is_some());
+    assert_eq!(record.confirmed_at, None);
+
+    let result = client.try_confirm_pickup(&test_env.member1, &token1, &2);
+    assert_eq!(result, Err(Ok(Error::DistributionNotRecorded)));
+
+    let result = client.try_confirm_pickup(&test_env.member1, &token2, &1);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let result = client.try_record_distribution(
+        &test_env.member1,
+        &farm_id,
+        &season,
+        &2,
+        &vec![env, token1.clone()],
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // The standard season has 13 weeks
+    let result = client.try_record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &14,
+        &vec![env, token1.clone()],
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidWeek)));
+
+    let result = client.try_record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &String::from_str(env, "Fall 2025"),
+        &2,
+        &vec![env, token1],
+    );
+    assert_eq!(result, Err(Ok(Error::NotFound)));
+}
+
+#[test]
+fn test_missed_week_credit() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm = setup_share_pricing(&test_env, &client);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let season = standard_season(env);
+
+    let token_id = enroll(&test_env, &client, &test_env.member1);
+
+    set_time(&test_env, grace_end(1) - 1);
+    let result = client.try_claim_missed_share_credit(&test_env.member1, &token_id, &1);
+    assert_eq!(result, Err(Ok(Error::GracePeriodActive)));
+
+    client.record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &2,
+        &vec![env, token_id.clone()],
+    );
+
+    // Week 1 was never recorded, so one weekly installment becomes credit
+    set_time(&test_env, grace_end(2));
+    let credit = client.claim_missed_share_credit(&test_env.member1, &token_id, &1);
+    assert_eq!(credit, MEDIUM_PRICE / 13);
+
+    let result = client.try_claim_missed_share_credit(&test_env.member1, &token_id, &1);
+    assert_eq!(result, Err(Ok(Error::CreditAlreadyClaimed)));
+
+    let result = client.try_claim_missed_share_credit(&test_env.member1, &token_id, &2);
+    assert_eq!(result, Err(Ok(Error::ShareDelivered)));
+
+    // A late recording cannot overwrite the credited week
+    let result = client.try_record_distribution(
+        &test_env.admin,
+        &farm_id,
+        &season,
+        &1,
+        &vec![env, token_id.clone()],
+    );
+    assert_eq!(result, Err(Ok(Error::CreditAlreadyClaimed)));
+
+    let log = client.get_distribution_log(&token_id);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().credit, credit);
+
+    let balance = client
+        .get_share_credit(&test_env.member1, &farm_id)
+        .unwrap();
+    assert_eq!(balance.amount, credit);
+
+    // The credited installment is never released to the farm
+    set_time(&test_env, FUTURE_END_DATE);
+    let claimed = client.claim_season_revenue(&farm.farm_admin, &farm_id, &season);
+    assert_eq!(claimed, MEDIUM_PRICE - credit);
+    assert_eq!(farm.token.balance(&test_env.contract_id), credit);
+
+    let withdrawn = client.withdraw_share_credit(&test_env.member1, &farm_id);
+    assert_eq!(withdrawn, credit);
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - MEDIUM_PRICE + credit
+    );
+    assert!(client
+        .get_share_credit(&test_env.member1, &farm_id)
+        .is_none());
+}
+
+#[test]
+fn test_credit_applied_to_renewal() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm = setup_share_pricing(&test_env, &client);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let fall = String::from_str(env, "Fall 2025");
+
+    client.set_share_pricing(
+        &farm.farm_admin,
+        &farm_id,
+        &fall,
+        &SMALL_PRICE,
+        &MEDIUM_PRICE,
+        &LARGE_PRICE,
+        &farm.token.address,
+    );
+
+    let token_id = enroll(&test_env, &client, &test_env.member1);
+
+    set_time(&test_env, grace_end(3));
+    let first = client.claim_missed_share_credit(&test_env.member1, &token_id, &2);
+    let second = client.claim_missed_share_credit(&test_env.member1, &token_id, &3);
+    let credit = first + second;
+
+    let renewal = client.enroll_membership(
+        &farm_id,
+        &fall,
+        &ShareSize::Medium,
+        &standard_pickup_location(env),
+        &FUTURE_END_DATE,
+        &(FUTURE_END_DATE + 13 * SECONDS_PER_WEEK),
+        &test_env.member1,
+    );
+
+    // The member only pays the part of the new share not covered by credit
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - 2 * MEDIUM_PRICE + credit
+    );
+    assert_eq!(
+        client.get_membership_payment(&renewal).unwrap().amount,
+        MEDIUM_PRICE
+    );
+    assert!(client
+        .get_share_credit(&test_env.member1, &farm_id)
+        .is_none());
+
+    let result = client.try_withdraw_share_credit(&test_env.member1, &farm_id);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+}
@@ -184,7 +184,7 @@ fn test_duplicate_enrollment_same_member() {
         &test_env.member1,
     );
 
-    // Second enrollment (will overwrite first due to same token_id)
+    // Second enrollment for another season
     let token_id2 = client.enroll_membership(
         &standard_farm_id(&test_env.env),
         &String::from_str(&test_env.env, "Fall 2025"),
@@ -195,16 +195,19 @@ fn test_duplicate_enrollment_same_member() {
         &test_env.member1,
     );
 
-    // Token IDs are the same (hardcoded to zeros in enroll.rs)
-    assert_eq!(token_id1, token_id2);
+    // Both memberships are kept under their own token
+    assert_ne!(token_id1, token_id2);
 
-    // Only the latest membership data should exist
     let membership = client.get_membership_metadata(&token_id2).unwrap();
     assert_eq!(
         membership.season,
         String::from_str(&test_env.env, "Fall 2025")
     );
     assert_eq!(membership.share_size, ShareSize::Large);
+
+    let membership = client.get_membership_metadata(&token_id1).unwrap();
+    assert_eq!(membership.season, standard_season(&test_env.env));
+    assert_eq!(membership.share_size, ShareSize::Medium);
 }
 
 #[test]
@@ -234,13 +237,16 @@ fn test_membership_id_consistency() {
         &test_env.member2,
     );
 
-    // Token IDs are the same (hardcoded to zeros in enroll.rs)
-    assert_eq!(token_id1, token_id2);
+    // Token IDs are unique and never the all-zero id
+    assert_ne!(token_id1, token_id2);
+    assert_ne!(token_id1, BytesN::from_array(&test_env.env, &[0; 32]));
 
-    // Latest membership data should exist
     let membership = client.get_membership_metadata(&token_id2).unwrap();
     assert_eq!(membership.member, test_env.member2);
     assert_eq!(membership.share_size, ShareSize::Large);
+
+    let membership = client.get_membership_metadata(&token_id1).unwrap();
+    assert_eq!(membership.member, test_env.member1);
 }
 
 #[test]
@@ -270,7 +276,7 @@ fn test_high_volume_enrollment() {
 }
 
 #[test]
-fn test_enrollment_different_farms_overwrites() {
+fn test_enrollment_different_farms_separate() {
     let test_env = setup_test();
     let client = create_client(&test_env);
 
@@ -300,12 +306,16 @@ fn test_enrollment_different_farms_overwrites() {
         &test_env.member1,
     );
 
-    // Token IDs are the same, so second enrollment overwrites first
-    assert_eq!(token_id1, token_id2);
+    // The member holds one membership per farm
+    assert_ne!(token_id1, token_id2);
 
     let membership = client.get_membership_metadata(&token_id2).unwrap();
     assert_eq!(membership.farm_id, farm2);
     assert_eq!(membership.share_size, ShareSize::Large);
+
+    let membership = client.get_membership_metadata(&token_id1).unwrap();
+    assert_eq!(membership.farm_id, farm1);
+    assert_eq!(membership.share_size, ShareSize::Medium);
 }
 
 #[test]
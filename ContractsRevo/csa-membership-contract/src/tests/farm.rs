@@ -0,0 +1,164 @@
+use crate::{tests::utils::*, Error, ShareSize};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, String};
+
+fn enroll(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+    size: ShareSize,
+    member: &Address,
+) -> Result<BytesN<32>, Error> {
+    client
+        .try_enroll_membership(
+            &standard_farm_id(&test_env.env),
+            &standard_season(&test_env.env),
+            &size,
+            &standard_pickup_location(&test_env.env),
+            &FUTURE_START_DATE,
+            &FUTURE_END_DATE,
+            member,
+        )
+        .map(|token_id| token_id.unwrap())
+        .map_err(|error| error.unwrap())
+}
+
+#[test]
+fn test_register_farm() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_admin = Address::generate(&test_env.env);
+    let farm_id = create_farm_id(&test_env.env, 10);
+
+    client.register_farm(
+        &farm_admin,
+        &farm_id,
+        &String::from_str(&test_env.env, "Green Acres"),
+        &String::from_str(&test_env.env, "Hill County"),
+    );
+
+    let farm = client.get_farm(&farm_id).unwrap();
+    assert_eq!(farm.farm_admin, farm_admin);
+    assert_eq!(farm.name, String::from_str(&test_env.env, "Green Acres"));
+    assert_eq!(farm.registered_at, 1700000000);
+
+    let result = client.try_register_farm(
+        &Address::generate(&test_env.env),
+        &farm_id,
+        &String::from_str(&test_env.env, "Impostor Farm"),
+        &String::from_str(&test_env.env, "Elsewhere"),
+    );
+    assert_eq!(result, Err(Ok(Error::FarmAlreadyRegistered)));
+
+    let result = client.try_register_farm(
+        &farm_admin,
+        &create_farm_id(&test_env.env, 11),
+        &String::from_str(&test_env.env, ""),
+        &String::from_str(&test_env.env, "Hill County"),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidFarm)));
+}
+
+#[test]
+fn test_unregistered_farm_rejected() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+
+    let result = client.try_enroll_membership(
+        &create_farm_id(&test_env.env, 42),
+        &standard_season(&test_env.env),
+        &ShareSize::Medium,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        &test_env.member1,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidFarm)));
+
+    let result = client.try_set_season_capacity(
+        &test_env.admin,
+        &create_farm_id(&test_env.env, 42),
+        &standard_season(&test_env.env),
+        &1,
+        &1,
+        &1,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidFarm)));
+}
+
+#[test]
+fn test_capacity_exhausted_per_size() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    // Uncapped seasons report unlimited availability
+    assert_eq!(
+        client.get_season_availability(&farm_id, &season),
+        (u32::MAX, u32::MAX, u32::MAX)
+    );
+
+    let result = client.try_set_season_capacity(&test_env.member1, &farm_id, &season, &5, &5, &5);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.set_season_capacity(&test_env.admin, &farm_id, &season, &2, &1, &0);
+
+    enroll(&test_env, &client, ShareSize::Medium, &test_env.member1).unwrap();
+    let result = enroll(&test_env, &client, ShareSize::Medium, &test_env.member2);
+    assert_eq!(result, Err(Error::SeasonFull));
+
+    let result = enroll(&test_env, &client, ShareSize::Large, &test_env.member2);
+    assert_eq!(result, Err(Error::SeasonFull));
+
+    // Other sizes still have room
+    enroll(&test_env, &client, ShareSize::Small, &test_env.member2).unwrap();
+    assert_eq!(client.get_season_availability(&farm_id, &season), (1, 0, 0));
+
+    // Raising a cap keeps the shares already sold
+    client.set_season_capacity(&test_env.admin, &farm_id, &season, &2, &3, &0);
+    assert_eq!(client.get_season_availability(&farm_id, &season), (1, 2, 0));
+}
+
+#[test]
+fn test_capacity_restored_on_cancel() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    client.set_season_capacity(&test_env.admin, &farm_id, &season, &0, &0, &1);
+
+    let token_id = enroll(&test_env, &client, ShareSize::Large, &test_env.member1).unwrap();
+    assert_eq!(client.get_season_availability(&farm_id, &season), (0, 0, 0));
+
+    client.cancel_membership(&token_id, &test_env.member1);
+    assert_eq!(client.get_season_availability(&farm_id, &season), (0, 0, 1));
+
+    enroll(&test_env, &client, ShareSize::Large, &test_env.member2).unwrap();
+}
+
+#[test]
+fn test_list_farm_members() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    let first = enroll(&test_env, &client, ShareSize::Small, &test_env.member1).unwrap();
+    let second = enroll(&test_env, &client, ShareSize::Medium, &test_env.member2).unwrap();
+    let third = enroll(&test_env, &client, ShareSize::Large, &test_env.member3).unwrap();
+
+    let page = client.list_farm_members(&farm_id, &season, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().0, first);
+    assert_eq!(page.get(1).unwrap().1.member, test_env.member2);
+
+    client.cancel_membership(&second, &test_env.member2);
+
+    let page = client.list_farm_members(&farm_id, &season, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().0, third);
+
+    // Members of other seasons are not listed
+    let fall = String::from_str(&test_env.env, "Fall 2025");
+    assert_eq!(client.list_farm_members(&farm_id, &fall, &0, &10).len(), 0);
+}
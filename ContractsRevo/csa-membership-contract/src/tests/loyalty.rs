@@ -0,0 +1,217 @@
+use crate::{tests::utils::*, Error, LoyaltyTokenContract, ShareSize};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String, Symbol,
+};
+
+// Stands in for the loyalty token contract, keeping a running total per user
+#[contract]
+pub struct MockLoyaltyContract;
+
+#[contractimpl]
+impl LoyaltyTokenContract for MockLoyaltyContract {
+    fn award_points(
+        env: Env,
+        _program_id: BytesN<32>,
+        _awarder: Address,
+        user_address: Address,
+        transaction_amount: u32,
+    ) {
+        if env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "failing"))
+            .unwrap_or(false)
+        {
+            panic!("Program not found");
+        }
+
+        let points: u32 = env.storage().instance().get(&user_address).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&user_address, &(points + transaction_amount));
+    }
+}
+
+#[contractimpl]
+impl MockLoyaltyContract {
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "failing"), &failing);
+    }
+
+    pub fn points(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+const POINTS_PER_ENROLLMENT: u32 = 25;
+
+fn enroll(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+    member: &Address,
+) -> BytesN<32> {
+    client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &ShareSize::Medium,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        member,
+    )
+}
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+fn setup_loyalty_hook<'a>(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+) -> MockLoyaltyContractClient<'a> {
+    let loyalty_id = test_env.env.register(MockLoyaltyContract, ());
+    client.set_loyalty_hook(
+        &test_env.admin,
+        &standard_farm_id(&test_env.env),
+        &loyalty_id,
+        &BytesN::from_array(&test_env.env, &[7; 32]),
+        &POINTS_PER_ENROLLMENT,
+    );
+
+    MockLoyaltyContractClient::new(&test_env.env, &loyalty_id)
+}
+
+#[test]
+fn test_active_member_boundaries() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let member = &test_env.member1;
+
+    assert!(!client.is_active_member(member, &farm_id, &FUTURE_START_DATE));
+
+    let token_id = enroll(&test_env, &client, member);
+
+    assert!(!client.is_active_member(member, &farm_id, &(FUTURE_START_DATE - 1)));
+    assert!(client.is_active_member(member, &farm_id, &FUTURE_START_DATE));
+    assert!(client.is_active_member(member, &farm_id, &(FUTURE_END_DATE - 1)));
+    assert!(!client.is_active_member(member, &farm_id, &FUTURE_END_DATE));
+
+    // Membership at one farm says nothing about another
+    let other_farm = create_farm_id(&test_env.env, 2);
+    assert!(!client.is_active_member(member, &other_farm, &FUTURE_START_DATE));
+    assert!(!client.is_active_member(&test_env.member2, &farm_id, &FUTURE_START_DATE));
+
+    // Upcoming memberships are listed until the season ends
+    assert_eq!(client.get_active_memberships(member).len(), 1);
+    set_time(&test_env, FUTURE_END_DATE);
+    assert_eq!(client.get_active_memberships(member).len(), 0);
+
+    client.cancel_membership(&token_id, member);
+    assert!(!client.is_active_member(member, &farm_id, &FUTURE_START_DATE));
+}
+
+#[test]
+fn test_member_index_follows_transfers_and_cancellations() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+
+    let first = enroll(&test_env, &client, &test_env.member1);
+    let second = enroll(&test_env, &client, &test_env.member1);
+    assert_eq!(client.get_active_memberships(&test_env.member1).len(), 2);
+
+    client.transfer_membership(&test_env.member1, &first, &test_env.member2);
+
+    assert_eq!(client.get_active_memberships(&test_env.member1).len(), 1);
+    let received = client.get_active_memberships(&test_env.member2);
+    assert_eq!(received.len(), 1);
+    assert_eq!(received.get(0).unwrap().member, test_env.member2);
+    assert!(client.is_active_member(&test_env.member2, &farm_id, &FUTURE_START_DATE));
+
+    client.cancel_membership(&second, &test_env.member1);
+    assert_eq!(client.get_active_memberships(&test_env.member1).len(), 0);
+    assert!(!client.is_active_member(&test_env.member1, &farm_id, &FUTURE_START_DATE));
+    assert!(client.is_active_member(&test_env.member2, &farm_id, &FUTURE_START_DATE));
+}
+
+#[test]
+fn test_loyalty_points_awarded_once_per_enrollment() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let loyalty = setup_loyalty_hook(&test_env, &client);
+
+    let hook = client
+        .get_loyalty_hook(&standard_farm_id(&test_env.env))
+        .unwrap();
+    assert_eq!(hook.points_per_enrollment, POINTS_PER_ENROLLMENT);
+
+    let token_id = enroll(&test_env, &client, &test_env.member1);
+    assert_eq!(loyalty.points(&test_env.member1), POINTS_PER_ENROLLMENT);
+
+    // Retrying or transferring never awards the same enrollment twice
+    assert!(!client.award_enrollment_points(&token_id));
+    client.transfer_membership(&test_env.member1, &token_id, &test_env.member2);
+    assert!(!client.award_enrollment_points(&token_id));
+    assert_eq!(loyalty.points(&test_env.member1), POINTS_PER_ENROLLMENT);
+    assert_eq!(loyalty.points(&test_env.member2), 0);
+
+    // Points are earned once per season, however often the member enrolls
+    let second = enroll(&test_env, &client, &test_env.member1);
+    client.cancel_membership(&second, &test_env.member1);
+    enroll(&test_env, &client, &test_env.member1);
+    assert_eq!(loyalty.points(&test_env.member1), POINTS_PER_ENROLLMENT);
+
+    client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &String::from_str(&test_env.env, "Fall 2025"),
+        &ShareSize::Medium,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        &test_env.member1,
+    );
+    assert_eq!(loyalty.points(&test_env.member1), 2 * POINTS_PER_ENROLLMENT);
+
+    // Only the farm's admin can point its enrollments at a loyalty program
+    let result = client.try_set_loyalty_hook(
+        &Address::generate(&test_env.env),
+        &standard_farm_id(&test_env.env),
+        &loyalty.address,
+        &BytesN::from_array(&test_env.env, &[7; 32]),
+        &POINTS_PER_ENROLLMENT,
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_failed_loyalty_award_can_be_retried() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+
+    let unhooked = enroll(&test_env, &client, &test_env.member2);
+    let result = client.try_award_enrollment_points(&unhooked);
+    assert_eq!(result, Err(Ok(Error::LoyaltyHookNotSet)));
+
+    let loyalty = setup_loyalty_hook(&test_env, &client);
+    loyalty.set_failing(&true);
+
+    // A failing loyalty contract does not block the enrollment
+    let token_id = enroll(&test_env, &client, &test_env.member1);
+    assert_eq!(loyalty.points(&test_env.member1), 0);
+
+    let result = client.try_award_enrollment_points(&token_id);
+    assert_eq!(result, Err(Ok(Error::LoyaltyAwardFailed)));
+
+    loyalty.set_failing(&false);
+    assert!(client.award_enrollment_points(&token_id));
+    assert!(!client.award_enrollment_points(&token_id));
+    assert_eq!(loyalty.points(&test_env.member1), POINTS_PER_ENROLLMENT);
+}
@@ -1,4 +1,11 @@
 pub mod benefits;
+pub mod board;
+pub mod distribution;
 pub mod enrollment;
+pub mod farm;
+pub mod loyalty;
+pub mod payments;
+pub mod season;
 pub mod subscription;
+pub mod transfer;
 pub mod utils;
@@ -0,0 +1,199 @@
+use crate::{payments::SECONDS_PER_WEEK, tests::utils::*, Error, ShareSize};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, String,
+};
+
+fn enroll(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+    size: ShareSize,
+    member: &Address,
+) -> BytesN<32> {
+    client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &size,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        member,
+    )
+}
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_enrollment_pays_share_price_into_escrow() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    test_env.env.mock_all_auths();
+    let farm = setup_share_pricing(&test_env, &client);
+
+    let price = client.get_share_price(
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &ShareSize::Large,
+    );
+    assert_eq!(price, LARGE_PRICE);
+
+    let token_id = enroll(&test_env, &client, ShareSize::Medium, &test_env.member1);
+
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - MEDIUM_PRICE
+    );
+    assert_eq!(farm.token.balance(&test_env.contract_id), MEDIUM_PRICE);
+
+    let payment = client.get_membership_payment(&token_id).unwrap();
+    assert_eq!(payment.amount, MEDIUM_PRICE);
+    assert_eq!(payment.released, 0);
+
+    // Seasons the farm has not priced remain free to join
+    let free_token = client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &String::from_str(&test_env.env, "Fall 2025"),
+        &ShareSize::Large,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        &test_env.member2,
+    );
+    assert!(client.get_membership_payment(&free_token).is_none());
+    assert_eq!(farm.token.balance(&test_env.member2), SUBSCRIPTION_AMOUNT);
+}
+
+#[test]
+fn test_mid_season_cancellation_refund() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    test_env.env.mock_all_auths();
+    let farm = setup_share_pricing(&test_env, &client);
+
+    // Cancelling before the season starts refunds everything
+    let early = enroll(&test_env, &client, ShareSize::Small, &test_env.member1);
+    client.cancel_membership(&early, &test_env.member1);
+    assert_eq!(farm.token.balance(&test_env.member1), SUBSCRIPTION_AMOUNT);
+
+    let full = enroll(&test_env, &client, ShareSize::Medium, &test_env.member2);
+
+    // Four full weeks in: 4 of 13 installments belong to the farm
+    set_time(&test_env, FUTURE_START_DATE + 4 * SECONDS_PER_WEEK + 3600);
+    client.cancel_membership(&full, &test_env.member2);
+
+    assert_eq!(
+        farm.token.balance(&test_env.member2),
+        SUBSCRIPTION_AMOUNT - 400
+    );
+    assert_eq!(farm.token.balance(&farm.farm_admin), 400);
+    assert!(client.get_membership_payment(&full).is_none());
+
+    // With a 50% policy only half of the unused season comes back
+    set_time(&test_env, 1700000000);
+    client.set_refund_policy(
+        &farm.farm_admin,
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &5_000,
+    );
+    let half = enroll(&test_env, &client, ShareSize::Medium, &test_env.member3);
+
+    set_time(&test_env, FUTURE_START_DATE + 4 * SECONDS_PER_WEEK);
+    client.cancel_membership(&half, &test_env.member3);
+
+    assert_eq!(
+        farm.token.balance(&test_env.member3),
+        SUBSCRIPTION_AMOUNT - MEDIUM_PRICE + 450
+    );
+    assert_eq!(farm.token.balance(&farm.farm_admin), 400 + 850);
+    assert_eq!(farm.token.balance(&test_env.contract_id), 0);
+}
+
+#[test]
+fn test_season_revenue_claims_over_time() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    test_env.env.mock_all_auths();
+    let farm = setup_share_pricing(&test_env, &client);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    let medium = enroll(&test_env, &client, ShareSize::Medium, &test_env.member1);
+    enroll(&test_env, &client, ShareSize::Small, &test_env.member2);
+
+    let result = client.try_claim_season_revenue(&farm.farm_admin, &farm_id, &season);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+
+    // Two weekly installments of 100 + 50
+    set_time(&test_env, FUTURE_START_DATE + 2 * SECONDS_PER_WEEK);
+    let claimed = client.claim_season_revenue(&farm.farm_admin, &farm_id, &season);
+    assert_eq!(claimed, 300);
+    assert_eq!(farm.token.balance(&farm.farm_admin), 300);
+
+    let result = client.try_claim_season_revenue(&farm.farm_admin, &farm_id, &season);
+    assert_eq!(result, Err(Ok(Error::NothingToClaim)));
+
+    let result = client.try_claim_season_revenue(&test_env.member1, &farm_id, &season);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    // Everything is released once the season ends
+    set_time(&test_env, FUTURE_END_DATE);
+    let claimed = client.claim_season_revenue(&farm.farm_admin, &farm_id, &season);
+    assert_eq!(claimed, MEDIUM_PRICE + SMALL_PRICE - 300);
+    assert_eq!(farm.token.balance(&test_env.contract_id), 0);
+
+    // Nothing is left to refund after the season
+    client.cancel_membership(&medium, &test_env.member1);
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - MEDIUM_PRICE
+    );
+}
+
+#[test]
+fn test_share_pricing_validation() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    test_env.env.mock_all_auths();
+    let farm = setup_share_pricing(&test_env, &client);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+    let fall = String::from_str(&test_env.env, "Fall 2025");
+
+    let result = client.try_set_share_pricing(
+        &farm.farm_admin,
+        &farm_id,
+        &fall,
+        &-1,
+        &MEDIUM_PRICE,
+        &LARGE_PRICE,
+        &farm.token.address,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPrice)));
+
+    // Only the farm's admin can price its seasons
+    let result = client.try_set_share_pricing(
+        &Address::generate(&test_env.env),
+        &farm_id,
+        &fall,
+        &SMALL_PRICE,
+        &MEDIUM_PRICE,
+        &LARGE_PRICE,
+        &farm.token.address,
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let result = client.try_set_refund_policy(&farm.farm_admin, &farm_id, &season, &10_001);
+    assert_eq!(result, Err(Ok(Error::InvalidRefundPolicy)));
+
+    let result = client.try_set_refund_policy(&farm.farm_admin, &farm_id, &fall, &5_000);
+    assert_eq!(result, Err(Ok(Error::PricingNotSet)));
+
+    let result = client.try_get_share_price(&farm_id, &fall, &ShareSize::Small);
+    assert_eq!(result, Err(Ok(Error::PricingNotSet)));
+}
@@ -0,0 +1,203 @@
+use crate::{payments::SECONDS_PER_WEEK, tests::utils::*, Error, ShareSize};
+use soroban_sdk::{testutils::Ledger, String};
+
+const NOW: u64 = 1700000000;
+const SPRING_OPEN: u64 = NOW + 1_000;
+const SPRING_START: u64 = NOW + 20_000;
+const SPRING_CLOSE: u64 = SPRING_START + SECONDS_PER_WEEK;
+const SPRING_END: u64 = SPRING_START + 13 * SECONDS_PER_WEEK;
+
+// Fall follows the standard season; its public enrollment opens two weeks before it starts
+const FALL_OPEN: u64 = FUTURE_END_DATE - 2 * SECONDS_PER_WEEK;
+const FALL_END: u64 = FUTURE_END_DATE + 13 * SECONDS_PER_WEEK;
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+fn create_spring(test_env: &TestEnv, client: &crate::CSAMembershipContractClient) -> String {
+    let spring = String::from_str(&test_env.env, "Spring 2026");
+    client.create_season(
+        &test_env.admin,
+        &standard_farm_id(&test_env.env),
+        &spring,
+        &SPRING_OPEN,
+        &SPRING_CLOSE,
+        &SPRING_START,
+        &SPRING_END,
+    );
+    spring
+}
+
+#[test]
+fn test_enrollment_outside_window_rejected() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let spring = create_spring(&test_env, &client);
+
+    let enroll = |start_date: u64| {
+        client.try_enroll_membership(
+            &farm_id,
+            &spring,
+            &ShareSize::Small,
+            &standard_pickup_location(&test_env.env),
+            &start_date,
+            &SPRING_END,
+            &test_env.member1,
+        )
+    };
+
+    assert_eq!(enroll(SPRING_START), Err(Ok(Error::EnrollmentClosed)));
+
+    set_time(&test_env, SPRING_OPEN);
+    assert_eq!(enroll(SPRING_START + 1), Err(Ok(Error::InvalidDates)));
+    assert!(enroll(SPRING_START).is_ok());
+
+    // Late enrollment into a running season is fine while the window is open
+    set_time(&test_env, SPRING_START + 100);
+    assert!(enroll(SPRING_START).is_ok());
+
+    set_time(&test_env, SPRING_CLOSE);
+    assert_eq!(enroll(SPRING_START), Err(Ok(Error::EnrollmentClosed)));
+}
+
+#[test]
+fn test_season_window_ordering() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let code = String::from_str(&test_env.env, "Winter 2026");
+
+    let create = |open: u64, close: u64, start: u64, end: u64| {
+        client.try_create_season(
+            &test_env.admin,
+            &farm_id,
+            &code,
+            &open,
+            &close,
+            &start,
+            &end,
+        )
+    };
+
+    // Window closes before it opens
+    assert_eq!(
+        create(SPRING_CLOSE, SPRING_OPEN, SPRING_START, SPRING_END),
+        Err(Ok(Error::InvalidDates))
+    );
+    // Season ends before it starts
+    assert_eq!(
+        create(SPRING_OPEN, SPRING_CLOSE, SPRING_END, SPRING_START),
+        Err(Ok(Error::InvalidDates))
+    );
+    // Enrollment stays open after the season ended
+    assert_eq!(
+        create(SPRING_OPEN, SPRING_END + 1, SPRING_START, SPRING_END),
+        Err(Ok(Error::InvalidDates))
+    );
+
+    let result = client.try_create_season(
+        &test_env.member1,
+        &farm_id,
+        &code,
+        &SPRING_OPEN,
+        &SPRING_CLOSE,
+        &SPRING_START,
+        &SPRING_END,
+    );
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    create(SPRING_OPEN, SPRING_CLOSE, SPRING_START, SPRING_END)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        create(SPRING_OPEN, SPRING_CLOSE, SPRING_START, SPRING_END),
+        Err(Ok(Error::SeasonAlreadyExists))
+    );
+
+    let season = client.get_season(&farm_id, &code).unwrap();
+    assert_eq!(season.enrollment_close, SPRING_CLOSE);
+    assert_eq!(client.list_seasons(&farm_id).len(), 1);
+}
+
+#[test]
+fn test_early_renewal_discount() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm = setup_share_pricing(&test_env, &client);
+    let env = &test_env.env;
+    let farm_id = standard_farm_id(env);
+    let summer = standard_season(env);
+    let fall = String::from_str(env, "Fall 2025");
+
+    client.create_season(
+        &farm.farm_admin,
+        &farm_id,
+        &fall,
+        &FALL_OPEN,
+        &FUTURE_END_DATE,
+        &FUTURE_END_DATE,
+        &FALL_END,
+    );
+    client.set_share_pricing(
+        &farm.farm_admin,
+        &farm_id,
+        &fall,
+        &SMALL_PRICE,
+        &MEDIUM_PRICE,
+        &LARGE_PRICE,
+        &farm.token.address,
+    );
+
+    let enroll_summer = |member| {
+        client.enroll_membership(
+            &farm_id,
+            &summer,
+            &ShareSize::Medium,
+            &standard_pickup_location(env),
+            &FUTURE_START_DATE,
+            &FUTURE_END_DATE,
+            member,
+        )
+    };
+    let token1 = enroll_summer(&test_env.member1);
+    let token2 = enroll_summer(&test_env.member2);
+
+    let result = client.try_accept_renewal(&test_env.member1, &token1, &fall);
+    assert_eq!(result, Err(Ok(Error::RenewalNotOffered)));
+
+    client.offer_renewal(&farm.farm_admin, &farm_id, &summer, &fall, &1_000);
+
+    let renewed = client.accept_renewal(&test_env.member1, &token1, &fall);
+    let membership = client.get_membership_metadata(&renewed).unwrap();
+    assert_eq!(membership.season, fall);
+    assert_eq!(membership.share_size, ShareSize::Medium);
+    assert_eq!(membership.start_date, FUTURE_END_DATE);
+
+    // 10% off the fall price
+    let discounted = MEDIUM_PRICE * 9 / 10;
+    assert_eq!(
+        client.get_membership_payment(&renewed).unwrap().amount,
+        discounted
+    );
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - MEDIUM_PRICE - discounted
+    );
+
+    let result = client.try_accept_renewal(&test_env.member1, &token1, &fall);
+    assert_eq!(result, Err(Ok(Error::AlreadyRenewed)));
+
+    // Once public enrollment opens the early renewal is over
+    set_time(&test_env, FALL_OPEN);
+    let result = client.try_accept_renewal(&test_env.member2, &token2, &fall);
+    assert_eq!(result, Err(Ok(Error::EnrollmentClosed)));
+
+    // Memberships from other seasons are not eligible
+    let result = client.try_accept_renewal(&test_env.member1, &renewed, &fall);
+    assert_eq!(result, Err(Ok(Error::RenewalNotOffered)));
+}
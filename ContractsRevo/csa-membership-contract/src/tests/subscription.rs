@@ -143,7 +143,7 @@ fn test_subscription_with_long_period() {
 }
 
 #[test]
-fn test_multiple_subscriptions_overwrites() {
+fn test_multiple_subscriptions_separate() {
     let test_env = setup_test();
     let client = create_client(&test_env);
 
@@ -160,7 +160,7 @@ fn test_multiple_subscriptions_overwrites() {
         &test_env.member1,
     );
 
-    // Second subscription for different season (will overwrite)
+    // Second subscription for different season
     let token_id2 = client.enroll_membership(
         &standard_farm_id(&test_env.env),
         &String::from_str(&test_env.env, "Fall 2025"),
@@ -171,10 +171,12 @@ fn test_multiple_subscriptions_overwrites() {
         &test_env.member1,
     );
 
-    // Token IDs are the same
-    assert_eq!(token_id1, token_id2);
+    assert_ne!(token_id1, token_id2);
+
+    // Both subscriptions exist side by side
+    let membership = client.get_membership_metadata(&token_id1).unwrap();
+    assert_eq!(membership.share_size, ShareSize::Small);
 
-    // Only latest subscription data exists
     let membership = client.get_membership_metadata(&token_id2).unwrap();
     assert_eq!(
         membership.season,
@@ -268,8 +270,9 @@ fn test_subscription_renewal_workflow() {
         &test_env.member1,
     );
 
-    // Token IDs are the same
-    assert_eq!(token_id1, token_id2);
+    // The renewal is a new membership token
+    assert_ne!(token_id1, token_id2);
+    assert!(client.get_membership_metadata(&token_id1).is_none());
     // New subscription should exist with updated data
     let membership = client.get_membership_metadata(&token_id2).unwrap();
     assert_eq!(
@@ -345,11 +348,16 @@ fn test_subscription_multiple_share_sizes() {
         &test_env.member3,
     );
 
-    // All token IDs are the same, last enrollment wins
-    assert_eq!(token_small, token_medium);
-    assert_eq!(token_medium, token_large);
+    assert_ne!(token_small, token_medium);
+    assert_ne!(token_medium, token_large);
+
+    let membership_small = client.get_membership_metadata(&token_small).unwrap();
+    assert_eq!(membership_small.share_size, ShareSize::Small);
+    assert_eq!(membership_small.member, test_env.member1);
+
+    let membership_medium = client.get_membership_metadata(&token_medium).unwrap();
+    assert_eq!(membership_medium.share_size, ShareSize::Medium);
 
-    // Only the last membership exists
     let membership_large = client.get_membership_metadata(&token_large).unwrap();
     assert_eq!(membership_large.share_size, ShareSize::Large);
     assert_eq!(membership_large.member, test_env.member3);
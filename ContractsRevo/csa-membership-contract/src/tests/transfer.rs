@@ -0,0 +1,147 @@
+use crate::{payments::SECONDS_PER_WEEK, tests::utils::*, Error, ShareSize};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+fn enroll(
+    test_env: &TestEnv,
+    client: &crate::CSAMembershipContractClient,
+    size: ShareSize,
+    member: &Address,
+) -> BytesN<32> {
+    client.enroll_membership(
+        &standard_farm_id(&test_env.env),
+        &standard_season(&test_env.env),
+        &size,
+        &standard_pickup_location(&test_env.env),
+        &FUTURE_START_DATE,
+        &FUTURE_END_DATE,
+        member,
+    )
+}
+
+fn set_time(test_env: &TestEnv, timestamp: u64) {
+    test_env
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_transfer_requires_both_parties() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm = setup_share_pricing(&test_env, &client);
+
+    let token_id = enroll(&test_env, &client, ShareSize::Medium, &test_env.member1);
+    client.transfer_membership(&test_env.member1, &token_id, &test_env.member2);
+
+    let auths = test_env.env.auths();
+    assert!(auths
+        .iter()
+        .any(|(address, _)| *address == test_env.member1));
+    assert!(auths
+        .iter()
+        .any(|(address, _)| *address == test_env.member2));
+
+    let membership = client.get_membership_metadata(&token_id).unwrap();
+    assert_eq!(membership.member, test_env.member2);
+
+    // The previous holder has no say over the membership anymore
+    let result = client.try_cancel_membership(&token_id, &test_env.member1);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    let result = client.try_transfer_membership(&test_env.member2, &token_id, &test_env.member2);
+    assert_eq!(result, Err(Ok(Error::InvalidTransfer)));
+
+    // The escrow follows the token, so the refund goes to the new holder
+    client.cancel_membership(&token_id, &test_env.member2);
+    assert_eq!(
+        farm.token.balance(&test_env.member2),
+        SUBSCRIPTION_AMOUNT + MEDIUM_PRICE
+    );
+}
+
+#[test]
+fn test_transfer_disabled_by_policy() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    let token_id = enroll(&test_env, &client, ShareSize::Small, &test_env.member1);
+
+    let result = client.try_set_transfers_enabled(&test_env.member1, &farm_id, &season, &false);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+
+    client.set_transfers_enabled(&test_env.admin, &farm_id, &season, &false);
+    let result = client.try_transfer_membership(&test_env.member1, &token_id, &test_env.member2);
+    assert_eq!(result, Err(Ok(Error::TransferDisabled)));
+
+    client.set_transfers_enabled(&test_env.admin, &farm_id, &season, &true);
+    client.transfer_membership(&test_env.member1, &token_id, &test_env.member2);
+}
+
+#[test]
+fn test_upgrade_charges_prorated_delta() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm = setup_share_pricing(&test_env, &client);
+
+    let token_id = enroll(&test_env, &client, ShareSize::Small, &test_env.member1);
+
+    // 10 of 13 weeks remain, so the upgrade costs 10/13 of the price difference
+    set_time(&test_env, FUTURE_START_DATE + 3 * SECONDS_PER_WEEK + 3600);
+    let charged = client.change_share_size(&test_env.member1, &token_id, &ShareSize::Medium);
+    assert_eq!(charged, (MEDIUM_PRICE - SMALL_PRICE) * 10 / 13);
+    assert_eq!(
+        farm.token.balance(&test_env.member1),
+        SUBSCRIPTION_AMOUNT - SMALL_PRICE - charged
+    );
+    assert_eq!(
+        client.get_membership_payment(&token_id).unwrap().amount,
+        SMALL_PRICE + charged
+    );
+    assert_eq!(
+        client
+            .get_membership_metadata(&token_id)
+            .unwrap()
+            .share_size,
+        ShareSize::Medium
+    );
+
+    // Going back down with 5 weeks left refunds the difference for those weeks
+    set_time(&test_env, FUTURE_START_DATE + 8 * SECONDS_PER_WEEK);
+    let charged = client.change_share_size(&test_env.member1, &token_id, &ShareSize::Small);
+    assert_eq!(charged, -(MEDIUM_PRICE - SMALL_PRICE) * 5 / 13);
+
+    let result = client.try_change_share_size(&test_env.member1, &token_id, &ShareSize::Small);
+    assert_eq!(result, Err(Ok(Error::InvalidShareSize)));
+
+    let result = client.try_change_share_size(&test_env.member2, &token_id, &ShareSize::Large);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_capacity_blocks_upgrade() {
+    let test_env = setup_test();
+    let client = create_client(&test_env);
+    let farm_id = standard_farm_id(&test_env.env);
+    let season = standard_season(&test_env.env);
+
+    client.set_season_capacity(&test_env.admin, &farm_id, &season, &5, &0, &1);
+    let token_id = enroll(&test_env, &client, ShareSize::Small, &test_env.member1);
+
+    let result = client.try_change_share_size(&test_env.member1, &token_id, &ShareSize::Medium);
+    assert_eq!(result, Err(Ok(Error::SeasonFull)));
+    assert_eq!(client.get_season_availability(&farm_id, &season), (4, 0, 1));
+
+    // Moving to a size with room frees the old share
+    client.change_share_size(&test_env.member1, &token_id, &ShareSize::Large);
+    assert_eq!(client.get_season_availability(&farm_id, &season), (5, 0, 0));
+
+    let other = Address::generate(&test_env.env);
+    let result = client.try_change_share_size(&other, &token_id, &ShareSize::Small);
+    assert_eq!(result, Err(Ok(Error::NotAuthorized)));
+}
@@ -1,7 +1,7 @@
 use crate::{CSAMembershipContract, CSAMembershipContractClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    Address, BytesN, Env, String,
+    token, Address, BytesN, Env, String,
 };
 
 pub struct TestEnv {
@@ -25,6 +25,18 @@ pub fn setup_test() -> TestEnv {
     let member2 = Address::generate(&env);
     let member3 = Address::generate(&env);
 
+    // Farms 1-5 are registered up front, administered by `admin`
+    let client = CSAMembershipContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    for seed in 1..=5 {
+        client.register_farm(
+            &admin,
+            &create_farm_id(&env, seed),
+            &String::from_str(&env, "Test Farm"),
+            &String::from_str(&env, "Test Valley"),
+        );
+    }
+
     TestEnv {
         env,
         contract_id,
@@ -61,6 +73,48 @@ pub const FUTURE_START_DATE: u64 = 1735689600; // Jan 1, 2025
 pub const FUTURE_END_DATE: u64 = 1743465600; // Apr 1, 2025
 pub const SUBSCRIPTION_AMOUNT: i128 = 10_000_000; // 10 tokens
 
+// The standard season spans 13 weeks, so these prices release 50/100/200 per week
+pub const SMALL_PRICE: i128 = 650;
+pub const MEDIUM_PRICE: i128 = 1300;
+pub const LARGE_PRICE: i128 = 2600;
+
+pub struct PricedFarm<'a> {
+    pub farm_admin: Address,
+    pub token: token::Client<'a>,
+}
+
+/// Prices the standard farm and season and funds every test member with `SUBSCRIPTION_AMOUNT`.
+pub fn setup_share_pricing<'a>(
+    test_env: &TestEnv,
+    client: &CSAMembershipContractClient,
+) -> PricedFarm<'a> {
+    let env = &test_env.env;
+    let farm_admin = test_env.admin.clone();
+    let token_address = env
+        .register_stellar_asset_contract_v2(test_env.admin.clone())
+        .address();
+
+    let minter = token::StellarAssetClient::new(env, &token_address);
+    for member in [&test_env.member1, &test_env.member2, &test_env.member3] {
+        minter.mint(member, &SUBSCRIPTION_AMOUNT);
+    }
+
+    client.set_share_pricing(
+        &farm_admin,
+        &standard_farm_id(env),
+        &standard_season(env),
+        &SMALL_PRICE,
+        &MEDIUM_PRICE,
+        &LARGE_PRICE,
+        &token_address,
+    );
+
+    PricedFarm {
+        farm_admin,
+        token: token::Client::new(env, &token_address),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
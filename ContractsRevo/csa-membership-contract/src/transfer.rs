@@ -0,0 +1,123 @@
+use crate::{
+    farm::{release_share, require_farm_admin, reserve_share},
+    member::{index_membership, unindex_membership},
+    payments::reprice_share,
+    CSAMembership, DataKey, Error, ShareSize,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol};
+
+pub fn transfer_membership(
+    env: Env,
+    member: Address,
+    token_id: BytesN<32>,
+    new_member: Address,
+) -> Result<(), Error> {
+    // Checked before auth, as one address cannot authorize the same call twice
+    if new_member == member {
+        return Err(Error::InvalidTransfer);
+    }
+    member.require_auth();
+    new_member.require_auth();
+
+    let mut membership = get_membership(&env, &token_id)?;
+    if membership.member != member {
+        return Err(Error::NotAuthorized);
+    }
+    if !transfers_enabled(&env, &membership.farm_id, &membership.season) {
+        return Err(Error::TransferDisabled);
+    }
+
+    // The escrow and distribution log follow the token, so only the holder changes
+    membership.member = new_member.clone();
+    env.storage().persistent().set(&token_id, &membership);
+    unindex_membership(&env, &member, &token_id);
+    index_membership(&env, &new_member, &token_id);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "membership_transferred"),
+            member,
+            new_member,
+        ),
+        token_id,
+    );
+
+    Ok(())
+}
+
+/// Moves a membership to another share size, charging or refunding the prorated price
+/// difference for the rest of the season. Returns the amount charged (negative if refunded).
+pub fn change_share_size(
+    env: Env,
+    member: Address,
+    token_id: BytesN<32>,
+    new_size: ShareSize,
+) -> Result<i128, Error> {
+    member.require_auth();
+
+    let mut membership = get_membership(&env, &token_id)?;
+    if membership.member != member {
+        return Err(Error::NotAuthorized);
+    }
+    if membership.share_size == new_size {
+        return Err(Error::InvalidShareSize);
+    }
+    if env.ledger().timestamp() >= membership.end_date {
+        return Err(Error::InvalidDates);
+    }
+
+    reserve_share(&env, &membership.farm_id, &membership.season, new_size)?;
+    release_share(
+        &env,
+        &membership.farm_id,
+        &membership.season,
+        membership.share_size,
+    );
+
+    let charged = reprice_share(&env, &token_id, &membership, new_size);
+
+    let old_size = membership.share_size;
+    membership.share_size = new_size;
+    env.storage().persistent().set(&token_id, &membership);
+
+    env.events().publish(
+        (Symbol::new(&env, "share_size_changed"), member),
+        (token_id, old_size, new_size, charged),
+    );
+
+    Ok(charged)
+}
+
+pub fn set_transfers_enabled(
+    env: Env,
+    farm_admin: Address,
+    farm_id: BytesN<32>,
+    season: String,
+    enabled: bool,
+) -> Result<(), Error> {
+    farm_admin.require_auth();
+    require_farm_admin(&env, &farm_id, &farm_admin)?;
+
+    let key = DataKey::TransfersDisabled(farm_id, season);
+    if enabled {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &true);
+    }
+
+    Ok(())
+}
+
+/// Transfers are allowed unless the farm disabled them for the season.
+pub(crate) fn transfers_enabled(env: &Env, farm_id: &BytesN<32>, season: &String) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&DataKey::TransfersDisabled(farm_id.clone(), season.clone()))
+}
+
+fn get_membership(env: &Env, token_id: &BytesN<32>) -> Result<CSAMembership, Error> {
+    env.storage()
+        .persistent()
+        .get(token_id)
+        .ok_or(Error::NotFound)
+}
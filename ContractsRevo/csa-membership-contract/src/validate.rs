@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, Season};
 use soroban_sdk::{BytesN, Env, IntoVal, String};
 
 pub fn validate_season(
@@ -32,3 +32,22 @@ pub fn validate_season(
 
     Ok(())
 }
+
+pub fn validate_enrollment_window(
+    env: &Env,
+    season: &Season,
+    start_date: u64,
+    end_date: u64,
+) -> Result<(), Error> {
+    let current_time = env.ledger().timestamp();
+
+    if current_time < season.enrollment_open || current_time >= season.enrollment_close {
+        return Err(Error::EnrollmentClosed);
+    }
+
+    if start_date != season.start_date || end_date != season.end_date {
+        return Err(Error::InvalidDates);
+    }
+
+    Ok(())
+}
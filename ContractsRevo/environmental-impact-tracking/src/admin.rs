@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env};
+
+use crate::datatypes::DataKey;
+use crate::error::ContractError;
+
+pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    admin.require_auth();
+
+    let stored: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    if stored != *admin {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
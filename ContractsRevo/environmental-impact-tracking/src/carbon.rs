@@ -1,5 +1,6 @@
 use soroban_sdk::{contractimpl, BytesN, Env, String, Symbol, Vec};
 
+use crate::compliance::require_issuance_compliance;
 use crate::datatypes::{CarbonCredit, DataKey, RetirementStatus};
 use crate::error::ContractError;
 use crate::interfaces::CarbonContract;
@@ -44,6 +45,8 @@ impl CarbonContract for EnvironmentalContract {
             return Err(ContractError::CreditAlreadyExists);
         }
 
+        require_issuance_compliance(env, &project_id)?;
+
         let issuance_date = env.ledger().timestamp();
         let credit = CarbonCredit {
             project_id: project_id.clone(),
@@ -0,0 +1,209 @@
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::datatypes::{ComplianceAttestation, ComplianceReport, DataKey, EnvironmentalStandard};
+use crate::error::ContractError;
+use crate::interfaces::ComplianceContract;
+use crate::reporting::verified_impact_records;
+use crate::{EnvironmentalContract, EnvironmentalContractArgs, EnvironmentalContractClient};
+
+fn load_standard(env: &Env, standard: &Symbol) -> Result<EnvironmentalStandard, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Standard(standard.clone()))
+        .ok_or(ContractError::StandardNotFound)
+}
+
+fn evaluate(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    requirements: &Vec<(Symbol, i128)>,
+    period: u64,
+) -> ComplianceReport {
+    let mut totals: Map<Symbol, i128> = Map::new(env);
+    for record in verified_impact_records(env, farm_id, period, period).iter() {
+        let total = totals.get(record.metric.clone()).unwrap_or(0);
+        totals.set(record.metric, total + record.value);
+    }
+
+    let mut failed_requirements = Vec::new(env);
+    for (metric, required) in requirements.iter() {
+        let actual = totals.get(metric.clone()).unwrap_or(0);
+        if actual < required {
+            failed_requirements.push_back((metric, required, actual));
+        }
+    }
+
+    ComplianceReport {
+        compliant: failed_requirements.is_empty(),
+        failed_requirements,
+    }
+}
+
+/// Fails unless the project holds a compliant attestation for the standard issuance requires,
+/// if any
+pub(crate) fn require_issuance_compliance(
+    env: &Env,
+    project_id: &BytesN<32>,
+) -> Result<(), ContractError> {
+    let standard: Option<Symbol> = env.storage().persistent().get(&DataKey::IssuanceStandard);
+    let standard = match standard {
+        Some(standard) => standard,
+        None => return Ok(()),
+    };
+
+    let attestation: Option<ComplianceAttestation> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LatestAttestation(project_id.clone(), standard));
+    match attestation {
+        Some(attestation) if attestation.report.compliant => Ok(()),
+        _ => Err(ContractError::ComplianceRequired),
+    }
+}
+
+#[contractimpl]
+impl ComplianceContract for EnvironmentalContract {
+    /// Creates or replaces a standard. Replacing bumps its version; attestations keep the
+    /// requirements they were checked against.
+    fn define_standard(
+        env: &Env,
+        admin: Address,
+        standard: Symbol,
+        requirements: Vec<(Symbol, i128)>,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+
+        if requirements.is_empty() {
+            return Err(ContractError::InvalidStandard);
+        }
+        for (_, minimum) in requirements.iter() {
+            if minimum <= 0 {
+                return Err(ContractError::InvalidStandard);
+            }
+        }
+
+        let version = load_standard(env, &standard).map_or(1, |previous| previous.version + 1);
+        let definition = EnvironmentalStandard {
+            standard: standard.clone(),
+            requirements,
+            version,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Standard(standard.clone()), &definition);
+
+        env.events()
+            .publish((Symbol::new(env, "Standard_Defined"), standard), version);
+        Ok(())
+    }
+
+    /// Returns the current definition of a standard
+    fn get_standard(env: &Env, standard: Symbol) -> Result<EnvironmentalStandard, ContractError> {
+        load_standard(env, &standard)
+    }
+
+    /// Evaluates verified impact records of a period against the current standard
+    fn check_compliance(
+        env: &Env,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceReport, ContractError> {
+        let definition = load_standard(env, &standard)?;
+        Ok(evaluate(
+            env,
+            &project_or_farm_id,
+            &definition.requirements,
+            period,
+        ))
+    }
+
+    /// Persists the current compliance result for a period, signed by an impact verifier
+    fn record_compliance_attestation(
+        env: &Env,
+        verifier: Address,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceAttestation, ContractError> {
+        verifier.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ImpactVerifier(verifier.clone()))
+        {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let definition = load_standard(env, &standard)?;
+        let report = evaluate(env, &project_or_farm_id, &definition.requirements, period);
+        let attestation = ComplianceAttestation {
+            farm_id: project_or_farm_id.clone(),
+            standard: standard.clone(),
+            period,
+            standard_version: definition.version,
+            requirements: definition.requirements,
+            report,
+            verifier,
+            attested_at: env.ledger().timestamp(),
+        };
+
+        env.storage().persistent().set(
+            &DataKey::ComplianceAttestation(project_or_farm_id.clone(), standard.clone(), period),
+            &attestation,
+        );
+        env.storage().persistent().set(
+            &DataKey::LatestAttestation(project_or_farm_id.clone(), standard),
+            &attestation,
+        );
+
+        env.events().publish(
+            (Symbol::new(env, "Compliance_Attested"), project_or_farm_id),
+            (period, attestation.report.compliant),
+        );
+        Ok(attestation)
+    }
+
+    /// Returns the attestation recorded for a period
+    fn get_compliance_attestation(
+        env: &Env,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceAttestation, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ComplianceAttestation(
+                project_or_farm_id,
+                standard,
+                period,
+            ))
+            .ok_or(ContractError::AttestationNotFound)
+    }
+
+    /// Makes credit issuance require a compliant attestation for `standard`; `None` lifts it
+    fn set_issuance_standard(
+        env: &Env,
+        admin: Address,
+        standard: Option<Symbol>,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+
+        match standard {
+            Some(standard) => {
+                load_standard(env, &standard)?;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::IssuanceStandard, &standard);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::IssuanceStandard),
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[derive(Clone)]
 #[contracttype]
@@ -14,6 +14,25 @@ pub struct CarbonCredit {
 pub enum DataKey {
     Credit(BytesN<32>),
     ProjectCredits(BytesN<32>),
+    Balance(BytesN<32>, Address), // Credit batch and holder -> quantity held
+    RetiredQuantity(BytesN<32>),  // Credit batch -> quantity retired
+    Retirement(BytesN<32>),       // Retirement ID -> RetirementCertificate
+    BeneficiaryRetirements(Address), // Beneficiary -> Vec<BytesN<32>>
+    RetirementCounter,
+    Admin,
+    ImpactVerifier(Address),
+    ImpactRecord(u64),             // Record ID -> ImpactRecord
+    FarmImpactRecords(BytesN<32>), // Farm ID -> Vec<u64>
+    ImpactCounter,
+    Standard(Symbol), // Standard -> EnvironmentalStandard
+    ComplianceAttestation(BytesN<32>, Symbol, u64), // Farm, standard, period -> attestation
+    LatestAttestation(BytesN<32>, Symbol), // Farm, standard -> latest attestation
+    IssuanceStandard, // Standard credit issuance requires
+    MarketFee,
+    Listing(u64),                // Listing ID -> CreditListing
+    ProjectListings(BytesN<32>), // Project ID -> Vec<u64>
+    ListingCounter,
+    CreditIssuer(Address), // Addresses allowed to issue credit batches besides the admin
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -22,3 +41,105 @@ pub enum RetirementStatus {
     Available,
     Retired(Address),
 }
+
+/// Immutable record of credits retired against a beneficiary's footprint
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct RetirementCertificate {
+    pub retirement_id: BytesN<32>,
+    pub batch_id: BytesN<32>,
+    pub project_id: BytesN<32>,
+    pub holder: Address,
+    pub beneficiary: Address,
+    pub quantity: u32, // in kg
+    pub reason: String,
+    pub retired_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ImpactStatus {
+    Pending,
+    Verified,
+    Rejected,
+}
+
+/// Practice-based impact reported by a farm for one period, e.g. hectares under cover crops
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ImpactRecord {
+    pub record_id: u64,
+    pub farmer: Address,
+    pub farm_id: BytesN<32>,
+    pub period: u64,
+    pub metric: Symbol,
+    pub value: i128,
+    pub evidence_hash: BytesN<32>,
+    pub status: ImpactStatus,
+    pub verifier: Option<Address>,
+    pub notes_hash: Option<BytesN<32>>,
+    pub amended: bool, // Rejected records can be amended once
+    pub recorded_at: u64,
+}
+
+/// Minimum verified value per metric a farm or project must reach in a period
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct EnvironmentalStandard {
+    pub standard: Symbol,
+    pub requirements: Vec<(Symbol, i128)>,
+    pub version: u32,
+    pub updated_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ComplianceReport {
+    pub compliant: bool,
+    pub failed_requirements: Vec<(Symbol, i128, i128)>, // Metric, required, actual
+}
+
+/// Verifier-signed compliance result, frozen with the standard version it was checked against
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ComplianceAttestation {
+    pub farm_id: BytesN<32>,
+    pub standard: Symbol,
+    pub period: u64,
+    pub standard_version: u32,
+    pub requirements: Vec<(Symbol, i128)>,
+    pub report: ComplianceReport,
+    pub verifier: Address,
+    pub attested_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct MarketFeeConfig {
+    pub fee_bps: u32,
+    pub recipient: Address,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ListingStatus {
+    Active,
+    Filled,
+    Cancelled,
+}
+
+/// Credits escrowed by the contract until they are bought or the listing is cancelled
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct CreditListing {
+    pub listing_id: u64,
+    pub seller: Address,
+    pub batch_id: BytesN<32>,
+    pub project_id: BytesN<32>,
+    pub quantity: u32,
+    pub remaining: u32,
+    pub unit_price: i128, // Per kg, in units of payment_token
+    pub payment_token: Address,
+    pub status: ListingStatus,
+    pub created_at: u64,
+}
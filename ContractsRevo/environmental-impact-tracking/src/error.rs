@@ -10,4 +10,21 @@ pub enum ContractError {
     InvalidVerificationMethod = 5,
     CreditAlreadyExists = 6,
     AlreadyRetired = 7,
+    InsufficientBalance = 8,
+    RetirementNotFound = 9,
+    Unauthorized = 10,
+    AlreadyInitialized = 11,
+    NotInitialized = 12,
+    ImpactRecordNotFound = 13,
+    ImpactNotPending = 14,
+    AmendmentNotAllowed = 15,
+    InvalidPeriod = 16,
+    StandardNotFound = 17,
+    InvalidStandard = 18,
+    AttestationNotFound = 19,
+    ComplianceRequired = 20,
+    ListingNotFound = 21,
+    ListingNotActive = 22,
+    InsufficientPayment = 23,
+    InvalidFee = 24,
 }
@@ -0,0 +1,157 @@
+use soroban_sdk::{contractimpl, Address, BytesN, Env, String, Symbol};
+
+use crate::admin::require_admin;
+use crate::datatypes::{CarbonCredit, DataKey};
+use crate::error::ContractError;
+use crate::interfaces::{CarbonContract, CreditHoldingContract};
+use crate::{EnvironmentalContract, EnvironmentalContractArgs, EnvironmentalContractClient};
+
+pub(crate) fn balance_of(env: &Env, batch_id: &BytesN<32>, holder: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(batch_id.clone(), holder.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_balance(env: &Env, batch_id: &BytesN<32>, holder: &Address, quantity: u32) {
+    let key = DataKey::Balance(batch_id.clone(), holder.clone());
+    if quantity == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &quantity);
+    }
+}
+
+pub(crate) fn retired_quantity(env: &Env, batch_id: &BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RetiredQuantity(batch_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Only the admin and registered issuers may bring new credits into circulation
+fn require_issuer(env: &Env, issuer: &Address) -> Result<(), ContractError> {
+    issuer.require_auth();
+
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+    if *issuer != admin
+        && !env
+            .storage()
+            .persistent()
+            .has(&DataKey::CreditIssuer(issuer.clone()))
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl CreditHoldingContract for EnvironmentalContract {
+    /// Allows an address to issue credit batches
+    fn register_credit_issuer(
+        env: &Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::CreditIssuer(issuer), &true);
+        Ok(())
+    }
+
+    /// Revokes a credit issuer
+    fn remove_credit_issuer(
+        env: &Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CreditIssuer(issuer));
+        Ok(())
+    }
+
+    /// Issues a credit batch whose whole amount is held by `holder`
+    fn issue_credit_batch(
+        env: &Env,
+        issuer: Address,
+        holder: Address,
+        batch_id: BytesN<32>,
+        project_id: BytesN<32>,
+        carbon_amount: u32,
+        verification_method: String,
+    ) -> Result<(), ContractError> {
+        require_issuer(env, &issuer)?;
+
+        Self::issue_carbon_credit(
+            env,
+            batch_id.clone(),
+            project_id,
+            carbon_amount,
+            verification_method,
+        )?;
+        set_balance(env, &batch_id, &holder, carbon_amount);
+
+        Ok(())
+    }
+
+    /// Moves part of a holder's balance in a batch to another address
+    fn transfer_credits(
+        env: &Env,
+        from: Address,
+        to: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+    ) -> Result<(), ContractError> {
+        from.require_auth();
+
+        if quantity == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Credit(batch_id.clone()))
+        {
+            return Err(ContractError::CreditNotFound);
+        }
+
+        // Retired credits have already left the holder's balance
+        let from_balance = balance_of(env, &batch_id, &from);
+        if from_balance < quantity {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        set_balance(env, &batch_id, &from, from_balance - quantity);
+        let to_balance = balance_of(env, &batch_id, &to);
+        set_balance(env, &batch_id, &to, to_balance + quantity);
+
+        env.events().publish(
+            (Symbol::new(env, "Credits_Transferred"), batch_id),
+            (from, to, quantity),
+        );
+        Ok(())
+    }
+
+    /// Returns how much of a batch an address holds
+    fn get_credit_balance(env: &Env, batch_id: BytesN<32>, holder: Address) -> u32 {
+        balance_of(env, &batch_id, &holder)
+    }
+
+    /// Returns the part of a batch that has not been retired
+    fn get_transferable_supply(env: &Env, batch_id: BytesN<32>) -> Result<u32, ContractError> {
+        let credit: CarbonCredit = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Credit(batch_id.clone()))
+            .ok_or(ContractError::CreditNotFound)?;
+
+        Ok(credit.carbon_amount - retired_quantity(env, &batch_id))
+    }
+}
@@ -1,6 +1,12 @@
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, Vec};
 
-use crate::{datatypes::RetirementStatus, error::ContractError};
+use crate::{
+    datatypes::{
+        ComplianceAttestation, ComplianceReport, CreditListing, EnvironmentalStandard,
+        ImpactRecord, MarketFeeConfig, RetirementCertificate, RetirementStatus,
+    },
+    error::ContractError,
+};
 
 pub trait CarbonContract {
     fn issue_carbon_credit(
@@ -49,3 +55,191 @@ pub trait RetirementContract {
         credit_id: BytesN<32>,
     ) -> Result<RetirementStatus, ContractError>;
 }
+
+#[allow(dead_code)]
+pub trait CreditHoldingContract {
+    fn register_credit_issuer(
+        env: &Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError>;
+
+    fn remove_credit_issuer(
+        env: &Env,
+        admin: Address,
+        issuer: Address,
+    ) -> Result<(), ContractError>;
+
+    fn issue_credit_batch(
+        env: &Env,
+        issuer: Address,
+        holder: Address,
+        batch_id: BytesN<32>,
+        project_id: BytesN<32>,
+        carbon_amount: u32,
+        verification_method: String,
+    ) -> Result<(), ContractError>;
+
+    fn transfer_credits(
+        env: &Env,
+        from: Address,
+        to: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+    ) -> Result<(), ContractError>;
+
+    fn get_credit_balance(env: &Env, batch_id: BytesN<32>, holder: Address) -> u32;
+
+    fn get_transferable_supply(env: &Env, batch_id: BytesN<32>) -> Result<u32, ContractError>;
+}
+
+#[allow(dead_code)]
+pub trait RetirementCertificateContract {
+    fn retire_credits(
+        env: &Env,
+        holder: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+        beneficiary: Address,
+        reason: String,
+    ) -> Result<BytesN<32>, ContractError>;
+
+    fn get_retirement(
+        env: &Env,
+        retirement_id: BytesN<32>,
+    ) -> Result<RetirementCertificate, ContractError>;
+
+    fn list_retirements_by_beneficiary(
+        env: &Env,
+        beneficiary: Address,
+    ) -> Vec<RetirementCertificate>;
+}
+
+#[allow(dead_code)]
+pub trait ImpactReportingContract {
+    fn register_impact_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError>;
+
+    fn remove_impact_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError>;
+
+    fn record_impact(
+        env: &Env,
+        farmer: Address,
+        farm_id: BytesN<32>,
+        period: u64,
+        metric: Symbol,
+        value: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, ContractError>;
+
+    fn verify_impact(
+        env: &Env,
+        verifier: Address,
+        record_id: u64,
+        approved: bool,
+        notes_hash: BytesN<32>,
+    ) -> Result<(), ContractError>;
+
+    fn amend_impact(
+        env: &Env,
+        farmer: Address,
+        record_id: u64,
+        value: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError>;
+
+    fn get_impact_record(env: &Env, record_id: u64) -> Result<ImpactRecord, ContractError>;
+
+    fn get_farm_impact_summary(
+        env: &Env,
+        farm_id: BytesN<32>,
+        period_start: u64,
+        period_end: u64,
+    ) -> Result<Map<Symbol, i128>, ContractError>;
+}
+
+#[allow(dead_code)]
+pub trait ComplianceContract {
+    fn define_standard(
+        env: &Env,
+        admin: Address,
+        standard: Symbol,
+        requirements: Vec<(Symbol, i128)>,
+    ) -> Result<(), ContractError>;
+
+    fn get_standard(env: &Env, standard: Symbol) -> Result<EnvironmentalStandard, ContractError>;
+
+    fn check_compliance(
+        env: &Env,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceReport, ContractError>;
+
+    fn record_compliance_attestation(
+        env: &Env,
+        verifier: Address,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceAttestation, ContractError>;
+
+    fn get_compliance_attestation(
+        env: &Env,
+        project_or_farm_id: BytesN<32>,
+        standard: Symbol,
+        period: u64,
+    ) -> Result<ComplianceAttestation, ContractError>;
+
+    fn set_issuance_standard(
+        env: &Env,
+        admin: Address,
+        standard: Option<Symbol>,
+    ) -> Result<(), ContractError>;
+}
+
+#[allow(dead_code)]
+pub trait MarketplaceContract {
+    fn set_market_fee(
+        env: &Env,
+        admin: Address,
+        fee_bps: u32,
+        recipient: Address,
+    ) -> Result<(), ContractError>;
+
+    fn get_market_fee(env: &Env) -> Option<MarketFeeConfig>;
+
+    fn list_credits_for_sale(
+        env: &Env,
+        holder: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+        unit_price: i128,
+        payment_token: Address,
+    ) -> Result<u64, ContractError>;
+
+    fn buy_credits(
+        env: &Env,
+        buyer: Address,
+        listing_id: u64,
+        quantity: u32,
+    ) -> Result<(), ContractError>;
+
+    fn cancel_listing(env: &Env, seller: Address, listing_id: u64) -> Result<(), ContractError>;
+
+    fn get_listing(env: &Env, listing_id: u64) -> Result<CreditListing, ContractError>;
+
+    fn list_active_listings(
+        env: &Env,
+        project_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CreditListing>;
+}
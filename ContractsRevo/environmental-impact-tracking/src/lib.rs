@@ -1,16 +1,33 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+use crate::datatypes::DataKey;
+use crate::error::ContractError;
 
 #[contract]
 pub struct EnvironmentalContract;
 
 #[contractimpl]
-impl EnvironmentalContract {}
+impl EnvironmentalContract {
+    /// Sets the admin that manages impact verifiers
+    pub fn initialize(env: &Env, admin: Address) -> Result<(), ContractError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+}
 
+mod admin;
 mod carbon;
+mod compliance;
 mod datatypes;
 mod error;
+mod holdings;
 mod interfaces;
+mod market;
 mod reporting;
 mod retirement;
 mod verification;
@@ -0,0 +1,238 @@
+use soroban_sdk::{contractimpl, token, Address, BytesN, Env, Symbol, Vec};
+
+use crate::admin::require_admin;
+use crate::datatypes::{CarbonCredit, CreditListing, DataKey, ListingStatus, MarketFeeConfig};
+use crate::error::ContractError;
+use crate::holdings::{balance_of, set_balance};
+use crate::interfaces::MarketplaceContract;
+use crate::{EnvironmentalContract, EnvironmentalContractArgs, EnvironmentalContractClient};
+
+const MAX_FEE_BPS: u32 = 10_000;
+
+fn load_listing(env: &Env, listing_id: u64) -> Result<CreditListing, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Listing(listing_id))
+        .ok_or(ContractError::ListingNotFound)
+}
+
+#[contractimpl]
+impl MarketplaceContract for EnvironmentalContract {
+    /// Sets the platform fee taken from every sale
+    fn set_market_fee(
+        env: &Env,
+        admin: Address,
+        fee_bps: u32,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+
+        if fee_bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFee);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MarketFee, &MarketFeeConfig { fee_bps, recipient });
+        Ok(())
+    }
+
+    /// Returns the platform fee, if one is set
+    fn get_market_fee(env: &Env) -> Option<MarketFeeConfig> {
+        env.storage().persistent().get(&DataKey::MarketFee)
+    }
+
+    /// Moves credits from the holder's balance into escrow and lists them for sale
+    fn list_credits_for_sale(
+        env: &Env,
+        holder: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+        unit_price: i128,
+        payment_token: Address,
+    ) -> Result<u64, ContractError> {
+        holder.require_auth();
+
+        if quantity == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+        if unit_price <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let credit: CarbonCredit = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Credit(batch_id.clone()))
+            .ok_or(ContractError::CreditNotFound)?;
+
+        let balance = balance_of(env, &batch_id, &holder);
+        if balance < quantity {
+            return Err(ContractError::InsufficientBalance);
+        }
+        set_balance(env, &batch_id, &holder, balance - quantity);
+
+        let listing_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ListingCounter)
+            .unwrap_or(0)
+            + 1;
+        let listing = CreditListing {
+            listing_id,
+            seller: holder,
+            batch_id,
+            project_id: credit.project_id.clone(),
+            quantity,
+            remaining: quantity,
+            unit_price,
+            payment_token,
+            status: ListingStatus::Active,
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(listing_id), &listing);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ListingCounter, &listing_id);
+
+        let key = DataKey::ProjectListings(credit.project_id.clone());
+        let mut listings: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        listings.push_back(listing_id);
+        env.storage().persistent().set(&key, &listings);
+
+        env.events().publish(
+            (Symbol::new(env, "Credits_Listed"), credit.project_id),
+            (listing_id, quantity, unit_price),
+        );
+        Ok(listing_id)
+    }
+
+    /// Buys part or all of a listing. Payment is escrowed, the platform fee is routed to the
+    /// fee recipient and the rest to the seller, all in the same call as the credit transfer.
+    fn buy_credits(
+        env: &Env,
+        buyer: Address,
+        listing_id: u64,
+        quantity: u32,
+    ) -> Result<(), ContractError> {
+        buyer.require_auth();
+
+        if quantity == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+
+        let mut listing = load_listing(env, listing_id)?;
+        if listing.status != ListingStatus::Active {
+            return Err(ContractError::ListingNotActive);
+        }
+        if quantity > listing.remaining {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let price = listing
+            .unit_price
+            .checked_mul(quantity as i128)
+            .ok_or(ContractError::InvalidAmount)?;
+        let token = token::Client::new(env, &listing.payment_token);
+        if token.balance(&buyer) < price {
+            return Err(ContractError::InsufficientPayment);
+        }
+
+        let contract = env.current_contract_address();
+        token.transfer(&buyer, &contract, &price);
+
+        let fee_config: Option<MarketFeeConfig> =
+            env.storage().persistent().get(&DataKey::MarketFee);
+        let mut proceeds = price;
+        if let Some(config) = fee_config {
+            let fee = price * config.fee_bps as i128 / MAX_FEE_BPS as i128;
+            if fee > 0 {
+                token.transfer(&contract, &config.recipient, &fee);
+                proceeds -= fee;
+            }
+        }
+        token.transfer(&contract, &listing.seller, &proceeds);
+
+        let buyer_balance = balance_of(env, &listing.batch_id, &buyer);
+        set_balance(env, &listing.batch_id, &buyer, buyer_balance + quantity);
+
+        listing.remaining -= quantity;
+        if listing.remaining == 0 {
+            listing.status = ListingStatus::Filled;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(listing_id), &listing);
+
+        env.events().publish(
+            (Symbol::new(env, "Credits_Sold"), listing.project_id),
+            (listing_id, buyer, quantity, price),
+        );
+        Ok(())
+    }
+
+    /// Withdraws a listing and returns the unsold credits to the seller
+    fn cancel_listing(env: &Env, seller: Address, listing_id: u64) -> Result<(), ContractError> {
+        seller.require_auth();
+
+        let mut listing = load_listing(env, listing_id)?;
+        if listing.seller != seller {
+            return Err(ContractError::Unauthorized);
+        }
+        if listing.status != ListingStatus::Active {
+            return Err(ContractError::ListingNotActive);
+        }
+
+        let balance = balance_of(env, &listing.batch_id, &seller);
+        set_balance(env, &listing.batch_id, &seller, balance + listing.remaining);
+
+        listing.status = ListingStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(listing_id), &listing);
+        Ok(())
+    }
+
+    /// Returns a listing
+    fn get_listing(env: &Env, listing_id: u64) -> Result<CreditListing, ContractError> {
+        load_listing(env, listing_id)
+    }
+
+    /// Returns a page of a project's active listings, oldest first
+    fn list_active_listings(
+        env: &Env,
+        project_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CreditListing> {
+        let listing_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProjectListings(project_id))
+            .unwrap_or(Vec::new(env));
+
+        let mut page = Vec::new(env);
+        let mut skipped = 0;
+        for listing_id in listing_ids.iter() {
+            if page.len() >= limit {
+                break;
+            }
+            if let Ok(listing) = load_listing(env, listing_id) {
+                if listing.status != ListingStatus::Active {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                page.push_back(listing);
+            }
+        }
+        page
+    }
+}
@@ -1,10 +1,13 @@
-use soroban_sdk::{BytesN, Env, Vec};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Map, Symbol, Vec};
 
-use crate::EnvironmentalContract;
+use crate::admin::require_admin;
+use crate::holdings::retired_quantity;
 use crate::{
-    datatypes::{CarbonCredit, DataKey, RetirementStatus},
-    interfaces::ReportingContract,
+    datatypes::{CarbonCredit, DataKey, ImpactRecord, ImpactStatus, RetirementStatus},
+    error::ContractError,
+    interfaces::{ImpactReportingContract, ReportingContract},
 };
+use crate::{EnvironmentalContract, EnvironmentalContractArgs, EnvironmentalContractClient};
 
 impl ReportingContract for EnvironmentalContract {
     fn generate_impact_report(env: &Env, project_id: BytesN<32>) -> u32 {
@@ -27,8 +30,11 @@ impl ReportingContract for EnvironmentalContract {
                 .persistent()
                 .get::<DataKey, CarbonCredit>(&DataKey::Credit(credit_id.clone()))
             {
+                // Partially retired batches count what has been retired so far
                 if let RetirementStatus::Retired(_) = credit.retirement_status {
                     total_offset += credit.carbon_amount;
+                } else {
+                    total_offset += retired_quantity(env, &credit_id);
                 }
             }
         }
@@ -36,3 +42,229 @@ impl ReportingContract for EnvironmentalContract {
         total_offset
     }
 }
+
+fn load_impact_record(env: &Env, record_id: u64) -> Result<ImpactRecord, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ImpactRecord(record_id))
+        .ok_or(ContractError::ImpactRecordNotFound)
+}
+
+/// Verified records of a farm within `period_start..=period_end`
+pub(crate) fn verified_impact_records(
+    env: &Env,
+    farm_id: &BytesN<32>,
+    period_start: u64,
+    period_end: u64,
+) -> Vec<ImpactRecord> {
+    let record_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FarmImpactRecords(farm_id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let mut records = Vec::new(env);
+    for record_id in record_ids.iter() {
+        if let Ok(record) = load_impact_record(env, record_id) {
+            if record.status == ImpactStatus::Verified
+                && record.period >= period_start
+                && record.period <= period_end
+            {
+                records.push_back(record);
+            }
+        }
+    }
+    records
+}
+
+#[contractimpl]
+impl ImpactReportingContract for EnvironmentalContract {
+    /// Allows an address to verify impact records
+    fn register_impact_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImpactVerifier(verifier), &true);
+        Ok(())
+    }
+
+    /// Revokes an impact verifier
+    fn remove_impact_verifier(
+        env: &Env,
+        admin: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(env, &admin)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ImpactVerifier(verifier));
+        Ok(())
+    }
+
+    /// Records a farm's impact for a period; it counts once a verifier approves it
+    fn record_impact(
+        env: &Env,
+        farmer: Address,
+        farm_id: BytesN<32>,
+        period: u64,
+        metric: Symbol,
+        value: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        farmer.require_auth();
+
+        if farm_id == BytesN::from_array(env, &[0u8; 32]) {
+            return Err(ContractError::InvalidIdentifier);
+        }
+        if value <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let record_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ImpactCounter)
+            .unwrap_or(0)
+            + 1;
+        let record = ImpactRecord {
+            record_id,
+            farmer,
+            farm_id: farm_id.clone(),
+            period,
+            metric: metric.clone(),
+            value,
+            evidence_hash,
+            status: ImpactStatus::Pending,
+            verifier: None,
+            notes_hash: None,
+            amended: false,
+            recorded_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImpactRecord(record_id), &record);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImpactCounter, &record_id);
+
+        let key = DataKey::FarmImpactRecords(farm_id.clone());
+        let mut farm_records: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        farm_records.push_back(record_id);
+        env.storage().persistent().set(&key, &farm_records);
+
+        env.events().publish(
+            (Symbol::new(env, "Impact_Recorded"), farm_id),
+            (record_id, metric, value),
+        );
+        Ok(record_id)
+    }
+
+    /// Approves or rejects a pending impact record
+    fn verify_impact(
+        env: &Env,
+        verifier: Address,
+        record_id: u64,
+        approved: bool,
+        notes_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        verifier.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ImpactVerifier(verifier.clone()))
+        {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let mut record = load_impact_record(env, record_id)?;
+        if record.farmer == verifier {
+            return Err(ContractError::Unauthorized);
+        }
+        if record.status != ImpactStatus::Pending {
+            return Err(ContractError::ImpactNotPending);
+        }
+
+        record.status = if approved {
+            ImpactStatus::Verified
+        } else {
+            ImpactStatus::Rejected
+        };
+        record.verifier = Some(verifier);
+        record.notes_hash = Some(notes_hash);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImpactRecord(record_id), &record);
+
+        env.events().publish(
+            (Symbol::new(env, "Impact_Verified"), record.farm_id),
+            (record_id, approved),
+        );
+        Ok(())
+    }
+
+    /// Corrects a rejected record and sends it back for verification. Allowed once per record.
+    fn amend_impact(
+        env: &Env,
+        farmer: Address,
+        record_id: u64,
+        value: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        farmer.require_auth();
+
+        let mut record = load_impact_record(env, record_id)?;
+        if record.farmer != farmer {
+            return Err(ContractError::Unauthorized);
+        }
+        if record.status != ImpactStatus::Rejected || record.amended {
+            return Err(ContractError::AmendmentNotAllowed);
+        }
+        if value <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        record.value = value;
+        record.evidence_hash = evidence_hash;
+        record.status = ImpactStatus::Pending;
+        record.verifier = None;
+        record.notes_hash = None;
+        record.amended = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImpactRecord(record_id), &record);
+        Ok(())
+    }
+
+    /// Returns an impact record
+    fn get_impact_record(env: &Env, record_id: u64) -> Result<ImpactRecord, ContractError> {
+        load_impact_record(env, record_id)
+    }
+
+    /// Sums verified values per metric over an inclusive period range
+    fn get_farm_impact_summary(
+        env: &Env,
+        farm_id: BytesN<32>,
+        period_start: u64,
+        period_end: u64,
+    ) -> Result<Map<Symbol, i128>, ContractError> {
+        if period_start > period_end {
+            return Err(ContractError::InvalidPeriod);
+        }
+
+        let mut summary = Map::new(env);
+        for record in verified_impact_records(env, &farm_id, period_start, period_end).iter() {
+            let total = summary.get(record.metric.clone()).unwrap_or(0);
+            summary.set(record.metric, total + record.value);
+        }
+        Ok(summary)
+    }
+}
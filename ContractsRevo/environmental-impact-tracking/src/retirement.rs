@@ -1,10 +1,11 @@
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{contractimpl, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
+use crate::holdings::{balance_of, retired_quantity, set_balance};
 use crate::{
-    datatypes::{CarbonCredit, DataKey, RetirementStatus},
+    datatypes::{CarbonCredit, DataKey, RetirementCertificate, RetirementStatus},
     error::ContractError,
-    interfaces::RetirementContract,
-    EnvironmentalContract,
+    interfaces::{RetirementCertificateContract, RetirementContract},
+    EnvironmentalContract, EnvironmentalContractArgs, EnvironmentalContractClient,
 };
 
 impl RetirementContract for EnvironmentalContract {
@@ -72,3 +73,124 @@ impl RetirementContract for EnvironmentalContract {
         }
     }
 }
+
+#[contractimpl]
+impl RetirementCertificateContract for EnvironmentalContract {
+    /// Retires part of a holder's balance against a beneficiary and returns the certificate ID.
+    /// Retired credits are removed from the transferable supply for good.
+    fn retire_credits(
+        env: &Env,
+        holder: Address,
+        batch_id: BytesN<32>,
+        quantity: u32,
+        beneficiary: Address,
+        reason: String,
+    ) -> Result<BytesN<32>, ContractError> {
+        holder.require_auth();
+
+        if quantity == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+
+        let mut credit: CarbonCredit = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Credit(batch_id.clone()))
+            .ok_or(ContractError::CreditNotFound)?;
+
+        let balance = balance_of(env, &batch_id, &holder);
+        if balance < quantity {
+            return Err(ContractError::InsufficientBalance);
+        }
+        set_balance(env, &batch_id, &holder, balance - quantity);
+
+        let retired = retired_quantity(env, &batch_id) + quantity;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RetiredQuantity(batch_id.clone()), &retired);
+        if retired == credit.carbon_amount {
+            credit.retirement_status = RetirementStatus::Retired(holder.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::Credit(batch_id.clone()), &credit);
+        }
+
+        let counter: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RetirementCounter)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RetirementCounter, &counter);
+
+        let mut seed = Bytes::from_array(env, &batch_id.to_array());
+        seed.extend_from_array(&counter.to_be_bytes());
+        let retirement_id: BytesN<32> = env.crypto().sha256(&seed).into();
+
+        let certificate = RetirementCertificate {
+            retirement_id: retirement_id.clone(),
+            batch_id: batch_id.clone(),
+            project_id: credit.project_id,
+            holder,
+            beneficiary: beneficiary.clone(),
+            quantity,
+            reason,
+            retired_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Retirement(retirement_id.clone()), &certificate);
+
+        let key = DataKey::BeneficiaryRetirements(beneficiary);
+        let mut retirements: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        retirements.push_back(retirement_id.clone());
+        env.storage().persistent().set(&key, &retirements);
+
+        env.events().publish(
+            (Symbol::new(env, "Credits_Retired"), batch_id),
+            (retirement_id.clone(), certificate.beneficiary, quantity),
+        );
+        Ok(retirement_id)
+    }
+
+    /// Returns a retirement certificate
+    fn get_retirement(
+        env: &Env,
+        retirement_id: BytesN<32>,
+    ) -> Result<RetirementCertificate, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Retirement(retirement_id))
+            .ok_or(ContractError::RetirementNotFound)
+    }
+
+    /// Lists the retirement certificates made out to a beneficiary, oldest first
+    fn list_retirements_by_beneficiary(
+        env: &Env,
+        beneficiary: Address,
+    ) -> Vec<RetirementCertificate> {
+        let retirement_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryRetirements(beneficiary))
+            .unwrap_or(Vec::new(env));
+
+        let mut certificates = Vec::new(env);
+        for retirement_id in retirement_ids.iter() {
+            if let Some(certificate) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Retirement(retirement_id))
+            {
+                certificates.push_back(certificate);
+            }
+        }
+        certificates
+    }
+}
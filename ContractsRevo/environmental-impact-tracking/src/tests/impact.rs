@@ -0,0 +1,270 @@
+//! Tests for practice-based impact reporting
+//!
+//! This module tests impact records, including:
+//! - Verifier registration and the verification flow
+//! - Farm summaries that only count verified records
+//! - Amending a rejected record once
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Symbol};
+
+    use crate::datatypes::ImpactStatus;
+    use crate::error::ContractError;
+    use crate::tests::utils::*;
+    use crate::EnvironmentalContractClient;
+
+    struct ImpactSetup<'a> {
+        test_env: TestEnv,
+        client: EnvironmentalContractClient<'a>,
+        verifier: Address,
+        farm_id: BytesN<32>,
+    }
+
+    /// Initialized contract with one registered verifier; `user1` farms `farm_id`
+    fn setup_impact<'a>() -> ImpactSetup<'a> {
+        let test_env = setup_test();
+        test_env.env.mock_all_auths();
+        let client = EnvironmentalContractClient::new(&test_env.env, &test_env.contract_id);
+        client.initialize(&test_env.admin);
+
+        let verifier = Address::generate(&test_env.env);
+        client.register_impact_verifier(&test_env.admin, &verifier);
+        let farm_id = create_project_id(&test_env.env, 1);
+
+        ImpactSetup {
+            test_env,
+            client,
+            verifier,
+            farm_id,
+        }
+    }
+
+    fn hash(setup: &ImpactSetup, seed: u8) -> BytesN<32> {
+        BytesN::from_array(&setup.test_env.env, &[seed; 32])
+    }
+
+    fn record(setup: &ImpactSetup, period: u64, metric: Symbol, value: i128) -> u64 {
+        setup.client.record_impact(
+            &setup.test_env.user1,
+            &setup.farm_id,
+            &period,
+            &metric,
+            &value,
+            &hash(setup, 1),
+        )
+    }
+
+    #[test]
+    fn test_initialize_only_once() {
+        let setup = setup_impact();
+        let result = setup.client.try_initialize(&setup.test_env.user1);
+        assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+
+        let result = setup
+            .client
+            .try_register_impact_verifier(&setup.test_env.user1, &setup.test_env.user2);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_verification_flow() {
+        let setup = setup_impact();
+        let record_id = record(&setup, 2024, symbol_short!("cover_ha"), 12);
+
+        let pending = setup.client.get_impact_record(&record_id);
+        assert_eq!(pending.status, ImpactStatus::Pending);
+        assert_eq!(pending.verifier, None);
+
+        // Only registered verifiers, never the farmer
+        let result = setup.client.try_verify_impact(
+            &setup.test_env.user2,
+            &record_id,
+            &true,
+            &hash(&setup, 2),
+        );
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+        setup
+            .client
+            .register_impact_verifier(&setup.test_env.admin, &setup.test_env.user1);
+        let result = setup.client.try_verify_impact(
+            &setup.test_env.user1,
+            &record_id,
+            &true,
+            &hash(&setup, 2),
+        );
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &true, &hash(&setup, 2));
+        let verified = setup.client.get_impact_record(&record_id);
+        assert_eq!(verified.status, ImpactStatus::Verified);
+        assert_eq!(verified.verifier, Some(setup.verifier.clone()));
+        assert_eq!(verified.notes_hash, Some(hash(&setup, 2)));
+
+        let result =
+            setup
+                .client
+                .try_verify_impact(&setup.verifier, &record_id, &false, &hash(&setup, 3));
+        assert_eq!(result, Err(Ok(ContractError::ImpactNotPending)));
+    }
+
+    #[test]
+    fn test_removed_verifier_cannot_verify() {
+        let setup = setup_impact();
+        let record_id = record(&setup, 2024, symbol_short!("cover_ha"), 12);
+        setup
+            .client
+            .remove_impact_verifier(&setup.test_env.admin, &setup.verifier);
+
+        let result =
+            setup
+                .client
+                .try_verify_impact(&setup.verifier, &record_id, &true, &hash(&setup, 2));
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_summary_counts_only_verified_records() {
+        let setup = setup_impact();
+        let cover = symbol_short!("cover_ha");
+        let tillage = symbol_short!("no_till");
+
+        let a = record(&setup, 2023, cover.clone(), 10);
+        let b = record(&setup, 2024, cover.clone(), 15);
+        let c = record(&setup, 2024, tillage.clone(), 30);
+        let rejected = record(&setup, 2024, tillage.clone(), 500);
+        record(&setup, 2024, cover.clone(), 99); // left pending
+        for record_id in [a, b, c] {
+            setup
+                .client
+                .verify_impact(&setup.verifier, &record_id, &true, &hash(&setup, 2));
+        }
+        setup
+            .client
+            .verify_impact(&setup.verifier, &rejected, &false, &hash(&setup, 2));
+
+        let summary = setup
+            .client
+            .get_farm_impact_summary(&setup.farm_id, &2023, &2024);
+        assert_eq!(summary.get(cover.clone()), Some(25));
+        assert_eq!(summary.get(tillage.clone()), Some(30));
+        assert_eq!(summary.len(), 2);
+
+        let summary = setup
+            .client
+            .get_farm_impact_summary(&setup.farm_id, &2024, &2024);
+        assert_eq!(summary.get(cover), Some(15));
+
+        let other_farm = setup.client.get_farm_impact_summary(
+            &create_project_id(&setup.test_env.env, 2),
+            &2023,
+            &2024,
+        );
+        assert_eq!(other_farm.len(), 0);
+
+        let result = setup
+            .client
+            .try_get_farm_impact_summary(&setup.farm_id, &2025, &2024);
+        assert_eq!(result, Err(Ok(ContractError::InvalidPeriod)));
+    }
+
+    #[test]
+    fn test_amendment_after_rejection() {
+        let setup = setup_impact();
+        let methane = symbol_short!("methane");
+        let record_id = record(&setup, 2024, methane.clone(), 800);
+
+        // Pending records cannot be amended
+        let result = setup.client.try_amend_impact(
+            &setup.test_env.user1,
+            &record_id,
+            &400,
+            &hash(&setup, 5),
+        );
+        assert_eq!(result, Err(Ok(ContractError::AmendmentNotAllowed)));
+
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &false, &hash(&setup, 2));
+
+        let result = setup.client.try_amend_impact(
+            &setup.test_env.user2,
+            &record_id,
+            &400,
+            &hash(&setup, 5),
+        );
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        setup
+            .client
+            .amend_impact(&setup.test_env.user1, &record_id, &400, &hash(&setup, 5));
+        let amended = setup.client.get_impact_record(&record_id);
+        assert_eq!(amended.status, ImpactStatus::Pending);
+        assert_eq!(amended.value, 400);
+        assert_eq!(amended.evidence_hash, hash(&setup, 5));
+        assert!(amended.amended);
+
+        // A second rejection is final
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &false, &hash(&setup, 3));
+        let result = setup.client.try_amend_impact(
+            &setup.test_env.user1,
+            &record_id,
+            &300,
+            &hash(&setup, 6),
+        );
+        assert_eq!(result, Err(Ok(ContractError::AmendmentNotAllowed)));
+    }
+
+    #[test]
+    fn test_amended_record_counts_once_verified() {
+        let setup = setup_impact();
+        let methane = symbol_short!("methane");
+        let record_id = record(&setup, 2024, methane.clone(), 800);
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &false, &hash(&setup, 2));
+        setup
+            .client
+            .amend_impact(&setup.test_env.user1, &record_id, &400, &hash(&setup, 5));
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &true, &hash(&setup, 3));
+
+        let summary = setup
+            .client
+            .get_farm_impact_summary(&setup.farm_id, &2024, &2024);
+        assert_eq!(summary.get(methane), Some(400));
+    }
+
+    #[test]
+    fn test_record_validation() {
+        let setup = setup_impact();
+
+        let result = setup.client.try_record_impact(
+            &setup.test_env.user1,
+            &setup.farm_id,
+            &2024,
+            &symbol_short!("cover_ha"),
+            &0,
+            &hash(&setup, 1),
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+        let result = setup.client.try_record_impact(
+            &setup.test_env.user1,
+            &BytesN::from_array(&setup.test_env.env, &[0; 32]),
+            &2024,
+            &symbol_short!("cover_ha"),
+            &5,
+            &hash(&setup, 1),
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidIdentifier)));
+
+        let result = setup.client.try_get_impact_record(&42);
+        assert_eq!(result, Err(Ok(ContractError::ImpactRecordNotFound)));
+    }
+}
@@ -0,0 +1,251 @@
+//! Tests for the credit marketplace
+//!
+//! This module tests escrowed credit sales, including:
+//! - Partial fills across several buyers
+//! - Platform fee routing
+//! - Cancellation returning unsold credits
+//! - Payment and quantity failures
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{testutils::Address as _, token, Address, BytesN};
+
+    use crate::datatypes::ListingStatus;
+    use crate::error::ContractError;
+    use crate::tests::utils::*;
+    use crate::EnvironmentalContractClient;
+
+    const UNIT_PRICE: i128 = 5;
+
+    struct MarketSetup<'a> {
+        test_env: TestEnv,
+        client: EnvironmentalContractClient<'a>,
+        token: Address,
+        batch_id: BytesN<32>,
+        project_id: BytesN<32>,
+    }
+
+    /// Initialized contract where `user1` holds a standard batch, plus a payment token
+    fn setup_market<'a>() -> MarketSetup<'a> {
+        let test_env = setup_test();
+        test_env.env.mock_all_auths();
+        let client = EnvironmentalContractClient::new(&test_env.env, &test_env.contract_id);
+        client.initialize(&test_env.admin);
+
+        let batch_id = create_credit_id(&test_env.env, 1);
+        let project_id = create_project_id(&test_env.env, 1);
+        client.issue_credit_batch(
+            &test_env.admin,
+            &test_env.user1,
+            &batch_id,
+            &project_id,
+            &STANDARD_CARBON_AMOUNT,
+            &standard_verification_method(&test_env.env),
+        );
+        let token = test_env
+            .env
+            .register_stellar_asset_contract_v2(test_env.admin.clone())
+            .address();
+
+        MarketSetup {
+            test_env,
+            client,
+            token,
+            batch_id,
+            project_id,
+        }
+    }
+
+    fn funded_buyer(setup: &MarketSetup, amount: i128) -> Address {
+        let buyer = Address::generate(&setup.test_env.env);
+        token::StellarAssetClient::new(&setup.test_env.env, &setup.token).mint(&buyer, &amount);
+        buyer
+    }
+
+    fn list(setup: &MarketSetup, quantity: u32) -> u64 {
+        setup.client.list_credits_for_sale(
+            &setup.test_env.user1,
+            &setup.batch_id,
+            &quantity,
+            &UNIT_PRICE,
+            &setup.token,
+        )
+    }
+
+    fn token_balance(setup: &MarketSetup, address: &Address) -> i128 {
+        token::Client::new(&setup.test_env.env, &setup.token).balance(address)
+    }
+
+    #[test]
+    fn test_partial_fills_across_two_buyers() {
+        let setup = setup_market();
+        let listing_id = list(&setup, 600);
+        assert_eq!(
+            setup
+                .client
+                .get_credit_balance(&setup.batch_id, &setup.test_env.user1),
+            400
+        );
+
+        let first = funded_buyer(&setup, 10_000);
+        let second = funded_buyer(&setup, 10_000);
+        setup.client.buy_credits(&first, &listing_id, &250);
+        let listing = setup.client.get_listing(&listing_id);
+        assert_eq!(listing.remaining, 350);
+        assert_eq!(listing.status, ListingStatus::Active);
+
+        setup.client.buy_credits(&second, &listing_id, &350);
+        let listing = setup.client.get_listing(&listing_id);
+        assert_eq!(listing.remaining, 0);
+        assert_eq!(listing.status, ListingStatus::Filled);
+
+        assert_eq!(
+            setup.client.get_credit_balance(&setup.batch_id, &first),
+            250
+        );
+        assert_eq!(
+            setup.client.get_credit_balance(&setup.batch_id, &second),
+            350
+        );
+        assert_eq!(token_balance(&setup, &first), 10_000 - 1_250);
+        assert_eq!(token_balance(&setup, &setup.test_env.user1), 3_000);
+
+        let result = setup.client.try_buy_credits(&first, &listing_id, &1);
+        assert_eq!(result, Err(Ok(ContractError::ListingNotActive)));
+    }
+
+    #[test]
+    fn test_fee_routing() {
+        let setup = setup_market();
+        let treasury = Address::generate(&setup.test_env.env);
+        setup
+            .client
+            .set_market_fee(&setup.test_env.admin, &250, &treasury);
+        let listing_id = list(&setup, 400);
+
+        let buyer = funded_buyer(&setup, 2_000);
+        setup.client.buy_credits(&buyer, &listing_id, &400);
+
+        // 2.5% of the 2_000 price goes to the platform
+        assert_eq!(token_balance(&setup, &treasury), 50);
+        assert_eq!(token_balance(&setup, &setup.test_env.user1), 1_950);
+        assert_eq!(token_balance(&setup, &buyer), 0);
+        assert_eq!(token_balance(&setup, &setup.test_env.contract_id), 0);
+
+        let result = setup
+            .client
+            .try_set_market_fee(&setup.test_env.admin, &10_001, &treasury);
+        assert_eq!(result, Err(Ok(ContractError::InvalidFee)));
+        let result = setup
+            .client
+            .try_set_market_fee(&setup.test_env.user1, &100, &treasury);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_cancel_returns_unsold_credits() {
+        let setup = setup_market();
+        let listing_id = list(&setup, 600);
+        let buyer = funded_buyer(&setup, 10_000);
+        setup.client.buy_credits(&buyer, &listing_id, &100);
+
+        let result = setup
+            .client
+            .try_cancel_listing(&setup.test_env.user2, &listing_id);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        setup
+            .client
+            .cancel_listing(&setup.test_env.user1, &listing_id);
+        assert_eq!(
+            setup.client.get_listing(&listing_id).status,
+            ListingStatus::Cancelled
+        );
+        assert_eq!(
+            setup
+                .client
+                .get_credit_balance(&setup.batch_id, &setup.test_env.user1),
+            900
+        );
+
+        let result = setup.client.try_buy_credits(&buyer, &listing_id, &1);
+        assert_eq!(result, Err(Ok(ContractError::ListingNotActive)));
+        let result = setup
+            .client
+            .try_cancel_listing(&setup.test_env.user1, &listing_id);
+        assert_eq!(result, Err(Ok(ContractError::ListingNotActive)));
+    }
+
+    #[test]
+    fn test_insufficient_payment_fails() {
+        let setup = setup_market();
+        let listing_id = list(&setup, 600);
+        let buyer = funded_buyer(&setup, 999);
+
+        let result = setup.client.try_buy_credits(&buyer, &listing_id, &200);
+        assert_eq!(result, Err(Ok(ContractError::InsufficientPayment)));
+        assert_eq!(token_balance(&setup, &buyer), 999);
+        assert_eq!(setup.client.get_listing(&listing_id).remaining, 600);
+
+        let result = setup.client.try_buy_credits(&buyer, &listing_id, &601);
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+    }
+
+    #[test]
+    fn test_listing_requires_balance() {
+        let setup = setup_market();
+
+        let result = setup.client.try_list_credits_for_sale(
+            &setup.test_env.user1,
+            &setup.batch_id,
+            &(STANDARD_CARBON_AMOUNT + 1),
+            &UNIT_PRICE,
+            &setup.token,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        let result = setup.client.try_list_credits_for_sale(
+            &setup.test_env.user1,
+            &setup.batch_id,
+            &10,
+            &0,
+            &setup.token,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+        // Escrowed credits cannot be transferred or listed twice
+        list(&setup, STANDARD_CARBON_AMOUNT);
+        let result = setup.client.try_transfer_credits(
+            &setup.test_env.user1,
+            &setup.test_env.user2,
+            &setup.batch_id,
+            &1,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+    }
+
+    #[test]
+    fn test_active_listings_pagination() {
+        let setup = setup_market();
+        let first = list(&setup, 100);
+        let second = list(&setup, 100);
+        let third = list(&setup, 100);
+        let fourth = list(&setup, 100);
+        setup.client.cancel_listing(&setup.test_env.user1, &second);
+
+        let page = setup.client.list_active_listings(&setup.project_id, &0, &2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().listing_id, first);
+        assert_eq!(page.get(1).unwrap().listing_id, third);
+
+        let page = setup.client.list_active_listings(&setup.project_id, &2, &2);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().listing_id, fourth);
+
+        let other =
+            setup
+                .client
+                .list_active_listings(&create_project_id(&setup.test_env.env, 2), &0, &10);
+        assert_eq!(other.len(), 0);
+    }
+}
@@ -7,11 +7,19 @@
 // - recording: Tests for environmental impact data recording
 // - reporting: Tests for impact report generation
 // - compliance: Tests for compliance verification
+// - impact: Tests for practice-based impact records and their verification
+// - market: Tests for escrowed credit sales
 // - retirement: Tests for credit retirement operations
+// - standards: Tests for compliance against environmental standards
+// - transfers: Tests for credit transfers and retirement certificates
 
 pub mod utils;
 
 pub mod compliance;
+pub mod impact;
+pub mod market;
 pub mod recording;
 pub mod reporting;
 pub mod retirement;
+pub mod standards;
+pub mod transfers;
@@ -0,0 +1,258 @@
+//! Tests for compliance against environmental standards
+//!
+//! This module tests standards and attestations, including:
+//! - Compliance evaluated from verified impact records
+//! - Failure details for unmet requirements
+//! - Attestations that survive later changes to a standard
+//! - Issuance gated on a compliant attestation
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Symbol};
+
+    use crate::error::ContractError;
+    use crate::tests::utils::*;
+    use crate::EnvironmentalContractClient;
+
+    const PERIOD: u64 = 2024;
+
+    struct StandardSetup<'a> {
+        test_env: TestEnv,
+        client: EnvironmentalContractClient<'a>,
+        verifier: Address,
+        farm_id: BytesN<32>,
+    }
+
+    fn standard() -> Symbol {
+        symbol_short!("REVO_AG1")
+    }
+
+    /// Initialized contract with a verifier and REVO_AG1 requiring 10 ha of cover crops and
+    /// 20 ha of reduced tillage
+    fn setup_standard<'a>() -> StandardSetup<'a> {
+        let test_env = setup_test();
+        test_env.env.mock_all_auths();
+        let client = EnvironmentalContractClient::new(&test_env.env, &test_env.contract_id);
+        client.initialize(&test_env.admin);
+
+        let verifier = Address::generate(&test_env.env);
+        client.register_impact_verifier(&test_env.admin, &verifier);
+        client.define_standard(
+            &test_env.admin,
+            &standard(),
+            &vec![
+                &test_env.env,
+                (symbol_short!("cover_ha"), 10),
+                (symbol_short!("no_till"), 20),
+            ],
+        );
+        let farm_id = create_project_id(&test_env.env, 1);
+
+        StandardSetup {
+            test_env,
+            client,
+            verifier,
+            farm_id,
+        }
+    }
+
+    /// Records and verifies impact for the farm
+    fn verified_impact(setup: &StandardSetup, period: u64, metric: Symbol, value: i128) {
+        let evidence = BytesN::from_array(&setup.test_env.env, &[1; 32]);
+        let record_id = setup.client.record_impact(
+            &setup.test_env.user1,
+            &setup.farm_id,
+            &period,
+            &metric,
+            &value,
+            &evidence,
+        );
+        setup
+            .client
+            .verify_impact(&setup.verifier, &record_id, &true, &evidence);
+    }
+
+    #[test]
+    fn test_compliant_farm_passes() {
+        let setup = setup_standard();
+        verified_impact(&setup, PERIOD, symbol_short!("cover_ha"), 6);
+        verified_impact(&setup, PERIOD, symbol_short!("cover_ha"), 4);
+        verified_impact(&setup, PERIOD, symbol_short!("no_till"), 25);
+
+        let report = setup
+            .client
+            .check_compliance(&setup.farm_id, &standard(), &PERIOD);
+        assert!(report.compliant);
+        assert_eq!(report.failed_requirements.len(), 0);
+    }
+
+    #[test]
+    fn test_partial_failure_detail() {
+        let setup = setup_standard();
+        verified_impact(&setup, PERIOD, symbol_short!("cover_ha"), 12);
+        verified_impact(&setup, PERIOD, symbol_short!("no_till"), 5);
+        // Other periods and unverified records do not count
+        verified_impact(&setup, PERIOD - 1, symbol_short!("no_till"), 50);
+        setup.client.record_impact(
+            &setup.test_env.user1,
+            &setup.farm_id,
+            &PERIOD,
+            &symbol_short!("no_till"),
+            &50,
+            &BytesN::from_array(&setup.test_env.env, &[2; 32]),
+        );
+
+        let report = setup
+            .client
+            .check_compliance(&setup.farm_id, &standard(), &PERIOD);
+        assert!(!report.compliant);
+        assert_eq!(
+            report.failed_requirements,
+            vec![&setup.test_env.env, (symbol_short!("no_till"), 20, 5)]
+        );
+
+        let report = setup.client.check_compliance(
+            &create_project_id(&setup.test_env.env, 2),
+            &standard(),
+            &PERIOD,
+        );
+        assert_eq!(report.failed_requirements.len(), 2);
+    }
+
+    #[test]
+    fn test_standard_update_not_retroactive() {
+        let setup = setup_standard();
+        verified_impact(&setup, PERIOD, symbol_short!("cover_ha"), 10);
+        verified_impact(&setup, PERIOD, symbol_short!("no_till"), 20);
+
+        let attestation = setup.client.record_compliance_attestation(
+            &setup.verifier,
+            &setup.farm_id,
+            &standard(),
+            &PERIOD,
+        );
+        assert!(attestation.report.compliant);
+        assert_eq!(attestation.standard_version, 1);
+
+        // Tightening the standard changes new checks only
+        setup.client.define_standard(
+            &setup.test_env.admin,
+            &standard(),
+            &vec![&setup.test_env.env, (symbol_short!("cover_ha"), 50)],
+        );
+        assert_eq!(setup.client.get_standard(&standard()).version, 2);
+        let report = setup
+            .client
+            .check_compliance(&setup.farm_id, &standard(), &PERIOD);
+        assert!(!report.compliant);
+
+        let stored = setup
+            .client
+            .get_compliance_attestation(&setup.farm_id, &standard(), &PERIOD);
+        assert_eq!(stored, attestation);
+        assert_eq!(stored.requirements.len(), 2);
+    }
+
+    #[test]
+    fn test_attestation_requires_verifier() {
+        let setup = setup_standard();
+
+        let result = setup.client.try_record_compliance_attestation(
+            &setup.test_env.user2,
+            &setup.farm_id,
+            &standard(),
+            &PERIOD,
+        );
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        let result =
+            setup
+                .client
+                .try_get_compliance_attestation(&setup.farm_id, &standard(), &PERIOD);
+        assert_eq!(result, Err(Ok(ContractError::AttestationNotFound)));
+    }
+
+    #[test]
+    fn test_standard_validation() {
+        let setup = setup_standard();
+
+        let result = setup.client.try_define_standard(
+            &setup.test_env.user1,
+            &symbol_short!("OTHER"),
+            &vec![&setup.test_env.env, (symbol_short!("cover_ha"), 1)],
+        );
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        let result = setup.client.try_define_standard(
+            &setup.test_env.admin,
+            &symbol_short!("OTHER"),
+            &vec![&setup.test_env.env],
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidStandard)));
+
+        let result = setup.client.try_define_standard(
+            &setup.test_env.admin,
+            &symbol_short!("OTHER"),
+            &vec![&setup.test_env.env, (symbol_short!("cover_ha"), 0)],
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidStandard)));
+
+        let result =
+            setup
+                .client
+                .try_check_compliance(&setup.farm_id, &symbol_short!("UNKNOWN"), &PERIOD);
+        assert_eq!(result, Err(Ok(ContractError::StandardNotFound)));
+    }
+
+    #[test]
+    fn test_issuance_can_require_attestation() {
+        let setup = setup_standard();
+        setup
+            .client
+            .set_issuance_standard(&setup.test_env.admin, &Some(standard()));
+
+        let issue = |seed: u8| {
+            setup.client.try_issue_credit_batch(
+                &setup.test_env.admin,
+                &setup.test_env.user1,
+                &create_credit_id(&setup.test_env.env, seed),
+                &setup.farm_id,
+                &STANDARD_CARBON_AMOUNT,
+                &standard_verification_method(&setup.test_env.env),
+            )
+        };
+        assert_eq!(issue(1), Err(Ok(ContractError::ComplianceRequired)));
+
+        // A failing attestation does not unlock issuance
+        verified_impact(&setup, PERIOD, symbol_short!("cover_ha"), 10);
+        setup.client.record_compliance_attestation(
+            &setup.verifier,
+            &setup.farm_id,
+            &standard(),
+            &PERIOD,
+        );
+        assert_eq!(issue(1), Err(Ok(ContractError::ComplianceRequired)));
+
+        verified_impact(&setup, PERIOD, symbol_short!("no_till"), 20);
+        setup.client.record_compliance_attestation(
+            &setup.verifier,
+            &setup.farm_id,
+            &standard(),
+            &PERIOD,
+        );
+        assert!(issue(1).is_ok());
+
+        setup
+            .client
+            .set_issuance_standard(&setup.test_env.admin, &None);
+        let result = setup.client.try_issue_credit_batch(
+            &setup.test_env.admin,
+            &setup.test_env.user1,
+            &create_credit_id(&setup.test_env.env, 2),
+            &create_project_id(&setup.test_env.env, 2),
+            &STANDARD_CARBON_AMOUNT,
+            &standard_verification_method(&setup.test_env.env),
+        );
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,283 @@
+//! Tests for credit transfers and retirement certificates
+//!
+//! This module tests batch holdings, including:
+//! - Transfers between holders
+//! - Partial and full retirement against a beneficiary
+//! - Double-spend prevention after retirement
+//! - Retirement certificate queries
+//!
+//! Calls go through the contract client because each holder authorizes several calls.
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, String};
+
+    use crate::datatypes::RetirementStatus;
+    use crate::error::ContractError;
+    use crate::interfaces::ReportingContract;
+    use crate::tests::utils::*;
+    use crate::{EnvironmentalContract, EnvironmentalContractClient};
+
+    fn client(test_env: &TestEnv) -> EnvironmentalContractClient<'_> {
+        test_env.env.mock_all_auths();
+        let client = EnvironmentalContractClient::new(&test_env.env, &test_env.contract_id);
+        client.initialize(&test_env.admin);
+        client
+    }
+
+    /// Issues a standard batch held by `user1`
+    fn issue_batch(
+        test_env: &TestEnv,
+        client: &EnvironmentalContractClient,
+        seed: u8,
+    ) -> BytesN<32> {
+        let batch_id = create_credit_id(&test_env.env, seed);
+        client.issue_credit_batch(
+            &test_env.admin,
+            &test_env.user1,
+            &batch_id,
+            &create_project_id(&test_env.env, 1),
+            &STANDARD_CARBON_AMOUNT,
+            &standard_verification_method(&test_env.env),
+        );
+        batch_id
+    }
+
+    fn reason(test_env: &TestEnv) -> String {
+        String::from_str(&test_env.env, "2024 scope 1 offset")
+    }
+
+    #[test]
+    fn test_transfer_then_retire() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let batch_id = issue_batch(&test_env, &client, 1);
+        assert_eq!(
+            client.get_credit_balance(&batch_id, &test_env.user1),
+            STANDARD_CARBON_AMOUNT
+        );
+
+        client.transfer_credits(&test_env.user1, &test_env.user2, &batch_id, &400);
+        assert_eq!(client.get_credit_balance(&batch_id, &test_env.user1), 600);
+        assert_eq!(client.get_credit_balance(&batch_id, &test_env.user2), 400);
+
+        let retirement_id = client.retire_credits(
+            &test_env.user2,
+            &batch_id,
+            &300,
+            &test_env.admin,
+            &reason(&test_env),
+        );
+
+        let certificate = client.get_retirement(&retirement_id);
+        assert_eq!(certificate.retirement_id, retirement_id);
+        assert_eq!(certificate.batch_id, batch_id);
+        assert_eq!(certificate.holder, test_env.user2);
+        assert_eq!(certificate.beneficiary, test_env.admin);
+        assert_eq!(certificate.quantity, 300);
+        assert_eq!(certificate.reason, reason(&test_env));
+
+        assert_eq!(client.get_credit_balance(&batch_id, &test_env.user2), 100);
+        assert_eq!(client.get_transferable_supply(&batch_id), 700);
+
+        // Partially retired batches remain available
+        assert_eq!(
+            client.get_credit_status(&batch_id),
+            RetirementStatus::Available
+        );
+    }
+
+    #[test]
+    fn test_retired_credits_cannot_be_transferred() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let batch_id = issue_batch(&test_env, &client, 1);
+
+        client.retire_credits(
+            &test_env.user1,
+            &batch_id,
+            &STANDARD_CARBON_AMOUNT,
+            &test_env.user1,
+            &reason(&test_env),
+        );
+
+        let result = client.try_transfer_credits(&test_env.user1, &test_env.user2, &batch_id, &1);
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        let result = client.try_retire_credits(
+            &test_env.user1,
+            &batch_id,
+            &1,
+            &test_env.user1,
+            &reason(&test_env),
+        );
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        assert_eq!(client.get_transferable_supply(&batch_id), 0);
+        assert_eq!(
+            client.get_credit_status(&batch_id),
+            RetirementStatus::Retired(test_env.user1.clone())
+        );
+    }
+
+    #[test]
+    fn test_over_retirement_fails() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let batch_id = issue_batch(&test_env, &client, 1);
+        client.transfer_credits(&test_env.user1, &test_env.user2, &batch_id, &100);
+
+        let result = client.try_retire_credits(
+            &test_env.user2,
+            &batch_id,
+            &101,
+            &test_env.user2,
+            &reason(&test_env),
+        );
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        let result = client.try_retire_credits(
+            &test_env.user2,
+            &batch_id,
+            &0,
+            &test_env.user2,
+            &reason(&test_env),
+        );
+        assert_eq!(result, Err(Ok(ContractError::ZeroAmount)));
+    }
+
+    #[test]
+    fn test_transfer_validation() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let batch_id = issue_batch(&test_env, &client, 1);
+
+        let result = client.try_transfer_credits(
+            &test_env.user1,
+            &test_env.user2,
+            &batch_id,
+            &(STANDARD_CARBON_AMOUNT + 1),
+        );
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        let result = client.try_transfer_credits(&test_env.user1, &test_env.user2, &batch_id, &0);
+        assert_eq!(result, Err(Ok(ContractError::ZeroAmount)));
+
+        let result = client.try_transfer_credits(
+            &test_env.user1,
+            &test_env.user2,
+            &create_credit_id(&test_env.env, 9),
+            &1,
+        );
+        assert_eq!(result, Err(Ok(ContractError::CreditNotFound)));
+    }
+
+    #[test]
+    fn test_only_issuers_can_issue_batches() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let issue = |issuer: &Address, seed: u8| {
+            client.try_issue_credit_batch(
+                issuer,
+                &test_env.user2,
+                &create_credit_id(&test_env.env, seed),
+                &create_project_id(&test_env.env, 1),
+                &STANDARD_CARBON_AMOUNT,
+                &standard_verification_method(&test_env.env),
+            )
+        };
+
+        // A holder cannot mint credits to itself
+        assert_eq!(
+            issue(&test_env.user2, 1),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(
+            client.get_credit_balance(&create_credit_id(&test_env.env, 1), &test_env.user2),
+            0
+        );
+
+        client.register_credit_issuer(&test_env.admin, &test_env.user1);
+        assert!(issue(&test_env.user1, 1).is_ok());
+        assert_eq!(
+            client.get_credit_balance(&create_credit_id(&test_env.env, 1), &test_env.user2),
+            STANDARD_CARBON_AMOUNT
+        );
+
+        client.remove_credit_issuer(&test_env.admin, &test_env.user1);
+        assert_eq!(
+            issue(&test_env.user1, 2),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_register_credit_issuer(&test_env.user1, &test_env.user1),
+            Err(Ok(ContractError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_retirements_by_beneficiary() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let beneficiary = Address::generate(&test_env.env);
+        let first = issue_batch(&test_env, &client, 1);
+        let second = issue_batch(&test_env, &client, 2);
+
+        let id1 = client.retire_credits(
+            &test_env.user1,
+            &first,
+            &100,
+            &beneficiary,
+            &reason(&test_env),
+        );
+        let id2 = client.retire_credits(
+            &test_env.user1,
+            &first,
+            &100,
+            &beneficiary,
+            &reason(&test_env),
+        );
+        client.retire_credits(
+            &test_env.user1,
+            &second,
+            &50,
+            &test_env.user2,
+            &reason(&test_env),
+        );
+        assert_ne!(id1, id2);
+
+        let retirements = client.list_retirements_by_beneficiary(&beneficiary);
+        assert_eq!(retirements.len(), 2);
+        assert_eq!(retirements.get(0).unwrap().retirement_id, id1);
+        assert_eq!(retirements.get(1).unwrap().retirement_id, id2);
+
+        let others = client.list_retirements_by_beneficiary(&test_env.user2);
+        assert_eq!(others.len(), 1);
+        assert_eq!(others.get(0).unwrap().batch_id, second);
+
+        let result = client.try_get_retirement(&create_credit_id(&test_env.env, 42));
+        assert_eq!(result, Err(Ok(ContractError::RetirementNotFound)));
+    }
+
+    #[test]
+    fn test_partial_retirements_count_in_impact_report() {
+        let test_env = setup_test();
+        let client = client(&test_env);
+        let batch_id = issue_batch(&test_env, &client, 1);
+        client.retire_credits(
+            &test_env.user1,
+            &batch_id,
+            &250,
+            &test_env.user1,
+            &reason(&test_env),
+        );
+
+        test_env.env.as_contract(&test_env.contract_id, || {
+            let total = EnvironmentalContract::generate_impact_report(
+                &test_env.env,
+                create_project_id(&test_env.env, 1),
+            );
+            assert_eq!(total, 250);
+        });
+    }
+}
@@ -1,6 +1,5 @@
-use soroban_sdk::{
-    contracttype, symbol_short, Address, BytesN, Env, Error, Map, String, Symbol, Vec,
-};
+use crate::error::ContractError;
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 /// Status of equipment maintenance
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
@@ -40,14 +39,14 @@ pub fn register_equipment(
     equipment_type: String,
     rental_price_per_day: i128,
     location: String,
-) {
+) -> Result<(), ContractError> {
     let mut equipment_map: Map<BytesN<32>, Equipment> = env
         .storage()
         .persistent()
         .get(&EQUIPMENT_STORAGE)
         .unwrap_or(Map::new(env));
     if equipment_map.contains_key(id.clone()) {
-        panic!("Equipment already registered");
+        return Err(ContractError::EquipmentAlreadyRegistered);
     }
     let owner = env.current_contract_address();
     let equipment = Equipment {
@@ -63,6 +62,7 @@ pub fn register_equipment(
     env.storage()
         .persistent()
         .set(&EQUIPMENT_STORAGE, &equipment_map);
+    Ok(())
 }
 
 /// Change the availability status of equipment
@@ -71,7 +71,7 @@ pub fn update_availability(
     id: BytesN<32>,
     caller: Address,
     available: bool,
-) -> Result<(), Error> {
+) -> Result<(), ContractError> {
     let mut equipment_map: Map<BytesN<32>, Equipment> = env
         .storage()
         .persistent()
@@ -79,12 +79,12 @@ pub fn update_availability(
         .unwrap_or(Map::new(env));
 
     if !equipment_map.contains_key(id.clone()) {
-        return Err(Error::from_contract_error(1006));
+        return Err(ContractError::EquipmentNotFound);
     }
 
     let mut equipment = equipment_map.get_unchecked(id.clone());
     if equipment.owner != caller {
-        return Err(Error::from_contract_error(1007));
+        return Err(ContractError::Unauthorized);
     }
 
     equipment.available = available;
@@ -101,7 +101,7 @@ pub fn update_maintenance_status(
     id: BytesN<32>,
     caller: Address,
     status: MaintenanceStatus,
-) -> Result<(), Error> {
+) -> Result<(), ContractError> {
     let mut equipment_map: Map<BytesN<32>, Equipment> = env
         .storage()
         .persistent()
@@ -109,12 +109,12 @@ pub fn update_maintenance_status(
         .unwrap_or(Map::new(env));
 
     if !equipment_map.contains_key(id.clone()) {
-        return Err(Error::from_contract_error(1006));
+        return Err(ContractError::EquipmentNotFound);
     }
 
     let mut equipment = equipment_map.get_unchecked(id.clone());
     if equipment.owner != caller {
-        return Err(Error::from_contract_error(1007));
+        return Err(ContractError::Unauthorized);
     }
 
     equipment.maintenance_status = status;
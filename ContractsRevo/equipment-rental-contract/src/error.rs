@@ -0,0 +1,42 @@
+use soroban_sdk::contracterror;
+
+/// Errors returned by the equipment rental contract
+///
+/// Codes 1001-1003 and 1007-1012 keep the values returned before this enum
+/// existed. Codes 1004-1006 are no longer used.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ContractError {
+    /// No equipment is registered under the given ID
+    EquipmentNotFound = 1001,
+    /// Rental period is invalid or its price overflows
+    InvalidRentalPeriod = 1002,
+    /// Proposed price is outside the allowed tolerance
+    PriceMismatch = 1003,
+    /// Contract admin is already set
+    AlreadyInitialized = 1007,
+    /// Caller is not allowed to perform this action
+    Unauthorized = 1008,
+    /// Insurance was requested but no provider is configured
+    InsuranceNotConfigured = 1009,
+    /// The insurance provider failed to write a policy
+    InsurancePolicyFailed = 1010,
+    /// Premium rate is zero or above 100%
+    InvalidPremiumRate = 1011,
+    /// No rental exists for the given equipment
+    RentalNotFound = 1012,
+    /// Equipment ID is already registered
+    EquipmentAlreadyRegistered = 1013,
+    /// Equipment is marked as unavailable
+    EquipmentUnavailable = 1014,
+    /// Equipment needs service or is under maintenance
+    EquipmentUnderMaintenance = 1015,
+    /// Equipment already has a pending or active rental
+    RentalAlreadyExists = 1016,
+    /// Rental is not pending
+    RentalNotPending = 1017,
+    /// Rental is not active
+    RentalNotActive = 1018,
+    /// The insurer refused to cancel the rental's policy
+    InsuranceRefundFailed = 1019,
+}
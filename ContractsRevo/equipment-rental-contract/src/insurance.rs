@@ -0,0 +1,226 @@
+use crate::error::ContractError;
+use soroban_sdk::{
+    contractclient, contracttype, symbol_short, token, Address, BytesN, Env, Symbol, Vec,
+};
+
+/// Insurance provider used for optional damage coverage at booking time
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InsuranceProvider {
+    /// Insurance contract that writes the rental policies
+    pub insurance_contract: Address,
+    /// Coverage product the policies are written under
+    pub coverage_symbol: Symbol,
+    /// Token the premium is paid in
+    pub premium_token: Address,
+    /// Premium as basis points of the total rental price
+    pub premium_bps: u32,
+}
+
+/// Damage reported against a rental, referencing its insurance policy if any
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DamageReport {
+    /// Equipment that was damaged
+    pub equipment_id: BytesN<32>,
+    /// Renter at the time of the damage
+    pub renter: Address,
+    /// Hash of the off-chain damage evidence
+    pub evidence_hash: BytesN<32>,
+    /// Policy covering the rental, if insurance was purchased
+    pub policy_id: Option<BytesN<32>>,
+    /// When the damage was reported (UNIX timestamp)
+    pub reported_at: u64,
+}
+
+/// Interface the insurance contract exposes for rental-scoped policies
+#[allow(dead_code)]
+#[contractclient(name = "RentalInsurerClient")]
+pub trait RentalInsurer {
+    /// Write a policy for `insured` covering `[coverage_start, coverage_end]`.
+    /// The premium has already been transferred to the insurance contract.
+    #[allow(clippy::too_many_arguments)]
+    fn create_rental_policy(
+        env: Env,
+        platform: Address,
+        insured: Address,
+        coverage: Symbol,
+        coverage_start: u64,
+        coverage_end: u64,
+        premium: i128,
+        reference: BytesN<32>,
+    ) -> BytesN<32>;
+
+    /// Cancel a policy written by `platform`, refunding the premium to the insured
+    fn cancel_rental_policy(env: Env, platform: Address, policy_id: BytesN<32>) -> i128;
+}
+
+const ADMIN: Symbol = symbol_short!("admin");
+const INSURANCE_PROVIDER: Symbol = symbol_short!("insurer");
+const POLICY_INSURER: Symbol = symbol_short!("pol_ins");
+const DAMAGE_REPORTS: Symbol = symbol_short!("damage");
+
+/// Set the admin allowed to configure the insurance provider (once)
+///
+/// Must be authorized by the platform account that owns the listed equipment
+pub fn initialize(env: &Env, admin: Address) -> Result<(), ContractError> {
+    if env.storage().instance().has(&ADMIN) {
+        return Err(ContractError::AlreadyInitialized);
+    }
+    env.current_contract_address().require_auth();
+    admin.require_auth();
+    env.storage().instance().set(&ADMIN, &admin);
+    Ok(())
+}
+
+/// Configure the insurance provider and premium rate (admin only)
+pub fn set_insurance_provider(
+    env: &Env,
+    admin: Address,
+    provider: InsuranceProvider,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    let stored_admin: Option<Address> = env.storage().instance().get(&ADMIN);
+    if stored_admin != Some(admin) {
+        return Err(ContractError::Unauthorized);
+    }
+    if provider.premium_bps == 0 || provider.premium_bps > 10_000 {
+        return Err(ContractError::InvalidPremiumRate);
+    }
+    env.storage().instance().set(&INSURANCE_PROVIDER, &provider);
+    env.events().publish(
+        (
+            symbol_short!("ins_set"),
+            provider.insurance_contract.clone(),
+        ),
+        (provider.coverage_symbol, provider.premium_bps),
+    );
+    Ok(())
+}
+
+/// Retrieve the configured insurance provider
+pub fn get_insurance_provider(env: &Env) -> Option<InsuranceProvider> {
+    env.storage().instance().get(&INSURANCE_PROVIDER)
+}
+
+/// Premium owed for insuring a rental of the given total price
+pub fn compute_premium(provider: &InsuranceProvider, total_price: i128) -> i128 {
+    total_price * provider.premium_bps as i128 / 10_000
+}
+
+/// Collect the premium from the renter and have the insurer write a policy
+/// covering the rental window. Returns the policy id and the premium paid.
+pub fn purchase_policy(
+    env: &Env,
+    equipment_id: &BytesN<32>,
+    renter: &Address,
+    start_date: u64,
+    end_date: u64,
+    total_price: i128,
+) -> Result<(BytesN<32>, i128), ContractError> {
+    let provider = get_insurance_provider(env).ok_or(ContractError::InsuranceNotConfigured)?;
+    renter.require_auth();
+
+    let premium = compute_premium(&provider, total_price);
+    token::Client::new(env, &provider.premium_token).transfer(
+        renter,
+        &provider.insurance_contract,
+        &premium,
+    );
+
+    let insurer = RentalInsurerClient::new(env, &provider.insurance_contract);
+    let policy_id = match insurer.try_create_rental_policy(
+        &env.current_contract_address(),
+        renter,
+        &provider.coverage_symbol,
+        &start_date,
+        &end_date,
+        &premium,
+        equipment_id,
+    ) {
+        Ok(Ok(policy_id)) => policy_id,
+        _ => return Err(ContractError::InsurancePolicyFailed),
+    };
+    env.storage().persistent().set(
+        &(POLICY_INSURER, policy_id.clone()),
+        &provider.insurance_contract,
+    );
+
+    env.events().publish(
+        (symbol_short!("insured"), equipment_id.clone()),
+        (renter.clone(), policy_id.clone(), premium),
+    );
+
+    Ok((policy_id, premium))
+}
+
+/// Have the insurer that wrote the policy cancel it and refund the premium to the renter.
+/// Returns the amount refunded.
+pub fn cancel_policy(
+    env: &Env,
+    equipment_id: &BytesN<32>,
+    policy_id: &BytesN<32>,
+) -> Result<i128, ContractError> {
+    let insurance_contract: Address = env
+        .storage()
+        .persistent()
+        .get(&(POLICY_INSURER, policy_id.clone()))
+        .ok_or(ContractError::InsuranceRefundFailed)?;
+
+    let insurer = RentalInsurerClient::new(env, &insurance_contract);
+    let platform = env.current_contract_address();
+    let refund = match insurer.try_cancel_rental_policy(&platform, policy_id) {
+        Ok(Ok(refund)) => refund,
+        _ => return Err(ContractError::InsuranceRefundFailed),
+    };
+
+    env.events().publish(
+        (symbol_short!("ins_rfnd"), equipment_id.clone()),
+        (policy_id.clone(), refund),
+    );
+
+    Ok(refund)
+}
+
+/// Record damage found on returned equipment against the current rental
+pub fn report_damage(
+    env: &Env,
+    equipment_id: BytesN<32>,
+    evidence_hash: BytesN<32>,
+) -> Result<DamageReport, ContractError> {
+    let rental = crate::rental::get_rental(env, equipment_id.clone())
+        .ok_or(ContractError::RentalNotFound)?;
+
+    let report = DamageReport {
+        equipment_id: equipment_id.clone(),
+        renter: rental.renter,
+        evidence_hash,
+        policy_id: rental.policy_id,
+        reported_at: env.ledger().timestamp(),
+    };
+
+    let mut reports: Vec<DamageReport> = env
+        .storage()
+        .persistent()
+        .get(&(DAMAGE_REPORTS, equipment_id.clone()))
+        .unwrap_or(Vec::new(env));
+    reports.push_back(report.clone());
+    env.storage()
+        .persistent()
+        .set(&(DAMAGE_REPORTS, equipment_id.clone()), &reports);
+
+    env.events().publish(
+        (symbol_short!("damage"), equipment_id),
+        (report.renter.clone(), report.policy_id.clone()),
+    );
+
+    Ok(report)
+}
+
+/// Retrieve all damage reports for a given equipment
+pub fn get_damage_reports(env: &Env, equipment_id: BytesN<32>) -> Vec<DamageReport> {
+    env.storage()
+        .persistent()
+        .get(&(DAMAGE_REPORTS, equipment_id))
+        .unwrap_or(Vec::new(env))
+}
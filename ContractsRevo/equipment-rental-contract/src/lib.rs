@@ -1,11 +1,15 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Error, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 mod equipment;
+mod error;
+mod insurance;
 mod maintenance;
 mod pricing;
 mod rental;
 
+pub use error::ContractError;
+
 #[cfg(test)]
 mod tests;
 
@@ -15,6 +19,11 @@ pub struct EquipmentRentalContract;
 
 #[contractimpl]
 impl EquipmentRentalContract {
+    /// Set the admin allowed to configure rental insurance (platform account only)
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        crate::insurance::initialize(&env, admin)
+    }
+
     // Equipment management
     /// Register a new equipment item to the platform
     pub fn register_equipment(
@@ -23,32 +32,34 @@ impl EquipmentRentalContract {
         equipment_type: String,
         rental_price_per_day: i128,
         location: String,
-    ) {
+    ) -> Result<(), ContractError> {
         equipment::register_equipment(&env, id, equipment_type, rental_price_per_day, location)
     }
     /// Change the availability status of equipment
-    pub fn update_availability(env: Env, id: BytesN<32>, available: bool) -> Result<(), Error> {
+    pub fn update_availability(
+        env: Env,
+        id: BytesN<32>,
+        available: bool,
+    ) -> Result<(), ContractError> {
         // Get equipment and verify caller is the owner
         let equipment = crate::equipment::get_equipment(&env, id.clone())
-            .ok_or(Error::from_contract_error(1006))?;
+            .ok_or(ContractError::EquipmentNotFound)?;
         // Require authentication from the equipment owner
         equipment.owner.require_auth();
         crate::equipment::update_availability(&env, id, equipment.owner, available)
-            .map_err(|_| Error::from_contract_error(1004))
     }
     /// Mark equipment status (Good, NeedsService, UnderMaintenance)
     pub fn update_maintenance_status(
         env: Env,
         id: BytesN<32>,
         status: crate::equipment::MaintenanceStatus,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ContractError> {
         // Get equipment and verify caller is the owner
         let equipment = crate::equipment::get_equipment(&env, id.clone())
-            .ok_or(Error::from_contract_error(1006))?;
+            .ok_or(ContractError::EquipmentNotFound)?;
         // Require authentication from the equipment owner
         equipment.owner.require_auth();
         crate::equipment::update_maintenance_status(&env, id, equipment.owner, status)
-            .map_err(|_| Error::from_contract_error(1005))
     }
     /// Retrieve equipment details by ID
     pub fn get_equipment(env: Env, id: BytesN<32>) -> Option<crate::equipment::Equipment> {
@@ -57,6 +68,8 @@ impl EquipmentRentalContract {
 
     // Rental lifecycle
     /// Initiate a rental request for a given date range
+    /// With `with_insurance`, the renter also pays a damage insurance premium
+    /// and a policy covering the rental window is written by the insurer
     pub fn create_rental(
         env: Env,
         equipment_id: BytesN<32>,
@@ -64,7 +77,8 @@ impl EquipmentRentalContract {
         start_date: u64,
         end_date: u64,
         total_price: i128,
-    ) {
+        with_insurance: bool,
+    ) -> Result<(), ContractError> {
         crate::rental::create_rental(
             &env,
             equipment_id,
@@ -72,33 +86,34 @@ impl EquipmentRentalContract {
             start_date,
             end_date,
             total_price,
-        );
+            with_insurance,
+        )
     }
     /// Confirm and activate a rental
-    pub fn confirm_rental(env: Env, equipment_id: BytesN<32>) {
+    pub fn confirm_rental(env: Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
         // Get equipment owner and verify auth
         let equipment = crate::equipment::get_equipment(&env, equipment_id.clone())
-            .expect("Equipment not found");
+            .ok_or(ContractError::EquipmentNotFound)?;
         equipment.owner.require_auth();
-        crate::rental::confirm_rental(&env, equipment_id.clone());
+        crate::rental::confirm_rental(&env, equipment_id)
     }
     /// Finalize rental and release equipment
-    pub fn complete_rental(env: Env, equipment_id: BytesN<32>) {
+    pub fn complete_rental(env: Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
         // Get equipment owner and verify auth
         let equipment = crate::equipment::get_equipment(&env, equipment_id.clone())
-            .expect("Equipment not found");
+            .ok_or(ContractError::EquipmentNotFound)?;
         equipment.owner.require_auth();
-        crate::rental::complete_rental(&env, equipment_id.clone());
+        crate::rental::complete_rental(&env, equipment_id)
     }
-    /// Cancel a rental agreement before start date
-    pub fn cancel_rental(env: Env, equipment_id: BytesN<32>) {
+    /// Cancel a rental agreement before start date, refunding any insurance premium
+    pub fn cancel_rental(env: Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
         // Get rental details
-        let rental =
-            crate::rental::get_rental(&env, equipment_id.clone()).expect("Rental not found");
+        let rental = crate::rental::get_rental(&env, equipment_id.clone())
+            .ok_or(ContractError::RentalNotFound)?;
         // Either the renter or equipment owner can cancel
         let caller = env.current_contract_address();
         let equipment = crate::equipment::get_equipment(&env, equipment_id.clone())
-            .expect("Equipment not found");
+            .ok_or(ContractError::EquipmentNotFound)?;
         if caller == rental.renter {
             // Renter is cancelling
             rental.renter.require_auth();
@@ -106,9 +121,10 @@ impl EquipmentRentalContract {
             // Owner is cancelling
             equipment.owner.require_auth();
         } else {
-            panic!("Only the renter or equipment owner can cancel a rental");
+            // Only the renter or equipment owner can cancel a rental
+            return Err(ContractError::Unauthorized);
         }
-        crate::rental::cancel_rental(&env, equipment_id.clone());
+        crate::rental::cancel_rental(&env, equipment_id)
     }
     /// Retrieve rental details by equipment ID
     pub fn get_rental(env: Env, equipment_id: BytesN<32>) -> Option<crate::rental::Rental> {
@@ -126,6 +142,54 @@ impl EquipmentRentalContract {
         crate::rental::get_rental_history_by_user(&env, renter)
     }
 
+    // Insurance
+    /// Configure the insurance provider used for optional rental coverage (admin only)
+    pub fn set_insurance_provider(
+        env: Env,
+        admin: Address,
+        insurance_contract: Address,
+        coverage_symbol: Symbol,
+        premium_token: Address,
+        premium_bps: u32,
+    ) -> Result<(), ContractError> {
+        let provider = crate::insurance::InsuranceProvider {
+            insurance_contract,
+            coverage_symbol,
+            premium_token,
+            premium_bps,
+        };
+        crate::insurance::set_insurance_provider(&env, admin, provider)
+    }
+    /// Retrieve the configured insurance provider
+    pub fn get_insurance_provider(env: Env) -> Option<crate::insurance::InsuranceProvider> {
+        crate::insurance::get_insurance_provider(&env)
+    }
+    /// Compute the insurance premium for a rental price
+    pub fn compute_insurance_premium(env: Env, total_price: i128) -> Result<i128, ContractError> {
+        let provider = crate::insurance::get_insurance_provider(&env)
+            .ok_or(ContractError::InsuranceNotConfigured)?;
+        Ok(crate::insurance::compute_premium(&provider, total_price))
+    }
+    /// Report damage on rented equipment, referencing the rental's insurance policy
+    pub fn report_damage(
+        env: Env,
+        equipment_id: BytesN<32>,
+        evidence_hash: BytesN<32>,
+    ) -> Result<crate::insurance::DamageReport, ContractError> {
+        // Get equipment owner and verify auth
+        let equipment = crate::equipment::get_equipment(&env, equipment_id.clone())
+            .ok_or(ContractError::EquipmentNotFound)?;
+        equipment.owner.require_auth();
+        crate::insurance::report_damage(&env, equipment_id, evidence_hash)
+    }
+    /// Retrieve all damage reports for a given equipment
+    pub fn get_damage_reports(
+        env: Env,
+        equipment_id: BytesN<32>,
+    ) -> Vec<crate::insurance::DamageReport> {
+        crate::insurance::get_damage_reports(&env, equipment_id)
+    }
+
     // Pricing
     /// Compute total rental price for a date range
     pub fn compute_total_price(
@@ -133,11 +197,11 @@ impl EquipmentRentalContract {
         equipment_id: BytesN<32>,
         start_date: u64,
         end_date: u64,
-    ) -> Result<i128, Error> {
+    ) -> Result<i128, ContractError> {
         let eq = crate::equipment::get_equipment(&env, equipment_id)
-            .ok_or(Error::from_contract_error(1001))?;
+            .ok_or(ContractError::EquipmentNotFound)?;
         crate::pricing::compute_total_price(&eq, start_date, end_date)
-            .map_err(|_| Error::from_contract_error(1002))
+            .map_err(|_| ContractError::InvalidRentalPeriod)
     }
     /// Validate proposed rental price for a date range
     pub fn validate_price(
@@ -147,11 +211,14 @@ impl EquipmentRentalContract {
         end_date: u64,
         proposed_price: i128,
         tolerance: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ContractError> {
         let equipment =
-            equipment::get_equipment(&env, equipment_id).ok_or(Error::from_contract_error(1001))?;
+            equipment::get_equipment(&env, equipment_id).ok_or(ContractError::EquipmentNotFound)?;
         pricing::validate_price(&equipment, start_date, end_date, proposed_price, tolerance)
-            .map_err(|_| Error::from_contract_error(1003))
+            .map_err(|e| match e {
+                pricing::PriceValidationError::InvalidDate => ContractError::InvalidRentalPeriod,
+                pricing::PriceValidationError::PriceMismatch { .. } => ContractError::PriceMismatch,
+            })
     }
 
     // Maintenance
@@ -162,19 +229,29 @@ impl EquipmentRentalContract {
         status: crate::equipment::MaintenanceStatus,
         timestamp: u64,
         notes: Option<String>,
-    ) {
+    ) -> Result<(), ContractError> {
         // Get equipment and verify caller is the owner
         let equipment = crate::equipment::get_equipment(&env, equipment_id.clone())
-            .expect("Equipment not found");
+            .ok_or(ContractError::EquipmentNotFound)?;
         // Require authentication from the equipment owner
         equipment.owner.require_auth();
         crate::maintenance::log_maintenance(&env, equipment_id, status, timestamp, notes);
+        Ok(())
     }
-    /// Retrieve maintenance history for all equipment
+    /// Retrieve maintenance history, capped at the first 100 records
     pub fn get_maintenance_history(
         env: Env,
         equipment_id: Option<BytesN<32>>,
     ) -> Vec<crate::maintenance::MaintenanceRecord> {
         crate::maintenance::get_maintenance_history(&env, equipment_id)
     }
+    /// Retrieve one page of maintenance history with the total record count
+    pub fn get_maintenance_history_paged(
+        env: Env,
+        equipment_id: Option<BytesN<32>>,
+        offset: u32,
+        limit: u32,
+    ) -> crate::maintenance::MaintenanceHistoryPage {
+        crate::maintenance::get_maintenance_history_paged(&env, equipment_id, offset, limit)
+    }
 }
@@ -15,6 +15,21 @@ pub struct MaintenanceRecord {
     pub notes: Option<String>,
 }
 
+/// One page of maintenance history
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MaintenanceHistoryPage {
+    /// Records in this page, in the order they were logged
+    pub records: Vec<MaintenanceRecord>,
+    /// Number of matching records across all pages
+    pub total: u32,
+    /// Whether more matching records exist past this page
+    pub truncated: bool,
+}
+
+/// Largest number of records returned by a single history query
+pub const MAX_MAINTENANCE_PAGE_SIZE: u32 = 100;
+
 const MAINTENANCE_HISTORY_STORAGE: Symbol = symbol_short!("maint");
 
 /// Log a maintenance event for equipment
@@ -43,35 +58,25 @@ pub fn log_maintenance(
 }
 
 /// Retrieve maintenance history, optionally filtered by equipment ID
+///
+/// Returns at most `MAX_MAINTENANCE_PAGE_SIZE` records; use
+/// `get_maintenance_history_paged` to read past them.
 pub fn get_maintenance_history(
     env: &Env,
     equipment_id: Option<BytesN<32>>,
 ) -> Vec<MaintenanceRecord> {
-    let all_records: Vec<MaintenanceRecord> = env
-        .storage()
-        .persistent()
-        .get(&MAINTENANCE_HISTORY_STORAGE)
-        .unwrap_or(Vec::new(env));
-    if let Some(id) = equipment_id {
-        let mut filtered = Vec::new(env);
-        for r in all_records.iter() {
-            if r.equipment_id == id {
-                filtered.push_back(r.clone());
-            }
-        }
-        return filtered;
-    }
-    all_records
+    get_maintenance_history_paged(env, equipment_id, 0, MAX_MAINTENANCE_PAGE_SIZE).records
 }
 
-/// Retrieve maintenance history with optional filtering and pagination
-#[allow(dead_code)]
-pub fn get_maintenance_history_paginated(
+/// Retrieve one page of maintenance history, oldest first
+///
+/// `limit` is clamped to `MAX_MAINTENANCE_PAGE_SIZE`.
+pub fn get_maintenance_history_paged(
     env: &Env,
     equipment_id: Option<BytesN<32>>,
     offset: u32,
     limit: u32,
-) -> Vec<MaintenanceRecord> {
+) -> MaintenanceHistoryPage {
     let all_records: Vec<MaintenanceRecord> = env
         .storage()
         .persistent()
@@ -88,18 +93,19 @@ pub fn get_maintenance_history_paginated(
     } else {
         all_records
     };
-    let start = offset as usize;
-    let end = core::cmp::min(start + limit as usize, filtered.len() as usize);
-    if start >= filtered.len() as usize {
-        return Vec::new(env);
-    }
-    let mut result = Vec::new(env);
-    let start_usize = start;
-    let end_usize = end;
-    for i in start_usize..end_usize {
-        if let Some(r) = filtered.get(i as u32) {
-            result.push_back(r.clone());
-        }
+    let total = filtered.len();
+    let start = offset.min(total);
+    let end = start
+        .saturating_add(limit.min(MAX_MAINTENANCE_PAGE_SIZE))
+        .min(total);
+    let records = if start < end {
+        filtered.slice(start..end)
+    } else {
+        Vec::new(env)
+    };
+    MaintenanceHistoryPage {
+        records,
+        total,
+        truncated: end < total,
     }
-    result
 }
@@ -1,4 +1,5 @@
 use crate::equipment::{get_equipment, MaintenanceStatus};
+use crate::error::ContractError;
 use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, Symbol, Vec};
 
 /// Status of a rental agreement
@@ -31,6 +32,10 @@ pub struct Rental {
     pub total_price: i128,
     /// Current status of the rental
     pub status: RentalStatus,
+    /// Damage insurance policy purchased at booking time, if any
+    pub policy_id: Option<BytesN<32>>,
+    /// Insurance premium paid by the renter (0 if uninsured)
+    pub insurance_premium: i128,
 }
 
 const RENTAL_STORAGE: Symbol = symbol_short!("rental");
@@ -45,13 +50,15 @@ pub fn create_rental(
     start_date: u64,
     end_date: u64,
     total_price: i128,
-) {
-    let equipment = get_equipment(env, equipment_id.clone()).expect("Equipment not found");
+    with_insurance: bool,
+) -> Result<(), ContractError> {
+    let equipment =
+        get_equipment(env, equipment_id.clone()).ok_or(ContractError::EquipmentNotFound)?;
     if !equipment.available {
-        panic!("Equipment not available");
+        return Err(ContractError::EquipmentUnavailable);
     }
     if equipment.maintenance_status != MaintenanceStatus::Good {
-        panic!("Equipment under maintenance or needs service");
+        return Err(ContractError::EquipmentUnderMaintenance);
     }
     let mut rental_map: Map<BytesN<32>, Rental> = env
         .storage()
@@ -62,9 +69,22 @@ pub fn create_rental(
         if existing_rental.status == RentalStatus::Pending
             || existing_rental.status == RentalStatus::Active
         {
-            panic!("Rental already exists for this equipment");
+            return Err(ContractError::RentalAlreadyExists);
         }
     }
+    let (policy_id, insurance_premium) = if with_insurance {
+        let (policy_id, premium) = crate::insurance::purchase_policy(
+            env,
+            &equipment_id,
+            &renter,
+            start_date,
+            end_date,
+            total_price,
+        )?;
+        (Some(policy_id), premium)
+    } else {
+        (None, 0)
+    };
     let rental = Rental {
         equipment_id: equipment_id.clone(),
         renter: renter.clone(),
@@ -72,6 +92,8 @@ pub fn create_rental(
         end_date,
         total_price,
         status: RentalStatus::Pending,
+        policy_id,
+        insurance_premium,
     };
     rental_map.set(equipment_id.clone(), rental.clone());
     env.storage().persistent().set(&RENTAL_STORAGE, &rental_map);
@@ -96,10 +118,11 @@ pub fn create_rental(
     env.storage()
         .persistent()
         .set(&(RENTAL_HISTORY_BY_USER, renter), &user_history);
+    Ok(())
 }
 
 /// Confirm and activate a pending rental
-pub fn confirm_rental(env: &Env, equipment_id: BytesN<32>) {
+pub fn confirm_rental(env: &Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
     let mut rental_map: Map<BytesN<32>, Rental> = env
         .storage()
         .persistent()
@@ -107,17 +130,18 @@ pub fn confirm_rental(env: &Env, equipment_id: BytesN<32>) {
         .unwrap_or(Map::new(env));
     let mut rental = rental_map
         .get(equipment_id.clone())
-        .expect("Rental not found");
+        .ok_or(ContractError::RentalNotFound)?;
     if rental.status != RentalStatus::Pending {
-        panic!("Rental not pending");
+        return Err(ContractError::RentalNotPending);
     }
     rental.status = RentalStatus::Active;
     rental_map.set(equipment_id.clone(), rental);
     env.storage().persistent().set(&RENTAL_STORAGE, &rental_map);
+    Ok(())
 }
 
 /// Finalize rental and release equipment
-pub fn complete_rental(env: &Env, equipment_id: BytesN<32>) {
+pub fn complete_rental(env: &Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
     let mut rental_map: Map<BytesN<32>, Rental> = env
         .storage()
         .persistent()
@@ -125,9 +149,9 @@ pub fn complete_rental(env: &Env, equipment_id: BytesN<32>) {
         .unwrap_or(Map::new(env));
     let mut rental = rental_map
         .get(equipment_id.clone())
-        .expect("Rental not found");
+        .ok_or(ContractError::RentalNotFound)?;
     if rental.status != RentalStatus::Active {
-        panic!("Rental not active");
+        return Err(ContractError::RentalNotActive);
     }
     rental.status = RentalStatus::Completed;
 
@@ -159,13 +183,14 @@ pub fn complete_rental(env: &Env, equipment_id: BytesN<32>) {
     env.storage().persistent().set(&RENTAL_STORAGE, &rental_map);
 
     // Mark equipment as available again
-    let equipment =
-        crate::equipment::get_equipment(env, equipment_id.clone()).expect("Equipment not found");
-    let _ = crate::equipment::update_availability(env, equipment_id, equipment.owner, true);
+    let equipment = crate::equipment::get_equipment(env, equipment_id.clone())
+        .ok_or(ContractError::EquipmentNotFound)?;
+    crate::equipment::update_availability(env, equipment_id, equipment.owner, true)
 }
 
-/// Cancel a rental agreement before it starts
-pub fn cancel_rental(env: &Env, equipment_id: BytesN<32>) {
+/// Cancel a rental agreement before it starts, cancelling its insurance policy
+/// and refunding the premium to the renter
+pub fn cancel_rental(env: &Env, equipment_id: BytesN<32>) -> Result<(), ContractError> {
     let mut rental_map: Map<BytesN<32>, Rental> = env
         .storage()
         .persistent()
@@ -173,11 +198,14 @@ pub fn cancel_rental(env: &Env, equipment_id: BytesN<32>) {
         .unwrap_or(Map::new(env));
     let mut rental = rental_map
         .get(equipment_id.clone())
-        .expect("Rental not found");
+        .ok_or(ContractError::RentalNotFound)?;
     if rental.status != RentalStatus::Pending {
-        panic!("Only pending rentals can be cancelled");
+        return Err(ContractError::RentalNotPending);
     }
     rental.status = RentalStatus::Cancelled;
+    if let Some(policy_id) = &rental.policy_id {
+        crate::insurance::cancel_policy(env, &equipment_id, policy_id)?;
+    }
 
     // Update the rental in history with cancelled status
     let mut eq_history = env
@@ -221,6 +249,7 @@ pub fn cancel_rental(env: &Env, equipment_id: BytesN<32>) {
 
     rental_map.set(equipment_id.clone(), rental);
     env.storage().persistent().set(&RENTAL_STORAGE, &rental_map);
+    Ok(())
 }
 
 /// Retrieve rental details by equipment ID
@@ -217,6 +217,7 @@ fn test_create_rental_success() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     // Verify rental was created
@@ -264,6 +265,7 @@ fn test_create_rental_unavailable_equipment() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 }
 
@@ -298,6 +300,7 @@ fn test_create_rental_equipment_under_maintenance() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 }
 
@@ -329,6 +332,7 @@ fn test_create_rental_already_exists() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     // Try to create second rental for same equipment - should panic
@@ -338,6 +342,7 @@ fn test_create_rental_already_exists() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 }
 
@@ -368,6 +373,7 @@ fn test_confirm_rental_success() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     // Confirm rental
@@ -405,6 +411,7 @@ fn test_complete_rental_success() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
     client.confirm_rental(&equipment_id);
 
@@ -447,6 +454,7 @@ fn test_cancel_rental_success() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     // Cancel rental
@@ -483,6 +491,7 @@ fn test_cancel_rental_success() {
         &new_start_date,
         &new_end_date,
         &new_total_price,
+        &false,
     );
 
     // Verify the new rental was created successfully
@@ -532,6 +541,7 @@ fn test_cancel_rental_already_active() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
     client.confirm_rental(&equipment_id);
 
@@ -566,6 +576,7 @@ fn test_rental_history_by_equipment() {
         &start_date1,
         &end_date1,
         &total_price1,
+        &false,
     );
     client.confirm_rental(&equipment_id);
     client.complete_rental(&equipment_id);
@@ -581,6 +592,7 @@ fn test_rental_history_by_equipment() {
         &start_date2,
         &end_date2,
         &total_price2,
+        &false,
     );
 
     // Get rental history for equipment
@@ -636,6 +648,7 @@ fn test_rental_history_by_user() {
         &start_date1,
         &end_date1,
         &total_price1,
+        &false,
     );
     client.create_rental(
         &equipment_id2,
@@ -643,6 +656,7 @@ fn test_rental_history_by_user() {
         &start_date2,
         &end_date2,
         &total_price2,
+        &false,
     );
 
     // Get rental history for user
@@ -931,6 +945,7 @@ fn test_complete_rental_lifecycle() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     let rental = client.get_rental(&equipment_id).unwrap();
@@ -997,6 +1012,7 @@ fn test_maintenance_prevents_rental() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 }
 
@@ -1038,6 +1054,7 @@ fn test_multiple_rentals_same_user() {
         &start_date1,
         &end_date1,
         &total_price1,
+        &false,
     );
     client.create_rental(
         &equipment_id2,
@@ -1045,6 +1062,7 @@ fn test_multiple_rentals_same_user() {
         &start_date2,
         &end_date2,
         &total_price2,
+        &false,
     );
 
     // Verify user rental history
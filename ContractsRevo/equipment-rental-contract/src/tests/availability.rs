@@ -3,7 +3,9 @@
 extern crate std;
 
 use crate::equipment::MaintenanceStatus;
-use soroban_sdk::String;
+use crate::maintenance::MAX_MAINTENANCE_PAGE_SIZE;
+use crate::ContractError;
+use soroban_sdk::{BytesN, String};
 
 use super::utils::{create_standard_rental, register_basic_equipment, setup_test};
 
@@ -51,6 +53,37 @@ fn test_get_nonexistent_equipment() {
     assert!(equipment.is_none());
 }
 
+#[test]
+fn test_register_duplicate_equipment() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
+
+    let result = client.try_register_equipment(
+        &equipment_id,
+        &String::from_str(&env, "Agricultural Tractor"),
+        &2000,
+        &String::from_str(&env, "Farm Location B"),
+    );
+    assert_eq!(result, Err(Ok(ContractError::EquipmentAlreadyRegistered)));
+
+    // The original registration is kept
+    let equipment = client.get_equipment(&equipment_id).unwrap();
+    assert_eq!(equipment.rental_price_per_day, 1000);
+}
+
+#[test]
+fn test_update_nonexistent_equipment() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let equipment_id = super::utils::create_equipment_id(&env, "nonexistent");
+
+    let result = client.try_update_availability(&equipment_id, &false);
+    assert_eq!(result, Err(Ok(ContractError::EquipmentNotFound)));
+
+    let result =
+        client.try_update_maintenance_status(&equipment_id, &MaintenanceStatus::NeedsService);
+    assert_eq!(result, Err(Ok(ContractError::EquipmentNotFound)));
+}
+
 // ============================================================================
 // MAINTENANCE STATUS TESTS
 // ============================================================================
@@ -81,7 +114,6 @@ fn test_maintenance_status_transitions() {
 }
 
 #[test]
-#[should_panic(expected = "Equipment under maintenance or needs service")]
 fn test_maintenance_blocks_rental_creation() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -93,14 +125,16 @@ fn test_maintenance_blocks_rental_creation() {
     let end_date = start_date + (2 * 86400);
     let total_price = 2000;
 
-    // Should panic when trying to create rental
-    client.create_rental(
+    // Should fail when trying to create rental
+    let result = client.try_create_rental(
         &equipment_id,
         &renter1,
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
+    assert_eq!(result, Err(Ok(ContractError::EquipmentUnderMaintenance)));
 }
 
 #[test]
@@ -125,20 +159,28 @@ fn test_maintenance_allows_rental_after_fixed() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "Rental already exists for this equipment")]
 fn test_scheduling_conflict_with_pending_rental() {
     let (env, _contract_id, client, _owner, renter1, renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
 
     // Create first rental (Pending)
-    create_standard_rental(&client, &env, &equipment_id, &renter1, 3);
+    let (start_date, _end_date, _total_price) =
+        create_standard_rental(&client, &env, &equipment_id, &renter1, 3);
 
     // Try to create overlapping rental - should fail
-    create_standard_rental(&client, &env, &equipment_id, &renter2, 2);
+    let end_date = start_date + (2 * 86400);
+    let result = client.try_create_rental(
+        &equipment_id,
+        &renter2,
+        &start_date,
+        &end_date,
+        &2000,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(ContractError::RentalAlreadyExists)));
 }
 
 #[test]
-#[should_panic(expected = "Rental already exists for this equipment")]
 fn test_scheduling_conflict_with_active_rental() {
     let (env, _contract_id, client, _owner, renter1, renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -152,13 +194,15 @@ fn test_scheduling_conflict_with_active_rental() {
     let end_date = start_date + (2 * 86400);
     let total_price = 2000;
 
-    client.create_rental(
+    let result = client.try_create_rental(
         &equipment_id,
         &renter2,
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
+    assert_eq!(result, Err(Ok(ContractError::RentalAlreadyExists)));
 }
 
 #[test]
@@ -182,6 +226,7 @@ fn test_scheduling_after_rental_completion() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     let rental = client.get_rental(&equipment_id).unwrap();
@@ -209,6 +254,7 @@ fn test_scheduling_after_rental_cancellation() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     let rental = client.get_rental(&equipment_id).unwrap();
@@ -281,6 +327,96 @@ fn test_maintenance_history_filtering() {
     assert_eq!(all_history.len(), 2);
 }
 
+// ============================================================================
+// MAINTENANCE HISTORY PAGINATION TESTS
+// ============================================================================
+
+/// Logs `count` maintenance events, using the timestamp to number them
+fn log_many_maintenance_events(
+    client: &crate::EquipmentRentalContractClient,
+    equipment_id: &BytesN<32>,
+    count: u64,
+) {
+    for i in 0..count {
+        client.log_maintenance(equipment_id, &MaintenanceStatus::Good, &i, &None);
+    }
+}
+
+#[test]
+fn test_maintenance_history_paged_ordering() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
+    log_many_maintenance_events(&client, &equipment_id, 160);
+
+    let mut next_timestamp = 0;
+    let mut offset = 0;
+    loop {
+        let page = client.get_maintenance_history_paged(&Some(equipment_id.clone()), &offset, &30);
+        assert_eq!(page.total, 160);
+        for record in page.records.iter() {
+            assert_eq!(record.timestamp, next_timestamp);
+            next_timestamp += 1;
+        }
+        offset += page.records.len();
+        if !page.truncated {
+            break;
+        }
+    }
+    assert_eq!(next_timestamp, 160);
+
+    // Repeated reads return the same page
+    assert_eq!(
+        client.get_maintenance_history_paged(&None, &45, &30),
+        client.get_maintenance_history_paged(&None, &45, &30)
+    );
+}
+
+#[test]
+fn test_maintenance_history_limit_is_clamped() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
+    log_many_maintenance_events(&client, &equipment_id, 160);
+
+    let page = client.get_maintenance_history_paged(&None, &0, &u32::MAX);
+    assert_eq!(page.records.len(), MAX_MAINTENANCE_PAGE_SIZE);
+    assert_eq!(page.total, 160);
+    assert!(page.truncated);
+
+    // The legacy endpoint returns the first clamped page
+    assert_eq!(client.get_maintenance_history(&None), page.records);
+
+    let last = client.get_maintenance_history_paged(&None, &150, &20);
+    assert_eq!(last.records.len(), 10);
+    assert_eq!(last.records.get(0).unwrap().timestamp, 150);
+    assert!(!last.truncated);
+
+    let past_end = client.get_maintenance_history_paged(&None, &u32::MAX, &20);
+    assert_eq!(past_end.records.len(), 0);
+    assert_eq!(past_end.total, 160);
+    assert!(!past_end.truncated);
+}
+
+#[test]
+fn test_maintenance_history_paged_with_filter() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let tractor = register_basic_equipment(&client, &env, "tractor_001", 1000);
+    let harvester = register_basic_equipment(&client, &env, "harvester_001", 1500);
+    log_many_maintenance_events(&client, &tractor, 150);
+    log_many_maintenance_events(&client, &harvester, 5);
+
+    let page = client.get_maintenance_history_paged(&Some(harvester.clone()), &2, &2);
+    assert_eq!(page.total, 5);
+    assert_eq!(page.records.len(), 2);
+    assert_eq!(page.records.get(0).unwrap().timestamp, 2);
+    assert!(page.records.iter().all(|r| r.equipment_id == harvester));
+    assert!(page.truncated);
+
+    let unknown = BytesN::from_array(&env, &[9u8; 32]);
+    let empty = client.get_maintenance_history_paged(&Some(unknown), &0, &10);
+    assert_eq!(empty.total, 0);
+    assert!(!empty.truncated);
+}
+
 // ============================================================================
 // HIGH-VOLUME AVAILABILITY TESTS
 // ============================================================================
@@ -309,14 +445,26 @@ fn test_multiple_equipment_availability() {
 }
 
 #[test]
-#[should_panic(expected = "Rental already exists for this equipment")]
 fn test_concurrent_rental_attempts() {
     let (env, _contract_id, client, _owner, renter1, renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
 
     // First renter creates rental
-    create_standard_rental(&client, &env, &equipment_id, &renter1, 3);
+    let (start_date, end_date, total_price) =
+        create_standard_rental(&client, &env, &equipment_id, &renter1, 3);
 
     // Second renter attempt should fail
-    create_standard_rental(&client, &env, &equipment_id, &renter2, 2);
+    let result = client.try_create_rental(
+        &equipment_id,
+        &renter2,
+        &start_date,
+        &end_date,
+        &total_price,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(ContractError::RentalAlreadyExists)));
+
+    // The first renter's booking is untouched
+    let rental = client.get_rental(&equipment_id).unwrap();
+    assert_eq!(rental.renter, renter1);
 }
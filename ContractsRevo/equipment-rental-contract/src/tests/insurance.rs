@@ -0,0 +1,356 @@
+#![cfg(test)]
+
+use farmer_insurance_contract::{FarmerInsuranceContract, FarmerInsuranceContractClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, BytesN, Env, IntoVal, Symbol,
+};
+
+use super::utils::{register_basic_equipment, setup_test};
+use crate::{ContractError, EquipmentRentalContractClient};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyRequest {
+    pub insured: Address,
+    pub coverage: Symbol,
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+    pub premium: i128,
+    pub reference: BytesN<32>,
+}
+
+/// Insurance contract stand-in that records the policy it was asked to write
+#[contract]
+pub struct MockInsurer;
+
+#[contractimpl]
+impl MockInsurer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_rental_policy(
+        env: Env,
+        _platform: Address,
+        insured: Address,
+        coverage: Symbol,
+        coverage_start: u64,
+        coverage_end: u64,
+        premium: i128,
+        reference: BytesN<32>,
+    ) -> BytesN<32> {
+        let request = PolicyRequest {
+            insured,
+            coverage,
+            coverage_start,
+            coverage_end,
+            premium,
+            reference,
+        };
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "last_request"), &request);
+        BytesN::from_array(&env, &[7u8; 32])
+    }
+
+    pub fn cancel_rental_policy(_env: Env, _platform: Address, _policy_id: BytesN<32>) -> i128 {
+        0
+    }
+
+    pub fn last_request(env: Env) -> Option<PolicyRequest> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "last_request"))
+    }
+}
+
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol};
+
+    /// Insurance contract that refuses to write policies
+    #[contract]
+    pub struct FailingInsurer;
+
+    #[contractimpl]
+    impl FailingInsurer {
+        #[allow(clippy::too_many_arguments)]
+        pub fn create_rental_policy(
+            _env: Env,
+            _platform: Address,
+            _insured: Address,
+            _coverage: Symbol,
+            _coverage_start: u64,
+            _coverage_end: u64,
+            _premium: i128,
+            _reference: BytesN<32>,
+        ) -> BytesN<32> {
+            panic!("coverage product not available")
+        }
+
+        pub fn cancel_rental_policy(_env: Env, _platform: Address, _policy_id: BytesN<32>) -> i128 {
+            panic!("coverage product not available")
+        }
+    }
+}
+
+/// Configure an insurer charging 5% of the rental price and fund the renter
+fn setup_insurance(
+    env: &Env,
+    client: &EquipmentRentalContractClient,
+    insurer: &Address,
+    renter: &Address,
+) -> token::Client<'static> {
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(renter, &10_000);
+
+    client.initialize(&admin);
+    client.set_insurance_provider(
+        &admin,
+        insurer,
+        &Symbol::new(env, "EQUIP_DMG"),
+        &token_address,
+        &500u32,
+    );
+
+    token::Client::new(env, &token_address)
+}
+
+#[test]
+fn test_insured_rental_creates_policy() {
+    let (env, _contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+    let insurer_id = env.register(MockInsurer, ());
+    let insurer = MockInsurerClient::new(&env, &insurer_id);
+    let token = setup_insurance(&env, &client, &insurer_id, &renter);
+
+    let start_date = env.ledger().timestamp() + 86400;
+    let end_date = start_date + 3 * 86400;
+    client.create_rental(&equipment_id, &renter, &start_date, &end_date, &3000, &true);
+
+    let rental = client.get_rental(&equipment_id).unwrap();
+    assert_eq!(rental.policy_id, Some(BytesN::from_array(&env, &[7u8; 32])));
+    assert_eq!(rental.insurance_premium, 150);
+
+    // The policy is scoped to the rental window and references the equipment
+    let request = insurer.last_request().unwrap();
+    assert_eq!(request.insured, renter);
+    assert_eq!(request.coverage, Symbol::new(&env, "EQUIP_DMG"));
+    assert_eq!(request.coverage_start, start_date);
+    assert_eq!(request.coverage_end, end_date);
+    assert_eq!(request.premium, 150);
+    assert_eq!(request.reference, equipment_id);
+
+    // Premium moved from the renter to the insurer
+    assert_eq!(token.balance(&renter), 9_850);
+    assert_eq!(token.balance(&insurer_id), 150);
+    assert_eq!(client.compute_insurance_premium(&3000), 150);
+}
+
+#[test]
+fn test_uninsured_rental_unchanged() {
+    let (env, _contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+    let insurer_id = env.register(MockInsurer, ());
+    let insurer = MockInsurerClient::new(&env, &insurer_id);
+    let token = setup_insurance(&env, &client, &insurer_id, &renter);
+
+    let start_date = env.ledger().timestamp() + 86400;
+    client.create_rental(
+        &equipment_id,
+        &renter,
+        &start_date,
+        &(start_date + 86400),
+        &1000,
+        &false,
+    );
+
+    let rental = client.get_rental(&equipment_id).unwrap();
+    assert_eq!(rental.policy_id, None);
+    assert_eq!(rental.insurance_premium, 0);
+    assert_eq!(token.balance(&renter), 10_000);
+    assert_eq!(insurer.last_request(), None);
+}
+
+#[test]
+fn test_insurance_requires_configured_provider() {
+    let (env, _contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+
+    let start_date = env.ledger().timestamp() + 86400;
+    let result = client.try_create_rental(
+        &equipment_id,
+        &renter,
+        &start_date,
+        &(start_date + 86400),
+        &1000,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InsuranceNotConfigured)));
+    assert!(client.get_rental(&equipment_id).is_none());
+}
+
+#[test]
+fn test_insurer_failure_surfaces_clean_error() {
+    let (env, _contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+    let insurer_id = env.register(failing::FailingInsurer, ());
+    let token = setup_insurance(&env, &client, &insurer_id, &renter);
+
+    let start_date = env.ledger().timestamp() + 86400;
+    let result = client.try_create_rental(
+        &equipment_id,
+        &renter,
+        &start_date,
+        &(start_date + 86400),
+        &1000,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InsurancePolicyFailed)));
+
+    // Nothing was booked and the premium was not taken
+    assert!(client.get_rental(&equipment_id).is_none());
+    assert_eq!(token.balance(&renter), 10_000);
+}
+
+#[test]
+fn test_set_insurance_provider_admin_only() {
+    let (env, _contract_id, client, _owner, _renter, _renter2) = setup_test();
+    let admin = Address::generate(&env);
+    let insurer = Address::generate(&env);
+    let token_address = Address::generate(&env);
+    let coverage = Symbol::new(&env, "EQUIP_DMG");
+
+    // Not initialized yet
+    let result =
+        client.try_set_insurance_provider(&admin, &insurer, &coverage, &token_address, &500u32);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+
+    let outsider = Address::generate(&env);
+    let result =
+        client.try_set_insurance_provider(&outsider, &insurer, &coverage, &token_address, &500u32);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result =
+        client.try_set_insurance_provider(&admin, &insurer, &coverage, &token_address, &0u32);
+    assert_eq!(result, Err(Ok(ContractError::InvalidPremiumRate)));
+
+    client.set_insurance_provider(&admin, &insurer, &coverage, &token_address, &500u32);
+    let provider = client.get_insurance_provider().unwrap();
+    assert_eq!(provider.insurance_contract, insurer);
+    assert_eq!(provider.premium_bps, 500);
+}
+
+#[test]
+fn test_damage_report_references_policy() {
+    let (env, _contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+    let insurer_id = env.register(MockInsurer, ());
+    setup_insurance(&env, &client, &insurer_id, &renter);
+
+    let start_date = env.ledger().timestamp() + 86400;
+    client.create_rental(
+        &equipment_id,
+        &renter,
+        &start_date,
+        &(start_date + 86400),
+        &1000,
+        &true,
+    );
+    client.confirm_rental(&equipment_id);
+
+    let evidence = BytesN::from_array(&env, &[9u8; 32]);
+    let report = client.report_damage(&equipment_id, &evidence);
+    assert_eq!(report.renter, renter);
+    assert_eq!(report.policy_id, Some(BytesN::from_array(&env, &[7u8; 32])));
+
+    let reports = client.get_damage_reports(&equipment_id);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports.get(0).unwrap().evidence_hash, evidence);
+}
+
+#[test]
+fn test_cancelled_rental_refunds_farmer_insurance_policy() {
+    let (env, contract_id, client, _owner, renter, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "harvester_001", 1000);
+    let coverage = Symbol::new(&env, "EQUIP_DMG");
+    let insurer_id = env.register(FarmerInsuranceContract, ());
+    let insurer = FarmerInsuranceContractClient::new(&env, &insurer_id);
+    let token = setup_insurance(&env, &client, &insurer_id, &renter);
+
+    // The insurer sells 30-day damage cover at 1% of a 10_000 limit, in the same token
+    let insurer_admin = Address::generate(&env);
+    insurer.initialize(&insurer_admin, &token.address);
+    insurer.create_coverage_product(
+        &insurer_admin,
+        &coverage,
+        &10_000,
+        &100,
+        &(30 * 86400),
+        &BytesN::from_array(&env, &[0u8; 32]),
+        &0,
+        &0,
+        &0,
+    );
+
+    // Until the rental contract is registered as a platform, the insurer refuses
+    let start_date = env.ledger().timestamp() + 86400;
+    let end_date = start_date + 3 * 86400;
+    let result =
+        client.try_create_rental(&equipment_id, &renter, &start_date, &end_date, &3000, &true);
+    assert_eq!(result, Err(Ok(ContractError::InsurancePolicyFailed)));
+
+    insurer.add_rental_platform(&insurer_admin, &contract_id);
+    client.create_rental(&equipment_id, &renter, &start_date, &end_date, &3000, &true);
+
+    let rental = client.get_rental(&equipment_id).unwrap();
+    let policy_id = rental.policy_id.unwrap();
+    let policy = insurer.get_policy(&policy_id);
+    assert!(policy.active);
+    assert_eq!(policy.farmer, renter);
+    assert_eq!(policy.coverage_start, start_date);
+    assert_eq!(policy.coverage_end, end_date);
+    assert_eq!(policy.premiums_paid, 150);
+    assert_eq!(token.balance(&renter), 9_850);
+    assert_eq!(insurer.get_pool_status(&coverage).balance, 150);
+
+    // Cancelling the booking cancels the policy and refunds the premium
+    client.cancel_rental(&equipment_id);
+    assert_eq!(token.balance(&renter), 10_000);
+    assert_eq!(token.balance(&insurer_id), 0);
+    let policy = insurer.get_policy(&policy_id);
+    assert!(!policy.active);
+    assert_eq!(policy.remaining_limit, 0);
+}
+
+#[test]
+fn test_initialize_requires_platform_account() {
+    let (env, contract_id, client, _owner, _renter, _renter2) = setup_test();
+    let admin = Address::generate(&env);
+
+    // The admin alone cannot claim the insurance configuration
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "initialize",
+            args: (admin.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_initialize(&admin).is_err());
+
+    env.mock_all_auths();
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(ContractError::AlreadyInitialized))
+    );
+}
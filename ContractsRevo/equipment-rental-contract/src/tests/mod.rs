@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 mod availability;
+mod insurance;
 mod payment;
 mod rental;
 pub mod utils;
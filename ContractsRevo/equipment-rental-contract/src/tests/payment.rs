@@ -5,6 +5,7 @@ extern crate std;
 // Remove unused imports to clean up warnings
 
 use super::utils::{register_basic_equipment, setup_test};
+use crate::ContractError;
 
 // ============================================================================
 // PRICING CALCULATION TESTS
@@ -36,7 +37,6 @@ fn test_compute_total_price_single_day() {
 }
 
 #[test]
-#[should_panic]
 fn test_compute_total_price_invalid_dates() {
     let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -44,11 +44,11 @@ fn test_compute_total_price_invalid_dates() {
     let start_day = (env.ledger().timestamp() / 86400) + 10;
     let end_day = start_day - 1; // Invalid: end before start
 
-    client.compute_total_price(&equipment_id, &start_day, &end_day);
+    let result = client.try_compute_total_price(&equipment_id, &start_day, &end_day);
+    assert_eq!(result, Err(Ok(ContractError::InvalidRentalPeriod)));
 }
 
 #[test]
-#[should_panic]
 fn test_compute_total_price_nonexistent_equipment() {
     let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
     let equipment_id = super::utils::create_equipment_id(&env, "nonexistent");
@@ -56,7 +56,8 @@ fn test_compute_total_price_nonexistent_equipment() {
     let start_day = (env.ledger().timestamp() / 86400) + 1;
     let end_day = start_day + 5;
 
-    client.compute_total_price(&equipment_id, &start_day, &end_day);
+    let result = client.try_compute_total_price(&equipment_id, &start_day, &end_day);
+    assert_eq!(result, Err(Ok(ContractError::EquipmentNotFound)));
 }
 
 // ============================================================================
@@ -105,7 +106,6 @@ fn test_validate_price_exact_match() {
 }
 
 #[test]
-#[should_panic]
 fn test_validate_price_outside_tolerance_high() {
     let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -115,17 +115,17 @@ fn test_validate_price_outside_tolerance_high() {
     let proposed_price = 5000; // Too high
     let tolerance = 100;
 
-    client.validate_price(
+    let result = client.try_validate_price(
         &equipment_id,
         &start_day,
         &end_day,
         &proposed_price,
         &tolerance,
     );
+    assert_eq!(result, Err(Ok(ContractError::PriceMismatch)));
 }
 
 #[test]
-#[should_panic]
 fn test_validate_price_outside_tolerance_low() {
     let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -135,13 +135,14 @@ fn test_validate_price_outside_tolerance_low() {
     let proposed_price = 1000; // Too low
     let tolerance = 100;
 
-    client.validate_price(
+    let result = client.try_validate_price(
         &equipment_id,
         &start_day,
         &end_day,
         &proposed_price,
         &tolerance,
     );
+    assert_eq!(result, Err(Ok(ContractError::PriceMismatch)));
 }
 
 // ============================================================================
@@ -168,6 +169,7 @@ fn test_rental_payment_tracking() {
         &start_timestamp,
         &end_timestamp,
         &expected_price,
+        &false,
     );
 
     // Verify rental tracks correct payment amount
@@ -257,6 +259,7 @@ fn test_pricing_integration_with_rental_flow() {
         &start_timestamp,
         &end_timestamp,
         &expected_price,
+        &false,
     );
 
     // 4. Verify rental was created with correct price
@@ -294,6 +297,7 @@ fn test_payment_validation_prevents_invalid_rentals_success() {
         &start_timestamp,
         &end_timestamp,
         &correct_price,
+        &false,
     );
 
     let rental = client.get_rental(&equipment_id).unwrap();
@@ -301,7 +305,6 @@ fn test_payment_validation_prevents_invalid_rentals_success() {
 }
 
 #[test]
-#[should_panic]
 fn test_payment_validation_prevents_invalid_rentals_failure() {
     let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -313,14 +316,15 @@ fn test_payment_validation_prevents_invalid_rentals_failure() {
     let incorrect_price = 5000; // Way too high for 2 days * 1000 = 2000
     let tolerance = 100;
 
-    // This should panic due to price being way outside tolerance
-    client.validate_price(
+    // This should fail due to price being way outside tolerance
+    let result = client.try_validate_price(
         &equipment_id,
         &start_day,
         &end_day,
         &incorrect_price,
         &tolerance,
     );
+    assert_eq!(result, Err(Ok(ContractError::PriceMismatch)));
 }
 
 // ============================================================================
@@ -358,6 +362,7 @@ fn test_payment_flow_simulation() {
         &start_timestamp,
         &end_timestamp,
         &expected_price,
+        &false,
     );
     client.confirm_rental(&equipment_id);
 
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{equipment::MaintenanceStatus, rental::RentalStatus};
+use crate::{equipment::MaintenanceStatus, rental::RentalStatus, ContractError};
 
 use super::utils::{create_standard_rental, register_basic_equipment, setup_test};
 
@@ -19,6 +19,7 @@ fn test_create_rental_success() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
 
     let rental = client.get_rental(&equipment_id).unwrap();
@@ -31,7 +32,6 @@ fn test_create_rental_success() {
 }
 
 #[test]
-#[should_panic(expected = "Equipment not available")]
 fn test_create_rental_unavailable_equipment() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -43,17 +43,18 @@ fn test_create_rental_unavailable_equipment() {
     let end_date = start_date + (3 * 86400);
     let total_price = 3000;
 
-    client.create_rental(
+    let result = client.try_create_rental(
         &equipment_id,
         &renter1,
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
+    assert_eq!(result, Err(Ok(ContractError::EquipmentUnavailable)));
 }
 
 #[test]
-#[should_panic(expected = "Equipment under maintenance or needs service")]
 fn test_create_rental_equipment_under_maintenance() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -65,17 +66,18 @@ fn test_create_rental_equipment_under_maintenance() {
     let end_date = start_date + (3 * 86400);
     let total_price = 3000;
 
-    client.create_rental(
+    let result = client.try_create_rental(
         &equipment_id,
         &renter1,
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
+    assert_eq!(result, Err(Ok(ContractError::EquipmentUnderMaintenance)));
 }
 
 #[test]
-#[should_panic(expected = "Rental already exists for this equipment")]
 fn test_create_rental_double_booking() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -90,15 +92,18 @@ fn test_create_rental_double_booking() {
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
     // Attempt double booking
-    client.create_rental(
+    let result = client.try_create_rental(
         &equipment_id,
         &renter1,
         &start_date,
         &end_date,
         &total_price,
+        &false,
     );
+    assert_eq!(result, Err(Ok(ContractError::RentalAlreadyExists)));
 }
 
 // ============================================================================
@@ -119,7 +124,6 @@ fn test_confirm_rental_success() {
 }
 
 #[test]
-#[should_panic(expected = "Rental not pending")]
 fn test_confirm_rental_not_pending() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -128,7 +132,8 @@ fn test_confirm_rental_not_pending() {
     client.confirm_rental(&equipment_id);
 
     // Try to confirm again (should fail)
-    client.confirm_rental(&equipment_id);
+    let result = client.try_confirm_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotPending)));
 }
 
 #[test]
@@ -149,7 +154,6 @@ fn test_complete_rental_success() {
 }
 
 #[test]
-#[should_panic(expected = "Rental not active")]
 fn test_complete_rental_not_active() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -157,7 +161,8 @@ fn test_complete_rental_not_active() {
     create_standard_rental(&client, &env, &equipment_id, &renter1, 3);
 
     // Try to complete without confirming first
-    client.complete_rental(&equipment_id);
+    let result = client.try_complete_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotActive)));
 }
 
 #[test]
@@ -187,6 +192,7 @@ fn test_cancel_rental_success() {
         &new_start_date,
         &new_end_date,
         &new_total_price,
+        &false,
     );
 
     let new_rental = client.get_rental(&equipment_id).unwrap();
@@ -194,7 +200,6 @@ fn test_cancel_rental_success() {
 }
 
 #[test]
-#[should_panic(expected = "Only pending rentals can be cancelled")]
 fn test_cancel_rental_already_active() {
     let (env, _contract_id, client, _owner, renter1, _renter2) = setup_test();
     let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
@@ -203,7 +208,23 @@ fn test_cancel_rental_already_active() {
     client.confirm_rental(&equipment_id);
 
     // Try to cancel active rental
-    client.cancel_rental(&equipment_id);
+    let result = client.try_cancel_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotPending)));
+}
+
+#[test]
+fn test_rental_lifecycle_without_rental() {
+    let (env, _contract_id, client, _owner, _renter1, _renter2) = setup_test();
+    let equipment_id = register_basic_equipment(&client, &env, "tractor_001", 1000);
+
+    let result = client.try_confirm_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotFound)));
+
+    let result = client.try_complete_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotFound)));
+
+    let result = client.try_cancel_rental(&equipment_id);
+    assert_eq!(result, Err(Ok(ContractError::RentalNotFound)));
 }
 
 // ============================================================================
@@ -230,6 +251,7 @@ fn test_rental_history_by_equipment() {
         &start_date2,
         &end_date2,
         &total_price2,
+        &false,
     );
 
     let history = client.get_rental_history_by_equipment(&equipment_id);
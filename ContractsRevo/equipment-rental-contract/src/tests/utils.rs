@@ -78,7 +78,14 @@ pub fn create_standard_rental(
     let end_date = start_date + (days_duration * 86400);
     let total_price = days_duration as i128 * 1000; // Assuming 1000 per day
 
-    client.create_rental(equipment_id, renter, &start_date, &end_date, &total_price);
+    client.create_rental(
+        equipment_id,
+        renter,
+        &start_date,
+        &end_date,
+        &total_price,
+        &false,
+    );
 
     (start_date, end_date, total_price)
 }
@@ -0,0 +1,142 @@
+use crate::utils::{ContractError, DataKey};
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// Sets the admin and the token premiums are paid in.
+pub fn initialize(env: Env, admin: Address, premium_token: Address) -> Result<(), ContractError> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(ContractError::AlreadyInitialized);
+    }
+
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    env.storage()
+        .instance()
+        .set(&DataKey::PremiumToken, &premium_token);
+
+    Ok(())
+}
+
+pub fn add_assessor(env: Env, admin: Address, assessor: Address) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Assessor(assessor.clone()), &true);
+    env.events()
+        .publish((symbol_short!("ASSESSOR"), assessor), true);
+
+    Ok(())
+}
+
+pub fn remove_assessor(env: Env, admin: Address, assessor: Address) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::Assessor(assessor.clone()));
+    env.events()
+        .publish((symbol_short!("ASSESSOR"), assessor), false);
+
+    Ok(())
+}
+
+pub fn set_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage().instance().set(&DataKey::Oracle, &oracle);
+    env.events().publish((symbol_short!("ORACLE"),), oracle);
+
+    Ok(())
+}
+
+/// Allows a rental platform to write policies for its bookings through `create_rental_policy`.
+pub fn add_rental_platform(
+    env: Env,
+    admin: Address,
+    platform: Address,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::RentalPlatform(platform.clone()), &true);
+    env.events()
+        .publish((symbol_short!("PLATFORM"), platform), true);
+
+    Ok(())
+}
+
+pub fn remove_rental_platform(
+    env: Env,
+    admin: Address,
+    platform: Address,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::RentalPlatform(platform.clone()));
+    env.events()
+        .publish((symbol_short!("PLATFORM"), platform), false);
+
+    Ok(())
+}
+
+pub fn is_assessor(env: Env, assessor: Address) -> bool {
+    env.storage().instance().has(&DataKey::Assessor(assessor))
+}
+
+pub fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    let stored: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(ContractError::NotInitialized)?;
+
+    if stored != *admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+pub fn premium_token(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PremiumToken)
+        .ok_or(ContractError::NotInitialized)
+}
+
+pub fn require_oracle(env: &Env, oracle: &Address) -> Result<(), ContractError> {
+    let stored: Option<Address> = env.storage().instance().get(&DataKey::Oracle);
+    if stored.as_ref() != Some(oracle) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+pub fn require_assessor(env: &Env, assessor: &Address) -> Result<(), ContractError> {
+    if !is_assessor(env.clone(), assessor.clone()) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+pub fn require_rental_platform(env: &Env, platform: &Address) -> Result<(), ContractError> {
+    if !env
+        .storage()
+        .instance()
+        .has(&DataKey::RentalPlatform(platform.clone()))
+    {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::admin::require_admin;
+use crate::pool::BPS_DENOMINATOR;
+use crate::utils::{ContractError, DataKey};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+/// Terms a coverage type is sold on. Every update is stored as a new version so policies keep
+/// the terms they were written under.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageProduct {
+    pub coverage: Symbol,
+    pub version: u32,
+    pub max_payout: i128,
+    /// Premium for the whole term, as basis points of `max_payout`
+    pub premium_rate_bps: u32,
+    pub term_secs: u64,
+    pub exclusions_hash: BytesN<32>,
+    /// Part of every loss the policyholder bears before the policy pays
+    pub deductible: i128,
+    /// Share of the loss above the deductible the policyholder bears
+    pub coinsurance_bps: u32,
+    /// How long after the insured event claims may be filed; `0` leaves claims open for the
+    /// whole coverage period
+    pub claim_window_secs: u64,
+    pub created_at: u64,
+}
+
+/// Publishes a coverage product, or a new version of it if the coverage type already exists.
+/// Returns the version number.
+#[allow(clippy::too_many_arguments)]
+pub fn create_coverage_product(
+    env: Env,
+    admin: Address,
+    coverage: Symbol,
+    max_payout: i128,
+    premium_rate_bps: u32,
+    term_secs: u64,
+    exclusions_hash: BytesN<32>,
+    deductible: i128,
+    coinsurance_bps: u32,
+    claim_window_secs: u64,
+) -> Result<u32, ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if max_payout <= 0
+        || premium_rate_bps == 0
+        || premium_rate_bps as i128 > BPS_DENOMINATOR
+        || term_secs == 0
+        || deductible < 0
+        || coinsurance_bps as i128 >= BPS_DENOMINATOR
+    {
+        return Err(ContractError::InvalidProduct);
+    }
+
+    let version = latest_version(&env, &coverage) + 1;
+    let product = CoverageProduct {
+        coverage: coverage.clone(),
+        version,
+        max_payout,
+        premium_rate_bps,
+        term_secs,
+        exclusions_hash,
+        deductible,
+        coinsurance_bps,
+        claim_window_secs,
+        created_at: env.ledger().timestamp(),
+    };
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Product(coverage.clone(), version), &product);
+    env.storage()
+        .instance()
+        .set(&DataKey::ProductVersion(coverage.clone()), &version);
+
+    env.events()
+        .publish((symbol_short!("PRODUCT"), coverage), product);
+
+    Ok(version)
+}
+
+/// Latest version of the coverage product.
+pub fn get_coverage_product(env: Env, coverage: Symbol) -> Result<CoverageProduct, ContractError> {
+    let version = latest_version(&env, &coverage);
+    get_product_version(env, coverage, version)
+}
+
+pub fn get_product_version(
+    env: Env,
+    coverage: Symbol,
+    version: u32,
+) -> Result<CoverageProduct, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Product(coverage, version))
+        .ok_or(ContractError::ProductNotFound)
+}
+
+/// Premium owed over the product's whole term.
+pub fn term_premium(product: &CoverageProduct) -> Result<i128, ContractError> {
+    product
+        .max_payout
+        .checked_mul(product.premium_rate_bps as i128)
+        .map(|premium| premium / BPS_DENOMINATOR)
+        .ok_or(ContractError::InvalidProduct)
+}
+
+fn latest_version(env: &Env, coverage: &Symbol) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProductVersion(coverage.clone()))
+        .unwrap_or(0)
+}
@@ -1,6 +1,32 @@
-use crate::insurance::InsurancePolicy;
+use crate::admin::{require_admin, require_assessor};
+use crate::catalog::get_product_version;
+use crate::insurance::{policy_status, InsurancePolicy, PolicyStatus};
+use crate::pool::{release_claim, reserve_claim};
 use crate::utils::{generate_claim_id, ContractError, DataKey};
-use soroban_sdk::{contracttype, symbol_short, BytesN, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+/// Claims left unassessed this long can be expired unless the admin sets another age
+pub const DEFAULT_CLAIM_EXPIRY_AGE: u64 = 90 * 24 * 60 * 60;
+/// Claims a single policy may ever file
+pub const MAX_CLAIMS_PER_POLICY: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimStatus {
+    Submitted = 0,
+    Approved = 1,
+    Rejected = 2,
+    Paid = 3,
+    /// Never assessed and expired by `expire_stale_claims`
+    Expired = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimDecision {
+    Approve = 0,
+    Reject = 1,
+}
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -8,7 +34,20 @@ pub struct Claim {
     pub claim_id: BytesN<32>,
     pub policy_id: BytesN<32>,
     pub event_hash: BytesN<32>,
+    /// Amount requested by the policyholder
     pub payout_amount: i128,
+    /// Loss the assessor approved; the payout is derived from it
+    pub approved_amount: i128,
+    /// Approved loss after deductible, coinsurance and the policy's remaining limit
+    pub paid_amount: i128,
+    /// Part of the request held in the coverage pool until the claim is assessed or expires
+    pub reserved_amount: i128,
+    pub status: ClaimStatus,
+    pub assessor: Option<Address>,
+    pub notes_hash: Option<BytesN<32>>,
+    pub submitted_at: u64,
+    pub assessed_at: u64,
+    pub paid_at: u64,
 }
 
 pub fn sub_claim(
@@ -29,21 +68,314 @@ pub fn sub_claim(
         panic!("Policy is not active");
     }
 
+    // Claims stay open during the grace period after a missed premium
+    match policy_status(&env, &policy) {
+        PolicyStatus::Lapsed => return Err(ContractError::PolicyLapsed),
+        PolicyStatus::Expired => return Err(ContractError::PolicyExpired),
+        _ => {}
+    }
+
+    // Registered events must fall within the policy's coverage period
+    let product =
+        get_product_version(env.clone(), policy.coverage.clone(), policy.product_version)?;
+    let occurred_at: Option<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::InsuredEvent(event_hash.clone()));
+    if let Some(occurred_at) = occurred_at {
+        if occurred_at < policy.coverage_start || occurred_at >= policy.coverage_end {
+            return Err(ContractError::EventOutsideCoverage);
+        }
+    }
+    if product.claim_window_secs > 0 {
+        let occurred_at = occurred_at.ok_or(ContractError::EventNotRegistered)?;
+        if env.ledger().timestamp() > occurred_at.saturating_add(product.claim_window_secs) {
+            return Err(ContractError::ClaimWindowExpired);
+        }
+    }
+
+    let claims_key = DataKey::PolicyClaims(policy_id.clone());
+    let mut policy_claims: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&claims_key)
+        .unwrap_or_else(|| Vec::new(&env));
+    if policy_claims.len() >= MAX_CLAIMS_PER_POLICY {
+        return Err(ContractError::TooManyClaims);
+    }
+
+    // Claims awaiting assessment together hold at most the policy's remaining limit
     let claim_id = generate_claim_id(&env)?;
+    let outstanding = policy_reserved(&env, &policy_id);
+    let reserved_amount = payout_amount.clamp(0, (policy.remaining_limit - outstanding).max(0));
+    reserve_claim(&env, &policy.coverage, reserved_amount);
+    set_policy_reserved(&env, &policy_id, outstanding + reserved_amount);
 
     let claim = Claim {
         claim_id: claim_id.clone(),
-        policy_id,
+        policy_id: policy_id.clone(),
         event_hash,
         payout_amount,
+        approved_amount: 0,
+        paid_amount: 0,
+        reserved_amount,
+        status: ClaimStatus::Submitted,
+        assessor: None,
+        notes_hash: None,
+        submitted_at: env.ledger().timestamp(),
+        assessed_at: 0,
+        paid_at: 0,
     };
 
     env.storage()
         .instance()
         .set(&DataKey::Claim(claim_id.clone()), &claim);
 
+    policy_claims.push_back(claim_id.clone());
+    env.storage().persistent().set(&claims_key, &policy_claims);
+
+    // Pending claims are queued in submission order under indexed keys
+    let tail: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingClaimsTail)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingClaim(tail), &claim_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingClaimsTail, &(tail + 1));
+
     env.events()
         .publish((symbol_short!("CLAIM"), claim_id.clone()), claim);
 
     Ok(claim_id)
 }
+
+/// Records an assessor's decision on a submitted claim. Approvals may be for less than the
+/// requested amount, never more; rejections must approve nothing.
+pub fn assess_claim(
+    env: Env,
+    assessor: Address,
+    claim_id: BytesN<32>,
+    approved_amount: i128,
+    decision: ClaimDecision,
+    notes_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    assessor.require_auth();
+    require_assessor(&env, &assessor)?;
+
+    let mut claim = get_claim(env.clone(), claim_id.clone());
+    if claim.status != ClaimStatus::Submitted {
+        return Err(ContractError::ClaimAlreadyAssessed);
+    }
+
+    claim.status = match decision {
+        ClaimDecision::Approve => {
+            if approved_amount <= 0 || approved_amount > claim.payout_amount {
+                return Err(ContractError::InvalidAmount);
+            }
+            ClaimStatus::Approved
+        }
+        ClaimDecision::Reject => {
+            if approved_amount != 0 {
+                return Err(ContractError::InvalidAmount);
+            }
+            ClaimStatus::Rejected
+        }
+    };
+    claim.approved_amount = approved_amount;
+    claim.assessor = Some(assessor);
+    claim.notes_hash = Some(notes_hash);
+    claim.assessed_at = env.ledger().timestamp();
+    release_reservation(&env, &mut claim);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Claim(claim_id.clone()), &claim);
+    env.events()
+        .publish((symbol_short!("ASSESSED"), claim_id), claim);
+
+    Ok(())
+}
+
+pub fn reject_claim(
+    env: Env,
+    assessor: Address,
+    claim_id: BytesN<32>,
+    reason_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    assess_claim(
+        env,
+        assessor,
+        claim_id,
+        0,
+        ClaimDecision::Reject,
+        reason_hash,
+    )
+}
+
+/// Records when an insured event happened, so claims referencing it can be checked against
+/// their coverage's claim window.
+pub fn register_insured_event(
+    env: Env,
+    admin: Address,
+    event_hash: BytesN<32>,
+    occurred_at: u64,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::InsuredEvent(event_hash.clone()), &occurred_at);
+    env.events()
+        .publish((symbol_short!("EVENT"), event_hash), occurred_at);
+
+    Ok(())
+}
+
+pub fn get_insured_event(env: Env, event_hash: BytesN<32>) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::InsuredEvent(event_hash))
+}
+
+pub fn set_claim_expiry_age(env: Env, admin: Address, age_secs: u64) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if age_secs == 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ClaimExpiryAge, &age_secs);
+
+    Ok(())
+}
+
+pub fn get_claim_expiry_age(env: Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimExpiryAge)
+        .unwrap_or(DEFAULT_CLAIM_EXPIRY_AGE)
+}
+
+/// Walks up to `max` of the oldest entries in the pending queue, expiring claims that were
+/// submitted longer than the expiry age ago and never assessed, and releasing what they held in
+/// their coverage pool. Claims assessed in the meantime are dropped from the queue. Anyone may
+/// call it. Returns the number of claims expired.
+pub fn expire_stale_claims(env: Env, max: u32) -> u32 {
+    let now = env.ledger().timestamp();
+    let age = get_claim_expiry_age(env.clone());
+    let mut head: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingClaimsHead)
+        .unwrap_or(0);
+    let tail: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingClaimsTail)
+        .unwrap_or(0);
+
+    let mut walked = 0;
+    let mut expired = 0;
+    while walked < max && head < tail {
+        let claim_id: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(head))
+            .unwrap_or_else(|| panic!("Pending claim not found"));
+        let mut claim = get_claim(env.clone(), claim_id.clone());
+
+        if claim.status == ClaimStatus::Submitted {
+            // Pending claims are kept in submission order, so the rest are younger still
+            if now.saturating_sub(claim.submitted_at) < age {
+                break;
+            }
+
+            claim.status = ClaimStatus::Expired;
+            release_reservation(&env, &mut claim);
+            env.storage()
+                .instance()
+                .set(&DataKey::Claim(claim_id.clone()), &claim);
+            env.events()
+                .publish((symbol_short!("EXPIRED"), claim_id), claim.policy_id);
+            expired += 1;
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingClaim(head));
+        head += 1;
+        walked += 1;
+    }
+
+    if walked > 0 {
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingClaimsHead, &head);
+    }
+
+    expired
+}
+
+pub fn get_claim(env: Env, claim_id: BytesN<32>) -> Claim {
+    env.storage()
+        .instance()
+        .get::<_, Claim>(&DataKey::Claim(claim_id))
+        .unwrap_or_else(|| panic!("Claim not found"))
+}
+
+pub fn list_claims_by_policy(env: Env, policy_id: BytesN<32>) -> Vec<Claim> {
+    let claim_ids: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PolicyClaims(policy_id))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut claims = Vec::new(&env);
+    for claim_id in claim_ids.iter() {
+        claims.push_back(get_claim(env.clone(), claim_id));
+    }
+
+    claims
+}
+
+/// Sum of the reservations held by a policy's claims awaiting assessment
+fn policy_reserved(env: &Env, policy_id: &BytesN<32>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyReserved(policy_id.clone()))
+        .unwrap_or(0)
+}
+
+fn set_policy_reserved(env: &Env, policy_id: &BytesN<32>, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyReserved(policy_id.clone()), &amount);
+}
+
+fn release_reservation(env: &Env, claim: &mut Claim) {
+    if claim.reserved_amount == 0 {
+        return;
+    }
+
+    let policy: InsurancePolicy = env
+        .storage()
+        .instance()
+        .get(&DataKey::Policy(claim.policy_id.clone()))
+        .unwrap_or_else(|| panic!("Policy not found"));
+    release_claim(env, &policy.coverage, claim.reserved_amount);
+    let outstanding = policy_reserved(env, &claim.policy_id);
+    set_policy_reserved(
+        env,
+        &claim.policy_id,
+        (outstanding - claim.reserved_amount).max(0),
+    );
+    claim.reserved_amount = 0;
+}
@@ -1,5 +1,36 @@
+use crate::admin::{premium_token, require_rental_platform};
+use crate::catalog::CoverageProduct;
+use crate::catalog::{get_coverage_product, term_premium};
+use crate::pool::{add_exposure, credit_pool, pay_from_pool, remove_exposure};
+use crate::quotes::take_quote;
 use crate::utils::{generate_policy_id, ContractError, DataKey};
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+use soroban_sdk::{contracttype, symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+/// How long after a missed due date claims are still accepted
+pub const PREMIUM_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PremiumFrequency {
+    /// One premium covers the whole coverage period
+    Single = 0,
+    Monthly = 1,
+    Quarterly = 2,
+    Annual = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyStatus {
+    /// Created but no premium paid yet
+    Inactive = 0,
+    Active = 1,
+    GracePeriod = 2,
+    Lapsed = 3,
+    Expired = 4,
+}
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -7,18 +38,223 @@ pub struct InsurancePolicy {
     pub policy_id: BytesN<32>,
     pub farmer: Address,
     pub coverage: Symbol,
+    /// Catalog version of the coverage product the policy was written under
+    pub product_version: u32,
+    pub region: Symbol,
+    /// Amount due per installment
     pub premium: i128,
+    /// Most the policy can pay out over its coverage period
+    pub max_payout: i128,
+    /// What is left of `max_payout` after earlier payouts; counts towards the pool's exposure
+    pub remaining_limit: i128,
+    pub deductible: i128,
+    pub coinsurance_bps: u32,
     pub active: bool,
+    pub coverage_start: u64,
+    pub coverage_end: u64,
+    pub premium_frequency: PremiumFrequency,
+    /// Coverage is paid up to this timestamp
+    pub next_premium_due: u64,
+    pub premiums_paid: i128,
 }
 
+/// Writes a policy on the current terms of the coverage product. The term premium is split
+/// evenly over the installments of `premium_frequency`.
 pub fn create_pol(
     env: Env,
     farmer: Address,
     coverage: Symbol,
-    premium: i128,
+    region: Symbol,
+    coverage_start: u64,
+    premium_frequency: PremiumFrequency,
+) -> Result<BytesN<32>, ContractError> {
+    farmer.require_auth();
+
+    let product = get_coverage_product(env.clone(), coverage)?;
+    let term_premium = term_premium(&product)?;
+    write_policy(
+        env,
+        farmer,
+        product,
+        region,
+        coverage_start,
+        premium_frequency,
+        term_premium,
+    )
+}
+
+/// Writes a policy at the discounted term premium of a quote from `compute_premium_quote`.
+/// The quote is consumed; `quoted_premium` must match its final premium.
+pub fn create_pol_with_quote(
+    env: Env,
+    farmer: Address,
+    quote_id: BytesN<32>,
+    quoted_premium: i128,
+    region: Symbol,
+    coverage_start: u64,
+    premium_frequency: PremiumFrequency,
 ) -> Result<BytesN<32>, ContractError> {
     farmer.require_auth();
 
+    let quote = take_quote(&env, &farmer, &quote_id, quoted_premium)?;
+    let product = get_coverage_product(env.clone(), quote.coverage)?;
+    write_policy(
+        env,
+        farmer,
+        product,
+        region,
+        coverage_start,
+        premium_frequency,
+        quote.final_premium,
+    )
+}
+
+/// Rental platform that wrote a rental policy and the booking it covers
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RentalCover {
+    pub platform: Address,
+    pub reference: BytesN<32>,
+}
+
+/// Writes a fully paid policy covering a booking on a registered rental platform. The platform
+/// has already transferred `premium` to this contract, which must be at least the coverage
+/// product's term premium pro-rated over the rental window.
+#[allow(clippy::too_many_arguments)]
+pub fn create_rental_policy(
+    env: Env,
+    platform: Address,
+    insured: Address,
+    coverage: Symbol,
+    coverage_start: u64,
+    coverage_end: u64,
+    premium: i128,
+    reference: BytesN<32>,
+) -> Result<BytesN<32>, ContractError> {
+    platform.require_auth();
+    require_rental_platform(&env, &platform)?;
+
+    let product = get_coverage_product(env.clone(), coverage)?;
+    if coverage_end <= coverage_start || coverage_end - coverage_start > product.term_secs {
+        return Err(ContractError::InvalidCoveragePeriod);
+    }
+    let minimum_premium = term_premium(&product)? * (coverage_end - coverage_start) as i128
+        / product.term_secs as i128;
+    if premium <= 0 || premium < minimum_premium {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let policy_id = generate_policy_id(&env)?;
+    let policy = InsurancePolicy {
+        policy_id: policy_id.clone(),
+        farmer: insured.clone(),
+        coverage: product.coverage.clone(),
+        product_version: product.version,
+        region: symbol_short!("RENTAL"),
+        premium,
+        max_payout: product.max_payout,
+        remaining_limit: product.max_payout,
+        deductible: product.deductible,
+        coinsurance_bps: product.coinsurance_bps,
+        active: true,
+        coverage_start,
+        coverage_end,
+        premium_frequency: PremiumFrequency::Single,
+        next_premium_due: coverage_end,
+        premiums_paid: premium,
+    };
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Policy(policy_id.clone()), &policy);
+    env.storage().instance().set(
+        &DataKey::RentalCover(policy_id.clone()),
+        &RentalCover {
+            platform,
+            reference,
+        },
+    );
+    credit_pool(&env, &product.coverage, premium);
+    add_exposure(&env, &policy);
+
+    let key = DataKey::FarmerPolicies(insured);
+    let mut farmer_policies: Vec<BytesN<32>> = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(&env));
+    farmer_policies.push_back(policy_id.clone());
+    env.storage().instance().set(&key, &farmer_policies);
+
+    env.events()
+        .publish((symbol_short!("POLICY"), policy_id.clone()), policy);
+    Ok(policy_id)
+}
+
+/// Cancels a rental policy for a booking that was called off, refunding the premium to the
+/// insured. Only the platform that wrote the policy may cancel it, and only before any claim
+/// is filed. Returns the amount refunded.
+pub fn cancel_rental_policy(
+    env: Env,
+    platform: Address,
+    policy_id: BytesN<32>,
+) -> Result<i128, ContractError> {
+    platform.require_auth();
+
+    let cover: RentalCover = env
+        .storage()
+        .instance()
+        .get(&DataKey::RentalCover(policy_id.clone()))
+        .ok_or(ContractError::Unauthorized)?;
+    if cover.platform != platform {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let mut policy = get_policy(env.clone(), policy_id.clone());
+    let has_claims = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<BytesN<32>>>(&DataKey::PolicyClaims(policy_id.clone()))
+        .is_some_and(|claims| !claims.is_empty());
+    if !policy.active || has_claims {
+        return Err(ContractError::PolicyNotCancellable);
+    }
+
+    let refund = policy.premiums_paid;
+    pay_from_pool(&env, &policy.coverage, &policy.farmer, refund)?;
+    remove_exposure(&env, &policy, policy.remaining_limit);
+
+    policy.active = false;
+    policy.remaining_limit = 0;
+    env.storage()
+        .instance()
+        .set(&DataKey::Policy(policy_id.clone()), &policy);
+    env.events()
+        .publish((symbol_short!("CANCELLED"), policy_id), refund);
+
+    Ok(refund)
+}
+
+fn write_policy(
+    env: Env,
+    farmer: Address,
+    product: CoverageProduct,
+    region: Symbol,
+    coverage_start: u64,
+    premium_frequency: PremiumFrequency,
+    term_premium: i128,
+) -> Result<BytesN<32>, ContractError> {
+    if coverage_start < env.ledger().timestamp() {
+        return Err(ContractError::InvalidCoveragePeriod);
+    }
+    let coverage_end = coverage_start
+        .checked_add(product.term_secs)
+        .ok_or(ContractError::InvalidCoveragePeriod)?;
+
+    let period = premium_period(premium_frequency, product.term_secs);
+    let installments = product.term_secs.div_ceil(period) as i128;
+    let premium = (term_premium + installments - 1) / installments;
+
     if premium <= 0 {
         panic!("Premium must be positive");
     }
@@ -27,33 +263,77 @@ pub fn create_pol(
     let policy = InsurancePolicy {
         policy_id: policy_id.clone(),
         farmer: farmer.clone(),
-        coverage,
+        coverage: product.coverage,
+        product_version: product.version,
+        region,
         premium,
+        max_payout: product.max_payout,
+        remaining_limit: product.max_payout,
+        deductible: product.deductible,
+        coinsurance_bps: product.coinsurance_bps,
         active: false,
+        coverage_start,
+        coverage_end,
+        premium_frequency,
+        next_premium_due: coverage_start,
+        premiums_paid: 0,
     };
 
     env.storage()
         .instance()
         .set(&DataKey::Policy(policy_id.clone()), &policy);
+
+    let key = DataKey::FarmerPolicies(farmer);
+    let mut farmer_policies: Vec<BytesN<32>> = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(&env));
+    farmer_policies.push_back(policy_id.clone());
+    env.storage().instance().set(&key, &farmer_policies);
+
     env.events()
         .publish((symbol_short!("POLICY"), policy_id.clone()), policy.clone());
     Ok(policy_id)
 }
 
-pub fn pay_prem(env: Env, policy_id: BytesN<32>) {
-    let mut policy = env
-        .storage()
-        .instance()
-        .get::<_, InsurancePolicy>(&DataKey::Policy(policy_id.clone()))
-        .unwrap_or_else(|| panic!("Policy not found"));
+/// Pays the next premium installment into the pool and extends coverage by one period.
+/// Lapsed policies must be reinstated instead. A policy stays pending until its first
+/// premium, so if that is paid after the requested start the coverage period is moved to
+/// begin now.
+pub fn pay_prem(env: Env, policy_id: BytesN<32>) -> Result<(), ContractError> {
+    let mut policy = get_policy(env.clone(), policy_id.clone());
 
     policy.farmer.require_auth();
 
-    if policy.active {
+    match policy_status(&env, &policy) {
+        PolicyStatus::Expired => return Err(ContractError::PolicyExpired),
+        PolicyStatus::Lapsed => return Err(ContractError::PolicyLapsed),
+        _ => {}
+    }
+
+    if policy.next_premium_due >= policy.coverage_end {
         panic!("Premium already paid");
     }
 
+    let now = env.ledger().timestamp();
+    if policy.premiums_paid == 0 && now > policy.coverage_start {
+        let term_secs = policy.coverage_end - policy.coverage_start;
+        policy.coverage_start = now;
+        policy.coverage_end = now
+            .checked_add(term_secs)
+            .ok_or(ContractError::InvalidCoveragePeriod)?;
+        policy.next_premium_due = now;
+    }
+
+    collect_premium(&env, &policy, policy.premium)?;
+    if policy.premiums_paid == 0 {
+        add_exposure(&env, &policy);
+    }
+
     policy.active = true;
+    policy.premiums_paid += policy.premium;
+    policy.next_premium_due = next_due_date(&policy, policy.next_premium_due);
     env.storage()
         .instance()
         .set(&DataKey::Policy(policy_id.clone()), &policy);
@@ -61,6 +341,38 @@ pub fn pay_prem(env: Env, policy_id: BytesN<32>) {
         (symbol_short!("PREMIUM"), policy_id.clone()),
         policy.clone(),
     );
+
+    Ok(())
+}
+
+/// Brings a lapsed policy back into force by paying every installment missed up to now.
+/// Returns the arrears paid.
+pub fn reinstate_policy(env: Env, policy_id: BytesN<32>) -> Result<i128, ContractError> {
+    let mut policy = get_policy(env.clone(), policy_id.clone());
+
+    policy.farmer.require_auth();
+
+    if policy_status(&env, &policy) != PolicyStatus::Lapsed {
+        return Err(ContractError::PolicyNotLapsed);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut arrears = 0;
+    while policy.next_premium_due <= now && policy.next_premium_due < policy.coverage_end {
+        arrears += policy.premium;
+        policy.next_premium_due = next_due_date(&policy, policy.next_premium_due);
+    }
+
+    collect_premium(&env, &policy, arrears)?;
+
+    policy.premiums_paid += arrears;
+    env.storage()
+        .instance()
+        .set(&DataKey::Policy(policy_id.clone()), &policy);
+    env.events()
+        .publish((symbol_short!("REINSTATE"), policy_id), arrears);
+
+    Ok(arrears)
 }
 
 pub fn get_policy(env: Env, policy_id: BytesN<32>) -> InsurancePolicy {
@@ -69,3 +381,73 @@ pub fn get_policy(env: Env, policy_id: BytesN<32>) -> InsurancePolicy {
         .get::<_, InsurancePolicy>(&DataKey::Policy(policy_id))
         .unwrap_or_else(|| panic!("Policy not found"))
 }
+
+pub fn list_policies_by_farmer(env: Env, farmer: Address) -> Vec<InsurancePolicy> {
+    let policy_ids: Vec<BytesN<32>> = env
+        .storage()
+        .instance()
+        .get(&DataKey::FarmerPolicies(farmer))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut policies = Vec::new(&env);
+    for policy_id in policy_ids.iter() {
+        policies.push_back(get_policy(env.clone(), policy_id));
+    }
+
+    policies
+}
+
+pub fn get_remaining_limit(env: Env, policy_id: BytesN<32>) -> i128 {
+    get_policy(env, policy_id).remaining_limit
+}
+
+pub fn get_policy_status(env: Env, policy_id: BytesN<32>) -> PolicyStatus {
+    let policy = get_policy(env.clone(), policy_id);
+    policy_status(&env, &policy)
+}
+
+/// Status is derived from the ledger time on every read, so nothing needs to run when a
+/// due date passes.
+pub fn policy_status(env: &Env, policy: &InsurancePolicy) -> PolicyStatus {
+    let now = env.ledger().timestamp();
+
+    if now >= policy.coverage_end {
+        PolicyStatus::Expired
+    } else if !policy.active {
+        PolicyStatus::Inactive
+    } else if now < policy.next_premium_due || policy.next_premium_due >= policy.coverage_end {
+        PolicyStatus::Active
+    } else if now < policy.next_premium_due + PREMIUM_GRACE_PERIOD {
+        PolicyStatus::GracePeriod
+    } else {
+        PolicyStatus::Lapsed
+    }
+}
+
+fn premium_period(frequency: PremiumFrequency, term_secs: u64) -> u64 {
+    match frequency {
+        PremiumFrequency::Single => term_secs,
+        PremiumFrequency::Monthly => 30 * DAY,
+        PremiumFrequency::Quarterly => 90 * DAY,
+        PremiumFrequency::Annual => 365 * DAY,
+    }
+}
+
+fn next_due_date(policy: &InsurancePolicy, due: u64) -> u64 {
+    let term_secs = policy.coverage_end - policy.coverage_start;
+    let period = premium_period(policy.premium_frequency, term_secs);
+
+    (due + period).min(policy.coverage_end)
+}
+
+fn collect_premium(env: &Env, policy: &InsurancePolicy, amount: i128) -> Result<(), ContractError> {
+    let token = premium_token(env)?;
+    token::Client::new(env, &token).transfer(
+        &policy.farmer,
+        &env.current_contract_address(),
+        &amount,
+    );
+    credit_pool(env, &policy.coverage, amount);
+
+    Ok(())
+}
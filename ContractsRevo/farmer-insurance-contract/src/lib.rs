@@ -1,11 +1,22 @@
 #![no_std]
 
+use crate::catalog::CoverageProduct;
+use crate::claims::{Claim, ClaimDecision};
+use crate::insurance::{PolicyStatus, PremiumFrequency};
+use crate::parametric::{IndexReport, ParametricTrigger};
+use crate::pool::PoolStatus;
+use crate::quotes::{DiscountSchedule, QuoteBreakdown, WaterDiscountTier};
 use crate::utils::ContractError;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
 
+mod admin;
+mod catalog;
 mod claims;
 mod insurance;
+mod parametric;
 mod payouts;
+mod pool;
+mod quotes;
 mod utils;
 
 #[contract]
@@ -13,19 +24,212 @@ pub struct FarmerInsuranceContract;
 
 #[contractimpl]
 impl FarmerInsuranceContract {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        premium_token: Address,
+    ) -> Result<(), ContractError> {
+        admin::initialize(env, admin, premium_token)
+    }
+
+    pub fn add_assessor(env: Env, admin: Address, assessor: Address) -> Result<(), ContractError> {
+        admin::add_assessor(env, admin, assessor)
+    }
+
+    pub fn remove_assessor(
+        env: Env,
+        admin: Address,
+        assessor: Address,
+    ) -> Result<(), ContractError> {
+        admin::remove_assessor(env, admin, assessor)
+    }
+
+    pub fn set_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), ContractError> {
+        admin::set_oracle(env, admin, oracle)
+    }
+
+    pub fn add_rental_platform(
+        env: Env,
+        admin: Address,
+        platform: Address,
+    ) -> Result<(), ContractError> {
+        admin::add_rental_platform(env, admin, platform)
+    }
+
+    pub fn remove_rental_platform(
+        env: Env,
+        admin: Address,
+        platform: Address,
+    ) -> Result<(), ContractError> {
+        admin::remove_rental_platform(env, admin, platform)
+    }
+
+    pub fn is_assessor(env: Env, assessor: Address) -> bool {
+        admin::is_assessor(env, assessor)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_coverage_product(
+        env: Env,
+        admin: Address,
+        coverage: Symbol,
+        max_payout: i128,
+        premium_rate_bps: u32,
+        term_secs: u64,
+        exclusions_hash: BytesN<32>,
+        deductible: i128,
+        coinsurance_bps: u32,
+        claim_window_secs: u64,
+    ) -> Result<u32, ContractError> {
+        catalog::create_coverage_product(
+            env,
+            admin,
+            coverage,
+            max_payout,
+            premium_rate_bps,
+            term_secs,
+            exclusions_hash,
+            deductible,
+            coinsurance_bps,
+            claim_window_secs,
+        )
+    }
+
+    pub fn get_coverage_product(
+        env: Env,
+        coverage: Symbol,
+    ) -> Result<CoverageProduct, ContractError> {
+        catalog::get_coverage_product(env, coverage)
+    }
+
+    pub fn get_product_version(
+        env: Env,
+        coverage: Symbol,
+        version: u32,
+    ) -> Result<CoverageProduct, ContractError> {
+        catalog::get_product_version(env, coverage, version)
+    }
+
     pub fn create_pol(
         env: Env,
         farmer: Address,
         coverage: Symbol,
+        region: Symbol,
+        coverage_start: u64,
+        premium_frequency: PremiumFrequency,
+    ) -> Result<BytesN<32>, ContractError> {
+        insurance::create_pol(
+            env,
+            farmer,
+            coverage,
+            region,
+            coverage_start,
+            premium_frequency,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_pol_with_quote(
+        env: Env,
+        farmer: Address,
+        quote_id: BytesN<32>,
+        quoted_premium: i128,
+        region: Symbol,
+        coverage_start: u64,
+        premium_frequency: PremiumFrequency,
+    ) -> Result<BytesN<32>, ContractError> {
+        insurance::create_pol_with_quote(
+            env,
+            farmer,
+            quote_id,
+            quoted_premium,
+            region,
+            coverage_start,
+            premium_frequency,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_rental_policy(
+        env: Env,
+        platform: Address,
+        insured: Address,
+        coverage: Symbol,
+        coverage_start: u64,
+        coverage_end: u64,
         premium: i128,
+        reference: BytesN<32>,
     ) -> Result<BytesN<32>, ContractError> {
-        insurance::create_pol(env, farmer, coverage, premium)
+        insurance::create_rental_policy(
+            env,
+            platform,
+            insured,
+            coverage,
+            coverage_start,
+            coverage_end,
+            premium,
+            reference,
+        )
+    }
+
+    pub fn cancel_rental_policy(
+        env: Env,
+        platform: Address,
+        policy_id: BytesN<32>,
+    ) -> Result<i128, ContractError> {
+        insurance::cancel_rental_policy(env, platform, policy_id)
+    }
+
+    pub fn set_risk_sources(
+        env: Env,
+        admin: Address,
+        water_contract: Option<Address>,
+        certification_contract: Option<Address>,
+    ) -> Result<(), ContractError> {
+        quotes::set_risk_sources(env, admin, water_contract, certification_contract)
+    }
+
+    pub fn set_discount_schedule(
+        env: Env,
+        admin: Address,
+        water_tiers: Vec<WaterDiscountTier>,
+        quality_discount_bps: u32,
+    ) -> Result<(), ContractError> {
+        quotes::set_discount_schedule(env, admin, water_tiers, quality_discount_bps)
     }
 
-    pub fn pay_prem(env: Env, policy_id: BytesN<32>) {
+    pub fn get_discount_schedule(env: Env) -> Option<DiscountSchedule> {
+        quotes::get_discount_schedule(env)
+    }
+
+    pub fn compute_premium_quote(
+        env: Env,
+        farmer: Address,
+        coverage: Symbol,
+    ) -> Result<QuoteBreakdown, ContractError> {
+        quotes::compute_premium_quote(env, farmer, coverage)
+    }
+
+    pub fn list_policies_by_farmer(env: Env, farmer: Address) -> Vec<insurance::InsurancePolicy> {
+        insurance::list_policies_by_farmer(env, farmer)
+    }
+
+    pub fn pay_prem(env: Env, policy_id: BytesN<32>) -> Result<(), ContractError> {
         insurance::pay_prem(env, policy_id)
     }
 
+    pub fn reinstate_policy(env: Env, policy_id: BytesN<32>) -> Result<i128, ContractError> {
+        insurance::reinstate_policy(env, policy_id)
+    }
+
+    pub fn get_remaining_limit(env: Env, policy_id: BytesN<32>) -> i128 {
+        insurance::get_remaining_limit(env, policy_id)
+    }
+
+    pub fn get_policy_status(env: Env, policy_id: BytesN<32>) -> PolicyStatus {
+        insurance::get_policy_status(env, policy_id)
+    }
+
     pub fn sub_claim(
         env: Env,
         policy_id: BytesN<32>,
@@ -35,10 +239,155 @@ impl FarmerInsuranceContract {
         claims::sub_claim(env, policy_id, event_hash, payout)
     }
 
-    pub fn pay_out(env: Env, claim_id: BytesN<32>, admin: Address) {
+    pub fn assess_claim(
+        env: Env,
+        assessor: Address,
+        claim_id: BytesN<32>,
+        approved_amount: i128,
+        decision: ClaimDecision,
+        notes_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        claims::assess_claim(
+            env,
+            assessor,
+            claim_id,
+            approved_amount,
+            decision,
+            notes_hash,
+        )
+    }
+
+    pub fn reject_claim(
+        env: Env,
+        assessor: Address,
+        claim_id: BytesN<32>,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        claims::reject_claim(env, assessor, claim_id, reason_hash)
+    }
+
+    pub fn pay_out(env: Env, claim_id: BytesN<32>, admin: Address) -> Result<(), ContractError> {
         payouts::pay_out(env, claim_id, admin)
     }
 
+    pub fn get_claim(env: Env, claim_id: BytesN<32>) -> Claim {
+        claims::get_claim(env, claim_id)
+    }
+
+    pub fn list_claims_by_policy(env: Env, policy_id: BytesN<32>) -> Vec<Claim> {
+        claims::list_claims_by_policy(env, policy_id)
+    }
+
+    pub fn register_insured_event(
+        env: Env,
+        admin: Address,
+        event_hash: BytesN<32>,
+        occurred_at: u64,
+    ) -> Result<(), ContractError> {
+        claims::register_insured_event(env, admin, event_hash, occurred_at)
+    }
+
+    pub fn get_insured_event(env: Env, event_hash: BytesN<32>) -> Option<u64> {
+        claims::get_insured_event(env, event_hash)
+    }
+
+    pub fn set_claim_expiry_age(
+        env: Env,
+        admin: Address,
+        age_secs: u64,
+    ) -> Result<(), ContractError> {
+        claims::set_claim_expiry_age(env, admin, age_secs)
+    }
+
+    pub fn get_claim_expiry_age(env: Env) -> u64 {
+        claims::get_claim_expiry_age(env)
+    }
+
+    pub fn expire_stale_claims(env: Env, max: u32) -> u32 {
+        claims::expire_stale_claims(env, max)
+    }
+
+    pub fn register_trigger(
+        env: Env,
+        admin: Address,
+        coverage: Symbol,
+        metric: Symbol,
+        threshold: i128,
+        payout_bps: u32,
+    ) -> Result<u32, ContractError> {
+        parametric::register_trigger(env, admin, coverage, metric, threshold, payout_bps)
+    }
+
+    pub fn report_index_value(
+        env: Env,
+        oracle: Address,
+        region: Symbol,
+        metric: Symbol,
+        period: u32,
+        value: i128,
+    ) -> Result<(), ContractError> {
+        parametric::report_index_value(env, oracle, region, metric, period, value)
+    }
+
+    pub fn claim_parametric_payout(
+        env: Env,
+        farmer: Address,
+        policy_id: BytesN<32>,
+        period: u32,
+    ) -> Result<i128, ContractError> {
+        parametric::claim_parametric_payout(env, farmer, policy_id, period)
+    }
+
+    pub fn get_triggers(env: Env) -> Vec<ParametricTrigger> {
+        parametric::get_triggers(env)
+    }
+
+    pub fn get_index_value(
+        env: Env,
+        region: Symbol,
+        metric: Symbol,
+        period: u32,
+    ) -> Option<IndexReport> {
+        parametric::get_index_value(env, region, metric, period)
+    }
+
+    pub fn capitalize_pool(
+        env: Env,
+        admin: Address,
+        coverage: Symbol,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        pool::capitalize_pool(env, admin, coverage, amount)
+    }
+
+    pub fn withdraw_surplus(
+        env: Env,
+        admin: Address,
+        coverage: Symbol,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        pool::withdraw_surplus(env, admin, coverage, amount)
+    }
+
+    pub fn set_reserve_ratio(
+        env: Env,
+        admin: Address,
+        reserve_ratio_bps: u32,
+    ) -> Result<(), ContractError> {
+        pool::set_reserve_ratio(env, admin, reserve_ratio_bps)
+    }
+
+    pub fn release_expired_exposure(
+        env: Env,
+        policy_id: BytesN<32>,
+    ) -> Result<i128, ContractError> {
+        pool::release_expired_exposure(env, policy_id)
+    }
+
+    pub fn get_pool_status(env: Env, coverage: Symbol) -> PoolStatus {
+        pool::get_pool_status(env, coverage)
+    }
+
     pub fn get_policy(env: Env, policy_id: BytesN<32>) -> insurance::InsurancePolicy {
         insurance::get_policy(env, policy_id)
     }
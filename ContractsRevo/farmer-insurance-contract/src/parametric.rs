@@ -0,0 +1,193 @@
+use crate::admin::{require_admin, require_oracle};
+use crate::insurance::{get_policy, policy_status, PolicyStatus};
+use crate::payouts::settle_loss;
+use crate::pool::BPS_DENOMINATOR;
+use crate::utils::{ContractError, DataKey};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+pub const MAX_TRIGGERS: u32 = 50;
+
+/// Pays `payout_bps` of the policy's maximum payout once `metric` is reported at or below
+/// `threshold` in the policy's region.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParametricTrigger {
+    pub trigger_id: u32,
+    pub coverage: Symbol,
+    pub metric: Symbol,
+    pub threshold: i128,
+    pub payout_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexReport {
+    pub value: i128,
+    pub reported_at: u64,
+}
+
+pub fn register_trigger(
+    env: Env,
+    admin: Address,
+    coverage: Symbol,
+    metric: Symbol,
+    threshold: i128,
+    payout_bps: u32,
+) -> Result<u32, ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if payout_bps == 0 || payout_bps as i128 > BPS_DENOMINATOR {
+        return Err(ContractError::InvalidTrigger);
+    }
+
+    let mut triggers = get_triggers(env.clone());
+    if triggers.len() >= MAX_TRIGGERS {
+        return Err(ContractError::InvalidTrigger);
+    }
+
+    let trigger = ParametricTrigger {
+        trigger_id: triggers.len() + 1,
+        coverage,
+        metric,
+        threshold,
+        payout_bps,
+    };
+    triggers.push_back(trigger.clone());
+    env.storage().instance().set(&DataKey::Triggers, &triggers);
+
+    env.events().publish(
+        (symbol_short!("TRIGGER"), trigger.trigger_id),
+        trigger.clone(),
+    );
+
+    Ok(trigger.trigger_id)
+}
+
+/// Records an index value for a region and period. Reports are final; every trigger the value
+/// crosses is announced so policyholders in the region can claim.
+pub fn report_index_value(
+    env: Env,
+    oracle: Address,
+    region: Symbol,
+    metric: Symbol,
+    period: u32,
+    value: i128,
+) -> Result<(), ContractError> {
+    oracle.require_auth();
+    require_oracle(&env, &oracle)?;
+
+    let key = DataKey::IndexValue(region.clone(), metric.clone(), period);
+    if env.storage().instance().has(&key) {
+        return Err(ContractError::IndexAlreadyReported);
+    }
+
+    let report = IndexReport {
+        value,
+        reported_at: env.ledger().timestamp(),
+    };
+    env.storage().instance().set(&key, &report);
+
+    env.events().publish(
+        (symbol_short!("INDEX"), region.clone(), metric.clone()),
+        (period, value),
+    );
+
+    for trigger in get_triggers(env.clone()).iter() {
+        if trigger.metric == metric && value <= trigger.threshold {
+            env.events().publish(
+                (
+                    symbol_short!("TRIGGERED"),
+                    region.clone(),
+                    trigger.trigger_id,
+                ),
+                period,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Pays the largest payout among the policy's crossed triggers for the period, subject to the
+/// policy's deductible, coinsurance and remaining limit. Each policy can claim once per period.
+pub fn claim_parametric_payout(
+    env: Env,
+    farmer: Address,
+    policy_id: BytesN<32>,
+    period: u32,
+) -> Result<i128, ContractError> {
+    farmer.require_auth();
+
+    let mut policy = get_policy(env.clone(), policy_id.clone());
+    if policy.farmer != farmer {
+        return Err(ContractError::Unauthorized);
+    }
+
+    match policy_status(&env, &policy) {
+        PolicyStatus::Lapsed => return Err(ContractError::PolicyLapsed),
+        PolicyStatus::Inactive => panic!("Policy is not active"),
+        _ => {}
+    }
+
+    let claimed_key = DataKey::ParametricClaim(policy_id.clone(), period);
+    if env.storage().instance().has(&claimed_key) {
+        return Err(ContractError::PayoutAlreadyClaimed);
+    }
+
+    let mut payout_bps = 0;
+    for trigger in get_triggers(env.clone()).iter() {
+        if trigger.coverage != policy.coverage {
+            continue;
+        }
+
+        // Only events reported while the policy was paid up count
+        let report = get_index_value(
+            env.clone(),
+            policy.region.clone(),
+            trigger.metric.clone(),
+            period,
+        );
+        if let Some(report) = report {
+            if report.value <= trigger.threshold
+                && report.reported_at >= policy.coverage_start
+                && report.reported_at < policy.next_premium_due
+            {
+                payout_bps = payout_bps.max(trigger.payout_bps);
+            }
+        }
+    }
+
+    if payout_bps == 0 {
+        return Err(ContractError::NoPayoutDue);
+    }
+
+    let loss = policy.max_payout * payout_bps as i128 / BPS_DENOMINATOR;
+    let amount = settle_loss(&env, &mut policy, loss)?;
+    env.storage().instance().set(&claimed_key, &amount);
+
+    env.events().publish(
+        (symbol_short!("PARAPAY"), policy_id, farmer),
+        (period, amount),
+    );
+
+    Ok(amount)
+}
+
+pub fn get_triggers(env: Env) -> Vec<ParametricTrigger> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Triggers)
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+pub fn get_index_value(
+    env: Env,
+    region: Symbol,
+    metric: Symbol,
+    period: u32,
+) -> Option<IndexReport> {
+    env.storage()
+        .instance()
+        .get(&DataKey::IndexValue(region, metric, period))
+}
@@ -1,18 +1,23 @@
-use crate::claims::Claim;
+use crate::admin::require_admin;
+use crate::claims::{Claim, ClaimStatus};
 use crate::insurance::InsurancePolicy;
-use crate::utils::DataKey;
+use crate::pool::{pay_from_pool, remove_exposure, BPS_DENOMINATOR};
+use crate::utils::{ContractError, DataKey};
 use soroban_sdk::{symbol_short, Address, BytesN, Env};
 
-pub fn pay_out(env: Env, claim_id: BytesN<32>, admin: Address) {
+/// Pays an approved claim from the policy's coverage pool, after applying the policy's
+/// deductible, coinsurance and remaining limit to the approved loss.
+pub fn pay_out(env: Env, claim_id: BytesN<32>, admin: Address) -> Result<(), ContractError> {
     admin.require_auth();
+    require_admin(&env, &admin)?;
 
-    let claim: Claim = env
+    let mut claim: Claim = env
         .storage()
         .instance()
         .get::<_, Claim>(&DataKey::Claim(claim_id.clone()))
         .expect("Claim not found");
 
-    let policy: InsurancePolicy = env
+    let mut policy: InsurancePolicy = env
         .storage()
         .instance()
         .get::<_, InsurancePolicy>(&DataKey::Policy(claim.policy_id.clone()))
@@ -22,12 +27,55 @@ pub fn pay_out(env: Env, claim_id: BytesN<32>, admin: Address) {
         panic!("Policy is not active or already closed");
     }
 
+    if claim.status != ClaimStatus::Approved {
+        return Err(ContractError::ClaimNotApproved);
+    }
+
+    claim.paid_amount = settle_loss(&env, &mut policy, claim.approved_amount)?;
+    claim.status = ClaimStatus::Paid;
+    claim.paid_at = env.ledger().timestamp();
     env.storage()
         .instance()
-        .remove(&DataKey::Claim(claim_id.clone()));
+        .set(&DataKey::Claim(claim_id.clone()), &claim);
 
     env.events().publish(
         (symbol_short!("PAYOUT"), claim_id, policy.farmer.clone()),
-        claim.payout_amount,
+        claim.paid_amount,
     );
+
+    Ok(())
+}
+
+/// payable = max(0, loss - deductible) * (10000 - coinsurance_bps) / 10000, capped by the
+/// policy's remaining limit.
+pub fn payable_amount(policy: &InsurancePolicy, loss: i128) -> i128 {
+    let above_deductible = (loss - policy.deductible).max(0);
+    let insured_share = BPS_DENOMINATOR - policy.coinsurance_bps as i128;
+    let payable = above_deductible
+        .checked_mul(insured_share)
+        .map(|amount| amount / BPS_DENOMINATOR)
+        .unwrap_or(policy.remaining_limit);
+
+    payable.min(policy.remaining_limit)
+}
+
+/// Pays the policyholder what is owed for `loss` and draws it down from the policy's limit.
+/// Returns the amount paid.
+pub fn settle_loss(
+    env: &Env,
+    policy: &mut InsurancePolicy,
+    loss: i128,
+) -> Result<i128, ContractError> {
+    let payable = payable_amount(policy, loss);
+    if payable > 0 {
+        pay_from_pool(env, &policy.coverage, &policy.farmer, payable)?;
+    }
+
+    policy.remaining_limit -= payable;
+    remove_exposure(env, policy, payable);
+    env.storage()
+        .instance()
+        .set(&DataKey::Policy(policy.policy_id.clone()), policy);
+
+    Ok(payable)
 }
@@ -0,0 +1,228 @@
+use crate::admin::{premium_token, require_admin};
+use crate::insurance::{get_policy, policy_status, InsurancePolicy, PolicyStatus};
+use crate::utils::{ContractError, DataKey};
+use soroban_sdk::{contracttype, symbol_short, token, Address, BytesN, Env, Symbol};
+
+pub const BPS_DENOMINATOR: i128 = 10_000;
+/// Pools must hold their full active exposure unless the admin lowers the ratio
+pub const DEFAULT_RESERVE_RATIO_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolStatus {
+    pub balance: i128,
+    /// Sum of the remaining limits of the coverage type's paid policies, until each is
+    /// released after expiry with `release_expired_exposure`
+    pub active_exposure: i128,
+    /// Requested amounts of claims still awaiting assessment
+    pub reserved_claims: i128,
+    pub reserve_ratio_bps: u32,
+}
+
+pub fn capitalize_pool(
+    env: Env,
+    admin: Address,
+    coverage: Symbol,
+    amount: i128,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let token = premium_token(&env)?;
+    token::Client::new(&env, &token).transfer(&admin, &env.current_contract_address(), &amount);
+    credit_pool(&env, &coverage, amount);
+
+    env.events()
+        .publish((symbol_short!("CAPITAL"), coverage), amount);
+
+    Ok(())
+}
+
+/// Withdraws pool funds above the reserve the pool's active exposure requires. Claims awaiting
+/// assessment stay fully covered whatever the reserve ratio.
+pub fn withdraw_surplus(
+    env: Env,
+    admin: Address,
+    coverage: Symbol,
+    amount: i128,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let status = get_pool_status(env.clone(), coverage.clone());
+    let required = (status.active_exposure * status.reserve_ratio_bps as i128 / BPS_DENOMINATOR)
+        .max(status.reserved_claims);
+    if status.balance - amount < required {
+        return Err(ContractError::InsufficientReserves);
+    }
+
+    debit_pool(&env, &coverage, amount)?;
+    let token = premium_token(&env)?;
+    token::Client::new(&env, &token).transfer(&env.current_contract_address(), &admin, &amount);
+
+    env.events()
+        .publish((symbol_short!("SURPLUS"), coverage), amount);
+
+    Ok(())
+}
+
+pub fn set_reserve_ratio(
+    env: Env,
+    admin: Address,
+    reserve_ratio_bps: u32,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ReserveRatio, &reserve_ratio_bps);
+
+    Ok(())
+}
+
+pub fn get_pool_status(env: Env, coverage: Symbol) -> PoolStatus {
+    PoolStatus {
+        balance: pool_balance(&env, &coverage),
+        active_exposure: active_exposure(&env, &coverage),
+        reserved_claims: reserved_claims(&env, &coverage),
+        reserve_ratio_bps: env
+            .storage()
+            .instance()
+            .get(&DataKey::ReserveRatio)
+            .unwrap_or(DEFAULT_RESERVE_RATIO_BPS),
+    }
+}
+
+/// Stops counting an expired policy's remaining limit towards its coverage type's exposure.
+/// Anyone may call it once the policy has expired. Returns the exposure released, which is 0
+/// for policies never paid for or already released.
+pub fn release_expired_exposure(env: Env, policy_id: BytesN<32>) -> Result<i128, ContractError> {
+    let policy = get_policy(env.clone(), policy_id.clone());
+    if policy_status(&env, &policy) != PolicyStatus::Expired {
+        return Err(ContractError::PolicyNotExpired);
+    }
+
+    let released = if policy.premiums_paid > 0 {
+        remove_exposure(&env, &policy, policy.remaining_limit)
+    } else {
+        0
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::ExposureReleased(policy_id.clone()), &true);
+
+    env.events()
+        .publish((symbol_short!("EXPOSURE"), policy_id), released);
+
+    Ok(released)
+}
+
+/// Counts a policy's limit towards its coverage type's exposure once it is paid for.
+pub fn add_exposure(env: &Env, policy: &InsurancePolicy) {
+    let exposure = active_exposure(env, &policy.coverage);
+    env.storage().instance().set(
+        &DataKey::ActiveExposure(policy.coverage.clone()),
+        &(exposure + policy.remaining_limit),
+    );
+}
+
+/// Takes `amount` of a paid policy's limit off its coverage type's exposure, unless the policy
+/// was already released after expiry. Returns the amount taken off.
+pub fn remove_exposure(env: &Env, policy: &InsurancePolicy, amount: i128) -> i128 {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::ExposureReleased(policy.policy_id.clone()))
+    {
+        return 0;
+    }
+
+    let exposure = active_exposure(env, &policy.coverage);
+    env.storage().instance().set(
+        &DataKey::ActiveExposure(policy.coverage.clone()),
+        &(exposure - amount).max(0),
+    );
+    amount
+}
+
+/// Holds `amount` of the pool against a claim until it is assessed or expires.
+pub fn reserve_claim(env: &Env, coverage: &Symbol, amount: i128) {
+    let reserved = reserved_claims(env, coverage);
+    env.storage().instance().set(
+        &DataKey::ReservedClaims(coverage.clone()),
+        &(reserved + amount),
+    );
+}
+
+pub fn release_claim(env: &Env, coverage: &Symbol, amount: i128) {
+    let reserved = reserved_claims(env, coverage);
+    env.storage().instance().set(
+        &DataKey::ReservedClaims(coverage.clone()),
+        &(reserved - amount).max(0),
+    );
+}
+
+pub fn credit_pool(env: &Env, coverage: &Symbol, amount: i128) {
+    let balance = pool_balance(env, coverage);
+    env.storage()
+        .instance()
+        .set(&DataKey::PoolBalance(coverage.clone()), &(balance + amount));
+}
+
+/// Takes `amount` out of the pool, refusing rather than paying part of it.
+pub fn debit_pool(env: &Env, coverage: &Symbol, amount: i128) -> Result<(), ContractError> {
+    let balance = pool_balance(env, coverage);
+    if balance < amount {
+        return Err(ContractError::InsufficientReserves);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PoolBalance(coverage.clone()), &(balance - amount));
+
+    Ok(())
+}
+
+/// Pays `amount` from the coverage type's pool to `to`.
+pub fn pay_from_pool(
+    env: &Env,
+    coverage: &Symbol,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    debit_pool(env, coverage, amount)?;
+    let token = premium_token(env)?;
+    token::Client::new(env, &token).transfer(&env.current_contract_address(), to, &amount);
+
+    Ok(())
+}
+
+fn pool_balance(env: &Env, coverage: &Symbol) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PoolBalance(coverage.clone()))
+        .unwrap_or(0)
+}
+
+fn reserved_claims(env: &Env, coverage: &Symbol) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReservedClaims(coverage.clone()))
+        .unwrap_or(0)
+}
+
+fn active_exposure(env: &Env, coverage: &Symbol) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActiveExposure(coverage.clone()))
+        .unwrap_or(0)
+}
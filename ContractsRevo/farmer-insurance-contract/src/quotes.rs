@@ -0,0 +1,260 @@
+use crate::admin::require_admin;
+use crate::catalog::{get_coverage_product, term_premium};
+use crate::pool::BPS_DENOMINATOR;
+use crate::utils::{generate_quote_id, ContractError, DataKey};
+use soroban_sdk::{
+    contractclient, contracttype, symbol_short, Address, BytesN, Env, Symbol, Val, Vec,
+};
+
+/// How long a premium quote can be accepted after it is computed
+pub const QUOTE_VALIDITY: u64 = 24 * 60 * 60;
+
+/// Most the water and quality discounts may take off a premium together
+pub const MAX_TOTAL_DISCOUNT_BPS: u32 = 5_000;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+// Minimal interface of the water-management contract; only the number of alerts is read.
+#[allow(dead_code)]
+#[contractclient(name = "WaterManagementClient")]
+pub trait WaterManagement {
+    fn get_farmer_alerts(env: Env, farmer_id: Address, include_resolved: bool) -> Vec<Val>;
+}
+
+// Minimal interface of the certificate-management contract; only the number of matching
+// certifications is read.
+#[allow(dead_code)]
+#[contractclient(name = "CertificationClient")]
+pub trait Certification {
+    fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Val>;
+}
+
+/// Mirror of the certificate-management contract's certification status
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertStatus {
+    Valid,
+    Expired,
+    Revoked,
+}
+
+/// Discount for farmers with at most `max_unresolved_alerts` open water alerts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaterDiscountTier {
+    pub max_unresolved_alerts: u32,
+    pub discount_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscountSchedule {
+    pub water_tiers: Vec<WaterDiscountTier>,
+    /// Discount for farmers holding at least one valid certification
+    pub quality_discount_bps: u32,
+}
+
+/// Term premium for a coverage product after track-record discounts. Stored until it is
+/// accepted through `create_pol_with_quote` or expires.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuoteBreakdown {
+    pub quote_id: BytesN<32>,
+    pub farmer: Address,
+    pub coverage: Symbol,
+    pub product_version: u32,
+    pub base_premium: i128,
+    pub water_discount_bps: u32,
+    pub quality_discount_bps: u32,
+    pub final_premium: i128,
+    pub expires_at: u64,
+}
+
+/// Sets the contracts track records are read from. Unset sources give no discount.
+pub fn set_risk_sources(
+    env: Env,
+    admin: Address,
+    water_contract: Option<Address>,
+    certification_contract: Option<Address>,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let storage = env.storage().instance();
+    match water_contract {
+        Some(contract) => storage.set(&DataKey::WaterContract, &contract),
+        None => storage.remove(&DataKey::WaterContract),
+    }
+    match certification_contract {
+        Some(contract) => storage.set(&DataKey::CertificationContract, &contract),
+        None => storage.remove(&DataKey::CertificationContract),
+    }
+
+    Ok(())
+}
+
+pub fn set_discount_schedule(
+    env: Env,
+    admin: Address,
+    water_tiers: Vec<WaterDiscountTier>,
+    quality_discount_bps: u32,
+) -> Result<(), ContractError> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let max_water_bps = water_tiers
+        .iter()
+        .map(|tier| tier.discount_bps)
+        .max()
+        .unwrap_or(0);
+    if max_water_bps.saturating_add(quality_discount_bps) > MAX_TOTAL_DISCOUNT_BPS {
+        return Err(ContractError::InvalidDiscount);
+    }
+
+    let schedule = DiscountSchedule {
+        water_tiers,
+        quality_discount_bps,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::DiscountSchedule, &schedule);
+    env.events()
+        .publish((symbol_short!("DISCOUNTS"),), schedule);
+
+    Ok(())
+}
+
+pub fn get_discount_schedule(env: Env) -> Option<DiscountSchedule> {
+    env.storage().instance().get(&DataKey::DiscountSchedule)
+}
+
+/// Quotes the term premium for `coverage` with the farmer's discounts applied. Track records
+/// that cannot be read count as no discount rather than failing the quote.
+pub fn compute_premium_quote(
+    env: Env,
+    farmer: Address,
+    coverage: Symbol,
+) -> Result<QuoteBreakdown, ContractError> {
+    farmer.require_auth();
+
+    let product = get_coverage_product(env.clone(), coverage.clone())?;
+    let base_premium = term_premium(&product)?;
+
+    let (water_discount_bps, quality_discount_bps) = match get_discount_schedule(env.clone()) {
+        Some(schedule) => (
+            water_discount(&env, &farmer, &schedule),
+            quality_discount(&env, &farmer, &schedule),
+        ),
+        None => (0, 0),
+    };
+
+    let discount =
+        base_premium * (water_discount_bps + quality_discount_bps) as i128 / BPS_DENOMINATOR;
+    let quote = QuoteBreakdown {
+        quote_id: generate_quote_id(&env)?,
+        farmer,
+        coverage,
+        product_version: product.version,
+        base_premium,
+        water_discount_bps,
+        quality_discount_bps,
+        final_premium: base_premium - discount,
+        expires_at: env.ledger().timestamp() + QUOTE_VALIDITY,
+    };
+
+    // Quotes are short-lived, so they are kept in temporary storage and expire on their own
+    let key = DataKey::Quote(quote.quote_id.clone());
+    let ttl = (QUOTE_VALIDITY / 5) as u32 + DAY_IN_LEDGERS;
+    env.storage().temporary().set(&key, &quote);
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+
+    env.events().publish(
+        (symbol_short!("QUOTE"), quote.quote_id.clone()),
+        quote.clone(),
+    );
+
+    Ok(quote)
+}
+
+/// Consumes a quote for `farmer`, checking it is unexpired, matches the premium the farmer
+/// agreed to pay and was computed on the coverage product's current terms.
+pub fn take_quote(
+    env: &Env,
+    farmer: &Address,
+    quote_id: &BytesN<32>,
+    quoted_premium: i128,
+) -> Result<QuoteBreakdown, ContractError> {
+    let key = DataKey::Quote(quote_id.clone());
+    let quote: QuoteBreakdown = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .ok_or(ContractError::QuoteNotFound)?;
+
+    if quote.farmer != *farmer {
+        return Err(ContractError::Unauthorized);
+    }
+    if env.ledger().timestamp() > quote.expires_at {
+        return Err(ContractError::QuoteExpired);
+    }
+    let product = get_coverage_product(env.clone(), quote.coverage.clone())?;
+    if quoted_premium != quote.final_premium || product.version != quote.product_version {
+        return Err(ContractError::QuoteMismatch);
+    }
+
+    env.storage().temporary().remove(&key);
+
+    Ok(quote)
+}
+
+// Best discount among the tiers the farmer's unresolved alert count qualifies for
+fn water_discount(env: &Env, farmer: &Address, schedule: &DiscountSchedule) -> u32 {
+    let Some(water_contract) = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::WaterContract)
+    else {
+        return 0;
+    };
+
+    let unresolved = match WaterManagementClient::new(env, &water_contract)
+        .try_get_farmer_alerts(farmer, &false)
+    {
+        Ok(Ok(alerts)) => alerts.len(),
+        _ => return 0,
+    };
+
+    schedule
+        .water_tiers
+        .iter()
+        .filter(|tier| unresolved <= tier.max_unresolved_alerts)
+        .map(|tier| tier.discount_bps)
+        .max()
+        .unwrap_or(0)
+}
+
+fn quality_discount(env: &Env, farmer: &Address, schedule: &DiscountSchedule) -> u32 {
+    let Some(certification_contract) = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::CertificationContract)
+    else {
+        return 0;
+    };
+
+    match CertificationClient::new(env, &certification_contract).try_list_certs(
+        farmer,
+        &Some(CertStatus::Valid),
+        &0,
+        &1,
+    ) {
+        Ok(Ok(certs)) if !certs.is_empty() => schedule.quality_discount_bps,
+        _ => 0,
+    }
+}
@@ -0,0 +1,277 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, BytesN as _},
+    Address, BytesN, Env,
+};
+
+use super::utils::{
+    add_product, approve_claim, capitalize, create_test_accounts, create_test_contract,
+    pay_premium, setup_admin, COVERAGE_START, MAX_PAYOUT,
+};
+use crate::{
+    admin,
+    claims::{self, ClaimDecision, ClaimStatus},
+    insurance::{self, PremiumFrequency},
+    payouts,
+    utils::ContractError,
+};
+
+fn active_policy(env: &Env, contract_id: &Address, farmer: &Address) -> BytesN<32> {
+    add_product(env, contract_id, &symbol_short!("drought"), MAX_PAYOUT, 100);
+    let policy_id = env.as_contract(contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    });
+    pay_premium(env, contract_id, &policy_id);
+
+    policy_id
+}
+
+fn submit(env: &Env, contract_id: &Address, policy_id: &BytesN<32>, amount: i128) -> BytesN<32> {
+    env.as_contract(contract_id, || {
+        claims::sub_claim(env.clone(), policy_id.clone(), BytesN::random(env), amount).unwrap()
+    })
+}
+
+#[test]
+fn test_partial_approval_pays_approved_amount() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+
+    approve_claim(&env, &contract_id, &assessor, &claim_id, 600);
+    capitalize(&env, &contract_id, &admin, &symbol_short!("drought"), 500);
+
+    let claim = env.as_contract(&contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    });
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.payout_amount, 1000);
+    assert_eq!(claim.approved_amount, 600);
+    assert_eq!(claim.assessor, Some(assessor));
+
+    env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    })
+    .unwrap();
+
+    let claim = env.as_contract(&contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    });
+    assert_eq!(claim.status, ClaimStatus::Paid);
+    assert_eq!(claim.approved_amount, 600);
+}
+
+#[test]
+fn test_approval_amount_is_bounded_by_request() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+    let notes = BytesN::random(&env);
+
+    for amount in [0, 1001] {
+        let result = env.as_contract(&contract_id, || {
+            claims::assess_claim(
+                env.clone(),
+                assessor.clone(),
+                claim_id.clone(),
+                amount,
+                ClaimDecision::Approve,
+                notes.clone(),
+            )
+        });
+        assert_eq!(result, Err(ContractError::InvalidAmount));
+    }
+
+    // Rejections cannot carry an amount
+    let result = env.as_contract(&contract_id, || {
+        claims::assess_claim(
+            env.clone(),
+            assessor.clone(),
+            claim_id.clone(),
+            500,
+            ClaimDecision::Reject,
+            notes.clone(),
+        )
+    });
+    assert_eq!(result, Err(ContractError::InvalidAmount));
+}
+
+#[test]
+fn test_rejected_claim_cannot_be_paid() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+    let reason = BytesN::random(&env);
+
+    env.as_contract(&contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            assessor.clone(),
+            claim_id.clone(),
+            reason.clone(),
+        )
+    })
+    .unwrap();
+
+    let claim = env.as_contract(&contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    });
+    assert_eq!(claim.status, ClaimStatus::Rejected);
+    assert_eq!(claim.notes_hash, Some(reason));
+
+    let result = env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    });
+    assert_eq!(result, Err(ContractError::ClaimNotApproved));
+}
+
+#[test]
+fn test_unassessed_claim_cannot_be_paid() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+
+    let result = env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    });
+    assert_eq!(result, Err(ContractError::ClaimNotApproved));
+}
+
+#[test]
+fn test_only_assessors_can_assess() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+    let reason = BytesN::random(&env);
+
+    let result = env.as_contract(&contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            outsider.clone(),
+            claim_id.clone(),
+            reason.clone(),
+        )
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+
+    // A removed assessor loses the right to assess
+    env.as_contract(&contract_id, || {
+        admin::remove_assessor(env.clone(), admin.clone(), assessor.clone())
+    })
+    .unwrap();
+    let result = env.as_contract(&contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            assessor.clone(),
+            claim_id.clone(),
+            reason.clone(),
+        )
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+
+    // Only the admin manages assessors
+    let result = env.as_contract(&contract_id, || {
+        admin::add_assessor(env.clone(), outsider.clone(), outsider.clone())
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_claim_cannot_be_assessed_twice() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = active_policy(&env, &contract_id, &farmer);
+    let claim_id = submit(&env, &contract_id, &policy_id, 1000);
+    let reason = BytesN::random(&env);
+
+    approve_claim(&env, &contract_id, &assessor, &claim_id, 1000);
+
+    let result = env.as_contract(&contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            assessor.clone(),
+            claim_id.clone(),
+            reason.clone(),
+        )
+    });
+    assert_eq!(result, Err(ContractError::ClaimAlreadyAssessed));
+}
+
+#[test]
+fn test_list_claims_by_policy() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_1 = active_policy(&env, &contract_id, &farmer);
+    let policy_2 = active_policy(&env, &contract_id, &farmer);
+
+    let first = submit(&env, &contract_id, &policy_1, 300);
+    let second = submit(&env, &contract_id, &policy_1, 400);
+    submit(&env, &contract_id, &policy_2, 500);
+
+    approve_claim(&env, &contract_id, &assessor, &first, 300);
+
+    let listed = env.as_contract(&contract_id, || {
+        claims::list_claims_by_policy(env.clone(), policy_1.clone())
+    });
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(0).unwrap().claim_id, first);
+    assert_eq!(listed.get(0).unwrap().status, ClaimStatus::Approved);
+    assert_eq!(listed.get(1).unwrap().claim_id, second);
+    assert_eq!(listed.get(1).unwrap().status, ClaimStatus::Submitted);
+
+    let empty = env.as_contract(&contract_id, || {
+        claims::list_claims_by_policy(env.clone(), BytesN::random(&env))
+    });
+    assert!(empty.is_empty());
+}
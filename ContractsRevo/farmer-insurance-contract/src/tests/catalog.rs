@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env,
+};
+
+use super::utils::{
+    create_test_accounts, create_test_contract, pay_premium, setup_admin, COVERAGE_START,
+};
+use crate::{
+    catalog,
+    insurance::{self, get_policy, PremiumFrequency},
+    utils::ContractError,
+};
+
+const YEAR: u64 = 365 * 24 * 60 * 60;
+
+fn publish(
+    env: &Env,
+    contract_id: &Address,
+    admin: &Address,
+    max_payout: i128,
+    premium_rate_bps: u32,
+    term_secs: u64,
+) -> Result<u32, ContractError> {
+    env.as_contract(contract_id, || {
+        catalog::create_coverage_product(
+            env.clone(),
+            admin.clone(),
+            symbol_short!("drought"),
+            max_payout,
+            premium_rate_bps,
+            term_secs,
+            BytesN::from_array(env, &[7; 32]),
+            0,
+            0,
+            0,
+        )
+    })
+}
+
+fn create(
+    env: &Env,
+    contract_id: &Address,
+    farmer: &Address,
+    frequency: PremiumFrequency,
+) -> BytesN<32> {
+    env.as_contract(contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            frequency,
+        )
+        .unwrap()
+    })
+}
+
+#[test]
+fn test_policy_terms_come_from_catalog() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+
+    // 6% of a 20_000 payout over one year
+    let version = publish(&env, &contract_id, &admin, 20_000, 600, YEAR).unwrap();
+    assert_eq!(version, 1);
+
+    let product = env
+        .as_contract(&contract_id, || {
+            catalog::get_coverage_product(env.clone(), symbol_short!("drought"))
+        })
+        .unwrap();
+    assert_eq!(product.max_payout, 20_000);
+    assert_eq!(product.exclusions_hash, BytesN::from_array(&env, &[7; 32]));
+
+    let single = create(&env, &contract_id, &farmer, PremiumFrequency::Single);
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), single.clone()));
+    assert_eq!(policy.premium, 1_200);
+    assert_eq!(policy.max_payout, 20_000);
+    assert_eq!(policy.coverage_end, COVERAGE_START + YEAR);
+    assert_eq!(policy.product_version, 1);
+
+    // Quarterly installments split the term premium over five payments, rounding up
+    let quarterly = create(&env, &contract_id, &farmer, PremiumFrequency::Quarterly);
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), quarterly.clone()));
+    assert_eq!(policy.premium, 240);
+}
+
+#[test]
+fn test_catalog_validation_and_authorization() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+
+    assert_eq!(
+        publish(&env, &contract_id, &admin, 0, 600, YEAR),
+        Err(ContractError::InvalidProduct)
+    );
+    assert_eq!(
+        publish(&env, &contract_id, &admin, 20_000, 0, YEAR),
+        Err(ContractError::InvalidProduct)
+    );
+    assert_eq!(
+        publish(&env, &contract_id, &admin, 20_000, 10_001, YEAR),
+        Err(ContractError::InvalidProduct)
+    );
+    assert_eq!(
+        publish(&env, &contract_id, &admin, 20_000, 600, 0),
+        Err(ContractError::InvalidProduct)
+    );
+    assert_eq!(
+        publish(&env, &contract_id, &farmer, 20_000, 600, YEAR),
+        Err(ContractError::Unauthorized)
+    );
+
+    let result = env.as_contract(&contract_id, || {
+        catalog::get_coverage_product(env.clone(), symbol_short!("drought"))
+    });
+    assert_eq!(result, Err(ContractError::ProductNotFound));
+}
+
+#[test]
+fn test_list_policies_by_farmer() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+    let other_farmer = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    publish(&env, &contract_id, &admin, 20_000, 600, YEAR).unwrap();
+
+    let first = create(&env, &contract_id, &farmer, PremiumFrequency::Single);
+    create(&env, &contract_id, &other_farmer, PremiumFrequency::Single);
+    let second = create(&env, &contract_id, &farmer, PremiumFrequency::Annual);
+
+    let policies = env.as_contract(&contract_id, || {
+        insurance::list_policies_by_farmer(env.clone(), farmer.clone())
+    });
+    assert_eq!(policies.len(), 2);
+    assert_eq!(policies.get(0).unwrap().policy_id, first);
+    assert_eq!(policies.get(1).unwrap().policy_id, second);
+
+    let none = env.as_contract(&contract_id, || {
+        insurance::list_policies_by_farmer(env.clone(), Address::generate(&env))
+    });
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_product_update_keeps_existing_policy_terms() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    publish(&env, &contract_id, &admin, 20_000, 600, YEAR).unwrap();
+
+    let old_policy = create(&env, &contract_id, &farmer, PremiumFrequency::Single);
+    pay_premium(&env, &contract_id, &old_policy);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let version = publish(&env, &contract_id, &admin, 30_000, 1_000, 2 * YEAR).unwrap();
+    assert_eq!(version, 2);
+
+    let old = env.as_contract(&contract_id, || get_policy(env.clone(), old_policy.clone()));
+    assert_eq!(old.product_version, 1);
+    assert_eq!(old.premium, 1_200);
+    assert_eq!(old.max_payout, 20_000);
+    assert_eq!(old.coverage_end, COVERAGE_START + YEAR);
+
+    // The original version stays readable
+    let original = env
+        .as_contract(&contract_id, || {
+            catalog::get_product_version(env.clone(), symbol_short!("drought"), 1)
+        })
+        .unwrap();
+    assert_eq!(original.premium_rate_bps, 600);
+
+    let new_policy = env
+        .as_contract(&contract_id, || {
+            insurance::create_pol(
+                env.clone(),
+                farmer.clone(),
+                symbol_short!("drought"),
+                symbol_short!("north"),
+                1_000,
+                PremiumFrequency::Single,
+            )
+        })
+        .unwrap();
+    let new = env.as_contract(&contract_id, || get_policy(env.clone(), new_policy.clone()));
+    assert_eq!(new.product_version, 2);
+    assert_eq!(new.premium, 3_000);
+    assert_eq!(new.max_payout, 30_000);
+    assert_eq!(new.coverage_end, 1_000 + 2 * YEAR);
+}
@@ -0,0 +1,388 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env,
+};
+
+use super::utils::{
+    approve_claim, capitalize, create_test_accounts, create_test_contract, pay_premium,
+    setup_admin, COVERAGE_END, COVERAGE_START,
+};
+use crate::{
+    catalog,
+    claims::{self, ClaimStatus},
+    insurance::{self, PremiumFrequency},
+    pool,
+    utils::ContractError,
+};
+
+const DAY: u64 = 24 * 60 * 60;
+/// Claims must be filed within two weeks of the insured event
+const CLAIM_WINDOW: u64 = 14 * DAY;
+
+struct Setup {
+    contract_id: Address,
+    admin: Address,
+    assessor: Address,
+    policy_id: BytesN<32>,
+}
+
+/// A paid-up policy on a 5_000 limit product with a `CLAIM_WINDOW` claim window.
+fn setup(env: &Env) -> Setup {
+    let (farmer, admin) = create_test_accounts(env);
+    let contract_id = create_test_contract(env);
+    let assessor = setup_admin(env, &contract_id, &admin);
+
+    env.as_contract(&contract_id, || {
+        catalog::create_coverage_product(
+            env.clone(),
+            admin.clone(),
+            symbol_short!("drought"),
+            5_000,
+            200,
+            COVERAGE_END - COVERAGE_START,
+            BytesN::from_array(env, &[0; 32]),
+            0,
+            0,
+            CLAIM_WINDOW,
+        )
+    })
+    .unwrap();
+
+    let policy_id = env.as_contract(&contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    });
+    pay_premium(env, &contract_id, &policy_id);
+
+    Setup {
+        contract_id,
+        admin,
+        assessor,
+        policy_id,
+    }
+}
+
+/// Registers an insured event that happened at `occurred_at`.
+fn register_event(env: &Env, setup: &Setup, seed: u8, occurred_at: u64) -> BytesN<32> {
+    let event_hash = BytesN::from_array(env, &[seed; 32]);
+    env.as_contract(&setup.contract_id, || {
+        claims::register_insured_event(
+            env.clone(),
+            setup.admin.clone(),
+            event_hash.clone(),
+            occurred_at,
+        )
+    })
+    .unwrap();
+    event_hash
+}
+
+fn submit(
+    env: &Env,
+    setup: &Setup,
+    event_hash: &BytesN<32>,
+    amount: i128,
+) -> Result<BytesN<32>, ContractError> {
+    env.as_contract(&setup.contract_id, || {
+        claims::sub_claim(
+            env.clone(),
+            setup.policy_id.clone(),
+            event_hash.clone(),
+            amount,
+        )
+    })
+}
+
+fn claim_status(env: &Env, setup: &Setup, claim_id: &BytesN<32>) -> ClaimStatus {
+    env.as_contract(&setup.contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    })
+    .status
+}
+
+fn pool_status(env: &Env, setup: &Setup) -> pool::PoolStatus {
+    env.as_contract(&setup.contract_id, || {
+        pool::get_pool_status(env.clone(), symbol_short!("drought"))
+    })
+}
+
+fn expire(env: &Env, setup: &Setup, max: u32) -> u32 {
+    env.as_contract(&setup.contract_id, || {
+        claims::expire_stale_claims(env.clone(), max)
+    })
+}
+
+#[test]
+fn test_claims_must_be_filed_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+
+    let storm = register_event(&env, &setup, 1, 10 * DAY);
+    let hail = register_event(&env, &setup, 2, 20 * DAY);
+    assert_eq!(
+        env.as_contract(&setup.contract_id, || {
+            claims::get_insured_event(env.clone(), storm.clone())
+        }),
+        Some(10 * DAY)
+    );
+
+    // The last second of the window is still on time
+    env.ledger().set_timestamp(10 * DAY + CLAIM_WINDOW);
+    let claim_id = submit(&env, &setup, &storm, 1_000).unwrap();
+    assert_eq!(
+        claim_status(&env, &setup, &claim_id),
+        ClaimStatus::Submitted
+    );
+
+    env.ledger().set_timestamp(10 * DAY + CLAIM_WINDOW + 1);
+    assert_eq!(
+        submit(&env, &setup, &storm, 1_000),
+        Err(ContractError::ClaimWindowExpired)
+    );
+
+    // The window runs from each event's own timestamp
+    submit(&env, &setup, &hail, 1_000).unwrap();
+
+    // With a claim window configured, claims need a registered event
+    let unknown = BytesN::from_array(&env, &[9; 32]);
+    assert_eq!(
+        submit(&env, &setup, &unknown, 1_000),
+        Err(ContractError::EventNotRegistered)
+    );
+}
+
+#[test]
+fn test_only_admin_registers_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = env.as_contract(&setup.contract_id, || {
+        claims::register_insured_event(
+            env.clone(),
+            outsider.clone(),
+            BytesN::from_array(&env, &[1; 32]),
+            0,
+        )
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+
+    let result = env.as_contract(&setup.contract_id, || {
+        claims::set_claim_expiry_age(env.clone(), outsider.clone(), DAY)
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_stale_claims_expire_and_release_reserved_exposure() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+    env.as_contract(&setup.contract_id, || {
+        claims::set_claim_expiry_age(env.clone(), setup.admin.clone(), 30 * DAY)
+    })
+    .unwrap();
+
+    let event = register_event(&env, &setup, 1, DAY);
+    env.ledger().set_timestamp(DAY);
+    let first = submit(&env, &setup, &event, 1_000).unwrap();
+    env.ledger().set_timestamp(2 * DAY);
+    let second = submit(&env, &setup, &event, 3_000).unwrap();
+    // Together the policy's claims hold no more than its remaining limit
+    let third = submit(&env, &setup, &event, 9_000).unwrap();
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 5_000);
+
+    // Pending claims stay covered even when the reserve ratio no longer requires it
+    capitalize(
+        &env,
+        &setup.contract_id,
+        &setup.admin,
+        &symbol_short!("drought"),
+        10_000,
+    );
+    env.as_contract(&setup.contract_id, || {
+        pool::set_reserve_ratio(env.clone(), setup.admin.clone(), 0)
+    })
+    .unwrap();
+    let balance = pool_status(&env, &setup).balance;
+    let result = env.as_contract(&setup.contract_id, || {
+        pool::withdraw_surplus(
+            env.clone(),
+            setup.admin.clone(),
+            symbol_short!("drought"),
+            balance - 4_999,
+        )
+    });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
+
+    // Nothing is old enough yet
+    env.ledger().set_timestamp(30 * DAY);
+    assert_eq!(expire(&env, &setup, 10), 0);
+
+    // Only the first claim has aged past 30 days
+    env.ledger().set_timestamp(31 * DAY);
+    assert_eq!(expire(&env, &setup, 10), 1);
+    assert_eq!(claim_status(&env, &setup, &first), ClaimStatus::Expired);
+    assert_eq!(claim_status(&env, &setup, &second), ClaimStatus::Submitted);
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 4_000);
+
+    // `max` bounds the work done per call
+    env.ledger().set_timestamp(60 * DAY);
+    assert_eq!(expire(&env, &setup, 1), 1);
+    assert_eq!(claim_status(&env, &setup, &third), ClaimStatus::Submitted);
+    assert_eq!(expire(&env, &setup, 1), 1);
+    assert_eq!(claim_status(&env, &setup, &third), ClaimStatus::Expired);
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 0);
+    assert_eq!(expire(&env, &setup, 10), 0);
+
+    env.as_contract(&setup.contract_id, || {
+        pool::withdraw_surplus(
+            env.clone(),
+            setup.admin.clone(),
+            symbol_short!("drought"),
+            balance,
+        )
+    })
+    .unwrap();
+
+    // Expired claims can no longer be assessed
+    let result = env.as_contract(&setup.contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            setup.assessor.clone(),
+            second.clone(),
+            BytesN::from_array(&env, &[0; 32]),
+        )
+    });
+    assert_eq!(result, Err(ContractError::ClaimAlreadyAssessed));
+}
+
+#[test]
+fn test_expiry_leaves_assessed_claims_alone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+
+    let event = register_event(&env, &setup, 1, 0);
+    let approved = submit(&env, &setup, &event, 1_000).unwrap();
+    let rejected = submit(&env, &setup, &event, 500).unwrap();
+    let pending = submit(&env, &setup, &event, 700).unwrap();
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 2_200);
+
+    // Assessment releases the reservation straight away
+    approve_claim(&env, &setup.contract_id, &setup.assessor, &approved, 800);
+    env.as_contract(&setup.contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            setup.assessor.clone(),
+            rejected.clone(),
+            BytesN::from_array(&env, &[0; 32]),
+        )
+    })
+    .unwrap();
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 700);
+
+    // Past the default expiry age only the unassessed claim expires
+    env.ledger().set_timestamp(claims::DEFAULT_CLAIM_EXPIRY_AGE);
+    assert_eq!(expire(&env, &setup, 10), 1);
+    assert_eq!(claim_status(&env, &setup, &approved), ClaimStatus::Approved);
+    assert_eq!(claim_status(&env, &setup, &rejected), ClaimStatus::Rejected);
+    assert_eq!(claim_status(&env, &setup, &pending), ClaimStatus::Expired);
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 0);
+}
+
+#[test]
+fn test_claims_on_one_policy_share_its_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+
+    let event = register_event(&env, &setup, 1, 0);
+    let first = submit(&env, &setup, &event, 5_000).unwrap();
+    let second = submit(&env, &setup, &event, 5_000).unwrap();
+    let reserved = |claim_id: &BytesN<32>| {
+        env.as_contract(&setup.contract_id, || {
+            claims::get_claim(env.clone(), claim_id.clone())
+        })
+        .reserved_amount
+    };
+
+    // The first claim already holds the whole limit
+    assert_eq!(reserved(&first), 5_000);
+    assert_eq!(reserved(&second), 0);
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 5_000);
+
+    // Once it is assessed, later claims can hold the limit again
+    env.as_contract(&setup.contract_id, || {
+        claims::reject_claim(
+            env.clone(),
+            setup.assessor.clone(),
+            first.clone(),
+            BytesN::from_array(&env, &[0; 32]),
+        )
+    })
+    .unwrap();
+    assert_eq!(pool_status(&env, &setup).reserved_claims, 0);
+    let third = submit(&env, &setup, &event, 2_000).unwrap();
+    assert_eq!(reserved(&third), 2_000);
+
+    // A policy can only file so many claims
+    for _ in 3..claims::MAX_CLAIMS_PER_POLICY {
+        submit(&env, &setup, &event, 0).unwrap();
+    }
+    assert_eq!(
+        submit(&env, &setup, &event, 1_000),
+        Err(ContractError::TooManyClaims)
+    );
+}
+
+#[test]
+fn test_claims_for_events_outside_coverage_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let setup = setup(&env);
+
+    // A second policy of the same product covering from day 30
+    let farmer = Address::generate(&env);
+    env.ledger().set_timestamp(30 * DAY);
+    let later = env.as_contract(&setup.contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            30 * DAY,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    });
+    pay_premium(&env, &setup.contract_id, &later);
+
+    let before = register_event(&env, &setup, 1, 25 * DAY);
+    let during = register_event(&env, &setup, 2, 30 * DAY);
+    let submit_later = |event_hash: &BytesN<32>| {
+        env.as_contract(&setup.contract_id, || {
+            claims::sub_claim(env.clone(), later.clone(), event_hash.clone(), 1_000)
+        })
+    };
+
+    // The event predates the policy, though it is still within the claim window
+    assert_eq!(
+        submit_later(&before),
+        Err(ContractError::EventOutsideCoverage)
+    );
+    assert!(submit(&env, &setup, &before, 1_000).is_ok());
+    assert!(submit_later(&during).is_ok());
+}
@@ -6,21 +6,43 @@ use soroban_sdk::{
     Address, BytesN, Env,
 };
 
-use super::utils::{create_test_accounts, create_test_contract};
-use crate::{claims, insurance};
+use super::utils::{
+    add_product, create_test_accounts, create_test_contract, pay_premium, setup_admin,
+    COVERAGE_START, MAX_PAYOUT,
+};
+use crate::{
+    claims,
+    insurance::{self, PremiumFrequency},
+};
 
 #[test]
 #[should_panic(expected = "Policy is not active")]
 fn test_sub_claim_fails_if_policy_not_active() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     let event_hash = BytesN::random(&env);
@@ -33,20 +55,34 @@ fn test_sub_claim_fails_if_policy_not_active() {
 #[test]
 fn test_valid_claim_submission_with_correct_event_data() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Create event hash with proper data
     let event_hash = BytesN::random(&env);
@@ -82,20 +118,34 @@ fn test_valid_claim_submission_with_correct_event_data() {
 #[test]
 fn test_multiple_claims_for_same_policy() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit multiple claims for the same policy
     let event_hash_1 = BytesN::random(&env);
@@ -153,20 +203,34 @@ fn test_multiple_claims_for_same_policy() {
 #[test]
 fn test_claim_with_zero_payout_amount() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     let event_hash = BytesN::random(&env);
 
@@ -191,20 +255,34 @@ fn test_claim_with_zero_payout_amount() {
 #[test]
 fn test_claim_with_negative_payout_amount() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     let event_hash = BytesN::random(&env);
 
@@ -248,20 +326,34 @@ fn test_claim_submission_with_invalid_policy_id() {
 #[test]
 fn test_event_hash_verification_different_events() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Test different event hashes for different types of events
     let drought_event = BytesN::random(&env);
@@ -319,20 +411,34 @@ fn test_event_hash_verification_different_events() {
 #[test]
 fn test_claim_submission_high_volume() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("disaster"),
+        MAX_PAYOUT,
+        1000,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("disaster"), 1000).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("disaster"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit 15 claims
     let mut claim_ids = soroban_sdk::vec!(&env);
@@ -374,20 +480,34 @@ fn test_claim_submission_high_volume() {
 #[test]
 fn test_claim_with_maximum_payout_amount() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("maxclaim"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("maxclaim"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("maxclaim"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     let event_hash = BytesN::random(&env);
     let max_payout = i128::MAX;
@@ -424,30 +544,60 @@ fn test_claim_integration_across_multiple_policies() {
     let farmer2 = Address::generate(&env);
     let farmer3 = Address::generate(&env);
 
+    let admin = Address::generate(&env);
+
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Create and activate multiple policies for different farmers
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy1 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer1.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer1.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 200);
     let policy2 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer2.clone(), symbol_short!("flood"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer2.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
+    add_product(&env, &contract_id, &symbol_short!("fire"), MAX_PAYOUT, 300);
     let policy3 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer3.clone(), symbol_short!("fire"), 300).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer3.clone(),
+            symbol_short!("fire"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy1.clone())
-    });
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy2.clone())
-    });
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy3.clone())
-    });
+    pay_premium(&env, &contract_id, &policy1);
+    pay_premium(&env, &contract_id, &policy2);
+    pay_premium(&env, &contract_id, &policy3);
 
     // Submit claims for each policy
     let event1 = BytesN::random(&env);
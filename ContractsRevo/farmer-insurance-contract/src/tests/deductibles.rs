@@ -0,0 +1,220 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, BytesN as _, Ledger},
+    Address, BytesN, Env,
+};
+
+use super::utils::{
+    approve_claim, capitalize, create_test_accounts, create_test_contract, pay_premium,
+    premium_token, setup_admin, COVERAGE_END, COVERAGE_START,
+};
+use crate::{
+    admin, catalog,
+    claims::{self, ClaimStatus},
+    insurance::{self, PremiumFrequency},
+    parametric, payouts, pool,
+};
+
+struct Setup {
+    contract_id: Address,
+    admin: Address,
+    assessor: Address,
+    policy_id: BytesN<32>,
+}
+
+/// A policy on a 5_000 limit product with the given deductible and coinsurance, backed by a
+/// well-funded pool.
+fn setup(
+    env: &Env,
+    farmer: &Address,
+    admin: &Address,
+    deductible: i128,
+    coinsurance_bps: u32,
+) -> Setup {
+    let contract_id = create_test_contract(env);
+    let assessor = setup_admin(env, &contract_id, admin);
+
+    env.as_contract(&contract_id, || {
+        catalog::create_coverage_product(
+            env.clone(),
+            admin.clone(),
+            symbol_short!("drought"),
+            5_000,
+            200,
+            COVERAGE_END - COVERAGE_START,
+            BytesN::from_array(env, &[0; 32]),
+            deductible,
+            coinsurance_bps,
+            0,
+        )
+    })
+    .unwrap();
+    capitalize(env, &contract_id, admin, &symbol_short!("drought"), 20_000);
+
+    let policy_id = env.as_contract(&contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            env.ledger().timestamp(),
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    });
+    pay_premium(env, &contract_id, &policy_id);
+
+    Setup {
+        contract_id,
+        admin: admin.clone(),
+        assessor,
+        policy_id,
+    }
+}
+
+/// Submits, approves and pays a claim for `loss`, returning the amount paid.
+fn settle(env: &Env, setup: &Setup, loss: i128) -> i128 {
+    let claim_id = env.as_contract(&setup.contract_id, || {
+        claims::sub_claim(
+            env.clone(),
+            setup.policy_id.clone(),
+            BytesN::random(env),
+            loss,
+        )
+        .unwrap()
+    });
+    approve_claim(env, &setup.contract_id, &setup.assessor, &claim_id, loss);
+    env.as_contract(&setup.contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), setup.admin.clone())
+    })
+    .unwrap();
+
+    let claim = env.as_contract(&setup.contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    });
+    assert_eq!(claim.status, ClaimStatus::Paid);
+    claim.paid_amount
+}
+
+fn remaining_limit(env: &Env, setup: &Setup) -> i128 {
+    env.as_contract(&setup.contract_id, || {
+        insurance::get_remaining_limit(env.clone(), setup.policy_id.clone())
+    })
+}
+
+#[test]
+fn test_deductible_below_and_above_loss() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let setup = setup(&env, &farmer, &admin, 500, 0);
+    let token = premium_token(&env, &setup.contract_id);
+
+    // A loss within the deductible pays nothing and leaves the limit untouched
+    assert_eq!(settle(&env, &setup, 400), 0);
+    assert_eq!(token.balance(&farmer), 0);
+    assert_eq!(remaining_limit(&env, &setup), 5_000);
+
+    assert_eq!(settle(&env, &setup, 1_200), 700);
+    assert_eq!(token.balance(&farmer), 700);
+    assert_eq!(remaining_limit(&env, &setup), 4_300);
+}
+
+#[test]
+fn test_coinsurance_applies_after_deductible() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    // 20% coinsurance on the loss above a 200 deductible
+    let setup = setup(&env, &farmer, &admin, 200, 2_000);
+
+    assert_eq!(settle(&env, &setup, 1_200), 800);
+    assert_eq!(settle(&env, &setup, 1_000), 640);
+    assert_eq!(remaining_limit(&env, &setup), 5_000 - 800 - 640);
+}
+
+#[test]
+fn test_limit_exhausted_across_claims() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let setup = setup(&env, &farmer, &admin, 0, 0);
+    let token = premium_token(&env, &setup.contract_id);
+
+    assert_eq!(settle(&env, &setup, 3_500), 3_500);
+    assert_eq!(remaining_limit(&env, &setup), 1_500);
+
+    // The second claim is capped at what is left of the limit
+    assert_eq!(settle(&env, &setup, 3_500), 1_500);
+    assert_eq!(remaining_limit(&env, &setup), 0);
+    assert_eq!(token.balance(&farmer), 5_000);
+
+    assert_eq!(settle(&env, &setup, 100), 0);
+
+    // A spent limit no longer counts towards the pool's exposure
+    let status = env.as_contract(&setup.contract_id, || {
+        pool::get_pool_status(env.clone(), symbol_short!("drought"))
+    });
+    assert_eq!(status.active_exposure, 0);
+}
+
+#[test]
+fn test_parametric_payout_uses_policy_terms() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+    let oracle = Address::generate(&env);
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    // The trigger owes 60% of the 5_000 maximum payout as the loss
+    let setup = setup(&env, &farmer, &admin, 1_000, 5_000);
+
+    env.as_contract(&setup.contract_id, || {
+        admin::set_oracle(env.clone(), admin.clone(), oracle.clone())
+    })
+    .unwrap();
+    env.as_contract(&setup.contract_id, || {
+        parametric::register_trigger(
+            env.clone(),
+            admin.clone(),
+            symbol_short!("drought"),
+            symbol_short!("rainfall"),
+            40,
+            6_000,
+        )
+    })
+    .unwrap();
+    env.as_contract(&setup.contract_id, || {
+        parametric::report_index_value(
+            env.clone(),
+            oracle.clone(),
+            symbol_short!("north"),
+            symbol_short!("rainfall"),
+            1,
+            12,
+        )
+    })
+    .unwrap();
+
+    let paid = env
+        .as_contract(&setup.contract_id, || {
+            parametric::claim_parametric_payout(
+                env.clone(),
+                farmer.clone(),
+                setup.policy_id.clone(),
+                1,
+            )
+        })
+        .unwrap();
+    assert_eq!(paid, (3_000 - 1_000) / 2);
+    assert_eq!(remaining_limit(&env, &setup), 5_000 - 1_000);
+}
@@ -3,27 +3,60 @@
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, BytesN as _},
-    Address, BytesN, Env,
+    token, Address, BytesN, Env,
 };
 
-use super::utils::{create_test_accounts, create_test_contract};
-use crate::insurance::{self, get_policy};
+use super::utils::{
+    add_product, create_test_accounts, create_test_contract, pay_premium, premium_token,
+    setup_admin, COVERAGE_START, MAX_PAYOUT,
+};
+use crate::{
+    admin,
+    insurance::{self, get_policy, PremiumFrequency},
+    pool,
+    utils::ContractError,
+};
 
 #[test]
 fn test_create_pol_generates_unique_policy_ids() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id_1 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("pest"), MAX_PAYOUT, 150);
     let policy_id_2 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("pest"), 150).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("pest"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     assert_ne!(policy_id_1, policy_id_2);
@@ -32,14 +65,30 @@ fn test_create_pol_generates_unique_policy_ids() {
 #[test]
 fn test_new_policy_is_inactive_by_default() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
@@ -51,75 +100,140 @@ fn test_new_policy_is_inactive_by_default() {
 #[should_panic(expected = "Premium already paid")]
 fn test_pay_prem_fails_when_called_twice() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 }
 
 #[test]
 #[should_panic(expected = "Premium must be positive")]
 fn test_create_pol_fails_with_zero_premium() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
+    // A 1 bps premium on a payout of 1 rounds down to nothing
+    add_product(&env, &contract_id, &symbol_short!("drought"), 1, 1);
     env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 0).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 }
 
 #[test]
-#[should_panic(expected = "Premium must be positive")]
-fn test_create_pol_fails_with_negative_premium() {
+fn test_create_pol_fails_without_coverage_product() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
-    env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), -100).unwrap()
+    let result = env.as_contract(&contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
     });
+    assert_eq!(result, Err(ContractError::ProductNotFound));
 }
 
 #[test]
 fn test_farmer_eligibility_multiple_policies() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Farmer should be able to create multiple policies with different coverage types
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id_1 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 200);
     let policy_id_2 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("flood"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("pest"), MAX_PAYOUT, 150);
     let policy_id_3 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("pest"), 150).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("pest"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     // All policies should be created successfully
@@ -144,27 +258,70 @@ fn test_farmer_eligibility_multiple_policies() {
 #[test]
 fn test_multiple_coverage_types_validation() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Test various coverage types
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let drought_policy = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 150);
     let flood_policy = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("flood"), 150).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("pest"), MAX_PAYOUT, 120);
     let pest_policy = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("pest"), 120).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("pest"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("fire"), MAX_PAYOUT, 200);
     let fire_policy = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("fire"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("fire"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     // Verify each policy has correct coverage type
@@ -182,14 +339,30 @@ fn test_multiple_coverage_types_validation() {
 #[test]
 fn test_policy_premium_payment_lifecycle() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        250,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 250).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     // Policy should start inactive
@@ -199,9 +372,7 @@ fn test_policy_premium_payment_lifecycle() {
     assert_eq!(initial_policy.premium, 250);
 
     // After paying premium, policy should become active
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     let active_policy =
         env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
@@ -212,22 +383,32 @@ fn test_policy_premium_payment_lifecycle() {
 #[test]
 fn test_high_volume_policy_creation() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     let mut policy_ids = soroban_sdk::vec!(&env);
 
     // Create 10 policies with different premiums
     for i in 1..=10 {
+        add_product(
+            &env,
+            &contract_id,
+            &symbol_short!("drought"),
+            MAX_PAYOUT,
+            100 * i as u32,
+        );
         let policy_id = env.as_contract(&contract_id, || {
             insurance::create_pol(
                 env.clone(),
                 farmer.clone(),
                 symbol_short!("drought"),
-                100 * i as i128,
+                symbol_short!("north"),
+                COVERAGE_START,
+                PremiumFrequency::Single,
             )
             .unwrap()
         });
@@ -251,20 +432,56 @@ fn test_policy_creation_with_different_farmers() {
     let farmer2 = Address::generate(&env);
     let farmer3 = Address::generate(&env);
 
+    let admin = Address::generate(&env);
+
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy1 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer1.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer1.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 200);
     let policy2 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer2.clone(), symbol_short!("flood"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer2.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
+    add_product(&env, &contract_id, &symbol_short!("pest"), MAX_PAYOUT, 150);
     let policy3 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer3.clone(), symbol_short!("pest"), 150).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer3.clone(),
+            symbol_short!("pest"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     // Verify each farmer owns their respective policy
@@ -285,18 +502,17 @@ fn test_policy_creation_with_different_farmers() {
 #[should_panic(expected = "Policy not found")]
 fn test_pay_premium_for_nonexistent_policy() {
     let env = Env::default();
-    let (_farmer, _admin) = create_test_accounts(&env);
+    let (_farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Try to pay premium for non-existent policy
     let fake_policy_id = BytesN::random(&env);
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), fake_policy_id)
-    });
+    pay_premium(&env, &contract_id, &fake_policy_id);
 }
 
 #[test]
@@ -318,29 +534,129 @@ fn test_get_policy_for_nonexistent_policy() {
 #[test]
 fn test_policy_creation_edge_case_maximum_values() {
     let env = Env::default();
-    let (farmer, _admin) = create_test_accounts(&env);
+    let (farmer, admin) = create_test_accounts(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
-    // Test with maximum i128 value
-    let max_premium = i128::MAX;
-
+    setup_admin(&env, &contract_id, &admin);
+
+    // Largest payout whose full-rate premium can still be computed
+    let max_payout = i128::MAX / 10_000;
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("extreme"),
+        max_payout,
+        10_000,
+    );
     let policy_id = env.as_contract(&contract_id, || {
         insurance::create_pol(
             env.clone(),
             farmer.clone(),
             symbol_short!("extreme"),
-            max_premium,
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
         )
         .unwrap()
     });
 
     let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id));
 
-    assert_eq!(policy.premium, max_premium);
+    assert_eq!(policy.premium, max_payout);
+    assert_eq!(policy.max_payout, max_payout);
     assert_eq!(policy.farmer, farmer);
     assert_eq!(policy.coverage, symbol_short!("extreme"));
     assert!(!policy.active);
 }
+
+#[test]
+fn test_rental_policy_written_for_registered_platform_and_refunded_on_cancel() {
+    let env = Env::default();
+    let (renter, admin) = create_test_accounts(&env);
+    let platform = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("equip"),
+        MAX_PAYOUT,
+        1_000,
+    );
+    let token = premium_token(&env, &contract_id);
+    let reference = BytesN::random(&env);
+    let rental_end = COVERAGE_START + 30 * 24 * 60 * 60;
+    let create = |premium: i128| {
+        env.as_contract(&contract_id, || {
+            insurance::create_rental_policy(
+                env.clone(),
+                platform.clone(),
+                renter.clone(),
+                symbol_short!("equip"),
+                COVERAGE_START,
+                rental_end,
+                premium,
+                reference.clone(),
+            )
+        })
+    };
+
+    // Unregistered platforms cannot write policies
+    assert_eq!(create(100), Err(ContractError::Unauthorized));
+
+    env.as_contract(&contract_id, || {
+        admin::add_rental_platform(env.clone(), admin.clone(), platform.clone())
+    })
+    .unwrap();
+
+    // The premium must cover the pro-rated term premium (1_000 * 30 / 365)
+    assert_eq!(create(50), Err(ContractError::InvalidAmount));
+
+    // The platform has already paid the premium in
+    token::StellarAssetClient::new(&env, &token.address).mint(&contract_id, &100);
+    let policy_id = create(100).unwrap();
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert!(policy.active);
+    assert_eq!(policy.farmer, renter);
+    assert_eq!(policy.coverage_end, rental_end);
+    assert_eq!(policy.premiums_paid, 100);
+    assert_eq!(
+        env.as_contract(&contract_id, || {
+            pool::get_pool_status(env.clone(), symbol_short!("equip"))
+        })
+        .balance,
+        100
+    );
+
+    // Only the writing platform may cancel, and the insured gets the premium back
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        env.as_contract(&contract_id, || {
+            insurance::cancel_rental_policy(env.clone(), outsider.clone(), policy_id.clone())
+        }),
+        Err(ContractError::Unauthorized)
+    );
+    let refund = env
+        .as_contract(&contract_id, || {
+            insurance::cancel_rental_policy(env.clone(), platform.clone(), policy_id.clone())
+        })
+        .unwrap();
+    assert_eq!(refund, 100);
+    assert_eq!(token.balance(&renter), 100);
+
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert!(!policy.active);
+    assert_eq!(policy.remaining_limit, 0);
+    assert_eq!(
+        env.as_contract(&contract_id, || {
+            insurance::cancel_rental_policy(env.clone(), platform.clone(), policy_id.clone())
+        }),
+        Err(ContractError::PolicyNotCancellable)
+    );
+}
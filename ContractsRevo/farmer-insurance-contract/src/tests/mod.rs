@@ -1,4 +1,12 @@
+pub mod assessment;
+pub mod catalog;
+pub mod claim_expiry;
 pub mod claims;
+pub mod deductibles;
 pub mod insurance;
+pub mod parametric;
 pub mod payouts;
+pub mod pool;
+pub mod premiums;
+pub mod quotes;
 pub mod utils;
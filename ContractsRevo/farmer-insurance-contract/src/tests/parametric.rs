@@ -0,0 +1,283 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Symbol,
+};
+
+use super::utils::{
+    add_product, capitalize, create_test_accounts, create_test_contract, pay_premium,
+    premium_token, setup_admin, MAX_PAYOUT,
+};
+use crate::{
+    admin,
+    insurance::{self, PremiumFrequency},
+    parametric,
+    utils::ContractError,
+};
+
+const PREMIUM: i128 = 200;
+const POOL: i128 = 10_000;
+const PERIOD: u32 = 202507;
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+struct Setup {
+    contract_id: Address,
+    admin: Address,
+    oracle: Address,
+    token: token::Client<'static>,
+}
+
+/// Configures an oracle, a drought product, a funded pool and a drought trigger paying 10% of the
+/// maximum payout when rainfall is reported at or below 40.
+fn setup(env: &Env) -> Setup {
+    let admin = Address::generate(env);
+    let oracle = Address::generate(env);
+    let contract_id = create_test_contract(env);
+    setup_admin(env, &contract_id, &admin);
+    let token = premium_token(env, &contract_id);
+
+    env.as_contract(&contract_id, || {
+        admin::set_oracle(env.clone(), admin.clone(), oracle.clone())
+    })
+    .unwrap();
+    env.as_contract(&contract_id, || {
+        parametric::register_trigger(
+            env.clone(),
+            admin.clone(),
+            symbol_short!("drought"),
+            symbol_short!("rainfall"),
+            40,
+            1_000,
+        )
+    })
+    .unwrap();
+    add_product(
+        env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        PREMIUM as u32,
+    );
+    capitalize(env, &contract_id, &admin, &symbol_short!("drought"), POOL);
+
+    Setup {
+        contract_id,
+        admin,
+        oracle,
+        token,
+    }
+}
+
+fn active_policy(env: &Env, setup: &Setup, farmer: &Address, region: Symbol) -> BytesN<32> {
+    let policy_id = env.as_contract(&setup.contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            region,
+            env.ledger().timestamp(),
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    });
+    pay_premium(env, &setup.contract_id, &policy_id);
+
+    policy_id
+}
+
+fn report(env: &Env, setup: &Setup, oracle: &Address, value: i128) -> Result<(), ContractError> {
+    env.as_contract(&setup.contract_id, || {
+        parametric::report_index_value(
+            env.clone(),
+            oracle.clone(),
+            symbol_short!("north"),
+            symbol_short!("rainfall"),
+            PERIOD,
+            value,
+        )
+    })
+}
+
+fn claim(
+    env: &Env,
+    setup: &Setup,
+    farmer: &Address,
+    policy_id: &BytesN<32>,
+) -> Result<i128, ContractError> {
+    env.as_contract(&setup.contract_id, || {
+        parametric::claim_parametric_payout(env.clone(), farmer.clone(), policy_id.clone(), PERIOD)
+    })
+}
+
+#[test]
+fn test_trigger_crossing_entitles_policies_in_region() {
+    let env = Env::default();
+    let (farmer, other_farmer) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let setup = setup(&env);
+    let policy_id = active_policy(&env, &setup, &farmer, symbol_short!("north"));
+    let elsewhere = active_policy(&env, &setup, &other_farmer, symbol_short!("south"));
+
+    report(&env, &setup, &setup.oracle, 25).unwrap();
+
+    let paid = claim(&env, &setup, &farmer, &policy_id).unwrap();
+    assert_eq!(paid, MAX_PAYOUT / 10);
+    assert_eq!(setup.token.balance(&farmer), MAX_PAYOUT / 10);
+
+    // Policies in other regions are not affected by the report
+    assert_eq!(
+        claim(&env, &setup, &other_farmer, &elsewhere),
+        Err(ContractError::NoPayoutDue)
+    );
+}
+
+#[test]
+fn test_double_parametric_claim_blocked() {
+    let env = Env::default();
+    let (farmer, _admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let setup = setup(&env);
+    let policy_id = active_policy(&env, &setup, &farmer, symbol_short!("north"));
+    report(&env, &setup, &setup.oracle, 40).unwrap();
+
+    claim(&env, &setup, &farmer, &policy_id).unwrap();
+    assert_eq!(
+        claim(&env, &setup, &farmer, &policy_id),
+        Err(ContractError::PayoutAlreadyClaimed)
+    );
+    assert_eq!(setup.token.balance(&farmer), MAX_PAYOUT / 10);
+}
+
+#[test]
+fn test_non_crossing_value_pays_nothing() {
+    let env = Env::default();
+    let (farmer, _admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let setup = setup(&env);
+    let policy_id = active_policy(&env, &setup, &farmer, symbol_short!("north"));
+    report(&env, &setup, &setup.oracle, 41).unwrap();
+
+    assert_eq!(
+        claim(&env, &setup, &farmer, &policy_id),
+        Err(ContractError::NoPayoutDue)
+    );
+
+    // Reports are final
+    assert_eq!(
+        report(&env, &setup, &setup.oracle, 10),
+        Err(ContractError::IndexAlreadyReported)
+    );
+
+    // Only the policyholder can claim
+    assert_eq!(
+        claim(&env, &setup, &Address::generate(&env), &policy_id),
+        Err(ContractError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_only_configured_oracle_can_report() {
+    let env = Env::default();
+
+    env.mock_all_auths();
+
+    let setup = setup(&env);
+    let impostor = Address::generate(&env);
+
+    assert_eq!(
+        report(&env, &setup, &impostor, 10),
+        Err(ContractError::Unauthorized)
+    );
+    assert_eq!(
+        report(&env, &setup, &setup.admin, 10),
+        Err(ContractError::Unauthorized)
+    );
+
+    let reported = env.as_contract(&setup.contract_id, || {
+        parametric::get_index_value(
+            env.clone(),
+            symbol_short!("north"),
+            symbol_short!("rainfall"),
+            PERIOD,
+        )
+    });
+    assert!(reported.is_none());
+
+    // Only the admin registers triggers and sets the oracle
+    let result = env.as_contract(&setup.contract_id, || {
+        parametric::register_trigger(
+            env.clone(),
+            impostor.clone(),
+            symbol_short!("drought"),
+            symbol_short!("yield"),
+            10,
+            10_000,
+        )
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+
+    let result = env.as_contract(&setup.contract_id, || {
+        admin::set_oracle(env.clone(), impostor.clone(), impostor.clone())
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_trigger_payout_capped_at_max_payout() {
+    let env = Env::default();
+
+    env.mock_all_auths();
+
+    let setup = setup(&env);
+    let result = env.as_contract(&setup.contract_id, || {
+        parametric::register_trigger(
+            env.clone(),
+            setup.admin.clone(),
+            symbol_short!("drought"),
+            symbol_short!("yield"),
+            10,
+            10_001,
+        )
+    });
+    assert_eq!(result, Err(ContractError::InvalidTrigger));
+}
+
+#[test]
+fn test_reports_outside_paid_coverage_pay_nothing() {
+    let env = Env::default();
+    let (farmer, _admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let setup = setup(&env);
+    let policy_id = env.as_contract(&setup.contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            0,
+            PremiumFrequency::Monthly,
+        )
+        .unwrap()
+    });
+    pay_premium(&env, &setup.contract_id, &policy_id);
+
+    // The second month is not paid for yet, though claims are still in their grace period
+    env.ledger().with_mut(|li| li.timestamp = MONTH + 1);
+    report(&env, &setup, &setup.oracle, 25).unwrap();
+    assert_eq!(
+        claim(&env, &setup, &farmer, &policy_id),
+        Err(ContractError::NoPayoutDue)
+    );
+    assert_eq!(setup.token.balance(&farmer), 0);
+}
@@ -6,14 +6,42 @@ use soroban_sdk::{
     Address, BytesN, Env,
 };
 
-use super::utils::{create_test_accounts, create_test_contract};
+use super::utils::{
+    add_product, approve_claim, capitalize, create_test_accounts, create_test_contract,
+    pay_premium, setup_admin, COVERAGE_START, MAX_PAYOUT,
+};
 use crate::{
-    claims::{self, Claim},
-    insurance::{self, get_policy},
+    claims::{self, Claim, ClaimStatus},
+    insurance::{self, get_policy, PremiumFrequency},
     payouts,
-    utils::DataKey,
+    utils::{ContractError, DataKey},
 };
 
+/// Approves the full requested amount and funds the policy's pool to cover it.
+fn approve_and_fund(
+    env: &Env,
+    contract_id: &Address,
+    admin: &Address,
+    assessor: &Address,
+    claim_id: &BytesN<32>,
+) {
+    let claim = env.as_contract(contract_id, || {
+        claims::get_claim(env.clone(), claim_id.clone())
+    });
+    let policy = env.as_contract(contract_id, || {
+        get_policy(env.clone(), claim.policy_id.clone())
+    });
+
+    capitalize(
+        env,
+        contract_id,
+        admin,
+        &policy.coverage,
+        claim.payout_amount,
+    );
+    approve_claim(env, contract_id, assessor, claim_id, claim.payout_amount);
+}
+
 #[test]
 fn test_full_insurance_flow() {
     let env = Env::default();
@@ -22,9 +50,25 @@ fn test_full_insurance_flow() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
-
+    let assessor = setup_admin(&env, &contract_id, &admin);
+
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
@@ -33,9 +77,7 @@ fn test_full_insurance_flow() {
     assert_eq!(policy.premium, 100);
     assert!(!policy.active);
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     let updated_policy =
         env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
@@ -64,9 +106,11 @@ fn test_full_insurance_flow() {
 
     assert_eq!(claim.payout_amount, payout_amount);
 
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
     let claim_stored = env.as_contract(&contract_id, || {
         env.storage()
@@ -74,7 +118,7 @@ fn test_full_insurance_flow() {
             .get::<_, Claim>(&DataKey::Claim(claim_id.clone()))
     });
 
-    assert!(claim_stored.is_none());
+    assert_eq!(claim_stored.unwrap().status, ClaimStatus::Paid);
 }
 
 #[test]
@@ -85,15 +129,29 @@ fn test_payout_amount_accuracy_verification() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        500,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 500).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit multiple claims with different payout amounts
     let event_hash_1 = BytesN::random(&env);
@@ -137,19 +195,25 @@ fn test_payout_amount_accuracy_verification() {
     assert_eq!(claim_3.payout_amount, 750);
 
     // Process payouts and verify accuracy
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id_1);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id_1.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id_2);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id_2.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id_3);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id_3.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
-    // Verify all claims are removed after payout
+    // Verify all claims are marked paid
     let claim_1_after = env.as_contract(&contract_id, || {
         env.storage()
             .instance()
@@ -166,9 +230,9 @@ fn test_payout_amount_accuracy_verification() {
             .get::<_, Claim>(&DataKey::Claim(claim_id_3))
     });
 
-    assert!(claim_1_after.is_none());
-    assert!(claim_2_after.is_none());
-    assert!(claim_3_after.is_none());
+    assert_eq!(claim_1_after.unwrap().status, ClaimStatus::Paid);
+    assert_eq!(claim_2_after.unwrap().status, ClaimStatus::Paid);
+    assert_eq!(claim_3_after.unwrap().status, ClaimStatus::Paid);
 }
 
 #[test]
@@ -180,10 +244,26 @@ fn test_payout_for_inactive_policy() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create policy but don't pay premium (stays inactive)
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
     // Try to submit claim on inactive policy first
@@ -195,9 +275,11 @@ fn test_payout_for_inactive_policy() {
     });
 
     // Try to process payout - should fail because policy is inactive
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
     env.as_contract(&contract_id, || {
-        payouts::pay_out(env.clone(), claim_id, admin.clone())
-    });
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    })
+    .unwrap();
 }
 
 #[test]
@@ -209,13 +291,15 @@ fn test_payout_for_nonexistent_claim() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
 
     // Try to process payout for a non-existent claim
     let fake_claim_id = BytesN::random(&env);
 
     env.as_contract(&contract_id, || {
-        payouts::pay_out(env.clone(), fake_claim_id, admin.clone())
-    });
+        payouts::pay_out(env.clone(), fake_claim_id.clone(), admin.clone())
+    })
+    .unwrap();
 }
 
 #[test]
@@ -227,15 +311,29 @@ fn test_admin_authorization_for_payouts() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit claim
     let event_hash = BytesN::random(&env);
@@ -243,20 +341,21 @@ fn test_admin_authorization_for_payouts() {
         claims::sub_claim(env.clone(), policy_id.clone(), event_hash, 300).unwrap()
     });
 
-    // Test that both admin and unauthorized user can process payouts
-    // (current contract doesn't restrict admin - any address can be admin)
+    // The configured admin can process the payout
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
-    // Verify claim is removed
+    // Verify claim is marked paid
     let claim_after = env.as_contract(&contract_id, || {
         env.storage()
             .instance()
             .get::<_, Claim>(&DataKey::Claim(claim_id))
     });
 
-    assert!(claim_after.is_none());
+    assert_eq!(claim_after.unwrap().status, ClaimStatus::Paid);
 
     // Create another claim to test with unauthorized user
     let event_hash_2 = BytesN::random(&env);
@@ -264,10 +363,12 @@ fn test_admin_authorization_for_payouts() {
         claims::sub_claim(env.clone(), policy_id.clone(), event_hash_2, 400).unwrap()
     });
 
-    // Unauthorized user can also process payouts (no admin restriction in current contract)
-    env.as_contract(&contract_id, || {
+    // Any other address is refused, even for an approved claim
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id_2);
+    let result = env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id_2.clone(), unauthorized_user.clone())
     });
+    assert_eq!(result, Err(ContractError::Unauthorized));
 
     let claim_2_after = env.as_contract(&contract_id, || {
         env.storage()
@@ -275,7 +376,7 @@ fn test_admin_authorization_for_payouts() {
             .get::<_, Claim>(&DataKey::Claim(claim_id_2))
     });
 
-    assert!(claim_2_after.is_none());
+    assert_eq!(claim_2_after.unwrap().status, ClaimStatus::Approved);
 }
 
 #[test]
@@ -286,15 +387,29 @@ fn test_multiple_payouts_same_policy_different_claims() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        200,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("drought"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Create multiple claims for the same policy
     let mut claim_ids = soroban_sdk::vec!(&env);
@@ -321,17 +436,19 @@ fn test_multiple_payouts_same_policy_different_claims() {
         assert_eq!(claim.payout_amount, expected_amount);
 
         // Process payout
+        approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
         env.as_contract(&contract_id, || {
             payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-        });
+        })
+        .unwrap();
 
-        // Verify claim is removed after payout
+        // Verify claim is marked paid after payout
         let claim_after = env.as_contract(&contract_id, || {
             env.storage()
                 .instance()
                 .get::<_, Claim>(&DataKey::Claim(claim_id))
         });
-        assert!(claim_after.is_none());
+        assert_eq!(claim_after.unwrap().status, ClaimStatus::Paid);
     }
 }
 
@@ -343,15 +460,23 @@ fn test_payout_transparency_and_auditability() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 150);
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("flood"), 150).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit claim
     let event_hash = BytesN::random(&env);
@@ -380,18 +505,20 @@ fn test_payout_transparency_and_auditability() {
     assert_eq!(claim_before.payout_amount, payout_amount);
 
     // Process payout
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
     env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-    });
+    })
+    .unwrap();
 
-    // Verify payout completed (claim removed)
+    // Verify payout completed (claim marked paid)
     let claim_after = env.as_contract(&contract_id, || {
         env.storage()
             .instance()
             .get::<_, Claim>(&DataKey::Claim(claim_id))
     });
 
-    assert!(claim_after.is_none());
+    assert_eq!(claim_after.unwrap().status, ClaimStatus::Paid);
 
     // Verify policy is still active after payout
     let policy_after_payout = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id));
@@ -409,15 +536,29 @@ fn test_high_volume_payout_processing() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("disaster"),
+        MAX_PAYOUT,
+        1000,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("disaster"), 1000).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("disaster"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Create 20 claims
     let mut claim_ids = soroban_sdk::vec!(&env);
@@ -444,17 +585,19 @@ fn test_high_volume_payout_processing() {
         assert_eq!(claim.payout_amount, expected_amount);
 
         // Process payout
+        approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
         env.as_contract(&contract_id, || {
             payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-        });
+        })
+        .unwrap();
 
-        // Verify claim removed
+        // Verify claim marked paid
         let claim_after = env.as_contract(&contract_id, || {
             env.storage()
                 .instance()
                 .get::<_, Claim>(&DataKey::Claim(claim_id))
         });
-        assert!(claim_after.is_none());
+        assert_eq!(claim_after.unwrap().status, ClaimStatus::Paid);
     }
 }
 
@@ -466,15 +609,29 @@ fn test_payout_with_maximum_amount() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate policy
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("maxpayout"),
+        MAX_PAYOUT,
+        100,
+    );
     let policy_id = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer.clone(), symbol_short!("maxpayout"), 100).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("maxpayout"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy_id.clone())
-    });
+    pay_premium(&env, &contract_id, &policy_id);
 
     // Submit claim with maximum payout
     let event_hash = BytesN::random(&env);
@@ -493,18 +650,19 @@ fn test_payout_with_maximum_amount() {
     });
     assert_eq!(claim_before.payout_amount, max_payout);
 
-    // Process payout
-    env.as_contract(&contract_id, || {
+    // The pool only holds the premium, so the payout is refused rather than partially paid
+    approve_claim(&env, &contract_id, &assessor, &claim_id, max_payout);
+    let result = env.as_contract(&contract_id, || {
         payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
     });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
 
-    // Verify payout completed
     let claim_after = env.as_contract(&contract_id, || {
         env.storage()
             .instance()
             .get::<_, Claim>(&DataKey::Claim(claim_id))
     });
-    assert!(claim_after.is_none());
+    assert_eq!(claim_after.unwrap().status, ClaimStatus::Approved);
 }
 
 #[test]
@@ -512,27 +670,47 @@ fn test_cross_policy_payout_integration() {
     let env = Env::default();
     let farmer1 = Address::generate(&env);
     let farmer2 = Address::generate(&env);
-    let admin1 = Address::generate(&env);
-    let admin2 = Address::generate(&env);
+    let admin = Address::generate(&env);
 
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Create and activate multiple policies
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        200,
+    );
     let policy1 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer1.clone(), symbol_short!("drought"), 200).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer1.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
+    add_product(&env, &contract_id, &symbol_short!("flood"), MAX_PAYOUT, 300);
     let policy2 = env.as_contract(&contract_id, || {
-        insurance::create_pol(env.clone(), farmer2.clone(), symbol_short!("flood"), 300).unwrap()
+        insurance::create_pol(
+            env.clone(),
+            farmer2.clone(),
+            symbol_short!("flood"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
     });
 
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy1.clone())
-    });
-    env.as_contract(&contract_id, || {
-        insurance::pay_prem(env.clone(), policy2.clone())
-    });
+    pay_premium(&env, &contract_id, &policy1);
+    pay_premium(&env, &contract_id, &policy2);
 
     // Submit claims for both policies
     let event1 = BytesN::random(&env);
@@ -545,13 +723,17 @@ fn test_cross_policy_payout_integration() {
         claims::sub_claim(env.clone(), policy2.clone(), event2, 1200).unwrap()
     });
 
-    // Process payouts with different admins
+    // Process payouts for both policies
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim1);
     env.as_contract(&contract_id, || {
-        payouts::pay_out(env.clone(), claim1.clone(), admin1.clone())
-    });
+        payouts::pay_out(env.clone(), claim1.clone(), admin.clone())
+    })
+    .unwrap();
+    approve_and_fund(&env, &contract_id, &admin, &assessor, &claim2);
     env.as_contract(&contract_id, || {
-        payouts::pay_out(env.clone(), claim2.clone(), admin2.clone())
-    });
+        payouts::pay_out(env.clone(), claim2.clone(), admin.clone())
+    })
+    .unwrap();
 
     // Verify both payouts completed
     let claim1_after = env.as_contract(&contract_id, || {
@@ -565,8 +747,8 @@ fn test_cross_policy_payout_integration() {
             .get::<_, Claim>(&DataKey::Claim(claim2))
     });
 
-    assert!(claim1_after.is_none());
-    assert!(claim2_after.is_none());
+    assert_eq!(claim1_after.unwrap().status, ClaimStatus::Paid);
+    assert_eq!(claim2_after.unwrap().status, ClaimStatus::Paid);
 
     // Verify policies remain active
     let policy1_after = env.as_contract(&contract_id, || get_policy(env.clone(), policy1));
@@ -586,15 +768,25 @@ fn test_end_to_end_insurance_ecosystem() {
     env.mock_all_auths();
 
     let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
 
     // Test complete ecosystem flow with multiple cycles
     for cycle in 1..=3 {
+        add_product(
+            &env,
+            &contract_id,
+            &symbol_short!("ecosys"),
+            MAX_PAYOUT,
+            (cycle * 100) as u32,
+        );
         let policy_id = env.as_contract(&contract_id, || {
             insurance::create_pol(
                 env.clone(),
                 farmer.clone(),
                 symbol_short!("ecosys"),
-                cycle * 100,
+                symbol_short!("north"),
+                COVERAGE_START,
+                PremiumFrequency::Single,
             )
             .unwrap()
         });
@@ -605,9 +797,7 @@ fn test_end_to_end_insurance_ecosystem() {
         assert_eq!(policy.premium, cycle * 100);
 
         // Pay premium
-        env.as_contract(&contract_id, || {
-            insurance::pay_prem(env.clone(), policy_id.clone())
-        });
+        pay_premium(&env, &contract_id, &policy_id);
 
         // Verify policy activated
         let active_policy =
@@ -625,9 +815,11 @@ fn test_end_to_end_insurance_ecosystem() {
             });
 
             // Process payout immediately
+            approve_and_fund(&env, &contract_id, &admin, &assessor, &claim_id);
             env.as_contract(&contract_id, || {
                 payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
-            });
+            })
+            .unwrap();
 
             // Verify payout completed
             let claim_after = env.as_contract(&contract_id, || {
@@ -635,7 +827,7 @@ fn test_end_to_end_insurance_ecosystem() {
                     .instance()
                     .get::<_, Claim>(&DataKey::Claim(claim_id))
             });
-            assert!(claim_after.is_none());
+            assert_eq!(claim_after.unwrap().status, ClaimStatus::Paid);
         }
 
         // Verify policy still active after all payouts
@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, BytesN as _, Ledger},
+    Address, BytesN, Env,
+};
+
+use super::utils::{
+    add_product, approve_claim, capitalize, create_test_accounts, create_test_contract,
+    pay_premium, premium_token, setup_admin, COVERAGE_END, COVERAGE_START,
+};
+use crate::{
+    claims,
+    insurance::{self, PremiumFrequency},
+    payouts, pool,
+    utils::ContractError,
+};
+
+fn drought_policy(
+    env: &Env,
+    contract_id: &Address,
+    farmer: &Address,
+    max_payout: i128,
+) -> BytesN<32> {
+    // Premium is 10% of the maximum payout
+    add_product(
+        env,
+        contract_id,
+        &symbol_short!("drought"),
+        max_payout,
+        1_000,
+    );
+    env.as_contract(contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+        .unwrap()
+    })
+}
+
+fn pool_status(env: &Env, contract_id: &Address) -> pool::PoolStatus {
+    env.as_contract(contract_id, || {
+        pool::get_pool_status(env.clone(), symbol_short!("drought"))
+    })
+}
+
+#[test]
+fn test_payout_blocked_when_pool_underfunded() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let token = premium_token(&env, &contract_id);
+    let policy_id = drought_policy(&env, &contract_id, &farmer, 5_000);
+    pay_premium(&env, &contract_id, &policy_id);
+
+    let claim_id = env.as_contract(&contract_id, || {
+        claims::sub_claim(env.clone(), policy_id.clone(), BytesN::random(&env), 1_000).unwrap()
+    });
+    approve_claim(&env, &contract_id, &assessor, &claim_id, 1_000);
+
+    // Only the premium is in the pool; nothing is paid
+    let result = env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
+    assert_eq!(token.balance(&farmer), 0);
+    assert_eq!(pool_status(&env, &contract_id).balance, 500);
+
+    // Other coverage types' funds cannot be used
+    capitalize(&env, &contract_id, &admin, &symbol_short!("flood"), 5_000);
+    let result = env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
+
+    capitalize(&env, &contract_id, &admin, &symbol_short!("drought"), 500);
+    env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    })
+    .unwrap();
+    assert_eq!(token.balance(&farmer), 1_000);
+    assert_eq!(pool_status(&env, &contract_id).balance, 0);
+}
+
+#[test]
+fn test_surplus_withdrawal_respects_reserve_ratio() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let token = premium_token(&env, &contract_id);
+    let policy_id = drought_policy(&env, &contract_id, &farmer, 4_000);
+    pay_premium(&env, &contract_id, &policy_id);
+    capitalize(&env, &contract_id, &admin, &symbol_short!("drought"), 4_600);
+
+    // Fully reserved by default: only 1_000 is surplus
+    let result = env.as_contract(&contract_id, || {
+        pool::withdraw_surplus(env.clone(), admin.clone(), symbol_short!("drought"), 1_001)
+    });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
+
+    // At a 50% reserve ratio 3_000 can leave the pool
+    env.as_contract(&contract_id, || {
+        pool::set_reserve_ratio(env.clone(), admin.clone(), 5_000)
+    })
+    .unwrap();
+    env.as_contract(&contract_id, || {
+        pool::withdraw_surplus(env.clone(), admin.clone(), symbol_short!("drought"), 3_000)
+    })
+    .unwrap();
+    assert_eq!(token.balance(&admin), 3_000);
+
+    let result = env.as_contract(&contract_id, || {
+        pool::withdraw_surplus(env.clone(), admin.clone(), symbol_short!("drought"), 1)
+    });
+    assert_eq!(result, Err(ContractError::InsufficientReserves));
+
+    // Only the admin manages the pool
+    let outsider = Address::generate(&env);
+    let result = env.as_contract(&contract_id, || {
+        pool::capitalize_pool(env.clone(), outsider.clone(), symbol_short!("drought"), 10)
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_exposure_follows_policy_lifetime() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+
+    let status = pool_status(&env, &contract_id);
+    assert_eq!(status.active_exposure, 0);
+    assert_eq!(status.reserve_ratio_bps, pool::DEFAULT_RESERVE_RATIO_BPS);
+
+    // Policies only count once paid for
+    let first = drought_policy(&env, &contract_id, &farmer, 3_000);
+    let unpaid = drought_policy(&env, &contract_id, &farmer, 2_000);
+    assert_eq!(pool_status(&env, &contract_id).active_exposure, 0);
+
+    pay_premium(&env, &contract_id, &first);
+    let status = pool_status(&env, &contract_id);
+    assert_eq!(status.balance, 300);
+    assert_eq!(status.active_exposure, 3_000);
+
+    let result = env.as_contract(&contract_id, || {
+        pool::release_expired_exposure(env.clone(), first.clone())
+    });
+    assert_eq!(result, Err(ContractError::PolicyNotExpired));
+
+    // Expired policies are released once, freeing the pool for withdrawal
+    env.ledger().with_mut(|li| li.timestamp = COVERAGE_END);
+    let release = |policy_id: &BytesN<32>| {
+        env.as_contract(&contract_id, || {
+            pool::release_expired_exposure(env.clone(), policy_id.clone()).unwrap()
+        })
+    };
+    assert_eq!(release(&unpaid), 0);
+    assert_eq!(pool_status(&env, &contract_id).active_exposure, 3_000);
+    assert_eq!(release(&first), 3_000);
+    assert_eq!(release(&first), 0);
+    assert_eq!(pool_status(&env, &contract_id).active_exposure, 0);
+
+    env.as_contract(&contract_id, || {
+        pool::withdraw_surplus(env.clone(), admin.clone(), symbol_short!("drought"), 300)
+    })
+    .unwrap();
+    assert_eq!(pool_status(&env, &contract_id).balance, 0);
+}
+
+#[test]
+fn test_payouts_draw_down_exposure() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let assessor = setup_admin(&env, &contract_id, &admin);
+    let policy_id = drought_policy(&env, &contract_id, &farmer, 5_000);
+    pay_premium(&env, &contract_id, &policy_id);
+    capitalize(&env, &contract_id, &admin, &symbol_short!("drought"), 5_000);
+
+    let claim_id = env.as_contract(&contract_id, || {
+        claims::sub_claim(env.clone(), policy_id.clone(), BytesN::random(&env), 1_000).unwrap()
+    });
+    approve_claim(&env, &contract_id, &assessor, &claim_id, 1_000);
+    env.as_contract(&contract_id, || {
+        payouts::pay_out(env.clone(), claim_id.clone(), admin.clone())
+    })
+    .unwrap();
+    assert_eq!(pool_status(&env, &contract_id).active_exposure, 4_000);
+
+    // Only what is left of the limit is released on expiry
+    env.ledger().with_mut(|li| li.timestamp = COVERAGE_END);
+    let released = env.as_contract(&contract_id, || {
+        pool::release_expired_exposure(env.clone(), policy_id.clone()).unwrap()
+    });
+    assert_eq!(released, 4_000);
+    assert_eq!(pool_status(&env, &contract_id).active_exposure, 0);
+}
@@ -0,0 +1,269 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{BytesN as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+use super::utils::{
+    add_product, create_test_accounts, create_test_contract, pay_premium, premium_token,
+    setup_admin, COVERAGE_END, COVERAGE_START, MAX_PAYOUT,
+};
+use crate::{
+    claims,
+    insurance::{self, get_policy, PolicyStatus, PremiumFrequency, PREMIUM_GRACE_PERIOD},
+    utils::ContractError,
+};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+const PREMIUM: i128 = 100;
+
+/// A one-year product whose term premium splits into 13 monthly installments of `PREMIUM`.
+fn monthly_policy(env: &Env, contract_id: &Address, farmer: &Address) -> BytesN<32> {
+    add_product(
+        env,
+        contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        1_300,
+    );
+    env.as_contract(contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Monthly,
+        )
+        .unwrap()
+    })
+}
+
+fn status(env: &Env, contract_id: &Address, policy_id: &BytesN<32>) -> PolicyStatus {
+    env.as_contract(contract_id, || {
+        insurance::get_policy_status(env.clone(), policy_id.clone())
+    })
+}
+
+fn submit(
+    env: &Env,
+    contract_id: &Address,
+    policy_id: &BytesN<32>,
+) -> Result<BytesN<32>, ContractError> {
+    env.as_contract(contract_id, || {
+        claims::sub_claim(env.clone(), policy_id.clone(), BytesN::random(env), 500)
+    })
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_on_time_premiums_extend_coverage() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let token = premium_token(&env, &contract_id);
+    let policy_id = monthly_policy(&env, &contract_id, &farmer);
+
+    assert_eq!(
+        status(&env, &contract_id, &policy_id),
+        PolicyStatus::Inactive
+    );
+
+    pay_premium(&env, &contract_id, &policy_id);
+
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert!(policy.active);
+    assert_eq!(policy.next_premium_due, COVERAGE_START + MONTH);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Active);
+
+    set_time(&env, MONTH - 1);
+    pay_premium(&env, &contract_id, &policy_id);
+
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert_eq!(policy.next_premium_due, COVERAGE_START + 2 * MONTH);
+    assert_eq!(policy.premiums_paid, 2 * PREMIUM);
+    assert_eq!(token.balance(&contract_id), 2 * PREMIUM);
+    assert_eq!(token.balance(&farmer), 0);
+
+    // Coverage ends with the period
+    set_time(&env, COVERAGE_END);
+    assert_eq!(
+        status(&env, &contract_id, &policy_id),
+        PolicyStatus::Expired
+    );
+    assert_eq!(
+        submit(&env, &contract_id, &policy_id),
+        Err(ContractError::PolicyExpired)
+    );
+}
+
+#[test]
+fn test_claim_allowed_during_grace_period() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let policy_id = monthly_policy(&env, &contract_id, &farmer);
+    pay_premium(&env, &contract_id, &policy_id);
+
+    set_time(&env, MONTH + PREMIUM_GRACE_PERIOD - 1);
+    assert_eq!(
+        status(&env, &contract_id, &policy_id),
+        PolicyStatus::GracePeriod
+    );
+    assert!(submit(&env, &contract_id, &policy_id).is_ok());
+
+    // Paying within the grace period keeps the policy in force
+    pay_premium(&env, &contract_id, &policy_id);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Active);
+}
+
+#[test]
+fn test_lapsed_policy_rejects_claims_and_premiums() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let policy_id = monthly_policy(&env, &contract_id, &farmer);
+    pay_premium(&env, &contract_id, &policy_id);
+
+    set_time(&env, MONTH + PREMIUM_GRACE_PERIOD);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Lapsed);
+    assert_eq!(
+        submit(&env, &contract_id, &policy_id),
+        Err(ContractError::PolicyLapsed)
+    );
+
+    let result = env.as_contract(&contract_id, || {
+        insurance::pay_prem(env.clone(), policy_id.clone())
+    });
+    assert_eq!(result, Err(ContractError::PolicyLapsed));
+}
+
+#[test]
+fn test_reinstatement_requires_arrears() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let token = premium_token(&env, &contract_id);
+    let policy_id = monthly_policy(&env, &contract_id, &farmer);
+    pay_premium(&env, &contract_id, &policy_id);
+
+    // Only lapsed policies can be reinstated
+    let result = env.as_contract(&contract_id, || {
+        insurance::reinstate_policy(env.clone(), policy_id.clone())
+    });
+    assert_eq!(result, Err(ContractError::PolicyNotLapsed));
+
+    // Missed the installments due at months one and two
+    set_time(&env, 2 * MONTH + MONTH / 2);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Lapsed);
+
+    token::StellarAssetClient::new(&env, &token.address).mint(&farmer, &(2 * PREMIUM));
+    let arrears = env
+        .as_contract(&contract_id, || {
+            insurance::reinstate_policy(env.clone(), policy_id.clone())
+        })
+        .unwrap();
+    assert_eq!(arrears, 2 * PREMIUM);
+    assert_eq!(token.balance(&contract_id), 3 * PREMIUM);
+
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert_eq!(policy.next_premium_due, 3 * MONTH);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Active);
+    assert!(submit(&env, &contract_id, &policy_id).is_ok());
+}
+
+#[test]
+fn test_invalid_coverage_period_rejected() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        1_300,
+    );
+
+    // The product term would run past the end of time
+    let result = env.as_contract(&contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            u64::MAX,
+            PremiumFrequency::Monthly,
+        )
+    });
+    assert_eq!(result, Err(ContractError::InvalidCoveragePeriod));
+
+    // Coverage cannot be backdated
+    set_time(&env, MONTH);
+    let result = env.as_contract(&contract_id, || {
+        insurance::create_pol(
+            env.clone(),
+            farmer.clone(),
+            symbol_short!("drought"),
+            symbol_short!("north"),
+            MONTH - 1,
+            PremiumFrequency::Monthly,
+        )
+    });
+    assert_eq!(result, Err(ContractError::InvalidCoveragePeriod));
+}
+
+#[test]
+fn test_coverage_starts_with_late_first_premium() {
+    let env = Env::default();
+    let (farmer, admin) = create_test_accounts(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    setup_admin(&env, &contract_id, &admin);
+    let policy_id = monthly_policy(&env, &contract_id, &farmer);
+
+    // Nothing is covered while the policy waits for its first premium
+    set_time(&env, 2 * MONTH);
+    assert_eq!(
+        status(&env, &contract_id, &policy_id),
+        PolicyStatus::Inactive
+    );
+
+    pay_premium(&env, &contract_id, &policy_id);
+    let policy = env.as_contract(&contract_id, || get_policy(env.clone(), policy_id.clone()));
+    assert_eq!(policy.coverage_start, 2 * MONTH);
+    assert_eq!(
+        policy.coverage_end,
+        2 * MONTH + COVERAGE_END - COVERAGE_START
+    );
+    assert_eq!(policy.next_premium_due, 3 * MONTH);
+    assert_eq!(policy.premiums_paid, PREMIUM);
+    assert_eq!(status(&env, &contract_id, &policy_id), PolicyStatus::Active);
+}
@@ -0,0 +1,349 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, Vec,
+};
+
+use super::utils::{
+    add_product, create_test_accounts, create_test_contract, setup_admin, COVERAGE_START,
+    MAX_PAYOUT,
+};
+use crate::{
+    insurance::{self, get_policy, PremiumFrequency},
+    quotes::{self, CertStatus, QuoteBreakdown, WaterDiscountTier, QUOTE_VALIDITY},
+    utils::{ContractError, DataKey},
+};
+
+// Term premium of the test product: 10% of MAX_PAYOUT
+const BASE_PREMIUM: i128 = 1_000;
+
+/// Stands in for the water-management contract, reporting a set number of open alerts
+#[contract]
+pub struct MockWaterContract;
+
+#[contractimpl]
+impl MockWaterContract {
+    pub fn set_alerts(env: Env, count: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("alerts"), &count);
+    }
+
+    pub fn get_farmer_alerts(env: Env, _farmer_id: Address, include_resolved: bool) -> Vec<u32> {
+        assert!(!include_resolved);
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("alerts"))
+            .unwrap_or(0);
+        let mut alerts = Vec::new(&env);
+        for i in 0..count {
+            alerts.push_back(i);
+        }
+        alerts
+    }
+}
+
+/// Stands in for the certificate-management contract, holding one certification per farmer
+#[contract]
+pub struct MockCertificationContract;
+
+#[contractimpl]
+impl MockCertificationContract {
+    pub fn set_status(env: Env, owner: Address, status: CertStatus) {
+        env.storage().instance().set(&owner, &status);
+    }
+
+    pub fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        _offset: u32,
+        _limit: u32,
+    ) -> Vec<u32> {
+        let status: Option<CertStatus> = env.storage().instance().get(&owner);
+        match status {
+            Some(status) if status_filter.is_none_or(|filter| filter == status) => {
+                vec![&env, 1]
+            }
+            _ => Vec::new(&env),
+        }
+    }
+}
+
+struct QuoteTest {
+    env: Env,
+    contract_id: Address,
+    admin: Address,
+    farmer: Address,
+    water: MockWaterContractClient<'static>,
+    certification: MockCertificationContractClient<'static>,
+}
+
+/// A product with a `BASE_PREMIUM` term premium, both risk sources linked and a schedule of
+/// 10% for no open alerts, 5% for up to two, and 5% for a valid certification.
+fn setup() -> QuoteTest {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let (admin, farmer) = create_test_accounts(&env);
+    setup_admin(&env, &contract_id, &admin);
+    add_product(
+        &env,
+        &contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        1_000,
+    );
+
+    let water = MockWaterContractClient::new(&env, &env.register(MockWaterContract, ()));
+    let certification =
+        MockCertificationContractClient::new(&env, &env.register(MockCertificationContract, ()));
+    env.as_contract(&contract_id, || {
+        quotes::set_risk_sources(
+            env.clone(),
+            admin.clone(),
+            Some(water.address.clone()),
+            Some(certification.address.clone()),
+        )
+    })
+    .unwrap();
+    let tiers = vec![
+        &env,
+        WaterDiscountTier {
+            max_unresolved_alerts: 0,
+            discount_bps: 1_000,
+        },
+        WaterDiscountTier {
+            max_unresolved_alerts: 2,
+            discount_bps: 500,
+        },
+    ];
+    set_schedule(&env, &contract_id, &admin, tiers, 500).unwrap();
+
+    QuoteTest {
+        env,
+        contract_id,
+        admin,
+        farmer,
+        water,
+        certification,
+    }
+}
+
+fn set_schedule(
+    env: &Env,
+    contract_id: &Address,
+    admin: &Address,
+    water_tiers: Vec<WaterDiscountTier>,
+    quality_discount_bps: u32,
+) -> Result<(), ContractError> {
+    env.as_contract(contract_id, || {
+        quotes::set_discount_schedule(
+            env.clone(),
+            admin.clone(),
+            water_tiers,
+            quality_discount_bps,
+        )
+    })
+}
+
+fn quote(t: &QuoteTest) -> QuoteBreakdown {
+    t.env
+        .as_contract(&t.contract_id, || {
+            quotes::compute_premium_quote(t.env.clone(), t.farmer.clone(), symbol_short!("drought"))
+        })
+        .unwrap()
+}
+
+fn accept(
+    t: &QuoteTest,
+    quote_id: &BytesN<32>,
+    quoted_premium: i128,
+) -> Result<BytesN<32>, ContractError> {
+    t.env.as_contract(&t.contract_id, || {
+        insurance::create_pol_with_quote(
+            t.env.clone(),
+            t.farmer.clone(),
+            quote_id.clone(),
+            quoted_premium,
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+    })
+}
+
+#[test]
+fn test_quote_applies_both_discounts() {
+    let t = setup();
+    t.certification.set_status(&t.farmer, &CertStatus::Valid);
+
+    let quote = quote(&t);
+    assert_eq!(quote.base_premium, BASE_PREMIUM);
+    assert_eq!(quote.water_discount_bps, 1_000);
+    assert_eq!(quote.quality_discount_bps, 500);
+    assert_eq!(quote.final_premium, 850);
+    assert_eq!(
+        quote.expires_at,
+        t.env.ledger().timestamp() + QUOTE_VALIDITY
+    );
+
+    let policy_id = accept(&t, &quote.quote_id, quote.final_premium).unwrap();
+    let policy = t
+        .env
+        .as_contract(&t.contract_id, || get_policy(t.env.clone(), policy_id));
+    assert_eq!(policy.premium, 850);
+    assert_eq!(policy.coverage, symbol_short!("drought"));
+
+    // A quote pays for one policy only
+    assert_eq!(
+        accept(&t, &quote.quote_id, quote.final_premium),
+        Err(ContractError::QuoteNotFound)
+    );
+}
+
+#[test]
+fn test_water_discount_follows_open_alerts() {
+    let t = setup();
+
+    t.water.set_alerts(&2);
+    let quote_two = quote(&t);
+    assert_eq!(quote_two.water_discount_bps, 500);
+    assert_eq!(quote_two.quality_discount_bps, 0);
+    assert_eq!(quote_two.final_premium, 950);
+
+    t.water.set_alerts(&3);
+    assert_eq!(quote(&t).final_premium, BASE_PREMIUM);
+
+    // Revoked or expired certifications earn nothing
+    t.certification.set_status(&t.farmer, &CertStatus::Revoked);
+    assert_eq!(quote(&t).quality_discount_bps, 0);
+}
+
+#[test]
+fn test_unreadable_track_records_give_no_discount() {
+    let t = setup();
+    let not_a_contract = Address::generate(&t.env);
+    t.env
+        .as_contract(&t.contract_id, || {
+            quotes::set_risk_sources(
+                t.env.clone(),
+                t.admin.clone(),
+                Some(not_a_contract.clone()),
+                Some(not_a_contract.clone()),
+            )
+        })
+        .unwrap();
+
+    let quote = quote(&t);
+    assert_eq!(quote.water_discount_bps, 0);
+    assert_eq!(quote.quality_discount_bps, 0);
+    assert_eq!(quote.final_premium, BASE_PREMIUM);
+    assert!(accept(&t, &quote.quote_id, BASE_PREMIUM).is_ok());
+}
+
+#[test]
+fn test_expired_quote_rejected() {
+    let t = setup();
+    let quote = quote(&t);
+
+    t.env
+        .ledger()
+        .with_mut(|li| li.timestamp += QUOTE_VALIDITY + 1);
+    assert_eq!(
+        accept(&t, &quote.quote_id, quote.final_premium),
+        Err(ContractError::QuoteExpired)
+    );
+}
+
+#[test]
+fn test_tampered_premium_rejected() {
+    let t = setup();
+    let quote = quote(&t);
+
+    assert_eq!(
+        accept(&t, &quote.quote_id, quote.final_premium - 1),
+        Err(ContractError::QuoteMismatch)
+    );
+
+    // Repricing the product invalidates quotes made on the old terms
+    add_product(
+        &t.env,
+        &t.contract_id,
+        &symbol_short!("drought"),
+        MAX_PAYOUT,
+        2_000,
+    );
+    assert_eq!(
+        accept(&t, &quote.quote_id, quote.final_premium),
+        Err(ContractError::QuoteMismatch)
+    );
+}
+
+#[test]
+fn test_quote_belongs_to_farmer() {
+    let t = setup();
+    let quote = quote(&t);
+    let other = Address::generate(&t.env);
+
+    let result = t.env.as_contract(&t.contract_id, || {
+        insurance::create_pol_with_quote(
+            t.env.clone(),
+            other.clone(),
+            quote.quote_id.clone(),
+            quote.final_premium,
+            symbol_short!("north"),
+            COVERAGE_START,
+            PremiumFrequency::Single,
+        )
+    });
+    assert_eq!(result, Err(ContractError::Unauthorized));
+}
+
+#[test]
+fn test_discount_schedule_bounds() {
+    let t = setup();
+    let tiers = vec![
+        &t.env,
+        WaterDiscountTier {
+            max_unresolved_alerts: 0,
+            discount_bps: 4_000,
+        },
+    ];
+
+    assert_eq!(
+        set_schedule(&t.env, &t.contract_id, &t.admin, tiers.clone(), 1_001),
+        Err(ContractError::InvalidDiscount)
+    );
+    assert_eq!(
+        set_schedule(&t.env, &t.contract_id, &t.farmer, tiers.clone(), 0),
+        Err(ContractError::Unauthorized)
+    );
+
+    set_schedule(&t.env, &t.contract_id, &t.admin, tiers.clone(), 1_000).unwrap();
+    let schedule = t
+        .env
+        .as_contract(&t.contract_id, || {
+            quotes::get_discount_schedule(t.env.clone())
+        })
+        .unwrap();
+    assert_eq!(schedule.water_tiers, tiers);
+    assert_eq!(schedule.quality_discount_bps, 1_000);
+}
+
+#[test]
+fn test_quotes_kept_in_temporary_storage() {
+    let t = setup();
+    let quote = quote(&t);
+
+    let stored: Option<QuoteBreakdown> = t.env.as_contract(&t.contract_id, || {
+        t.env
+            .storage()
+            .temporary()
+            .get(&DataKey::Quote(quote.quote_id.clone()))
+    });
+    assert_eq!(stored, Some(quote));
+}
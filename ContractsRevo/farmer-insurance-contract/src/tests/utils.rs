@@ -1,7 +1,17 @@
 #![cfg(test)]
 
-use crate::FarmerInsuranceContract;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use crate::{
+    admin,
+    claims::{self, ClaimDecision},
+    insurance, pool, FarmerInsuranceContract,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, Symbol};
+
+use crate::{catalog, utils::DataKey};
+
+pub const COVERAGE_START: u64 = 0;
+pub const COVERAGE_END: u64 = 365 * 24 * 60 * 60;
+pub const MAX_PAYOUT: i128 = 10_000;
 
 pub fn create_test_contract(env: &Env) -> Address {
     env.register(FarmerInsuranceContract, ())
@@ -10,3 +20,113 @@ pub fn create_test_contract(env: &Env) -> Address {
 pub fn create_test_accounts(env: &Env) -> (Address, Address) {
     (Address::generate(env), Address::generate(env))
 }
+
+/// Initializes the contract with `admin` and a fresh premium token, and registers a fresh
+/// assessor, which is returned.
+pub fn setup_admin(env: &Env, contract_id: &Address, admin: &Address) -> Address {
+    let assessor = Address::generate(env);
+    let premium_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    env.as_contract(contract_id, || {
+        admin::initialize(env.clone(), admin.clone(), premium_token.clone())
+    })
+    .unwrap();
+    env.as_contract(contract_id, || {
+        admin::add_assessor(env.clone(), admin.clone(), assessor.clone())
+    })
+    .unwrap();
+
+    assessor
+}
+
+pub fn approve_claim(
+    env: &Env,
+    contract_id: &Address,
+    assessor: &Address,
+    claim_id: &BytesN<32>,
+    approved_amount: i128,
+) {
+    env.as_contract(contract_id, || {
+        claims::assess_claim(
+            env.clone(),
+            assessor.clone(),
+            claim_id.clone(),
+            approved_amount,
+            ClaimDecision::Approve,
+            BytesN::from_array(env, &[1; 32]),
+        )
+    })
+    .unwrap();
+}
+
+pub fn premium_token(env: &Env, contract_id: &Address) -> token::Client<'static> {
+    let address = env
+        .as_contract(contract_id, || admin::premium_token(env))
+        .unwrap();
+    token::Client::new(env, &address)
+}
+
+/// Mints the next premium to the policyholder and pays it.
+pub fn pay_premium(env: &Env, contract_id: &Address, policy_id: &BytesN<32>) {
+    let policy = env.as_contract(contract_id, || {
+        insurance::get_policy(env.clone(), policy_id.clone())
+    });
+    let token = premium_token(env, contract_id);
+    token::StellarAssetClient::new(env, &token.address).mint(&policy.farmer, &policy.premium);
+
+    env.as_contract(contract_id, || {
+        insurance::pay_prem(env.clone(), policy_id.clone())
+    })
+    .unwrap();
+}
+
+/// Mints `amount` to the admin and adds it to the coverage type's pool.
+pub fn capitalize(
+    env: &Env,
+    contract_id: &Address,
+    admin: &Address,
+    coverage: &Symbol,
+    amount: i128,
+) {
+    let token = premium_token(env, contract_id);
+    token::StellarAssetClient::new(env, &token.address).mint(admin, &amount);
+
+    env.as_contract(contract_id, || {
+        pool::capitalize_pool(env.clone(), admin.clone(), coverage.clone(), amount)
+    })
+    .unwrap();
+}
+
+/// Publishes a coverage product with a one-year term on behalf of the configured admin.
+/// With `MAX_PAYOUT` the term premium equals `premium_rate_bps`.
+pub fn add_product(
+    env: &Env,
+    contract_id: &Address,
+    coverage: &Symbol,
+    max_payout: i128,
+    premium_rate_bps: u32,
+) -> u32 {
+    let admin: Address = env
+        .as_contract(contract_id, || {
+            env.storage().instance().get(&DataKey::Admin)
+        })
+        .unwrap();
+
+    env.as_contract(contract_id, || {
+        catalog::create_coverage_product(
+            env.clone(),
+            admin.clone(),
+            coverage.clone(),
+            max_payout,
+            premium_rate_bps,
+            COVERAGE_END - COVERAGE_START,
+            BytesN::from_array(env, &[0; 32]),
+            0,
+            0,
+            0,
+        )
+    })
+    .unwrap()
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Bytes, BytesN, Env};
+use soroban_sdk::{contracterror, contracttype, Address, Bytes, BytesN, Env, Symbol};
 
 #[contracttype]
 #[derive(Clone)]
@@ -7,6 +7,35 @@ pub enum DataKey {
     Claim(BytesN<32>),
     PolicyCount,
     ClaimCount,
+    Admin,
+    Assessor(Address),
+    PolicyClaims(BytesN<32>),
+    PremiumToken,
+    Oracle,
+    Triggers,
+    IndexValue(Symbol, Symbol, u32),
+    ParametricClaim(BytesN<32>, u32),
+    PoolBalance(Symbol),
+    ActiveExposure(Symbol),
+    ReserveRatio,
+    Product(Symbol, u32),
+    ProductVersion(Symbol),
+    FarmerPolicies(Address),
+    QuoteCount,
+    Quote(BytesN<32>),
+    DiscountSchedule,
+    WaterContract,
+    CertificationContract,
+    InsuredEvent(BytesN<32>),
+    PendingClaim(u64),
+    PendingClaimsHead,
+    PendingClaimsTail,
+    PolicyReserved(BytesN<32>),
+    ClaimExpiryAge,
+    ReservedClaims(Symbol),
+    RentalPlatform(Address),
+    RentalCover(BytesN<32>),
+    ExposureReleased(BytesN<32>),
 }
 
 #[contracterror]
@@ -15,6 +44,33 @@ pub enum DataKey {
 pub enum ContractError {
     PolicyCountOverflow = 1,
     ClaimCountOverflow = 2,
+    AlreadyInitialized = 3,
+    NotInitialized = 4,
+    Unauthorized = 5,
+    ClaimAlreadyAssessed = 6,
+    ClaimNotApproved = 7,
+    InvalidAmount = 8,
+    InvalidCoveragePeriod = 9,
+    PolicyLapsed = 10,
+    PolicyExpired = 11,
+    PolicyNotLapsed = 12,
+    InvalidTrigger = 13,
+    IndexAlreadyReported = 14,
+    NoPayoutDue = 15,
+    PayoutAlreadyClaimed = 16,
+    InsufficientReserves = 17,
+    ProductNotFound = 18,
+    InvalidProduct = 19,
+    InvalidDiscount = 20,
+    QuoteNotFound = 21,
+    QuoteExpired = 22,
+    QuoteMismatch = 23,
+    ClaimWindowExpired = 24,
+    EventNotRegistered = 25,
+    PolicyNotCancellable = 26,
+    PolicyNotExpired = 27,
+    EventOutsideCoverage = 28,
+    TooManyClaims = 29,
 }
 
 pub fn generate_policy_id(env: &Env) -> Result<BytesN<32>, ContractError> {
@@ -70,3 +126,27 @@ pub fn generate_claim_id(env: &Env) -> Result<BytesN<32>, ContractError> {
     let hash = env.crypto().sha256(&buffer);
     Ok(hash.to_bytes())
 }
+
+pub fn generate_quote_id(env: &Env) -> Result<BytesN<32>, ContractError> {
+    let count: u64 = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::QuoteCount)
+        .unwrap_or(0);
+
+    let new_count = count
+        .checked_add(1)
+        .ok_or(ContractError::PolicyCountOverflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::QuoteCount, &new_count);
+
+    let mut buffer = Bytes::from_slice(env, b"quote");
+    buffer.append(&Bytes::from_slice(
+        env,
+        &env.ledger().timestamp().to_be_bytes(),
+    ));
+    buffer.append(&Bytes::from_slice(env, &new_count.to_be_bytes()));
+    let hash = env.crypto().sha256(&buffer);
+    Ok(hash.to_bytes())
+}
@@ -0,0 +1,160 @@
+use soroban_sdk::{contracterror, contracttype, Address, Env, Error, Symbol, Vec};
+
+use crate::{
+    token::{get_balance, is_paused, set_balance, total_supply, update_total_supply},
+    utils::{get_admin, is_minter},
+    BurnError, MintError, TokenError,
+};
+
+/// Maximum number of operations accepted in a single batch
+pub const MAX_BATCH_OPS: u32 = 50;
+
+/// Failing operations are reported as `OP_FAILURE_BASE * (index + 1) + code`,
+/// where `code` is the error code returned by the underlying operation
+pub const OP_FAILURE_BASE: u32 = 100;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchError {
+    EmptyBatch = 1,
+    BatchTooLarge = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenOp {
+    Mint(Address, i128),              // (to, amount)
+    Burn(Address, i128, Symbol),      // (from, amount, reason)
+    Transfer(Address, Address, i128), // (from, to, amount)
+}
+
+/// Execute a batch of token operations submitted by a relaying operator
+/// Mints require the operator to be a minter, burns require the operator to be
+/// the admin, and transfers require the authorization of the sending address.
+/// The batch is atomic: any failing operation reverts every operation before it.
+pub fn execute_batch(env: Env, operator: Address, ops: Vec<TokenOp>) -> Result<(), Error> {
+    operator.require_auth();
+
+    if ops.is_empty() {
+        return Err(BatchError::EmptyBatch.into());
+    }
+    if ops.len() > MAX_BATCH_OPS {
+        return Err(BatchError::BatchTooLarge.into());
+    }
+
+    let operator_is_minter = is_minter(env.clone(), operator.clone());
+    let operator_is_admin = get_admin(env.clone()).is_ok_and(|admin| admin == operator);
+    let paused = is_paused(&env);
+
+    // Each address may only be authorized once per invocation
+    let mut authorized: Vec<Address> = Vec::new(&env);
+    authorized.push_back(operator.clone());
+
+    let mut supply = total_supply(env.clone());
+    let mut minted = 0i128;
+    let mut burned = 0i128;
+    let mut transferred = 0i128;
+
+    for (index, op) in ops.iter().enumerate() {
+        let result = match op {
+            TokenOp::Mint(to, amount) => {
+                if !operator_is_minter {
+                    Err(MintError::Unauthorized as u32)
+                } else if paused {
+                    Err(MintError::Paused as u32)
+                } else if amount <= 0 {
+                    Err(MintError::InvalidAmount as u32)
+                } else {
+                    let new_balance = get_balance(&env, &to) + amount;
+                    set_balance(&env, &to, new_balance);
+                    supply += amount;
+                    minted += amount;
+
+                    env.events().publish(
+                        (Symbol::new(&env, "mint"), operator.clone(), to),
+                        (amount, new_balance, supply),
+                    );
+                    Ok(())
+                }
+            }
+            TokenOp::Burn(from, amount, reason) => {
+                let balance = get_balance(&env, &from);
+                if !operator_is_admin {
+                    Err(BurnError::Unauthorized as u32)
+                } else if amount <= 0 {
+                    Err(BurnError::InvalidAmount as u32)
+                } else if balance < amount {
+                    Err(BurnError::InsufficientBalance as u32)
+                } else {
+                    set_balance(&env, &from, balance - amount);
+                    supply -= amount;
+                    burned += amount;
+
+                    env.events().publish(
+                        (
+                            Symbol::new(&env, "penalty_burn"),
+                            operator.clone(),
+                            from,
+                            reason,
+                        ),
+                        amount,
+                    );
+                    Ok(())
+                }
+            }
+            TokenOp::Transfer(from, to, amount) => {
+                if !authorized.contains(&from) {
+                    from.require_auth();
+                    authorized.push_back(from.clone());
+                }
+
+                let from_balance = get_balance(&env, &from);
+                if amount <= 0 {
+                    Err(TokenError::InvalidAmount as u32)
+                } else if paused {
+                    Err(TokenError::Paused as u32)
+                } else if from_balance < amount {
+                    Err(TokenError::InsufficientBalance as u32)
+                } else {
+                    set_balance(&env, &from, from_balance - amount);
+                    set_balance(&env, &to, get_balance(&env, &to) + amount);
+                    transferred += amount;
+
+                    env.events()
+                        .publish((Symbol::new(&env, "transfer"), from, to), amount);
+                    Ok(())
+                }
+            }
+        };
+
+        if let Err(code) = result {
+            return Err(op_failure(index as u32, code));
+        }
+    }
+
+    if minted != 0 || burned != 0 {
+        update_total_supply(&env, supply);
+    }
+
+    // Emit batch summary event
+    env.events().publish(
+        (Symbol::new(&env, "batch_exec"), operator),
+        (ops.len(), minted, burned, transferred),
+    );
+
+    Ok(())
+}
+
+/// Build the contract error reported for a failing operation
+pub fn op_failure(index: u32, code: u32) -> Error {
+    Error::from_contract_error(OP_FAILURE_BASE * (index + 1) + code)
+}
+
+/// Recover the failing operation index and its error code from a batch error
+pub fn decode_op_failure(error: Error) -> Option<(u32, u32)> {
+    let code = error.get_code();
+    if !error.is_type(soroban_sdk::xdr::ScErrorType::Contract) || code < OP_FAILURE_BASE {
+        return None;
+    }
+    Some((code / OP_FAILURE_BASE - 1, code % OP_FAILURE_BASE))
+}
@@ -1,14 +1,18 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Error, String, Symbol, Vec};
 
+mod batch;
 mod burn;
 mod mint;
 mod token;
+mod upgrade;
 mod utils;
 
+pub use batch::*;
 pub use burn::*;
 pub use mint::*;
 pub use token::*;
+pub use upgrade::*;
 pub use utils::*;
 
 #[contract]
@@ -160,6 +164,28 @@ impl FarmerTokenContract {
     ) -> Result<(), BurnError> {
         burn::burn_as_penalty(env, admin, from, amount, reason)
     }
+
+    /// Execute a batch of mint, burn and transfer operations atomically
+    /// On failure the error code encodes the index of the failing operation
+    pub fn execute_batch(env: Env, operator: Address, ops: Vec<TokenOp>) -> Result<(), Error> {
+        batch::execute_batch(env, operator, ops)
+    }
+
+    /// Replace the contract code with an uploaded WASM (admin only)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), UpgradeError> {
+        upgrade::upgrade(env, new_wasm_hash)
+    }
+
+    /// Bring stored data up to the current storage version after an upgrade
+    /// Returns the new version; fails if the data is already current
+    pub fn migrate(env: Env) -> Result<u32, UpgradeError> {
+        upgrade::migrate(env)
+    }
+
+    /// Get the storage version of the deployed data
+    pub fn storage_version(env: Env) -> Result<u32, UpgradeError> {
+        upgrade::get_storage_version(&env)
+    }
 }
 
 #[cfg(test)]
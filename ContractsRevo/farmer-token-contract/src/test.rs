@@ -1,9 +1,14 @@
 #![cfg(test)]
 
 use crate::{
-    AdminError, BurnError, FarmerTokenContract, FarmerTokenContractClient, MintError, TokenError,
+    decode_op_failure, AdminError, BatchError, BurnError, DataKey, FarmerTokenContract,
+    FarmerTokenContractClient, MintError, Minters, TokenError, TokenOp, UpgradeError,
+    CURRENT_STORAGE_VERSION, MAX_BATCH_OPS,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, BytesN, Env, IntoVal, Map, String, Symbol, Vec,
 };
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol, Vec};
 
 fn setup_test<'a>() -> (
     Env,
@@ -364,3 +369,182 @@ fn test_burn_as_penalty() {
     // Check balance
     assert_eq!(client.balance(&farmer1), mint_amount - penalty_amount);
 }
+
+#[test]
+fn test_execute_batch_mixed_ops() {
+    let (env, client, admin, farmer1, farmer2, _) = setup_test();
+
+    let reason = Symbol::new(&env, "violation");
+    client.mint(&admin, &farmer1, &1000_0000000i128);
+
+    let ops = vec![
+        &env,
+        TokenOp::Mint(farmer2.clone(), 500_0000000i128),
+        TokenOp::Transfer(farmer1.clone(), farmer2.clone(), 300_0000000i128),
+        TokenOp::Burn(farmer1.clone(), 100_0000000i128, reason),
+    ];
+
+    // Admin is a minter by default, so it can relay every op type
+    client.execute_batch(&admin, &ops);
+
+    assert_eq!(client.balance(&farmer1), 600_0000000i128);
+    assert_eq!(client.balance(&farmer2), 800_0000000i128);
+    assert_eq!(client.total_supply(), 1400_0000000i128);
+}
+
+#[test]
+fn test_execute_batch_rolls_back_on_failure() {
+    let (env, client, admin, farmer1, farmer2, _) = setup_test();
+
+    client.mint(&admin, &farmer1, &100_0000000i128);
+
+    let ops = vec![
+        &env,
+        TokenOp::Mint(farmer2.clone(), 500_0000000i128),
+        TokenOp::Transfer(farmer1.clone(), farmer2.clone(), 50_0000000i128),
+        TokenOp::Transfer(farmer1.clone(), farmer2.clone(), 80_0000000i128),
+    ];
+
+    let err = client.try_execute_batch(&admin, &ops).unwrap_err().unwrap();
+    assert_eq!(
+        decode_op_failure(err),
+        Some((2, TokenError::InsufficientBalance as u32))
+    );
+
+    // Nothing from the batch was applied
+    assert_eq!(client.balance(&farmer1), 100_0000000i128);
+    assert_eq!(client.balance(&farmer2), 0);
+    assert_eq!(client.total_supply(), 100_0000000i128);
+}
+
+#[test]
+fn test_execute_batch_enforces_roles() {
+    let (env, client, admin, farmer1, farmer2, minter) = setup_test();
+
+    client.add_minter(&admin, &minter);
+    client.mint(&admin, &farmer1, &100_0000000i128);
+
+    // A minter may mint but not burn
+    let ops = vec![
+        &env,
+        TokenOp::Mint(farmer2.clone(), 10_0000000i128),
+        TokenOp::Burn(farmer1.clone(), 10_0000000i128, Symbol::new(&env, "fraud")),
+    ];
+    let err = client
+        .try_execute_batch(&minter, &ops)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        decode_op_failure(err),
+        Some((1, BurnError::Unauthorized as u32))
+    );
+
+    // A non-minter may not mint
+    let ops = vec![&env, TokenOp::Mint(farmer2.clone(), 10_0000000i128)];
+    let err = client
+        .try_execute_batch(&farmer1, &ops)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        decode_op_failure(err),
+        Some((0, MintError::Unauthorized as u32))
+    );
+
+    // Anyone may relay transfers authorized by the sender
+    let ops = vec![
+        &env,
+        TokenOp::Transfer(farmer1.clone(), farmer2.clone(), 40_0000000i128),
+    ];
+    client.execute_batch(&minter, &ops);
+    assert_eq!(client.balance(&farmer2), 40_0000000i128);
+}
+
+#[test]
+fn test_execute_batch_size_limits() {
+    let (env, client, admin, farmer1, _, _) = setup_test();
+
+    let empty: Vec<TokenOp> = Vec::new(&env);
+    let result = client.try_execute_batch(&admin, &empty);
+    assert_eq!(result, Err(Ok(BatchError::EmptyBatch.into())));
+
+    let mut ops = Vec::new(&env);
+    for _ in 0..=MAX_BATCH_OPS {
+        ops.push_back(TokenOp::Mint(farmer1.clone(), 1));
+    }
+    let result = client.try_execute_batch(&admin, &ops);
+    assert_eq!(result, Err(Ok(BatchError::BatchTooLarge.into())));
+}
+
+#[test]
+fn test_migrate_v1_minters_layout() {
+    let (env, client, admin, _, farmer2, minter) = setup_test();
+    client.add_minter(&admin, &minter);
+    assert_eq!(client.storage_version(), CURRENT_STORAGE_VERSION);
+
+    // Rewrite storage the way a pre-versioning deployment left it
+    env.as_contract(&client.address, || {
+        let storage = env.storage();
+        storage.instance().remove(&DataKey::StorageVersion);
+        storage.persistent().remove(&DataKey::Minter(admin.clone()));
+        storage
+            .persistent()
+            .remove(&DataKey::Minter(minter.clone()));
+
+        let mut legacy: Minters = Map::new(&env);
+        legacy.set(admin.clone(), true);
+        legacy.set(minter.clone(), true);
+        legacy.set(farmer2.clone(), false);
+        storage.persistent().set(&DataKey::Minters, &legacy);
+    });
+    assert_eq!(client.storage_version(), 1);
+    assert!(!client.is_minter(&minter));
+
+    // After an upgrade the new code migrates the old layout
+    assert_eq!(client.migrate(), CURRENT_STORAGE_VERSION);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        vec![
+            &env,
+            Symbol::new(&env, "migrate").into_val(&env),
+            1u32.into_val(&env)
+        ]
+    );
+    let migrated_to: u32 = data.into_val(&env);
+    assert_eq!(migrated_to, CURRENT_STORAGE_VERSION);
+
+    assert_eq!(client.storage_version(), CURRENT_STORAGE_VERSION);
+    assert!(client.is_minter(&admin));
+    assert!(client.is_minter(&minter));
+    assert!(!client.is_minter(&farmer2));
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&DataKey::Minters));
+    });
+
+    // Migrated minters keep working and migrate only runs once
+    client.mint(&minter, &farmer2, &100);
+    assert_eq!(client.balance(&farmer2), 100);
+    assert_eq!(client.try_migrate(), Err(Ok(UpgradeError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_migrate_requires_initialized_contract() {
+    let env = Env::default();
+    let contract_id = env.register(FarmerTokenContract, ());
+    let client = FarmerTokenContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.try_migrate(), Err(Ok(UpgradeError::NotInitialized)));
+    assert_eq!(
+        client.try_upgrade(&BytesN::from_array(&env, &[1; 32])),
+        Err(Ok(UpgradeError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth() {
+    let (env, client, _, _, _, _) = setup_test();
+    env.set_auths(&[]);
+
+    let result = client.try_upgrade(&BytesN::from_array(&env, &[1; 32]));
+    assert!(result.is_err());
+}
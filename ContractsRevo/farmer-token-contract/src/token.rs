@@ -29,8 +29,10 @@ pub enum DataKey {
     Balance(Address),
     Allowance(Address, Address), // (owner, spender)
     TotalSupply,
-    Minters,
+    Minters, // v1 layout: a single map of every minter, split up by `migrate`
     Paused,
+    Minter(Address),
+    StorageVersion,
 }
 
 pub type Balances = Map<Address, i128>;
@@ -68,10 +70,13 @@ pub fn initialize(
     // Initialize total supply
     env.storage().instance().set(&DataKey::TotalSupply, &0i128);
 
-    // Initialize minters map and add admin as first minter
-    let mut minters: Minters = Map::new(&env);
-    minters.set(admin.clone(), true);
-    env.storage().persistent().set(&DataKey::Minters, &minters);
+    // Add admin as first minter
+    env.storage()
+        .persistent()
+        .set(&DataKey::Minter(admin.clone()), &true);
+
+    // Fresh deployments start on the current storage layout
+    crate::upgrade::set_storage_version(&env, crate::upgrade::CURRENT_STORAGE_VERSION);
 
     // Set paused state to false
     env.storage().instance().set(&DataKey::Paused, &false);
@@ -214,14 +219,14 @@ pub fn token_metadata(env: Env) -> TokenMetadata {
 
 // Internal helper functions
 
-fn get_balance(env: &Env, address: &Address) -> i128 {
+pub(crate) fn get_balance(env: &Env, address: &Address) -> i128 {
     env.storage()
         .persistent()
         .get(&DataKey::Balance(address.clone()))
         .unwrap_or(0)
 }
 
-fn set_balance(env: &Env, address: &Address, balance: i128) {
+pub(crate) fn set_balance(env: &Env, address: &Address, balance: i128) {
     if balance == 0 {
         env.storage()
             .persistent()
@@ -253,7 +258,7 @@ fn set_allowance(env: &Env, owner: &Address, spender: &Address, allowance: i128)
     }
 }
 
-fn is_paused(env: &Env) -> bool {
+pub(crate) fn is_paused(env: &Env) -> bool {
     env.storage()
         .instance()
         .get(&DataKey::Paused)
@@ -0,0 +1,99 @@
+use soroban_sdk::{contracterror, BytesN, Env, Symbol};
+
+use crate::{
+    token::{DataKey, Minters},
+    utils::get_admin,
+};
+
+/// Storage layout written by this build of the contract
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+
+/// Layout of contracts deployed before storage versions were tracked
+const LEGACY_STORAGE_VERSION: u32 = 1;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UpgradeError {
+    NotInitialized = 1,
+    AlreadyMigrated = 2,
+    UnsupportedVersion = 3,
+}
+
+/// Storage version of the deployed data; contracts initialized before versioning report 1
+pub fn get_storage_version(env: &Env) -> Result<u32, UpgradeError> {
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(UpgradeError::NotInitialized);
+    }
+
+    Ok(env
+        .storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(LEGACY_STORAGE_VERSION))
+}
+
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::StorageVersion, &version);
+}
+
+/// Replace the contract code (admin only). Call `migrate` afterwards to bring the
+/// stored data up to the layout the new code expects.
+pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), UpgradeError> {
+    let admin = get_admin(env.clone()).map_err(|_| UpgradeError::NotInitialized)?;
+    admin.require_auth();
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    env.events()
+        .publish((Symbol::new(&env, "upgrade"), admin), new_wasm_hash);
+
+    Ok(())
+}
+
+/// Run every migration step between the stored version and the current one.
+/// Each step is idempotent, so a partially applied migration can be retried.
+pub fn migrate(env: Env) -> Result<u32, UpgradeError> {
+    let from_version = get_storage_version(&env)?;
+    if from_version == CURRENT_STORAGE_VERSION {
+        return Err(UpgradeError::AlreadyMigrated);
+    }
+
+    let mut version = from_version;
+    while version < CURRENT_STORAGE_VERSION {
+        match version {
+            1 => migrate_minters_to_entries(&env),
+            _ => return Err(UpgradeError::UnsupportedVersion),
+        }
+        version += 1;
+        set_storage_version(&env, version);
+    }
+
+    if version != CURRENT_STORAGE_VERSION {
+        return Err(UpgradeError::UnsupportedVersion);
+    }
+
+    env.events()
+        .publish((Symbol::new(&env, "migrate"), from_version), version);
+
+    Ok(version)
+}
+
+/// v1 -> v2: split the single minters map into one entry per minter
+fn migrate_minters_to_entries(env: &Env) {
+    let legacy: Option<Minters> = env.storage().persistent().get(&DataKey::Minters);
+    let Some(minters) = legacy else {
+        return;
+    };
+
+    for (minter, enabled) in minters.iter() {
+        if enabled {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Minter(minter), &true);
+        }
+    }
+    env.storage().persistent().remove(&DataKey::Minters);
+}
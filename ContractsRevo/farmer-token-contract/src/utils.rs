@@ -1,6 +1,6 @@
-use soroban_sdk::{contracterror, Address, Env, Map, String, Symbol};
+use soroban_sdk::{contracterror, Address, Env, String, Symbol};
 
-use crate::token::{DataKey, Minters};
+use crate::token::DataKey;
 
 #[contracterror]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -23,13 +23,10 @@ pub fn get_admin(env: Env) -> Result<Address, AdminError> {
 
 /// Check if an address is a minter
 pub fn is_minter(env: Env, address: Address) -> bool {
-    let minters = env
-        .storage()
+    env.storage()
         .persistent()
-        .get::<_, Minters>(&DataKey::Minters)
-        .unwrap_or(Map::new(&env));
-
-    minters.get(address).unwrap_or(false)
+        .get(&DataKey::Minter(address))
+        .unwrap_or(false)
 }
 
 /// Add a new minter (admin only)
@@ -42,21 +39,15 @@ pub fn add_minter(env: Env, admin: Address, minter: Address) -> Result<(), Admin
         return Err(AdminError::Unauthorized);
     }
 
-    // Get current minters
-    let mut minters = env
-        .storage()
-        .persistent()
-        .get::<_, Minters>(&DataKey::Minters)
-        .unwrap_or(Map::new(&env));
-
     // Check if already a minter
-    if minters.get(minter.clone()).unwrap_or(false) {
+    if is_minter(env.clone(), minter.clone()) {
         return Err(AdminError::AlreadyMinter);
     }
 
     // Add new minter
-    minters.set(minter.clone(), true);
-    env.storage().persistent().set(&DataKey::Minters, &minters);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Minter(minter.clone()), &true);
 
     // Emit event
     env.events()
@@ -75,21 +66,15 @@ pub fn remove_minter(env: Env, admin: Address, minter: Address) -> Result<(), Ad
         return Err(AdminError::Unauthorized);
     }
 
-    // Get current minters
-    let mut minters = env
-        .storage()
-        .persistent()
-        .get::<_, Minters>(&DataKey::Minters)
-        .unwrap_or(Map::new(&env));
-
     // Check if not a minter
-    if !minters.get(minter.clone()).unwrap_or(false) {
+    if !is_minter(env.clone(), minter.clone()) {
         return Err(AdminError::NotMinter);
     }
 
     // Remove minter
-    minters.remove(minter.clone());
-    env.storage().persistent().set(&DataKey::Minters, &minters);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Minter(minter.clone()));
 
     // Emit event
     env.events()
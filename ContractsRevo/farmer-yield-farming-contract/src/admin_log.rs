@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{AdminTarget, ContractError, MAX_ADMIN_LOG, MAX_ADMIN_LOG_PAGE};
+use soroban_sdk::{symbol_short, Symbol};
+
+// ================================================================================
+// ADMIN ACTION LOG TESTS
+// ================================================================================
+
+#[test]
+fn test_admin_calls_are_logged_in_order() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_000);
+    ctx.client.set_farm_paused(&farm_id, &true);
+    ctx.client.set_global_multiplier(&200);
+
+    let page = ctx.client.get_admin_log(&0, &10);
+    assert_eq!(page.total, 5);
+    assert_eq!(page.entries.len(), 5);
+
+    let expected: [(Symbol, AdminTarget); 5] = [
+        (symbol_short!("init"), AdminTarget::Contract),
+        (symbol_short!("farm_new"), AdminTarget::Farm(farm_id)),
+        (
+            symbol_short!("dep_rwd"),
+            AdminTarget::Token(ctx.reward_token.clone()),
+        ),
+        (symbol_short!("farm_paus"), AdminTarget::Farm(farm_id)),
+        (symbol_short!("glob_mult"), AdminTarget::Contract),
+    ];
+    for (i, (action, target)) in expected.iter().enumerate() {
+        let entry = page.entries.get(i as u32).unwrap();
+        assert_eq!(entry.seq, i as u64);
+        assert_eq!(entry.action, *action);
+        assert_eq!(entry.target, *target);
+        assert_eq!(entry.caller, ctx.admin);
+    }
+    // Logged at the ledger time of the call; init ran before the ledger was set
+    assert_eq!(page.entries.get(0).unwrap().timestamp, 0);
+    assert_eq!(page.entries.get(4).unwrap().timestamp, 12345);
+
+    // Pages pick up where the previous one stopped
+    let page = ctx.client.get_admin_log(&3, &10);
+    assert_eq!(page.entries.len(), 2);
+    assert_eq!(page.entries.get(0).unwrap().seq, 3);
+}
+
+#[test]
+fn test_ring_buffer_evicts_oldest_entries() {
+    let ctx = setup_test();
+    ctx.client.initialize(&ctx.admin);
+    for i in 0..MAX_ADMIN_LOG + 4 {
+        ctx.client.set_emergency_withdraw(&(i % 2 == 0));
+    }
+
+    // The init entry and the first four toggles were overwritten
+    let page = ctx.client.get_admin_log(&0, &3);
+    assert_eq!(page.total, MAX_ADMIN_LOG + 5);
+    assert_eq!(page.entries.len(), 3);
+    assert_eq!(page.entries.get(0).unwrap().seq, 5);
+    assert_eq!(page.entries.get(2).unwrap().seq, 7);
+    assert_eq!(
+        page.entries.get(0).unwrap().action,
+        symbol_short!("emerg_cfg")
+    );
+
+    let last_page = ctx.client.get_admin_log(&(MAX_ADMIN_LOG as u32 - 2), &10);
+    assert_eq!(last_page.entries.len(), 2);
+    assert_eq!(last_page.entries.get(1).unwrap().seq, MAX_ADMIN_LOG + 4);
+
+    // Page size is capped and offsets past the end are empty
+    let capped = ctx.client.get_admin_log(&0, &1000);
+    assert_eq!(capped.entries.len(), MAX_ADMIN_LOG_PAGE);
+    let past_end = ctx.client.get_admin_log(&(MAX_ADMIN_LOG as u32), &10);
+    assert_eq!(past_end.entries.len(), 0);
+    assert_eq!(past_end.total, MAX_ADMIN_LOG + 5);
+}
+
+#[test]
+fn test_non_admin_and_failed_calls_not_logged() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    assert_eq!(ctx.client.get_admin_log(&0, &10).total, 3);
+
+    set_ledger_sequence(&ctx.env, 1200);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 5000);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &5000);
+    advance_ledger(&ctx.env, 10);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    ctx.client.update_pool(&farm_id);
+    ctx.client.unstake_lp(&ctx.farmer1, &farm_id, &1000);
+
+    // A rejected admin call rolls back with everything else it wrote
+    assert_eq!(
+        ctx.client.try_set_global_multiplier(&1),
+        Err(Ok(ContractError::InvalidMultiplier))
+    );
+
+    let page = ctx.client.get_admin_log(&0, &10);
+    assert_eq!(page.total, 3);
+    assert_eq!(page.entries.len(), 3);
+}
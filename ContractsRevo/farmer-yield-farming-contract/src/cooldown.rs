@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{ContractError, PendingWithdrawal, MAX_PENDING_WITHDRAWALS};
+use soroban_sdk::vec;
+
+// ================================================================================
+// UNSTAKE COOLDOWN TESTS
+// ================================================================================
+
+/// A funded farm with a 500 block unstake cooldown and farmer1 staked from ledger 1200
+fn setup_cooldown_stake(ctx: &TestContext) -> u32 {
+    let farm_id = setup_farm_with_rewards(ctx, 10_000_000_0000000);
+    ctx.client.set_farm_cooldown(&farm_id, &500);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    farm_id
+}
+
+#[test]
+fn test_unstake_requests_unlock_after_cooldown() {
+    let ctx = setup_test();
+    let farm_id = setup_cooldown_stake(&ctx);
+    advance_ledger(&ctx.env, 100);
+
+    assert_eq!(
+        ctx.client
+            .try_unstake_lp(&ctx.farmer1, &farm_id, &4_000_0000000),
+        Err(Ok(ContractError::CooldownRequired))
+    );
+    ctx.client
+        .request_unstake(&ctx.farmer1, &farm_id, &4_000_0000000);
+    assert_eq!(get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1), 0);
+    assert_eq!(ctx.client.get_farm(&farm_id).total_staked, 6_000_0000000);
+    assert_eq!(
+        ctx.client
+            .get_user_farm(&ctx.farmer1, &farm_id)
+            .unwrap()
+            .amount,
+        6_000_0000000
+    );
+
+    // Requests overlap, each with its own unlock block
+    advance_ledger(&ctx.env, 100);
+    ctx.client
+        .request_unstake(&ctx.farmer1, &farm_id, &6_000_0000000);
+    assert_eq!(
+        ctx.client.get_pending_withdrawals(&ctx.farmer1, &farm_id),
+        vec![
+            &ctx.env,
+            PendingWithdrawal {
+                amount: 4_000_0000000,
+                unlock_block: 1800,
+            },
+            PendingWithdrawal {
+                amount: 6_000_0000000,
+                unlock_block: 1900,
+            }
+        ]
+    );
+
+    // Requested amounts no longer earn rewards
+    assert!(ctx.client.get_user_farm(&ctx.farmer1, &farm_id).is_none());
+    advance_ledger(&ctx.env, 100);
+    assert_eq!(ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0, 0);
+    assert_eq!(
+        ctx.client.try_claim_unstaked(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::WithdrawalLocked))
+    );
+
+    set_ledger_sequence(&ctx.env, 1800);
+    assert_eq!(
+        ctx.client.claim_unstaked(&ctx.farmer1, &farm_id),
+        4_000_0000000
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        4_000_0000000
+    );
+    assert_eq!(
+        ctx.client
+            .get_pending_withdrawals(&ctx.farmer1, &farm_id)
+            .len(),
+        1
+    );
+
+    set_ledger_sequence(&ctx.env, 1900);
+    assert_eq!(
+        ctx.client.claim_unstaked(&ctx.farmer1, &farm_id),
+        6_000_0000000
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        10_000_0000000
+    );
+    assert!(ctx
+        .client
+        .get_pending_withdrawals(&ctx.farmer1, &farm_id)
+        .is_empty());
+}
+
+#[test]
+fn test_emergency_withdraw_drains_pending_withdrawals() {
+    let ctx = setup_test();
+    let farm_id = setup_cooldown_stake(&ctx);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 5_000_0000000);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &5_000_0000000);
+
+    advance_ledger(&ctx.env, 100);
+    ctx.client
+        .request_unstake(&ctx.farmer1, &farm_id, &4_000_0000000);
+    ctx.client
+        .request_unstake(&ctx.farmer2, &farm_id, &5_000_0000000);
+    ctx.client.set_emergency_withdraw(&true);
+
+    ctx.client.emergency_withdraw(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        10_000_0000000
+    );
+    assert!(ctx
+        .client
+        .get_pending_withdrawals(&ctx.farmer1, &farm_id)
+        .is_empty());
+
+    // A farmer with nothing left staked still gets their requests back
+    ctx.client.emergency_withdraw(&ctx.farmer2, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer2),
+        5_000_0000000
+    );
+    assert_eq!(ctx.client.get_farm(&farm_id).total_staked, 0);
+    assert_eq!(
+        ctx.client.try_emergency_withdraw(&ctx.farmer2, &farm_id),
+        Err(Ok(ContractError::NoStakeFound))
+    );
+}
+
+#[test]
+fn test_set_farm_cooldown() {
+    let ctx = setup_test();
+    let farm_id = setup_cooldown_stake(&ctx);
+    assert_eq!(ctx.client.get_farm_cooldown(&farm_id), 500);
+    assert_eq!(
+        ctx.client.try_set_farm_cooldown(&99, &500),
+        Err(Ok(ContractError::FarmNotFound))
+    );
+
+    // Only so many requests can wait at once
+    for _ in 0..MAX_PENDING_WITHDRAWALS {
+        ctx.client.request_unstake(&ctx.farmer1, &farm_id, &100);
+    }
+    assert_eq!(
+        ctx.client.try_request_unstake(&ctx.farmer1, &farm_id, &100),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    // Without a cooldown unstaking is immediate again
+    ctx.client.set_farm_cooldown(&farm_id, &0);
+    assert_eq!(ctx.client.get_farm_cooldown(&farm_id), 0);
+    ctx.client.unstake_lp(&ctx.farmer1, &farm_id, &100);
+    assert_eq!(get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1), 100);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address};
+use soroban_sdk::{contracterror, contracttype, Address, Symbol, Vec};
 
 #[derive(Clone)]
 #[contracttype]
@@ -11,6 +11,41 @@ pub enum DataKey {
     GlobalMultiplier,
     MinStakePeriod,
     EmergencyWithdraw,
+    StakeCheckpoints(Address),
+    TotalStakeCheckpoints,
+    GovernanceConfig,
+    ProposalCount,
+    Proposal(u32),
+    Vote(u32, Address),
+    // Last emitted event sequence number. Stored in instance storage under this
+    // variant name, which must stay stable across upgrades.
+    EventSequence,
+    AdminLog(u32), // Ring-buffer slot, seq % MAX_ADMIN_LOG
+    AdminLogTotal, // Admin actions ever logged, including evicted ones
+    // Referee -> referrer
+    Referral(Address),
+    ReferralConfig,
+    // (referrer, farm_id) -> referral bonus paid so far
+    ReferralBonusPaid(Address, u32),
+    FarmVesting(u32),
+    // (farmer, farm_id) -> harvested rewards still vesting
+    PendingVest(Address, u32),
+    // Reward streams paid alongside the farm's main reward token
+    ExtraRewards(u32),
+    // (farmer, farm_id) -> reward debt per extra reward stream, in the same order
+    ExtraRewardDebt(Address, u32),
+    // Set for every token some farm pays rewards in
+    RewardToken(Address),
+    // Blocks LP tokens stay locked after an unstake request
+    FarmCooldown(u32),
+    // (farmer, farm_id) -> unstake requests waiting out the cooldown
+    Withdrawal(Address, u32),
+    // Farms the farmer has an open position in
+    UserFarmIndex(Address),
+    // Set while every farm is paused at once
+    GlobalPause,
+    // Oldest proposal that may still be open for voting
+    OldestOpenProposal,
 }
 
 #[derive(Clone)]
@@ -38,6 +73,147 @@ pub struct UserFarm {
     pub last_harvest: u64,
 }
 
+/// Total staked amount (across all farms) as of a ledger sequence
+#[derive(Clone)]
+#[contracttype]
+pub struct StakeCheckpoint {
+    pub ledger: u32,
+    pub amount: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct FarmParams {
+    pub lp_token: Address,
+    pub reward_token: Address,
+    pub reward_per_block: i128,
+    pub multiplier: u32,
+    pub start_block: u64,
+    pub end_block: u64,
+}
+
+/// LP tokens taken out of a stake that can be claimed from `unlock_block` on
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PendingWithdrawal {
+    pub amount: i128,
+    pub unlock_block: u64,
+}
+
+/// An additional token a farm emits per block, accrued like the main reward
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExtraReward {
+    pub token: Address,
+    pub reward_per_block: i128,
+    pub acc_reward_per_share: i128,
+}
+
+/// Share of a referee's harvested rewards paid to their referrer, capped per referrer per farm
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReferralConfig {
+    pub bonus_bps: u32,
+    pub max_bonus_per_farm: i128,
+}
+
+/// Harvested rewards unlock linearly over `total_blocks` ledgers, with nothing
+/// unlocked before `cliff_blocks` have passed
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub cliff_blocks: u64,
+    pub total_blocks: u64,
+}
+
+/// Rewards vesting for one farmer in one farm since `start_block`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VestingPosition {
+    pub total: i128,
+    pub start_block: u64,
+    pub claimed: i128, // Part of `total` already transferred
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct GovernanceConfig {
+    pub min_proposal_stake: i128,
+    pub quorum_bps: u32,    // Share of snapshotted stake that must vote
+    pub approval_bps: u32,  // Share of cast votes that must be in favour
+    pub voting_period: u32, // In ledgers
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ProposalStatus {
+    Active,
+    Executed,
+    Vetoed,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct FarmProposal {
+    pub id: u32,
+    pub proposer: Address,
+    pub params: FarmParams,
+    pub snapshot_ledger: u32,
+    pub deadline: u32,
+    pub total_stake: i128,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub status: ProposalStatus,
+    pub farm_id: Option<u32>,
+}
+
+/// Entries whose TTLs `bump_entries` should extend
+#[derive(Clone)]
+#[contracttype]
+pub struct EntryKeys {
+    pub farms: Vec<u32>,
+    pub user_farms: Vec<(Address, u32)>,
+    pub proposals: Vec<u32>,
+}
+
+/// Read-only snapshot of contract-wide state for monitoring.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ContractInfo {
+    pub admin: Option<Address>,
+    pub farm_count: u32,
+    pub emergency_withdraw: bool,
+    pub global_multiplier: u32,
+}
+
+/// What an admin action applied to
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum AdminTarget {
+    Contract,
+    Farm(u32),
+    Proposal(u32),
+    Token(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdminActionLog {
+    pub seq: u64, // Position in the full log; gaps against an archive mean evicted entries
+    pub action: Symbol,
+    pub caller: Address,
+    pub target: AdminTarget,
+    pub timestamp: u64,
+}
+
+/// One page of the admin log, oldest retained entry first
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdminLogPage {
+    pub entries: Vec<AdminActionLog>,
+    pub total: u64, // Admin actions ever logged; only the last MAX_ADMIN_LOG are retained
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum FarmerTier {
@@ -47,6 +223,7 @@ pub enum FarmerTier {
 }
 
 #[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ContractError {
     AlreadyInitialized = 1,
     InvalidParameters = 2,
@@ -63,10 +240,48 @@ pub enum ContractError {
     InsufficientBalance = 13,
     InvalidMultiplier = 14,
     NotInitialized = 15,
+    ProposalNotFound = 16,
+    ProposalNotActive = 17,
+    VotingClosed = 18,
+    VotingNotEnded = 19,
+    AlreadyVoted = 20,
+    NoVotingPower = 21,
+    QuorumNotReached = 22,
+    ProposalRejected = 23,
+    InsufficientProposalStake = 24,
+    EntryArchived = 25,
+    InvalidReferral = 26,
+    Unauthorized = 27,
+    RewardTokenNotRegistered = 28,
+    IncompatibleFarms = 29,
+    CooldownRequired = 30,
+    WithdrawalLocked = 31,
+    MathOverflow = 32,
+    ContractPaused = 33,
+    GovernanceEnabled = 34,
 }
 
+pub const CONTRACT_VERSION: (u32, u32, u32) = (1, 0, 0);
 pub const PRECISION: i128 = 1_000_000_000_000;
 pub const MIN_STAKE_AMOUNT: i128 = 100;
+// Most farms a single batch_harvest call may touch
+pub const MAX_BATCH_HARVEST: u32 = 20;
+// Most reward tokens a farm may emit on top of its main one
+pub const MAX_EXTRA_REWARDS: u32 = 4;
+// Most unstake requests one farmer may have waiting in a farm
+pub const MAX_PENDING_WITHDRAWALS: u32 = 10;
 pub const COOLDOWN_PERIOD: u64 = 86400;
 pub const MAX_MULTIPLIER: u32 = 500;
 pub const BASE_MULTIPLIER: u32 = 100;
+pub const MAX_BPS: u32 = 10_000;
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+pub const YEAR_IN_LEDGERS: u32 = 365 * DAY_IN_LEDGERS;
+// Entries touched with fewer than 15 days to live are extended back to 30 days
+pub const ENTRY_LIFETIME_THRESHOLD: u32 = 15 * DAY_IN_LEDGERS;
+pub const ENTRY_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const DEFAULT_MIN_PROPOSAL_STAKE: i128 = 1_000;
+pub const DEFAULT_QUORUM_BPS: u32 = 2_000;
+pub const DEFAULT_APPROVAL_BPS: u32 = 5_000;
+pub const DEFAULT_VOTING_PERIOD: u32 = 17_280; // ~1 day of ledgers
+pub const MAX_ADMIN_LOG: u64 = 500;
+pub const MAX_ADMIN_LOG_PAGE: u32 = 100;
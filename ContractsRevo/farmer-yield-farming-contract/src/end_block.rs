@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::ContractError;
+
+// ================================================================================
+// FARM END AND REWARD MATH TESTS
+// ================================================================================
+
+/// A funded farm emitting from ledger 1100 until 2000
+fn setup_short_farm(ctx: &TestContext, reward_amount: i128) -> u32 {
+    ctx.client.initialize(&ctx.admin);
+    set_ledger_sequence(&ctx.env, 1000);
+    let farm_id = ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &2000,
+    );
+    mint_reward_tokens(&ctx.env, &ctx.reward_token, &ctx.admin, reward_amount);
+    ctx.client
+        .deposit_rewards(&ctx.reward_token, &reward_amount);
+    farm_id
+}
+
+#[test]
+fn test_rewards_stop_accruing_after_end_block() {
+    let ctx = setup_test();
+    let farm_id = setup_short_farm(&ctx, 10_000_000_0000000);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 20_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+
+    set_ledger_sequence(&ctx.env, 1900);
+    let (before_end, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    set_ledger_sequence(&ctx.env, 2000);
+    let (at_end, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert!(at_end > before_end);
+
+    // Nothing accrues past the end, whether or not the pool is updated
+    set_ledger_sequence(&ctx.env, 2500);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0,
+        at_end
+    );
+    ctx.client.update_pool(&farm_id);
+    assert_eq!(ctx.client.get_farm(&farm_id).last_reward_block, 2000);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0,
+        at_end
+    );
+    assert_eq!(
+        ctx.client
+            .try_stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000),
+        Err(Ok(ContractError::FarmNotActive))
+    );
+    assert_eq!(ctx.client.get_farm_apr(&farm_id), 0);
+
+    set_ledger_sequence(&ctx.env, 3000);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        at_end
+    );
+    assert_eq!(
+        ctx.client.try_harvest(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::NoRewards))
+    );
+
+    // The stake itself can still be withdrawn
+    ctx.client
+        .unstake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        20_000_0000000
+    );
+}
+
+#[test]
+fn test_lowering_reward_rate_mid_stream() {
+    let ctx = setup_test();
+    let farm_id = setup_short_farm(&ctx, 10_000_000_0000000);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+
+    set_ledger_sequence(&ctx.env, 1400);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &10_000_0000000);
+    let (first, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+
+    // Rewards earned at the old rate are kept, later blocks pay the new one
+    ctx.client.update_farm(&farm_id, &1_0000000, &150);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0,
+        first
+    );
+    assert_eq!(ctx.client.get_pending_rewards(&ctx.farmer2, &farm_id).0, 0);
+
+    set_ledger_sequence(&ctx.env, 1500);
+    let (later, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let (joined, _, _) = ctx.client.get_pending_rewards(&ctx.farmer2, &farm_id);
+    assert!(later > first);
+    assert_eq!(later - first, joined);
+
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer2, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        later
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2),
+        joined
+    );
+}
+
+#[test]
+fn test_harvests_never_exceed_deposited_rewards() {
+    let ctx = setup_test();
+    let deposited = 100_000_0000000;
+    let farm_id = setup_short_farm(&ctx, deposited);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 500_0000000);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 500_0000000);
+    set_ledger_sequence(&ctx.env, 1100);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &500_0000000);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &500_0000000);
+
+    // The farm emits more than was deposited, with tier bonuses on top
+    set_ledger_sequence(&ctx.env, 3000);
+    let (owed, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert!(owed * 2 > deposited);
+
+    // The second harvest fails rather than dropping what the farm cannot cover
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client.try_harvest(&ctx.farmer2, &farm_id),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    let paid = get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1)
+        + get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2);
+    assert!(paid <= deposited);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.client.address),
+        deposited - paid
+    );
+
+    // Farmer2's rewards stay owed and are paid once the farm is topped up
+    let (still_owed, _, _) = ctx.client.get_pending_rewards(&ctx.farmer2, &farm_id);
+    assert_eq!(still_owed, owed);
+    mint_reward_tokens(&ctx.env, &ctx.reward_token, &ctx.admin, owed);
+    ctx.client.deposit_rewards(&ctx.reward_token, &owed);
+    ctx.client.harvest(&ctx.farmer2, &farm_id);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2), owed);
+}
+
+#[test]
+fn test_get_farm_apr() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    assert_eq!(ctx.client.get_farm_apr(&farm_id), 0);
+    assert_eq!(
+        ctx.client.try_get_farm_apr(&99),
+        Err(Ok(ContractError::FarmNotFound))
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 20_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+
+    // 150 tokens a block for a year, against 10,000 staked
+    assert_eq!(ctx.client.get_farm_apr(&farm_id), 946_080_000);
+    ctx.client.set_global_multiplier(&200);
+    assert_eq!(ctx.client.get_farm_apr(&farm_id), 1_892_160_000);
+
+    // Twice the stake halves the rate
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    assert_eq!(ctx.client.get_farm_apr(&farm_id), 946_080_000);
+}
+
+#[test]
+fn test_reward_overflow_returns_error() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    ctx.client.update_farm(&farm_id, &(i128::MAX / 2), &150);
+
+    advance_ledger(&ctx.env, 100);
+    assert_eq!(
+        ctx.client.try_update_pool(&farm_id),
+        Err(Ok(ContractError::MathOverflow))
+    );
+    assert_eq!(
+        ctx.client.try_harvest(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::MathOverflow))
+    );
+    assert_eq!(
+        ctx.client
+            .try_unstake_lp(&ctx.farmer1, &farm_id, &10_000_0000000),
+        Err(Ok(ContractError::MathOverflow))
+    );
+
+    // Emergency withdrawals skip reward math, so the stake is never stuck
+    ctx.client.set_emergency_withdraw(&true);
+    ctx.client.emergency_withdraw(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        10_000_0000000
+    );
+}
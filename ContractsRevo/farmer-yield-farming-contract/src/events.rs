@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use super::utils::*;
+use soroban_sdk::{
+    testutils::{Events, Ledger},
+    vec, Address, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+// ================================================================================
+// EVENT SEQUENCE TESTS
+// ================================================================================
+
+fn contract_events(ctx: &TestContext) -> Vec<(Address, Vec<Val>, Val)> {
+    let mut events = Vec::new(&ctx.env);
+    for event in ctx.env.events().all().iter() {
+        if event.0 == ctx.client.address {
+            events.push_back(event);
+        }
+    }
+    events
+}
+
+fn last_event_sequence(ctx: &TestContext) -> u64 {
+    let (_, topics, _) = contract_events(ctx).last().unwrap();
+    u64::try_from_val(&ctx.env, &topics.get(0).unwrap()).unwrap()
+}
+
+#[test]
+fn test_event_sequence_increments_across_operations() {
+    let ctx = setup_test();
+    ctx.client.initialize(&ctx.admin);
+    assert_eq!(ctx.client.get_event_sequence(), 0);
+
+    set_ledger_sequence(&ctx.env, 1000);
+    let farm_id = ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    );
+    assert_eq!(last_event_sequence(&ctx), 1);
+
+    ctx.client.set_farm_paused(&farm_id, &true);
+    assert_eq!(last_event_sequence(&ctx), 2);
+    ctx.client.set_farm_paused(&farm_id, &false);
+
+    // Fund the rewards without a deposit event
+    mint_reward_tokens(
+        &ctx.env,
+        &ctx.reward_token,
+        &ctx.client.address,
+        10_000_000_0000000,
+    );
+    set_ledger_sequence(&ctx.env, 1200);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 5000);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &5000);
+    assert_eq!(last_event_sequence(&ctx), 4);
+
+    // Unstaking emits a harvest event before the unstake event
+    ctx.env.ledger().with_mut(|li| li.sequence_number += 10);
+    ctx.client.unstake_lp(&ctx.farmer1, &farm_id, &1000);
+    assert_eq!(contract_events(&ctx).len(), 2);
+    assert_eq!(last_event_sequence(&ctx), 6);
+
+    // The event name follows the sequence number
+    let (_, topics, _) = contract_events(&ctx).last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap(),
+        Symbol::new(&ctx.env, "unstake")
+    );
+    assert_eq!(ctx.client.get_event_sequence(), 6);
+}
+
+#[test]
+fn test_event_sequence_continues_from_stored_key() {
+    let ctx = setup_test();
+    ctx.client.initialize(&ctx.admin);
+
+    // State written by an earlier contract version under the documented key
+    ctx.env.as_contract(&ctx.client.address, || {
+        let key: Vec<Val> = vec![
+            &ctx.env,
+            Symbol::new(&ctx.env, "EventSequence").into_val(&ctx.env),
+        ];
+        ctx.env.storage().instance().set(&key, &41u64);
+    });
+    assert_eq!(ctx.client.get_event_sequence(), 41);
+
+    set_ledger_sequence(&ctx.env, 1000);
+    ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    );
+    assert_eq!(last_event_sequence(&ctx), 42);
+    assert_eq!(ctx.client.get_event_sequence(), 42);
+}
@@ -137,7 +137,7 @@ fn test_farm_with_same_lp_and_reward_token() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -154,11 +154,35 @@ fn test_set_global_multiplier() {
     set_ledger_sequence(&ctx.env, 1000);
 }
 
+#[test]
+fn test_contract_info_reflects_state() {
+    let ctx = setup_test();
+
+    let info = ctx.client.get_contract_info();
+    assert_eq!(info.admin, None);
+    assert_eq!(info.farm_count, 0);
+
+    ctx.client.initialize(&ctx.admin);
+    set_ledger_sequence(&ctx.env, 1000);
+    ctx.client.create_farm(&ctx.lp_token, &ctx.reward_token, &100_0000000, &150, &1100, &100000);
+    ctx.client.set_global_multiplier(&200);
+    ctx.client.set_emergency_withdraw(&true);
+
+    let info = ctx.client.get_contract_info();
+    assert_eq!(info.admin, Some(ctx.admin.clone()));
+    assert_eq!(info.farm_count, 1);
+    assert!(info.emergency_withdraw);
+    assert_eq!(info.global_multiplier, 200);
+    assert_eq!(ctx.client.version(), (1, 0, 0));
+}
+
 #[test]
 fn test_deposit_rewards() {
     let ctx = setup_test();
 
     ctx.client.initialize(&ctx.admin);
+    set_ledger_sequence(&ctx.env, 1000);
+    ctx.client.create_farm(&ctx.lp_token, &ctx.reward_token, &100_0000000, &150, &1100, &100000);
 
     mint_reward_tokens(&ctx.env, &ctx.reward_token, &ctx.admin, 1_000_000_0000000);
 
@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::ContractError;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Symbol, TryFromVal,
+};
+
+// ================================================================================
+// GLOBAL PAUSE TESTS
+// ================================================================================
+
+fn last_event_name(ctx: &TestContext) -> Symbol {
+    let (_, topics, _) = ctx.env.events().all().last().unwrap();
+    Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap()
+}
+
+#[test]
+fn test_pause_all_blocks_every_farm() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    assert!(!ctx.client.is_globally_paused());
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &5_000_0000000);
+    advance_ledger(&ctx.env, 100);
+
+    ctx.client.pause_all(&ctx.admin);
+    assert_eq!(last_event_name(&ctx), Symbol::new(&ctx.env, "global_pause"));
+    assert!(ctx.client.is_globally_paused());
+
+    assert_eq!(
+        ctx.client
+            .try_stake_lp(&ctx.farmer1, &farm_id, &1_000_0000000),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    assert_eq!(
+        ctx.client
+            .try_unstake_lp(&ctx.farmer1, &farm_id, &1_000_0000000),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    assert_eq!(
+        ctx.client.try_harvest(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    assert_eq!(
+        ctx.client
+            .try_batch_harvest(&ctx.farmer1, &vec![&ctx.env, farm_id]),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    ctx.client.set_emergency_withdraw(&true);
+    assert_eq!(
+        ctx.client.try_emergency_withdraw(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::ContractPaused))
+    );
+
+    // Resuming restores every operation, with rewards accrued while paused
+    ctx.client.resume_all(&ctx.admin);
+    assert_eq!(
+        last_event_name(&ctx),
+        Symbol::new(&ctx.env, "global_resume")
+    );
+    assert!(!ctx.client.is_globally_paused());
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1) > 0);
+    ctx.client.emergency_withdraw(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        10_000_0000000
+    );
+}
+
+#[test]
+fn test_global_pause_is_independent_of_farm_pause() {
+    let ctx = setup_test();
+    let paused_farm = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let open_farm = ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.set_farm_paused(&paused_farm, &true);
+
+    // The global pause takes precedence over the farm's own
+    ctx.client.pause_all(&ctx.admin);
+    assert_eq!(
+        ctx.client
+            .try_stake_lp(&ctx.farmer1, &paused_farm, &1_000_0000000),
+        Err(Ok(ContractError::ContractPaused))
+    );
+
+    // Resuming everything leaves the paused farm paused
+    ctx.client.resume_all(&ctx.admin);
+    assert_eq!(
+        ctx.client
+            .try_stake_lp(&ctx.farmer1, &paused_farm, &1_000_0000000),
+        Err(Ok(ContractError::FarmPaused))
+    );
+    ctx.client
+        .stake_lp(&ctx.farmer1, &open_farm, &1_000_0000000);
+
+    ctx.client.set_farm_paused(&paused_farm, &false);
+    ctx.client
+        .stake_lp(&ctx.farmer1, &paused_farm, &1_000_0000000);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 2);
+}
+
+#[test]
+fn test_pause_all_requires_admin() {
+    let ctx = setup_test();
+    setup_farm_with_rewards(&ctx, 0);
+    let stranger = Address::generate(&ctx.env);
+
+    assert_eq!(
+        ctx.client.try_pause_all(&stranger),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(!ctx.client.is_globally_paused());
+
+    ctx.client.pause_all(&ctx.admin);
+    assert_eq!(
+        ctx.client.try_resume_all(&stranger),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(ctx.client.is_globally_paused());
+}
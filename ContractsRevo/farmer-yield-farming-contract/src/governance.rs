@@ -0,0 +1,275 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{ContractError, DataKey, FarmParams, ProposalStatus, StakeCheckpoint};
+use soroban_sdk::{testutils::Address as _, Address, Vec};
+
+// ================================================================================
+// GOVERNANCE TEST HELPERS
+// ================================================================================
+
+const VOTING_PERIOD: u32 = 100;
+
+/// Bootstraps an admin farm with farmer1 staking 3000 and farmer2 staking 1000,
+/// and requires 50% quorum with a simple majority.
+fn setup_governance(ctx: &TestContext) -> u32 {
+    let farm_id = setup_farm_with_rewards(ctx, 10_000_000_0000000);
+    ctx.client
+        .set_governance_config(&1000, &5000, &5000, &VOTING_PERIOD);
+
+    set_ledger_sequence(&ctx.env, 1100);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 3000);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 1000);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &3000);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &1000);
+    advance_ledger(&ctx.env, 1);
+
+    farm_id
+}
+
+fn farm_params(ctx: &TestContext) -> FarmParams {
+    FarmParams {
+        lp_token: ctx.lp_token.clone(),
+        reward_token: ctx.reward_token.clone(),
+        reward_per_block: 50_0000000,
+        multiplier: 200,
+        start_block: 5000,
+        end_block: 100000,
+    }
+}
+
+// ================================================================================
+// PROPOSAL TESTS
+// ================================================================================
+
+#[test]
+fn test_propose_farm_requires_minimum_stake() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+
+    // farmer2 holds 1000, exactly the minimum
+    assert_eq!(ctx.client.propose_farm(&ctx.farmer2, &farm_params(&ctx)), 0);
+
+    let outsider = Address::generate(&ctx.env);
+    assert_eq!(
+        ctx.client.try_propose_farm(&outsider, &farm_params(&ctx)),
+        Err(Ok(ContractError::InsufficientProposalStake))
+    );
+}
+
+#[test]
+fn test_vote_weight_uses_snapshot_at_proposal_creation() {
+    let ctx = setup_test();
+    let farm_id = setup_governance(&ctx);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer1, &farm_params(&ctx));
+
+    // Stake added after the proposal does not count
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 10_000);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &10_000);
+    let late_staker = Address::generate(&ctx.env);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &late_staker, 5000);
+    ctx.client.stake_lp(&late_staker, &farm_id, &5000);
+
+    // Withdrawing after the proposal does not reduce voting power either
+    ctx.client.unstake_lp(&ctx.farmer1, &farm_id, &2000);
+
+    assert_eq!(
+        ctx.client
+            .vote_on_proposal(&ctx.farmer2, &proposal_id, &false),
+        1000
+    );
+    assert_eq!(
+        ctx.client
+            .vote_on_proposal(&ctx.farmer1, &proposal_id, &true),
+        3000
+    );
+    assert_eq!(
+        ctx.client
+            .try_vote_on_proposal(&late_staker, &proposal_id, &true),
+        Err(Ok(ContractError::NoVotingPower))
+    );
+    assert_eq!(
+        ctx.client
+            .try_vote_on_proposal(&ctx.farmer2, &proposal_id, &true),
+        Err(Ok(ContractError::AlreadyVoted))
+    );
+
+    let proposal = ctx.client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.total_stake, 4000);
+    assert_eq!(proposal.for_votes, 3000);
+    assert_eq!(proposal.against_votes, 1000);
+}
+
+#[test]
+fn test_execute_fails_without_quorum() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer1, &farm_params(&ctx));
+
+    // 1000 of 4000 snapshotted stake votes, short of the 50% quorum
+    ctx.client
+        .vote_on_proposal(&ctx.farmer2, &proposal_id, &true);
+    assert_eq!(
+        ctx.client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::VotingNotEnded))
+    );
+
+    advance_ledger(&ctx.env, VOTING_PERIOD);
+    assert_eq!(
+        ctx.client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::QuorumNotReached))
+    );
+    assert_eq!(
+        ctx.client
+            .try_vote_on_proposal(&ctx.farmer1, &proposal_id, &true),
+        Err(Ok(ContractError::VotingClosed))
+    );
+    assert_eq!(ctx.client.get_farm_count(), 1);
+}
+
+#[test]
+fn test_execute_fails_when_rejected() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer2, &farm_params(&ctx));
+
+    ctx.client
+        .vote_on_proposal(&ctx.farmer1, &proposal_id, &false);
+    ctx.client
+        .vote_on_proposal(&ctx.farmer2, &proposal_id, &true);
+    advance_ledger(&ctx.env, VOTING_PERIOD);
+
+    assert_eq!(
+        ctx.client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::ProposalRejected))
+    );
+}
+
+#[test]
+fn test_executed_proposal_creates_farm() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+    let params = farm_params(&ctx);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer1, &params);
+
+    ctx.client
+        .vote_on_proposal(&ctx.farmer1, &proposal_id, &true);
+    advance_ledger(&ctx.env, VOTING_PERIOD);
+
+    let farm_id = ctx.client.execute_proposal(&proposal_id);
+    assert_eq!(farm_id, 1);
+    assert_eq!(ctx.client.get_farm_count(), 2);
+
+    let farm = ctx.client.get_farm(&farm_id);
+    assert_eq!(farm.reward_per_block, params.reward_per_block);
+    assert_eq!(farm.multiplier, params.multiplier);
+    assert_eq!(farm.start_block, params.start_block);
+    assert_eq!(farm.end_block, params.end_block);
+    assert!(farm.is_active);
+
+    let proposal = ctx.client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.farm_id, Some(farm_id));
+    assert_eq!(
+        ctx.client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::ProposalNotActive))
+    );
+
+    // The new farm accepts stakes once it starts
+    set_ledger_sequence(&ctx.env, 5000);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 500);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &500);
+    assert_eq!(ctx.client.get_farm(&farm_id).total_staked, 500);
+}
+
+#[test]
+fn test_admin_cannot_create_farms_once_governance_is_configured() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+    let params = farm_params(&ctx);
+
+    assert_eq!(
+        ctx.client.try_create_farm(
+            &params.lp_token,
+            &params.reward_token,
+            &params.reward_per_block,
+            &params.multiplier,
+            &params.start_block,
+            &params.end_block,
+        ),
+        Err(Ok(ContractError::GovernanceEnabled))
+    );
+    assert_eq!(ctx.client.get_farm_count(), 1);
+}
+
+#[test]
+fn test_admin_veto_blocks_proposal() {
+    let ctx = setup_test();
+    setup_governance(&ctx);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer1, &farm_params(&ctx));
+    ctx.client
+        .vote_on_proposal(&ctx.farmer1, &proposal_id, &true);
+
+    ctx.client.veto_proposal(&proposal_id);
+    assert_eq!(
+        ctx.client.get_proposal(&proposal_id).unwrap().status,
+        ProposalStatus::Vetoed
+    );
+
+    assert_eq!(
+        ctx.client
+            .try_vote_on_proposal(&ctx.farmer2, &proposal_id, &true),
+        Err(Ok(ContractError::ProposalNotActive))
+    );
+    advance_ledger(&ctx.env, VOTING_PERIOD);
+    assert_eq!(
+        ctx.client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::ProposalNotActive))
+    );
+    assert_eq!(ctx.client.get_farm_count(), 1);
+}
+
+#[test]
+fn test_stake_history_is_pruned_outside_open_votes() {
+    let ctx = setup_test();
+    let farm_id = setup_governance(&ctx);
+    let checkpoints = |farmer: &Address| {
+        ctx.env.as_contract(&ctx.client.address, || {
+            ctx.env
+                .storage()
+                .persistent()
+                .get::<_, Vec<StakeCheckpoint>>(&DataKey::StakeCheckpoints(farmer.clone()))
+                .unwrap()
+                .len()
+        })
+    };
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000);
+
+    // Without an open proposal only the stake before this ledger and the latest are kept
+    for _ in 0..5 {
+        advance_ledger(&ctx.env, 1);
+        ctx.client.stake_lp(&ctx.farmer1, &farm_id, &100);
+    }
+    assert_eq!(checkpoints(&ctx.farmer1), 2);
+    assert_eq!(ctx.client.get_total_user_stake(&ctx.farmer1), 3500);
+
+    // An open proposal keeps the stake at its snapshot readable
+    advance_ledger(&ctx.env, 1);
+    let proposal_id = ctx.client.propose_farm(&ctx.farmer1, &farm_params(&ctx));
+    for _ in 0..5 {
+        advance_ledger(&ctx.env, 1);
+        ctx.client.stake_lp(&ctx.farmer1, &farm_id, &100);
+    }
+    assert_eq!(checkpoints(&ctx.farmer1), 6);
+    assert_eq!(
+        ctx.client
+            .vote_on_proposal(&ctx.farmer1, &proposal_id, &true),
+        3500
+    );
+
+    // Once voting closes the history collapses again
+    advance_ledger(&ctx.env, VOTING_PERIOD);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &100);
+    assert_eq!(checkpoints(&ctx.farmer1), 2);
+    assert_eq!(ctx.client.get_total_user_stake(&ctx.farmer1), 4100);
+}
@@ -1,5 +1,8 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, token, Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, events::Topics, panic_with_error, symbol_short, token, vec, Address,
+    Env, IntoVal, Symbol, Val, Vec,
+};
 mod datatype;
 use crate::datatype::*;
 
@@ -20,10 +23,13 @@ impl FarmerYieldFarmingContract {
         env.storage().instance().set(&DataKey::MinStakePeriod, &COOLDOWN_PERIOD);
         env.storage().instance().set(&DataKey::EmergencyWithdraw, &false);
         env.storage().instance().extend_ttl(1000000, 1000000);
+        Self::log_admin_action(&env, symbol_short!("init"), &admin, AdminTarget::Contract);
         Ok(true)
     }
 
     // ========== FARM MANAGEMENT ==========
+    /// Bootstraps farms before governance is configured; afterwards new farms are only
+    /// created through `execute_proposal`.
     pub fn create_farm(
         env: Env,
         lp_token: Address,
@@ -35,13 +41,55 @@ impl FarmerYieldFarmingContract {
     ) -> Result<u32, ContractError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        if env.storage().instance().has(&DataKey::GovernanceConfig) {
+            return Err(ContractError::GovernanceEnabled);
+        }
+
+        let farm_id = Self::create_farm_internal(
+            &env,
+            FarmParams {
+                lp_token,
+                reward_token,
+                reward_per_block,
+                multiplier,
+                start_block,
+                end_block,
+            },
+        )?;
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_new"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
+        Ok(farm_id)
+    }
 
-        if reward_per_block <= 0 || multiplier < BASE_MULTIPLIER || multiplier > MAX_MULTIPLIER {
+    fn validate_farm_params(env: &Env, params: &FarmParams) -> Result<(), ContractError> {
+        if params.reward_per_block <= 0
+            || params.multiplier < BASE_MULTIPLIER
+            || params.multiplier > MAX_MULTIPLIER
+        {
             return Err(ContractError::InvalidParameters);
         }
-        if start_block >= end_block || start_block < env.ledger().sequence() as u64 {
+        if params.start_block >= params.end_block
+            || params.start_block < env.ledger().sequence() as u64
+        {
             return Err(ContractError::InvalidBlockRange);
         }
+        Ok(())
+    }
+
+    fn create_farm_internal(env: &Env, params: FarmParams) -> Result<u32, ContractError> {
+        Self::validate_farm_params(env, &params)?;
+        let FarmParams {
+            lp_token,
+            reward_token,
+            reward_per_block,
+            multiplier,
+            start_block,
+            end_block,
+        } = params;
 
         let farm_id: u32 = env
             .storage()
@@ -70,8 +118,11 @@ impl FarmerYieldFarmingContract {
         env.storage()
             .instance()
             .set(&DataKey::FarmCount, &(farm_id + 1));
+        Self::extend_farm_ttl(env, farm_id);
+        Self::register_reward_token(env, &reward_token);
 
-        env.events().publish(
+        Self::publish_event(
+            env,
             (soroban_sdk::symbol_short!("farm_new"),),
             (farm_id, lp_token, reward_token),
         );
@@ -82,12 +133,10 @@ impl FarmerYieldFarmingContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        let mut farm: FarmPool = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
-        Self::update_pool_internal(&env, farm_id);
+        Self::load_farm(&env, farm_id).unwrap_or_else(|e| panic_with_error!(&env, e));
+        Self::update_pool_internal(&env, farm_id).unwrap_or_else(|e| panic_with_error!(&env, e));
+        let mut farm =
+            Self::load_farm(&env, farm_id).unwrap_or_else(|e| panic_with_error!(&env, e));
 
         if reward_per_block > 0 {
             farm.reward_per_block = reward_per_block;
@@ -99,10 +148,17 @@ impl FarmerYieldFarmingContract {
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
-        env.events().publish(
+        Self::publish_event(
+            &env,
             (soroban_sdk::symbol_short!("farm_upd"),),
             (farm_id, reward_per_block, multiplier),
         );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_upd"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
     }
 
     pub fn set_farm_paused(env: Env, farm_id: u32, paused: bool) {
@@ -111,28 +167,181 @@ impl FarmerYieldFarmingContract {
         env.storage()
             .persistent()
             .set(&DataKey::Paused(farm_id), &paused);
-        env.events().publish(
+        Self::publish_event(
+            &env,
             (soroban_sdk::symbol_short!("farm_paus"),),
             (farm_id, paused),
         );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_paus"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
     }
 
     pub fn end_farm(env: Env, farm_id: u32) {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        let mut farm: FarmPool = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
+        let mut farm =
+            Self::load_farm(&env, farm_id).unwrap_or_else(|e| panic_with_error!(&env, e));
         farm.end_block = env.ledger().sequence() as u64;
         farm.is_active = false;
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
-        env.events()
-            .publish((soroban_sdk::symbol_short!("farm_end"),), farm_id);
+        Self::publish_event(&env, (soroban_sdk::symbol_short!("farm_end"),), farm_id);
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_end"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
+    }
+
+    // ========== EXTRA REWARD TOKENS ==========
+    /// Adds a reward token the farm emits alongside its main one. The new stream
+    /// only accrues from the current ledger on and is split between stakers like
+    /// the main reward, including tier and loyalty bonuses. Extra rewards are paid
+    /// out directly and do not vest.
+    pub fn add_reward_token(
+        env: Env,
+        farm_id: u32,
+        token: Address,
+        reward_per_block: i128,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+
+        let farm = Self::load_farm(&env, farm_id)?;
+        let extras = Self::get_extra_rewards(env.clone(), farm_id);
+        if reward_per_block <= 0
+            || token == farm.reward_token
+            || extras.iter().any(|extra| extra.token == token)
+            || extras.len() >= MAX_EXTRA_REWARDS
+        {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        // Settle accrual up to now so the new stream starts from zero
+        Self::update_pool_internal(&env, farm_id)?;
+        let mut extras = Self::get_extra_rewards(env.clone(), farm_id);
+        extras.push_back(ExtraReward {
+            token: token.clone(),
+            reward_per_block,
+            acc_reward_per_share: 0,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ExtraRewards(farm_id), &extras);
+        Self::extend_farm_ttl(&env, farm_id);
+        Self::register_reward_token(&env, &token);
+
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("farm_rwd"),),
+            (farm_id, token.clone(), reward_per_block),
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_rwd"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
+        Ok(())
+    }
+
+    pub fn get_extra_rewards(env: Env, farm_id: u32) -> Vec<ExtraReward> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExtraRewards(farm_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    fn register_reward_token(env: &Env, token: &Address) {
+        let key = DataKey::RewardToken(token.clone());
+        env.storage().persistent().set(&key, &true);
+        Self::extend_if_present(env, &key);
+    }
+
+    /// Pays the farmer's pending extra rewards, as accrued for the stake in `user`,
+    /// and resets their reward debts for `new_amount` staked. With `halve` only half
+    /// is paid, as for early unstakes. Returns each extra token with the amount paid.
+    /// Payments that fail are forfeited, unless `strict` is set and the error is
+    /// returned instead. Expects the pool to be updated already.
+    fn settle_extra_rewards(
+        env: &Env,
+        farm_id: u32,
+        user: &UserFarm,
+        new_amount: i128,
+        halve: bool,
+        strict: bool,
+    ) -> Result<Vec<(Address, i128)>, ContractError> {
+        let mut paid = Vec::new(env);
+        let extras = Self::get_extra_rewards(env.clone(), farm_id);
+        if extras.is_empty() {
+            return Ok(paid);
+        }
+
+        let farm: FarmPool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Farm(farm_id))
+            .unwrap();
+        let debt_key = DataKey::ExtraRewardDebt(user.farmer.clone(), farm_id);
+        let debts: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&debt_key)
+            .unwrap_or(Vec::new(env));
+
+        // Work out every amount before paying anything
+        let mut pendings = Vec::new(env);
+        let mut new_debts = Vec::new(env);
+        for (i, extra) in extras.iter().enumerate() {
+            let pending = Self::calc_stream_pending(
+                env,
+                &farm,
+                user,
+                extra.acc_reward_per_share,
+                extra.reward_per_block,
+                debts.get(i as u32).unwrap_or(0),
+            )?;
+            pendings.push_back(if halve { pending / 2 } else { pending });
+            let debt = new_amount
+                .checked_mul(extra.acc_reward_per_share)
+                .ok_or(ContractError::MathOverflow)?
+                / PRECISION;
+            new_debts.push_back(debt);
+        }
+
+        for (extra, mut pending) in extras.iter().zip(pendings.iter()) {
+            if pending > 0 {
+                match Self::safe_transfer(env, &extra.token, &user.farmer, pending) {
+                    Ok(_) => Self::publish_event(
+                        env,
+                        (soroban_sdk::symbol_short!("harv_xtra"),),
+                        (user.farmer.clone(), farm_id, extra.token.clone(), pending),
+                    ),
+                    Err(err) if strict => return Err(err),
+                    Err(_) => pending = 0,
+                }
+            }
+            paid.push_back((extra.token.clone(), pending));
+        }
+
+        if new_amount == 0 {
+            env.storage().persistent().remove(&debt_key);
+        } else {
+            env.storage().persistent().set(&debt_key, &new_debts);
+            Self::extend_if_present(env, &debt_key);
+        }
+        Ok(paid)
     }
 
     // ========== STAKING OPERATIONS ==========
@@ -144,6 +353,8 @@ impl FarmerYieldFarmingContract {
     ) -> Result<(), ContractError> {
         farmer.require_auth();
 
+        Self::ensure_not_globally_paused(&env)?;
+
         if amount < MIN_STAKE_AMOUNT {
             return Err(ContractError::AmountBelowMinimum);
         }
@@ -157,18 +368,15 @@ impl FarmerYieldFarmingContract {
             return Err(ContractError::FarmPaused);
         }
 
-        let mut farm: FarmPool = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
+        let farm = Self::load_farm(&env, farm_id)?;
         let current_block = env.ledger().sequence() as u64;
 
         if current_block < farm.start_block || current_block >= farm.end_block {
             return Err(ContractError::FarmNotActive);
         }
 
-        Self::update_pool_internal(&env, farm_id);
+        Self::update_pool_internal(&env, farm_id)?;
+        let mut farm = Self::load_farm(&env, farm_id)?;
 
         let key = DataKey::UserFarm(farmer.clone(), farm_id);
         let mut user = env.storage().persistent().get(&key).unwrap_or(UserFarm {
@@ -180,16 +388,20 @@ impl FarmerYieldFarmingContract {
         });
 
         if user.amount > 0 {
-            let pending = Self::calc_pending(&env, &farm, &user);
+            let pending = Self::calc_pending(&env, &farm, &user)?;
             if pending > 0 {
-                let _ = Self::safe_transfer(&env, &farm.reward_token, &farmer, pending);
-                env.events().publish(
+                let paid = Self::pay_reward(&env, farm_id, &farm, &farmer, pending)?;
+                Self::publish_event(
+                    &env,
                     (soroban_sdk::symbol_short!("harvest"),),
-                    (farmer.clone(), farm_id, pending),
+                    (farmer.clone(), farm_id, paid),
                 );
+                Self::pay_referral_bonus(&env, farm_id, &farm, &farmer, pending);
             }
         }
 
+        Self::settle_extra_rewards(&env, farm_id, &user, user.amount + amount, false, false)?;
+
         token::Client::new(&env, &farm.lp_token).transfer(
             &farmer,
             &env.current_contract_address(),
@@ -201,18 +413,25 @@ impl FarmerYieldFarmingContract {
         user.stake_time = current_block;
 
         farm.total_staked += amount;
+        Self::record_stake_change(&env, &farmer, amount);
 
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
         env.storage().persistent().set(&key, &user);
-        env.events().publish(
+        Self::index_user_farm(&env, &farmer, farm_id);
+        Self::extend_user_ttl(&env, &farmer, farm_id);
+        Self::extend_contract_ttl(&env);
+        Self::publish_event(
+            &env,
             (soroban_sdk::symbol_short!("stake"),),
             (farmer, farm_id, amount),
         );
         Ok(())
     }
 
+    /// Unstakes LP tokens straight away. Farms with an unstake cooldown only allow
+    /// `request_unstake`.
     pub fn unstake_lp(
         env: Env,
         farmer: Address,
@@ -221,15 +440,173 @@ impl FarmerYieldFarmingContract {
     ) -> Result<(), ContractError> {
         farmer.require_auth();
 
-        if amount <= 0 {
-            return Err(ContractError::InvalidAmount);
+        Self::ensure_not_globally_paused(&env)?;
+
+        if Self::get_farm_cooldown(env.clone(), farm_id) > 0 {
+            return Err(ContractError::CooldownRequired);
         }
+        let farm = Self::remove_stake(&env, &farmer, farm_id, amount)?;
 
-        let mut farm: FarmPool = env
+        token::Client::new(&env, &farm.lp_token).transfer(
+            &env.current_contract_address(),
+            &farmer,
+            &amount,
+        );
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("unstake"),),
+            (farmer, farm_id, amount),
+        );
+        Ok(())
+    }
+
+    /// Takes `amount` out of the farmer's stake, paying rewards as `unstake_lp`
+    /// does, and locks the LP tokens for the farm's cooldown. The amount stops
+    /// earning rewards straight away and is claimed with `claim_unstaked`.
+    pub fn request_unstake(
+        env: Env,
+        farmer: Address,
+        farm_id: u32,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        farmer.require_auth();
+
+        Self::ensure_not_globally_paused(&env)?;
+
+        let key = DataKey::Withdrawal(farmer.clone(), farm_id);
+        let mut withdrawals = Self::get_pending_withdrawals(env.clone(), farmer.clone(), farm_id);
+        if withdrawals.len() >= MAX_PENDING_WITHDRAWALS {
+            return Err(ContractError::InvalidParameters);
+        }
+        Self::remove_stake(&env, &farmer, farm_id, amount)?;
+
+        let unlock_block =
+            env.ledger().sequence() as u64 + Self::get_farm_cooldown(env.clone(), farm_id);
+        withdrawals.push_back(PendingWithdrawal {
+            amount,
+            unlock_block,
+        });
+        env.storage().persistent().set(&key, &withdrawals);
+        Self::extend_if_present(&env, &key);
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("unstk_req"),),
+            (farmer, farm_id, amount, unlock_block),
+        );
+        Ok(())
+    }
+
+    /// Transfers the LP tokens of every unlocked unstake request and returns the
+    /// amount claimed. Requests still cooling down are kept.
+    pub fn claim_unstaked(env: Env, farmer: Address, farm_id: u32) -> Result<i128, ContractError> {
+        farmer.require_auth();
+
+        Self::ensure_not_globally_paused(&env)?;
+
+        let farm = Self::load_farm(&env, farm_id)?;
+        let key = DataKey::Withdrawal(farmer.clone(), farm_id);
+        let current_block = env.ledger().sequence() as u64;
+
+        let mut claimed = 0;
+        let mut locked = Vec::new(&env);
+        for withdrawal in Self::get_pending_withdrawals(env.clone(), farmer.clone(), farm_id) {
+            if withdrawal.unlock_block <= current_block {
+                claimed += withdrawal.amount;
+            } else {
+                locked.push_back(withdrawal);
+            }
+        }
+        if claimed == 0 {
+            return Err(ContractError::WithdrawalLocked);
+        }
+
+        token::Client::new(&env, &farm.lp_token).transfer(
+            &env.current_contract_address(),
+            &farmer,
+            &claimed,
+        );
+        if locked.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &locked);
+            Self::extend_if_present(&env, &key);
+        }
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("unstake"),),
+            (farmer, farm_id, claimed),
+        );
+        Ok(claimed)
+    }
+
+    /// Sets how many blocks LP tokens stay locked after an unstake request. With a
+    /// cooldown of 0 the farm allows `unstake_lp` again.
+    pub fn set_farm_cooldown(
+        env: Env,
+        farm_id: u32,
+        cooldown_blocks: u64,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env
             .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        admin.require_auth();
+        Self::load_farm(&env, farm_id)?;
+
+        let key = DataKey::FarmCooldown(farm_id);
+        if cooldown_blocks == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &cooldown_blocks);
+            Self::extend_if_present(&env, &key);
+        }
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("farm_cool"),),
+            (farm_id, cooldown_blocks),
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_cool"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
+        Ok(())
+    }
+
+    pub fn get_farm_cooldown(env: Env, farm_id: u32) -> u64 {
+        env.storage()
             .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
+            .get(&DataKey::FarmCooldown(farm_id))
+            .unwrap_or(0)
+    }
+
+    pub fn get_pending_withdrawals(
+        env: Env,
+        farmer: Address,
+        farm_id: u32,
+    ) -> Vec<PendingWithdrawal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Withdrawal(farmer, farm_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Takes `amount` out of the farmer's stake and pays their rewards, halved
+    /// within the minimum stake period. Returns the updated farm; the LP tokens
+    /// are left for the caller to transfer.
+    fn remove_stake(
+        env: &Env,
+        farmer: &Address,
+        farm_id: u32,
+        amount: i128,
+    ) -> Result<FarmPool, ContractError> {
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        Self::load_farm(env, farm_id)?;
         let key = DataKey::UserFarm(farmer.clone(), farm_id);
         let mut user: UserFarm = env.storage().persistent().get(&key).unwrap();
 
@@ -237,85 +614,154 @@ impl FarmerYieldFarmingContract {
             return Err(ContractError::InsufficientStake);
         }
 
-        let current_block = env.ledger().sequence() as u64;
-        let min_period: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::MinStakePeriod)
-            .unwrap_or(COOLDOWN_PERIOD);
-        let time_staked = current_block.saturating_sub(user.stake_time);
+        let early = Self::within_min_stake_period(env, &user);
 
-        Self::update_pool_internal(&env, farm_id);
+        Self::update_pool_internal(env, farm_id)?;
+        let mut farm = Self::load_farm(env, farm_id)?;
 
-        let pending = Self::calc_pending(&env, &farm, &user);
+        let pending = Self::calc_pending(env, &farm, &user)?;
+        Self::settle_extra_rewards(env, farm_id, &user, user.amount - amount, early, false)?;
         if pending > 0 {
-            let actual_reward = if time_staked < min_period {
-                pending / 2
-            } else {
-                pending
-            };
-            let _ = Self::safe_transfer(&env, &farm.reward_token, &farmer, actual_reward);
-            env.events().publish(
+            let actual_reward = if early { pending / 2 } else { pending };
+            let paid = Self::pay_reward(env, farm_id, &farm, farmer, actual_reward)?;
+            Self::publish_event(
+                env,
                 (soroban_sdk::symbol_short!("harvest"),),
-                (farmer.clone(), farm_id, actual_reward),
+                (farmer.clone(), farm_id, paid),
             );
+            Self::pay_referral_bonus(env, farm_id, &farm, farmer, actual_reward);
         }
 
         user.amount -= amount;
         user.reward_debt = (user.amount * farm.acc_reward_per_share) / PRECISION;
         farm.total_staked -= amount;
-
-        token::Client::new(&env, &farm.lp_token).transfer(
-            &env.current_contract_address(),
-            &farmer,
-            &amount,
-        );
+        Self::record_stake_change(env, farmer, -amount);
 
         if user.amount == 0 {
             env.storage().persistent().remove(&key);
+            Self::unindex_user_farm(env, farmer, farm_id);
         } else {
             env.storage().persistent().set(&key, &user);
         }
+        Self::extend_user_ttl(env, farmer, farm_id);
+        Self::extend_contract_ttl(env);
 
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
-        env.events().publish(
-            (soroban_sdk::symbol_short!("unstake"),),
-            (farmer, farm_id, amount),
-        );
-        Ok(())
+        Ok(farm)
+    }
+
+    /// Whether the position was staked less than the minimum stake period ago, in
+    /// which case unstaking halves its rewards
+    fn within_min_stake_period(env: &Env, user: &UserFarm) -> bool {
+        let min_period: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinStakePeriod)
+            .unwrap_or(COOLDOWN_PERIOD);
+        (env.ledger().sequence() as u64).saturating_sub(user.stake_time) < min_period
     }
 
-    pub fn harvest(env: Env, farmer: Address, farm_id: u32) -> Result<(), ContractError> {
+    /// Harvests the farmer's rewards from a farm and returns each reward token with
+    /// the amount transferred, starting with the farm's main reward token. Under a
+    /// vesting schedule the main reward is locked and only what has vested so far is
+    /// transferred.
+    pub fn harvest(
+        env: Env,
+        farmer: Address,
+        farm_id: u32,
+    ) -> Result<Vec<(Address, i128)>, ContractError> {
         farmer.require_auth();
 
-        let farm: FarmPool = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
+        Self::ensure_not_globally_paused(&env)?;
+
+        Self::load_farm(&env, farm_id)?;
         let key = DataKey::UserFarm(farmer.clone(), farm_id);
         let mut user: UserFarm = env.storage().persistent().get(&key).unwrap();
 
-        Self::update_pool_internal(&env, farm_id);
+        Self::update_pool_internal(&env, farm_id)?;
+        let farm = Self::load_farm(&env, farm_id)?;
 
-        let pending = Self::calc_pending(&env, &farm, &user);
+        let pending = Self::calc_pending(&env, &farm, &user)?;
         if pending <= 0 {
             return Err(ContractError::NoRewards);
         }
 
-        let _ = Self::safe_transfer(&env, &farm.reward_token, &farmer, pending);
+        let paid = Self::pay_reward(&env, farm_id, &farm, &farmer, pending)?;
+        let mut harvested = vec![&env, (farm.reward_token.clone(), paid)];
+        let extra = Self::settle_extra_rewards(&env, farm_id, &user, user.amount, false, false)?;
+        harvested.append(&extra);
 
         user.reward_debt = (user.amount * farm.acc_reward_per_share) / PRECISION;
         user.last_harvest = env.ledger().sequence() as u64;
 
         env.storage().persistent().set(&key, &user);
-        env.events().publish(
+        Self::extend_user_ttl(&env, &farmer, farm_id);
+        Self::publish_event(
+            &env,
             (soroban_sdk::symbol_short!("harvest"),),
-            (farmer, farm_id, pending),
+            (farmer.clone(), farm_id, paid),
         );
-        Ok(())
+        Self::pay_referral_bonus(&env, farm_id, &farm, &farmer, pending);
+        Ok(harvested)
+    }
+
+    /// Harvests several farms in one transaction and returns the amount transferred
+    /// from each, in the order given. Farms with nothing pending pay 0, and farms with
+    /// a vesting schedule only transfer what has vested so far. If the contract
+    /// cannot cover a farm's rewards the whole batch is rolled back with
+    /// `InsufficientBalance`; only the error code is returned, so callers wanting to
+    /// know which farm failed should harvest the farms one by one.
+    pub fn batch_harvest(
+        env: Env,
+        farmer: Address,
+        farm_ids: Vec<u32>,
+    ) -> Result<Vec<i128>, ContractError> {
+        farmer.require_auth();
+
+        Self::ensure_not_globally_paused(&env)?;
+
+        if farm_ids.is_empty() || farm_ids.len() > MAX_BATCH_HARVEST {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let mut paid = Vec::new(&env);
+        for farm_id in farm_ids.iter() {
+            Self::load_farm(&env, farm_id)?;
+            let key = DataKey::UserFarm(farmer.clone(), farm_id);
+            let mut user: UserFarm = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .ok_or(ContractError::NoStakeFound)?;
+
+            Self::update_pool_internal(&env, farm_id)?;
+            let farm = Self::load_farm(&env, farm_id)?;
+
+            let pending = Self::calc_pending(&env, &farm, &user)?;
+            let mut transferred = 0;
+            if pending > 0 {
+                transferred = Self::pay_reward(&env, farm_id, &farm, &farmer, pending)?;
+                user.last_harvest = env.ledger().sequence() as u64;
+            }
+            Self::settle_extra_rewards(&env, farm_id, &user, user.amount, false, true)?;
+            user.reward_debt = (user.amount * farm.acc_reward_per_share) / PRECISION;
+
+            env.storage().persistent().set(&key, &user);
+            Self::extend_user_ttl(&env, &farmer, farm_id);
+            Self::publish_event(
+                &env,
+                (soroban_sdk::symbol_short!("harvest"),),
+                (farmer.clone(), farm_id, transferred),
+            );
+            if pending > 0 {
+                Self::pay_referral_bonus(&env, farm_id, &farm, &farmer, pending);
+            }
+            paid.push_back(transferred);
+        }
+
+        Ok(paid)
     }
 
     pub fn emergency_withdraw(
@@ -325,6 +771,8 @@ impl FarmerYieldFarmingContract {
     ) -> Result<(), ContractError> {
         farmer.require_auth();
 
+        Self::ensure_not_globally_paused(&env)?;
+
         let enabled: bool = env
             .storage()
             .instance()
@@ -334,77 +782,587 @@ impl FarmerYieldFarmingContract {
            return  Err(ContractError::EmergencyNotEnabled);
         }
 
-        let mut farm: FarmPool = env
+        let mut farm = Self::load_farm(&env, farm_id)?;
+        let key = DataKey::UserFarm(farmer.clone(), farm_id);
+        let staked = env
             .storage()
             .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap();
-        let key = DataKey::UserFarm(farmer.clone(), farm_id);
-        let user: UserFarm = env.storage().persistent().get(&key).unwrap();
+            .get::<_, UserFarm>(&key)
+            .map(|user| user.amount)
+            .unwrap_or(0);
 
-        let amount = user.amount;
+        // Unstake requests still cooling down are released as well
+        let withdrawal_key = DataKey::Withdrawal(farmer.clone(), farm_id);
+        let mut amount = staked;
+        for withdrawal in Self::get_pending_withdrawals(env.clone(), farmer.clone(), farm_id) {
+            amount += withdrawal.amount;
+        }
+        if amount == 0 {
+            return Err(ContractError::NoStakeFound);
+        }
         token::Client::new(&env, &farm.lp_token).transfer(
             &env.current_contract_address(),
             &farmer,
             &amount,
         );
 
-        farm.total_staked -= amount;
+        farm.total_staked -= staked;
+        Self::record_stake_change(&env, &farmer, -staked);
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
         env.storage().persistent().remove(&key);
+        env.storage().persistent().remove(&withdrawal_key);
+        Self::unindex_user_farm(&env, &farmer, farm_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ExtraRewardDebt(farmer.clone(), farm_id));
 
-        env.events().publish(
+        Self::publish_event(
+            &env,
             (soroban_sdk::symbol_short!("emerg_wd"),),
             (farmer, farm_id, amount),
         );
         Ok(())
     }
 
-    // ========== REWARD QUERIES ==========
-    pub fn get_pending_rewards(env: Env, farmer: Address, farm_id: u32) -> i128 {
-        let farm: FarmPool = match env.storage().persistent().get(&DataKey::Farm(farm_id)) {
-            Some(f) => f,
-            None => return 0,
-        };
+    /// Moves the farmer's whole stake from one farm to another that takes the same
+    /// LP and reward tokens, in a single transaction. Rewards pending in the source
+    /// farm are paid as `unstake_lp` pays them, halved within the minimum stake
+    /// period, and the LP tokens never leave the contract. A new position in the destination keeps the
+    /// source's stake time, so loyalty bonuses carry over. The destination's unstake
+    /// cooldown must be at least the source's, so migrating never shortens the wait.
+    pub fn migrate_stake(
+        env: Env,
+        farmer: Address,
+        src_farm_id: u32,
+        dst_farm_id: u32,
+    ) -> Result<(), ContractError> {
+        farmer.require_auth();
 
-        let user: UserFarm = match env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserFarm(farmer, farm_id))
-        {
-            Some(u) => u,
-            None => return 0,
-        };
+        Self::ensure_not_globally_paused(&env)?;
 
-        Self::calc_pending(&env, &farm, &user)
+        if src_farm_id == dst_farm_id {
+            return Err(ContractError::InvalidParameters);
+        }
+        let src = Self::load_farm(&env, src_farm_id)?;
+        let dst = Self::load_farm(&env, dst_farm_id)?;
+        if src.lp_token != dst.lp_token || src.reward_token != dst.reward_token {
+            return Err(ContractError::IncompatibleFarms);
+        }
+        if Self::get_farm_cooldown(env.clone(), dst_farm_id)
+            < Self::get_farm_cooldown(env.clone(), src_farm_id)
+        {
+            return Err(ContractError::CooldownRequired);
+        }
+
+        let paused: bool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Paused(dst_farm_id))
+            .unwrap_or(false);
+        if paused {
+            return Err(ContractError::FarmPaused);
+        }
+        let current_block = env.ledger().sequence() as u64;
+        if current_block < dst.start_block || current_block >= dst.end_block {
+            return Err(ContractError::FarmNotActive);
+        }
+
+        let src_key = DataKey::UserFarm(farmer.clone(), src_farm_id);
+        let src_user: UserFarm = env
+            .storage()
+            .persistent()
+            .get(&src_key)
+            .ok_or(ContractError::NoStakeFound)?;
+        let amount = src_user.amount;
+
+        Self::update_pool_internal(&env, src_farm_id)?;
+        Self::update_pool_internal(&env, dst_farm_id)?;
+        let mut src = Self::load_farm(&env, src_farm_id)?;
+        let mut dst = Self::load_farm(&env, dst_farm_id)?;
+
+        // Settle the source position as a full unstake
+        let early = Self::within_min_stake_period(&env, &src_user);
+        let pending = Self::calc_pending(&env, &src, &src_user)?;
+        Self::settle_extra_rewards(&env, src_farm_id, &src_user, 0, early, false)?;
+        if pending > 0 {
+            let actual_reward = if early { pending / 2 } else { pending };
+            let paid = Self::pay_reward(&env, src_farm_id, &src, &farmer, actual_reward)?;
+            Self::publish_event(
+                &env,
+                (soroban_sdk::symbol_short!("harvest"),),
+                (farmer.clone(), src_farm_id, paid),
+            );
+            Self::pay_referral_bonus(&env, src_farm_id, &src, &farmer, actual_reward);
+        }
+        src.total_staked -= amount;
+        env.storage().persistent().remove(&src_key);
+        Self::unindex_user_farm(&env, &farmer, src_farm_id);
+
+        // Add to the destination, harvesting it first as `stake_lp` does
+        let dst_key = DataKey::UserFarm(farmer.clone(), dst_farm_id);
+        let mut dst_user = env
+            .storage()
+            .persistent()
+            .get(&dst_key)
+            .unwrap_or(UserFarm {
+                farmer: farmer.clone(),
+                amount: 0,
+                reward_debt: 0,
+                stake_time: src_user.stake_time,
+                last_harvest: current_block,
+            });
+        if dst_user.amount > 0 {
+            let pending = Self::calc_pending(&env, &dst, &dst_user)?;
+            if pending > 0 {
+                let paid = Self::pay_reward(&env, dst_farm_id, &dst, &farmer, pending)?;
+                Self::publish_event(
+                    &env,
+                    (soroban_sdk::symbol_short!("harvest"),),
+                    (farmer.clone(), dst_farm_id, paid),
+                );
+                Self::pay_referral_bonus(&env, dst_farm_id, &dst, &farmer, pending);
+            }
+        }
+        Self::settle_extra_rewards(
+            &env,
+            dst_farm_id,
+            &dst_user,
+            dst_user.amount + amount,
+            false,
+            false,
+        )?;
+        dst_user.amount += amount;
+        dst_user.reward_debt = (dst_user.amount * dst.acc_reward_per_share) / PRECISION;
+        dst.total_staked += amount;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Farm(src_farm_id), &src);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Farm(dst_farm_id), &dst);
+        env.storage().persistent().set(&dst_key, &dst_user);
+        Self::index_user_farm(&env, &farmer, dst_farm_id);
+        Self::extend_user_ttl(&env, &farmer, dst_farm_id);
+        Self::extend_contract_ttl(&env);
+        Self::publish_event(
+            &env,
+            (Symbol::new(&env, "stake_migrated"),),
+            (farmer, src_farm_id, dst_farm_id, amount),
+        );
+        Ok(())
+    }
+
+    // ========== REFERRALS ==========
+    /// Records who referred `referee`. Each referee can be referred once and
+    /// cannot refer themselves.
+    pub fn register_referral(
+        env: Env,
+        referrer: Address,
+        referee: Address,
+    ) -> Result<(), ContractError> {
+        referee.require_auth();
+
+        let key = DataKey::Referral(referee.clone());
+        if referrer == referee || env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidReferral);
+        }
+
+        env.storage().persistent().set(&key, &referrer);
+        Self::extend_if_present(&env, &key);
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("referral"),),
+            (referrer, referee),
+        );
+        Ok(())
     }
 
-    fn calc_pending(env: &Env, farm: &FarmPool, user: &UserFarm) -> i128 {
+    /// Sets the referral bonus in basis points of each harvest and the most one
+    /// referrer can earn per farm. A bonus of 0 turns referral payouts off.
+    pub fn set_referral_config(
+        env: Env,
+        bonus_bps: u32,
+        max_bonus_per_farm: i128,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if bonus_bps > MAX_BPS || max_bonus_per_farm < 0 {
+            return Err(ContractError::InvalidParameters);
+        }
+        env.storage().instance().set(
+            &DataKey::ReferralConfig,
+            &ReferralConfig {
+                bonus_bps,
+                max_bonus_per_farm,
+            },
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("ref_cfg"),
+            &admin,
+            AdminTarget::Contract,
+        );
+        Ok(())
+    }
+
+    pub fn get_referral_config(env: Env) -> ReferralConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReferralConfig)
+            .unwrap_or(ReferralConfig {
+                bonus_bps: 0,
+                max_bonus_per_farm: 0,
+            })
+    }
+
+    pub fn get_referrer(env: Env, referee: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Referral(referee))
+    }
+
+    pub fn get_referral_bonus_paid(env: Env, referrer: Address, farm_id: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralBonusPaid(referrer, farm_id))
+            .unwrap_or(0)
+    }
+
+    /// Pays the referee's referrer their share of `reward` from the farm's reward
+    /// pool. The bonus is skipped after the farm ends, once the referrer's cap for
+    /// the farm is reached, or when the pool cannot cover it.
+    fn pay_referral_bonus(
+        env: &Env,
+        farm_id: u32,
+        farm: &FarmPool,
+        referee: &Address,
+        reward: i128,
+    ) {
+        let config = Self::get_referral_config(env.clone());
+        if config.bonus_bps == 0 || env.ledger().sequence() as u64 > farm.end_block {
+            return;
+        }
+        let Some(referrer) = Self::get_referrer(env.clone(), referee.clone()) else {
+            return;
+        };
+
+        let paid_key = DataKey::ReferralBonusPaid(referrer.clone(), farm_id);
+        let paid: i128 = env.storage().persistent().get(&paid_key).unwrap_or(0);
+        let bonus = ((reward * config.bonus_bps as i128) / MAX_BPS as i128)
+            .min(config.max_bonus_per_farm - paid);
+        if bonus <= 0 || Self::safe_transfer(env, &farm.reward_token, &referrer, bonus).is_err() {
+            return;
+        }
+
+        env.storage().persistent().set(&paid_key, &(paid + bonus));
+        Self::extend_if_present(env, &paid_key);
+        Self::publish_event(
+            env,
+            (soroban_sdk::symbol_short!("ref_bonus"),),
+            (referrer, referee.clone(), farm_id, bonus),
+        );
+    }
+
+    // ========== VESTING ==========
+    /// Sets how a farm's harvested rewards vest. A schedule with `total_blocks` of 0
+    /// removes vesting, and rewards still vesting under the old schedule unlock at once.
+    pub fn set_farm_vesting(
+        env: Env,
+        admin: Address,
+        farm_id: u32,
+        schedule: VestingSchedule,
+    ) -> Result<(), ContractError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        if admin != stored_admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+
+        Self::load_farm(&env, farm_id)?;
+        if schedule.cliff_blocks > schedule.total_blocks {
+            return Err(ContractError::InvalidParameters);
+        }
+
+        let key = DataKey::FarmVesting(farm_id);
+        if schedule.total_blocks == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &schedule);
+            Self::extend_if_present(&env, &key);
+        }
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("farm_vest"),),
+            (farm_id, schedule.cliff_blocks, schedule.total_blocks),
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("farm_vest"),
+            &admin,
+            AdminTarget::Farm(farm_id),
+        );
+        Ok(())
+    }
+
+    pub fn get_farm_vesting(env: Env, farm_id: u32) -> Option<VestingSchedule> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FarmVesting(farm_id))
+    }
+
+    pub fn get_vesting_position(
+        env: Env,
+        farmer: Address,
+        farm_id: u32,
+    ) -> Option<VestingPosition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingVest(farmer, farm_id))
+    }
+
+    /// Transfers the farmer's vested but unclaimed rewards from a farm and returns
+    /// the amount claimed.
+    pub fn claim_vested(env: Env, farmer: Address, farm_id: u32) -> Result<i128, ContractError> {
+        farmer.require_auth();
+
+        Self::ensure_not_globally_paused(&env)?;
+
+        let farm = Self::load_farm(&env, farm_id)?;
+        let key = DataKey::PendingVest(farmer.clone(), farm_id);
+        let mut position: VestingPosition = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::NoRewards)?;
+
+        let claimable = Self::vested_amount(&env, farm_id, &position) - position.claimed;
+        if claimable <= 0 {
+            return Err(ContractError::NoRewards);
+        }
+        Self::safe_transfer(&env, &farm.reward_token, &farmer, claimable)?;
+
+        position.claimed += claimable;
+        if position.claimed >= position.total {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &position);
+            Self::extend_if_present(&env, &key);
+        }
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("vest_clm"),),
+            (farmer, farm_id, claimable),
+        );
+        Ok(claimable)
+    }
+
+    /// Pays a harvested reward to the farmer. Under a vesting schedule the reward is
+    /// locked instead: whatever has vested so far is transferred and the new reward
+    /// joins the open position, which keeps vesting from its first accrual. A new
+    /// position, and with it a new cliff, starts only once the last one is paid out.
+    /// Returns the amount transferred.
+    fn pay_reward(
+        env: &Env,
+        farm_id: u32,
+        farm: &FarmPool,
+        farmer: &Address,
+        reward: i128,
+    ) -> Result<i128, ContractError> {
+        if Self::get_farm_vesting(env.clone(), farm_id).is_none() {
+            return Self::safe_transfer(env, &farm.reward_token, farmer, reward).map(|_| reward);
+        }
+
+        let key = DataKey::PendingVest(farmer.clone(), farm_id);
+        let mut transferred = 0;
+        let mut position = match env.storage().persistent().get::<_, VestingPosition>(&key) {
+            Some(mut position) => {
+                let claimable = Self::vested_amount(env, farm_id, &position) - position.claimed;
+                if claimable > 0 {
+                    Self::safe_transfer(env, &farm.reward_token, farmer, claimable)?;
+                    position.claimed += claimable;
+                    transferred = claimable;
+                }
+                position
+            }
+            None => VestingPosition {
+                total: 0,
+                start_block: 0,
+                claimed: 0,
+            },
+        };
+        if position.claimed >= position.total {
+            position = VestingPosition {
+                total: 0,
+                start_block: env.ledger().sequence() as u64,
+                claimed: 0,
+            };
+        }
+        position.total += reward;
+
+        env.storage().persistent().set(&key, &position);
+        Self::extend_if_present(env, &key);
+        Ok(transferred)
+    }
+
+    /// Part of the position's total unlocked under the farm's current schedule
+    fn vested_amount(env: &Env, farm_id: u32, position: &VestingPosition) -> i128 {
+        let Some(schedule) = Self::get_farm_vesting(env.clone(), farm_id) else {
+            return position.total;
+        };
+        let elapsed = (env.ledger().sequence() as u64).saturating_sub(position.start_block);
+        if elapsed >= schedule.total_blocks {
+            position.total
+        } else if elapsed < schedule.cliff_blocks {
+            0
+        } else {
+            (position.total * elapsed as i128) / schedule.total_blocks as i128
+        }
+    }
+
+    // ========== REWARD QUERIES ==========
+    /// Returns `(pending, unvested, claimable)`: rewards accrued but not yet
+    /// harvested, harvested rewards still vesting, and vested rewards waiting for
+    /// `claim_vested`.
+    pub fn get_pending_rewards(env: Env, farmer: Address, farm_id: u32) -> (i128, i128, i128) {
+        let farm: FarmPool = match env.storage().persistent().get(&DataKey::Farm(farm_id)) {
+            Some(f) => f,
+            None => return (0, 0, 0),
+        };
+
+        let pending = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserFarm(farmer.clone(), farm_id))
+        {
+            Some(user) => Self::calc_pending(&env, &farm, &user)
+                .unwrap_or_else(|e| panic_with_error!(&env, e)),
+            None => 0,
+        };
+
+        match Self::get_vesting_position(env.clone(), farmer, farm_id) {
+            Some(position) => {
+                let vested = Self::vested_amount(&env, farm_id, &position);
+                (pending, position.total - vested, vested - position.claimed)
+            }
+            None => (pending, 0, 0),
+        }
+    }
+
+    /// Pending rewards from every reward token of the farm, starting with its main
+    /// reward token. Extra rewards are listed even when nothing has accrued yet.
+    pub fn get_pending_rewards_all(
+        env: Env,
+        farmer: Address,
+        farm_id: u32,
+    ) -> Vec<(Address, i128)> {
+        let mut pending = Vec::new(&env);
+        let farm: FarmPool = match env.storage().persistent().get(&DataKey::Farm(farm_id)) {
+            Some(f) => f,
+            None => return pending,
+        };
+
+        let user: Option<UserFarm> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserFarm(farmer.clone(), farm_id));
+        let main = user
+            .as_ref()
+            .map(|user| Self::calc_pending(&env, &farm, user))
+            .unwrap_or(Ok(0))
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        pending.push_back((farm.reward_token.clone(), main));
+
+        let debts: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ExtraRewardDebt(farmer, farm_id))
+            .unwrap_or(Vec::new(&env));
+        for (i, extra) in Self::get_extra_rewards(env.clone(), farm_id)
+            .iter()
+            .enumerate()
+        {
+            let amount = user
+                .as_ref()
+                .map(|user| {
+                    Self::calc_stream_pending(
+                        &env,
+                        &farm,
+                        user,
+                        extra.acc_reward_per_share,
+                        extra.reward_per_block,
+                        debts.get(i as u32).unwrap_or(0),
+                    )
+                })
+                .unwrap_or(Ok(0))
+                .unwrap_or_else(|e| panic_with_error!(&env, e));
+            pending.push_back((extra.token, amount));
+        }
+        pending
+    }
+
+    /// Current annual reward rate of the farm's main reward token in basis points,
+    /// valuing reward and LP tokens one to one. Tier and loyalty bonuses are not
+    /// included. Returns 0 once the farm has ended or while nothing is staked.
+    pub fn get_farm_apr(env: Env, farm_id: u32) -> Result<u32, ContractError> {
+        let farm = Self::load_farm(&env, farm_id)?;
+        if farm.total_staked <= 0 || env.ledger().sequence() as u64 >= farm.end_block {
+            return Ok(0);
+        }
+
+        let yearly = farm
+            .reward_per_block
+            .saturating_mul(Self::total_multiplier(&env, &farm))
+            / BASE_MULTIPLIER as i128;
+        let apr = yearly
+            .saturating_mul(YEAR_IN_LEDGERS as i128)
+            .saturating_mul(MAX_BPS as i128)
+            / farm.total_staked;
+        Ok(apr.clamp(0, u32::MAX as i128) as u32)
+    }
+
+    fn calc_pending(env: &Env, farm: &FarmPool, user: &UserFarm) -> Result<i128, ContractError> {
+        Self::calc_stream_pending(
+            env,
+            farm,
+            user,
+            farm.acc_reward_per_share,
+            farm.reward_per_block,
+            user.reward_debt,
+        )
+    }
+
+    /// Pending rewards from one reward stream of the farm, accruing blocks the pool
+    /// has not been updated for yet
+    fn calc_stream_pending(
+        env: &Env,
+        farm: &FarmPool,
+        user: &UserFarm,
+        acc_reward_per_share: i128,
+        reward_per_block: i128,
+        reward_debt: i128,
+    ) -> Result<i128, ContractError> {
         if user.amount == 0 {
-            return 0;
+            return Ok(0);
         }
 
-        let mut acc = farm.acc_reward_per_share;
+        let mut acc = acc_reward_per_share;
         let current_block = env.ledger().sequence() as u64;
 
-        if current_block > farm.last_reward_block && farm.total_staked > 0 {
-            let end_block = if current_block > farm.end_block {
-                farm.end_block
-            } else {
-                current_block
-            };
+        let end_block = current_block.min(farm.end_block);
+        if end_block > farm.last_reward_block && farm.total_staked > 0 {
             let blocks = (end_block - farm.last_reward_block) as i128;
-            let global_mult: u32 = env
-                .storage()
-                .instance()
-                .get(&DataKey::GlobalMultiplier)
-                .unwrap_or(BASE_MULTIPLIER);
-            let total_mult =
-                (farm.multiplier as i128 * global_mult as i128) / BASE_MULTIPLIER as i128;
-            let reward = (blocks * farm.reward_per_block * total_mult) / BASE_MULTIPLIER as i128;
-            acc += (reward * PRECISION) / farm.total_staked;
+            acc = Self::accrue(
+                acc,
+                blocks,
+                reward_per_block,
+                Self::total_multiplier(env, farm),
+                farm.total_staked,
+            )?;
         }
 
         let tier = Self::get_tier(user.amount);
@@ -414,13 +1372,57 @@ impl FarmerYieldFarmingContract {
             FarmerTier::Enterprise => 100,
         };
 
-        let base = (user.amount * acc) / PRECISION - user.reward_debt;
-        let with_tier = (base * tier_mult as i128) / 100;
+        let share = user
+            .amount
+            .checked_mul(acc)
+            .ok_or(ContractError::MathOverflow)?
+            / PRECISION;
+        // A debt above the share would otherwise claw back rewards already paid
+        let base = share.saturating_sub(reward_debt).max(0);
+        let with_tier = base
+            .checked_mul(tier_mult)
+            .ok_or(ContractError::MathOverflow)?
+            / 100;
 
         let time_staked = current_block.saturating_sub(user.stake_time);
         let loyalty = Self::get_loyalty_bonus(time_staked);
 
-        with_tier + (with_tier * loyalty as i128) / 10000
+        let bonus = with_tier
+            .checked_mul(loyalty as i128)
+            .ok_or(ContractError::MathOverflow)?
+            / 10000;
+        with_tier
+            .checked_add(bonus)
+            .ok_or(ContractError::MathOverflow)
+    }
+
+    /// Farm multiplier scaled by the global multiplier, in units of `BASE_MULTIPLIER`
+    fn total_multiplier(env: &Env, farm: &FarmPool) -> i128 {
+        let global_mult: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GlobalMultiplier)
+            .unwrap_or(BASE_MULTIPLIER);
+        (farm.multiplier as i128 * global_mult as i128) / BASE_MULTIPLIER as i128
+    }
+
+    /// Adds what a stream emitting `reward_per_block` pays each staked unit over
+    /// `blocks` to its accumulated reward per share
+    fn accrue(
+        acc_reward_per_share: i128,
+        blocks: i128,
+        reward_per_block: i128,
+        total_mult: i128,
+        total_staked: i128,
+    ) -> Result<i128, ContractError> {
+        blocks
+            .checked_mul(reward_per_block)
+            .and_then(|reward| reward.checked_mul(total_mult))
+            .map(|reward| reward / BASE_MULTIPLIER as i128)
+            .and_then(|reward| reward.checked_mul(PRECISION))
+            .map(|reward| reward / total_staked)
+            .and_then(|per_share| acc_reward_per_share.checked_add(per_share))
+            .ok_or(ContractError::MathOverflow)
     }
 
     fn get_tier(amount: i128) -> FarmerTier {
@@ -449,49 +1451,174 @@ impl FarmerYieldFarmingContract {
     }
 
     // ========== POOL UPDATES ==========
-    pub fn update_pool(env: Env, farm_id: u32) {
-        Self::update_pool_internal(&env, farm_id);
+    pub fn update_pool(env: Env, farm_id: u32) -> Result<(), ContractError> {
+        Self::update_pool_internal(&env, farm_id)
     }
 
-    fn update_pool_internal(env: &Env, farm_id: u32) {
+    /// Accrues every reward stream of the farm up to the current ledger, or up to
+    /// `end_block` once the farm has ended
+    fn update_pool_internal(env: &Env, farm_id: u32) -> Result<(), ContractError> {
         let mut farm: FarmPool = env
             .storage()
             .persistent()
             .get(&DataKey::Farm(farm_id))
             .unwrap();
-        let current = env.ledger().sequence() as u64;
+        let end_block = (env.ledger().sequence() as u64).min(farm.end_block);
+        if end_block <= farm.last_reward_block {
+            return Ok(());
+        }
 
-        if current <= farm.last_reward_block || farm.total_staked == 0 {
-            farm.last_reward_block = current;
+        if farm.total_staked == 0 {
+            farm.last_reward_block = end_block;
             env.storage()
                 .persistent()
                 .set(&DataKey::Farm(farm_id), &farm);
-            return;
+            return Ok(());
         }
 
-        let end_block = if current > farm.end_block {
-            farm.end_block
-        } else {
-            current
-        };
         let blocks = (end_block - farm.last_reward_block) as i128;
-        let global_mult: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::GlobalMultiplier)
-            .unwrap_or(BASE_MULTIPLIER);
-        let total_mult = (farm.multiplier as i128 * global_mult as i128) / BASE_MULTIPLIER as i128;
-        let reward = (blocks * farm.reward_per_block * total_mult) / BASE_MULTIPLIER as i128;
-
-        farm.acc_reward_per_share += (reward * PRECISION) / farm.total_staked;
+        let total_mult = Self::total_multiplier(env, &farm);
+        farm.acc_reward_per_share = Self::accrue(
+            farm.acc_reward_per_share,
+            blocks,
+            farm.reward_per_block,
+            total_mult,
+            farm.total_staked,
+        )?;
         farm.last_reward_block = end_block;
 
+        // Extra reward streams accrue over the same blocks at their own rates
+        let mut extras = Self::get_extra_rewards(env.clone(), farm_id);
+        if !extras.is_empty() {
+            for i in 0..extras.len() {
+                let mut extra = extras.get(i).unwrap();
+                extra.acc_reward_per_share = Self::accrue(
+                    extra.acc_reward_per_share,
+                    blocks,
+                    extra.reward_per_block,
+                    total_mult,
+                    farm.total_staked,
+                )?;
+                extras.set(i, extra);
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::ExtraRewards(farm_id), &extras);
+        }
+
         env.storage()
             .persistent()
             .set(&DataKey::Farm(farm_id), &farm);
+        Ok(())
+    }
+
+    // ========== STORAGE TTL ==========
+    /// Extends the TTLs of the given farms, user positions and proposals. Anyone
+    /// can call this to keep entries live.
+    pub fn bump_entries(env: Env, keys: EntryKeys) {
+        Self::extend_contract_ttl(&env);
+        for farm_id in keys.farms.iter() {
+            Self::extend_farm_ttl(&env, farm_id);
+        }
+        for (farmer, farm_id) in keys.user_farms.iter() {
+            Self::extend_user_ttl(&env, &farmer, farm_id);
+        }
+        for proposal_id in keys.proposals.iter() {
+            Self::extend_if_present(&env, &DataKey::Proposal(proposal_id));
+        }
+    }
+
+    /// Loads a farm, extending its TTL. A farm that was created but is no longer in
+    /// the live state is reported as `EntryArchived` so clients can restore it.
+    fn load_farm(env: &Env, farm_id: u32) -> Result<FarmPool, ContractError> {
+        match env.storage().persistent().get(&DataKey::Farm(farm_id)) {
+            Some(farm) => {
+                Self::extend_farm_ttl(env, farm_id);
+                Ok(farm)
+            }
+            None if farm_id < Self::get_farm_count(env.clone()) => {
+                Err(ContractError::EntryArchived)
+            }
+            None => Err(ContractError::FarmNotFound),
+        }
+    }
+
+    fn extend_contract_ttl(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(ENTRY_LIFETIME_THRESHOLD, ENTRY_BUMP_AMOUNT);
+        Self::extend_if_present(env, &DataKey::TotalStakeCheckpoints);
+    }
+
+    fn extend_farm_ttl(env: &Env, farm_id: u32) {
+        Self::extend_if_present(env, &DataKey::Farm(farm_id));
+        Self::extend_if_present(env, &DataKey::Paused(farm_id));
+        Self::extend_if_present(env, &DataKey::FarmVesting(farm_id));
+        Self::extend_if_present(env, &DataKey::ExtraRewards(farm_id));
+        Self::extend_if_present(env, &DataKey::FarmCooldown(farm_id));
+    }
+
+    fn extend_user_ttl(env: &Env, farmer: &Address, farm_id: u32) {
+        Self::extend_if_present(env, &DataKey::UserFarm(farmer.clone(), farm_id));
+        Self::extend_if_present(env, &DataKey::StakeCheckpoints(farmer.clone()));
+        Self::extend_if_present(env, &DataKey::PendingVest(farmer.clone(), farm_id));
+        Self::extend_if_present(env, &DataKey::ExtraRewardDebt(farmer.clone(), farm_id));
+        Self::extend_if_present(env, &DataKey::Withdrawal(farmer.clone(), farm_id));
+        Self::extend_if_present(env, &DataKey::UserFarmIndex(farmer.clone()));
+    }
+
+    fn extend_if_present(env: &Env, key: &DataKey) {
+        if env.storage().persistent().has(key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(key, ENTRY_LIFETIME_THRESHOLD, ENTRY_BUMP_AMOUNT);
+        }
     }
 
     // ========== UTILITY FUNCTIONS ==========
+    /// Publishes an event with the next contract-wide sequence number as its
+    /// first topic, so indexers can detect missed events.
+    fn publish_event<T: Topics, D: IntoVal<Env, Val>>(env: &Env, topics: T, data: D) {
+        let sequence: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EventSequence)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::EventSequence, &sequence);
+
+        let mut topics: Vec<Val> = topics.into_val(env);
+        topics.push_front(sequence.into_val(env));
+        env.events().publish(topics, data);
+    }
+
+    /// Appends to the admin log, overwriting the oldest entry once
+    /// `MAX_ADMIN_LOG` are held. Called by every admin-gated entry point.
+    fn log_admin_action(env: &Env, action: Symbol, caller: &Address, target: AdminTarget) {
+        let seq: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminLogTotal)
+            .unwrap_or(0);
+        let key = DataKey::AdminLog((seq % MAX_ADMIN_LOG) as u32);
+        env.storage().persistent().set(
+            &key,
+            &AdminActionLog {
+                seq,
+                action,
+                caller: caller.clone(),
+                target,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Self::extend_if_present(env, &key);
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminLogTotal, &(seq + 1));
+    }
+
     fn safe_transfer(
         env: &Env,
         token: &Address,
@@ -510,14 +1637,67 @@ impl FarmerYieldFarmingContract {
         Ok(true)
     }
 
-    pub fn get_farm(env: Env, farm_id: u32) -> FarmPool {
+    pub fn get_farm(env: Env, farm_id: u32) -> Result<FarmPool, ContractError> {
+        Self::load_farm(&env, farm_id)
+    }
+
+    /// Every farm the farmer has an open position in, oldest stake first
+    pub fn get_all_user_farms(env: Env, farmer: Address) -> Vec<UserFarm> {
+        let mut positions: Vec<UserFarm> = Vec::new(&env);
+        for farm_id in Self::user_farm_index(&env, &farmer) {
+            let Some(user) = env
+                .storage()
+                .persistent()
+                .get::<_, UserFarm>(&DataKey::UserFarm(farmer.clone(), farm_id))
+            else {
+                continue;
+            };
+            // Insertion sort; the index stays small
+            let mut i = positions.len();
+            while i > 0 && positions.get(i - 1).unwrap().stake_time > user.stake_time {
+                i -= 1;
+            }
+            positions.insert(i, user);
+        }
+        positions
+    }
+
+    pub fn get_user_farm_count(env: Env, farmer: Address) -> u32 {
+        Self::user_farm_index(&env, &farmer).len()
+    }
+
+    fn user_farm_index(env: &Env, farmer: &Address) -> Vec<u32> {
         env.storage()
             .persistent()
-            .get(&DataKey::Farm(farm_id))
-            .unwrap()
+            .get(&DataKey::UserFarmIndex(farmer.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn index_user_farm(env: &Env, farmer: &Address, farm_id: u32) {
+        let mut index = Self::user_farm_index(env, farmer);
+        if !index.contains(farm_id) {
+            index.push_back(farm_id);
+            let key = DataKey::UserFarmIndex(farmer.clone());
+            env.storage().persistent().set(&key, &index);
+            Self::extend_if_present(env, &key);
+        }
+    }
+
+    fn unindex_user_farm(env: &Env, farmer: &Address, farm_id: u32) {
+        let mut index = Self::user_farm_index(env, farmer);
+        if let Some(i) = index.first_index_of(farm_id) {
+            index.remove(i);
+            let key = DataKey::UserFarmIndex(farmer.clone());
+            if index.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &index);
+            }
+        }
     }
 
     pub fn get_user_farm(env: Env, farmer: Address, farm_id: u32) -> Option<UserFarm> {
+        Self::extend_user_ttl(&env, &farmer, farm_id);
         env.storage()
             .persistent()
             .get(&DataKey::UserFarm(farmer, farm_id))
@@ -527,6 +1707,35 @@ impl FarmerYieldFarmingContract {
         env.storage().instance().get(&DataKey::Admin).unwrap()
     }
 
+    /// Sequence number of the most recently emitted event, 0 if none
+    pub fn get_event_sequence(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventSequence)
+            .unwrap_or(0)
+    }
+
+    /// Page through the retained admin log, oldest first. `offset` counts from
+    /// the oldest retained entry; `limit` is capped at `MAX_ADMIN_LOG_PAGE`.
+    pub fn get_admin_log(env: Env, offset: u32, limit: u32) -> AdminLogPage {
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminLogTotal)
+            .unwrap_or(0);
+        let first = total.saturating_sub(MAX_ADMIN_LOG) + offset as u64;
+        let end = total.min(first + limit.min(MAX_ADMIN_LOG_PAGE) as u64);
+
+        let mut entries = Vec::new(&env);
+        for seq in first..end {
+            let key = DataKey::AdminLog((seq % MAX_ADMIN_LOG) as u32);
+            if let Some(entry) = env.storage().persistent().get(&key) {
+                entries.push_back(entry);
+            }
+        }
+        AdminLogPage { entries, total }
+    }
+
     pub fn get_farm_count(env: Env) -> u32 {
         env.storage()
             .instance()
@@ -534,6 +1743,24 @@ impl FarmerYieldFarmingContract {
             .unwrap_or(0)
     }
 
+    /// Contract-wide state for monitoring; safe to call before `initialize`
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        let storage = env.storage().instance();
+        ContractInfo {
+            admin: storage.get(&DataKey::Admin),
+            farm_count: storage.get(&DataKey::FarmCount).unwrap_or(0),
+            emergency_withdraw: storage.get(&DataKey::EmergencyWithdraw).unwrap_or(false),
+            global_multiplier: storage
+                .get(&DataKey::GlobalMultiplier)
+                .unwrap_or(BASE_MULTIPLIER),
+        }
+    }
+
+    /// Contract version as (major, minor, patch)
+    pub fn version(_env: Env) -> (u32, u32, u32) {
+        CONTRACT_VERSION
+    }
+
     pub fn set_global_multiplier(env: Env, multiplier: u32) -> Result<(), ContractError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -543,6 +1770,12 @@ impl FarmerYieldFarmingContract {
         env.storage()
             .instance()
             .set(&DataKey::GlobalMultiplier, &multiplier);
+        Self::log_admin_action(
+            &env,
+            symbol_short!("glob_mult"),
+            &admin,
+            AdminTarget::Contract,
+        );
         Ok(())
     }
 
@@ -552,12 +1785,416 @@ impl FarmerYieldFarmingContract {
         env.storage()
             .instance()
             .set(&DataKey::EmergencyWithdraw, &enabled);
+        Self::log_admin_action(
+            &env,
+            symbol_short!("emerg_cfg"),
+            &admin,
+            AdminTarget::Contract,
+        );
+    }
+
+    /// Halts staking, unstaking, harvesting and withdrawals in every farm at once,
+    /// on top of any per-farm pauses
+    pub fn pause_all(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::set_global_pause(&env, &admin, true)
+    }
+
+    /// Lifts a `pause_all`. Farms paused on their own stay paused.
+    pub fn resume_all(env: Env, admin: Address) -> Result<(), ContractError> {
+        Self::set_global_pause(&env, &admin, false)
+    }
+
+    pub fn is_globally_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::GlobalPause)
+            .unwrap_or(false)
+    }
+
+    fn set_global_pause(env: &Env, admin: &Address, paused: bool) -> Result<(), ContractError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)?;
+        if *admin != stored_admin {
+            return Err(ContractError::Unauthorized);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::GlobalPause, &paused);
+        let (event, action) = if paused {
+            ("global_pause", symbol_short!("paus_all"))
+        } else {
+            ("global_resume", symbol_short!("resm_all"))
+        };
+        Self::publish_event(env, (Symbol::new(env, event),), admin.clone());
+        Self::log_admin_action(env, action, admin, AdminTarget::Contract);
+        Ok(())
+    }
+
+    fn ensure_not_globally_paused(env: &Env) -> Result<(), ContractError> {
+        if Self::is_globally_paused(env.clone()) {
+            return Err(ContractError::ContractPaused);
+        }
+        Ok(())
     }
 
+    /// Funds rewards in a token that at least one farm pays out
     pub fn deposit_rewards(env: Env, token: Address, amount: i128) {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::RewardToken(token.clone()))
+        {
+            panic_with_error!(&env, ContractError::RewardTokenNotRegistered);
+        }
         token::Client::new(&env, &token).transfer(&admin, &env.current_contract_address(), &amount);
+        Self::log_admin_action(
+            &env,
+            symbol_short!("dep_rwd"),
+            &admin,
+            AdminTarget::Token(token),
+        );
+    }
+
+    // ========== GOVERNANCE ==========
+    pub fn set_governance_config(
+        env: Env,
+        min_proposal_stake: i128,
+        quorum_bps: u32,
+        approval_bps: u32,
+        voting_period: u32,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if min_proposal_stake <= 0
+            || quorum_bps == 0
+            || quorum_bps > MAX_BPS
+            || approval_bps == 0
+            || approval_bps > MAX_BPS
+            || voting_period == 0
+        {
+            return Err(ContractError::InvalidParameters);
+        }
+        env.storage().instance().set(
+            &DataKey::GovernanceConfig,
+            &GovernanceConfig {
+                min_proposal_stake,
+                quorum_bps,
+                approval_bps,
+                voting_period,
+            },
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("gov_cfg"),
+            &admin,
+            AdminTarget::Contract,
+        );
+        Ok(())
+    }
+
+    pub fn get_governance_config(env: Env) -> GovernanceConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::GovernanceConfig)
+            .unwrap_or(GovernanceConfig {
+                min_proposal_stake: DEFAULT_MIN_PROPOSAL_STAKE,
+                quorum_bps: DEFAULT_QUORUM_BPS,
+                approval_bps: DEFAULT_APPROVAL_BPS,
+                voting_period: DEFAULT_VOTING_PERIOD,
+            })
+    }
+
+    pub fn propose_farm(
+        env: Env,
+        proposer: Address,
+        params: FarmParams,
+    ) -> Result<u32, ContractError> {
+        proposer.require_auth();
+
+        let config = Self::get_governance_config(env.clone());
+        if Self::get_total_user_stake(env.clone(), proposer.clone()) < config.min_proposal_stake {
+            return Err(ContractError::InsufficientProposalStake);
+        }
+        Self::validate_farm_params(&env, &params)?;
+
+        // Voting power is taken from stakes held before this ledger, so staking
+        // after the proposal is created cannot influence the vote
+        let snapshot_ledger = env.ledger().sequence();
+        let total_stake = Self::stake_at(
+            &env.storage()
+                .persistent()
+                .get(&DataKey::TotalStakeCheckpoints)
+                .unwrap_or(Vec::new(&env)),
+            snapshot_ledger,
+        );
+
+        let proposal_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0);
+        let proposal = FarmProposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            params,
+            snapshot_ledger,
+            deadline: snapshot_ledger + config.voting_period,
+            total_stake,
+            for_votes: 0,
+            against_votes: 0,
+            status: ProposalStatus::Active,
+            farm_id: None,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCount, &(proposal_id + 1));
+        Self::extend_if_present(&env, &DataKey::Proposal(proposal_id));
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("prop_new"),),
+            (proposal_id, proposer, proposal.deadline),
+        );
+        Ok(proposal_id)
+    }
+
+    pub fn vote_on_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        support: bool,
+    ) -> Result<i128, ContractError> {
+        voter.require_auth();
+
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ProposalNotActive);
+        }
+        if env.ledger().sequence() >= proposal.deadline {
+            return Err(ContractError::VotingClosed);
+        }
+
+        let vote_key = DataKey::Vote(proposal_id, voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(ContractError::AlreadyVoted);
+        }
+
+        let weight = Self::get_voting_power(env.clone(), voter.clone(), proposal_id);
+        if weight <= 0 {
+            return Err(ContractError::NoVotingPower);
+        }
+
+        if support {
+            proposal.for_votes += weight;
+        } else {
+            proposal.against_votes += weight;
+        }
+
+        env.storage().persistent().set(&vote_key, &support);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("prop_vote"),),
+            (proposal_id, voter, support, weight),
+        );
+        Ok(weight)
+    }
+
+    /// Creates the proposed farm once voting has ended with quorum reached and
+    /// the approval threshold met. Callable by anyone.
+    pub fn execute_proposal(env: Env, proposal_id: u32) -> Result<u32, ContractError> {
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ProposalNotActive);
+        }
+        if env.ledger().sequence() < proposal.deadline {
+            return Err(ContractError::VotingNotEnded);
+        }
+
+        let config = Self::get_governance_config(env.clone());
+        let cast = proposal.for_votes + proposal.against_votes;
+        if proposal.total_stake == 0
+            || cast * (MAX_BPS as i128) < proposal.total_stake * config.quorum_bps as i128
+        {
+            return Err(ContractError::QuorumNotReached);
+        }
+        if proposal.for_votes * (MAX_BPS as i128) <= cast * config.approval_bps as i128 {
+            return Err(ContractError::ProposalRejected);
+        }
+
+        let farm_id = Self::create_farm_internal(&env, proposal.params.clone())?;
+        proposal.status = ProposalStatus::Executed;
+        proposal.farm_id = Some(farm_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("prop_exec"),),
+            (proposal_id, farm_id),
+        );
+        Ok(farm_id)
+    }
+
+    pub fn veto_proposal(env: Env, proposal_id: u32) -> Result<(), ContractError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ProposalNotActive);
+        }
+        proposal.status = ProposalStatus::Vetoed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        Self::publish_event(
+            &env,
+            (soroban_sdk::symbol_short!("prop_veto"),),
+            proposal_id,
+        );
+        Self::log_admin_action(
+            &env,
+            symbol_short!("prop_veto"),
+            &admin,
+            AdminTarget::Proposal(proposal_id),
+        );
+        Ok(())
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<FarmProposal> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+    }
+
+    /// Stake history is pruned once no open proposal needs it, so this is only
+    /// meaningful while the proposal is open for voting
+    pub fn get_voting_power(env: Env, voter: Address, proposal_id: u32) -> i128 {
+        let proposal: FarmProposal = match Self::get_proposal(env.clone(), proposal_id) {
+            Some(p) => p,
+            None => return 0,
+        };
+        let checkpoints: Vec<StakeCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StakeCheckpoints(voter))
+            .unwrap_or(Vec::new(&env));
+        Self::stake_at(&checkpoints, proposal.snapshot_ledger)
+    }
+
+    pub fn get_total_user_stake(env: Env, farmer: Address) -> i128 {
+        let checkpoints: Vec<StakeCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StakeCheckpoints(farmer))
+            .unwrap_or(Vec::new(&env));
+        checkpoints.last().map(|c| c.amount).unwrap_or(0)
+    }
+
+    fn load_proposal(env: &Env, proposal_id: u32) -> Result<FarmProposal, ContractError> {
+        let proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(ContractError::ProposalNotFound)?;
+        Self::extend_if_present(env, &DataKey::Proposal(proposal_id));
+        Ok(proposal)
+    }
+
+    /// Stake recorded strictly before `ledger`
+    fn stake_at(checkpoints: &Vec<StakeCheckpoint>, ledger: u32) -> i128 {
+        let mut amount = 0;
+        for checkpoint in checkpoints.iter() {
+            if checkpoint.ledger >= ledger {
+                break;
+            }
+            amount = checkpoint.amount;
+        }
+        amount
+    }
+
+    fn record_stake_change(env: &Env, farmer: &Address, delta: i128) {
+        let horizon = Self::checkpoint_horizon(env);
+        Self::push_checkpoint(
+            env,
+            &DataKey::StakeCheckpoints(farmer.clone()),
+            delta,
+            horizon,
+        );
+        Self::push_checkpoint(env, &DataKey::TotalStakeCheckpoints, delta, horizon);
+    }
+
+    /// Earliest ledger a stake snapshot may still be read at: the snapshot of the
+    /// oldest proposal open for voting, or the current ledger if none is
+    fn checkpoint_horizon(env: &Env) -> u32 {
+        let now = env.ledger().sequence();
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0);
+        let mut cursor: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::OldestOpenProposal)
+            .unwrap_or(0);
+
+        let mut horizon = now;
+        while cursor < count {
+            let proposal: Option<FarmProposal> =
+                env.storage().persistent().get(&DataKey::Proposal(cursor));
+            if let Some(proposal) =
+                proposal.filter(|p| p.status == ProposalStatus::Active && p.deadline > now)
+            {
+                horizon = proposal.snapshot_ledger;
+                break;
+            }
+            cursor += 1;
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::OldestOpenProposal, &cursor);
+        horizon
+    }
+
+    fn push_checkpoint(env: &Env, key: &DataKey, delta: i128, horizon: u32) {
+        let ledger = env.ledger().sequence();
+        let mut checkpoints: Vec<StakeCheckpoint> =
+            env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+        let current = checkpoints.last().map(|c| c.amount).unwrap_or(0);
+        let checkpoint = StakeCheckpoint {
+            ledger,
+            amount: current + delta,
+        };
+        // Several changes within one ledger collapse into a single checkpoint
+        match checkpoints.last() {
+            Some(last) if last.ledger == ledger => {
+                checkpoints.set(checkpoints.len() - 1, checkpoint)
+            }
+            _ => checkpoints.push_back(checkpoint),
+        }
+
+        // Only the last checkpoint before the horizon is still needed to read the
+        // stake at it; everything older is dropped
+        let before_horizon = checkpoints
+            .iter()
+            .take_while(|c| c.ledger < horizon)
+            .count() as u32;
+        if before_horizon > 1 {
+            checkpoints = checkpoints.slice(before_horizon - 1..);
+        }
+        env.storage().persistent().set(key, &checkpoints);
     }
 }
 
@@ -573,3 +2210,39 @@ mod staking;
 
 // Reward harvesting and distribution tests
 mod rewards;
+
+// Governance proposals for farm creation
+mod governance;
+
+// Event sequence numbering tests
+mod events;
+
+// Storage TTL extension and archival tests
+mod ttl;
+
+// Admin action log tests
+mod admin_log;
+
+// Referral bonus tests
+mod referral;
+
+// Reward vesting tests
+mod vesting;
+
+// Multiple reward token tests
+mod multi_reward;
+
+// Stake migration tests
+mod migration;
+
+// Unstake cooldown tests
+mod cooldown;
+
+// User farm index tests
+mod user_farms;
+
+// Farm end and reward math tests
+mod end_block;
+
+// Global pause tests
+mod global_pause;
@@ -0,0 +1,253 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::ContractError;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, Symbol, TryFromVal,
+};
+
+// ================================================================================
+// STAKE MIGRATION TESTS
+// ================================================================================
+
+/// A successor farm for the same LP and reward tokens as the one from
+/// `setup_farm_with_rewards`
+fn create_successor_farm(ctx: &TestContext) -> u32 {
+    ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &200000,
+    )
+}
+
+#[test]
+fn test_migrate_stake_moves_position_and_pays_rewards() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let dst = create_successor_farm(&ctx);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+
+    // Migrating within the minimum stake period halves the source rewards, as
+    // unstaking would
+    advance_ledger(&ctx.env, 100);
+    let (pending, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &src);
+    ctx.client.migrate_stake(&ctx.farmer1, &src, &dst);
+
+    let (_, topics, data) = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap(),
+        Symbol::new(&ctx.env, "stake_migrated")
+    );
+    assert_eq!(
+        <(Address, u32, u32, i128)>::try_from_val(&ctx.env, &data).unwrap(),
+        (ctx.farmer1.clone(), src, dst, 10_000_0000000)
+    );
+
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending / 2
+    );
+    assert_eq!(get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1), 0);
+    assert!(ctx.client.get_user_farm(&ctx.farmer1, &src).is_none());
+    assert_eq!(ctx.client.get_farm(&src).total_staked, 0);
+    assert_eq!(ctx.client.get_farm(&dst).total_staked, 10_000_0000000);
+
+    let user = ctx.client.get_user_farm(&ctx.farmer1, &dst).unwrap();
+    assert_eq!(user.amount, 10_000_0000000);
+    assert_eq!(user.stake_time, 1200);
+    assert_eq!(
+        ctx.client.get_total_user_stake(&ctx.farmer1),
+        10_000_0000000
+    );
+
+    // Rewards keep accruing in the destination from the migration on
+    assert_eq!(ctx.client.get_pending_rewards(&ctx.farmer1, &src).0, 0);
+    advance_ledger(&ctx.env, 100);
+    let (next, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &dst);
+    assert!(next > 0);
+    ctx.client.harvest(&ctx.farmer1, &dst);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending / 2 + next
+    );
+}
+
+#[test]
+fn test_migrate_stake_merges_into_existing_position() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let dst = create_successor_farm(&ctx);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 15_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+    ctx.client.stake_lp(&ctx.farmer1, &dst, &5_000_0000000);
+
+    advance_ledger(&ctx.env, 100);
+    let (from_src, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &src);
+    let (from_dst, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &dst);
+    ctx.client.migrate_stake(&ctx.farmer1, &src, &dst);
+
+    // Both farms' rewards are harvested before the stakes are combined, the
+    // source's with the early unstake penalty
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        from_src / 2 + from_dst
+    );
+    assert_eq!(
+        ctx.client.get_user_farm(&ctx.farmer1, &dst).unwrap().amount,
+        15_000_0000000
+    );
+    assert_eq!(ctx.client.get_pending_rewards(&ctx.farmer1, &dst).0, 0);
+}
+
+#[test]
+fn test_migrate_stake_validation() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let dst = create_successor_farm(&ctx);
+    let other_token = Address::generate(&ctx.env);
+    let other_reward = ctx.client.create_farm(
+        &ctx.lp_token,
+        &other_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    );
+    let other_lp = ctx.client.create_farm(
+        &other_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+
+    assert_eq!(
+        ctx.client
+            .try_migrate_stake(&ctx.farmer1, &src, &other_reward),
+        Err(Ok(ContractError::IncompatibleFarms))
+    );
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &other_lp),
+        Err(Ok(ContractError::IncompatibleFarms))
+    );
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &src),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer2, &src, &dst),
+        Err(Ok(ContractError::NoStakeFound))
+    );
+
+    ctx.client.set_farm_paused(&dst, &true);
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &dst),
+        Err(Ok(ContractError::FarmPaused))
+    );
+    ctx.client.set_farm_paused(&dst, &false);
+    ctx.client.end_farm(&dst);
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &dst),
+        Err(Ok(ContractError::FarmNotActive))
+    );
+
+    // Nothing moved
+    assert_eq!(
+        ctx.client.get_user_farm(&ctx.farmer1, &src).unwrap().amount,
+        10_000_0000000
+    );
+}
+
+#[test]
+fn test_migrate_stake_keeps_source_cooldown() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let dst = create_successor_farm(&ctx);
+    ctx.client.set_farm_cooldown(&src, &500);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+
+    // A farm without the cooldown would let the stake be unstaked straight away
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &dst),
+        Err(Ok(ContractError::CooldownRequired))
+    );
+    ctx.client.set_farm_cooldown(&dst, &300);
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &dst),
+        Err(Ok(ContractError::CooldownRequired))
+    );
+
+    ctx.client.set_farm_cooldown(&dst, &500);
+    ctx.client.migrate_stake(&ctx.farmer1, &src, &dst);
+    assert_eq!(
+        ctx.client
+            .try_unstake_lp(&ctx.farmer1, &dst, &10_000_0000000),
+        Err(Ok(ContractError::CooldownRequired))
+    );
+}
+
+#[test]
+fn test_migrate_stake_pays_in_full_after_min_stake_period() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 100_000_000_0000000);
+    let dst = ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &200000,
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+
+    advance_ledger(&ctx.env, 86400);
+    let (pending, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &src);
+    assert!(pending > 0);
+    ctx.client.migrate_stake(&ctx.farmer1, &src, &dst);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending
+    );
+}
+
+#[test]
+fn test_migrate_stake_fails_when_rewards_cannot_be_paid() {
+    let ctx = setup_test();
+    let src = setup_farm_with_rewards(&ctx, 1_0000000);
+    let dst = create_successor_farm(&ctx);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &src, &10_000_0000000);
+
+    advance_ledger(&ctx.env, 100);
+    assert_eq!(
+        ctx.client.try_migrate_stake(&ctx.farmer1, &src, &dst),
+        Err(Ok(ContractError::InsufficientBalance))
+    );
+    assert_eq!(
+        ctx.client.get_user_farm(&ctx.farmer1, &src).unwrap().amount,
+        10_000_0000000
+    );
+    assert_eq!(ctx.client.get_farm(&dst).total_staked, 0);
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{ContractError, MAX_EXTRA_REWARDS};
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+// ================================================================================
+// MULTIPLE REWARD TOKEN TESTS
+// ================================================================================
+
+/// Registers a new token, adds it to the farm at `reward_per_block` and funds it
+fn add_funded_reward_token(ctx: &TestContext, farm_id: u32, reward_per_block: i128) -> Address {
+    let token = ctx
+        .env
+        .register_stellar_asset_contract_v2(ctx.admin.clone())
+        .address();
+    ctx.client
+        .add_reward_token(&farm_id, &token, &reward_per_block);
+    mint_reward_tokens(&ctx.env, &token, &ctx.admin, 10_000_000_0000000);
+    ctx.client.deposit_rewards(&token, &10_000_000_0000000);
+    token
+}
+
+#[test]
+fn test_extra_rewards_harvested_alongside_main_reward() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let stable = add_funded_reward_token(&ctx, farm_id, 50_0000000);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    advance_ledger(&ctx.env, 100);
+
+    // Each token accrues at its own rate with the same bonuses applied
+    let pending = ctx.client.get_pending_rewards_all(&ctx.farmer1, &farm_id);
+    let (_, main) = pending.get(0).unwrap();
+    assert!(main > 0);
+    assert_eq!(
+        pending,
+        vec![
+            &ctx.env,
+            (ctx.reward_token.clone(), main),
+            (stable.clone(), main / 2)
+        ]
+    );
+
+    assert_eq!(ctx.client.harvest(&ctx.farmer1, &farm_id), pending);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1), main);
+    assert_eq!(get_balance(&ctx.env, &stable, &ctx.farmer1), main / 2);
+
+    // Early unstakes forfeit half of every reward token
+    advance_ledger(&ctx.env, 100);
+    let (_, extra) = ctx
+        .client
+        .get_pending_rewards_all(&ctx.farmer1, &farm_id)
+        .get(1)
+        .unwrap();
+    ctx.client
+        .unstake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    assert_eq!(
+        get_balance(&ctx.env, &stable, &ctx.farmer1),
+        main / 2 + extra / 2
+    );
+    assert_eq!(
+        ctx.client.get_pending_rewards_all(&ctx.farmer1, &farm_id),
+        vec![&ctx.env, (ctx.reward_token.clone(), 0), (stable, 0)]
+    );
+}
+
+#[test]
+fn test_extra_reward_accrues_from_when_it_is_added() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+
+    advance_ledger(&ctx.env, 100);
+    let stable = add_funded_reward_token(&ctx, farm_id, 100_0000000);
+    let pending = ctx.client.get_pending_rewards_all(&ctx.farmer1, &farm_id);
+    assert_eq!(pending.get(1), Some((stable.clone(), 0)));
+
+    // A farmer joining later only earns the extra reward from their stake on
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer2, 10_000_0000000);
+    advance_ledger(&ctx.env, 100);
+    ctx.client.stake_lp(&ctx.farmer2, &farm_id, &10_000_0000000);
+    advance_ledger(&ctx.env, 100);
+
+    let (_, first) = ctx
+        .client
+        .get_pending_rewards_all(&ctx.farmer1, &farm_id)
+        .get(1)
+        .unwrap();
+    let (_, second) = ctx
+        .client
+        .get_pending_rewards_all(&ctx.farmer2, &farm_id)
+        .get(1)
+        .unwrap();
+    // farmer1 earned it alone for 100 blocks, then shared it for 100 more
+    assert_eq!(first, second * 3);
+
+    ctx.client.harvest(&ctx.farmer2, &farm_id);
+    assert_eq!(get_balance(&ctx.env, &stable, &ctx.farmer2), second);
+}
+
+#[test]
+fn test_add_reward_token_validation() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 0);
+    let token = Address::generate(&ctx.env);
+
+    // Rewards can only be deposited in tokens some farm pays out
+    assert_eq!(
+        ctx.client.try_deposit_rewards(&token, &1),
+        Err(Ok(ContractError::RewardTokenNotRegistered.into()))
+    );
+    assert_eq!(
+        ctx.client.try_add_reward_token(&farm_id, &token, &0),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(
+        ctx.client
+            .try_add_reward_token(&farm_id, &ctx.reward_token, &100),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(
+        ctx.client.try_add_reward_token(&99, &token, &100),
+        Err(Ok(ContractError::FarmNotFound))
+    );
+
+    let stable = add_funded_reward_token(&ctx, farm_id, 100);
+    assert_eq!(
+        ctx.client.try_add_reward_token(&farm_id, &stable, &100),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+    assert_eq!(ctx.client.get_extra_rewards(&farm_id).len(), 1);
+
+    for _ in 1..MAX_EXTRA_REWARDS {
+        add_funded_reward_token(&ctx, farm_id, 100);
+    }
+    assert_eq!(
+        ctx.client.try_add_reward_token(&farm_id, &token, &100),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{ContractError, ReferralConfig};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Symbol, TryFromVal,
+};
+
+// ================================================================================
+// REFERRAL BONUS TESTS
+// ================================================================================
+
+/// A funded farm with farmer1 staked from ledger 1200, referred by farmer2
+fn setup_referred_stake(ctx: &TestContext) -> u32 {
+    let farm_id = setup_farm_with_rewards(ctx, 10_000_000_0000000);
+    ctx.client.register_referral(&ctx.farmer2, &ctx.farmer1);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 20_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    farm_id
+}
+
+#[test]
+fn test_referrer_earns_share_of_referee_harvests() {
+    let ctx = setup_test();
+    let farm_id = setup_referred_stake(&ctx);
+    assert_eq!(
+        ctx.client.get_referrer(&ctx.farmer1),
+        Some(ctx.farmer2.clone())
+    );
+    ctx.client.set_referral_config(&200, &1_000_000_0000000);
+    assert_eq!(
+        ctx.client.get_referral_config(),
+        ReferralConfig {
+            bonus_bps: 200,
+            max_bonus_per_farm: 1_000_000_0000000,
+        }
+    );
+
+    advance_ledger(&ctx.env, 100);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    let bonus = pending * 200 / 10_000;
+
+    let (_, topics, data) = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap(),
+        symbol_short!("ref_bonus")
+    );
+    assert_eq!(
+        <(Address, Address, u32, i128)>::try_from_val(&ctx.env, &data).unwrap(),
+        (ctx.farmer2.clone(), ctx.farmer1.clone(), farm_id, bonus)
+    );
+
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2),
+        bonus
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending
+    );
+    assert_eq!(
+        ctx.client.get_referral_bonus_paid(&ctx.farmer2, &farm_id),
+        bonus
+    );
+
+    // Rewards paid out when the referee tops up their stake count as well
+    advance_ledger(&ctx.env, 100);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &1_000_0000000);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2),
+        bonus + pending * 200 / 10_000
+    );
+}
+
+#[test]
+fn test_referral_bonus_capped_per_farm() {
+    let ctx = setup_test();
+    let farm_id = setup_referred_stake(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
+    let cap = pending * 200 / 10_000 + 1_000;
+    ctx.client.set_referral_config(&200, &cap);
+
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client.get_referral_bonus_paid(&ctx.farmer2, &farm_id),
+        cap - 1_000
+    );
+
+    // Only what is left under the cap is paid, then nothing more
+    advance_ledger(&ctx.env, 100);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client.get_referral_bonus_paid(&ctx.farmer2, &farm_id),
+        cap
+    );
+    advance_ledger(&ctx.env, 100);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2), cap);
+}
+
+#[test]
+fn test_referral_bonus_stops_after_farm_ends() {
+    let ctx = setup_test();
+    let farm_id = setup_referred_stake(&ctx);
+    ctx.client.set_referral_config(&200, &1_000_000_0000000);
+
+    // Enough rewards to cover the whole farm
+    mint_reward_tokens(
+        &ctx.env,
+        &ctx.reward_token,
+        &ctx.admin,
+        1_000_000_000_0000000,
+    );
+    ctx.client
+        .deposit_rewards(&ctx.reward_token, &1_000_000_000_0000000);
+
+    // Rewards accrued before the end block are still harvested, without a bonus
+    set_ledger_sequence(&ctx.env, 100_001);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
+    assert!(pending > 0);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending
+    );
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2), 0);
+    assert_eq!(
+        ctx.client.get_referral_bonus_paid(&ctx.farmer2, &farm_id),
+        0
+    );
+}
+
+#[test]
+fn test_referral_registration_rules() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let other = Address::generate(&ctx.env);
+
+    assert_eq!(
+        ctx.client.try_register_referral(&ctx.farmer1, &ctx.farmer1),
+        Err(Ok(ContractError::InvalidReferral))
+    );
+
+    // A referee keeps their first referrer
+    ctx.client.register_referral(&ctx.farmer2, &ctx.farmer1);
+    assert_eq!(
+        ctx.client.try_register_referral(&other, &ctx.farmer1),
+        Err(Ok(ContractError::InvalidReferral))
+    );
+    assert_eq!(
+        ctx.client.get_referrer(&ctx.farmer1),
+        Some(ctx.farmer2.clone())
+    );
+    assert_eq!(ctx.client.get_referrer(&other), None);
+
+    assert_eq!(
+        ctx.client.try_set_referral_config(&10_001, &0),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    // Without a configured bonus referrers earn nothing
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    advance_ledger(&ctx.env, 100);
+    let paid = ctx
+        .client
+        .batch_harvest(&ctx.farmer1, &vec![&ctx.env, farm_id]);
+    assert!(paid.get(0).unwrap() > 0);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer2), 0);
+}
@@ -1,7 +1,9 @@
 #![cfg(test)]
 
 use super::utils::*;
+use crate::datatype::{ContractError, MAX_BATCH_HARVEST};
 use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, testutils::Events, vec, Symbol, TryFromVal, Vec};
     
 
 // ================================================================================
@@ -25,7 +27,7 @@ fn test_harvest_rewards_success() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 17280 * 30);
-    let pending_30days = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending_30days = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending_30days > 0);
 }
@@ -70,7 +72,7 @@ fn test_rewards_stop_after_end_block() {
 
     set_ledger_sequence(&ctx.env, 2500);
 
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -93,7 +95,7 @@ fn test_rewards_calculation_accuracy() {
 
     advance_ledger(&ctx.env, 100);
 
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -115,7 +117,7 @@ fn test_max_multiplier_rewards() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -141,7 +143,7 @@ fn test_farmer_tier_smallholder() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &500_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -163,7 +165,7 @@ fn test_farmer_tier_cooperative() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &5_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -185,7 +187,7 @@ fn test_farmer_tier_enterprise() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &15_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -207,7 +209,7 @@ fn test_loyalty_bonus_7_days() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 17280 * 7);
-    let pending_7days = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending_7days = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending_7days > 0);
 }
@@ -251,7 +253,7 @@ fn test_get_pending_rewards() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -266,7 +268,7 @@ fn test_pending_rewards_no_stake() {
     let farm_id = ctx.client.create_farm(&ctx.lp_token, &ctx.reward_token, &100_0000000, &150, &1100, &100000);
 
     let farmer3 = soroban_sdk::Address::generate(&ctx.env);
-    let pending = ctx.client.get_pending_rewards(&farmer3, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&farmer3, &farm_id).0;
     assert_eq!(pending, 0);
 }
 
@@ -355,3 +357,151 @@ fn test_rewards_distribution_multiple_farmers() {
     let diff = if reward1 > reward2 { reward1 - reward2 } else { reward2 - reward1 };
     assert!(diff <= 10); // allow tiny rounding differences
 }
+
+// ================================================================================
+// BATCH HARVEST TESTS
+// ================================================================================
+
+/// Two farms paying `ctx.reward_token`, both staked by farmer1 from ledger 1200
+fn setup_two_staked_farms(ctx: &TestContext) -> (u32, u32) {
+    let first = setup_farm_with_rewards(ctx, 10_000_000_0000000);
+    let second = ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &50_0000000,
+        &100,
+        &1100,
+        &100000,
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 20_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &first, &10_000_0000000);
+    ctx.client.stake_lp(&ctx.farmer1, &second, &10_000_0000000);
+
+    (first, second)
+}
+
+#[test]
+fn test_batch_harvest_pays_each_farm() {
+    let ctx = setup_test();
+    let (first, second) = setup_two_staked_farms(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let expected_first = ctx.client.get_pending_rewards(&ctx.farmer1, &first).0;
+    let expected_second = ctx.client.get_pending_rewards(&ctx.farmer1, &second).0;
+    assert!(expected_first > expected_second && expected_second > 0);
+
+    let paid = ctx
+        .client
+        .batch_harvest(&ctx.farmer1, &vec![&ctx.env, first, second]);
+    assert_eq!(paid, vec![&ctx.env, expected_first, expected_second]);
+
+    // One harvest event per farm
+    let harvests = ctx
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            *contract == ctx.client.address
+                && Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap()
+                    == symbol_short!("harvest")
+        })
+        .count();
+    assert_eq!(harvests, 2);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        expected_first + expected_second
+    );
+
+    // Reward debt is settled, so harvesting again in the same ledger pays nothing
+    assert_eq!(ctx.client.get_pending_rewards(&ctx.farmer1, &first).0, 0);
+    let paid = ctx
+        .client
+        .batch_harvest(&ctx.farmer1, &vec![&ctx.env, second, first]);
+    assert_eq!(paid, vec![&ctx.env, 0, 0]);
+
+    // Later rewards accrue from the harvest point only
+    advance_ledger(&ctx.env, 100);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &first).0;
+    let paid = ctx.client.batch_harvest(&ctx.farmer1, &vec![&ctx.env, first]);
+    assert_eq!(paid, vec![&ctx.env, pending]);
+}
+
+#[test]
+fn test_batch_harvest_rolls_back_on_insufficient_balance() {
+    let ctx = setup_test();
+    let (first, second) = setup_two_staked_farms(&ctx);
+
+    // A third farm, created before its start block, whose reward token was never deposited
+    set_ledger_sequence(&ctx.env, 1000);
+    let unfunded_token = ctx
+        .env
+        .register_stellar_asset_contract_v2(ctx.admin.clone())
+        .address();
+    let unfunded = ctx.client.create_farm(
+        &ctx.lp_token,
+        &unfunded_token,
+        &100_0000000,
+        &100,
+        &1100,
+        &100000,
+    );
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &unfunded, &10_000_0000000);
+
+    advance_ledger(&ctx.env, 100);
+    let pending_first = ctx.client.get_pending_rewards(&ctx.farmer1, &first).0;
+    let result = ctx
+        .client
+        .try_batch_harvest(&ctx.farmer1, &vec![&ctx.env, first, unfunded, second]);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+    // Nothing was paid out, including for the farm processed before the failure
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1), 0);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &first).0,
+        pending_first
+    );
+
+    // The funded farms can still be harvested without the failing one
+    let paid = ctx
+        .client
+        .batch_harvest(&ctx.farmer1, &vec![&ctx.env, first, second]);
+    assert_eq!(paid.get(0).unwrap(), pending_first);
+}
+
+#[test]
+fn test_batch_harvest_rejects_invalid_batches() {
+    let ctx = setup_test();
+    let (first, _) = setup_two_staked_farms(&ctx);
+
+    assert_eq!(
+        ctx.client
+            .try_batch_harvest(&ctx.farmer1, &Vec::new(&ctx.env)),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    let mut too_many = Vec::new(&ctx.env);
+    for _ in 0..=MAX_BATCH_HARVEST {
+        too_many.push_back(first);
+    }
+    assert_eq!(
+        ctx.client.try_batch_harvest(&ctx.farmer1, &too_many),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    // A farm the farmer never staked in fails the batch rather than being skipped
+    assert_eq!(
+        ctx.client
+            .try_batch_harvest(&ctx.farmer2, &vec![&ctx.env, first]),
+        Err(Ok(ContractError::NoStakeFound))
+    );
+    assert_eq!(
+        ctx.client
+            .try_batch_harvest(&ctx.farmer1, &vec![&ctx.env, first, 99]),
+        Err(Ok(ContractError::FarmNotFound))
+    );
+}
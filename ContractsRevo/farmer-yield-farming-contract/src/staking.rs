@@ -137,7 +137,7 @@ fn test_farm_with_same_lp_and_reward_token() {
     ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&ctx.env, 100);
-    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    let pending = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -159,6 +159,8 @@ fn test_deposit_rewards() {
     let ctx = setup_test();
 
     ctx.client.initialize(&ctx.admin);
+    set_ledger_sequence(&ctx.env, 1000);
+    ctx.client.create_farm(&ctx.lp_token, &ctx.reward_token, &100_0000000, &150, &1100, &100000);
 
     mint_reward_tokens(&ctx.env, &ctx.reward_token, &ctx.admin, 1_000_000_0000000);
 
@@ -206,7 +206,7 @@ fn test_precision_with_small_amounts() {
     client.stake_lp(&farmer1, &farm_id, &100_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending >= 0);
 }
@@ -228,7 +228,7 @@ fn test_farm_with_same_lp_and_reward_token() {
     client.stake_lp(&farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -250,7 +250,7 @@ fn test_max_multiplier_rewards() {
     client.stake_lp(&farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -273,7 +273,7 @@ fn test_rewards_calculation_accuracy() {
 
     advance_ledger(&env, 100);
 
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 
@@ -503,7 +503,7 @@ fn test_get_pending_rewards() {
     client.stake_lp(&farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -518,7 +518,7 @@ fn test_pending_rewards_no_stake() {
     let farm_id = client.create_farm(&lp_token, &reward_token, &100_0000000, &150, &1100, &100000);
 
     let farmer3 = Address::generate(&env);
-    let pending = client.get_pending_rewards(&farmer3, &farm_id);
+    let pending = client.get_pending_rewards(&farmer3, &farm_id).0;
     assert_eq!(pending, 0);
 }
 
@@ -566,9 +566,11 @@ fn test_set_global_multiplier() {
 
 #[test]
 fn test_deposit_rewards() {
-    let (env, client, admin, _, _, _, reward_token) = setup_test();
+    let (env, client, admin, _, _, lp_token, reward_token) = setup_test();
 
     client.initialize(&admin);
+    set_ledger_sequence(&env, 1000);
+    client.create_farm(&lp_token, &reward_token, &100_0000000, &150, &1100, &100000);
 
     mint_reward_tokens(&env, &reward_token, &admin, 1_000_000_0000000);
 
@@ -621,7 +623,7 @@ fn test_farmer_tier_smallholder() {
     client.stake_lp(&farmer1, &farm_id, &500_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -643,7 +645,7 @@ fn test_farmer_tier_cooperative() {
     client.stake_lp(&farmer1, &farm_id, &5_000_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -665,7 +667,7 @@ fn test_farmer_tier_enterprise() {
     client.stake_lp(&farmer1, &farm_id, &15_000_0000000);
 
     advance_ledger(&env, 100);
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
@@ -688,7 +690,7 @@ fn test_loyalty_bonus_7_days() {
     client.stake_lp(&farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&env, 17280 * 7);
-    let pending_7days = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending_7days = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending_7days > 0);
 }
@@ -710,7 +712,7 @@ fn test_loyalty_bonus_30_days() {
     client.stake_lp(&farmer1, &farm_id, &10_000_0000000);
 
     advance_ledger(&env, 17280 * 30);
-    let pending_30days = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending_30days = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending_30days > 0);
 }
@@ -767,8 +769,8 @@ fn test_rewards_distribution_multiple_farmers() {
 
     advance_ledger(&env, 100);
 
-    let pending1 = client.get_pending_rewards(&farmer1, &farm_id);
-    let pending2 = client.get_pending_rewards(&farmer2, &farm_id);
+    let pending1 = client.get_pending_rewards(&farmer1, &farm_id).0;
+    let pending2 = client.get_pending_rewards(&farmer2, &farm_id).0;
 
     assert!(pending1 > 0);
     assert!(pending2 > 0);
@@ -792,7 +794,7 @@ fn test_rewards_stop_after_end_block() {
 
     set_ledger_sequence(&env, 2500);
 
-    let pending = client.get_pending_rewards(&farmer1, &farm_id);
+    let pending = client.get_pending_rewards(&farmer1, &farm_id).0;
 
     assert!(pending > 0);
 }
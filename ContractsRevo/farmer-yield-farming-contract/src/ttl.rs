@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{
+    ContractError, DataKey, EntryKeys, ENTRY_BUMP_AMOUNT, ENTRY_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{
+    testutils::storage::{Instance, Persistent},
+    vec, Vec,
+};
+
+// ================================================================================
+// TTL TEST HELPERS
+// ================================================================================
+
+fn persistent_ttl(ctx: &TestContext, key: &DataKey) -> u32 {
+    ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env.storage().persistent().get_ttl(key)
+    })
+}
+
+/// Moves the ledger to just before the contract instance expires, which
+/// leaves the farm entries created after initialization close to expiry too.
+fn advance_near_expiry(ctx: &TestContext) {
+    let instance_ttl = ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env.storage().instance().get_ttl()
+    });
+    advance_ledger(&ctx.env, instance_ttl - 10);
+}
+
+fn setup_staked_farm(ctx: &TestContext) -> u32 {
+    let farm_id = setup_farm_with_rewards(ctx, 0);
+    set_ledger_sequence(&ctx.env, 1200);
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 5000);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &5000);
+    farm_id
+}
+
+// ================================================================================
+// TTL EXTENSION TESTS
+// ================================================================================
+
+#[test]
+fn test_reads_extend_entries_near_expiry() {
+    let ctx = setup_test();
+    let farm_id = setup_staked_farm(&ctx);
+    let user_key = DataKey::UserFarm(ctx.farmer1.clone(), farm_id);
+
+    advance_near_expiry(&ctx);
+    assert!(persistent_ttl(&ctx, &user_key) < ENTRY_LIFETIME_THRESHOLD);
+
+    ctx.client.get_farm(&farm_id);
+    ctx.client.get_user_farm(&ctx.farmer1, &farm_id);
+
+    assert_eq!(persistent_ttl(&ctx, &user_key), ENTRY_BUMP_AMOUNT);
+    assert_eq!(
+        persistent_ttl(&ctx, &DataKey::Farm(farm_id)),
+        ENTRY_BUMP_AMOUNT
+    );
+    assert_eq!(
+        persistent_ttl(&ctx, &DataKey::Paused(farm_id)),
+        ENTRY_BUMP_AMOUNT
+    );
+}
+
+#[test]
+fn test_bump_entries_extends_requested_entries() {
+    let ctx = setup_test();
+    let farm_id = setup_staked_farm(&ctx);
+
+    advance_near_expiry(&ctx);
+    ctx.client.bump_entries(&EntryKeys {
+        farms: vec![&ctx.env, farm_id],
+        user_farms: vec![&ctx.env, (ctx.farmer1.clone(), farm_id)],
+        proposals: Vec::new(&ctx.env),
+    });
+
+    for key in [
+        DataKey::Farm(farm_id),
+        DataKey::Paused(farm_id),
+        DataKey::UserFarm(ctx.farmer1.clone(), farm_id),
+        DataKey::StakeCheckpoints(ctx.farmer1.clone()),
+        DataKey::TotalStakeCheckpoints,
+    ] {
+        assert_eq!(persistent_ttl(&ctx, &key), ENTRY_BUMP_AMOUNT);
+    }
+
+    // Positions that do not exist are skipped
+    ctx.client.bump_entries(&EntryKeys {
+        farms: vec![&ctx.env, 7],
+        user_farms: vec![&ctx.env, (ctx.farmer2.clone(), farm_id)],
+        proposals: vec![&ctx.env, 3],
+    });
+}
+
+// ================================================================================
+// ARCHIVED ENTRY TESTS
+// ================================================================================
+
+#[test]
+fn test_missing_farm_reports_archived_entry() {
+    let ctx = setup_test();
+    let farm_id = setup_staked_farm(&ctx);
+
+    // Stand in for the farm record leaving the live state
+    ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env
+            .storage()
+            .persistent()
+            .remove(&DataKey::Farm(farm_id));
+    });
+
+    assert!(matches!(
+        ctx.client.try_get_farm(&farm_id),
+        Err(Ok(ContractError::EntryArchived))
+    ));
+    assert_eq!(
+        ctx.client.try_harvest(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::EntryArchived))
+    );
+
+    // Farms that were never created are still reported as not found
+    assert!(matches!(
+        ctx.client.try_get_farm(&(farm_id + 1)),
+        Err(Ok(ContractError::FarmNotFound))
+    ));
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use super::utils::*;
+use soroban_sdk::{vec, Vec};
+
+// ================================================================================
+// USER FARM INDEX TESTS
+// ================================================================================
+
+fn create_farm(ctx: &TestContext) -> u32 {
+    ctx.client.create_farm(
+        &ctx.lp_token,
+        &ctx.reward_token,
+        &100_0000000,
+        &150,
+        &1100,
+        &100000,
+    )
+}
+
+#[test]
+fn test_all_user_farms_ordered_by_stake_time() {
+    let ctx = setup_test();
+    let first = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let second = create_farm(&ctx);
+    let third = create_farm(&ctx);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 0);
+    assert!(ctx.client.get_all_user_farms(&ctx.farmer1).is_empty());
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 7_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &third, &3_000_0000000);
+    advance_ledger(&ctx.env, 100);
+    ctx.client.stake_lp(&ctx.farmer1, &first, &1_000_0000000);
+    advance_ledger(&ctx.env, 100);
+    ctx.client.stake_lp(&ctx.farmer1, &second, &2_000_0000000);
+
+    // Topping up a farm restarts its stake time without adding it twice
+    advance_ledger(&ctx.env, 100);
+    ctx.client.stake_lp(&ctx.farmer1, &third, &100);
+
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 3);
+    let farms = ctx.client.get_all_user_farms(&ctx.farmer1);
+    assert_eq!(farms.len(), 3);
+    let mut stakes = Vec::new(&ctx.env);
+    for user in farms.iter() {
+        assert_eq!(user.farmer, ctx.farmer1);
+        stakes.push_back((user.amount, user.stake_time));
+    }
+    assert_eq!(
+        stakes,
+        vec![
+            &ctx.env,
+            (1_000_0000000, 1300),
+            (2_000_0000000, 1400),
+            (3_000_0000100, 1500)
+        ]
+    );
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer2), 0);
+}
+
+#[test]
+fn test_user_farm_index_after_full_unstake_and_restake() {
+    let ctx = setup_test();
+    let first = setup_farm_with_rewards(&ctx, 10_000_000_0000000);
+    let second = create_farm(&ctx);
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &first, &4_000_0000000);
+    ctx.client.stake_lp(&ctx.farmer1, &second, &4_000_0000000);
+
+    // A partial unstake keeps the farm listed, a full one drops it
+    advance_ledger(&ctx.env, 100);
+    ctx.client.unstake_lp(&ctx.farmer1, &first, &1_000_0000000);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 2);
+    ctx.client.unstake_lp(&ctx.farmer1, &first, &3_000_0000000);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 1);
+    assert_eq!(
+        ctx.client
+            .get_all_user_farms(&ctx.farmer1)
+            .get(0)
+            .unwrap()
+            .amount,
+        4_000_0000000
+    );
+
+    // Staking again lists the farm once more, after the older position
+    advance_ledger(&ctx.env, 100);
+    ctx.client.stake_lp(&ctx.farmer1, &first, &2_000_0000000);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 2);
+    let farms = ctx.client.get_all_user_farms(&ctx.farmer1);
+    assert_eq!(farms.get(0).unwrap().amount, 4_000_0000000);
+    assert_eq!(farms.get(1).unwrap().amount, 2_000_0000000);
+    assert_eq!(farms.get(1).unwrap().stake_time, 1400);
+
+    // Migrating merges the positions into one entry
+    ctx.client.migrate_stake(&ctx.farmer1, &first, &second);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 1);
+    assert_eq!(
+        ctx.client
+            .get_all_user_farms(&ctx.farmer1)
+            .get(0)
+            .unwrap()
+            .amount,
+        6_000_0000000
+    );
+
+    ctx.client.set_emergency_withdraw(&true);
+    ctx.client.emergency_withdraw(&ctx.farmer1, &second);
+    assert_eq!(ctx.client.get_user_farm_count(&ctx.farmer1), 0);
+    assert!(ctx.client.get_all_user_farms(&ctx.farmer1).is_empty());
+}
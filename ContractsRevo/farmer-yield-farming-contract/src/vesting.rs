@@ -0,0 +1,297 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::datatype::{ContractError, VestingPosition, VestingSchedule};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Symbol, TryFromVal,
+};
+
+// ================================================================================
+// REWARD VESTING TESTS
+// ================================================================================
+
+/// A funded farm vesting over 1000 blocks with a 100 block cliff, and farmer1
+/// staked from ledger 1200
+fn setup_vested_stake(ctx: &TestContext) -> u32 {
+    let farm_id = setup_farm_with_rewards(ctx, 10_000_000_0000000);
+    ctx.client.set_farm_vesting(
+        &ctx.admin,
+        &farm_id,
+        &VestingSchedule {
+            cliff_blocks: 100,
+            total_blocks: 1000,
+        },
+    );
+
+    mint_lp_tokens(&ctx.env, &ctx.lp_token, &ctx.farmer1, 10_000_0000000);
+    set_ledger_sequence(&ctx.env, 1200);
+    ctx.client.stake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+    farm_id
+}
+
+#[test]
+fn test_harvested_rewards_unlock_linearly() {
+    let ctx = setup_test();
+    let farm_id = setup_vested_stake(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let (pending, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1), 0);
+    assert_eq!(
+        ctx.client.get_vesting_position(&ctx.farmer1, &farm_id),
+        Some(VestingPosition {
+            total: pending,
+            start_block: 1300,
+            claimed: 0,
+        })
+    );
+    let (_, unvested, claimable) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert_eq!((unvested, claimable), (pending, 0));
+
+    // Nothing unlocks before the cliff
+    set_ledger_sequence(&ctx.env, 1399);
+    assert_eq!(
+        ctx.client.try_claim_vested(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::NoRewards))
+    );
+
+    set_ledger_sequence(&ctx.env, 1800);
+    let half = pending * 500 / 1000;
+    let (_, unvested, claimable) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert_eq!((unvested, claimable), (pending - half, half));
+    assert_eq!(ctx.client.claim_vested(&ctx.farmer1, &farm_id), half);
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1), half);
+
+    // Whatever is left is claimable once the schedule has run its course
+    set_ledger_sequence(&ctx.env, 2300);
+    assert_eq!(
+        ctx.client.claim_vested(&ctx.farmer1, &farm_id),
+        pending - half
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending
+    );
+    assert_eq!(
+        ctx.client.get_vesting_position(&ctx.farmer1, &farm_id),
+        None
+    );
+}
+
+#[test]
+fn test_new_harvest_releases_vested_rewards_and_keeps_schedule() {
+    let ctx = setup_test();
+    let farm_id = setup_vested_stake(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let (first, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+
+    set_ledger_sequence(&ctx.env, 1700);
+    let (second, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+
+    // The vested part of the first harvest is paid out and the second one joins
+    // the position without restarting its cliff
+    let vested = first * 400 / 1000;
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        vested
+    );
+    assert_eq!(
+        ctx.client.get_vesting_position(&ctx.farmer1, &farm_id),
+        Some(VestingPosition {
+            total: first + second,
+            start_block: 1300,
+            claimed: vested,
+        })
+    );
+
+    // Harvesting every block before the cliff no longer keeps rewards locked
+    set_ledger_sequence(&ctx.env, 1750);
+    let (third, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    let released = (first + second) * 450 / 1000;
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        released
+    );
+    let total = first + second + third;
+    let (_, _, claimable) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert_eq!(claimable, total * 450 / 1000 - released);
+
+    // Once the schedule has run its course everything is claimable, and the
+    // next harvest opens a new position
+    set_ledger_sequence(&ctx.env, 2300);
+    assert_eq!(
+        ctx.client.claim_vested(&ctx.farmer1, &farm_id),
+        total - released
+    );
+    assert_eq!(
+        ctx.client.get_vesting_position(&ctx.farmer1, &farm_id),
+        None
+    );
+    let (fourth, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client.harvest(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client.get_vesting_position(&ctx.farmer1, &farm_id),
+        Some(VestingPosition {
+            total: fourth,
+            start_block: 2300,
+            claimed: 0,
+        })
+    );
+}
+
+#[test]
+fn test_unstake_rewards_vest_until_schedule_removed() {
+    let ctx = setup_test();
+    let farm_id = setup_vested_stake(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let (pending, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    ctx.client
+        .unstake_lp(&ctx.farmer1, &farm_id, &10_000_0000000);
+
+    // The stake is returned in full while the (early unstake) reward is locked
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.lp_token, &ctx.farmer1),
+        10_000_0000000
+    );
+    assert_eq!(get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1), 0);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id),
+        (0, pending / 2, 0)
+    );
+
+    // Removing the schedule unlocks what is still vesting
+    ctx.client.set_farm_vesting(
+        &ctx.admin,
+        &farm_id,
+        &VestingSchedule {
+            cliff_blocks: 0,
+            total_blocks: 0,
+        },
+    );
+    assert_eq!(ctx.client.get_farm_vesting(&farm_id), None);
+    assert_eq!(
+        ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id),
+        (0, 0, pending / 2)
+    );
+    assert_eq!(ctx.client.claim_vested(&ctx.farmer1, &farm_id), pending / 2);
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        pending / 2
+    );
+}
+
+#[test]
+fn test_set_farm_vesting_validation() {
+    let ctx = setup_test();
+    let farm_id = setup_farm_with_rewards(&ctx, 0);
+    let schedule = VestingSchedule {
+        cliff_blocks: 10,
+        total_blocks: 100,
+    };
+
+    let outsider = Address::generate(&ctx.env);
+    assert_eq!(
+        ctx.client
+            .try_set_farm_vesting(&outsider, &farm_id, &schedule),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        ctx.client.try_set_farm_vesting(&ctx.admin, &99, &schedule),
+        Err(Ok(ContractError::FarmNotFound))
+    );
+    assert_eq!(
+        ctx.client.try_set_farm_vesting(
+            &ctx.admin,
+            &farm_id,
+            &VestingSchedule {
+                cliff_blocks: 101,
+                total_blocks: 100,
+            }
+        ),
+        Err(Ok(ContractError::InvalidParameters))
+    );
+
+    ctx.client.set_farm_vesting(&ctx.admin, &farm_id, &schedule);
+    assert_eq!(ctx.client.get_farm_vesting(&farm_id), Some(schedule));
+
+    // Nothing has been harvested yet
+    assert_eq!(
+        ctx.client.try_claim_vested(&ctx.farmer1, &farm_id),
+        Err(Ok(ContractError::NoRewards))
+    );
+}
+
+#[test]
+fn test_harvest_reports_only_transferred_rewards() {
+    let ctx = setup_test();
+    let farm_id = setup_vested_stake(&ctx);
+
+    // The first harvest is locked in full
+    advance_ledger(&ctx.env, 100);
+    let (first, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client.harvest(&ctx.farmer1, &farm_id),
+        vec![&ctx.env, (ctx.reward_token.clone(), 0)]
+    );
+    let (_, topics, data) = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&ctx.env, &topics.get(1).unwrap()).unwrap(),
+        symbol_short!("harvest")
+    );
+    assert_eq!(
+        <(Address, u32, i128)>::try_from_val(&ctx.env, &data).unwrap(),
+        (ctx.farmer1.clone(), farm_id, 0)
+    );
+
+    // The next one transfers what has vested of the first
+    set_ledger_sequence(&ctx.env, 1800);
+    let vested = first * 500 / 1000;
+    assert_eq!(
+        ctx.client.harvest(&ctx.farmer1, &farm_id),
+        vec![&ctx.env, (ctx.reward_token.clone(), vested)]
+    );
+    let (_, _, data) = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        <(Address, u32, i128)>::try_from_val(&ctx.env, &data).unwrap(),
+        (ctx.farmer1.clone(), farm_id, vested)
+    );
+}
+
+#[test]
+fn test_batch_harvest_reports_only_transferred_rewards() {
+    let ctx = setup_test();
+    let farm_id = setup_vested_stake(&ctx);
+
+    advance_ledger(&ctx.env, 100);
+    let (first, _, _) = ctx.client.get_pending_rewards(&ctx.farmer1, &farm_id);
+    assert_eq!(
+        ctx.client
+            .batch_harvest(&ctx.farmer1, &vec![&ctx.env, farm_id]),
+        vec![&ctx.env, 0]
+    );
+    let (_, _, data) = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        <(Address, u32, i128)>::try_from_val(&ctx.env, &data).unwrap(),
+        (ctx.farmer1.clone(), farm_id, 0)
+    );
+
+    set_ledger_sequence(&ctx.env, 1800);
+    let vested = first * 500 / 1000;
+    assert_eq!(
+        ctx.client
+            .batch_harvest(&ctx.farmer1, &vec![&ctx.env, farm_id]),
+        vec![&ctx.env, vested]
+    );
+    assert_eq!(
+        get_balance(&ctx.env, &ctx.reward_token, &ctx.farmer1),
+        vested
+    );
+}
@@ -0,0 +1,257 @@
+use crate::error::ContractError;
+use crate::leasing::{load_lease, require_active, PaymentMode, MAX_SHARE_BPS};
+use crate::payment::record_scheduled_payment;
+use soroban_sdk::{
+    contractclient, contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec,
+};
+
+/// Receipt returned by the commodity-token-contract when a quantity is locked
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralLock {
+    pub lock_ref: BytesN<32>,
+    pub token_id: BytesN<32>,
+    pub holder: Address,
+    pub quantity: u32,
+    pub beneficiary_contract: Address,
+    pub locked_at: u64,
+}
+
+/// Collateral lock interface of the commodity-token-contract. The lessor's share
+/// is locked on behalf of this contract and then seized to the lessor.
+#[allow(dead_code)]
+#[contractclient(name = "CommodityTokenClient")]
+pub trait CommodityToken {
+    fn lock_for(
+        env: Env,
+        holder: Address,
+        token_id: BytesN<32>,
+        quantity: u32,
+        beneficiary_contract: Address,
+        lock_ref: BytesN<32>,
+    ) -> CollateralLock;
+    fn seize_lock(
+        env: Env,
+        beneficiary_contract: Address,
+        lock_ref: BytesN<32>,
+        to: Address,
+    ) -> BytesN<32>;
+}
+
+/// Terms of a crop-share lease, fixed when the lease is created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CropShareTerms {
+    pub share_bps: u32, // Lessor's share of the harvest in basis points
+    pub commodity_token_contract: Address,
+    pub token_id: BytesN<32>, // Lessee's holding the lessor's share is taken from
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CropShareSettlement {
+    pub lease_id: BytesN<32>,
+    pub payment_id: BytesN<32>,
+    pub commodity_token_contract: Address,
+    pub token_id: BytesN<32>,
+    pub harvest_quantity: u32,
+    pub lessor_share: u32,
+    pub unit_price: Option<i128>, // Reference price at settlement time
+    pub timestamp: u64,
+}
+
+const REFERENCE_PRICES: Symbol = symbol_short!("REFPRICE");
+const SETTLEMENTS: Symbol = symbol_short!("SETTLES");
+const CROP_TERMS: Symbol = symbol_short!("CROPTERMS");
+const HARVESTS: Symbol = symbol_short!("HARVEST");
+
+/// Lessor's share of a harvest, rounded down to whole commodity units
+pub fn calculate_lessor_share(quantity: u32, share_bps: u32) -> u32 {
+    ((quantity as u64 * share_bps as u64) / MAX_SHARE_BPS as u64) as u32
+}
+
+pub fn set_reference_price(
+    env: &Env,
+    admin: Address,
+    commodity_token_contract: Address,
+    token_id: BytesN<32>,
+    unit_price: i128,
+) -> Result<bool, ContractError> {
+    admin.require_auth();
+    if !crate::utils::is_admin(env, &admin) {
+        return Err(ContractError::Unauthorized);
+    }
+    if unit_price <= 0 {
+        return Err(ContractError::InvalidReferencePrice);
+    }
+
+    env.storage().persistent().set(
+        &(
+            REFERENCE_PRICES,
+            commodity_token_contract.clone(),
+            token_id.clone(),
+        ),
+        &unit_price,
+    );
+
+    env.events().publish(
+        (symbol_short!("refprice"),),
+        (commodity_token_contract, token_id, unit_price),
+    );
+
+    Ok(true)
+}
+
+pub fn get_reference_price(
+    env: &Env,
+    commodity_token_contract: Address,
+    token_id: BytesN<32>,
+) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&(REFERENCE_PRICES, commodity_token_contract, token_id))
+}
+
+pub fn set_crop_share_terms(env: &Env, lease_id: &BytesN<32>, terms: &CropShareTerms) {
+    env.storage()
+        .persistent()
+        .set(&(CROP_TERMS, lease_id.clone()), terms);
+}
+
+pub fn get_crop_share_terms(env: &Env, lease_id: BytesN<32>) -> Option<CropShareTerms> {
+    env.storage().persistent().get(&(CROP_TERMS, lease_id))
+}
+
+/// Lessor attests the quantity harvested this season; the lessee settles against it
+pub fn attest_harvest(
+    env: &Env,
+    lessor: Address,
+    lease_id: BytesN<32>,
+    quantity: u32,
+) -> Result<bool, ContractError> {
+    lessor.require_auth();
+
+    let lease = load_lease(env, &lease_id)?;
+
+    if lessor != lease.lessor_id {
+        return Err(ContractError::Unauthorized);
+    }
+    if lease.payment_mode != PaymentMode::CropShare {
+        return Err(ContractError::WrongPaymentMode);
+    }
+    require_active(env, &lease)?;
+    if quantity == 0 {
+        return Err(ContractError::InvalidQuantity);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(HARVESTS, lease_id.clone()), &quantity);
+
+    env.events()
+        .publish((symbol_short!("harvest"),), (lease_id, lessor, quantity));
+
+    Ok(true)
+}
+
+pub fn get_attested_harvest(env: &Env, lease_id: BytesN<32>) -> Option<u32> {
+    env.storage().persistent().get(&(HARVESTS, lease_id))
+}
+
+pub fn settle_crop_share(
+    env: &Env,
+    lessee: Address,
+    lease_id: BytesN<32>,
+    commodity_token_contract: Address,
+    token_id: BytesN<32>,
+    quantity: u32,
+) -> Result<u32, ContractError> {
+    lessee.require_auth();
+
+    let lease = load_lease(env, &lease_id)?;
+
+    if lessee != lease.lessee_id {
+        return Err(ContractError::Unauthorized);
+    }
+    if lease.payment_mode != PaymentMode::CropShare {
+        return Err(ContractError::WrongPaymentMode);
+    }
+    require_active(env, &lease)?;
+    if quantity == 0 {
+        return Err(ContractError::InvalidQuantity);
+    }
+
+    // Only the commodity fixed on the lease can settle it
+    let terms =
+        get_crop_share_terms(env, lease_id.clone()).ok_or(ContractError::CommodityMismatch)?;
+    if terms.commodity_token_contract != commodity_token_contract || terms.token_id != token_id {
+        return Err(ContractError::CommodityMismatch);
+    }
+
+    // The settled quantity must match the lessor's attestation for the season
+    let attested =
+        get_attested_harvest(env, lease_id.clone()).ok_or(ContractError::HarvestNotAttested)?;
+    if attested != quantity {
+        return Err(ContractError::HarvestMismatch);
+    }
+
+    let lessor_share = calculate_lessor_share(quantity, lease.share_bps);
+    if lessor_share == 0 {
+        return Err(ContractError::HarvestTooSmall);
+    }
+
+    // Lock the lessor's share for this contract, then seize it to the lessor
+    let mut seed = Bytes::from_array(env, &lease_id.to_array());
+    seed.extend_from_array(&lease.payments_made.to_be_bytes());
+    let lock_ref: BytesN<32> = env.crypto().sha256(&seed).into();
+
+    let commodity = CommodityTokenClient::new(env, &commodity_token_contract);
+    let this_contract = env.current_contract_address();
+    commodity.lock_for(&lessee, &token_id, &lessor_share, &this_contract, &lock_ref);
+    commodity.seize_lock(&this_contract, &lock_ref, &lease.lessor_id);
+    env.storage()
+        .persistent()
+        .remove(&(HARVESTS, lease_id.clone()));
+
+    // Snapshot the value of the share if a reference price is configured
+    let unit_price = get_reference_price(env, commodity_token_contract.clone(), token_id.clone());
+    let valuation = unit_price.map(|price| price * lessor_share as i128);
+
+    let payment_id =
+        record_scheduled_payment(env, &lease, lessee.clone(), lessor_share as i128, valuation)?;
+
+    let settlement = CropShareSettlement {
+        lease_id: lease_id.clone(),
+        payment_id: payment_id.clone(),
+        commodity_token_contract,
+        token_id,
+        harvest_quantity: quantity,
+        lessor_share,
+        unit_price,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let mut settlements: Vec<CropShareSettlement> = env
+        .storage()
+        .persistent()
+        .get(&(SETTLEMENTS, lease_id.clone()))
+        .unwrap_or(Vec::new(env));
+    settlements.push_back(settlement);
+    env.storage()
+        .persistent()
+        .set(&(SETTLEMENTS, lease_id.clone()), &settlements);
+
+    env.events().publish(
+        (symbol_short!("cropshare"),),
+        (payment_id, lease_id, lessee, lessor_share),
+    );
+
+    Ok(lessor_share)
+}
+
+pub fn get_crop_share_settlements(env: &Env, lease_id: BytesN<32>) -> Vec<CropShareSettlement> {
+    env.storage()
+        .persistent()
+        .get(&(SETTLEMENTS, lease_id))
+        .unwrap_or(Vec::new(env))
+}
@@ -1,5 +1,6 @@
-use crate::leasing::{get_lease_agreement, update_lease_status};
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+use crate::error::ContractError;
+use crate::leasing::{load_lease, require_active, update_lease_status};
+use soroban_sdk::{contracttype, symbol_short, token, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -16,35 +17,133 @@ pub struct Dispute {
     pub resolved_at: Option<u64>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BondStatus {
+    NotRequired, // No bond was configured when the dispute was raised
+    Waived,      // Complainant exempted by the admin
+    Held,        // Escrowed by the contract until resolution
+    Refunded,    // Returned to the complainant
+    Forfeited,   // Paid to the treasury, or the defendant if none is set
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBond {
+    pub token: Option<Address>,
+    pub amount: i128,
+    pub status: BondStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    pub token: Address,
+    pub amount: i128,              // 0 disables the bond
+    pub treasury: Option<Address>, // Receives forfeited bonds instead of the defendant
+}
+
 const DISPUTES: Symbol = symbol_short!("DISPUTES");
 const DISPUTE_COUNTER: Symbol = symbol_short!("DISPCNT");
+const LEASE_DISPUTES: Symbol = symbol_short!("LEASEDSP");
+const LEASE_DISPUTE_COUNT: Symbol = symbol_short!("LEASEDCNT");
+const DISPUTE_BONDS: Symbol = symbol_short!("DISPBOND");
+
+pub const MAX_DISPUTE_PAGE_SIZE: u32 = 50;
+const BOND_CONFIG: Symbol = symbol_short!("BONDCFG");
+const BOND_WAIVERS: Symbol = symbol_short!("BONDWAIV");
+
+pub fn set_dispute_bond(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    treasury: Option<Address>,
+) -> Result<bool, ContractError> {
+    admin.require_auth();
+    if !crate::utils::is_admin(env, &admin) {
+        return Err(ContractError::Unauthorized);
+    }
+    if amount < 0 {
+        return Err(ContractError::InvalidBondAmount);
+    }
+
+    env.storage().instance().set(
+        &BOND_CONFIG,
+        &BondConfig {
+            token: token.clone(),
+            amount,
+            treasury,
+        },
+    );
+
+    env.events()
+        .publish((symbol_short!("bondcfg"),), (token, amount));
+
+    Ok(true)
+}
+
+pub fn get_dispute_bond(env: &Env) -> Option<BondConfig> {
+    env.storage().instance().get(&BOND_CONFIG)
+}
+
+pub fn set_bond_waiver(
+    env: &Env,
+    admin: Address,
+    complainant: Address,
+    waived: bool,
+) -> Result<bool, ContractError> {
+    admin.require_auth();
+    if !crate::utils::is_admin(env, &admin) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let key = (BOND_WAIVERS, complainant.clone());
+    if waived {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((symbol_short!("bondwaiv"),), (complainant, waived));
+
+    Ok(true)
+}
+
+pub fn is_bond_waived(env: &Env, complainant: Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(BOND_WAIVERS, complainant))
+        .unwrap_or(false)
+}
 
 pub fn raise_dispute(
     env: &Env,
     lease_id: BytesN<32>,
     complainant: Address,
     reason: String,
-) -> bool {
+) -> Result<bool, ContractError> {
     complainant.require_auth();
 
     // Get lease agreement
-    let lease = get_lease_agreement(env, lease_id.clone()).expect("Lease agreement not found");
+    let lease = load_lease(env, &lease_id)?;
 
     // Verify complainant is involved in the lease
-    assert!(
-        complainant == lease.lessor_id || complainant == lease.lessee_id,
-        "Only lease parties can raise disputes"
-    );
+    if complainant != lease.lessor_id && complainant != lease.lessee_id {
+        return Err(ContractError::Unauthorized);
+    }
 
     // Check if lease is active
-    assert_eq!(
-        lease.status,
-        String::from_str(env, "Active"),
-        "Lease is not active"
-    );
+    require_active(env, &lease)?;
 
     // Validate reason
-    assert!(!reason.is_empty(), "Dispute reason cannot be empty");
+    if reason.is_empty() {
+        return Err(ContractError::EmptyDisputeReason);
+    }
+
+    // Escrow the filing bond; if the transfer fails no dispute is raised
+    let bond = collect_bond(env, &complainant)?;
 
     // Generate dispute ID
     let mut counter: u64 = env.storage().instance().get(&DISPUTE_COUNTER).unwrap_or(0);
@@ -78,9 +177,19 @@ pub fn raise_dispute(
     env.storage()
         .persistent()
         .set(&(DISPUTES, dispute_id.clone()), &dispute);
+    store_bond(env, &dispute_id, &bond);
+
+    // Index the dispute under its lease, one entry per dispute
+    let index = get_lease_dispute_count(env, lease_id.clone());
+    env.storage()
+        .persistent()
+        .set(&(LEASE_DISPUTES, lease_id.clone(), index), &dispute_id);
+    env.storage()
+        .persistent()
+        .set(&(LEASE_DISPUTE_COUNT, lease_id.clone()), &(index + 1));
 
     // Update lease status to disputed
-    update_lease_status(env, lease_id.clone(), String::from_str(env, "Disputed"));
+    update_lease_status(env, lease_id.clone(), String::from_str(env, "Disputed"))?;
 
     // Emit dispute event
     env.events().publish(
@@ -88,45 +197,72 @@ pub fn raise_dispute(
         (dispute_id, lease_id, complainant),
     );
 
-    true
+    Ok(true)
 }
 
+/// Resolves the lease's open dispute in the complainant's favour and refunds their bond
 pub fn resolve_lease_dispute(
     env: &Env,
     lease_id: BytesN<32>,
     resolver: Address,
     resolution: String,
-) -> bool {
+) -> Result<bool, ContractError> {
+    close_dispute(env, lease_id, resolver, resolution, false)
+}
+
+/// Rejects the lease's open dispute as frivolous and forfeits the complainant's bond
+pub fn dismiss_lease_dispute(
+    env: &Env,
+    lease_id: BytesN<32>,
+    resolver: Address,
+    resolution: String,
+) -> Result<bool, ContractError> {
+    close_dispute(env, lease_id, resolver, resolution, true)
+}
+
+fn close_dispute(
+    env: &Env,
+    lease_id: BytesN<32>,
+    resolver: Address,
+    resolution: String,
+    dismissed: bool,
+) -> Result<bool, ContractError> {
     resolver.require_auth();
 
     // Check if resolver is authorized (admin)
-    assert!(
-        crate::utils::is_admin(env, &resolver),
-        "Unauthorized resolver"
-    );
+    if !crate::utils::is_admin(env, &resolver) {
+        return Err(ContractError::Unauthorized);
+    }
 
     // Find open dispute for this lease
     let dispute_id =
-        find_open_dispute_for_lease(env, &lease_id).expect("No open dispute found for this lease");
+        find_open_dispute_for_lease(env, &lease_id).ok_or(ContractError::DisputeNotFound)?;
 
     let mut dispute: Dispute = env
         .storage()
         .persistent()
         .get(&(DISPUTES, dispute_id.clone()))
-        .expect("Dispute not found");
+        .ok_or(ContractError::DisputeNotFound)?;
 
     // Check if dispute is open
-    assert_eq!(
-        dispute.status,
-        String::from_str(env, "Open"),
-        "Dispute is not open"
-    );
+    if dispute.status != String::from_str(env, "Open") {
+        return Err(ContractError::DisputeNotOpen);
+    }
 
     // Validate resolution
-    assert!(!resolution.is_empty(), "Resolution cannot be empty");
+    if resolution.is_empty() {
+        return Err(ContractError::EmptyResolution);
+    }
+
+    // Pay out the bond in the same call that closes the dispute
+    settle_bond(env, &dispute, dismissed);
 
     // Update dispute
-    dispute.status = String::from_str(env, "Resolved");
+    dispute.status = if dismissed {
+        String::from_str(env, "Rejected")
+    } else {
+        String::from_str(env, "Resolved")
+    };
     dispute.resolution = resolution.clone();
     dispute.resolver = Some(resolver.clone());
     dispute.resolved_at = Some(env.ledger().timestamp());
@@ -137,21 +273,141 @@ pub fn resolve_lease_dispute(
         .set(&(DISPUTES, dispute_id.clone()), &dispute);
 
     // Update lease status back to active
-    update_lease_status(env, lease_id.clone(), String::from_str(env, "Active"));
+    update_lease_status(env, lease_id.clone(), String::from_str(env, "Active"))?;
 
     // Emit resolution event
-    env.events().publish(
-        (symbol_short!("resolved"),),
-        (dispute_id, lease_id, resolver),
-    );
+    let topic = if dismissed {
+        symbol_short!("dismissed")
+    } else {
+        symbol_short!("resolved")
+    };
+    env.events()
+        .publish((topic,), (dispute_id, lease_id, resolver));
 
-    true
+    Ok(true)
 }
 
 pub fn get_dispute_details(env: &Env, dispute_id: BytesN<32>) -> Option<Dispute> {
     env.storage().persistent().get(&(DISPUTES, dispute_id))
 }
 
+pub fn get_lease_dispute_count(env: &Env, lease_id: BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(LEASE_DISPUTE_COUNT, lease_id))
+        .unwrap_or(0)
+}
+
+/// Disputes raised on a lease, oldest first, starting at `offset`.
+/// `limit` is clamped to `MAX_DISPUTE_PAGE_SIZE`.
+pub fn get_lease_disputes(
+    env: &Env,
+    lease_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<Dispute> {
+    let count = get_lease_dispute_count(env, lease_id.clone());
+    let end = offset
+        .saturating_add(limit.min(MAX_DISPUTE_PAGE_SIZE))
+        .min(count);
+
+    let mut disputes = Vec::new(env);
+    for index in offset..end {
+        let dispute_id: Option<BytesN<32>> =
+            env.storage()
+                .persistent()
+                .get(&(LEASE_DISPUTES, lease_id.clone(), index));
+        if let Some(dispute) = dispute_id.and_then(|id| get_dispute_details(env, id)) {
+            disputes.push_back(dispute);
+        }
+    }
+    disputes
+}
+
+/// Bond posted with a dispute; disputes raised without one report `NotRequired`
+pub fn get_filing_bond(env: &Env, dispute_id: BytesN<32>) -> DisputeBond {
+    env.storage()
+        .persistent()
+        .get(&(DISPUTE_BONDS, dispute_id))
+        .unwrap_or(DisputeBond {
+            token: None,
+            amount: 0,
+            status: BondStatus::NotRequired,
+        })
+}
+
+fn store_bond(env: &Env, dispute_id: &BytesN<32>, bond: &DisputeBond) {
+    if bond.status != BondStatus::NotRequired {
+        env.storage()
+            .persistent()
+            .set(&(DISPUTE_BONDS, dispute_id.clone()), bond);
+    }
+}
+
+fn collect_bond(env: &Env, complainant: &Address) -> Result<DisputeBond, ContractError> {
+    let config = match get_dispute_bond(env) {
+        Some(config) if config.amount > 0 => config,
+        _ => {
+            return Ok(DisputeBond {
+                token: None,
+                amount: 0,
+                status: BondStatus::NotRequired,
+            })
+        }
+    };
+
+    if is_bond_waived(env, complainant.clone()) {
+        return Ok(DisputeBond {
+            token: Some(config.token),
+            amount: 0,
+            status: BondStatus::Waived,
+        });
+    }
+
+    let transfer = token::Client::new(env, &config.token).try_transfer(
+        complainant,
+        &env.current_contract_address(),
+        &config.amount,
+    );
+    if !matches!(transfer, Ok(Ok(()))) {
+        return Err(ContractError::BondTransferFailed);
+    }
+
+    Ok(DisputeBond {
+        token: Some(config.token),
+        amount: config.amount,
+        status: BondStatus::Held,
+    })
+}
+
+fn settle_bond(env: &Env, dispute: &Dispute, forfeit: bool) {
+    let mut bond = get_filing_bond(env, dispute.dispute_id.clone());
+    let token = match (&bond.status, &bond.token) {
+        (BondStatus::Held, Some(token)) => token.clone(),
+        _ => return,
+    };
+
+    let recipient = if forfeit {
+        get_dispute_bond(env)
+            .and_then(|config| config.treasury)
+            .unwrap_or_else(|| dispute.defendant.clone())
+    } else {
+        dispute.complainant.clone()
+    };
+
+    token::Client::new(env, &token).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &bond.amount,
+    );
+    bond.status = if forfeit {
+        BondStatus::Forfeited
+    } else {
+        BondStatus::Refunded
+    };
+    store_bond(env, &dispute.dispute_id, &bond);
+}
+
 fn find_open_dispute_for_lease(env: &Env, lease_id: &BytesN<32>) -> Option<BytesN<32>> {
     let counter: u64 = env.storage().instance().get(&DISPUTE_COUNTER).unwrap_or(0);
 
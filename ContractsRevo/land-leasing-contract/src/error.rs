@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ContractError {
+    // General errors
+    AlreadyInitialized = 1,
+    Unauthorized = 2,
+
+    // Lease errors
+    LeaseNotFound = 10,
+    LeaseNotActive = 11,
+    InvalidDuration = 12,
+    InvalidPaymentAmount = 13,
+    InvalidShare = 14,
+    InvalidLandSize = 15,
+    SameParty = 16,
+
+    // Payment errors
+    InsufficientPayment = 20,
+    WrongPaymentMode = 21,
+    InvalidQuantity = 22,
+    HarvestTooSmall = 23,
+    InvalidReferencePrice = 24,
+    CommodityMismatch = 25,
+    HarvestNotAttested = 26,
+    HarvestMismatch = 27,
+
+    // Dispute errors
+    DisputeNotFound = 30,
+    DisputeNotOpen = 31,
+    EmptyDisputeReason = 32,
+    EmptyResolution = 33,
+    InvalidBondAmount = 34,
+    BondTransferFailed = 35,
+}
@@ -1,5 +1,14 @@
+use crate::crop_share::{set_crop_share_terms, CropShareTerms};
+use crate::error::ContractError;
 use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PaymentMode {
+    FixedRent, // Monthly cash rent of `payment_amount`
+    CropShare, // Seasonal share of the harvest, `share_bps` of each settlement
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LeaseAgreement {
@@ -14,6 +23,8 @@ pub struct LeaseAgreement {
     pub next_payment_due: u64,
     pub payments_made: u32,
     pub total_payments_required: u32,
+    pub payment_mode: PaymentMode,
+    pub share_bps: u32, // Lessor's share of the harvest in basis points (CropShare only)
 }
 
 #[contracttype]
@@ -32,6 +43,26 @@ const LAND_REGISTRY: Symbol = symbol_short!("LANDS");
 const LEASE_COUNTER: Symbol = symbol_short!("COUNTER");
 const USER_LEASES: Symbol = symbol_short!("USERLS");
 
+pub const ONE_MONTH_SECONDS: u64 = 2629746; // Approximately 1 month in seconds
+pub const SEASON_MONTHS: u64 = 6; // Crop-share leases settle once per growing season
+pub const MAX_SHARE_BPS: u32 = 10000;
+
+/// Seconds between two scheduled payments (rent) or settlements (crop share)
+pub fn payment_period(mode: &PaymentMode) -> u64 {
+    match mode {
+        PaymentMode::FixedRent => ONE_MONTH_SECONDS,
+        PaymentMode::CropShare => ONE_MONTH_SECONDS * SEASON_MONTHS,
+    }
+}
+
+/// Number of payments or seasonal settlements needed to cover `duration` months
+pub fn payments_required(mode: &PaymentMode, duration: u64) -> u32 {
+    match mode {
+        PaymentMode::FixedRent => duration as u32,
+        PaymentMode::CropShare => duration.div_ceil(SEASON_MONTHS) as u32,
+    }
+}
+
 pub fn create_lease_agreement(
     env: &Env,
     lessor: Address,
@@ -42,15 +73,38 @@ pub fn create_lease_agreement(
     duration: u64,
     payment_amount: i128,
     data_hash: BytesN<32>,
-) -> BytesN<32> {
+    payment_mode: PaymentMode,
+    crop_share: Option<CropShareTerms>,
+) -> Result<BytesN<32>, ContractError> {
     // Verify lessor authorization
     lessor.require_auth();
 
     // Validate inputs
-    assert!(duration > 0, "Duration must be greater than 0");
-    assert!(payment_amount > 0, "Payment amount must be greater than 0");
-    assert!(size > 0, "Land size must be greater than 0");
-    assert!(lessor != lessee, "Lessor and lessee cannot be the same");
+    if duration == 0 {
+        return Err(ContractError::InvalidDuration);
+    }
+    let share_bps = match (&payment_mode, &crop_share) {
+        (PaymentMode::FixedRent, None) => {
+            if payment_amount <= 0 {
+                return Err(ContractError::InvalidPaymentAmount);
+            }
+            0
+        }
+        (PaymentMode::CropShare, Some(terms)) => {
+            if terms.share_bps == 0 || terms.share_bps > MAX_SHARE_BPS {
+                return Err(ContractError::InvalidShare);
+            }
+            terms.share_bps
+        }
+        // Share terms apply to crop-share leases only, and crop-share leases need them
+        _ => return Err(ContractError::InvalidShare),
+    };
+    if size == 0 {
+        return Err(ContractError::InvalidLandSize);
+    }
+    if lessor == lessee {
+        return Err(ContractError::SameParty);
+    }
 
     // Generate unique lease ID
     let mut counter: u64 = env.storage().instance().get(&LEASE_COUNTER).unwrap_or(0);
@@ -74,7 +128,6 @@ pub fn create_lease_agreement(
 
     // Create lease agreement
     let current_time = env.ledger().timestamp();
-    let one_month_seconds: u64 = 2629746; // Approximately 1 month in seconds
 
     let lease_agreement = LeaseAgreement {
         lease_id: lease_id.clone(),
@@ -85,9 +138,11 @@ pub fn create_lease_agreement(
         payment_amount,
         status: String::from_str(env, "Active"),
         start_time: current_time,
-        next_payment_due: current_time + one_month_seconds,
+        next_payment_due: current_time + payment_period(&payment_mode),
         payments_made: 0,
-        total_payments_required: duration as u32,
+        total_payments_required: payments_required(&payment_mode, duration),
+        payment_mode,
+        share_bps,
     };
 
     // Store lease agreement
@@ -95,6 +150,11 @@ pub fn create_lease_agreement(
         .persistent()
         .set(&(LEASE_AGREEMENTS, lease_id.clone()), &lease_agreement);
 
+    // Settlements are only accepted in the commodity pinned here
+    if let Some(terms) = crop_share {
+        set_crop_share_terms(env, &lease_id, &terms);
+    }
+
     // Track user leases
     add_user_lease(env, &lessee, &lease_id);
     add_user_lease(env, &lessor, &lease_id);
@@ -105,30 +165,25 @@ pub fn create_lease_agreement(
         (lease_id.clone(), lessor, lessee),
     );
 
-    lease_id
+    Ok(lease_id)
 }
 
-pub fn terminate_lease_agreement(env: &Env, lease_id: BytesN<32>, terminator: Address) -> bool {
+pub fn terminate_lease_agreement(
+    env: &Env,
+    lease_id: BytesN<32>,
+    terminator: Address,
+) -> Result<bool, ContractError> {
     // Get lease agreement
-    let mut lease: LeaseAgreement = env
-        .storage()
-        .persistent()
-        .get(&(LEASE_AGREEMENTS, lease_id.clone()))
-        .expect("Lease agreement not found");
+    let mut lease = load_lease(env, &lease_id)?;
 
     // Verify authorization
-    assert!(
-        terminator == lease.lessor_id || terminator == lease.lessee_id,
-        "Unauthorized termination attempt"
-    );
+    if terminator != lease.lessor_id && terminator != lease.lessee_id {
+        return Err(ContractError::Unauthorized);
+    }
     terminator.require_auth();
 
     // Check if lease is active
-    assert_eq!(
-        lease.status,
-        String::from_str(env, "Active"),
-        "Lease is not active"
-    );
+    require_active(env, &lease)?;
 
     // Update status
     lease.status = String::from_str(env, "Terminated");
@@ -150,7 +205,7 @@ pub fn terminate_lease_agreement(env: &Env, lease_id: BytesN<32>, terminator: Ad
     env.events()
         .publish((symbol_short!("ended"),), (lease_id, terminator));
 
-    true
+    Ok(true)
 }
 
 pub fn extend_lease_duration(
@@ -158,31 +213,22 @@ pub fn extend_lease_duration(
     lease_id: BytesN<32>,
     requester: Address,
     additional_months: u64,
-) -> bool {
+) -> Result<bool, ContractError> {
     requester.require_auth();
 
-    let mut lease: LeaseAgreement = env
-        .storage()
-        .persistent()
-        .get(&(LEASE_AGREEMENTS, lease_id.clone()))
-        .expect("Lease agreement not found");
+    let mut lease = load_lease(env, &lease_id)?;
 
     // Only lessor or lessee can extend
-    assert!(
-        requester == lease.lessor_id || requester == lease.lessee_id,
-        "Unauthorized extension attempt"
-    );
+    if requester != lease.lessor_id && requester != lease.lessee_id {
+        return Err(ContractError::Unauthorized);
+    }
 
     // Check if lease is active
-    assert_eq!(
-        lease.status,
-        String::from_str(env, "Active"),
-        "Lease is not active"
-    );
+    require_active(env, &lease)?;
 
     // Extend duration
     lease.duration += additional_months;
-    lease.total_payments_required += additional_months as u32;
+    lease.total_payments_required = payments_required(&lease.payment_mode, lease.duration);
 
     // Store updated lease
     env.storage()
@@ -195,7 +241,7 @@ pub fn extend_lease_duration(
         (lease_id, requester, additional_months),
     );
 
-    true
+    Ok(true)
 }
 
 pub fn get_lease_agreement(env: &Env, lease_id: BytesN<32>) -> Option<LeaseAgreement> {
@@ -204,47 +250,59 @@ pub fn get_lease_agreement(env: &Env, lease_id: BytesN<32>) -> Option<LeaseAgree
         .get(&(LEASE_AGREEMENTS, lease_id))
 }
 
+/// Loads a lease agreement, failing with `LeaseNotFound` if it does not exist
+pub fn load_lease(env: &Env, lease_id: &BytesN<32>) -> Result<LeaseAgreement, ContractError> {
+    get_lease_agreement(env, lease_id.clone()).ok_or(ContractError::LeaseNotFound)
+}
+
+/// Fails with `LeaseNotActive` unless the lease is active
+pub fn require_active(env: &Env, lease: &LeaseAgreement) -> Result<(), ContractError> {
+    if lease.status != String::from_str(env, "Active") {
+        return Err(ContractError::LeaseNotActive);
+    }
+    Ok(())
+}
+
 pub fn get_land_info(env: &Env, land_id: BytesN<32>) -> Option<Land> {
     env.storage().persistent().get(&(LAND_REGISTRY, land_id))
 }
 
-pub fn update_lease_status(env: &Env, lease_id: BytesN<32>, new_status: String) {
-    let mut lease: LeaseAgreement = env
-        .storage()
-        .persistent()
-        .get(&(LEASE_AGREEMENTS, lease_id.clone()))
-        .expect("Lease agreement not found");
+pub fn update_lease_status(
+    env: &Env,
+    lease_id: BytesN<32>,
+    new_status: String,
+) -> Result<(), ContractError> {
+    let mut lease = load_lease(env, &lease_id)?;
 
     lease.status = new_status;
     env.storage()
         .persistent()
         .set(&(LEASE_AGREEMENTS, lease_id), &lease);
+    Ok(())
 }
 
-pub fn update_next_payment_due(env: &Env, lease_id: BytesN<32>, next_due: u64) {
-    let mut lease: LeaseAgreement = env
-        .storage()
-        .persistent()
-        .get(&(LEASE_AGREEMENTS, lease_id.clone()))
-        .expect("Lease agreement not found");
+pub fn update_next_payment_due(
+    env: &Env,
+    lease_id: BytesN<32>,
+    next_due: u64,
+) -> Result<(), ContractError> {
+    let mut lease = load_lease(env, &lease_id)?;
 
     lease.next_payment_due = next_due;
     env.storage()
         .persistent()
         .set(&(LEASE_AGREEMENTS, lease_id), &lease);
+    Ok(())
 }
 
-pub fn increment_payments_made(env: &Env, lease_id: BytesN<32>) {
-    let mut lease: LeaseAgreement = env
-        .storage()
-        .persistent()
-        .get(&(LEASE_AGREEMENTS, lease_id.clone()))
-        .expect("Lease agreement not found");
+pub fn increment_payments_made(env: &Env, lease_id: BytesN<32>) -> Result<(), ContractError> {
+    let mut lease = load_lease(env, &lease_id)?;
 
     lease.payments_made += 1;
     env.storage()
         .persistent()
         .set(&(LEASE_AGREEMENTS, lease_id), &lease);
+    Ok(())
 }
 
 fn add_user_lease(env: &Env, user: &Address, lease_id: &BytesN<32>) {
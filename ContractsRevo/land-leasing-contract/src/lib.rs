@@ -1,11 +1,15 @@
 #![no_std]
 
+mod crop_share;
 mod dispute;
+mod error;
 mod leasing;
 mod payment;
 mod utils;
 
+pub use crop_share::*;
 pub use dispute::*;
+pub use error::*;
 pub use leasing::*;
 pub use payment::*;
 pub use utils::*;
@@ -18,11 +22,12 @@ pub struct LandLeasingContract;
 #[contractimpl]
 impl LandLeasingContract {
     /// Initialize the contract with admin
-    pub fn initialize(env: Env, admin: Address) {
-        utils::set_admin(&env, &admin);
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        utils::set_admin(&env, &admin)
     }
 
     /// Create a new lease agreement
+    #[allow(clippy::too_many_arguments)]
     pub fn create_lease(
         env: Env,
         lessor: Address,
@@ -33,7 +38,9 @@ impl LandLeasingContract {
         duration: u64,
         payment_amount: i128,
         data_hash: soroban_sdk::BytesN<32>,
-    ) -> soroban_sdk::BytesN<32> {
+        payment_mode: leasing::PaymentMode,
+        crop_share: Option<crop_share::CropShareTerms>,
+    ) -> Result<soroban_sdk::BytesN<32>, ContractError> {
         leasing::create_lease_agreement(
             &env,
             lessor,
@@ -44,6 +51,8 @@ impl LandLeasingContract {
             duration,
             payment_amount,
             data_hash,
+            payment_mode,
+            crop_share,
         )
     }
 
@@ -53,16 +62,94 @@ impl LandLeasingContract {
         lease_id: soroban_sdk::BytesN<32>,
         payer: Address,
         amount: i128,
-    ) -> bool {
+    ) -> Result<bool, ContractError> {
         payment::process_lease_payment(&env, lease_id, payer, amount)
     }
 
+    /// Attest the quantity harvested this season on a crop-share lease (lessor only)
+    pub fn attest_harvest(
+        env: Env,
+        lessor: Address,
+        lease_id: soroban_sdk::BytesN<32>,
+        quantity: u32,
+    ) -> Result<bool, ContractError> {
+        crop_share::attest_harvest(&env, lessor, lease_id, quantity)
+    }
+
+    /// Get the harvest quantity attested for the current season, if any
+    pub fn get_attested_harvest(env: Env, lease_id: soroban_sdk::BytesN<32>) -> Option<u32> {
+        crop_share::get_attested_harvest(&env, lease_id)
+    }
+
+    /// Get the share and commodity holding a crop-share lease settles in
+    pub fn get_crop_share_terms(
+        env: Env,
+        lease_id: soroban_sdk::BytesN<32>,
+    ) -> Option<crop_share::CropShareTerms> {
+        crop_share::get_crop_share_terms(&env, lease_id)
+    }
+
+    /// Settle a crop-share lease by transferring the lessor's share of the attested harvest
+    pub fn settle_crop_share(
+        env: Env,
+        lessee: Address,
+        lease_id: soroban_sdk::BytesN<32>,
+        commodity_token_contract: Address,
+        token_id: soroban_sdk::BytesN<32>,
+        quantity: u32,
+    ) -> Result<u32, ContractError> {
+        crop_share::settle_crop_share(
+            &env,
+            lessee,
+            lease_id,
+            commodity_token_contract,
+            token_id,
+            quantity,
+        )
+    }
+
+    /// Set the reference unit price used to value crop-share settlements
+    pub fn set_reference_price(
+        env: Env,
+        admin: Address,
+        commodity_token_contract: Address,
+        token_id: soroban_sdk::BytesN<32>,
+        unit_price: i128,
+    ) -> Result<bool, ContractError> {
+        crop_share::set_reference_price(&env, admin, commodity_token_contract, token_id, unit_price)
+    }
+
+    /// Get the reference unit price of a commodity token, if configured
+    pub fn get_reference_price(
+        env: Env,
+        commodity_token_contract: Address,
+        token_id: soroban_sdk::BytesN<32>,
+    ) -> Option<i128> {
+        crop_share::get_reference_price(&env, commodity_token_contract, token_id)
+    }
+
+    /// Get crop-share settlement history
+    pub fn get_crop_share_settlements(
+        env: Env,
+        lease_id: soroban_sdk::BytesN<32>,
+    ) -> soroban_sdk::Vec<crop_share::CropShareSettlement> {
+        crop_share::get_crop_share_settlements(&env, lease_id)
+    }
+
+    /// Get the number of overdue rent payments or seasonal settlements
+    pub fn get_missed_payments(
+        env: Env,
+        lease_id: soroban_sdk::BytesN<32>,
+    ) -> Result<u32, ContractError> {
+        payment::get_missed_payments(&env, lease_id)
+    }
+
     /// Terminate a lease agreement
     pub fn terminate_lease(
         env: Env,
         lease_id: soroban_sdk::BytesN<32>,
         terminator: Address,
-    ) -> bool {
+    ) -> Result<bool, ContractError> {
         leasing::terminate_lease_agreement(&env, lease_id, terminator)
     }
 
@@ -72,10 +159,72 @@ impl LandLeasingContract {
         lease_id: soroban_sdk::BytesN<32>,
         resolver: Address,
         resolution: soroban_sdk::String,
-    ) -> bool {
+    ) -> Result<bool, ContractError> {
         dispute::resolve_lease_dispute(&env, lease_id, resolver, resolution)
     }
 
+    /// Dismiss a dispute as frivolous, forfeiting the complainant's bond
+    pub fn dismiss_dispute(
+        env: Env,
+        lease_id: soroban_sdk::BytesN<32>,
+        resolver: Address,
+        resolution: soroban_sdk::String,
+    ) -> Result<bool, ContractError> {
+        dispute::dismiss_lease_dispute(&env, lease_id, resolver, resolution)
+    }
+
+    /// Require a bond of `amount` in `token` to raise a dispute (0 disables it).
+    /// Forfeited bonds go to `treasury`, or to the defendant when unset.
+    pub fn set_dispute_bond(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        treasury: Option<Address>,
+    ) -> Result<bool, ContractError> {
+        dispute::set_dispute_bond(&env, admin, token, amount, treasury)
+    }
+
+    /// Get the dispute bond configuration, if any
+    pub fn get_dispute_bond(env: Env) -> Option<dispute::BondConfig> {
+        dispute::get_dispute_bond(&env)
+    }
+
+    /// Exempt a complainant (e.g. a low-income farmer) from the dispute bond
+    pub fn set_bond_waiver(
+        env: Env,
+        admin: Address,
+        complainant: Address,
+        waived: bool,
+    ) -> Result<bool, ContractError> {
+        dispute::set_bond_waiver(&env, admin, complainant, waived)
+    }
+
+    /// Check whether a complainant is exempt from the dispute bond
+    pub fn is_bond_waived(env: Env, complainant: Address) -> bool {
+        dispute::is_bond_waived(&env, complainant)
+    }
+
+    /// Get up to `limit` disputes raised on a lease, oldest first, starting at `offset`
+    pub fn get_lease_disputes(
+        env: Env,
+        lease_id: soroban_sdk::BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> soroban_sdk::Vec<dispute::Dispute> {
+        dispute::get_lease_disputes(&env, lease_id, offset, limit)
+    }
+
+    /// Get the number of disputes ever raised on a lease
+    pub fn get_lease_dispute_count(env: Env, lease_id: soroban_sdk::BytesN<32>) -> u32 {
+        dispute::get_lease_dispute_count(&env, lease_id)
+    }
+
+    /// Get the bond posted with a dispute and whether it is held, refunded or forfeited
+    pub fn get_filing_bond(env: Env, dispute_id: soroban_sdk::BytesN<32>) -> dispute::DisputeBond {
+        dispute::get_filing_bond(&env, dispute_id)
+    }
+
     /// Get lease details
     pub fn get_lease_details(
         env: Env,
@@ -103,7 +252,7 @@ impl LandLeasingContract {
         lease_id: soroban_sdk::BytesN<32>,
         complainant: Address,
         reason: soroban_sdk::String,
-    ) -> bool {
+    ) -> Result<bool, ContractError> {
         dispute::raise_dispute(&env, lease_id, complainant, reason)
     }
 
@@ -113,7 +262,7 @@ impl LandLeasingContract {
         lease_id: soroban_sdk::BytesN<32>,
         requester: Address,
         additional_months: u64,
-    ) -> bool {
+    ) -> Result<bool, ContractError> {
         leasing::extend_lease_duration(&env, lease_id, requester, additional_months)
     }
 
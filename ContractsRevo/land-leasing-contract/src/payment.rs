@@ -1,5 +1,7 @@
+use crate::error::ContractError;
 use crate::leasing::{
-    get_lease_agreement, increment_payments_made, update_lease_status, update_next_payment_due,
+    increment_payments_made, load_lease, payment_period, require_active, update_lease_status,
+    update_next_payment_due, LeaseAgreement, PaymentMode,
 };
 use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
@@ -11,7 +13,8 @@ pub struct PaymentRecord {
     pub payer: Address,
     pub amount: i128,
     pub timestamp: u64,
-    pub payment_type: String, // Regular, Late, Early, Penalty
+    pub payment_type: String,    // Regular, Late, Early, Penalty
+    pub valuation: Option<i128>, // Value of a crop-share settlement at the reference price
 }
 
 const PAYMENT_HISTORY: Symbol = symbol_short!("PAYMENTS");
@@ -22,29 +25,55 @@ pub fn process_lease_payment(
     lease_id: BytesN<32>,
     payer: Address,
     amount: i128,
-) -> bool {
+) -> Result<bool, ContractError> {
     payer.require_auth();
 
     // Get lease agreement
-    let lease = get_lease_agreement(env, lease_id.clone()).expect("Lease agreement not found");
+    let lease = load_lease(env, &lease_id)?;
 
     // Verify payer is the lessee
-    assert_eq!(payer, lease.lessee_id, "Only lessee can make payments");
+    if payer != lease.lessee_id {
+        return Err(ContractError::Unauthorized);
+    }
+    // Crop-share leases are settled with settle_crop_share
+    if lease.payment_mode != PaymentMode::FixedRent {
+        return Err(ContractError::WrongPaymentMode);
+    }
 
     // Check if lease is active
-    assert_eq!(
-        lease.status,
-        String::from_str(env, "Active"),
-        "Lease is not active"
-    );
+    require_active(env, &lease)?;
 
     // Validate payment amount
-    assert!(amount > 0, "Payment amount must be greater than 0");
-    assert!(
-        amount >= lease.payment_amount,
-        "Insufficient payment amount"
+    if amount <= 0 {
+        return Err(ContractError::InvalidPaymentAmount);
+    }
+    if amount < lease.payment_amount {
+        return Err(ContractError::InsufficientPayment);
+    }
+
+    let payment_id = record_scheduled_payment(env, &lease, payer.clone(), amount, None)?;
+
+    // Emit payment event
+    env.events().publish(
+        (symbol_short!("payment"),),
+        (payment_id, lease_id, payer, amount),
     );
 
+    Ok(true)
+}
+
+/// Records a rent payment or crop-share settlement against the lease schedule.
+/// Advances the next due date by one period and completes the lease once all
+/// scheduled payments have been made.
+pub fn record_scheduled_payment(
+    env: &Env,
+    lease: &LeaseAgreement,
+    payer: Address,
+    amount: i128,
+    valuation: Option<i128>,
+) -> Result<BytesN<32>, ContractError> {
+    let lease_id = lease.lease_id.clone();
+
     // Determine payment type
     let current_time = env.ledger().timestamp();
     let payment_type = if current_time > lease.next_payment_due {
@@ -67,35 +96,56 @@ pub fn process_lease_payment(
     let payment_record = PaymentRecord {
         payment_id: payment_id.clone(),
         lease_id: lease_id.clone(),
-        payer: payer.clone(),
+        payer,
         amount,
         timestamp: current_time,
-        payment_type: payment_type.clone(),
+        payment_type,
+        valuation,
     };
 
     // Store payment record
     store_payment_record(env, &lease_id, &payment_record);
 
     // Update lease payment tracking
-    increment_payments_made(env, lease_id.clone());
+    increment_payments_made(env, lease_id.clone())?;
 
-    // Calculate next payment due (1 month = 2629746 seconds)
-    let next_due = lease.next_payment_due + 2629746;
-    update_next_payment_due(env, lease_id.clone(), next_due);
+    // Calculate next payment due one month (rent) or one season (crop share) later
+    let next_due = lease.next_payment_due + payment_period(&lease.payment_mode);
+    update_next_payment_due(env, lease_id.clone(), next_due)?;
 
     // Check if lease is fully paid
-    let updated_lease = get_lease_agreement(env, lease_id.clone()).unwrap();
+    let updated_lease = load_lease(env, &lease_id)?;
     if updated_lease.payments_made >= updated_lease.total_payments_required {
-        update_lease_status(env, lease_id.clone(), String::from_str(env, "Completed"));
+        update_lease_status(env, lease_id, String::from_str(env, "Completed"))?;
     }
 
-    // Emit payment event
-    env.events().publish(
-        (symbol_short!("payment"),),
-        (payment_id, lease_id, payer, amount),
-    );
+    Ok(payment_id)
+}
+
+/// Number of scheduled payments whose due date has passed without being paid.
+/// A missed seasonal crop-share settlement counts the same as a missed month of rent.
+pub fn get_missed_payments(env: &Env, lease_id: BytesN<32>) -> Result<u32, ContractError> {
+    let lease = load_lease(env, &lease_id)?;
 
-    true
+    if lease.status != String::from_str(env, "Active") {
+        return Ok(0);
+    }
+
+    let current_time = env.ledger().timestamp();
+    if current_time <= lease.next_payment_due {
+        return Ok(0);
+    }
+
+    let overdue = (current_time - lease.next_payment_due) / payment_period(&lease.payment_mode) + 1;
+    let remaining = lease
+        .total_payments_required
+        .saturating_sub(lease.payments_made);
+
+    if overdue > remaining as u64 {
+        Ok(remaining)
+    } else {
+        Ok(overdue as u32)
+    }
 }
 
 pub fn get_payment_history(env: &Env, lease_id: BytesN<32>) -> Vec<PaymentRecord> {
@@ -118,16 +168,16 @@ pub fn get_total_payments_made(env: &Env, lease_id: BytesN<32>) -> i128 {
     total
 }
 
-pub fn get_outstanding_balance(env: &Env, lease_id: BytesN<32>) -> i128 {
-    let lease = get_lease_agreement(env, lease_id.clone()).expect("Lease agreement not found");
+pub fn get_outstanding_balance(env: &Env, lease_id: BytesN<32>) -> Result<i128, ContractError> {
+    let lease = load_lease(env, &lease_id)?;
 
     let total_required = lease.payment_amount * lease.total_payments_required as i128;
     let total_paid = get_total_payments_made(env, lease_id);
 
     if total_required > total_paid {
-        total_required - total_paid
+        Ok(total_required - total_paid)
     } else {
-        0
+        Ok(0)
     }
 }
 
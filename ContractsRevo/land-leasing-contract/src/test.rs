@@ -63,6 +63,8 @@ fn test_create_lease_agreement() {
         &12, // 12 months
         &1000, // 1000 units per month
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // Verify lease was created
@@ -105,6 +107,8 @@ fn test_process_payment() {
         &6,
         &500,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // Process payment
@@ -148,6 +152,8 @@ fn test_terminate_lease() {
         &8,
         &800,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // Terminate lease
@@ -188,6 +194,8 @@ fn test_create_lease_invalid_duration() {
         &0, // Invalid duration
         &1000,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 }
 
@@ -219,6 +227,8 @@ fn test_payment_by_wrong_user() {
         &6,
         &500,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // This should panic - other_user is not the lessee
@@ -254,6 +264,8 @@ fn test_simple_functionality() {
         &3, // 3 months instead of 1
         &100,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // Verify it exists
@@ -307,6 +319,8 @@ fn test_lease_completion() {
         &1, // 1 month - will complete after 1 payment
         &100,
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
     
     // Make the payment (completes the lease)
@@ -0,0 +1,439 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::*;
+use commodity_token_contract::{CommodityTokenContract, CommodityTokenContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Map, String,
+};
+
+struct CropShareSetup<'a> {
+    env: Env,
+    client: LandLeasingContractClient<'a>,
+    commodity: CommodityTokenContractClient<'a>,
+    admin: Address,
+    lessor: Address,
+    lessee: Address,
+    lease_id: BytesN<32>,
+    token_id: BytesN<32>,
+}
+
+/// Issues the lessee a 1000 unit maize holding on a real commodity-token-contract
+fn issue_harvest_token(
+    env: &Env,
+    commodity: &CommodityTokenContractClient,
+    lessee: &Address,
+) -> BytesN<32> {
+    let commodity_admin = Address::generate(env);
+    let maize = String::from_str(env, "MAIZE");
+    let verification = BytesN::from_array(env, &[6; 32]);
+
+    commodity.initialize(&commodity_admin);
+    // The admin entry points authorize twice, so each is called from inside the contract
+    env.as_contract(&commodity.address, || {
+        commodity_token_contract::add_inventory(env, &commodity_admin, &maize, 10_000).unwrap();
+    });
+    env.as_contract(&commodity.address, || {
+        commodity_token_contract::register_commodity_verification(
+            env,
+            &commodity_admin,
+            &maize,
+            &verification,
+            &Map::new(env),
+        )
+        .unwrap();
+    });
+    env.as_contract(&commodity.address, || {
+        commodity_token_contract::add_authorized_issuer(env, &commodity_admin, lessee).unwrap();
+    });
+    commodity.issue_token(
+        lessee,
+        &maize,
+        &1000,
+        &String::from_str(env, "A"),
+        &String::from_str(env, "Farm Silo"),
+        &(ONE_MONTH_SECONDS * 120),
+        &verification,
+    )
+}
+
+fn setup_crop_share_lease<'a>(duration: u64, share_bps: u32) -> CropShareSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let commodity_id = env.register(CommodityTokenContract, ());
+    let commodity = CommodityTokenContractClient::new(&env, &commodity_id);
+
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+    let token_id = issue_harvest_token(&env, &commodity, &lessee);
+
+    let land_id = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"crop_share_land"))
+        .into();
+    let data_hash = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"crop_share_data"))
+        .into();
+    let location = String::from_str(&env, "Sharecropped Field");
+
+    let lease_id = client.create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &20,
+        &duration,
+        &0,
+        &data_hash,
+        &PaymentMode::CropShare,
+        &Some(CropShareTerms {
+            share_bps,
+            commodity_token_contract: commodity_id,
+            token_id: token_id.clone(),
+        }),
+    );
+
+    CropShareSetup {
+        env,
+        client,
+        commodity,
+        admin,
+        lessor,
+        lessee,
+        lease_id,
+        token_id,
+    }
+}
+
+/// Lessor attests `quantity` and the lessee settles it against the pinned holding
+fn attest_and_settle(s: &CropShareSetup, quantity: u32) -> u32 {
+    s.client.attest_harvest(&s.lessor, &s.lease_id, &quantity);
+    s.client.settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &quantity,
+    )
+}
+
+/// Holdings split off to the lessor, as (token id, quantity)
+fn lessor_holdings(s: &CropShareSetup) -> soroban_sdk::Vec<(BytesN<32>, u32)> {
+    let mut holdings = soroban_sdk::Vec::new(&s.env);
+    for token_id in s
+        .commodity
+        .list_tokens_by_commodity(&String::from_str(&s.env, "MAIZE"))
+    {
+        let owner = s.env.as_contract(&s.commodity.address, || {
+            commodity_token_contract::get_token_owner(&s.env, &token_id).unwrap()
+        });
+        if owner == s.lessor {
+            let quantity = s.commodity.get_token_metadata(&token_id).quantity;
+            holdings.push_back((token_id, quantity));
+        }
+    }
+    holdings
+}
+
+#[test]
+fn test_crop_share_transfer_math() {
+    let s = setup_crop_share_lease(12, 3333);
+
+    let lease = s.client.get_lease_details(&s.lease_id).unwrap();
+    assert_eq!(lease.payment_mode, PaymentMode::CropShare);
+    assert_eq!(lease.share_bps, 3333);
+    assert_eq!(lease.total_payments_required, 2); // two seasons in 12 months
+    let terms = s.client.get_crop_share_terms(&s.lease_id).unwrap();
+    assert_eq!(terms.commodity_token_contract, s.commodity.address);
+    assert_eq!(terms.token_id, s.token_id);
+
+    // 33.33% of 1000 units, rounded down, split off the lessee's holding
+    assert_eq!(attest_and_settle(&s, 1000), 333);
+    assert_eq!(s.commodity.get_token_metadata(&s.token_id).quantity, 667);
+    let holdings = lessor_holdings(&s);
+    assert_eq!(holdings.len(), 1);
+    assert_eq!(holdings.get(0).unwrap().1, 333);
+    assert_eq!(s.commodity.get_locked_quantity(&s.token_id), 0);
+    assert_eq!(calculate_lessor_share(10, 2500), 2);
+}
+
+#[test]
+fn test_crop_share_settlement_history() {
+    let s = setup_crop_share_lease(12, 5000);
+
+    // No price snapshot without a reference price
+    attest_and_settle(&s, 400);
+
+    let history = s.client.get_payment_history(&s.lease_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.amount, 200);
+    assert_eq!(record.payer, s.lessee);
+    assert_eq!(record.valuation, None);
+
+    // Second season is valued at the reference price and completes the lease
+    s.client
+        .set_reference_price(&s.admin, &s.commodity.address, &s.token_id, &25);
+    attest_and_settle(&s, 100);
+
+    let record = s.client.get_payment_history(&s.lease_id).get(1).unwrap();
+    assert_eq!(record.amount, 50);
+    assert_eq!(record.valuation, Some(1250));
+
+    let settlements = s.client.get_crop_share_settlements(&s.lease_id);
+    let settlement = settlements.get(1).unwrap();
+    assert_eq!(settlement.harvest_quantity, 100);
+    assert_eq!(settlement.lessor_share, 50);
+    assert_eq!(settlement.unit_price, Some(25));
+    assert_eq!(settlement.payment_id, record.payment_id);
+
+    assert_eq!(s.commodity.get_token_metadata(&s.token_id).quantity, 750);
+    assert_eq!(lessor_holdings(&s).len(), 2);
+
+    let lease = s.client.get_lease_details(&s.lease_id).unwrap();
+    assert_eq!(lease.payments_made, 2);
+    assert_eq!(lease.status, String::from_str(&s.env, "Completed"));
+}
+
+#[test]
+fn test_missed_crop_share_settlements_count_as_arrears() {
+    let s = setup_crop_share_lease(24, 4000);
+    let season = ONE_MONTH_SECONDS * SEASON_MONTHS;
+
+    assert_eq!(s.client.get_missed_payments(&s.lease_id), 0);
+
+    // First seasonal settlement passes unpaid
+    s.env.ledger().with_mut(|l| l.timestamp += season + 1);
+    assert_eq!(s.client.get_missed_payments(&s.lease_id), 1);
+
+    // A second season goes by
+    s.env.ledger().with_mut(|l| l.timestamp += season);
+    assert_eq!(s.client.get_missed_payments(&s.lease_id), 2);
+
+    // Late settlement is recorded as such and clears one season of arrears
+    attest_and_settle(&s, 100);
+    let record = s.client.get_payment_history(&s.lease_id).get(0).unwrap();
+    assert_eq!(record.payment_type, String::from_str(&s.env, "Late"));
+    assert_eq!(s.client.get_missed_payments(&s.lease_id), 1);
+}
+
+#[test]
+fn test_missed_rent_counts_as_arrears() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = LandLeasingContractClient::new(&env, &create_test_contract(&env));
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+
+    let land_id = BytesN::from_array(&env, &[1; 32]);
+    let data_hash = BytesN::from_array(&env, &[2; 32]);
+    let lease_id = client.create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &String::from_str(&env, "Rented Field"),
+        &10,
+        &3,
+        &100,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += 10 * ONE_MONTH_SECONDS);
+    // Capped at the 3 scheduled payments
+    assert_eq!(client.get_missed_payments(&lease_id), 3);
+}
+
+#[test]
+fn test_cash_payment_rejected_on_crop_share_lease() {
+    let s = setup_crop_share_lease(12, 5000);
+    let result = s.client.try_process_payment(&s.lease_id, &s.lessee, &100);
+    assert_eq!(result, Err(Ok(ContractError::WrongPaymentMode)));
+}
+
+#[test]
+fn test_only_lessee_can_settle_crop_share() {
+    let s = setup_crop_share_lease(12, 5000);
+    let stranger = Address::generate(&s.env);
+    s.client.attest_harvest(&s.lessor, &s.lease_id, &100);
+    let result = s.client.try_settle_crop_share(
+        &stranger,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_only_lessor_can_attest_harvest() {
+    let s = setup_crop_share_lease(12, 5000);
+
+    let result = s.client.try_attest_harvest(&s.lessee, &s.lease_id, &100);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    let result = s.client.try_attest_harvest(&s.lessor, &s.lease_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidQuantity)));
+    assert_eq!(s.client.get_attested_harvest(&s.lease_id), None);
+
+    let fixed_lease = create_fixed_lease(&s.env, &s.client, &s.lessor, &s.lessee);
+    let result = s.client.try_attest_harvest(&s.lessor, &fixed_lease, &100);
+    assert_eq!(result, Err(Ok(ContractError::WrongPaymentMode)));
+}
+
+#[test]
+fn test_settlement_must_match_attested_harvest() {
+    let s = setup_crop_share_lease(12, 5000);
+
+    // The lessee cannot settle before the lessor attests the harvest
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(ContractError::HarvestNotAttested)));
+
+    // Nor under-report it
+    s.client.attest_harvest(&s.lessor, &s.lease_id, &400);
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(ContractError::HarvestMismatch)));
+
+    // Each attestation settles one season
+    s.client.settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &400,
+    );
+    assert_eq!(s.client.get_attested_harvest(&s.lease_id), None);
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &400,
+    );
+    assert_eq!(result, Err(Ok(ContractError::HarvestNotAttested)));
+}
+
+#[test]
+fn test_settlement_only_in_pinned_commodity() {
+    let s = setup_crop_share_lease(12, 5000);
+    s.client.attest_harvest(&s.lessor, &s.lease_id, &400);
+
+    // A different holding on the pinned contract
+    let other_token = BytesN::from_array(&s.env, &[9; 32]);
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &other_token,
+        &400,
+    );
+    assert_eq!(result, Err(Ok(ContractError::CommodityMismatch)));
+
+    // The same token id on another contract
+    let other_contract = Address::generate(&s.env);
+    let result =
+        s.client
+            .try_settle_crop_share(&s.lessee, &s.lease_id, &other_contract, &s.token_id, &400);
+    assert_eq!(result, Err(Ok(ContractError::CommodityMismatch)));
+    assert_eq!(s.client.get_payment_history(&s.lease_id).len(), 0);
+}
+
+#[test]
+fn test_crop_share_requires_valid_share() {
+    let s = setup_crop_share_lease(12, 5000);
+    let land_id = BytesN::from_array(&s.env, &[8; 32]);
+    let data_hash = BytesN::from_array(&s.env, &[9; 32]);
+    let location = String::from_str(&s.env, "Sharecropped Field");
+    let terms = |share_bps| {
+        Some(CropShareTerms {
+            share_bps,
+            commodity_token_contract: s.commodity.address.clone(),
+            token_id: s.token_id.clone(),
+        })
+    };
+
+    for crop_share in [terms(0), terms(10001), None] {
+        let result = s.client.try_create_lease(
+            &s.lessor,
+            &s.lessee,
+            &land_id,
+            &location,
+            &20,
+            &12,
+            &0,
+            &data_hash,
+            &PaymentMode::CropShare,
+            &crop_share,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidShare)));
+    }
+}
+
+#[test]
+fn test_settle_crop_share_error_codes() {
+    let s = setup_crop_share_lease(12, 500);
+
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &0,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidQuantity)));
+
+    // 5% of 10 units rounds down to nothing
+    s.client.attest_harvest(&s.lessor, &s.lease_id, &10);
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &s.lease_id,
+        &s.commodity.address,
+        &s.token_id,
+        &10,
+    );
+    assert_eq!(result, Err(Ok(ContractError::HarvestTooSmall)));
+
+    let fixed_lease = create_fixed_lease(&s.env, &s.client, &s.lessor, &s.lessee);
+    let result = s.client.try_settle_crop_share(
+        &s.lessee,
+        &fixed_lease,
+        &s.commodity.address,
+        &s.token_id,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(ContractError::WrongPaymentMode)));
+}
+
+#[test]
+fn test_reference_price_error_codes() {
+    let s = setup_crop_share_lease(12, 5000);
+
+    let result =
+        s.client
+            .try_set_reference_price(&s.lessor, &s.commodity.address, &s.token_id, &10);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = s
+        .client
+        .try_set_reference_price(&s.admin, &s.commodity.address, &s.token_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidReferencePrice)));
+}
@@ -22,7 +22,16 @@ fn test_basic_dispute_creation_and_resolution() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let dispute_reason = String::from_str(&env, "Property damage claim");
@@ -39,7 +48,6 @@ fn test_basic_dispute_creation_and_resolution() {
 }
 
 #[test]
-#[should_panic(expected = "Lease is not active")]
 fn test_dispute_blocks_payments() {
     let env = Env::default();
     env.mock_all_auths();
@@ -57,7 +65,16 @@ fn test_dispute_blocks_payments() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     assert!(client.process_payment(&lease_id, &lessee, &1000));
@@ -70,12 +87,12 @@ fn test_dispute_blocks_payments() {
         client.get_lease_details(&lease_id).unwrap().payments_made,
         1
     );
-    // Try to make payment while disputed - this should panic because lease is not active
-    client.process_payment(&lease_id, &lessee, &1000);
+    // Try to make payment while disputed - this should fail because lease is not active
+    let result = client.try_process_payment(&lease_id, &lessee, &1000);
+    assert_eq!(result, Err(Ok(ContractError::LeaseNotActive)));
 }
 
 #[test]
-#[should_panic(expected = "Lease is not active")]
 fn test_multiple_dispute_attempts_on_same_lease() {
     let env = Env::default();
     env.mock_all_auths();
@@ -93,7 +110,16 @@ fn test_multiple_dispute_attempts_on_same_lease() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let dispute_reason1 = String::from_str(&env, "First dispute");
@@ -104,10 +130,11 @@ fn test_multiple_dispute_attempts_on_same_lease() {
         client.get_lease_details(&lease_id).unwrap().status,
         String::from_str(&env, "Disputed")
     );
-    // Try to raise another dispute while first is open - this should panic because lease is not active
+    // Try to raise another dispute while first is open - this should fail because lease is not active
 
     let dispute_reason2 = String::from_str(&env, "Second dispute");
-    client.raise_dispute(&lease_id, &lessee, &dispute_reason2);
+    let result = client.try_raise_dispute(&lease_id, &lessee, &dispute_reason2);
+    assert_eq!(result, Err(Ok(ContractError::LeaseNotActive)));
 }
 
 #[test]
@@ -128,7 +155,16 @@ fn test_dispute_after_partial_payments() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &6, &500, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &6,
+        &500,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make some payments first
@@ -180,7 +216,16 @@ fn test_dispute_resolution_with_termination_outcome() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make some payments to show this isn't immediate termination
@@ -231,6 +276,8 @@ fn test_lessee_initiated_dispute_vs_lessor_initiated() {
         &12,
         &1000,
         &data_hash1,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let land_bytes2 = Bytes::from_slice(&env, b"lessee_dispute");
@@ -248,6 +295,8 @@ fn test_lessee_initiated_dispute_vs_lessor_initiated() {
         &12,
         &1000,
         &data_hash2,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Lessor-initiated dispute
@@ -291,7 +340,16 @@ fn test_dispute_state_persistence_across_operations() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Initial state
@@ -325,7 +383,6 @@ fn test_dispute_state_persistence_across_operations() {
 }
 
 #[test]
-#[should_panic(expected = "Only lease parties can raise disputes")]
 fn test_unauthorized_dispute_creation() {
     let env = Env::default();
     env.mock_all_auths();
@@ -343,15 +400,24 @@ fn test_unauthorized_dispute_creation() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let dispute_reason = String::from_str(&env, "Unauthorized interference");
-    client.raise_dispute(&lease_id, &outsider, &dispute_reason);
+    let result = client.try_raise_dispute(&lease_id, &outsider, &dispute_reason);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 }
 
 #[test]
-#[should_panic(expected = "Unauthorized resolver")]
 fn test_unauthorized_dispute_resolution() {
     let env = Env::default();
     env.mock_all_auths();
@@ -369,18 +435,27 @@ fn test_unauthorized_dispute_resolution() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let dispute_reason = String::from_str(&env, "Valid dispute");
     assert!(client.raise_dispute(&lease_id, &lessor, &dispute_reason));
 
     let resolution = String::from_str(&env, "Unauthorized resolution");
-    client.resolve_dispute(&lease_id, &unauthorized, &resolution);
+    let result = client.try_resolve_dispute(&lease_id, &unauthorized, &resolution);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 }
 
 #[test]
-#[should_panic(expected = "Dispute reason cannot be empty")]
 fn test_empty_dispute_reason() {
     let env = Env::default();
     env.mock_all_auths();
@@ -398,15 +473,24 @@ fn test_empty_dispute_reason() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     let empty_reason = String::from_str(&env, "");
-    client.raise_dispute(&lease_id, &lessor, &empty_reason);
+    let result = client.try_raise_dispute(&lease_id, &lessor, &empty_reason);
+    assert_eq!(result, Err(Ok(ContractError::EmptyDisputeReason)));
 }
 
 #[test]
-#[should_panic(expected = "Lease is not active")]
 fn test_dispute_on_terminated_lease() {
     let env = Env::default();
     env.mock_all_auths();
@@ -424,11 +508,45 @@ fn test_dispute_on_terminated_lease() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     assert!(client.terminate_lease(&lease_id, &lessor));
 
     let dispute_reason = String::from_str(&env, "Post-termination dispute");
-    client.raise_dispute(&lease_id, &lessor, &dispute_reason);
+    let result = client.try_raise_dispute(&lease_id, &lessor, &dispute_reason);
+    assert_eq!(result, Err(Ok(ContractError::LeaseNotActive)));
+}
+
+#[test]
+fn test_dispute_resolution_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+    let lease_id = create_fixed_lease(&env, &client, &lessor, &lessee);
+
+    // Nothing to resolve before a dispute is raised
+    let resolution = String::from_str(&env, "Settled");
+    assert_eq!(
+        client.try_resolve_dispute(&lease_id, &admin, &resolution),
+        Err(Ok(ContractError::DisputeNotFound))
+    );
+
+    client.raise_dispute(&lease_id, &lessee, &String::from_str(&env, "Fence"));
+    assert_eq!(
+        client.try_resolve_dispute(&lease_id, &admin, &String::from_str(&env, "")),
+        Err(Ok(ContractError::EmptyResolution))
+    );
 }
@@ -0,0 +1,278 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::*;
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String};
+
+const BOND: i128 = 200;
+
+struct BondSetup<'a> {
+    env: Env,
+    client: LandLeasingContractClient<'a>,
+    admin: Address,
+    lessor: Address,
+    lessee: Address,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    lease_id: BytesN<32>,
+}
+
+fn setup_bond(treasury: Option<Address>) -> BondSetup<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+    let lease_id = create_fixed_lease(&env, &client, &lessor, &lessee);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_dispute_bond(&admin, &token_address, &BOND, &treasury);
+
+    BondSetup {
+        token: token::Client::new(&env, &token_address),
+        token_admin: token::StellarAssetClient::new(&env, &token_address),
+        env,
+        client,
+        admin,
+        lessor,
+        lessee,
+        lease_id,
+    }
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "Fence damaged")
+}
+
+#[test]
+fn test_bond_refunded_when_dispute_upheld() {
+    let setup = setup_bond(None);
+    setup.token_admin.mint(&setup.lessee, &BOND);
+
+    assert!(setup
+        .client
+        .raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env)));
+    let dispute = setup
+        .client
+        .get_lease_disputes(&setup.lease_id, &0, &10)
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        setup.client.get_filing_bond(&dispute.dispute_id).status,
+        BondStatus::Held
+    );
+    assert_eq!(
+        setup.client.get_filing_bond(&dispute.dispute_id).amount,
+        BOND
+    );
+    assert_eq!(setup.token.balance(&setup.lessee), 0);
+    assert_eq!(setup.token.balance(&setup.client.address), BOND);
+
+    setup.client.resolve_dispute(
+        &setup.lease_id,
+        &setup.admin,
+        &String::from_str(&setup.env, "Lessor to repair fence"),
+    );
+    let dispute = setup
+        .client
+        .get_lease_disputes(&setup.lease_id, &0, &10)
+        .get(0)
+        .unwrap();
+    assert_eq!(dispute.status, String::from_str(&setup.env, "Resolved"));
+    assert_eq!(
+        setup.client.get_filing_bond(&dispute.dispute_id).status,
+        BondStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.lessee), BOND);
+    assert_eq!(setup.token.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_bond_forfeited_when_dismissed() {
+    let setup = setup_bond(None);
+    setup.token_admin.mint(&setup.lessee, &(BOND * 2));
+    let resolution = String::from_str(&setup.env, "No damage found");
+
+    setup
+        .client
+        .raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env));
+    setup
+        .client
+        .dismiss_dispute(&setup.lease_id, &setup.admin, &resolution);
+
+    // Without a treasury the defendant receives the bond
+    let dispute = setup
+        .client
+        .get_lease_disputes(&setup.lease_id, &0, &10)
+        .get(0)
+        .unwrap();
+    assert_eq!(dispute.status, String::from_str(&setup.env, "Rejected"));
+    assert_eq!(
+        setup.client.get_filing_bond(&dispute.dispute_id).status,
+        BondStatus::Forfeited
+    );
+    assert_eq!(setup.token.balance(&setup.lessor), BOND);
+    let lease = setup.client.get_lease_details(&setup.lease_id).unwrap();
+    assert_eq!(lease.status, String::from_str(&setup.env, "Active"));
+
+    // Once a treasury is configured it receives forfeited bonds instead
+    let treasury = Address::generate(&setup.env);
+    setup.client.set_dispute_bond(
+        &setup.admin,
+        &setup.token.address,
+        &BOND,
+        &Some(treasury.clone()),
+    );
+    setup
+        .client
+        .raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env));
+    setup
+        .client
+        .dismiss_dispute(&setup.lease_id, &setup.admin, &resolution);
+    assert_eq!(setup.token.balance(&treasury), BOND);
+    assert_eq!(setup.token.balance(&setup.lessor), BOND);
+    assert_eq!(
+        setup
+            .client
+            .get_lease_disputes(&setup.lease_id, &0, &10)
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_waived_complainant_files_without_bond() {
+    let setup = setup_bond(None);
+
+    assert_eq!(
+        setup
+            .client
+            .try_set_bond_waiver(&setup.lessee, &setup.lessee, &true),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    setup
+        .client
+        .set_bond_waiver(&setup.admin, &setup.lessee, &true);
+    assert!(setup.client.is_bond_waived(&setup.lessee));
+
+    assert!(setup
+        .client
+        .raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env)));
+    setup.client.dismiss_dispute(
+        &setup.lease_id,
+        &setup.admin,
+        &String::from_str(&setup.env, "No damage found"),
+    );
+
+    let dispute = setup
+        .client
+        .get_lease_disputes(&setup.lease_id, &0, &10)
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        setup.client.get_filing_bond(&dispute.dispute_id).status,
+        BondStatus::Waived
+    );
+    assert_eq!(setup.client.get_filing_bond(&dispute.dispute_id).amount, 0);
+    assert_eq!(setup.token.balance(&setup.lessor), 0);
+}
+
+#[test]
+fn test_failed_bond_transfer_blocks_dispute() {
+    let setup = setup_bond(None);
+    setup.token_admin.mint(&setup.lessee, &(BOND - 1));
+
+    let result =
+        setup
+            .client
+            .try_raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env));
+    assert_eq!(result, Err(Ok(ContractError::BondTransferFailed)));
+
+    // The lease was never frozen and there is no dispute to resolve
+    let lease = setup.client.get_lease_details(&setup.lease_id).unwrap();
+    assert_eq!(lease.status, String::from_str(&setup.env, "Active"));
+    assert_eq!(
+        setup
+            .client
+            .get_lease_disputes(&setup.lease_id, &0, &10)
+            .len(),
+        0
+    );
+    assert_eq!(setup.token.balance(&setup.lessee), BOND - 1);
+    let result = setup.client.try_resolve_dispute(
+        &setup.lease_id,
+        &setup.admin,
+        &String::from_str(&setup.env, "Nothing to resolve"),
+    );
+    assert_eq!(result, Err(Ok(ContractError::DisputeNotFound)));
+}
+
+#[test]
+fn test_invalid_bond_config_rejected() {
+    let setup = setup_bond(None);
+    assert_eq!(
+        setup
+            .client
+            .try_set_dispute_bond(&setup.admin, &setup.token.address, &-1, &None),
+        Err(Ok(ContractError::InvalidBondAmount))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_set_dispute_bond(&setup.lessor, &setup.token.address, &BOND, &None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(setup.client.get_dispute_bond().unwrap().amount, BOND);
+}
+
+#[test]
+fn test_lease_disputes_are_paged() {
+    let setup = setup_bond(None);
+    setup
+        .client
+        .set_bond_waiver(&setup.admin, &setup.lessee, &true);
+    let resolution = String::from_str(&setup.env, "No damage found");
+
+    for _ in 0..3 {
+        setup
+            .client
+            .raise_dispute(&setup.lease_id, &setup.lessee, &reason(&setup.env));
+        setup
+            .client
+            .dismiss_dispute(&setup.lease_id, &setup.admin, &resolution);
+    }
+    assert_eq!(setup.client.get_lease_dispute_count(&setup.lease_id), 3);
+
+    let all = setup.client.get_lease_disputes(&setup.lease_id, &0, &10);
+    assert_eq!(all.len(), 3);
+    let page = setup.client.get_lease_disputes(&setup.lease_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), all.get(1).unwrap());
+    assert_eq!(
+        setup
+            .client
+            .get_lease_disputes(&setup.lease_id, &3, &10)
+            .len(),
+        0
+    );
+
+    // An oversized limit is clamped instead of overflowing
+    assert_eq!(
+        setup
+            .client
+            .get_lease_disputes(&setup.lease_id, &0, &u32::MAX)
+            .len(),
+        3
+    );
+    assert_eq!(
+        setup
+            .client
+            .get_filing_bond(&all.get(0).unwrap().dispute_id)
+            .status,
+        BondStatus::Waived
+    );
+}
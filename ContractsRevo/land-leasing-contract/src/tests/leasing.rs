@@ -2,7 +2,7 @@
 
 use super::utils::*;
 use crate::*;
-use soroban_sdk::{testutils::Address as _, Bytes, String};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, String};
 
 #[test]
 fn test_initialize_contract() {
@@ -38,10 +38,16 @@ fn test_create_lease_agreement() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100,  // 100 hectares
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,  // 100 hectares
         &12,   // 12 months
         &1000, // 1000 units per month
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Verify lease was created
@@ -76,7 +82,16 @@ fn test_terminate_lease() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &75, &8, &800, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &75,
+        &8,
+        &800,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Terminate lease
@@ -89,7 +104,6 @@ fn test_terminate_lease() {
 }
 
 #[test]
-#[should_panic(expected = "Duration must be greater than 0")]
 fn test_create_lease_invalid_duration() {
     let env = Env::default();
     env.mock_all_auths();
@@ -107,11 +121,20 @@ fn test_create_lease_invalid_duration() {
     let data_bytes = Bytes::from_slice(&env, b"invalid_data_hash");
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
-    // This should panic due to zero duration
-    client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &0, // Invalid duration
-        &1000, &data_hash,
+    // This should fail due to zero duration
+    let result = client.try_create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &0, // Invalid duration
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
+    assert_eq!(result, Err(Ok(ContractError::InvalidDuration)));
 }
 
 #[test]
@@ -135,8 +158,16 @@ fn test_simple_functionality() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &10, &3, // 3 months instead of 1
-        &100, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &10,
+        &3, // 3 months instead of 1
+        &100,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Verify it exists
@@ -164,7 +195,6 @@ fn test_simple_functionality() {
 // ============ ADDITIONAL COMPREHENSIVE LEASE TESTS ============
 
 #[test]
-#[should_panic(expected = "Payment amount must be greater than 0")]
 fn test_create_lease_invalid_payment_amount() {
     let env = Env::default();
     env.mock_all_auths();
@@ -182,15 +212,23 @@ fn test_create_lease_invalid_payment_amount() {
     let data_bytes = Bytes::from_slice(&env, b"invalid_payment_hash");
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
-    // This should panic due to zero payment amount
-    client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &0, // Invalid payment amount
+    // This should fail due to zero payment amount
+    let result = client.try_create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &0, // Invalid payment amount
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
+    assert_eq!(result, Err(Ok(ContractError::InvalidPaymentAmount)));
 }
 
 #[test]
-#[should_panic(expected = "Land size must be greater than 0")]
 fn test_create_lease_invalid_land_size() {
     let env = Env::default();
     env.mock_all_auths();
@@ -208,11 +246,20 @@ fn test_create_lease_invalid_land_size() {
     let data_bytes = Bytes::from_slice(&env, b"invalid_size_hash");
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
-    // This should panic due to zero land size
-    client.create_lease(
-        &lessor, &lessee, &land_id, &location, &0, // Invalid land size
-        &12, &1000, &data_hash,
+    // This should fail due to zero land size
+    let result = client.try_create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &0, // Invalid land size
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
+    assert_eq!(result, Err(Ok(ContractError::InvalidLandSize)));
 }
 
 #[test]
@@ -244,6 +291,8 @@ fn test_multiple_lease_creation() {
             &(6 + i as u64),
             &(500 * i as i128),
             &data_hash,
+            &PaymentMode::FixedRent,
+            &None,
         );
 
         // Verify each lease was created properly
@@ -256,7 +305,6 @@ fn test_multiple_lease_creation() {
 }
 
 #[test]
-#[should_panic(expected = "Unauthorized termination attempt")]
 fn test_unauthorized_lease_termination() {
     let env = Env::default();
     env.mock_all_auths();
@@ -275,11 +323,21 @@ fn test_unauthorized_lease_termination() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
-    // This should panic - unauthorized termination
-    client.terminate_lease(&lease_id, &unauthorized);
+    // This should fail - unauthorized termination
+    let result = client.try_terminate_lease(&lease_id, &unauthorized);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 }
 
 #[test]
@@ -301,7 +359,16 @@ fn test_lease_termination_by_lessee() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Lessee should be able to terminate
@@ -352,6 +419,8 @@ fn test_scalability_high_volume_leases() {
             &(6 + (i % 18) as u64),
             &((1000 + (i % 5) * 200) as i128),
             &data_hash,
+            &PaymentMode::FixedRent,
+            &None,
         );
 
         // Verify lease creation
@@ -403,6 +472,8 @@ fn test_end_to_end_lease_ecosystem() {
             &4, // 4 months each
             &((600 + i * 100) as i128),
             &data_hash,
+            &PaymentMode::FixedRent,
+            &None,
         );
 
         ecosystem_leases.push_back(lease_id);
@@ -465,3 +536,114 @@ fn test_end_to_end_lease_ecosystem() {
         assert_eq!(payment_history.len(), 2);
     }
 }
+
+// ============ ERROR CODE TESTS ============
+
+#[test]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, other_admin, _, _) = create_test_accounts(&env);
+
+    client.initialize(&admin);
+    let result = client.try_initialize(&other_admin);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_create_lease_rejects_invalid_parties_and_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+
+    let land_id = BytesN::from_array(&env, &[1; 32]);
+    let location = String::from_str(&env, "Error Location");
+    let data_hash = BytesN::from_array(&env, &[2; 32]);
+
+    let result = client.try_create_lease(
+        &lessor,
+        &lessor,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(ContractError::SameParty)));
+
+    // Share only applies to crop-share leases
+    let result = client.try_create_lease(
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &Some(CropShareTerms {
+            share_bps: 2500,
+            commodity_token_contract: Address::generate(&env),
+            token_id: BytesN::from_array(&env, &[3; 32]),
+        }),
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidShare)));
+}
+
+#[test]
+fn test_missing_lease_returns_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, _, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+
+    let missing = BytesN::from_array(&env, &[42; 32]);
+    assert_eq!(
+        client.try_terminate_lease(&missing, &lessor),
+        Err(Ok(ContractError::LeaseNotFound))
+    );
+    assert_eq!(
+        client.try_extend_lease(&missing, &lessor, &6),
+        Err(Ok(ContractError::LeaseNotFound))
+    );
+    assert_eq!(
+        client.try_get_missed_payments(&missing),
+        Err(Ok(ContractError::LeaseNotFound))
+    );
+}
+
+#[test]
+fn test_extend_lease_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, lessee, outsider) = create_test_accounts(&env);
+    client.initialize(&admin);
+    let lease_id = create_fixed_lease(&env, &client, &lessor, &lessee);
+
+    assert_eq!(
+        client.try_extend_lease(&lease_id, &outsider, &6),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    client.terminate_lease(&lease_id, &lessor);
+    assert_eq!(
+        client.try_extend_lease(&lease_id, &lessee, &6),
+        Err(Ok(ContractError::LeaseNotActive))
+    );
+    assert_eq!(
+        client.try_terminate_lease(&lease_id, &lessee),
+        Err(Ok(ContractError::LeaseNotActive))
+    );
+}
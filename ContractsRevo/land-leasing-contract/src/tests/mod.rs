@@ -1,4 +1,6 @@
+mod crop_share;
 mod dispute;
+mod dispute_bond;
 mod leasing;
 mod payment;
 mod utils;
@@ -2,7 +2,7 @@
 
 use super::utils::*;
 use crate::*;
-use soroban_sdk::{testutils::Address as _, Address, Bytes, String};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, String};
 
 #[test]
 fn test_process_payment() {
@@ -23,7 +23,16 @@ fn test_process_payment() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &50, &6, &500, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &50,
+        &6,
+        &500,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Process payment
@@ -41,7 +50,6 @@ fn test_process_payment() {
 }
 
 #[test]
-#[should_panic(expected = "Only lessee can make payments")]
 fn test_payment_by_wrong_user() {
     let env = Env::default();
     env.mock_all_auths();
@@ -60,11 +68,21 @@ fn test_payment_by_wrong_user() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &50, &6, &500, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &50,
+        &6,
+        &500,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
-    // This should panic - other_user is not the lessee
-    client.process_payment(&lease_id, &other_user, &500);
+    // This should fail - other_user is not the lessee
+    let result = client.try_process_payment(&lease_id, &other_user, &500);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 }
 
 #[test]
@@ -88,9 +106,16 @@ fn test_lease_completion() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &10,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &10,
         &1, // 1 month - will complete after 1 payment
-        &100, &data_hash,
+        &100,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make the payment (completes the lease)
@@ -124,8 +149,16 @@ fn test_recurring_payments_validation() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &5, // 5 months
-        &200, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &5, // 5 months
+        &200,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make payments over multiple months
@@ -144,7 +177,6 @@ fn test_recurring_payments_validation() {
 }
 
 #[test]
-#[should_panic(expected = "Insufficient payment amount")]
 fn test_incorrect_payment_amount() {
     let env = Env::default();
     env.mock_all_auths();
@@ -163,16 +195,24 @@ fn test_incorrect_payment_amount() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, // Expected payment is 1000
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000, // Expected payment is 1000
         &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
-    // This should panic - incorrect payment amount
-    client.process_payment(&lease_id, &lessee, &800); // Wrong amount
+    // This should fail - incorrect payment amount
+    let result = client.try_process_payment(&lease_id, &lessee, &800); // Wrong amount
+    assert_eq!(result, Err(Ok(ContractError::InsufficientPayment)));
 }
 
 #[test]
-#[should_panic(expected = "Insufficient payment amount")]
 fn test_payment_insufficient_funds() {
     let env = Env::default();
     env.mock_all_auths();
@@ -191,11 +231,21 @@ fn test_payment_insufficient_funds() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
-    // This should panic - insufficient payment amount (paying less than required)
-    client.process_payment(&lease_id, &lessee, &500); // Less than required 1000
+    // This should fail - insufficient payment amount (paying less than required)
+    let result = client.try_process_payment(&lease_id, &lessee, &500); // Less than required 1000
+    assert_eq!(result, Err(Ok(ContractError::InsufficientPayment)));
 }
 
 #[test]
@@ -217,8 +267,16 @@ fn test_payment_schedule_adherence() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &150, &6, // 6 months
-        &300, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &150,
+        &6, // 6 months
+        &300,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make payments according to schedule
@@ -263,8 +321,16 @@ fn test_early_lease_termination_with_payments() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &10, // 10 months
-        &500, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &10, // 10 months
+        &500,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Make partial payments (3 of 10)
@@ -333,6 +399,8 @@ fn test_multiple_lease_payment_processing() {
             &3, // 3 months each
             &((400 + (i % 3) * 100) as i128),
             &data_hash,
+            &PaymentMode::FixedRent,
+            &None,
         );
 
         lease_ids.push_back(lease_id);
@@ -381,7 +449,16 @@ fn test_commodity_token_integration_placeholder() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &100, &12, &1000, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &100,
+        &12,
+        &1000,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Test foundation exists for commodity token integration
@@ -416,7 +493,16 @@ fn test_tokenized_payment_validation() {
     let data_hash = env.crypto().sha256(&data_bytes).into();
 
     let lease_id = client.create_lease(
-        &lessor, &lessee, &land_id, &location, &150, &8, &750, &data_hash,
+        &lessor,
+        &lessee,
+        &land_id,
+        &location,
+        &150,
+        &8,
+        &750,
+        &data_hash,
+        &PaymentMode::FixedRent,
+        &None,
     );
 
     // Test foundation for tokenized payments
@@ -430,3 +516,25 @@ fn test_tokenized_payment_validation() {
     let payment_history = client.get_payment_history(&lease_id);
     assert_eq!(payment_history.len(), 1);
 }
+
+#[test]
+fn test_payment_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_test_contract(&env);
+    let client = LandLeasingContractClient::new(&env, &contract_id);
+    let (admin, lessor, lessee, _) = create_test_accounts(&env);
+    client.initialize(&admin);
+    let lease_id = create_fixed_lease(&env, &client, &lessor, &lessee);
+
+    assert_eq!(
+        client.try_process_payment(&lease_id, &lessee, &0),
+        Err(Ok(ContractError::InvalidPaymentAmount))
+    );
+
+    let missing = BytesN::from_array(&env, &[42; 32]);
+    assert_eq!(
+        client.try_process_payment(&missing, &lessee, &1000),
+        Err(Ok(ContractError::LeaseNotFound))
+    );
+}
@@ -1,12 +1,33 @@
 #![cfg(test)]
 
 use crate::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
 
 pub fn create_test_contract(env: &Env) -> Address {
     env.register(LandLeasingContract, ())
 }
 
+/// Creates a 12-month fixed rent lease of 1000 per month
+pub fn create_fixed_lease(
+    env: &Env,
+    client: &LandLeasingContractClient,
+    lessor: &Address,
+    lessee: &Address,
+) -> BytesN<32> {
+    client.create_lease(
+        lessor,
+        lessee,
+        &BytesN::from_array(env, &[1; 32]),
+        &String::from_str(env, "Fixed Rent Field"),
+        &100,
+        &12,
+        &1000,
+        &BytesN::from_array(env, &[2; 32]),
+        &PaymentMode::FixedRent,
+        &None,
+    )
+}
+
 pub fn create_test_accounts(env: &Env) -> (Address, Address, Address, Address) {
     (
         Address::generate(env),
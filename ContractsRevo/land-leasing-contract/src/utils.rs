@@ -1,9 +1,14 @@
+use crate::error::ContractError;
 use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol};
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
 
-pub fn set_admin(env: &Env, admin: &Address) {
+pub fn set_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    if get_admin(env).is_some() {
+        return Err(ContractError::AlreadyInitialized);
+    }
     env.storage().instance().set(&ADMIN, admin);
+    Ok(())
 }
 
 pub fn get_admin(env: &Env) -> Option<Address> {
@@ -0,0 +1,52 @@
+use crate::program::{get_program_info, require_program_admin};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn add_awarder(env: &Env, program_admin: Address, program_id: BytesN<32>, awarder: Address) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "awarder"),
+            program_id.clone(),
+            awarder.clone(),
+        ),
+        &true,
+    );
+
+    env.events()
+        .publish((Symbol::new(env, "awarder_added"), program_id), awarder);
+}
+
+pub fn remove_awarder(env: &Env, program_admin: Address, program_id: BytesN<32>, awarder: Address) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    let key = (
+        Symbol::new(env, "awarder"),
+        program_id.clone(),
+        awarder.clone(),
+    );
+    if !env.storage().persistent().has(&key) {
+        panic!("Awarder not found");
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events()
+        .publish((Symbol::new(env, "awarder_removed"), program_id), awarder);
+}
+
+/// The program owner can always award points; anyone else must be registered.
+pub fn is_awarder(env: &Env, program_id: BytesN<32>, awarder: Address) -> bool {
+    get_program_info(env, program_id.clone()).owner == awarder
+        || env
+            .storage()
+            .persistent()
+            .has(&(Symbol::new(env, "awarder"), program_id, awarder))
+}
+
+pub(crate) fn require_awarder(env: &Env, program_id: &BytesN<32>, awarder: &Address) {
+    awarder.require_auth();
+
+    if !is_awarder(env, program_id.clone(), awarder.clone()) {
+        panic!("Unauthorized awarder");
+    }
+}
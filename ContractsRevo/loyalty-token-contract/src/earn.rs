@@ -1,9 +1,10 @@
-use crate::LoyaltyProgram;
+use crate::{awarders::require_awarder, points, tiers, LoyaltyProgram, PointsEventKind};
 use soroban_sdk::{Address, BytesN, Env, Symbol};
 
 pub fn award_points(
     env: &Env,
     program_id: BytesN<32>,
+    awarder: Address,
     user_address: Address,
     transaction_amount: u32,
 ) {
@@ -15,23 +16,10 @@ pub fn award_points(
         .get::<(Symbol, BytesN<32>), LoyaltyProgram>(&program_key)
         .expect("Program not found");
 
-    let points_to_award = (program.points_per_transaction as u64) * (transaction_amount as u64);
+    require_awarder(env, &program_id, &awarder);
 
-    let points_key = (
-        Symbol::new(env, "points"),
-        program_id.clone(),
-        user_address.clone(),
-    );
-
-    let current_points: u64 = env
-        .storage()
-        .persistent()
-        .get::<(Symbol, BytesN<32>, Address), u64>(&points_key)
-        .unwrap_or(0);
-
-    let new_points = current_points + points_to_award;
-
-    env.storage().persistent().set(&points_key, &new_points);
+    let base_points = (program.points_per_transaction as u64) * (transaction_amount as u64);
+    let points_to_award = credit_earned(env, &program_id, &user_address, base_points, &awarder);
 
     env.events().publish(
         (
@@ -42,3 +30,29 @@ pub fn award_points(
         points_to_award,
     );
 }
+
+/// Applies the user's tier multiplier to `base_points` and credits the result as earned.
+/// The multiplier comes from the tier reached before this award.
+pub(crate) fn credit_earned(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    base_points: u64,
+    actor: &Address,
+) -> u64 {
+    let multiplier_bps = tiers::earn_multiplier_bps(env, program_id, user_address);
+    let points_to_award =
+        (base_points as u128 * multiplier_bps as u128 / tiers::BASE_MULTIPLIER_BPS as u128) as u64;
+
+    points::credit(
+        env,
+        program_id,
+        user_address,
+        points_to_award,
+        PointsEventKind::Earn,
+        actor,
+    );
+    tiers::record_earned(env, program_id, user_address, points_to_award);
+
+    points_to_award
+}
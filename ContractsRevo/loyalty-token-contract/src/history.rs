@@ -0,0 +1,70 @@
+use crate::{PointsEvent, PointsEventKind};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Events kept per user and program; the oldest is dropped when a new one would exceed this
+pub const MAX_HISTORY_EVENTS: u32 = 100;
+
+/// Pages through a user's points history in a program, newest first.
+pub fn get_points_history(
+    env: &Env,
+    program_id: BytesN<32>,
+    user_address: Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<PointsEvent> {
+    let history = get_history(env, &program_id, &user_address);
+    let mut page = Vec::new(env);
+
+    for event in history
+        .iter()
+        .rev()
+        .skip(offset as usize)
+        .take(limit as usize)
+    {
+        page.push_back(event);
+    }
+
+    page
+}
+
+pub(crate) fn record(
+    env: &Env,
+    program_id: &BytesN<32>,
+    kind: PointsEventKind,
+    user_address: &Address,
+    amount: u64,
+    actor: &Address,
+) {
+    let mut history = get_history(env, program_id, user_address);
+    if history.len() >= MAX_HISTORY_EVENTS {
+        history.pop_front();
+    }
+
+    history.push_back(PointsEvent {
+        kind,
+        user: user_address.clone(),
+        amount,
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "history"),
+            program_id.clone(),
+            user_address.clone(),
+        ),
+        &history,
+    );
+}
+
+fn get_history(env: &Env, program_id: &BytesN<32>, user_address: &Address) -> Vec<PointsEvent> {
+    env.storage()
+        .persistent()
+        .get(&(
+            Symbol::new(env, "history"),
+            program_id.clone(),
+            user_address.clone(),
+        ))
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -1,10 +1,19 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, Address, BytesN, Env, String, Symbol, Vec,
+};
 
+mod awarders;
 mod earn;
+mod history;
+mod merchants;
+mod points;
 mod program;
 mod redeem;
 mod rewards;
+mod stats;
+mod tiers;
+mod transfer;
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +21,7 @@ mod tests;
 #[contracttype]
 pub struct LoyaltyProgram {
     pub program_id: BytesN<32>,
+    pub owner: Address,
     pub points_per_transaction: u32,
     pub redemption_options: Vec<RedemptionOption>,
 }
@@ -25,6 +35,106 @@ pub struct RedemptionOption {
     pub available_quantity: u32,
 }
 
+/// Points earned on the same day, tracked together so they can expire together
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointLot {
+    pub earned_at: u64,
+    pub amount: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointsEventKind {
+    Earn,
+    Redeem,
+    Expire,
+    Refund,
+    TransferIn,
+    TransferOut,
+    ConvertIn,
+    ConvertOut,
+}
+
+/// Exchange rate between two programs. `min_amount` is the smallest amount that converts to
+/// at least one destination point.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionRate {
+    pub rate_bps: u32,
+    pub min_amount: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedemptionStatus {
+    Pending,
+    Fulfilled,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Redemption {
+    pub id: u64,
+    pub program_id: BytesN<32>,
+    pub user: Address,
+    pub option_id: u32,
+    pub points: u64,
+    pub status: RedemptionStatus,
+    pub created_at: u64,
+    pub proof_hash: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsEvent {
+    pub kind: PointsEventKind,
+    pub user: Address,
+    pub amount: u64,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Running totals for a program. `outstanding` is the points liability: earned and refunded
+/// points minus redeemed and expired ones.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramStats {
+    pub total_earned: u64,
+    pub total_redeemed: u64,
+    pub total_expired: u64,
+    pub total_refunded: u64,
+    pub outstanding: u64,
+    pub active_users: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodStats {
+    pub period_bucket: u64,
+    pub earned: u64,
+    pub redeemed: u64,
+    pub expired: u64,
+    pub refunded: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub lifetime_points: u64,
+}
+
+/// A marketplace contract allowed to award points for purchases made through it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerchantRegistration {
+    pub merchant_contract: Address,
+    pub points_per_unit_spent: u32,
+    pub registered_at: u64,
+}
+
 #[contract]
 pub struct LoyaltyContract;
 
@@ -32,12 +142,14 @@ pub struct LoyaltyContract;
 impl LoyaltyContract {
     pub fn create_loyalty_program(
         env: Env,
+        program_admin: Address,
         program_id: BytesN<32>,
         points_per_transaction: u32,
         redemption_options: Vec<RedemptionOption>,
     ) {
         program::create_loyalty_program(
             &env,
+            program_admin,
             program_id,
             points_per_transaction,
             redemption_options,
@@ -47,10 +159,89 @@ impl LoyaltyContract {
     pub fn award_points(
         env: Env,
         program_id: BytesN<32>,
+        awarder: Address,
         user_address: Address,
         transaction_amount: u32,
     ) {
-        earn::award_points(&env, program_id, user_address, transaction_amount);
+        earn::award_points(&env, program_id, awarder, user_address, transaction_amount);
+    }
+
+    pub fn register_merchant_contract(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        merchant_contract: Address,
+        points_per_unit_spent: u32,
+    ) {
+        merchants::register_merchant_contract(
+            &env,
+            program_admin,
+            program_id,
+            merchant_contract,
+            points_per_unit_spent,
+        );
+    }
+
+    pub fn get_merchant_registration(
+        env: Env,
+        program_id: BytesN<32>,
+        merchant_contract: Address,
+    ) -> Option<MerchantRegistration> {
+        merchants::get_merchant_registration(&env, program_id, merchant_contract)
+    }
+
+    pub fn award_for_purchase(
+        env: Env,
+        merchant_contract: Address,
+        program_id: BytesN<32>,
+        buyer: Address,
+        amount_spent: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64 {
+        merchants::award_for_purchase(
+            &env,
+            merchant_contract,
+            program_id,
+            buyer,
+            amount_spent,
+            purchase_ref,
+        )
+    }
+
+    pub fn is_purchase_awarded(
+        env: Env,
+        program_id: BytesN<32>,
+        merchant_contract: Address,
+        purchase_ref: BytesN<32>,
+    ) -> bool {
+        merchants::is_purchase_awarded(&env, program_id, merchant_contract, purchase_ref)
+    }
+
+    pub fn add_awarder(env: Env, program_admin: Address, program_id: BytesN<32>, awarder: Address) {
+        awarders::add_awarder(&env, program_admin, program_id, awarder);
+    }
+
+    pub fn remove_awarder(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        awarder: Address,
+    ) {
+        awarders::remove_awarder(&env, program_admin, program_id, awarder);
+    }
+
+    pub fn is_awarder(env: Env, program_id: BytesN<32>, awarder: Address) -> bool {
+        awarders::is_awarder(&env, program_id, awarder)
+    }
+
+    pub fn get_points_history(
+        env: Env,
+        program_id: BytesN<32>,
+        user_address: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<PointsEvent> {
+        history::get_points_history(&env, program_id, user_address, offset, limit)
     }
 
     pub fn redeem_reward(
@@ -58,8 +249,34 @@ impl LoyaltyContract {
         program_id: BytesN<32>,
         user_address: Address,
         redemption_option_id: u32,
+    ) -> u64 {
+        redeem::redeem_reward(&env, program_id, user_address, redemption_option_id)
+    }
+
+    pub fn fulfill_redemption(
+        env: Env,
+        program_admin: Address,
+        redemption_id: u64,
+        proof_hash: BytesN<32>,
     ) {
-        redeem::redeem_reward(&env, program_id, user_address, redemption_option_id);
+        redeem::fulfill_redemption(&env, program_admin, redemption_id, proof_hash);
+    }
+
+    pub fn cancel_redemption(env: Env, program_admin: Address, redemption_id: u64) {
+        redeem::cancel_redemption(&env, program_admin, redemption_id);
+    }
+
+    pub fn get_redemption(env: Env, redemption_id: u64) -> Redemption {
+        redeem::get_redemption(&env, redemption_id)
+    }
+
+    pub fn list_redemptions(
+        env: Env,
+        program_id: BytesN<32>,
+        user_address: Address,
+        status_filter: Option<RedemptionStatus>,
+    ) -> Vec<Redemption> {
+        redeem::list_redemptions(&env, program_id, user_address, status_filter)
     }
 
     pub fn get_program_info(env: Env, program_id: BytesN<32>) -> LoyaltyProgram {
@@ -69,4 +286,150 @@ impl LoyaltyContract {
     pub fn list_available_rewards(env: Env, program_id: BytesN<32>) -> Vec<RedemptionOption> {
         rewards::list_available_rewards(&env, program_id)
     }
+
+    pub fn add_redemption_option(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        option: RedemptionOption,
+    ) {
+        rewards::add_redemption_option(&env, program_admin, program_id, option);
+    }
+
+    pub fn restock_option(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        option_id: u32,
+        additional_quantity: u32,
+    ) {
+        rewards::restock_option(
+            &env,
+            program_admin,
+            program_id,
+            option_id,
+            additional_quantity,
+        );
+    }
+
+    pub fn deactivate_option(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        option_id: u32,
+    ) {
+        rewards::deactivate_option(&env, program_admin, program_id, option_id);
+    }
+
+    pub fn set_expiry_policy(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        validity_secs: u64,
+    ) {
+        points::set_expiry_policy(&env, program_admin, program_id, validity_secs);
+    }
+
+    pub fn get_points_balance(env: Env, program_id: BytesN<32>, user_address: Address) -> u64 {
+        points::get_points_balance(&env, program_id, user_address)
+    }
+
+    pub fn get_expiring_points(
+        env: Env,
+        program_id: BytesN<32>,
+        user_address: Address,
+        within_secs: u64,
+    ) -> u64 {
+        points::get_expiring_points(&env, program_id, user_address, within_secs)
+    }
+
+    pub fn set_tiers(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        thresholds: Vec<(u32, Symbol)>,
+    ) {
+        tiers::set_tiers(&env, program_admin, program_id, thresholds);
+    }
+
+    pub fn set_tier_multiplier(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        tier: Symbol,
+        multiplier_bps: u32,
+    ) {
+        tiers::set_tier_multiplier(&env, program_admin, program_id, tier, multiplier_bps);
+    }
+
+    pub fn get_user_tier(
+        env: Env,
+        program_id: BytesN<32>,
+        user_address: Address,
+    ) -> Option<Symbol> {
+        tiers::get_user_tier(&env, program_id, user_address)
+    }
+
+    pub fn get_lifetime_points(env: Env, program_id: BytesN<32>, user_address: Address) -> u64 {
+        tiers::get_lifetime_points(&env, program_id, user_address)
+    }
+
+    pub fn set_transfers_enabled(
+        env: Env,
+        program_admin: Address,
+        program_id: BytesN<32>,
+        enabled: bool,
+    ) {
+        transfer::set_transfers_enabled(&env, program_admin, program_id, enabled);
+    }
+
+    pub fn transfer_points(
+        env: Env,
+        program_id: BytesN<32>,
+        from: Address,
+        to: Address,
+        amount: u64,
+    ) {
+        transfer::transfer_points(&env, program_id, from, to, amount);
+    }
+
+    pub fn set_conversion_rate(
+        env: Env,
+        program_admin: Address,
+        from_program: BytesN<32>,
+        to_program: BytesN<32>,
+        rate_bps: u32,
+    ) {
+        transfer::set_conversion_rate(&env, program_admin, from_program, to_program, rate_bps);
+    }
+
+    pub fn get_conversion_rate(
+        env: Env,
+        from_program: BytesN<32>,
+        to_program: BytesN<32>,
+    ) -> Option<ConversionRate> {
+        transfer::get_conversion_rate(&env, from_program, to_program)
+    }
+
+    pub fn convert_points(
+        env: Env,
+        user: Address,
+        from_program: BytesN<32>,
+        to_program: BytesN<32>,
+        amount: u64,
+    ) -> u64 {
+        transfer::convert_points(&env, user, from_program, to_program, amount)
+    }
+
+    pub fn get_program_stats(env: Env, program_id: BytesN<32>) -> ProgramStats {
+        stats::get_program_stats(&env, program_id)
+    }
+
+    pub fn get_period_stats(env: Env, program_id: BytesN<32>, period_bucket: u64) -> PeriodStats {
+        stats::get_period_stats(&env, program_id, period_bucket)
+    }
+
+    pub fn list_top_earners(env: Env, program_id: BytesN<32>, limit: u32) -> Vec<LeaderboardEntry> {
+        stats::list_top_earners(&env, program_id, limit)
+    }
 }
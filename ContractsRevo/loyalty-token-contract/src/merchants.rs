@@ -0,0 +1,112 @@
+use crate::{earn::credit_earned, program::require_program_admin, MerchantRegistration};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn register_merchant_contract(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    merchant_contract: Address,
+    points_per_unit_spent: u32,
+) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    if points_per_unit_spent == 0 {
+        panic!("Rate must be positive");
+    }
+
+    // Re-registering updates the rate; purchases already awarded keep their points
+    let registration = MerchantRegistration {
+        merchant_contract: merchant_contract.clone(),
+        points_per_unit_spent,
+        registered_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "merchant"),
+            program_id.clone(),
+            merchant_contract,
+        ),
+        &registration,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "merchant_registered"), program_id),
+        registration,
+    );
+}
+
+pub fn get_merchant_registration(
+    env: &Env,
+    program_id: BytesN<32>,
+    merchant_contract: Address,
+) -> Option<MerchantRegistration> {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "merchant"), program_id, merchant_contract))
+}
+
+/// Awards points for a purchase settled by a registered merchant contract. Each
+/// `purchase_ref` is awarded at most once per merchant; repeated calls award nothing and
+/// return 0.
+pub fn award_for_purchase(
+    env: &Env,
+    merchant_contract: Address,
+    program_id: BytesN<32>,
+    buyer: Address,
+    amount_spent: u64,
+    purchase_ref: BytesN<32>,
+) -> u64 {
+    merchant_contract.require_auth();
+
+    let registration =
+        get_merchant_registration(env, program_id.clone(), merchant_contract.clone())
+            .unwrap_or_else(|| panic!("Merchant not registered"));
+
+    let awarded_key = (
+        Symbol::new(env, "purchase"),
+        program_id.clone(),
+        merchant_contract.clone(),
+        purchase_ref.clone(),
+    );
+    if env.storage().persistent().has(&awarded_key) {
+        return 0;
+    }
+
+    if amount_spent == 0 {
+        panic!("Amount spent must be positive");
+    }
+
+    let base_points = amount_spent
+        .checked_mul(registration.points_per_unit_spent as u64)
+        .unwrap_or_else(|| panic!("Points overflow"));
+    let points_awarded = credit_earned(env, &program_id, &buyer, base_points, &merchant_contract);
+
+    env.storage()
+        .persistent()
+        .set(&awarded_key, &points_awarded);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "purchase_points_awarded"),
+            program_id,
+            buyer,
+        ),
+        (merchant_contract, purchase_ref, points_awarded),
+    );
+
+    points_awarded
+}
+
+pub fn is_purchase_awarded(
+    env: &Env,
+    program_id: BytesN<32>,
+    merchant_contract: Address,
+    purchase_ref: BytesN<32>,
+) -> bool {
+    env.storage().persistent().has(&(
+        Symbol::new(env, "purchase"),
+        program_id,
+        merchant_contract,
+        purchase_ref,
+    ))
+}
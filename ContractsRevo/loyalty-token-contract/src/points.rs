@@ -0,0 +1,290 @@
+use crate::{history, program::require_program_admin, stats, PointLot, PointsEventKind};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Awards made within the same day share a lot and expire together with the day's first award
+pub const LOT_BUCKET_SECS: u64 = 24 * 60 * 60;
+
+pub fn set_expiry_policy(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    validity_secs: u64,
+) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    if validity_secs == 0 {
+        panic!("Validity must be positive");
+    }
+
+    env.storage().persistent().set(
+        &(Symbol::new(env, "expiry"), program_id.clone()),
+        &validity_secs,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "expiry_policy_set"), program_id),
+        validity_secs,
+    );
+}
+
+pub fn get_points_balance(env: &Env, program_id: BytesN<32>, user_address: Address) -> u64 {
+    sweep_expired(env, &program_id, &user_address);
+    get_balance(env, &program_id, &user_address)
+}
+
+/// Points that are still spendable now but expire within the next `within_secs`.
+pub fn get_expiring_points(
+    env: &Env,
+    program_id: BytesN<32>,
+    user_address: Address,
+    within_secs: u64,
+) -> u64 {
+    let Some(validity) = get_validity(env, &program_id) else {
+        return 0;
+    };
+
+    let now = env.ledger().timestamp();
+    let horizon = now.saturating_add(within_secs);
+
+    get_lots(env, &program_id, &user_address)
+        .iter()
+        .filter(|lot| {
+            let expires_at = lot.earned_at.saturating_add(validity);
+            expires_at > now && expires_at <= horizon
+        })
+        .map(|lot| lot.amount)
+        .sum()
+}
+
+pub(crate) fn credit(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    amount: u64,
+    kind: PointsEventKind,
+    actor: &Address,
+) {
+    if amount == 0 {
+        return;
+    }
+    sweep_expired(env, program_id, user_address);
+
+    let now = env.ledger().timestamp();
+    let bucket = now - now % LOT_BUCKET_SECS;
+
+    let mut lots = get_lots(env, program_id, user_address);
+    match lots.last() {
+        Some(mut lot) if lot.earned_at >= bucket => {
+            lot.amount += amount;
+            lots.set(lots.len() - 1, lot);
+        }
+        _ => lots.push_back(PointLot {
+            earned_at: now,
+            amount,
+        }),
+    }
+    save_lots(env, program_id, user_address, &lots);
+
+    let balance = get_balance(env, program_id, user_address);
+    set_balance(env, program_id, user_address, balance + amount);
+
+    log(env, program_id, kind, user_address, amount, actor);
+}
+
+/// Spends points oldest-first, so the lots closest to expiring are used up before newer ones.
+/// Returns the (parts of) lots that were taken.
+pub(crate) fn debit(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    amount: u64,
+    kind: PointsEventKind,
+    actor: &Address,
+) -> Vec<PointLot> {
+    sweep_expired(env, program_id, user_address);
+
+    let balance = get_balance(env, program_id, user_address);
+    if balance < amount {
+        panic!("Insufficient points");
+    }
+
+    let mut lots = get_lots(env, program_id, user_address);
+
+    // Points earned before lots were tracked never expire and are the oldest, so they go first
+    let lots_total: u64 = lots.iter().map(|lot| lot.amount).sum();
+    let mut remaining = amount.saturating_sub(balance.saturating_sub(lots_total));
+    let mut taken = Vec::new(env);
+
+    while remaining > 0 {
+        let Some(mut lot) = lots.first() else {
+            break;
+        };
+        if lot.amount > remaining {
+            lot.amount -= remaining;
+            lots.set(0, lot.clone());
+            taken.push_back(PointLot {
+                earned_at: lot.earned_at,
+                amount: remaining,
+            });
+            remaining = 0;
+        } else {
+            remaining -= lot.amount;
+            lots.pop_front();
+            taken.push_back(lot);
+        }
+    }
+    save_lots(env, program_id, user_address, &lots);
+
+    set_balance(env, program_id, user_address, balance - amount);
+
+    log(env, program_id, kind, user_address, amount, actor);
+
+    taken
+}
+
+/// Credits points that keep the earn dates of the given lots, so they expire when they would
+/// have for the previous holder. Any part of `amount` not covered by `lots` never expires.
+pub(crate) fn credit_lots(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    amount: u64,
+    incoming: Vec<PointLot>,
+    kind: PointsEventKind,
+    actor: &Address,
+) {
+    sweep_expired(env, program_id, user_address);
+
+    let mut lots = get_lots(env, program_id, user_address);
+    for lot in incoming.iter() {
+        let position = lots
+            .iter()
+            .position(|existing| existing.earned_at > lot.earned_at)
+            .unwrap_or(lots.len() as usize);
+        lots.insert(position as u32, lot);
+    }
+    save_lots(env, program_id, user_address, &lots);
+
+    let balance = get_balance(env, program_id, user_address);
+    set_balance(env, program_id, user_address, balance + amount);
+
+    log(env, program_id, kind, user_address, amount, actor);
+}
+
+/// Drops every lot past the program's validity and returns how many points expired.
+pub(crate) fn sweep_expired(env: &Env, program_id: &BytesN<32>, user_address: &Address) -> u64 {
+    let Some(validity) = get_validity(env, program_id) else {
+        return 0;
+    };
+
+    let now = env.ledger().timestamp();
+    let mut lots = get_lots(env, program_id, user_address);
+    let mut expired: u64 = 0;
+
+    while let Some(lot) = lots.first() {
+        if lot.earned_at.saturating_add(validity) > now {
+            break;
+        }
+        expired += lot.amount;
+        lots.pop_front();
+    }
+
+    if expired == 0 {
+        return 0;
+    }
+
+    save_lots(env, program_id, user_address, &lots);
+    let balance = get_balance(env, program_id, user_address);
+    set_balance(
+        env,
+        program_id,
+        user_address,
+        balance.saturating_sub(expired),
+    );
+
+    // Expiry happens lazily on whoever touches the balance, so the contract is the actor
+    log(
+        env,
+        program_id,
+        PointsEventKind::Expire,
+        user_address,
+        expired,
+        &env.current_contract_address(),
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(env, "points_expired"),
+            program_id.clone(),
+            user_address.clone(),
+        ),
+        expired,
+    );
+
+    expired
+}
+
+fn log(
+    env: &Env,
+    program_id: &BytesN<32>,
+    kind: PointsEventKind,
+    user_address: &Address,
+    amount: u64,
+    actor: &Address,
+) {
+    history::record(env, program_id, kind, user_address, amount, actor);
+    stats::record(env, program_id, kind, amount);
+}
+
+fn get_validity(env: &Env, program_id: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "expiry"), program_id.clone()))
+}
+
+fn get_balance(env: &Env, program_id: &BytesN<32>, user_address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get::<(Symbol, BytesN<32>, Address), u64>(&(
+            Symbol::new(env, "points"),
+            program_id.clone(),
+            user_address.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+fn set_balance(env: &Env, program_id: &BytesN<32>, user_address: &Address, balance: u64) {
+    let previous = get_balance(env, program_id, user_address);
+    stats::record_balance_change(env, program_id, previous, balance);
+
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "points"),
+            program_id.clone(),
+            user_address.clone(),
+        ),
+        &balance,
+    );
+}
+
+fn get_lots(env: &Env, program_id: &BytesN<32>, user_address: &Address) -> Vec<PointLot> {
+    env.storage()
+        .persistent()
+        .get(&(
+            Symbol::new(env, "lots"),
+            program_id.clone(),
+            user_address.clone(),
+        ))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_lots(env: &Env, program_id: &BytesN<32>, user_address: &Address, lots: &Vec<PointLot>) {
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "lots"),
+            program_id.clone(),
+            user_address.clone(),
+        ),
+        lots,
+    );
+}
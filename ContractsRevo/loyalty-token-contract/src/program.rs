@@ -1,18 +1,22 @@
 use crate::{LoyaltyProgram, RedemptionOption};
-use soroban_sdk::{BytesN, Env, Symbol, Vec};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn create_loyalty_program(
     env: &Env,
+    program_admin: Address,
     program_id: BytesN<32>,
     points_per_transaction: u32,
     redemption_options: Vec<RedemptionOption>,
 ) {
+    program_admin.require_auth();
+
     let key = (Symbol::new(env, "program"), program_id.clone());
     if env.storage().persistent().has(&key) {
         panic!("Program already exists");
     }
     let program = LoyaltyProgram {
         program_id,
+        owner: program_admin,
         points_per_transaction,
         redemption_options,
     };
@@ -26,3 +30,18 @@ pub fn get_program_info(env: &Env, program_id: BytesN<32>) -> LoyaltyProgram {
         .get::<(Symbol, BytesN<32>), LoyaltyProgram>(&key)
         .expect("Program not found")
 }
+
+/// Checks that `program_admin` authorized the call and owns the program.
+pub(crate) fn require_program_admin(
+    env: &Env,
+    program_id: &BytesN<32>,
+    program_admin: &Address,
+) -> LoyaltyProgram {
+    program_admin.require_auth();
+
+    let program = get_program_info(env, program_id.clone());
+    if program.owner != *program_admin {
+        panic!("Not the program owner");
+    }
+    program
+}
@@ -1,59 +1,74 @@
-use crate::LoyaltyProgram;
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::{
+    points,
+    program::{get_program_info, require_program_admin},
+    rewards::{is_option_active, update_stock},
+    PointsEventKind, Redemption, RedemptionStatus,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
+/// Spends the user's points on a reward and opens a pending redemption for the program to
+/// fulfill. Returns the redemption id.
 pub fn redeem_reward(
     env: &Env,
     program_id: BytesN<32>,
     user_address: Address,
     redemption_option_id: u32,
-) {
-    let program_key = (Symbol::new(env, "program"), program_id.clone());
+) -> u64 {
+    user_address.require_auth();
 
-    let mut program: LoyaltyProgram = env
-        .storage()
-        .persistent()
-        .get::<(Symbol, BytesN<32>), LoyaltyProgram>(&program_key)
-        .expect("Program not found");
+    let program = get_program_info(env, program_id.clone());
 
-    let points_key = (
-        Symbol::new(env, "points"),
-        program_id.clone(),
-        user_address.clone(),
-    );
-
-    let user_points: u64 = env
-        .storage()
-        .persistent()
-        .get::<(Symbol, BytesN<32>, Address), u64>(&points_key)
-        .unwrap_or(0);
-
-    let option_index = program
+    let option = program
         .redemption_options
         .iter()
-        .position(|opt| opt.id == redemption_option_id)
-        .expect("Redemption option not found");
-
-    let mut option = program
-        .redemption_options
-        .get(option_index.try_into().unwrap())
+        .find(|opt| opt.id == redemption_option_id)
         .expect("Redemption option not found");
 
+    if !is_option_active(env, &program_id, redemption_option_id) {
+        panic!("Redemption option is inactive");
+    }
     if option.available_quantity == 0 {
         panic!("Reward is out of stock");
     }
-    if user_points < option.points_required as u64 {
-        panic!("Insufficient points");
-    }
+    points::debit(
+        env,
+        &program_id,
+        &user_address,
+        option.points_required as u64,
+        PointsEventKind::Redeem,
+        &user_address,
+    );
 
-    let new_points = user_points - option.points_required as u64;
-    env.storage().persistent().set(&points_key, &new_points);
+    update_stock(env, program, redemption_option_id, |quantity| quantity - 1);
 
-    option.available_quantity -= 1;
-    program
-        .redemption_options
-        .set(option_index.try_into().unwrap(), option);
+    let count_key = Symbol::new(env, "redemption_count");
+    let redemption_id: u64 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &redemption_id);
 
-    env.storage().persistent().set(&program_key, &program);
+    let redemption = Redemption {
+        id: redemption_id,
+        program_id: program_id.clone(),
+        user: user_address.clone(),
+        option_id: redemption_option_id,
+        points: option.points_required as u64,
+        status: RedemptionStatus::Pending,
+        created_at: env.ledger().timestamp(),
+        proof_hash: None,
+    };
+    save_redemption(env, &redemption);
+
+    let index_key = (
+        Symbol::new(env, "user_redemptions"),
+        program_id.clone(),
+        user_address.clone(),
+    );
+    let mut redemption_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&index_key)
+        .unwrap_or_else(|| Vec::new(env));
+    redemption_ids.push_back(redemption_id);
+    env.storage().persistent().set(&index_key, &redemption_ids);
 
     env.events().publish(
         (
@@ -61,6 +76,114 @@ pub fn redeem_reward(
             program_id,
             user_address,
         ),
-        redemption_option_id,
+        (redemption_option_id, redemption_id),
+    );
+
+    redemption_id
+}
+
+/// Records that the reward was handed over, with a hash of the delivery proof.
+pub fn fulfill_redemption(
+    env: &Env,
+    program_admin: Address,
+    redemption_id: u64,
+    proof_hash: BytesN<32>,
+) {
+    let mut redemption = get_pending_redemption(env, &program_admin, redemption_id);
+
+    redemption.status = RedemptionStatus::Fulfilled;
+    redemption.proof_hash = Some(proof_hash.clone());
+    save_redemption(env, &redemption);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "redemption_fulfilled"),
+            redemption.program_id,
+            redemption.user,
+        ),
+        (redemption_id, proof_hash),
     );
 }
+
+/// Cancels a pending redemption, giving the points back to the user and the reward back to
+/// stock. Restored points start a new validity period.
+pub fn cancel_redemption(env: &Env, program_admin: Address, redemption_id: u64) {
+    let mut redemption = get_pending_redemption(env, &program_admin, redemption_id);
+
+    redemption.status = RedemptionStatus::Cancelled;
+    save_redemption(env, &redemption);
+
+    points::credit(
+        env,
+        &redemption.program_id,
+        &redemption.user,
+        redemption.points,
+        PointsEventKind::Refund,
+        &program_admin,
+    );
+
+    let program = get_program_info(env, redemption.program_id.clone());
+    update_stock(env, program, redemption.option_id, |quantity| {
+        quantity.saturating_add(1)
+    });
+
+    env.events().publish(
+        (
+            Symbol::new(env, "redemption_cancelled"),
+            redemption.program_id,
+            redemption.user,
+        ),
+        redemption_id,
+    );
+}
+
+pub fn get_redemption(env: &Env, redemption_id: u64) -> Redemption {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "redemption"), redemption_id))
+        .expect("Redemption not found")
+}
+
+/// The user's redemptions in a program, oldest first, optionally limited to one status.
+pub fn list_redemptions(
+    env: &Env,
+    program_id: BytesN<32>,
+    user_address: Address,
+    status_filter: Option<RedemptionStatus>,
+) -> Vec<Redemption> {
+    let redemption_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&(
+            Symbol::new(env, "user_redemptions"),
+            program_id,
+            user_address,
+        ))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut redemptions = Vec::new(env);
+    for redemption_id in redemption_ids.iter() {
+        let redemption = get_redemption(env, redemption_id);
+        if status_filter.is_none_or(|status| redemption.status == status) {
+            redemptions.push_back(redemption);
+        }
+    }
+
+    redemptions
+}
+
+fn get_pending_redemption(env: &Env, program_admin: &Address, redemption_id: u64) -> Redemption {
+    let redemption = get_redemption(env, redemption_id);
+    require_program_admin(env, &redemption.program_id, program_admin);
+
+    if redemption.status != RedemptionStatus::Pending {
+        panic!("Redemption is not pending");
+    }
+    redemption
+}
+
+fn save_redemption(env: &Env, redemption: &Redemption) {
+    env.storage()
+        .persistent()
+        .set(&(Symbol::new(env, "redemption"), redemption.id), redemption);
+}
@@ -1,15 +1,134 @@
-use crate::{program::get_program_info, RedemptionOption};
-use soroban_sdk::{BytesN, Env, Vec};
+use crate::{
+    program::{get_program_info, require_program_admin},
+    LoyaltyProgram, RedemptionOption,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn list_available_rewards(env: &Env, program_id: BytesN<32>) -> Vec<RedemptionOption> {
-    let program = get_program_info(env, program_id);
+    let program = get_program_info(env, program_id.clone());
 
     let mut available_rewards = Vec::new(env);
 
     for opt in program.redemption_options {
-        if opt.available_quantity > 0 {
+        if opt.available_quantity > 0 && is_option_active(env, &program_id, opt.id) {
             available_rewards.push_back(opt);
         }
     }
     available_rewards
 }
+
+pub fn add_redemption_option(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    option: RedemptionOption,
+) {
+    let mut program = require_program_admin(env, &program_id, &program_admin);
+
+    if program
+        .redemption_options
+        .iter()
+        .any(|existing| existing.id == option.id)
+    {
+        panic!("Redemption option already exists");
+    }
+
+    program.redemption_options.push_back(option.clone());
+    save_program(env, &program);
+
+    env.events().publish(
+        (Symbol::new(env, "redemption_option_added"), program_id),
+        option.id,
+    );
+}
+
+pub fn restock_option(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    option_id: u32,
+    additional_quantity: u32,
+) {
+    let program = require_program_admin(env, &program_id, &program_admin);
+
+    if additional_quantity == 0 {
+        panic!("Quantity must be positive");
+    }
+
+    let available_quantity = update_stock(env, program, option_id, |quantity| {
+        quantity
+            .checked_add(additional_quantity)
+            .expect("Quantity overflow")
+    });
+
+    env.events().publish(
+        (Symbol::new(env, "redemption_option_restocked"), program_id),
+        (option_id, available_quantity),
+    );
+}
+
+/// Retires an option so it can no longer be redeemed. Pending redemptions are unaffected.
+pub fn deactivate_option(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    option_id: u32,
+) {
+    let program = require_program_admin(env, &program_id, &program_admin);
+
+    if !program
+        .redemption_options
+        .iter()
+        .any(|option| option.id == option_id)
+    {
+        panic!("Redemption option not found");
+    }
+
+    env.storage().persistent().set(
+        &(Symbol::new(env, "inactive"), program_id.clone(), option_id),
+        &true,
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(env, "redemption_option_deactivated"),
+            program_id,
+        ),
+        option_id,
+    );
+}
+
+pub(crate) fn is_option_active(env: &Env, program_id: &BytesN<32>, option_id: u32) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&(Symbol::new(env, "inactive"), program_id.clone(), option_id))
+}
+
+/// Applies `update` to an option's available quantity and returns the new quantity.
+pub(crate) fn update_stock(
+    env: &Env,
+    mut program: LoyaltyProgram,
+    option_id: u32,
+    update: impl FnOnce(u32) -> u32,
+) -> u32 {
+    let option_index = program
+        .redemption_options
+        .iter()
+        .position(|opt| opt.id == option_id)
+        .expect("Redemption option not found") as u32;
+
+    let mut option = program.redemption_options.get(option_index).unwrap();
+    option.available_quantity = update(option.available_quantity);
+    let available_quantity = option.available_quantity;
+    program.redemption_options.set(option_index, option);
+    save_program(env, &program);
+
+    available_quantity
+}
+
+fn save_program(env: &Env, program: &LoyaltyProgram) {
+    env.storage().persistent().set(
+        &(Symbol::new(env, "program"), program.program_id.clone()),
+        program,
+    );
+}
@@ -0,0 +1,156 @@
+use crate::{LeaderboardEntry, PeriodStats, PointsEventKind, ProgramStats};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Length of a reporting period; periods are numbered from the Unix epoch
+pub const PERIOD_SECS: u64 = 24 * 60 * 60;
+/// Users kept on a program's leaderboard
+pub const LEADERBOARD_SIZE: u32 = 10;
+
+pub fn get_program_stats(env: &Env, program_id: BytesN<32>) -> ProgramStats {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "stats"), program_id))
+        .unwrap_or(ProgramStats {
+            total_earned: 0,
+            total_redeemed: 0,
+            total_expired: 0,
+            total_refunded: 0,
+            outstanding: 0,
+            active_users: 0,
+        })
+}
+
+pub fn get_period_stats(env: &Env, program_id: BytesN<32>, period_bucket: u64) -> PeriodStats {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "period_stats"), program_id, period_bucket))
+        .unwrap_or(PeriodStats {
+            period_bucket,
+            earned: 0,
+            redeemed: 0,
+            expired: 0,
+            refunded: 0,
+        })
+}
+
+/// Highest lifetime earners, highest first.
+pub fn list_top_earners(env: &Env, program_id: BytesN<32>, limit: u32) -> Vec<LeaderboardEntry> {
+    let leaderboard = get_leaderboard(env, &program_id);
+    let mut top = Vec::new(env);
+
+    for entry in leaderboard.iter().take(limit as usize) {
+        top.push_back(entry);
+    }
+
+    top
+}
+
+/// Adds a points movement to the program and period totals. Points converted into the
+/// program count as earned and points converted out as redeemed, since they enter or leave
+/// the program's liability; transfers between users leave the totals unchanged.
+pub(crate) fn record(env: &Env, program_id: &BytesN<32>, kind: PointsEventKind, amount: u64) {
+    let mut stats = get_program_stats(env, program_id.clone());
+    let period_bucket = env.ledger().timestamp() / PERIOD_SECS;
+    let mut period = get_period_stats(env, program_id.clone(), period_bucket);
+
+    match kind {
+        PointsEventKind::Earn | PointsEventKind::ConvertIn => {
+            stats.total_earned += amount;
+            period.earned += amount;
+        }
+        PointsEventKind::Redeem | PointsEventKind::ConvertOut => {
+            stats.total_redeemed += amount;
+            period.redeemed += amount;
+        }
+        PointsEventKind::Expire => {
+            stats.total_expired += amount;
+            period.expired += amount;
+        }
+        PointsEventKind::Refund => {
+            stats.total_refunded += amount;
+            period.refunded += amount;
+        }
+        PointsEventKind::TransferIn | PointsEventKind::TransferOut => return,
+    }
+    stats.outstanding = (stats.total_earned + stats.total_refunded)
+        .saturating_sub(stats.total_redeemed + stats.total_expired);
+
+    save_program_stats(env, program_id, &stats);
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "period_stats"),
+            program_id.clone(),
+            period_bucket,
+        ),
+        &period,
+    );
+}
+
+/// Keeps the count of users holding points in step with a balance change.
+pub(crate) fn record_balance_change(env: &Env, program_id: &BytesN<32>, old: u64, new: u64) {
+    if (old == 0) == (new == 0) {
+        return;
+    }
+
+    let mut stats = get_program_stats(env, program_id.clone());
+    if new > 0 {
+        stats.active_users += 1;
+    } else {
+        stats.active_users = stats.active_users.saturating_sub(1);
+    }
+    save_program_stats(env, program_id, &stats);
+}
+
+pub(crate) fn update_leaderboard(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    lifetime_points: u64,
+) {
+    let mut leaderboard = get_leaderboard(env, program_id);
+
+    if let Some(index) = leaderboard
+        .iter()
+        .position(|entry| entry.user == *user_address)
+    {
+        leaderboard.remove(index as u32);
+    }
+
+    // Earlier entries win ties, so a newcomer never evicts an equal score
+    let position = leaderboard
+        .iter()
+        .position(|entry| entry.lifetime_points < lifetime_points)
+        .unwrap_or(leaderboard.len() as usize) as u32;
+    if position >= LEADERBOARD_SIZE {
+        return;
+    }
+
+    leaderboard.insert(
+        position,
+        LeaderboardEntry {
+            user: user_address.clone(),
+            lifetime_points,
+        },
+    );
+    while leaderboard.len() > LEADERBOARD_SIZE {
+        leaderboard.pop_back();
+    }
+
+    env.storage().persistent().set(
+        &(Symbol::new(env, "leaderboard"), program_id.clone()),
+        &leaderboard,
+    );
+}
+
+fn get_leaderboard(env: &Env, program_id: &BytesN<32>) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "leaderboard"), program_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_program_stats(env: &Env, program_id: &BytesN<32>, stats: &ProgramStats) {
+    env.storage()
+        .persistent()
+        .set(&(Symbol::new(env, "stats"), program_id.clone()), stats);
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::{history::MAX_HISTORY_EVENTS, PointsEventKind};
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn test_registered_awarder_can_award() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let awarder = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    assert_eq!(client.get_program_info(&program_id).owner, admin);
+    assert!(client.is_awarder(&program_id, &admin));
+    assert!(!client.is_awarder(&program_id, &awarder));
+
+    client.add_awarder(&admin, &program_id, &awarder);
+    client.award_points(&program_id, &awarder, &user, &40);
+    assert_eq!(client.get_points_balance(&program_id, &user), 40);
+
+    client.remove_awarder(&admin, &program_id, &awarder);
+    assert!(!client.is_awarder(&program_id, &awarder));
+    assert!(client
+        .try_award_points(&program_id, &awarder, &user, &40)
+        .is_err());
+    assert_eq!(client.get_points_balance(&program_id, &user), 40);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized awarder")]
+fn test_unauthorized_award_rejected() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let stranger = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &stranger, &stranger, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Not the program owner")]
+fn test_only_owner_manages_awarders() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let stranger = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.add_awarder(&stranger, &program_id, &stranger);
+}
+
+#[test]
+fn test_history_across_earn_redeem_and_expiry() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let awarder = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.set_expiry_policy(&admin, &program_id, &1_000);
+    client.add_awarder(&admin, &program_id, &awarder);
+
+    client.award_points(&program_id, &awarder, &user, &150);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.redeem_reward(&program_id, &user, &2);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.get_points_balance(&program_id, &user), 0);
+
+    // Newest first
+    let history = client.get_points_history(&program_id, &user, &0, &10);
+    assert_eq!(history.len(), 3);
+
+    let expired = history.get(0).unwrap();
+    assert_eq!(expired.kind, PointsEventKind::Expire);
+    assert_eq!(expired.amount, 50);
+    assert_eq!(expired.actor, contract_address);
+    assert_eq!(expired.timestamp, 1_000);
+
+    let redeemed = history.get(1).unwrap();
+    assert_eq!(redeemed.kind, PointsEventKind::Redeem);
+    assert_eq!(redeemed.amount, 100);
+    assert_eq!(redeemed.actor, user);
+
+    let earned = history.get(2).unwrap();
+    assert_eq!(earned.kind, PointsEventKind::Earn);
+    assert_eq!(earned.amount, 150);
+    assert_eq!(earned.actor, awarder);
+    assert_eq!(earned.user, user);
+}
+
+#[test]
+fn test_history_pagination_and_bound() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    for amount in 1..=5 {
+        client.award_points(&program_id, &admin, &user, &amount);
+    }
+
+    let page = client.get_points_history(&program_id, &user, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 4);
+    assert_eq!(page.get(1).unwrap().amount, 3);
+
+    let tail = client.get_points_history(&program_id, &user, &4, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().amount, 1);
+    assert!(client
+        .get_points_history(&program_id, &user, &5, &10)
+        .is_empty());
+
+    // Only the most recent events are retained
+    for _ in 0..MAX_HISTORY_EVENTS {
+        client.award_points(&program_id, &admin, &user, &10);
+    }
+    let history = client.get_points_history(&program_id, &user, &0, &(MAX_HISTORY_EVENTS + 10));
+    assert_eq!(history.len(), MAX_HISTORY_EVENTS);
+    assert!(history.iter().all(|event| event.amount == 10));
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::utils::*;
+use soroban_sdk::{testutils::Ledger, Env};
+
+const DAY: u64 = 24 * 60 * 60;
+const VALIDITY: u64 = 30 * DAY;
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_fifo_expiry_during_redemption() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let rewards = create_basic_rewards(&env);
+
+    let client = create_client(&env, &contract_address);
+    client.create_loyalty_program(&admin, &program_id, &1, &rewards);
+    client.set_expiry_policy(&admin, &program_id, &VALIDITY);
+
+    client.award_points(&program_id, &admin, &user, &100);
+    set_time(&env, 10 * DAY);
+    client.award_points(&program_id, &admin, &user, &100);
+
+    // Free Shipping (50 points) is paid from the oldest lot
+    set_time(&env, 11 * DAY);
+    client.redeem_reward(&program_id, &user, &3);
+
+    // Only what is left of the first lot expires
+    set_time(&env, VALIDITY);
+    assert_eq!(client.get_points_balance(&program_id, &user), 100);
+
+    set_time(&env, 10 * DAY + VALIDITY);
+    assert_eq!(client.get_points_balance(&program_id, &user), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient points")]
+fn test_expired_points_cannot_be_redeemed() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let rewards = create_basic_rewards(&env);
+
+    let client = create_client(&env, &contract_address);
+    client.create_loyalty_program(&admin, &program_id, &1, &rewards);
+    client.set_expiry_policy(&admin, &program_id, &VALIDITY);
+    client.award_points(&program_id, &admin, &user, &100);
+
+    // The sweep runs before the balance check, even without a prior read
+    set_time(&env, VALIDITY);
+    client.redeem_reward(&program_id, &user, &2);
+}
+
+#[test]
+fn test_expiring_points_query() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let rewards = create_basic_rewards(&env);
+
+    let client = create_client(&env, &contract_address);
+    client.create_loyalty_program(&admin, &program_id, &1, &rewards);
+
+    // Without a policy nothing ever expires
+    client.award_points(&program_id, &admin, &user, &100);
+    let expiring = client.get_expiring_points(&program_id, &user, &VALIDITY);
+    assert_eq!(expiring, 0);
+
+    client.set_expiry_policy(&admin, &program_id, &VALIDITY);
+
+    // Awards later the same day join the day's lot
+    set_time(&env, DAY / 2);
+    client.award_points(&program_id, &admin, &user, &20);
+    set_time(&env, 20 * DAY);
+    client.award_points(&program_id, &admin, &user, &40);
+
+    set_time(&env, 25 * DAY);
+    let within_week = client.get_expiring_points(&program_id, &user, &(7 * DAY));
+    assert_eq!(within_week, 120);
+    let within_month = client.get_expiring_points(&program_id, &user, &VALIDITY);
+    assert_eq!(within_month, 160);
+
+    set_time(&env, VALIDITY);
+    let balance = client.get_points_balance(&program_id, &user);
+    assert_eq!(balance, 40);
+    let within_week = client.get_expiring_points(&program_id, &user, &(7 * DAY));
+    assert_eq!(within_week, 0);
+}
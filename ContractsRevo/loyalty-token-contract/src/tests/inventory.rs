@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::{PointsEventKind, RedemptionOption, RedemptionStatus};
+use soroban_sdk::{BytesN, String};
+
+#[test]
+fn test_add_and_restock_options() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_rewards(&env));
+    client.add_redemption_option(
+        &admin,
+        &program_id,
+        &RedemptionOption {
+            id: 3,
+            name: String::from_str(&env, "Seed Pack"),
+            points_required: 50,
+            available_quantity: 0,
+        },
+    );
+    assert_reward_not_exists(&client.list_available_rewards(&program_id), 3);
+
+    client.restock_option(&admin, &program_id, &3, &4);
+    client.restock_option(&admin, &program_id, &1, &2);
+    let rewards = client.list_available_rewards(&program_id);
+    assert_reward_quantity(&rewards, 3, 4);
+    assert_reward_quantity(&rewards, 1, 3);
+
+    client.award_points(&program_id, &admin, &user, &50);
+    client.redeem_reward(&program_id, &user, &3);
+    assert_reward_quantity(&client.list_available_rewards(&program_id), 3, 3);
+
+    let result = client.try_add_redemption_option(
+        &admin,
+        &program_id,
+        &RedemptionOption {
+            id: 1,
+            name: String::from_str(&env, "Duplicate"),
+            points_required: 1,
+            available_quantity: 1,
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Redemption option is inactive")]
+fn test_deactivated_option_rejected() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &admin, &user, &500);
+
+    client.deactivate_option(&admin, &program_id, &2);
+    assert_reward_not_exists(&client.list_available_rewards(&program_id), 2);
+
+    client.redeem_reward(&program_id, &user, &2);
+}
+
+#[test]
+fn test_cancel_redemption_restores_points_and_stock() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_rewards(&env));
+    client.award_points(&program_id, &admin, &user, &200);
+
+    let redemption_id = client.redeem_reward(&program_id, &user, &1);
+    assert_eq!(client.get_points_balance(&program_id, &user), 0);
+    assert_reward_not_exists(&client.list_available_rewards(&program_id), 1);
+
+    let redemption = client.get_redemption(&redemption_id);
+    assert_eq!(redemption.status, RedemptionStatus::Pending);
+    assert_eq!(redemption.points, 200);
+
+    client.cancel_redemption(&admin, &redemption_id);
+
+    assert_eq!(client.get_points_balance(&program_id, &user), 200);
+    assert_reward_quantity(&client.list_available_rewards(&program_id), 1, 1);
+    assert_eq!(
+        client.get_redemption(&redemption_id).status,
+        RedemptionStatus::Cancelled
+    );
+
+    let history = client.get_points_history(&program_id, &user, &0, &1);
+    assert_eq!(history.get(0).unwrap().kind, PointsEventKind::Refund);
+
+    // A cancelled redemption cannot be fulfilled afterwards
+    let proof = BytesN::from_array(&env, &[9; 32]);
+    assert!(client
+        .try_fulfill_redemption(&admin, &redemption_id, &proof)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Redemption is not pending")]
+fn test_double_fulfillment_blocked() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &admin, &user, &100);
+
+    let redemption_id = client.redeem_reward(&program_id, &user, &2);
+    let proof = BytesN::from_array(&env, &[9; 32]);
+    client.fulfill_redemption(&admin, &redemption_id, &proof);
+
+    let redemption = client.get_redemption(&redemption_id);
+    assert_eq!(redemption.status, RedemptionStatus::Fulfilled);
+    assert_eq!(redemption.proof_hash, Some(proof.clone()));
+
+    client.fulfill_redemption(&admin, &redemption_id, &proof);
+}
+
+#[test]
+fn test_list_redemptions_by_status() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let other_user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &admin, &user, &300);
+    client.award_points(&program_id, &admin, &other_user, &300);
+
+    let fulfilled = client.redeem_reward(&program_id, &user, &2);
+    let cancelled = client.redeem_reward(&program_id, &user, &3);
+    let pending = client.redeem_reward(&program_id, &user, &3);
+    client.redeem_reward(&program_id, &other_user, &2);
+
+    client.fulfill_redemption(&admin, &fulfilled, &BytesN::from_array(&env, &[1; 32]));
+    client.cancel_redemption(&admin, &cancelled);
+
+    let all = client.list_redemptions(&program_id, &user, &None);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().id, fulfilled);
+
+    let open = client.list_redemptions(&program_id, &user, &Some(RedemptionStatus::Pending));
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().id, pending);
+
+    let done = client.list_redemptions(&program_id, &user, &Some(RedemptionStatus::Fulfilled));
+    assert_eq!(done.len(), 1);
+    assert_eq!(done.get(0).unwrap().option_id, 2);
+}
+
+#[test]
+#[should_panic(expected = "Not the program owner")]
+fn test_only_owner_fulfills() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &admin, &user, &100);
+    let redemption_id = client.redeem_reward(&program_id, &user, &2);
+
+    client.fulfill_redemption(&user, &redemption_id, &BytesN::from_array(&env, &[9; 32]));
+}
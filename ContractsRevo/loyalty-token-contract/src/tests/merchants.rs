@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::{LoyaltyContractClient, PointsEventKind};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, BytesN, Env};
+
+// Stands in for a marketplace contract that awards points as itself at settlement
+#[contract]
+pub struct MockMerchant;
+
+#[contractimpl]
+impl MockMerchant {
+    pub fn settle(
+        env: Env,
+        loyalty: Address,
+        program_id: BytesN<32>,
+        buyer: Address,
+        amount: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64 {
+        LoyaltyContractClient::new(&env, &loyalty).award_for_purchase(
+            &env.current_contract_address(),
+            &program_id,
+            &buyer,
+            &amount,
+            &purchase_ref,
+        )
+    }
+}
+
+fn purchase_ref(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+#[test]
+fn test_award_for_purchase_is_idempotent() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let buyer = create_user(&env);
+    let merchant = env.register(MockMerchant, ());
+    let merchant_client = MockMerchantClient::new(&env, &merchant);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.register_merchant_contract(&admin, &program_id, &merchant, &3);
+
+    let awarded = merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &50,
+        &purchase_ref(&env, 1),
+    );
+    assert_eq!(awarded, 150);
+    assert!(client.is_purchase_awarded(&program_id, &merchant, &purchase_ref(&env, 1)));
+
+    // Replaying the same purchase awards nothing
+    let replayed = merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &50,
+        &purchase_ref(&env, 1),
+    );
+    assert_eq!(replayed, 0);
+    assert_eq!(client.get_points_balance(&program_id, &buyer), 150);
+
+    let history = client.get_points_history(&program_id, &buyer, &0, &10);
+    assert_eq!(history.len(), 1);
+    let event = history.get(0).unwrap();
+    assert_eq!(event.kind, PointsEventKind::Earn);
+    assert_eq!(event.actor, merchant);
+}
+
+#[test]
+#[should_panic(expected = "Merchant not registered")]
+fn test_unregistered_merchant_rejected() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let buyer = create_user(&env);
+    let merchant = env.register(MockMerchant, ());
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+
+    MockMerchantClient::new(&env, &merchant).settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &50,
+        &purchase_ref(&env, 1),
+    );
+}
+
+#[test]
+fn test_program_owner_is_not_a_merchant() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let buyer = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+
+    // Even the program owner cannot claim purchases without registering a merchant
+    assert!(client
+        .try_award_for_purchase(&admin, &program_id, &buyer, &50, &purchase_ref(&env, 1))
+        .is_err());
+    assert_eq!(client.get_points_balance(&program_id, &buyer), 0);
+}
+
+#[test]
+fn test_rate_update_affects_only_future_purchases() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let buyer = create_user(&env);
+    let merchant = env.register(MockMerchant, ());
+    let merchant_client = MockMerchantClient::new(&env, &merchant);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.register_merchant_contract(&admin, &program_id, &merchant, &2);
+    merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &100,
+        &purchase_ref(&env, 1),
+    );
+    assert_eq!(client.get_points_balance(&program_id, &buyer), 200);
+
+    client.register_merchant_contract(&admin, &program_id, &merchant, &5);
+    assert_eq!(
+        client
+            .get_merchant_registration(&program_id, &merchant)
+            .unwrap()
+            .points_per_unit_spent,
+        5
+    );
+
+    // The earlier purchase is not re-rated, even if replayed
+    merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &100,
+        &purchase_ref(&env, 1),
+    );
+    assert_eq!(client.get_points_balance(&program_id, &buyer), 200);
+
+    merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &100,
+        &purchase_ref(&env, 2),
+    );
+    assert_eq!(client.get_points_balance(&program_id, &buyer), 700);
+}
+
+#[test]
+fn test_purchase_points_use_tier_multiplier() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let buyer = create_user(&env);
+    let merchant = env.register(MockMerchant, ());
+    let merchant_client = MockMerchantClient::new(&env, &merchant);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.set_tiers(
+        &admin,
+        &program_id,
+        &vec![&env, (100, symbol_short!("Silver"))],
+    );
+    client.set_tier_multiplier(&admin, &program_id, &symbol_short!("Silver"), &15_000);
+    client.register_merchant_contract(&admin, &program_id, &merchant, &1);
+
+    merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &100,
+        &purchase_ref(&env, 1),
+    );
+    let awarded = merchant_client.settle(
+        &contract_address,
+        &program_id,
+        &buyer,
+        &100,
+        &purchase_ref(&env, 2),
+    );
+    assert_eq!(awarded, 150);
+}
+
+#[test]
+#[should_panic(expected = "Not the program owner")]
+fn test_only_owner_registers_merchants() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let stranger = create_user(&env);
+    let merchant = env.register(MockMerchant, ());
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.register_merchant_contract(&stranger, &program_id, &merchant, &1);
+}
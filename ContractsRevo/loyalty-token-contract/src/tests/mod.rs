@@ -1,4 +1,11 @@
+pub mod awarders;
 pub mod earn;
+pub mod expiry;
+pub mod inventory;
+pub mod merchants;
 pub mod program;
 pub mod redeem;
+pub mod stats;
+pub mod tiers;
+pub mod transfer;
 pub mod utils;
@@ -1,17 +1,18 @@
 #![cfg(test)]
 
 use super::utils::*;
-use crate::LoyaltyContract;
 use soroban_sdk::{String, Vec};
 
 #[test]
 fn test_create_basic_loyalty_program() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         1,
         rewards.clone(),
@@ -26,6 +27,7 @@ fn test_create_basic_loyalty_program() {
 #[test]
 fn test_create_program_with_different_point_rates() {
     let (env, contract_address, _default_program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
     let test_rates = [1, 5, 10, 100, 1000];
@@ -35,6 +37,7 @@ fn test_create_program_with_different_point_rates() {
         setup_loyalty_program(
             &env,
             &contract_address,
+            &admin,
             program_id.clone(),
             rate,
             rewards.clone(),
@@ -48,11 +51,13 @@ fn test_create_program_with_different_point_rates() {
 #[test]
 fn test_create_program_with_no_rewards() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let empty_rewards = Vec::new(&env);
 
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         1,
         empty_rewards,
@@ -65,11 +70,13 @@ fn test_create_program_with_no_rewards() {
 #[test]
 fn test_create_program_with_single_reward() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let single_reward = create_single_reward(&env, 1, "Exclusive Gift", 500, 1);
 
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         2,
         single_reward,
@@ -89,24 +96,27 @@ fn test_create_program_with_single_reward() {
 #[should_panic(expected = "Program already exists")]
 fn test_create_duplicate_program() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
     // Create program first time
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         1,
         rewards.clone(),
     );
 
     // Attempt to create same program again
-    setup_loyalty_program(&env, &contract_address, program_id, 2, rewards);
+    setup_loyalty_program(&env, &contract_address, &admin, program_id, 2, rewards);
 }
 
 #[test]
 fn test_create_multiple_different_programs() {
     let (env, contract_address, _default_program_id) = setup_test();
+    let admin = create_user(&env);
     let basic_rewards = create_basic_rewards(&env);
     let premium_rewards = create_premium_rewards(&env);
 
@@ -114,10 +124,18 @@ fn test_create_multiple_different_programs() {
     let program2 = create_program_with_id(&env, 11);
     let program3 = create_program_with_id(&env, 12);
 
-    setup_loyalty_program(&env, &contract_address, program1.clone(), 1, basic_rewards);
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
+        program1.clone(),
+        1,
+        basic_rewards,
+    );
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
         program2.clone(),
         5,
         premium_rewards,
@@ -125,6 +143,7 @@ fn test_create_multiple_different_programs() {
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program3.clone(),
         10,
         Vec::new(&env),
@@ -157,13 +176,21 @@ fn test_get_nonexistent_program() {
 #[test]
 fn test_program_with_varying_reward_quantities() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
 
     let mut rewards = Vec::new(&env);
     rewards.push_back(create_test_reward(&env, 1, "Common", 50, 100));
     rewards.push_back(create_test_reward(&env, 2, "Rare", 200, 10));
     rewards.push_back(create_test_reward(&env, 3, "Ultra Rare", 1000, 1));
 
-    setup_loyalty_program(&env, &contract_address, program_id.clone(), 1, rewards);
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
+        program_id.clone(),
+        1,
+        rewards,
+    );
 
     let program = get_program_details(&env, &contract_address, program_id);
     assert_eq!(program.redemption_options.len(), 3);
@@ -192,13 +219,21 @@ fn test_program_with_varying_reward_quantities() {
 #[test]
 fn test_program_with_varying_point_requirements() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
 
     let mut rewards = Vec::new(&env);
     rewards.push_back(create_test_reward(&env, 1, "Low Cost", 10, 50));
     rewards.push_back(create_test_reward(&env, 2, "Medium Cost", 250, 20));
     rewards.push_back(create_test_reward(&env, 3, "High Cost", 5000, 5));
 
-    setup_loyalty_program(&env, &contract_address, program_id.clone(), 1, rewards);
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
+        program_id.clone(),
+        1,
+        rewards,
+    );
 
     let program = get_program_details(&env, &contract_address, program_id);
 
@@ -226,9 +261,17 @@ fn test_program_with_varying_point_requirements() {
 #[test]
 fn test_program_persistence() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
-    setup_loyalty_program(&env, &contract_address, program_id.clone(), 3, rewards);
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
+        program_id.clone(),
+        3,
+        rewards,
+    );
 
     // Retrieve program multiple times to test persistence
     for _ in 0..5 {
@@ -242,9 +285,17 @@ fn test_program_persistence() {
 #[test]
 fn test_program_with_zero_point_rate() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
-    setup_loyalty_program(&env, &contract_address, program_id.clone(), 0, rewards);
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
+        program_id.clone(),
+        0,
+        rewards,
+    );
 
     let program = get_program_details(&env, &contract_address, program_id);
     assert_eq!(program.points_per_transaction, 0);
@@ -253,11 +304,13 @@ fn test_program_with_zero_point_rate() {
 #[test]
 fn test_program_with_max_point_rate() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let rewards = create_basic_rewards(&env);
 
     setup_loyalty_program(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         u32::MAX,
         rewards,
@@ -270,14 +323,16 @@ fn test_program_with_max_point_rate() {
 #[test]
 fn test_program_reward_modifications_after_creation() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let user = create_user(&env);
 
-    setup_basic_program(&env, &contract_address, program_id.clone());
+    setup_basic_program(&env, &contract_address, &admin, program_id.clone());
 
     // Award points and redeem to modify inventory
     award_points_to_user(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         user.clone(),
         200,
@@ -297,9 +352,10 @@ fn test_program_reward_modifications_after_creation() {
 #[test]
 fn test_list_available_rewards() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
     let user = create_user(&env);
 
-    setup_basic_program(&env, &contract_address, program_id.clone());
+    setup_basic_program(&env, &contract_address, &admin, program_id.clone());
 
     // Initially all rewards should be available
     let available_rewards = get_available_rewards(&env, &contract_address, program_id.clone());
@@ -309,6 +365,7 @@ fn test_list_available_rewards() {
     award_points_to_user(
         &env,
         &contract_address,
+        &admin,
         program_id.clone(),
         user.clone(),
         500,
@@ -332,6 +389,7 @@ fn test_list_available_rewards() {
 #[test]
 fn test_program_with_large_number_of_rewards() {
     let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
 
     let mut rewards = Vec::new(&env);
     for i in 1..=50 {
@@ -345,7 +403,14 @@ fn test_program_with_large_number_of_rewards() {
         rewards.push_back(create_test_reward(&env, i, reward_name, i * 10, i));
     }
 
-    setup_loyalty_program(&env, &contract_address, program_id.clone(), 1, rewards);
+    setup_loyalty_program(
+        &env,
+        &contract_address,
+        &admin,
+        program_id.clone(),
+        1,
+        rewards,
+    );
 
     let program = get_program_details(&env, &contract_address, program_id);
     assert_eq!(program.redemption_options.len(), 50);
@@ -374,30 +439,20 @@ fn test_program_with_large_number_of_rewards() {
 #[should_panic(expected = "Insufficient points")]
 fn test_cross_program_insufficient_points() {
     let (env, contract_address, _default_program_id) = setup_test();
+    let admin = create_user(&env);
     let user = create_user(&env);
 
     let program1 = create_program_with_id(&env, 10);
     let program2 = create_program_with_id(&env, 11);
 
-    env.as_contract(&contract_address, || {
-        LoyaltyContract::create_loyalty_program(
-            env.clone(),
-            program1,
-            1,
-            create_basic_rewards(&env),
-        );
-        LoyaltyContract::create_loyalty_program(
-            env.clone(),
-            program2.clone(),
-            3,
-            create_premium_rewards(&env),
-        );
+    let client = create_client(&env, &contract_address);
+    client.create_loyalty_program(&admin, &program1, &1, &create_basic_rewards(&env));
+    client.create_loyalty_program(&admin, &program2, &3, &create_premium_rewards(&env));
 
-        LoyaltyContract::award_points(env.clone(), program2.clone(), user.clone(), 200);
+    client.award_points(&program2, &admin, &user, &200);
 
-        // Should fail - user has 600 points in program2 but premium reward needs 1000
-        LoyaltyContract::redeem_reward(env.clone(), program2, user, 1);
-    });
+    // Should fail - user has 600 points in program2 but premium reward needs 1000
+    client.redeem_reward(&program2, &user, &1);
 }
 
 // Helper function for creating test rewards
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::stats::{LEADERBOARD_SIZE, PERIOD_SECS};
+use soroban_sdk::{testutils::Ledger, BytesN, Env};
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_stats_after_mixed_operations() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let alice = create_user(&env);
+    let bob = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.set_expiry_policy(&admin, &program_id, &(10 * PERIOD_SECS));
+
+    client.award_points(&program_id, &admin, &alice, &300);
+    client.award_points(&program_id, &admin, &bob, &80);
+    client.redeem_reward(&program_id, &alice, &1);
+    let cancelled = client.redeem_reward(&program_id, &alice, &3);
+    client.cancel_redemption(&admin, &cancelled);
+    client.transfer_points(&program_id, &alice, &bob, &100);
+
+    let stats = client.get_program_stats(&program_id);
+    assert_eq!(stats.total_earned, 380);
+    assert_eq!(stats.total_redeemed, 250);
+    assert_eq!(stats.total_refunded, 50);
+    assert_eq!(stats.total_expired, 0);
+    assert_eq!(stats.outstanding, 180);
+    assert_eq!(stats.active_users, 1);
+
+    // Bob's points lapse once they are read after the validity period
+    set_time(&env, 10 * PERIOD_SECS);
+    assert_eq!(client.get_points_balance(&program_id, &bob), 0);
+
+    let stats = client.get_program_stats(&program_id);
+    assert_eq!(stats.total_expired, 180);
+    assert_eq!(stats.outstanding, 0);
+    assert_eq!(stats.active_users, 0);
+}
+
+#[test]
+fn test_period_buckets_across_two_days() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+
+    set_time(&env, 3 * PERIOD_SECS + 100);
+    client.award_points(&program_id, &admin, &user, &120);
+    set_time(&env, 4 * PERIOD_SECS - 1);
+    client.redeem_reward(&program_id, &user, &2);
+
+    set_time(&env, 4 * PERIOD_SECS);
+    client.award_points(&program_id, &admin, &user, &30);
+    client.redeem_reward(&program_id, &user, &3);
+
+    let first_day = client.get_period_stats(&program_id, &3);
+    assert_eq!(first_day.earned, 120);
+    assert_eq!(first_day.redeemed, 100);
+
+    let second_day = client.get_period_stats(&program_id, &4);
+    assert_eq!(second_day.earned, 30);
+    assert_eq!(second_day.redeemed, 50);
+
+    let quiet_day = client.get_period_stats(&program_id, &5);
+    assert_eq!(quiet_day.earned, 0);
+    assert_eq!(quiet_day.period_bucket, 5);
+
+    assert_eq!(client.get_program_stats(&program_id).total_earned, 150);
+}
+
+#[test]
+fn test_leaderboard_eviction() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+
+    let users = create_multiple_users(&env, LEADERBOARD_SIZE);
+    for (index, user) in users.iter().enumerate() {
+        client.award_points(&program_id, &admin, &user, &(100 + index as u32));
+    }
+
+    let board = client.list_top_earners(&program_id, &LEADERBOARD_SIZE);
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert_eq!(board.get(0).unwrap().lifetime_points, 109);
+    let lowest = users.get(0).unwrap();
+    assert_eq!(board.get(LEADERBOARD_SIZE - 1).unwrap().user, lowest);
+
+    // A tie with the lowest entry does not get in, a higher score evicts it
+    let tied = create_user(&env);
+    client.award_points(&program_id, &admin, &tied, &100);
+    let board = client.list_top_earners(&program_id, &LEADERBOARD_SIZE);
+    assert!(board.iter().all(|entry| entry.user != tied));
+
+    let newcomer = create_user(&env);
+    client.award_points(&program_id, &admin, &newcomer, &500);
+    let board = client.list_top_earners(&program_id, &3);
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap().user, newcomer);
+
+    let board = client.list_top_earners(&program_id, &LEADERBOARD_SIZE);
+    assert!(board.iter().all(|entry| entry.user != lowest));
+
+    // Existing entries move up rather than being duplicated
+    client.award_points(&program_id, &admin, &lowest, &1_000);
+    let board = client.list_top_earners(&program_id, &LEADERBOARD_SIZE);
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert_eq!(board.get(0).unwrap().user, lowest);
+    assert_eq!(board.get(0).unwrap().lifetime_points, 1_100);
+
+    let empty = client.list_top_earners(&BytesN::from_array(&env, &[9; 32]), &5);
+    assert!(empty.is_empty());
+}
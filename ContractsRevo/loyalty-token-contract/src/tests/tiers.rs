@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::LoyaltyContractClient;
+use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, Symbol, Vec};
+
+fn standard_tiers(env: &Env) -> Vec<(u32, Symbol)> {
+    vec![
+        env,
+        (100, symbol_short!("Bronze")),
+        (500, symbol_short!("Silver")),
+        (1000, symbol_short!("Gold")),
+    ]
+}
+
+fn setup_tiered_program(
+    env: &Env,
+    client: &LoyaltyContractClient,
+    admin: &Address,
+    program_id: &BytesN<32>,
+) {
+    client.create_loyalty_program(admin, program_id, &1, &create_basic_rewards(env));
+    client.set_tiers(admin, program_id, &standard_tiers(env));
+}
+
+#[test]
+fn test_tier_boundaries() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    let client = create_client(&env, &contract_address);
+    setup_tiered_program(&env, &client, &admin, &program_id);
+
+    client.award_points(&program_id, &admin, &user, &99);
+    let tier = client.get_user_tier(&program_id, &user);
+    assert_eq!(tier, None);
+
+    client.award_points(&program_id, &admin, &user, &1);
+    let tier = client.get_user_tier(&program_id, &user);
+    assert_eq!(tier, Some(symbol_short!("Bronze")));
+
+    client.award_points(&program_id, &admin, &user, &399);
+    let tier = client.get_user_tier(&program_id, &user);
+    assert_eq!(tier, Some(symbol_short!("Bronze")));
+
+    client.award_points(&program_id, &admin, &user, &1);
+    let tier = client.get_user_tier(&program_id, &user);
+    assert_eq!(tier, Some(symbol_short!("Silver")));
+
+    // Tiers follow lifetime earnings, so redeeming does not demote
+    client.redeem_reward(&program_id, &user, &1);
+    let tier = client.get_user_tier(&program_id, &user);
+    assert_eq!(tier, Some(symbol_short!("Silver")));
+}
+
+#[test]
+fn test_tier_multiplier_applied_on_award() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+    let user = create_user(&env);
+
+    let client = create_client(&env, &contract_address);
+    setup_tiered_program(&env, &client, &admin, &program_id);
+    client.set_tier_multiplier(&admin, &program_id, &symbol_short!("Silver"), &15_000);
+
+    // The award that reaches Silver is still earned at the base rate
+    client.award_points(&program_id, &admin, &user, &500);
+    assert_eq!(client.get_points_balance(&program_id, &user), 500);
+
+    client.award_points(&program_id, &admin, &user, &101);
+    assert_eq!(client.get_points_balance(&program_id, &user), 651);
+
+    let lifetime = client.get_lifetime_points(&program_id, &user);
+    assert_eq!(lifetime, 651);
+}
+
+#[test]
+#[should_panic(expected = "Tier thresholds must be ascending")]
+fn test_unordered_tiers_rejected() {
+    let (env, contract_address, program_id) = setup_test();
+    let admin = create_user(&env);
+
+    let client = create_client(&env, &contract_address);
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    let thresholds = vec![
+        &env,
+        (500, symbol_short!("Silver")),
+        (100, symbol_short!("Bronze")),
+    ];
+    client.set_tiers(&admin, &program_id, &thresholds);
+}
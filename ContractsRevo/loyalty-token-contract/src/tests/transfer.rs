@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use super::utils::*;
+use crate::PointsEventKind;
+use soroban_sdk::{testutils::Ledger, Env};
+
+const DAY: u64 = 24 * 60 * 60;
+const VALIDITY: u64 = 30 * DAY;
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_transfer_toggle() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let parent = create_user(&env);
+    let child = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.award_points(&program_id, &admin, &child, &80);
+
+    client.transfer_points(&program_id, &child, &parent, &30);
+    assert_eq!(client.get_points_balance(&program_id, &child), 50);
+    assert_eq!(client.get_points_balance(&program_id, &parent), 30);
+
+    let received = client.get_points_history(&program_id, &parent, &0, &1);
+    assert_eq!(received.get(0).unwrap().kind, PointsEventKind::TransferIn);
+    assert_eq!(received.get(0).unwrap().actor, child);
+
+    client.set_transfers_enabled(&admin, &program_id, &false);
+    assert!(client
+        .try_transfer_points(&program_id, &child, &parent, &10)
+        .is_err());
+
+    client.set_transfers_enabled(&admin, &program_id, &true);
+    client.transfer_points(&program_id, &child, &parent, &50);
+    assert_eq!(client.get_points_balance(&program_id, &child), 0);
+    assert_eq!(client.get_points_balance(&program_id, &parent), 80);
+
+    // Transfers move points, they do not count as earned
+    assert_eq!(client.get_lifetime_points(&program_id, &parent), 0);
+
+    assert!(client
+        .try_transfer_points(&program_id, &child, &parent, &1)
+        .is_err());
+}
+
+#[test]
+fn test_transferred_points_keep_expiry() {
+    let (env, contract_address, program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let sender = create_user(&env);
+    let receiver = create_user(&env);
+
+    client.create_loyalty_program(&admin, &program_id, &1, &create_basic_rewards(&env));
+    client.set_expiry_policy(&admin, &program_id, &VALIDITY);
+
+    client.award_points(&program_id, &admin, &sender, &100);
+    set_time(&env, 5 * DAY);
+    client.award_points(&program_id, &admin, &receiver, &50);
+
+    set_time(&env, 10 * DAY);
+    client.transfer_points(&program_id, &sender, &receiver, &60);
+    let expiring = client.get_expiring_points(&program_id, &receiver, &(20 * DAY));
+    assert_eq!(expiring, 60);
+
+    // The transferred points expire with the sender's lot, before the receiver's own points
+    set_time(&env, VALIDITY);
+    assert_eq!(client.get_points_balance(&program_id, &sender), 0);
+    assert_eq!(client.get_points_balance(&program_id, &receiver), 50);
+
+    set_time(&env, 5 * DAY + VALIDITY);
+    assert_eq!(client.get_points_balance(&program_id, &receiver), 0);
+}
+
+#[test]
+fn test_conversion_math_and_rounding() {
+    let (env, contract_address, _program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let north = create_program_with_id(&env, 1);
+    let south = create_program_with_id(&env, 2);
+
+    client.create_loyalty_program(&admin, &north, &1, &create_basic_rewards(&env));
+    client.create_loyalty_program(&admin, &south, &1, &create_basic_rewards(&env));
+    client.award_points(&north, &admin, &user, &100);
+
+    // Four north points for one south point
+    client.set_conversion_rate(&admin, &north, &south, &2_500);
+    let rate = client.get_conversion_rate(&north, &south).unwrap();
+    assert_eq!(rate.min_amount, 4);
+    assert!(client.get_conversion_rate(&south, &north).is_none());
+
+    let received = client.convert_points(&user, &north, &south, &10);
+    assert_eq!(received, 2);
+    assert_eq!(client.get_points_balance(&north, &user), 90);
+    assert_eq!(client.get_points_balance(&south, &user), 2);
+
+    assert!(client
+        .try_convert_points(&user, &north, &south, &3)
+        .is_err());
+    assert!(client
+        .try_convert_points(&user, &south, &north, &2)
+        .is_err());
+
+    // Rates above parity also round down
+    client.set_conversion_rate(&admin, &north, &south, &15_000);
+    let received = client.convert_points(&user, &north, &south, &3);
+    assert_eq!(received, 4);
+    assert_eq!(client.get_points_balance(&north, &user), 87);
+    assert_eq!(client.get_points_balance(&south, &user), 6);
+}
+
+#[test]
+#[should_panic(expected = "Not the program owner")]
+fn test_conversion_rate_requires_owner_of_both() {
+    let (env, contract_address, _program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let other_admin = create_user(&env);
+    let north = create_program_with_id(&env, 1);
+    let south = create_program_with_id(&env, 2);
+
+    client.create_loyalty_program(&admin, &north, &1, &create_basic_rewards(&env));
+    client.create_loyalty_program(&other_admin, &south, &1, &create_basic_rewards(&env));
+
+    client.set_conversion_rate(&admin, &north, &south, &10_000);
+}
+
+#[test]
+fn test_converted_points_start_new_validity() {
+    let (env, contract_address, _program_id) = setup_test();
+    let client = create_client(&env, &contract_address);
+    let admin = create_user(&env);
+    let user = create_user(&env);
+    let north = create_program_with_id(&env, 1);
+    let south = create_program_with_id(&env, 2);
+
+    client.create_loyalty_program(&admin, &north, &1, &create_basic_rewards(&env));
+    client.create_loyalty_program(&admin, &south, &1, &create_basic_rewards(&env));
+    client.set_expiry_policy(&admin, &north, &VALIDITY);
+    client.set_expiry_policy(&admin, &south, &VALIDITY);
+    client.set_conversion_rate(&admin, &north, &south, &10_000);
+
+    client.award_points(&north, &admin, &user, &100);
+    set_time(&env, 20 * DAY);
+    client.convert_points(&user, &north, &south, &60);
+
+    set_time(&env, VALIDITY);
+    assert_eq!(client.get_points_balance(&north, &user), 0);
+    assert_eq!(client.get_points_balance(&south, &user), 60);
+
+    let history = client.get_points_history(&north, &user, &0, &10);
+    assert_eq!(history.get(0).unwrap().kind, PointsEventKind::Expire);
+    assert_eq!(history.get(0).unwrap().amount, 40);
+    assert_eq!(history.get(1).unwrap().kind, PointsEventKind::ConvertOut);
+
+    set_time(&env, 20 * DAY + VALIDITY);
+    assert_eq!(client.get_points_balance(&south, &user), 0);
+}
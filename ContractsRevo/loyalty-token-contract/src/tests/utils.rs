@@ -2,17 +2,22 @@
 
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
 
-use crate::{LoyaltyContract, LoyaltyProgram, RedemptionOption};
+use crate::{LoyaltyContract, LoyaltyContractClient, LoyaltyProgram, RedemptionOption};
 
 // ============ CORE SETUP FUNCTIONS ============
 
 pub fn setup_test() -> (Env, Address, BytesN<32>) {
     let env = Env::default();
+    env.mock_all_auths();
     let contract_address = env.register(LoyaltyContract, ());
     let program_id = BytesN::from_array(&env, &[1u8; 32]);
     (env, contract_address, program_id)
 }
 
+pub fn create_client<'a>(env: &Env, contract_address: &Address) -> LoyaltyContractClient<'a> {
+    LoyaltyContractClient::new(env, contract_address)
+}
+
 pub fn create_user(env: &Env) -> Address {
     Address::generate(env)
 }
@@ -154,6 +159,7 @@ pub fn assert_reward_quantity(
 pub fn setup_loyalty_program(
     env: &Env,
     contract_address: &Address,
+    admin: &Address,
     program_id: BytesN<32>,
     points_per_transaction: u32,
     rewards: Vec<RedemptionOption>,
@@ -161,6 +167,7 @@ pub fn setup_loyalty_program(
     env.as_contract(contract_address, || {
         LoyaltyContract::create_loyalty_program(
             env.clone(),
+            admin.clone(),
             program_id,
             points_per_transaction,
             rewards,
@@ -168,20 +175,32 @@ pub fn setup_loyalty_program(
     });
 }
 
-pub fn setup_basic_program(env: &Env, contract_address: &Address, program_id: BytesN<32>) {
+pub fn setup_basic_program(
+    env: &Env,
+    contract_address: &Address,
+    admin: &Address,
+    program_id: BytesN<32>,
+) {
     let rewards = create_basic_rewards(env);
-    setup_loyalty_program(env, contract_address, program_id, 1, rewards);
+    setup_loyalty_program(env, contract_address, admin, program_id, 1, rewards);
 }
 
 pub fn award_points_to_user(
     env: &Env,
     contract_address: &Address,
+    admin: &Address,
     program_id: BytesN<32>,
     user: Address,
     transaction_amount: u32,
 ) {
     env.as_contract(contract_address, || {
-        LoyaltyContract::award_points(env.clone(), program_id, user, transaction_amount);
+        LoyaltyContract::award_points(
+            env.clone(),
+            program_id,
+            admin.clone(),
+            user,
+            transaction_amount,
+        );
     });
 }
 
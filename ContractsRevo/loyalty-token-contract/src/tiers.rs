@@ -0,0 +1,123 @@
+use crate::{program::require_program_admin, stats};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Earn multiplier for users without a tier, or tiers without a configured multiplier
+pub const BASE_MULTIPLIER_BPS: u32 = 10_000;
+
+/// Sets the program's tiers as (minimum lifetime points, tier name), lowest first.
+pub fn set_tiers(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    thresholds: Vec<(u32, Symbol)>,
+) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    let mut previous: Option<u32> = None;
+    for (threshold, _) in thresholds.iter() {
+        if previous.is_some_and(|previous| threshold <= previous) {
+            panic!("Tier thresholds must be ascending");
+        }
+        previous = Some(threshold);
+    }
+
+    env.storage().persistent().set(
+        &(Symbol::new(env, "tiers"), program_id.clone()),
+        &thresholds,
+    );
+
+    env.events()
+        .publish((Symbol::new(env, "tiers_set"), program_id), thresholds);
+}
+
+pub fn set_tier_multiplier(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    tier: Symbol,
+    multiplier_bps: u32,
+) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    if !get_tiers(env, &program_id)
+        .iter()
+        .any(|(_, name)| name == tier)
+    {
+        panic!("Tier not found");
+    }
+    if multiplier_bps == 0 {
+        panic!("Multiplier must be positive");
+    }
+
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "tier_mult"),
+            program_id.clone(),
+            tier.clone(),
+        ),
+        &multiplier_bps,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "tier_multiplier_set"), program_id, tier),
+        multiplier_bps,
+    );
+}
+
+/// The highest tier whose threshold the user's lifetime earned points have reached.
+pub fn get_user_tier(env: &Env, program_id: BytesN<32>, user_address: Address) -> Option<Symbol> {
+    let lifetime = get_lifetime_points(env, program_id.clone(), user_address);
+
+    get_tiers(env, &program_id)
+        .iter()
+        .filter(|(threshold, _)| lifetime >= *threshold as u64)
+        .last()
+        .map(|(_, name)| name)
+}
+
+pub fn get_lifetime_points(env: &Env, program_id: BytesN<32>, user_address: Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "lifetime"), program_id, user_address))
+        .unwrap_or(0)
+}
+
+pub(crate) fn earn_multiplier_bps(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+) -> u32 {
+    match get_user_tier(env, program_id.clone(), user_address.clone()) {
+        Some(tier) => env
+            .storage()
+            .persistent()
+            .get(&(Symbol::new(env, "tier_mult"), program_id.clone(), tier))
+            .unwrap_or(BASE_MULTIPLIER_BPS),
+        None => BASE_MULTIPLIER_BPS,
+    }
+}
+
+pub(crate) fn record_earned(
+    env: &Env,
+    program_id: &BytesN<32>,
+    user_address: &Address,
+    amount: u64,
+) {
+    let lifetime = get_lifetime_points(env, program_id.clone(), user_address.clone());
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "lifetime"),
+            program_id.clone(),
+            user_address.clone(),
+        ),
+        &(lifetime + amount),
+    );
+    stats::update_leaderboard(env, program_id, user_address, lifetime + amount);
+}
+
+fn get_tiers(env: &Env, program_id: &BytesN<32>) -> Vec<(u32, Symbol)> {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "tiers"), program_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
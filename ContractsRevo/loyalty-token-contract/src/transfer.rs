@@ -0,0 +1,175 @@
+use crate::{
+    points,
+    program::{get_program_info, require_program_admin},
+    ConversionRate, PointsEventKind,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+const BASIS_POINTS: u64 = 10_000;
+
+/// Points are transferable unless the program owner turns transfers off.
+pub fn set_transfers_enabled(
+    env: &Env,
+    program_admin: Address,
+    program_id: BytesN<32>,
+    enabled: bool,
+) {
+    require_program_admin(env, &program_id, &program_admin);
+
+    let key = (Symbol::new(env, "transfers_off"), program_id.clone());
+    if enabled {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &true);
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "transfers_enabled_set"), program_id),
+        enabled,
+    );
+}
+
+/// Moves points between users of a program. Transferred points keep their original earn
+/// dates, so they expire for the recipient when they would have for the sender.
+pub fn transfer_points(env: &Env, program_id: BytesN<32>, from: Address, to: Address, amount: u64) {
+    from.require_auth();
+    get_program_info(env, program_id.clone());
+
+    if env
+        .storage()
+        .persistent()
+        .has(&(Symbol::new(env, "transfers_off"), program_id.clone()))
+    {
+        panic!("Transfers are disabled");
+    }
+    if from == to {
+        panic!("Cannot transfer to self");
+    }
+    if amount == 0 {
+        panic!("Amount must be positive");
+    }
+
+    let lots = points::debit(
+        env,
+        &program_id,
+        &from,
+        amount,
+        PointsEventKind::TransferOut,
+        &from,
+    );
+    points::credit_lots(
+        env,
+        &program_id,
+        &to,
+        amount,
+        lots,
+        PointsEventKind::TransferIn,
+        &from,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "points_transferred"), program_id, from),
+        (to, amount),
+    );
+}
+
+/// Lets users convert points from one program into another, receiving `rate_bps / 10000`
+/// destination points per source point. Only an admin owning both programs can set it.
+pub fn set_conversion_rate(
+    env: &Env,
+    program_admin: Address,
+    from_program: BytesN<32>,
+    to_program: BytesN<32>,
+    rate_bps: u32,
+) {
+    program_admin.require_auth();
+
+    if get_program_info(env, from_program.clone()).owner != program_admin
+        || get_program_info(env, to_program.clone()).owner != program_admin
+    {
+        panic!("Not the program owner");
+    }
+    if from_program == to_program {
+        panic!("Cannot convert within a program");
+    }
+    if rate_bps == 0 {
+        panic!("Rate must be positive");
+    }
+
+    let conversion = ConversionRate {
+        rate_bps,
+        min_amount: BASIS_POINTS.div_ceil(rate_bps as u64),
+    };
+    env.storage().persistent().set(
+        &(
+            Symbol::new(env, "conversion"),
+            from_program.clone(),
+            to_program.clone(),
+        ),
+        &conversion,
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(env, "conversion_rate_set"),
+            from_program,
+            to_program,
+        ),
+        conversion,
+    );
+}
+
+pub fn get_conversion_rate(
+    env: &Env,
+    from_program: BytesN<32>,
+    to_program: BytesN<32>,
+) -> Option<ConversionRate> {
+    env.storage()
+        .persistent()
+        .get(&(Symbol::new(env, "conversion"), from_program, to_program))
+}
+
+/// Burns `amount` points in the source program and awards the converted amount, rounded
+/// down, in the destination. Converted points start a new validity period in the destination,
+/// since the two programs can have different expiry policies. Returns the points received.
+pub fn convert_points(
+    env: &Env,
+    user: Address,
+    from_program: BytesN<32>,
+    to_program: BytesN<32>,
+    amount: u64,
+) -> u64 {
+    user.require_auth();
+
+    let conversion = get_conversion_rate(env, from_program.clone(), to_program.clone())
+        .expect("Conversion not available");
+    if amount < conversion.min_amount {
+        panic!("Amount below conversion minimum");
+    }
+
+    let converted = (amount as u128 * conversion.rate_bps as u128 / BASIS_POINTS as u128) as u64;
+
+    points::debit(
+        env,
+        &from_program,
+        &user,
+        amount,
+        PointsEventKind::ConvertOut,
+        &user,
+    );
+    points::credit(
+        env,
+        &to_program,
+        &user,
+        converted,
+        PointsEventKind::ConvertIn,
+        &user,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "points_converted"), user),
+        (from_program, to_program, amount, converted),
+    );
+
+    converted
+}
@@ -1,5 +1,6 @@
 use crate::datatypes::*;
 use crate::fund::{calculate_lender_share_percentage, get_loan_fundings};
+use crate::group::seize_guarantees;
 use crate::repay::{calculate_total_repayment_due, get_loan_repayments};
 use crate::request::get_loan_request;
 use soroban_sdk::{panic_with_error, token, Address, Env, Symbol};
@@ -75,6 +76,9 @@ pub fn claim_default(env: &Env, lender: Address, loan_id: u32) {
         .persistent()
         .set(&DataKey::SystemStats, &system_stats);
 
+    // Group guarantees go to lenders before any collateral is claimed
+    seize_guarantees(env, &loan);
+
     // Distribute collateral value to the calling lender
     let collateral_value = loan.collateral.estimated_value;
     let token_id = env
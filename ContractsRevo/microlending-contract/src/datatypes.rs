@@ -15,6 +15,13 @@ pub enum DataKey {
     TotalLoansDefaulted,      // Total number of loans defaulted
     AssetCode,                // Token contract address for funding
     SystemStats,              // System-wide statistics
+    Group(BytesN<32>),        // Group ID -> Group
+    GroupLoans(BytesN<32>),   // Group ID -> Vec<u32>
+    LoanGuarantees(u32),      // Loan ID -> Vec<GuaranteeDeposit>
+    NextGroupNonce,           // Counter for group ID generation
+    Admin,                    // Address allowed to change configuration
+    RateLimitConfig,          // Per-borrower loan request limit
+    RateWindow(Address),      // Borrower Address -> RateWindow (temporary storage)
 }
 
 #[contracttype]
@@ -33,6 +40,7 @@ pub struct LoanRequest {
     pub funded_timestamp: Option<u64>,         // Ledger timestamp when loan is funded
     pub repayment_due_timestamp: Option<u64>,  // Ledger timestamp when repayment is due
     pub repayment_schedule: RepaymentSchedule, // Repayment schedule (if applicable)
+    pub group_id: Option<BytesN<32>>,          // Guarantee group for joint-liability loans
 }
 
 #[contracttype]
@@ -106,6 +114,81 @@ pub struct RepaymentSchedule {
     pub per_installment_amount: i128, // Amount per installment (principal + interest) and 0 for single payment
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Group {
+    pub id: BytesN<32>,
+    pub leader: Address,
+    pub members: Vec<Address>,  // Invited members, including the leader
+    pub accepted: Vec<Address>, // Members who accepted joint liability
+    pub guarantee_bps: u32,     // Guarantee each member locks, in basis points of the loan
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GuaranteeStatus {
+    Pending,  // Waiting for the member to lock the deposit
+    Locked,   // Deposit held by the contract
+    Released, // Returned to the member
+    Seized,   // Distributed to lenders after a default
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuaranteeDeposit {
+    pub member: Address,
+    pub amount: i128,
+    pub status: GuaranteeStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupExposure {
+    pub group_id: BytesN<32>,
+    pub active_loans: u32,        // Pending, funded or repaying group loans
+    pub outstanding_amount: i128, // Requested amount or remaining repayment due
+    pub locked_guarantees: i128,  // Guarantee deposits currently held
+}
+
+/// Entries whose TTLs `bump_entries` should extend
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntryKeys {
+    pub loans: Vec<u32>,
+    pub users: Vec<Address>, // Borrowers and lenders
+    pub groups: Vec<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitConfig {
+    pub max_actions_per_window: u32,
+    pub window_secs: u64,
+}
+
+// Requests made by one borrower in the current window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateWindow {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+// Read-only snapshot of contract-wide state for monitoring.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub admin: Option<Address>,
+    pub token: Option<Address>,
+    pub total_loans_created: u32,
+    pub total_loans_funded: u32,
+    pub total_loans_completed: u32,
+    pub total_loans_defaulted: u32,
+}
+
+pub const CONTRACT_VERSION: (u32, u32, u32) = (1, 0, 0);
+
 // === Error Definitions ===
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -128,4 +211,16 @@ pub enum MicrolendingError {
     InsufficientBalance = 15,
     InvalidRepaymentSchedule = 16,
     RepaymentScheduleViolation = 17,
+    GroupNotFound = 18,
+    NotGroupMember = 19,
+    AlreadyGroupMember = 20,
+    InvalidGroup = 21,
+    InvalidGuaranteeRate = 22,
+    GuaranteesNotLocked = 23,
+    GuaranteeAlreadyLocked = 24,
+    GroupHasExposure = 25,
+    EntryArchived = 26,
+    NotInitialized = 27,
+    RateLimited = 28,
+    InvalidRateLimit = 29,
 }
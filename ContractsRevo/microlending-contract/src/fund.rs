@@ -1,5 +1,7 @@
 use crate::datatypes::*;
+use crate::group::require_guarantees_locked;
 use crate::request::get_loan_request;
+use crate::ttl;
 use soroban_sdk::{panic_with_error, token, Address, Env, Symbol, Vec};
 
 pub fn fund_loan(env: &Env, lender: Address, loan_id: u32, amount: i128) {
@@ -28,6 +30,9 @@ pub fn fund_loan(env: &Env, lender: Address, loan_id: u32, amount: i128) {
         panic_with_error!(env, MicrolendingError::Unauthorized);
     }
 
+    // Group loans need every member guarantee locked first
+    require_guarantees_locked(env, &loan);
+
     // Calculate remaining amount needed
     let remaining_amount = loan.amount - loan.funded_amount;
     if remaining_amount <= 0 {
@@ -83,6 +88,7 @@ pub fn fund_loan(env: &Env, lender: Address, loan_id: u32, amount: i128) {
             .persistent()
             .set(&DataKey::LenderLoans(lender.clone()), &lender_loans);
     }
+    ttl::extend_user_ttl(env, &lender);
 
     // If fully funded, update loan status, timestamps, and disburse to borrower
     let mut total_loans_funded: u32 = env
@@ -171,6 +177,7 @@ pub fn get_loan_fundings(env: &Env, loan_id: u32) -> Vec<FundingContribution> {
 }
 
 pub fn get_lender_loans(env: &Env, lender: Address) -> Vec<u32> {
+    ttl::extend_user_ttl(env, &lender);
     env.storage()
         .persistent()
         .get(&DataKey::LenderLoans(lender))
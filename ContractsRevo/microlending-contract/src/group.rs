@@ -0,0 +1,416 @@
+use crate::datatypes::*;
+use crate::fund::{calculate_lender_share, get_loan_fundings};
+use crate::repay::{calculate_total_repayment_due, get_loan_repayments};
+use crate::request::get_loan_request;
+use crate::ttl;
+use soroban_sdk::{panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+pub const DEFAULT_GUARANTEE_BPS: u32 = 1000; // 10% of the loan per guarantor
+pub const MAX_GROUP_MEMBERS: u32 = 30;
+
+pub fn create_group(env: &Env, leader: Address, members: Vec<Address>) -> BytesN<32> {
+    leader.require_auth();
+
+    // The leader is always a member; invited members must be unique
+    let mut invited: Vec<Address> = Vec::new(env);
+    invited.push_back(leader.clone());
+    for member in members.iter() {
+        if invited.contains(&member) {
+            panic_with_error!(env, MicrolendingError::InvalidGroup);
+        }
+        invited.push_back(member);
+    }
+    if invited.len() < 2 || invited.len() > MAX_GROUP_MEMBERS {
+        panic_with_error!(env, MicrolendingError::InvalidGroup);
+    }
+
+    let group_id = next_group_id(env, &leader);
+
+    let mut accepted: Vec<Address> = Vec::new(env);
+    accepted.push_back(leader.clone());
+
+    let group = Group {
+        id: group_id.clone(),
+        leader: leader.clone(),
+        members: invited,
+        accepted,
+        guarantee_bps: DEFAULT_GUARANTEE_BPS,
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Group(group_id.clone()), &group);
+    ttl::extend_group_ttl(env, &group_id);
+
+    env.events().publish(
+        (Symbol::new(env, "group_created"),),
+        (group_id.clone(), leader),
+    );
+
+    group_id
+}
+
+pub fn accept_group_membership(env: &Env, member: Address, group_id: BytesN<32>) {
+    member.require_auth();
+
+    let mut group = get_group(env, group_id.clone());
+    if !group.members.contains(&member) {
+        panic_with_error!(env, MicrolendingError::NotGroupMember);
+    }
+    if group.accepted.contains(&member) {
+        panic_with_error!(env, MicrolendingError::AlreadyGroupMember);
+    }
+
+    group.accepted.push_back(member.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Group(group_id.clone()), &group);
+
+    env.events().publish(
+        (Symbol::new(env, "group_member_accepted"),),
+        (group_id, member),
+    );
+}
+
+pub fn leave_group(env: &Env, member: Address, group_id: BytesN<32>) {
+    member.require_auth();
+
+    let mut group = get_group(env, group_id.clone());
+    if !group.members.contains(&member) {
+        panic_with_error!(env, MicrolendingError::NotGroupMember);
+    }
+    // The leader holds the group together and cannot leave it
+    if group.leader == member {
+        panic_with_error!(env, MicrolendingError::Unauthorized);
+    }
+    // Members are jointly liable until every group loan is settled
+    if get_group_exposure(env, group_id.clone()).active_loans > 0 {
+        panic_with_error!(env, MicrolendingError::GroupHasExposure);
+    }
+
+    if let Some(index) = group.members.first_index_of(&member) {
+        group.members.remove(index);
+    }
+    if let Some(index) = group.accepted.first_index_of(&member) {
+        group.accepted.remove(index);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Group(group_id.clone()), &group);
+
+    env.events()
+        .publish((Symbol::new(env, "group_member_left"),), (group_id, member));
+}
+
+pub fn set_group_guarantee_rate(
+    env: &Env,
+    leader: Address,
+    group_id: BytesN<32>,
+    guarantee_bps: u32,
+) {
+    leader.require_auth();
+
+    let mut group = get_group(env, group_id.clone());
+    if group.leader != leader {
+        panic_with_error!(env, MicrolendingError::Unauthorized);
+    }
+    if guarantee_bps == 0 || guarantee_bps > 10000 {
+        panic_with_error!(env, MicrolendingError::InvalidGuaranteeRate);
+    }
+
+    // Applies to loans requested after the change
+    group.guarantee_bps = guarantee_bps;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Group(group_id.clone()), &group);
+
+    env.events().publish(
+        (Symbol::new(env, "group_guarantee_rate_set"),),
+        (group_id, guarantee_bps),
+    );
+}
+
+pub fn get_group(env: &Env, group_id: BytesN<32>) -> Group {
+    let group = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Group(group_id.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, MicrolendingError::GroupNotFound));
+    ttl::extend_group_ttl(env, &group_id);
+    group
+}
+
+pub fn get_group_loans(env: &Env, group_id: BytesN<32>) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GroupLoans(group_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_loan_guarantees(env: &Env, loan_id: u32) -> Vec<GuaranteeDeposit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LoanGuarantees(loan_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_group_exposure(env: &Env, group_id: BytesN<32>) -> GroupExposure {
+    get_group(env, group_id.clone());
+
+    let mut exposure = GroupExposure {
+        group_id: group_id.clone(),
+        active_loans: 0,
+        outstanding_amount: 0,
+        locked_guarantees: 0,
+    };
+
+    for loan_id in get_group_loans(env, group_id).iter() {
+        let loan = get_loan_request(env, loan_id);
+        let outstanding = match loan.status {
+            LoanStatus::Pending => loan.amount,
+            LoanStatus::Funded | LoanStatus::Repaying => {
+                let repaid: i128 = get_loan_repayments(env, loan_id)
+                    .iter()
+                    .map(|r| r.amount)
+                    .sum();
+                calculate_total_repayment_due(&loan) - repaid
+            }
+            _ => continue,
+        };
+
+        exposure.active_loans += 1;
+        exposure.outstanding_amount += outstanding;
+        for deposit in get_loan_guarantees(env, loan_id).iter() {
+            if deposit.status == GuaranteeStatus::Locked {
+                exposure.locked_guarantees += deposit.amount;
+            }
+        }
+    }
+
+    exposure
+}
+
+/// Attach a new loan request to its group and set up the guarantee deposits
+/// every other accepted member must lock before the loan can be funded
+pub fn register_group_loan(env: &Env, group_id: &BytesN<32>, loan: &LoanRequest) {
+    let group = get_group(env, group_id.clone());
+    if !group.accepted.contains(&loan.borrower) {
+        panic_with_error!(env, MicrolendingError::NotGroupMember);
+    }
+
+    let guarantee_amount = guarantee_amount(loan.amount, group.guarantee_bps);
+    let mut deposits: Vec<GuaranteeDeposit> = Vec::new(env);
+    for member in group.accepted.iter() {
+        if member != loan.borrower {
+            deposits.push_back(GuaranteeDeposit {
+                member,
+                amount: guarantee_amount,
+                status: GuaranteeStatus::Pending,
+            });
+        }
+    }
+    if deposits.is_empty() {
+        panic_with_error!(env, MicrolendingError::InvalidGroup);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoanGuarantees(loan.id), &deposits);
+
+    let mut group_loans = get_group_loans(env, group_id.clone());
+    group_loans.push_back(loan.id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::GroupLoans(group_id.clone()), &group_loans);
+}
+
+/// Resize the pending guarantees after a group loan request is updated
+pub fn update_group_loan(env: &Env, loan: &LoanRequest) {
+    let Some(group_id) = loan.group_id.clone() else {
+        return;
+    };
+
+    let mut deposits = get_loan_guarantees(env, loan.id);
+    for deposit in deposits.iter() {
+        if deposit.status != GuaranteeStatus::Pending {
+            panic_with_error!(env, MicrolendingError::InvalidLoanStatus);
+        }
+    }
+
+    let group = get_group(env, group_id);
+    let guarantee_amount = guarantee_amount(loan.amount, group.guarantee_bps);
+    for i in 0..deposits.len() {
+        let mut deposit = deposits.get_unchecked(i);
+        deposit.amount = guarantee_amount;
+        deposits.set(i, deposit);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoanGuarantees(loan.id), &deposits);
+}
+
+pub fn lock_guarantee(env: &Env, member: Address, loan_id: u32) {
+    member.require_auth();
+
+    let loan = get_loan_request(env, loan_id);
+    if loan.status != LoanStatus::Pending {
+        panic_with_error!(env, MicrolendingError::InvalidLoanStatus);
+    }
+
+    let mut deposits = get_loan_guarantees(env, loan_id);
+    let index = deposits
+        .iter()
+        .position(|d| d.member == member)
+        .unwrap_or_else(|| panic_with_error!(env, MicrolendingError::NotGroupMember))
+        as u32;
+    let mut deposit = deposits.get_unchecked(index);
+    if deposit.status != GuaranteeStatus::Pending {
+        panic_with_error!(env, MicrolendingError::GuaranteeAlreadyLocked);
+    }
+
+    let token_client = token_client(env);
+    if token_client.balance(&member) < deposit.amount {
+        panic_with_error!(env, MicrolendingError::InsufficientBalance);
+    }
+    token_client.transfer(&member, &env.current_contract_address(), &deposit.amount);
+
+    deposit.status = GuaranteeStatus::Locked;
+    let amount = deposit.amount;
+    deposits.set(index, deposit);
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoanGuarantees(loan_id), &deposits);
+
+    env.events().publish(
+        (Symbol::new(env, "guarantee_locked"),),
+        (loan_id, member, amount),
+    );
+}
+
+/// Group loans only become fundable once every guarantee is locked
+pub fn require_guarantees_locked(env: &Env, loan: &LoanRequest) {
+    if loan.group_id.is_none() {
+        return;
+    }
+    for deposit in get_loan_guarantees(env, loan.id).iter() {
+        if deposit.status != GuaranteeStatus::Locked {
+            panic_with_error!(env, MicrolendingError::GuaranteesNotLocked);
+        }
+    }
+}
+
+/// Return locked guarantees to their members (loan completed or cancelled)
+pub fn release_guarantees(env: &Env, loan: &LoanRequest) {
+    if loan.group_id.is_none() {
+        return;
+    }
+
+    let token_client = token_client(env);
+    let mut deposits = get_loan_guarantees(env, loan.id);
+    for i in 0..deposits.len() {
+        let mut deposit = deposits.get_unchecked(i);
+        if deposit.status == GuaranteeStatus::Locked {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &deposit.member,
+                &deposit.amount,
+            );
+            deposit.status = GuaranteeStatus::Released;
+            deposits.set(i, deposit.clone());
+
+            env.events().publish(
+                (Symbol::new(env, "guarantee_released"),),
+                (loan.id, deposit.member, deposit.amount),
+            );
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoanGuarantees(loan.id), &deposits);
+}
+
+/// Distribute locked guarantees to lenders in proportion to their funding
+/// Runs once, when the loan is first marked as defaulted
+pub fn seize_guarantees(env: &Env, loan: &LoanRequest) {
+    if loan.group_id.is_none() {
+        return;
+    }
+
+    let mut deposits = get_loan_guarantees(env, loan.id);
+    let mut seized: i128 = 0;
+    for i in 0..deposits.len() {
+        let mut deposit = deposits.get_unchecked(i);
+        if deposit.status == GuaranteeStatus::Locked {
+            seized += deposit.amount;
+            deposit.status = GuaranteeStatus::Seized;
+            deposits.set(i, deposit);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::LoanGuarantees(loan.id), &deposits);
+
+    if seized == 0 || loan.funded_amount == 0 {
+        return;
+    }
+
+    let mut lenders: Vec<Address> = Vec::new(env);
+    for contribution in get_loan_fundings(env, loan.id).iter() {
+        if !lenders.contains(&contribution.lender) {
+            lenders.push_back(contribution.lender);
+        }
+    }
+
+    let token_client = token_client(env);
+    let mut distributed: i128 = 0;
+    for i in 1..lenders.len() {
+        let lender = lenders.get_unchecked(i);
+        let share =
+            seized * calculate_lender_share(env, lender.clone(), loan.id) / loan.funded_amount;
+        if share > 0 {
+            token_client.transfer(&env.current_contract_address(), &lender, &share);
+            distributed += share;
+        }
+    }
+
+    // The first lender absorbs any rounding remainder
+    let first_share = seized - distributed;
+    if first_share > 0 {
+        token_client.transfer(
+            &env.current_contract_address(),
+            &lenders.get_unchecked(0),
+            &first_share,
+        );
+    }
+
+    env.events()
+        .publish((Symbol::new(env, "guarantees_seized"),), (loan.id, seized));
+}
+
+fn guarantee_amount(loan_amount: i128, guarantee_bps: u32) -> i128 {
+    (loan_amount as u128 * guarantee_bps as u128 / 10000) as i128
+}
+
+fn token_client(env: &Env) -> token::Client<'_> {
+    let token_id: Address = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AssetCode)
+        .unwrap_or_else(|| panic_with_error!(env, MicrolendingError::TokenNotConfigured));
+    token::Client::new(env, &token_id)
+}
+
+fn next_group_id(env: &Env, leader: &Address) -> BytesN<32> {
+    let nonce: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NextGroupNonce)
+        .unwrap_or(0u32);
+    env.storage()
+        .persistent()
+        .set(&DataKey::NextGroupNonce, &(nonce + 1));
+
+    let mut data = Bytes::new(env);
+    data.append(&leader.clone().to_xdr(env));
+    data.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+    env.crypto().sha256(&data).into()
+}
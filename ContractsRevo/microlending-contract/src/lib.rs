@@ -1,17 +1,25 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Symbol, Vec,
+};
 
 mod claim;
 mod datatypes;
 mod fund;
+mod group;
+mod rate_limit;
 mod repay;
 mod request;
+mod ttl;
 
 pub use claim::*;
 pub use datatypes::*;
 pub use fund::*;
+pub use group::*;
+pub use rate_limit::*;
 pub use repay::*;
 pub use request::*;
+pub use ttl::*;
 
 #[contract]
 pub struct Microlending;
@@ -19,23 +27,64 @@ pub struct Microlending;
 #[contractimpl]
 impl Microlending {
     // Initialize the contract
-    pub fn initialize(env: Env, token_address: Address) {
+    pub fn initialize(env: Env, admin: Address, token_address: Address) {
         // Check if already initialized
         if env.storage().persistent().has(&DataKey::AssetCode) {
             panic_with_error!(env, MicrolendingError::AlreadyInitialized);
         }
 
-        // Store token address
+        // Store admin and token address
+        env.storage().persistent().set(&DataKey::Admin, &admin);
         env.storage()
             .persistent()
             .set(&DataKey::AssetCode, &token_address);
 
+        ttl::extend_contract_ttl(&env);
+
         // Emit initialization event
         env.events()
             .publish((Symbol::new(&env, "initialized"),), (token_address,));
     }
 
+    // Limit how many loan requests one borrower can create per window (admin only)
+    pub fn set_rate_limit(env: Env, admin: Address, max_actions_per_window: u32, window_secs: u64) {
+        rate_limit::set_rate_limit(&env, admin, max_actions_per_window, window_secs)
+    }
+
+    pub fn get_rate_limit(env: Env) -> Option<RateLimitConfig> {
+        rate_limit::get_rate_limit(&env)
+    }
+
+    // When a rate-limited borrower may request again; 0 if they can now
+    pub fn get_retry_after(env: Env, borrower: Address) -> u64 {
+        rate_limit::get_retry_after(&env, &borrower)
+    }
+
+    // Contract-wide state for monitoring; safe to call before initialization
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        let storage = env.storage().persistent();
+        ContractInfo {
+            admin: storage.get(&DataKey::Admin),
+            token: storage.get(&DataKey::AssetCode),
+            total_loans_created: storage.get(&DataKey::TotalLoansCreated).unwrap_or(0),
+            total_loans_funded: storage.get(&DataKey::TotalLoansFunded).unwrap_or(0),
+            total_loans_completed: storage.get(&DataKey::TotalLoansCompleted).unwrap_or(0),
+            total_loans_defaulted: storage.get(&DataKey::TotalLoansDefaulted).unwrap_or(0),
+        }
+    }
+
+    // Contract version as (major, minor, patch)
+    pub fn version(_env: Env) -> (u32, u32, u32) {
+        CONTRACT_VERSION
+    }
+
+    // Extend the TTLs of the given loans, users and groups; callable by anyone
+    pub fn bump_entries(env: Env, keys: EntryKeys) {
+        ttl::bump_entries(&env, keys)
+    }
+
     // Loan request functions
+    #[allow(clippy::too_many_arguments)]
     pub fn create_loan_request(
         env: Env,
         borrower: Address,
@@ -44,6 +93,7 @@ impl Microlending {
         duration_days: u32,
         interest_rate: u32,
         collateral: CollateralInfo,
+        group_id: Option<BytesN<32>>,
     ) -> u32 {
         request::create_loan_request(
             &env,
@@ -53,6 +103,7 @@ impl Microlending {
             duration_days,
             interest_rate,
             collateral,
+            group_id,
         )
     }
 
@@ -152,6 +203,48 @@ impl Microlending {
         repay::calculate_total_repayment_due(&loan)
     }
 
+    // Group guarantee functions
+    pub fn create_group(env: Env, leader: Address, members: Vec<Address>) -> BytesN<32> {
+        group::create_group(&env, leader, members)
+    }
+
+    pub fn accept_group_membership(env: Env, member: Address, group_id: BytesN<32>) {
+        group::accept_group_membership(&env, member, group_id)
+    }
+
+    pub fn leave_group(env: Env, member: Address, group_id: BytesN<32>) {
+        group::leave_group(&env, member, group_id)
+    }
+
+    pub fn set_group_guarantee_rate(
+        env: Env,
+        leader: Address,
+        group_id: BytesN<32>,
+        guarantee_bps: u32,
+    ) {
+        group::set_group_guarantee_rate(&env, leader, group_id, guarantee_bps)
+    }
+
+    pub fn lock_guarantee(env: Env, member: Address, loan_id: u32) {
+        group::lock_guarantee(&env, member, loan_id)
+    }
+
+    pub fn get_group(env: Env, group_id: BytesN<32>) -> Group {
+        group::get_group(&env, group_id)
+    }
+
+    pub fn get_group_loans(env: Env, group_id: BytesN<32>) -> Vec<u32> {
+        group::get_group_loans(&env, group_id)
+    }
+
+    pub fn get_loan_guarantees(env: Env, loan_id: u32) -> Vec<GuaranteeDeposit> {
+        group::get_loan_guarantees(&env, loan_id)
+    }
+
+    pub fn get_group_exposure(env: Env, group_id: BytesN<32>) -> GroupExposure {
+        group::get_group_exposure(&env, group_id)
+    }
+
     // Default claim functions
     pub fn claim_default(env: Env, lender: Address, loan_id: u32) {
         claim::claim_default(&env, lender, loan_id)
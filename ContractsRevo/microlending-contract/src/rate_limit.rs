@@ -0,0 +1,92 @@
+use crate::datatypes::*;
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+// Ledgers close roughly every five seconds
+const LEDGER_SECS: u64 = 5;
+
+pub fn set_rate_limit(env: &Env, admin: Address, max_actions_per_window: u32, window_secs: u64) {
+    let stored_admin: Address = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, MicrolendingError::NotInitialized));
+    if admin != stored_admin {
+        panic_with_error!(env, MicrolendingError::Unauthorized);
+    }
+    admin.require_auth();
+
+    // Window records must be able to live for the whole window
+    if max_actions_per_window == 0
+        || window_secs == 0
+        || window_ledgers(window_secs) > env.storage().max_ttl() as u64
+    {
+        panic_with_error!(env, MicrolendingError::InvalidRateLimit);
+    }
+
+    let config = RateLimitConfig {
+        max_actions_per_window,
+        window_secs,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::RateLimitConfig, &config);
+
+    env.events().publish(
+        (Symbol::new(env, "rate_limit_set"),),
+        (max_actions_per_window, window_secs),
+    );
+}
+
+pub fn get_rate_limit(env: &Env) -> Option<RateLimitConfig> {
+    env.storage().persistent().get(&DataKey::RateLimitConfig)
+}
+
+// When `actor` may act again: 0 if the current window still has room, otherwise the
+// timestamp the window reopens at.
+pub fn get_retry_after(env: &Env, actor: &Address) -> u64 {
+    let Some(config) = get_rate_limit(env) else {
+        return 0;
+    };
+
+    let window = current_window(env, &config, actor);
+    if window.count < config.max_actions_per_window {
+        0
+    } else {
+        window.window_start.saturating_add(config.window_secs)
+    }
+}
+
+// Count one action for `actor`, rejecting it once the window's allowance is used up.
+// The window record lives in temporary storage and expires with the window.
+pub fn check_rate_limit(env: &Env, actor: &Address) {
+    let Some(config) = get_rate_limit(env) else {
+        return;
+    };
+
+    let mut window = current_window(env, &config, actor);
+    if window.count >= config.max_actions_per_window {
+        panic_with_error!(env, MicrolendingError::RateLimited);
+    }
+
+    window.count += 1;
+    let key = DataKey::RateWindow(actor.clone());
+    env.storage().temporary().set(&key, &window);
+    let ttl = window_ledgers(config.window_secs) as u32;
+    env.storage().temporary().extend_ttl(&key, ttl, ttl);
+}
+
+fn current_window(env: &Env, config: &RateLimitConfig, actor: &Address) -> RateWindow {
+    let now = env.ledger().timestamp();
+    env.storage()
+        .temporary()
+        .get(&DataKey::RateWindow(actor.clone()))
+        .filter(|w: &RateWindow| now < w.window_start.saturating_add(config.window_secs))
+        .unwrap_or(RateWindow {
+            window_start: now,
+            count: 0,
+        })
+}
+
+fn window_ledgers(window_secs: u64) -> u64 {
+    window_secs / LEDGER_SECS + 1
+}
@@ -1,5 +1,6 @@
 use crate::datatypes::*;
 use crate::fund::{calculate_lender_share_percentage, get_loan_fundings};
+use crate::group::release_guarantees;
 use crate::request::get_loan_request;
 use soroban_sdk::{panic_with_error, token, Address, Env, Symbol, Vec};
 
@@ -224,6 +225,9 @@ pub fn repay_loan(env: &Env, borrower: Address, loan_id: u32, amount: i128) {
         env.storage()
             .persistent()
             .set(&DataKey::TotalLoansCompleted, &(total_loans_completed + 1));
+
+        // Return group guarantees now that the loan is settled
+        release_guarantees(env, &loan);
     }
 
     // Update system stats for total repaid
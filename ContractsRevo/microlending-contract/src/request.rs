@@ -1,6 +1,10 @@
 use crate::datatypes::*;
-use soroban_sdk::{panic_with_error, Address, Env, String, Symbol, Vec};
+use crate::group;
+use crate::rate_limit;
+use crate::ttl;
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, String, Symbol, Vec};
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_loan_request(
     env: &Env,
     borrower: Address,
@@ -9,8 +13,10 @@ pub fn create_loan_request(
     duration_days: u32,
     interest_rate: u32,
     collateral: CollateralInfo,
+    group_id: Option<BytesN<32>>,
 ) -> u32 {
     borrower.require_auth();
+    rate_limit::check_rate_limit(env, &borrower);
 
     // Validate inputs
     validate_loan_inputs(env, amount, duration_days, interest_rate, &collateral);
@@ -59,8 +65,14 @@ pub fn create_loan_request(
         funded_timestamp: None,
         repayment_due_timestamp: None,
         repayment_schedule,
+        group_id: group_id.clone(),
     };
 
+    // Group loans require guarantee deposits from the other members
+    if let Some(group_id) = &group_id {
+        group::register_group_loan(env, group_id, &loan_request);
+    }
+
     // Store loan request
     env.storage()
         .persistent()
@@ -115,6 +127,10 @@ pub fn create_loan_request(
         .persistent()
         .set(&DataKey::TotalLoansCreated, &(total_loans + 1));
 
+    ttl::extend_loan_ttl(env, loan_id);
+    ttl::extend_user_ttl(env, &borrower);
+    ttl::extend_contract_ttl(env);
+
     // Emit loan created event
     env.events().publish(
         (Symbol::new(&env, "loan_created"),),
@@ -138,13 +154,17 @@ pub fn create_loan_request(
 }
 
 pub fn get_loan_request(env: &Env, loan_id: u32) -> LoanRequest {
-    env.storage()
+    let loan = env
+        .storage()
         .persistent()
         .get(&DataKey::Loan(loan_id))
-        .unwrap_or_else(|| panic_with_error!(env, MicrolendingError::LoanNotFound))
+        .unwrap_or_else(|| ttl::missing_loan_error(env, loan_id));
+    ttl::extend_loan_ttl(env, loan_id);
+    loan
 }
 
 pub fn get_borrower_loans(env: &Env, borrower: Address) -> Vec<u32> {
+    ttl::extend_user_ttl(env, &borrower);
     env.storage()
         .persistent()
         .get(&DataKey::BorrowerLoans(borrower))
@@ -173,6 +193,9 @@ pub fn cancel_loan_request(env: &Env, borrower: Address, loan_id: u32) {
         .persistent()
         .set(&DataKey::Loan(loan_id), &loan);
 
+    // Return any guarantees already locked by group members
+    group::release_guarantees(env, &loan);
+
     // Emit loan cancelled event
     env.events().publish(
         (Symbol::new(&env, "loan_cancelled"),),
@@ -237,6 +260,9 @@ pub fn update_loan_request(
         }
     };
 
+    // Resize pending group guarantees to the new amount
+    group::update_group_loan(env, &loan);
+
     // Store updated loan
     env.storage()
         .persistent()
@@ -0,0 +1,83 @@
+use crate::datatypes::*;
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, IntoVal, Val};
+
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+// Entries touched with fewer than 15 days to live are extended back to 30 days
+pub const ENTRY_LIFETIME_THRESHOLD: u32 = 15 * DAY_IN_LEDGERS;
+pub const ENTRY_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+pub fn bump_entries(env: &Env, keys: EntryKeys) {
+    extend_contract_ttl(env);
+    for loan_id in keys.loans.iter() {
+        extend_loan_ttl(env, loan_id);
+    }
+    for user in keys.users.iter() {
+        extend_user_ttl(env, &user);
+    }
+    for group_id in keys.groups.iter() {
+        extend_group_ttl(env, &group_id);
+    }
+}
+
+/// Keeps the contract configuration and counters live
+pub fn extend_contract_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(ENTRY_LIFETIME_THRESHOLD, ENTRY_BUMP_AMOUNT);
+    for key in [
+        DataKey::Admin,
+        DataKey::AssetCode,
+        DataKey::RateLimitConfig,
+        DataKey::NextLoanId,
+        DataKey::NextGroupNonce,
+        DataKey::TotalLoansCreated,
+        DataKey::TotalLoansFunded,
+        DataKey::TotalLoansCompleted,
+        DataKey::TotalLoansDefaulted,
+        DataKey::SystemStats,
+    ] {
+        extend_if_present(env, &key);
+    }
+}
+
+/// Keeps a loan and its funding, repayment and guarantee records live
+pub fn extend_loan_ttl(env: &Env, loan_id: u32) {
+    extend_if_present(env, &DataKey::Loan(loan_id));
+    extend_if_present(env, &DataKey::Funding(loan_id));
+    extend_if_present(env, &DataKey::Repayments(loan_id));
+    extend_if_present(env, &DataKey::LoanGuarantees(loan_id));
+}
+
+/// Keeps a borrower's or lender's loan indexes and metrics live
+pub fn extend_user_ttl(env: &Env, user: &Address) {
+    extend_if_present(env, &DataKey::BorrowerLoans(user.clone()));
+    extend_if_present(env, &DataKey::LenderLoans(user.clone()));
+    extend_if_present(env, &DataKey::BorrowerMetrics(user.clone()));
+}
+
+pub fn extend_group_ttl(env: &Env, group_id: &BytesN<32>) {
+    extend_if_present(env, &DataKey::Group(group_id.clone()));
+    extend_if_present(env, &DataKey::GroupLoans(group_id.clone()));
+}
+
+fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, ENTRY_LIFETIME_THRESHOLD, ENTRY_BUMP_AMOUNT);
+    }
+}
+
+/// Distinguishes a loan that was never created from one whose record has left the
+/// live state and must be restored before it can be used
+pub fn missing_loan_error(env: &Env, loan_id: u32) -> ! {
+    let next_loan_id: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NextLoanId)
+        .unwrap_or(1);
+    if loan_id >= 1 && loan_id < next_loan_id {
+        panic_with_error!(env, MicrolendingError::EntryArchived)
+    }
+    panic_with_error!(env, MicrolendingError::LoanNotFound)
+}
@@ -0,0 +1,308 @@
+use crate::datatype::{
+    DataKey, FundParameters, PeriodReport, ReserveStatus, StabilizationError, WithdrawalRequest,
+    WithdrawalStatus,
+};
+use crate::interface::FundAccounting;
+use crate::reserve::{load_reserve, require_admin};
+use crate::PriceStabilizationContractArgs;
+use crate::{PriceStabilizationContract, PriceStabilizationContractClient};
+use soroban_sdk::{contractimpl, symbol_short, token, Address, Env};
+
+const DAY: u64 = 24 * 60 * 60;
+const MAX_BPS: u32 = 10_000;
+
+pub(crate) fn fund_parameters(env: &Env) -> FundParameters {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FundParameters)
+        .unwrap_or(FundParameters {
+            period_secs: 30 * DAY,
+            withdrawal_window_secs: 3 * DAY,
+            max_disbursement_per_period: 0,
+            admin_fee_bps: 0,
+        })
+}
+
+pub(crate) fn current_period(env: &Env, params: &FundParameters) -> u64 {
+    env.ledger().timestamp() / params.period_secs
+}
+
+pub(crate) fn load_period_report(env: &Env, period: u64) -> PeriodReport {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PeriodReport(period))
+        .unwrap_or(PeriodReport {
+            period,
+            contributions: 0,
+            disbursements: 0,
+            admin_fees: 0,
+            withdrawals: 0,
+        })
+}
+
+/// Applies `update` to the report of the current accounting period
+pub(crate) fn record_in_period(env: &Env, update: impl FnOnce(&mut PeriodReport)) {
+    let params = fund_parameters(env);
+    let mut report = load_period_report(env, current_period(env, &params));
+    update(&mut report);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PeriodReport(report.period), &report);
+}
+
+/// Tracks how much of the reserve is set aside for claims awaiting manual approval
+pub(crate) fn adjust_pending_committed(env: &Env, delta: i128) {
+    let committed: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PendingCommitted)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingCommitted, &(committed + delta).max(0));
+}
+
+/// Reserve balance not set aside for pending claims
+pub(crate) fn uncommitted_balance(env: &Env, reserve: &ReserveStatus) -> i128 {
+    let committed: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PendingCommitted)
+        .unwrap_or(0);
+    (reserve.balance - committed).max(0)
+}
+
+fn total_stake(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TotalReserveStake)
+        .unwrap_or(0)
+}
+
+fn stake_epoch(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeEpoch)
+        .unwrap_or(0)
+}
+
+/// Stake issued in an earlier epoch was written off and counts as zero
+fn stake_of(env: &Env, contributor: &Address) -> i128 {
+    let epoch: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ReserveStakeEpoch(contributor.clone()))
+        .unwrap_or(0);
+    if epoch != stake_epoch(env) {
+        return 0;
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReserveStake(contributor.clone()))
+        .unwrap_or(0)
+}
+
+fn set_stakes(env: &Env, contributor: &Address, stake: i128, total: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReserveStake(contributor.clone()), &stake);
+    env.storage().persistent().set(
+        &DataKey::ReserveStakeEpoch(contributor.clone()),
+        &stake_epoch(env),
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::TotalReserveStake, &total);
+}
+
+/// Issues `amount * total_stake / reserve_value` stake for a contribution, so earlier
+/// contributors keep bearing the disbursements made before it. The reserve is valued at its
+/// uncommitted balance, or its full balance while everything is set aside for pending claims.
+/// Only the first contribution, or one into an emptied reserve whose outstanding stake is
+/// written off, is issued 1:1. `reserve` is the reserve as it was before the contribution.
+pub(crate) fn issue_stake(env: &Env, contributor: &Address, amount: i128, reserve: &ReserveStatus) {
+    let mut total = total_stake(env);
+    let uncommitted = uncommitted_balance(env, reserve);
+    let value = if uncommitted > 0 {
+        uncommitted
+    } else {
+        reserve.balance
+    };
+
+    if total > 0 && value <= 0 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::StakeEpoch, &(stake_epoch(env) + 1));
+        total = 0;
+    }
+    let issued = if total == 0 {
+        amount
+    } else {
+        amount * total / value
+    };
+
+    set_stakes(
+        env,
+        contributor,
+        stake_of(env, contributor) + issued,
+        total + issued,
+    );
+}
+
+fn withdrawable_share(env: &Env, contributor: &Address) -> i128 {
+    let total = total_stake(env);
+    if total == 0 {
+        return 0;
+    }
+    let uncommitted = uncommitted_balance(env, &load_reserve(env));
+    uncommitted * stake_of(env, contributor) / total
+}
+
+#[contractimpl]
+impl FundAccounting for PriceStabilizationContract {
+    fn set_fund_parameters(
+        env: Env,
+        admin: Address,
+        params: FundParameters,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        if params.period_secs == 0
+            || params.withdrawal_window_secs > params.period_secs
+            || params.max_disbursement_per_period < 0
+            || params.admin_fee_bps > MAX_BPS
+        {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FundParameters, &params);
+        env.events()
+            .publish((symbol_short!("fund"), symbol_short!("params")), params);
+
+        Ok(())
+    }
+
+    fn get_fund_parameters(env: Env) -> FundParameters {
+        fund_parameters(&env)
+    }
+
+    fn get_period_report(env: Env, period: u64) -> PeriodReport {
+        load_period_report(&env, period)
+    }
+
+    fn get_current_period(env: Env) -> u64 {
+        current_period(&env, &fund_parameters(&env))
+    }
+
+    fn request_withdrawal(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<u64, StabilizationError> {
+        // Verify contributor authorization
+        contributor.require_auth();
+
+        if amount <= 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+        if stake_of(&env, &contributor) == 0 {
+            return Err(StabilizationError::WithdrawalExceedsShare);
+        }
+
+        let request_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::WithdrawalCounter)
+            .unwrap_or(0)
+            + 1;
+        let request = WithdrawalRequest {
+            request_id,
+            contributor,
+            amount,
+            requested_at: env.ledger().timestamp(),
+            status: WithdrawalStatus::Queued,
+            executed_at: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Withdrawal(request_id), &request);
+        env.storage()
+            .persistent()
+            .set(&DataKey::WithdrawalCounter, &request_id);
+
+        Ok(request_id)
+    }
+
+    fn execute_withdrawal(
+        env: Env,
+        contributor: Address,
+        request_id: u64,
+    ) -> Result<(), StabilizationError> {
+        // Verify contributor authorization
+        contributor.require_auth();
+
+        let mut request: WithdrawalRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Withdrawal(request_id))
+            .ok_or(StabilizationError::WithdrawalNotFound)?;
+        if request.contributor != contributor {
+            return Err(StabilizationError::Unauthorized);
+        }
+        if request.status != WithdrawalStatus::Queued {
+            return Err(StabilizationError::WithdrawalNotQueued);
+        }
+
+        // Withdrawals only go through at the start of each period
+        let params = fund_parameters(&env);
+        let now = env.ledger().timestamp();
+        if now % params.period_secs >= params.withdrawal_window_secs {
+            return Err(StabilizationError::OutsideWithdrawalWindow);
+        }
+
+        let mut reserve = load_reserve(&env);
+        let uncommitted = uncommitted_balance(&env, &reserve);
+        let total = total_stake(&env);
+        let stake = stake_of(&env, &contributor);
+        if total == 0 || request.amount > uncommitted * stake / total {
+            return Err(StabilizationError::WithdrawalExceedsShare);
+        }
+
+        // Burn the stake the withdrawn amount represents, rounding up in favour of the others
+        let burned = ((request.amount * total + uncommitted - 1) / uncommitted).min(stake);
+        set_stakes(&env, &contributor, stake - burned, total - burned);
+
+        reserve.balance -= request.amount;
+        reserve.total_withdrawn += request.amount;
+        env.storage().persistent().set(&DataKey::Reserve, &reserve);
+        record_in_period(&env, |report| report.withdrawals += request.amount);
+
+        request.status = WithdrawalStatus::Executed;
+        request.executed_at = Some(now);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Withdrawal(request_id), &request);
+
+        let reserve_token = reserve.token.ok_or(StabilizationError::InsufficientFunds)?;
+        token::Client::new(&env, &reserve_token).transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &request.amount,
+        );
+
+        Ok(())
+    }
+
+    fn get_withdrawal(env: Env, request_id: u64) -> Result<WithdrawalRequest, StabilizationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Withdrawal(request_id))
+            .ok_or(StabilizationError::WithdrawalNotFound)
+    }
+
+    fn get_withdrawable_share(env: Env, contributor: Address) -> i128 {
+        withdrawable_share(&env, &contributor)
+    }
+}
@@ -1,6 +1,6 @@
-use soroban_sdk::{contracterror, contracttype, Address, BytesN, String};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracterror]
 pub enum StabilizationError {
     FundNotFound = 1,
@@ -21,6 +21,18 @@ pub enum StabilizationError {
     ChainlinkFeedNotRegistered = 16,
     ChainlinkFeedAlreadyRegistered = 17,
     CropAlreadyRegistered = 18,
+    FloorNotSet = 19,
+    PeriodCapExceeded = 20,
+    TokenMismatch = 21,
+    PaymentNotFound = 22,
+    VerificationFailed = 23,
+    PaymentNotPending = 24,
+    VerificationNotConfigured = 25,
+    OutsideWithdrawalWindow = 26,
+    WithdrawalExceedsShare = 27,
+    PeriodDisbursementLimit = 28,
+    WithdrawalNotFound = 29,
+    WithdrawalNotQueued = 30,
 }
 
 #[derive(Debug)]
@@ -38,6 +50,30 @@ pub enum DataKey {
     PayoutCounter(BytesN<32>, Address),
     ChainlinkFeed(String),
     ChainlinkPrice(String),
+    Reserve,
+    ReserveContribution(Address),
+    PriceFloor(Symbol, Symbol),
+    MarketOracle(Address),
+    RegionalPrice(Symbol, Symbol),
+    PaymentCap,
+    FarmerPeriodPaid(Address, u64),
+    StabilizationPayment(u64),
+    PaymentCounter,
+    EvidenceUsed(BytesN<32>),
+    VerificationSource,
+    SaleReferenceUsed(BytesN<32>),
+    FundParameters,
+    PeriodReport(u64),
+    ReserveStake(Address),
+    TotalReserveStake,
+    /// Bumped when outstanding stake is written off against an empty reserve
+    StakeEpoch,
+    ReserveStakeEpoch(Address),
+    PendingCommitted,
+    Withdrawal(u64),
+    WithdrawalCounter,
+    DynamicFloor(Symbol, Symbol),
+    PriceWindow(Symbol, Symbol),
 }
 
 #[contracttype]
@@ -109,3 +145,167 @@ pub struct ChainlinkPriceData {
     pub round_id: u64,
     pub decimals: u32,
 }
+
+/// Pooled reserve that backs floor-price stabilization payments
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ReserveStatus {
+    /// Fixed by the first contribution
+    pub token: Option<Address>,
+    pub balance: i128,
+    pub total_contributed: i128,
+    pub total_disbursed: i128,
+    pub total_withdrawn: i128,
+    pub contributor_count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PriceFloor {
+    pub floor_price: i128,
+    pub updated_at: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum FloorMode {
+    /// Floor set directly by the admin
+    Static = 0,
+    /// Floor derived from the moving average of reported market prices
+    Dynamic = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DynamicFloorConfig {
+    pub lookback_periods: u32,
+    /// Floor as a share of the moving average, in basis points
+    pub pct_bps: u32,
+    pub min_floor: i128,
+    pub max_floor: i128,
+}
+
+/// Closing market prices of the most recent accounting periods with a report
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PriceWindow {
+    pub last_period: u64,
+    pub samples: Vec<i128>,
+    /// Running sum of `samples`
+    pub sum: i128,
+}
+
+/// Effective floor for a product and region together with the inputs it was derived from.
+/// The dynamic fields are zero in static mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct FloorQuote {
+    pub mode: FloorMode,
+    pub floor_price: i128,
+    pub updated_at: u64,
+    pub moving_average: i128,
+    pub sample_count: u32,
+    pub lookback_periods: u32,
+    pub pct_bps: u32,
+    pub min_floor: i128,
+    pub max_floor: i128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PaymentCap {
+    pub period_secs: u64,
+    /// Most a single farmer can receive within one period
+    pub max_per_farmer: i128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct StabilizationPayment {
+    pub payment_id: u64,
+    pub farmer: Address,
+    pub product_type: Symbol,
+    pub region: Symbol,
+    pub quantity_sold: i128,
+    pub sale_price: i128,
+    pub floor_price: i128,
+    pub market_price: i128,
+    /// Amount paid, or the amount claimed while the payment is pending
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+    /// Supply-chain product id or commodity redemption id backing the sale
+    pub sale_reference: Option<BytesN<32>>,
+    pub status: PaymentStatus,
+    /// Evidence the admin relied on when approving an unverifiable claim
+    pub approval_evidence: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum PaymentStatus {
+    Paid = 0,
+    /// The sale could not be verified and awaits a manual decision
+    Pending = 1,
+    Rejected = 2,
+}
+
+/// Where sale claims are checked before a stabilization payment is made
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum VerificationMode {
+    /// The reference is a supply-chain product that must have reached distribution or retail
+    SupplyChain = 0,
+    /// The reference is a commodity token redemption made by the farmer
+    CommodityRedemption = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct VerificationSource {
+    pub source_contract: Address,
+    pub mode: VerificationMode,
+}
+
+/// Admin-set parameters governing reserve accounting, withdrawals and disbursements
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct FundParameters {
+    /// Length of an accounting period
+    pub period_secs: u64,
+    /// Withdrawals can only execute during the first `withdrawal_window_secs` of each period
+    pub withdrawal_window_secs: u64,
+    /// Most the reserve pays out per period, 0 for no limit
+    pub max_disbursement_per_period: i128,
+    /// Fee on each disbursement paid to the admin, in basis points
+    pub admin_fee_bps: u32,
+}
+
+/// Reserve activity within one accounting period
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PeriodReport {
+    pub period: u64,
+    pub contributions: i128,
+    pub disbursements: i128,
+    pub admin_fees: i128,
+    pub withdrawals: i128,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum WithdrawalStatus {
+    Queued = 0,
+    Executed = 1,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct WithdrawalRequest {
+    pub request_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub requested_at: u64,
+    pub status: WithdrawalStatus,
+    pub executed_at: Option<u64>,
+}
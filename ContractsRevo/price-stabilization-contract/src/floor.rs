@@ -0,0 +1,162 @@
+use crate::accounting::{current_period, fund_parameters};
+use crate::datatype::{
+    DataKey, DynamicFloorConfig, FloorMode, FloorQuote, PriceFloor, PriceWindow, StabilizationError,
+};
+use crate::interface::DynamicFloor;
+use crate::reserve::require_admin;
+use crate::PriceStabilizationContractArgs;
+use crate::{PriceStabilizationContract, PriceStabilizationContractClient};
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+/// Longest moving average supported, and how much history is kept while no dynamic floor
+/// is configured
+pub const MAX_LOOKBACK_PERIODS: u32 = 52;
+
+const MAX_BPS: u32 = 10_000;
+
+fn window_len(env: &Env, product_type: &Symbol, region: &Symbol) -> u32 {
+    let config: Option<DynamicFloorConfig> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::DynamicFloor(product_type.clone(), region.clone()));
+    config.map_or(MAX_LOOKBACK_PERIODS, |config| config.lookback_periods)
+}
+
+fn trim_window(window: &mut PriceWindow, len: u32) {
+    while window.samples.len() > len {
+        let oldest = window.samples.pop_front_unchecked();
+        window.sum -= oldest;
+    }
+}
+
+/// Keeps the closing price of each accounting period, so the moving average is updated in
+/// constant time per report. A later report in the same period replaces the earlier one.
+pub(crate) fn record_price_sample(env: &Env, product_type: &Symbol, region: &Symbol, price: i128) {
+    let key = DataKey::PriceWindow(product_type.clone(), region.clone());
+    let period = current_period(env, &fund_parameters(env));
+
+    let mut window: PriceWindow = env.storage().persistent().get(&key).unwrap_or(PriceWindow {
+        last_period: period,
+        samples: Vec::new(env),
+        sum: 0,
+    });
+
+    if window.last_period == period && !window.samples.is_empty() {
+        let previous = window.samples.pop_back_unchecked();
+        window.sum -= previous;
+    }
+    window.samples.push_back(price);
+    window.sum += price;
+    window.last_period = period;
+    trim_window(&mut window, window_len(env, product_type, region));
+
+    env.storage().persistent().set(&key, &window);
+}
+
+/// Resolves the floor currently in force for a product and region
+pub(crate) fn effective_floor(
+    env: &Env,
+    product_type: &Symbol,
+    region: &Symbol,
+) -> Result<FloorQuote, StabilizationError> {
+    let config: Option<DynamicFloorConfig> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::DynamicFloor(product_type.clone(), region.clone()));
+
+    let config = match config {
+        Some(config) => config,
+        None => {
+            let floor: PriceFloor = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PriceFloor(product_type.clone(), region.clone()))
+                .ok_or(StabilizationError::FloorNotSet)?;
+            return Ok(FloorQuote {
+                mode: FloorMode::Static,
+                floor_price: floor.floor_price,
+                updated_at: floor.updated_at,
+                moving_average: 0,
+                sample_count: 0,
+                lookback_periods: 0,
+                pct_bps: 0,
+                min_floor: 0,
+                max_floor: 0,
+            });
+        }
+    };
+
+    let window: PriceWindow = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PriceWindow(product_type.clone(), region.clone()))
+        .ok_or(StabilizationError::PriceDataNotAvailable)?;
+    if window.samples.is_empty() {
+        return Err(StabilizationError::PriceDataNotAvailable);
+    }
+
+    let sample_count = window.samples.len();
+    let moving_average = window.sum / sample_count as i128;
+    let floor_price = (moving_average * config.pct_bps as i128 / MAX_BPS as i128)
+        .clamp(config.min_floor, config.max_floor);
+
+    Ok(FloorQuote {
+        mode: FloorMode::Dynamic,
+        floor_price,
+        updated_at: env.ledger().timestamp(),
+        moving_average,
+        sample_count,
+        lookback_periods: config.lookback_periods,
+        pct_bps: config.pct_bps,
+        min_floor: config.min_floor,
+        max_floor: config.max_floor,
+    })
+}
+
+#[contractimpl]
+impl DynamicFloor for PriceStabilizationContract {
+    #[allow(clippy::too_many_arguments)]
+    fn set_dynamic_floor(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        region: Symbol,
+        lookback_periods: u32,
+        pct_bps: u32,
+        min_floor: i128,
+        max_floor: i128,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        if lookback_periods == 0
+            || lookback_periods > MAX_LOOKBACK_PERIODS
+            || pct_bps == 0
+            || pct_bps > MAX_BPS
+            || min_floor <= 0
+            || max_floor < min_floor
+        {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        let config = DynamicFloorConfig {
+            lookback_periods,
+            pct_bps,
+            min_floor,
+            max_floor,
+        };
+        env.storage().persistent().set(
+            &DataKey::DynamicFloor(product_type.clone(), region.clone()),
+            &config,
+        );
+
+        // Prices reported before the switch still count towards the average
+        let window_key = DataKey::PriceWindow(product_type, region);
+        let window: Option<PriceWindow> = env.storage().persistent().get(&window_key);
+        if let Some(mut window) = window {
+            trim_window(&mut window, lookback_periods);
+            env.storage().persistent().set(&window_key, &window);
+        }
+
+        Ok(())
+    }
+}
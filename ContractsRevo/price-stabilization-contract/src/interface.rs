@@ -1,5 +1,8 @@
-use crate::datatype::StabilizationError;
-use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
+use crate::datatype::{
+    FloorQuote, FundParameters, PeriodReport, ReserveStatus, StabilizationError,
+    StabilizationPayment, VerificationMode, VerificationSource, WithdrawalRequest,
+};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Symbol, Vec};
 
 #[allow(dead_code)]
 pub trait FundManagement {
@@ -114,3 +117,165 @@ pub trait DistributionManagement {
         farmer: Address,
     ) -> Result<Vec<Map<String, i128>>, StabilizationError>;
 }
+
+#[allow(dead_code)]
+pub trait ReserveManagement {
+    /// Add capital to the pooled stabilization reserve
+    fn contribute_to_fund(
+        env: Env,
+        contributor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), StabilizationError>;
+
+    /// Set the guaranteed floor price for a product in a region, switching it to static mode
+    fn set_price_floor(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        region: Symbol,
+        floor_price: i128,
+    ) -> Result<(), StabilizationError>;
+
+    /// Allow an address to report regional market prices
+    fn register_market_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+    ) -> Result<(), StabilizationError>;
+
+    /// Record the current market price for a product in a region
+    fn report_market_price(
+        env: Env,
+        oracle: Address,
+        product_type: Symbol,
+        region: Symbol,
+        price: i128,
+    ) -> Result<(), StabilizationError>;
+
+    /// Limit how much a single farmer can receive per period
+    fn set_payment_cap(
+        env: Env,
+        admin: Address,
+        period_secs: u64,
+        max_per_farmer: i128,
+    ) -> Result<(), StabilizationError>;
+
+    /// Pay a farmer the gap between the floor and their sale price. When a verification
+    /// source is configured, unverifiable sales are recorded as pending instead.
+    #[allow(clippy::too_many_arguments)]
+    fn request_stabilization_payment(
+        env: Env,
+        farmer: Address,
+        product_type: Symbol,
+        region: Symbol,
+        quantity_sold: i128,
+        sale_price: i128,
+        evidence_hash: BytesN<32>,
+        sale_reference: Option<BytesN<32>>,
+    ) -> Result<u64, StabilizationError>;
+
+    /// Retrieve the pooled reserve balance and totals
+    fn get_reserve_status(env: Env) -> ReserveStatus;
+
+    /// Retrieve a contributor's total contribution to the reserve
+    fn get_reserve_contribution(env: Env, contributor: Address) -> i128;
+
+    /// Retrieve the floor in force for a product in a region, whether it is static or
+    /// dynamic, and the inputs it was derived from
+    fn get_floor(
+        env: Env,
+        product_type: Symbol,
+        region: Symbol,
+    ) -> Result<FloorQuote, StabilizationError>;
+
+    /// Retrieve a stabilization payment record
+    fn get_stabilization_payment(
+        env: Env,
+        payment_id: u64,
+    ) -> Result<StabilizationPayment, StabilizationError>;
+}
+
+#[allow(dead_code)]
+pub trait PaymentVerification {
+    /// Check sale claims against a supply-chain or commodity token contract
+    fn set_verification_source(
+        env: Env,
+        admin: Address,
+        source_contract: Address,
+        mode: VerificationMode,
+    ) -> Result<(), StabilizationError>;
+
+    /// Retrieve the configured verification source
+    fn get_verification_source(env: Env) -> Result<VerificationSource, StabilizationError>;
+
+    /// Pay out a pending claim the admin verified off-chain
+    fn approve_pending_payment(
+        env: Env,
+        admin: Address,
+        payment_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), StabilizationError>;
+
+    /// Decline a pending claim
+    fn reject_pending_payment(
+        env: Env,
+        admin: Address,
+        payment_id: u64,
+    ) -> Result<(), StabilizationError>;
+}
+
+#[allow(dead_code)]
+pub trait FundAccounting {
+    /// Update the accounting period, withdrawal window, disbursement limit and admin fee
+    fn set_fund_parameters(
+        env: Env,
+        admin: Address,
+        params: FundParameters,
+    ) -> Result<(), StabilizationError>;
+
+    /// Retrieve the current fund parameters
+    fn get_fund_parameters(env: Env) -> FundParameters;
+
+    /// Retrieve the contributions, disbursements, fees and withdrawals of a period
+    fn get_period_report(env: Env, period: u64) -> PeriodReport;
+
+    /// Retrieve the index of the current accounting period
+    fn get_current_period(env: Env) -> u64;
+
+    /// Queue a withdrawal of unused reserve capital
+    fn request_withdrawal(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<u64, StabilizationError>;
+
+    /// Execute a queued withdrawal during the withdrawal window
+    fn execute_withdrawal(
+        env: Env,
+        contributor: Address,
+        request_id: u64,
+    ) -> Result<(), StabilizationError>;
+
+    /// Retrieve a withdrawal request
+    fn get_withdrawal(env: Env, request_id: u64) -> Result<WithdrawalRequest, StabilizationError>;
+
+    /// Retrieve how much of the uncommitted reserve a contributor can withdraw
+    fn get_withdrawable_share(env: Env, contributor: Address) -> i128;
+}
+
+#[allow(dead_code)]
+pub trait DynamicFloor {
+    /// Derive the floor for a product in a region from the moving average of reported prices
+    #[allow(clippy::too_many_arguments)]
+    fn set_dynamic_floor(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        region: Symbol,
+        lookback_periods: u32,
+        pct_bps: u32,
+        min_floor: i128,
+        max_floor: i128,
+    ) -> Result<(), StabilizationError>;
+}
@@ -2,12 +2,16 @@
 
 use soroban_sdk::{contract, contractimpl, Address, Env};
 
+mod accounting;
 mod datatype;
 mod distribution;
+mod floor;
 mod fund;
 mod interface;
 mod pricing;
+mod reserve;
 mod utils;
+mod verification;
 
 use crate::datatype::DataKey;
 
@@ -0,0 +1,412 @@
+use crate::accounting::{
+    adjust_pending_committed, current_period, fund_parameters, issue_stake, load_period_report,
+    record_in_period,
+};
+use crate::datatype::{
+    DataKey, Farmer, FloorQuote, PaymentCap, PaymentStatus, PriceData, PriceFloor, ReserveStatus,
+    StabilizationError, StabilizationPayment,
+};
+use crate::floor::{effective_floor, record_price_sample};
+use crate::interface::ReserveManagement;
+use crate::verification::{verify_sale, SaleVerification};
+use crate::PriceStabilizationContractArgs;
+use crate::{PriceStabilizationContract, PriceStabilizationContractClient};
+use soroban_sdk::{contractimpl, token, Address, BytesN, Env, Symbol};
+
+pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), StabilizationError> {
+    admin.require_auth();
+
+    let stored_admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+    if stored_admin != Some(admin.clone()) {
+        return Err(StabilizationError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub(crate) fn load_reserve(env: &Env) -> ReserveStatus {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Reserve)
+        .unwrap_or(ReserveStatus {
+            token: None,
+            balance: 0,
+            total_contributed: 0,
+            total_disbursed: 0,
+            total_withdrawn: 0,
+            contributor_count: 0,
+        })
+}
+
+#[contractimpl]
+impl ReserveManagement for PriceStabilizationContract {
+    fn contribute_to_fund(
+        env: Env,
+        contributor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), StabilizationError> {
+        // Verify contributor authorization
+        contributor.require_auth();
+
+        if amount <= 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        // The first contribution fixes the reserve token
+        let mut reserve = load_reserve(&env);
+        match &reserve.token {
+            Some(reserve_token) if *reserve_token != token => {
+                return Err(StabilizationError::TokenMismatch);
+            }
+            Some(_) => {}
+            None => reserve.token = Some(token.clone()),
+        }
+
+        token::Client::new(&env, &token).transfer(
+            &contributor,
+            &env.current_contract_address(),
+            &amount,
+        );
+        issue_stake(&env, &contributor, amount, &reserve);
+
+        // Per-contributor accounting
+        let contribution_key = DataKey::ReserveContribution(contributor.clone());
+        let previous: i128 = env
+            .storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0);
+        if previous == 0 {
+            reserve.contributor_count += 1;
+        }
+        env.storage()
+            .persistent()
+            .set(&contribution_key, &(previous + amount));
+
+        reserve.balance += amount;
+        reserve.total_contributed += amount;
+        env.storage().persistent().set(&DataKey::Reserve, &reserve);
+        record_in_period(&env, |report| report.contributions += amount);
+
+        Ok(())
+    }
+
+    fn set_price_floor(
+        env: Env,
+        admin: Address,
+        product_type: Symbol,
+        region: Symbol,
+        floor_price: i128,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        if floor_price <= 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        let floor = PriceFloor {
+            floor_price,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(
+            &DataKey::PriceFloor(product_type.clone(), region.clone()),
+            &floor,
+        );
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DynamicFloor(product_type, region));
+
+        Ok(())
+    }
+
+    fn register_market_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MarketOracle(oracle), &true);
+
+        Ok(())
+    }
+
+    fn report_market_price(
+        env: Env,
+        oracle: Address,
+        product_type: Symbol,
+        region: Symbol,
+        price: i128,
+    ) -> Result<(), StabilizationError> {
+        // Verify oracle authorization
+        oracle.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::MarketOracle(oracle.clone()))
+        {
+            return Err(StabilizationError::OracleNotRegistered);
+        }
+
+        if price <= 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        record_price_sample(&env, &product_type, &region, price);
+
+        let price_data = PriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+            oracle,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::RegionalPrice(product_type, region), &price_data);
+
+        Ok(())
+    }
+
+    fn set_payment_cap(
+        env: Env,
+        admin: Address,
+        period_secs: u64,
+        max_per_farmer: i128,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        if period_secs == 0 || max_per_farmer <= 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        let cap = PaymentCap {
+            period_secs,
+            max_per_farmer,
+        };
+        env.storage().persistent().set(&DataKey::PaymentCap, &cap);
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn request_stabilization_payment(
+        env: Env,
+        farmer: Address,
+        product_type: Symbol,
+        region: Symbol,
+        quantity_sold: i128,
+        sale_price: i128,
+        evidence_hash: BytesN<32>,
+        sale_reference: Option<BytesN<32>>,
+    ) -> Result<u64, StabilizationError> {
+        // Verify farmer authorization
+        farmer.require_auth();
+
+        if quantity_sold <= 0 || sale_price < 0 {
+            return Err(StabilizationError::InvalidInput);
+        }
+
+        // Only registered, active farmers can claim
+        let farmer_record: Farmer = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Farmer(farmer.clone()))
+            .ok_or(StabilizationError::FarmerNotRegistered)?;
+        if !farmer_record.active {
+            return Err(StabilizationError::FarmerNotRegistered);
+        }
+
+        // Each piece of sale evidence can back a single payment
+        let evidence_key = DataKey::EvidenceUsed(evidence_hash.clone());
+        if env.storage().persistent().has(&evidence_key) {
+            return Err(StabilizationError::PayoutAlreadyProcessed);
+        }
+
+        let floor = effective_floor(&env, &product_type, &region)?;
+        let market: PriceData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegionalPrice(
+                product_type.clone(),
+                region.clone(),
+            ))
+            .ok_or(StabilizationError::PriceDataNotAvailable)?;
+
+        // The market as a whole has to be below the floor, not just this sale
+        if market.price >= floor.floor_price || sale_price >= floor.floor_price {
+            return Err(StabilizationError::NoPayoutNeeded);
+        }
+
+        let amount = (floor.floor_price - sale_price)
+            .checked_mul(quantity_sold)
+            .ok_or(StabilizationError::InvalidInput)?;
+
+        // Sales that cannot be verified against the configured source wait for the admin
+        let verification = verify_sale(&env, &farmer, quantity_sold, &sale_reference)?;
+
+        let payment_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PaymentCounter)
+            .unwrap_or(0)
+            + 1;
+        let mut payment = StabilizationPayment {
+            payment_id,
+            farmer,
+            product_type,
+            region,
+            quantity_sold,
+            sale_price,
+            floor_price: floor.floor_price,
+            market_price: market.price,
+            amount,
+            evidence_hash,
+            sale_reference: sale_reference.clone(),
+            status: PaymentStatus::Pending,
+            approval_evidence: None,
+            timestamp: env.ledger().timestamp(),
+        };
+        if verification == SaleVerification::Verified {
+            disburse(&env, &mut payment)?;
+        } else {
+            adjust_pending_committed(&env, amount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::StabilizationPayment(payment_id), &payment);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PaymentCounter, &payment_id);
+        env.storage().persistent().set(&evidence_key, &payment_id);
+        if let Some(reference) = sale_reference {
+            env.storage()
+                .persistent()
+                .set(&DataKey::SaleReferenceUsed(reference), &payment_id);
+        }
+
+        Ok(payment_id)
+    }
+
+    fn get_reserve_status(env: Env) -> ReserveStatus {
+        load_reserve(&env)
+    }
+
+    fn get_reserve_contribution(env: Env, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReserveContribution(contributor))
+            .unwrap_or(0)
+    }
+
+    fn get_floor(
+        env: Env,
+        product_type: Symbol,
+        region: Symbol,
+    ) -> Result<FloorQuote, StabilizationError> {
+        effective_floor(&env, &product_type, &region)
+    }
+
+    fn get_stabilization_payment(
+        env: Env,
+        payment_id: u64,
+    ) -> Result<StabilizationPayment, StabilizationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StabilizationPayment(payment_id))
+            .ok_or(StabilizationError::PaymentNotFound)
+    }
+}
+
+/// Pays out a claim from the reserve, clamped to what is left of the farmer's allowance for
+/// the current period, and marks it as paid. The admin fee is charged on top of the payment.
+pub(crate) fn disburse(
+    env: &Env,
+    payment: &mut StabilizationPayment,
+) -> Result<(), StabilizationError> {
+    let mut amount = payment.amount;
+
+    let now = env.ledger().timestamp();
+    let cap: Option<PaymentCap> = env.storage().persistent().get(&DataKey::PaymentCap);
+    let mut period_usage = None;
+    if let Some(cap) = cap {
+        let period_key = DataKey::FarmerPeriodPaid(payment.farmer.clone(), now / cap.period_secs);
+        let paid: i128 = env.storage().persistent().get(&period_key).unwrap_or(0);
+        let remaining = cap.max_per_farmer - paid;
+        if remaining <= 0 {
+            return Err(StabilizationError::PeriodCapExceeded);
+        }
+        amount = amount.min(remaining);
+        period_usage = Some((period_key, paid));
+    }
+
+    let params = fund_parameters(env);
+    let report = load_period_report(env, current_period(env, &params));
+    if params.max_disbursement_per_period > 0
+        && report.disbursements + amount > params.max_disbursement_per_period
+    {
+        return Err(StabilizationError::PeriodDisbursementLimit);
+    }
+    let fee = amount * params.admin_fee_bps as i128 / 10_000;
+
+    let mut reserve = load_reserve(env);
+    if reserve.balance < amount + fee {
+        return Err(StabilizationError::InsufficientFunds);
+    }
+    let reserve_token = reserve
+        .token
+        .clone()
+        .ok_or(StabilizationError::InsufficientFunds)?;
+
+    reserve.balance -= amount + fee;
+    reserve.total_disbursed += amount;
+    env.storage().persistent().set(&DataKey::Reserve, &reserve);
+    record_in_period(env, |report| {
+        report.disbursements += amount;
+        report.admin_fees += fee;
+    });
+
+    if let Some((period_key, paid)) = period_usage {
+        env.storage()
+            .persistent()
+            .set(&period_key, &(paid + amount));
+    }
+
+    let farmer_key = DataKey::Farmer(payment.farmer.clone());
+    let mut farmer_record: Farmer = env
+        .storage()
+        .persistent()
+        .get(&farmer_key)
+        .ok_or(StabilizationError::FarmerNotRegistered)?;
+    farmer_record.total_received_payouts = farmer_record
+        .total_received_payouts
+        .checked_add(amount)
+        .ok_or(StabilizationError::InvalidInput)?;
+    env.storage().persistent().set(&farmer_key, &farmer_record);
+
+    token::Client::new(env, &reserve_token).transfer(
+        &env.current_contract_address(),
+        &payment.farmer,
+        &amount,
+    );
+
+    if fee > 0 {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(StabilizationError::Unauthorized)?;
+        token::Client::new(env, &reserve_token).transfer(
+            &env.current_contract_address(),
+            &admin,
+            &fee,
+        );
+    }
+
+    payment.amount = amount;
+    payment.status = PaymentStatus::Paid;
+
+    Ok(())
+}
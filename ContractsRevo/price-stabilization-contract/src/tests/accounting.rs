@@ -0,0 +1,274 @@
+use super::reserve::{evidence, maize, region, setup_reserve, ReserveSetup};
+use super::utils::*;
+use crate::datatype::{FundParameters, StabilizationError, WithdrawalStatus};
+use soroban_sdk::{testutils::Address as _, token, Address};
+
+const PERIOD: u64 = 1_000;
+const WINDOW: u64 = 100;
+
+fn params(max_disbursement_per_period: i128, admin_fee_bps: u32) -> FundParameters {
+    FundParameters {
+        period_secs: PERIOD,
+        withdrawal_window_secs: WINDOW,
+        max_disbursement_per_period,
+        admin_fee_bps,
+    }
+}
+
+/// Reserve setup with short accounting periods and the market below the floor
+fn setup_accounting() -> ReserveSetup {
+    let setup = setup_reserve(0);
+    setup
+        .client
+        .set_fund_parameters(&setup.admin, &params(0, 0));
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+    setup
+}
+
+fn contribute(setup: &ReserveSetup, amount: i128) -> Address {
+    let contributor = Address::generate(&setup.env);
+    token::StellarAssetClient::new(&setup.env, &setup.token).mint(&contributor, &amount);
+    setup
+        .client
+        .contribute_to_fund(&contributor, &setup.token, &amount);
+    contributor
+}
+
+/// Verified claim paying (100 - 70) per unit
+fn claim(setup: &ReserveSetup, quantity: i128, seed: u8) -> u64 {
+    setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &quantity,
+        &70,
+        &evidence(&setup.env, seed),
+        &None,
+    )
+}
+
+fn balance(setup: &ReserveSetup, address: &Address) -> i128 {
+    token::Client::new(&setup.env, &setup.token).balance(address)
+}
+
+#[test]
+fn test_withdrawal_only_inside_window() {
+    let setup = setup_accounting();
+    let ngo = contribute(&setup, 1_000);
+
+    set_current_time(&setup.env, PERIOD + WINDOW);
+    let request_id = setup.client.request_withdrawal(&ngo, &400);
+    let result = setup.client.try_execute_withdrawal(&ngo, &request_id);
+    assert_eq!(result, Err(Ok(StabilizationError::OutsideWithdrawalWindow)));
+
+    // Still queued, and executable once the next window opens
+    set_current_time(&setup.env, 2 * PERIOD + 10);
+    setup.client.execute_withdrawal(&ngo, &request_id);
+
+    let request = setup.client.get_withdrawal(&request_id);
+    assert_eq!(request.status, WithdrawalStatus::Executed);
+    assert_eq!(request.executed_at, Some(2 * PERIOD + 10));
+    assert_eq!(balance(&setup, &ngo), 400);
+
+    let status = setup.client.get_reserve_status();
+    assert_eq!(status.balance, 600);
+    assert_eq!(status.total_withdrawn, 400);
+
+    let result = setup.client.try_execute_withdrawal(&ngo, &request_id);
+    assert_eq!(result, Err(Ok(StabilizationError::WithdrawalNotQueued)));
+}
+
+#[test]
+fn test_only_requester_executes_withdrawal() {
+    let setup = setup_accounting();
+    let ngo = contribute(&setup, 1_000);
+    let coop = contribute(&setup, 1_000);
+
+    let request_id = setup.client.request_withdrawal(&ngo, &100);
+    let result = setup.client.try_execute_withdrawal(&coop, &request_id);
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+
+    let result = setup.client.try_execute_withdrawal(&ngo, &99);
+    assert_eq!(result, Err(Ok(StabilizationError::WithdrawalNotFound)));
+}
+
+#[test]
+fn test_share_after_disbursements() {
+    let setup = setup_accounting();
+    let ngo = contribute(&setup, 6_000);
+    let coop = contribute(&setup, 4_000);
+
+    claim(&setup, 50, 1);
+    assert_eq!(setup.client.get_reserve_status().balance, 8_500);
+
+    // Disbursements are borne pro rata
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 5_100);
+    assert_eq!(setup.client.get_withdrawable_share(&coop), 3_400);
+
+    let too_much = setup.client.request_withdrawal(&coop, &3_401);
+    let result = setup.client.try_execute_withdrawal(&coop, &too_much);
+    assert_eq!(result, Err(Ok(StabilizationError::WithdrawalExceedsShare)));
+
+    let all = setup.client.request_withdrawal(&coop, &3_400);
+    setup.client.execute_withdrawal(&coop, &all);
+    assert_eq!(setup.client.get_withdrawable_share(&coop), 0);
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 5_100);
+
+    // Later contributors do not pick up earlier losses; stake issuance rounds down
+    let buyer = contribute(&setup, 1_000);
+    assert_eq!(setup.client.get_withdrawable_share(&buyer), 999);
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 5_100);
+}
+
+#[test]
+fn test_stake_in_an_emptied_reserve_is_written_off() {
+    let setup = setup_accounting();
+    let ngo = contribute(&setup, 1_500);
+
+    // A single claim pays out the whole reserve
+    claim(&setup, 50, 1);
+    assert_eq!(setup.client.get_reserve_status().balance, 0);
+
+    // The next contributor owns everything they put in
+    let coop = contribute(&setup, 1_000);
+    assert_eq!(setup.client.get_withdrawable_share(&coop), 1_000);
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 0);
+
+    // Later contributions are priced against the reserve value again
+    let buyer = contribute(&setup, 500);
+    assert_eq!(setup.client.get_withdrawable_share(&buyer), 500);
+    assert_eq!(setup.client.get_withdrawable_share(&coop), 1_000);
+
+    // The earlier contributor has nothing left to withdraw
+    let result = setup.client.try_request_withdrawal(&ngo, &1);
+    assert_eq!(result, Err(Ok(StabilizationError::WithdrawalExceedsShare)));
+}
+
+#[test]
+fn test_pending_claims_reduce_withdrawable_share() {
+    let setup = setup_accounting();
+    let ngo = contribute(&setup, 10_000);
+
+    let source_id = setup.env.register(super::verification::MockSupplyChain, ());
+    setup.client.set_verification_source(
+        &setup.admin,
+        &source_id,
+        &crate::datatype::VerificationMode::SupplyChain,
+    );
+    let payment_id = claim(&setup, 100, 1);
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 7_000);
+
+    setup
+        .client
+        .reject_pending_payment(&setup.admin, &payment_id);
+    assert_eq!(setup.client.get_withdrawable_share(&ngo), 10_000);
+}
+
+#[test]
+fn test_max_disbursement_per_period() {
+    let setup = setup_accounting();
+    contribute(&setup, 10_000);
+    setup
+        .client
+        .set_fund_parameters(&setup.admin, &params(2_000, 0));
+
+    claim(&setup, 50, 1);
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 2),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::PeriodDisbursementLimit)));
+
+    set_current_time(&setup.env, PERIOD);
+    claim(&setup, 50, 3);
+    assert_eq!(setup.client.get_reserve_status().total_disbursed, 3_000);
+}
+
+#[test]
+fn test_admin_fee_charged_on_disbursements() {
+    let setup = setup_accounting();
+    contribute(&setup, 10_000);
+    setup
+        .client
+        .set_fund_parameters(&setup.admin, &params(0, 200));
+
+    claim(&setup, 50, 1);
+
+    // 2% of the 1_500 payment goes to the admin on top
+    assert_eq!(balance(&setup, &setup.farmer), 1_500);
+    assert_eq!(balance(&setup, &setup.admin), 30);
+    assert_eq!(setup.client.get_reserve_status().balance, 8_470);
+}
+
+#[test]
+fn test_fund_parameters_validation() {
+    let setup = setup_accounting();
+    assert_eq!(setup.client.get_fund_parameters(), params(0, 0));
+
+    let result = setup
+        .client
+        .try_set_fund_parameters(&setup.farmer, &params(0, 100));
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+
+    let result = setup
+        .client
+        .try_set_fund_parameters(&setup.admin, &params(0, 10_001));
+    assert_eq!(result, Err(Ok(StabilizationError::InvalidInput)));
+
+    let mut window_too_long = params(0, 0);
+    window_too_long.withdrawal_window_secs = PERIOD + 1;
+    let result = setup
+        .client
+        .try_set_fund_parameters(&setup.admin, &window_too_long);
+    assert_eq!(result, Err(Ok(StabilizationError::InvalidInput)));
+}
+
+#[test]
+fn test_period_report_accuracy() {
+    let setup = setup_accounting();
+    setup
+        .client
+        .set_fund_parameters(&setup.admin, &params(0, 100));
+    let ngo = contribute(&setup, 6_000);
+    contribute(&setup, 4_000);
+    claim(&setup, 50, 1);
+    let request_id = setup.client.request_withdrawal(&ngo, &1_000);
+    setup.client.execute_withdrawal(&ngo, &request_id);
+
+    set_current_time(&setup.env, PERIOD + WINDOW);
+    assert_eq!(setup.client.get_current_period(), 1);
+    contribute(&setup, 500);
+    claim(&setup, 10, 2);
+
+    let first = setup.client.get_period_report(&0);
+    assert_eq!(first.contributions, 10_000);
+    assert_eq!(first.disbursements, 1_500);
+    assert_eq!(first.admin_fees, 15);
+    assert_eq!(first.withdrawals, 1_000);
+
+    let second = setup.client.get_period_report(&1);
+    assert_eq!(second.contributions, 500);
+    assert_eq!(second.disbursements, 300);
+    assert_eq!(second.admin_fees, 3);
+    assert_eq!(second.withdrawals, 0);
+
+    // Reports add up to the reserve balance
+    let status = setup.client.get_reserve_status();
+    assert_eq!(
+        status.balance,
+        first.contributions + second.contributions
+            - first.disbursements
+            - second.disbursements
+            - first.admin_fees
+            - second.admin_fees
+            - first.withdrawals
+    );
+    assert_eq!(setup.client.get_period_report(&7).contributions, 0);
+}
@@ -0,0 +1,183 @@
+use super::reserve::{evidence, maize, region, setup_reserve, ReserveSetup};
+use super::utils::*;
+use crate::datatype::{FloorMode, FundParameters, StabilizationError};
+use soroban_sdk::{symbol_short, token};
+
+const PERIOD: u64 = 1_000;
+
+/// Reserve setup with short accounting periods and a static floor of 100
+fn setup_floor() -> ReserveSetup {
+    let setup = setup_reserve(10_000);
+    setup.client.set_fund_parameters(
+        &setup.admin,
+        &FundParameters {
+            period_secs: PERIOD,
+            withdrawal_window_secs: 100,
+            max_disbursement_per_period: 0,
+            admin_fee_bps: 0,
+        },
+    );
+    setup
+}
+
+/// Reports one closing price per accounting period, starting at `first_period`
+fn report_series(setup: &ReserveSetup, first_period: u64, prices: &[i128]) {
+    for (offset, price) in prices.iter().enumerate() {
+        set_current_time(&setup.env, (first_period + offset as u64) * PERIOD);
+        setup
+            .client
+            .report_market_price(&setup.oracle, &maize(), &region(), price);
+    }
+}
+
+#[test]
+fn test_moving_average_over_price_series() {
+    let setup = setup_floor();
+    setup
+        .client
+        .set_dynamic_floor(&setup.admin, &maize(), &region(), &3, &9_000, &1, &1_000);
+
+    report_series(&setup, 0, &[100]);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.mode, FloorMode::Dynamic);
+    assert_eq!(quote.moving_average, 100);
+    assert_eq!(quote.sample_count, 1);
+    assert_eq!(quote.floor_price, 90);
+
+    // A later report in the same period replaces the period's closing price
+    report_series(&setup, 1, &[110]);
+    set_current_time(&setup.env, PERIOD + 500);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &120);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.moving_average, 110);
+    assert_eq!(quote.sample_count, 2);
+
+    // Only the last three periods count: (130 + 140 + 150) / 3
+    report_series(&setup, 2, &[130, 140, 150]);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.moving_average, 140);
+    assert_eq!(quote.sample_count, 3);
+    assert_eq!(quote.lookback_periods, 3);
+    assert_eq!(quote.pct_bps, 9_000);
+    assert_eq!(quote.floor_price, 126);
+}
+
+#[test]
+fn test_dynamic_floor_bounds_clamping() {
+    let setup = setup_floor();
+    setup
+        .client
+        .set_dynamic_floor(&setup.admin, &maize(), &region(), &2, &8_000, &95, &105);
+
+    report_series(&setup, 0, &[50, 60]);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.moving_average, 55);
+    assert_eq!(quote.floor_price, 95);
+
+    report_series(&setup, 2, &[200, 300]);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.moving_average, 250);
+    assert_eq!(quote.floor_price, 105);
+    assert_eq!(quote.min_floor, 95);
+    assert_eq!(quote.max_floor, 105);
+}
+
+#[test]
+fn test_switching_modes_mid_stream() {
+    let setup = setup_floor();
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.mode, FloorMode::Static);
+    assert_eq!(quote.floor_price, 100);
+    assert_eq!(quote.moving_average, 0);
+
+    // History reported under the static floor carries over into dynamic mode
+    report_series(&setup, 0, &[200, 220, 240, 260]);
+    setup
+        .client
+        .set_dynamic_floor(&setup.admin, &maize(), &region(), &2, &5_000, &1, &1_000);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.mode, FloorMode::Dynamic);
+    assert_eq!(quote.moving_average, 250);
+    assert_eq!(quote.floor_price, 125);
+
+    // Payments use the dynamic floor: market at 70, sale at 60
+    report_series(&setup, 4, &[70]);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.moving_average, 165);
+    assert_eq!(quote.floor_price, 82);
+    let payment_id = setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &10,
+        &60,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).amount,
+        220
+    );
+    assert_eq!(
+        token::Client::new(&setup.env, &setup.token).balance(&setup.farmer),
+        220
+    );
+
+    // Setting a static floor switches back
+    setup
+        .client
+        .set_price_floor(&setup.admin, &maize(), &region(), &150);
+    let quote = setup.client.get_floor(&maize(), &region());
+    assert_eq!(quote.mode, FloorMode::Static);
+    assert_eq!(quote.floor_price, 150);
+}
+
+#[test]
+fn test_dynamic_floor_requires_price_data() {
+    let setup = setup_floor();
+    let south = symbol_short!("south");
+    setup
+        .client
+        .set_dynamic_floor(&setup.admin, &maize(), &south, &3, &9_000, &1, &1_000);
+
+    let result = setup.client.try_get_floor(&maize(), &south);
+    assert_eq!(result, Err(Ok(StabilizationError::PriceDataNotAvailable)));
+}
+
+#[test]
+fn test_dynamic_floor_validation() {
+    let setup = setup_floor();
+
+    let result = setup.client.try_set_dynamic_floor(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &3,
+        &9_000,
+        &1,
+        &1_000,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+
+    for (lookback, pct_bps, min_floor, max_floor) in [
+        (0, 9_000, 1, 1_000),
+        (53, 9_000, 1, 1_000),
+        (3, 0, 1, 1_000),
+        (3, 10_001, 1, 1_000),
+        (3, 9_000, 0, 1_000),
+        (3, 9_000, 500, 400),
+    ] {
+        let result = setup.client.try_set_dynamic_floor(
+            &setup.admin,
+            &maize(),
+            &region(),
+            &lookback,
+            &pct_bps,
+            &min_floor,
+            &max_floor,
+        );
+        assert_eq!(result, Err(Ok(StabilizationError::InvalidInput)));
+    }
+}
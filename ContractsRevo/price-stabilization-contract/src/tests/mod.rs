@@ -1,4 +1,8 @@
+pub mod accounting;
 pub mod distribution;
+pub mod floor;
 pub mod fund;
 pub mod pricing;
+pub mod reserve;
 pub mod utils;
+pub mod verification;
@@ -0,0 +1,305 @@
+use super::utils::*;
+use crate::datatype::StabilizationError;
+use crate::PriceStabilizationContractClient;
+use soroban_sdk::{symbol_short, testutils::Address as _, token, Address, BytesN, Env, Symbol};
+
+const FLOOR: i128 = 100;
+
+pub struct ReserveSetup {
+    pub env: Env,
+    pub client: PriceStabilizationContractClient<'static>,
+    pub admin: Address,
+    pub farmer: Address,
+    pub oracle: Address,
+    pub token: Address,
+}
+
+pub fn maize() -> Symbol {
+    symbol_short!("maize")
+}
+
+pub fn region() -> Symbol {
+    symbol_short!("north")
+}
+
+pub fn evidence(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Initialized contract with a registered farmer and oracle, a floor of 100 for maize in the
+/// north and a reserve funded with `reserve` tokens
+pub fn setup_reserve(reserve: i128) -> ReserveSetup {
+    let (env, client, admin, farmer) = setup_test_environment();
+    client.init(&admin);
+    client.register_farmer(&admin, &farmer);
+
+    let oracle = create_test_oracle(&env);
+    client.register_market_oracle(&admin, &oracle);
+    client.set_price_floor(&admin, &maize(), &region(), &FLOOR);
+
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    if reserve > 0 {
+        let contributor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&contributor, &reserve);
+        client.contribute_to_fund(&contributor, &token, &reserve);
+    }
+
+    ReserveSetup {
+        env,
+        client,
+        admin,
+        farmer,
+        oracle,
+        token,
+    }
+}
+
+#[test]
+fn test_contributions_tracked_per_contributor() {
+    let setup = setup_reserve(0);
+    let ngo = Address::generate(&setup.env);
+    let coop = Address::generate(&setup.env);
+    let minter = token::StellarAssetClient::new(&setup.env, &setup.token);
+    minter.mint(&ngo, &1_000);
+    minter.mint(&coop, &500);
+
+    setup.client.contribute_to_fund(&ngo, &setup.token, &600);
+    setup.client.contribute_to_fund(&ngo, &setup.token, &400);
+    setup.client.contribute_to_fund(&coop, &setup.token, &500);
+
+    let status = setup.client.get_reserve_status();
+    assert_eq!(status.token, Some(setup.token.clone()));
+    assert_eq!(status.balance, 1_500);
+    assert_eq!(status.total_contributed, 1_500);
+    assert_eq!(status.contributor_count, 2);
+    assert_eq!(setup.client.get_reserve_contribution(&ngo), 1_000);
+    assert_eq!(setup.client.get_reserve_contribution(&coop), 500);
+    assert_eq!(
+        token::Client::new(&setup.env, &setup.token).balance(&setup.client.address),
+        1_500
+    );
+}
+
+#[test]
+fn test_contribution_in_other_token_rejected() {
+    let setup = setup_reserve(1_000);
+    let other = setup
+        .env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    token::StellarAssetClient::new(&setup.env, &other).mint(&setup.farmer, &100);
+
+    let result = setup
+        .client
+        .try_contribute_to_fund(&setup.farmer, &other, &100);
+    assert_eq!(result, Err(Ok(StabilizationError::TokenMismatch)));
+}
+
+#[test]
+fn test_payment_below_floor() {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+
+    let payment_id = setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+
+    // (100 - 70) per unit over 50 units
+    let payment = setup.client.get_stabilization_payment(&payment_id);
+    assert_eq!(payment.amount, 1_500);
+    assert_eq!(payment.market_price, 80);
+    assert_eq!(
+        token::Client::new(&setup.env, &setup.token).balance(&setup.farmer),
+        1_500
+    );
+
+    let status = setup.client.get_reserve_status();
+    assert_eq!(status.balance, 8_500);
+    assert_eq!(status.total_disbursed, 1_500);
+}
+
+#[test]
+fn test_no_payment_above_floor() {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &120);
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::NoPayoutNeeded)));
+}
+
+#[test]
+fn test_evidence_cannot_be_reused() {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+    setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &10,
+        &90,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &10,
+        &90,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::PayoutAlreadyProcessed)));
+}
+
+#[test]
+fn test_per_farmer_period_cap() {
+    let setup = setup_reserve(10_000);
+    setup.client.set_payment_cap(&setup.admin, &1_000, &500);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+
+    let first = setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &30,
+        &90,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(setup.client.get_stabilization_payment(&first).amount, 300);
+
+    // Only the remaining 200 of the allowance is paid
+    let second = setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &30,
+        &90,
+        &evidence(&setup.env, 2),
+        &None,
+    );
+    assert_eq!(setup.client.get_stabilization_payment(&second).amount, 200);
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &30,
+        &90,
+        &evidence(&setup.env, 3),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::PeriodCapExceeded)));
+
+    // The allowance resets in the next period
+    set_current_time(&setup.env, 1_000);
+    assert!(setup
+        .client
+        .try_request_stabilization_payment(
+            &setup.farmer,
+            &maize(),
+            &region(),
+            &30,
+            &90,
+            &evidence(&setup.env, 4),
+            &None,
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_fund_insolvency() {
+    let setup = setup_reserve(1_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &50);
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &100,
+        &50,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::InsufficientFunds)));
+    assert_eq!(setup.client.get_reserve_status().balance, 1_000);
+}
+
+#[test]
+fn test_unregistered_oracle_rejected() {
+    let setup = setup_reserve(0);
+    let impostor = create_test_oracle(&setup.env);
+
+    let result = setup
+        .client
+        .try_report_market_price(&impostor, &maize(), &region(), &80);
+    assert_eq!(result, Err(Ok(StabilizationError::OracleNotRegistered)));
+}
+
+#[test]
+fn test_unregistered_farmer_rejected() {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+    let stranger = create_test_farmer(&setup.env, 2);
+
+    let result = setup.client.try_request_stabilization_payment(
+        &stranger,
+        &maize(),
+        &region(),
+        &10,
+        &70,
+        &evidence(&setup.env, 1),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::FarmerNotRegistered)));
+}
+
+#[test]
+fn test_floor_requires_admin() {
+    let setup = setup_reserve(0);
+
+    let result = setup
+        .client
+        .try_set_price_floor(&setup.farmer, &maize(), &region(), &FLOOR);
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+    assert_eq!(
+        setup.client.get_floor(&maize(), &region()).floor_price,
+        FLOOR
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_get_floor(&maize(), &symbol_short!("south")),
+        Err(Ok(StabilizationError::FloorNotSet))
+    );
+}
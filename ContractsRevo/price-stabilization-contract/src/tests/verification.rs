@@ -0,0 +1,386 @@
+use super::reserve::{evidence, maize, region, setup_reserve, ReserveSetup};
+use super::utils::*;
+use crate::datatype::{PaymentStatus, StabilizationError, VerificationMode};
+use crate::verification::{
+    CommodityRedemption, SupplyChainCertificateId, SupplyChainProduct, SupplyChainStage,
+    SupplyChainStageTier,
+};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, testutils::Address as _, token, vec, Address, BytesN,
+    Env, String, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MockSourceError {
+    NotFound = 1,
+}
+
+#[contract]
+pub struct MockSupplyChain;
+
+#[contractimpl]
+impl MockSupplyChain {
+    pub fn add_product(
+        env: Env,
+        product_id: BytesN<32>,
+        farmer_id: Address,
+        tiers: Vec<SupplyChainStageTier>,
+    ) {
+        let mut stages = Vec::new(&env);
+        for (index, tier) in tiers.iter().enumerate() {
+            stages.push_back(SupplyChainStage {
+                stage_id: index as u32 + 1,
+                tier,
+                name: String::from_str(&env, "stage"),
+                timestamp: 0,
+                location: String::from_str(&env, "farm"),
+                data_hash: BytesN::from_array(&env, &[0; 32]),
+            });
+        }
+        let product = SupplyChainProduct {
+            product_id: product_id.clone(),
+            farmer_id,
+            stages,
+            certificate_id: SupplyChainCertificateId::None,
+        };
+        env.storage().persistent().set(&product_id, &product);
+    }
+
+    pub fn get_product_details(
+        env: Env,
+        product_id: BytesN<32>,
+    ) -> Result<SupplyChainProduct, MockSourceError> {
+        env.storage()
+            .persistent()
+            .get(&product_id)
+            .ok_or(MockSourceError::NotFound)
+    }
+}
+
+#[contract]
+pub struct MockCommodityToken;
+
+#[contractimpl]
+impl MockCommodityToken {
+    pub fn add_redemption(env: Env, redemption_id: BytesN<32>, redeemer: Address, quantity: u32) {
+        let redemption = CommodityRedemption {
+            redeemer,
+            commodity_type: String::from_str(&env, "maize"),
+            quantity,
+            redeemed_at: 0,
+        };
+        env.storage().persistent().set(&redemption_id, &redemption);
+    }
+
+    pub fn get_redemption(
+        env: Env,
+        redemption_id: BytesN<32>,
+    ) -> Result<CommodityRedemption, MockSourceError> {
+        env.storage()
+            .persistent()
+            .get(&redemption_id)
+            .ok_or(MockSourceError::NotFound)
+    }
+}
+
+fn reference(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Reserve setup with the market below the floor and sales checked against a mock
+/// supply-chain contract
+fn setup_supply_chain() -> (ReserveSetup, MockSupplyChainClient<'static>) {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+
+    let source_id = setup.env.register(MockSupplyChain, ());
+    setup
+        .client
+        .set_verification_source(&setup.admin, &source_id, &VerificationMode::SupplyChain);
+
+    let source = MockSupplyChainClient::new(&setup.env, &source_id);
+    (setup, source)
+}
+
+fn setup_commodity() -> (ReserveSetup, MockCommodityTokenClient<'static>) {
+    let setup = setup_reserve(10_000);
+    setup
+        .client
+        .report_market_price(&setup.oracle, &maize(), &region(), &80);
+
+    let source_id = setup.env.register(MockCommodityToken, ());
+    setup.client.set_verification_source(
+        &setup.admin,
+        &source_id,
+        &VerificationMode::CommodityRedemption,
+    );
+
+    let source = MockCommodityTokenClient::new(&setup.env, &source_id);
+    (setup, source)
+}
+
+fn request(setup: &ReserveSetup, seed: u8, sale_reference: Option<BytesN<32>>) -> u64 {
+    setup.client.request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, seed),
+        &sale_reference,
+    )
+}
+
+fn farmer_balance(setup: &ReserveSetup) -> i128 {
+    token::Client::new(&setup.env, &setup.token).balance(&setup.farmer)
+}
+
+#[test]
+fn test_verification_source_configuration() {
+    let setup = setup_reserve(0);
+    assert_eq!(
+        setup.client.try_get_verification_source(),
+        Err(Ok(StabilizationError::VerificationNotConfigured))
+    );
+
+    let source_id = setup.env.register(MockSupplyChain, ());
+    let result = setup.client.try_set_verification_source(
+        &setup.farmer,
+        &source_id,
+        &VerificationMode::SupplyChain,
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+
+    setup
+        .client
+        .set_verification_source(&setup.admin, &source_id, &VerificationMode::SupplyChain);
+    let source = setup.client.get_verification_source();
+    assert_eq!(source.source_contract, source_id);
+    assert_eq!(source.mode, VerificationMode::SupplyChain);
+}
+
+#[test]
+fn test_supply_chain_sale_auto_approved() {
+    let (setup, source) = setup_supply_chain();
+    let product_id = reference(&setup.env, 10);
+    source.add_product(
+        &product_id,
+        &setup.farmer,
+        &vec![
+            &setup.env,
+            SupplyChainStageTier::Harvesting,
+            SupplyChainStageTier::Distribution,
+        ],
+    );
+
+    let payment_id = request(&setup, 1, Some(product_id.clone()));
+
+    let payment = setup.client.get_stabilization_payment(&payment_id);
+    assert_eq!(payment.status, PaymentStatus::Paid);
+    assert_eq!(payment.sale_reference, Some(product_id));
+    assert_eq!(payment.amount, 1_500);
+    assert_eq!(farmer_balance(&setup), 1_500);
+}
+
+#[test]
+fn test_product_not_yet_sold_is_pending_until_approved() {
+    let (setup, source) = setup_supply_chain();
+    let product_id = reference(&setup.env, 10);
+    source.add_product(
+        &product_id,
+        &setup.farmer,
+        &vec![&setup.env, SupplyChainStageTier::Harvesting],
+    );
+
+    let payment_id = request(&setup, 1, Some(product_id));
+    let payment = setup.client.get_stabilization_payment(&payment_id);
+    assert_eq!(payment.status, PaymentStatus::Pending);
+    assert_eq!(farmer_balance(&setup), 0);
+    assert_eq!(setup.client.get_reserve_status().balance, 10_000);
+
+    let receipt = evidence(&setup.env, 42);
+    setup
+        .client
+        .approve_pending_payment(&setup.admin, &payment_id, &receipt);
+
+    let payment = setup.client.get_stabilization_payment(&payment_id);
+    assert_eq!(payment.status, PaymentStatus::Paid);
+    assert_eq!(payment.approval_evidence, Some(receipt));
+    assert_eq!(farmer_balance(&setup), 1_500);
+    assert_eq!(setup.client.get_reserve_status().total_disbursed, 1_500);
+}
+
+#[test]
+fn test_unknown_reference_is_pending_and_can_be_rejected() {
+    let (setup, _source) = setup_supply_chain();
+
+    let payment_id = request(&setup, 1, Some(reference(&setup.env, 99)));
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).status,
+        PaymentStatus::Pending
+    );
+
+    let result = setup
+        .client
+        .try_reject_pending_payment(&setup.farmer, &payment_id);
+    assert_eq!(result, Err(Ok(StabilizationError::Unauthorized)));
+
+    setup
+        .client
+        .reject_pending_payment(&setup.admin, &payment_id);
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).status,
+        PaymentStatus::Rejected
+    );
+
+    let result = setup.client.try_approve_pending_payment(
+        &setup.admin,
+        &payment_id,
+        &evidence(&setup.env, 42),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::PaymentNotPending)));
+    assert_eq!(farmer_balance(&setup), 0);
+}
+
+#[test]
+fn test_missing_reference_is_pending() {
+    let (setup, _source) = setup_supply_chain();
+
+    let payment_id = request(&setup, 1, None);
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).status,
+        PaymentStatus::Pending
+    );
+}
+
+#[test]
+fn test_spoofed_product_reference_rejected() {
+    let (setup, source) = setup_supply_chain();
+    let neighbour = Address::generate(&setup.env);
+    let product_id = reference(&setup.env, 10);
+    source.add_product(
+        &product_id,
+        &neighbour,
+        &vec![&setup.env, SupplyChainStageTier::Retail],
+    );
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 1),
+        &Some(product_id),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::VerificationFailed)));
+}
+
+#[test]
+fn test_reference_cannot_back_two_claims() {
+    let (setup, source) = setup_supply_chain();
+    let product_id = reference(&setup.env, 10);
+    source.add_product(
+        &product_id,
+        &setup.farmer,
+        &vec![&setup.env, SupplyChainStageTier::Retail],
+    );
+    request(&setup, 1, Some(product_id.clone()));
+
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 2),
+        &Some(product_id),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::PayoutAlreadyProcessed)));
+}
+
+#[test]
+fn test_commodity_redemption_verification() {
+    let (setup, source) = setup_commodity();
+    let redemption_id = reference(&setup.env, 20);
+    source.add_redemption(&redemption_id, &setup.farmer, &50);
+
+    let payment_id = request(&setup, 1, Some(redemption_id));
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).status,
+        PaymentStatus::Paid
+    );
+    assert_eq!(farmer_balance(&setup), 1_500);
+}
+
+#[test]
+fn test_spoofed_commodity_redemption_rejected() {
+    let (setup, source) = setup_commodity();
+    let neighbour = Address::generate(&setup.env);
+    let theirs = reference(&setup.env, 20);
+    let small = reference(&setup.env, 21);
+    source.add_redemption(&theirs, &neighbour, &50);
+    source.add_redemption(&small, &setup.farmer, &10);
+
+    // Someone else's redemption
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 1),
+        &Some(theirs),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::VerificationFailed)));
+
+    // More sold than was redeemed
+    let result = setup.client.try_request_stabilization_payment(
+        &setup.farmer,
+        &maize(),
+        &region(),
+        &50,
+        &70,
+        &evidence(&setup.env, 2),
+        &Some(small),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::VerificationFailed)));
+    assert_eq!(farmer_balance(&setup), 0);
+}
+
+#[test]
+fn test_approval_respects_reserve_balance() {
+    let (setup, _source) = setup_supply_chain();
+    let payment_id = request(&setup, 1, None);
+
+    // Another farmer's approved claim leaves too little for this one
+    let other = create_test_farmer(&setup.env, 2);
+    setup.client.register_farmer(&setup.admin, &other);
+    let large = setup.client.request_stabilization_payment(
+        &other,
+        &maize(),
+        &region(),
+        &300,
+        &70,
+        &evidence(&setup.env, 2),
+        &None,
+    );
+    setup
+        .client
+        .approve_pending_payment(&setup.admin, &large, &evidence(&setup.env, 43));
+    assert_eq!(setup.client.get_reserve_status().balance, 1_000);
+
+    let result = setup.client.try_approve_pending_payment(
+        &setup.admin,
+        &payment_id,
+        &evidence(&setup.env, 42),
+    );
+    assert_eq!(result, Err(Ok(StabilizationError::InsufficientFunds)));
+    assert_eq!(
+        setup.client.get_stabilization_payment(&payment_id).status,
+        PaymentStatus::Pending
+    );
+}
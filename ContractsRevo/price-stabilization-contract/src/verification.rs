@@ -0,0 +1,240 @@
+use crate::accounting::adjust_pending_committed;
+use crate::datatype::{
+    DataKey, PaymentStatus, StabilizationError, StabilizationPayment, VerificationMode,
+    VerificationSource,
+};
+use crate::interface::PaymentVerification;
+use crate::reserve::{disburse, require_admin};
+use crate::PriceStabilizationContractArgs;
+use crate::{PriceStabilizationContract, PriceStabilizationContractClient};
+use soroban_sdk::{contractclient, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+
+// Mirrors of the supply-chain tracking contract types returned by `get_product_details`.
+// Field names and variants must match for the values to decode.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SupplyChainCertificateId {
+    None,
+    Some(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupplyChainStageTier {
+    Planting = 1,
+    Cultivation = 2,
+    Harvesting = 3,
+    Processing = 4,
+    Packaging = 5,
+    Storage = 6,
+    Transportation = 7,
+    Distribution = 8,
+    Retail = 9,
+    Consumer = 10,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SupplyChainStage {
+    pub stage_id: u32,
+    pub tier: SupplyChainStageTier,
+    pub name: String,
+    pub timestamp: u64,
+    pub location: String,
+    pub data_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SupplyChainProduct {
+    pub product_id: BytesN<32>,
+    pub farmer_id: Address,
+    pub stages: Vec<SupplyChainStage>,
+    pub certificate_id: SupplyChainCertificateId,
+}
+
+/// Redemption record exposed by a commodity token contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommodityRedemption {
+    pub redeemer: Address,
+    pub commodity_type: String,
+    pub quantity: u32,
+    pub redeemed_at: u64,
+}
+
+// Minimal interface of the supply-chain tracking contract used to verify sales.
+#[allow(dead_code)]
+#[contractclient(name = "SupplyChainContractClient")]
+pub trait SupplyChainContract {
+    fn get_product_details(env: Env, product_id: BytesN<32>) -> SupplyChainProduct;
+}
+
+// Minimal interface of a commodity token contract used to verify sales.
+#[allow(dead_code)]
+#[contractclient(name = "CommodityTokenContractClient")]
+pub trait CommodityTokenContract {
+    fn get_redemption(env: Env, redemption_id: BytesN<32>) -> CommodityRedemption;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SaleVerification {
+    Verified,
+    /// Nothing on record backs the sale; the claim needs a manual decision
+    Unverifiable,
+}
+
+/// Checks a sale claim against the configured verification source. References that point at
+/// someone else's product or redemption are rejected outright.
+pub(crate) fn verify_sale(
+    env: &Env,
+    farmer: &Address,
+    quantity_sold: i128,
+    sale_reference: &Option<BytesN<32>>,
+) -> Result<SaleVerification, StabilizationError> {
+    // Without a source, sales are self-declared
+    let source: VerificationSource =
+        match env.storage().persistent().get(&DataKey::VerificationSource) {
+            Some(source) => source,
+            None => return Ok(SaleVerification::Verified),
+        };
+
+    let reference = match sale_reference {
+        Some(reference) => reference,
+        None => return Ok(SaleVerification::Unverifiable),
+    };
+
+    // A product or redemption can back a single claim
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::SaleReferenceUsed(reference.clone()))
+    {
+        return Err(StabilizationError::PayoutAlreadyProcessed);
+    }
+
+    match source.mode {
+        VerificationMode::SupplyChain => {
+            let client = SupplyChainContractClient::new(env, &source.source_contract);
+            let product = match client.try_get_product_details(reference) {
+                Ok(Ok(product)) => product,
+                _ => return Ok(SaleVerification::Unverifiable),
+            };
+
+            if product.farmer_id != *farmer {
+                return Err(StabilizationError::VerificationFailed);
+            }
+
+            let sold = product.stages.iter().any(|stage| {
+                matches!(
+                    stage.tier,
+                    SupplyChainStageTier::Distribution | SupplyChainStageTier::Retail
+                )
+            });
+            if sold {
+                Ok(SaleVerification::Verified)
+            } else {
+                Ok(SaleVerification::Unverifiable)
+            }
+        }
+        VerificationMode::CommodityRedemption => {
+            let client = CommodityTokenContractClient::new(env, &source.source_contract);
+            let redemption = match client.try_get_redemption(reference) {
+                Ok(Ok(redemption)) => redemption,
+                _ => return Ok(SaleVerification::Unverifiable),
+            };
+
+            // Claiming more than was redeemed is treated like a spoofed reference
+            if redemption.redeemer != *farmer || quantity_sold > redemption.quantity as i128 {
+                return Err(StabilizationError::VerificationFailed);
+            }
+
+            Ok(SaleVerification::Verified)
+        }
+    }
+}
+
+fn load_pending_payment(
+    env: &Env,
+    payment_id: u64,
+) -> Result<StabilizationPayment, StabilizationError> {
+    let payment: StabilizationPayment = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StabilizationPayment(payment_id))
+        .ok_or(StabilizationError::PaymentNotFound)?;
+
+    if payment.status != PaymentStatus::Pending {
+        return Err(StabilizationError::PaymentNotPending);
+    }
+    Ok(payment)
+}
+
+#[contractimpl]
+impl PaymentVerification for PriceStabilizationContract {
+    fn set_verification_source(
+        env: Env,
+        admin: Address,
+        source_contract: Address,
+        mode: VerificationMode,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        let source = VerificationSource {
+            source_contract,
+            mode,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::VerificationSource, &source);
+
+        Ok(())
+    }
+
+    fn get_verification_source(env: Env) -> Result<VerificationSource, StabilizationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VerificationSource)
+            .ok_or(StabilizationError::VerificationNotConfigured)
+    }
+
+    fn approve_pending_payment(
+        env: Env,
+        admin: Address,
+        payment_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        let mut payment = load_pending_payment(&env, payment_id)?;
+
+        // Caps and reserve balance apply as of the approval
+        adjust_pending_committed(&env, -payment.amount);
+        payment.approval_evidence = Some(evidence_hash);
+        disburse(&env, &mut payment)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::StabilizationPayment(payment_id), &payment);
+
+        Ok(())
+    }
+
+    fn reject_pending_payment(
+        env: Env,
+        admin: Address,
+        payment_id: u64,
+    ) -> Result<(), StabilizationError> {
+        require_admin(&env, &admin)?;
+
+        let mut payment = load_pending_payment(&env, payment_id)?;
+        adjust_pending_committed(&env, -payment.amount);
+        payment.status = PaymentStatus::Rejected;
+        env.storage()
+            .persistent()
+            .set(&DataKey::StabilizationPayment(payment_id), &payment);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Symbol, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
 #[contracterror]
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +21,22 @@ pub enum AuctionError {
     NoBidsPlaced = 9,
     ProductNotFound = 10,
     OutOfStock = 11,
+    AuctionAlreadySettled = 12,
+    ProductDelisted = 13,
+    InvalidAntiSnipingConfig = 14,
+    AuctionHasBids = 15,
+    Unauthorized = 16,
+    SellerNotVerified = 17,
+    PaymentTokenNotSet = 18,
+    BidEscrowed = 19,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AntiSnipingConfig {
+    pub extension_window_secs: u64,
+    pub extension_secs: u64,
+    pub max_total_extension: u64,
 }
 
 #[contracttype]
@@ -32,21 +48,254 @@ pub struct Auction {
     pub reserve_price: u64,
     pub auction_end_time: u64,
     pub seller: Address,
+    pub escrow_status: EscrowStatus,
+    pub settled_at: u64,
+    pub tracking_number: Option<String>,
+    pub total_extension: u64,
+    pub extensions: Vec<AuctionExtension>,
+    pub deposit: u64,               // Bid funds held from the highest bidder
+    pub runner_up: Option<Address>, // Bidder the highest bidder outbid
+    pub runner_up_bid: u64,
+    pub settlement_deadline: u64, // Zero when no forfeiture schedule applies
+    pub forfeitures: Vec<ForfeitureRecord>,
+    pub runner_up_offers: Vec<RunnerUpOffer>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuctionExtension {
+    pub bid_time: u64,
+    pub new_end_time: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum EscrowStatus {
+    None,
+    Held,
+    Released,
+    Refunded,
+    Forfeited, // The winner cancelled or missed the settlement deadline
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForfeitureTier {
+    pub within_secs: u64, // Applies to cancellations this soon after the auction ends
+    pub forfeit_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForfeitureSchedule {
+    pub deposit_bps: u32, // Share of the winning bid at risk of forfeiture
+    pub settlement_window_secs: u64,
+    pub tiers: Vec<ForfeitureTier>, // Ascending by within_secs
+    pub late_forfeit_bps: u32,      // Past every tier, and when the deadline lapses
+    pub runner_up_window_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForfeitureReason {
+    Cancelled,
+    DeadlineMissed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForfeitureRecord {
+    pub bidder: Address,
+    pub bid: u64,
+    pub deposit: u64,
+    pub forfeited: u64, // Paid to the seller; the rest of the deposit is refunded
+    pub reason: ForfeitureReason,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunnerUpOfferStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Expired,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunnerUpOffer {
+    pub bidder: Address,
+    pub price: u64,
+    pub offered_at: u64,
+    pub expires_at: u64,
+    pub status: RunnerUpOfferStatus,
+}
+
+#[contracterror]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForfeitureError {
+    Unauthorized = 1,
+    InvalidSchedule = 2,
+    NoForfeitureSchedule = 3,
+    AuctionNotFound = 4,
+    AuctionNotYetEnded = 5,
+    AlreadySettled = 6,
+    SettlementOpen = 7,
+    NoBidsPlaced = 8,
+    OfferAlreadyMade = 9,
+    NoPendingOffer = 10,
+    OfferExpired = 11,
+    PaymentTokenNotSet = 12,
+    OutOfStock = 13,
+}
+
+#[contracterror]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EscrowError {
+    PaymentTokenNotSet = 1,
+    Unauthorized = 2,
+    AuctionNotFound = 3,
+    AuctionNotYetEnded = 4,
+    NoBidsPlaced = 5,
+    AlreadySettled = 6,
+    NotEscrowed = 7,
+    RefundLocked = 8,
+    ShipmentMismatch = 9,
+    OutOfStock = 10,
+    BidNotFunded = 11,
+}
+
+#[contracterror]
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantityAuctionError {
+    PaymentTokenNotSet = 1,
+    AuctionAlreadyExists = 2,
+    AuctionNotFound = 3,
+    ProductNotFound = 4,
+    ProductDelisted = 5,
+    SellerNotVerified = 6,
+    InvalidUnits = 7,
+    InvalidAuctionEndTime = 8,
+    AuctionEnded = 9,
+    AuctionNotYetEnded = 10,
+    BidBelowReserve = 11,
+    InvalidQuantity = 12,
+    TooManyBids = 13,
+    AlreadyFinalized = 14,
+    InvalidBidder = 15,
+    FinalizeWindowOpen = 16,
+    NoBidsToReclaim = 17,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PricingMode {
+    Uniform, // Every winner pays the lowest accepted unit price
+    PayAsBid,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantityBid {
+    pub bidder: Address,
+    pub unit_price: u64,
+    pub quantity: u32,
+    pub placed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantityAuction {
+    pub product_id: u64,
+    pub seller: Address,
+    pub total_units: u32,
+    pub reserve_unit_price: u64,
+    pub auction_end_time: u64,
+    pub pricing_mode: PricingMode,
+    pub bids: Vec<QuantityBid>, // Sorted by unit price, best first
+    pub finalized: bool,
+    pub clearing_price: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Allocation {
+    pub bidder: Address,
+    pub units: u32,
+    pub unit_price: u64,
+    pub paid: u64,
+    pub refunded: u64,
+}
+
+// Maximum number of distinct bids a quantity auction accepts
+pub const MAX_QUANTITY_BIDS: u32 = 50;
+
+// Days a seller has to finalize an ended quantity auction before bidders may reclaim their escrow
+pub const FINALIZE_BY_DAYS: u64 = 7;
+
+// Maximum number of tiers in a forfeiture schedule
+pub const MAX_FORFEITURE_TIERS: u32 = 10;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Days a seller has to attach a shipment to a settled auction before the buyer may claim a refund
+pub const SHIP_BY_DAYS: u64 = 5;
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKeys {
     Admin,
-    Auction(Address, u64),          // Sellers Created Auctions
-    ProductList(Address),           // ProductList of Seller
-    Product(Address, u64),          // Product related to Seller
-    ShipmentList(Address),          // ShipmentList of Seller
-    Shipment(Address, String),      // Shipment related to Seller
-    SellerVerification(Address),    // Seller Verification
-    Dispute(Address, Address, u64), // Dispute related to Buyer and Seller and Product_id
-    ReturnPolicy(Address),          // Return Policy of Seller,
-    ReturnRequest(Address, u64),    // Return Request related to Seller
+    PaymentToken,                        // Token used to escrow winning bids
+    AntiSniping,                         // Contract-wide anti-sniping rule
+    ForfeitureSchedule,                  // Contract-wide bid deposit and forfeiture rule
+    AuctionForfeiture(Address, u64),     // Forfeiture rule an Auction was created under
+    AuctionAntiSniping(Address, u64),    // Anti-sniping rule of a single Auction
+    QuantityAuction(Address, u64),       // Sellers Created multi-unit Auctions
+    QuantityAllocation(Address, u64),    // Final allocation of a multi-unit Auction
+    Auction(Address, u64),               // Sellers Created Auctions
+    ProductList(Address),                // ProductList of Seller
+    Product(Address, u64),               // Product related to Seller
+    ShipmentList(Address),               // ShipmentList of Seller
+    Shipment(Address, String),           // Shipment related to Seller
+    SellerVerification(Address),         // Seller Verification
+    Dispute(Address, Address, u64),      // Dispute related to Buyer and Seller and Product_id
+    ReturnPolicy(Address),               // Return Policy of Seller,
+    ReturnRequest(Address, u64),         // Return Request related to Seller
+    ShipmentMilestones(Address, String), // Milestone history of a Seller's Shipment
+    SellerKyc(Address),                  // KYC document hash submitted by a Seller
+    Certifier(Address),                  // Approved product claim Certifier
+    BuyerPurchaseCount(Address),         // Number of purchases won by a Buyer
+    BuyerPurchase(Address, u32),         // Purchase of a Buyer by history index
+    SellerStats(Address),                // Aggregated sales of a Seller
+    LoyaltyProgram,                      // Loyalty program credited for purchases
+    EscrowedPurchase(Address, u64),      // Buyer history index of an Auction's escrowed purchase
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyConfig {
+    pub loyalty_contract: Address,
+    pub program_id: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurchaseRecord {
+    pub seller: Address,
+    pub product_id: u64,
+    pub price: u64,
+    pub timestamp: u64,
+    pub tracking_number: Option<String>,
+    pub refunded: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SellerStats {
+    pub sales_count: u32,
+    pub gross_volume: u64,
+    pub disputed_count: u32,
 }
 
 #[contracterror]
@@ -60,6 +309,8 @@ pub enum ProductError {
     ProductNotFound = 6,
     Unauthorized = 7,
     ReturnPolicyNotFound = 8,
+    ActiveAuction = 9,
+    ProductDelisted = 10,
 }
 
 #[contracttype]
@@ -85,6 +336,17 @@ pub struct Product {
     pub images: Vec<String>,
     pub weight_pounds: u64,
     pub verified: bool,
+    pub delisted: bool,
+    pub claims: Vec<ProductClaim>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductClaim {
+    pub claim: Symbol,
+    pub claim_hash: BytesN<32>,
+    pub certifier: Address,
+    pub attested_at: u64,
 }
 
 #[contracttype]
@@ -98,10 +360,35 @@ pub struct Shipment {
     pub delivery_estimate_days: u32,
     pub status: Symbol,
     pub tracking_number: String,
+    pub shipment_status: ShipmentStatus,
+    pub created_at: u64,
+    pub delivery_deadline: u64,
+    pub receipt_confirmed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShipmentStatus {
+    Preparing,
+    InTransit,
+    Delivered,
+    Failed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShipmentMilestone {
+    pub status: ShipmentStatus,
+    pub location: String,
+    pub proof_hash: BytesN<32>,
+    pub timestamp: u64,
 }
 
 pub const COST_PER_POUND: u64 = 6;
 pub const COST_PER_KM: u64 = 1;
+// Extra days granted on top of the delivery estimate before a shipment is overdue
+pub const DELIVERY_GRACE_DAYS: u64 = 2;
+pub const SECONDS_PER_DAY: u64 = 86_400;
 
 #[contracterror]
 #[derive(Debug, Clone, PartialEq)]
@@ -110,6 +397,10 @@ pub enum ShippingError {
     ShipmentNotFound = 2,
     ShipmentAlreadyExists = 3,
     InvalidBuyerZone = 4,
+    InvalidStatusTransition = 5,
+    Unauthorized = 6,
+    DeadlineNotReached = 7,
+    ShipmentFinalized = 8,
 }
 
 #[contracttype]
@@ -133,7 +424,7 @@ pub struct ReturnRequest {
 }
 
 #[contracterror]
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum VerificationError {
     ProductNotFound = 1,
     AlreadyRequested = 2,
@@ -143,6 +434,8 @@ pub enum VerificationError {
     ReturnAlreadyRequested = 6,
     RestrictedLocation = 7,
     ReturnRequestNotFound = 8,
+    Unauthorized = 9,
+    InvalidStatus = 10,
 }
 
 #[contracttype]
@@ -151,6 +444,7 @@ pub enum SellerVerificationStatus {
     Rejected,
     Verified,
     Pending,
+    Revoked,
 }
 
 #[contracttype]
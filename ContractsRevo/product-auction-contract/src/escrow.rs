@@ -0,0 +1,268 @@
+use soroban_sdk::{contractimpl, token, Address, Env, String};
+
+use crate::{
+    datatype::{
+        Auction, DataKeys, EscrowError, EscrowStatus, Product, Shipment, ShipmentStatus,
+        SECONDS_PER_DAY, SHIP_BY_DAYS,
+    },
+    history::{record_purchase, record_refund, record_tracking_number},
+    interfaces::EscrowOperations,
+    loyalty::{drop_purchase_points, hold_purchase_points, release_purchase_points},
+    ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
+};
+
+#[contractimpl]
+impl EscrowOperations for ProductAuctionContract {
+    fn initialize_payment_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), EscrowError> {
+        admin.require_auth();
+
+        if !is_admin(&env, &admin) {
+            return Err(EscrowError::Unauthorized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKeys::PaymentToken, &token);
+
+        env.events().publish(("PaymentTokenSet", admin), token);
+
+        Ok(())
+    }
+
+    fn settle_auction(env: Env, seller: Address, product_id: u64) -> Result<(), EscrowError> {
+        seller.require_auth();
+
+        get_payment_token(&env)?;
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+
+        let current_time = env.ledger().timestamp();
+        if auction.auction_end_time > current_time {
+            return Err(EscrowError::AuctionNotYetEnded);
+        }
+
+        if auction.escrow_status != EscrowStatus::None {
+            return Err(EscrowError::AlreadySettled);
+        }
+
+        let winner = auction
+            .highest_bidder
+            .clone()
+            .ok_or(EscrowError::NoBidsPlaced)?;
+
+        let product_key = DataKeys::Product(seller.clone(), product_id);
+        let mut product: Product = env
+            .storage()
+            .persistent()
+            .get(&product_key)
+            .ok_or(EscrowError::OutOfStock)?;
+
+        if product.stock == 0 {
+            return Err(EscrowError::OutOfStock);
+        }
+
+        // The winning bid was escrowed when it was placed; bids taken before the payment token
+        // was set hold nothing and can only be finalized outside escrow
+        if auction.deposit < auction.highest_bid {
+            return Err(EscrowError::BidNotFunded);
+        }
+
+        product.stock -= 1;
+        env.storage().persistent().set(&product_key, &product);
+
+        // The auction stays in storage so the escrow can be released or refunded later
+        auction.escrow_status = EscrowStatus::Held;
+        auction.settled_at = current_time;
+        auction.deposit = 0;
+        env.storage().instance().set(&auction_key, &auction);
+
+        let purchase_index =
+            record_purchase(&env, &seller, &winner, product_id, auction.highest_bid);
+        hold_purchase_points(&env, &seller, product_id, purchase_index);
+
+        env.events().publish(
+            (seller, "AuctionSettled", product_id),
+            &(winner, auction.highest_bid),
+        );
+
+        Ok(())
+    }
+
+    fn attach_shipment(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        tracking_number: String,
+    ) -> Result<(), EscrowError> {
+        seller.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+
+        if auction.escrow_status != EscrowStatus::Held {
+            return Err(EscrowError::NotEscrowed);
+        }
+
+        let shipment: Shipment = env
+            .storage()
+            .persistent()
+            .get(&DataKeys::Shipment(seller.clone(), tracking_number.clone()))
+            .ok_or(EscrowError::ShipmentMismatch)?;
+
+        if auction.tracking_number.is_some()
+            || auction.highest_bidder != Some(shipment.buyer.clone())
+        {
+            return Err(EscrowError::ShipmentMismatch);
+        }
+
+        auction.tracking_number = Some(tracking_number.clone());
+        env.storage().instance().set(&auction_key, &auction);
+
+        record_tracking_number(&env, &seller, &shipment.buyer, product_id, &tracking_number);
+
+        env.events()
+            .publish((seller, "ShipmentAttached", product_id), tracking_number);
+
+        Ok(())
+    }
+
+    fn release_escrow(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), EscrowError> {
+        buyer.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+
+        if auction.highest_bidder != Some(buyer.clone()) {
+            return Err(EscrowError::Unauthorized);
+        }
+
+        if auction.escrow_status != EscrowStatus::Held {
+            return Err(EscrowError::NotEscrowed);
+        }
+
+        let token = get_payment_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &seller,
+            &(auction.highest_bid as i128),
+        );
+
+        auction.escrow_status = EscrowStatus::Released;
+        env.storage().instance().set(&auction_key, &auction);
+
+        release_purchase_points(&env, &seller, product_id, &buyer, auction.highest_bid);
+
+        env.events().publish(
+            (seller, "EscrowReleased", product_id),
+            &(buyer, auction.highest_bid),
+        );
+
+        Ok(())
+    }
+
+    fn refund_escrow(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), EscrowError> {
+        caller.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+
+        if auction.escrow_status != EscrowStatus::Held {
+            return Err(EscrowError::NotEscrowed);
+        }
+
+        let buyer = auction
+            .highest_bidder
+            .clone()
+            .ok_or(EscrowError::NoBidsPlaced)?;
+
+        // The admin may refund at any time to resolve a dispute; the buyer only once delivery has failed
+        if !is_admin(&env, &caller) {
+            if caller != buyer {
+                return Err(EscrowError::Unauthorized);
+            }
+
+            if !delivery_failed(&env, &seller, &auction) {
+                return Err(EscrowError::RefundLocked);
+            }
+        }
+
+        let token = get_payment_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &buyer,
+            &(auction.highest_bid as i128),
+        );
+
+        auction.escrow_status = EscrowStatus::Refunded;
+        env.storage().instance().set(&auction_key, &auction);
+
+        record_refund(&env, &seller, &buyer, product_id);
+        drop_purchase_points(&env, &seller, product_id);
+
+        env.events().publish(
+            (seller, "EscrowRefunded", product_id),
+            &(buyer, auction.highest_bid),
+        );
+
+        Ok(())
+    }
+}
+
+/// Returns true once the seller has missed the shipping or delivery window for a settled auction.
+fn delivery_failed(env: &Env, seller: &Address, auction: &Auction) -> bool {
+    let current_time = env.ledger().timestamp();
+
+    match &auction.tracking_number {
+        Some(tracking_number) => {
+            let shipment: Option<Shipment> = env
+                .storage()
+                .persistent()
+                .get(&DataKeys::Shipment(seller.clone(), tracking_number.clone()));
+
+            match shipment {
+                Some(shipment) => {
+                    shipment.shipment_status == ShipmentStatus::Failed
+                        || (!shipment.receipt_confirmed
+                            && current_time > shipment.delivery_deadline)
+                }
+                None => true,
+            }
+        }
+        None => current_time > auction.settled_at + SHIP_BY_DAYS * SECONDS_PER_DAY,
+    }
+}
+
+fn get_auction(env: &Env, key: &DataKeys) -> Result<Auction, EscrowError> {
+    env.storage()
+        .instance()
+        .get(key)
+        .ok_or(EscrowError::AuctionNotFound)
+}
+
+fn get_payment_token(env: &Env) -> Result<Address, EscrowError> {
+    env.storage()
+        .instance()
+        .get(&DataKeys::PaymentToken)
+        .ok_or(EscrowError::PaymentTokenNotSet)
+}
+
+fn is_admin(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, Address>(&DataKeys::Admin)
+        .is_some_and(|admin| admin == *address)
+}
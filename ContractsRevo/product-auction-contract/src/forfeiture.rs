@@ -0,0 +1,397 @@
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::{
+    datatype::{
+        Auction, DataKeys, EscrowStatus, ForfeitureError, ForfeitureReason, ForfeitureRecord,
+        ForfeitureSchedule, Product, RunnerUpOffer, RunnerUpOfferStatus, BPS_DENOMINATOR,
+        MAX_FORFEITURE_TIERS,
+    },
+    history::record_purchase,
+    interfaces::ForfeitureOperations,
+    loyalty::hold_purchase_points,
+    ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
+};
+
+#[contractimpl]
+impl ForfeitureOperations for ProductAuctionContract {
+    fn set_forfeiture_schedule(
+        env: Env,
+        admin: Address,
+        schedule: Option<ForfeitureSchedule>,
+    ) -> Result<(), ForfeitureError> {
+        admin.require_auth();
+
+        if !is_admin(&env, &admin) {
+            return Err(ForfeitureError::Unauthorized);
+        }
+
+        match schedule {
+            Some(schedule) => {
+                if !is_valid_schedule(&schedule) {
+                    return Err(ForfeitureError::InvalidSchedule);
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKeys::ForfeitureSchedule, &schedule);
+            }
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKeys::ForfeitureSchedule),
+        }
+
+        Ok(())
+    }
+
+    fn get_forfeiture_schedule(env: Env) -> Option<ForfeitureSchedule> {
+        env.storage().instance().get(&DataKeys::ForfeitureSchedule)
+    }
+
+    fn cancel_purchase(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<u64, ForfeitureError> {
+        buyer.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+        let schedule = auction_schedule(&env, &seller, product_id)
+            .ok_or(ForfeitureError::NoForfeitureSchedule)?;
+
+        if auction.highest_bidder != Some(buyer.clone()) {
+            return Err(ForfeitureError::Unauthorized);
+        }
+
+        let current_time = env.ledger().timestamp();
+        if auction.auction_end_time > current_time {
+            return Err(ForfeitureError::AuctionNotYetEnded);
+        }
+
+        if auction.escrow_status != EscrowStatus::None {
+            return Err(ForfeitureError::AlreadySettled);
+        }
+
+        // The earliest tier the cancellation falls in sets the penalty
+        let elapsed = current_time - auction.auction_end_time;
+        let forfeit_bps = if current_time > auction.settlement_deadline {
+            schedule.late_forfeit_bps
+        } else {
+            schedule
+                .tiers
+                .iter()
+                .find(|tier| elapsed <= tier.within_secs)
+                .map_or(schedule.late_forfeit_bps, |tier| tier.forfeit_bps)
+        };
+
+        let forfeited = forfeit_deposit(
+            &env,
+            &mut auction,
+            buyer,
+            schedule.deposit_bps,
+            forfeit_bps,
+            ForfeitureReason::Cancelled,
+        )?;
+        env.storage().instance().set(&auction_key, &auction);
+
+        Ok(forfeited)
+    }
+
+    fn offer_to_runner_up(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Option<RunnerUpOffer>, ForfeitureError> {
+        seller.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+        let schedule = auction_schedule(&env, &seller, product_id)
+            .ok_or(ForfeitureError::NoForfeitureSchedule)?;
+        let current_time = env.ledger().timestamp();
+
+        // A winner who let the deadline pass forfeits at the late rate before the product moves on
+        match auction.escrow_status {
+            EscrowStatus::None => {
+                if current_time <= auction.settlement_deadline {
+                    return Err(ForfeitureError::SettlementOpen);
+                }
+
+                let winner = auction
+                    .highest_bidder
+                    .clone()
+                    .ok_or(ForfeitureError::NoBidsPlaced)?;
+                forfeit_deposit(
+                    &env,
+                    &mut auction,
+                    winner,
+                    schedule.deposit_bps,
+                    schedule.late_forfeit_bps,
+                    ForfeitureReason::DeadlineMissed,
+                )?;
+            }
+            EscrowStatus::Forfeited => {
+                if !auction.runner_up_offers.is_empty() {
+                    return Err(ForfeitureError::OfferAlreadyMade);
+                }
+            }
+            _ => return Err(ForfeitureError::AlreadySettled),
+        }
+
+        // Without a runner-up the product goes straight back to the seller
+        let Some(runner_up) = auction.runner_up.clone() else {
+            env.storage().instance().set(&auction_key, &auction);
+            return Ok(None);
+        };
+        let offer = RunnerUpOffer {
+            bidder: runner_up.clone(),
+            price: auction.runner_up_bid,
+            offered_at: current_time,
+            expires_at: current_time + schedule.runner_up_window_secs,
+            status: RunnerUpOfferStatus::Pending,
+        };
+        auction.runner_up_offers.push_back(offer.clone());
+        env.storage().instance().set(&auction_key, &auction);
+
+        env.events().publish(
+            (seller, "RunnerUpOffered", product_id),
+            &(runner_up, offer.price, offer.expires_at),
+        );
+
+        Ok(Some(offer))
+    }
+
+    fn accept_runner_up_offer(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), ForfeitureError> {
+        bidder.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+        let (index, mut offer) = pending_offer(&auction)?;
+
+        if offer.bidder != bidder {
+            return Err(ForfeitureError::Unauthorized);
+        }
+
+        let current_time = env.ledger().timestamp();
+        if current_time > offer.expires_at {
+            return Err(ForfeitureError::OfferExpired);
+        }
+
+        let product_key = DataKeys::Product(seller.clone(), product_id);
+        let mut product: Product = env
+            .storage()
+            .persistent()
+            .get(&product_key)
+            .ok_or(ForfeitureError::OutOfStock)?;
+
+        if product.stock == 0 {
+            return Err(ForfeitureError::OutOfStock);
+        }
+
+        // The runner-up's bid was refunded when they were outbid, so the full price is escrowed
+        let token = get_payment_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &bidder,
+            &env.current_contract_address(),
+            &(offer.price as i128),
+        );
+
+        product.stock -= 1;
+        env.storage().persistent().set(&product_key, &product);
+
+        offer.status = RunnerUpOfferStatus::Accepted;
+        auction.runner_up_offers.set(index, offer.clone());
+        auction.highest_bidder = Some(bidder.clone());
+        auction.highest_bid = offer.price;
+        auction.runner_up = None;
+        auction.runner_up_bid = 0;
+        auction.escrow_status = EscrowStatus::Held;
+        auction.settled_at = current_time;
+        env.storage().instance().set(&auction_key, &auction);
+
+        let purchase_index = record_purchase(&env, &seller, &bidder, product_id, offer.price);
+        hold_purchase_points(&env, &seller, product_id, purchase_index);
+
+        env.events().publish(
+            (seller, "RunnerUpAccepted", product_id),
+            &(bidder, offer.price),
+        );
+
+        Ok(())
+    }
+
+    fn decline_runner_up_offer(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), ForfeitureError> {
+        caller.require_auth();
+
+        let auction_key = DataKeys::Auction(seller.clone(), product_id);
+        let mut auction = get_auction(&env, &auction_key)?;
+        let (index, mut offer) = pending_offer(&auction)?;
+
+        // The runner-up may decline at any time; the seller may close an offer left to expire
+        offer.status = if caller == offer.bidder {
+            RunnerUpOfferStatus::Declined
+        } else if caller == seller && env.ledger().timestamp() > offer.expires_at {
+            RunnerUpOfferStatus::Expired
+        } else {
+            return Err(ForfeitureError::Unauthorized);
+        };
+
+        auction.runner_up_offers.set(index, offer.clone());
+        env.storage().instance().set(&auction_key, &auction);
+
+        env.events().publish(
+            (seller, "RunnerUpDeclined", product_id),
+            &(offer.bidder, offer.status),
+        );
+
+        Ok(())
+    }
+}
+
+/// Forfeiture schedule the auction was created under, if any.
+pub(crate) fn auction_schedule(
+    env: &Env,
+    seller: &Address,
+    product_id: u64,
+) -> Option<ForfeitureSchedule> {
+    env.storage()
+        .instance()
+        .get(&DataKeys::AuctionForfeiture(seller.clone(), product_id))
+}
+
+/// Returns true once an auction that fell through can no longer sell the product, so the
+/// seller may auction it again.
+pub(crate) fn is_released(auction: &Auction) -> bool {
+    if auction.escrow_status != EscrowStatus::Forfeited {
+        return false;
+    }
+
+    match auction.runner_up_offers.last() {
+        Some(offer) => matches!(
+            offer.status,
+            RunnerUpOfferStatus::Declined | RunnerUpOfferStatus::Expired
+        ),
+        None => auction.runner_up.is_none(),
+    }
+}
+
+pub(crate) fn bps_of(amount: u64, bps: u32) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/// Pays `forfeit_bps` of the winner's deposit (`deposit_bps` of the winning bid) to the seller,
+/// refunds the rest of the held bid and records it.
+fn forfeit_deposit(
+    env: &Env,
+    auction: &mut Auction,
+    bidder: Address,
+    deposit_bps: u32,
+    forfeit_bps: u32,
+    reason: ForfeitureReason,
+) -> Result<u64, ForfeitureError> {
+    let deposit = bps_of(auction.highest_bid, deposit_bps).min(auction.deposit);
+    let forfeited = bps_of(deposit, forfeit_bps);
+    let refunded = auction.deposit - forfeited;
+
+    if auction.deposit > 0 {
+        let token = token::Client::new(env, &get_payment_token(env)?);
+        let contract = env.current_contract_address();
+        if forfeited > 0 {
+            token.transfer(&contract, &auction.seller, &(forfeited as i128));
+        }
+        if refunded > 0 {
+            token.transfer(&contract, &bidder, &(refunded as i128));
+        }
+    }
+
+    auction.forfeitures.push_back(ForfeitureRecord {
+        bidder: bidder.clone(),
+        bid: auction.highest_bid,
+        deposit,
+        forfeited,
+        reason,
+        timestamp: env.ledger().timestamp(),
+    });
+    auction.deposit = 0;
+    auction.escrow_status = EscrowStatus::Forfeited;
+
+    env.events().publish(
+        (
+            auction.seller.clone(),
+            "DepositForfeited",
+            auction.product_id,
+        ),
+        &(bidder, forfeited, refunded),
+    );
+
+    Ok(forfeited)
+}
+
+fn pending_offer(auction: &Auction) -> Result<(u32, RunnerUpOffer), ForfeitureError> {
+    let index = auction
+        .runner_up_offers
+        .len()
+        .checked_sub(1)
+        .ok_or(ForfeitureError::NoPendingOffer)?;
+    let offer = auction.runner_up_offers.get(index).unwrap();
+
+    if offer.status != RunnerUpOfferStatus::Pending {
+        return Err(ForfeitureError::NoPendingOffer);
+    }
+
+    Ok((index, offer))
+}
+
+fn is_valid_schedule(schedule: &ForfeitureSchedule) -> bool {
+    let bps = BPS_DENOMINATOR as u32;
+    if schedule.deposit_bps > bps
+        || schedule.late_forfeit_bps > bps
+        || schedule.settlement_window_secs == 0
+        || schedule.runner_up_window_secs == 0
+        || schedule.tiers.len() > MAX_FORFEITURE_TIERS
+    {
+        return false;
+    }
+
+    let mut previous: Option<u64> = None;
+    for tier in schedule.tiers.iter() {
+        if tier.forfeit_bps > bps || previous.is_some_and(|within| tier.within_secs <= within) {
+            return false;
+        }
+        previous = Some(tier.within_secs);
+    }
+
+    true
+}
+
+fn get_auction(env: &Env, key: &DataKeys) -> Result<Auction, ForfeitureError> {
+    env.storage()
+        .instance()
+        .get(key)
+        .ok_or(ForfeitureError::AuctionNotFound)
+}
+
+fn get_payment_token(env: &Env) -> Result<Address, ForfeitureError> {
+    env.storage()
+        .instance()
+        .get(&DataKeys::PaymentToken)
+        .ok_or(ForfeitureError::PaymentTokenNotSet)
+}
+
+fn is_admin(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, Address>(&DataKeys::Admin)
+        .is_some_and(|admin| admin == *address)
+}
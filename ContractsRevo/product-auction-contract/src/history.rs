@@ -0,0 +1,152 @@
+use soroban_sdk::{contractimpl, Address, Env, String, Vec};
+
+use crate::{
+    datatype::{DataKeys, PurchaseRecord, SellerStats},
+    interfaces::HistoryOperations,
+    ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
+};
+
+#[contractimpl]
+impl HistoryOperations for ProductAuctionContract {
+    fn get_buyer_history(env: Env, buyer: Address, offset: u32, limit: u32) -> Vec<PurchaseRecord> {
+        let mut page = Vec::new(&env);
+
+        let end = offset
+            .saturating_add(limit)
+            .min(get_purchase_count(&env, &buyer));
+        for i in offset..end {
+            if let Some(record) = get_purchase(&env, &buyer, i) {
+                page.push_back(record);
+            }
+        }
+
+        page
+    }
+
+    fn get_seller_stats(env: Env, seller: Address) -> SellerStats {
+        get_stats(&env, &seller)
+    }
+}
+
+/// Records a completed sale in the buyer's history and the seller's aggregates, returning
+/// the purchase's position in the buyer's history.
+pub(crate) fn record_purchase(
+    env: &Env,
+    seller: &Address,
+    buyer: &Address,
+    product_id: u64,
+    price: u64,
+) -> u32 {
+    let purchase_index = get_purchase_count(env, buyer);
+    set_purchase(
+        env,
+        buyer,
+        purchase_index,
+        &PurchaseRecord {
+            seller: seller.clone(),
+            product_id,
+            price,
+            timestamp: env.ledger().timestamp(),
+            tracking_number: None,
+            refunded: false,
+        },
+    );
+    env.storage().persistent().set(
+        &DataKeys::BuyerPurchaseCount(buyer.clone()),
+        &(purchase_index + 1),
+    );
+
+    let mut stats = get_stats(env, seller);
+    stats.sales_count += 1;
+    stats.gross_volume = stats.gross_volume.saturating_add(price);
+    set_stats(env, seller, &stats);
+
+    purchase_index
+}
+
+/// Links a shipment to the escrowed purchase of the product.
+pub(crate) fn record_tracking_number(
+    env: &Env,
+    seller: &Address,
+    buyer: &Address,
+    product_id: u64,
+    tracking_number: &String,
+) {
+    let Some(purchase_index) = escrowed_purchase_index(env, seller, product_id) else {
+        return;
+    };
+
+    if let Some(mut record) = get_purchase(env, buyer, purchase_index) {
+        record.tracking_number = Some(tracking_number.clone());
+        set_purchase(env, buyer, purchase_index, &record);
+    }
+}
+
+/// Marks the escrowed purchase of the product as refunded and takes it out of the seller's
+/// aggregates. Must run before the escrowed purchase is dropped.
+pub(crate) fn record_refund(env: &Env, seller: &Address, buyer: &Address, product_id: u64) {
+    let Some(purchase_index) = escrowed_purchase_index(env, seller, product_id) else {
+        return;
+    };
+
+    let Some(mut record) = get_purchase(env, buyer, purchase_index) else {
+        return;
+    };
+    record.refunded = true;
+    set_purchase(env, buyer, purchase_index, &record);
+
+    let mut stats = get_stats(env, seller);
+    stats.sales_count = stats.sales_count.saturating_sub(1);
+    stats.gross_volume = stats.gross_volume.saturating_sub(record.price);
+    set_stats(env, seller, &stats);
+}
+
+pub(crate) fn record_dispute(env: &Env, seller: &Address) {
+    let mut stats = get_stats(env, seller);
+    stats.disputed_count += 1;
+    set_stats(env, seller, &stats);
+}
+
+/// History index of the purchase held in escrow for an auction, if any.
+fn escrowed_purchase_index(env: &Env, seller: &Address, product_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKeys::EscrowedPurchase(seller.clone(), product_id))
+}
+
+fn get_purchase_count(env: &Env, buyer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKeys::BuyerPurchaseCount(buyer.clone()))
+        .unwrap_or(0)
+}
+
+fn get_purchase(env: &Env, buyer: &Address, purchase_index: u32) -> Option<PurchaseRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKeys::BuyerPurchase(buyer.clone(), purchase_index))
+}
+
+fn set_purchase(env: &Env, buyer: &Address, purchase_index: u32, record: &PurchaseRecord) {
+    env.storage().persistent().set(
+        &DataKeys::BuyerPurchase(buyer.clone(), purchase_index),
+        record,
+    );
+}
+
+fn get_stats(env: &Env, seller: &Address) -> SellerStats {
+    env.storage()
+        .persistent()
+        .get(&DataKeys::SellerStats(seller.clone()))
+        .unwrap_or(SellerStats {
+            sales_count: 0,
+            gross_volume: 0,
+            disputed_count: 0,
+        })
+}
+
+fn set_stats(env: &Env, seller: &Address, stats: &SellerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKeys::SellerStats(seller.clone()), stats);
+}
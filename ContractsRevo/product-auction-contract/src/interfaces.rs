@@ -1,7 +1,10 @@
 use crate::datatype::{
-    AuctionError, Condition, DisputeStatus, ProductError, ShippingError, VerificationError,
+    AdminError, Allocation, AntiSnipingConfig, AuctionError, Condition, DisputeStatus, EscrowError,
+    ForfeitureError, ForfeitureSchedule, LoyaltyConfig, PricingMode, Product, ProductError,
+    PurchaseRecord, QuantityAuction, QuantityAuctionError, QuantityBid, RunnerUpOffer, SellerStats,
+    SellerVerificationStatus, ShipmentMilestone, ShipmentStatus, ShippingError, VerificationError,
 };
-use soroban_sdk::{Address, Env, String, Symbol, Vec};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 #[allow(dead_code)]
 pub trait AuctionOperations {
@@ -29,6 +32,19 @@ pub trait AuctionOperations {
     ) -> Result<(), AuctionError>;
 
     fn finalize_auction(env: Env, seller: Address, product_id: u64) -> Result<(), AuctionError>;
+
+    fn set_anti_sniping_config(
+        env: Env,
+        admin: Address,
+        config: Option<AntiSnipingConfig>,
+    ) -> Result<(), AuctionError>;
+
+    fn set_auction_anti_sniping(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        config: AntiSnipingConfig,
+    ) -> Result<(), AuctionError>;
 }
 
 #[allow(dead_code)]
@@ -51,6 +67,20 @@ pub trait ProductListing {
         product_id: u64,
         new_stock: u32,
     ) -> Result<(), ProductError>;
+
+    fn update_product(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        price: Option<u64>,
+        description: Option<String>,
+        condition: Option<Condition>,
+        images: Option<Vec<String>>,
+    ) -> Result<(), ProductError>;
+
+    fn delist_product(env: Env, seller: Address, product_id: u64) -> Result<(), ProductError>;
+
+    fn list_active_products(env: Env, seller: Address) -> Vec<Product>;
 }
 
 #[allow(dead_code)]
@@ -75,6 +105,35 @@ pub trait ShippingOperations {
         seller: Address,
         new_status: Symbol,
     ) -> Result<(), ShippingError>;
+
+    fn update_shipment_status(
+        env: Env,
+        seller: Address,
+        tracking_number: String,
+        status: ShipmentStatus,
+        location: String,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), ShippingError>;
+
+    fn confirm_receipt(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<(), ShippingError>;
+
+    fn claim_late_delivery(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<(), ShippingError>;
+
+    fn get_shipment_milestones(
+        env: Env,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<Vec<ShipmentMilestone>, ShippingError>;
 }
 
 #[allow(dead_code)]
@@ -87,7 +146,38 @@ pub trait VerificationOperations {
         is_authentic: bool,
     ) -> Result<(), VerificationError>;
 
-    fn request_seller_verification(env: Env, seller: Address) -> Result<(), VerificationError>;
+    fn request_seller_verification(
+        env: Env,
+        seller: Address,
+        kyc_hash: BytesN<32>,
+    ) -> Result<(), VerificationError>;
+
+    fn approve_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError>;
+
+    fn reject_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError>;
+
+    fn revoke_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError>;
+
+    fn get_verification_status(
+        env: Env,
+        seller: Address,
+    ) -> Result<SellerVerificationStatus, VerificationError>;
+
+    fn set_certifier(
+        env: Env,
+        admin: Address,
+        certifier: Address,
+        approved: bool,
+    ) -> Result<(), VerificationError>;
+
+    fn attest_product_claim(
+        env: Env,
+        certifier: Address,
+        seller: Address,
+        product_id: u64,
+        claim: Symbol,
+        claim_hash: BytesN<32>,
+    ) -> Result<(), VerificationError>;
 
     fn verify_seller(
         env: Env,
@@ -143,3 +233,146 @@ pub trait VerificationOperations {
         resolution: Symbol,
     ) -> Result<(), VerificationError>;
 }
+
+#[allow(dead_code)]
+pub trait QuantityAuctionOperations {
+    fn create_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        total_units: u32,
+        reserve_unit_price: u64,
+        auction_end_time: u64,
+        pricing_mode: PricingMode,
+    ) -> Result<(), QuantityAuctionError>;
+
+    fn place_quantity_bid(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+        unit_price: u64,
+        quantity: u32,
+    ) -> Result<(), QuantityAuctionError>;
+
+    fn finalize_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Vec<Allocation>, QuantityAuctionError>;
+
+    fn reclaim_quantity_bids(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<u64, QuantityAuctionError>;
+
+    fn get_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<QuantityAuction, QuantityAuctionError>;
+
+    fn get_order_book(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        limit: u32,
+    ) -> Result<Vec<QuantityBid>, QuantityAuctionError>;
+
+    fn get_quantity_allocation(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Vec<Allocation>, QuantityAuctionError>;
+}
+
+#[allow(dead_code)]
+pub trait EscrowOperations {
+    fn initialize_payment_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), EscrowError>;
+
+    fn settle_auction(env: Env, seller: Address, product_id: u64) -> Result<(), EscrowError>;
+
+    fn attach_shipment(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        tracking_number: String,
+    ) -> Result<(), EscrowError>;
+
+    fn release_escrow(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), EscrowError>;
+
+    fn refund_escrow(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), EscrowError>;
+}
+
+#[allow(dead_code)]
+pub trait ForfeitureOperations {
+    fn set_forfeiture_schedule(
+        env: Env,
+        admin: Address,
+        schedule: Option<ForfeitureSchedule>,
+    ) -> Result<(), ForfeitureError>;
+
+    fn get_forfeiture_schedule(env: Env) -> Option<ForfeitureSchedule>;
+
+    fn cancel_purchase(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<u64, ForfeitureError>;
+
+    fn offer_to_runner_up(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Option<RunnerUpOffer>, ForfeitureError>;
+
+    fn accept_runner_up_offer(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), ForfeitureError>;
+
+    fn decline_runner_up_offer(
+        env: Env,
+        caller: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<(), ForfeitureError>;
+}
+
+#[allow(dead_code)]
+pub trait HistoryOperations {
+    fn get_buyer_history(env: Env, buyer: Address, offset: u32, limit: u32) -> Vec<PurchaseRecord>;
+
+    fn get_seller_stats(env: Env, seller: Address) -> SellerStats;
+}
+
+#[allow(dead_code)]
+pub trait LoyaltyOperations {
+    fn set_loyalty_program(
+        env: Env,
+        admin: Address,
+        loyalty_contract: Address,
+        program_id: BytesN<32>,
+    ) -> Result<(), AdminError>;
+
+    fn get_loyalty_program(env: Env) -> Option<LoyaltyConfig>;
+}
@@ -5,9 +5,14 @@ use datatype::{
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec};
 
 mod datatype;
+mod escrow;
+mod forfeiture;
+mod history;
 mod interfaces;
 mod listing;
+mod loyalty;
 mod product_auction;
+mod quantity_auction;
 mod shipping;
 mod verification;
 
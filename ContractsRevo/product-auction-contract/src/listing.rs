@@ -1,7 +1,7 @@
 use soroban_sdk::{contractimpl, Address, Env, String, Symbol, Vec};
 
 use crate::{
-    datatype::{Condition, DataKeys, Product, ProductError},
+    datatype::{Auction, Condition, DataKeys, EscrowStatus, Product, ProductError},
     interfaces::ProductListing,
     ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
 };
@@ -57,6 +57,8 @@ impl ProductListing for ProductAuctionContract {
             images,
             weight_pounds,
             verified: false,
+            delisted: false,
+            claims: Vec::new(&env),
         };
 
         // Retrieve or initialize the product list for the seller
@@ -107,4 +109,123 @@ impl ProductListing for ProductAuctionContract {
 
         Ok(())
     }
+
+    fn update_product(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        price: Option<u64>,
+        description: Option<String>,
+        condition: Option<Condition>,
+        images: Option<Vec<String>>,
+    ) -> Result<(), ProductError> {
+        seller.require_auth();
+
+        let key = DataKeys::Product(seller.clone(), product_id);
+        let mut product: Product = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ProductError::ProductNotFound)?;
+
+        if product.delisted {
+            return Err(ProductError::ProductDelisted);
+        }
+
+        // Bidders must see the listing they bid on; changes wait until the auction closes
+        if has_active_auction(&env, &seller, product_id) {
+            return Err(ProductError::ActiveAuction);
+        }
+
+        if let Some(price) = price {
+            if price == 0 {
+                return Err(ProductError::InvalidPrice);
+            }
+            product.price = price;
+        }
+
+        if let Some(description) = description {
+            if description.len() < 10 || description.len() > 500 {
+                return Err(ProductError::InvalidDescription);
+            }
+            product.description = description;
+        }
+
+        if let Some(condition) = condition {
+            product.condition = condition;
+        }
+
+        if let Some(images) = images {
+            if images.is_empty() {
+                return Err(ProductError::InvalidImageCount);
+            }
+            product.images = images;
+        }
+
+        env.storage().persistent().set(&key, &product);
+
+        env.events()
+            .publish((seller.clone(), "ProductUpdated", product_id), product);
+
+        Ok(())
+    }
+
+    fn delist_product(env: Env, seller: Address, product_id: u64) -> Result<(), ProductError> {
+        seller.require_auth();
+
+        let key = DataKeys::Product(seller.clone(), product_id);
+        let mut product: Product = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ProductError::ProductNotFound)?;
+
+        if product.delisted {
+            return Err(ProductError::ProductDelisted);
+        }
+
+        if has_active_auction(&env, &seller, product_id) {
+            return Err(ProductError::ActiveAuction);
+        }
+
+        product.delisted = true;
+        env.storage().persistent().set(&key, &product);
+
+        env.events()
+            .publish((seller.clone(), "ProductDelisted", product_id), ());
+
+        Ok(())
+    }
+
+    fn list_active_products(env: Env, seller: Address) -> Vec<Product> {
+        let listed = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Product>>(&DataKeys::ProductList(seller.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        // The seller's list keeps listing-time snapshots, so read each product's current state
+        let mut active = Vec::new(&env);
+        for listed_product in listed.iter() {
+            if let Some(product) = env
+                .storage()
+                .persistent()
+                .get::<_, Product>(&DataKeys::Product(seller.clone(), listed_product.id))
+            {
+                if !product.delisted && product.stock > 0 {
+                    active.push_back(product);
+                }
+            }
+        }
+
+        active
+    }
+}
+
+/// Returns true while an auction for the product is open or awaiting finalization.
+fn has_active_auction(env: &Env, seller: &Address, product_id: u64) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, Auction>(&DataKeys::Auction(seller.clone(), product_id))
+        .is_some_and(|auction| auction.escrow_status == EscrowStatus::None)
 }
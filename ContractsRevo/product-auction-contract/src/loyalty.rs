@@ -0,0 +1,128 @@
+use soroban_sdk::{contractclient, contractimpl, xdr::ToXdr, Address, BytesN, Env, Symbol};
+
+use crate::{
+    datatype::{AdminError, DataKeys, LoyaltyConfig},
+    interfaces::LoyaltyOperations,
+    ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
+};
+
+// Minimal interface of the loyalty token contract used to reward purchases.
+#[allow(dead_code)]
+#[contractclient(name = "LoyaltyTokenContractClient")]
+pub trait LoyaltyTokenContract {
+    fn award_for_purchase(
+        env: Env,
+        merchant_contract: Address,
+        program_id: BytesN<32>,
+        buyer: Address,
+        amount_spent: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64;
+}
+
+#[contractimpl]
+impl LoyaltyOperations for ProductAuctionContract {
+    fn set_loyalty_program(
+        env: Env,
+        admin: Address,
+        loyalty_contract: Address,
+        program_id: BytesN<32>,
+    ) -> Result<(), AdminError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKeys::Admin)
+            .ok_or(AdminError::UnauthorizedAccess)?;
+        if admin != stored_admin {
+            return Err(AdminError::UnauthorizedAccess);
+        }
+
+        let config = LoyaltyConfig {
+            loyalty_contract,
+            program_id,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKeys::LoyaltyProgram, &config);
+
+        env.events()
+            .publish((Symbol::new(&env, "loyalty_program_set"), admin), config);
+
+        Ok(())
+    }
+
+    fn get_loyalty_program(env: Env) -> Option<LoyaltyConfig> {
+        env.storage().instance().get(&DataKeys::LoyaltyProgram)
+    }
+}
+
+/// Remembers an escrowed purchase so its points are only awarded once the seller is paid.
+pub(crate) fn hold_purchase_points(
+    env: &Env,
+    seller: &Address,
+    product_id: u64,
+    purchase_index: u32,
+) {
+    env.storage().instance().set(
+        &DataKeys::EscrowedPurchase(seller.clone(), product_id),
+        &purchase_index,
+    );
+}
+
+/// Awards the points of an escrowed purchase once the escrow is released to the seller.
+pub(crate) fn release_purchase_points(
+    env: &Env,
+    seller: &Address,
+    product_id: u64,
+    buyer: &Address,
+    price: u64,
+) {
+    let key = DataKeys::EscrowedPurchase(seller.clone(), product_id);
+    if let Some(purchase_index) = env.storage().instance().get::<_, u32>(&key) {
+        env.storage().instance().remove(&key);
+        award_purchase_points(env, buyer, price, purchase_index);
+    }
+}
+
+/// Drops the points of an escrowed purchase that was refunded to the buyer.
+pub(crate) fn drop_purchase_points(env: &Env, seller: &Address, product_id: u64) {
+    env.storage()
+        .instance()
+        .remove(&DataKeys::EscrowedPurchase(seller.clone(), product_id));
+}
+
+/// Credits the buyer's loyalty points for a recorded purchase. Best effort: a missing or
+/// failing loyalty contract never blocks the sale.
+///
+/// The purchase is referenced by the buyer and the position of the purchase in their
+/// history, which is unique and stable for this contract.
+pub(crate) fn award_purchase_points(env: &Env, buyer: &Address, price: u64, purchase_index: u32) {
+    let config: LoyaltyConfig = match env.storage().instance().get(&DataKeys::LoyaltyProgram) {
+        Some(config) => config,
+        None => return,
+    };
+
+    if price == 0 {
+        return;
+    }
+
+    let mut data = buyer.clone().to_xdr(env);
+    data.extend_from_array(&purchase_index.to_be_bytes());
+    let purchase_ref: BytesN<32> = env.crypto().sha256(&data).into();
+
+    let client = LoyaltyTokenContractClient::new(env, &config.loyalty_contract);
+    if let Ok(Ok(points)) = client.try_award_for_purchase(
+        &env.current_contract_address(),
+        &config.program_id,
+        buyer,
+        &price,
+        &purchase_ref,
+    ) {
+        env.events().publish(
+            (Symbol::new(env, "loyalty_points_awarded"), buyer.clone()),
+            (purchase_ref, points),
+        );
+    }
+}
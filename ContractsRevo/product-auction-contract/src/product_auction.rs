@@ -1,8 +1,16 @@
-use soroban_sdk::{contractimpl, Address, Env};
+use soroban_sdk::{contractimpl, token, Address, Env, Vec};
 
 use crate::{
-    datatype::{Auction, AuctionError, DataKeys, Product},
+    datatype::{
+        AntiSnipingConfig, Auction, AuctionError, AuctionExtension, DataKeys, EscrowStatus,
+        ForfeitureSchedule, Product,
+    },
+    forfeiture::{auction_schedule, is_released},
+    history::record_purchase,
     interfaces::AuctionOperations,
+    loyalty::award_purchase_points,
+    quantity_auction::has_open_quantity_auction,
+    verification::can_list,
     ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
 };
 
@@ -18,11 +26,49 @@ impl AuctionOperations for ProductAuctionContract {
         seller.require_auth();
         let key = &DataKeys::Auction(seller.clone(), product_id);
 
-        // Ensure auction does not already exist
-        if env.storage().instance().has(key) {
+        if !can_list(&env, &seller) {
+            return Err(AuctionError::SellerNotVerified);
+        }
+
+        // Ensure auction does not already exist; one that fell through no longer counts
+        if has_open_auction(&env, &seller, product_id)
+            || has_open_quantity_auction(&env, &seller, product_id)
+        {
             return Err(AuctionError::AuctionAlreadyExists);
         }
 
+        // Delisted and sold-out products cannot be put up for auction
+        if let Some(product) = env
+            .storage()
+            .persistent()
+            .get::<_, Product>(&DataKeys::Product(seller.clone(), product_id))
+        {
+            if product.delisted {
+                return Err(AuctionError::ProductDelisted);
+            }
+
+            if product.stock == 0 {
+                return Err(AuctionError::OutOfStock);
+            }
+        }
+
+        // Bidders are held to the forfeiture rules in force when the auction opened
+        let schedule = env
+            .storage()
+            .instance()
+            .get::<_, ForfeitureSchedule>(&DataKeys::ForfeitureSchedule);
+        let forfeiture_key = DataKeys::AuctionForfeiture(seller.clone(), product_id);
+        let settlement_deadline = match &schedule {
+            Some(schedule) => {
+                env.storage().instance().set(&forfeiture_key, schedule);
+                auction_end_time + schedule.settlement_window_secs
+            }
+            None => {
+                env.storage().instance().remove(&forfeiture_key);
+                0
+            }
+        };
+
         // Create a new auction
         let auction = Auction {
             product_id,
@@ -31,10 +77,24 @@ impl AuctionOperations for ProductAuctionContract {
             reserve_price,
             auction_end_time,
             seller: seller.clone(),
+            escrow_status: EscrowStatus::None,
+            settled_at: 0,
+            tracking_number: None,
+            total_extension: 0,
+            extensions: Vec::new(&env),
+            deposit: 0,
+            runner_up: None,
+            runner_up_bid: 0,
+            settlement_deadline,
+            forfeitures: Vec::new(&env),
+            runner_up_offers: Vec::new(&env),
         };
 
         // Save the auction to storage
         env.storage().instance().set(key, &auction);
+        env.storage()
+            .instance()
+            .remove(&DataKeys::AuctionAntiSniping(seller.clone(), product_id));
 
         env.events()
             .publish((seller.clone(), "AuctionCreated", seller.clone()), auction);
@@ -49,6 +109,8 @@ impl AuctionOperations for ProductAuctionContract {
         bidder: Address,
         seller: Address,
     ) -> Result<bool, AuctionError> {
+        bidder.require_auth();
+
         let key = DataKeys::Auction(seller.clone(), product_id);
 
         // Fetch the auction from storage
@@ -74,10 +136,94 @@ impl AuctionOperations for ProductAuctionContract {
             return Err(AuctionError::BidTooLow);
         }
 
+        // The leading bid is escrowed in full; the displaced bidder's funds are returned. Auctions
+        // with a deposit schedule cannot take bids until the payment token is set.
+        let schedule = auction_schedule(&env, &seller, product_id);
+        let token = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKeys::PaymentToken);
+        match token {
+            Some(token) => {
+                let token = token::Client::new(&env, &token);
+                let contract = env.current_contract_address();
+
+                if auction.highest_bidder == Some(bidder.clone()) {
+                    token.transfer(
+                        &bidder,
+                        &contract,
+                        &((bid_amount - auction.deposit) as i128),
+                    );
+                } else {
+                    token.transfer(&bidder, &contract, &(bid_amount as i128));
+                    if let Some(previous) = auction
+                        .highest_bidder
+                        .as_ref()
+                        .filter(|_| auction.deposit > 0)
+                    {
+                        token.transfer(&contract, previous, &(auction.deposit as i128));
+                    }
+                }
+                auction.deposit = bid_amount;
+            }
+            None => {
+                if schedule
+                    .as_ref()
+                    .is_some_and(|schedule| schedule.deposit_bps > 0)
+                {
+                    return Err(AuctionError::PaymentTokenNotSet);
+                }
+            }
+        }
+
+        // A bidder raising their own bid stays ahead of the same runner-up
+        if auction.highest_bidder != Some(bidder.clone()) {
+            auction.runner_up = auction.highest_bidder.clone();
+            auction.runner_up_bid = auction.highest_bid;
+        }
+
         // Update auction state with the new highest bid
         auction.highest_bid = bid_amount;
         auction.highest_bidder = Some(bidder.clone());
 
+        // Push the end time back when a bid lands in the closing window
+        let config = env
+            .storage()
+            .instance()
+            .get::<_, AntiSnipingConfig>(&DataKeys::AuctionAntiSniping(seller.clone(), product_id))
+            .or_else(|| {
+                env.storage()
+                    .instance()
+                    .get::<_, AntiSnipingConfig>(&DataKeys::AntiSniping)
+            });
+        if let Some(config) = config {
+            let extension = config.extension_secs.min(
+                config
+                    .max_total_extension
+                    .saturating_sub(auction.total_extension),
+            );
+
+            if auction.auction_end_time - current_time < config.extension_window_secs
+                && extension > 0
+            {
+                auction.auction_end_time += extension;
+                auction.total_extension += extension;
+                if let Some(schedule) = &schedule {
+                    auction.settlement_deadline =
+                        auction.auction_end_time + schedule.settlement_window_secs;
+                }
+                auction.extensions.push_back(AuctionExtension {
+                    bid_time: current_time,
+                    new_end_time: auction.auction_end_time,
+                });
+
+                env.events().publish(
+                    (seller.clone(), "auction_extended", product_id),
+                    auction.auction_end_time,
+                );
+            }
+        }
+
         // Save the updated auction to storage
         env.storage().instance().set(&key, &auction);
 
@@ -125,6 +271,9 @@ impl AuctionOperations for ProductAuctionContract {
 
         // Update auction end time
         auction.auction_end_time = new_end_time;
+        if let Some(schedule) = auction_schedule(&env, &seller, product_id) {
+            auction.settlement_deadline = new_end_time + schedule.settlement_window_secs;
+        }
 
         // Save updated auction to storage
         env.storage().instance().set(&key, &auction);
@@ -156,9 +305,19 @@ impl AuctionOperations for ProductAuctionContract {
             return Err(AuctionError::AuctionNotYetEnded);
         }
 
+        // Settled auctions hold escrowed funds and are closed through the escrow flow
+        if auction.escrow_status != EscrowStatus::None {
+            return Err(AuctionError::AuctionAlreadySettled);
+        }
+
         // Ensure there is a winning bidder
         let winner = auction.highest_bidder.ok_or(AuctionError::NoBidsPlaced)?;
 
+        // A held winning bid is paid out through `settle_auction` and `release_escrow`
+        if auction.deposit > 0 {
+            return Err(AuctionError::BidEscrowed);
+        }
+
         let product_key = DataKeys::Product(seller.clone(), product_id.clone());
 
         // Fetch product from storage
@@ -181,6 +340,16 @@ impl AuctionOperations for ProductAuctionContract {
 
         // Remove auction from storage (auction is complete)
         env.storage().instance().remove(&auction_key);
+        env.storage()
+            .instance()
+            .remove(&DataKeys::AuctionAntiSniping(seller.clone(), product_id));
+        env.storage()
+            .instance()
+            .remove(&DataKeys::AuctionForfeiture(seller.clone(), product_id));
+
+        let purchase_index =
+            record_purchase(&env, &seller, &winner, product_id, auction.highest_bid);
+        award_purchase_points(&env, &winner, auction.highest_bid, purchase_index);
 
         // Emit event to notify that the auction is finalized
         env.events()
@@ -188,4 +357,88 @@ impl AuctionOperations for ProductAuctionContract {
 
         Ok(())
     }
+
+    fn set_anti_sniping_config(
+        env: Env,
+        admin: Address,
+        config: Option<AntiSnipingConfig>,
+    ) -> Result<(), AuctionError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKeys::Admin)
+            .ok_or(AuctionError::Unauthorized)?;
+
+        if admin != stored_admin {
+            return Err(AuctionError::Unauthorized);
+        }
+
+        match config {
+            Some(config) => {
+                if !is_valid_anti_sniping(&config) {
+                    return Err(AuctionError::InvalidAntiSnipingConfig);
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKeys::AntiSniping, &config);
+            }
+            None => env.storage().instance().remove(&DataKeys::AntiSniping),
+        }
+
+        Ok(())
+    }
+
+    fn set_auction_anti_sniping(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        config: AntiSnipingConfig,
+    ) -> Result<(), AuctionError> {
+        seller.require_auth();
+
+        let key = DataKeys::Auction(seller.clone(), product_id);
+        let auction: Auction = env
+            .storage()
+            .instance()
+            .get(&key)
+            .ok_or(AuctionError::AuctionNotFound)?;
+
+        if !is_valid_anti_sniping(&config) {
+            return Err(AuctionError::InvalidAntiSnipingConfig);
+        }
+
+        // Bidders must know the closing rules before they commit
+        if auction.highest_bidder.is_some() {
+            return Err(AuctionError::AuctionHasBids);
+        }
+
+        env.storage().instance().set(
+            &DataKeys::AuctionAntiSniping(seller.clone(), product_id),
+            &config,
+        );
+
+        Ok(())
+    }
+}
+
+/// Returns true if the product has a single-lot auction that has neither closed through the
+/// escrow nor fallen through.
+pub(crate) fn has_open_auction(env: &Env, seller: &Address, product_id: u64) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, Auction>(&DataKeys::Auction(seller.clone(), product_id))
+        .is_some_and(|auction| {
+            !matches!(
+                auction.escrow_status,
+                EscrowStatus::Released | EscrowStatus::Refunded
+            ) && !is_released(&auction)
+        })
+}
+
+fn is_valid_anti_sniping(config: &AntiSnipingConfig) -> bool {
+    config.extension_window_secs > 0
+        && config.extension_secs > 0
+        && config.max_total_extension >= config.extension_secs
 }
@@ -0,0 +1,350 @@
+use soroban_sdk::{contractimpl, token, Address, Env, Vec};
+
+use crate::{
+    datatype::{
+        Allocation, DataKeys, PricingMode, Product, QuantityAuction, QuantityAuctionError,
+        QuantityBid, FINALIZE_BY_DAYS, MAX_QUANTITY_BIDS, SECONDS_PER_DAY,
+    },
+    history::record_purchase,
+    interfaces::QuantityAuctionOperations,
+    loyalty::award_purchase_points,
+    product_auction::has_open_auction,
+    verification::can_list,
+    ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
+};
+
+#[contractimpl]
+impl QuantityAuctionOperations for ProductAuctionContract {
+    fn create_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        total_units: u32,
+        reserve_unit_price: u64,
+        auction_end_time: u64,
+        pricing_mode: PricingMode,
+    ) -> Result<(), QuantityAuctionError> {
+        seller.require_auth();
+
+        if !can_list(&env, &seller) {
+            return Err(QuantityAuctionError::SellerNotVerified);
+        }
+
+        let key = DataKeys::QuantityAuction(seller.clone(), product_id);
+
+        // A product is either sold as a single lot or by quantity, never both at once
+        if has_open_quantity_auction(&env, &seller, product_id)
+            || has_open_auction(&env, &seller, product_id)
+        {
+            return Err(QuantityAuctionError::AuctionAlreadyExists);
+        }
+
+        let product: Product = env
+            .storage()
+            .persistent()
+            .get(&DataKeys::Product(seller.clone(), product_id))
+            .ok_or(QuantityAuctionError::ProductNotFound)?;
+
+        if product.delisted {
+            return Err(QuantityAuctionError::ProductDelisted);
+        }
+
+        if total_units == 0 || total_units > product.stock {
+            return Err(QuantityAuctionError::InvalidUnits);
+        }
+
+        if auction_end_time <= env.ledger().timestamp() {
+            return Err(QuantityAuctionError::InvalidAuctionEndTime);
+        }
+
+        let auction = QuantityAuction {
+            product_id,
+            seller: seller.clone(),
+            total_units,
+            reserve_unit_price,
+            auction_end_time,
+            pricing_mode,
+            bids: Vec::new(&env),
+            finalized: false,
+            clearing_price: 0,
+        };
+
+        env.storage().instance().set(&key, &auction);
+        env.storage()
+            .instance()
+            .remove(&DataKeys::QuantityAllocation(seller.clone(), product_id));
+
+        env.events()
+            .publish((seller, "QuantityAuctionCreated", product_id), auction);
+
+        Ok(())
+    }
+
+    fn place_quantity_bid(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+        unit_price: u64,
+        quantity: u32,
+    ) -> Result<(), QuantityAuctionError> {
+        bidder.require_auth();
+
+        let key = DataKeys::QuantityAuction(seller.clone(), product_id);
+        let mut auction = get_quantity_auction(&env, &key)?;
+
+        if bidder == auction.seller {
+            return Err(QuantityAuctionError::InvalidBidder);
+        }
+
+        let current_time = env.ledger().timestamp();
+        if auction.finalized || auction.auction_end_time < current_time {
+            return Err(QuantityAuctionError::AuctionEnded);
+        }
+
+        if unit_price < auction.reserve_unit_price {
+            return Err(QuantityAuctionError::BidBelowReserve);
+        }
+
+        if quantity == 0 || quantity > auction.total_units {
+            return Err(QuantityAuctionError::InvalidQuantity);
+        }
+
+        if auction.bids.len() >= MAX_QUANTITY_BIDS {
+            return Err(QuantityAuctionError::TooManyBids);
+        }
+
+        let amount = unit_price
+            .checked_mul(quantity as u64)
+            .ok_or(QuantityAuctionError::InvalidQuantity)?;
+
+        // The full bid is escrowed up front; unfilled units are refunded at finalization
+        let token = get_payment_token(&env)?;
+        token::Client::new(&env, &token).transfer(
+            &bidder,
+            &env.current_contract_address(),
+            &(amount as i128),
+        );
+
+        let bid = QuantityBid {
+            bidder: bidder.clone(),
+            unit_price,
+            quantity,
+            placed_at: current_time,
+        };
+
+        // Keep the book sorted by unit price; equal prices keep arrival order
+        let position = auction
+            .bids
+            .iter()
+            .position(|b| b.unit_price < unit_price)
+            .unwrap_or(auction.bids.len() as usize);
+        auction.bids.insert(position as u32, bid);
+
+        env.storage().instance().set(&key, &auction);
+
+        env.events().publish(
+            (seller, "QuantityBid", product_id),
+            &(bidder, unit_price, quantity),
+        );
+
+        Ok(())
+    }
+
+    fn finalize_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Vec<Allocation>, QuantityAuctionError> {
+        seller.require_auth();
+
+        let key = DataKeys::QuantityAuction(seller.clone(), product_id);
+        let mut auction = get_quantity_auction(&env, &key)?;
+
+        if auction.finalized {
+            return Err(QuantityAuctionError::AlreadyFinalized);
+        }
+
+        if auction.auction_end_time > env.ledger().timestamp() {
+            return Err(QuantityAuctionError::AuctionNotYetEnded);
+        }
+
+        // Award units to the best bids first, partially filling the marginal bid
+        let mut remaining = auction.total_units;
+        let mut filled: Vec<u32> = Vec::new(&env);
+        for bid in auction.bids.iter() {
+            let units = bid.quantity.min(remaining);
+            remaining -= units;
+            if units > 0 {
+                auction.clearing_price = bid.unit_price;
+            }
+            filled.push_back(units);
+        }
+
+        let token_client = token::Client::new(&env, &get_payment_token(&env)?);
+        let contract = env.current_contract_address();
+        let mut allocations: Vec<Allocation> = Vec::new(&env);
+        let mut proceeds = 0u64;
+
+        for (bid, units) in auction.bids.iter().zip(filled.iter()) {
+            let unit_price = match auction.pricing_mode {
+                PricingMode::Uniform => auction.clearing_price,
+                PricingMode::PayAsBid => bid.unit_price,
+            };
+            let paid = unit_price * units as u64;
+            let refunded = bid.unit_price * bid.quantity as u64 - paid;
+
+            if refunded > 0 {
+                token_client.transfer(&contract, &bid.bidder, &(refunded as i128));
+            }
+
+            if units > 0 {
+                proceeds += paid;
+                let purchase_index = record_purchase(&env, &seller, &bid.bidder, product_id, paid);
+                award_purchase_points(&env, &bid.bidder, paid, purchase_index);
+            }
+
+            allocations.push_back(Allocation {
+                bidder: bid.bidder.clone(),
+                units,
+                unit_price,
+                paid,
+                refunded,
+            });
+        }
+
+        if proceeds > 0 {
+            token_client.transfer(&contract, &seller, &(proceeds as i128));
+        }
+
+        let units_sold = auction.total_units - remaining;
+        let product_key = DataKeys::Product(seller.clone(), product_id);
+        if let Some(mut product) = env.storage().persistent().get::<_, Product>(&product_key) {
+            product.stock = product.stock.saturating_sub(units_sold);
+            env.storage().persistent().set(&product_key, &product);
+        }
+
+        auction.finalized = true;
+        env.storage().instance().set(&key, &auction);
+        env.storage().instance().set(
+            &DataKeys::QuantityAllocation(seller.clone(), product_id),
+            &allocations,
+        );
+
+        env.events().publish(
+            (seller, "QuantityAuctionFinalized", product_id),
+            (units_sold, auction.clearing_price),
+        );
+
+        Ok(allocations)
+    }
+
+    fn reclaim_quantity_bids(
+        env: Env,
+        bidder: Address,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<u64, QuantityAuctionError> {
+        bidder.require_auth();
+
+        let key = DataKeys::QuantityAuction(seller.clone(), product_id);
+        let mut auction = get_quantity_auction(&env, &key)?;
+
+        if auction.finalized {
+            return Err(QuantityAuctionError::AlreadyFinalized);
+        }
+
+        // The seller gets a window to finalize before bidders can walk away with their escrow
+        let deadline = auction
+            .auction_end_time
+            .saturating_add(FINALIZE_BY_DAYS * SECONDS_PER_DAY);
+        if env.ledger().timestamp() <= deadline {
+            return Err(QuantityAuctionError::FinalizeWindowOpen);
+        }
+
+        let mut refunded = 0u64;
+        let mut remaining: Vec<QuantityBid> = Vec::new(&env);
+        for bid in auction.bids.iter() {
+            if bid.bidder == bidder {
+                refunded += bid.unit_price * bid.quantity as u64;
+            } else {
+                remaining.push_back(bid);
+            }
+        }
+
+        if refunded == 0 {
+            return Err(QuantityAuctionError::NoBidsToReclaim);
+        }
+
+        auction.bids = remaining;
+        env.storage().instance().set(&key, &auction);
+
+        token::Client::new(&env, &get_payment_token(&env)?).transfer(
+            &env.current_contract_address(),
+            &bidder,
+            &(refunded as i128),
+        );
+
+        env.events().publish(
+            (seller, "QuantityBidsReclaimed", product_id),
+            &(bidder, refunded),
+        );
+
+        Ok(refunded)
+    }
+
+    fn get_quantity_auction(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<QuantityAuction, QuantityAuctionError> {
+        get_quantity_auction(&env, &DataKeys::QuantityAuction(seller, product_id))
+    }
+
+    fn get_order_book(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+        limit: u32,
+    ) -> Result<Vec<QuantityBid>, QuantityAuctionError> {
+        let auction = get_quantity_auction(&env, &DataKeys::QuantityAuction(seller, product_id))?;
+
+        Ok(auction.bids.slice(0..limit.min(auction.bids.len())))
+    }
+
+    fn get_quantity_allocation(
+        env: Env,
+        seller: Address,
+        product_id: u64,
+    ) -> Result<Vec<Allocation>, QuantityAuctionError> {
+        env.storage()
+            .instance()
+            .get(&DataKeys::QuantityAllocation(seller, product_id))
+            .ok_or(QuantityAuctionError::AuctionNotFound)
+    }
+}
+
+fn get_quantity_auction(
+    env: &Env,
+    key: &DataKeys,
+) -> Result<QuantityAuction, QuantityAuctionError> {
+    env.storage()
+        .instance()
+        .get(key)
+        .ok_or(QuantityAuctionError::AuctionNotFound)
+}
+
+/// Returns true while a quantity auction for the product is still collecting bids or awaiting finalization.
+pub(crate) fn has_open_quantity_auction(env: &Env, seller: &Address, product_id: u64) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, QuantityAuction>(&DataKeys::QuantityAuction(seller.clone(), product_id))
+        .is_some_and(|auction| !auction.finalized)
+}
+
+fn get_payment_token(env: &Env) -> Result<Address, QuantityAuctionError> {
+    env.storage()
+        .instance()
+        .get(&DataKeys::PaymentToken)
+        .ok_or(QuantityAuctionError::PaymentTokenNotSet)
+}
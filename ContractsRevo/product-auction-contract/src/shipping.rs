@@ -1,7 +1,10 @@
-use soroban_sdk::{contractimpl, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 use crate::{
-    datatype::{DataKeys, Shipment, ShippingError, COST_PER_KM, COST_PER_POUND},
+    datatype::{
+        DataKeys, Shipment, ShipmentMilestone, ShipmentStatus, ShippingError, COST_PER_KM,
+        COST_PER_POUND, DELIVERY_GRACE_DAYS, SECONDS_PER_DAY,
+    },
     interfaces::ShippingOperations,
     ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
 };
@@ -63,6 +66,9 @@ impl ShippingOperations for ProductAuctionContract {
 
         let shipping_cost = Self::calculate_shipping_cost(weight_pounds, distance_km);
         let delivery_time = Self::estimate_delivery_time(distance_km);
+        let created_at = env.ledger().timestamp();
+        let delivery_deadline =
+            created_at + (delivery_time as u64 + DELIVERY_GRACE_DAYS) * SECONDS_PER_DAY;
 
         //Create new shipment
         let shipment = Shipment {
@@ -74,6 +80,10 @@ impl ShippingOperations for ProductAuctionContract {
             delivery_estimate_days: delivery_time,
             status: Symbol::new(&env, "Pending"),
             tracking_number: tracking_number.clone(),
+            shipment_status: ShipmentStatus::Preparing,
+            created_at,
+            delivery_deadline,
+            receipt_confirmed: false,
         };
 
         // Retrieve or initialize the shipment list for the seller
@@ -112,16 +122,201 @@ impl ShippingOperations for ProductAuctionContract {
         let shipment_key = DataKeys::Shipment(seller, tracking_number.clone());
         let mut shipment: Shipment = env
             .storage()
-            .instance()
+            .persistent()
             .get(&shipment_key)
             .ok_or(ShippingError::ShipmentNotFound)?;
 
         shipment.status = new_status.clone();
-        env.storage().instance().set(&shipment_key, &shipment);
+        env.storage().persistent().set(&shipment_key, &shipment);
 
         env.events()
             .publish(("StatusUpdated", tracking_number), new_status);
 
         Ok(())
     }
+
+    fn update_shipment_status(
+        env: Env,
+        seller: Address,
+        tracking_number: String,
+        status: ShipmentStatus,
+        location: String,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), ShippingError> {
+        seller.require_auth();
+
+        let shipment_key = DataKeys::Shipment(seller.clone(), tracking_number.clone());
+        let mut shipment: Shipment = env
+            .storage()
+            .persistent()
+            .get(&shipment_key)
+            .ok_or(ShippingError::ShipmentNotFound)?;
+
+        if is_finalized(&shipment) {
+            return Err(ShippingError::ShipmentFinalized);
+        }
+
+        // Milestones only move forward; repeated InTransit updates record new locations
+        if status_rank(&status) < status_rank(&shipment.shipment_status)
+            || (status == ShipmentStatus::Preparing
+                && shipment.shipment_status == ShipmentStatus::Preparing)
+        {
+            return Err(ShippingError::InvalidStatusTransition);
+        }
+
+        record_milestone(&env, &mut shipment, status.clone(), location, proof_hash);
+        env.storage().persistent().set(&shipment_key, &shipment);
+
+        env.events()
+            .publish(("ShipmentStatusUpdated", tracking_number), status);
+
+        Ok(())
+    }
+
+    fn confirm_receipt(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<(), ShippingError> {
+        buyer.require_auth();
+
+        let shipment_key = DataKeys::Shipment(seller.clone(), tracking_number.clone());
+        let mut shipment: Shipment = env
+            .storage()
+            .persistent()
+            .get(&shipment_key)
+            .ok_or(ShippingError::ShipmentNotFound)?;
+
+        if shipment.buyer != buyer {
+            return Err(ShippingError::Unauthorized);
+        }
+
+        if shipment.receipt_confirmed || shipment.shipment_status == ShipmentStatus::Failed {
+            return Err(ShippingError::ShipmentFinalized);
+        }
+
+        shipment.receipt_confirmed = true;
+        shipment.shipment_status = ShipmentStatus::Delivered;
+        shipment.status = Symbol::new(&env, "Delivered");
+        env.storage().persistent().set(&shipment_key, &shipment);
+
+        env.events()
+            .publish(("ReceiptConfirmed", tracking_number), buyer);
+
+        Ok(())
+    }
+
+    fn claim_late_delivery(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<(), ShippingError> {
+        buyer.require_auth();
+
+        let shipment_key = DataKeys::Shipment(seller.clone(), tracking_number.clone());
+        let mut shipment: Shipment = env
+            .storage()
+            .persistent()
+            .get(&shipment_key)
+            .ok_or(ShippingError::ShipmentNotFound)?;
+
+        if shipment.buyer != buyer {
+            return Err(ShippingError::Unauthorized);
+        }
+
+        // Only the buyer's confirmation settles a delivery; a seller-reported Delivered does not
+        if shipment.receipt_confirmed || shipment.shipment_status == ShipmentStatus::Failed {
+            return Err(ShippingError::ShipmentFinalized);
+        }
+
+        if env.ledger().timestamp() <= shipment.delivery_deadline {
+            return Err(ShippingError::DeadlineNotReached);
+        }
+
+        // A missed deadline fails the shipment, opening the buyer's refund path
+        let location = String::from_str(&env, "");
+        let proof_hash = BytesN::from_array(&env, &[0; 32]);
+        record_milestone(
+            &env,
+            &mut shipment,
+            ShipmentStatus::Failed,
+            location,
+            proof_hash,
+        );
+        env.storage().persistent().set(&shipment_key, &shipment);
+
+        env.events()
+            .publish(("DeliveryDeadlineMissed", tracking_number), buyer);
+
+        Ok(())
+    }
+
+    fn get_shipment_milestones(
+        env: Env,
+        seller: Address,
+        tracking_number: String,
+    ) -> Result<Vec<ShipmentMilestone>, ShippingError> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKeys::Shipment(seller.clone(), tracking_number.clone()))
+        {
+            return Err(ShippingError::ShipmentNotFound);
+        }
+
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKeys::ShipmentMilestones(seller, tracking_number))
+            .unwrap_or_else(|| Vec::new(&env)))
+    }
+}
+
+fn status_rank(status: &ShipmentStatus) -> u32 {
+    match status {
+        ShipmentStatus::Preparing => 0,
+        ShipmentStatus::InTransit => 1,
+        ShipmentStatus::Delivered => 2,
+        ShipmentStatus::Failed => 3,
+    }
+}
+
+fn is_finalized(shipment: &Shipment) -> bool {
+    shipment.receipt_confirmed
+        || shipment.shipment_status == ShipmentStatus::Delivered
+        || shipment.shipment_status == ShipmentStatus::Failed
+}
+
+fn record_milestone(
+    env: &Env,
+    shipment: &mut Shipment,
+    status: ShipmentStatus,
+    location: String,
+    proof_hash: BytesN<32>,
+) {
+    let key =
+        DataKeys::ShipmentMilestones(shipment.seller.clone(), shipment.tracking_number.clone());
+    let mut milestones: Vec<ShipmentMilestone> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    milestones.push_back(ShipmentMilestone {
+        status: status.clone(),
+        location,
+        proof_hash,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&key, &milestones);
+
+    shipment.status = match status {
+        ShipmentStatus::Preparing => Symbol::new(env, "Preparing"),
+        ShipmentStatus::InTransit => Symbol::new(env, "InTransit"),
+        ShipmentStatus::Delivered => Symbol::new(env, "Delivered"),
+        ShipmentStatus::Failed => Symbol::new(env, "Failed"),
+    };
+    shipment.shipment_status = status;
 }
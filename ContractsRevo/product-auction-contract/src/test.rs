@@ -4,7 +4,7 @@ use crate::datatype::{Condition, Dispute, DisputeStatus, ReturnRequest, SellerVe
 use super::*;
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    vec, Address, Env,
+    vec, Address, BytesN, Env,
 };
 
 fn setup_test(mock_auths: bool) -> (Env, ProductAuctionContractClient<'static>, Address, Address) {
@@ -23,6 +23,16 @@ fn setup_test(mock_auths: bool) -> (Env, ProductAuctionContractClient<'static>,
     (env, client, admin, user)
 }
 
+// Sellers must be verified before they can list
+fn approve_seller_for_test(env: &Env, client: &ProductAuctionContractClient, seller: &Address) {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKeys::SellerVerification(seller.clone()),
+            &SellerVerificationStatus::Verified,
+        );
+    });
+}
+
 #[test]
 fn test_initialize_contract() {
     let env = Env::default();
@@ -337,6 +347,7 @@ fn test_update_stock_invalid_product() {
 #[test]
 fn test_create_auction() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -363,6 +374,7 @@ fn test_create_auction() {
 #[test]
 fn test_get_auction() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -394,6 +406,7 @@ fn test_create_auction_unauthorized() {
 #[should_panic(expected = "Error(Contract, #3)")]
 fn test_create_auction_already_exists() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -406,6 +419,7 @@ fn test_create_auction_already_exists() {
 #[test]
 fn test_place_bid() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -433,6 +447,7 @@ fn test_place_bid() {
 #[should_panic(expected = "Error(Contract, #4)")]
 fn test_place_bid_invalid_bidder() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -458,6 +473,7 @@ fn test_place_bid_auction_not_found() {
 #[should_panic(expected = "Error(Contract, #2)")]
 fn test_place_bid_auction_ended() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -474,6 +490,7 @@ fn test_place_bid_auction_ended() {
 #[should_panic(expected = "Error(Contract, #1)")]
 fn test_place_bid_bid_too_low() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -488,6 +505,7 @@ fn test_place_bid_bid_too_low() {
 #[test]
 fn test_extend_auction() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -537,6 +555,7 @@ fn test_extend_auction_not_found() {
 #[should_panic(expected = "Error(Contract, #2)")]
 fn test_extend_auction_ended() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -554,6 +573,7 @@ fn test_extend_auction_ended() {
 #[should_panic(expected = "Error(Contract, #6)")]
 fn test_extend_auction_too_late_to_extend() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -571,6 +591,7 @@ fn test_extend_auction_too_late_to_extend() {
 #[should_panic(expected = "Error(Contract, #7)")]
 fn test_extend_auction_invalid_auction_end_time() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
     let product_id = 1u64;
 
     let reserve_price = &50u64;
@@ -584,6 +605,7 @@ fn test_extend_auction_invalid_auction_end_time() {
 #[test]
 fn test_finalize_auction() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
 
     let name = &Symbol::new(&env, "Product1");
     let description = &String::from_str(&env, "This is a product");
@@ -672,6 +694,7 @@ fn test_finalize_auction_not_found() {
 #[should_panic(expected = "Error(Contract, #8)")]
 fn test_finalize_auction_not_ended() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
 
     let product_id = 1u64;
 
@@ -687,6 +710,7 @@ fn test_finalize_auction_not_ended() {
 #[should_panic(expected = "Error(Contract, #9)")]
 fn test_finalize_auction_no_bids() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
 
     let product_id = 1u64;
 
@@ -702,6 +726,7 @@ fn test_finalize_auction_no_bids() {
 #[should_panic(expected = "Error(Contract, #11)")]
 fn test_finalize_auction_out_of_stock() {
     let (env, client, _, seller) = setup_test(true);
+    approve_seller_for_test(&env, &client, &seller);
 
     let name = &Symbol::new(&env, "Product1");
     let description = &String::from_str(&env, "This is a product");
@@ -1069,7 +1094,7 @@ fn test_verify_product_invalid_product() {
 fn test_request_seller_verification() {
     let (env, client, _, seller) = setup_test(true);
 
-    client.request_seller_verification(&seller);
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[1; 32]));
 
     let key = DataKeys::SellerVerification(seller.clone());
 
@@ -1088,7 +1113,7 @@ fn test_request_seller_verification() {
 fn test_request_seller_verification_already_verified() {
     let (env, client, _, seller) = setup_test(true);
 
-    client.request_seller_verification(&seller);
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[1; 32]));
 
     let key = DataKeys::SellerVerification(seller.clone());
 
@@ -1101,14 +1126,14 @@ fn test_request_seller_verification_already_verified() {
         assert_eq!(status, SellerVerificationStatus::Pending);
     });
 
-    client.request_seller_verification(&seller);
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[1; 32]));
 }
 
 #[test]
 fn test_verify_seller() {
     let (env, client, admin, seller) = setup_test(true);
 
-    client.request_seller_verification(&seller);
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[1; 32]));
 
     let key = DataKeys::SellerVerification(seller.clone());
 
@@ -1128,7 +1153,7 @@ fn test_verify_seller() {
 fn test_verify_seller_unverified() {
     let (env, client, admin, seller) = setup_test(true);
 
-    client.request_seller_verification(&seller);
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[1; 32]));
 
     let key = DataKeys::SellerVerification(seller.clone());
 
@@ -0,0 +1,109 @@
+use crate::datatype::{AntiSnipingConfig, AuctionError};
+use crate::tests::utils::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+fn config(window: u64, extension: u64, max_total: u64) -> AntiSnipingConfig {
+    AntiSnipingConfig {
+        extension_window_secs: window,
+        extension_secs: extension,
+        max_total_extension: max_total,
+    }
+}
+
+#[test]
+fn test_bid_in_window_extends_once() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    env.mock_all_auths();
+
+    // Auction ends at 1000
+    let product_id = create_auction_with_product(&env, &client, &seller, 1000);
+    client.set_auction_anti_sniping(&seller, &product_id, &config(60, 120, 600));
+
+    env.ledger().set_timestamp(970);
+    client.place_bid(&product_id, &60u64, &Address::generate(&env), &seller);
+
+    let auction = client.get_auction(&seller, &product_id);
+    assert_eq!(auction.auction_end_time, 1120);
+    assert_eq!(auction.total_extension, 120);
+    assert_eq!(auction.extensions.len(), 1);
+    let extension = auction.extensions.get(0).unwrap();
+    assert_eq!(extension.bid_time, 970);
+    assert_eq!(extension.new_end_time, 1120);
+}
+
+#[test]
+fn test_bid_outside_window_does_not_extend() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    env.mock_all_auths();
+
+    let product_id = create_auction_with_product(&env, &client, &seller, 1000);
+    client.set_auction_anti_sniping(&seller, &product_id, &config(60, 120, 600));
+
+    env.ledger().set_timestamp(900);
+    client.place_bid(&product_id, &60u64, &Address::generate(&env), &seller);
+
+    let auction = client.get_auction(&seller, &product_id);
+    assert_eq!(auction.auction_end_time, 1000);
+    assert_eq!(auction.extensions.len(), 0);
+
+    // The rules are fixed once bidding has started
+    let result = client.try_set_auction_anti_sniping(&seller, &product_id, &config(60, 60, 60));
+    assert_eq!(result, Err(Ok(AuctionError::AuctionHasBids)));
+}
+
+#[test]
+fn test_cumulative_extension_cap() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    // Contract-wide rule: at most 250 seconds of extensions in total
+    client.set_anti_sniping_config(&admin, &Some(config(60, 100, 250)));
+    let product_id = create_auction_with_product(&env, &client, &seller, 1000);
+
+    let mut bid = 60u64;
+    for _ in 0..4 {
+        let end_time = client.get_auction(&seller, &product_id).auction_end_time;
+        env.ledger().set_timestamp(end_time - 10);
+        client.place_bid(&product_id, &bid, &Address::generate(&env), &seller);
+        bid += 10;
+    }
+
+    let auction = client.get_auction(&seller, &product_id);
+    assert_eq!(auction.total_extension, 250);
+    assert_eq!(auction.auction_end_time, 1250);
+    assert_eq!(auction.extensions.len(), 3);
+    assert_eq!(auction.extensions.get(2).unwrap().new_end_time, 1250);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_set_anti_sniping_config(&outsider, &None);
+    assert_eq!(result, Err(Ok(AuctionError::Unauthorized)));
+}
+
+#[test]
+fn test_extension_delays_finalization() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    env.mock_all_auths();
+
+    let product_id = create_auction_with_product(&env, &client, &seller, 1000);
+    client.set_auction_anti_sniping(&seller, &product_id, &config(60, 120, 600));
+
+    env.ledger().set_timestamp(990);
+    client.place_bid(&product_id, &60u64, &Address::generate(&env), &seller);
+
+    // The original end time has passed, but the extended one has not
+    env.ledger().set_timestamp(1050);
+    let result = client.try_finalize_auction(&seller, &product_id);
+    assert_eq!(result, Err(Ok(AuctionError::AuctionNotYetEnded)));
+
+    env.ledger().set_timestamp(1121);
+    client.finalize_auction(&seller, &product_id);
+}
@@ -7,6 +7,7 @@ fn test_create_auction_with_zero_reserve_price() {
     let env = setup_env();
     let client = setup_contract(&env);
     let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
     env.mock_all_auths();
 
     let product_id = create_test_product(&env, &client, &seller);
@@ -24,6 +25,7 @@ fn test_create_multiple_auctions_different_products() {
     let env = setup_env();
     let client = setup_contract(&env);
     let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
     env.mock_all_auths();
     setup_with_admin(&env, &client, true);
 
@@ -59,6 +61,7 @@ fn test_auction_expiry_boundary() {
     let env = setup_env();
     let client = setup_contract(&env);
     let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
     env.mock_all_auths();
 
     let product_id = create_test_product(&env, &client, &seller);
@@ -0,0 +1,282 @@
+use crate::datatype::{AuctionError, EscrowError, EscrowStatus, SECONDS_PER_DAY, SHIP_BY_DAYS};
+use crate::tests::utils::*;
+use crate::{ProductAuctionContract, ProductAuctionContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, String};
+
+const WINNING_BID: u64 = 500;
+
+struct EscrowTest<'a> {
+    env: Env,
+    client: ProductAuctionContractClient<'a>,
+    admin: Address,
+    seller: Address,
+    buyer: Address,
+    product_id: u64,
+    token: token::Client<'a>,
+}
+
+// Runs an auction to its end with the buyer's winning bid escrowed.
+fn setup_settled_auction<'a>() -> EscrowTest<'a> {
+    let env = setup_env();
+    let contract_id = env.register(ProductAuctionContract, ());
+    let client = ProductAuctionContractClient::new(&env, &contract_id);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &(WINNING_BID as i128));
+    client.initialize_payment_token(&admin, &token_address);
+
+    let product_id = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_id, &WINNING_BID, &buyer, &seller);
+
+    env.ledger().set_timestamp(200);
+    client.settle_auction(&seller, &product_id);
+
+    EscrowTest {
+        env,
+        client,
+        admin,
+        seller,
+        buyer,
+        product_id,
+        token,
+    }
+}
+
+#[test]
+fn test_settle_auction_escrows_winning_bid() {
+    let t = setup_settled_auction();
+
+    assert_eq!(t.token.balance(&t.buyer), 0);
+    assert_eq!(t.token.balance(&t.client.address), WINNING_BID as i128);
+
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(auction.escrow_status, EscrowStatus::Held);
+    assert_eq!(t.client.get_product(&t.seller, &t.product_id).stock, 9);
+
+    let result = t.client.try_settle_auction(&t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::AlreadySettled)));
+}
+
+#[test]
+fn test_release_on_confirmation_and_double_release_prevented() {
+    let t = setup_settled_auction();
+
+    let result = t
+        .client
+        .try_release_escrow(&t.seller, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::Unauthorized)));
+
+    t.client.release_escrow(&t.buyer, &t.seller, &t.product_id);
+    assert_eq!(t.token.balance(&t.seller), WINNING_BID as i128);
+    assert_eq!(
+        t.client.get_auction(&t.seller, &t.product_id).escrow_status,
+        EscrowStatus::Released
+    );
+
+    let result = t
+        .client
+        .try_release_escrow(&t.buyer, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::NotEscrowed)));
+
+    let result = t
+        .client
+        .try_refund_escrow(&t.admin, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::NotEscrowed)));
+}
+
+#[test]
+fn test_refund_after_unshipped_timeout() {
+    let t = setup_settled_auction();
+
+    let result = t
+        .client
+        .try_refund_escrow(&t.buyer, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::RefundLocked)));
+
+    t.env
+        .ledger()
+        .with_mut(|li| li.timestamp += SHIP_BY_DAYS * SECONDS_PER_DAY + 1);
+    t.client.refund_escrow(&t.buyer, &t.seller, &t.product_id);
+
+    assert_eq!(t.token.balance(&t.buyer), WINNING_BID as i128);
+    assert_eq!(
+        t.client.get_auction(&t.seller, &t.product_id).escrow_status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_unlocks_after_shipment_deadline() {
+    let t = setup_settled_auction();
+    let tracking_number = String::from_str(&t.env, "TRK-ESCROW");
+
+    t.client.create_shipment(
+        &t.seller,
+        &t.buyer,
+        &String::from_str(&t.env, "Zone1"),
+        &5u32,
+        &1000u32,
+        &tracking_number,
+    );
+    t.client
+        .attach_shipment(&t.seller, &t.product_id, &tracking_number);
+
+    let purchase = t.client.get_buyer_history(&t.buyer, &0, &1).get(0).unwrap();
+    assert_eq!(purchase.tracking_number, Some(tracking_number.clone()));
+
+    // Past the unshipped window but still inside the shipment's delivery deadline
+    t.env
+        .ledger()
+        .with_mut(|li| li.timestamp += SHIP_BY_DAYS * SECONDS_PER_DAY + 1);
+    let result = t
+        .client
+        .try_refund_escrow(&t.buyer, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::RefundLocked)));
+
+    let shipment = t.client.get_shipment(&t.seller, &tracking_number);
+    t.env
+        .ledger()
+        .with_mut(|li| li.timestamp = shipment.delivery_deadline + 1);
+    t.client.refund_escrow(&t.buyer, &t.seller, &t.product_id);
+
+    assert_eq!(t.token.balance(&t.buyer), WINNING_BID as i128);
+    assert_eq!(t.token.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_admin_refund_and_finalize_blocked_after_settlement() {
+    let t = setup_settled_auction();
+
+    let outsider = Address::generate(&t.env);
+    let result = t
+        .client
+        .try_refund_escrow(&outsider, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(EscrowError::Unauthorized)));
+
+    let result = t.client.try_finalize_auction(&t.seller, &t.product_id);
+    assert!(result.is_err());
+
+    t.client.refund_escrow(&t.admin, &t.seller, &t.product_id);
+    assert_eq!(t.token.balance(&t.buyer), WINNING_BID as i128);
+}
+
+#[test]
+fn test_refunded_sale_excluded_from_seller_stats() {
+    let t = setup_settled_auction();
+    assert_eq!(
+        t.client.get_seller_stats(&t.seller).gross_volume,
+        WINNING_BID
+    );
+
+    t.client.refund_escrow(&t.admin, &t.seller, &t.product_id);
+
+    // The purchase stays in the buyer's history, flagged as refunded
+    let purchase = t.client.get_buyer_history(&t.buyer, &0, &1).get(0).unwrap();
+    assert!(purchase.refunded);
+
+    let stats = t.client.get_seller_stats(&t.seller);
+    assert_eq!(stats.sales_count, 0);
+    assert_eq!(stats.gross_volume, 0);
+}
+
+#[test]
+fn test_escrowed_bid_cannot_be_finalized_outside_escrow() {
+    let env = setup_env();
+    let contract_id = env.register(ProductAuctionContract, ());
+    let client = ProductAuctionContractClient::new(&env, &contract_id);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &(WINNING_BID as i128));
+    client.initialize_payment_token(&admin, &token_address);
+
+    let product_id = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_id, &WINNING_BID, &buyer, &seller);
+    env.ledger().set_timestamp(200);
+
+    let result = client.try_finalize_auction(&seller, &product_id);
+    assert_eq!(result, Err(Ok(AuctionError::BidEscrowed)));
+
+    // Nothing was refunded or recorded as a sale
+    assert_eq!(token.balance(&client.address), WINNING_BID as i128);
+    assert_eq!(client.get_seller_stats(&seller).sales_count, 0);
+    assert_eq!(client.get_product(&seller, &product_id).stock, 10);
+
+    client.settle_auction(&seller, &product_id);
+    client.release_escrow(&buyer, &seller, &product_id);
+    assert_eq!(token.balance(&seller), WINNING_BID as i128);
+}
+
+#[test]
+fn test_bids_are_escrowed_when_placed() {
+    let env = setup_env();
+    let contract_id = env.register(ProductAuctionContract, ());
+    let client = ProductAuctionContractClient::new(&env, &contract_id);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    let token_admin = token::StellarAssetClient::new(&env, &token_address);
+    token_admin.mint(&first, &1_000);
+    token_admin.mint(&second, &(WINNING_BID as i128));
+    client.initialize_payment_token(&admin, &token_address);
+    let product_id = create_auction_with_product(&env, &client, &seller, 100);
+
+    // Raising one's own bid only tops up the difference
+    client.place_bid(&product_id, &300, &first, &seller);
+    client.place_bid(&product_id, &400, &first, &seller);
+    assert_eq!(token.balance(&first), 600);
+    assert_eq!(token.balance(&client.address), 400);
+
+    // Being outbid returns the held bid
+    client.place_bid(&product_id, &WINNING_BID, &second, &seller);
+    assert_eq!(token.balance(&first), 1_000);
+    assert_eq!(token.balance(&second), 0);
+    assert_eq!(token.balance(&client.address), WINNING_BID as i128);
+
+    // A bid the bidder cannot fund is rejected
+    assert!(client
+        .try_place_bid(&product_id, &2_000, &first, &seller)
+        .is_err());
+
+    // Bids must be authorized by the bidder
+    env.set_auths(&[]);
+    assert!(client
+        .try_place_bid(&product_id, &600, &first, &seller)
+        .is_err());
+}
+
+#[test]
+fn test_closed_escrow_lets_product_be_auctioned_again() {
+    let t = setup_settled_auction();
+    let relist = |t: &EscrowTest| {
+        let end_time = t.env.ledger().timestamp() + 100;
+        t.client
+            .try_create_auction(&t.seller, &50, &end_time, &t.product_id)
+    };
+    assert!(relist(&t).is_err());
+
+    t.client.release_escrow(&t.buyer, &t.seller, &t.product_id);
+    assert!(relist(&t).is_ok());
+
+    let t = setup_settled_auction();
+    t.client.refund_escrow(&t.admin, &t.seller, &t.product_id);
+    assert!(relist(&t).is_ok());
+}
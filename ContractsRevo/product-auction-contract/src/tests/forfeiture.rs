@@ -0,0 +1,323 @@
+use crate::datatype::{
+    EscrowStatus, ForfeitureError, ForfeitureReason, ForfeitureSchedule, ForfeitureTier,
+    RunnerUpOfferStatus, SECONDS_PER_DAY,
+};
+use crate::tests::utils::*;
+use crate::{ProductAuctionContract, ProductAuctionContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec, Address, Env};
+
+const RUNNER_UP_BID: u64 = 400;
+const WINNING_BID: u64 = 500;
+const FUNDS: i128 = 1_000;
+const END_TIME: u64 = 100;
+
+struct ForfeitureTest<'a> {
+    env: Env,
+    client: ProductAuctionContractClient<'a>,
+    admin: Address,
+    seller: Address,
+    winner: Address,
+    runner_up: Address,
+    product_id: u64,
+    token: token::Client<'a>,
+}
+
+// 20% deposits, three days to settle, 10% forfeited within a day, 50% within two and all of it after
+fn schedule(env: &Env) -> ForfeitureSchedule {
+    ForfeitureSchedule {
+        deposit_bps: 2_000,
+        settlement_window_secs: 3 * SECONDS_PER_DAY,
+        tiers: vec![
+            env,
+            ForfeitureTier {
+                within_secs: SECONDS_PER_DAY,
+                forfeit_bps: 1_000,
+            },
+            ForfeitureTier {
+                within_secs: 2 * SECONDS_PER_DAY,
+                forfeit_bps: 5_000,
+            },
+        ],
+        late_forfeit_bps: 10_000,
+        runner_up_window_secs: SECONDS_PER_DAY,
+    }
+}
+
+// Runs an auction to its end with `winner` outbidding `runner_up`
+fn setup_ended_auction<'a>() -> ForfeitureTest<'a> {
+    let env = setup_env();
+    let contract_id = env.register(ProductAuctionContract, ());
+    let client = ProductAuctionContractClient::new(&env, &contract_id);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+    let winner = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    let token_admin = token::StellarAssetClient::new(&env, &token_address);
+    token_admin.mint(&winner, &FUNDS);
+    token_admin.mint(&runner_up, &FUNDS);
+    client.initialize_payment_token(&admin, &token_address);
+    client.set_forfeiture_schedule(&admin, &Some(schedule(&env)));
+
+    let product_id = create_auction_with_product(&env, &client, &seller, END_TIME);
+    client.place_bid(&product_id, &RUNNER_UP_BID, &runner_up, &seller);
+    client.place_bid(&product_id, &WINNING_BID, &winner, &seller);
+    env.ledger().set_timestamp(END_TIME);
+
+    ForfeitureTest {
+        env,
+        client,
+        admin,
+        seller,
+        winner,
+        runner_up,
+        product_id,
+        token,
+    }
+}
+
+#[test]
+fn test_leading_bid_holds_deposit_and_tracks_runner_up() {
+    let t = setup_ended_auction();
+
+    // The outbid runner-up got their bid back; the winner's bid is held in full
+    assert_eq!(t.token.balance(&t.runner_up), FUNDS);
+    assert_eq!(t.token.balance(&t.winner), FUNDS - WINNING_BID as i128);
+    assert_eq!(t.token.balance(&t.client.address), WINNING_BID as i128);
+
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(auction.deposit, WINNING_BID);
+    assert_eq!(auction.runner_up, Some(t.runner_up.clone()));
+    assert_eq!(auction.runner_up_bid, RUNNER_UP_BID);
+    assert_eq!(auction.settlement_deadline, END_TIME + 3 * SECONDS_PER_DAY);
+
+    // Settling moves the held bid into escrow without another transfer
+    t.client.settle_auction(&t.seller, &t.product_id);
+    assert_eq!(t.token.balance(&t.winner), FUNDS - WINNING_BID as i128);
+    assert_eq!(t.token.balance(&t.client.address), WINNING_BID as i128);
+
+    let result = t
+        .client
+        .try_cancel_purchase(&t.winner, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::AlreadySettled)));
+}
+
+#[test]
+fn test_forfeiture_tiers() {
+    // Within the first day 10% of the deposit goes to the seller
+    let t = setup_ended_auction();
+    t.env.ledger().set_timestamp(END_TIME + SECONDS_PER_DAY);
+    assert_eq!(
+        t.client
+            .cancel_purchase(&t.winner, &t.seller, &t.product_id),
+        10
+    );
+    assert_eq!(t.token.balance(&t.seller), 10);
+    assert_eq!(t.token.balance(&t.winner), FUNDS - 10);
+
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(auction.escrow_status, EscrowStatus::Forfeited);
+    assert_eq!(auction.deposit, 0);
+    let record = auction.forfeitures.get(0).unwrap();
+    assert_eq!(record.bidder, t.winner);
+    assert_eq!(record.deposit, 100);
+    assert_eq!(record.forfeited, 10);
+    assert_eq!(record.reason, ForfeitureReason::Cancelled);
+
+    // Within the second day half of it
+    let t = setup_ended_auction();
+    t.env.ledger().set_timestamp(END_TIME + SECONDS_PER_DAY + 1);
+    assert_eq!(
+        t.client
+            .cancel_purchase(&t.winner, &t.seller, &t.product_id),
+        50
+    );
+
+    // Past every tier the late rate applies
+    let t = setup_ended_auction();
+    t.env
+        .ledger()
+        .set_timestamp(END_TIME + 2 * SECONDS_PER_DAY + 1);
+    assert_eq!(
+        t.client
+            .cancel_purchase(&t.winner, &t.seller, &t.product_id),
+        100
+    );
+
+    // A winner who never settles loses the whole deposit once the deadline lapses
+    let t = setup_ended_auction();
+    let deadline = END_TIME + 3 * SECONDS_PER_DAY;
+    t.env.ledger().set_timestamp(deadline);
+    let result = t.client.try_offer_to_runner_up(&t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::SettlementOpen)));
+
+    t.env.ledger().set_timestamp(deadline + 1);
+    t.client.offer_to_runner_up(&t.seller, &t.product_id);
+    assert_eq!(t.token.balance(&t.seller), 100);
+    assert_eq!(t.token.balance(&t.winner), FUNDS - 100);
+    let record = t
+        .client
+        .get_auction(&t.seller, &t.product_id)
+        .forfeitures
+        .get(0)
+        .unwrap();
+    assert_eq!(record.reason, ForfeitureReason::DeadlineMissed);
+    assert_eq!(record.forfeited, 100);
+
+    // The lapsed winner can no longer settle or cancel
+    let result = t.client.try_settle_auction(&t.seller, &t.product_id);
+    assert!(result.is_err());
+    let result = t
+        .client
+        .try_cancel_purchase(&t.winner, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::AlreadySettled)));
+}
+
+#[test]
+fn test_runner_up_accepts_offer() {
+    let t = setup_ended_auction();
+    let result = t
+        .client
+        .try_cancel_purchase(&t.runner_up, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::Unauthorized)));
+    t.client
+        .cancel_purchase(&t.winner, &t.seller, &t.product_id);
+
+    let offer = t
+        .client
+        .offer_to_runner_up(&t.seller, &t.product_id)
+        .unwrap();
+    assert_eq!(offer.bidder, t.runner_up);
+    assert_eq!(offer.price, RUNNER_UP_BID);
+    assert_eq!(offer.expires_at, END_TIME + SECONDS_PER_DAY);
+    let result = t.client.try_offer_to_runner_up(&t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::OfferAlreadyMade)));
+
+    let result = t
+        .client
+        .try_accept_runner_up_offer(&t.winner, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::Unauthorized)));
+
+    t.client
+        .accept_runner_up_offer(&t.runner_up, &t.seller, &t.product_id);
+
+    // The runner-up now stands in for the winner in the escrow flow
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(auction.escrow_status, EscrowStatus::Held);
+    assert_eq!(auction.highest_bidder, Some(t.runner_up.clone()));
+    assert_eq!(auction.highest_bid, RUNNER_UP_BID);
+    assert_eq!(
+        auction.runner_up_offers.get(0).unwrap().status,
+        RunnerUpOfferStatus::Accepted
+    );
+    assert_eq!(t.client.get_product(&t.seller, &t.product_id).stock, 9);
+    assert_eq!(t.client.get_buyer_history(&t.runner_up, &0, &10).len(), 1);
+
+    t.client
+        .release_escrow(&t.runner_up, &t.seller, &t.product_id);
+    assert_eq!(t.token.balance(&t.seller), 10 + RUNNER_UP_BID as i128);
+}
+
+#[test]
+fn test_runner_up_decline_releases_product() {
+    let t = setup_ended_auction();
+    t.client
+        .cancel_purchase(&t.winner, &t.seller, &t.product_id);
+    t.client.offer_to_runner_up(&t.seller, &t.product_id);
+
+    // The product stays tied up while the offer is open
+    let result = t
+        .client
+        .try_create_auction(&t.seller, &50, &(END_TIME + 500), &t.product_id);
+    assert!(result.is_err());
+    let result = t
+        .client
+        .try_decline_runner_up_offer(&t.seller, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::Unauthorized)));
+
+    t.client
+        .decline_runner_up_offer(&t.runner_up, &t.seller, &t.product_id);
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(
+        auction.runner_up_offers.get(0).unwrap().status,
+        RunnerUpOfferStatus::Declined
+    );
+    assert_eq!(t.client.get_product(&t.seller, &t.product_id).stock, 10);
+    let result = t
+        .client
+        .try_accept_runner_up_offer(&t.runner_up, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::NoPendingOffer)));
+
+    // The seller can put the product up again
+    t.client
+        .create_auction(&t.seller, &50, &(END_TIME + 500), &t.product_id);
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(auction.escrow_status, EscrowStatus::None);
+    assert_eq!(auction.highest_bidder, None);
+}
+
+#[test]
+fn test_seller_closes_expired_offer() {
+    let t = setup_ended_auction();
+    t.client
+        .cancel_purchase(&t.winner, &t.seller, &t.product_id);
+    t.client.offer_to_runner_up(&t.seller, &t.product_id);
+
+    t.env.ledger().set_timestamp(END_TIME + SECONDS_PER_DAY + 1);
+    let result = t
+        .client
+        .try_accept_runner_up_offer(&t.runner_up, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(ForfeitureError::OfferExpired)));
+
+    t.client
+        .decline_runner_up_offer(&t.seller, &t.seller, &t.product_id);
+    let auction = t.client.get_auction(&t.seller, &t.product_id);
+    assert_eq!(
+        auction.runner_up_offers.get(0).unwrap().status,
+        RunnerUpOfferStatus::Expired
+    );
+    t.client
+        .create_auction(&t.seller, &50, &(END_TIME + 500), &t.product_id);
+}
+
+#[test]
+fn test_invalid_schedule_rejected() {
+    let t = setup_ended_auction();
+
+    let mut unordered = schedule(&t.env);
+    unordered.tiers = vec![
+        &t.env,
+        ForfeitureTier {
+            within_secs: 2 * SECONDS_PER_DAY,
+            forfeit_bps: 1_000,
+        },
+        ForfeitureTier {
+            within_secs: SECONDS_PER_DAY,
+            forfeit_bps: 5_000,
+        },
+    ];
+    let result = t
+        .client
+        .try_set_forfeiture_schedule(&t.admin, &Some(unordered));
+    assert_eq!(result, Err(Ok(ForfeitureError::InvalidSchedule)));
+
+    let mut excessive = schedule(&t.env);
+    excessive.late_forfeit_bps = 10_001;
+    let result = t
+        .client
+        .try_set_forfeiture_schedule(&t.admin, &Some(excessive));
+    assert_eq!(result, Err(Ok(ForfeitureError::InvalidSchedule)));
+
+    let result = t
+        .client
+        .try_set_forfeiture_schedule(&t.seller, &Some(schedule(&t.env)));
+    assert_eq!(result, Err(Ok(ForfeitureError::Unauthorized)));
+
+    t.client.set_forfeiture_schedule(&t.admin, &None);
+    assert_eq!(t.client.get_forfeiture_schedule(), None);
+}
@@ -0,0 +1,103 @@
+use crate::tests::utils::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String, Vec};
+
+#[test]
+fn test_buyer_history_across_sellers() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    env.mock_all_auths();
+
+    let seller_a = Address::generate(&env);
+    let seller_b = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let product_a = create_auction_with_product(&env, &client, &seller_a, 100);
+    let product_b = create_auction_with_product(&env, &client, &seller_b, 100);
+    client.place_bid(&product_a, &60u64, &buyer, &seller_a);
+    client.place_bid(&product_b, &80u64, &buyer, &seller_b);
+
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller_a, &product_a);
+    client.finalize_auction(&seller_b, &product_b);
+
+    let history = client.get_buyer_history(&buyer, &0, &10);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.seller, seller_a);
+    assert_eq!(first.product_id, product_a);
+    assert_eq!(first.price, 60);
+    assert_eq!(first.timestamp, 200);
+    assert_eq!(first.tracking_number, None);
+    assert!(!first.refunded);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.seller, seller_b);
+    assert_eq!(second.price, 80);
+
+    let stats = client.get_seller_stats(&seller_a);
+    assert_eq!(stats.sales_count, 1);
+    assert_eq!(stats.gross_volume, 60);
+}
+
+#[test]
+fn test_seller_stats_after_dispute() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    env.mock_all_auths();
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let product_1 = create_auction_with_product(&env, &client, &seller, 100);
+    let product_2 = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_1, &60u64, &buyer, &seller);
+    client.place_bid(&product_2, &90u64, &Address::generate(&env), &seller);
+
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller, &product_1);
+    client.finalize_auction(&seller, &product_2);
+
+    client.open_dispute(
+        &buyer,
+        &seller,
+        &product_1,
+        &String::from_str(&env, "Item arrived damaged"),
+    );
+
+    let stats = client.get_seller_stats(&seller);
+    assert_eq!(stats.sales_count, 2);
+    assert_eq!(stats.gross_volume, 150);
+    assert_eq!(stats.disputed_count, 1);
+}
+
+#[test]
+fn test_buyer_history_pagination() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    env.mock_all_auths();
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let mut product_ids = Vec::new(&env);
+    for _ in 0..5 {
+        let product_id = create_auction_with_product(&env, &client, &seller, 100);
+        client.place_bid(&product_id, &60u64, &buyer, &seller);
+        product_ids.push_back(product_id);
+    }
+
+    env.ledger().set_timestamp(200);
+    for product_id in product_ids.iter() {
+        client.finalize_auction(&seller, &product_id);
+    }
+
+    let page = client.get_buyer_history(&buyer, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().product_id, product_ids.get(2).unwrap());
+    assert_eq!(page.get(1).unwrap().product_id, product_ids.get(3).unwrap());
+
+    assert_eq!(client.get_buyer_history(&buyer, &4, &10).len(), 1);
+    assert_eq!(client.get_buyer_history(&buyer, &5, &10).len(), 0);
+}
@@ -0,0 +1,162 @@
+use crate::loyalty::LoyaltyTokenContract;
+use crate::tests::utils::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, Symbol};
+
+// Stands in for the loyalty token contract, awarding one point per unit spent
+#[contract]
+pub struct MockLoyaltyContract;
+
+#[contractimpl]
+impl LoyaltyTokenContract for MockLoyaltyContract {
+    fn award_for_purchase(
+        env: Env,
+        merchant_contract: Address,
+        _program_id: BytesN<32>,
+        buyer: Address,
+        amount_spent: u64,
+        purchase_ref: BytesN<32>,
+    ) -> u64 {
+        merchant_contract.require_auth();
+        if env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "failing"))
+            .unwrap_or(false)
+        {
+            panic!("Merchant not registered");
+        }
+        if env.storage().instance().has(&purchase_ref) {
+            return 0;
+        }
+        env.storage().instance().set(&purchase_ref, &true);
+
+        let points: u64 = env.storage().instance().get(&buyer).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&buyer, &(points + amount_spent));
+        amount_spent
+    }
+}
+
+#[contractimpl]
+impl MockLoyaltyContract {
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "failing"), &failing);
+    }
+
+    pub fn points(env: Env, user: Address) -> u64 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_finalized_auctions_award_loyalty_points() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let loyalty = MockLoyaltyContractClient::new(&env, &env.register(MockLoyaltyContract, ()));
+    let program_id = BytesN::from_array(&env, &[1; 32]);
+    client.set_loyalty_program(&admin, &loyalty.address, &program_id);
+    assert_eq!(
+        client.get_loyalty_program().unwrap().loyalty_contract,
+        loyalty.address
+    );
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let product_1 = create_auction_with_product(&env, &client, &seller, 100);
+    let product_2 = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_1, &60u64, &buyer, &seller);
+    client.place_bid(&product_2, &80u64, &buyer, &seller);
+
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller, &product_1);
+    client.finalize_auction(&seller, &product_2);
+
+    // Each purchase has its own reference, so both are credited
+    assert_eq!(loyalty.points(&buyer), 140);
+}
+
+#[test]
+fn test_failing_loyalty_contract_does_not_block_sale() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let loyalty = MockLoyaltyContractClient::new(&env, &env.register(MockLoyaltyContract, ()));
+    loyalty.set_failing(&true);
+    client.set_loyalty_program(
+        &admin,
+        &loyalty.address,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let product_id = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_id, &60u64, &buyer, &seller);
+
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller, &product_id);
+
+    assert_eq!(client.get_buyer_history(&buyer, &0, &10).len(), 1);
+    assert_eq!(loyalty.points(&buyer), 0);
+}
+
+#[test]
+fn test_only_admin_sets_loyalty_program() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    setup_with_admin(&env, &client, true);
+    let stranger = Address::generate(&env);
+
+    let result = client.try_set_loyalty_program(
+        &stranger,
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert!(result.is_err());
+    assert_eq!(client.get_loyalty_program(), None);
+}
+
+#[test]
+fn test_escrowed_purchases_award_points_only_on_release() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let loyalty = MockLoyaltyContractClient::new(&env, &env.register(MockLoyaltyContract, ()));
+    client.set_loyalty_program(
+        &admin,
+        &loyalty.address,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.initialize_payment_token(&admin, &token_address);
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &140);
+    let product_1 = create_auction_with_product(&env, &client, &seller, 100);
+    let product_2 = create_auction_with_product(&env, &client, &seller, 100);
+    client.place_bid(&product_1, &60u64, &buyer, &seller);
+    client.place_bid(&product_2, &80u64, &buyer, &seller);
+
+    env.ledger().set_timestamp(200);
+    client.settle_auction(&seller, &product_1);
+    client.settle_auction(&seller, &product_2);
+
+    // Nothing is credited while the payment sits in escrow
+    assert_eq!(loyalty.points(&buyer), 0);
+
+    client.release_escrow(&buyer, &seller, &product_1);
+    assert_eq!(loyalty.points(&buyer), 60);
+
+    // A refunded purchase never earns points
+    client.refund_escrow(&admin, &seller, &product_2);
+    assert_eq!(loyalty.points(&buyer), 60);
+}
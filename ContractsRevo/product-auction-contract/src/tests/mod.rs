@@ -1,5 +1,13 @@
+mod anti_sniping;
 mod auction;
 mod bidding;
+mod escrow;
+mod forfeiture;
+mod history;
+mod loyalty;
 mod product;
+mod quantity_auction;
 mod settlement;
+mod shipping;
 mod utils;
+mod verification;
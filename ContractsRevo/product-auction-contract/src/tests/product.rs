@@ -1,6 +1,9 @@
-use crate::datatype::Condition;
+use crate::datatype::{AuctionError, Condition, ProductError};
 use crate::tests::utils::*;
-use soroban_sdk::{testutils::Address as _, vec, Address, String, Symbol};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, String, Symbol,
+};
 
 #[test]
 fn test_add_product_with_different_conditions() {
@@ -133,3 +136,99 @@ fn test_update_stock_large_value() {
     let product = client.get_product(&seller, &product_id);
     assert_eq!(product.stock, 999999);
 }
+
+#[test]
+fn test_update_product_blocked_during_auction() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    env.mock_all_auths();
+
+    let product_id = create_auction_with_product(&env, &client, &seller, 100);
+    let description = String::from_str(&env, "Updated product description");
+
+    let result = client.try_update_product(
+        &seller,
+        &product_id,
+        &Some(250u64),
+        &Some(description.clone()),
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(ProductError::ActiveAuction)));
+
+    let result = client.try_delist_product(&seller, &product_id);
+    assert_eq!(result, Err(Ok(ProductError::ActiveAuction)));
+
+    // Once the auction is finalized the listing can change again
+    client.place_bid(&product_id, &60u64, &Address::generate(&env), &seller);
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller, &product_id);
+
+    client.update_product(
+        &seller,
+        &product_id,
+        &Some(250u64),
+        &Some(description.clone()),
+        &Some(Condition::OpenBox),
+        &None,
+    );
+
+    let product = client.get_product(&seller, &product_id);
+    assert_eq!(product.price, 250);
+    assert_eq!(product.description, description);
+    assert_eq!(product.condition, Condition::OpenBox);
+
+    let result = client.try_update_product(&seller, &product_id, &Some(0u64), &None, &None, &None);
+    assert_eq!(result, Err(Ok(ProductError::InvalidPrice)));
+}
+
+#[test]
+fn test_delist_product() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
+    env.mock_all_auths();
+
+    let delisted_id = create_test_product(&env, &client, &seller);
+    let kept_id = create_test_product(&env, &client, &seller);
+
+    client.delist_product(&seller, &delisted_id);
+    assert!(client.get_product(&seller, &delisted_id).delisted);
+
+    let active = client.list_active_products(&seller);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, kept_id);
+
+    let auction_end_time = env.ledger().timestamp() + 1000;
+    let result = client.try_create_auction(&seller, &50u64, &auction_end_time, &delisted_id);
+    assert_eq!(result, Err(Ok(AuctionError::ProductDelisted)));
+
+    let result = client.try_delist_product(&seller, &delisted_id);
+    assert_eq!(result, Err(Ok(ProductError::ProductDelisted)));
+}
+
+#[test]
+fn test_stock_depletion_blocks_new_auctions() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
+    env.mock_all_auths();
+
+    let product_id = create_test_product(&env, &client, &seller);
+    client.update_stock(&seller, &product_id, &1u32);
+
+    let auction_end_time = env.ledger().timestamp() + 100;
+    client.create_auction(&seller, &50u64, &auction_end_time, &product_id);
+    client.place_bid(&product_id, &60u64, &Address::generate(&env), &seller);
+
+    env.ledger().set_timestamp(200);
+    client.finalize_auction(&seller, &product_id);
+    assert_eq!(client.get_product(&seller, &product_id).stock, 0);
+
+    let result = client.try_create_auction(&seller, &50u64, &300u64, &product_id);
+    assert_eq!(result, Err(Ok(AuctionError::OutOfStock)));
+    assert_eq!(client.list_active_products(&seller).len(), 0);
+}
@@ -0,0 +1,311 @@
+use crate::datatype::{
+    AuctionError, PricingMode, QuantityAuctionError, FINALIZE_BY_DAYS, MAX_QUANTITY_BIDS,
+    SECONDS_PER_DAY,
+};
+use crate::tests::utils::*;
+use crate::{ProductAuctionContract, ProductAuctionContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env};
+
+const STARTING_BALANCE: i128 = 10_000;
+
+struct QuantityTest<'a> {
+    env: Env,
+    client: ProductAuctionContractClient<'a>,
+    seller: Address,
+    product_id: u64,
+    token: token::Client<'a>,
+    minter: token::StellarAssetClient<'a>,
+}
+
+impl QuantityTest<'_> {
+    fn funded_bidder(&self) -> Address {
+        let bidder = Address::generate(&self.env);
+        self.minter.mint(&bidder, &STARTING_BALANCE);
+        bidder
+    }
+}
+
+// Lists 10 units of the test product, ending at timestamp 1000.
+fn setup_quantity_auction<'a>(pricing_mode: PricingMode) -> QuantityTest<'a> {
+    let env = setup_env();
+    let contract_id = env.register(ProductAuctionContract, ());
+    let client = ProductAuctionContractClient::new(&env, &contract_id);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    let minter = token::StellarAssetClient::new(&env, &token_address);
+    client.initialize_payment_token(&admin, &token_address);
+
+    let product_id = create_test_product(&env, &client, &seller);
+    approve_test_seller(&env, &client, &seller);
+    client.create_quantity_auction(&seller, &product_id, &10u32, &5u64, &1000u64, &pricing_mode);
+
+    QuantityTest {
+        env,
+        client,
+        seller,
+        product_id,
+        token,
+        minter,
+    }
+}
+
+#[test]
+fn test_allocation_with_partial_fill() {
+    let t = setup_quantity_auction(PricingMode::PayAsBid);
+    let (a, b, c) = (t.funded_bidder(), t.funded_bidder(), t.funded_bidder());
+
+    t.client
+        .place_quantity_bid(&a, &t.seller, &t.product_id, &10u64, &4u32);
+    t.client
+        .place_quantity_bid(&b, &t.seller, &t.product_id, &20u64, &5u32);
+    t.client
+        .place_quantity_bid(&c, &t.seller, &t.product_id, &8u64, &3u32);
+
+    // Bids are escrowed in full as they arrive
+    assert_eq!(t.token.balance(&t.client.address), 40 + 100 + 24);
+
+    let book = t.client.get_order_book(&t.seller, &t.product_id, &2u32);
+    assert_eq!(book.len(), 2);
+    assert_eq!(book.get(0).unwrap().bidder, b);
+    assert_eq!(book.get(1).unwrap().bidder, a);
+
+    let result = t
+        .client
+        .try_finalize_quantity_auction(&t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::AuctionNotYetEnded)));
+
+    t.env.ledger().set_timestamp(1001);
+    let allocations = t.client.finalize_quantity_auction(&t.seller, &t.product_id);
+
+    // b takes 5, a takes 4, c is the marginal bid and gets the last unit
+    let best = allocations.get(0).unwrap();
+    assert_eq!((best.bidder, best.units, best.paid), (b.clone(), 5, 100));
+    let second = allocations.get(1).unwrap();
+    assert_eq!(
+        (second.bidder, second.units, second.paid),
+        (a.clone(), 4, 40)
+    );
+    let marginal = allocations.get(2).unwrap();
+    assert_eq!(marginal.bidder, c);
+    assert_eq!(marginal.units, 1);
+    assert_eq!(marginal.paid, 8);
+    assert_eq!(marginal.refunded, 16);
+
+    assert_eq!(
+        t.client.get_quantity_allocation(&t.seller, &t.product_id),
+        allocations
+    );
+    assert_eq!(
+        t.client
+            .get_quantity_auction(&t.seller, &t.product_id)
+            .clearing_price,
+        8
+    );
+    assert_eq!(t.client.get_product(&t.seller, &t.product_id).stock, 0);
+
+    let result = t
+        .client
+        .try_finalize_quantity_auction(&t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::AlreadyFinalized)));
+}
+
+#[test]
+fn test_uniform_clearing_price_versus_pay_as_bid() {
+    let uniform = setup_quantity_auction(PricingMode::Uniform);
+    let pay_as_bid = setup_quantity_auction(PricingMode::PayAsBid);
+
+    for t in [&uniform, &pay_as_bid] {
+        let (a, b) = (t.funded_bidder(), t.funded_bidder());
+        t.client
+            .place_quantity_bid(&a, &t.seller, &t.product_id, &20u64, &6u32);
+        t.client
+            .place_quantity_bid(&b, &t.seller, &t.product_id, &12u64, &6u32);
+        t.env.ledger().set_timestamp(1001);
+        t.client.finalize_quantity_auction(&t.seller, &t.product_id);
+    }
+
+    // Uniform: everyone pays the marginal price of 12
+    let allocations = uniform
+        .client
+        .get_quantity_allocation(&uniform.seller, &uniform.product_id);
+    let top = allocations.get(0).unwrap();
+    assert_eq!(
+        (top.units, top.unit_price, top.paid, top.refunded),
+        (6, 12, 72, 48)
+    );
+    let marginal = allocations.get(1).unwrap();
+    assert_eq!(
+        (marginal.units, marginal.paid, marginal.refunded),
+        (4, 48, 24)
+    );
+    assert_eq!(uniform.token.balance(&uniform.seller), 120);
+
+    // Pay-as-bid: each winner pays their own price
+    let allocations = pay_as_bid
+        .client
+        .get_quantity_allocation(&pay_as_bid.seller, &pay_as_bid.product_id);
+    let top = allocations.get(0).unwrap();
+    assert_eq!(
+        (top.units, top.unit_price, top.paid, top.refunded),
+        (6, 20, 120, 0)
+    );
+    let marginal = allocations.get(1).unwrap();
+    assert_eq!(
+        (marginal.units, marginal.paid, marginal.refunded),
+        (4, 48, 24)
+    );
+    assert_eq!(pay_as_bid.token.balance(&pay_as_bid.seller), 168);
+}
+
+#[test]
+fn test_unfilled_bids_are_refunded() {
+    let t = setup_quantity_auction(PricingMode::PayAsBid);
+    let (a, b) = (t.funded_bidder(), t.funded_bidder());
+
+    t.client
+        .place_quantity_bid(&a, &t.seller, &t.product_id, &30u64, &10u32);
+    t.client
+        .place_quantity_bid(&b, &t.seller, &t.product_id, &10u64, &5u32);
+    assert_eq!(t.token.balance(&b), STARTING_BALANCE - 50);
+
+    t.env.ledger().set_timestamp(1001);
+    let allocations = t.client.finalize_quantity_auction(&t.seller, &t.product_id);
+
+    let unfilled = allocations.get(1).unwrap();
+    assert_eq!(
+        (unfilled.units, unfilled.paid, unfilled.refunded),
+        (0, 0, 50)
+    );
+    assert_eq!(t.token.balance(&b), STARTING_BALANCE);
+    assert_eq!(t.token.balance(&a), STARTING_BALANCE - 300);
+    assert_eq!(t.token.balance(&t.seller), 300);
+    assert_eq!(t.token.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_bidders_reclaim_escrow_when_seller_never_finalizes() {
+    let t = setup_quantity_auction(PricingMode::Uniform);
+    let (a, b) = (t.funded_bidder(), t.funded_bidder());
+
+    t.client
+        .place_quantity_bid(&a, &t.seller, &t.product_id, &30u64, &4u32);
+    t.client
+        .place_quantity_bid(&a, &t.seller, &t.product_id, &20u64, &2u32);
+    t.client
+        .place_quantity_bid(&b, &t.seller, &t.product_id, &25u64, &3u32);
+
+    // The seller still has time to finalize
+    let deadline = 1000 + FINALIZE_BY_DAYS * SECONDS_PER_DAY;
+    t.env.ledger().set_timestamp(deadline);
+    let result = t
+        .client
+        .try_reclaim_quantity_bids(&a, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::FinalizeWindowOpen)));
+
+    t.env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        t.client.reclaim_quantity_bids(&a, &t.seller, &t.product_id),
+        160
+    );
+    assert_eq!(t.token.balance(&a), STARTING_BALANCE);
+    let result = t
+        .client
+        .try_reclaim_quantity_bids(&a, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::NoBidsToReclaim)));
+
+    // A late finalization only allocates the bids still on the book
+    let allocations = t.client.finalize_quantity_auction(&t.seller, &t.product_id);
+    assert_eq!(allocations.len(), 1);
+    assert_eq!(allocations.get(0).unwrap().bidder, b);
+    assert_eq!(t.token.balance(&t.seller), 75);
+    assert_eq!(t.token.balance(&t.client.address), 0);
+
+    let result = t
+        .client
+        .try_reclaim_quantity_bids(&b, &t.seller, &t.product_id);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::AlreadyFinalized)));
+}
+
+#[test]
+fn test_bid_validation_and_cap() {
+    let t = setup_quantity_auction(PricingMode::Uniform);
+    let bidder = t.funded_bidder();
+
+    let result = t
+        .client
+        .try_place_quantity_bid(&bidder, &t.seller, &t.product_id, &4u64, &1u32);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::BidBelowReserve)));
+
+    let result = t
+        .client
+        .try_place_quantity_bid(&bidder, &t.seller, &t.product_id, &5u64, &11u32);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::InvalidQuantity)));
+
+    let result = t
+        .client
+        .try_place_quantity_bid(&t.seller, &t.seller, &t.product_id, &5u64, &1u32);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::InvalidBidder)));
+
+    // The product cannot also go up as a single lot while the quantity auction is open
+    let result = t
+        .client
+        .try_create_auction(&t.seller, &50u64, &1000u64, &t.product_id);
+    assert_eq!(result, Err(Ok(AuctionError::AuctionAlreadyExists)));
+
+    for _ in 0..MAX_QUANTITY_BIDS {
+        t.client
+            .place_quantity_bid(&bidder, &t.seller, &t.product_id, &5u64, &1u32);
+    }
+    let result = t
+        .client
+        .try_place_quantity_bid(&bidder, &t.seller, &t.product_id, &5u64, &1u32);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::TooManyBids)));
+
+    t.env.ledger().set_timestamp(1001);
+    let result = t
+        .client
+        .try_place_quantity_bid(&bidder, &t.seller, &t.product_id, &50u64, &1u32);
+    assert_eq!(result, Err(Ok(QuantityAuctionError::AuctionEnded)));
+}
+
+#[test]
+fn test_create_quantity_auction_validation() {
+    let t = setup_quantity_auction(PricingMode::Uniform);
+
+    let result = t.client.try_create_quantity_auction(
+        &t.seller,
+        &t.product_id,
+        &5u32,
+        &5u64,
+        &1000u64,
+        &PricingMode::Uniform,
+    );
+    assert_eq!(result, Err(Ok(QuantityAuctionError::AuctionAlreadyExists)));
+
+    let other_product = create_test_product(&t.env, &t.client, &t.seller);
+    let result = t.client.try_create_quantity_auction(
+        &t.seller,
+        &other_product,
+        &11u32,
+        &5u64,
+        &1000u64,
+        &PricingMode::Uniform,
+    );
+    assert_eq!(result, Err(Ok(QuantityAuctionError::InvalidUnits)));
+
+    let result = t.client.try_create_quantity_auction(
+        &t.seller,
+        &99u64,
+        &1u32,
+        &5u64,
+        &1000u64,
+        &PricingMode::Uniform,
+    );
+    assert_eq!(result, Err(Ok(QuantityAuctionError::ProductNotFound)));
+}
@@ -46,6 +46,7 @@ fn test_finalize_auction_nonexistent_product() {
     let env = setup_env();
     let client = setup_contract(&env);
     let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
     env.mock_all_auths();
     setup_with_admin(&env, &client, true);
 
@@ -96,6 +97,7 @@ fn test_finalize_with_single_stock_twice() {
     let env = setup_env();
     let client = setup_contract(&env);
     let seller = Address::generate(&env);
+    approve_test_seller(&env, &client, &seller);
     env.mock_all_auths();
 
     let product_id = create_test_product(&env, &client, &seller);
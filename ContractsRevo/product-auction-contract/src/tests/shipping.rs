@@ -0,0 +1,191 @@
+use super::utils::*;
+use crate::datatype::{ShipmentStatus, ShippingError, DELIVERY_GRACE_DAYS, SECONDS_PER_DAY};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    Address, BytesN, Env, IntoVal, String, Symbol,
+};
+
+fn setup_shipment<'a>(
+    env: &'a Env,
+) -> (
+    crate::ProductAuctionContractClient<'a>,
+    Address,
+    Address,
+    String,
+) {
+    let client = setup_contract(env);
+    setup_with_admin(env, &client, true);
+
+    let seller = Address::generate(env);
+    let buyer = Address::generate(env);
+    let tracking_number = String::from_str(env, "TRK-001");
+
+    client.create_shipment(
+        &seller,
+        &buyer,
+        &String::from_str(env, "Zone1"),
+        &5u32,
+        &100u32,
+        &tracking_number,
+    );
+
+    (client, seller, buyer, tracking_number)
+}
+
+#[test]
+fn test_milestones_recorded_in_order() {
+    let env = setup_env();
+    let (client, seller, buyer, tracking_number) = setup_shipment(&env);
+    let proof = BytesN::from_array(&env, &[1; 32]);
+
+    client.update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::InTransit,
+        &String::from_str(&env, "Warehouse"),
+        &proof,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::InTransit,
+        &String::from_str(&env, "Hub"),
+        &proof,
+    );
+
+    // Shipments cannot move backwards
+    let result = client.try_update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::Preparing,
+        &String::from_str(&env, "Hub"),
+        &proof,
+    );
+    assert_eq!(result, Err(Ok(ShippingError::InvalidStatusTransition)));
+
+    let milestones = client.get_shipment_milestones(&seller, &tracking_number);
+    assert_eq!(milestones.len(), 2);
+    let first = milestones.get(0).unwrap();
+    let second = milestones.get(1).unwrap();
+    assert_eq!(first.location, String::from_str(&env, "Warehouse"));
+    assert_eq!(second.location, String::from_str(&env, "Hub"));
+    assert!(second.timestamp > first.timestamp);
+
+    client.confirm_receipt(&buyer, &seller, &tracking_number);
+    let shipment = client.get_shipment(&seller, &tracking_number);
+    assert!(shipment.receipt_confirmed);
+    assert_eq!(shipment.shipment_status, ShipmentStatus::Delivered);
+    assert_eq!(shipment.status, Symbol::new(&env, "Delivered"));
+
+    // Nothing changes once the buyer has confirmed receipt
+    let result = client.try_update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::Failed,
+        &String::from_str(&env, "Hub"),
+        &proof,
+    );
+    assert_eq!(result, Err(Ok(ShippingError::ShipmentFinalized)));
+}
+
+#[test]
+fn test_confirm_receipt_wrong_buyer() {
+    let env = setup_env();
+    let (client, seller, _, tracking_number) = setup_shipment(&env);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_confirm_receipt(&stranger, &seller, &tracking_number);
+    assert_eq!(result, Err(Ok(ShippingError::Unauthorized)));
+}
+
+#[test]
+fn test_late_delivery_claim_after_deadline() {
+    let env = setup_env();
+    let (client, seller, buyer, tracking_number) = setup_shipment(&env);
+
+    let shipment = client.get_shipment(&seller, &tracking_number);
+    assert_eq!(
+        shipment.delivery_deadline,
+        shipment.created_at
+            + (shipment.delivery_estimate_days as u64 + DELIVERY_GRACE_DAYS) * SECONDS_PER_DAY
+    );
+
+    let result = client.try_claim_late_delivery(&buyer, &seller, &tracking_number);
+    assert_eq!(result, Err(Ok(ShippingError::DeadlineNotReached)));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = shipment.delivery_deadline + 1);
+    client.claim_late_delivery(&buyer, &seller, &tracking_number);
+
+    let shipment = client.get_shipment(&seller, &tracking_number);
+    assert_eq!(shipment.shipment_status, ShipmentStatus::Failed);
+
+    let result = client.try_confirm_receipt(&buyer, &seller, &tracking_number);
+    assert_eq!(result, Err(Ok(ShippingError::ShipmentFinalized)));
+}
+
+#[test]
+fn test_late_delivery_claim_after_seller_reported_delivery() {
+    let env = setup_env();
+    let (client, seller, buyer, tracking_number) = setup_shipment(&env);
+
+    client.update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::Delivered,
+        &String::from_str(&env, "Doorstep"),
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    // The buyer never confirmed, so the missed deadline can still be claimed
+    let shipment = client.get_shipment(&seller, &tracking_number);
+    env.ledger()
+        .with_mut(|li| li.timestamp = shipment.delivery_deadline + 1);
+    client.claim_late_delivery(&buyer, &seller, &tracking_number);
+    assert_eq!(
+        client
+            .get_shipment(&seller, &tracking_number)
+            .shipment_status,
+        ShipmentStatus::Failed
+    );
+
+    let result = client.try_claim_late_delivery(&buyer, &seller, &tracking_number);
+    assert_eq!(result, Err(Ok(ShippingError::ShipmentFinalized)));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_status_update_requires_seller_auth() {
+    let env = setup_env();
+    let (client, seller, buyer, tracking_number) = setup_shipment(&env);
+
+    let location = String::from_str(&env, "Fake");
+    let proof = BytesN::from_array(&env, &[0; 32]);
+
+    // Only the buyer signs, so the seller's authorization is missing
+    env.mock_auths(&[MockAuth {
+        address: &buyer,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "update_shipment_status",
+            args: (
+                seller.clone(),
+                tracking_number.clone(),
+                ShipmentStatus::Delivered,
+                location.clone(),
+                proof.clone(),
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    client.update_shipment_status(
+        &seller,
+        &tracking_number,
+        &ShipmentStatus::Delivered,
+        &location,
+        &proof,
+    );
+}
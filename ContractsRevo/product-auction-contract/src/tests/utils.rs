@@ -1,4 +1,4 @@
-use crate::datatype::Condition;
+use crate::datatype::{Condition, DataKeys, SellerVerificationStatus};
 use crate::ProductAuctionContract;
 use crate::ProductAuctionContractClient;
 use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol};
@@ -25,6 +25,16 @@ pub fn setup_with_admin(
     admin
 }
 
+/// Marks the seller as verified so they can list, without going through the admin flow.
+pub fn approve_test_seller(env: &Env, client: &ProductAuctionContractClient, seller: &Address) {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKeys::SellerVerification(seller.clone()),
+            &SellerVerificationStatus::Verified,
+        );
+    });
+}
+
 pub fn create_test_product(
     env: &Env,
     client: &ProductAuctionContractClient,
@@ -60,6 +70,7 @@ pub fn create_auction_with_product(
     let reserve_price = 50u64;
     let auction_end_time = env.ledger().timestamp() + end_time_offset;
 
+    approve_test_seller(env, client, seller);
     client.create_auction(seller, &reserve_price, &auction_end_time, &product_id);
     product_id
 }
@@ -0,0 +1,171 @@
+use crate::datatype::{AuctionError, SellerVerificationStatus, VerificationError};
+use crate::tests::utils::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+fn kyc_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+#[test]
+fn test_unverified_seller_blocked() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    let product_id = create_test_product(&env, &client, &seller);
+    let result = client.try_create_auction(&seller, &50u64, &1000u64, &product_id);
+    assert_eq!(result, Err(Ok(AuctionError::SellerNotVerified)));
+
+    // A pending request is not enough
+    client.request_seller_verification(&seller, &kyc_hash(&env));
+    let result = client.try_create_auction(&seller, &50u64, &1000u64, &product_id);
+    assert_eq!(result, Err(Ok(AuctionError::SellerNotVerified)));
+
+    // Only the admin can verify a seller through the legacy entry point
+    let result = client.try_verify_seller(&seller, &seller, &true);
+    assert_eq!(result, Err(Ok(VerificationError::Unauthorized)));
+    client.verify_seller(&admin, &seller, &true);
+    client.create_auction(&seller, &50u64, &1000u64, &product_id);
+}
+
+#[test]
+fn test_approved_seller_can_list() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    client.request_seller_verification(&seller, &kyc_hash(&env));
+    assert_eq!(
+        client.get_verification_status(&seller),
+        SellerVerificationStatus::Pending
+    );
+
+    client.approve_seller(&admin, &seller);
+    assert_eq!(
+        client.get_verification_status(&seller),
+        SellerVerificationStatus::Verified
+    );
+
+    let product_id = create_test_product(&env, &client, &seller);
+    client.create_auction(&seller, &50u64, &1000u64, &product_id);
+    let auction = client.get_auction(&seller, &product_id);
+    assert_eq!(auction.product_id, product_id);
+}
+
+#[test]
+fn test_rejected_seller_can_resubmit() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    client.request_seller_verification(&seller, &kyc_hash(&env));
+    let result = client.try_request_seller_verification(&seller, &kyc_hash(&env));
+    assert_eq!(result, Err(Ok(VerificationError::AlreadyRequested)));
+
+    client.reject_seller(&admin, &seller);
+    assert_eq!(
+        client.get_verification_status(&seller),
+        SellerVerificationStatus::Rejected
+    );
+
+    // Only a verified seller can be revoked
+    let result = client.try_revoke_seller(&admin, &seller);
+    assert_eq!(result, Err(Ok(VerificationError::InvalidStatus)));
+
+    client.request_seller_verification(&seller, &BytesN::from_array(&env, &[8; 32]));
+    assert_eq!(
+        client.get_verification_status(&seller),
+        SellerVerificationStatus::Pending
+    );
+}
+
+#[test]
+fn test_revoked_seller_keeps_existing_auction() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+
+    client.request_seller_verification(&seller, &kyc_hash(&env));
+    client.approve_seller(&admin, &seller);
+    let product_id = create_auction_with_product(&env, &client, &seller, 1000);
+
+    client.revoke_seller(&admin, &seller);
+    assert_eq!(
+        client.get_verification_status(&seller),
+        SellerVerificationStatus::Revoked
+    );
+
+    // New listings are blocked
+    let new_product_id = create_test_product(&env, &client, &seller);
+    let result = client.try_create_auction(&seller, &50u64, &1000u64, &new_product_id);
+    assert_eq!(result, Err(Ok(AuctionError::SellerNotVerified)));
+
+    // The running auction still completes
+    let bidder = Address::generate(&env);
+    client.place_bid(&product_id, &60u64, &bidder, &seller);
+    env.ledger().set_timestamp(1001);
+    client.finalize_auction(&seller, &product_id);
+}
+
+#[test]
+fn test_certifier_attests_product_claim() {
+    let env = setup_env();
+    let client = setup_contract(&env);
+    let admin = setup_with_admin(&env, &client, true);
+    let seller = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    let product_id = create_test_product(&env, &client, &seller);
+    let organic = Symbol::new(&env, "organic");
+
+    let result = client.try_attest_product_claim(
+        &certifier,
+        &seller,
+        &product_id,
+        &organic,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(result, Err(Ok(VerificationError::Unauthorized)));
+
+    client.set_certifier(&admin, &certifier, &true);
+    client.attest_product_claim(
+        &certifier,
+        &seller,
+        &product_id,
+        &organic,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    // Re-attesting the same claim replaces the earlier record
+    env.ledger().set_timestamp(50);
+    client.attest_product_claim(
+        &certifier,
+        &seller,
+        &product_id,
+        &organic,
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+
+    let product = client.get_product(&seller, &product_id);
+    assert_eq!(product.claims.len(), 1);
+    let claim = product.claims.get(0).unwrap();
+    assert_eq!(claim.claim, organic);
+    assert_eq!(claim.claim_hash, BytesN::from_array(&env, &[2; 32]));
+    assert_eq!(claim.certifier, certifier);
+    assert_eq!(claim.attested_at, 50);
+
+    // Removed certifiers can no longer attest
+    client.set_certifier(&admin, &certifier, &false);
+    let result = client.try_attest_product_claim(
+        &certifier,
+        &seller,
+        &product_id,
+        &Symbol::new(&env, "fair_trade"),
+        &BytesN::from_array(&env, &[3; 32]),
+    );
+    assert_eq!(result, Err(Ok(VerificationError::Unauthorized)));
+}
@@ -1,10 +1,11 @@
-use soroban_sdk::{contractimpl, Address, Env, String, Symbol};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, String, Symbol};
 
 use crate::{
     datatype::{
-        Condition, DataKeys, Dispute, DisputeStatus, Product, ReturnRequest,
+        Condition, DataKeys, Dispute, DisputeStatus, Product, ProductClaim, ReturnRequest,
         SellerVerificationStatus, VerificationError,
     },
+    history::record_dispute,
     interfaces::VerificationOperations,
     ProductAuctionContract, ProductAuctionContractArgs, ProductAuctionContractClient,
 };
@@ -36,21 +37,143 @@ impl VerificationOperations for ProductAuctionContract {
         Ok(())
     }
 
-    fn request_seller_verification(env: Env, seller: Address) -> Result<(), VerificationError> {
+    fn request_seller_verification(
+        env: Env,
+        seller: Address,
+        kyc_hash: BytesN<32>,
+    ) -> Result<(), VerificationError> {
         seller.require_auth();
 
         let verification_key = DataKeys::SellerVerification(seller.clone());
 
-        if env.storage().persistent().has(&verification_key) {
-            return Err(VerificationError::AlreadyRequested);
+        // Rejected or revoked sellers may resubmit with new documents
+        if let Some(status) = env
+            .storage()
+            .persistent()
+            .get::<_, SellerVerificationStatus>(&verification_key)
+        {
+            if status == SellerVerificationStatus::Pending
+                || status == SellerVerificationStatus::Verified
+            {
+                return Err(VerificationError::AlreadyRequested);
+            }
         }
 
         env.storage()
             .persistent()
             .set(&verification_key, &SellerVerificationStatus::Pending);
+        env.storage()
+            .persistent()
+            .set(&DataKeys::SellerKyc(seller.clone()), &kyc_hash);
+
+        env.events()
+            .publish(("SellerVerificationRequested", seller.clone()), kyc_hash);
+
+        Ok(())
+    }
+
+    fn approve_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError> {
+        require_admin(&env, &admin)?;
+        transition_seller(
+            &env,
+            &seller,
+            SellerVerificationStatus::Pending,
+            SellerVerificationStatus::Verified,
+        )
+    }
+
+    fn reject_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError> {
+        require_admin(&env, &admin)?;
+        transition_seller(
+            &env,
+            &seller,
+            SellerVerificationStatus::Pending,
+            SellerVerificationStatus::Rejected,
+        )
+    }
+
+    fn revoke_seller(env: Env, admin: Address, seller: Address) -> Result<(), VerificationError> {
+        require_admin(&env, &admin)?;
+        transition_seller(
+            &env,
+            &seller,
+            SellerVerificationStatus::Verified,
+            SellerVerificationStatus::Revoked,
+        )
+    }
+
+    fn get_verification_status(
+        env: Env,
+        seller: Address,
+    ) -> Result<SellerVerificationStatus, VerificationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKeys::SellerVerification(seller))
+            .ok_or(VerificationError::NoVerificationRequest)
+    }
+
+    fn set_certifier(
+        env: Env,
+        admin: Address,
+        certifier: Address,
+        approved: bool,
+    ) -> Result<(), VerificationError> {
+        require_admin(&env, &admin)?;
+
+        let certifier_key = DataKeys::Certifier(certifier.clone());
+        if approved {
+            env.storage().persistent().set(&certifier_key, &true);
+        } else {
+            env.storage().persistent().remove(&certifier_key);
+        }
+
+        env.events()
+            .publish(("CertifierUpdated", certifier), approved);
+
+        Ok(())
+    }
+
+    fn attest_product_claim(
+        env: Env,
+        certifier: Address,
+        seller: Address,
+        product_id: u64,
+        claim: Symbol,
+        claim_hash: BytesN<32>,
+    ) -> Result<(), VerificationError> {
+        certifier.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKeys::Certifier(certifier.clone()))
+        {
+            return Err(VerificationError::Unauthorized);
+        }
+
+        let product_key = DataKeys::Product(seller.clone(), product_id);
+        let mut product: Product = env
+            .storage()
+            .persistent()
+            .get(&product_key)
+            .ok_or(VerificationError::ProductNotFound)?;
+
+        let attestation = ProductClaim {
+            claim: claim.clone(),
+            claim_hash,
+            certifier,
+            attested_at: env.ledger().timestamp(),
+        };
+
+        // A fresh attestation replaces an earlier one for the same claim
+        match product.claims.iter().position(|c| c.claim == claim) {
+            Some(index) => product.claims.set(index as u32, attestation.clone()),
+            None => product.claims.push_back(attestation.clone()),
+        }
+        env.storage().persistent().set(&product_key, &product);
 
         env.events()
-            .publish(("SellerVerificationRequested", seller.clone()), ());
+            .publish(("ProductClaimAttested", product_id), attestation);
 
         Ok(())
     }
@@ -61,7 +184,7 @@ impl VerificationOperations for ProductAuctionContract {
         seller: Address,
         is_verified: bool,
     ) -> Result<(), VerificationError> {
-        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         let verification_key = DataKeys::SellerVerification(seller.clone());
 
@@ -132,6 +255,7 @@ impl VerificationOperations for ProductAuctionContract {
         };
 
         env.storage().persistent().set(&dispute_key, &dispute);
+        record_dispute(&env, &seller);
 
         env.events()
             .publish(("DisputeOpened", product_id), dispute.clone());
@@ -238,3 +362,51 @@ impl VerificationOperations for ProductAuctionContract {
         Ok(())
     }
 }
+
+/// Returns true once the admin has verified the seller; listing is gated on it.
+pub(crate) fn can_list(env: &Env, seller: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, SellerVerificationStatus>(&DataKeys::SellerVerification(seller.clone()))
+        == Some(SellerVerificationStatus::Verified)
+}
+
+fn transition_seller(
+    env: &Env,
+    seller: &Address,
+    from: SellerVerificationStatus,
+    to: SellerVerificationStatus,
+) -> Result<(), VerificationError> {
+    let verification_key = DataKeys::SellerVerification(seller.clone());
+    let status: SellerVerificationStatus = env
+        .storage()
+        .persistent()
+        .get(&verification_key)
+        .ok_or(VerificationError::NoVerificationRequest)?;
+
+    if status != from {
+        return Err(VerificationError::InvalidStatus);
+    }
+
+    env.storage().persistent().set(&verification_key, &to);
+
+    env.events().publish(("SellerVerified", seller.clone()), to);
+
+    Ok(())
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), VerificationError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKeys::Admin)
+        .ok_or(VerificationError::Unauthorized)?;
+
+    if *admin != stored_admin {
+        return Err(VerificationError::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::interface::{AttachmentOperations, VerificationOperations};
+use crate::{
+    datatype::{
+        AttachmentStatus, DataKeys, PurchaseReviewError, ReviewAttachment, ReviewDetails,
+        ReviewModerationData,
+    },
+    PurchaseReviewContract, PurchaseReviewContractArgs, PurchaseReviewContractClient,
+};
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+/// Maximum number of media attachments a single review can hold.
+const MAX_ATTACHMENTS_PER_REVIEW: u32 = 5;
+
+#[contractimpl]
+impl AttachmentOperations for PurchaseReviewContract {
+    /// Attaches a media evidence hash to a review
+    ///
+    /// # Arguments
+    /// * `author` - Address of the review author
+    /// * `product_id` - Unique identifier of the product
+    /// * `review_id` - Unique identifier of the review
+    /// * `media_hash` - Hash of the off-chain media file
+    /// * `media_kind` - Kind of media, e.g. `photo`
+    ///
+    /// # Returns
+    /// * `Result<(), PurchaseReviewError>` - Success or error status
+    fn add_review_attachment(
+        env: Env,
+        author: Address,
+        product_id: u64,
+        review_id: u32,
+        media_hash: BytesN<32>,
+        media_kind: Symbol,
+    ) -> Result<(), PurchaseReviewError> {
+        author.require_auth();
+
+        let review = env
+            .storage()
+            .persistent()
+            .get::<_, ReviewDetails>(&DataKeys::Review(product_id, review_id))
+            .ok_or(PurchaseReviewError::ReviewNotFound)?;
+
+        if review.reviewer != author {
+            return Err(PurchaseReviewError::UnauthorizedAccess);
+        }
+
+        if !Self::is_review_editable(env.clone(), review_id, product_id)? {
+            return Err(PurchaseReviewError::EditWindowExpired);
+        }
+
+        let key = DataKeys::ReviewAttachments(product_id, review_id);
+        let mut attachments: Vec<ReviewAttachment> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+
+        if attachments.len() >= MAX_ATTACHMENTS_PER_REVIEW {
+            return Err(PurchaseReviewError::AttachmentLimitExceeded);
+        }
+
+        attachments.push_back(ReviewAttachment {
+            media_hash: media_hash.clone(),
+            media_kind,
+            added_at: env.ledger().timestamp(),
+            status: AttachmentStatus::Active,
+            removed_by: None,
+        });
+        env.storage().persistent().set(&key, &attachments);
+
+        env.events().publish(
+            (Symbol::new(&env, "review_attachment_added"), author),
+            (product_id, review_id, media_hash),
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves all attachments of a review, including struck ones, in insertion order
+    ///
+    /// # Arguments
+    /// * `product_id` - Unique identifier of the product
+    /// * `review_id` - Unique identifier of the review
+    ///
+    /// # Returns
+    /// * `Result<Vec<ReviewAttachment>, PurchaseReviewError>` - Ordered attachments
+    fn get_review_attachments(
+        env: Env,
+        product_id: u64,
+        review_id: u32,
+    ) -> Result<Vec<ReviewAttachment>, PurchaseReviewError> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKeys::Review(product_id, review_id))
+        {
+            return Err(PurchaseReviewError::ReviewNotFound);
+        }
+
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKeys::ReviewAttachments(product_id, review_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Marks a single attachment as removed; the review itself is left untouched
+    ///
+    /// # Arguments
+    /// * `moderator` - Address of the moderator (the contract admin)
+    /// * `product_id` - Unique identifier of the product
+    /// * `review_id` - Unique identifier of the review
+    /// * `index` - Position of the attachment in the review's gallery
+    ///
+    /// # Returns
+    /// * `Result<(), PurchaseReviewError>` - Success or error status
+    fn strike_review_attachment(
+        env: Env,
+        moderator: Address,
+        product_id: u64,
+        review_id: u32,
+        index: u32,
+    ) -> Result<(), PurchaseReviewError> {
+        moderator.require_auth();
+
+        let admin = Self::get_admin(env.clone())?;
+        if moderator != admin {
+            return Err(PurchaseReviewError::UnauthorizedAccess);
+        }
+
+        let mut attachments = Self::get_review_attachments(env.clone(), product_id, review_id)?;
+        let mut attachment = attachments
+            .get(index)
+            .ok_or(PurchaseReviewError::AttachmentNotFound)?;
+
+        if attachment.status == AttachmentStatus::Removed {
+            return Err(PurchaseReviewError::AttachmentAlreadyRemoved);
+        }
+
+        attachment.status = AttachmentStatus::Removed;
+        attachment.removed_by = Some(moderator.clone());
+        attachments.set(index, attachment);
+        env.storage().persistent().set(
+            &DataKeys::ReviewAttachments(product_id, review_id),
+            &attachments,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "review_attachment_struck"), moderator),
+            (product_id, review_id, index),
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the data moderators review for a flagged review
+    ///
+    /// # Arguments
+    /// * `product_id` - Unique identifier of the product
+    /// * `review_id` - Unique identifier of the review
+    ///
+    /// # Returns
+    /// * `Result<ReviewModerationData, PurchaseReviewError>` - Review, attachments and report count
+    fn get_review_moderation_data(
+        env: Env,
+        product_id: u64,
+        review_id: u32,
+    ) -> Result<ReviewModerationData, PurchaseReviewError> {
+        let review = Self::get_review(env.clone(), product_id, review_id)?;
+        let attachments = Self::get_review_attachments(env.clone(), product_id, review_id)?;
+        let report_count = env
+            .storage()
+            .persistent()
+            .get(&DataKeys::ReviewReport(product_id, review_id))
+            .unwrap_or(0);
+
+        Ok(ReviewModerationData {
+            review,
+            attachments,
+            report_count,
+        })
+    }
+}
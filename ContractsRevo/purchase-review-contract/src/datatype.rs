@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Main categories for rating different aspects of products/services
 /// Used to organize and segment ratings into specific areas of evaluation
@@ -36,12 +36,13 @@ pub enum DataKeys {
     CategoryMapping(Address),           // Maps categories to products/users
     Review(u64, u32),                   // Specific review identified by product_id and review_id
     PurchaseVerification(u64, Address), // Verification status for a purchase
-    ReviewReport(u64, u32),             // Report data for a specific review
+    ReviewReport(u64, u32),             // Number of reports filed against a review
     ReviewCount(u64),
     ReviewVote(u64, u32, Address),   // (product_id, review_id, voter)
     AlreadyVoted(u64, u32, Address), // (product_id, review_id, voter)
     UserReviewReport(u64, u32, Address), // (product_id, review_id, reporter)
     VoteRateLimit(Address),
+    ReviewAttachments(u64, u32), // (product_id, review_id) -> ordered media attachments
 }
 
 /// Error types that can occur during contract operations
@@ -73,6 +74,9 @@ pub enum PurchaseReviewError {
     RateLimitExceeded = 21,
     InvalidPurchaseLink = 22,
     InvalidResponseText = 23,
+    AttachmentLimitExceeded = 24, // Review already holds the maximum number of attachments
+    AttachmentNotFound = 25,      // Referenced attachment index doesn't exist
+    AttachmentAlreadyRemoved = 26, // Attachment was already struck by a moderator
 }
 
 /// Represents a rating for a specific category with additional metadata
@@ -138,3 +142,31 @@ pub struct Review {
     pub timestamp: u64,
     pub verified: bool,
 }
+
+/// Moderation state of a media attachment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttachmentStatus {
+    Active,  // Visible alongside the review
+    Removed, // Struck by a moderator; the review itself stays up
+}
+
+/// Hash of a piece of media evidence attached to a review
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewAttachment {
+    pub media_hash: BytesN<32>,      // Hash of the off-chain media file
+    pub media_kind: Symbol,          // e.g. "photo" or "video"
+    pub added_at: u64,               // When the attachment was added
+    pub status: AttachmentStatus,    // Current moderation state
+    pub removed_by: Option<Address>, // Moderator who struck the attachment
+}
+
+/// Everything a moderator needs to judge a flagged review
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewModerationData {
+    pub review: ReviewDetails,
+    pub attachments: Vec<ReviewAttachment>,
+    pub report_count: u32,
+}
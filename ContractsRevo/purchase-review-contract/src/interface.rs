@@ -1,5 +1,8 @@
-use crate::datatype::{Category, ProductRatings, PurchaseReviewError, Rating, ReviewDetails};
-use soroban_sdk::{Address, Env, String};
+use crate::datatype::{
+    Category, ProductRatings, PurchaseReviewError, Rating, ReviewAttachment, ReviewDetails,
+    ReviewModerationData,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 /// Handles rating-related operations for products
 #[allow(dead_code)]
@@ -128,3 +131,41 @@ pub trait VerificationOperations {
         new_details: ReviewDetails,
     ) -> Result<(), PurchaseReviewError>;
 }
+
+/// Manages media evidence attached to reviews and its moderation
+#[allow(dead_code)]
+pub trait AttachmentOperations {
+    /// Attaches a media hash to a review
+    /// Only the review author may attach, and only within the edit window
+    fn add_review_attachment(
+        env: Env,
+        author: Address,
+        product_id: u64,
+        review_id: u32,
+        media_hash: BytesN<32>,
+        media_kind: Symbol,
+    ) -> Result<(), PurchaseReviewError>;
+
+    /// Retrieves the attachments of a review in the order they were added
+    fn get_review_attachments(
+        env: Env,
+        product_id: u64,
+        review_id: u32,
+    ) -> Result<Vec<ReviewAttachment>, PurchaseReviewError>;
+
+    /// Lets a moderator strike a single attachment without removing the review
+    fn strike_review_attachment(
+        env: Env,
+        moderator: Address,
+        product_id: u64,
+        review_id: u32,
+        index: u32,
+    ) -> Result<(), PurchaseReviewError>;
+
+    /// Retrieves a review together with its attachments and report count
+    fn get_review_moderation_data(
+        env: Env,
+        product_id: u64,
+        review_id: u32,
+    ) -> Result<ReviewModerationData, PurchaseReviewError>;
+}
@@ -5,6 +5,7 @@ use crate::datatype::{
     DataKeys, ProductRatings, PurchaseReviewError, PurchaseVerificationData, ReviewDetails,
 };
 
+mod attachment;
 mod datatype;
 mod interface;
 mod rating;
@@ -0,0 +1,186 @@
+#![cfg(test)]
+
+use super::super::*;
+use super::utils::*;
+use crate::datatype::AttachmentStatus;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Symbol};
+
+/// Submits the first review for `product_id` and returns its review id
+fn submit_review_for(
+    env: &Env,
+    client: &PurchaseReviewContractClient,
+    user: &Address,
+    product_id: u64,
+) -> u32 {
+    client.submit_review(
+        user,
+        &product_id,
+        &String::from_str(env, "Produce arrived moldy"),
+        &String::from_str(env, "https://example.com/purchase/1"),
+    );
+    0
+}
+
+fn media_hash(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn test_attachments_retrieved_in_order() {
+    let (env, client, _, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+
+    let photo = Symbol::new(&env, "photo");
+    let video = Symbol::new(&env, "video");
+    client.add_review_attachment(&user, &product_id, &review_id, &media_hash(&env, 1), &photo);
+    client.add_review_attachment(&user, &product_id, &review_id, &media_hash(&env, 2), &video);
+    client.add_review_attachment(&user, &product_id, &review_id, &media_hash(&env, 3), &photo);
+
+    let attachments = client.get_review_attachments(&product_id, &review_id);
+    assert_eq!(attachments.len(), 3);
+    for (i, attachment) in attachments.iter().enumerate() {
+        assert_eq!(attachment.media_hash, media_hash(&env, i as u8 + 1));
+        assert_eq!(attachment.status, AttachmentStatus::Active);
+    }
+    assert_eq!(attachments.get(1).unwrap().media_kind, video);
+}
+
+#[test]
+fn test_attachment_cap_enforced() {
+    let (env, client, _, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+    let photo = Symbol::new(&env, "photo");
+
+    for seed in 0..5u8 {
+        client.add_review_attachment(
+            &user,
+            &product_id,
+            &review_id,
+            &media_hash(&env, seed),
+            &photo,
+        );
+    }
+
+    let result = client.try_add_review_attachment(
+        &user,
+        &product_id,
+        &review_id,
+        &media_hash(&env, 9),
+        &photo,
+    );
+    assert_eq!(
+        result,
+        Err(Ok(PurchaseReviewError::AttachmentLimitExceeded))
+    );
+    assert_eq!(
+        client.get_review_attachments(&product_id, &review_id).len(),
+        5
+    );
+}
+
+#[test]
+fn test_only_author_can_attach() {
+    let (env, client, _, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+    let stranger = Address::generate(&env);
+
+    let result = client.try_add_review_attachment(
+        &stranger,
+        &product_id,
+        &review_id,
+        &media_hash(&env, 1),
+        &Symbol::new(&env, "photo"),
+    );
+    assert_eq!(result, Err(Ok(PurchaseReviewError::UnauthorizedAccess)));
+    assert_eq!(
+        client.get_review_attachments(&product_id, &review_id).len(),
+        0
+    );
+}
+
+#[test]
+fn test_attachment_rejected_after_edit_window() {
+    let (env, client, _, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+
+    advance_time(&env, 24 * 60 * 60 + 1);
+
+    let result = client.try_add_review_attachment(
+        &user,
+        &product_id,
+        &review_id,
+        &media_hash(&env, 1),
+        &Symbol::new(&env, "photo"),
+    );
+    assert_eq!(result, Err(Ok(PurchaseReviewError::EditWindowExpired)));
+}
+
+#[test]
+fn test_moderator_strikes_attachment_without_removing_review() {
+    let (env, client, admin, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+    let photo = Symbol::new(&env, "photo");
+    client.add_review_attachment(&user, &product_id, &review_id, &media_hash(&env, 1), &photo);
+    client.add_review_attachment(&user, &product_id, &review_id, &media_hash(&env, 2), &photo);
+
+    // Non-moderators cannot strike
+    let result = client.try_strike_review_attachment(&user, &product_id, &review_id, &0);
+    assert_eq!(result, Err(Ok(PurchaseReviewError::UnauthorizedAccess)));
+
+    client.strike_review_attachment(&admin, &product_id, &review_id, &0);
+
+    let attachments = client.get_review_attachments(&product_id, &review_id);
+    assert_eq!(
+        attachments.get(0).unwrap().status,
+        AttachmentStatus::Removed
+    );
+    assert_eq!(attachments.get(0).unwrap().removed_by, Some(admin.clone()));
+    assert_eq!(attachments.get(1).unwrap().status, AttachmentStatus::Active);
+
+    // Review remains in place
+    assert_eq!(client.get_review(&product_id, &review_id).reviewer, user);
+
+    let result = client.try_strike_review_attachment(&admin, &product_id, &review_id, &0);
+    assert_eq!(
+        result,
+        Err(Ok(PurchaseReviewError::AttachmentAlreadyRemoved))
+    );
+    let result = client.try_strike_review_attachment(&admin, &product_id, &review_id, &7);
+    assert_eq!(result, Err(Ok(PurchaseReviewError::AttachmentNotFound)));
+}
+
+#[test]
+fn test_moderation_data_includes_attachments_and_reports() {
+    let (env, client, _, user) = setup_test();
+    let product_id = 1u64;
+    let review_id = submit_review_for(&env, &client, &user, product_id);
+    client.add_review_attachment(
+        &user,
+        &product_id,
+        &review_id,
+        &media_hash(&env, 1),
+        &Symbol::new(&env, "photo"),
+    );
+
+    let reporter = Address::generate(&env);
+    client.report_review(
+        &reporter,
+        &product_id,
+        &review_id,
+        &String::from_str(&env, "Misleading photo"),
+    );
+
+    let data = client.get_review_moderation_data(&product_id, &review_id);
+    assert_eq!(data.review.reviewer, user);
+    assert_eq!(data.attachments.len(), 1);
+    assert_eq!(
+        data.attachments.get(0).unwrap().media_hash,
+        media_hash(&env, 1)
+    );
+    assert_eq!(data.report_count, 1);
+}
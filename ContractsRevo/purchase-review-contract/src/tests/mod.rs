@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 pub mod aggregation;
+pub mod attachment;
 pub mod review;
 pub mod utils;
 pub mod validation;
@@ -85,6 +85,12 @@ impl VerificationOperations for PurchaseReviewContract {
 
         env.storage().persistent().set(&report_key, &report_data);
 
+        let count_key = DataKeys::ReviewReport(product_id, review_id);
+        let report_count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &(report_count + 1));
+
         env.events().publish(
             (Symbol::new(&env, "review_reported"), reporter),
             (product_id, review_id, reason),
@@ -1,8 +1,14 @@
 use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Symbol, Vec};
 
+/// Storage version 1 key of the certificate management contract address, moved to
+/// `DataKey::CertMgmtContract` by the version 2 migration
 pub const CERTIFICATE_MANAGEMENT_CONTRACT_KEY: &str = "cert_mgmt_contract";
 pub const MAX_PRODUCTS_PER_FARMER: u32 = 1000;
 pub const MAX_PRODUCTS_PER_TYPE: u32 = 5000;
+pub const MAX_FEEDBACK_PER_PRODUCT: u32 = 500;
+pub const MIN_FEEDBACK_RATING: u32 = 1;
+pub const MAX_FEEDBACK_RATING: u32 = 5;
+pub const MAX_TRACE_SUMMARY_IDS: u32 = 25;
 
 /// Storage keys for different data types
 #[contracttype]
@@ -15,6 +21,14 @@ pub enum DataKey {
     ProductTypeIndex(String), // Product Type -> Vec<BytesN<32>>
     StageValidation(u32), // Stage validation rules
     QRCodeMapping(String), // QR Code -> BytesN<32>
+    Recalled(BytesN<32>), // Product ID -> recall flag
+    ProductFeedback(BytesN<32>), // Product ID -> Vec<ConsumerFeedback>
+    FeedbackSubmitted(BytesN<32>, Address), // (Product ID, submitter) -> bool
+    FeedbackSummary(BytesN<32>), // Product ID -> FeedbackSummary
+    EventSequence, // Last published event sequence (instance storage); keep this name stable across upgrades
+    CertMgmtContract, // Certificate management contract address (instance storage)
+    StorageVersion, // Storage layout version (instance storage); absent before version 2
+    TraceState(BytesN<32>), // Product ID -> TraceState; absent for products registered before it
 }
 
 /// Product structure
@@ -27,6 +41,16 @@ pub struct Product {
     pub certificate_id: CertificateId,
 }
 
+/// Listing fields of a product, stored apart from it so listings skip the stage data
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceState {
+    pub farmer_id: Address,
+    pub certificate_id: CertificateId,
+    pub stage_count: u32,
+    pub last_stage_timestamp: u64, // Timestamp of the latest stage, 0 before the first
+}
+
 /// Custom Option type for BytesN<32> to use with #[contracttype]
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -45,6 +69,61 @@ pub struct ProductRegistration {
     pub metadata_hash: BytesN<32>,
 }
 
+/// Consumer feedback left after scanning a product QR code
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsumerFeedback {
+    pub submitter: Address,
+    pub rating: u32,              // 1-5 stars
+    pub comment_hash: BytesN<32>, // Hash of the off-chain comment
+    pub timestamp: u64,
+    pub recalled: bool, // Product was recalled when the feedback was left
+}
+
+/// Aggregated consumer feedback for a product
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedbackSummary {
+    pub product_id: BytesN<32>,
+    pub feedback_count: u32,
+    pub rating_total: u32,
+    pub average_rating: u32, // Scaled by 100 (e.g. 425 = 4.25 stars)
+    pub recalled_feedback_count: u32,
+}
+
+/// Whether a product looked up in a bulk read exists
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SummaryStatus {
+    Found,
+    NotFound,
+}
+
+/// Status of the certificate linked to a product, as reported by certificate management
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LinkedCertStatus {
+    Unlinked,
+    Valid,
+    Expired,
+    Revoked,
+    Unavailable, // The certificate management contract could not be read
+}
+
+/// Compact view of a product's trace for listings, without the stage data
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceSummary {
+    pub product_id: BytesN<32>,
+    pub status: SummaryStatus,
+    pub product_type: String,
+    pub current_tier: u32, // StageTier value, 0 before the first stage
+    pub stage_count: u32,
+    pub last_stage_timestamp: u64,
+    pub certificate_status: LinkedCertStatus,
+    pub recalled: bool,
+}
+
 /// Stage structure
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -143,6 +222,13 @@ pub enum SupplyChainError {
     DuplicateStageTier = 16,
     InvalidTierProgression = 17,
     ProductLimitExceeded = 18,
+    InvalidRating = 27,
+    DuplicateFeedback = 28,
+    FeedbackLimitExceeded = 29,
+    AlreadyRecalled = 30,
+    AlreadyMigrated = 31,
+    UnsupportedStorageVersion = 32,
+    TooManyProducts = 33,
 }
 
 // Certificate datatypes
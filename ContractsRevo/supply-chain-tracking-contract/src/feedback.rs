@@ -0,0 +1,114 @@
+use crate::datatypes::{
+    ConsumerFeedback, DataKey, FeedbackSummary, SupplyChainError, MAX_FEEDBACK_PER_PRODUCT,
+    MAX_FEEDBACK_RATING, MIN_FEEDBACK_RATING,
+};
+use crate::{product, utils};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+/// Submit consumer feedback for the product behind a QR code
+pub fn submit_consumer_feedback(
+    env: Env,
+    submitter: Address,
+    qr_code: String,
+    rating: u32,
+    comment_hash: BytesN<32>,
+) -> Result<BytesN<32>, SupplyChainError> {
+    submitter.require_auth();
+
+    if !(MIN_FEEDBACK_RATING..=MAX_FEEDBACK_RATING).contains(&rating) {
+        return Err(SupplyChainError::InvalidRating);
+    }
+
+    let product_id = utils::resolve_qr_code(&env, &qr_code)?;
+
+    // One feedback per submitter per product
+    let submitted_key = DataKey::FeedbackSubmitted(product_id.clone(), submitter.clone());
+    if env.storage().persistent().has(&submitted_key) {
+        return Err(SupplyChainError::DuplicateFeedback);
+    }
+
+    let feedback_key = DataKey::ProductFeedback(product_id.clone());
+    let mut feedback_list: Vec<ConsumerFeedback> = env
+        .storage()
+        .persistent()
+        .get(&feedback_key)
+        .unwrap_or_else(|| Vec::new(&env));
+
+    if feedback_list.len() >= MAX_FEEDBACK_PER_PRODUCT {
+        return Err(SupplyChainError::FeedbackLimitExceeded);
+    }
+
+    let recalled = product::is_product_recalled(env.clone(), product_id.clone());
+    let feedback = ConsumerFeedback {
+        submitter: submitter.clone(),
+        rating,
+        comment_hash,
+        timestamp: env.ledger().timestamp(),
+        recalled,
+    };
+    feedback_list.push_back(feedback);
+    env.storage()
+        .persistent()
+        .set(&feedback_key, &feedback_list);
+    env.storage().persistent().set(&submitted_key, &true);
+
+    // Keep running totals so the summary does not need to walk the list
+    let mut summary = get_feedback_summary(env.clone(), product_id.clone());
+    summary.feedback_count += 1;
+    summary.rating_total += rating;
+    summary.average_rating = summary.rating_total * 100 / summary.feedback_count;
+    if recalled {
+        summary.recalled_feedback_count += 1;
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeedbackSummary(product_id.clone()), &summary);
+
+    let topic = if recalled {
+        "recalled_feedback"
+    } else {
+        "feedback_submitted"
+    };
+    utils::publish_event(
+        &env,
+        (Symbol::new(&env, topic), product_id.clone()),
+        (submitter, rating),
+    );
+
+    Ok(product_id)
+}
+
+/// Get a page of consumer feedback for a product, oldest first
+pub fn get_product_feedback(
+    env: Env,
+    product_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> Vec<ConsumerFeedback> {
+    let feedback_list: Vec<ConsumerFeedback> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProductFeedback(product_id))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut page = Vec::new(&env);
+    let end = offset.saturating_add(limit).min(feedback_list.len());
+    for i in offset..end {
+        page.push_back(feedback_list.get_unchecked(i));
+    }
+    page
+}
+
+/// Get the aggregated feedback for a product
+pub fn get_feedback_summary(env: Env, product_id: BytesN<32>) -> FeedbackSummary {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeedbackSummary(product_id.clone()))
+        .unwrap_or(FeedbackSummary {
+            product_id,
+            feedback_count: 0,
+            rating_total: 0,
+            average_rating: 0,
+            recalled_feedback_count: 0,
+        })
+}
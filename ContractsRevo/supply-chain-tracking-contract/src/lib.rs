@@ -2,8 +2,10 @@
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 mod datatypes;
+mod feedback;
 mod product;
 mod tracking;
+mod upgrade;
 mod utils;
 mod validation;
 
@@ -11,6 +13,7 @@ mod validation;
 mod test;
 
 pub use datatypes::*;
+pub use upgrade::CURRENT_STORAGE_VERSION;
 
 #[contract]
 pub struct SupplyChainTrackingContract;
@@ -35,13 +38,16 @@ impl SupplyChainTrackingContract {
         env.storage().instance().set(&DataKey::Admin, &admin);
 
         // Store certificate management contract address for production cross-contract calls
-        env.storage().instance().set(
-            &Symbol::new(&env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY),
-            &cert_management_contract,
-        );
+        env.storage()
+            .instance()
+            .set(&DataKey::CertMgmtContract, &cert_management_contract);
+
+        // Fresh deployments start on the current storage layout
+        upgrade::set_storage_version(&env, upgrade::CURRENT_STORAGE_VERSION);
 
         // Emit initialization event
-        env.events().publish(
+        utils::publish_event(
+            &env,
             (Symbol::new(&env, "contract_initialized"), admin.clone()),
             env.ledger().timestamp(),
         );
@@ -68,13 +74,13 @@ impl SupplyChainTrackingContract {
             return Err(SupplyChainError::UnauthorizedAccess);
         }
 
-        env.storage().instance().set(
-            &Symbol::new(&env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY),
-            &cert_management_contract,
-        );
+        env.storage()
+            .instance()
+            .set(&DataKey::CertMgmtContract, &cert_management_contract);
 
         // Emit configuration event
-        env.events().publish(
+        utils::publish_event(
+            &env,
             (Symbol::new(&env, "cert_contract_configured"), admin),
             cert_management_contract,
         );
@@ -86,10 +92,15 @@ impl SupplyChainTrackingContract {
     pub fn get_cert_mgmt_contract(env: Env) -> Result<Address, SupplyChainError> {
         env.storage()
             .instance()
-            .get(&Symbol::new(&env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY))
+            .get(&DataKey::CertMgmtContract)
             .ok_or(SupplyChainError::NotInitialized)
     }
 
+    /// Get the sequence number of the most recently published event
+    pub fn get_event_sequence(env: Env) -> u64 {
+        utils::get_event_sequence(&env)
+    }
+
     /// Get the contract admin
     pub fn get_admin(env: Env) -> Result<Address, SupplyChainError> {
         env.storage()
@@ -98,6 +109,21 @@ impl SupplyChainTrackingContract {
             .ok_or(SupplyChainError::NotInitialized)
     }
 
+    /// Replace the contract code with an uploaded WASM (admin only)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), SupplyChainError> {
+        upgrade::upgrade(&env, new_wasm_hash)
+    }
+
+    /// Bring stored data up to the current storage version after an upgrade
+    pub fn migrate(env: Env) -> Result<u32, SupplyChainError> {
+        upgrade::migrate(&env)
+    }
+
+    /// Get the storage version of the deployed data
+    pub fn get_storage_version(env: Env) -> Result<u32, SupplyChainError> {
+        upgrade::get_storage_version(&env)
+    }
+
     // ========== CORE FUNCTIONS ==========
 
     /// Register a new agricultural product with initial details
@@ -142,6 +168,14 @@ impl SupplyChainTrackingContract {
         tracking::get_product_trace(env, product_id)
     }
 
+    /// Summarize up to 25 products' traces for listings; missing products are marked NotFound
+    pub fn get_trace_summaries(
+        env: Env,
+        product_ids: Vec<BytesN<32>>,
+    ) -> Result<Vec<TraceSummary>, SupplyChainError> {
+        tracking::get_trace_summaries(env, product_ids)
+    }
+
     /// Validate product authenticity against recorded data and certifications
     pub fn verify_authenticity(
         env: Env,
@@ -172,6 +206,20 @@ impl SupplyChainTrackingContract {
         product::get_product_details(env, product_id)
     }
 
+    /// Mark a product as recalled (admin only)
+    pub fn recall_product(
+        env: Env,
+        admin: Address,
+        product_id: BytesN<32>,
+    ) -> Result<(), SupplyChainError> {
+        product::recall_product(env, admin, product_id)
+    }
+
+    /// Check whether a product has been recalled
+    pub fn is_product_recalled(env: Env, product_id: BytesN<32>) -> bool {
+        product::is_product_recalled(env, product_id)
+    }
+
     /// Get product registration details
     pub fn get_product_registration(
         env: Env,
@@ -270,4 +318,33 @@ impl SupplyChainTrackingContract {
     pub fn generate_qr_code(env: Env, product_id: BytesN<32>) -> Result<String, SupplyChainError> {
         utils::generate_qr_code_data(&env, &product_id)
     }
+
+    // ========== CONSUMER FEEDBACK ==========
+
+    /// Submit consumer feedback (1-5 rating) for the product behind a QR code
+    /// Each submitter can leave one feedback per product
+    pub fn submit_consumer_feedback(
+        env: Env,
+        submitter: Address,
+        qr_code: String,
+        rating: u32,
+        comment_hash: BytesN<32>,
+    ) -> Result<BytesN<32>, SupplyChainError> {
+        feedback::submit_consumer_feedback(env, submitter, qr_code, rating, comment_hash)
+    }
+
+    /// Get a page of consumer feedback for a product
+    pub fn get_product_feedback(
+        env: Env,
+        product_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ConsumerFeedback> {
+        feedback::get_product_feedback(env, product_id, offset, limit)
+    }
+
+    /// Get the feedback count and average rating for a product
+    pub fn get_feedback_summary(env: Env, product_id: BytesN<32>) -> FeedbackSummary {
+        feedback::get_feedback_summary(env, product_id)
+    }
 }
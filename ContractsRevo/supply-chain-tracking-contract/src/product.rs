@@ -2,7 +2,7 @@ use crate::datatypes::{
     CertificateId, DataKey, Product, ProductRegistration, SupplyChainError,
     MAX_PRODUCTS_PER_FARMER, MAX_PRODUCTS_PER_TYPE,
 };
-use crate::utils;
+use crate::{tracking, utils};
 use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 /// Register a new agricultural product with initial details
@@ -45,6 +45,7 @@ pub fn register_product(
     env.storage()
         .persistent()
         .set(&DataKey::Product(product_id.clone()), &product);
+    tracking::write_trace_state(&env, &product);
 
     // Create ProductRegistration struct to store all registration details
     let registration = ProductRegistration {
@@ -67,7 +68,8 @@ pub fn register_product(
     update_product_type_index(&env, &product_type, &product_id)?;
 
     // Emit event
-    env.events().publish(
+    utils::publish_event(
+        &env,
         (Symbol::new(&env, "product_registered"), farmer_id),
         product_id.clone(),
     );
@@ -75,6 +77,56 @@ pub fn register_product(
     Ok(product_id)
 }
 
+/// Mark a product as recalled (admin only)
+pub fn recall_product(
+    env: Env,
+    admin: Address,
+    product_id: BytesN<32>,
+) -> Result<(), SupplyChainError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SupplyChainError::NotInitialized)?;
+    if admin != stored_admin {
+        return Err(SupplyChainError::UnauthorizedAccess);
+    }
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::Product(product_id.clone()))
+    {
+        return Err(SupplyChainError::ProductNotFound);
+    }
+
+    if is_product_recalled(env.clone(), product_id.clone()) {
+        return Err(SupplyChainError::AlreadyRecalled);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Recalled(product_id.clone()), &true);
+
+    utils::publish_event(
+        &env,
+        (Symbol::new(&env, "product_recalled"), admin),
+        product_id,
+    );
+
+    Ok(())
+}
+
+/// Check whether a product has been recalled
+pub fn is_product_recalled(env: Env, product_id: BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recalled(product_id))
+        .unwrap_or(false)
+}
+
 /// Get product registration details
 pub fn get_product_registration(
     env: Env,
@@ -143,7 +195,8 @@ fn update_farmer_products(
     products.push_back(product_id.clone());
     env.storage().persistent().set(&key, &products);
 
-    env.events().publish(
+    utils::publish_event(
+        env,
         (Symbol::new(env, "farmer_products_updated"),),
         (farmer_id.clone(), product_id.clone()),
     );
@@ -172,7 +225,8 @@ fn update_product_type_index(
     products.push_back(product_id.clone());
     env.storage().persistent().set(&key, &products);
 
-    env.events().publish(
+    utils::publish_event(
+        env,
         (Symbol::new(env, "product_type_index_updated"),),
         (product_type.clone(), product_id.clone()),
     );
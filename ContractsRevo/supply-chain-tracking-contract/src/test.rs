@@ -3,9 +3,10 @@
 use super::*;
 use soroban_sdk::{
     contract, contractimpl, log, symbol_short,
-    testutils::{Address as _, Ledger},
+    testutils::{Address as _, Events, Ledger},
+    vec,
     xdr::ToXdr,
-    Address, BytesN, Env, Map, String, Symbol,
+    Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
 };
 
 // Test Constants
@@ -2215,3 +2216,599 @@ impl MockCertificateManagement {
         Ok(())
     }
 }
+
+// ========== CONSUMER FEEDBACK TESTS ==========
+
+/// Register a product and generate its consumer QR code
+fn setup_product_with_qr_code(
+    env: &Env,
+    supply_chain_client: &SupplyChainTrackingContractClient,
+    farmer: &Address,
+) -> (BytesN<32>, String) {
+    let (product_type, batch_number, origin_location, metadata_hash) =
+        create_test_product_data(env, "feedback");
+    let product_id = supply_chain_client.register_product(
+        farmer,
+        &product_type,
+        &batch_number,
+        &origin_location,
+        &metadata_hash,
+    );
+    let qr_code = supply_chain_client.generate_qr_code(&product_id);
+    (product_id, qr_code)
+}
+
+#[test]
+fn test_submit_consumer_feedback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, farmer, _, _, supply_chain_client, _) = setup_test_environment(&env);
+    let (product_id, qr_code) = setup_product_with_qr_code(&env, &supply_chain_client, &farmer);
+
+    let consumer1 = Address::generate(&env);
+    let consumer2 = Address::generate(&env);
+    let comment_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    let resolved =
+        supply_chain_client.submit_consumer_feedback(&consumer1, &qr_code, &5, &comment_hash);
+    assert_eq!(resolved, product_id);
+    supply_chain_client.submit_consumer_feedback(&consumer2, &qr_code, &4, &comment_hash);
+
+    let feedback = supply_chain_client.get_product_feedback(&product_id, &0, &10);
+    assert_eq!(feedback.len(), 2);
+    assert_eq!(feedback.get(0).unwrap().submitter, consumer1);
+    assert!(!feedback.get(0).unwrap().recalled);
+
+    let summary = supply_chain_client.get_feedback_summary(&product_id);
+    assert_eq!(summary.feedback_count, 2);
+    assert_eq!(summary.average_rating, 450);
+    assert_eq!(summary.recalled_feedback_count, 0);
+}
+
+#[test]
+fn test_duplicate_feedback_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, farmer, _, _, supply_chain_client, _) = setup_test_environment(&env);
+    let (_, qr_code) = setup_product_with_qr_code(&env, &supply_chain_client, &farmer);
+
+    let consumer = Address::generate(&env);
+    let comment_hash = BytesN::from_array(&env, &[7u8; 32]);
+    supply_chain_client.submit_consumer_feedback(&consumer, &qr_code, &3, &comment_hash);
+
+    let result =
+        supply_chain_client.try_submit_consumer_feedback(&consumer, &qr_code, &5, &comment_hash);
+    assert_eq!(result, Err(Ok(SupplyChainError::DuplicateFeedback)));
+}
+
+#[test]
+fn test_feedback_rating_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, farmer, _, _, supply_chain_client, _) = setup_test_environment(&env);
+    let (product_id, qr_code) = setup_product_with_qr_code(&env, &supply_chain_client, &farmer);
+
+    let consumer = Address::generate(&env);
+    let comment_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    for rating in [0u32, 6u32] {
+        let result = supply_chain_client.try_submit_consumer_feedback(
+            &consumer,
+            &qr_code,
+            &rating,
+            &comment_hash,
+        );
+        assert_eq!(result, Err(Ok(SupplyChainError::InvalidRating)));
+    }
+
+    // Unknown QR codes are rejected
+    let result = supply_chain_client.try_submit_consumer_feedback(
+        &consumer,
+        &String::from_str(&env, "unknown"),
+        &3,
+        &comment_hash,
+    );
+    assert_eq!(result, Err(Ok(SupplyChainError::QRCodeNotFound)));
+
+    supply_chain_client.submit_consumer_feedback(&consumer, &qr_code, &1, &comment_hash);
+    assert_eq!(
+        supply_chain_client
+            .get_feedback_summary(&product_id)
+            .average_rating,
+        100
+    );
+}
+
+#[test]
+fn test_feedback_on_recalled_product_is_flagged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, farmer, _, _, supply_chain_client, _) = setup_test_environment(&env);
+    let (product_id, qr_code) = setup_product_with_qr_code(&env, &supply_chain_client, &farmer);
+    let comment_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    let before_recall = Address::generate(&env);
+    supply_chain_client.submit_consumer_feedback(&before_recall, &qr_code, &4, &comment_hash);
+
+    // Only the admin can recall
+    let result = supply_chain_client.try_recall_product(&farmer, &product_id);
+    assert_eq!(result, Err(Ok(SupplyChainError::UnauthorizedAccess)));
+
+    supply_chain_client.recall_product(&admin, &product_id);
+    assert!(supply_chain_client.is_product_recalled(&product_id));
+    let result = supply_chain_client.try_recall_product(&admin, &product_id);
+    assert_eq!(result, Err(Ok(SupplyChainError::AlreadyRecalled)));
+
+    let after_recall = Address::generate(&env);
+    supply_chain_client.submit_consumer_feedback(&after_recall, &qr_code, &1, &comment_hash);
+
+    let feedback = supply_chain_client.get_product_feedback(&product_id, &0, &10);
+    assert!(!feedback.get(0).unwrap().recalled);
+    assert!(feedback.get(1).unwrap().recalled);
+
+    let summary = supply_chain_client.get_feedback_summary(&product_id);
+    assert_eq!(summary.feedback_count, 2);
+    assert_eq!(summary.recalled_feedback_count, 1);
+}
+
+#[test]
+fn test_product_feedback_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, farmer, _, _, supply_chain_client, _) = setup_test_environment(&env);
+    let (product_id, qr_code) = setup_product_with_qr_code(&env, &supply_chain_client, &farmer);
+    let comment_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    let mut consumers = Vec::new(&env);
+    for rating in 1..=5u32 {
+        let consumer = Address::generate(&env);
+        supply_chain_client.submit_consumer_feedback(&consumer, &qr_code, &rating, &comment_hash);
+        consumers.push_back(consumer);
+    }
+
+    let first_page = supply_chain_client.get_product_feedback(&product_id, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(
+        first_page.get(0).unwrap().submitter,
+        consumers.get(0).unwrap()
+    );
+
+    let last_page = supply_chain_client.get_product_feedback(&product_id, &4, &2);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().rating, 5);
+
+    let past_end = supply_chain_client.get_product_feedback(&product_id, &10, &2);
+    assert_eq!(past_end.len(), 0);
+
+    assert_eq!(
+        supply_chain_client
+            .get_feedback_summary(&product_id)
+            .average_rating,
+        300
+    );
+}
+
+// =====================================================================================
+// EVENT SEQUENCE TESTS
+// =====================================================================================
+
+/// Sequence numbers (first topic) of the events the contract published in the last call
+fn published_sequences(env: &Env, contract: &Address) -> Vec<u64> {
+    let mut sequences = Vec::new(env);
+    for (address, topics, _) in env.events().all().iter() {
+        if address == *contract {
+            sequences.push_back(u64::try_from_val(env, &topics.get(0).unwrap()).unwrap());
+        }
+    }
+    sequences
+}
+
+#[test]
+fn test_event_sequence_increments_across_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, cert_mgmt_id, supply_chain_client, _) = create_test_contracts(&env);
+    let contract = supply_chain_client.address.clone();
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let handler = Address::generate(&env);
+
+    assert_eq!(supply_chain_client.get_event_sequence(), 0);
+    supply_chain_client.initialize(&admin, &cert_mgmt_id);
+    assert_eq!(published_sequences(&env, &contract), vec![&env, 1]);
+
+    // Registration publishes the product event and both index updates
+    let (product_type, batch_number, origin_location, metadata_hash) =
+        create_test_product_data(&env, "SEQ");
+    let product_id = supply_chain_client.register_product(
+        &farmer,
+        &product_type,
+        &batch_number,
+        &origin_location,
+        &metadata_hash,
+    );
+    assert_eq!(published_sequences(&env, &contract), vec![&env, 2, 3, 4]);
+
+    supply_chain_client.add_stage(
+        &product_id,
+        &StageTier::Planting,
+        &String::from_str(&env, "Planting"),
+        &String::from_str(&env, "Field A"),
+        &handler,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    assert_eq!(published_sequences(&env, &contract), vec![&env, 5]);
+
+    supply_chain_client.set_cert_mgmt_contract(&admin, &cert_mgmt_id);
+    assert_eq!(published_sequences(&env, &contract), vec![&env, 6]);
+    assert_eq!(supply_chain_client.get_event_sequence(), 6);
+}
+
+#[test]
+fn test_event_sequence_continues_from_stored_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (supply_chain_id, cert_mgmt_id, supply_chain_client, _) = create_test_contracts(&env);
+    let admin = Address::generate(&env);
+
+    // State written by an earlier contract version under the documented key
+    env.as_contract(&supply_chain_id, || {
+        let key: Vec<Val> = vec![&env, Symbol::new(&env, "EventSequence").into_val(&env)];
+        env.storage().instance().set(&key, &99u64);
+    });
+    assert_eq!(supply_chain_client.get_event_sequence(), 99);
+
+    supply_chain_client.initialize(&admin, &cert_mgmt_id);
+    assert_eq!(published_sequences(&env, &supply_chain_id), vec![&env, 100]);
+    assert_eq!(supply_chain_client.get_event_sequence(), 100);
+}
+
+// =====================================================================================
+// UPGRADE AND MIGRATION TESTS
+// =====================================================================================
+
+#[test]
+fn test_migrate_v1_cert_mgmt_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (supply_chain_id, cert_mgmt_id, supply_chain_client, _) = create_test_contracts(&env);
+    let admin = Address::generate(&env);
+    let farmer = Address::generate(&env);
+
+    supply_chain_client.initialize(&admin, &cert_mgmt_id);
+    assert_eq!(
+        supply_chain_client.get_storage_version(),
+        CURRENT_STORAGE_VERSION
+    );
+    let (product_type, batch_number, origin_location, metadata_hash) =
+        create_test_product_data(&env, "MIG");
+    let product_id = supply_chain_client.register_product(
+        &farmer,
+        &product_type,
+        &batch_number,
+        &origin_location,
+        &metadata_hash,
+    );
+
+    // Rewrite storage the way a pre-versioning deployment left it
+    env.as_contract(&supply_chain_id, || {
+        let storage = env.storage().instance();
+        storage.remove(&DataKey::StorageVersion);
+        storage.remove(&DataKey::CertMgmtContract);
+        storage.set(
+            &Symbol::new(&env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY),
+            &cert_mgmt_id,
+        );
+    });
+    assert_eq!(supply_chain_client.get_storage_version(), 1);
+    assert_eq!(
+        supply_chain_client.try_get_cert_mgmt_contract(),
+        Err(Ok(SupplyChainError::NotInitialized))
+    );
+
+    // After an upgrade the new code migrates the old layout
+    assert_eq!(supply_chain_client.migrate(), CURRENT_STORAGE_VERSION);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+        Symbol::new(&env, "storage_migrated")
+    );
+    assert_eq!(u32::try_from_val(&env, &topics.get(2).unwrap()).unwrap(), 1);
+    assert_eq!(
+        u32::try_from_val(&env, &data).unwrap(),
+        CURRENT_STORAGE_VERSION
+    );
+
+    assert_eq!(
+        supply_chain_client.get_storage_version(),
+        CURRENT_STORAGE_VERSION
+    );
+    assert_eq!(supply_chain_client.get_cert_mgmt_contract(), cert_mgmt_id);
+    env.as_contract(&supply_chain_id, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&Symbol::new(&env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY)));
+    });
+    assert_eq!(
+        supply_chain_client
+            .get_product_details(&product_id)
+            .farmer_id,
+        farmer
+    );
+
+    // Migrating twice is rejected
+    assert_eq!(
+        supply_chain_client.try_migrate(),
+        Err(Ok(SupplyChainError::AlreadyMigrated))
+    );
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_initialization() {
+    let env = Env::default();
+    let (_, _, supply_chain_client, _) = create_test_contracts(&env);
+
+    assert_eq!(
+        supply_chain_client.try_migrate(),
+        Err(Ok(SupplyChainError::NotInitialized))
+    );
+    assert_eq!(
+        supply_chain_client.try_upgrade(&BytesN::from_array(&env, &[1; 32])),
+        Err(Ok(SupplyChainError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, cert_mgmt_id, supply_chain_client, _) = create_test_contracts(&env);
+    supply_chain_client.initialize(&Address::generate(&env), &cert_mgmt_id);
+    env.set_auths(&[]);
+
+    let result = supply_chain_client.try_upgrade(&BytesN::from_array(&env, &[1; 32]));
+    assert!(result.is_err());
+}
+
+// =====================================================================================
+// TRACE SUMMARY TESTS
+// =====================================================================================
+
+/// Register a product and record its first `stages` tiers, one hour apart
+fn register_product_with_stages(
+    env: &Env,
+    supply_chain_client: &SupplyChainTrackingContractClient,
+    farmer: &Address,
+    handler: &Address,
+    prefix: &str,
+    stages: u32,
+) -> BytesN<32> {
+    let (product_type, batch_number, origin_location, metadata_hash) =
+        create_test_product_data(env, prefix);
+    let product_id = supply_chain_client.register_product(
+        farmer,
+        &product_type,
+        &batch_number,
+        &origin_location,
+        &metadata_hash,
+    );
+
+    for tier in 1..=stages {
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3_600);
+        supply_chain_client.add_stage(
+            &product_id,
+            &StageTier::from_value(tier).unwrap(),
+            &String::from_str(env, "Stage"),
+            &String::from_str(env, "Farm"),
+            handler,
+            &BytesN::from_array(env, &[tier as u8; 32]),
+        );
+    }
+
+    product_id
+}
+
+#[test]
+fn test_trace_summaries_mark_missing_products() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, farmer, handler, authority, supply_chain_client, cert_client) =
+        setup_test_environment(&env);
+
+    let certified =
+        register_product_with_stages(&env, &supply_chain_client, &farmer, &handler, "Cert", 3);
+    let cert_bytes = BytesN::from_array(&env, &[2u8; 32]);
+    setup_mock_certificate(
+        &cert_client,
+        &farmer,
+        &authority,
+        &cert_bytes,
+        CertStatus::Valid,
+        cert_bytes.clone(),
+    );
+    supply_chain_client.link_certificate(
+        &certified,
+        &CertificateId::Some(cert_bytes.clone()),
+        &authority,
+    );
+
+    let recalled =
+        register_product_with_stages(&env, &supply_chain_client, &farmer, &handler, "Recall", 0);
+    supply_chain_client.recall_product(&admin, &recalled);
+    let missing = BytesN::from_array(&env, &[9u8; 32]);
+
+    let summaries = supply_chain_client.get_trace_summaries(&vec![
+        &env,
+        certified.clone(),
+        missing.clone(),
+        recalled.clone(),
+    ]);
+    assert_eq!(summaries.len(), 3);
+
+    let summary = summaries.get(0).unwrap();
+    assert_eq!(summary.product_id, certified);
+    assert_eq!(summary.status, SummaryStatus::Found);
+    assert_eq!(
+        summary.product_type,
+        String::from_str(&env, "Cert_Organic_Tomatoes")
+    );
+    assert_eq!(summary.current_tier, StageTier::Harvesting.value());
+    assert_eq!(summary.stage_count, 3);
+    assert_eq!(summary.certificate_status, LinkedCertStatus::Valid);
+    assert!(!summary.recalled);
+
+    // A missing id is reported in place instead of failing the whole read
+    let summary = summaries.get(1).unwrap();
+    assert_eq!(summary.product_id, missing);
+    assert_eq!(summary.status, SummaryStatus::NotFound);
+    assert_eq!(summary.stage_count, 0);
+
+    let summary = summaries.get(2).unwrap();
+    assert_eq!(summary.status, SummaryStatus::Found);
+    assert_eq!(summary.current_tier, 0);
+    assert_eq!(summary.last_stage_timestamp, 0);
+    assert_eq!(summary.certificate_status, LinkedCertStatus::Unlinked);
+    assert!(summary.recalled);
+
+    // Certificate status is read live from certificate management
+    let cert_id = utils::convert_bytes_to_u32(&env, &cert_bytes);
+    cert_client.set_cert_status(&farmer, &cert_id, &CertStatus::Revoked);
+    let summaries = supply_chain_client.get_trace_summaries(&vec![&env, certified]);
+    assert_eq!(
+        summaries.get(0).unwrap().certificate_status,
+        LinkedCertStatus::Revoked
+    );
+}
+
+#[test]
+fn test_trace_summary_counters_follow_stages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, farmer, handler, _, supply_chain_client, _) = setup_test_environment(&env);
+
+    let product_id =
+        register_product_with_stages(&env, &supply_chain_client, &farmer, &handler, "Sync", 0);
+
+    for tier in 1..=4u32 {
+        env.ledger().set_timestamp(tier as u64 * 86_400);
+        supply_chain_client.add_stage(
+            &product_id,
+            &StageTier::from_value(tier).unwrap(),
+            &String::from_str(&env, "Stage"),
+            &String::from_str(&env, "Farm"),
+            &handler,
+            &BytesN::from_array(&env, &[tier as u8; 32]),
+        );
+
+        let (_, stages) = supply_chain_client.get_product_trace(&product_id);
+        let last_stage = stages.get(stages.len() - 1).unwrap();
+
+        let summary = supply_chain_client
+            .get_trace_summaries(&vec![&env, product_id.clone()])
+            .get(0)
+            .unwrap();
+        assert_eq!(summary.stage_count, stages.len());
+        assert_eq!(summary.last_stage_timestamp, last_stage.timestamp);
+        assert_eq!(summary.last_stage_timestamp, tier as u64 * 86_400);
+        assert_eq!(
+            summary.current_tier,
+            supply_chain_client
+                .get_current_tier(&product_id)
+                .unwrap()
+                .value()
+        );
+    }
+
+    // A rejected stage leaves the counters untouched
+    let result = supply_chain_client.try_add_stage(
+        &product_id,
+        &StageTier::Retail,
+        &String::from_str(&env, "Stage"),
+        &String::from_str(&env, "Farm"),
+        &handler,
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(SupplyChainError::InvalidTierProgression)));
+    let summary = supply_chain_client
+        .get_trace_summaries(&vec![&env, product_id])
+        .get(0)
+        .unwrap();
+    assert_eq!(summary.stage_count, 4);
+    assert_eq!(summary.last_stage_timestamp, 4 * 86_400);
+}
+
+#[test]
+fn test_trace_summaries_of_products_without_trace_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, farmer, handler, _, supply_chain_client, _) = setup_test_environment(&env);
+
+    let product_id =
+        register_product_with_stages(&env, &supply_chain_client, &farmer, &handler, "Old", 2);
+    let (_, stages) = supply_chain_client.get_product_trace(&product_id);
+
+    // Products registered before trace state was kept only have the product record
+    env.as_contract(&supply_chain_client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TraceState(product_id.clone()));
+    });
+    let summary = supply_chain_client
+        .get_trace_summaries(&vec![&env, product_id.clone()])
+        .get(0)
+        .unwrap();
+    assert_eq!(summary.status, SummaryStatus::Found);
+    assert_eq!(summary.stage_count, 2);
+    assert_eq!(
+        summary.last_stage_timestamp,
+        stages.get(1).unwrap().timestamp
+    );
+
+    // The next stage writes it again
+    supply_chain_client.add_stage(
+        &product_id,
+        &StageTier::Harvesting,
+        &String::from_str(&env, "Stage"),
+        &String::from_str(&env, "Farm"),
+        &handler,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    env.as_contract(&supply_chain_client.address, || {
+        let state: TraceState = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TraceState(product_id.clone()))
+            .unwrap();
+        assert_eq!(state.stage_count, 3);
+        assert_eq!(state.farmer_id, farmer);
+    });
+}
+
+#[test]
+fn test_trace_summaries_enforce_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, _, _, _, supply_chain_client, _) = setup_test_environment(&env);
+
+    let mut product_ids = Vec::new(&env);
+    for i in 0..MAX_TRACE_SUMMARY_IDS {
+        product_ids.push_back(BytesN::from_array(&env, &[i as u8; 32]));
+    }
+    let summaries = supply_chain_client.get_trace_summaries(&product_ids);
+    assert_eq!(summaries.len(), MAX_TRACE_SUMMARY_IDS);
+    assert!(summaries
+        .iter()
+        .all(|summary| summary.status == SummaryStatus::NotFound));
+
+    product_ids.push_back(BytesN::from_array(&env, &[255u8; 32]));
+    assert_eq!(
+        supply_chain_client.try_get_trace_summaries(&product_ids),
+        Err(Ok(SupplyChainError::TooManyProducts))
+    );
+}
@@ -1,4 +1,8 @@
-use crate::datatypes::{DataKey, Product, Stage, StageTier, SupplyChainError};
+use crate::datatypes::{
+    DataKey, LinkedCertStatus, Product, ProductRegistration, Stage, StageTier, SummaryStatus,
+    SupplyChainError, TraceState, TraceSummary, MAX_TRACE_SUMMARY_IDS,
+};
+use crate::{product, utils, validation};
 use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
 /// Add a new stage to the product lifecycle with tier validation
@@ -48,9 +52,11 @@ pub fn add_stage(
     env.storage()
         .persistent()
         .set(&DataKey::Product(product_id.clone()), &product);
+    write_trace_state(&env, &product);
 
     // Emit event
-    env.events().publish(
+    utils::publish_event(
+        &env,
         (Symbol::new(&env, "stage_added"), handler),
         (product_id, stage_id),
     );
@@ -74,6 +80,107 @@ pub fn get_product_trace(
     Ok((product, stages))
 }
 
+/// Refresh the listing fields of a product after it is registered or changed
+pub fn write_trace_state(env: &Env, product: &Product) {
+    let state = TraceState {
+        farmer_id: product.farmer_id.clone(),
+        certificate_id: product.certificate_id.clone(),
+        stage_count: product.stages.len(),
+        last_stage_timestamp: product
+            .stages
+            .last()
+            .map(|stage| stage.timestamp)
+            .unwrap_or(0),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::TraceState(product.product_id.clone()), &state);
+}
+
+/// Listing fields of a product. Products registered before they were kept get them
+/// from the product record until their next stage or certificate change.
+fn read_trace_state(env: &Env, product_id: &BytesN<32>) -> Option<TraceState> {
+    if let Some(state) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::TraceState(product_id.clone()))
+    {
+        return Some(state);
+    }
+
+    let product: Product = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Product(product_id.clone()))?;
+    Some(TraceState {
+        farmer_id: product.farmer_id,
+        certificate_id: product.certificate_id,
+        stage_count: product.stages.len(),
+        last_stage_timestamp: product
+            .stages
+            .last()
+            .map(|stage| stage.timestamp)
+            .unwrap_or(0),
+    })
+}
+
+/// Summarize up to `MAX_TRACE_SUMMARY_IDS` products for listings. Only the per-product
+/// trace state is read, not the stages, and missing products are reported with
+/// `SummaryStatus::NotFound` rather than failing the call.
+pub fn get_trace_summaries(
+    env: Env,
+    product_ids: Vec<BytesN<32>>,
+) -> Result<Vec<TraceSummary>, SupplyChainError> {
+    if product_ids.len() > MAX_TRACE_SUMMARY_IDS {
+        return Err(SupplyChainError::TooManyProducts);
+    }
+
+    let mut summaries = Vec::new(&env);
+    for product_id in product_ids.iter() {
+        let summary = match read_trace_state(&env, &product_id) {
+            Some(state) => {
+                let product_type = env
+                    .storage()
+                    .persistent()
+                    .get::<_, ProductRegistration>(&DataKey::ProductRegistration(
+                        product_id.clone(),
+                    ))
+                    .map(|registration| registration.product_type)
+                    .unwrap_or_else(|| String::from_str(&env, ""));
+
+                // Tiers advance one at a time from Planting, so the count is the current tier
+                TraceSummary {
+                    product_id: product_id.clone(),
+                    status: SummaryStatus::Found,
+                    product_type,
+                    current_tier: state.stage_count,
+                    stage_count: state.stage_count,
+                    last_stage_timestamp: state.last_stage_timestamp,
+                    certificate_status: validation::linked_cert_status(
+                        &env,
+                        &state.farmer_id,
+                        &state.certificate_id,
+                    ),
+                    recalled: product::is_product_recalled(env.clone(), product_id.clone()),
+                }
+            }
+            None => TraceSummary {
+                product_id: product_id.clone(),
+                status: SummaryStatus::NotFound,
+                product_type: String::from_str(&env, ""),
+                current_tier: 0,
+                stage_count: 0,
+                last_stage_timestamp: 0,
+                certificate_status: LinkedCertStatus::Unlinked,
+                recalled: false,
+            },
+        };
+        summaries.push_back(summary);
+    }
+
+    Ok(summaries)
+}
+
 /// Get the current stage of a product
 pub fn get_current_stage(env: Env, product_id: BytesN<32>) -> Result<Stage, SupplyChainError> {
     let product: Product = env
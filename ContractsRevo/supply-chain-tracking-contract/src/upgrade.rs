@@ -0,0 +1,95 @@
+use crate::datatypes::{DataKey, SupplyChainError, CERTIFICATE_MANAGEMENT_CONTRACT_KEY};
+use crate::utils;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+/// Storage layout written by this build of the contract
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+
+/// Layout of contracts deployed before storage versions were tracked
+const LEGACY_STORAGE_VERSION: u32 = 1;
+
+/// Storage version of the deployed data; contracts initialized before versioning report 1
+pub fn get_storage_version(env: &Env) -> Result<u32, SupplyChainError> {
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(SupplyChainError::NotInitialized);
+    }
+
+    Ok(env
+        .storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(LEGACY_STORAGE_VERSION))
+}
+
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::StorageVersion, &version);
+}
+
+/// Replace the contract code (admin only). Call `migrate` afterwards to bring the
+/// stored data up to the layout the new code expects.
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), SupplyChainError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SupplyChainError::NotInitialized)?;
+    admin.require_auth();
+
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    utils::publish_event(
+        env,
+        (Symbol::new(env, "contract_upgraded"), admin),
+        new_wasm_hash,
+    );
+
+    Ok(())
+}
+
+/// Run every migration step between the stored version and the current one.
+/// Each step is idempotent, so a partially applied migration can be retried.
+pub fn migrate(env: &Env) -> Result<u32, SupplyChainError> {
+    let from_version = get_storage_version(env)?;
+    if from_version == CURRENT_STORAGE_VERSION {
+        return Err(SupplyChainError::AlreadyMigrated);
+    }
+
+    let mut version = from_version;
+    while version < CURRENT_STORAGE_VERSION {
+        match version {
+            1 => migrate_cert_mgmt_key(env),
+            _ => return Err(SupplyChainError::UnsupportedStorageVersion),
+        }
+        version += 1;
+        set_storage_version(env, version);
+    }
+
+    if version != CURRENT_STORAGE_VERSION {
+        return Err(SupplyChainError::UnsupportedStorageVersion);
+    }
+
+    utils::publish_event(
+        env,
+        (Symbol::new(env, "storage_migrated"), from_version),
+        version,
+    );
+
+    Ok(version)
+}
+
+/// v1 -> v2: move the certificate management address from its string key to a DataKey variant
+fn migrate_cert_mgmt_key(env: &Env) {
+    let legacy_key = Symbol::new(env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY);
+    let legacy: Option<Address> = env.storage().instance().get(&legacy_key);
+    let Some(cert_mgmt) = legacy else {
+        return;
+    };
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CertMgmtContract, &cert_mgmt);
+    env.storage().instance().remove(&legacy_key);
+}
@@ -1,5 +1,28 @@
 use crate::datatypes::{DataKey, Product, SupplyChainError};
-use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
+use soroban_sdk::{
+    events::Topics, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Val, Vec,
+};
+
+/// Publish an event with the next contract-wide sequence number as its first topic,
+/// letting indexers detect and backfill missed events
+pub fn publish_event<T: Topics, D: IntoVal<Env, Val>>(env: &Env, topics: T, data: D) {
+    let sequence = get_event_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::EventSequence, &sequence);
+
+    let mut topics: Vec<Val> = topics.into_val(env);
+    topics.push_front(sequence.into_val(env));
+    env.events().publish(topics, data);
+}
+
+/// Sequence number of the most recently published event, 0 if none
+pub fn get_event_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventSequence)
+        .unwrap_or(0)
+}
 
 /// Generate unique product ID using hash of farmer, product type, batch, and timestamp
 pub fn generate_product_id(
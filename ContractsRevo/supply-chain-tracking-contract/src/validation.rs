@@ -1,8 +1,8 @@
 use crate::datatypes::{
-    CertStatus, CertificateId, Certification, CertificationError, DataKey, Product,
-    SupplyChainError, VerifyError, CERTIFICATE_MANAGEMENT_CONTRACT_KEY,
+    CertStatus, CertificateId, Certification, CertificationError, DataKey, LinkedCertStatus,
+    Product, SupplyChainError, VerifyError,
 };
-use crate::utils;
+use crate::{tracking, utils};
 use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
 
 /// Validate product authenticity against recorded data and certifications
@@ -72,8 +72,10 @@ pub fn link_certificate(
     env.storage()
         .persistent()
         .set(&DataKey::Product(product_id.clone()), &product);
+    tracking::write_trace_state(&env, &product);
 
-    env.events().publish(
+    utils::publish_event(
+        &env,
         (Symbol::new(&env, "certificate_linked"), authority.clone()),
         (product_id.clone(), certificate_id.clone()),
     );
@@ -95,6 +97,39 @@ pub fn get_linked_certificate(
     Ok(product.certificate_id)
 }
 
+/// Current status of a product's linked certificate. Read failures are reported as
+/// `Unavailable` so a listing never fails because of one certificate.
+pub fn linked_cert_status(
+    env: &Env,
+    farmer_id: &Address,
+    certificate_id: &CertificateId,
+) -> LinkedCertStatus {
+    let CertificateId::Some(cert_bytes) = certificate_id else {
+        return LinkedCertStatus::Unlinked;
+    };
+    let Some(cert_mgmt) = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::CertMgmtContract)
+    else {
+        return LinkedCertStatus::Unavailable;
+    };
+
+    let cert_id_u32 = utils::convert_bytes_to_u32(env, cert_bytes);
+    let args = vec![&env, farmer_id.into_val(env), cert_id_u32.into_val(env)];
+
+    match env.try_invoke_contract::<CertStatus, CertificationError>(
+        &cert_mgmt,
+        &Symbol::new(env, "check_cert_status"),
+        args,
+    ) {
+        Ok(Ok(CertStatus::Valid)) => LinkedCertStatus::Valid,
+        Ok(Ok(CertStatus::Expired)) => LinkedCertStatus::Expired,
+        Ok(Ok(CertStatus::Revoked)) => LinkedCertStatus::Revoked,
+        _ => LinkedCertStatus::Unavailable,
+    }
+}
+
 /// Verify the integrity of all stages in a product's supply chain
 fn verify_stages_integrity(env: &Env, product: &Product, verification_data: &BytesN<32>) -> bool {
     if product.stages.is_empty() {
@@ -117,11 +152,7 @@ fn validate_certificate_hash(
     certificate_id_bytes: &BytesN<32>,
     verification_hash: &BytesN<32>,
 ) -> Result<bool, SupplyChainError> {
-    let cert_mgmt: Address = match env
-        .storage()
-        .instance()
-        .get(&Symbol::new(env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY))
-    {
+    let cert_mgmt: Address = match env.storage().instance().get(&DataKey::CertMgmtContract) {
         Some(addr) => addr,
         None => return Err(SupplyChainError::NotInitialized),
     };
@@ -161,11 +192,7 @@ fn confirm_certificate_status_valid(
     cert_id_bytes: &BytesN<32>,
 ) -> Result<bool, SupplyChainError> {
     // Check certification status by interacting with certification management contract
-    let cert_mgmt: Address = match env
-        .storage()
-        .instance()
-        .get(&Symbol::new(env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY))
-    {
+    let cert_mgmt: Address = match env.storage().instance().get(&DataKey::CertMgmtContract) {
         Some(addr) => addr,
         None => return Err(SupplyChainError::NotInitialized),
     };
@@ -196,11 +223,7 @@ fn verify_certificate_exists(
     certificate_id_bytes: &BytesN<32>,
 ) -> Result<bool, SupplyChainError> {
     // Retrieve the certificate management contract address
-    let cert_mgmt: Address = match env
-        .storage()
-        .instance()
-        .get(&Symbol::new(env, CERTIFICATE_MANAGEMENT_CONTRACT_KEY))
-    {
+    let cert_mgmt: Address = match env.storage().instance().get(&DataKey::CertMgmtContract) {
         Some(addr) => addr,
         None => return Err(SupplyChainError::NotInitialized),
     };
@@ -0,0 +1,54 @@
+use crate::datatypes::*;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+/// Number of admin actions retained; older entries are overwritten
+pub const MAX_ADMIN_LOG: u64 = 500;
+
+/// Maximum number of entries returned by a single `get_admin_log` call
+pub const MAX_ADMIN_LOG_PAGE_SIZE: u32 = 100;
+
+/// Appends an admin action to the log, overwriting the oldest entry once it is full
+pub fn record(env: &Env, action: &str, caller: &Address, target: AdminTarget) {
+    let seq = total(env);
+    env.storage().persistent().set(
+        &DataKey::AdminLog((seq % MAX_ADMIN_LOG) as u32),
+        &AdminActionLog {
+            seq,
+            action: Symbol::new(env, action),
+            caller: caller.clone(),
+            target,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    env.storage()
+        .instance()
+        .set(&DataKey::AdminLogTotal, &(seq + 1));
+}
+
+/// Gets one page of the retained log. `offset` counts from the oldest retained entry and
+/// `limit` is clamped to `MAX_ADMIN_LOG_PAGE_SIZE`.
+pub fn get_admin_log(env: &Env, offset: u32, limit: u32) -> AdminLogPage {
+    let total = total(env);
+    let start = total.saturating_sub(MAX_ADMIN_LOG) + offset as u64;
+    let end = total.min(start + limit.min(MAX_ADMIN_LOG_PAGE_SIZE) as u64);
+
+    let mut entries = Vec::new(env);
+    for seq in start..end {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AdminLog((seq % MAX_ADMIN_LOG) as u32))
+        {
+            entries.push_back(entry);
+        }
+    }
+
+    AdminLogPage { entries, total }
+}
+
+fn total(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AdminLogTotal)
+        .unwrap_or(0)
+}
@@ -1,5 +1,5 @@
 use crate::{datatypes::*, error::ContractError, incentives, utils, water_usage};
-use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 /// Generates alert for excessive water consumption
 pub fn generate_alert(
@@ -67,6 +67,20 @@ pub fn generate_alert(
     Ok(())
 }
 
+/// Generates an alert id for an alert raised without one
+pub fn generate_manual_alert_id(
+    env: &Env,
+    farmer_id: &Address,
+    parcel_id: &BytesN<32>,
+    alert_type: &AlertType,
+) -> BytesN<32> {
+    let mut fields = Bytes::new(env);
+    fields.append(&farmer_id.clone().to_xdr(env));
+    fields.append(&Bytes::from_array(env, &parcel_id.to_array()));
+    fields.append(&alert_type.clone().to_xdr(env));
+    utils::generate_id(env, &fields)
+}
+
 /// Checks water usage against thresholds and generates alerts if needed
 pub fn check_usage_and_alert(env: &Env, usage_id: BytesN<32>) -> Result<(), ContractError> {
     // Get the water usage record
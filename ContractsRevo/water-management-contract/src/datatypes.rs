@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
@@ -11,6 +11,15 @@ pub struct WaterUsage {
     pub data_hash: BytesN<32>, // Hash of off-chain sensor data
 }
 
+/// One page of usage records for a farmer or parcel
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct WaterUsagePage {
+    pub usages: Vec<WaterUsage>, // Records in this page, oldest first
+    pub total: u32,              // Records across all pages
+    pub truncated: bool,         // true if more records exist past this page
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub struct Incentive {
@@ -18,6 +27,41 @@ pub struct Incentive {
     pub reward_amount: i128,
     pub timestamp: u64,
     pub usage_id: BytesN<32>, // Reference to the water usage that earned the reward
+    pub boost_bps: u32,       // Certification boost applied to the reward (0 if none)
+    pub boost_cert_id: Option<u32>, // Certificate that justified the boost
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct CertificationBoost {
+    pub cert_contract: Address, // certificate-management-contract instance
+    pub cert_type: Symbol,      // Certification type that earns the boost
+    pub boost_bps: u32,         // Extra reward in basis points (10000 = +100%)
+}
+
+/// Mirror of the certificate-management-contract `CertStatus`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum CertStatus {
+    Valid,
+    Expired,
+    Revoked,
+}
+
+/// Mirror of the certificate-management-contract `Certification`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Certification {
+    pub id: u32,
+    pub cert_type: Symbol,
+    pub issuer: Address,
+    pub issued_date: u64,
+    pub expiration_date: u64,
+    pub verification_hash: BytesN<32>,
+    pub status: CertStatus,
+    pub delegate: Option<Address>,
+    pub attributes: Map<Symbol, String>,
+    pub document_uri: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -62,6 +106,44 @@ pub struct UsageReport {
     pub efficiency_score: u32, // 0-100 efficiency rating
 }
 
+/// What an admin action applied to
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum AdminTarget {
+    Contract,
+    Parcel(BytesN<32>),
+    Alert(BytesN<32>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AdminActionLog {
+    pub seq: u64, // Position in the full log; a gap against an archive means evicted entries
+    pub action: Symbol,
+    pub caller: Address,
+    pub target: AdminTarget,
+    pub timestamp: u64,
+}
+
+/// One page of the admin log
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct AdminLogPage {
+    pub entries: Vec<AdminActionLog>, // Retained entries in this page, oldest first
+    pub total: u64,                   // Admin actions ever logged
+}
+
+/// Read-only snapshot of contract-wide state for monitoring.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ContractInfo {
+    pub admin: Option<Address>,
+    pub cert_contract: Option<Address>, // Certificate registry used for incentive boosts
+    pub boost_bps: u32,                 // Configured certification boost (0 if none)
+}
+
+pub const CONTRACT_VERSION: (u32, u32, u32) = (1, 0, 0);
+
 #[contracttype]
 pub enum DataKey {
     Usage(BytesN<32>),
@@ -73,4 +155,9 @@ pub enum DataKey {
     FarmerIncentives(Address),
     FarmerAlerts(Address), // Index of alert IDs for a farmer
     Admin,
+    CertificationBoost,
+    IdCounter,        // Bumped for every contract-generated id
+    LegacyIdsEnabled, // Whether caller-supplied ids are still accepted
+    AdminLog(u32),    // Ring-buffer slot for admin action seq % MAX_ADMIN_LOG
+    AdminLogTotal,    // Admin actions ever logged, including evicted ones
 }
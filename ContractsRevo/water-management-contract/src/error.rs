@@ -8,6 +8,7 @@ pub enum ContractError {
     AlreadyInitialized = 2,
     Unauthorized = 3,
     InvalidInput = 4,
+    LegacyIdsDisabled = 5,
 
     // Water usage errors
     UsageNotFound = 10,
@@ -26,6 +27,7 @@ pub enum ContractError {
     IncentiveAlreadyExists = 31,
     InvalidRewardAmount = 32,
     InsufficientEfficiency = 33,
+    InvalidBoost = 34,
 
     // Alert errors
     AlertNotFound = 40,
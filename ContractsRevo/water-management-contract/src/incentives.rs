@@ -1,5 +1,24 @@
 use crate::{datatypes::*, error::ContractError, utils, water_usage};
-use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{contractclient, Address, BytesN, Env, Symbol, Vec};
+
+/// Maximum certification boost (doubles the reward)
+pub const MAX_CERTIFICATION_BOOST_BPS: u32 = 10_000;
+
+/// Number of valid certifications inspected when looking for a boost
+const CERT_LOOKUP_LIMIT: u32 = 20;
+
+// Interface of the certificate-management-contract used for boost lookups
+#[allow(dead_code)]
+#[contractclient(name = "CertificateManagementContractClient")]
+pub trait CertificateManagementContract {
+    fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Certification>;
+}
 
 /// Issues incentive rewards for efficient water usage
 pub fn issue_incentive(
@@ -29,9 +48,13 @@ pub fn issue_incentive(
     }
 
     // Calculate reward amount based on efficiency
-    let reward_amount =
+    let mut reward_amount =
         utils::calculate_reward_amount(usage.volume, threshold.daily_limit, base_reward);
 
+    // Apply the sustainability certification boost, if the farmer qualifies
+    let (boost_bps, boost_cert_id) = find_certification_boost(env, &usage.farmer_id);
+    reward_amount += reward_amount * boost_bps as i128 / 10_000;
+
     if reward_amount <= 0 {
         return Err(ContractError::InvalidRewardAmount);
     }
@@ -44,6 +67,8 @@ pub fn issue_incentive(
         reward_amount,
         timestamp,
         usage_id: usage_id.clone(),
+        boost_bps,
+        boost_cert_id,
     };
 
     // Store the incentive
@@ -71,6 +96,16 @@ pub fn issue_incentive(
         (usage_id.clone(), reward_amount, timestamp),
     );
 
+    if let Some(cert_id) = boost_cert_id {
+        env.events().publish(
+            (
+                Symbol::new(env, "certification_boost_applied"),
+                usage.farmer_id.clone(),
+            ),
+            (usage_id.clone(), cert_id, boost_bps),
+        );
+    }
+
     // Emit loyalty token reward event for integration
     env.events().publish(
         (
@@ -83,6 +118,68 @@ pub fn issue_incentive(
     Ok(())
 }
 
+/// Configures the reward boost for farmers holding a valid certification (admin only)
+pub fn set_certification_boost(
+    env: &Env,
+    admin: Address,
+    cert_contract: Address,
+    cert_type: Symbol,
+    boost_bps: u32,
+) -> Result<(), ContractError> {
+    utils::require_admin_auth(env, &admin)?;
+
+    if boost_bps > MAX_CERTIFICATION_BOOST_BPS {
+        return Err(ContractError::InvalidBoost);
+    }
+
+    let boost = CertificationBoost {
+        cert_contract: cert_contract.clone(),
+        cert_type: cert_type.clone(),
+        boost_bps,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::CertificationBoost, &boost);
+
+    env.events().publish(
+        (Symbol::new(env, "certification_boost_set"), admin),
+        (cert_contract, cert_type, boost_bps),
+    );
+
+    Ok(())
+}
+
+/// Gets the configured certification boost
+pub fn get_certification_boost(env: &Env) -> Option<CertificationBoost> {
+    env.storage().instance().get(&DataKey::CertificationBoost)
+}
+
+/// Looks up a valid certification of the configured type for the farmer
+/// A failed cross-contract call yields no boost instead of failing the incentive
+fn find_certification_boost(env: &Env, farmer_id: &Address) -> (u32, Option<u32>) {
+    let Some(boost) = get_certification_boost(env) else {
+        return (0, None);
+    };
+    if boost.boost_bps == 0 {
+        return (0, None);
+    }
+
+    let client = CertificateManagementContractClient::new(env, &boost.cert_contract);
+    let certs =
+        match client.try_list_certs(farmer_id, &Some(CertStatus::Valid), &0, &CERT_LOOKUP_LIMIT) {
+            Ok(Ok(certs)) => certs,
+            _ => return (0, None),
+        };
+
+    for cert in certs.iter() {
+        if cert.cert_type == boost.cert_type && cert.status == CertStatus::Valid {
+            return (boost.boost_bps, Some(cert.id));
+        }
+    }
+
+    (0, None)
+}
+
 /// Retrieves incentive record by usage ID
 pub fn get_incentive(env: &Env, usage_id: BytesN<32>) -> Result<Incentive, ContractError> {
     env.storage()
@@ -1,6 +1,7 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
+mod admin_log;
 mod alerts;
 mod datatypes;
 mod error;
@@ -35,11 +36,12 @@ impl WaterManagementContract {
         // Emit initialization event
         env.events()
             .publish((Symbol::new(&env, "contract_initialized"),), admin.clone());
+        admin_log::record(&env, "initialize", &admin, AdminTarget::Contract);
 
         Ok(())
     }
 
-    /// Record water usage data for a parcel or crop
+    /// Record water usage data under a caller-supplied ID (compatibility mode only)
     pub fn record_usage(
         env: Env,
         usage_id: BytesN<32>,
@@ -49,9 +51,23 @@ impl WaterManagementContract {
         data_hash: BytesN<32>,
     ) -> Result<(), ContractError> {
         farmer_id.require_auth();
+        utils::require_legacy_ids(&env)?;
 
-        // Record the usage
-        water_usage::record_usage(
+        record_and_process_usage(&env, usage_id, farmer_id, parcel_id, volume, data_hash)
+    }
+
+    /// Record water usage data for a parcel or crop and return its generated ID
+    pub fn submit_usage(
+        env: Env,
+        farmer_id: Address,
+        parcel_id: BytesN<32>,
+        volume: i128,
+        data_hash: BytesN<32>,
+    ) -> Result<BytesN<32>, ContractError> {
+        farmer_id.require_auth();
+
+        let usage_id = water_usage::generate_usage_id(&env, &farmer_id, &parcel_id, &data_hash);
+        record_and_process_usage(
             &env,
             usage_id.clone(),
             farmer_id,
@@ -60,21 +76,22 @@ impl WaterManagementContract {
             data_hash,
         )?;
 
-        // Check for alerts - log errors but don't fail the main operation
-        if let Err(_e) = alerts::check_usage_and_alert(&env, usage_id.clone()) {
-            // In production, you would log this error for monitoring
-            // For now, we continue as usage recording is the primary operation
-        }
-
-        // Process automatic incentive - log errors but don't fail the main operation
-        if let Err(_e) = incentives::process_automatic_incentive(&env, usage_id) {
-            // In production, you would log this error for monitoring
-            // For now, we continue as usage recording is the primary operation
-        }
+        Ok(usage_id)
+    }
 
+    /// Turn acceptance of caller-supplied usage and alert IDs on or off (admin only)
+    pub fn set_legacy_ids(env: Env, admin: Address, enabled: bool) -> Result<(), ContractError> {
+        admin.require_auth();
+        utils::set_legacy_ids(&env, admin.clone(), enabled)?;
+        admin_log::record(&env, "set_legacy_ids", &admin, AdminTarget::Contract);
         Ok(())
     }
 
+    /// Whether caller-supplied usage and alert IDs are still accepted
+    pub fn legacy_ids_enabled(env: Env) -> bool {
+        utils::legacy_ids_enabled(&env)
+    }
+
     /// Issue incentive reward for efficient water usage
     pub fn issue_incentive(
         env: Env,
@@ -88,7 +105,52 @@ impl WaterManagementContract {
         incentives::issue_incentive(&env, usage_id, base_reward)
     }
 
-    /// Generate alert for excessive water consumption
+    /// Configure the incentive boost for farmers holding a valid certification (admin only)
+    pub fn set_certification_boost(
+        env: Env,
+        admin: Address,
+        cert_contract: Address,
+        cert_type: Symbol,
+        boost_bps: u32,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        incentives::set_certification_boost(
+            &env,
+            admin.clone(),
+            cert_contract,
+            cert_type,
+            boost_bps,
+        )?;
+        admin_log::record(
+            &env,
+            "set_certification_boost",
+            &admin,
+            AdminTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Get the configured certification boost, if any
+    pub fn get_certification_boost(env: Env) -> Option<CertificationBoost> {
+        incentives::get_certification_boost(&env)
+    }
+
+    /// Contract-wide state for monitoring; safe to call before `initialize`
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        let boost = incentives::get_certification_boost(&env);
+        ContractInfo {
+            admin: env.storage().instance().get(&DataKey::Admin),
+            cert_contract: boost.as_ref().map(|b| b.cert_contract.clone()),
+            boost_bps: boost.map(|b| b.boost_bps).unwrap_or(0),
+        }
+    }
+
+    /// Contract version as (major, minor, patch)
+    pub fn version(_env: Env) -> (u32, u32, u32) {
+        CONTRACT_VERSION
+    }
+
+    /// Generate alert under a caller-supplied ID (compatibility mode only)
     pub fn generate_alert(
         env: Env,
         alert_id: BytesN<32>,
@@ -98,10 +160,34 @@ impl WaterManagementContract {
         message: String,
     ) -> Result<(), ContractError> {
         farmer_id.require_auth();
+        utils::require_legacy_ids(&env)?;
 
         alerts::generate_alert(&env, alert_id, farmer_id, parcel_id, alert_type, message)
     }
 
+    /// Generate alert for excessive water consumption and return its generated ID
+    pub fn raise_alert(
+        env: Env,
+        farmer_id: Address,
+        parcel_id: BytesN<32>,
+        alert_type: AlertType,
+        message: String,
+    ) -> Result<BytesN<32>, ContractError> {
+        farmer_id.require_auth();
+
+        let alert_id = alerts::generate_manual_alert_id(&env, &farmer_id, &parcel_id, &alert_type);
+        alerts::generate_alert(
+            &env,
+            alert_id.clone(),
+            farmer_id,
+            parcel_id,
+            alert_type,
+            message,
+        )?;
+
+        Ok(alert_id)
+    }
+
     /// Get water usage report for a farmer or parcel
     pub fn get_usage_report(
         env: Env,
@@ -125,12 +211,19 @@ impl WaterManagementContract {
         admin.require_auth();
         incentives::set_threshold(
             &env,
-            admin,
-            parcel_id,
+            admin.clone(),
+            parcel_id.clone(),
             daily_limit,
             weekly_limit,
             monthly_limit,
-        )
+        )?;
+        admin_log::record(
+            &env,
+            "set_threshold",
+            &admin,
+            AdminTarget::Parcel(parcel_id),
+        );
+        Ok(())
     }
 
     /// Get water usage threshold for a parcel
@@ -143,16 +236,36 @@ impl WaterManagementContract {
         water_usage::get_usage(&env, usage_id)
     }
 
-    /// Get all usage records for a farmer
+    /// Get usage records for a farmer, truncated to the first 100
     pub fn get_farmer_usages(env: Env, farmer_id: Address) -> Vec<WaterUsage> {
         water_usage::get_farmer_usages(&env, farmer_id)
     }
 
-    /// Get all usage records for a parcel
+    /// Get one page of usage records for a farmer
+    pub fn get_farmer_usages_paged(
+        env: Env,
+        farmer_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> WaterUsagePage {
+        water_usage::get_farmer_usages_paged(&env, farmer_id, offset, limit)
+    }
+
+    /// Get usage records for a parcel, truncated to the first 100
     pub fn get_parcel_usages(env: Env, parcel_id: BytesN<32>) -> Vec<WaterUsage> {
         water_usage::get_parcel_usages(&env, parcel_id)
     }
 
+    /// Get one page of usage records for a parcel
+    pub fn get_parcel_usages_paged(
+        env: Env,
+        parcel_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> WaterUsagePage {
+        water_usage::get_parcel_usages_paged(&env, parcel_id, offset, limit)
+    }
+
     /// Get incentive record by usage ID
     pub fn get_incentive(env: Env, usage_id: BytesN<32>) -> Result<Incentive, ContractError> {
         incentives::get_incentive(&env, usage_id)
@@ -201,7 +314,17 @@ impl WaterManagementContract {
             return Err(ContractError::Unauthorized);
         }
 
-        alerts::resolve_alert(&env, alert_id, resolver)
+        alerts::resolve_alert(&env, alert_id.clone(), resolver.clone())?;
+        // Farmers resolving their own alerts are not admin actions
+        if resolver == admin {
+            admin_log::record(&env, "resolve_alert", &admin, AdminTarget::Alert(alert_id));
+        }
+        Ok(())
+    }
+
+    /// Get one page of the admin action log, oldest retained entry first
+    pub fn get_admin_log(env: Env, offset: u32, limit: u32) -> AdminLogPage {
+        admin_log::get_admin_log(&env, offset, limit)
     }
 
     /// Get all alerts for a farmer
@@ -209,3 +332,37 @@ impl WaterManagementContract {
         alerts::get_farmer_alerts(&env, farmer_id, include_resolved)
     }
 }
+
+/// Stores a usage record, then runs alert checks and automatic incentives
+fn record_and_process_usage(
+    env: &Env,
+    usage_id: BytesN<32>,
+    farmer_id: Address,
+    parcel_id: BytesN<32>,
+    volume: i128,
+    data_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    // Record the usage
+    water_usage::record_usage(
+        env,
+        usage_id.clone(),
+        farmer_id,
+        parcel_id,
+        volume,
+        data_hash,
+    )?;
+
+    // Check for alerts - log errors but don't fail the main operation
+    if let Err(_e) = alerts::check_usage_and_alert(env, usage_id.clone()) {
+        // In production, you would log this error for monitoring
+        // For now, we continue as usage recording is the primary operation
+    }
+
+    // Process automatic incentive - log errors but don't fail the main operation
+    if let Err(_e) = incentives::process_automatic_incentive(env, usage_id) {
+        // In production, you would log this error for monitoring
+        // For now, we continue as usage recording is the primary operation
+    }
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, String, Symbol};
+
+use crate::{
+    admin_log::{MAX_ADMIN_LOG, MAX_ADMIN_LOG_PAGE_SIZE},
+    datatypes::*,
+    ContractError,
+};
+
+use super::utils::*;
+
+#[test]
+fn test_admin_calls_logged_in_order() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    client.set_threshold(&admin, &parcel_id, &5000i128, &35000i128, &150000i128);
+    client.set_legacy_ids(&admin, &false);
+    let alert_id = client.raise_alert(
+        &farmer,
+        &parcel_id,
+        &AlertType::SensorMalfunction,
+        &String::from_str(&env, "Sensor offline"),
+    );
+    env.ledger().set_timestamp(2_000);
+    client.resolve_alert(&alert_id, &admin);
+
+    let page = client.get_admin_log(&0, &10);
+    assert_eq!(page.total, 4);
+    assert_eq!(page.entries.len(), 4);
+
+    let expected = [
+        ("initialize", AdminTarget::Contract),
+        ("set_threshold", AdminTarget::Parcel(parcel_id)),
+        ("set_legacy_ids", AdminTarget::Contract),
+        ("resolve_alert", AdminTarget::Alert(alert_id)),
+    ];
+    for (i, (action, target)) in expected.iter().enumerate() {
+        let entry = page.entries.get(i as u32).unwrap();
+        assert_eq!(entry.seq, i as u64);
+        assert_eq!(entry.action, Symbol::new(&env, action));
+        assert_eq!(entry.target, *target);
+        assert_eq!(entry.caller, admin);
+    }
+    assert_eq!(page.entries.get(0).unwrap().timestamp, 1_000);
+    assert_eq!(page.entries.get(3).unwrap().timestamp, 2_000);
+
+    let second_page = client.get_admin_log(&2, &1);
+    assert_eq!(second_page.entries.len(), 1);
+    assert_eq!(second_page.entries.get(0).unwrap().seq, 2);
+}
+
+#[test]
+fn test_admin_log_evicts_oldest_entries() {
+    let (env, client, admin, _farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    for i in 0..MAX_ADMIN_LOG + 4 {
+        client.set_legacy_ids(&admin, &(i % 2 == 0));
+    }
+
+    // The initialize entry and the first four toggles were overwritten
+    let page = client.get_admin_log(&0, &2);
+    assert_eq!(page.total, MAX_ADMIN_LOG + 5);
+    assert_eq!(page.entries.get(0).unwrap().seq, 5);
+    assert_eq!(page.entries.get(1).unwrap().seq, 6);
+    assert_eq!(
+        page.entries.get(0).unwrap().action,
+        Symbol::new(&env, "set_legacy_ids")
+    );
+
+    let tail = client.get_admin_log(&(MAX_ADMIN_LOG as u32 - 1), &10);
+    assert_eq!(tail.entries.len(), 1);
+    assert_eq!(tail.entries.get(0).unwrap().seq, MAX_ADMIN_LOG + 4);
+
+    // Page size is clamped and offsets past the retained entries are empty
+    let capped = client.get_admin_log(&0, &1_000);
+    assert_eq!(capped.entries.len(), MAX_ADMIN_LOG_PAGE_SIZE);
+    let past_end = client.get_admin_log(&(MAX_ADMIN_LOG as u32), &10);
+    assert_eq!(past_end.entries.len(), 0);
+    assert_eq!(past_end.total, MAX_ADMIN_LOG + 5);
+}
+
+#[test]
+fn test_non_admin_calls_not_logged() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    client.submit_usage(
+        &farmer,
+        &parcel_id,
+        &100i128,
+        &create_test_data_hash(&env, 1),
+    );
+    let alert_id = client.raise_alert(
+        &farmer,
+        &parcel_id,
+        &AlertType::SensorMalfunction,
+        &String::from_str(&env, "Sensor offline"),
+    );
+    // The owning farmer resolving their own alert is not an admin action
+    client.resolve_alert(&alert_id, &farmer);
+
+    // Rejected admin calls leave no entry behind
+    let result = client.try_set_threshold(&farmer, &parcel_id, &5000i128, &35000i128, &150000i128);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    let result = client.try_set_threshold(&admin, &parcel_id, &0i128, &0i128, &0i128);
+    assert_eq!(result, Err(Ok(ContractError::InvalidThreshold)));
+
+    let page = client.get_admin_log(&0, &10);
+    assert_eq!(page.total, 1);
+    assert_eq!(
+        page.entries.get(0).unwrap().action,
+        Symbol::new(&env, "initialize")
+    );
+}
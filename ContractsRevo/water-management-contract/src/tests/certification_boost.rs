@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _, Address, BytesN, Env, Map,
+    Symbol, Vec,
+};
+
+use crate::{CertStatus, Certification, ContractError, WaterManagementContractClient};
+
+use super::utils::*;
+
+#[contracttype]
+enum MockKey {
+    Certs(Address),
+}
+
+/// Minimal stand-in for the certificate-management-contract
+#[contract]
+pub struct MockCertificateContract;
+
+#[contractimpl]
+impl MockCertificateContract {
+    pub fn add_cert(env: Env, owner: Address, id: u32, cert_type: Symbol, status: CertStatus) {
+        let key = MockKey::Certs(owner);
+        let mut certs: Vec<Certification> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        certs.push_back(Certification {
+            id,
+            cert_type,
+            issuer: env.current_contract_address(),
+            issued_date: 0,
+            expiration_date: u64::MAX,
+            verification_hash: BytesN::from_array(&env, &[9u8; 32]),
+            status,
+            delegate: None,
+            attributes: Map::new(&env),
+            document_uri: None,
+        });
+        env.storage().instance().set(&key, &certs);
+    }
+
+    pub fn revoke(env: Env, owner: Address, id: u32) {
+        let key = MockKey::Certs(owner);
+        let certs: Vec<Certification> = env.storage().instance().get(&key).unwrap();
+        let mut updated = Vec::new(&env);
+        for mut cert in certs.iter() {
+            if cert.id == id {
+                cert.status = CertStatus::Revoked;
+            }
+            updated.push_back(cert);
+        }
+        env.storage().instance().set(&key, &updated);
+    }
+
+    pub fn list_certs(
+        env: Env,
+        owner: Address,
+        status_filter: Option<CertStatus>,
+        _offset: u32,
+        _limit: u32,
+    ) -> Vec<Certification> {
+        let certs: Vec<Certification> = env
+            .storage()
+            .instance()
+            .get(&MockKey::Certs(owner))
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut page = Vec::new(&env);
+        for cert in certs.iter() {
+            if status_filter
+                .as_ref()
+                .is_none_or(|status| *status == cert.status)
+            {
+                page.push_back(cert);
+            }
+        }
+        page
+    }
+}
+
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    use crate::{CertStatus, Certification};
+
+    /// Certificate contract whose lookups always fail
+    #[contract]
+    pub struct FailingCertificateContract;
+
+    #[contractimpl]
+    impl FailingCertificateContract {
+        pub fn list_certs(
+            _env: Env,
+            _owner: Address,
+            _status_filter: Option<CertStatus>,
+            _offset: u32,
+            _limit: u32,
+        ) -> Vec<Certification> {
+            panic!("certificate registry unavailable")
+        }
+    }
+}
+
+/// Records an efficient usage (40% of the daily limit) which earns 2x the default reward
+fn record_efficient_usage(
+    env: &Env,
+    client: &WaterManagementContractClient,
+    admin: &Address,
+    farmer: &Address,
+    suffix: u8,
+) -> BytesN<32> {
+    let usage_id = create_test_usage_id(env, suffix);
+    let parcel_id = create_test_parcel_id(env, suffix);
+    client.set_threshold(admin, &parcel_id, &5000i128, &35000i128, &150000i128);
+    client.record_usage(
+        &usage_id,
+        farmer,
+        &parcel_id,
+        &2000i128,
+        &create_test_data_hash(env, suffix),
+    );
+    usage_id
+}
+
+fn setup_with_cert_contract() -> (
+    Env,
+    WaterManagementContractClient<'static>,
+    Address,
+    Address,
+    MockCertificateContractClient<'static>,
+) {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let cert_id = env.register(MockCertificateContract, ());
+    let certs = MockCertificateContractClient::new(&env, &cert_id);
+    client.set_certification_boost(&admin, &cert_id, &Symbol::new(&env, "WaterWise"), &2_500u32);
+
+    (env, client, admin, farmer, certs)
+}
+
+#[test]
+fn test_certified_farmer_earns_boost() {
+    let (env, client, admin, farmer, certs) = setup_with_cert_contract();
+    certs.add_cert(
+        &farmer,
+        &7,
+        &Symbol::new(&env, "WaterWise"),
+        &CertStatus::Valid,
+    );
+
+    let usage_id = record_efficient_usage(&env, &client, &admin, &farmer, 1);
+    let incentive = client.get_incentive(&usage_id);
+
+    assert_eq!(incentive.reward_amount, 250);
+    assert_eq!(incentive.boost_bps, 2_500);
+    assert_eq!(incentive.boost_cert_id, Some(7));
+}
+
+#[test]
+fn test_uncertified_farmer_is_not_boosted() {
+    let (env, client, admin, farmer, certs) = setup_with_cert_contract();
+    // A valid certification of another type does not qualify
+    certs.add_cert(
+        &farmer,
+        &1,
+        &Symbol::new(&env, "Organic"),
+        &CertStatus::Valid,
+    );
+
+    let usage_id = record_efficient_usage(&env, &client, &admin, &farmer, 1);
+    let incentive = client.get_incentive(&usage_id);
+
+    assert_eq!(incentive.reward_amount, 200);
+    assert_eq!(incentive.boost_bps, 0);
+    assert_eq!(incentive.boost_cert_id, None);
+}
+
+#[test]
+fn test_revoked_certification_loses_boost() {
+    let (env, client, admin, farmer, certs) = setup_with_cert_contract();
+    certs.add_cert(
+        &farmer,
+        &3,
+        &Symbol::new(&env, "WaterWise"),
+        &CertStatus::Valid,
+    );
+
+    let first = record_efficient_usage(&env, &client, &admin, &farmer, 1);
+    assert_eq!(client.get_incentive(&first).boost_cert_id, Some(3));
+
+    certs.revoke(&farmer, &3);
+
+    let second = record_efficient_usage(&env, &client, &admin, &farmer, 2);
+    let incentive = client.get_incentive(&second);
+    assert_eq!(incentive.reward_amount, 200);
+    assert_eq!(incentive.boost_cert_id, None);
+
+    // Rewards already issued keep their boost
+    assert_eq!(client.get_incentive(&first).reward_amount, 250);
+}
+
+#[test]
+fn test_failed_certificate_lookup_falls_back_to_no_boost() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let failing_id = env.register(failing::FailingCertificateContract, ());
+    client.set_certification_boost(
+        &admin,
+        &failing_id,
+        &Symbol::new(&env, "WaterWise"),
+        &2_500u32,
+    );
+
+    let usage_id = record_efficient_usage(&env, &client, &admin, &farmer, 1);
+    let incentive = client.get_incentive(&usage_id);
+
+    assert_eq!(incentive.reward_amount, 200);
+    assert_eq!(incentive.boost_cert_id, None);
+}
+
+#[test]
+fn test_set_certification_boost_validation() {
+    let (env, client, admin, farmer, _) = setup_with_cert_contract();
+    let cert_contract = Address::generate(&env);
+    let cert_type = Symbol::new(&env, "WaterWise");
+
+    let result = client.try_set_certification_boost(&farmer, &cert_contract, &cert_type, &1_000);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = client.try_set_certification_boost(&admin, &cert_contract, &cert_type, &10_001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidBoost)));
+
+    client.set_certification_boost(&admin, &cert_contract, &cert_type, &1_000);
+    let boost = client.get_certification_boost().unwrap();
+    assert_eq!(boost.cert_contract, cert_contract);
+    assert_eq!(boost.boost_bps, 1_000);
+}
@@ -0,0 +1,39 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Symbol};
+
+use super::utils::*;
+
+#[test]
+fn test_contract_info_before_initialize() {
+    let (_env, client, _admin, _farmer) = setup_test_environment();
+
+    let info = client.get_contract_info();
+    assert_eq!(info.admin, None);
+    assert_eq!(info.cert_contract, None);
+    assert_eq!(info.boost_bps, 0);
+    assert_eq!(client.version(), (1, 0, 0));
+}
+
+#[test]
+fn test_contract_info_reflects_configuration() {
+    let (env, client, admin, _farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.admin, Some(admin.clone()));
+    assert_eq!(info.cert_contract, None);
+
+    let cert_contract = Address::generate(&env);
+    client.set_certification_boost(
+        &admin,
+        &cert_contract,
+        &Symbol::new(&env, "WaterWise"),
+        &2_500u32,
+    );
+
+    let info = client.get_contract_info();
+    assert_eq!(info.cert_contract, Some(cert_contract));
+    assert_eq!(info.boost_bps, 2_500);
+}
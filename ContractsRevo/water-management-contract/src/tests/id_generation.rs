@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use soroban_sdk::{BytesN, String, Vec};
+
+use crate::{datatypes::*, ContractError};
+
+use super::utils::*;
+
+#[test]
+fn test_submit_usage_generates_unique_ids() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    let data_hash = create_test_data_hash(&env, 1);
+
+    // Identical submissions in one ledger still get distinct ids
+    let mut ids: Vec<BytesN<32>> = Vec::new(&env);
+    for _ in 0..20 {
+        let usage_id = client.submit_usage(&farmer, &parcel_id, &100i128, &data_hash);
+        assert!(!ids.contains(&usage_id));
+        assert_eq!(client.get_usage(&usage_id).volume, 100);
+        ids.push_back(usage_id);
+    }
+    assert_eq!(client.get_farmer_usages(&farmer).len(), 20);
+}
+
+#[test]
+fn test_raise_alert_generates_unique_ids() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    let message = String::from_str(&env, "Sensor offline");
+
+    let first = client.raise_alert(&farmer, &parcel_id, &AlertType::SensorMalfunction, &message);
+    let second = client.raise_alert(&farmer, &parcel_id, &AlertType::SensorMalfunction, &message);
+    assert_ne!(first, second);
+    assert_eq!(client.get_farmer_alerts(&farmer, &false).len(), 2);
+}
+
+#[test]
+fn test_caller_ids_rejected_when_legacy_mode_off() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+    assert!(client.legacy_ids_enabled());
+
+    client.set_legacy_ids(&admin, &false);
+    assert!(!client.legacy_ids_enabled());
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    let usage = client.try_record_usage(
+        &create_test_usage_id(&env, 1),
+        &farmer,
+        &parcel_id,
+        &100i128,
+        &create_test_data_hash(&env, 1),
+    );
+    assert_eq!(usage, Err(Ok(ContractError::LegacyIdsDisabled)));
+
+    let alert = client.try_generate_alert(
+        &create_test_alert_id(&env, 1),
+        &farmer,
+        &parcel_id,
+        &AlertType::SensorMalfunction,
+        &String::from_str(&env, "Sensor offline"),
+    );
+    assert_eq!(alert, Err(Ok(ContractError::LegacyIdsDisabled)));
+
+    // Generated ids keep working
+    client.submit_usage(
+        &farmer,
+        &parcel_id,
+        &100i128,
+        &create_test_data_hash(&env, 1),
+    );
+}
+
+#[test]
+fn test_legacy_mode_accepts_caller_ids() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.set_legacy_ids(&admin, &false);
+    client.set_legacy_ids(&admin, &true);
+
+    let usage_id = create_test_usage_id(&env, 7);
+    let parcel_id = create_test_parcel_id(&env, 1);
+    client.record_usage(
+        &usage_id,
+        &farmer,
+        &parcel_id,
+        &100i128,
+        &create_test_data_hash(&env, 1),
+    );
+    assert_eq!(client.get_usage(&usage_id).usage_id, usage_id);
+
+    // A reused caller id is rejected rather than overwritten
+    let duplicate = client.try_record_usage(
+        &usage_id,
+        &farmer,
+        &parcel_id,
+        &500i128,
+        &create_test_data_hash(&env, 2),
+    );
+    assert_eq!(duplicate, Err(Ok(ContractError::UsageAlreadyExists)));
+    assert_eq!(client.get_usage(&usage_id).volume, 100);
+}
+
+#[test]
+fn test_set_legacy_ids_requires_admin() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let result = client.try_set_legacy_ids(&farmer, &false);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert!(client.legacy_ids_enabled());
+}
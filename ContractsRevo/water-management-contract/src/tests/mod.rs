@@ -1,5 +1,9 @@
 // Test modules for water management contract
+pub mod admin_log;
 pub mod alerts;
+pub mod certification_boost;
+pub mod contract_info;
+pub mod id_generation;
 pub mod incentives;
 pub mod utils;
 pub mod water_usage;
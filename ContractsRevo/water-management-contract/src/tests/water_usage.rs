@@ -379,3 +379,92 @@ fn test_usage_timestamp_validation() {
     assert!(usage.timestamp <= current_time);
     assert!(current_time - usage.timestamp < 10); // Should be very recent
 }
+
+/// Records `count` usages for one farmer and parcel, with volume equal to the record number
+fn record_many_usages(
+    env: &Env,
+    client: &WaterManagementContractClient,
+    farmer: &Address,
+    parcel_id: &BytesN<32>,
+    count: u8,
+) {
+    for i in 1..=count {
+        client.record_usage(
+            &create_test_usage_id(env, i),
+            farmer,
+            parcel_id,
+            &(i as i128),
+            &create_test_data_hash(env, i),
+        );
+    }
+}
+
+#[test]
+fn test_farmer_usages_paged_ordering() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    record_many_usages(&env, &client, &farmer, &parcel_id, 160);
+
+    let mut expected_volume = 1i128;
+    let mut offset = 0;
+    loop {
+        let page = client.get_farmer_usages_paged(&farmer, &offset, &25);
+        assert_eq!(page.total, 160);
+        for usage in page.usages.iter() {
+            assert_eq!(usage.volume, expected_volume);
+            expected_volume += 1;
+        }
+        offset += page.usages.len();
+        if !page.truncated {
+            break;
+        }
+    }
+    assert_eq!(expected_volume, 161);
+
+    // The same page is returned on every read
+    assert_eq!(
+        client.get_farmer_usages_paged(&farmer, &50, &25),
+        client.get_farmer_usages_paged(&farmer, &50, &25)
+    );
+}
+
+#[test]
+fn test_usage_listing_limit_is_clamped() {
+    let (env, client, admin, farmer) = setup_test_environment();
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let parcel_id = create_test_parcel_id(&env, 1);
+    record_many_usages(&env, &client, &farmer, &parcel_id, 160);
+
+    let page = client.get_parcel_usages_paged(&parcel_id, &0, &u32::MAX);
+    assert_eq!(page.usages.len(), crate::water_usage::MAX_USAGE_PAGE_SIZE);
+    assert_eq!(page.total, 160);
+    assert!(page.truncated);
+
+    // Legacy listings return the first clamped page
+    assert_eq!(client.get_parcel_usages(&parcel_id), page.usages);
+    assert_eq!(
+        client.get_farmer_usages(&farmer),
+        client
+            .get_farmer_usages_paged(&farmer, &0, &u32::MAX)
+            .usages
+    );
+
+    let last = client.get_parcel_usages_paged(&parcel_id, &150, &100);
+    assert_eq!(last.usages.len(), 10);
+    assert_eq!(last.usages.get(0).unwrap().volume, 151);
+    assert!(!last.truncated);
+
+    let past_end = client.get_farmer_usages_paged(&farmer, &200, &10);
+    assert_eq!(past_end.usages.len(), 0);
+    assert_eq!(past_end.total, 160);
+    assert!(!past_end.truncated);
+
+    let other = client.get_farmer_usages_paged(&Address::generate(&env), &0, &10);
+    assert_eq!(other.total, 0);
+    assert!(!other.truncated);
+}
@@ -1,5 +1,5 @@
 use crate::{datatypes::*, error::ContractError};
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
 
 /// Validates that a water volume is within acceptable limits
 pub fn validate_water_volume(volume: i128) -> Result<(), ContractError> {
@@ -113,6 +113,58 @@ pub fn require_admin_auth(env: &Env, caller: &Address) -> Result<(), ContractErr
     Ok(())
 }
 
+/// Derives a collision-resistant id as sha256(contract address, counter, timestamp, fields)
+pub fn generate_id(env: &Env, fields: &Bytes) -> BytesN<32> {
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::IdCounter)
+        .unwrap_or(0u64)
+        + 1;
+    env.storage().instance().set(&DataKey::IdCounter, &counter);
+
+    let mut data = Bytes::new(env);
+    data.append(&env.current_contract_address().to_xdr(env));
+    data.append(&Bytes::from_array(env, &counter.to_be_bytes()));
+    data.append(&Bytes::from_array(
+        env,
+        &env.ledger().timestamp().to_be_bytes(),
+    ));
+    data.append(fields);
+
+    env.crypto().sha256(&data).into()
+}
+
+/// Whether callers may still supply their own usage and alert ids (on until the admin turns it off)
+pub fn legacy_ids_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::LegacyIdsEnabled)
+        .unwrap_or(true)
+}
+
+/// Rejects caller-supplied ids once compatibility mode is off
+pub fn require_legacy_ids(env: &Env) -> Result<(), ContractError> {
+    if !legacy_ids_enabled(env) {
+        return Err(ContractError::LegacyIdsDisabled);
+    }
+    Ok(())
+}
+
+/// Turns acceptance of caller-supplied ids on or off (admin only)
+pub fn set_legacy_ids(env: &Env, admin: Address, enabled: bool) -> Result<(), ContractError> {
+    require_admin_auth(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::LegacyIdsEnabled, &enabled);
+
+    env.events()
+        .publish((Symbol::new(env, "legacy_ids_set"), admin), enabled);
+
+    Ok(())
+}
+
 /// Gets the current day timestamp (start of day)
 pub fn get_day_start(timestamp: u64) -> u64 {
     const SECONDS_PER_DAY: u64 = 86400;
@@ -1,5 +1,8 @@
 use crate::{datatypes::*, error::ContractError, utils};
-use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+/// Maximum number of usage records returned by a single listing call
+pub const MAX_USAGE_PAGE_SIZE: u32 = 100;
 
 /// Records water usage data for a parcel or crop
 pub fn record_usage(
@@ -78,6 +81,20 @@ pub fn record_usage(
     Ok(())
 }
 
+/// Generates a usage id for a record submitted without one
+pub fn generate_usage_id(
+    env: &Env,
+    farmer_id: &Address,
+    parcel_id: &BytesN<32>,
+    data_hash: &BytesN<32>,
+) -> BytesN<32> {
+    let mut fields = Bytes::new(env);
+    fields.append(&farmer_id.clone().to_xdr(env));
+    fields.append(&Bytes::from_array(env, &parcel_id.to_array()));
+    fields.append(&Bytes::from_array(env, &data_hash.to_array()));
+    utils::generate_id(env, &fields)
+}
+
 /// Retrieves water usage record by ID
 pub fn get_usage(env: &Env, usage_id: BytesN<32>) -> Result<WaterUsage, ContractError> {
     env.storage()
@@ -168,46 +185,76 @@ pub fn get_usage_report(
     })
 }
 
-/// Gets all usage records for a farmer
+/// Gets the first `MAX_USAGE_PAGE_SIZE` usage records for a farmer
 pub fn get_farmer_usages(env: &Env, farmer_id: Address) -> Vec<WaterUsage> {
+    get_farmer_usages_paged(env, farmer_id, 0, MAX_USAGE_PAGE_SIZE).usages
+}
+
+/// Gets one page of usage records for a farmer, oldest first
+pub fn get_farmer_usages_paged(
+    env: &Env,
+    farmer_id: Address,
+    offset: u32,
+    limit: u32,
+) -> WaterUsagePage {
     let usage_ids: Vec<BytesN<32>> = env
         .storage()
         .persistent()
         .get(&DataKey::FarmerUsages(farmer_id))
         .unwrap_or_else(|| Vec::new(env));
 
-    let mut usages = Vec::new(env);
-    for usage_id in usage_ids.iter() {
-        if let Some(usage) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, WaterUsage>(&DataKey::Usage(usage_id.clone()))
-        {
-            usages.push_back(usage);
-        }
-    }
-
-    usages
+    load_usage_page(env, usage_ids, offset, limit)
 }
 
-/// Gets all usage records for a parcel
+/// Gets the first `MAX_USAGE_PAGE_SIZE` usage records for a parcel
 pub fn get_parcel_usages(env: &Env, parcel_id: BytesN<32>) -> Vec<WaterUsage> {
+    get_parcel_usages_paged(env, parcel_id, 0, MAX_USAGE_PAGE_SIZE).usages
+}
+
+/// Gets one page of usage records for a parcel, oldest first
+pub fn get_parcel_usages_paged(
+    env: &Env,
+    parcel_id: BytesN<32>,
+    offset: u32,
+    limit: u32,
+) -> WaterUsagePage {
     let usage_ids: Vec<BytesN<32>> = env
         .storage()
         .persistent()
         .get(&DataKey::ParcelUsages(parcel_id))
         .unwrap_or_else(|| Vec::new(env));
 
+    load_usage_page(env, usage_ids, offset, limit)
+}
+
+/// Loads the usage records for one page of an index, clamping `limit` to `MAX_USAGE_PAGE_SIZE`
+fn load_usage_page(
+    env: &Env,
+    usage_ids: Vec<BytesN<32>>,
+    offset: u32,
+    limit: u32,
+) -> WaterUsagePage {
+    let total = usage_ids.len();
+    let start = offset.min(total);
+    let end = start
+        .saturating_add(limit.min(MAX_USAGE_PAGE_SIZE))
+        .min(total);
+
     let mut usages = Vec::new(env);
-    for usage_id in usage_ids.iter() {
+    for i in start..end {
+        let usage_id = usage_ids.get_unchecked(i);
         if let Some(usage) = env
             .storage()
             .persistent()
-            .get::<DataKey, WaterUsage>(&DataKey::Usage(usage_id.clone()))
+            .get::<DataKey, WaterUsage>(&DataKey::Usage(usage_id))
         {
             usages.push_back(usage);
         }
     }
 
-    usages
+    WaterUsagePage {
+        usages,
+        total,
+        truncated: end < total,
+    }
 }